@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::{Abs, AbsAssign, UnsignedAbs};
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_abs_primitive_int {
     ($u:ident, $s:ident) => {
@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::{AddMul, AddMulAssign, WrappingAddMul, WrappingAddMulAssign};
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_add_mul_primitive_int {
     ($t:ident) => {
@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::{Ceiling, CeilingAssign};
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_ceiling {
     ($f:ident) => {
@@ -1,5 +1,7 @@
 use crate::num::arithmetic::traits::IsPowerOf2;
+use crate::num::basic::traits::Zero;
 use crate::num::conversion::traits::IntegerMantissaAndExponent;
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_is_power_of_2_unsigned {
     ($t:ident) => {
@@ -29,7 +31,7 @@ macro_rules! impl_is_power_of_2_primitive_float {
             /// See [here](super::is_power_of_2#is_power_of_2).
             #[inline]
             fn is_power_of_2(&self) -> bool {
-                self.is_finite() && *self > 0.0 && self.integer_mantissa() == 1
+                self.is_finite() && *self > $t::ZERO && self.integer_mantissa() == 1
             }
         }
     };
@@ -1,7 +1,9 @@
 use crate::num::arithmetic::traits::{CeilingLogBase2, CheckedLogBase2, FloorLogBase2};
 use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::traits::{One, Zero};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::SciMantissaAndExponent;
+use crate::num::float::half::{BF16, F16};
 use crate::num::logic::traits::{LeadingZeros, TrailingZeros};
 
 fn floor_log_base_2<T: PrimitiveUnsigned>(x: T) -> u64 {
@@ -126,7 +128,7 @@ macro_rules! impl_log_base_2_primitive_float {
             /// See [here](super::log_base_2#floor_log_base_2).
             #[inline]
             fn floor_log_base_2(self) -> i64 {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 self.sci_exponent()
             }
         }
@@ -148,9 +150,9 @@ macro_rules! impl_log_base_2_primitive_float {
             /// See [here](super::log_base_2#ceiling_log_base_2).
             #[inline]
             fn ceiling_log_base_2(self) -> i64 {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 let (mantissa, exponent) = self.sci_mantissa_and_exponent();
-                if mantissa == 1.0 {
+                if mantissa == $t::ONE {
                     exponent
                 } else {
                     exponent + 1
@@ -181,9 +183,9 @@ macro_rules! impl_log_base_2_primitive_float {
             /// See [here](super::log_base_2#checked_log_base_2).
             #[inline]
             fn checked_log_base_2(self) -> Option<i64> {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 let (mantissa, exponent) = self.sci_mantissa_and_exponent();
-                if mantissa == 1.0 {
+                if mantissa == $t::ONE {
                     Some(exponent)
                 } else {
                     None
@@ -1,8 +1,10 @@
 use crate::num::arithmetic::traits::{
     CeilingLogBasePowerOf2, CheckedLogBasePowerOf2, DivMod, DivRound, FloorLogBasePowerOf2,
 };
+use crate::num::basic::traits::{One, Zero};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::{ExactFrom, SciMantissaAndExponent};
+use crate::num::float::half::{BF16, F16};
 use crate::rounding_modes::RoundingMode;
 
 #[cfg(feature = "test_build")]
@@ -155,7 +157,7 @@ macro_rules! impl_log_base_power_of_2_primitive_float {
             /// See [here](super::log_base_power_of_2#floor_log_base_power_of_2).
             #[inline]
             fn floor_log_base_power_of_2(self, pow: u64) -> i64 {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 self.sci_exponent()
                     .div_round(i64::exact_from(pow), RoundingMode::Floor)
             }
@@ -178,9 +180,9 @@ macro_rules! impl_log_base_power_of_2_primitive_float {
             /// See [here](super::log_base_power_of_2#ceiling_log_base_power_of_2).
             #[inline]
             fn ceiling_log_base_power_of_2(self, pow: u64) -> i64 {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 let (mantissa, exponent) = self.sci_mantissa_and_exponent();
-                let exact = mantissa == 1.0;
+                let exact = mantissa == $t::ONE;
                 let (q, r) = exponent.div_mod(i64::exact_from(pow));
                 if exact && r == 0 {
                     q
@@ -213,9 +215,9 @@ macro_rules! impl_log_base_power_of_2_primitive_float {
             /// See [here](super::log_base_power_of_2#checked_log_base_power_of_2).
             #[inline]
             fn checked_log_base_power_of_2(self, pow: u64) -> Option<i64> {
-                assert!(self > 0.0);
+                assert!(self > $t::ZERO);
                 let (mantissa, exponent) = self.sci_mantissa_and_exponent();
-                if mantissa != 1.0 {
+                if mantissa != $t::ONE {
                     return None;
                 }
                 let (q, r) = exponent.div_mod(i64::exact_from(pow));
@@ -1,6 +1,8 @@
 use crate::num::arithmetic::traits::{NextPowerOf2, NextPowerOf2Assign, PowerOf2, Sign};
 use crate::num::basic::floats::PrimitiveFloat;
+use crate::num::basic::traits::{One, Zero};
 use crate::num::conversion::traits::SciMantissaAndExponent;
+use crate::num::float::half::{BF16, F16};
 use std::cmp::Ordering;
 
 macro_rules! impl_next_power_of_2_unsigned {
@@ -60,11 +62,11 @@ macro_rules! impl_next_power_of_2_primitive_float {
             fn next_power_of_2(self) -> $t {
                 assert_eq!(self.sign(), Ordering::Greater);
                 assert!(self.is_finite());
-                if self == 0.0 {
+                if self == $t::ZERO {
                     return $t::MIN_POSITIVE_SUBNORMAL;
                 }
                 let (mantissa, exponent) = self.sci_mantissa_and_exponent();
-                if mantissa == 1.0 {
+                if mantissa == $t::ONE {
                     self
                 } else if exponent == $t::MAX_EXPONENT {
                     panic!("Next power of 2 is too large to represent");
@@ -1,5 +1,6 @@
 use crate::num::arithmetic::traits::{Parity, Pow, PowAssign};
 use crate::num::conversion::traits::ExactFrom;
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_pow_unsigned {
     ($t:ident) => {
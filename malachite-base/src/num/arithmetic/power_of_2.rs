@@ -2,6 +2,7 @@ use crate::num::arithmetic::traits::PowerOf2;
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::IntegerMantissaAndExponent;
+use crate::num::float::half::{BF16, F16};
 
 fn power_of_2_unsigned<T: PrimitiveUnsigned>(pow: u64) -> T {
     assert!(pow < T::WIDTH);
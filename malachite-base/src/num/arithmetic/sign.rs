@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::Sign;
+use crate::num::float::half::{BF16, F16};
 use std::cmp::Ordering;
 
 macro_rules! impl_sign_primitive_int {
@@ -6,6 +6,7 @@ use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::WrappingFrom;
+use crate::num::float::half::{BF16, F16};
 use crate::num::logic::traits::SignificantBits;
 use crate::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -197,6 +198,10 @@ pub fn sqrt_rem_newton<
 >(
     n: U,
 ) -> (U, U) {
+    if U::WIDTH == u128::WIDTH {
+        // There are no magic constants for 128-bit limbs, so fall back to the general algorithm.
+        return n.sqrt_rem();
+    }
     let magic = match U::WIDTH {
         u32::WIDTH => {
             U::wrapping_from(0x100000u32) // 0xfee6f < MAGIC < 0x29cbc8
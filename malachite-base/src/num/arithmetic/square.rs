@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::{Square, SquareAssign};
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_square {
     ($t:ident) => {
@@ -1,4 +1,5 @@
 use crate::num::arithmetic::traits::{SubMul, SubMulAssign, WrappingSubMul, WrappingSubMulAssign};
+use crate::num::float::half::{BF16, F16};
 
 macro_rules! impl_sub_mul_primitive_int {
     ($t:ident) => {
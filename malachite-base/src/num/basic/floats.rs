@@ -8,8 +8,9 @@ use crate::num::arithmetic::traits::{
 };
 use crate::num::basic::traits::{Iverson, NegativeOne, One, OneHalf, Two, Zero};
 use crate::num::conversion::traits::{
-    ConvertibleFrom, ExactInto, IntegerMantissaAndExponent, IsInteger, RawMantissaAndExponent,
-    RoundingFrom, RoundingInto, SciMantissaAndExponent, WrappingFrom,
+    ConvertibleFrom, ExactInto, FromSciString, IntegerMantissaAndExponent, IsInteger,
+    RawMantissaAndExponent, RoundingFrom, RoundingInto, SciMantissaAndExponent, ToSci,
+    WrappingFrom,
 };
 use crate::num::float::FmtRyuString;
 use crate::num::logic::traits::{BitAccess, LowMask, SignificantBits, TrailingZeros};
@@ -22,7 +23,8 @@ use std::ops::{
 };
 use std::str::FromStr;
 
-/// This trait defines functions on primitive float types: [`f32`] and [`f64`].
+/// This trait defines functions on primitive float types: [`f32`], [`f64`], and the 16-bit types
+/// [`F16`](crate::num::float::half::F16) and [`BF16`](crate::num::float::half::BF16).
 ///
 /// Many of the functions here concern exponents and mantissas. We define three ways to express a
 /// float, each with its own exponent and mantissa. In the following, let $x$ be an arbitrary
@@ -109,6 +111,7 @@ pub trait PrimitiveFloat:
     + FloorAssign
     + FmtRyuString
     + From<f32>
+    + FromSciString
     + FromStr
     + IsInteger
     + IsPowerOf2
@@ -159,6 +162,7 @@ pub trait PrimitiveFloat:
     + SubMul<Output = Self>
     + SubMulAssign<Self, Self>
     + Sum<Self>
+    + ToSci
     + Two
     + UpperExp
     + Zero
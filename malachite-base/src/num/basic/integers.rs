@@ -27,9 +27,10 @@ use crate::num::arithmetic::traits::{
 use crate::num::basic::traits::{Iverson, One, Two, Zero};
 use crate::num::comparison::traits::{EqAbs, OrdAbs, PartialOrdAbs};
 use crate::num::conversion::traits::{
-    ConvertibleFrom, ExactFrom, ExactInto, FromSciString, FromStringBase, IsInteger,
-    OverflowingFrom, OverflowingInto, RoundingFrom, RoundingInto, SaturatingFrom, SaturatingInto,
-    ToSci, ToStringBase, WrappingFrom, WrappingInto,
+    ConvertibleFrom, ExactFrom, ExactInto, FromSciString, FromStringBase, FromStringWithAlphabet,
+    FromStringWithOptions, IsInteger, OverflowingFrom, OverflowingInto, RoundingFrom, RoundingInto,
+    SaturatingFrom, SaturatingInto, ToSci, ToStringBase, ToStringWithAlphabet, WrappingFrom,
+    WrappingInto,
 };
 use crate::num::float::NiceFloat;
 use crate::num::logic::traits::{
@@ -152,6 +153,8 @@ pub trait PrimitiveInt:
     + FromSciString
     + FromStr
     + FromStringBase
+    + FromStringWithAlphabet
+    + FromStringWithOptions
     + HasRandomPrimitiveInts
     + Hash
     + IsInteger
@@ -320,6 +323,7 @@ pub trait PrimitiveInt:
     + Sum<Self>
     + ToSci
     + ToStringBase
+    + ToStringWithAlphabet
     + TrailingZeros
     + TryFrom<NiceFloat<f32>>
     + TryFrom<i16>
@@ -8,6 +8,7 @@ use crate::num::conversion::traits::{
     ConvertibleFrom, OverflowingFrom, RoundingFrom, SaturatingFrom, SciMantissaAndExponent,
     WrappingFrom,
 };
+use crate::num::float::half::{BF16, F16};
 use crate::num::float::NiceFloat;
 use crate::rounding_modes::RoundingMode;
 use std::ops::Neg;
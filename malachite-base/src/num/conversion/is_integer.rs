@@ -1,5 +1,6 @@
 use crate::num::basic::floats::PrimitiveFloat;
 use crate::num::conversion::traits::{IsInteger, WrappingFrom};
+use crate::num::float::half::{BF16, F16};
 use crate::num::logic::traits::TrailingZeros;
 
 fn is_integer_float<T: PrimitiveFloat>(x: T) -> bool {
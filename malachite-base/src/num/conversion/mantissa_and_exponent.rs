@@ -8,6 +8,7 @@ use crate::num::conversion::traits::{
     ExactFrom, ExactInto, IntegerMantissaAndExponent, RawMantissaAndExponent,
     SciMantissaAndExponent, WrappingFrom,
 };
+use crate::num::float::half::{BF16, F16};
 use crate::num::logic::traits::{BitAccess, LeadingZeros, LowMask, SignificantBits, TrailingZeros};
 use crate::rounding_modes::RoundingMode;
 
@@ -0,0 +1,627 @@
+use crate::num::arithmetic::traits::UnsignedAbs;
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::traits::Zero;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::string::from_string::{ParseNumberError, ParseNumberErrorKind};
+use crate::num::conversion::traits::{
+    ConvertibleFrom, FromStringWithAlphabet, ToStringWithAlphabet, WrappingFrom,
+};
+
+/// The symbols that stand for the digits of a number in some base, used by
+/// [`ToStringWithAlphabet`] and [`FromStringWithAlphabet`].
+///
+/// The base is the number of symbols, and the $i$th symbol stands for the digit $i$. Every symbol
+/// is a printable, non-space ASCII character. An alphabet may also be case-insensitive, in which
+/// case a letter is accepted in either case when parsing, but is always written in the case in
+/// which it appears in the alphabet.
+///
+/// Several commonly-used alphabets are provided as constants.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DigitAlphabet {
+    digits: &'static [u8],
+    case_insensitive: bool,
+    // Extra symbols that are accepted when parsing, along with the digits they stand for
+    aliases: &'static [(u8, u8)],
+    // A symbol that may appear between digits when parsing, and is ignored
+    separator: Option<u8>,
+}
+
+/// The symbols used for the check symbol values 32 through 36 in Crockford's base 32. Values 0
+/// through 31 use the digits of [`DigitAlphabet::CROCKFORD_BASE32`].
+pub const CROCKFORD_CHECK_SYMBOLS: &[u8; 5] = b"*~$=U";
+
+impl DigitAlphabet {
+    /// The alphabet used by Bitcoin's base 58. It omits the easily-confused characters '0', 'O',
+    /// 'I', and 'l'.
+    pub const BASE58_BITCOIN: DigitAlphabet =
+        DigitAlphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// A base-62 alphabet: the decimal digits, followed by the uppercase letters, followed by the
+    /// lowercase letters.
+    pub const BASE62: DigitAlphabet =
+        DigitAlphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+
+    /// The URL- and filename-safe base-64 alphabet from RFC 4648.
+    pub const BASE64_URL: DigitAlphabet =
+        DigitAlphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+
+    /// Douglas Crockford's base-32 alphabet. It omits 'I', 'L', 'O', and 'U'. When parsing, it is
+    /// case-insensitive, 'O' is read as 0, 'I' and 'L' are read as 1, and hyphens between digits
+    /// are ignored.
+    pub const CROCKFORD_BASE32: DigitAlphabet = DigitAlphabet {
+        digits: b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+        case_insensitive: true,
+        aliases: &[(b'O', 0), (b'I', 1), (b'L', 1)],
+        separator: Some(b'-'),
+    };
+
+    /// Creates a new case-sensitive [`DigitAlphabet`] from a string of digit symbols.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `digits.len()`.
+    ///
+    /// # Panics
+    /// Panics if `digits` has fewer than two symbols, if any symbol is not a printable, non-space
+    /// ASCII character, or if any symbol appears more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// let alphabet = DigitAlphabet::new("01234567");
+    /// assert_eq!(alphabet.get_base(), 8);
+    /// ```
+    pub const fn new(digits: &'static str) -> DigitAlphabet {
+        let digits = digits.as_bytes();
+        assert!(
+            digits.len() >= 2,
+            "an alphabet must have at least two digits"
+        );
+        let mut i = 0;
+        while i < digits.len() {
+            assert!(
+                digits[i].is_ascii_graphic(),
+                "alphabet symbols must be printable ASCII"
+            );
+            let mut j = 0;
+            while j < i {
+                assert!(digits[i] != digits[j], "alphabet symbols must be distinct");
+                j += 1;
+            }
+            i += 1;
+        }
+        DigitAlphabet {
+            digits,
+            case_insensitive: false,
+            aliases: &[],
+            separator: None,
+        }
+    }
+
+    /// Returns a case-insensitive version of a [`DigitAlphabet`]: when parsing, letters are
+    /// accepted in either case.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_base()`.
+    ///
+    /// # Panics
+    /// Panics if the alphabet contains both the uppercase and the lowercase version of a letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// let alphabet = DigitAlphabet::new("0123456789abcdef").case_insensitive();
+    /// assert_eq!(alphabet.byte_to_digit(b'F'), Some(15));
+    /// ```
+    pub const fn case_insensitive(mut self) -> DigitAlphabet {
+        let mut i = 0;
+        while i < self.digits.len() {
+            let mut j = 0;
+            while j < i {
+                assert!(
+                    !self.digits[i].eq_ignore_ascii_case(&self.digits[j]),
+                    "alphabet symbols must be distinct when case is ignored"
+                );
+                j += 1;
+            }
+            i += 1;
+        }
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Returns the base of a [`DigitAlphabet`]; that is, its number of digits.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE58_BITCOIN.get_base(), 58);
+    /// assert_eq!(DigitAlphabet::CROCKFORD_BASE32.get_base(), 32);
+    /// ```
+    pub const fn get_base(&self) -> u8 {
+        // An alphabet has at most 94 symbols, so this cast is lossless
+        self.digits.len() as u8
+    }
+
+    /// Returns whether a [`DigitAlphabet`] is case-insensitive.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert!(!DigitAlphabet::BASE62.is_case_insensitive());
+    /// assert!(DigitAlphabet::CROCKFORD_BASE32.is_case_insensitive());
+    /// ```
+    pub const fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Returns the symbol that stands for a digit, or `None` if the digit is not less than the
+    /// base.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE58_BITCOIN.digit_to_byte(0), Some(b'1'));
+    /// assert_eq!(DigitAlphabet::BASE58_BITCOIN.digit_to_byte(57), Some(b'z'));
+    /// assert_eq!(DigitAlphabet::BASE58_BITCOIN.digit_to_byte(58), None);
+    /// ```
+    pub const fn digit_to_byte(&self, digit: u8) -> Option<u8> {
+        if (digit as usize) < self.digits.len() {
+            Some(self.digits[digit as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the digit that a symbol stands for, or `None` if the symbol is not accepted by the
+    /// alphabet.
+    ///
+    /// Separators are not digits, so `None` is returned for them.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_base()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE62.byte_to_digit(b'a'), Some(36));
+    /// assert_eq!(DigitAlphabet::BASE62.byte_to_digit(b'-'), None);
+    /// assert_eq!(DigitAlphabet::CROCKFORD_BASE32.byte_to_digit(b'z'), Some(31));
+    /// assert_eq!(DigitAlphabet::CROCKFORD_BASE32.byte_to_digit(b'o'), Some(0));
+    /// assert_eq!(DigitAlphabet::CROCKFORD_BASE32.byte_to_digit(b'u'), None);
+    /// ```
+    pub const fn byte_to_digit(&self, b: u8) -> Option<u8> {
+        let mut i = 0;
+        while i < self.digits.len() {
+            if self.matches(self.digits[i], b) {
+                return Some(i as u8);
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < self.aliases.len() {
+            let (alias, digit) = self.aliases[i];
+            if self.matches(alias, b) {
+                return Some(digit);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    const fn matches(&self, symbol: u8, b: u8) -> bool {
+        if self.case_insensitive {
+            symbol.eq_ignore_ascii_case(&b)
+        } else {
+            symbol == b
+        }
+    }
+
+    // Maps every byte to the digit it stands for, or to `u8::MAX` if it's not accepted.
+    fn decoding_table(&self) -> [u8; 256] {
+        let mut table = [u8::MAX; 256];
+        for b in 0..=u8::MAX {
+            if let Some(digit) = self.byte_to_digit(b) {
+                table[usize::from(b)] = digit;
+            }
+        }
+        table
+    }
+
+    /// Converts digits, in any order, to the string of corresponding symbols.
+    ///
+    /// This is a building block for implementations of [`ToStringWithAlphabet`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `digits.len()`.
+    ///
+    /// # Panics
+    /// Panics if any digit is not less than the base.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE62.encode_digits(vec![1, 10, 61]), "1Az");
+    /// ```
+    pub fn encode_digits(&self, mut digits: Vec<u8>) -> String {
+        for digit in &mut digits {
+            *digit = self.digit_to_byte(*digit).expect("digit out of range");
+        }
+        String::from_utf8(digits).unwrap()
+    }
+
+    /// Converts a string of symbols to the digits they stand for, in the same order.
+    ///
+    /// If the alphabet has a separator, separators are removed; each must be between two digits.
+    /// `offset` is the byte offset of `s` within a larger string; it is added to the offsets of
+    /// any errors. This is a building block for implementations of [`FromStringWithAlphabet`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    ///
+    /// assert_eq!(DigitAlphabet::BASE62.decode_digits("1Az", 0), Ok(vec![1, 10, 61]));
+    /// assert_eq!(DigitAlphabet::CROCKFORD_BASE32.decode_digits("1o-z", 0), Ok(vec![1, 0, 31]));
+    /// assert_eq!(
+    ///     DigitAlphabet::BASE58_BITCOIN.decode_digits("10", 3),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 4))
+    /// );
+    /// assert_eq!(
+    ///     DigitAlphabet::BASE58_BITCOIN.decode_digits("", 3),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 3))
+    /// );
+    /// ```
+    pub fn decode_digits(&self, s: &str, offset: usize) -> Result<Vec<u8>, ParseNumberError> {
+        if s.is_empty() {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::Empty, offset));
+        }
+        let table = self.decoding_table();
+        let mut digits = Vec::with_capacity(s.len());
+        let mut previous_is_digit = false;
+        for (i, b) in s.bytes().enumerate() {
+            let digit = table[usize::from(b)];
+            if digit != u8::MAX {
+                digits.push(digit);
+                previous_is_digit = true;
+            } else if self.separator == Some(b) && previous_is_digit {
+                previous_is_digit = false;
+            } else {
+                return Err(ParseNumberError::new(
+                    if self.separator == Some(b) {
+                        ParseNumberErrorKind::MisplacedSeparator
+                    } else {
+                        ParseNumberErrorKind::InvalidDigit
+                    },
+                    offset + i,
+                ));
+            }
+        }
+        if !previous_is_digit {
+            return Err(ParseNumberError::new(
+                ParseNumberErrorKind::MisplacedSeparator,
+                offset + s.len() - 1,
+            ));
+        }
+        Ok(digits)
+    }
+
+    /// Determines whether a string written in a [`DigitAlphabet`] starts with a minus sign.
+    /// Returns whether it does, along with the offset of the first byte after the sign.
+    ///
+    /// A leading `'-'` is only a sign if the alphabet does not use `'-'` as a digit.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_base()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE62.strip_minus_sign("-1Az"), (true, 1));
+    /// assert_eq!(DigitAlphabet::BASE64_URL.strip_minus_sign("-1Az"), (false, 0));
+    /// ```
+    pub const fn strip_minus_sign(&self, s: &str) -> (bool, usize) {
+        if !s.is_empty() && s.as_bytes()[0] == b'-' && self.byte_to_digit(b'-').is_none() {
+            (true, 1)
+        } else {
+            (false, 0)
+        }
+    }
+
+    /// Returns the symbol written before negative numbers, panicking if there is none.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_base()`.
+    ///
+    /// # Panics
+    /// Panics if the alphabet uses `'-'` as a digit.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    ///
+    /// assert_eq!(DigitAlphabet::BASE62.minus_sign(), b'-');
+    /// ```
+    pub const fn minus_sign(&self) -> u8 {
+        assert!(
+            self.byte_to_digit(b'-').is_none(),
+            "cannot write a negative number with an alphabet that uses '-' as a digit"
+        );
+        b'-'
+    }
+}
+
+/// Returns the Crockford base-32 check symbol for a value less than 37.
+///
+/// The value is usually a number modulo 37.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Panics
+/// Panics if `value` is greater than 36.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::crockford_check_symbol;
+///
+/// assert_eq!(crockford_check_symbol(10), b'A');
+/// assert_eq!(crockford_check_symbol(32), b'*');
+/// assert_eq!(crockford_check_symbol(36), b'U');
+/// ```
+pub const fn crockford_check_symbol(value: u8) -> u8 {
+    assert!(value < 37, "check value out of range");
+    if value < 32 {
+        DigitAlphabet::CROCKFORD_BASE32.digits[value as usize]
+    } else {
+        CROCKFORD_CHECK_SYMBOLS[(value - 32) as usize]
+    }
+}
+
+/// Returns the value of a Crockford base-32 check symbol, or `None` if the byte is not a check
+/// symbol. Like the digits, check symbols are case-insensitive.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::crockford_check_value;
+///
+/// assert_eq!(crockford_check_value(b'a'), Some(10));
+/// assert_eq!(crockford_check_value(b'$'), Some(34));
+/// assert_eq!(crockford_check_value(b'u'), Some(36));
+/// assert_eq!(crockford_check_value(b'-'), None);
+/// ```
+pub const fn crockford_check_value(b: u8) -> Option<u8> {
+    if let Some(value) = DigitAlphabet::CROCKFORD_BASE32.byte_to_digit(b) {
+        return Some(value);
+    }
+    let mut i = 0;
+    while i < CROCKFORD_CHECK_SYMBOLS.len() {
+        if CROCKFORD_CHECK_SYMBOLS[i].eq_ignore_ascii_case(&b) {
+            return Some(32 + i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn to_string_with_alphabet_unsigned<T: PrimitiveUnsigned>(
+    x: &T,
+    alphabet: DigitAlphabet,
+) -> String {
+    if *x == T::ZERO {
+        alphabet.encode_digits(vec![0])
+    } else {
+        alphabet.encode_digits(x.to_digits_desc(&alphabet.get_base()))
+    }
+}
+
+fn from_string_with_alphabet_unsigned<T: PrimitiveUnsigned>(
+    alphabet: DigitAlphabet,
+    s: &str,
+) -> Result<T, ParseNumberError> {
+    let digits = alphabet.decode_digits(s, 0)?;
+    T::from_digits_desc(&alphabet.get_base(), digits.into_iter())
+        .ok_or_else(|| ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+}
+
+macro_rules! impl_alphabet_unsigned {
+    ($t:ident) => {
+        impl ToStringWithAlphabet for $t {
+            /// Converts an unsigned number to a string using the digits of a [`DigitAlphabet`].
+            ///
+            /// Zero is written as the alphabet's zero digit; otherwise, there are no leading
+            /// zeros.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::alphabet#to_string_with_alphabet).
+            #[inline]
+            fn to_string_with_alphabet(&self, alphabet: DigitAlphabet) -> String {
+                to_string_with_alphabet_unsigned(self, alphabet)
+            }
+        }
+
+        impl FromStringWithAlphabet for $t {
+            /// Converts a string written using the digits of a [`DigitAlphabet`] to an unsigned
+            /// number.
+            ///
+            /// If the string is not a valid number or the number is too large, an error is
+            /// returned. Leading zeros are allowed.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::alphabet#from_string_with_alphabet).
+            #[inline]
+            fn from_string_with_alphabet(
+                alphabet: DigitAlphabet,
+                s: &str,
+            ) -> Result<$t, ParseNumberError> {
+                from_string_with_alphabet_unsigned(alphabet, s)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_alphabet_unsigned);
+
+fn to_string_with_alphabet_signed<
+    U: PrimitiveUnsigned,
+    S: Copy + Ord + UnsignedAbs<Output = U> + Zero,
+>(
+    x: &S,
+    alphabet: DigitAlphabet,
+) -> String {
+    let s = to_string_with_alphabet_unsigned(&x.unsigned_abs(), alphabet);
+    if *x < S::ZERO {
+        let mut signed = String::with_capacity(s.len() + 1);
+        signed.push(char::from(alphabet.minus_sign()));
+        signed.push_str(&s);
+        signed
+    } else {
+        s
+    }
+}
+
+fn from_string_with_alphabet_signed<
+    U: PrimitiveUnsigned,
+    S: ConvertibleFrom<U> + PrimitiveSigned + WrappingFrom<U>,
+>(
+    alphabet: DigitAlphabet,
+    s: &str,
+) -> Result<S, ParseNumberError> {
+    let (negative, start) = alphabet.strip_minus_sign(s);
+    let digits = alphabet.decode_digits(&s[start..], start)?;
+    let out_of_range = ParseNumberError::new(ParseNumberErrorKind::OutOfRange, start);
+    let abs = U::from_digits_desc(&alphabet.get_base(), digits.into_iter()).ok_or(out_of_range)?;
+    if negative {
+        // Negating wraps to a positive value exactly when `abs` exceeds `-S::MIN`
+        let x = S::wrapping_from(abs).wrapping_neg();
+        if x > S::ZERO {
+            Err(out_of_range)
+        } else {
+            Ok(x)
+        }
+    } else if S::convertible_from(abs) {
+        Ok(S::wrapping_from(abs))
+    } else {
+        Err(out_of_range)
+    }
+}
+
+macro_rules! impl_alphabet_signed {
+    ($u:ident, $s:ident) => {
+        impl ToStringWithAlphabet for $s {
+            /// Converts a signed number to a string using the digits of a [`DigitAlphabet`].
+            ///
+            /// Zero is written as the alphabet's zero digit; otherwise, there are no leading
+            /// zeros. Negative numbers are preceded by `'-'`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is negative and the alphabet uses `'-'` as a digit.
+            ///
+            /// # Examples
+            /// See [here](super::alphabet#to_string_with_alphabet).
+            #[inline]
+            fn to_string_with_alphabet(&self, alphabet: DigitAlphabet) -> String {
+                to_string_with_alphabet_signed::<$u, $s>(self, alphabet)
+            }
+        }
+
+        impl FromStringWithAlphabet for $s {
+            /// Converts a string written using the digits of a [`DigitAlphabet`] to a signed
+            /// number.
+            ///
+            /// The string may start with `'-'`, unless the alphabet uses `'-'` as a digit. If the
+            /// string is not a valid number or the number is out of range, an error is returned.
+            /// Leading zeros are allowed.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::alphabet#from_string_with_alphabet).
+            #[inline]
+            fn from_string_with_alphabet(
+                alphabet: DigitAlphabet,
+                s: &str,
+            ) -> Result<$s, ParseNumberError> {
+                from_string_with_alphabet_signed::<$u, $s>(alphabet, s)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_alphabet_signed);
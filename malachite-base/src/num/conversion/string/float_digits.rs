@@ -0,0 +1,552 @@
+use crate::num::arithmetic::traits::Parity;
+use crate::num::basic::floats::PrimitiveFloat;
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::conversion::string::from_string::digit_from_display_byte;
+use crate::num::conversion::string::options::SciSizeOptions;
+use crate::num::conversion::traits::{ExactFrom, RawMantissaAndExponent, WrappingFrom};
+use crate::num::logic::traits::{LowMask, SignificantBits};
+use crate::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+
+// A minimal arbitrary-precision natural number, stored as little-endian `u32` limbs with no
+// trailing zero limbs. `malachite-base` has no bignum type of its own, but converting primitive
+// floats to and from strings exactly requires numbers like $2^{1074}$ and $10^{324}$.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct BigNat(Vec<u32>);
+
+impl BigNat {
+    fn from_u64(x: u64) -> BigNat {
+        let mut n = BigNat(vec![u32::wrapping_from(x), u32::wrapping_from(x >> 32)]);
+        n.trim();
+        n
+    }
+
+    fn power_of_2(pow: u64) -> BigNat {
+        let mut n = BigNat::from_u64(1);
+        n.shl_assign(pow);
+        n
+    }
+
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn significant_bits(&self) -> u64 {
+        match self.0.last() {
+            None => 0,
+            Some(&x) => (u64::exact_from(self.0.len()) - 1) * 32 + x.significant_bits(),
+        }
+    }
+
+    fn mul_small_assign(&mut self, y: u32) {
+        let mut carry = 0;
+        for x in &mut self.0 {
+            let product = u64::from(*x) * u64::from(y) + carry;
+            *x = u32::wrapping_from(product);
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.0.push(u32::wrapping_from(carry));
+        }
+        self.trim();
+    }
+
+    fn mul_power_assign(&mut self, base: u32, pow: u64) {
+        for _ in 0..pow {
+            self.mul_small_assign(base);
+        }
+    }
+
+    fn add_small_assign(&mut self, y: u32) {
+        let mut carry = y;
+        for x in &mut self.0 {
+            if carry == 0 {
+                return;
+            }
+            let (sum, overflow) = x.overflowing_add(carry);
+            *x = sum;
+            carry = u32::from(overflow);
+        }
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    fn add_assign(&mut self, other: &BigNat) {
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), 0);
+        }
+        let mut carry = false;
+        for (i, x) in self.0.iter_mut().enumerate() {
+            let y = other.0.get(i).copied().unwrap_or(0);
+            if y == 0 && !carry && i >= other.0.len() {
+                break;
+            }
+            let (sum, overflow_1) = x.overflowing_add(y);
+            let (sum, overflow_2) = sum.overflowing_add(u32::from(carry));
+            *x = sum;
+            carry = overflow_1 || overflow_2;
+        }
+        if carry {
+            self.0.push(1);
+        }
+    }
+
+    // `self` must be greater than or equal to `other`.
+    fn sub_assign(&mut self, other: &BigNat) {
+        let mut borrow = false;
+        for (i, x) in self.0.iter_mut().enumerate() {
+            let y = other.0.get(i).copied().unwrap_or(0);
+            if y == 0 && !borrow && i >= other.0.len() {
+                break;
+            }
+            let (diff, overflow_1) = x.overflowing_sub(y);
+            let (diff, overflow_2) = diff.overflowing_sub(u32::from(borrow));
+            *x = diff;
+            borrow = overflow_1 || overflow_2;
+        }
+        assert!(!borrow);
+        self.trim();
+    }
+
+    fn shl_assign(&mut self, bits: u64) {
+        if self.is_zero() {
+            return;
+        }
+        let limbs = usize::exact_from(bits >> 5);
+        let bits = u32::wrapping_from(bits & 31);
+        if bits != 0 {
+            let mut carry = 0;
+            for x in &mut self.0 {
+                let shifted = (*x << bits) | carry;
+                carry = *x >> (32 - bits);
+                *x = shifted;
+            }
+            if carry != 0 {
+                self.0.push(carry);
+            }
+        }
+        if limbs != 0 {
+            let mut xs = vec![0; limbs];
+            xs.append(&mut self.0);
+            self.0 = xs;
+        }
+    }
+
+    // Replaces `self` with `self % d` and returns `self / d`, which must be small.
+    fn div_rem_small_quotient(&mut self, d: &BigNat) -> u32 {
+        let mut q = 0;
+        while *self >= *d {
+            self.sub_assign(d);
+            q += 1;
+        }
+        q
+    }
+
+    // Compares `self + other` with `rhs`.
+    fn sum_cmp(&self, other: &BigNat, rhs: &BigNat) -> Ordering {
+        let mut sum = self.clone();
+        sum.add_assign(other);
+        sum.cmp(rhs)
+    }
+
+    // Compares `2 * self` with `rhs`.
+    fn double_cmp(&self, rhs: &BigNat) -> Ordering {
+        let mut double = self.clone();
+        double.shl_assign(1);
+        double.cmp(rhs)
+    }
+}
+
+impl PartialOrd for BigNat {
+    fn partial_cmp(&self, other: &BigNat) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNat {
+    fn cmp(&self, other: &BigNat) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+// Returns the integer mantissa $m$ and exponent $e$ of a positive finite float, where the float is
+// equal to $m2^e$. Unlike `integer_mantissa_and_exponent`, the mantissa is not reduced, so for
+// normal floats it always has `T::MANTISSA_WIDTH + 1` significant bits.
+fn unreduced_mantissa_and_exponent<T: PrimitiveFloat>(x: T) -> (u64, i64) {
+    let (raw_mantissa, raw_exponent) = x.raw_mantissa_and_exponent();
+    if raw_exponent == 0 {
+        (raw_mantissa, T::MIN_EXPONENT)
+    } else {
+        (
+            raw_mantissa | (1 << T::MANTISSA_WIDTH),
+            i64::wrapping_from(raw_exponent) + T::MIN_EXPONENT - 1,
+        )
+    }
+}
+
+// Returns a lower bound for $\lceil \log_b (m2^e) \rceil$ that is off by at most 2.
+fn estimate_log_base(mantissa: u64, exponent: i64, base: u8) -> i64 {
+    let log_2 = (exponent + i64::wrapping_from(mantissa.significant_bits()) - 1) as f64;
+    (log_2 / f64::from(base).log2()).floor() as i64 - 1
+}
+
+// Multiplies either `r` or `s` by $b^{|k|}$ so that $r/s$ is divided by $b^k$.
+fn scale_by_power_of_base(r: &mut BigNat, s: &mut BigNat, base: u32, k: i64) {
+    if k >= 0 {
+        s.mul_power_assign(base, k.unsigned_abs());
+    } else {
+        r.mul_power_assign(base, k.unsigned_abs());
+    }
+}
+
+// Computes the digits of the shortest base-$b$ string that rounds back to `x` (assuming that the
+// string is parsed using round-to-nearest, ties-to-even), using the free-format algorithm of
+// Steele and White, as improved by Burger and Dybvig.
+//
+// `x` must be positive and finite. Returns the digit values and the exponent of the first digit.
+pub(crate) fn shortest_digits<T: PrimitiveFloat>(x: T, base: u8) -> (Vec<u8>, i64) {
+    let (mantissa, exponent) = unreduced_mantissa_and_exponent(x);
+    let (raw_mantissa, raw_exponent) = x.raw_mantissa_and_exponent();
+    let even = mantissa.even();
+    // If `x` is a power of 2, and not the smallest normal float, the gap to its predecessor is half
+    // the gap to its successor.
+    let lower_gap_smaller = raw_mantissa == 0 && raw_exponent > 1;
+    // x = r / s; the gaps to the neighboring floats, divided by 2, are m_plus / s and m_minus / s.
+    let (mut r, mut s, mut m_plus, mut m_minus);
+    if exponent >= 0 {
+        let power = BigNat::power_of_2(exponent.unsigned_abs());
+        r = BigNat::from_u64(mantissa);
+        r.shl_assign(exponent.unsigned_abs());
+        m_minus = power.clone();
+        m_plus = power;
+        if lower_gap_smaller {
+            r.shl_assign(2);
+            s = BigNat::from_u64(4);
+            m_plus.shl_assign(1);
+        } else {
+            r.shl_assign(1);
+            s = BigNat::from_u64(2);
+        }
+    } else {
+        r = BigNat::from_u64(mantissa);
+        m_minus = BigNat::from_u64(1);
+        if lower_gap_smaller {
+            r.shl_assign(2);
+            s = BigNat::power_of_2(2 + exponent.unsigned_abs());
+            m_plus = BigNat::from_u64(2);
+        } else {
+            r.shl_assign(1);
+            s = BigNat::power_of_2(1 + exponent.unsigned_abs());
+            m_plus = BigNat::from_u64(1);
+        }
+    }
+    let b = u32::from(base);
+    // Find the smallest k such that the upper boundary is less than b^k (or at most b^k, if the
+    // boundaries are inclusive).
+    let mut k = estimate_log_base(mantissa, exponent, base);
+    if k >= 0 {
+        s.mul_power_assign(b, k.unsigned_abs());
+    } else {
+        let pow = k.unsigned_abs();
+        r.mul_power_assign(b, pow);
+        m_plus.mul_power_assign(b, pow);
+        m_minus.mul_power_assign(b, pow);
+    }
+    loop {
+        let c = r.sum_cmp(&m_plus, &s);
+        if c == Ordering::Greater || (even && c == Ordering::Equal) {
+            s.mul_small_assign(b);
+            k += 1;
+        } else {
+            break;
+        }
+    }
+    let mut digits = Vec::new();
+    loop {
+        r.mul_small_assign(b);
+        m_plus.mul_small_assign(b);
+        m_minus.mul_small_assign(b);
+        let d = u8::wrapping_from(r.div_rem_small_quotient(&s));
+        let low_c = r.cmp(&m_minus);
+        let high_c = r.sum_cmp(&m_plus, &s);
+        let low = low_c == Ordering::Less || (even && low_c == Ordering::Equal);
+        let high = high_c == Ordering::Greater || (even && high_c == Ordering::Equal);
+        match (low, high) {
+            (false, false) => digits.push(d),
+            (true, false) => {
+                digits.push(d);
+                break;
+            }
+            (false, true) => {
+                digits.push(d + 1);
+                break;
+            }
+            (true, true) => {
+                digits.push(if r.double_cmp(&s) == Ordering::Less {
+                    d
+                } else {
+                    d + 1
+                });
+                break;
+            }
+        }
+    }
+    (digits, k - 1)
+}
+
+// Returns whether a positive finite float has a terminating expansion in base $b$.
+pub(crate) fn has_terminating_expansion<T: PrimitiveFloat>(x: T, base: u8) -> bool {
+    let (mantissa, exponent) = unreduced_mantissa_and_exponent(x);
+    base.even() || exponent >= 0 || u64::from(mantissa.trailing_zeros()) >= exponent.unsigned_abs()
+}
+
+// Computes the base-$b$ digits of a positive finite float, rounded according to `size_options`
+// and `rm`. If `size_options` is `Complete`, the expansion of `x` in base $b$ must terminate.
+//
+// Returns the digit values, the exponent of the first digit, and whether the digits represent `x`
+// exactly. If `x` rounds to zero, the digits are empty. If `rm` is `Exact` but rounding is
+// necessary, the result is truncated.
+pub(crate) fn rounded_digits<T: PrimitiveFloat>(
+    x: T,
+    base: u8,
+    size_options: SciSizeOptions,
+    rm: RoundingMode,
+) -> (Vec<u8>, i64, bool) {
+    let (mantissa, exponent) = unreduced_mantissa_and_exponent(x);
+    // x = r / s
+    let mut r = BigNat::from_u64(mantissa);
+    let mut s = BigNat::from_u64(1);
+    if exponent >= 0 {
+        r.shl_assign(exponent.unsigned_abs());
+    } else {
+        s.shl_assign(exponent.unsigned_abs());
+    }
+    let b = u32::from(base);
+    // Find k such that b^(k - 1) <= x < b^k.
+    let mut k = estimate_log_base(mantissa, exponent, base);
+    scale_by_power_of_base(&mut r, &mut s, b, k);
+    while r >= s {
+        s.mul_small_assign(b);
+        k += 1;
+    }
+    loop {
+        let mut next = r.clone();
+        next.mul_small_assign(b);
+        if next >= s {
+            break;
+        }
+        r = next;
+        k -= 1;
+    }
+    let mut log = k - 1;
+    let count = match size_options {
+        SciSizeOptions::Complete => None,
+        SciSizeOptions::Precision(precision) => Some(i64::exact_from(precision)),
+        SciSizeOptions::Scale(scale) => Some(k + i64::exact_from(scale)),
+    };
+    let mut digits = Vec::new();
+    if let Some(count) = count {
+        if count <= 0 {
+            // x is less than one unit in the last place. Here, 0 <= r / s < 1 is x divided by
+            // b^(log + 1), so if count is 0, r / s is the fraction of the unit.
+            let round_up = match rm {
+                RoundingMode::Down | RoundingMode::Floor | RoundingMode::Exact => false,
+                RoundingMode::Up | RoundingMode::Ceiling => true,
+                RoundingMode::Nearest => count == 0 && r.double_cmp(&s) == Ordering::Greater,
+            };
+            return if round_up {
+                (vec![1], log + 1 - count, false)
+            } else {
+                (digits, 0, false)
+            };
+        }
+        while i64::exact_from(digits.len()) < count {
+            r.mul_small_assign(b);
+            digits.push(u8::wrapping_from(r.div_rem_small_quotient(&s)));
+        }
+    } else {
+        while !r.is_zero() {
+            r.mul_small_assign(b);
+            digits.push(u8::wrapping_from(r.div_rem_small_quotient(&s)));
+        }
+    }
+    let exact = r.is_zero();
+    let round_up = match rm {
+        RoundingMode::Down | RoundingMode::Floor | RoundingMode::Exact => false,
+        RoundingMode::Up | RoundingMode::Ceiling => !exact,
+        RoundingMode::Nearest => match r.double_cmp(&s) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => digits.last().unwrap().odd(),
+        },
+    };
+    if round_up {
+        let mut carry = true;
+        for d in digits.iter_mut().rev() {
+            if *d == base - 1 {
+                *d = 0;
+            } else {
+                *d += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            // We rounded up to a power of the base.
+            digits.insert(0, 1);
+            log += 1;
+            if let SciSizeOptions::Precision(_) = size_options {
+                digits.pop();
+            }
+        }
+    }
+    (digits, log, exact)
+}
+
+// Rounds $(q + \epsilon)2^e$ to a positive float, where $\epsilon$ is in $(0, 1)$ if `sticky` is
+// true and 0 otherwise. `q` must have more significant bits than the float's precision. Returns
+// `None` if `rm` is `Exact` but the value cannot be represented exactly.
+fn round_to_float<T: PrimitiveFloat>(
+    q: u64,
+    sticky: bool,
+    exponent: i64,
+    rm: RoundingMode,
+) -> Option<T> {
+    let lead = exponent + i64::wrapping_from(q.significant_bits()) - 1;
+    if lead > T::MAX_EXPONENT {
+        return overflow(rm);
+    }
+    let low = max(lead - i64::from(T::MANTISSA_WIDTH), T::MIN_EXPONENT);
+    let shift = u64::exact_from(low - exponent);
+    let (mut mantissa, half_c, inexact) = if shift >= u64::WIDTH {
+        (0, Ordering::Less, true)
+    } else {
+        let rest = q & u64::low_mask(shift);
+        let half = 1 << (shift - 1);
+        let half_c = rest.cmp(&half).then(if sticky {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        });
+        (q >> shift, half_c, rest != 0 || sticky)
+    };
+    let round_up = match rm {
+        RoundingMode::Down | RoundingMode::Floor => false,
+        RoundingMode::Up | RoundingMode::Ceiling => inexact,
+        RoundingMode::Nearest => {
+            half_c == Ordering::Greater || (half_c == Ordering::Equal && mantissa.odd())
+        }
+        RoundingMode::Exact => {
+            if inexact {
+                return None;
+            }
+            false
+        }
+    };
+    if round_up {
+        mantissa += 1;
+    }
+    // Rounding up may carry the result out of the finite range.
+    Some(T::from_integer_mantissa_and_exponent(mantissa, low).unwrap_or(T::POSITIVE_INFINITY))
+}
+
+// The result of rounding a positive number that is larger than every finite float.
+fn overflow<T: PrimitiveFloat>(rm: RoundingMode) -> Option<T> {
+    match rm {
+        RoundingMode::Down | RoundingMode::Floor => Some(T::MAX_FINITE),
+        RoundingMode::Up | RoundingMode::Ceiling | RoundingMode::Nearest => {
+            Some(T::POSITIVE_INFINITY)
+        }
+        RoundingMode::Exact => None,
+    }
+}
+
+// The result of rounding a positive number that is smaller than half of the smallest positive
+// float.
+fn underflow<T: PrimitiveFloat>(rm: RoundingMode) -> Option<T> {
+    match rm {
+        RoundingMode::Down | RoundingMode::Floor | RoundingMode::Nearest => Some(T::ZERO),
+        RoundingMode::Up | RoundingMode::Ceiling => Some(T::MIN_POSITIVE_SUBNORMAL),
+        RoundingMode::Exact => None,
+    }
+}
+
+// Converts the unsigned digit string `s`, multiplied by $b^e$, to a non-negative float, rounding
+// according to `rm`. Returns `None` if `s` is empty or contains invalid digits, or if `rm` is
+// `Exact` but the value cannot be represented exactly.
+pub(crate) fn parse_float<T: PrimitiveFloat>(
+    s: &[u8],
+    base: u8,
+    exponent: i64,
+    rm: RoundingMode,
+) -> Option<T> {
+    if s.is_empty() {
+        return None;
+    }
+    let b = u32::from(base);
+    let mut n = BigNat(Vec::new());
+    let mut significant_digits = 0u64;
+    for &c in s {
+        let d = digit_from_display_byte(c)?;
+        if d >= base {
+            return None;
+        }
+        if significant_digits != 0 || d != 0 {
+            n.mul_small_assign(b);
+            n.add_small_assign(u32::from(d));
+            significant_digits += 1;
+        }
+    }
+    if n.is_zero() {
+        return Some(T::ZERO);
+    }
+    // b^(significant_digits - 1 + exponent) <= n * b^exponent < b^(significant_digits + exponent).
+    // Handle values that are far out of range without computing huge powers of the base.
+    let log_2_base = f64::from(base).log2();
+    let log_lower = (i64::exact_from(significant_digits) - 1).saturating_add(exponent);
+    if log_lower as f64 * log_2_base > (T::MAX_EXPONENT + 2) as f64 {
+        return overflow(rm);
+    }
+    let log_upper = i64::exact_from(significant_digits).saturating_add(exponent);
+    if (log_upper as f64 * log_2_base) < (T::MIN_EXPONENT - 2) as f64 {
+        return underflow(rm);
+    }
+    // x = num / den
+    let mut num = n;
+    let mut den = BigNat::from_u64(1);
+    if exponent >= 0 {
+        num.mul_power_assign(b, exponent.unsigned_abs());
+    } else {
+        den.mul_power_assign(b, exponent.unsigned_abs());
+    }
+    // Compute q = floor(num * 2^shift / den), which has between precision + 3 and precision + 4
+    // significant bits.
+    let precision = u64::from(T::MANTISSA_WIDTH) + 1;
+    let shift = i64::exact_from(precision + 3) - i64::exact_from(num.significant_bits())
+        + i64::exact_from(den.significant_bits());
+    if shift >= 0 {
+        num.shl_assign(shift.unsigned_abs());
+    } else {
+        den.shl_assign(shift.unsigned_abs());
+    }
+    let mut q = 0u64;
+    for i in (0..precision + 4).rev() {
+        let mut shifted = den.clone();
+        shifted.shl_assign(i);
+        if num >= shifted {
+            num.sub_assign(&shifted);
+            q |= 1 << i;
+        }
+    }
+    round_to_float(q, !num.is_zero(), -shift, rm)
+}
@@ -1,8 +1,13 @@
 use crate::num::arithmetic::traits::Parity;
+use crate::num::basic::floats::PrimitiveFloat;
 use crate::num::basic::integers::PrimitiveInt;
-use crate::num::conversion::string::from_string::digit_from_display_byte;
+use crate::num::conversion::string::float_digits::parse_float;
+use crate::num::conversion::string::from_string::{
+    digit_from_display_byte, ParseNumberError, ParseNumberErrorKind,
+};
 use crate::num::conversion::string::options::FromSciStringOptions;
 use crate::num::conversion::traits::FromSciString;
+use crate::num::float::half::{BF16, F16};
 use crate::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
 use std::str::FromStr;
@@ -174,6 +179,79 @@ pub fn preprocess_sci_string(s: &str, options: FromSciStringOptions) -> Option<(
     Some((s, exponent))
 }
 
+// Checks the exponent of a scientific-notation string, which starts at byte `start` and consists
+// of an optional sign followed by decimal digits. `number_start` is the index of the first byte of
+// the number after its sign, which is where an `OutOfRange` error is reported.
+fn check_exponent(s: &[u8], start: usize, number_start: usize) -> Result<(), ParseNumberError> {
+    let digits_start = match s.get(start) {
+        Some(&b'+' | &b'-') => start + 1,
+        _ => start,
+    };
+    if digits_start == s.len() {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::Empty,
+            digits_start,
+        ));
+    }
+    if let Some(i) = s[digits_start..].iter().position(|c| !c.is_ascii_digit()) {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::InvalidDigit,
+            digits_start + i,
+        ));
+    }
+    if parse_exponent(&s[start..]).is_none() {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::OutOfRange,
+            number_start,
+        ));
+    }
+    Ok(())
+}
+
+// Describes why `T::from_sci_string_with_options(s, options)` returned `None`. The string is
+// scanned the way `preprocess_sci_string` reads it: an optional sign, digits in the base with at
+// most one `'.'`, and an optional exponent. In bases 15 and above, 'e' and 'E' are digits, so the
+// exponent must start with a sign. If the syntax is valid, the number either would have to be
+// rounded while the rounding mode is `Exact`, or it doesn't fit in `T`.
+pub(crate) fn sci_string_error<T: FromSciString>(
+    s: &str,
+    options: FromSciStringOptions,
+) -> ParseNumberError {
+    let bytes = s.as_bytes();
+    let start = usize::from(matches!(bytes.first(), Some(&b'+' | &b'-')));
+    let mut point_seen = false;
+    let mut digit_seen = false;
+    for (i, &c) in bytes.iter().enumerate().skip(start) {
+        match c {
+            b'.' if !point_seen => point_seen = true,
+            b'e' | b'E'
+                if digit_seen
+                    && (options.base < 15 || matches!(bytes.get(i + 1), Some(&b'+' | &b'-'))) =>
+            {
+                if let Err(e) = check_exponent(bytes, i + 1, start) {
+                    return e;
+                }
+                break;
+            }
+            c => match digit_from_display_byte(c) {
+                Some(d) if d < options.base => digit_seen = true,
+                _ => return ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, i),
+            },
+        }
+    }
+    if !digit_seen {
+        return ParseNumberError::new(ParseNumberErrorKind::Empty, start);
+    }
+    if options.rounding_mode == RoundingMode::Exact {
+        let mut nearest_options = options;
+        nearest_options.set_rounding_mode(RoundingMode::Nearest);
+        if T::from_sci_string_with_options(s, nearest_options).is_some() {
+            return ParseNumberError::new(ParseNumberErrorKind::Inexact, start);
+        }
+    }
+    ParseNumberError::new(ParseNumberErrorKind::OutOfRange, start)
+}
+
 fn from_sci_string_with_options_primitive_int<T: PrimitiveInt>(
     s: &str,
     options: FromSciStringOptions,
@@ -305,3 +383,68 @@ macro_rules! impl_from_sci_string {
     };
 }
 apply_to_primitive_ints!(impl_from_sci_string);
+
+fn from_sci_string_with_options_primitive_float<T: PrimitiveFloat>(
+    s: &str,
+    options: FromSciStringOptions,
+) -> Option<T> {
+    match s {
+        "NaN" => return Some(T::NAN),
+        "Infinity" => return Some(T::POSITIVE_INFINITY),
+        "-Infinity" => return Some(T::NEGATIVE_INFINITY),
+        _ => {}
+    }
+    let (s, exponent) = preprocess_sci_string(s, options)?;
+    let (neg, s) = match s.first() {
+        Some(&b'-') => (true, &s[1..]),
+        Some(&b'+') => (false, &s[1..]),
+        _ => (false, &s[..]),
+    };
+    let rm = if neg {
+        -options.rounding_mode
+    } else {
+        options.rounding_mode
+    };
+    let x = parse_float::<T>(s, options.base, exponent, rm)?;
+    Some(if neg { -x } else { x })
+}
+
+macro_rules! impl_from_sci_string_primitive_float {
+    ($t:ident) => {
+        impl FromSciString for $t {
+            /// Converts a [`String`], possibly in scientific notation, to a primitive float.
+            ///
+            /// Use [`FromSciStringOptions`](super::options::FromSciStringOptions) to specify the
+            /// base (from 2 to 36, inclusive) and the rounding mode. The string may have any
+            /// number of digits; the float is computed from the exact value that the string
+            /// represents and rounded only once, so with the default `Nearest` rounding mode the
+            /// result is correctly rounded (ties are broken by choosing the float with an even
+            /// mantissa). Values too large to be represented are rounded to infinity or to the
+            /// largest finite float, depending on the rounding mode, and likewise for values too
+            /// small to be represented.
+            ///
+            /// The syntax for digits, exponents, and points is the same as for primitive integers;
+            /// see [here](super::super::traits::FromSciString). Additionally, the strings `"NaN"`,
+            /// `"Infinity"`, and `"-Infinity"` are accepted, and `"-0"` produces negative zero.
+            ///
+            /// If the string is unparseable, `None` is returned. `None` is also returned if the
+            /// rounding mode in options is `Exact`, but rounding is necessary.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()` plus the absolute
+            /// value of the exponent, if it is within the float's range.
+            ///
+            /// # Examples
+            /// See [here](super::from_sci_string).
+            #[inline]
+            fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<$t> {
+                from_sci_string_with_options_primitive_float(s, options)
+            }
+        }
+    };
+}
+apply_to_primitive_floats!(impl_from_sci_string_primitive_float);
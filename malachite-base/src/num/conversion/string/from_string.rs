@@ -1,4 +1,65 @@
-use crate::num::conversion::traits::FromStringBase;
+use crate::num::conversion::string::options::ParseOptions;
+use crate::num::conversion::traits::{FromStringBase, FromStringWithOptions};
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// The reasons why a string may fail to parse as a number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseNumberErrorKind {
+    /// There were no digits where digits were expected.
+    Empty,
+    /// A byte that is neither a digit in the base nor an allowed separator was found.
+    InvalidDigit,
+    /// A separator did not have a digit on either side.
+    MisplacedSeparator,
+    /// A sign was found where none is allowed: a `'-'` before an unsigned number, or a `'+'` when
+    /// plus signs are not allowed.
+    InvalidSign,
+    /// The string represents a number that does not fit in the target type.
+    OutOfRange,
+    /// The string represents a number that would have to be rounded, and the rounding mode is
+    /// [`Exact`](crate::rounding_modes::RoundingMode::Exact).
+    Inexact,
+    /// The denominator of a fraction was zero.
+    ZeroDenominator,
+    /// A check symbol did not match the value of the digits before it.
+    CheckSymbolMismatch,
+}
+
+/// An error produced when a number can't be parsed from a string: the reason for the failure,
+/// along with the byte offset in the string where the problem was found.
+///
+/// For an [`OutOfRange`](ParseNumberErrorKind::OutOfRange) or
+/// [`Inexact`](ParseNumberErrorKind::Inexact) error, the offset is that of the first byte after the
+/// sign.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseNumberError {
+    /// The reason for the failure.
+    pub kind: ParseNumberErrorKind,
+    /// The byte offset in the string where the problem was found.
+    pub index: usize,
+}
+
+impl ParseNumberError {
+    /// Creates a [`ParseNumberError`] from a kind and a byte offset.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    ///
+    /// let e = ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3);
+    /// assert_eq!(e.kind, ParseNumberErrorKind::InvalidDigit);
+    /// assert_eq!(e.index, 3);
+    /// ```
+    pub const fn new(kind: ParseNumberErrorKind, index: usize) -> ParseNumberError {
+        ParseNumberError { kind, index }
+    }
+}
 
 /// Produces a digit from a byte corresponding to a numeric or alphabetic (lower- or uppercase)
 /// [`char`] that represents the digit.
@@ -46,3 +107,227 @@ macro_rules! impl_from_string_base {
     };
 }
 apply_to_primitive_ints!(impl_from_string_base);
+
+/// Removes whitespace, if [`ParseOptions`] allows it, and a sign from a number string. Returns
+/// whether the sign was `'-'`, along with the byte range of the rest of the string.
+///
+/// A `'-'` is always accepted here; it's up to the caller to reject it for unsigned types. This is
+/// a building block for implementations of
+/// [`FromStringWithOptions`](crate::num::conversion::traits::FromStringWithOptions).
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(1)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::from_string::{
+///     strip_sign, ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::string::options::ParseOptions;
+///
+/// let mut options = ParseOptions::default();
+/// assert_eq!(strip_sign("-123", options), Ok((true, 1..4)));
+/// assert_eq!(
+///     strip_sign("+123", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+/// );
+///
+/// options.set_allow_whitespace(true);
+/// options.set_allow_plus_sign(true);
+/// assert_eq!(strip_sign("  +123 ", options), Ok((false, 3..6)));
+/// ```
+pub fn strip_sign(
+    s: &str,
+    options: ParseOptions,
+) -> Result<(bool, Range<usize>), ParseNumberError> {
+    let mut start = 0;
+    let mut end = s.len();
+    if options.whitespace {
+        let trimmed = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        start = s.len() - trimmed.len();
+        end = start
+            + trimmed
+                .trim_end_matches(|c: char| c.is_ascii_whitespace())
+                .len();
+    }
+    let negative = match s.as_bytes().get(start) {
+        Some(b'-') => true,
+        Some(b'+') if options.plus_sign => false,
+        Some(b'+') => {
+            return Err(ParseNumberError::new(
+                ParseNumberErrorKind::InvalidSign,
+                start,
+            ))
+        }
+        _ => return Ok((false, start..end)),
+    };
+    Ok((negative, start + 1..end))
+}
+
+/// Checks the digits of a number string against [`ParseOptions`], returning the base that they
+/// are written in and the digits themselves, with any prefix and separators removed.
+///
+/// `s` should not contain a sign or surrounding whitespace. `offset` is the byte offset of `s`
+/// within the full string; it is added to the offsets of any errors. This is a building block for
+/// implementations of
+/// [`FromStringWithOptions`](crate::num::conversion::traits::FromStringWithOptions).
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::from_string::{
+///     clean_digits, ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::string::options::ParseOptions;
+///
+/// let mut options = ParseOptions::default();
+/// assert_eq!(clean_digits("123", 0, options).unwrap(), (10, "123".into()));
+/// assert_eq!(
+///     clean_digits("12_345", 0, options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 2))
+/// );
+///
+/// options.set_allow_underscore_separators(true);
+/// options.set_allow_prefixes(true);
+/// assert_eq!(clean_digits("12_345", 0, options).unwrap(), (10, "12345".into()));
+/// assert_eq!(clean_digits("0x1F", 0, options).unwrap(), (16, "1F".into()));
+/// assert_eq!(
+///     clean_digits("1__2", 5, options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::MisplacedSeparator, 7))
+/// );
+/// assert_eq!(
+///     clean_digits("", 5, options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 5))
+/// );
+/// ```
+pub fn clean_digits(
+    s: &str,
+    offset: usize,
+    options: ParseOptions,
+) -> Result<(u8, Cow<str>), ParseNumberError> {
+    let mut base = options.base;
+    let mut s = s;
+    let mut offset = offset;
+    if options.prefixes && s.len() >= 2 && s.as_bytes()[0] == b'0' {
+        let prefix_base = match s.as_bytes()[1] {
+            b'x' | b'X' => 16,
+            b'o' | b'O' => 8,
+            b'b' | b'B' => 2,
+            _ => 0,
+        };
+        if prefix_base != 0 && (base == 10 || base == prefix_base) {
+            base = prefix_base;
+            s = &s[2..];
+            offset += 2;
+        }
+    }
+    if s.is_empty() {
+        return Err(ParseNumberError::new(ParseNumberErrorKind::Empty, offset));
+    }
+    let mut has_separators = false;
+    let mut previous_is_digit = false;
+    for (i, b) in s.bytes().enumerate() {
+        if options.is_separator(b) {
+            if !previous_is_digit {
+                return Err(ParseNumberError::new(
+                    ParseNumberErrorKind::MisplacedSeparator,
+                    offset + i,
+                ));
+            }
+            has_separators = true;
+            previous_is_digit = false;
+        } else if digit_from_display_byte(b).map_or(false, |d| d < base) {
+            previous_is_digit = true;
+        } else {
+            return Err(ParseNumberError::new(
+                ParseNumberErrorKind::InvalidDigit,
+                offset + i,
+            ));
+        }
+    }
+    if !previous_is_digit {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::MisplacedSeparator,
+            offset + s.len() - 1,
+        ));
+    }
+    Ok((
+        base,
+        if has_separators {
+            // Any '_' or ',' left must be an allowed separator
+            Cow::Owned(s.chars().filter(|&c| c != '_' && c != ',').collect())
+        } else {
+            Cow::Borrowed(s)
+        },
+    ))
+}
+
+fn from_string_with_options_primitive<T: FromStringBase>(
+    s: &str,
+    options: ParseOptions,
+    signed: bool,
+) -> Result<T, ParseNumberError> {
+    let (negative, range) = strip_sign(s, options)?;
+    if negative && !signed {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::InvalidSign,
+            range.start - 1,
+        ));
+    }
+    let (base, digits) = clean_digits(&s[range.clone()], range.start, options)?;
+    let digits = if negative {
+        Cow::Owned(format!("-{}", digits))
+    } else {
+        digits
+    };
+    T::from_string_base(base, &digits)
+        .ok_or_else(|| ParseNumberError::new(ParseNumberErrorKind::OutOfRange, range.start))
+}
+
+macro_rules! impl_from_string_with_options_unsigned {
+    ($t:ident) => {
+        impl FromStringWithOptions for $t {
+            /// Converts a string to an unsigned primitive integer, accepting the syntax specified
+            /// by a [`ParseOptions`].
+            ///
+            /// See [here](super::from_string) for more details.
+            #[inline]
+            fn from_string_with_options(
+                s: &str,
+                options: ParseOptions,
+            ) -> Result<$t, ParseNumberError> {
+                from_string_with_options_primitive(s, options, false)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_from_string_with_options_unsigned);
+
+macro_rules! impl_from_string_with_options_signed {
+    ($t:ident) => {
+        impl FromStringWithOptions for $t {
+            /// Converts a string to a signed primitive integer, accepting the syntax specified by
+            /// a [`ParseOptions`].
+            ///
+            /// See [here](super::from_string) for more details.
+            #[inline]
+            fn from_string_with_options(
+                s: &str,
+                options: ParseOptions,
+            ) -> Result<$t, ParseNumberError> {
+                from_string_with_options_primitive(s, options, true)
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_from_string_with_options_signed);
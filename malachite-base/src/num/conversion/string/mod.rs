@@ -1,3 +1,46 @@
+/// The [`DigitAlphabet`](alphabet::DigitAlphabet) struct, and the
+/// [`ToStringWithAlphabet`](super::traits::ToStringWithAlphabet) and
+/// [`FromStringWithAlphabet`](super::traits::FromStringWithAlphabet) traits, used for converting
+/// numbers to and from strings that use custom digit symbols, in bases that may be larger than 36.
+///
+/// # to_string_with_alphabet
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+/// use malachite_base::num::conversion::traits::ToStringWithAlphabet;
+///
+/// assert_eq!(0u32.to_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN), "1");
+/// assert_eq!(1000000u32.to_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN), "68GP");
+/// assert_eq!(1000000u32.to_string_with_alphabet(DigitAlphabet::BASE62), "4C92");
+/// assert_eq!(1000000u32.to_string_with_alphabet(DigitAlphabet::BASE64_URL), "D0JA");
+/// assert_eq!(1000000u32.to_string_with_alphabet(DigitAlphabet::CROCKFORD_BASE32), "YGJ0");
+/// assert_eq!((-1000000i32).to_string_with_alphabet(DigitAlphabet::BASE62), "-4C92");
+/// ```
+///
+/// # from_string_with_alphabet
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+/// use malachite_base::num::conversion::string::from_string::{
+///     ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::traits::FromStringWithAlphabet;
+///
+/// assert_eq!(u32::from_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN, "68GP"), Ok(1000000));
+/// assert_eq!(u32::from_string_with_alphabet(DigitAlphabet::BASE62, "4C92"), Ok(1000000));
+/// assert_eq!(
+///     u32::from_string_with_alphabet(DigitAlphabet::CROCKFORD_BASE32, "ygj-o"),
+///     Ok(1000000)
+/// );
+/// assert_eq!(i32::from_string_with_alphabet(DigitAlphabet::BASE62, "-4C92"), Ok(-1000000));
+/// assert_eq!(
+///     u32::from_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN, "60GP"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1))
+/// );
+/// assert_eq!(
+///     u8::from_string_with_alphabet(DigitAlphabet::BASE62, "4C92"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+/// );
+/// ```
+pub mod alphabet;
 /// [`FromSciString`](super::traits::FromSciString), a trait for converting strings, possibly using
 /// scientific notation, to numbers.
 ///
@@ -9,6 +52,14 @@
 /// assert_eq!(u8::from_sci_string("123.5"), Some(124));
 /// assert_eq!(u8::from_sci_string("256"), None);
 /// assert_eq!(u64::from_sci_string("1.23e10"), Some(12300000000));
+///
+/// assert_eq!(f64::from_sci_string("0.1"), Some(0.1));
+/// assert_eq!(f64::from_sci_string("1e400"), Some(f64::INFINITY));
+/// // 16777217 is halfway between two `f32`s; the one with the even mantissa is chosen
+/// assert_eq!(f32::from_sci_string("16777217"), Some(16777216.0));
+/// // the smallest positive `f64` is about 4.94e-324
+/// assert_eq!(f64::from_sci_string("3e-324"), Some(5.0e-324));
+/// assert_eq!(f64::from_sci_string("2e-324"), Some(0.0));
 /// ```
 ///
 /// # from_sci_string_with_options
@@ -26,15 +77,120 @@
 /// options = FromSciStringOptions::default();
 /// options.set_base(16);
 /// assert_eq!(u8::from_sci_string_with_options("ff", options), Some(255));
+///
+/// options = FromSciStringOptions::default();
+/// options.set_rounding_mode(RoundingMode::Floor);
+/// assert_eq!(f32::from_sci_string_with_options("0.1", options), Some(0.099999994));
+///
+/// options.set_rounding_mode(RoundingMode::Exact);
+/// assert_eq!(f32::from_sci_string_with_options("0.1", options), None);
+/// assert_eq!(f32::from_sci_string_with_options("0.375", options), Some(0.375));
+///
+/// options = FromSciStringOptions::default();
+/// options.set_base(2);
+/// assert_eq!(f64::from_sci_string_with_options("-0.011", options), Some(-0.375));
+/// ```
+///
+/// # try_from_sci_string_with_options
+/// ```
+/// use malachite_base::num::conversion::string::from_string::{
+///     ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+/// use malachite_base::num::conversion::traits::FromSciString;
+/// use malachite_base::rounding_modes::RoundingMode;
+///
+/// assert_eq!(u8::try_from_sci_string("1.23e2"), Ok(123));
+/// assert_eq!(
+///     u8::try_from_sci_string("1.2x"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3))
+/// );
+/// assert_eq!(
+///     u8::try_from_sci_string("1e"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 2))
+/// );
+/// assert_eq!(
+///     u8::try_from_sci_string("256"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+/// );
+///
+/// let mut options = FromSciStringOptions::default();
+/// options.set_rounding_mode(RoundingMode::Exact);
+/// assert_eq!(
+///     u8::try_from_sci_string_with_options("1.5", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::Inexact, 0))
+/// );
 /// ```
 pub mod from_sci_string;
 /// [`FromStringBase`](super::traits::FromStringBase), a trait for converting strings in a
-/// specified base to numbers.
+/// specified base to numbers, and
+/// [`FromStringWithOptions`](super::traits::FromStringWithOptions), a trait for converting strings
+/// to numbers with configurable syntax and descriptive errors.
+///
+/// # from_string_with_options
+/// ```
+/// use malachite_base::num::conversion::string::from_string::{
+///     ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_base::num::conversion::string::options::ParseOptions;
+/// use malachite_base::num::conversion::traits::FromStringWithOptions;
+///
+/// let mut options = ParseOptions::default();
+/// assert_eq!(u32::from_string_with_options("12345", options), Ok(12345));
+/// assert_eq!(
+///     u32::from_string_with_options("12_345", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 2))
+/// );
+/// assert_eq!(
+///     u32::from_string_with_options(" 0x1F", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 0))
+/// );
+/// assert_eq!(
+///     u8::from_string_with_options("-1", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+/// );
+/// assert_eq!(
+///     u8::from_string_with_options("256", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+/// );
+/// assert_eq!(i8::from_string_with_options("-128", options), Ok(-128));
+///
+/// options.set_allow_underscore_separators(true);
+/// options.set_allow_comma_separators(true);
+/// options.set_allow_whitespace(true);
+/// options.set_allow_prefixes(true);
+/// options.set_allow_plus_sign(true);
+/// assert_eq!(u32::from_string_with_options("12_345", options), Ok(12345));
+/// assert_eq!(u32::from_string_with_options("1,000,000", options), Ok(1000000));
+/// assert_eq!(u32::from_string_with_options(" 0x1F", options), Ok(31));
+/// assert_eq!(i32::from_string_with_options("-0b101\n", options), Ok(-5));
+/// assert_eq!(i32::from_string_with_options("+0o17", options), Ok(15));
+/// assert_eq!(
+///     u32::from_string_with_options("12__345", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::MisplacedSeparator, 3))
+/// );
+/// assert_eq!(
+///     u32::from_string_with_options("0x", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 2))
+/// );
+///
+/// options = ParseOptions::default();
+/// options.set_base(16);
+/// assert_eq!(u32::from_string_with_options("ff", options), Ok(255));
+/// assert_eq!(
+///     u32::from_string_with_options("fg", options),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1))
+/// );
+/// ```
 pub mod from_string;
-/// [`ToSciOptions`](options::ToSciOptions) and
-/// [`FromSciSringOptions`](options::FromSciStringOptions), `struct`s for specifying parameters
-/// when using the [`FromSciString`](super::traits::FromSciString) and
-/// [`ToSci`](super::traits::ToSci) traits.
+// Exact conversion between primitive floats and digit strings, shared by the `ToSci` and
+// `FromSciString` implementations for floats, and by the formatting of the 16-bit float types.
+pub(crate) mod float_digits;
+/// [`ToSciOptions`](options::ToSciOptions),
+/// [`FromSciSringOptions`](options::FromSciStringOptions), and
+/// [`ParseOptions`](options::ParseOptions), `struct`s for specifying parameters when using the
+/// [`FromSciString`](super::traits::FromSciString), [`ToSci`](super::traits::ToSci), and
+/// [`FromStringWithOptions`](super::traits::FromStringWithOptions) traits.
 pub mod options;
 /// [`ToSci`](super::traits::ToSci), a trait for converting a number to string, possibly using
 /// scientific notation.
@@ -48,6 +204,12 @@ pub mod options;
 ///
 /// assert_eq!(u128::MAX.to_sci().to_string(), "3.402823669209385e38");
 /// assert_eq!(i128::MIN.to_sci().to_string(), "-1.701411834604692e38");
+///
+/// assert_eq!(0.1f64.to_sci().to_string(), "0.1");
+/// assert_eq!((1.0f64 / 3.0).to_sci().to_string(), "0.3333333333333333");
+/// assert_eq!((-0.0f64).to_sci().to_string(), "-0");
+/// assert_eq!(f64::NAN.to_sci().to_string(), "NaN");
+/// assert_eq!(f64::NEG_INFINITY.to_sci().to_string(), "-Infinity");
 /// ```
 ///
 /// # to_sci_with_options
@@ -84,6 +246,33 @@ pub mod options;
 ///
 /// options.set_include_trailing_zeros(true);
 /// assert_eq!(123456u32.to_sci_with_options(options).to_string(), "1.111000100e16");
+///
+/// // Shortest round-trip output
+/// options = ToSciOptions::default();
+/// options.set_size_shortest();
+/// assert_eq!(0.1f64.to_sci_with_options(options).to_string(), "0.1");
+/// assert_eq!(1.0e15f64.to_sci_with_options(options).to_string(), "1000000000000000");
+/// assert_eq!(1.0e20f64.to_sci_with_options(options).to_string(), "1e20");
+/// assert_eq!(16777216.0f32.to_sci_with_options(options).to_string(), "16777216");
+///
+/// options.set_base(2);
+/// assert_eq!(0.375f64.to_sci_with_options(options).to_string(), "0.011");
+///
+/// // Exact output
+/// options = ToSciOptions::default();
+/// options.set_size_complete();
+/// assert_eq!(
+///     0.1f64.to_sci_with_options(options).to_string(),
+///     "0.1000000000000000055511151231257827021181583404541015625"
+/// );
+///
+/// options.set_precision(20);
+/// assert_eq!(0.1f64.to_sci_with_options(options).to_string(), "0.10000000000000000555");
+///
+/// options.set_scale(3);
+/// options.set_rounding_mode(RoundingMode::Ceiling);
+/// assert_eq!((1.0f64 / 3.0).to_sci_with_options(options).to_string(), "0.334");
+/// assert_eq!((-1.0f64 / 3.0).to_sci_with_options(options).to_string(), "-0.333");
 /// ```
 ///
 /// # fmt_sci_valid
@@ -99,6 +288,14 @@ pub mod options;
 /// assert!(!u128::MAX.fmt_sci_valid(options)); // u128::MAX has more than 16 significant digits
 /// options.set_precision(50);
 /// assert!(u128::MAX.fmt_sci_valid(options));
+///
+/// let mut options = ToSciOptions::default();
+/// options.set_size_complete();
+/// assert!(0.1f64.fmt_sci_valid(options));
+/// // 0.1 is not an integer, so its expansion in an odd base does not terminate
+/// options.set_base(3);
+/// assert!(!0.1f64.fmt_sci_valid(options));
+/// assert!(3.0f64.fmt_sci_valid(options));
 /// ```
 pub mod to_sci;
 /// The [`BaseFmtWrapper`](to_string::BaseFmtWrapper) struct and
@@ -80,6 +80,7 @@ impl Iterator for ExhaustiveToSciOptions {
             e_lowercase,
             force_exponent_plus_sign,
             include_trailing_zeros,
+            shortest: false,
         })
     }
 }
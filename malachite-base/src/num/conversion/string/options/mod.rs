@@ -63,6 +63,12 @@ impl SciSizeOptions {
 ///
 /// - The "include trailing zeros" setting determines whether trailing zeros after the decimal (or
 ///   other-base) point should be included. The default is `false`.
+///
+/// - The "shortest" setting, which is turned on by
+///   [`set_size_shortest`](ToSciOptions::set_size_shortest), determines whether the fewest
+///   significant figures that uniquely identify the number should be shown. For primitive floats,
+///   this is the shortest string that parses back to the same float; for exact types, such as
+///   integers, this is the same as the `Complete` size option. The default is `false`.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ToSciOptions {
     pub(crate) base: u8,
@@ -73,6 +79,8 @@ pub struct ToSciOptions {
     pub(crate) e_lowercase: bool,
     pub(crate) force_exponent_plus_sign: bool,
     pub(crate) include_trailing_zeros: bool,
+    // If this is set, `size_options` is `Complete`.
+    pub(crate) shortest: bool,
 }
 
 impl Default for ToSciOptions {
@@ -86,6 +94,7 @@ impl Default for ToSciOptions {
             e_lowercase: true,
             force_exponent_plus_sign: false,
             include_trailing_zeros: false,
+            shortest: false,
         }
     }
 }
@@ -103,12 +112,20 @@ impl ToSciOptions {
         self.rounding_mode
     }
 
-    /// Returns the size options to be used in the conversion.
+    /// Returns the size options to be used in the conversion. In the "Shortest" mode, this is
+    /// `Complete`.
     #[inline]
     pub const fn get_size_options(&self) -> SciSizeOptions {
         self.size_options
     }
 
+    /// Returns whether the size options are in the "Shortest" mode, indicating that the number
+    /// should be converted using the fewest digits that uniquely identify it.
+    #[inline]
+    pub const fn get_shortest(&self) -> bool {
+        self.shortest
+    }
+
     /// Returns the exponent low threshold to be used in the conversion. It is always negative.
     #[inline]
     pub const fn get_neg_exp_threshold(&self) -> i64 {
@@ -161,6 +178,16 @@ impl ToSciOptions {
     #[inline]
     pub fn set_size_complete(&mut self) {
         self.size_options = SciSizeOptions::Complete;
+        self.shortest = false;
+    }
+
+    /// Sets the size options to the "Shortest" mode, indicating that the number should be
+    /// converted using the fewest digits that uniquely identify it. For exact types, such as
+    /// integers, this is the same as the "Complete" mode.
+    #[inline]
+    pub fn set_size_shortest(&mut self) {
+        self.size_options = SciSizeOptions::Complete;
+        self.shortest = true;
     }
 
     /// Sets the size options to some precision, or number of significant digits.
@@ -171,6 +198,7 @@ impl ToSciOptions {
     pub fn set_precision(&mut self, precision: u64) {
         assert_ne!(precision, 0);
         self.size_options = SciSizeOptions::Precision(precision);
+        self.shortest = false;
     }
 
     /// Sets the size options to some scale, or number of digits after the decimal (or other-base)
@@ -178,6 +206,7 @@ impl ToSciOptions {
     #[inline]
     pub fn set_scale(&mut self, scale: u64) {
         self.size_options = SciSizeOptions::Scale(scale);
+        self.shortest = false;
     }
 
     /// Sets the threshold at which nonzero numbers with a small absolute value start being
@@ -229,7 +258,10 @@ impl ToSciOptions {
 
     #[cfg(feature = "test_build")]
     pub fn is_valid(&self) -> bool {
-        (2..=36).contains(&self.base) && self.neg_exp_threshold < 0 && self.size_options.is_valid()
+        (2..=36).contains(&self.base)
+            && self.neg_exp_threshold < 0
+            && self.size_options.is_valid()
+            && (!self.shortest || self.size_options == SciSizeOptions::Complete)
     }
 }
 
@@ -292,6 +324,138 @@ impl FromSciStringOptions {
     }
 }
 
+/// A `struct` determining which syntax is accepted when a number is parsed from a string using
+/// [`FromStringWithOptions`](crate::num::conversion::traits::FromStringWithOptions).
+///
+/// - The base must be between 2 and 36, inclusive. The characters representing the digits may be
+///   `'0'` through `'9'` and either `'a'` through `'z'` or `'A'` through `'Z'`. The default base
+///   is 10.
+///
+/// - If underscore or comma separators are allowed, digits may be grouped using `'_'` or `','`.
+///   Each separator must have a digit on either side. By default, neither is allowed.
+///
+/// - If whitespace is allowed, leading and trailing ASCII whitespace is ignored. By default, it is
+///   not allowed.
+///
+/// - If prefixes are allowed, the digits may be preceded by `"0x"`, `"0o"`, or `"0b"` (or their
+///   uppercase forms), which select base 16, 8, or 2. A prefix is only recognized if the base is
+///   10 or matches the prefix; in base 16, for example, `"0b1"` is read as the digits 0, b, and 1.
+///   By default, prefixes are not allowed.
+///
+/// - If a plus sign is allowed, a non-negative number may begin with `'+'`. By default, it may
+///   not. A `'-'` is always allowed for signed types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseOptions {
+    pub(crate) base: u8,
+    pub(crate) underscore_separators: bool,
+    pub(crate) comma_separators: bool,
+    pub(crate) whitespace: bool,
+    pub(crate) prefixes: bool,
+    pub(crate) plus_sign: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            base: 10,
+            underscore_separators: false,
+            comma_separators: false,
+            whitespace: false,
+            prefixes: false,
+            plus_sign: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Returns the base to be used in the conversion. It is always between 2 and 36, inclusive.
+    #[inline]
+    pub const fn get_base(&self) -> u8 {
+        self.base
+    }
+
+    /// Returns whether `'_'` may be used to separate groups of digits.
+    #[inline]
+    pub const fn get_allow_underscore_separators(&self) -> bool {
+        self.underscore_separators
+    }
+
+    /// Returns whether `','` may be used to separate groups of digits.
+    #[inline]
+    pub const fn get_allow_comma_separators(&self) -> bool {
+        self.comma_separators
+    }
+
+    /// Returns whether leading and trailing ASCII whitespace is ignored.
+    #[inline]
+    pub const fn get_allow_whitespace(&self) -> bool {
+        self.whitespace
+    }
+
+    /// Returns whether the `"0x"`, `"0o"`, and `"0b"` prefixes are recognized.
+    #[inline]
+    pub const fn get_allow_prefixes(&self) -> bool {
+        self.prefixes
+    }
+
+    /// Returns whether a non-negative number may begin with `'+'`.
+    #[inline]
+    pub const fn get_allow_plus_sign(&self) -> bool {
+        self.plus_sign
+    }
+
+    /// Sets the base to be used in the conversion.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    #[inline]
+    pub fn set_base(&mut self, base: u8) {
+        assert!(base >= 2);
+        assert!(base <= 36);
+        self.base = base;
+    }
+
+    /// Sets whether `'_'` may be used to separate groups of digits.
+    #[inline]
+    pub fn set_allow_underscore_separators(&mut self, allow: bool) {
+        self.underscore_separators = allow;
+    }
+
+    /// Sets whether `','` may be used to separate groups of digits.
+    #[inline]
+    pub fn set_allow_comma_separators(&mut self, allow: bool) {
+        self.comma_separators = allow;
+    }
+
+    /// Sets whether leading and trailing ASCII whitespace is ignored.
+    #[inline]
+    pub fn set_allow_whitespace(&mut self, allow: bool) {
+        self.whitespace = allow;
+    }
+
+    /// Sets whether the `"0x"`, `"0o"`, and `"0b"` prefixes are recognized.
+    #[inline]
+    pub fn set_allow_prefixes(&mut self, allow: bool) {
+        self.prefixes = allow;
+    }
+
+    /// Sets whether a non-negative number may begin with `'+'`.
+    #[inline]
+    pub fn set_allow_plus_sign(&mut self, allow: bool) {
+        self.plus_sign = allow;
+    }
+
+    // Returns whether a byte is a separator that these options allow.
+    pub(crate) const fn is_separator(&self, b: u8) -> bool {
+        (b == b'_' && self.underscore_separators) || (b == b',' && self.comma_separators)
+    }
+
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        (2..=36).contains(&self.base)
+    }
+}
+
 /// Iterators that generate [`SciSizeOptions`], [`ToSciOptions`], and [`FromSciStringOptions`]
 /// without repetition.
 pub mod exhaustive;
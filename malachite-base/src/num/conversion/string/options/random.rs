@@ -78,6 +78,7 @@ impl Iterator for RandomToSciOptions {
             e_lowercase: self.bs.next().unwrap(),
             force_exponent_plus_sign: self.bs.next().unwrap(),
             include_trailing_zeros: self.bs.next().unwrap(),
+            shortest: false,
         })
     }
 }
@@ -1,13 +1,18 @@
 use crate::num::arithmetic::traits::{CheckedLogBase2, NegAssign, Pow, UnsignedAbs};
+use crate::num::basic::floats::PrimitiveFloat;
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::string::float_digits::{
+    has_terminating_expansion, rounded_digits, shortest_digits,
+};
 use crate::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
 use crate::num::conversion::string::to_string::BaseFmtWrapper;
 use crate::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
 use crate::num::conversion::traits::{ExactFrom, ToSci};
+use crate::num::float::half::{BF16, F16};
 use crate::rounding_modes::RoundingMode;
 use crate::slices::slice_trailing_zeros;
 use std::fmt::{Display, Formatter, Write};
@@ -276,3 +281,228 @@ macro_rules! impl_to_sci_signed {
     };
 }
 apply_to_signeds!(impl_to_sci_signed);
+
+// Exponents at least this large are always written using scientific notation when formatting a
+// float in "Shortest" mode. This matches the behavior of `f64`'s `Debug` implementation.
+const SHORTEST_POS_EXP_THRESHOLD: i64 = 16;
+
+fn fmt_zero_primitive_float(f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+    f.write_char('0')?;
+    let scale = if options.include_trailing_zeros {
+        match options.size_options {
+            SciSizeOptions::Complete | SciSizeOptions::Scale(0) => 0,
+            SciSizeOptions::Scale(scale) => scale,
+            SciSizeOptions::Precision(precision) => precision - 1,
+        }
+    } else {
+        0
+    };
+    if scale != 0 {
+        f.write_char('.')?;
+        for _ in 0..scale {
+            f.write_char('0')?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_sci_valid_primitive_float<T: PrimitiveFloat>(x: T, options: ToSciOptions) -> bool {
+    if !x.is_finite() || x == T::ZERO {
+        return true;
+    }
+    if options.shortest {
+        return true;
+    }
+    let x = if x < T::ZERO { -x } else { x };
+    match options.size_options {
+        SciSizeOptions::Complete => has_terminating_expansion(x, options.base),
+        size_options => {
+            options.rounding_mode != RoundingMode::Exact
+                || rounded_digits(x, options.base, size_options, RoundingMode::Exact).2
+        }
+    }
+}
+
+fn fmt_sci_primitive_float<T: PrimitiveFloat>(
+    x: T,
+    f: &mut Formatter,
+    mut options: ToSciOptions,
+) -> std::fmt::Result {
+    if x.is_nan() {
+        return f.write_str("NaN");
+    }
+    let x = if x < T::ZERO || x.is_negative_zero() {
+        f.write_char('-')?;
+        options.rounding_mode.neg_assign();
+        -x
+    } else {
+        x
+    };
+    if x.is_infinite() {
+        return f.write_str("Infinity");
+    } else if x == T::ZERO {
+        return fmt_zero_primitive_float(f, options);
+    }
+    let base = options.base;
+    let mut trim_zeros = !options.include_trailing_zeros;
+    let (mut digits, log) = if options.shortest {
+        trim_zeros = false;
+        let (mut digits, log) = shortest_digits(x, base);
+        if (0..SHORTEST_POS_EXP_THRESHOLD).contains(&log) && usize::exact_from(log) >= digits.len()
+        {
+            digits.resize(usize::exact_from(log) + 1, 0);
+        }
+        (digits, log)
+    } else {
+        match options.size_options {
+            SciSizeOptions::Complete => {
+                trim_zeros = false;
+                assert!(
+                    has_terminating_expansion(x, base),
+                    "{} has a non-terminating expansion in base {}",
+                    x,
+                    base
+                );
+                let (mut digits, log, _) =
+                    rounded_digits(x, base, SciSizeOptions::Complete, options.rounding_mode);
+                if log >= 0 && usize::exact_from(log) >= digits.len() {
+                    digits.resize(usize::exact_from(log) + 1, 0);
+                }
+                (digits, log)
+            }
+            size_options => {
+                let (digits, log, exact) =
+                    rounded_digits(x, base, size_options, options.rounding_mode);
+                assert!(
+                    exact || options.rounding_mode != RoundingMode::Exact,
+                    "Inexact float to string conversion"
+                );
+                if digits.is_empty() {
+                    return fmt_zero_primitive_float(f, options);
+                }
+                (digits, log)
+            }
+        }
+    };
+    let mut precision = digits.len();
+    let scale = i64::exact_from(precision) - 1 - log;
+    for digit in &mut digits {
+        *digit = if options.lowercase {
+            digit_to_display_byte_lower(*digit).unwrap()
+        } else {
+            digit_to_display_byte_upper(*digit).unwrap()
+        };
+    }
+    if log <= options.get_neg_exp_threshold() || scale < 0 {
+        // exponent
+        if trim_zeros {
+            precision -= count_trailing_zero_chars(&digits);
+            digits.truncate(precision);
+        }
+        if precision > 1 {
+            digits.push(0);
+            digits.copy_within(1..precision, 2);
+            digits[1] = b'.';
+        }
+        f.write_str(&String::from_utf8(digits).unwrap())?;
+        write_exponent(f, options, log)
+    } else if scale == 0 {
+        // no exponent or point
+        f.write_str(&String::from_utf8(digits).unwrap())
+    } else {
+        // no exponent
+        if trim_zeros {
+            precision -= count_trailing_zero_chars(&digits).min(usize::exact_from(scale));
+            digits.truncate(precision);
+        }
+        if log < 0 {
+            f.write_char('0')?;
+            f.write_char('.')?;
+            for _ in 0..-log - 1 {
+                f.write_char('0')?;
+            }
+        } else {
+            let digits_before = usize::exact_from(log) + 1;
+            if precision > digits_before {
+                digits.push(0);
+                digits.copy_within(digits_before..precision, digits_before + 1);
+                digits[digits_before] = b'.';
+            }
+        }
+        f.write_str(&String::from_utf8(digits).unwrap())
+    }
+}
+
+fn count_trailing_zero_chars(cs: &[u8]) -> usize {
+    cs.iter().rev().take_while(|&&c| c == b'0').count()
+}
+
+macro_rules! impl_to_sci_primitive_float {
+    ($t:ident) => {
+        impl ToSci for $t {
+            /// Determines whether a primitive float can be converted to a string using
+            /// [`to_sci_with_options`](super::super::traits::ToSci::to_sci_with_options) and a
+            /// particular set of options.
+            ///
+            /// Every float can be converted in the "Shortest" mode, and, when the rounding mode
+            /// is not `Exact`, using the `Precision` or `Scale` size options. The
+            /// `Complete` size option can be used iff the float has a terminating expansion in
+            /// the given base; this is always the case when the base is even.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of the absolute
+            /// value of the exponent of `self` and the precision or scale specified in `options`.
+            ///
+            /// # Examples
+            /// See [here](super::to_sci#fmt_sci_valid).
+            #[inline]
+            fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+                fmt_sci_valid_primitive_float(*self, options)
+            }
+
+            /// Converts a primitive float to a string using a specified base, possibly formatting
+            /// the number using scientific notation.
+            ///
+            /// See [`ToSciOptions`](super::options::ToSciOptions) for details on the available
+            /// options.
+            ///
+            /// - In the "Shortest" mode, set using
+            ///   [`set_size_shortest`](super::options::ToSciOptions::set_size_shortest), the
+            ///   output is the shortest string that, when
+            ///   parsed with [`FromSciString`](super::super::traits::FromSciString) in the same
+            ///   base and with the `Nearest` rounding mode, produces the same float. The rounding
+            ///   mode in `options` is ignored.
+            /// - With the `Complete` size option, the output is the exact expansion of the float.
+            /// - With the `Precision` and `Scale` size options, the exact value of the float is
+            ///   rounded using the rounding mode in `options`.
+            ///
+            /// NaN is written as `"NaN"`, and the infinities as `"Infinity"` and `"-Infinity"`.
+            /// Negative zero is written with a minus sign.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of the absolute
+            /// value of the exponent of `self` and the precision or scale specified in `options`.
+            ///
+            /// # Panics
+            /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that
+            /// the input must be rounded, or if the size option is `Complete` but the float has a
+            /// non-terminating expansion in the given base.
+            ///
+            /// # Examples
+            /// See [here](super::to_sci).
+            #[inline]
+            fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+                fmt_sci_primitive_float(*self, f, options)
+            }
+        }
+    };
+}
+apply_to_primitive_floats!(impl_to_sci_primitive_float);
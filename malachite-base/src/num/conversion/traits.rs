@@ -1,4 +1,7 @@
-use crate::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use crate::num::conversion::string::alphabet::DigitAlphabet;
+use crate::num::conversion::string::from_sci_string::sci_string_error;
+use crate::num::conversion::string::from_string::ParseNumberError;
+use crate::num::conversion::string::options::{FromSciStringOptions, ParseOptions, ToSciOptions};
 use crate::num::conversion::string::to_sci::SciWrapper;
 use crate::rounding_modes::RoundingMode;
 use std::fmt::{Formatter, Result};
@@ -74,10 +77,39 @@ pub trait PowerOf2Digits<T>: Sized {
 }
 
 /// Converts a string slice in a given base to a value.
+///
+/// To learn why a conversion failed, use [`FromStringWithOptions`] with
+/// [`ParseOptions::set_base`]; every type that implements this trait implements that one as well.
 pub trait FromStringBase: Sized {
     fn from_string_base(base: u8, s: &str) -> Option<Self>;
 }
 
+/// Converts a string slice to a value, accepting the syntax specified by a [`ParseOptions`]. If
+/// the conversion fails, the error describes why and where.
+pub trait FromStringWithOptions: Sized {
+    /// Converts a string slice to a value, accepting the syntax specified by `options`.
+    ///
+    /// Returns a [`ParseNumberError`] if the string is not valid under `options` or if the value
+    /// does not fit in `Self`.
+    fn from_string_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> std::result::Result<Self, ParseNumberError>;
+}
+
+/// Converts a string slice written using the digits of a [`DigitAlphabet`] to a value. If the
+/// conversion fails, the error describes why and where.
+pub trait FromStringWithAlphabet: Sized {
+    /// Converts a string slice written using the digits of `alphabet` to a value.
+    ///
+    /// Returns a [`ParseNumberError`] if the string is not a valid numeral in `alphabet` or if the
+    /// value does not fit in `Self`.
+    fn from_string_with_alphabet(
+        alphabet: DigitAlphabet,
+        s: &str,
+    ) -> std::result::Result<Self, ParseNumberError>;
+}
+
 /// Converts a number to a string using a specified base.
 pub trait ToStringBase {
     /// Converts a signed number to a lowercase string using a specified base.
@@ -87,6 +119,12 @@ pub trait ToStringBase {
     fn to_string_base_upper(&self, base: u8) -> String;
 }
 
+/// Converts a number to a string using the digits of a [`DigitAlphabet`].
+pub trait ToStringWithAlphabet {
+    /// Converts a number to a string using the digits of `alphabet`.
+    fn to_string_with_alphabet(&self, alphabet: DigitAlphabet) -> String;
+}
+
 /// Converts a number to a string, possibly in scientific notation.
 pub trait ToSci: Sized {
     /// Formats a number, possibly in scientific notation.
@@ -122,6 +160,32 @@ pub trait FromSciString: Sized {
     fn from_sci_string(s: &str) -> Option<Self> {
         Self::from_sci_string_with_options(s, FromSciStringOptions::default())
     }
+
+    /// Converts a `&str`, possibly in scientific notation, to a number. If the conversion fails,
+    /// the error describes why and where.
+    ///
+    /// The accepted strings are the same as those accepted by
+    /// [`from_sci_string_with_options`](Self::from_sci_string_with_options). If the string is
+    /// well-formed, the error is
+    /// [`Inexact`](super::string::from_string::ParseNumberErrorKind::Inexact) when the rounding
+    /// mode is [`Exact`](RoundingMode::Exact) and the number would have to be rounded, and
+    /// [`OutOfRange`](super::string::from_string::ParseNumberErrorKind::OutOfRange) otherwise.
+    #[inline]
+    fn try_from_sci_string_with_options(
+        s: &str,
+        options: FromSciStringOptions,
+    ) -> std::result::Result<Self, ParseNumberError> {
+        Self::from_sci_string_with_options(s, options)
+            .ok_or_else(|| sci_string_error::<Self>(s, options))
+    }
+
+    /// Converts a `&str`, possibly in scientific notation, to a number, using the default
+    /// [`FromSciStringOptions`](super::string::options::FromSciStringOptions). If the conversion
+    /// fails, the error describes why and where.
+    #[inline]
+    fn try_from_sci_string(s: &str) -> std::result::Result<Self, ParseNumberError> {
+        Self::try_from_sci_string_with_options(s, FromSciStringOptions::default())
+    }
 }
 
 /// Converts a value from one type to another. If the conversion fails, the function panics.
@@ -1,6 +1,6 @@
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
-use crate::num::conversion::traits::ExactFrom;
+use crate::num::conversion::traits::{ExactFrom, JoinHalves};
 use crate::num::logic::traits::CountOnes;
 
 pub const SIEVE_SEED_U32: u32 = 0x69128480;
@@ -473,3 +473,21 @@ pub fn limbs_prime_sieve_u64(bit_array: &mut [u64], n: u64) -> u64 {
         SEED_LIMIT_U64,
     )
 }
+
+#[doc(hidden)]
+// This is equivalent to `gmp_primesieve` from `primesieve.c`, GMP 6.2.1, for 128-bit limbs. There
+// is no 128-bit bit pattern, so the sieve is computed with 64-bit words, which are then joined in
+// pairs.
+pub fn limbs_prime_sieve_u128(bit_array: &mut [u128], n: u64) -> u64 {
+    let size = limbs_prime_sieve_size::<u128>(n);
+    let mut xs = vec![0; size << 1];
+    let count = limbs_prime_sieve_u64(&mut xs, n);
+    for (x, ys) in bit_array[..size].iter_mut().zip(xs.chunks_exact(2)) {
+        *x = u128::join_halves(ys[1], ys[0]);
+    }
+    let bits = n_to_bit(n);
+    if (bits + 1) & u128::WIDTH_MASK != 0 {
+        bit_array[size - 1] |= u128::MAX << ((bits + 1) & u128::WIDTH_MASK);
+    }
+    count
+}
@@ -0,0 +1,754 @@
+use crate::comparison::traits::{Max, Min};
+use crate::named::Named;
+use crate::num::basic::floats::PrimitiveFloat;
+use crate::num::basic::traits::{NegativeOne, One, OneHalf, Two, Zero};
+use crate::num::conversion::string::float_digits::shortest_digits;
+use crate::num::conversion::traits::{ExactFrom, FromSciString};
+use crate::num::float::FmtRyuString;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp, Write};
+use std::iter::{Product, Sum};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// A 16-bit IEEE 754 binary floating-point number (`binary16`, or "half precision"), with a
+/// 10-bit mantissa and a 5-bit exponent.
+///
+/// `F16` implements [`PrimitiveFloat`], so it can be used anywhere that [`f32`] and [`f64`] can be
+/// used: with [`NiceFloat`](super::NiceFloat), with the exhaustive and random float generators,
+/// and in the conversions to and from Malachite's other number types.
+///
+/// Arithmetic is performed by converting to [`f32`], operating, and rounding back to the nearest
+/// `F16`. For addition, subtraction, multiplication, division, remainder, and square root the
+/// result is the correctly-rounded `F16` result, since an [`f32`] has more than twice the
+/// precision of an `F16`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::basic::floats::PrimitiveFloat;
+/// use malachite_base::num::float::half::F16;
+///
+/// let x = F16::from(0.1f32);
+/// assert_eq!(x.to_bits(), 0x2e66);
+/// assert_eq!(x.to_string(), "0.1");
+/// assert_eq!(f32::from(x), 0.099975586);
+/// assert_eq!((x + x).to_string(), "0.2");
+/// assert_eq!(F16::MAX_FINITE.to_string(), "65500");
+/// assert_eq!(F16::from(65520.0f32), F16::POSITIVE_INFINITY);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/// A 16-bit "brain floating-point" number (`bfloat16`), with a 7-bit mantissa and an 8-bit
+/// exponent.
+///
+/// A `BF16` has the same exponent range as an [`f32`], but much lower precision; its bits are the
+/// upper 16 bits of the nearest [`f32`].
+///
+/// `BF16` implements [`PrimitiveFloat`], so it can be used anywhere that [`f32`] and [`f64`] can
+/// be used: with [`NiceFloat`](super::NiceFloat), with the exhaustive and random float generators,
+/// and in the conversions to and from Malachite's other number types.
+///
+/// Arithmetic is performed by converting to [`f32`], operating, and rounding back to the nearest
+/// `BF16`. For addition, subtraction, multiplication, division, remainder, and square root the
+/// result is the correctly-rounded `BF16` result, since an [`f32`] has more than twice the
+/// precision of a `BF16`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::basic::floats::PrimitiveFloat;
+/// use malachite_base::num::float::half::BF16;
+///
+/// let x = BF16::from(0.1f32);
+/// assert_eq!(x.to_bits(), 0x3dcd);
+/// assert_eq!(x.to_string(), "0.1");
+/// assert_eq!(f32::from(x), 0.10009766);
+/// assert_eq!(BF16::from(257.0f32).to_string(), "256");
+/// assert_eq!(format!("{:?}", BF16::MAX_FINITE), "3.39e38");
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct BF16(u16);
+
+// Rounds an `f32` to the nearest float with the given mantissa and exponent widths, breaking ties
+// by choosing the float with an even mantissa, and returns the bits of the result. The result is
+// at most 16 bits wide. All `NaN`s are mapped to the canonical positive `NaN`.
+fn round_f32_to_bits(x: f32, mantissa_width: u32, exponent_width: u32) -> u16 {
+    let bits = x.to_bits();
+    let sign = if bits >> 31 == 0 { 0 } else { 0x8000 };
+    let infinity = ((1u16 << exponent_width) - 1) << mantissa_width;
+    if x.is_nan() {
+        return infinity | (1 << (mantissa_width - 1));
+    } else if x.is_infinite() {
+        return sign | infinity;
+    }
+    let raw_exponent = (bits >> 23) & 0xff;
+    let raw_mantissa = bits & 0x7fffff;
+    if raw_exponent == 0 && raw_mantissa == 0 {
+        return sign;
+    }
+    // |x| = mantissa * 2^exponent
+    let (mantissa, exponent) = if raw_exponent == 0 {
+        (raw_mantissa, -149)
+    } else {
+        (raw_mantissa | 0x800000, i32::exact_from(raw_exponent) - 150)
+    };
+    // The exponent of the smallest positive float of the target type
+    let min_exponent = 2 - (1 << (exponent_width - 1)) - i32::exact_from(mantissa_width);
+    let sci_exponent = exponent + 31 - i32::exact_from(mantissa.leading_zeros());
+    let ulp_exponent = std::cmp::max(sci_exponent - i32::exact_from(mantissa_width), min_exponent);
+    let shift = ulp_exponent - exponent;
+    let rounded_mantissa = if shift <= 0 {
+        mantissa << -shift
+    } else if shift > 24 {
+        // |x| is less than half of the smallest positive float
+        0
+    } else {
+        let quotient = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if remainder > half || remainder == half && quotient & 1 == 1 {
+            quotient + 1
+        } else {
+            quotient
+        }
+    };
+    // If the rounded mantissa has overflowed into the next binade, adding it to the shifted
+    // exponent still produces the right bits.
+    let result =
+        (u32::exact_from(ulp_exponent - min_exponent) << mantissa_width) + rounded_mantissa;
+    if result >= u32::from(infinity) {
+        sign | infinity
+    } else {
+        sign | u16::exact_from(result)
+    }
+}
+
+// Converts the bits of a float with the given mantissa and exponent widths to an `f32`. This
+// conversion is exact.
+fn bits_to_f32(bits: u16, mantissa_width: u32, exponent_width: u32) -> f32 {
+    let sign = u32::from(bits >> 15) << 31;
+    let raw_exponent = u32::from(bits & 0x7fff) >> mantissa_width;
+    let raw_mantissa = u32::from(bits) & ((1 << mantissa_width) - 1);
+    let max_raw_exponent = (1 << exponent_width) - 1;
+    let f32_bits = if raw_exponent == max_raw_exponent {
+        // Infinity or NaN; `NaN`s keep their payload's high bits
+        0x7f800000 | (raw_mantissa << (23 - mantissa_width))
+    } else if raw_exponent == 0 {
+        if raw_mantissa == 0 {
+            0
+        } else {
+            // Subnormal; every subnormal 16-bit float is a normal f32, except for `BF16`
+            // subnormals, which are f32 subnormals with the same exponent
+            let min_exponent = 2 - (1 << (exponent_width - 1)) - i32::exact_from(mantissa_width);
+            let leading_zeros = raw_mantissa.leading_zeros();
+            let value_exponent = min_exponent + 31 - i32::exact_from(leading_zeros);
+            if value_exponent < -126 {
+                raw_mantissa << u32::exact_from(min_exponent + 149)
+            } else {
+                let normalized = raw_mantissa << (leading_zeros - 8);
+                (u32::exact_from(value_exponent + 127) << 23) | (normalized & 0x7fffff)
+            }
+        }
+    } else {
+        let exponent_bias = (1 << (exponent_width - 1)) - 1;
+        ((raw_exponent + 127 - exponent_bias) << 23) | (raw_mantissa << (23 - mantissa_width))
+    };
+    f32::from_bits(sign | f32_bits)
+}
+
+// Formats a finite, nonzero float that has already been decomposed into its sign, its shortest
+// decimal digits, and the exponent of its first digit. If `exponent_range` is `Some((lo, hi))`,
+// then values whose first digit's exponent is outside `[lo, hi)` are written in scientific
+// notation; if it's `None`, scientific notation is never used. If `force_point` is true, a ".0"
+// is appended to integers that are not written in scientific notation.
+fn write_shortest(
+    s: &mut String,
+    digits: &[u8],
+    exponent: i64,
+    exponent_range: Option<(i64, i64)>,
+    force_point: bool,
+) {
+    let len = i64::exact_from(digits.len());
+    let push_digits = |s: &mut String, ds: &[u8]| {
+        for &d in ds {
+            s.push(char::from(b'0' + d));
+        }
+    };
+    let use_sci = exponent_range.map_or(false, |(lo, hi)| exponent < lo || exponent >= hi);
+    if use_sci {
+        push_digits(s, &digits[..1]);
+        if digits.len() > 1 {
+            s.push('.');
+            push_digits(s, &digits[1..]);
+        }
+        write!(s, "e{}", exponent).unwrap();
+    } else if exponent < 0 {
+        s.push_str("0.");
+        for _ in 0..-exponent - 1 {
+            s.push('0');
+        }
+        push_digits(s, digits);
+    } else if len <= exponent + 1 {
+        push_digits(s, digits);
+        for _ in 0..exponent + 1 - len {
+            s.push('0');
+        }
+        if force_point {
+            s.push_str(".0");
+        }
+    } else {
+        let point = usize::exact_from(exponent + 1);
+        push_digits(s, &digits[..point]);
+        s.push('.');
+        push_digits(s, &digits[point..]);
+    }
+}
+
+// Writes a float using the shortest decimal digits that round back to it. `NaN`s and infinities
+// are written the same way that `f32` writes them.
+fn fmt_shortest<T: PrimitiveFloat>(
+    x: T,
+    f: &mut Formatter,
+    exponent_range: Option<(i64, i64)>,
+    force_point: bool,
+) -> fmt::Result {
+    if x.is_nan() {
+        return f.pad("NaN");
+    }
+    let non_negative = x.sign() == Ordering::Greater;
+    let x = x.abs();
+    let mut s = String::new();
+    if x.is_infinite() {
+        s.push_str("inf");
+    } else if x == T::ZERO {
+        s.push('0');
+        if force_point {
+            s.push_str(".0");
+        }
+    } else {
+        let (digits, exponent) = shortest_digits(x, 10);
+        write_shortest(&mut s, &digits, exponent, exponent_range, force_point);
+    }
+    f.pad_integral(non_negative, "", &s)
+}
+
+// Writes a float in scientific notation, using the shortest decimal digits that round back to it.
+fn fmt_shortest_exp<T: PrimitiveFloat>(x: T, f: &mut Formatter, upper: bool) -> fmt::Result {
+    if x.is_nan() {
+        return f.pad("NaN");
+    }
+    let non_negative = x.sign() == Ordering::Greater;
+    let x = x.abs();
+    let mut s = String::new();
+    if x.is_infinite() {
+        s.push_str("inf");
+    } else if x == T::ZERO {
+        s.push_str("0e0");
+    } else {
+        let (digits, exponent) = shortest_digits(x, 10);
+        // An empty exponent range forces scientific notation
+        write_shortest(&mut s, &digits, exponent, Some((0, 0)), false);
+    }
+    if upper {
+        s = s.replace('e', "E");
+    }
+    f.pad_integral(non_negative, "", &s)
+}
+
+// Parses a float using the same syntax that `f32`'s `FromStr` implementation accepts.
+fn parse_half_float<T: PrimitiveFloat>(src: &str) -> Result<T, ()> {
+    let (negative, body) = match src.as_bytes().first() {
+        Some(&b'-') => (true, &src[1..]),
+        Some(&b'+') => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let special = if body.eq_ignore_ascii_case("inf") || body.eq_ignore_ascii_case("infinity") {
+        Some(T::POSITIVE_INFINITY)
+    } else if body.eq_ignore_ascii_case("nan") {
+        Some(T::NAN)
+    } else {
+        None
+    };
+    if let Some(x) = special {
+        return Ok(if negative { -x } else { x });
+    }
+    if matches!(body.as_bytes().first(), Some(&b'+') | Some(&b'-'))
+        || !body.bytes().any(|b| b.is_ascii_digit())
+        || !body
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'))
+    {
+        return Err(());
+    }
+    let x = T::from_sci_string(body).ok_or(())?;
+    Ok(if negative { -x } else { x })
+}
+
+macro_rules! impl_half_float {
+    (
+        $t: ident,
+        $mantissa_width: expr,
+        $exponent_width: expr,
+        $max_finite: expr,
+        $one: expr,
+        $two: expr,
+        $one_half: expr,
+        $epsilon: expr
+    ) => {
+        impl $t {
+            /// The number of significant bits, including the implicit leading bit.
+            pub const MANTISSA_DIGITS: u32 = $mantissa_width + 1;
+            /// Positive infinity.
+            pub const INFINITY: $t = $t(((1 << $exponent_width) - 1) << $mantissa_width);
+            /// Negative infinity.
+            pub const NEG_INFINITY: $t = $t($t::INFINITY.0 | 0x8000);
+            /// The canonical `NaN`.
+            pub const NAN: $t = $t(
+                (((1 << $exponent_width) - 1) << $mantissa_width) | (1 << ($mantissa_width - 1)),
+            );
+            /// The largest finite value.
+            pub const MAX: $t = $t($max_finite);
+            /// The smallest (most negative) finite value.
+            pub const MIN: $t = $t(0x8000 | $max_finite);
+            /// The smallest positive normal value.
+            pub const MIN_POSITIVE: $t = $t(1 << $mantissa_width);
+            /// The difference between 1 and the next larger representable value.
+            pub const EPSILON: $t = $t($epsilon);
+
+            /// Creates a float from its raw bits.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            pub const fn from_bits(bits: u16) -> $t {
+                $t(bits)
+            }
+
+            /// Returns the raw bits of a float.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Converts an [`f32`] to the nearest float of this type, breaking ties by choosing
+            /// the float with an even mantissa. Values too large to be represented become
+            /// infinities.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            pub fn from_f32(x: f32) -> $t {
+                $t(round_f32_to_bits(x, $mantissa_width, $exponent_width))
+            }
+
+            /// Converts a float to an [`f32`]. The conversion is exact.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                bits_to_f32(self.0, $mantissa_width, $exponent_width)
+            }
+
+            /// Converts a float to an [`f64`]. The conversion is exact.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                f64::from(self.to_f32())
+            }
+
+            /// Returns `true` if this value is `NaN`.
+            #[inline]
+            pub const fn is_nan(self) -> bool {
+                self.0 & 0x7fff > Self::INFINITY.0
+            }
+
+            /// Returns `true` if this value is positive or negative infinity.
+            #[inline]
+            pub const fn is_infinite(self) -> bool {
+                self.0 & 0x7fff == Self::INFINITY.0
+            }
+
+            /// Returns `true` if this value is neither infinite nor `NaN`.
+            #[inline]
+            pub const fn is_finite(self) -> bool {
+                self.0 & 0x7fff < Self::INFINITY.0
+            }
+
+            /// Returns `true` if this value is neither zero, infinite, subnormal, nor `NaN`.
+            #[inline]
+            pub fn is_normal(self) -> bool {
+                self.classify() == FpCategory::Normal
+            }
+
+            /// Returns the floating-point category of this value.
+            pub fn classify(self) -> FpCategory {
+                let raw_exponent = self.0 & Self::INFINITY.0;
+                let raw_mantissa = self.0 & ((1 << $mantissa_width) - 1);
+                if raw_exponent == Self::INFINITY.0 {
+                    if raw_mantissa == 0 {
+                        FpCategory::Infinite
+                    } else {
+                        FpCategory::Nan
+                    }
+                } else if raw_exponent == 0 {
+                    if raw_mantissa == 0 {
+                        FpCategory::Zero
+                    } else {
+                        FpCategory::Subnormal
+                    }
+                } else {
+                    FpCategory::Normal
+                }
+            }
+
+            /// Returns `true` if this value has a positive sign, including `+0.0`, positive
+            /// infinity, and `NaN`s with a positive sign bit.
+            #[inline]
+            pub const fn is_sign_positive(self) -> bool {
+                self.0 & 0x8000 == 0
+            }
+
+            /// Returns `true` if this value has a negative sign, including `-0.0`, negative
+            /// infinity, and `NaN`s with a negative sign bit.
+            #[inline]
+            pub const fn is_sign_negative(self) -> bool {
+                self.0 & 0x8000 != 0
+            }
+
+            /// Returns the absolute value of this value.
+            #[inline]
+            pub const fn abs(self) -> $t {
+                $t(self.0 & 0x7fff)
+            }
+
+            /// Returns the largest integer less than or equal to this value.
+            #[inline]
+            pub fn floor(self) -> $t {
+                $t::from_f32(self.to_f32().floor())
+            }
+
+            /// Returns the smallest integer greater than or equal to this value.
+            #[inline]
+            pub fn ceil(self) -> $t {
+                $t::from_f32(self.to_f32().ceil())
+            }
+
+            /// Returns the square root of this value, correctly rounded.
+            #[inline]
+            pub fn sqrt(self) -> $t {
+                $t::from_f32(self.to_f32().sqrt())
+            }
+
+            /// Raises this value to an integer power.
+            #[inline]
+            pub fn powi(self, n: i32) -> $t {
+                $t::from_f32(self.to_f32().powi(n))
+            }
+
+            /// Raises this value to a floating-point power.
+            #[inline]
+            pub fn powf(self, n: $t) -> $t {
+                $t::from_f32(self.to_f32().powf(n.to_f32()))
+            }
+        }
+
+        impl PrimitiveFloat for $t {
+            const WIDTH: u32 = 16;
+            const MANTISSA_WIDTH: u32 = $mantissa_width;
+
+            const POSITIVE_INFINITY: Self = $t::INFINITY;
+            const NEGATIVE_INFINITY: Self = $t::NEG_INFINITY;
+            const NEGATIVE_ZERO: Self = $t(0x8000);
+            const NAN: Self = $t::NAN;
+            const MAX_FINITE: Self = $t::MAX;
+            const MIN_POSITIVE_SUBNORMAL: Self = $t(1);
+            const MAX_SUBNORMAL: Self = $t((1 << $mantissa_width) - 1);
+            const MIN_POSITIVE_NORMAL: Self = $t::MIN_POSITIVE;
+            const SMALLEST_UNREPRESENTABLE_UINT: u32 = (1 << (Self::MANTISSA_WIDTH + 1)) + 1;
+            const LARGEST_ORDERED_REPRESENTATION: u32 =
+                (1 << 16) - (((1 << Self::MANTISSA_WIDTH) - 1) << 1) - 1;
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                $t::is_normal(self)
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                $t::classify(self)
+            }
+
+            #[inline]
+            fn to_bits(self) -> u32 {
+                u32::from($t::to_bits(self))
+            }
+
+            #[inline]
+            fn from_bits(v: u32) -> $t {
+                $t::from_bits(u16::exact_from(v))
+            }
+        }
+
+        impl_named!($t);
+
+        /// The constant 0.
+        impl Zero for $t {
+            const ZERO: $t = $t(0);
+        }
+
+        /// The constant 1.
+        impl One for $t {
+            const ONE: $t = $t($one);
+        }
+
+        /// The constant 2.
+        impl Two for $t {
+            const TWO: $t = $t($two);
+        }
+
+        /// The constant 1/2.
+        impl OneHalf for $t {
+            const ONE_HALF: $t = $t($one_half);
+        }
+
+        /// The constant -1.0.
+        impl NegativeOne for $t {
+            const NEGATIVE_ONE: $t = $t(0x8000 | $one);
+        }
+
+        /// The lowest value representable by this type, negative infinity.
+        impl Min for $t {
+            const MIN: $t = $t::NEG_INFINITY;
+        }
+
+        /// The highest value representable by this type, positive infinity.
+        impl Max for $t {
+            const MAX: $t = $t::INFINITY;
+        }
+
+        impl From<f32> for $t {
+            /// Converts an [`f32`] to the nearest float of this type. See
+            /// [`from_f32`](Self::from_f32).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn from(x: f32) -> $t {
+                $t::from_f32(x)
+            }
+        }
+
+        impl From<$t> for f32 {
+            /// Converts a float to an [`f32`]. The conversion is exact.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn from(x: $t) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl From<$t> for f64 {
+            /// Converts a float to an [`f64`]. The conversion is exact.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            #[inline]
+            fn from(x: $t) -> f64 {
+                x.to_f64()
+            }
+        }
+
+        impl PartialEq for $t {
+            /// Compares two floats for equality, following the IEEE 754 standard: `NaN` is not
+            /// equal to anything, and positive and negative zero are equal.
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $t {
+            /// Compares two floats, following the IEEE 754 standard: `NaN` is incomparable to
+            /// everything, and positive and negative zero are equal.
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                $t(self.0 ^ 0x8000)
+            }
+        }
+
+        impl<'a> Neg for &'a $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                $t(self.0 ^ 0x8000)
+            }
+        }
+
+        impl_half_float_binary_op!($t, Add, add, AddAssign, add_assign, +);
+        impl_half_float_binary_op!($t, Sub, sub, SubAssign, sub_assign, -);
+        impl_half_float_binary_op!($t, Mul, mul, MulAssign, mul_assign, *);
+        impl_half_float_binary_op!($t, Div, div, DivAssign, div_assign, /);
+        impl_half_float_binary_op!($t, Rem, rem, RemAssign, rem_assign, %);
+
+        impl Sum for $t {
+            fn sum<I: Iterator<Item = $t>>(xs: I) -> $t {
+                xs.fold($t::ZERO, Add::add)
+            }
+        }
+
+        impl Product for $t {
+            fn product<I: Iterator<Item = $t>>(xs: I) -> $t {
+                xs.fold($t::ONE, Mul::mul)
+            }
+        }
+
+        impl Display for $t {
+            /// Formats a float using the shortest decimal representation that rounds back to it,
+            /// without an exponent. If a precision is specified, the exact value is rounded to
+            /// that many digits after the point instead.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                if f.precision().is_some() {
+                    Display::fmt(&self.to_f32(), f)
+                } else {
+                    fmt_shortest(*self, f, None, false)
+                }
+            }
+        }
+
+        impl Debug for $t {
+            /// Formats a float using the shortest decimal representation that rounds back to it,
+            /// in the same style that [`f32`]'s `Debug` implementation uses.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                if f.precision().is_some() {
+                    Debug::fmt(&self.to_f32(), f)
+                } else {
+                    fmt_shortest(*self, f, Some((-4, 16)), true)
+                }
+            }
+        }
+
+        impl LowerExp for $t {
+            /// Formats a float in scientific notation with a lowercase `e`, using the shortest
+            /// decimal representation that rounds back to it.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                if f.precision().is_some() {
+                    LowerExp::fmt(&self.to_f32(), f)
+                } else {
+                    fmt_shortest_exp(*self, f, false)
+                }
+            }
+        }
+
+        impl UpperExp for $t {
+            /// Formats a float in scientific notation with an uppercase `E`, using the shortest
+            /// decimal representation that rounds back to it.
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                if f.precision().is_some() {
+                    UpperExp::fmt(&self.to_f32(), f)
+                } else {
+                    fmt_shortest_exp(*self, f, true)
+                }
+            }
+        }
+
+        impl FmtRyuString for $t {
+            // Uses the same layout as Ryu does for `f32`s, with the modifications described in
+            // the documentation of `NiceFloat`.
+            fn fmt_ryu_string(self, f: &mut Formatter<'_>) -> fmt::Result {
+                if self == $t::ZERO {
+                    return f.write_str(if self.is_sign_negative() { "-0.0" } else { "0.0" });
+                }
+                let mut s = String::new();
+                if self.is_sign_negative() {
+                    s.push('-');
+                }
+                let (digits, exponent) = shortest_digits(self.abs(), 10);
+                write_shortest(&mut s, &digits, exponent, Some((-6, 13)), true);
+                // Convert e.g. "1e-7" to "1.0e-7"
+                if let Some(e_index) = s.find('e') {
+                    if !s.contains('.') {
+                        s.insert_str(e_index, ".0");
+                    }
+                }
+                f.write_str(&s)
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ();
+
+            /// Converts a string to a float, using the syntax accepted by [`f32`]'s `FromStr`
+            /// implementation. The result is correctly rounded.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `src.len()`.
+            #[inline]
+            fn from_str(src: &str) -> Result<$t, ()> {
+                parse_half_float(src)
+            }
+        }
+    };
+}
+
+macro_rules! impl_half_float_binary_op {
+    ($t: ident, $op: ident, $fn: ident, $op_assign: ident, $fn_assign: ident, $symbol: tt) => {
+        impl $op for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $fn(self, other: $t) -> $t {
+                $t::from_f32(self.to_f32() $symbol other.to_f32())
+            }
+        }
+
+        impl $op_assign for $t {
+            #[inline]
+            fn $fn_assign(&mut self, other: $t) {
+                *self = $op::$fn(*self, other);
+            }
+        }
+    };
+}
+
+impl_half_float!(F16, 10, 5, 0x7bff, 0x3c00, 0x4000, 0x3800, 0x1400);
+impl_half_float!(BF16, 7, 8, 0x7f7f, 0x3f80, 0x4000, 0x3f00, 0x3c00);
@@ -4,6 +4,10 @@ use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+/// [`F16`](half::F16) and [`BF16`](half::BF16), 16-bit floating-point types that implement
+/// [`PrimitiveFloat`].
+pub mod half;
+
 /// `NiceFloat` is a wrapper around primitive float types that provides nicer [`Eq`], [`Ord`],
 /// [`Hash`], [`Display`], and [`FromStr`] instances.
 ///
@@ -293,6 +293,8 @@ macro_rules! apply_fn_to_primitive_ints_and_primitive_ints {
 macro_rules! apply_to_primitive_floats {
     ($m: tt) => {
         $m!(f32);
+        $m!(F16);
+        $m!(BF16);
     };
 }
 
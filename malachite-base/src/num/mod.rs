@@ -15,7 +15,8 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for generating primes, primality testing, and factorization (TODO!)
 pub mod factorization;
-/// [`NiceFloat`](float::NiceFloat), a wrapper around primitive floats.
+/// [`NiceFloat`](float::NiceFloat), a wrapper around primitive floats, and the 16-bit float types
+/// [`F16`](float::half::F16) and [`BF16`](float::half::BF16).
 pub mod float;
 /// Iterators related to numbers.
 pub mod iterators;
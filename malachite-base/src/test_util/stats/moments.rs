@@ -4,6 +4,7 @@ use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::traits::Zero;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::WrappingFrom;
+use crate::num::float::half::{BF16, F16};
 use crate::num::float::NiceFloat;
 use crate::test_util::stats::common_values_map::common_values_map;
 use crate::test_util::stats::median;
@@ -58,6 +59,19 @@ impl CheckedToF64 for f64 {
     }
 }
 
+macro_rules! impl_checked_to_f64_for_half_floats {
+    ($t: ident) => {
+        impl CheckedToF64 for $t {
+            #[inline]
+            fn checked_to_f64(&self) -> f64 {
+                f64::from(*self)
+            }
+        }
+    };
+}
+impl_checked_to_f64_for_half_floats!(F16);
+impl_checked_to_f64_for_half_floats!(BF16);
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct MomentStats {
     pub mean: NiceFloat<f64>,
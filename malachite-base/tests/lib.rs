@@ -324,6 +324,7 @@ pub mod num {
             pub mod vec_from_other_type_slice;
         }
         pub mod string {
+            pub mod alphabet;
             pub mod from_sci_string;
             pub mod from_string;
             pub mod options {
@@ -387,6 +388,12 @@ pub mod num {
             pub mod precision;
             pub mod to_ordered_representation;
         }
+        pub mod half {
+            pub mod arithmetic;
+            pub mod from_f32;
+            pub mod from_str;
+            pub mod to_string;
+        }
         pub mod nice_float {
             pub mod cmp;
             pub mod eq;
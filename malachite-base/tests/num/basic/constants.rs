@@ -2,6 +2,7 @@ use malachite_base::named::Named;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{NegativeOne, One, OneHalf, Two, Zero};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 
 macro_rules! test_unsigned_constants {
@@ -0,0 +1,217 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::alphabet::{
+    crockford_check_symbol, crockford_check_value, DigitAlphabet,
+};
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{
+    FromStringWithAlphabet, ToStringBase, ToStringWithAlphabet,
+};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+
+const ALPHABETS: [DigitAlphabet; 4] = [
+    DigitAlphabet::BASE58_BITCOIN,
+    DigitAlphabet::BASE62,
+    DigitAlphabet::BASE64_URL,
+    DigitAlphabet::CROCKFORD_BASE32,
+];
+
+#[test]
+fn test_digit_alphabet() {
+    assert_eq!(DigitAlphabet::BASE58_BITCOIN.get_base(), 58);
+    assert_eq!(DigitAlphabet::BASE62.get_base(), 62);
+    assert_eq!(DigitAlphabet::BASE64_URL.get_base(), 64);
+    assert_eq!(DigitAlphabet::CROCKFORD_BASE32.get_base(), 32);
+
+    let hex = DigitAlphabet::new("0123456789abcdef");
+    assert!(!hex.is_case_insensitive());
+    assert_eq!(hex.byte_to_digit(b'f'), Some(15));
+    assert_eq!(hex.byte_to_digit(b'F'), None);
+    let hex = hex.case_insensitive();
+    assert!(hex.is_case_insensitive());
+    assert_eq!(hex.byte_to_digit(b'F'), Some(15));
+    assert_eq!(hex.digit_to_byte(15), Some(b'f'));
+    assert_eq!(hex.digit_to_byte(16), None);
+
+    let crockford = DigitAlphabet::CROCKFORD_BASE32;
+    assert_eq!(crockford.byte_to_digit(b'O'), Some(0));
+    assert_eq!(crockford.byte_to_digit(b'o'), Some(0));
+    assert_eq!(crockford.byte_to_digit(b'I'), Some(1));
+    assert_eq!(crockford.byte_to_digit(b'l'), Some(1));
+    assert_eq!(crockford.byte_to_digit(b'U'), None);
+    assert_eq!(crockford.byte_to_digit(b'-'), None);
+}
+
+#[test]
+#[should_panic]
+fn digit_alphabet_new_fail_1() {
+    DigitAlphabet::new("0");
+}
+
+#[test]
+#[should_panic]
+fn digit_alphabet_new_fail_2() {
+    DigitAlphabet::new("0120");
+}
+
+#[test]
+#[should_panic]
+fn digit_alphabet_new_fail_3() {
+    DigitAlphabet::new("01 ");
+}
+
+#[test]
+#[should_panic]
+fn case_insensitive_fail() {
+    DigitAlphabet::BASE62.case_insensitive();
+}
+
+#[test]
+fn test_decode_digits() {
+    use ParseNumberErrorKind::*;
+    let test_ok = |alphabet: DigitAlphabet, s, out: &[u8]| {
+        assert_eq!(alphabet.decode_digits(s, 0).unwrap(), out);
+    };
+    test_ok(DigitAlphabet::BASE62, "0", &[0]);
+    test_ok(DigitAlphabet::BASE62, "09Az", &[0, 9, 10, 61]);
+    test_ok(DigitAlphabet::BASE64_URL, "A-_", &[0, 62, 63]);
+    test_ok(DigitAlphabet::CROCKFORD_BASE32, "1o-Il", &[1, 0, 1, 1]);
+
+    let test_err = |alphabet: DigitAlphabet, s, offset, kind, index| {
+        assert_eq!(
+            alphabet.decode_digits(s, offset),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err(DigitAlphabet::BASE62, "", 0, Empty, 0);
+    test_err(DigitAlphabet::BASE62, "", 2, Empty, 2);
+    test_err(DigitAlphabet::BASE62, "a-b", 0, InvalidDigit, 1);
+    test_err(DigitAlphabet::BASE58_BITCOIN, "0", 1, InvalidDigit, 1);
+    let crockford = DigitAlphabet::CROCKFORD_BASE32;
+    test_err(crockford, "-1", 0, MisplacedSeparator, 0);
+    test_err(crockford, "1--1", 0, MisplacedSeparator, 2);
+    test_err(crockford, "1-", 0, MisplacedSeparator, 1);
+    test_err(crockford, "1u", 0, InvalidDigit, 1);
+}
+
+#[test]
+fn test_crockford_check_symbols() {
+    for value in 0..37 {
+        let symbol = crockford_check_symbol(value);
+        assert_eq!(crockford_check_value(symbol), Some(value));
+        assert_eq!(
+            crockford_check_value(symbol.to_ascii_lowercase()),
+            Some(value)
+        );
+    }
+    assert_eq!(crockford_check_value(b'o'), Some(0));
+    assert_eq!(crockford_check_value(b'-'), None);
+}
+
+#[test]
+#[should_panic]
+fn crockford_check_symbol_fail() {
+    crockford_check_symbol(37);
+}
+
+#[test]
+fn test_to_string_with_alphabet() {
+    assert_eq!(
+        0u8.to_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN),
+        "1"
+    );
+    assert_eq!(
+        u64::MAX.to_string_with_alphabet(DigitAlphabet::BASE62),
+        "LygHa16AHYF"
+    );
+    assert_eq!(
+        u64::MAX.to_string_with_alphabet(DigitAlphabet::BASE64_URL),
+        "P__________"
+    );
+    assert_eq!(
+        i64::MIN.to_string_with_alphabet(DigitAlphabet::BASE62),
+        "-AzL8n0Y58m8"
+    );
+    assert_eq!(
+        (-1i8).to_string_with_alphabet(DigitAlphabet::CROCKFORD_BASE32),
+        "-1"
+    );
+}
+
+#[test]
+#[should_panic]
+fn to_string_with_alphabet_fail() {
+    (-1i8).to_string_with_alphabet(DigitAlphabet::BASE64_URL);
+}
+
+#[test]
+fn test_from_string_with_alphabet() {
+    assert_eq!(
+        u64::from_string_with_alphabet(DigitAlphabet::BASE62, "LygHa16AHYF"),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        i64::from_string_with_alphabet(DigitAlphabet::BASE62, "-AzL8n0Y58m8"),
+        Ok(i64::MIN)
+    );
+    assert_eq!(
+        i8::from_string_with_alphabet(DigitAlphabet::BASE64_URL, "-"),
+        Ok(62)
+    );
+    assert_eq!(
+        u64::from_string_with_alphabet(DigitAlphabet::BASE62, "LygHa16AHYG"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+    );
+    assert_eq!(
+        i64::from_string_with_alphabet(DigitAlphabet::BASE62, "-AzL8n0Y58m9"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 1))
+    );
+    assert_eq!(
+        i8::from_string_with_alphabet(DigitAlphabet::BASE62, "-"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1))
+    );
+    assert_eq!(
+        u8::from_string_with_alphabet(DigitAlphabet::BASE62, "-1"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 0))
+    );
+}
+
+fn alphabet_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|x| {
+        for alphabet in ALPHABETS {
+            let s = x.to_string_with_alphabet(alphabet);
+            assert_eq!(T::from_string_with_alphabet(alphabet, &s), Ok(x));
+            assert_eq!(s.len() == 1, x < T::from(alphabet.get_base()));
+        }
+        let decimal = DigitAlphabet::new("0123456789");
+        assert_eq!(x.to_string_with_alphabet(decimal), x.to_string());
+        let hex = DigitAlphabet::new("0123456789abcdef").case_insensitive();
+        assert_eq!(x.to_string_with_alphabet(hex), x.to_string_base(16));
+        assert_eq!(
+            T::from_string_with_alphabet(hex, &x.to_string_base_upper(16)),
+            Ok(x)
+        );
+    });
+}
+
+fn alphabet_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|x| {
+        for alphabet in ALPHABETS {
+            if x < T::ZERO && alphabet == DigitAlphabet::BASE64_URL {
+                continue;
+            }
+            let s = x.to_string_with_alphabet(alphabet);
+            assert_eq!(T::from_string_with_alphabet(alphabet, &s), Ok(x));
+        }
+        let decimal = DigitAlphabet::new("0123456789");
+        assert_eq!(x.to_string_with_alphabet(decimal), x.to_string());
+    });
+}
+
+#[test]
+fn alphabet_properties() {
+    apply_fn_to_unsigneds!(alphabet_properties_helper_unsigned);
+    apply_fn_to_signeds!(alphabet_properties_helper_signed);
+}
@@ -1,12 +1,18 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::float::NiceFloat;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::{
-    signed_gen, string_from_sci_string_options_pair_gen,
+    primitive_float_gen, signed_gen, string_from_sci_string_options_pair_gen,
     string_from_sci_string_options_pair_gen_var_1, string_gen, string_gen_var_13, unsigned_gen,
 };
+use std::str::FromStr;
 
 #[test]
 pub fn test_from_sci_string() {
@@ -730,6 +736,95 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-1.9999999999999999999999999999", options, None);
 }
 
+#[test]
+pub fn test_try_from_sci_string_with_options() {
+    fn test<T: PrimitiveInt>(
+        s: &str,
+        options: FromSciStringOptions,
+        out: Result<T, (ParseNumberErrorKind, usize)>,
+    ) {
+        assert_eq!(
+            T::try_from_sci_string_with_options(s, options),
+            out.map_err(|(kind, index)| ParseNumberError::new(kind, index))
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    test::<u8>("123", options, Ok(123));
+    test::<u8>("1.25e2", options, Ok(125));
+    test::<u8>("", options, Err((ParseNumberErrorKind::Empty, 0)));
+    test::<u8>("-", options, Err((ParseNumberErrorKind::Empty, 1)));
+    test::<u8>(".e5", options, Err((ParseNumberErrorKind::InvalidDigit, 1)));
+    test::<u8>("1e", options, Err((ParseNumberErrorKind::Empty, 2)));
+    test::<u8>("1e+", options, Err((ParseNumberErrorKind::Empty, 3)));
+    test::<u8>("12a", options, Err((ParseNumberErrorKind::InvalidDigit, 2)));
+    test::<u8>(
+        "1e5x",
+        options,
+        Err((ParseNumberErrorKind::InvalidDigit, 3)),
+    );
+    test::<u8>(
+        "1.2.3",
+        options,
+        Err((ParseNumberErrorKind::InvalidDigit, 3)),
+    );
+    test::<u8>("1-2", options, Err((ParseNumberErrorKind::InvalidDigit, 1)));
+    test::<u8>(
+        "1e5-2",
+        options,
+        Err((ParseNumberErrorKind::InvalidDigit, 3)),
+    );
+    test::<u8>("256", options, Err((ParseNumberErrorKind::OutOfRange, 0)));
+    test::<u8>("-1", options, Err((ParseNumberErrorKind::OutOfRange, 1)));
+    test::<u8>("-1e-1", options, Ok(0));
+    test::<i8>("+1e3", options, Err((ParseNumberErrorKind::OutOfRange, 1)));
+    test::<u8>(
+        "1e99999999999999999999",
+        options,
+        Err((ParseNumberErrorKind::OutOfRange, 0)),
+    );
+
+    options.set_rounding_mode(RoundingMode::Exact);
+    test::<u8>("1.5e1", options, Ok(15));
+    test::<u8>("1.5", options, Err((ParseNumberErrorKind::Inexact, 0)));
+    test::<i8>("-1.5", options, Err((ParseNumberErrorKind::Inexact, 1)));
+    test::<u8>("-1.5", options, Err((ParseNumberErrorKind::OutOfRange, 1)));
+    test::<u8>("255.5", options, Err((ParseNumberErrorKind::OutOfRange, 0)));
+
+    options = FromSciStringOptions::default();
+    options.set_base(16);
+    test::<u16>("1e", options, Ok(0x1e));
+    test::<u16>("1e+2", options, Ok(0x100));
+    test::<u16>(
+        "1f-2",
+        options,
+        Err((ParseNumberErrorKind::InvalidDigit, 2)),
+    );
+    test::<u16>("1e-", options, Err((ParseNumberErrorKind::Empty, 3)));
+    test::<u16>("1g", options, Err((ParseNumberErrorKind::InvalidDigit, 1)));
+}
+
+#[test]
+pub fn test_try_from_sci_string_primitive_float() {
+    fn test<T: PrimitiveFloat>(s: &str, out: Result<T, (ParseNumberErrorKind, usize)>) {
+        assert_eq!(
+            T::try_from_sci_string(s).map(NiceFloat),
+            out.map(NiceFloat)
+                .map_err(|(kind, index)| ParseNumberError::new(kind, index))
+        );
+    }
+    test::<f32>("0.1", Ok(0.1));
+    test::<f32>("NaN", Ok(f32::NAN));
+    test::<f32>("nan", Err((ParseNumberErrorKind::InvalidDigit, 0)));
+    test::<f64>("1.5e", Err((ParseNumberErrorKind::Empty, 4)));
+
+    let mut options = FromSciStringOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    assert_eq!(
+        f32::try_from_sci_string_with_options("0.1", options),
+        Err(ParseNumberError::new(ParseNumberErrorKind::Inexact, 0))
+    );
+}
+
 fn from_sci_string_helper_helper<T: PrimitiveInt>(s: &str) {
     if let Some(x) = T::from_sci_string(s) {
         assert!(!s.ends_with('+'));
@@ -787,6 +882,16 @@ fn from_sci_string_with_options_helper_helper<T: PrimitiveInt>(
     s: &str,
     options: FromSciStringOptions,
 ) {
+    match T::try_from_sci_string_with_options(s, options) {
+        Ok(x) => assert_eq!(T::from_sci_string_with_options(s, options), Some(x)),
+        Err(e) => {
+            assert_eq!(T::from_sci_string_with_options(s, options), None);
+            assert!(e.index <= s.len());
+            if e.kind == ParseNumberErrorKind::Inexact {
+                assert_eq!(options.get_rounding_mode(), RoundingMode::Exact);
+            }
+        }
+    }
     if let Some(x) = T::from_sci_string_with_options(s, options) {
         assert!(!s.ends_with('+'));
         assert!(!s.ends_with('-'));
@@ -829,3 +934,131 @@ fn from_sci_string_with_options_helper<T: PrimitiveInt>() {
 fn from_sci_string_with_options_properties() {
     apply_fn_to_primitive_ints!(from_sci_string_with_options_helper);
 }
+
+#[test]
+pub fn test_from_sci_string_primitive_float() {
+    fn test<T: PrimitiveFloat>(s: &str, out: Option<T>) {
+        assert_eq!(T::from_sci_string(s).map(NiceFloat), out.map(NiceFloat));
+        assert_eq!(
+            T::from_sci_string_with_options(s, FromSciStringOptions::default()).map(NiceFloat),
+            out.map(NiceFloat)
+        );
+    }
+    test::<f32>("0", Some(0.0));
+    test::<f32>("-0", Some(-0.0));
+    test::<f32>("0.000e5", Some(0.0));
+    test::<f32>("NaN", Some(f32::NAN));
+    test::<f32>("Infinity", Some(f32::INFINITY));
+    test::<f32>("-Infinity", Some(f32::NEG_INFINITY));
+    test::<f32>("1", Some(1.0));
+    test::<f32>("0.1", Some(0.1));
+    test::<f32>("-123.5", Some(-123.5));
+    test::<f32>("16777217", Some(16777216.0));
+    test::<f32>("16777219", Some(16777220.0));
+    test::<f32>("3.4028235e38", Some(f32::MAX));
+    test::<f32>("3.5e38", Some(f32::INFINITY));
+    test::<f32>("1e-45", Some(f32::MIN_POSITIVE_SUBNORMAL));
+    test::<f32>("8e-46", Some(f32::MIN_POSITIVE_SUBNORMAL));
+    test::<f32>("7e-47", Some(0.0));
+
+    test::<f64>("0.1", Some(0.1));
+    test::<f64>("0.30000000000000004", Some(0.30000000000000004));
+    test::<f64>("1e23", Some(1.0e23));
+    test::<f64>("8.988465674311580536566680e307", Some(8.98846567431158e307));
+    test::<f64>("1.7976931348623157e308", Some(f64::MAX));
+    test::<f64>("1.7976931348623159e308", Some(f64::INFINITY));
+    test::<f64>("1e99999999999", Some(f64::INFINITY));
+    test::<f64>("-1e99999999999", Some(f64::NEG_INFINITY));
+    test::<f64>("2.2250738585072011e-308", Some(2.225073858507201e-308));
+    test::<f64>("5e-324", Some(f64::MIN_POSITIVE_SUBNORMAL));
+    test::<f64>("2.4703282292062328e-324", Some(f64::MIN_POSITIVE_SUBNORMAL));
+    test::<f64>("2.4703282292062327e-324", Some(0.0));
+    test::<f64>("1e-99999999999", Some(0.0));
+    // A halfway case that needs many digits to resolve
+    test::<f64>(
+        "9007199254740993.00000000000000000000000000000000000000000000000000000000001",
+        Some(9007199254740994.0),
+    );
+    test::<f64>("9007199254740993", Some(9007199254740992.0));
+
+    test::<f32>("", None);
+    test::<f32>("-", None);
+    test::<f32>(".", None);
+    test::<f32>("e5", None);
+    test::<f32>("1e", None);
+    test::<f32>("1.2.3", None);
+    test::<f32>("abc", None);
+    test::<f32>("nan", None);
+    test::<f32>("inf", None);
+}
+
+#[test]
+pub fn test_from_sci_string_with_options_primitive_float() {
+    fn test<T: PrimitiveFloat>(s: &str, options: FromSciStringOptions, out: Option<T>) {
+        assert_eq!(
+            T::from_sci_string_with_options(s, options).map(NiceFloat),
+            out.map(NiceFloat)
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    options.set_rounding_mode(RoundingMode::Floor);
+    test::<f32>("0.1", options, Some(0.099999994));
+    test::<f32>("-0.1", options, Some(-0.1));
+    test::<f32>("1e39", options, Some(f32::MAX));
+    test::<f32>("-1e39", options, Some(f32::NEG_INFINITY));
+    test::<f32>("1e-50", options, Some(0.0));
+    test::<f32>("-1e-50", options, Some(-f32::MIN_POSITIVE_SUBNORMAL));
+    options.set_rounding_mode(RoundingMode::Ceiling);
+    test::<f32>("0.1", options, Some(0.1));
+    test::<f32>("-0.1", options, Some(-0.099999994));
+    test::<f32>("1e39", options, Some(f32::INFINITY));
+    test::<f32>("1e-50", options, Some(f32::MIN_POSITIVE_SUBNORMAL));
+    options.set_rounding_mode(RoundingMode::Down);
+    test::<f32>("-1e39", options, Some(-f32::MAX));
+    options.set_rounding_mode(RoundingMode::Up);
+    test::<f32>("-1e-50", options, Some(-f32::MIN_POSITIVE_SUBNORMAL));
+    options.set_rounding_mode(RoundingMode::Exact);
+    test::<f32>("0.1", options, None);
+    test::<f32>("0.375", options, Some(0.375));
+    test::<f32>("16777217", options, None);
+    test::<f32>("1e39", options, None);
+    test::<f32>("1e-50", options, None);
+
+    let mut options = FromSciStringOptions::default();
+    options.set_base(2);
+    test::<f64>("-0.011", options, Some(-0.375));
+    // The exponent is always written in base 10
+    test::<f64>("1e-10", options, Some(0.0009765625));
+    options.set_base(16);
+    test::<f64>("ff.8", options, Some(255.5));
+    test::<f64>("FF.8", options, Some(255.5));
+    test::<f64>("1e+2", options, Some(256.0));
+    test::<f64>("1e2", options, Some(482.0));
+    options.set_base(3);
+    test::<f64>("0.1", options, Some(1.0 / 3.0));
+    test::<f64>("3", options, None);
+}
+
+fn from_sci_string_helper_primitive_float<T: PrimitiveFloat>() {
+    primitive_float_gen::<T>().test_properties(|x| {
+        let s = NiceFloat(x).to_string();
+        assert_eq!(NiceFloat(T::from_sci_string(&s).unwrap()), NiceFloat(x));
+        if x.is_finite() {
+            let s = format!("{:e}", x);
+            assert_eq!(NiceFloat(T::from_sci_string(&s).unwrap()), NiceFloat(x));
+        }
+    });
+
+    string_gen_var_13().test_properties(|s| {
+        if let Ok(x) = T::from_str(&s) {
+            if let Some(y) = T::from_sci_string(&s) {
+                assert_eq!(NiceFloat(y), NiceFloat(x));
+            }
+        }
+    });
+}
+
+#[test]
+fn from_sci_string_primitive_float_properties() {
+    apply_fn_to_primitive_floats!(from_sci_string_helper_primitive_float);
+}
@@ -1,8 +1,14 @@
-use malachite_base::num::conversion::string::from_string::digit_from_display_byte;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::from_string::{
+    clean_digits, digit_from_display_byte, strip_sign, ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
-use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_10};
+use malachite_base::num::conversion::traits::FromStringWithOptions;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen, unsigned_gen_var_10};
 
 #[test]
 fn test_digit_from_display_byte() {
@@ -62,3 +68,139 @@ fn digit_from_display_byte_properties() {
         }
     });
 }
+
+fn lenient_options() -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_allow_underscore_separators(true);
+    options.set_allow_comma_separators(true);
+    options.set_allow_whitespace(true);
+    options.set_allow_prefixes(true);
+    options.set_allow_plus_sign(true);
+    options
+}
+
+#[test]
+fn test_strip_sign() {
+    let test = |s, options, out: Result<(bool, std::ops::Range<usize>), ParseNumberError>| {
+        assert_eq!(strip_sign(s, options), out);
+    };
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    test("", default, Ok((false, 0..0)));
+    test("123", default, Ok((false, 0..3)));
+    test("-123", default, Ok((true, 1..4)));
+    test(
+        "+123",
+        default,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0)),
+    );
+    test(" 123", default, Ok((false, 0..4)));
+    test("+123", lenient, Ok((false, 1..4)));
+    test("  -123\t", lenient, Ok((true, 3..6)));
+    test("   ", lenient, Ok((false, 3..3)));
+}
+
+#[test]
+fn test_clean_digits() {
+    use ParseNumberErrorKind::*;
+    let test_ok = |s, offset, options, base, out: &str| {
+        let (actual_base, digits) = clean_digits(s, offset, options).unwrap();
+        assert_eq!(actual_base, base);
+        assert_eq!(digits, out);
+    };
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    test_ok("123", 0, default, 10, "123");
+    test_ok("0x1f", 0, lenient, 16, "1f");
+    test_ok("0B101", 0, lenient, 2, "101");
+    test_ok("0o17", 0, lenient, 8, "17");
+    test_ok("1,000_000", 0, lenient, 10, "1000000");
+    let mut hex = lenient;
+    hex.set_base(16);
+    test_ok("0x1f", 0, hex, 16, "1f");
+    test_ok("0b1f", 0, hex, 16, "0b1f");
+
+    let test_err = |s, offset, options, kind, index| {
+        assert_eq!(
+            clean_digits(s, offset, options),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err("", 0, default, Empty, 0);
+    test_err("", 3, default, Empty, 3);
+    test_err("0x", 1, lenient, Empty, 3);
+    test_err("12a", 0, default, InvalidDigit, 2);
+    test_err("12a", 1, default, InvalidDigit, 3);
+    test_err("1_0", 0, default, InvalidDigit, 1);
+    test_err("0x1g", 0, lenient, InvalidDigit, 3);
+    test_err("_1", 0, lenient, MisplacedSeparator, 0);
+    test_err("1__0", 0, lenient, MisplacedSeparator, 2);
+    test_err("10_", 0, lenient, MisplacedSeparator, 2);
+    test_err("0x_1", 0, lenient, MisplacedSeparator, 2);
+}
+
+#[test]
+fn test_from_string_with_options() {
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    assert_eq!(u8::from_string_with_options("255", default), Ok(255));
+    assert_eq!(i8::from_string_with_options("-128", default), Ok(-128));
+    assert_eq!(u32::from_string_with_options(" +1_000 ", lenient), Ok(1000));
+    assert_eq!(i32::from_string_with_options("-0x7f", lenient), Ok(-127));
+    assert_eq!(
+        u8::from_string_with_options("256", default),
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+    );
+    assert_eq!(
+        i8::from_string_with_options(" -129", lenient),
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 2))
+    );
+    assert_eq!(
+        u8::from_string_with_options("-1", default),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+    );
+    assert_eq!(
+        u8::from_string_with_options("-0", default),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+    );
+    assert_eq!(
+        i16::from_string_with_options("-", default),
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1))
+    );
+    assert_eq!(
+        i16::from_string_with_options("+5", default),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+    );
+}
+
+fn from_string_with_options_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(
+            T::from_string_with_options(&s, ParseOptions::default()),
+            Ok(x)
+        );
+        assert_eq!(T::from_string_with_options(&s, lenient_options()), Ok(x));
+        assert_eq!(
+            T::from_string_with_options(&format!(" +{:#x} ", x), lenient_options()),
+            Ok(x)
+        );
+    });
+}
+
+fn from_string_with_options_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(
+            T::from_string_with_options(&s, ParseOptions::default()),
+            Ok(x)
+        );
+        assert_eq!(T::from_string_with_options(&s, lenient_options()), Ok(x));
+    });
+}
+
+#[test]
+fn from_string_with_options_properties() {
+    apply_fn_to_unsigneds!(from_string_with_options_properties_helper_unsigned);
+    apply_fn_to_signeds!(from_string_with_options_properties_helper_signed);
+}
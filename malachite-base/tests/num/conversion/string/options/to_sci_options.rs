@@ -131,6 +131,22 @@ fn to_sci_options_properties() {
         assert_eq!(options, old_options);
     });
 
+    to_sci_options_gen().test_properties(|mut options| {
+        let old_options = options;
+        let old_size_options = options.get_size_options();
+        assert!(!options.get_shortest());
+        options.set_size_shortest();
+        assert!(options.get_shortest());
+        assert_eq!(options.get_size_options(), SciSizeOptions::Complete);
+        match old_size_options {
+            SciSizeOptions::Complete => options.set_size_complete(),
+            SciSizeOptions::Precision(p) => options.set_precision(p),
+            SciSizeOptions::Scale(s) => options.set_scale(s),
+        }
+        assert!(!options.get_shortest());
+        assert_eq!(options, old_options);
+    });
+
     to_sci_options_unsigned_pair_gen_var_3().test_properties(|(mut options, precision)| {
         let old_options = options;
         let old_size_options = options.get_size_options();
@@ -1,14 +1,16 @@
 use malachite_base::num::arithmetic::traits::{FloorLogBase, UnsignedAbs};
+use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::string::options::{
     FromSciStringOptions, SciSizeOptions, ToSciOptions,
 };
+use malachite_base::num::float::NiceFloat;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{
-    signed_gen, signed_to_sci_options_pair_gen_var_1, unsigned_gen,
+    primitive_float_gen, signed_gen, signed_to_sci_options_pair_gen_var_1, unsigned_gen,
     unsigned_to_sci_options_pair_gen_var_1,
 };
 use malachite_base::test_util::num::conversion::string::from_sci_string::DECIMAL_SCI_STRING_CHARS;
@@ -1050,3 +1052,174 @@ fn to_sci_with_options_properties() {
     apply_fn_to_unsigneds!(to_sci_with_options_helper_unsigned);
     apply_fn_to_signeds!(to_sci_with_options_helper_signed);
 }
+
+#[test]
+pub fn test_to_sci_primitive_float() {
+    fn test<T: PrimitiveFloat>(x: T, out: &str) {
+        assert_eq!(x.to_sci().to_string(), out);
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            out
+        );
+    }
+    test::<f32>(0.0, "0");
+    test::<f32>(-0.0, "-0");
+    test::<f32>(f32::NAN, "NaN");
+    test::<f32>(f32::INFINITY, "Infinity");
+    test::<f32>(f32::NEG_INFINITY, "-Infinity");
+    test::<f32>(1.0, "1");
+    test::<f32>(0.1, "0.1000000014901161");
+    test::<f32>(-123.5, "-123.5");
+    test::<f32>(f32::MAX, "3.402823466385289e38");
+    test::<f32>(f32::MIN_POSITIVE_SUBNORMAL, "1.401298464324817e-45");
+
+    test::<f64>(0.0, "0");
+    test::<f64>(1.0, "1");
+    test::<f64>(0.1, "0.1");
+    test::<f64>(1.0 / 3.0, "0.3333333333333333");
+    test::<f64>(-2.0 / 3.0, "-0.6666666666666666");
+    test::<f64>(1.0e16, "1e16");
+    test::<f64>(1.0e-7, "1e-7");
+    test::<f64>(f64::MAX, "1.797693134862316e308");
+    test::<f64>(f64::MIN_POSITIVE_SUBNORMAL, "4.940656458412465e-324");
+}
+
+#[test]
+pub fn test_to_sci_with_options_primitive_float() {
+    fn test<T: PrimitiveFloat>(x: T, options: ToSciOptions, out: &str) {
+        assert!(x.fmt_sci_valid(options));
+        assert_eq!(x.to_sci_with_options(options).to_string(), out);
+    }
+    let mut options = ToSciOptions::default();
+    options.set_size_shortest();
+    test::<f32>(0.0, options, "0");
+    test::<f32>(-0.0, options, "-0");
+    test::<f32>(0.1, options, "0.1");
+    test::<f32>(1.0 / 3.0, options, "0.33333334");
+    test::<f32>(16777216.0, options, "16777216");
+    test::<f32>(f32::MAX, options, "3.4028235e38");
+    test::<f32>(f32::MIN_POSITIVE_SUBNORMAL, options, "1e-45");
+    test::<f64>(0.1, options, "0.1");
+    test::<f64>(0.3, options, "0.3");
+    test::<f64>(0.1 + 0.2, options, "0.30000000000000004");
+    test::<f64>(100.0, options, "100");
+    test::<f64>(1.0e15, options, "1000000000000000");
+    test::<f64>(1.0e16, options, "1e16");
+    test::<f64>(1.0e-7, options, "1e-7");
+    test::<f64>(123456.789, options, "123456.789");
+    test::<f64>(f64::MAX, options, "1.7976931348623157e308");
+    test::<f64>(f64::MIN_POSITIVE_NORMAL, options, "2.2250738585072014e-308");
+    test::<f64>(f64::MIN_POSITIVE_SUBNORMAL, options, "5e-324");
+    options.set_base(2);
+    test::<f64>(0.375, options, "0.011");
+    test::<f64>(-5.0, options, "-101");
+    options.set_base(16);
+    test::<f64>(255.5, options, "ff.8");
+    options.set_uppercase();
+    test::<f64>(255.5, options, "FF.8");
+
+    options = ToSciOptions::default();
+    options.set_size_complete();
+    test::<f32>(0.1, options, "0.100000001490116119384765625");
+    test::<f64>(
+        0.1,
+        options,
+        "0.1000000000000000055511151231257827021181583404541015625",
+    );
+    test::<f64>(1.0e20, options, "100000000000000000000");
+    test::<f64>(-0.5, options, "-0.5");
+    options.set_base(3);
+    test::<f64>(9.0, options, "100");
+    options.set_base(2);
+    test::<f64>(
+        0.1,
+        options,
+        "0.0001100110011001100110011001100110011001100110011001101",
+    );
+
+    options = ToSciOptions::default();
+    options.set_precision(20);
+    test::<f64>(0.1, options, "0.10000000000000000555");
+    options.set_include_trailing_zeros(true);
+    test::<f64>(0.5, options, "0.50000000000000000000");
+    options = ToSciOptions::default();
+    options.set_precision(3);
+    test::<f64>(123456.0, options, "1.23e5");
+    test::<f64>(999.9, options, "1e3");
+    options.set_rounding_mode(RoundingMode::Floor);
+    test::<f64>(999.9, options, "999");
+    test::<f64>(-999.9, options, "-1e3");
+    options.set_rounding_mode(RoundingMode::Exact);
+    test::<f64>(0.375, options, "0.375");
+
+    options = ToSciOptions::default();
+    options.set_scale(3);
+    test::<f64>(1.0 / 3.0, options, "0.333");
+    test::<f64>(0.0004, options, "0");
+    test::<f64>(0.0005, options, "0.001");
+    options.set_rounding_mode(RoundingMode::Ceiling);
+    test::<f64>(1.0 / 3.0, options, "0.334");
+    test::<f64>(-1.0 / 3.0, options, "-0.333");
+    test::<f64>(0.0000001, options, "0.001");
+    options.set_rounding_mode(RoundingMode::Nearest);
+    options.set_scale(0);
+    test::<f64>(2.5, options, "2");
+    test::<f64>(3.5, options, "4");
+}
+
+#[test]
+pub fn to_sci_with_options_primitive_float_fail() {
+    let mut options = ToSciOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    options.set_precision(2);
+    assert!(!0.125f64.fmt_sci_valid(options));
+    assert_panic!(0.125f64.to_sci_with_options(options).to_string());
+
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    options.set_base(3);
+    assert!(!0.5f64.fmt_sci_valid(options));
+    assert_panic!(0.5f64.to_sci_with_options(options).to_string());
+}
+
+fn to_sci_with_options_helper_primitive_float<T: PrimitiveFloat>() {
+    primitive_float_gen::<T>().test_properties(|x| {
+        let mut options = ToSciOptions::default();
+        options.set_size_shortest();
+        assert!(x.fmt_sci_valid(options));
+        let s = x.to_sci_with_options(options).to_string();
+        assert_eq!(NiceFloat(T::from_sci_string(&s).unwrap()), NiceFloat(x));
+
+        options.set_size_complete();
+        assert!(x.fmt_sci_valid(options));
+        let s = x.to_sci_with_options(options).to_string();
+        let mut from_options = FromSciStringOptions::default();
+        from_options.set_rounding_mode(RoundingMode::Exact);
+        assert_eq!(
+            NiceFloat(T::from_sci_string_with_options(&s, from_options).unwrap()),
+            NiceFloat(x)
+        );
+
+        if x.is_finite() {
+            for precision in [1, 5, 20] {
+                options.set_precision(precision);
+                for rm in [RoundingMode::Floor, RoundingMode::Ceiling] {
+                    options.set_rounding_mode(rm);
+                    from_options.set_rounding_mode(rm);
+                    let s = x.to_sci_with_options(options).to_string();
+                    let y = T::from_sci_string_with_options(&s, from_options).unwrap();
+                    if rm == RoundingMode::Floor {
+                        assert!(y <= x);
+                    } else {
+                        assert!(y >= x);
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn to_sci_with_options_primitive_float_properties() {
+    apply_fn_to_primitive_floats!(to_sci_with_options_helper_primitive_float);
+}
@@ -1,5 +1,5 @@
 use malachite_base::num::factorization::prime_sieve::{
-    limbs_prime_sieve_size, limbs_prime_sieve_u32, limbs_prime_sieve_u64,
+    limbs_prime_sieve_size, limbs_prime_sieve_u128, limbs_prime_sieve_u32, limbs_prime_sieve_u64,
 };
 use malachite_base::test_util::generators::unsigned_gen_var_26;
 use malachite_base::test_util::num::factorization::prime_sieve::{
@@ -234,6 +234,32 @@ fn test_limbs_prime_sieve_u64() {
     test_large(1808453, 135656);
 }
 
+#[test]
+fn test_limbs_prime_sieve_u128() {
+    let test = |n, out, out_sieve: &[u128]| {
+        let mut sieve = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_u128(&mut sieve, n), out);
+        assert_eq!(sieve, out_sieve);
+        let mut sieve_alt = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_naive_1(&mut sieve_alt, n), out);
+        assert_eq!(sieve, sieve_alt);
+    };
+    let test_large = |n, out| {
+        let mut sieve = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_u128(&mut sieve, n), out);
+        let mut sieve_alt = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_naive_1(&mut sieve_alt, n), out);
+        assert_eq!(sieve, sieve_alt);
+    };
+    test(5, 1, &[u128::MAX - 1]);
+    test(197, 43, &[340282366920938463430125879248471360640]);
+    test_large(10000, 1227);
+    // an odd number of 64-bit words
+    test_large(400, 76);
+    test_large(800000, 63949);
+    test_large(1808453, 135656);
+}
+
 #[test]
 fn limbs_prime_sieve_properties() {
     unsigned_gen_var_26().test_properties(|n: u64| {
@@ -260,5 +286,12 @@ fn limbs_prime_sieve_properties() {
         let mut sieve_alt = vec![0; limbs_prime_sieve_size::<u64>(n)];
         assert_eq!(limbs_prime_sieve_naive_2::<u64>(&mut sieve_alt, n), out);
         assert_eq!(sieve, sieve_alt);
+
+        let mut sieve = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_u128(&mut sieve, n), out);
+
+        let mut sieve_alt = vec![0; limbs_prime_sieve_size::<u128>(n)];
+        assert_eq!(limbs_prime_sieve_naive_1::<u128>(&mut sieve_alt, n), out);
+        assert_eq!(sieve, sieve_alt);
     });
 }
@@ -1,4 +1,5 @@
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::test_util::generators::{unsigned_gen_var_13, unsigned_pair_gen_var_22};
 use std::panic::catch_unwind;
@@ -89,4 +90,6 @@ fn from_ordered_representation_properties_helper<T: PrimitiveFloat>() {
 #[test]
 fn from_ordered_representation_properties() {
     apply_fn_to_primitive_floats!(from_ordered_representation_properties_helper);
+    from_ordered_representation_properties_helper::<F16>();
+    from_ordered_representation_properties_helper::<BF16>();
 }
@@ -1,4 +1,5 @@
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::test_util::generators::{
     primitive_float_gen_var_11, primitive_float_pair_gen_var_1,
@@ -92,4 +93,6 @@ fn to_ordered_representation_properties_helper<T: PrimitiveFloat>() {
 #[test]
 fn to_ordered_representation_properties() {
     apply_fn_to_primitive_floats!(to_ordered_representation_properties_helper);
+    to_ordered_representation_properties_helper::<F16>();
+    to_ordered_representation_properties_helper::<BF16>();
 }
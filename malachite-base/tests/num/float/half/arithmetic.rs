@@ -0,0 +1,52 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
+use malachite_base::num::float::NiceFloat;
+
+#[test]
+fn test_arithmetic() {
+    let f = F16::from;
+    assert_eq!(f(1.5) + f(2.25), f(3.75));
+    assert_eq!(f(1.0) - f(3.0), f(-2.0));
+    assert_eq!(f(3.0) * f(-0.5), f(-1.5));
+    assert_eq!(f(1.0) / f(3.0), F16::from_bits(0x3555));
+    assert_eq!(f(7.0) % f(4.0), f(3.0));
+    assert_eq!(f(2.0).sqrt(), F16::from_bits(0x3da8));
+    assert_eq!(-f(1.0), f(-1.0));
+    assert_eq!(F16::MAX_FINITE + F16::ONE, F16::MAX_FINITE);
+    assert_eq!(F16::MAX_FINITE * F16::TWO, F16::POSITIVE_INFINITY);
+    assert!((F16::POSITIVE_INFINITY - F16::POSITIVE_INFINITY).is_nan());
+    assert_eq!(
+        NiceFloat(F16::MIN_POSITIVE_SUBNORMAL * F16::ONE_HALF),
+        NiceFloat(F16::ZERO)
+    );
+
+    let mut x = f(1.0);
+    x += f(1.0);
+    x *= f(3.0);
+    x -= f(0.5);
+    x /= f(2.0);
+    assert_eq!(x, f(2.75));
+
+    let b = BF16::from;
+    assert_eq!(b(256.0) + b(1.0), b(256.0));
+    assert_eq!(b(256.0) + b(2.0), b(258.0));
+    assert_eq!(b(1.0) / b(3.0), BF16::from_bits(0x3eab));
+    assert_eq!(b(-4.0).abs(), b(4.0));
+    assert_eq!(
+        [b(1.0), b(2.0), b(3.0)].iter().copied().sum::<BF16>(),
+        b(6.0)
+    );
+    assert_eq!(
+        [b(1.0), b(2.0), b(3.0)].iter().copied().product::<BF16>(),
+        b(6.0)
+    );
+}
+
+#[test]
+fn test_comparison() {
+    assert!(F16::NEGATIVE_INFINITY < F16::ZERO);
+    assert_eq!(F16::ZERO, F16::NEGATIVE_ZERO);
+    assert_ne!(F16::NAN, F16::NAN);
+    assert!(BF16::MAX_FINITE > BF16::from(3.0e38));
+    assert_eq!(F16::NAN.partial_cmp(&F16::ZERO), None);
+}
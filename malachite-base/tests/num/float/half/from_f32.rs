@@ -0,0 +1,73 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::exhaustive::primitive_floats_increasing;
+use malachite_base::num::float::half::{BF16, F16};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::primitive_float_gen;
+
+#[test]
+fn test_from_f32() {
+    let test = |x: f32, out: u16| {
+        assert_eq!(F16::from(x).to_bits(), out);
+    };
+    test(0.0, 0);
+    test(-0.0, 0x8000);
+    test(1.0, 0x3c00);
+    test(-2.5, 0xc100);
+    test(0.1, 0x2e66);
+    test(2049.0, 0x6800);
+    test(2051.0, 0x6802);
+    test(65519.0, 0x7bff);
+    test(65520.0, 0x7c00);
+    test(1.0e5, 0x7c00);
+    test(5.9604645e-8, 0x0001);
+    test(2.9802322e-8, 0);
+    test(2.9802326e-8, 0x0001);
+    test(1.0e-8, 0);
+    test(3.0e-8, 0x0001);
+    test(f32::INFINITY, 0x7c00);
+    test(f32::NEG_INFINITY, 0xfc00);
+    assert!(F16::from(f32::NAN).is_nan());
+
+    let test = |x: f32, out: u16| {
+        assert_eq!(BF16::from(x).to_bits(), out);
+    };
+    test(0.0, 0);
+    test(-1.0, 0xbf80);
+    test(0.1, 0x3dcd);
+    test(257.0, 0x4380);
+    test(259.0, 0x4382);
+    test(3.0e38, 0x7f62);
+    test(f32::MAX, 0x7f80);
+    test(1.0e-40, 0x0001);
+    test(1.0e-45, 0);
+    assert!(BF16::from(f32::NAN).is_nan());
+}
+
+fn from_f32_properties_helper<T: PrimitiveFloat + From<f32>>()
+where
+    f32: From<T>,
+{
+    for x in primitive_floats_increasing::<T>() {
+        assert_eq!(NiceFloat(T::from(f32::from(x))), NiceFloat(x));
+    }
+
+    primitive_float_gen::<f32>().test_properties(|x| {
+        let y = T::from(x);
+        if x.is_nan() {
+            assert!(y.is_nan());
+        } else if y.is_finite() {
+            // No representable value is closer to `x` than `y`.
+            let error = (f32::from(y) - x).abs();
+            let lower = f32::from(y.next_lower());
+            let higher = f32::from(y.next_higher());
+            assert!((lower - x).abs() >= error);
+            assert!((higher - x).abs() >= error);
+        }
+    });
+}
+
+#[test]
+fn from_f32_properties() {
+    from_f32_properties_helper::<F16>();
+    from_f32_properties_helper::<BF16>();
+}
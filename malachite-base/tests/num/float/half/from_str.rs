@@ -0,0 +1,50 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
+use malachite_base::num::float::NiceFloat;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test_ok = |s: &str, out: F16| {
+        assert_eq!(NiceFloat(F16::from_str(s).unwrap()), NiceFloat(out));
+    };
+    test_ok("0", F16::ZERO);
+    test_ok("-0", F16::NEGATIVE_ZERO);
+    test_ok("1", F16::ONE);
+    test_ok("+1.5", F16::from(1.5));
+    test_ok(".5", F16::ONE_HALF);
+    test_ok("1.", F16::ONE);
+    test_ok("1e2", F16::from(100.0));
+    test_ok("0.1", F16::from_bits(0x2e66));
+    test_ok("65519", F16::MAX_FINITE);
+    test_ok("65520", F16::POSITIVE_INFINITY);
+    test_ok("1e-8", F16::ZERO);
+    test_ok("3e-8", F16::MIN_POSITIVE_SUBNORMAL);
+    test_ok("inf", F16::POSITIVE_INFINITY);
+    test_ok("-infinity", F16::NEGATIVE_INFINITY);
+    test_ok("NaN", F16::NAN);
+
+    let test_ok = |s: &str, out: BF16| {
+        assert_eq!(NiceFloat(BF16::from_str(s).unwrap()), NiceFloat(out));
+    };
+    test_ok("0.1", BF16::from_bits(0x3dcd));
+    test_ok("257", BF16::from(256.0));
+    test_ok("259", BF16::from(260.0));
+    test_ok("1e-40", BF16::MIN_POSITIVE_SUBNORMAL);
+    test_ok("1e39", BF16::POSITIVE_INFINITY);
+
+    let test_err = |s: &str| {
+        assert!(F16::from_str(s).is_err());
+        assert!(BF16::from_str(s).is_err());
+    };
+    test_err("");
+    test_err(".");
+    test_err("e");
+    test_err("1e");
+    test_err("--1");
+    test_err("+-1");
+    test_err("abc");
+    test_err("0x10");
+    test_err(" 1");
+    test_err("1 ");
+}
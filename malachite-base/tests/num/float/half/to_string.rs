@@ -0,0 +1,79 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::exhaustive::primitive_floats_increasing;
+use malachite_base::num::float::half::{BF16, F16};
+use malachite_base::num::float::NiceFloat;
+use std::fmt::{Debug, Display, LowerExp};
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    let test = |bits: u16, display: &str, debug: &str, nice: &str| {
+        let x = F16::from_bits(bits);
+        assert_eq!(x.to_string(), display);
+        assert_eq!(format!("{x:?}"), debug);
+        assert_eq!(NiceFloat(x).to_string(), nice);
+    };
+    test(0x0000, "0", "0.0", "0.0");
+    test(0x8000, "-0", "-0.0", "-0.0");
+    test(0x3c00, "1", "1.0", "1.0");
+    test(0x5640, "100", "100.0", "100.0");
+    test(0x2e66, "0.1", "0.1", "0.1");
+    test(0x3555, "0.3333", "0.3333", "0.3333");
+    test(0x1400, "0.000977", "0.000977", "0.000977");
+    test(0x0001, "0.00000006", "6e-8", "6.0e-8");
+    test(0x03ff, "0.000061", "6.1e-5", "0.000061");
+    test(0x0400, "0.00006104", "6.104e-5", "0.00006104");
+    test(0x7bff, "65500", "65500.0", "65500.0");
+    test(0x7c00, "inf", "inf", "Infinity");
+    test(0xfc00, "-inf", "-inf", "-Infinity");
+    test(0x7e00, "NaN", "NaN", "NaN");
+
+    let test = |bits: u16, debug: &str, nice: &str| {
+        let x = BF16::from_bits(bits);
+        assert_eq!(format!("{x:?}"), debug);
+        assert_eq!(NiceFloat(x).to_string(), nice);
+    };
+    test(0x3f80, "1.0", "1.0");
+    test(0x3dcd, "0.1", "0.1");
+    test(0x3eab, "0.334", "0.334");
+    test(0x3c00, "0.0078", "0.0078");
+    test(0x0001, "1e-40", "1.0e-40");
+    test(0x007f, "1.17e-38", "1.17e-38");
+    test(0x0080, "1.18e-38", "1.18e-38");
+    test(0x7f7f, "3.39e38", "3.39e38");
+    assert_eq!(
+        BF16::MAX_FINITE.to_string(),
+        "339000000000000000000000000000000000000"
+    );
+}
+
+#[test]
+fn test_to_string_with_flags() {
+    let x = F16::from(0.1);
+    assert_eq!(format!("{x:.3}"), "0.100");
+    assert_eq!(format!("{x:>6}"), "   0.1");
+    assert_eq!(format!("{x:+}"), "+0.1");
+    assert_eq!(format!("{x:e}"), "1e-1");
+    assert_eq!(format!("{:E}", F16::from(1500.0)), "1.5E3");
+    assert_eq!(format!("{:e}", F16::ZERO), "0e0");
+    assert_eq!(format!("{:08.2}", F16::from(-2.5)), "-0002.50");
+}
+
+fn to_string_round_trip_helper<T: PrimitiveFloat + Display + LowerExp>()
+where
+    <T as FromStr>::Err: Debug,
+{
+    for x in primitive_floats_increasing::<T>() {
+        let x = NiceFloat(x);
+        assert_eq!(NiceFloat(T::from_str(&x.0.to_string()).unwrap()), x);
+        assert_eq!(NiceFloat(T::from_str(&format!("{:?}", x.0)).unwrap()), x);
+        assert_eq!(NiceFloat(T::from_str(&format!("{:e}", x.0)).unwrap()), x);
+        assert_eq!(NiceFloat::<T>::from_str(&x.to_string()).unwrap(), x);
+    }
+}
+
+#[test]
+fn to_string_properties() {
+    to_string_round_trip_helper::<F16>();
+    to_string_round_trip_helper::<BF16>();
+}
@@ -1,4 +1,5 @@
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_10};
@@ -98,4 +99,6 @@ fn from_str_helper<T: PrimitiveFloat>() {
 #[test]
 fn from_str_properties() {
     apply_fn_to_primitive_floats!(from_str_helper);
+    from_str_helper::<F16>();
+    from_str_helper::<BF16>();
 }
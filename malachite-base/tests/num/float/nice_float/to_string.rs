@@ -1,4 +1,5 @@
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::primitive_float_gen;
@@ -70,4 +71,6 @@ where
 #[test]
 fn to_string_properties() {
     apply_fn_to_primitive_floats!(to_string_properties_helper);
+    to_string_properties_helper::<F16>();
+    to_string_properties_helper::<BF16>();
 }
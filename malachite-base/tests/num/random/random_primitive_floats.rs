@@ -1,9 +1,11 @@
+use itertools::Itertools;
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::num::random::random_primitive_floats;
 use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::test_util::num::random::random_primitive_floats_helper_helper;
-use malachite_base::test_util::stats::moments::{CheckedToF64, MomentStats};
+use malachite_base::test_util::stats::moments::{CheckedToF64, MomentStats, NAN_MOMENT_STATS};
 
 fn random_primitive_floats_helper<T: CheckedToF64 + PrimitiveFloat>(
     expected_values: &[T],
@@ -20,6 +22,25 @@ fn random_primitive_floats_helper<T: CheckedToF64 + PrimitiveFloat>(
     )
 }
 
+// The expected values of the 16-bit floats are given as `f32`s, which represent them exactly.
+fn random_half_floats_helper<T: CheckedToF64 + From<f32> + PrimitiveFloat>(
+    expected_values: &[f32],
+    expected_common_values: &[(f32, usize)],
+    expected_median: (f32, Option<f32>),
+    expected_moment_stats: MomentStats,
+) {
+    let (lo, hi) = expected_median;
+    random_primitive_floats_helper::<T>(
+        &expected_values.iter().map(|&x| T::from(x)).collect_vec(),
+        &expected_common_values
+            .iter()
+            .map(|&(x, freq)| (T::from(x), freq))
+            .collect_vec(),
+        (T::from(lo), hi.map(T::from)),
+        expected_moment_stats,
+    )
+}
+
 #[test]
 fn test_random_primitive_floats() {
     // f32
@@ -119,4 +140,80 @@ fn test_random_primitive_floats() {
         sample_median,
         sample_moment_stats,
     );
+
+    // F16
+    let values = &[
+        15232.0,
+        -0.00047183037,
+        110.1875,
+        0.022079468,
+        -245.125,
+        -0.035705566,
+        -168.5,
+        12992.0,
+        17.4375,
+        -0.0001348257,
+        0.0027999878,
+        23.375,
+        0.00157547,
+        -0.24621582,
+        0.08728027,
+        -202.25,
+        -95.5625,
+        0.0010528564,
+        0.000050008297,
+        0.00006771088,
+    ];
+    let common_values = &[
+        (-1562.0, 35),
+        (-1332.0, 34),
+        (-0.0005841255, 34),
+        (10.625, 33),
+        (-0.19726563, 33),
+        (-3344.0, 33),
+        (-6152.0, 33),
+        (0.009536743, 33),
+        (48.1875, 32),
+        (139.375, 32),
+    ];
+    let sample_median = (8.940697e-7, None);
+    random_half_floats_helper::<F16>(values, common_values, sample_median, NAN_MOMENT_STATS);
+
+    // BF16
+    let values = &[
+        1.485528e29,
+        -2.3602718e-27,
+        9.749576e11,
+        2.263272e-18,
+        -1.2177733e19,
+        -2.0747848e-12,
+        -4.4135276e17,
+        3.342438e28,
+        450560.0,
+        -9.051871e-32,
+        1.5186519e-25,
+        3538944.0,
+        1.5398565e-27,
+        -0.000013411045,
+        1.3322676e-13,
+        -1.8915118e18,
+        -4.3804543e15,
+        8.2041534e-29,
+        -5.142788e-39,
+        2.2040519e-38,
+    ];
+    let common_values = &[
+        (-1.0147907e-26, 34),
+        (6656.0, 33),
+        (-6.800208e24, 33),
+        (1.0509209e37, 33),
+        (-2.3271822e25, 33),
+        (8.7437567e21, 32),
+        (-1.2874576e30, 32),
+        (-1.648441e-7, 32),
+        (2.991085e-21, 32),
+        (-1.3460522e-10, 32),
+    ];
+    let sample_median = (-2.75506e-40, None);
+    random_half_floats_helper::<BF16>(values, common_values, sample_median, NAN_MOMENT_STATS);
 }
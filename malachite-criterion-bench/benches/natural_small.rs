@@ -0,0 +1,107 @@
+extern crate criterion;
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate num;
+extern crate rug;
+
+use criterion::*;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::random::get_random_natural_with_bits;
+use malachite_nz::natural::Natural;
+use num::BigUint;
+use std::str::FromStr;
+
+// These sizes straddle the largest `Natural`s that are stored without a heap allocation, so that
+// the inline and heap-allocated paths can be compared.
+const SIZES: [u64; 6] = [64, 128, 192, 256, 320, 512];
+
+pub fn natural_to_biguint(n: &Natural) -> BigUint {
+    BigUint::from_str(n.to_string().as_ref()).unwrap()
+}
+
+pub fn natural_to_rug_integer(n: &Natural) -> rug::Integer {
+    rug::Integer::from_str(n.to_string().as_ref()).unwrap()
+}
+
+fn random_pair(x_bits: u64, y_bits: u64) -> (Natural, Natural) {
+    (
+        get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), x_bits),
+        get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), y_bits),
+    )
+}
+
+fn bench_small_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural + Natural");
+    for &i in SIZES.iter() {
+        let (x, y) = random_pair(i, i);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("malachite ref", i), |b| b.iter(|| &x + &y));
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(|| (x_num.clone(), y_num.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(|| (x_rug.clone(), y_rug.clone()), |(x, y)| x + y)
+        });
+    }
+    group.finish();
+}
+
+fn bench_small_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural * Natural");
+    for &i in SIZES.iter() {
+        // The product has about `i` bits.
+        let (x, y) = random_pair(i >> 1, i >> 1);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x * y)
+        });
+        group.bench_function(BenchmarkId::new("malachite ref", i), |b| b.iter(|| &x * &y));
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(|| (x_num.clone(), y_num.clone()), |(x, y)| x * y)
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(|| (x_rug.clone(), y_rug.clone()), |(x, y)| x * y)
+        });
+    }
+    group.finish();
+}
+
+fn bench_small_div(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small Natural / Natural");
+    for &i in SIZES.iter() {
+        // The dividend has `i` bits and the quotient has about `i / 2` bits.
+        let (x, y) = random_pair(i, i >> 1);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x / y)
+        });
+        group.bench_function(BenchmarkId::new("malachite ref", i), |b| b.iter(|| &x / &y));
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(|| (x_num.clone(), y_num.clone()), |(x, y)| x / y)
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(|| (x_rug.clone(), y_rug.clone()), |(x, y)| x / y)
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().significance_level(0.1).sample_size(100);
+    targets = bench_small_add, bench_small_mul, bench_small_div
+}
+criterion_main!(benches);
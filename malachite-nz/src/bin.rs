@@ -21,6 +21,8 @@ use crate::bin_util::generate::digits_data::generate_string_data;
 #[cfg(feature = "bin_build")]
 use crate::bin_util::generate::factorial_data::generate_factorial_data;
 #[cfg(feature = "bin_build")]
+use crate::bin_util::generate::tune::generate_tuned_thresholds;
+#[cfg(feature = "bin_build")]
 use malachite_base::test_util::runner::cmd::read_command_line_arguments;
 #[cfg(feature = "bin_build")]
 use malachite_base::test_util::runner::Runner;
@@ -34,6 +36,8 @@ use malachite_base::test_util::runner::Runner;
 //     benchmark_limbs_to_digits_small_base_algorithms
 //
 // cargo run --features bin_build -- -g digits_data
+//
+// cargo run --release --features bin_build -- -g tune > thresholds.rs
 #[cfg(feature = "bin_build")]
 fn main() {
     let args = read_command_line_arguments("malachite-nz test utils");
@@ -54,6 +58,7 @@ fn main() {
         match codegen_key.as_str() {
             "digits_data" => generate_string_data(),
             "factorial_data" => generate_factorial_data(),
+            "tune" => generate_tuned_thresholds(),
             _ => panic!("Invalid codegen key: {}", codegen_key),
         }
     }
@@ -73,7 +73,7 @@ fn demo_limbs_to_digits_small_base_basecase<T: PrimitiveUnsigned>(
     }
 }
 
-fn demo_limbs_to_digits_small_base<T: PrimitiveUnsigned>(
+fn demo_limbs_to_digits_small_base<T: PrimitiveUnsigned + Send>(
     gm: GenMode,
     config: GenConfig,
     limit: usize,
@@ -113,7 +113,7 @@ fn demo_limbs_to_digits_basecase<T: ConvertibleFrom<Limb> + PrimitiveUnsigned>(
 }
 
 fn demo_to_digits_asc_limb<
-    T: ConvertibleFrom<Limb> + for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned,
+    T: ConvertibleFrom<Limb> + for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     gm: GenMode,
     config: GenConfig,
@@ -136,7 +136,7 @@ fn demo_to_digits_asc_limb<
 }
 
 fn demo_to_digits_desc_limb<
-    T: ConvertibleFrom<Limb> + for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned,
+    T: ConvertibleFrom<Limb> + for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     gm: GenMode,
     config: GenConfig,
@@ -309,7 +309,7 @@ fn benchmark_limbs_to_digits_small_base_basecase_algorithms_2<T: PrimitiveUnsign
     );
 }
 
-fn benchmark_limbs_to_digits_small_base_algorithms<T: PrimitiveUnsigned>(
+fn benchmark_limbs_to_digits_small_base_algorithms<T: PrimitiveUnsigned + Send>(
     gm: GenMode,
     config: GenConfig,
     limit: usize,
@@ -343,7 +343,7 @@ fn benchmark_limbs_to_digits_small_base_algorithms<T: PrimitiveUnsigned>(
 }
 
 fn benchmark_limbs_to_digits_basecase_algorithms<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >(
     gm: GenMode,
     config: GenConfig,
@@ -374,7 +374,7 @@ fn benchmark_limbs_to_digits_basecase_algorithms<
 }
 
 fn benchmark_to_digits_asc_limb<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >(
     gm: GenMode,
     config: GenConfig,
@@ -399,7 +399,7 @@ fn benchmark_to_digits_asc_limb<
 }
 
 fn benchmark_to_digits_desc_limb<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >(
     gm: GenMode,
     config: GenConfig,
@@ -0,0 +1,655 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::logic::traits::BitAccess;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::arithmetic::div_mod::{
+    limbs_div_mod_barrett, limbs_div_mod_barrett_scratch_len, limbs_div_mod_divide_and_conquer,
+    limbs_div_mod_schoolbook, limbs_two_limb_inverse_helper,
+};
+use malachite_nz::natural::arithmetic::mul::fft::{
+    limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
+    limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::mul_mod::{
+    limbs_mul_mod_base_pow_n_minus_1, limbs_mul_mod_base_pow_n_minus_1_next_size,
+    limbs_mul_mod_base_pow_n_minus_1_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::ntt::{
+    limbs_mul_greater_to_out_ntt, limbs_square_to_out_ntt,
+};
+use malachite_nz::natural::arithmetic::mul::toom::{
+    limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_scratch_len,
+    limbs_mul_greater_to_out_toom_33, limbs_mul_greater_to_out_toom_33_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_33_scratch_len, limbs_mul_greater_to_out_toom_44,
+    limbs_mul_greater_to_out_toom_44_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_44_scratch_len, limbs_mul_greater_to_out_toom_6h,
+    limbs_mul_greater_to_out_toom_6h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_6h_scratch_len, limbs_mul_greater_to_out_toom_8h,
+    limbs_mul_greater_to_out_toom_8h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_8h_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::{
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_basecase,
+    limbs_mul_greater_to_out_scratch_len,
+};
+use malachite_nz::natural::arithmetic::square::{
+    limbs_square_to_out_basecase, limbs_square_to_out_toom_2,
+    limbs_square_to_out_toom_2_scratch_len, limbs_square_to_out_toom_3,
+    limbs_square_to_out_toom_3_scratch_len, limbs_square_to_out_toom_4,
+    limbs_square_to_out_toom_4_scratch_len, limbs_square_to_out_toom_6,
+    limbs_square_to_out_toom_6_scratch_len, limbs_square_to_out_toom_8,
+    limbs_square_to_out_toom_8_input_size_valid, limbs_square_to_out_toom_8_scratch_len,
+};
+use malachite_nz::platform::{
+    Limb, DC_DIV_QR_THRESHOLD, MUL_FFT_THRESHOLD, MUL_NTT_THRESHOLD, MUL_TOOM22_THRESHOLD,
+    MUL_TOOM33_THRESHOLD, MUL_TOOM44_THRESHOLD, MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD,
+    MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD, MU_DIV_QR_THRESHOLD, SQR_FFT_THRESHOLD,
+    SQR_NTT_THRESHOLD, SQR_TOOM2_THRESHOLD, SQR_TOOM3_THRESHOLD, SQR_TOOM4_THRESHOLD,
+    SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
+};
+use std::cmp::max;
+use std::time::Instant;
+
+// Times each pair of algorithms that a threshold chooses between, on the current machine, and
+// prints a module containing the measured thresholds. The module can be compiled into the crate
+// in place of the defaults by building with the `tuned_thresholds` feature and setting the
+// `MALACHITE_NZ_THRESHOLDS` environment variable to the module's absolute path; see the crate
+// documentation. Progress is written to stderr, so that stdout can be redirected to a file.
+//
+// If no crossover is found in a threshold's search range, the threshold is left out of the module,
+// so it keeps its default value. Thresholds that choose between successively faster algorithms must
+// not decrease along the chain (for example, Toom-33 must not take over from Toom-22 before Toom-22
+// takes over from schoolbook multiplication), so any threshold that would violate this is raised
+// to the one before it.
+//
+// `INV_MULMOD_BNM1_THRESHOLD` isn't tuned: besides choosing how Barrett division computes its
+// products, it also decides when Newton inversion multiplies mod $B^n - 1$, which this binary
+// doesn't time. The Barrett products are governed by `MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD`,
+// which is tuned on its own.
+//
+// Only the top level of each algorithm is timed; any recursive calls still go through the
+// thresholds that this binary was compiled with. As with GMP's tuneup program, running the tuner a
+// second time with the first run's thresholds compiled in can refine the results.
+
+// Each timing is repeated until it takes at least this long, so that the clock's resolution doesn't
+// matter.
+const MIN_TIMING_NANOS: u128 = 1_000_000;
+
+// Each timing is the minimum over this many runs, which filters out most interruptions.
+const TIMING_RUNS: usize = 5;
+
+// The larger algorithm has to win at this many consecutive sizes before a crossover is accepted.
+// This keeps a single noisy measurement from producing a threshold that is much too small.
+const CONFIRMATIONS: usize = 3;
+
+fn time_nanos<F: FnMut()>(mut f: F) -> f64 {
+    let mut reps = 1u32;
+    loop {
+        let start = Instant::now();
+        for _ in 0..reps {
+            f();
+        }
+        if start.elapsed().as_nanos() >= MIN_TIMING_NANOS {
+            break;
+        }
+        reps <<= 1;
+    }
+    let fastest = (0..TIMING_RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..reps {
+                f();
+            }
+            start.elapsed().as_nanos()
+        })
+        .min()
+        .unwrap();
+    fastest as f64 / f64::from(reps)
+}
+
+fn random_limbs(len: usize) -> Vec<Limb> {
+    random_primitive_ints(EXAMPLE_SEED).take(len).collect()
+}
+
+fn random_limbs_pair(xs_len: usize, ys_len: usize) -> (Vec<Limb>, Vec<Limb>) {
+    let mut limbs = random_primitive_ints(EXAMPLE_SEED);
+    let xs = (&mut limbs).take(xs_len).collect();
+    let ys = limbs.take(ys_len).collect();
+    (xs, ys)
+}
+
+type MulFn = fn(&mut [Limb], &[Limb], &[Limb], &mut [Limb]);
+
+type MulScratchLenFn = fn(usize, usize) -> usize;
+
+type SquareFn = fn(&mut [Limb], &[Limb], &mut [Limb]);
+
+type SquareScratchLenFn = fn(usize) -> usize;
+
+fn time_mul(n: usize, f: MulFn, scratch_len: MulScratchLenFn) -> f64 {
+    let (xs, ys) = random_limbs_pair(n, n);
+    let mut out = vec![0; n << 1];
+    let mut scratch = vec![0; scratch_len(n, n)];
+    time_nanos(|| f(&mut out, &xs, &ys, &mut scratch))
+}
+
+fn time_square(n: usize, f: SquareFn, scratch_len: SquareScratchLenFn) -> f64 {
+    let xs = random_limbs(n);
+    let mut out = vec![0; n << 1];
+    let mut scratch = vec![0; scratch_len(n)];
+    time_nanos(|| f(&mut out, &xs, &mut scratch))
+}
+
+// Returns the smallest length in `[lo, hi]` at which `large` is faster than `small`, both there and
+// at the next `CONFIRMATIONS` lengths that are checked. If there is no such length, returns `None`.
+// Lengths for which `valid` returns false are skipped. The lengths grow by about 5% each step, so
+// the result is accurate to within about 5%.
+fn find_crossover<V: Fn(usize) -> bool, S: FnMut(usize) -> f64, L: FnMut(usize) -> f64>(
+    name: &str,
+    lo: usize,
+    hi: usize,
+    valid: V,
+    mut small: S,
+    mut large: L,
+) -> Option<usize> {
+    let mut crossover = None;
+    let mut wins = 0;
+    let mut n = lo;
+    while n <= hi {
+        if valid(n) {
+            let small_time = small(n);
+            let large_time = large(n);
+            eprintln!(
+                "{}: n = {}: {:.0} ns vs. {:.0} ns",
+                name, n, small_time, large_time
+            );
+            if large_time < small_time {
+                if wins == 0 {
+                    crossover = Some(n);
+                }
+                wins += 1;
+                if wins > CONFIRMATIONS {
+                    break;
+                }
+            } else {
+                wins = 0;
+                crossover = None;
+            }
+        }
+        n = max(n + 1, n * 21 / 20);
+    }
+    // A crossover that wasn't confirmed before reaching `hi` may just be noise.
+    if wins <= CONFIRMATIONS {
+        crossover = None;
+    }
+    if let Some(threshold) = crossover {
+        eprintln!("{} = {}", name, threshold);
+    } else {
+        eprintln!(
+            "{}: no crossover in [{}, {}]; keeping the default",
+            name, lo, hi
+        );
+    }
+    crossover
+}
+
+// A threshold's compiled-in value, and the value measured by the tuner, if any.
+struct Threshold {
+    name: &'static str,
+    default: usize,
+    measured: Option<usize>,
+}
+
+impl Threshold {
+    fn value(&self) -> usize {
+        self.measured.unwrap_or(self.default)
+    }
+}
+
+// Makes sure that the thresholds in `chain`, which choose between successively faster algorithms,
+// don't decrease. A threshold that is smaller than the one before it, whether measured or not, is
+// raised to match and written out.
+fn enforce_monotonicity(chain: &mut [Threshold]) {
+    let mut floor = 0;
+    for threshold in chain {
+        let value = threshold.value();
+        if value < floor {
+            eprintln!(
+                "{} = {} is less than the preceding threshold; raising it to {}",
+                threshold.name, value, floor
+            );
+            threshold.measured = Some(floor);
+        }
+        floor = threshold.value();
+    }
+}
+
+fn always_valid(_n: usize) -> bool {
+    true
+}
+
+fn limbs_mul_greater_to_out_basecase_with_scratch(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+    _scratch: &mut [Limb],
+) {
+    limbs_mul_greater_to_out_basecase(out, xs, ys);
+}
+
+fn limbs_mul_greater_to_out_ntt_with_scratch(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+    _scratch: &mut [Limb],
+) {
+    limbs_mul_greater_to_out_ntt(out, xs, ys);
+}
+
+fn limbs_square_to_out_basecase_with_scratch(out: &mut [Limb], xs: &[Limb], _scratch: &mut [Limb]) {
+    limbs_square_to_out_basecase(out, xs);
+}
+
+fn limbs_square_to_out_ntt_with_scratch(out: &mut [Limb], xs: &[Limb], _scratch: &mut [Limb]) {
+    limbs_square_to_out_ntt(out, xs);
+}
+
+const fn no_mul_scratch(_xs_len: usize, _ys_len: usize) -> usize {
+    0
+}
+
+const fn no_square_scratch(_xs_len: usize) -> usize {
+    0
+}
+
+// A pair of multiplication algorithms that a threshold chooses between, and the range of
+// same-length operands over which to look for the crossover.
+struct MulPair {
+    name: &'static str,
+    default: usize,
+    lo: usize,
+    hi: usize,
+    valid: fn(usize) -> bool,
+    small: (MulFn, MulScratchLenFn),
+    large: (MulFn, MulScratchLenFn),
+}
+
+struct SquarePair {
+    name: &'static str,
+    default: usize,
+    lo: usize,
+    hi: usize,
+    valid: fn(usize) -> bool,
+    small: (SquareFn, SquareScratchLenFn),
+    large: (SquareFn, SquareScratchLenFn),
+}
+
+fn mul_pairs() -> Vec<MulPair> {
+    vec![
+        MulPair {
+            name: "MUL_TOOM22_THRESHOLD",
+            default: MUL_TOOM22_THRESHOLD,
+            lo: 2,
+            hi: 200,
+            valid: always_valid,
+            small: (
+                limbs_mul_greater_to_out_basecase_with_scratch,
+                no_mul_scratch,
+            ),
+            large: (
+                limbs_mul_greater_to_out_toom_22,
+                limbs_mul_greater_to_out_toom_22_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_TOOM33_THRESHOLD",
+            default: MUL_TOOM33_THRESHOLD,
+            lo: 4,
+            hi: 500,
+            valid: |n| limbs_mul_greater_to_out_toom_33_input_sizes_valid(n, n),
+            small: (
+                limbs_mul_greater_to_out_toom_22,
+                limbs_mul_greater_to_out_toom_22_scratch_len,
+            ),
+            large: (
+                limbs_mul_greater_to_out_toom_33,
+                limbs_mul_greater_to_out_toom_33_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_TOOM44_THRESHOLD",
+            default: MUL_TOOM44_THRESHOLD,
+            lo: 8,
+            hi: 1000,
+            valid: |n| limbs_mul_greater_to_out_toom_44_input_sizes_valid(n, n),
+            small: (
+                limbs_mul_greater_to_out_toom_33,
+                limbs_mul_greater_to_out_toom_33_scratch_len,
+            ),
+            large: (
+                limbs_mul_greater_to_out_toom_44,
+                limbs_mul_greater_to_out_toom_44_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_TOOM6H_THRESHOLD",
+            default: MUL_TOOM6H_THRESHOLD,
+            lo: 42,
+            hi: 2000,
+            valid: |n| limbs_mul_greater_to_out_toom_6h_input_sizes_valid(n, n),
+            small: (
+                limbs_mul_greater_to_out_toom_44,
+                limbs_mul_greater_to_out_toom_44_scratch_len,
+            ),
+            large: (
+                limbs_mul_greater_to_out_toom_6h,
+                limbs_mul_greater_to_out_toom_6h_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_TOOM8H_THRESHOLD",
+            default: MUL_TOOM8H_THRESHOLD,
+            lo: 86,
+            hi: 4000,
+            valid: |n| limbs_mul_greater_to_out_toom_8h_input_sizes_valid(n, n),
+            small: (
+                limbs_mul_greater_to_out_toom_6h,
+                limbs_mul_greater_to_out_toom_6h_scratch_len,
+            ),
+            large: (
+                limbs_mul_greater_to_out_toom_8h,
+                limbs_mul_greater_to_out_toom_8h_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_FFT_THRESHOLD",
+            default: MUL_FFT_THRESHOLD,
+            lo: 100,
+            hi: 20000,
+            valid: |n| limbs_mul_greater_to_out_toom_8h_input_sizes_valid(n, n),
+            small: (
+                limbs_mul_greater_to_out_toom_8h,
+                limbs_mul_greater_to_out_toom_8h_scratch_len,
+            ),
+            large: (
+                limbs_mul_greater_to_out_fft,
+                limbs_mul_greater_to_out_fft_scratch_len,
+            ),
+        },
+        MulPair {
+            name: "MUL_NTT_THRESHOLD",
+            default: MUL_NTT_THRESHOLD,
+            lo: 1000,
+            hi: 200000,
+            valid: always_valid,
+            small: (
+                limbs_mul_greater_to_out_fft,
+                limbs_mul_greater_to_out_fft_scratch_len,
+            ),
+            large: (limbs_mul_greater_to_out_ntt_with_scratch, no_mul_scratch),
+        },
+    ]
+}
+
+fn square_pairs() -> Vec<SquarePair> {
+    vec![
+        // `limbs_square_to_out_basecase` only accepts inputs up to the compiled-in
+        // `SQR_TOOM2_THRESHOLD`, so this threshold can be lowered by tuning but not raised.
+        SquarePair {
+            name: "SQR_TOOM2_THRESHOLD",
+            default: SQR_TOOM2_THRESHOLD,
+            lo: 2,
+            hi: SQR_TOOM2_THRESHOLD,
+            valid: always_valid,
+            small: (limbs_square_to_out_basecase_with_scratch, no_square_scratch),
+            large: (
+                limbs_square_to_out_toom_2,
+                limbs_square_to_out_toom_2_scratch_len,
+            ),
+        },
+        SquarePair {
+            name: "SQR_TOOM3_THRESHOLD",
+            default: SQR_TOOM3_THRESHOLD,
+            lo: 4,
+            hi: 1000,
+            valid: always_valid,
+            small: (
+                limbs_square_to_out_toom_2,
+                limbs_square_to_out_toom_2_scratch_len,
+            ),
+            large: (
+                limbs_square_to_out_toom_3,
+                limbs_square_to_out_toom_3_scratch_len,
+            ),
+        },
+        SquarePair {
+            name: "SQR_TOOM4_THRESHOLD",
+            default: SQR_TOOM4_THRESHOLD,
+            lo: 8,
+            hi: 2000,
+            valid: always_valid,
+            small: (
+                limbs_square_to_out_toom_3,
+                limbs_square_to_out_toom_3_scratch_len,
+            ),
+            large: (
+                limbs_square_to_out_toom_4,
+                limbs_square_to_out_toom_4_scratch_len,
+            ),
+        },
+        SquarePair {
+            name: "SQR_TOOM6_THRESHOLD",
+            default: SQR_TOOM6_THRESHOLD,
+            lo: 18,
+            hi: 2000,
+            valid: always_valid,
+            small: (
+                limbs_square_to_out_toom_4,
+                limbs_square_to_out_toom_4_scratch_len,
+            ),
+            large: (
+                limbs_square_to_out_toom_6,
+                limbs_square_to_out_toom_6_scratch_len,
+            ),
+        },
+        SquarePair {
+            name: "SQR_TOOM8_THRESHOLD",
+            default: SQR_TOOM8_THRESHOLD,
+            lo: 40,
+            hi: 4000,
+            valid: always_valid,
+            small: (
+                limbs_square_to_out_toom_6,
+                limbs_square_to_out_toom_6_scratch_len,
+            ),
+            large: (
+                limbs_square_to_out_toom_8,
+                limbs_square_to_out_toom_8_scratch_len,
+            ),
+        },
+        SquarePair {
+            name: "SQR_FFT_THRESHOLD",
+            default: SQR_FFT_THRESHOLD,
+            lo: 100,
+            hi: 50000,
+            valid: limbs_square_to_out_toom_8_input_size_valid,
+            small: (
+                limbs_square_to_out_toom_8,
+                limbs_square_to_out_toom_8_scratch_len,
+            ),
+            large: (limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len),
+        },
+        SquarePair {
+            name: "SQR_NTT_THRESHOLD",
+            default: SQR_NTT_THRESHOLD,
+            lo: 1000,
+            hi: 200000,
+            valid: always_valid,
+            small: (limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len),
+            large: (limbs_square_to_out_ntt_with_scratch, no_square_scratch),
+        },
+    ]
+}
+
+// Returns a random normalized divisor of length `d_len` and a random dividend of length
+// `2 * d_len`, so that the quotient also has length `d_len`.
+fn random_division_inputs(d_len: usize) -> (Vec<Limb>, Vec<Limb>) {
+    let (ns, mut ds) = random_limbs_pair(d_len << 1, d_len);
+    ds.last_mut().unwrap().set_bit(Limb::WIDTH - 1);
+    (ns, ds)
+}
+
+fn time_div_mod_schoolbook(d_len: usize) -> f64 {
+    let (ns, ds) = random_division_inputs(d_len);
+    let d_inv = limbs_two_limb_inverse_helper(ds[d_len - 1], ds[d_len - 2]);
+    let mut ns_copy = ns.clone();
+    let mut qs = vec![0; d_len];
+    time_nanos(|| {
+        ns_copy.copy_from_slice(&ns);
+        limbs_div_mod_schoolbook(&mut qs, &mut ns_copy, &ds, d_inv);
+    })
+}
+
+fn time_div_mod_divide_and_conquer(d_len: usize) -> f64 {
+    let (ns, ds) = random_division_inputs(d_len);
+    let d_inv = limbs_two_limb_inverse_helper(ds[d_len - 1], ds[d_len - 2]);
+    let mut ns_copy = ns.clone();
+    let mut qs = vec![0; d_len];
+    time_nanos(|| {
+        ns_copy.copy_from_slice(&ns);
+        limbs_div_mod_divide_and_conquer(&mut qs, &mut ns_copy, &ds, d_inv);
+    })
+}
+
+fn time_div_mod_barrett(d_len: usize) -> f64 {
+    let (ns, ds) = random_division_inputs(d_len);
+    let mut ns_copy = ns.clone();
+    let mut qs = vec![0; d_len];
+    let mut rs = vec![0; d_len];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), d_len)];
+    time_nanos(|| {
+        // The other division algorithms need to restore the dividend, so this does the same work
+        // to keep the comparison fair.
+        ns_copy.copy_from_slice(&ns);
+        limbs_div_mod_barrett(&mut qs, &mut rs, &ns_copy, &ds, &mut scratch);
+    })
+}
+
+// Times the product of a `2 * n`-limb divisor and an `n`-limb block of quotient limbs, computed in
+// full, as Barrett division does when `n` is below `MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD`.
+fn time_2n_by_n_mul(n: usize) -> f64 {
+    let (xs, ys) = random_limbs_pair(n << 1, n);
+    let mut out = vec![0; 3 * n];
+    let mut scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs.len(), ys.len())];
+    time_nanos(|| {
+        limbs_mul_greater_to_out(&mut out, &xs, &ys, &mut scratch);
+    })
+}
+
+// Times the same product modulo $B^m - 1$, as Barrett division does otherwise.
+fn time_2n_by_n_mul_mod(n: usize) -> f64 {
+    let (xs, ys) = random_limbs_pair(n << 1, n);
+    let m = limbs_mul_mod_base_pow_n_minus_1_next_size((n << 1) + 1);
+    let mut out = vec![0; m];
+    let mut scratch = vec![0; limbs_mul_mod_base_pow_n_minus_1_scratch_len(m, xs.len(), ys.len())];
+    time_nanos(|| limbs_mul_mod_base_pow_n_minus_1(&mut out, m, &xs, &ys, &mut scratch))
+}
+
+pub(crate) fn generate_tuned_thresholds() {
+    let mut mul_chain = Vec::new();
+    for pair in mul_pairs() {
+        let (small, small_scratch_len) = pair.small;
+        let (large, large_scratch_len) = pair.large;
+        let measured = find_crossover(
+            pair.name,
+            pair.lo,
+            pair.hi,
+            pair.valid,
+            |n| time_mul(n, small, small_scratch_len),
+            |n| time_mul(n, large, large_scratch_len),
+        );
+        mul_chain.push(Threshold {
+            name: pair.name,
+            default: pair.default,
+            measured,
+        });
+    }
+    let mut square_chain = Vec::new();
+    for pair in square_pairs() {
+        let (small, small_scratch_len) = pair.small;
+        let (large, large_scratch_len) = pair.large;
+        let measured = find_crossover(
+            pair.name,
+            pair.lo,
+            pair.hi,
+            pair.valid,
+            |n| time_square(n, small, small_scratch_len),
+            |n| time_square(n, large, large_scratch_len),
+        );
+        square_chain.push(Threshold {
+            name: pair.name,
+            default: pair.default,
+            measured,
+        });
+    }
+    let mut div_chain = vec![Threshold {
+        name: "DC_DIV_QR_THRESHOLD",
+        default: DC_DIV_QR_THRESHOLD,
+        measured: find_crossover(
+            "DC_DIV_QR_THRESHOLD",
+            6,
+            1000,
+            always_valid,
+            time_div_mod_schoolbook,
+            time_div_mod_divide_and_conquer,
+        ),
+    }];
+    let mu_lo = div_chain[0].value();
+    div_chain.push(Threshold {
+        name: "MU_DIV_QR_THRESHOLD",
+        default: MU_DIV_QR_THRESHOLD,
+        measured: find_crossover(
+            "MU_DIV_QR_THRESHOLD",
+            mu_lo,
+            20000,
+            always_valid,
+            time_div_mod_divide_and_conquer,
+            time_div_mod_barrett,
+        ),
+    });
+    let mul_to_mul_mod = Threshold {
+        name: "MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD",
+        default: MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD,
+        measured: find_crossover(
+            "MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD",
+            2,
+            5000,
+            always_valid,
+            time_2n_by_n_mul,
+            time_2n_by_n_mul_mod,
+        ),
+    };
+    enforce_monotonicity(&mut mul_chain);
+    enforce_monotonicity(&mut square_chain);
+    enforce_monotonicity(&mut div_chain);
+
+    println!("// This file was generated by `malachite_nz_main -g tune`. It replaces the default");
+    println!("// thresholds when malachite-nz is built with the `tuned_thresholds` feature.");
+    println!();
+    println!(
+        "const _: () = assert!(Limb::BITS == {}, \"these thresholds are for {}-bit limbs\");",
+        Limb::WIDTH,
+        Limb::WIDTH
+    );
+    println!();
+    for threshold in mul_chain
+        .iter()
+        .chain(square_chain.iter())
+        .chain(div_chain.iter())
+        .chain(std::iter::once(&mul_to_mul_mod))
+    {
+        if let Some(measured) = threshold.measured {
+            println!("pub const {}: usize = {};", threshold.name, measured);
+        }
+    }
+}
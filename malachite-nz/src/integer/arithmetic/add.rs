@@ -200,6 +200,68 @@ impl<'a, 'b> Add<&'a Integer> for &'b Integer {
     }
 }
 
+// Writes `x + y` to `out`, where `x` and `y` are given by their signs and absolute values. A zero
+// may have either sign.
+pub(crate) fn add_signed_to(
+    x_sign: bool,
+    x_abs: &Natural,
+    y_sign: bool,
+    y_abs: &Natural,
+    out: &mut Integer,
+) {
+    if x_sign == y_sign {
+        x_abs.add_to(y_abs, &mut out.abs);
+        out.sign = x_sign;
+    } else if x_abs >= y_abs {
+        x_abs.sub_to(y_abs, &mut out.abs);
+        out.sign = x_sign;
+    } else {
+        y_abs.sub_to(x_abs, &mut out.abs);
+        out.sign = y_sign;
+    }
+    if out.abs == 0 {
+        out.sign = true;
+    }
+}
+
+impl Integer {
+    /// Adds two [`Integer`]s, taking both by reference and writing the sum to an existing
+    /// [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $$
+    /// o \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(-123).add_to(&Integer::from(456), &mut out);
+    /// assert_eq!(out, 333);
+    ///
+    /// (-Integer::from(10u32).pow(12)).add_to(&(Integer::from(10u32).pow(12) << 1), &mut out);
+    /// assert_eq!(out, 1000000000000u64);
+    /// ```
+    #[inline]
+    pub fn add_to(&self, other: &Integer, out: &mut Integer) {
+        add_signed_to(self.sign, &self.abs, other.sign, &other.abs, out);
+    }
+}
+
 impl AddAssign<Integer> for Integer {
     /// Adds an [`Integer`] to an [`Integer`] in place, taking the [`Integer`] on the right-hand
     /// side by value.
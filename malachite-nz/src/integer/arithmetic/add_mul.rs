@@ -44,7 +44,8 @@ impl Natural {
                 false
             }
             (Natural(Large(ref mut xs)), Natural(Large(ref mut ys)), z) => {
-                let (right, sign) = limbs_overflowing_sub_mul_limb_in_place_either(xs, ys, z);
+                let (right, sign) =
+                    limbs_overflowing_sub_mul_limb_in_place_either(xs.vec_mut(), ys.vec_mut(), z);
                 if right {
                     b.trim();
                     *self = b;
@@ -79,7 +80,7 @@ impl Natural {
                 false
             }
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), z) => {
-                let sign = limbs_overflowing_sub_mul_limb_in_place_left(xs, ys, z);
+                let sign = limbs_overflowing_sub_mul_limb_in_place_left(xs.vec_mut(), ys, z);
                 self.trim();
                 sign
             }
@@ -1,8 +1,10 @@
 use crate::integer::Integer;
+use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
     CeilingDivAssignMod, CeilingDivAssignNegMod, CeilingDivMod, CeilingDivNegMod, DivAssignMod,
     DivAssignRem, DivMod, DivRem,
 };
+use malachite_base::num::basic::traits::One;
 
 impl DivMod<Integer> for Integer {
     type DivOutput = Integer;
@@ -224,6 +226,65 @@ impl<'a, 'b> DivMod<&'b Integer> for &'a Integer {
     }
 }
 
+impl Integer {
+    /// Divides an [`Integer`] by another [`Integer`], taking both by reference and writing the
+    /// quotient and remainder to existing [`Integer`]s. The quotient is rounded towards negative
+    /// infinity, and the remainder has the same sign as the second [`Integer`].
+    ///
+    /// If `q` or `r` already have buffers, they are reused, so apart from scratch space for large
+    /// inputs, this doesn't allocate unless the buffers need to grow.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// (q, r) \gets \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut q = Integer::ZERO;
+    /// let mut r = Integer::ZERO;
+    /// // 2 * 10 + 3 = 23
+    /// Integer::from(23).div_mod_to(&Integer::from(10), &mut q, &mut r);
+    /// assert_eq!(q, 2);
+    /// assert_eq!(r, 3);
+    ///
+    /// // -3 * -10 + -7 = 23
+    /// Integer::from(23).div_mod_to(&Integer::from(-10), &mut q, &mut r);
+    /// assert_eq!(q, -3);
+    /// assert_eq!(r, -7);
+    ///
+    /// // -3 * 10 + 7 = -23
+    /// Integer::from(-23).div_mod_to(&Integer::from(10), &mut q, &mut r);
+    /// assert_eq!(q, -3);
+    /// assert_eq!(r, 7);
+    /// ```
+    pub fn div_mod_to(&self, other: &Integer, q: &mut Integer, r: &mut Integer) {
+        let q_sign = self.sign == other.sign;
+        self.abs.div_mod_to(&other.abs, &mut q.abs, &mut r.abs);
+        if !q_sign && r.abs != 0 {
+            q.abs += Natural::ONE;
+            assert!(!r.abs.sub_right_assign_no_panic(&other.abs));
+        }
+        q.sign = q_sign || q.abs == 0;
+        r.sign = other.sign || r.abs == 0;
+    }
+}
+
 impl DivAssignMod<Integer> for Integer {
     type ModOutput = Integer;
 
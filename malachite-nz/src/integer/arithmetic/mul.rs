@@ -154,6 +154,43 @@ impl<'a, 'b> Mul<&'a Integer> for &'b Integer {
     }
 }
 
+impl Integer {
+    /// Multiplies two [`Integer`]s, taking both by reference and writing the product to an
+    /// existing [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow. The scratch space needed by the multiplication is taken from the same buffer.
+    ///
+    /// $$
+    /// o \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(123).mul_to(&Integer::from(-456), &mut out);
+    /// assert_eq!(out, -56088);
+    ///
+    /// Integer::from(-123456789000i64).mul_to(&Integer::from(-987654321000i64), &mut out);
+    /// assert_eq!(out.to_string(), "121932631112635269000000");
+    /// ```
+    pub fn mul_to(&self, other: &Integer, out: &mut Integer) {
+        self.abs.mul_to(&other.abs, &mut out.abs);
+        out.sign = self.sign == other.sign || out.abs == 0;
+    }
+}
+
 impl MulAssign<Integer> for Integer {
     /// Multiplies an [`Integer`] by an [`Integer`] in place, taking the [`Integer`] on the
     /// right-hand side by value.
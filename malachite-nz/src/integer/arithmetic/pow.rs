@@ -77,6 +77,41 @@ impl<'a> Pow<u64> for &'a Integer {
     }
 }
 
+impl Integer {
+    /// Raises an [`Integer`] to a power, taking the [`Integer`] by reference and writing the
+    /// result to an existing [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so apart from scratch space, this doesn't
+    /// allocate unless the buffer needs to grow.
+    ///
+    /// $o \gets x^n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(-3).pow_to(3, &mut out);
+    /// assert_eq!(out, -27);
+    ///
+    /// Integer::from(-3).pow_to(100, &mut out);
+    /// assert_eq!(out.to_string(), "515377520732011331036461129765621272702107522001");
+    /// ```
+    pub fn pow_to(&self, exp: u64, out: &mut Integer) {
+        self.abs.pow_to(exp, &mut out.abs);
+        out.sign = self.sign || exp.even();
+    }
+}
+
 impl PowAssign<u64> for Integer {
     /// Raises an [`Integer`] to a power in place.
     ///
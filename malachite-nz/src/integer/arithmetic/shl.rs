@@ -24,6 +24,38 @@ where
     }
 }
 
+impl Integer {
+    /// Left-shifts an [`Integer`] (multiplies it by a power of 2), taking it by reference and
+    /// writing the result to an existing [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $o \gets x2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(-123).shl_to(2, &mut out);
+    /// assert_eq!(out, -492);
+    /// ```
+    pub fn shl_to(&self, bits: u64, out: &mut Integer) {
+        self.abs.shl_to(bits, &mut out.abs);
+        out.sign = self.sign;
+    }
+}
+
 macro_rules! impl_shl_unsigned {
     ($t:ident) => {
         impl Shl<$t> for Integer {
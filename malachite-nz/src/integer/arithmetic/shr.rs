@@ -1,7 +1,9 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
-use malachite_base::num::arithmetic::traits::{ShrRound, ShrRoundAssign, UnsignedAbs};
-use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::arithmetic::traits::{
+    DivisibleByPowerOf2, ShrRound, ShrRoundAssign, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::rounding_modes::RoundingMode;
 use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
 
@@ -57,6 +59,49 @@ where
     }
 }
 
+impl Integer {
+    /// Right-shifts an [`Integer`] (divides it by a power of 2 and takes the floor), taking it by
+    /// reference and writing the result to an existing [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $o \gets \lfloor x/2^k \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(1, self.significant_bits() - bits)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(492).shr_to(2, &mut out);
+    /// assert_eq!(out, 123);
+    ///
+    /// Integer::from(-493).shr_to(2, &mut out);
+    /// assert_eq!(out, -124);
+    /// ```
+    pub fn shr_to(&self, bits: u64, out: &mut Integer) {
+        self.abs.shr_to(bits, &mut out.abs);
+        if self.sign {
+            out.sign = true;
+        } else {
+            // Round towards negative infinity. The result can't be zero, since `self` is nonzero.
+            if !self.abs.divisible_by_power_of_2(bits) {
+                out.abs += Natural::ONE;
+            }
+            out.sign = false;
+        }
+    }
+}
+
 macro_rules! impl_shr_unsigned {
     ($t:ident) => {
         impl Shr<$t> for Integer {
@@ -69,6 +69,39 @@ impl<'a> Square for &'a Integer {
     }
 }
 
+impl Integer {
+    /// Squares an [`Integer`], taking it by reference and writing the result to an existing
+    /// [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow. The scratch space needed by the squaring is taken from the same buffer.
+    ///
+    /// $$
+    /// o \gets x^2.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(-123).square_to(&mut out);
+    /// assert_eq!(out, 15129);
+    /// ```
+    pub fn square_to(&self, out: &mut Integer) {
+        self.abs.square_to(&mut out.abs);
+        out.sign = true;
+    }
+}
+
 impl SquareAssign for Integer {
     /// Squares an [`Integer`] in place.
     ///
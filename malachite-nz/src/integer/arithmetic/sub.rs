@@ -1,3 +1,4 @@
+use crate::integer::arithmetic::add::add_signed_to;
 use crate::integer::Integer;
 use crate::natural::InnerNatural::Small;
 use crate::natural::Natural;
@@ -199,6 +200,44 @@ impl<'a, 'b> Sub<&'a Integer> for &'b Integer {
     }
 }
 
+impl Integer {
+    /// Subtracts an [`Integer`] by another [`Integer`], taking both by reference and writing the
+    /// difference to an existing [`Integer`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $$
+    /// o \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Integer::ZERO;
+    /// Integer::from(123).sub_to(&Integer::from(456), &mut out);
+    /// assert_eq!(out, -333);
+    ///
+    /// (-Integer::from(10u32).pow(12)).sub_to(&(Integer::from(10u32).pow(12) << 1), &mut out);
+    /// assert_eq!(out, -3000000000000i64);
+    /// ```
+    #[inline]
+    pub fn sub_to(&self, other: &Integer, out: &mut Integer) {
+        add_signed_to(self.sign, &self.abs, !other.sign, &other.abs, out);
+    }
+}
+
 impl SubAssign<Integer> for Integer {
     /// Subtracts an [`Integer`] by another [`Integer`] in place, taking the [`Integer`] on the
     /// right-hand side by value.
@@ -1,6 +1,7 @@
 use crate::integer::Integer;
 use malachite_base::num::arithmetic::traits::UnsignedAbs;
 use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::float::half::{BF16, F16};
 use std::cmp::Ordering;
 
 macro_rules! impl_float {
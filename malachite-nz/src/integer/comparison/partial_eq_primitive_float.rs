@@ -1,5 +1,6 @@
 use crate::integer::Integer;
 use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::float::half::{BF16, F16};
 
 macro_rules! impl_float {
     ($t: ident) => {
@@ -0,0 +1,233 @@
+use crate::integer::Integer;
+use crate::natural::conversion::bytes::BytesOverflowError;
+use crate::platform::Limb;
+use malachite_base::num::basic::traits::Zero;
+use std::mem::size_of;
+
+impl Integer {
+    /// Returns the bytes of an [`Integer`] in two's complement, in ascending order, so that less
+    /// significant bytes have lower indices in the output vector.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. There are no trailing
+    /// bytes that only repeat the sign, so the output is as short as possible. If the [`Integer`]
+    /// is zero, the output is empty. This function borrows the [`Integer`].
+    ///
+    /// The result doesn't depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_le().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_le(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_le(), &[133]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_le(), &[128, 0]);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_le(), &[128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_le(), &[127, 255]);
+    /// ```
+    pub fn to_twos_complement_bytes_le(&self) -> Vec<u8> {
+        let limbs = self.to_twos_complement_limbs_asc();
+        let mut bytes = Vec::with_capacity(limbs.len() * size_of::<Limb>());
+        for limb in limbs {
+            bytes.extend_from_slice(&limb.to_le_bytes());
+        }
+        let negative = !self.sign;
+        let fill = if negative { u8::MAX } else { 0 };
+        // Remove the highest byte while it only repeats the sign bit of the byte below it.
+        while let [.., second_last, last] = bytes[..] {
+            if last != fill || (second_last >> 7 != 0) != negative {
+                break;
+            }
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in descending order, so that less
+    /// significant bytes have higher indices in the output vector.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. There are no leading
+    /// bytes that only repeat the sign, so the output is as short as possible. If the [`Integer`]
+    /// is zero, the output is empty. This function borrows the [`Integer`].
+    ///
+    /// The result doesn't depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_be().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_be(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_be(), &[133]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_be(), &[0, 128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_be(), &[255, 127]);
+    /// ```
+    pub fn to_twos_complement_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in ascending order, sign-extended
+    /// to a specified length. Returns an error if the [`Integer`] doesn't fit in that many bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), len)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-2).to_twos_complement_bytes_le_padded(4),
+    ///     Ok(vec![254, 255, 255, 255])
+    /// );
+    /// assert_eq!(Integer::from(127).to_twos_complement_bytes_le_padded(1), Ok(vec![127]));
+    /// assert_eq!(
+    ///     Integer::from(128).to_twos_complement_bytes_le_padded(1),
+    ///     Err(BytesOverflowError)
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_le_padded(
+        &self,
+        len: usize,
+    ) -> Result<Vec<u8>, BytesOverflowError> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        if bytes.len() > len {
+            Err(BytesOverflowError)
+        } else {
+            bytes.resize(len, if self.sign { 0 } else { u8::MAX });
+            Ok(bytes)
+        }
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in descending order, sign-extended
+    /// to a specified length. Returns an error if the [`Integer`] doesn't fit in that many bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), len)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-2).to_twos_complement_bytes_be_padded(4),
+    ///     Ok(vec![255, 255, 255, 254])
+    /// );
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_be_padded(1), Ok(vec![128]));
+    /// assert_eq!(
+    ///     Integer::from(-129).to_twos_complement_bytes_be_padded(1),
+    ///     Err(BytesOverflowError)
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_be_padded(
+        &self,
+        len: usize,
+    ) -> Result<Vec<u8>, BytesOverflowError> {
+        let mut bytes = self.to_twos_complement_bytes_le_padded(len)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// Converts a slice of bytes in two's complement, in ascending order, to an [`Integer`].
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. If the slice is empty,
+    /// zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128, 0]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[127, 255, 255]), -129);
+    /// ```
+    pub fn from_twos_complement_bytes_le(bytes: &[u8]) -> Integer {
+        const LIMB_BYTES: usize = size_of::<Limb>();
+        let fill = match bytes.last() {
+            None => return Integer::ZERO,
+            Some(&last) if last >> 7 != 0 => u8::MAX,
+            _ => 0,
+        };
+        Integer::from_owned_twos_complement_limbs_asc(
+            bytes
+                .chunks(LIMB_BYTES)
+                .map(|chunk| {
+                    let mut limb_bytes = [fill; LIMB_BYTES];
+                    limb_bytes[..chunk.len()].copy_from_slice(chunk);
+                    Limb::from_le_bytes(limb_bytes)
+                })
+                .collect(),
+        )
+    }
+
+    /// Converts a slice of bytes in two's complement, in descending order, to an [`Integer`].
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. If the slice is empty,
+    /// zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[0, 128]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[255, 255, 127]), -129);
+    /// ```
+    pub fn from_twos_complement_bytes_be(bytes: &[u8]) -> Integer {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Integer::from_twos_complement_bytes_le(&bytes)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::integer::Integer;
+use crate::natural::conversion::cbor::{
+    decode_cbor_magnitude, encode_cbor_magnitude, CborError, MAJOR_NEGATIVE, MAJOR_UNSIGNED,
+    TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM,
+};
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+
+impl Integer {
+    /// Encodes an [`Integer`] as a CBOR data item, following RFC 8949.
+    ///
+    /// Non-negative [`Integer`]s are encoded like [`Natural`]s: as unsigned integers (major type
+    /// 0) if they are less than $2^{64}$, and as unsigned bignums (tag 2) otherwise. A negative
+    /// [`Integer`] $x$ is encoded via $-1-x$: as a negative integer (major type 1) if
+    /// $-1-x < 2^{64}$, and otherwise as tag 3 (a negative bignum) followed by a byte string
+    /// containing $-1-x$ in big-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(10).to_cbor(), &[0x0a]);
+    /// assert_eq!(Integer::from(-1).to_cbor(), &[0x20]);
+    /// assert_eq!(Integer::from(-500).to_cbor(), &[0x39, 0x01, 0xf3]);
+    /// assert_eq!(
+    ///     (-Integer::power_of_2(64) - Integer::from(1)).to_cbor(),
+    ///     &[0xc3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    /// );
+    /// ```
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if self.sign {
+            encode_cbor_magnitude(&mut out, MAJOR_UNSIGNED, TAG_POSITIVE_BIGNUM, &self.abs);
+        } else {
+            encode_cbor_magnitude(
+                &mut out,
+                MAJOR_NEGATIVE,
+                TAG_NEGATIVE_BIGNUM,
+                &(&self.abs - Natural::ONE),
+            );
+        }
+        out
+    }
+
+    /// Decodes an [`Integer`] from a CBOR data item at the start of a byte slice, returning the
+    /// [`Integer`] and the number of bytes that were read.
+    ///
+    /// Unsigned and negative integers (major types 0 and 1) and unsigned and negative bignums
+    /// (tags 2 and 3) are accepted, whether or not they use the preferred serialization. Bytes
+    /// after the end of the data item are ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::conversion::cbor::CborError;
+    ///
+    /// assert_eq!(Integer::from_cbor(&[0x39, 0x01, 0xf3]), Ok((Integer::from(-500), 3)));
+    /// assert_eq!(Integer::from_cbor(&[0xc3, 0x41, 0x00]), Ok((Integer::from(-1), 3)));
+    /// assert_eq!(Integer::from_cbor(&[0xc4, 0x00]), Err(CborError::WrongTag(4)));
+    /// ```
+    pub fn from_cbor(bytes: &[u8]) -> Result<(Integer, usize), CborError> {
+        let (sign, x, len) = decode_cbor_magnitude(bytes)?;
+        Ok((
+            if sign {
+                Integer::from(x)
+            } else {
+                Integer::from_sign_and_abs(false, x + Natural::ONE)
+            },
+            len,
+        ))
+    }
+}
@@ -0,0 +1,198 @@
+use crate::integer::Integer;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// The identifier octet of an ASN.1 INTEGER: universal class, primitive, tag number 2.
+const INTEGER_TAG: u8 = 0x02;
+
+/// The reasons why a byte slice may fail to decode as an ASN.1 `INTEGER`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DerError {
+    /// The input ended before the encoding was complete.
+    UnexpectedEnd,
+    /// The identifier octet was not `0x02`, the tag of a universal, primitive `INTEGER`. The
+    /// octet that was found is included.
+    WrongTag(u8),
+    /// The length octet was `0x80`, which introduces an indefinite length. Primitive encodings
+    /// must have a definite length.
+    IndefiniteLength,
+    /// The length octet was `0xff`, which is reserved.
+    ReservedLength,
+    /// The length does not fit in a [`usize`].
+    LengthOverflow,
+    /// The length was encoded in the long form when the short form would do, or with leading
+    /// zero octets. Only rejected in DER.
+    NonMinimalLength,
+    /// The contents were empty. An `INTEGER` must have at least one contents octet.
+    EmptyContents,
+    /// The contents began with a `0x00` or `0xff` octet that only repeated the sign of the
+    /// following octet. Only rejected in DER.
+    NonMinimalContents,
+    /// There were bytes left over after the encoding. The number of extra bytes is included.
+    TrailingBytes(usize),
+    /// The encoded value was negative but a [`Natural`](crate::natural::Natural) was expected.
+    Negative,
+}
+
+// Returns the tag, length, and contents of an `INTEGER` whose contents are `contents`.
+pub(crate) fn der_encode_contents(contents: &[u8]) -> Vec<u8> {
+    let len = contents.len();
+    let mut out = Vec::with_capacity(len + 11);
+    out.push(INTEGER_TAG);
+    if len < 0x80 {
+        out.push(u8::exact_from(len));
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let start = len_bytes.iter().position(|&b| b != 0).unwrap();
+        out.push(0x80 | u8::exact_from(len_bytes.len() - start));
+        out.extend_from_slice(&len_bytes[start..]);
+    }
+    out.extend_from_slice(contents);
+    out
+}
+
+// Checks the tag and length of an `INTEGER` and returns its contents. If `strict` is true, the DER
+// rules are enforced; otherwise the BER rules are.
+pub(crate) fn der_decode_contents(bytes: &[u8], strict: bool) -> Result<&[u8], DerError> {
+    let (&tag, bytes) = bytes.split_first().ok_or(DerError::UnexpectedEnd)?;
+    if tag != INTEGER_TAG {
+        return Err(DerError::WrongTag(tag));
+    }
+    let (&first_len_byte, mut bytes) = bytes.split_first().ok_or(DerError::UnexpectedEnd)?;
+    let len = match first_len_byte {
+        0x80 => return Err(DerError::IndefiniteLength),
+        0xff => return Err(DerError::ReservedLength),
+        b if b < 0x80 => usize::from(b),
+        b => {
+            let len_len = usize::from(b & 0x7f);
+            if bytes.len() < len_len {
+                return Err(DerError::UnexpectedEnd);
+            }
+            let (len_bytes, rest) = bytes.split_at(len_len);
+            bytes = rest;
+            if strict && len_bytes[0] == 0 {
+                return Err(DerError::NonMinimalLength);
+            }
+            let mut len: usize = 0;
+            for &b in len_bytes {
+                len = len.checked_mul(0x100).ok_or(DerError::LengthOverflow)? | usize::from(b);
+            }
+            if strict && len < 0x80 {
+                return Err(DerError::NonMinimalLength);
+            }
+            len
+        }
+    };
+    if bytes.len() < len {
+        return Err(DerError::UnexpectedEnd);
+    }
+    let (contents, rest) = bytes.split_at(len);
+    if !rest.is_empty() {
+        return Err(DerError::TrailingBytes(rest.len()));
+    }
+    match *contents {
+        [] => Err(DerError::EmptyContents),
+        [first @ (0x00 | 0xff), second, ..]
+            if strict && (first >> 7 == 0) == (second >> 7 == 0) =>
+        {
+            Err(DerError::NonMinimalContents)
+        }
+        _ => Ok(contents),
+    }
+}
+
+impl Integer {
+    /// Encodes an [`Integer`] as an ASN.1 `INTEGER` using the Distinguished Encoding Rules (DER).
+    ///
+    /// The output consists of the identifier octet `0x02`, the length of the contents in the
+    /// shortest possible form, and the contents: the [`Integer`] in two's complement, big-endian,
+    /// with as few octets as possible. Zero is encoded with a single `0x00` contents octet.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_der(), &[0x02, 0x01, 0x00]);
+    /// assert_eq!(Integer::from(127).to_der(), &[0x02, 0x01, 0x7f]);
+    /// assert_eq!(Integer::from(128).to_der(), &[0x02, 0x02, 0x00, 0x80]);
+    /// assert_eq!(Integer::from(-128).to_der(), &[0x02, 0x01, 0x80]);
+    /// assert_eq!(Integer::from(-129).to_der(), &[0x02, 0x02, 0xff, 0x7f]);
+    /// ```
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut contents = self.to_twos_complement_bytes_be();
+        if contents.is_empty() {
+            contents.push(0);
+        }
+        der_encode_contents(&contents)
+    }
+
+    /// Decodes an [`Integer`] from an ASN.1 `INTEGER` encoded using the Distinguished Encoding
+    /// Rules (DER).
+    ///
+    /// The input must be exactly one encoding. Besides malformed input, DER rejects lengths that
+    /// are not in their shortest form and contents that start with a redundant sign octet; use
+    /// [`from_ber`](Integer::from_ber) to accept those.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::conversion::der::DerError;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_der(&[0x02, 0x02, 0x00, 0x80]), Ok(Integer::from(128)));
+    /// assert_eq!(Integer::from_der(&[0x02, 0x02, 0xff, 0x7f]), Ok(Integer::from(-129)));
+    /// assert_eq!(
+    ///     Integer::from_der(&[0x02, 0x02, 0x00, 0x7f]),
+    ///     Err(DerError::NonMinimalContents)
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_der(&[0x02, 0x81, 0x01, 0x7f]),
+    ///     Err(DerError::NonMinimalLength)
+    /// );
+    /// assert_eq!(Integer::from_der(&[0x04, 0x01, 0x00]), Err(DerError::WrongTag(0x04)));
+    /// assert_eq!(Integer::from_der(&[0x02, 0x02, 0x01]), Err(DerError::UnexpectedEnd));
+    /// ```
+    pub fn from_der(bytes: &[u8]) -> Result<Integer, DerError> {
+        der_decode_contents(bytes, true).map(Integer::from_twos_complement_bytes_be)
+    }
+
+    /// Decodes an [`Integer`] from an ASN.1 `INTEGER` encoded using the Basic Encoding Rules
+    /// (BER).
+    ///
+    /// This is like [`from_der`](Integer::from_der), except that lengths in the long form and
+    /// contents with redundant sign octets are accepted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::conversion::der::DerError;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_ber(&[0x02, 0x02, 0x00, 0x7f]), Ok(Integer::from(127)));
+    /// assert_eq!(Integer::from_ber(&[0x02, 0x81, 0x01, 0xff]), Ok(Integer::from(-1)));
+    /// assert_eq!(Integer::from_ber(&[0x02, 0x00]), Err(DerError::EmptyContents));
+    /// assert_eq!(Integer::from_ber(&[0x02, 0x80]), Err(DerError::IndefiniteLength));
+    /// ```
+    pub fn from_ber(bytes: &[u8]) -> Result<Integer, DerError> {
+        der_decode_contents(bytes, false).map(Integer::from_twos_complement_bytes_be)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::rounding_modes::RoundingMode;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -27,7 +29,7 @@ macro_rules! float_impls {
             /// # Examples
             /// See [here](super::from_primitive_float#rounding_from).
             fn rounding_from(value: $f, rm: RoundingMode) -> Self {
-                if value >= 0.0 {
+                if value >= $f::ZERO {
                     Integer {
                         sign: true,
                         abs: Natural::rounding_from(value, rm),
@@ -57,7 +59,7 @@ macro_rules! float_impls {
             fn try_from(value: $f) -> Result<Integer, Self::Error> {
                 Natural::try_from(value.abs())
                     .map(|n| Integer {
-                        sign: value >= 0.0,
+                        sign: value >= $f::ZERO,
                         abs: n,
                     })
                     .map_err(|_| IntegerFromPrimitiveFloatError)
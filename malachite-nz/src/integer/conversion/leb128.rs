@@ -0,0 +1,316 @@
+use crate::integer::Integer;
+use crate::natural::conversion::leb128::{
+    leb128_from_digits, leb128_len, read_leb128_bytes, Leb128Error,
+};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use std::io::{self, Read, Write};
+
+impl Integer {
+    /// Maps an [`Integer`] to a [`Natural`] using the zigzag encoding: 0, −1, 1, −2, 2, ... are
+    /// mapped to 0, 1, 2, 3, 4, ....
+    ///
+    /// $f(x) = 2x$ if $x \geq 0$, and $f(x) = -2x - 1$ otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(0).to_zigzag(), 0);
+    /// assert_eq!(Integer::from(-1).to_zigzag(), 1);
+    /// assert_eq!(Integer::from(1).to_zigzag(), 2);
+    /// assert_eq!(Integer::from(-64).to_zigzag(), 127);
+    /// ```
+    pub fn to_zigzag(&self) -> Natural {
+        if self.sign {
+            &self.abs << 1u64
+        } else {
+            ((&self.abs - Natural::ONE) << 1u64) | Natural::ONE
+        }
+    }
+
+    /// Maps a [`Natural`] to an [`Integer`] using the zigzag encoding. This is the inverse of
+    /// [`to_zigzag`](Integer::to_zigzag).
+    ///
+    /// $f(x) = x/2$ if $x$ is even, and $f(x) = -(x+1)/2$ otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from_zigzag(&Natural::from(0u32)), 0);
+    /// assert_eq!(Integer::from_zigzag(&Natural::from(1u32)), -1);
+    /// assert_eq!(Integer::from_zigzag(&Natural::from(2u32)), 1);
+    /// assert_eq!(Integer::from_zigzag(&Natural::from(127u32)), -64);
+    /// ```
+    pub fn from_zigzag(x: &Natural) -> Integer {
+        if x.divisible_by_power_of_2(1) {
+            Integer::from(x >> 1u64)
+        } else {
+            Integer::from_sign_and_abs(false, (x >> 1u64) + Natural::ONE)
+        }
+    }
+
+    /// Encodes an [`Integer`] using signed LEB128.
+    ///
+    /// The [`Integer`] is split into 7-bit groups of its two's complement representation,
+    /// least-significant first, and each group is stored in the low 7 bits of a byte. The high
+    /// bit of each byte is set if more bytes follow. The bit below it in the last byte is the
+    /// sign. The encoding is as short as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(0).to_sleb128(), &[0x00]);
+    /// assert_eq!(Integer::from(63).to_sleb128(), &[0x3f]);
+    /// assert_eq!(Integer::from(64).to_sleb128(), &[0xc0, 0x00]);
+    /// assert_eq!(Integer::from(-1).to_sleb128(), &[0x7f]);
+    /// assert_eq!(Integer::from(-123456).to_sleb128(), &[0xc0, 0xbb, 0x78]);
+    /// ```
+    pub fn to_sleb128(&self) -> Vec<u8> {
+        // A negative x is stored as the complement of the digits of -x - 1 = !x.
+        let mut digits: Vec<u8> = if self.sign {
+            self.abs.to_power_of_2_digits_asc(7)
+        } else {
+            (&self.abs - Natural::ONE).to_power_of_2_digits_asc(7)
+        };
+        if digits.last().map_or(true, |&d| d & 0x40 != 0) {
+            digits.push(0);
+        }
+        if !self.sign {
+            for d in &mut digits {
+                *d ^= 0x7f;
+            }
+        }
+        leb128_from_digits(digits)
+    }
+
+    /// Writes the signed LEB128 encoding of an [`Integer`] to a [`Write`], returning the number
+    /// of bytes written.
+    ///
+    /// See [`to_sleb128`](Integer::to_sleb128) for the format.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Errors
+    /// Returns any error returned by `writer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Vec::new();
+    /// assert_eq!(Integer::from(-129).write_sleb128(&mut out).unwrap(), 2);
+    /// assert_eq!(out, &[0xff, 0x7e]);
+    /// ```
+    pub fn write_sleb128<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        let bytes = self.to_sleb128();
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Decodes a signed LEB128 [`Integer`] from the start of a byte slice, returning the
+    /// [`Integer`] and the number of bytes that were read.
+    ///
+    /// Bytes after the end of the encoding are ignored. Overlong encodings, whose last byte only
+    /// repeats the sign of the byte before it, are rejected.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::conversion::leb128::Leb128Error;
+    ///
+    /// assert_eq!(
+    ///     Integer::from_sleb128(&[0xc0, 0xbb, 0x78, 0x00]),
+    ///     Ok((Integer::from(-123456), 3))
+    /// );
+    /// assert_eq!(Integer::from_sleb128(&[0xc0]), Err(Leb128Error::UnexpectedEnd));
+    /// assert_eq!(Integer::from_sleb128(&[0xff, 0x7f]), Err(Leb128Error::Overlong));
+    /// ```
+    pub fn from_sleb128(bytes: &[u8]) -> Result<(Integer, usize), Leb128Error> {
+        let len = leb128_len(bytes)?;
+        let bytes = &bytes[..len];
+        let last = bytes[len - 1];
+        let negative = last & 0x40 != 0;
+        let mask = if negative { 0x7f } else { 0 };
+        // The last byte is redundant if it only sign-extends the byte before it
+        if len > 1 && last == mask && (bytes[len - 2] & 0x40 != 0) == negative {
+            return Err(Leb128Error::Overlong);
+        }
+        let mut abs =
+            Natural::from_power_of_2_digits_asc(7, bytes.iter().map(|&b| (b & 0x7f) ^ mask))
+                .unwrap();
+        if negative {
+            abs += Natural::ONE;
+        }
+        Ok((Integer::from_sign_and_abs(!negative, abs), len))
+    }
+
+    /// Reads a signed LEB128 [`Integer`] from a [`Read`].
+    ///
+    /// Exactly as many bytes as the encoding contains are read.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes read.
+    ///
+    /// # Errors
+    /// Returns any error returned by `reader`. If the input ends before the encoding does, an
+    /// [`ErrorKind::UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error is returned, and if
+    /// the encoding is overlong, an [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)
+    /// error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut input: &[u8] = &[0xff, 0x7e, 0x3f];
+    /// assert_eq!(Integer::read_sleb128(&mut input).unwrap(), -129);
+    /// assert_eq!(Integer::read_sleb128(&mut input).unwrap(), 63);
+    /// assert!(Integer::read_sleb128(&mut input).is_err());
+    /// ```
+    pub fn read_sleb128<R: Read>(reader: &mut R) -> io::Result<Integer> {
+        Ok(Integer::from_sleb128(&read_leb128_bytes(reader)?)?.0)
+    }
+
+    /// Encodes an [`Integer`] by applying the [zigzag map](Integer::to_zigzag) and then unsigned
+    /// LEB128.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(0).to_zigzag_uleb128(), &[0x00]);
+    /// assert_eq!(Integer::from(-64).to_zigzag_uleb128(), &[0x7f]);
+    /// assert_eq!(Integer::from(64).to_zigzag_uleb128(), &[0x80, 0x01]);
+    /// ```
+    pub fn to_zigzag_uleb128(&self) -> Vec<u8> {
+        self.to_zigzag().to_uleb128()
+    }
+
+    /// Writes the zigzag unsigned LEB128 encoding of an [`Integer`] to a [`Write`], returning the
+    /// number of bytes written.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Errors
+    /// Returns any error returned by `writer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut out = Vec::new();
+    /// assert_eq!(Integer::from(-65).write_zigzag_uleb128(&mut out).unwrap(), 2);
+    /// assert_eq!(out, &[0x81, 0x01]);
+    /// ```
+    pub fn write_zigzag_uleb128<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        self.to_zigzag().write_uleb128(writer)
+    }
+
+    /// Decodes a zigzag unsigned LEB128 [`Integer`] from the start of a byte slice, returning the
+    /// [`Integer`] and the number of bytes that were read.
+    ///
+    /// Bytes after the end of the encoding are ignored, and overlong encodings are rejected.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::conversion::leb128::Leb128Error;
+    ///
+    /// assert_eq!(Integer::from_zigzag_uleb128(&[0x81, 0x01]), Ok((Integer::from(-65), 2)));
+    /// assert_eq!(Integer::from_zigzag_uleb128(&[0x81, 0x00]), Err(Leb128Error::Overlong));
+    /// ```
+    pub fn from_zigzag_uleb128(bytes: &[u8]) -> Result<(Integer, usize), Leb128Error> {
+        let (x, len) = Natural::from_uleb128(bytes)?;
+        Ok((Integer::from_zigzag(&x), len))
+    }
+
+    /// Reads a zigzag unsigned LEB128 [`Integer`] from a [`Read`].
+    ///
+    /// Exactly as many bytes as the encoding contains are read.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes read.
+    ///
+    /// # Errors
+    /// Returns any error returned by `reader`. If the input ends before the encoding does, an
+    /// [`ErrorKind::UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error is returned, and if
+    /// the encoding is overlong, an [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData)
+    /// error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut input: &[u8] = &[0x81, 0x01, 0x02];
+    /// assert_eq!(Integer::read_zigzag_uleb128(&mut input).unwrap(), -65);
+    /// assert_eq!(Integer::read_zigzag_uleb128(&mut input).unwrap(), 1);
+    /// ```
+    pub fn read_zigzag_uleb128<R: Read>(reader: &mut R) -> io::Result<Integer> {
+        Ok(Integer::from_zigzag(&Natural::read_uleb128(reader)?))
+    }
+}
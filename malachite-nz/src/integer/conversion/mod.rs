@@ -1,3 +1,12 @@
+/// Functions for converting an [`Integer`](crate::integer::Integer) to and from bytes in two's
+/// complement.
+pub mod bytes;
+/// Functions for encoding [`Integer`](crate::integer::Integer)s as CBOR data items, using bignum
+/// tags when necessary, and for decoding them.
+pub mod cbor;
+/// Functions for encoding [`Integer`](crate::integer::Integer)s as ASN.1 `INTEGER`s, and for
+/// decoding them using either the Distinguished or the Basic Encoding Rules.
+pub mod der;
 /// An implementation of the [`From`] trait for converting a [`bool`] to an
 /// [`Integer`](crate::integer::Integer).
 pub mod from_bool;
@@ -128,6 +137,9 @@ pub mod from_twos_complement_limbs;
 ///
 /// An [`Integer`](crate::integer::Integer) is always an integer.
 pub mod is_integer;
+/// Functions for encoding [`Integer`](crate::integer::Integer)s using signed LEB128 and zigzag
+/// LEB128, and for decoding them.
+pub mod leb128;
 /// Implementations of traits for converting an [`Integer`](crate::integer::Integer) to a
 /// [`Natural`](crate::natural::Natural).
 ///
@@ -135,6 +147,10 @@ pub mod is_integer;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`SaturatingFrom`](malachite_base::num::conversion::traits::SaturatingFrom).
 pub mod natural_from_integer;
+/// Implementations of [`From`] for converting [`Integer`](crate::integer::Integer)s to and from the
+/// `BigInt` type of [num-bigint](https://crates.io/crates/num-bigint).
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint;
 /// Implementations of traits for converting an [`Integer`](crate::integer::Integer) to a primitive
 /// float.
 ///
@@ -314,6 +330,7 @@ pub mod primitive_float_from_integer;
 pub mod primitive_int_from_integer;
 /// Implementations of traits for serialization and deserialization using
 /// [serde](https://serde.rs/).
+#[cfg(feature = "serde")]
 pub mod serde;
 /// Implementations of traits for converting [`Integer`](crate::integer::Integer)s to and from
 /// [`String`]s.
@@ -0,0 +1,133 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Sign as SignTrait, UnsignedAbs};
+use num_bigint::{BigInt, BigUint, Sign};
+use std::cmp::Ordering;
+
+const fn sign_from_ordering(sign: Ordering) -> Sign {
+    match sign {
+        Ordering::Less => Sign::Minus,
+        Ordering::Equal => Sign::NoSign,
+        Ordering::Greater => Sign::Plus,
+    }
+}
+
+impl From<BigInt> for Integer {
+    /// Converts a [`BigInt`] to an [`Integer`], taking the [`BigInt`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(Integer::from(BigInt::from(123)), 123);
+    /// assert_eq!(Integer::from(BigInt::from(-123)), -123);
+    /// assert_eq!(
+    ///     Integer::from(-BigInt::from(10).pow(20)).to_string(),
+    ///     "-100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: BigInt) -> Integer {
+        let (sign, abs) = value.into_parts();
+        Integer::from_sign_and_abs(sign != Sign::Minus, Natural::from(abs))
+    }
+}
+
+impl<'a> From<&'a BigInt> for Integer {
+    /// Converts a [`BigInt`] to an [`Integer`], taking the [`BigInt`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(Integer::from(&BigInt::from(123)), 123);
+    /// assert_eq!(Integer::from(&BigInt::from(-123)), -123);
+    /// assert_eq!(
+    ///     Integer::from(&-BigInt::from(10).pow(20)).to_string(),
+    ///     "-100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: &'a BigInt) -> Integer {
+        Integer::from_sign_and_abs(
+            value.sign() != Sign::Minus,
+            Natural::from(value.magnitude()),
+        )
+    }
+}
+
+impl From<Integer> for BigInt {
+    /// Converts an [`Integer`] to a [`BigInt`], taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::integer::Integer;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(Integer::from(123)), BigInt::from(123));
+    /// assert_eq!(BigInt::from(Integer::from(-123)), BigInt::from(-123));
+    /// assert_eq!(
+    ///     BigInt::from(-Integer::from(10).pow(20)).to_string(),
+    ///     "-100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: Integer) -> BigInt {
+        let sign = sign_from_ordering(SignTrait::sign(&value));
+        BigInt::from_biguint(sign, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl<'a> From<&'a Integer> for BigInt {
+    /// Converts an [`Integer`] to a [`BigInt`], taking the [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::integer::Integer;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(&Integer::from(123)), BigInt::from(123));
+    /// assert_eq!(BigInt::from(&Integer::from(-123)), BigInt::from(-123));
+    /// assert_eq!(
+    ///     BigInt::from(&-Integer::from(10).pow(20)).to_string(),
+    ///     "-100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: &'a Integer) -> BigInt {
+        let sign = sign_from_ordering(SignTrait::sign(value));
+        BigInt::from_biguint(sign, BigUint::from(value.unsigned_abs_ref()))
+    }
+}
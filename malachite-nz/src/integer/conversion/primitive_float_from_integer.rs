@@ -1,5 +1,6 @@
 use crate::integer::Integer;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::rounding_modes::RoundingMode;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1,6 +1,9 @@
 use crate::integer::{Integer, SerdeInteger};
+use crate::natural::conversion::serde::BytesVisitor;
 use crate::natural::Natural;
 use malachite_base::num::conversion::traits::FromStringBase;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
 
 impl From<Integer> for SerdeInteger {
@@ -41,3 +44,48 @@ impl TryFrom<SerdeInteger> for Integer {
         }
     }
 }
+
+impl Serialize for Integer {
+    /// Serializes an [`Integer`].
+    ///
+    /// Human-readable formats, such as JSON, get a hexadecimal string starting with `0x` or
+    /// `-0x`. Other formats get a byte array whose first byte is 0 if the [`Integer`] is
+    /// non-negative and 1 if it is negative, followed by the bytes of its absolute value in
+    /// ascending order. Neither representation depends on the width of a [limb](crate#limbs).
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{:#x}", self))
+        } else {
+            let abs_bytes = self.abs.to_bytes_le();
+            let mut bytes = Vec::with_capacity(abs_bytes.len() + 1);
+            bytes.push(u8::from(!self.sign));
+            bytes.extend_from_slice(&abs_bytes);
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Integer {
+    /// Deserializes an [`Integer`] from either of the representations produced by its
+    /// [`Serialize`] implementation, depending on whether the format is human-readable.
+    ///
+    /// In the byte representation, trailing zero bytes are allowed, and negative zero is read as
+    /// zero.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Integer, D::Error> {
+        if deserializer.is_human_readable() {
+            Integer::try_from(SerdeInteger::deserialize(deserializer)?).map_err(D::Error::custom)
+        } else {
+            let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+            let sign = match bytes.first() {
+                Some(0) => true,
+                Some(1) => false,
+                Some(b) => return Err(D::Error::custom(format!("Invalid sign byte {}", b))),
+                None => return Err(D::Error::custom("Missing sign byte")),
+            };
+            Ok(Integer::from_sign_and_abs(
+                sign,
+                Natural::from_bytes_le(&bytes[1..]),
+            ))
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+use malachite_base::num::conversion::string::from_string::ParseNumberError;
+use malachite_base::num::conversion::traits::{
+    Digits, FromStringWithAlphabet, ToStringWithAlphabet,
+};
+
+impl ToStringWithAlphabet for Integer {
+    /// Converts an [`Integer`] to a string using the digits of a [`DigitAlphabet`].
+    ///
+    /// Zero is written as the alphabet's zero digit; otherwise, there are no leading zeros.
+    /// Negative numbers are preceded by `'-'`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is negative and the alphabet uses `'-'` as a digit, as
+    /// [`DigitAlphabet::BASE64_URL`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    /// use malachite_base::num::conversion::traits::ToStringWithAlphabet;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(10).pow(20).to_string_with_alphabet(DigitAlphabet::BASE62),
+    ///     "1v973mBjywOu"
+    /// );
+    /// assert_eq!(
+    ///     (-Integer::from(10).pow(20)).to_string_with_alphabet(DigitAlphabet::BASE62),
+    ///     "-1v973mBjywOu"
+    /// );
+    /// ```
+    fn to_string_with_alphabet(&self, alphabet: DigitAlphabet) -> String {
+        let s = self.unsigned_abs_ref().to_string_with_alphabet(alphabet);
+        if *self < 0 {
+            let mut signed = String::with_capacity(s.len() + 1);
+            signed.push(char::from(alphabet.minus_sign()));
+            signed.push_str(&s);
+            signed
+        } else {
+            s
+        }
+    }
+}
+
+impl FromStringWithAlphabet for Integer {
+    /// Converts a string written using the digits of a [`DigitAlphabet`] to an [`Integer`].
+    ///
+    /// The string may start with `'-'`, unless the alphabet uses `'-'` as a digit. If the string
+    /// is not a valid number, an error is returned, describing the problem and where in the string
+    /// it was found. Leading zeros are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_base::num::conversion::traits::FromStringWithAlphabet;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::from_string_with_alphabet(DigitAlphabet::BASE62, "-1v973mBjywOu")
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "-100000000000000000000"
+    /// );
+    /// // In base 64url, '-' is the digit 62
+    /// assert_eq!(
+    ///     Integer::from_string_with_alphabet(DigitAlphabet::BASE64_URL, "-A").unwrap(),
+    ///     3968
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_string_with_alphabet(DigitAlphabet::BASE62, "-"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1))
+    /// );
+    /// ```
+    fn from_string_with_alphabet(
+        alphabet: DigitAlphabet,
+        s: &str,
+    ) -> Result<Integer, ParseNumberError> {
+        let (negative, start) = alphabet.strip_minus_sign(s);
+        let digits = alphabet.decode_digits(&s[start..], start)?;
+        Ok(Integer::from_sign_and_abs(
+            !negative,
+            Natural::from_digits_desc(&alphabet.get_base(), digits.into_iter()).unwrap(),
+        ))
+    }
+}
@@ -1,18 +1,24 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
-use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_base::num::conversion::string::from_string::{
+    clean_digits, strip_sign, ParseNumberError,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{FromStringBase, FromStringWithOptions};
 use std::ops::Neg;
 use std::str::FromStr;
 
 impl FromStr for Integer {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to an [`Integer`].
     ///
-    /// If the string does not represent a valid [`Integer`], an `Err` is returned. To be valid,
-    /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`, with an
-    /// optional leading `'-'`. Leading zeros are allowed, as is the string `"-0"`. The string
-    /// `"-"` is not.
+    /// If the string does not represent a valid [`Integer`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. To be valid, the string must be nonempty and
+    /// only contain the [`char`]s `'0'` through `'9'`, with an optional leading `'-'`. Leading
+    /// zeros are allowed, as is the string `"-0"`. The string `"-"` is not. To accept other
+    /// syntax, such as separators or a base prefix, use
+    /// [`from_string_with_options`](Integer::from_string_with_options).
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -23,6 +29,9 @@ impl FromStr for Integer {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
     /// use malachite_nz::integer::Integer;
     /// use std::str::FromStr;
     ///
@@ -33,12 +42,76 @@ impl FromStr for Integer {
     /// assert_eq!(Integer::from_str("-00123456").unwrap(), -123456);
     /// assert_eq!(Integer::from_str("-0").unwrap(), 0);
     ///
-    /// assert!(Integer::from_str("").is_err());
-    /// assert!(Integer::from_str("a").is_err());
+    /// assert_eq!(
+    ///     Integer::from_str(""),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_str("-"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_str("-12a"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3))
+    /// );
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Integer, ()> {
-        Integer::from_string_base(10, s).ok_or(())
+    fn from_str(s: &str) -> Result<Integer, ParseNumberError> {
+        Integer::from_string_with_options(s, ParseOptions::default())
+    }
+}
+
+impl FromStringWithOptions for Integer {
+    /// Converts a string to an [`Integer`], accepting the syntax specified by a [`ParseOptions`].
+    ///
+    /// If the string does not represent a valid [`Integer`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. A prefix, if allowed, comes after the sign.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_base::num::conversion::string::options::ParseOptions;
+    /// use malachite_base::num::conversion::traits::FromStringWithOptions;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.set_allow_comma_separators(true);
+    /// options.set_allow_prefixes(true);
+    /// options.set_allow_plus_sign(true);
+    /// assert_eq!(
+    ///     Integer::from_string_with_options("-1,000,000", options).unwrap(),
+    ///     -1000000
+    /// );
+    /// assert_eq!(Integer::from_string_with_options("+0b101", options).unwrap(), 5);
+    /// assert_eq!(Integer::from_string_with_options("-0xff", options).unwrap(), -255);
+    /// assert_eq!(
+    ///     Integer::from_string_with_options("0x-ff", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 2))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_string_with_options(" 5", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 0))
+    /// );
+    /// ```
+    fn from_string_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> Result<Integer, ParseNumberError> {
+        let (negative, range) = strip_sign(s, options)?;
+        let (base, digits) = clean_digits(&s[range.clone()], range.start, options)?;
+        Ok(Integer::from_sign_and_abs(
+            !negative,
+            Natural::from_string_base(base, &digits).unwrap(),
+        ))
     }
 }
 
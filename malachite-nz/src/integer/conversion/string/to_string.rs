@@ -1,17 +1,20 @@
 use crate::integer::Integer;
-use crate::natural::conversion::string::to_string::BaseFmtWrapper;
+use crate::natural::conversion::string::to_string::{fmt_natural_exp, BaseFmtWrapper};
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
 use malachite_base::num::conversion::traits::{Digits, ToStringBase};
 use malachite_base::vecs::vec_pad_left;
-use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex, Write};
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
 
 impl<'a> Display for BaseFmtWrapper<&'a Integer> {
     /// Writes a wrapped [`Integer`] to a string using a specified base.
     ///
     /// If the base is greater than 10, lowercase alphabetic letters are used by default. Using the
-    /// `#` flag switches to uppercase letters. Padding with zeros works as usual.
+    /// `#` flag switches to uppercase letters. Width, fill, alignment, sign, and padding with zeros
+    /// work as usual.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -34,32 +37,17 @@ impl<'a> Display for BaseFmtWrapper<&'a Integer> {
     /// assert_eq!(format!("{:#}", x), "-GJDGXS");
     /// assert_eq!(format!("{:010}", x), "-000gjdgxs");
     /// assert_eq!(format!("{:#010}", x), "-000GJDGXS");
+    /// assert_eq!(format!("{:>10}", x), "   -gjdgxs");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if !self.x.sign {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$}",
-                        &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$}",
-                        &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
+        let abs = BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base);
+        if self.x.sign {
+            Display::fmt(&abs, f)
+        } else if f.alternate() {
+            f.pad_integral(false, "", &format!("{:#}", abs))
+        } else {
+            f.pad_integral(false, "", &abs.to_string())
         }
-        Display::fmt(
-            &BaseFmtWrapper::new(self.x.unsigned_abs_ref(), self.base),
-            f,
-        )
     }
 }
 
@@ -67,7 +55,8 @@ impl<'a> Debug for BaseFmtWrapper<&'a Integer> {
     /// Writes a wrapped [`Integer`] to a string using a specified base.
     ///
     /// If the base is greater than 10, lowercase alphabetic letters are used by default. Using the
-    /// `#` flag switches to uppercase letters. Padding with zeros works as usual.
+    /// `#` flag switches to uppercase letters. Width, fill, alignment, sign, and padding with zeros
+    /// work as usual.
     ///
     /// This is the same as the [`Display::fmt`] implementation.
     ///
@@ -220,20 +209,16 @@ impl Display for Integer {
     ///     "-1000000000000"
     /// );
     /// assert_eq!(format!("{:05}", Integer::from(-123)), "-0123");
+    /// assert_eq!(format!("{:+}", Integer::from(123)), "+123");
+    /// assert_eq!(format!("{:>6}", Integer::from(-123)), "  -123");
+    /// assert_eq!(format!("{:*<6}", Integer::from(-123)), "-123**");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return write!(
-                    f,
-                    "{:0width$}",
-                    self.unsigned_abs_ref(),
-                    width = width.saturating_sub(1)
-                );
-            }
+        if self.sign {
+            Display::fmt(&self.abs, f)
+        } else {
+            f.pad_integral(false, "", &self.abs.to_string())
         }
-        Display::fmt(self.unsigned_abs_ref(), f)
     }
 }
 
@@ -332,29 +317,14 @@ impl Binary for Integer {
     ///     "-0b1110100011010100101001010001000000000000"
     /// );
     /// assert_eq!(format!("{:#011b}", Integer::from(-123)), "-0b01111011");
+    /// assert_eq!(format!("{:>#11b}", Integer::from(-123)), " -0b1111011");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$b}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$b}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
+        if self.sign {
+            Binary::fmt(&self.abs, f)
+        } else {
+            f.pad_integral(false, "0b", &format!("{:b}", self.abs))
         }
-        Binary::fmt(self.unsigned_abs_ref(), f)
     }
 }
 
@@ -410,27 +380,11 @@ impl Octal for Integer {
     /// assert_eq!(format!("{:#07o}", Integer::from(-123)), "-0o0173");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$o}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$o}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
+        if self.sign {
+            Octal::fmt(&self.abs, f)
+        } else {
+            f.pad_integral(false, "0o", &format!("{:o}", self.abs))
         }
-        Octal::fmt(self.unsigned_abs_ref(), f)
     }
 }
 
@@ -486,27 +440,11 @@ impl LowerHex for Integer {
     /// assert_eq!(format!("{:#07x}", Integer::from(-123)), "-0x007b");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$x}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$x}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
+        if self.sign {
+            LowerHex::fmt(&self.abs, f)
+        } else {
+            f.pad_integral(false, "0x", &format!("{:x}", self.abs))
         }
-        LowerHex::fmt(self.unsigned_abs_ref(), f)
     }
 }
 
@@ -562,26 +500,74 @@ impl UpperHex for Integer {
     /// assert_eq!(format!("{:#07X}", Integer::from(-123)), "-0x007B");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if *self < 0 {
-            f.write_char('-')?;
-            if let Some(width) = f.width() {
-                return if f.alternate() {
-                    write!(
-                        f,
-                        "{:#0width$X}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "{:0width$X}",
-                        self.unsigned_abs_ref(),
-                        width = width.saturating_sub(1)
-                    )
-                };
-            }
+        if self.sign {
+            UpperHex::fmt(&self.abs, f)
+        } else {
+            f.pad_integral(false, "0x", &format!("{:X}", self.abs))
         }
-        UpperHex::fmt(self.unsigned_abs_ref(), f)
+    }
+}
+
+impl LowerExp for Integer {
+    /// Converts an [`Integer`] to a [`String`] in scientific notation, using a lowercase `e`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even; otherwise, the value is written exactly, without trailing
+    /// zeros. This matches the behavior of the primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(format!("{:e}", Integer::ZERO), "0e0");
+    /// assert_eq!(format!("{:e}", Integer::from(-123)), "-1.23e2");
+    /// assert_eq!(
+    ///     format!("{:.3e}", Integer::from_str("-1000000000000").unwrap()),
+    ///     "-1.000e12"
+    /// );
+    /// assert_eq!(format!("{:.0e}", Integer::from(-25)), "-2e1");
+    /// assert_eq!(format!("{:010e}", Integer::from(-123)), "-0001.23e2");
+    /// assert_eq!(format!("{:+e}", Integer::from(5)), "+5e0");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_natural_exp(f, self.sign, &self.abs, false)
+    }
+}
+
+impl UpperExp for Integer {
+    /// Converts an [`Integer`] to a [`String`] in scientific notation, using an uppercase `E`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even; otherwise, the value is written exactly, without trailing
+    /// zeros. This matches the behavior of the primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(format!("{:E}", Integer::from(-1200)), "-1.2E3");
+    /// assert_eq!(format!("{:.2E}", Integer::from(999)), "9.99E2");
+    /// assert_eq!(format!("{:>9E}", Integer::from(-1200)), "   -1.2E3");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_natural_exp(f, self.sign, &self.abs, true)
     }
 }
@@ -754,7 +754,7 @@ impl Natural {
             (_, Natural(Small(y))) => self.or_assign_pos_limb_neg(y.wrapping_neg()),
             (Natural(Small(x)), _) => *self = other.or_neg_limb_pos(*x),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_vec_or_pos_neg_in_place_left(xs, ys);
+                limbs_vec_or_pos_neg_in_place_left(xs.vec_mut(), ys);
                 self.trim();
             }
         }
@@ -815,7 +815,7 @@ impl Natural {
             (_, Natural(Small(y))) => self.or_assign_neg_limb_neg(y.wrapping_neg()),
             (Natural(Small(x)), _) => *self = other.or_neg_limb_neg(x.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_vec_or_neg_neg_in_place_left(xs, ys);
+                limbs_vec_or_neg_neg_in_place_left(xs.vec_mut(), ys);
                 self.trim();
             }
         }
@@ -6,6 +6,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub, limbs_sub_greater_in_place_left, limbs_sub_greater_to_out, limbs_sub_limb,
     limbs_sub_limb_in_place, limbs_sub_limb_to_out, limbs_vec_sub_in_place_right,
 };
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::logic::not::limbs_not_in_place;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
@@ -987,13 +988,13 @@ impl Natural {
             Natural(Small(ref mut small)) => {
                 let result = small.wrapping_neg() ^ other;
                 if result == 0 {
-                    *self = Natural(Large(vec![0, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])));
                 } else {
                     *small = result.wrapping_neg();
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_neg_xor_limb_in_place(limbs, other);
+                limbs_vec_neg_xor_limb_in_place(limbs.vec_mut(), other);
                 self.trim();
             }
         }
@@ -1005,7 +1006,7 @@ impl Natural {
             Natural(Small(ref small)) => {
                 let result = small.wrapping_neg() ^ other;
                 Natural(if result == 0 {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 } else {
                     Small(result.wrapping_neg())
                 })
@@ -1021,13 +1022,13 @@ impl Natural {
             Natural(Small(ref mut small)) => {
                 let result = *small ^ other;
                 if result == 0 {
-                    *self = Natural(Large(vec![0, 1]))
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])))
                 } else {
                     *small = result.wrapping_neg();
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_pos_xor_limb_neg_in_place(limbs, other);
+                limbs_vec_pos_xor_limb_neg_in_place(limbs.vec_mut(), other);
                 self.trim();
             }
         }
@@ -1038,12 +1039,12 @@ impl Natural {
             Natural(Small(small)) => {
                 let result = small ^ other;
                 if result == 0 {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 } else {
                     Small(result.wrapping_neg())
                 }
             }
-            Natural(Large(ref limbs)) => Large(limbs_pos_xor_limb_neg(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_pos_xor_limb_neg(limbs, other).into()),
         })
     }
 
@@ -1074,7 +1075,7 @@ impl Natural {
             }
             (_, Natural(Small(y))) => self.xor_assign_pos_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ys))) => {
-                if limbs_xor_pos_neg_in_place_either(xs, ys) {
+                if limbs_xor_pos_neg_in_place_either(xs.vec_mut(), ys.vec_mut()) {
                     *self = other;
                 }
                 self.trim();
@@ -1087,7 +1088,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_neg_limb_pos(*x),
             (_, Natural(Small(y))) => self.xor_assign_pos_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_pos_neg_in_place_left(xs, ys);
+                limbs_xor_pos_neg_in_place_left(xs.vec_mut(), ys);
                 self.trim();
             }
         }
@@ -1103,7 +1104,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_pos_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.xor_assign_neg_limb_pos(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_pos_neg_in_place_right(ys, xs);
+                limbs_xor_pos_neg_in_place_right(ys, xs.vec_mut());
                 self.trim();
             }
         }
@@ -1137,7 +1138,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_neg_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.xor_assign_neg_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_neg_neg_in_place_left(xs, ys);
+                limbs_xor_neg_neg_in_place_left(xs.vec_mut(), ys);
                 self.trim();
             }
         }
@@ -7,20 +7,16 @@ use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
 ///
 /// Any `Integer` whose absolute value is small enough to fit into a [`Limb`](crate#limbs) is
 /// represented inline. Only integers outside this range incur the costs of heap-allocation.
-#[derive(Clone, Hash, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeInteger", into = "SerdeInteger")
-)]
+#[derive(Hash, Eq, PartialEq)]
 pub struct Integer {
     // whether the `Integer` is non-negative
     pub(crate) sign: bool,
     pub(crate) abs: Natural,
 }
 
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
 pub(crate) struct SerdeInteger(String);
 
 impl Integer {
@@ -70,6 +66,49 @@ macro_rules! integer_negative_one {
     };
 }
 
+impl Clone for Integer {
+    /// Clones an [`Integer`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    #[inline]
+    fn clone(&self) -> Integer {
+        Integer {
+            sign: self.sign,
+            abs: self.abs.clone(),
+        }
+    }
+
+    /// Copies an [`Integer`] into an existing one, reusing its buffer when both have absolute
+    /// values with more than one limb.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the underlying [`Vec`] needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `source.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(10).pow(100);
+    /// x.clone_from(&-Integer::from(3).pow(100));
+    /// assert_eq!(x, -Integer::from(3).pow(100));
+    /// ```
+    #[inline]
+    fn clone_from(&mut self, source: &Integer) {
+        self.sign = source.sign;
+        self.abs.clone_from(&source.abs);
+    }
+}
+
 /// The constant 0.
 impl Zero for Integer {
     const ZERO: Integer = integer_zero!();
@@ -111,5 +150,8 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for logic and bit manipulation.
 pub mod logic;
+/// Implementations of the traits from the `num-traits` and `num-integer` crates for [`Integer`]s.
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
 /// Iterators that generate [`Integer`]s randomly.
 pub mod random;
@@ -0,0 +1,405 @@
+use crate::integer::Integer;
+use crate::natural::num_traits::from_str_radix_options;
+use malachite_base::num::arithmetic::traits::{
+    Abs, CeilingRoot, DivMod, DivRem, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Mod, Parity,
+    Pow, Sign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::string::from_string::ParseNumberError;
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringWithOptions, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+impl num_traits::Zero for Integer {
+    /// Returns 0.
+    #[inline]
+    fn zero() -> Integer {
+        Integer::ZERO
+    }
+
+    /// Determines whether an [`Integer`] is 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+}
+
+impl num_traits::One for Integer {
+    /// Returns 1.
+    #[inline]
+    fn one() -> Integer {
+        Integer::ONE
+    }
+
+    /// Determines whether an [`Integer`] is 1.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1
+    }
+}
+
+impl num_traits::Num for Integer {
+    type FromStrRadixErr = ParseNumberError;
+
+    /// Converts a string to an [`Integer`], using a specified radix.
+    ///
+    /// The digits may be preceded by a `'+'` or a `'-'`, and groups of digits may be separated by
+    /// `'_'`. Digits greater than 9 may be lowercase or uppercase. If the string is not valid, an
+    /// `Err` is returned, describing the problem and where in the string it was found.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_traits::Num;
+    ///
+    /// assert_eq!(Integer::from_str_radix("-ff", 16).unwrap(), -255);
+    /// assert_eq!(Integer::from_str_radix("+1_000", 10).unwrap(), 1000);
+    /// assert!(Integer::from_str_radix("--1", 10).is_err());
+    /// ```
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Integer, ParseNumberError> {
+        Integer::from_string_with_options(s, from_str_radix_options(radix))
+    }
+}
+
+impl num_traits::Signed for Integer {
+    /// Returns the absolute value of an [`Integer`].
+    #[inline]
+    fn abs(&self) -> Integer {
+        Abs::abs(self)
+    }
+
+    /// Returns the difference of two [`Integer`]s if it is positive, and 0 otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Integer) -> Integer {
+        if self <= other {
+            Integer::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Returns $-1$, 0, or 1, depending on whether an [`Integer`] is negative, zero, or positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_traits::Signed;
+    ///
+    /// assert_eq!(Integer::from(-10).signum(), -1);
+    /// assert_eq!(Integer::from(0).signum(), 0);
+    /// assert_eq!(Integer::from(10).abs_sub(&Integer::from(3)), 7);
+    /// assert_eq!(Integer::from(3).abs_sub(&Integer::from(10)), 0);
+    /// ```
+    #[inline]
+    fn signum(&self) -> Integer {
+        match self.sign() {
+            Ordering::Less => Integer::NEGATIVE_ONE,
+            Ordering::Equal => Integer::ZERO,
+            Ordering::Greater => Integer::ONE,
+        }
+    }
+
+    /// Determines whether an [`Integer`] is greater than 0.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0
+    }
+
+    /// Determines whether an [`Integer`] is less than 0.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0
+    }
+}
+
+impl num_traits::ToPrimitive for Integer {
+    /// Converts an [`Integer`] to an [`i64`], returning `None` if it is out of range.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to an [`i128`], returning `None` if it is out of range.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to a [`u64`], returning `None` if it is out of range.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to a [`u128`], returning `None` if it is out of range.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f32`]. An [`Integer`] whose absolute value is
+    /// too large to be represented is converted to the finite [`f32`] with the largest absolute
+    /// value and the same sign. The result is never `None`.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest))
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f64`]. An [`Integer`] whose absolute value is
+    /// too large to be represented is converted to the finite [`f64`] with the largest absolute
+    /// value and the same sign. The result is never `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_traits::ToPrimitive;
+    ///
+    /// assert_eq!(Integer::from(-123).to_i8(), Some(-123));
+    /// assert_eq!(Integer::from(-123).to_u64(), None);
+    /// assert_eq!(Integer::from(-123).to_f64(), Some(-123.0));
+    /// ```
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest))
+    }
+}
+
+impl num_traits::FromPrimitive for Integer {
+    /// Converts an [`i64`] to an [`Integer`]. The result is never `None`.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts an [`i128`] to an [`Integer`]. The result is never `None`.
+    #[inline]
+    fn from_i128(n: i128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts a [`u64`] to an [`Integer`]. The result is never `None`.
+    #[inline]
+    fn from_u64(n: u64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts a [`u128`] to an [`Integer`]. The result is never `None`.
+    #[inline]
+    fn from_u128(n: u128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts an [`f64`] to an [`Integer`], rounding towards zero. Returns `None` if the [`f64`]
+    /// is infinite or NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_traits::FromPrimitive;
+    ///
+    /// assert_eq!(Integer::from_f64(-123.9).unwrap(), -123);
+    /// assert_eq!(Integer::from_f64(1.0e20).unwrap().to_string(), "100000000000000000000");
+    /// assert_eq!(Integer::from_f64(f64::INFINITY), None);
+    /// ```
+    #[inline]
+    fn from_f64(n: f64) -> Option<Integer> {
+        Integer::try_from(n.trunc()).ok()
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        impl num_traits::Pow<$t> for Integer {
+            type Output = Integer;
+
+            /// Raises an [`Integer`] to a power, taking the [`Integer`] by value.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                Pow::pow(self, u64::exact_from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Integer {
+            type Output = Integer;
+
+            /// Raises an [`Integer`] to a power, taking the [`Integer`] by reference.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                Pow::pow(self, u64::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+impl_pow!(usize);
+
+impl num_traits::CheckedDiv for Integer {
+    /// Divides an [`Integer`] by another [`Integer`], rounding the quotient towards zero. Returns
+    /// `None` if the divisor is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_traits::CheckedDiv;
+    ///
+    /// assert_eq!(Integer::from(-23).checked_div(&Integer::from(10)).unwrap(), -2);
+    /// assert_eq!(Integer::from(-23).checked_div(&Integer::from(0)), None);
+    /// ```
+    #[inline]
+    fn checked_div(&self, other: &Integer) -> Option<Integer> {
+        if *other == 0 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl num_integer::Integer for Integer {
+    /// Divides an [`Integer`] by another [`Integer`], rounding the quotient towards negative
+    /// infinity.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(num_integer::Integer::div_floor(&Integer::from(-23), &Integer::from(10)), -3);
+    /// assert_eq!(num_integer::Integer::mod_floor(&Integer::from(-23), &Integer::from(10)), 7);
+    /// ```
+    #[inline]
+    fn div_floor(&self, other: &Integer) -> Integer {
+        self.div_mod(other).0
+    }
+
+    /// Returns the remainder when an [`Integer`] is divided by another [`Integer`], rounding the
+    /// quotient towards negative infinity. The remainder has the same sign as `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn mod_floor(&self, other: &Integer) -> Integer {
+        self.mod_op(other)
+    }
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The result is never
+    /// negative.
+    #[inline]
+    fn gcd(&self, other: &Integer) -> Integer {
+        Integer::from(Gcd::gcd(self.unsigned_abs_ref(), other.unsigned_abs_ref()))
+    }
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The result is never
+    /// negative.
+    #[inline]
+    fn lcm(&self, other: &Integer) -> Integer {
+        Integer::from(Lcm::lcm(self.unsigned_abs_ref(), other.unsigned_abs_ref()))
+    }
+
+    /// Determines whether an [`Integer`] is divisible by another [`Integer`]. Only 0 is
+    /// divisible by 0.
+    #[inline]
+    fn divides(&self, other: &Integer) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether an [`Integer`] is divisible by another [`Integer`]. Only 0 is
+    /// divisible by 0.
+    #[inline]
+    fn is_multiple_of(&self, other: &Integer) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether an [`Integer`] is even.
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.even()
+    }
+
+    /// Determines whether an [`Integer`] is odd.
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.odd()
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`], returning the quotient and remainder. The
+    /// quotient is rounded towards zero and the remainder has the same sign as `self`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_rem(&self, other: &Integer) -> (Integer, Integer) {
+        DivRem::div_rem(self, other)
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`], returning the quotient and remainder. The
+    /// quotient is rounded towards negative infinity and the remainder has the same sign as
+    /// `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_mod_floor(&self, other: &Integer) -> (Integer, Integer) {
+        self.div_mod(other)
+    }
+}
+
+impl num_integer::Roots for Integer {
+    /// Returns the $n$th root of an [`Integer`], rounded towards zero.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero, or if `n` is even and `self` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(Integer::from(1001).nth_root(3), 10);
+    /// assert_eq!(Integer::from(-1001).nth_root(3), -10);
+    /// assert_eq!(Integer::from(-999).cbrt(), -9);
+    /// ```
+    #[inline]
+    fn nth_root(&self, n: u32) -> Integer {
+        if *self >= 0 {
+            self.floor_root(u64::from(n))
+        } else {
+            self.ceiling_root(u64::from(n))
+        }
+    }
+
+    /// Returns the floor of the square root of an [`Integer`].
+    ///
+    /// # Panics
+    /// Panics if `self` is negative.
+    #[inline]
+    fn sqrt(&self) -> Integer {
+        self.floor_sqrt()
+    }
+
+    /// Returns the cube root of an [`Integer`], rounded towards zero.
+    #[inline]
+    fn cbrt(&self) -> Integer {
+        num_integer::Roots::nth_root(self, 3)
+    }
+}
@@ -31,7 +31,9 @@
 //!   $2^{64}$ does not use any allocated memory, and working with such numbers is almost as fast
 //!   as working with primitive integers. As a result, Malachite does not provide implementations
 //!   for _e.g._ adding a [`Natural`](natural::Natural) to a [`u64`], since the [`u64`] can be
-//!   converted to a [`Natural`](natural::Natural) very cheaply.
+//!   converted to a [`Natural`](natural::Natural) very cheaply. With the default 64-bit
+//!   [limbs](crate#limbs), numbers smaller than $2^{256}$ don't use any allocated memory either;
+//!   their limbs are stored inline.
 //! - Malachite handles memory intelligently. Consider the problem of adding a 1000-bit
 //!   [`Natural`](natural::Natural) and a 500-bit [`Natural`](natural::Natural). If we only have
 //!   references to the [`Natural`](natural::Natural)s, then we must allocate new memory for the
@@ -56,9 +58,10 @@
 //!
 //! # Limbs
 //! Large [`Natural`](natural::Natural)s and [`Integer`](integer::Integer)s store their data as
-//! [`Vec`]s of some primitive type. The elements of these [`Vec`]s are called "limbs" in GMP
-//! terminology, since they're large digits. By default, the type of a `Limb` is [`u64`], but you
-//! can set it to [`u32`] using the `32_bit_limbs` feature.
+//! [`Vec`]s of some primitive type; values that are only a few elements long keep them in an
+//! inline array instead. The elements are called "limbs" in GMP terminology, since they're large
+//! digits. By default, the type of a `Limb` is [`u64`], but you can set it to [`u32`] using the
+//! `32_bit_limbs` feature, or to [`u128`] using the `128_bit_limbs` feature.
 //!
 //! # Demos and benchmarks
 //! This crate comes with a `bin` target that can be used for running demos and benchmarks.
@@ -96,6 +99,45 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u32`] instead of the default,
 //!   [`u64`].
+//! - `128_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u128`] instead of the default,
+//!   [`u64`]. This can't be combined with `32_bit_limbs`. The thresholds used by this
+//!   configuration are provisional; they were derived from the 64-bit ones rather than tuned.
+//! - `parallel`: Uses [rayon](https://crates.io/crates/rayon) to run independent parts of very
+//!   large computations on multiple threads. This affects the row transforms and pointwise products
+//!   of FFT multiplication and squaring, the independent sub-products of Toom-Cook multiplication,
+//!   and the two halves of divide-and-conquer conversion to digits. The halves of a
+//!   divide-and-conquer quotient depend on each other, so they are computed one after the other,
+//!   but the product that is subtracted after each half is split across threads. The results are
+//!   identical to those computed without the feature.
+//! - `asm`: On x86_64 with 64-bit limbs, checks at runtime whether the CPU supports the ADX and
+//!   BMI2 instructions and, if it does, uses specialized versions of the basecase kernels for
+//!   addition, subtraction, multiplication by a limb, and multiply-accumulate by a limb. Everything
+//!   that is built on these kernels, including schoolbook and Toom-Cook multiplication, benefits.
+//!   On other targets, or on CPUs without these instructions, the portable kernels are used. The
+//!   results are identical either way.
+//! - `tuned_thresholds`: Replaces the default thresholds that choose between multiplication,
+//!   squaring, and division algorithms with ones measured on a particular machine. Generate them by
+//!   running
+//!   ```text
+//!   cargo run --release --features bin_build -- -g tune > thresholds.rs
+//!   ```
+//!   on the target machine, with the same limb width as the build that will use them. Then build
+//!   with this feature and with the `MALACHITE_NZ_THRESHOLDS` environment variable set to the
+//!   file's absolute path. Thresholds that the tuner doesn't measure, or for which it finds no
+//!   crossover, keep their default values. The results are identical either way; only the speed
+//!   changes.
+//! - `num-traits`: Implements the traits from the [num-traits](https://crates.io/crates/num-traits)
+//!   and [num-integer](https://crates.io/crates/num-integer) crates, such as `Num`, `Signed`,
+//!   `ToPrimitive`, `FromPrimitive`, `Integer`, and `Roots`, for [`Natural`](natural::Natural)s
+//!   and [`Integer`](integer::Integer)s, so that they can be used by code that is generic over
+//!   those traits. Some of these traits have methods with the same names as methods of
+//!   Malachite's traits, such as `gcd`, `abs`, and `pow`. If both traits are imported, calls to
+//!   these methods on [`Natural`](natural::Natural)s and [`Integer`](integer::Integer)s are
+//!   ambiguous and must be written in fully qualified form, like
+//!   `num_integer::Integer::gcd(&x, &y)`.
+//! - `num-bigint`: Implements conversions between [`Natural`](natural::Natural)s and
+//!   [`Integer`](integer::Integer)s and the `BigUint` and `BigInt` types from the
+//!   [num-bigint](https://crates.io/crates/num-bigint) crate, in both directions.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. Some of it is also used for testing `malachite-q`, so it can't
@@ -147,24 +189,67 @@ extern crate malachite_base;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "num-bigint")]
+extern crate num_bigint;
+#[cfg(feature = "num-traits")]
+extern crate num_integer;
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+
+#[cfg(feature = "parallel")]
+extern crate rayon;
 
 #[cfg(feature = "test_build")]
 extern crate num;
 #[cfg(feature = "test_build")]
 extern crate rug;
 
+#[cfg(all(feature = "32_bit_limbs", feature = "128_bit_limbs"))]
+compile_error!("The features `32_bit_limbs` and `128_bit_limbs` can't both be enabled.");
+
 #[doc(hidden)]
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(all(
+    not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")),
+    not(feature = "tuned_thresholds")
+))]
 pub use crate::platform_64 as platform;
 #[doc(hidden)]
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(all(
+    feature = "128_bit_limbs",
+    not(feature = "32_bit_limbs"),
+    not(feature = "tuned_thresholds")
+))]
+pub use platform_128 as platform;
+#[doc(hidden)]
+#[cfg(all(feature = "32_bit_limbs", not(feature = "tuned_thresholds")))]
 pub use platform_32 as platform;
 
+// The constants in the generated file shadow the glob-imported defaults of the same name.
+#[doc(hidden)]
+#[cfg(feature = "tuned_thresholds")]
+pub mod platform {
+    #[cfg(all(feature = "128_bit_limbs", not(feature = "32_bit_limbs")))]
+    pub use crate::platform_128::*;
+    #[cfg(feature = "32_bit_limbs")]
+    pub use crate::platform_32::*;
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+    pub use crate::platform_64::*;
+
+    include!(env!(
+        "MALACHITE_NZ_THRESHOLDS",
+        "The `tuned_thresholds` feature requires MALACHITE_NZ_THRESHOLDS to be set to the absolute \
+        path of a file generated by `malachite_nz_main -g tune`"
+    ));
+}
+
+#[doc(hidden)]
+#[cfg(all(feature = "128_bit_limbs", not(feature = "32_bit_limbs")))]
+pub mod platform_128;
 #[doc(hidden)]
 #[cfg(feature = "32_bit_limbs")]
 pub mod platform_32;
 #[doc(hidden)]
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 pub mod platform_64;
 
 #[cfg(feature = "doc-images")]
@@ -177,5 +262,11 @@ pub mod natural;
 /// values.
 pub mod integer;
 
+#[doc(hidden)]
+#[cfg(feature = "test_build")]
+pub mod parallel;
+#[cfg(not(feature = "test_build"))]
+pub(crate) mod parallel;
+
 #[cfg(feature = "test_build")]
 pub mod test_util;
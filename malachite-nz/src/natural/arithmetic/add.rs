@@ -1,10 +1,12 @@
 use crate::natural::arithmetic::shl::{limbs_shl, limbs_vec_shl_in_place};
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
-use crate::platform::Limb;
+use crate::platform::{Limb, INLINE_LIMB_COUNT};
 use malachite_base::num::arithmetic::traits::OverflowingAddAssign;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use std::cmp::max;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 
@@ -210,6 +212,17 @@ pub_crate_test! {limbs_add(xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
 //
 // This is equivalent to `mpn_add_n` from `gmp.h`, GMP 6.2.1.
 pub_crate_test! {limbs_add_same_length_to_out(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) -> bool {
+    x86_64_kernel!(limbs_add_same_length_to_out(out, xs, ys));
+    limbs_add_same_length_to_out_portable(out, xs, ys)
+}}
+
+// The portable implementation of `limbs_add_same_length_to_out`, which is used when no faster
+// kernel is available.
+pub_test! {limbs_add_same_length_to_out_portable(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+) -> bool {
     let len = xs.len();
     assert_eq!(len, ys.len());
     assert!(out.len() >= len);
@@ -325,6 +338,16 @@ pub_crate_test! {limbs_add_to_out_aliased(xs: &mut [Limb], xs_len: usize, ys: &[
 // This is equivalent to `mpn_add_n` from `gmp.h`, GMP 6.2.1, where the output is written to the
 // first input.
 pub_crate_test! {limbs_slice_add_same_length_in_place_left(xs: &mut [Limb], ys: &[Limb]) -> bool {
+    x86_64_kernel!(limbs_slice_add_same_length_in_place_left(xs, ys));
+    limbs_slice_add_same_length_in_place_left_portable(xs, ys)
+}}
+
+// The portable implementation of `limbs_slice_add_same_length_in_place_left`, which is used when no
+// faster kernel is available.
+pub_test! {limbs_slice_add_same_length_in_place_left_portable(
+    xs: &mut [Limb],
+    ys: &[Limb],
+) -> bool {
     let xs_len = xs.len();
     assert_eq!(xs_len, ys.len());
     let mut carry = false;
@@ -440,7 +463,8 @@ pub_test! {limbs_slice_add_in_place_either(xs: &mut [Limb], ys: &mut [Limb]) ->
 //
 // This is equivalent to `mpz_add` from `mpz/aors.h`, GMP 6.2.1, where both inputs are non-negative
 // and the output is written to the longer input.
-pub_test! {limbs_vec_add_in_place_either(xs: &mut Vec<Limb>, ys: &mut Vec<Limb>) -> bool {
+#[cfg(feature = "test_build")]
+pub fn limbs_vec_add_in_place_either(xs: &mut Vec<Limb>, ys: &mut Vec<Limb>) -> bool {
     if xs.len() >= ys.len() {
         if limbs_slice_add_greater_in_place_left(xs, ys) {
             xs.push(1);
@@ -452,7 +476,7 @@ pub_test! {limbs_vec_add_in_place_either(xs: &mut Vec<Limb>, ys: &mut Vec<Limb>)
         }
         true
     }
-}}
+}
 
 // Interpreting two equal-length slices of `Limb`s as the limbs (in ascending order) of two
 // `Natural`s, writes the `xs.len()` least-significant limbs of the sum of the `Natural`s and a
@@ -511,6 +535,67 @@ pub_crate_test! {limbs_add_same_length_with_carry_in_in_place_left(
     carry
 }}
 
+// Like `limbs_add`, but the sum is stored inline if it is short enough.
+fn limb_vec_add(xs: &[Limb], ys: &[Limb]) -> LimbVec {
+    let (xs, ys) = if xs.len() >= ys.len() {
+        (xs, ys)
+    } else {
+        (ys, xs)
+    };
+    if xs.len() < INLINE_LIMB_COUNT {
+        let mut out = LimbVec::zeroed(xs.len());
+        if limbs_add_greater_to_out(&mut out, xs, ys) {
+            out.push(1);
+        }
+        out
+    } else {
+        limbs_add_greater(xs, ys).into()
+    }
+}
+
+// Like `limbs_vec_add_limb_in_place`, but for a `LimbVec`, which only moves to the heap if the sum
+// doesn't fit inline.
+fn limb_vec_add_limb_in_place(xs: &mut LimbVec, y: Limb) {
+    if limbs_slice_add_limb_in_place(xs, y) {
+        xs.push(1);
+    }
+}
+
+// Like `limbs_vec_add_in_place_left`, but for a `LimbVec`, which only moves to the heap if the sum
+// doesn't fit inline.
+fn limb_vec_add_in_place_left(xs: &mut LimbVec, ys: &[Limb]) {
+    let xs_len = xs.len();
+    let carry = if xs_len >= ys.len() {
+        limbs_slice_add_greater_in_place_left(xs, ys)
+    } else {
+        let (ys_lo, ys_hi) = ys.split_at(xs_len);
+        let mut carry = limbs_slice_add_same_length_in_place_left(xs, ys_lo);
+        xs.extend_from_slice(ys_hi);
+        if carry {
+            carry = limbs_slice_add_limb_in_place(&mut xs[xs_len..], 1);
+        }
+        carry
+    };
+    if carry {
+        xs.push(1);
+    }
+}
+
+// Like `limbs_vec_add_in_place_either`, but for `LimbVec`s.
+fn limb_vec_add_in_place_either(xs: &mut LimbVec, ys: &mut LimbVec) -> bool {
+    if xs.len() >= ys.len() {
+        if limbs_slice_add_greater_in_place_left(xs, ys) {
+            xs.push(1);
+        }
+        false
+    } else {
+        if limbs_slice_add_greater_in_place_left(ys, xs) {
+            ys.push(1);
+        }
+        true
+    }
+}
+
 impl Natural {
     #[inline]
     pub(crate) fn add_limb(mut self, other: Limb) -> Natural {
@@ -523,9 +608,9 @@ impl Natural {
             (x, 0) => x.clone(),
             (Natural(Small(small)), other) => match small.overflowing_add(other) {
                 (sum, false) => Natural::from(sum),
-                (sum, true) => Natural(Large(vec![sum, 1])),
+                (sum, true) => Natural(Large(LimbVec::from_slice(&[sum, 1]))),
             },
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_add_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => Natural(Large(limb_vec_add(limbs, &[other]))),
         }
     }
 
@@ -536,18 +621,66 @@ impl Natural {
             (&mut Natural(Small(ref mut small)), other) => {
                 let (sum, overflow) = small.overflowing_add(other);
                 if overflow {
-                    *self = Natural(Large(vec![sum, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[sum, 1])));
                 } else {
                     *small = sum;
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
-                limbs_vec_add_limb_in_place(limbs, other);
+                limb_vec_add_limb_in_place(limbs, other);
             }
         }
     }
 }
 
+impl Natural {
+    /// Adds two [`Natural`]s, taking both by reference and writing the sum to an existing
+    /// [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $$
+    /// o \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(123u32).add_to(&Natural::from(456u32), &mut out);
+    /// assert_eq!(out, 579);
+    ///
+    /// Natural::from(10u32).pow(12).add_to(&(Natural::from(10u32).pow(12) << 1), &mut out);
+    /// assert_eq!(out, 3000000000000u64);
+    /// ```
+    pub fn add_to(&self, other: &Natural, out: &mut Natural) {
+        if let (&Natural(Small(x)), &Natural(Small(y))) = (self, other) {
+            if let (sum, false) = x.overflowing_add(y) {
+                out.set_limb(sum);
+                return;
+            }
+        }
+        let xs = self.limbs_slice();
+        let ys = other.limbs_slice();
+        let len = max(xs.len(), ys.len());
+        let out_limbs = out.limbs_for_overwrite(len + 1);
+        out_limbs[len] = Limb::from(limbs_add_to_out(out_limbs, xs, ys));
+        out.finish_overwrite(len + 1);
+    }
+}
+
 impl Add<Natural> for Natural {
     type Output = Natural;
 
@@ -696,7 +829,9 @@ impl<'a, 'b> Add<&'a Natural> for &'b Natural {
         match (self, other) {
             (x, &Natural(Small(y))) => x.add_limb_ref(y),
             (&Natural(Small(x)), y) => y.add_limb_ref(x),
-            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => Natural(Large(limbs_add(xs, ys))),
+            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
+                Natural(Large(limb_vec_add(xs, ys)))
+            }
         }
     }
 }
@@ -735,7 +870,7 @@ impl AddAssign<Natural> for Natural {
             (x, &mut Natural(Small(y))) => x.add_assign_limb(y),
             (&mut Natural(Small(x)), y) => *self = y.add_limb_ref(x),
             (&mut Natural(Large(ref mut xs)), &mut Natural(Large(ref mut ys))) => {
-                if limbs_vec_add_in_place_either(xs, ys) {
+                if limb_vec_add_in_place_either(xs, ys) {
                     *self = other;
                 }
             }
@@ -777,7 +912,7 @@ impl<'a> AddAssign<&'a Natural> for Natural {
             (x, &Natural(Small(y))) => x.add_assign_limb(y),
             (&mut Natural(Small(x)), y) => *self = y.add_limb_ref(x),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                limbs_vec_add_in_place_left(xs, ys);
+                limb_vec_add_in_place_left(xs, ys);
             }
         }
     }
@@ -7,8 +7,7 @@ use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
 use malachite_base::num::arithmetic::traits::{AddMul, AddMulAssign};
-use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::conversion::traits::{ExactFrom, SplitInHalf};
+use malachite_base::num::conversion::traits::SplitInHalf;
 use std::mem::swap;
 
 // Given the limbs of two `Natural`s x and y, and a limb `z`, returns the limbs of x + y * z. `xs`
@@ -55,17 +54,27 @@ pub_crate_test! {limbs_slice_add_mul_limb_same_length_in_place_left(
     xs: &mut [Limb],
     ys: &[Limb],
     z: Limb,
+) -> Limb {
+    x86_64_kernel!(limbs_slice_add_mul_limb_same_length_in_place_left(xs, ys, z));
+    limbs_slice_add_mul_limb_same_length_in_place_left_portable(xs, ys, z)
+}}
+
+// The portable implementation of `limbs_slice_add_mul_limb_same_length_in_place_left`, which is
+// used when no faster kernel is available.
+pub_test! {limbs_slice_add_mul_limb_same_length_in_place_left_portable(
+    xs: &mut [Limb],
+    ys: &[Limb],
+    z: Limb,
 ) -> Limb {
     let len = xs.len();
     assert_eq!(ys.len(), len);
     let mut carry = 0;
     let dz = DoubleLimb::from(z);
     for (x, &y) in xs.iter_mut().zip(ys.iter()) {
-        let out = DoubleLimb::from(*x) + DoubleLimb::from(y) * dz + carry;
-        *x = out.lower_half();
-        carry = out >> Limb::WIDTH;
+        let out = DoubleLimb::from(*x) + DoubleLimb::from(y) * dz + DoubleLimb::from(carry);
+        (carry, *x) = out.split_in_half();
     }
-    Limb::exact_from(carry)
+    carry
 }}
 
 // Given the limbs of two `Natural`s x and y, and a limb `z`, computes x + y * z. The lowest limbs
@@ -95,11 +104,10 @@ pub_test! {limbs_slice_add_mul_limb_same_length_in_place_right(
     let mut carry = 0;
     let dz = DoubleLimb::from(z);
     for (&x, y) in xs.iter().zip(ys.iter_mut()) {
-        let out = DoubleLimb::from(x) + DoubleLimb::from(*y) * dz + carry;
-        *y = out.lower_half();
-        carry = out >> Limb::WIDTH;
+        let out = DoubleLimb::from(x) + DoubleLimb::from(*y) * dz + DoubleLimb::from(carry);
+        (carry, *y) = out.split_in_half();
     }
-    Limb::exact_from(carry)
+    carry
 }}
 
 // Given the limbs of two `Natural`s a and b, and a limb c, writes the limbs of a + b * c to the
@@ -300,7 +308,7 @@ impl Natural {
             (x, y, 1) => x + y,
             (x, natural_one!(), z) => x + Natural::from(z),
             (Natural(Large(ref xs)), Natural(Large(ref ys)), z) => {
-                Natural(Large(limbs_add_mul_limb(xs, ys, z)))
+                Natural(Large(limbs_add_mul_limb(xs, ys, z).into()))
             }
             (x, y, z) => x + y * Natural::from(z),
         }
@@ -312,7 +320,7 @@ impl Natural {
             (x, _, 1) => *x += y,
             (x, natural_one!(), z) => *x += Natural::from(z),
             (Natural(Large(ref mut xs)), Natural(Large(ref mut ys)), z) => {
-                if limbs_vec_add_mul_limb_in_place_either(xs, ys, z) {
+                if limbs_vec_add_mul_limb_in_place_either(xs.vec_mut(), ys.vec_mut(), z) {
                     *self = y;
                 }
             }
@@ -326,7 +334,7 @@ impl Natural {
             (x, y, 1) => *x += y,
             (x, natural_one!(), z) => *x += Natural::from(z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), z) => {
-                limbs_vec_add_mul_limb_in_place_left(xs, ys, z);
+                limbs_vec_add_mul_limb_in_place_left(xs.vec_mut(), ys, z);
             }
             (x, y, z) => *x += y * Natural::from(z),
         }
@@ -506,7 +514,7 @@ impl<'a, 'b, 'c> AddMul<&'a Natural, &'b Natural> for &'c Natural {
             (x, Natural(Small(y)), z) => x.add_mul_limb_ref_ref(z, *y),
             (x, y, Natural(Small(z))) => x.add_mul_limb_ref_ref(y, *z),
             (Natural(Large(ref xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                Natural(Large(limbs_add_mul(xs, ys, zs)))
+                Natural(Large(limbs_add_mul(xs, ys, zs).into()))
             }
         }
     }
@@ -545,7 +553,7 @@ impl AddMulAssign<Natural, Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.vec_mut(), ys, zs)
             }
         }
     }
@@ -584,7 +592,7 @@ impl<'a> AddMulAssign<Natural, &'a Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb_ref(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.vec_mut(), ys, zs)
             }
         }
     }
@@ -623,7 +631,7 @@ impl<'a> AddMulAssign<&'a Natural, Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb_ref(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.vec_mut(), ys, zs)
             }
         }
     }
@@ -662,7 +670,7 @@ impl<'a, 'b> AddMulAssign<&'a Natural, &'b Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb_ref(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb_ref(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.vec_mut(), ys, zs)
             }
         }
     }
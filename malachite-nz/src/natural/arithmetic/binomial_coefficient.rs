@@ -24,9 +24,11 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+#[cfg(feature = "128_bit_limbs")]
+use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u128;
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
 use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
 use malachite_base::num::logic::traits::{CountOnes, LeadingZeros, SignificantBits};
@@ -323,13 +325,15 @@ pub_test! {
 limbs_binomial_coefficient_limb_limb_goetgheluck(n: Limb, k: Limb) -> Vec<Limb> {
     assert!(BIN_GOETGHELUCK_THRESHOLD >= 13);
     assert!(n >= 25);
-    let n_64 = u64::from(n);
-    let k_64 = u64::from(k);
+    let n_64 = u64::exact_from(n);
+    let k_64 = u64::exact_from(k);
     let mut sieve = vec![0; limbs_prime_sieve_size::<Limb>(n_64)];
     #[cfg(feature = "32_bit_limbs")]
     let count = limbs_prime_sieve_u32(&mut sieve, n_64) + 1;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let count = limbs_prime_sieve_u64(&mut sieve, n) + 1;
+    #[cfg(feature = "128_bit_limbs")]
+    let count = limbs_prime_sieve_u128(&mut sieve, n_64) + 1;
     let mut factors = vec![0; usize::exact_from(count / log_n_max(n) + 1)];
     let mut max_prod = Limb::MAX / n;
     // Handle primes = 2, 3 separately.
@@ -357,7 +361,7 @@ limbs_binomial_coefficient_limb_limb_goetgheluck(n: Limb, k: Limb) -> Vec<Limb>
         }
     }
     // Accumulate prime factors from 5 to n / 2
-    let s = n_to_bit(u64::from(limbs_approx_sqrt(n)));
+    let s = n_to_bit(u64::exact_from(limbs_approx_sqrt(n)));
     assert!(bit_to_n(s + 1).square() > n_64);
     let half_n_bit = n_to_bit(n_64 >> 1);
     assert!(s <= half_n_bit);
@@ -1,13 +1,34 @@
 use crate::natural::arithmetic::sub::{
     limbs_sub, limbs_sub_greater_in_place_left, limbs_sub_limb, limbs_sub_limb_in_place,
-    limbs_vec_sub_in_place_right,
+    limbs_sub_same_length_in_place_right,
 };
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
-use crate::platform::Limb;
+use crate::platform::{Limb, INLINE_LIMB_COUNT};
 use malachite_base::num::arithmetic::traits::CheckedSub;
 use malachite_base::num::basic::traits::Zero;
 
+// Like `limbs_vec_sub_in_place_right`, but for a `LimbVec`, which stays inline if the difference
+// fits.
+fn limb_vec_sub_in_place_right(xs: &[Limb], ys: &mut LimbVec) -> bool {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert!(xs_len >= ys_len);
+    let (xs_lo, xs_hi) = xs.split_at(ys_len);
+    let borrow = limbs_sub_same_length_in_place_right(xs_lo, ys);
+    if xs_len == ys_len {
+        borrow
+    } else {
+        ys.extend_from_slice(xs_hi);
+        if borrow {
+            limbs_sub_limb_in_place(&mut ys[ys_len..], 1)
+        } else {
+            false
+        }
+    }
+}
+
 impl Natural {
     pub(crate) fn checked_sub_limb(mut self, other: Limb) -> Option<Natural> {
         if self.sub_assign_limb_no_panic(other) {
@@ -24,6 +45,10 @@ impl Natural {
             (Natural(Large(ref limbs)), other) => {
                 if *self < other {
                     None
+                } else if limbs.len() <= INLINE_LIMB_COUNT {
+                    let mut diff = self.clone();
+                    diff.sub_assign_limb_no_panic(other);
+                    Some(diff)
                 } else {
                     Some(Natural::from_owned_limbs_asc(
                         limbs_sub_limb(limbs, other).0,
@@ -107,7 +132,7 @@ impl Natural {
             }),
             (_, Natural(Small(_))) => true,
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                let borrow = xs.len() > ys.len() || limbs_vec_sub_in_place_right(ys, xs);
+                let borrow = xs.len() > ys.len() || limb_vec_sub_in_place_right(ys, xs);
                 if !borrow {
                     self.trim();
                 }
@@ -311,6 +336,10 @@ impl<'a, 'b> CheckedSub<&'a Natural> for &'b Natural {
             (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
                 if self < other {
                     None
+                } else if xs.len() <= INLINE_LIMB_COUNT {
+                    let mut diff = self.clone();
+                    diff.sub_assign_ref_no_panic(other);
+                    Some(diff)
                 } else {
                     Some(Natural::from_owned_limbs_asc(limbs_sub(xs, ys).0))
                 }
@@ -6,14 +6,14 @@ use crate::natural::arithmetic::div_mod::{
     div_mod_by_preinversion, limbs_div_barrett_large_product, limbs_div_mod_by_two_limb_normalized,
     limbs_div_mod_divide_and_conquer_helper, limbs_div_mod_schoolbook,
     limbs_div_mod_three_limb_by_two_limb, limbs_invert_approx, limbs_invert_approx_scratch_len,
-    limbs_invert_limb, limbs_two_limb_inverse_helper, MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD,
-    MUPI_DIV_QR_THRESHOLD,
+    limbs_invert_limb, limbs_two_limb_inverse_helper, MUPI_DIV_QR_THRESHOLD,
 };
 use crate::natural::arithmetic::mul::mul_mod::{
     limbs_mul_mod_base_pow_n_minus_1_next_size, limbs_mul_mod_base_pow_n_minus_1_scratch_len,
 };
 use crate::natural::arithmetic::mul::{
-    limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_maybe_threaded,
+    limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
     limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
 };
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
@@ -24,10 +24,13 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::use_threads;
 use crate::platform::{
-    DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, FUDGE, MU_DIVAPPR_Q_THRESHOLD,
+    DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, FUDGE, INLINE_LIMB_COUNT,
+    MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD, MU_DIVAPPR_Q_THRESHOLD,
 };
 use malachite_base::fail_on_untested_path;
 #[cfg(feature = "test_build")]
@@ -886,8 +889,7 @@ fn limbs_div_divide_and_conquer_approx_helper(
     } else {
         limbs_div_mod_divide_and_conquer_helper(qs_hi, ns_hi, ds_hi, d_inv, scratch)
     };
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(hi, ds_lo.len())];
-    limbs_mul_greater_to_out(scratch, &qs_hi[..hi], ds_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, &qs_hi[..hi], ds_lo, use_threads(d_len));
     let ns_lo = &mut ns[..d_len];
     let mut carry = Limb::from(limbs_sub_same_length_in_place_left(
         ns_lo,
@@ -1722,11 +1724,12 @@ pub_test! {limbs_div_to_out_balanced(qs: &mut [Limb], ns: &[Limb], ds: &[Limb])
 //
 // This is equivalent to `mpn_div_q` from `mpn/generic/div_q.c`, GMP 6.2.1, where `scratch` is
 // allocated internally and `qp` is returned.
-pub_test! {limbs_div(ns: &[Limb], ds: &[Limb]) -> Vec<Limb> {
+#[cfg(feature = "test_build")]
+pub fn limbs_div(ns: &[Limb], ds: &[Limb]) -> Vec<Limb> {
     let mut qs = vec![0; ns.len() - ds.len() + 1];
     limbs_div_to_out_ref_ref(&mut qs, ns, ds);
     qs
-}}
+}
 
 // Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
 // `Natural`s, divides them, writing the `ns.len() - ds.len() + 1` limbs of the quotient to `qs`.
@@ -1932,7 +1935,13 @@ impl Natural {
             (n, 1) => n.clone(),
             (Natural(Small(small)), other) => Natural(Small(small / other)),
             (Natural(Large(ref limbs)), other) => {
-                Natural::from_owned_limbs_asc(limbs_div_limb(limbs, other))
+                if limbs.len() <= INLINE_LIMB_COUNT {
+                    let mut q = self.clone();
+                    q.div_assign_limb(other);
+                    q
+                } else {
+                    Natural::from_owned_limbs_asc(limbs_div_limb(limbs, other))
+                }
             }
         }
     }
@@ -2102,9 +2111,9 @@ impl<'a> Div<Natural> for &'a Natural {
                 if ns_len < ds_len {
                     Natural::ZERO
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_ref_val(&mut qs, ns, ds);
-                    Natural::from_owned_limbs_asc(qs)
+                    Natural::from_limb_vec_asc(qs)
                 }
             }
         }
@@ -2157,7 +2166,9 @@ impl<'a, 'b> Div<&'b Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     Natural::ZERO
                 } else {
-                    Natural::from_owned_limbs_asc(limbs_div(ns, ds))
+                    let mut qs = LimbVec::zeroed(ns.len() - ds.len() + 1);
+                    limbs_div_to_out_ref_ref(&mut qs, ns, ds);
+                    Natural::from_limb_vec_asc(qs)
                 }
             }
         }
@@ -2210,7 +2221,7 @@ impl DivAssign<Natural> for Natural {
                 if ns_len < ds_len {
                     *self = Natural::ZERO;
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out(&mut qs, ns, ds);
                     swap(&mut qs, ns);
                     self.trim();
@@ -2266,7 +2277,7 @@ impl<'a> DivAssign<&'a Natural> for Natural {
                 if ns_len < ds_len {
                     *self = Natural::ZERO;
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_val_ref(&mut qs, ns, ds);
                     swap(&mut qs, ns);
                     self.trim();
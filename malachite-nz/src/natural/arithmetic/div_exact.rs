@@ -8,15 +8,14 @@ use crate::natural::arithmetic::div::{
     limbs_div_divisor_of_limb_max_with_carry_in_place,
     limbs_div_divisor_of_limb_max_with_carry_to_out,
 };
-use crate::natural::arithmetic::div_mod::MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD;
 use crate::natural::arithmetic::mul::mul_low::limbs_mul_low_same_length;
 use crate::natural::arithmetic::mul::mul_mod::{
     limbs_mul_mod_base_pow_n_minus_1, limbs_mul_mod_base_pow_n_minus_1_next_size,
     limbs_mul_mod_base_pow_n_minus_1_scratch_len,
 };
 use crate::natural::arithmetic::mul::{
-    limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len, limbs_mul_to_out,
-    limbs_mul_to_out_scratch_len,
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_maybe_threaded,
+    limbs_mul_greater_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
 };
 use crate::natural::arithmetic::neg::limbs_neg_in_place;
 use crate::natural::arithmetic::shr::{limbs_shr_to_out, limbs_slice_shr_in_place};
@@ -27,11 +26,13 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::use_threads;
 use crate::platform::{
     DoubleLimb, Limb, BINV_NEWTON_THRESHOLD, DC_BDIV_QR_THRESHOLD, DC_BDIV_Q_THRESHOLD,
-    MU_BDIV_Q_THRESHOLD,
+    MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD, MU_BDIV_Q_THRESHOLD,
 };
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
@@ -97,6 +98,9 @@ pub_crate_test! {limbs_modular_invert_limb(x: Limb) -> Limb {
     if !cfg!(feature = "32_bit_limbs") {
         inv = (inv << 1).wrapping_sub(inv.wrapping_mul(inv).wrapping_mul(x));
     }
+    if cfg!(feature = "128_bit_limbs") {
+        inv = (inv << 1).wrapping_sub(inv.wrapping_mul(inv).wrapping_mul(x));
+    }
     inv
 }}
 
@@ -598,11 +602,11 @@ fn limbs_modular_div_mod_divide_and_conquer_helper(
     let scratch = &mut scratch[..n];
     let lo = n >> 1; // floor(n / 2)
     let hi = n - lo; // ceil(n / 2)
+    let threaded = use_threads(n);
     let (ds_lo, ds_hi) = ds.split_at(lo);
     let carry = limbs_modular_div_mod_helper(qs, ns, lo, ds_lo, d_inv, scratch);
     let (qs_lo, qs_hi) = qs.split_at_mut(lo);
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(ds_hi.len(), qs_lo.len())];
-    limbs_mul_greater_to_out(scratch, ds_hi, qs_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, ds_hi, qs_lo, threaded);
     if carry {
         assert!(!limbs_slice_add_limb_in_place(&mut scratch[lo..], 1));
     }
@@ -610,8 +614,7 @@ fn limbs_modular_div_mod_divide_and_conquer_helper(
     let highest_r = limbs_sub_greater_in_place_left(ns, scratch);
     let (ds_lo, ds_hi) = ds.split_at(hi);
     let carry = limbs_modular_div_mod_helper(qs_hi, ns, hi, ds_lo, d_inv, scratch);
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(hi, ds_hi.len())];
-    limbs_mul_greater_to_out(scratch, &qs_hi[..hi], ds_hi, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, &qs_hi[..hi], ds_hi, threaded);
     if carry {
         assert!(!limbs_slice_add_limb_in_place(&mut scratch[hi..], 1));
     }
@@ -2128,7 +2131,7 @@ impl DivExactAssign<Natural> for Natural {
                 if ns_len < ds_len {
                     panic!("division not exact");
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_exact_to_out(&mut qs, ns, ds);
                     swap(&mut qs, ns);
                     self.trim();
@@ -2194,7 +2197,7 @@ impl<'a> DivExactAssign<&'a Natural> for Natural {
                 if ns_len < ds_len {
                     panic!("division not exact");
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_exact_to_out_val_ref(&mut qs, ns, ds);
                     swap(&mut qs, ns);
                     self.trim();
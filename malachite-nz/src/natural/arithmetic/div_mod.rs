@@ -11,7 +11,8 @@ use crate::natural::arithmetic::mul::mul_mod::{
     limbs_mul_mod_base_pow_n_minus_1_scratch_len,
 };
 use crate::natural::arithmetic::mul::{
-    limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_maybe_threaded,
+    limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
     limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
 };
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
@@ -25,12 +26,15 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::logic::not::limbs_not_to_out;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::use_threads;
 use crate::platform::{
-    DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, INV_MULMOD_BNM1_THRESHOLD,
-    INV_NEWTON_THRESHOLD, MAYBE_DCP1_DIVAPPR, MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
+    DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, INLINE_LIMB_COUNT,
+    INV_MULMOD_BNM1_THRESHOLD, INV_NEWTON_THRESHOLD, MAYBE_DCP1_DIVAPPR,
+    MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD, MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
 };
 use malachite_base::num::arithmetic::traits::{
     CeilingDivAssignNegMod, CeilingDivNegMod, DivAssignMod, DivAssignRem, DivMod, DivRem,
@@ -617,6 +621,7 @@ pub(crate) fn limbs_div_mod_divide_and_conquer_helper(
     let n = ds.len();
     let lo = n >> 1; // floor(n / 2)
     let hi = n - lo; // ceil(n / 2)
+    let threaded = use_threads(n);
     let qs_hi = &mut qs[lo..];
     let (ds_lo, ds_hi) = ds.split_at(lo);
     let mut highest_q = if hi < DC_DIV_QR_THRESHOLD {
@@ -625,8 +630,7 @@ pub(crate) fn limbs_div_mod_divide_and_conquer_helper(
         limbs_div_mod_divide_and_conquer_helper(qs_hi, &mut ns[lo << 1..], ds_hi, d_inv, scratch)
     };
     let qs_hi = &mut qs_hi[..hi];
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(qs_hi.len(), ds_lo.len())];
-    limbs_mul_greater_to_out(scratch, qs_hi, ds_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, qs_hi, ds_lo, threaded);
     let ns_lo = &mut ns[..n + lo];
     let mut carry = Limb::from(limbs_sub_same_length_in_place_left(
         &mut ns_lo[lo..],
@@ -652,8 +656,7 @@ pub(crate) fn limbs_div_mod_divide_and_conquer_helper(
     };
     let qs_lo = &mut qs[..lo];
     let ns_lo = &mut ns[..n];
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(ds_lo.len(), lo)];
-    limbs_mul_greater_to_out(scratch, ds_lo, qs_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, ds_lo, qs_lo, threaded);
     let mut carry = Limb::from(limbs_sub_same_length_in_place_left(ns_lo, &scratch[..n]));
     if q_lo && limbs_sub_same_length_in_place_left(&mut ns_lo[lo..], ds_lo) {
         carry += 1;
@@ -1130,9 +1133,6 @@ pub_crate_test! {limbs_invert_approx(is: &mut [Limb], ds: &[Limb], scratch: &mut
     }
 }}
 
-//TODO tune
-pub(crate) const MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD: usize = INV_MULMOD_BNM1_THRESHOLD >> 1;
-
 // # Worst-case complexity
 // $T(n) = O(n \log n \log\log n)$
 //
@@ -1795,13 +1795,23 @@ pub(crate) fn limbs_div_mod_balanced(
 //
 // This is equivalent to `mpn_tdiv_qr` from `mpn/generic/tdiv_qr.c`, GMP 6.2.1, where `dn > 1` and
 // `qp` and `rp` are returned.
-pub_test! {limbs_div_mod(ns: &[Limb], ds: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+#[cfg(feature = "test_build")]
+pub fn limbs_div_mod(ns: &[Limb], ds: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
     let d_len = ds.len();
     let mut qs = vec![0; ns.len() - d_len + 1];
     let mut rs = vec![0; d_len];
     limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
     (qs, rs)
-}}
+}
+
+// Like `limbs_div_mod`, but the quotient and remainder are stored inline if they are short enough.
+fn limb_vec_div_mod(ns: &[Limb], ds: &[Limb]) -> (LimbVec, LimbVec) {
+    let d_len = ds.len();
+    let mut qs = LimbVec::zeroed(ns.len() - d_len + 1);
+    let mut rs = LimbVec::zeroed(d_len);
+    limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
+    (qs, rs)
+}
 
 // Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
 // `Natural`s, divides them, writing the `ns.len() - ds.len() + 1` limbs of the quotient to `qs`
@@ -1869,8 +1879,14 @@ impl Natural {
                 (Natural(Small(q)), r)
             }
             (Natural(Large(ref limbs)), other) => {
-                let (qs, r) = limbs_div_limb_mod(limbs, other);
-                (Natural::from_owned_limbs_asc(qs), r)
+                if limbs.len() <= INLINE_LIMB_COUNT {
+                    let mut q = self.clone();
+                    let r = q.div_assign_mod_limb(other);
+                    (q, r)
+                } else {
+                    let (qs, r) = limbs_div_limb_mod(limbs, other);
+                    (Natural::from_owned_limbs_asc(qs), r)
+                }
             }
         }
     }
@@ -2050,10 +2066,10 @@ impl<'a> DivMod<Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     (Natural::ZERO, self.clone())
                 } else {
-                    let (qs, mut rs) = limbs_div_mod(ns, ds);
+                    let (qs, mut rs) = limb_vec_div_mod(ns, ds);
                     swap(&mut rs, ds);
                     other.trim();
-                    (Natural::from_owned_limbs_asc(qs), other)
+                    (Natural::from_limb_vec_asc(qs), other)
                 }
             }
         }
@@ -2120,10 +2136,10 @@ impl<'a, 'b> DivMod<&'b Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     (Natural::ZERO, self.clone())
                 } else {
-                    let (qs, rs) = limbs_div_mod(ns, ds);
+                    let (qs, rs) = limb_vec_div_mod(ns, ds);
                     (
-                        Natural::from_owned_limbs_asc(qs),
-                        Natural::from_owned_limbs_asc(rs),
+                        Natural::from_limb_vec_asc(qs),
+                        Natural::from_limb_vec_asc(rs),
                     )
                 }
             }
@@ -2131,6 +2147,85 @@ impl<'a, 'b> DivMod<&'b Natural> for &'a Natural {
     }
 }
 
+impl Natural {
+    /// Divides a [`Natural`] by another [`Natural`], taking both by reference and writing the
+    /// quotient and remainder to existing [`Natural`]s. The quotient is rounded towards negative
+    /// infinity.
+    ///
+    /// If `q` or `r` already have buffers, they are reused, so apart from scratch space for large
+    /// inputs, this doesn't allocate unless the buffers need to grow.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq r < y$.
+    ///
+    /// $$
+    /// (q, r) \gets \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let mut q = Natural::ZERO;
+    /// let mut r = Natural::ZERO;
+    /// // 2 * 10 + 3 = 23
+    /// Natural::from(23u32).div_mod_to(&Natural::from(10u32), &mut q, &mut r);
+    /// assert_eq!(q, 2);
+    /// assert_eq!(r, 3);
+    ///
+    /// // 810000006723 * 1234567890987 + 530068894399 = 1000000000000000000000000
+    /// Natural::from_str("1000000000000000000000000")
+    ///     .unwrap()
+    ///     .div_mod_to(&Natural::from_str("1234567890987").unwrap(), &mut q, &mut r);
+    /// assert_eq!(q, 810000006723u64);
+    /// assert_eq!(r, 530068894399u64);
+    /// ```
+    pub fn div_mod_to(&self, other: &Natural, q: &mut Natural, r: &mut Natural) {
+        match (self, other) {
+            (_, &natural_zero!()) => panic!("division by zero"),
+            (&Natural(Small(n)), &Natural(Small(d))) => {
+                let (q_limb, r_limb) = n.div_mod(d);
+                q.set_limb(q_limb);
+                r.set_limb(r_limb);
+            }
+            (&Natural(Small(_)), _) => {
+                q.set_limb(0);
+                r.clone_from(self);
+            }
+            (&Natural(Large(ref ns)), &Natural(Small(d))) => {
+                let qs = q.limbs_for_overwrite(ns.len());
+                let r_limb = limbs_div_limb_to_out_mod(qs, ns, d);
+                q.finish_overwrite(ns.len());
+                r.set_limb(r_limb);
+            }
+            (&Natural(Large(ref ns)), &Natural(Large(ref ds))) => {
+                if ns.len() < ds.len() {
+                    q.set_limb(0);
+                    r.clone_from(self);
+                } else {
+                    let qs = q.limbs_for_overwrite(ns.len() - ds.len() + 1);
+                    let rs = r.limbs_for_overwrite(ds.len());
+                    limbs_div_mod_to_out(qs, rs, ns, ds);
+                    q.finish_overwrite(ns.len() - ds.len() + 1);
+                    r.finish_overwrite(ds.len());
+                }
+            }
+        }
+    }
+}
+
 impl DivAssignMod<Natural> for Natural {
     type ModOutput = Natural;
 
@@ -2193,7 +2288,7 @@ impl DivAssignMod<Natural> for Natural {
                     swap(self, &mut r);
                     r
                 } else {
-                    let (mut qs, mut rs) = limbs_div_mod(ns, ds);
+                    let (mut qs, mut rs) = limb_vec_div_mod(ns, ds);
                     swap(&mut qs, ns);
                     swap(&mut rs, ds);
                     self.trim();
@@ -2270,10 +2365,10 @@ impl<'a> DivAssignMod<&'a Natural> for Natural {
                     swap(self, &mut r);
                     r
                 } else {
-                    let (mut qs, rs) = limbs_div_mod(ns, ds);
+                    let (mut qs, rs) = limb_vec_div_mod(ns, ds);
                     swap(&mut qs, ns);
                     self.trim();
-                    Natural::from_owned_limbs_asc(rs)
+                    Natural::from_limb_vec_asc(rs)
                 }
             }
         }
@@ -17,9 +17,11 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, WrappingFrom};
+#[cfg(feature = "128_bit_limbs")]
+use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u128;
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
 use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
 use malachite_base::num::logic::traits::{BitAccess, CountOnes, NotAssign, SignificantBits};
@@ -295,8 +297,10 @@ pub_crate_test! { limbs_odd_factorial(n: usize, double: bool) -> Vec<Limb> {
             let sieve_offset = (size >> 1) + 1;
             #[cfg(feature = "32_bit_limbs")]
             let count = limbs_prime_sieve_u32(&mut swing_and_sieve[sieve_offset..], n_m_1);
-            #[cfg(not(feature = "32_bit_limbs"))]
+            #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
             let count = limbs_prime_sieve_u64(&mut swing_and_sieve[sieve_offset..], n_m_1);
+            #[cfg(feature = "128_bit_limbs")]
+            let count = limbs_prime_sieve_u128(&mut swing_and_sieve[sieve_offset..], n_m_1);
             size = usize::exact_from(
                 (count + 1)
                     / log_n_max(Limb::exact_from(n))
@@ -352,8 +356,10 @@ const FAC_ODD_THRESHOLD: Limb = 24;
 
 #[cfg(feature = "32_bit_limbs")]
 const SMALL_FACTORIAL_LIMIT: u64 = 13;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 const SMALL_FACTORIAL_LIMIT: u64 = 21;
+#[cfg(feature = "128_bit_limbs")]
+const SMALL_FACTORIAL_LIMIT: u64 = 35;
 
 impl Factorial for Natural {
     /// Computes the factorial of a number.
@@ -395,7 +401,7 @@ impl Factorial for Natural {
         assert!(Limb::convertible_from(n));
         if n < SMALL_FACTORIAL_LIMIT {
             Natural::from(Limb::factorial(n))
-        } else if n < u64::from(FAC_ODD_THRESHOLD) {
+        } else if n < u64::exact_from(FAC_ODD_THRESHOLD) {
             let mut factors =
                 vec![0; usize::wrapping_from(n - SMALL_FACTORIAL_LIMIT) / FACTORS_PER_LIMB + 2];
             factors[0] = Limb::factorial(SMALL_FACTORIAL_LIMIT - 1);
@@ -1,3 +1,19 @@
+// Returns the result of the x86_64 kernel named `$f` from the enclosing function, if the `asm`
+// feature is enabled, the target is x86_64 with 64-bit limbs, and the CPU supports the kernel.
+// Otherwise, does nothing, so that the portable code that follows runs.
+macro_rules! x86_64_kernel {
+    ($f: ident($($arg: expr),*)) => {
+        #[cfg(all(
+            feature = "asm",
+            target_arch = "x86_64",
+            not(any(feature = "32_bit_limbs", feature = "128_bit_limbs"))
+        ))]
+        if let Some(result) = crate::natural::arithmetic::x86_64::$f($($arg),*) {
+            return result;
+        }
+    };
+}
+
 /// Addition of [`Natural`](super::Natural)s.
 pub mod add;
 /// Implementations of [`AddMul`](malachite_base::num::arithmetic::traits::AddMul) and
@@ -916,3 +932,10 @@ pub mod sub;
 /// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 pub mod sub_mul;
+/// Basecase kernels for x86_64 that use the ADX and BMI2 instructions when the CPU supports them.
+#[cfg(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    not(any(feature = "32_bit_limbs", feature = "128_bit_limbs"))
+))]
+pub mod x86_64;
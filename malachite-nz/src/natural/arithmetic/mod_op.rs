@@ -6,12 +6,12 @@ use crate::natural::arithmetic::div_mod::{
     limbs_div_mod_barrett_is_len, limbs_div_mod_barrett_scratch_len,
     limbs_div_mod_by_two_limb_normalized, limbs_div_mod_divide_and_conquer_helper,
     limbs_div_mod_schoolbook, limbs_div_mod_three_limb_by_two_limb, limbs_invert_approx,
-    limbs_invert_limb, limbs_two_limb_inverse_helper, MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD,
-    MUPI_DIV_QR_THRESHOLD,
+    limbs_invert_limb, limbs_two_limb_inverse_helper, MUPI_DIV_QR_THRESHOLD,
 };
 use crate::natural::arithmetic::mul::mul_mod::limbs_mul_mod_base_pow_n_minus_1_next_size;
 use crate::natural::arithmetic::mul::{
-    limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_maybe_threaded,
+    limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
     limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
 };
 use crate::natural::arithmetic::shl::limbs_shl_to_out;
@@ -24,13 +24,15 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::use_threads;
 use crate::platform::{
     DoubleLimb, Limb, DC_DIV_QR_THRESHOLD, MOD_1N_TO_MOD_1_1_THRESHOLD,
     MOD_1U_TO_MOD_1_1_THRESHOLD, MOD_1_1P_METHOD, MOD_1_1_TO_MOD_1_2_THRESHOLD,
     MOD_1_2_TO_MOD_1_4_THRESHOLD, MOD_1_NORM_THRESHOLD, MOD_1_UNNORM_THRESHOLD,
-    MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
+    MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD, MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
 };
 use malachite_base::num::arithmetic::traits::{
     Mod, ModAssign, ModPowerOf2, NegMod, NegModAssign, OverflowingAddAssign, Parity, PowerOf2,
@@ -260,6 +262,7 @@ fn limbs_mod_divide_and_conquer_helper(
     let n = ds.len();
     let lo = n >> 1; // floor(n / 2)
     let hi = n - lo; // ceil(n / 2)
+    let threaded = use_threads(n);
     let qs_hi = &mut qs[lo..];
     let (ds_lo, ds_hi) = ds.split_at(lo);
     let highest_q = if hi < DC_DIV_QR_THRESHOLD {
@@ -268,8 +271,7 @@ fn limbs_mod_divide_and_conquer_helper(
         limbs_div_mod_divide_and_conquer_helper(qs_hi, &mut ns[lo << 1..], ds_hi, d_inv, scratch)
     };
     let qs_hi = &mut qs_hi[..hi];
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(qs_hi.len(), ds_lo.len())];
-    limbs_mul_greater_to_out(scratch, qs_hi, ds_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, qs_hi, ds_lo, threaded);
     let ns_lo = &mut ns[..n + lo];
     let mut carry = Limb::from(limbs_sub_same_length_in_place_left(
         &mut ns_lo[lo..],
@@ -292,8 +294,7 @@ fn limbs_mod_divide_and_conquer_helper(
     };
     let qs_lo = &mut qs[..lo];
     let ns_lo = &mut ns[..n];
-    let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(ds_lo.len(), lo)];
-    limbs_mul_greater_to_out(scratch, ds_lo, qs_lo, &mut mul_scratch);
+    limbs_mul_greater_to_out_maybe_threaded(scratch, ds_lo, qs_lo, threaded);
     let mut carry = Limb::from(limbs_sub_same_length_in_place_left(ns_lo, &scratch[..n]));
     if q_lo && limbs_sub_same_length_in_place_left(&mut ns_lo[lo..], ds_lo) {
         carry += 1;
@@ -2032,7 +2033,7 @@ impl<'a> RemAssign<&'a Natural> for Natural {
             (Natural(Small(_)), _) => {}
             (&mut Natural(Large(ref mut ns)), Natural(Large(ref ds))) => {
                 if ns.len() >= ds.len() {
-                    let mut rs = vec![0; ds.len()];
+                    let mut rs = LimbVec::zeroed(ds.len());
                     limbs_mod_to_out(&mut rs, ns, ds);
                     swap(&mut rs, ns);
                     self.trim();
@@ -2,6 +2,7 @@ use crate::natural::arithmetic::add::{
     limbs_add_limb, limbs_slice_add_greater_in_place_left, limbs_slice_add_limb_in_place,
     limbs_slice_add_same_length_in_place_left, limbs_vec_add_in_place_left,
 };
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::logic::bit_access::limbs_clear_bit;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
@@ -226,7 +227,7 @@ impl Natural {
             (&Natural(Small(small)), other, _) => {
                 let (sum, overflow) = small.overflowing_add(other);
                 if overflow {
-                    Natural(Large(vec![sum, 1]))
+                    Natural(Large(LimbVec::from_slice(&[sum, 1])))
                 } else {
                     Natural(Small(sum))
                 }
@@ -247,13 +248,13 @@ impl Natural {
             (&mut Natural(Small(ref mut small)), other, _) => {
                 let (sum, overflow) = small.overflowing_add(other);
                 if overflow {
-                    *self = Natural(Large(vec![sum, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[sum, 1])));
                 } else {
                     *small = sum;
                 }
             }
             (&mut Natural(Large(ref mut limbs)), y, pow) => {
-                limbs_vec_mod_power_of_2_add_limb_in_place(limbs, y, pow);
+                limbs_vec_mod_power_of_2_add_limb_in_place(limbs.vec_mut(), y, pow);
                 self.trim();
             }
         }
@@ -424,7 +425,7 @@ impl ModPowerOf2AddAssign<Natural> for Natural {
             (&mut Natural(Small(x)), y) => *self = y.mod_power_of_2_add_limb_ref(x, pow),
             (&mut Natural(Large(ref mut xs)), _) => {
                 if let Natural(Large(mut ys)) = other {
-                    if limbs_mod_power_of_2_add_in_place_either(xs, &mut ys, pow) {
+                    if limbs_mod_power_of_2_add_in_place_either(xs.vec_mut(), ys.vec_mut(), pow) {
                         *xs = ys;
                     }
                     self.trim();
@@ -469,7 +470,7 @@ impl<'a> ModPowerOf2AddAssign<&'a Natural> for Natural {
             (x, &Natural(Small(y))) => x.mod_power_of_2_add_assign_limb(y, pow),
             (&mut Natural(Small(x)), y) => *self = y.mod_power_of_2_add_limb_ref(x, pow),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                limbs_vec_mod_power_of_2_add_in_place_left(xs, ys, pow);
+                limbs_vec_mod_power_of_2_add_in_place_left(xs.vec_mut(), ys, pow);
                 self.trim();
             }
         }
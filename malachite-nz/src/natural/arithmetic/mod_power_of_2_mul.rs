@@ -363,7 +363,7 @@ impl ModPowerOf2MulAssign<Natural> for Natural {
                 *self = other;
             }
             (&mut Natural(Large(ref mut xs)), &mut Natural(Large(ref mut ys))) => {
-                *xs = limbs_mod_power_of_2_mul(xs, ys, pow);
+                *xs = limbs_mod_power_of_2_mul(xs.vec_mut(), ys.vec_mut(), pow).into();
                 self.trim();
             }
         }
@@ -403,7 +403,7 @@ impl<'a> ModPowerOf2MulAssign<&'a Natural> for Natural {
                 *self = y.mod_power_of_2_mul_limb_ref(x, pow);
             }
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                *xs = limbs_mod_power_of_2_mul_val_ref(xs, ys, pow);
+                *xs = limbs_mod_power_of_2_mul_val_ref(xs.vec_mut(), ys, pow).into();
                 self.trim();
             }
         }
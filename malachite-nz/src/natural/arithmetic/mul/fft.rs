@@ -13,6 +13,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left,
     limbs_sub_same_length_in_place_right, limbs_sub_same_length_to_out,
 };
+use crate::parallel::{for_each_init, join, use_threads};
 use crate::platform::{Limb, SignedLimb, FFT_TAB, MULMOD_TAB};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
@@ -2065,6 +2066,216 @@ fn limbs_ifft_mfa_truncate_sqrt_outer<'a>(
     }
 }
 
+// Splits `xs` into coefficients of `bits` bits each, stores them in `xss`, and applies the forward
+// transform of the matrix Fourier algorithm. Returns the number of coefficients that `xs` was split
+// into.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_split_bits_and_mfa_truncate_sqrt_outer<'a>(
+    xss: &mut [&'a mut [Limb]],
+    xs: &[Limb],
+    bits: usize,
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    ss: &mut &'a mut [Limb],
+    sqrt: usize,
+    trunc: usize,
+) -> usize {
+    let j = limbs_fft_split_bits(xss, xs, bits);
+    for ps in &mut xss[j..] {
+        slice_set_zero(ps);
+    }
+    limbs_fft_mfa_truncate_sqrt_outer(xss, w, ts, us, ss, sqrt, trunc);
+    j
+}
+
+// Returns, for each row of `sqrt` coefficients, whether the row takes part in the row
+// convolutions of the matrix Fourier algorithm. These are the rows in the lower half, and the rows
+// in the upper half that are needed for a truncation length of `trunc`.
+fn limbs_fft_mfa_row_mask(four_n: usize, sqrt: usize, trunc: usize) -> Vec<bool> {
+    let two_n = four_n >> 1;
+    let len = two_n / sqrt;
+    let depth = len.ceiling_log_base_2();
+    let mut mask = vec![false; four_n / sqrt];
+    mask[..len].fill(true);
+    for s in 0..(trunc - two_n) / sqrt {
+        mask[len + n_revbin(s, depth)] = true;
+    }
+    mask
+}
+
+// Performs the row convolutions of the matrix Fourier algorithm for a multiplication, possibly on
+// several threads. Each row of `xss` and `yss` selected by `limbs_fft_mfa_row_mask` is
+// transformed, multiplied pointwise using `pointwise`, and transformed back. Every row gets its
+// own pair of temporary coefficients from `temps`, and every thread gets its own scratch slice of
+// length `scratch_len`, so the rows don't interfere with each other.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_convolve_rows_threaded<'a, F>(
+    xss: &mut [&'a mut [Limb]],
+    yss: &mut [&'a mut [Limb]],
+    mut temps: &'a mut [Limb],
+    sqrt: usize,
+    trunc: usize,
+    w: usize,
+    scratch_len: usize,
+    pointwise: F,
+) where
+    F: Fn(&mut [Limb], &mut [Limb], &mut [Limb]) + Send + Sync,
+{
+    let size = xss[0].len();
+    let mask = limbs_fft_mfa_row_mask(xss.len(), sqrt, trunc);
+    let mut rows = Vec::new();
+    for ((xss_row, yss_row), &selected) in xss
+        .chunks_mut(sqrt)
+        .zip(yss.chunks_mut(sqrt))
+        .zip(mask.iter())
+    {
+        if selected {
+            let (ts, remainder) = temps.split_at_mut(size);
+            let (us, remainder) = remainder.split_at_mut(size);
+            temps = remainder;
+            rows.push((xss_row, yss_row, ts, us));
+        }
+    }
+    for_each_init(
+        rows,
+        || vec![0; scratch_len],
+        |scratch: &mut Vec<Limb>, (xss_row, yss_row, mut ts, mut us)| {
+            limbs_fft_radix2(xss_row, w, &mut ts, &mut us);
+            limbs_fft_radix2(yss_row, w, &mut ts, &mut us);
+            for (xs, ys) in xss_row.iter_mut().zip(yss_row.iter_mut()) {
+                limbs_fft_normmod_2expp1(xs);
+                limbs_fft_normmod_2expp1(ys);
+                pointwise(xs, ys, scratch);
+            }
+            limbs_ifft_radix2(xss_row, w, &mut ts, &mut us);
+        },
+    );
+}
+
+// Performs the row convolutions of the matrix Fourier algorithm for a squaring, possibly on
+// several threads. This is like `limbs_fft_mfa_convolve_rows_threaded`, but with only one input.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_square_rows_threaded<'a, F>(
+    xss: &mut [&'a mut [Limb]],
+    mut temps: &'a mut [Limb],
+    sqrt: usize,
+    trunc: usize,
+    w: usize,
+    scratch_len: usize,
+    pointwise: F,
+) where
+    F: Fn(&mut [Limb], &mut [Limb]) + Send + Sync,
+{
+    let size = xss[0].len();
+    let mask = limbs_fft_mfa_row_mask(xss.len(), sqrt, trunc);
+    let mut rows = Vec::new();
+    for (xss_row, &selected) in xss.chunks_mut(sqrt).zip(mask.iter()) {
+        if selected {
+            let (ts, remainder) = temps.split_at_mut(size);
+            let (us, remainder) = remainder.split_at_mut(size);
+            temps = remainder;
+            rows.push((xss_row, ts, us));
+        }
+    }
+    for_each_init(
+        rows,
+        || vec![0; scratch_len],
+        |scratch: &mut Vec<Limb>, (xss_row, mut ts, mut us)| {
+            limbs_fft_radix2(xss_row, w, &mut ts, &mut us);
+            for xs in xss_row.iter_mut() {
+                limbs_fft_normmod_2expp1(xs);
+                pointwise(xs, scratch);
+            }
+            limbs_ifft_radix2(xss_row, w, &mut ts, &mut us);
+        },
+    );
+}
+
+// Calls `limbs_fft_mulmod_2expp1`, taking all of its temporary storage from `scratch`, laid out
+// as in `limbs_mul_greater_to_out_fft_with_cutoff`. `n_3` and `size` are the number of
+// coefficients and the coefficient length of the inner transform.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mulmod_2expp1_in_scratch(
+    xs: &mut [Limb],
+    ys: &[Limb],
+    n: usize,
+    w: usize,
+    n_3: usize,
+    size: usize,
+    scratch: &mut [Limb],
+) {
+    let two_n_3 = n_3 << 1;
+    let yss_scratch_len = (n_3 + n_3 * size) << 1;
+    let (scratch, combine_scratch) = scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
+    let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
+    let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(two_n_3);
+    for _ in 0..two_n_3 {
+        let (lo, hi) = xss_scratch.split_at_mut(size);
+        xss.push(lo);
+        xss_scratch = hi;
+    }
+    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n_3);
+    let (mut ts, scratch_hi) = scratch_hi.split_at_mut(size);
+    let (mut us, mut ss) = scratch_hi.split_at_mut(size);
+    let mut yss: Vec<&mut [Limb]> = Vec::with_capacity(two_n_3);
+    for _ in 0..two_n_3 {
+        let (lo, hi) = yss_scratch.split_at_mut(size);
+        yss.push(lo);
+        yss_scratch = hi;
+    }
+    limbs_fft_mulmod_2expp1(
+        xs,
+        ys,
+        n,
+        w,
+        &mut xss,
+        xss0,
+        &mut yss,
+        yss_scratch,
+        &mut ts,
+        &mut us,
+        &mut ss,
+        combine_scratch,
+    );
+}
+
+// Calls `limbs_fft_mulmod_2expp1_same`, taking all of its temporary storage from `scratch`, laid
+// out as in `limbs_square_to_out_fft_with_cutoff`. `n_3` and `size` are the number of
+// coefficients and the coefficient length of the inner transform.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mulmod_2expp1_same_in_scratch(
+    xs: &mut [Limb],
+    n: usize,
+    w: usize,
+    n_3: usize,
+    size: usize,
+    scratch: &mut [Limb],
+) {
+    let two_n_3 = n_3 << 1;
+    let (mut xss_scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
+    let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(two_n_3);
+    for _ in 0..two_n_3 {
+        let (lo, hi) = xss_scratch.split_at_mut(size);
+        xss.push(lo);
+        xss_scratch = hi;
+    }
+    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n_3);
+    let (mut ts, scratch_hi) = scratch_hi.split_at_mut(size);
+    let (mut us, mut ss) = scratch_hi.split_at_mut(size);
+    limbs_fft_mulmod_2expp1_same(
+        xs,
+        n,
+        w,
+        &mut xss,
+        xss0,
+        &mut ts,
+        &mut us,
+        &mut ss,
+        combine_scratch,
+    );
+}
+
 pub_const_test! {
     #[cfg(feature = "32_bit_limbs")]
     limbs_mul_greater_to_out_fft_is_valid(xs_len: usize, ys_len: usize) -> bool {
@@ -2072,11 +2283,17 @@ pub_const_test! {
 }}
 
 pub_const_test! {
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     limbs_mul_greater_to_out_fft_is_valid(xs_len: usize, ys_len: usize) -> bool {
     xs_len + ys_len > 56
 }}
 
+pub_const_test! {
+    #[cfg(feature = "128_bit_limbs")]
+    limbs_mul_greater_to_out_fft_is_valid(xs_len: usize, ys_len: usize) -> bool {
+    xs_len + ys_len > 28
+}}
+
 pub_const_test! {
     #[cfg(feature = "32_bit_limbs")]
     limbs_square_to_out_fft_is_valid(xs_len: usize) -> bool {
@@ -2084,11 +2301,17 @@ pub_const_test! {
 }}
 
 pub_const_test! {
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     limbs_square_to_out_fft_is_valid(xs_len: usize) -> bool {
     xs_len > 28
 }}
 
+pub_const_test! {
+    #[cfg(feature = "128_bit_limbs")]
+    limbs_square_to_out_fft_is_valid(xs_len: usize) -> bool {
+    xs_len > 14
+}}
+
 const FFT_MULMOD_2EXPP1_CUTOFF: usize = 50;
 
 pub_test! {limbs_mul_greater_to_out_fft_with_cutoff_scratch_len(
@@ -2326,9 +2549,19 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let size = limbs + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
         let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let four_n = n << 2;
+        // When using threads, the transform of `ys` and each row convolution get their own
+        // temporary coefficients.
+        let threaded = use_threads(xs_len);
+        let mut thread_scratch: Vec<Limb> = if threaded {
+            vec![0; (3 + ((four_n / sqrt) << 1)) * size]
+        } else {
+            Vec::new()
+        };
+        let (ys_temps, row_temps) =
+            thread_scratch.split_at_mut(if threaded { 3 * size } else { 0 });
         let (scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
-        let four_n = n << 2;
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
         for _ in 0..four_n {
             let (lo, hi) = xss_scratch.split_at_mut(size);
@@ -2348,16 +2581,29 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         // trunc must be divisible by 2*sqrt
         let two_sqrt = sqrt << 1;
         trunc = two_sqrt * ((trunc + two_sqrt - 1) / two_sqrt);
-        j1 = limbs_fft_split_bits(&mut xss, xs, bits);
-        for ps in &mut xss[j1..] {
-            slice_set_zero(ps);
-        }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        j2 = limbs_fft_split_bits(&mut yss, ys, bits);
-        for qs in &mut yss[j2..] {
-            slice_set_zero(qs);
+        if threaded {
+            let (mut ts_2, remainder) = ys_temps.split_at_mut(size);
+            let (mut us_2, mut ss_2) = remainder.split_at_mut(size);
+            (j1, j2) = join(
+                || {
+                    limbs_fft_split_bits_and_mfa_truncate_sqrt_outer(
+                        &mut xss, xs, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+                    )
+                },
+                || {
+                    limbs_fft_split_bits_and_mfa_truncate_sqrt_outer(
+                        &mut yss, ys, bits, w, &mut ts_2, &mut us_2, &mut ss_2, sqrt, trunc,
+                    )
+                },
+            );
+        } else {
+            j1 = limbs_fft_split_bits_and_mfa_truncate_sqrt_outer(
+                &mut xss, xs, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+            );
+            j2 = limbs_fft_split_bits_and_mfa_truncate_sqrt_outer(
+                &mut yss, ys, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+            );
         }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut yss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         let two_n = four_n >> 1;
         let len = two_n / sqrt;
         let depth = len.ceiling_log_base_2();
@@ -2365,7 +2611,28 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let xss_hi = &mut xss[two_n..];
         let yss_hi = &mut yss[two_n..];
         let wy = w * len;
-        if bits >> Limb::LOG_WIDTH <= cutoff {
+        if bits >> Limb::LOG_WIDTH <= cutoff && threaded {
+            let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
+            let k = (n_2 << Limb::LOG_WIDTH) - nw;
+            limbs_fft_mfa_convolve_rows_threaded(
+                &mut xss,
+                &mut yss,
+                row_temps,
+                sqrt,
+                trunc,
+                wy,
+                limbs_fft_mulmod_2expp1_basecase_same_scratch_len(n_2),
+                |xs, ys, scratch| {
+                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
+                        &mut xs[..n_2],
+                        &ys[..n_2],
+                        false,
+                        k,
+                        scratch,
+                    ));
+                },
+            );
+        } else if bits >> Limb::LOG_WIDTH <= cutoff {
             let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
             for s in 0..(trunc - two_n) / sqrt {
                 let start = sqrt * n_revbin(s, depth);
@@ -2418,74 +2685,91 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
             let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
             let two_n3 = n3 << 1;
             let yss_scratch_len = (n3 + n3 * size) << 1;
-            let (scratch, combine_scratch) =
-                misc_scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
-            let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = yss_scratch.split_at_mut(size);
-                yss2.push(lo);
-                yss_scratch = hi;
-            }
-            let yss0 = yss_scratch;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
+            if threaded {
+                limbs_fft_mfa_convolve_rows_threaded(
+                    &mut xss,
+                    &mut yss,
+                    row_temps,
+                    sqrt,
+                    trunc,
+                    wy,
+                    (yss_scratch_len << 1) + 3 * size + limbs_fft_mulmod_2expp1_scratch_len(n, w),
+                    |xs, ys, scratch| {
+                        limbs_fft_mulmod_2expp1_in_scratch(xs, ys, n, w, n3, size, scratch);
+                    },
+                );
+            } else {
+                let (scratch, combine_scratch) =
+                    misc_scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
+                let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
+                let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                for _ in 0..two_n3 {
+                    let (lo, hi) = xss_scratch.split_at_mut(size);
+                    xss2.push(lo);
+                    xss_scratch = hi;
                 }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
+                let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+                let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+                let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+                let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                for _ in 0..two_n3 {
+                    let (lo, hi) = yss_scratch.split_at_mut(size);
+                    yss2.push(lo);
+                    yss_scratch = hi;
+                }
+                let yss0 = yss_scratch;
+                for s in 0..(trunc - two_n) / sqrt {
+                    let start = sqrt * n_revbin(s, depth);
+                    let xss_hi = &mut xss_hi[start..][..sqrt];
+                    let yss_hi = &mut yss_hi[start..][..sqrt];
+                    limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
+                    limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
+                    for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
+                        limbs_fft_normmod_2expp1(xs);
+                        limbs_fft_normmod_2expp1(ys);
+                        limbs_fft_mulmod_2expp1(
+                            xs,
+                            ys,
+                            n,
+                            w,
+                            &mut xss2,
+                            xss0,
+                            &mut yss2,
+                            yss0,
+                            &mut ts2,
+                            &mut us2,
+                            &mut ss2,
+                            combine_scratch,
+                        );
+                    }
+                    limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+                }
+                // convolutions on rows
+                for (xss_chunk, yss_chunk) in
+                    xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len)
+                {
+                    limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                    limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
+                    for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
+                        limbs_fft_normmod_2expp1(xs);
+                        limbs_fft_normmod_2expp1(ys);
+                        limbs_fft_mulmod_2expp1(
+                            xs,
+                            ys,
+                            n,
+                            w,
+                            &mut xss2,
+                            xss0,
+                            &mut yss2,
+                            yss0,
+                            &mut ts2,
+                            &mut us2,
+                            &mut ss2,
+                            combine_scratch,
+                        );
+                    }
+                    limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
                 }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
             }
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
@@ -2698,8 +2982,15 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         let limbs = nw >> Limb::LOG_WIDTH;
         let size = limbs + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
-        let (mut xss_scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
         let four_n = n << 2;
+        // When using threads, each row convolution gets its own temporary coefficients.
+        let threaded = use_threads(xs_len);
+        let mut row_temps: Vec<Limb> = if threaded {
+            vec![0; ((four_n / sqrt) << 1) * size]
+        } else {
+            Vec::new()
+        };
+        let (mut xss_scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
         for _ in 0..four_n {
             let (lo, hi) = xss_scratch.split_at_mut(size);
@@ -2724,7 +3015,19 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         // convolutions on relevant rows
         let xss_hi = &mut xss[two_n..];
         let wy = w * len;
-        if nw >> Limb::LOG_WIDTH <= cutoff {
+        if nw >> Limb::LOG_WIDTH <= cutoff && threaded {
+            limbs_fft_mfa_square_rows_threaded(
+                &mut xss,
+                &mut row_temps,
+                sqrt,
+                trunc,
+                wy,
+                limbs_fft_mulmod_2expp1_basecase_same2_scratch_len(nw),
+                |xs, scratch| {
+                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, scratch));
+                },
+            );
+        } else if nw >> Limb::LOG_WIDTH <= cutoff {
             for s in 0..(trunc - two_n) / sqrt {
                 let start = sqrt * n_revbin(s, depth);
                 let xss_hi = &mut xss_hi[start..][..sqrt];
@@ -2758,55 +3061,69 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             let n3 = usize::power_of_2(depth2);
             let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
             let two_n3 = n3 << 1;
-            let (mut xss_scratch, combine_scratch) =
-                misc_scratch.split_at_mut(((n * size) << 2) + 3 * size);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in xss_hi.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
+            if threaded {
+                limbs_fft_mfa_square_rows_threaded(
+                    &mut xss,
+                    &mut row_temps,
+                    sqrt,
+                    trunc,
+                    wy,
+                    ((n * size) << 2) + 3 * size + limbs_fft_mulmod_2expp1_same_scratch_len(n, w),
+                    |xs, scratch| {
+                        limbs_fft_mulmod_2expp1_same_in_scratch(xs, n, w, n3, size, scratch);
+                    },
+                );
+            } else {
+                let (mut xss_scratch, combine_scratch) =
+                    misc_scratch.split_at_mut(((n * size) << 2) + 3 * size);
+                let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+                for _ in 0..two_n3 {
+                    let (lo, hi) = xss_scratch.split_at_mut(size);
+                    xss2.push(lo);
+                    xss_scratch = hi;
                 }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in xss_chunk.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
+                let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+                let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+                let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+                for s in 0..(trunc - two_n) / sqrt {
+                    let start = sqrt * n_revbin(s, depth);
+                    let xss_hi = &mut xss_hi[start..][..sqrt];
+                    limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
+                    for xs in xss_hi.iter_mut() {
+                        limbs_fft_normmod_2expp1(xs);
+                        limbs_fft_mulmod_2expp1_same(
+                            xs,
+                            n,
+                            w,
+                            &mut xss2,
+                            xss0,
+                            &mut ts2,
+                            &mut us2,
+                            &mut ss2,
+                            combine_scratch,
+                        );
+                    }
+                    limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+                }
+                // convolutions on rows
+                for xss_chunk in xss.chunks_mut(sqrt).take(len) {
+                    limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                    for xs in xss_chunk.iter_mut() {
+                        limbs_fft_normmod_2expp1(xs);
+                        limbs_fft_mulmod_2expp1_same(
+                            xs,
+                            n,
+                            w,
+                            &mut xss2,
+                            xss0,
+                            &mut ts2,
+                            &mut us2,
+                            &mut ss2,
+                            combine_scratch,
+                        );
+                    }
+                    limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
                 }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
             }
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
@@ -1,6 +1,7 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
-use crate::platform::{DoubleLimb, Limb};
+use crate::platform::{DoubleLimb, Limb, INLINE_LIMB_COUNT};
 use malachite_base::num::arithmetic::traits::XMulYToZZ;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::SplitInHalf;
@@ -48,6 +49,18 @@ pub_test! {limbs_mul_limb(xs: &[Limb], y: Limb) -> Vec<Limb> {
 //
 // This is equivalent to `mul_1c` from `gmp-impl.h`, GMP 6.2.1.
 pub_crate_test! {limbs_mul_limb_with_carry_to_out(
+    out: &mut [Limb],
+    xs: &[Limb],
+    y: Limb,
+    carry: Limb,
+) -> Limb {
+    x86_64_kernel!(limbs_mul_limb_with_carry_to_out(out, xs, y, carry));
+    limbs_mul_limb_with_carry_to_out_portable(out, xs, y, carry)
+}}
+
+// The portable implementation of `limbs_mul_limb_with_carry_to_out`, which is used when no faster
+// kernel is available.
+pub_test! {limbs_mul_limb_with_carry_to_out_portable(
     out: &mut [Limb],
     xs: &[Limb],
     y: Limb,
@@ -95,6 +108,17 @@ pub_crate_test! {limbs_mul_limb_to_out(out: &mut [Limb], xs: &[Limb], y: Limb) -
 // This is equivalent to `mul_1c` from `gmp-impl.h`, GMP 6.2.1, where the output is the same as the
 // input.
 pub_crate_test! {limbs_slice_mul_limb_with_carry_in_place(
+    xs: &mut [Limb],
+    y: Limb,
+    carry: Limb
+) -> Limb {
+    x86_64_kernel!(limbs_slice_mul_limb_with_carry_in_place(xs, y, carry));
+    limbs_slice_mul_limb_with_carry_in_place_portable(xs, y, carry)
+}}
+
+// The portable implementation of `limbs_slice_mul_limb_with_carry_in_place`, which is used when no
+// faster kernel is available.
+pub_test! {limbs_slice_mul_limb_with_carry_in_place_portable(
     xs: &mut [Limb],
     y: Limb,
     mut carry: Limb
@@ -135,12 +159,13 @@ pub_crate_test! {limbs_slice_mul_limb_in_place(xs: &mut [Limb], y: Limb) -> Limb
 //
 // This is equivalent to `mpn_mul_1` from `mpn/generic/mul_1.c`, GMP 6.2.1, where the `rp == up`
 // and instead of returning the carry, it is appended to `rp`.
-pub_test! {limbs_vec_mul_limb_in_place(xs: &mut Vec<Limb>, y: Limb) {
+#[cfg(feature = "test_build")]
+pub fn limbs_vec_mul_limb_in_place(xs: &mut Vec<Limb>, y: Limb) {
     let carry = limbs_slice_mul_limb_in_place(xs, y);
     if carry != 0 {
         xs.push(carry);
     }
-}}
+}
 
 impl Natural {
     pub(crate) fn mul_assign_limb(&mut self, other: Limb) {
@@ -153,11 +178,14 @@ impl Natural {
                 if upper == 0 {
                     *small = lower;
                 } else {
-                    *self = Natural(Large(vec![lower, upper]));
+                    *self = Natural(Large(LimbVec::from_slice(&[lower, upper])));
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
-                limbs_vec_mul_limb_in_place(limbs, other);
+                let carry = limbs_slice_mul_limb_in_place(limbs, other);
+                if carry != 0 {
+                    limbs.push(carry);
+                }
             }
         }
     }
@@ -172,10 +200,18 @@ impl Natural {
                 if upper == 0 {
                     Small(lower)
                 } else {
-                    Large(vec![lower, upper])
+                    Large(LimbVec::from_slice(&[lower, upper]))
                 }
             }),
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_mul_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => {
+                if limbs.len() < INLINE_LIMB_COUNT {
+                    let mut product = self.clone();
+                    product.mul_assign_limb(other);
+                    product
+                } else {
+                    Natural(Large(limbs_mul_limb(limbs, other).into()))
+                }
+            }
         }
     }
 }
@@ -1,9 +1,12 @@
-use crate::natural::arithmetic::add::limbs_slice_add_greater_in_place_left;
+use crate::natural::arithmetic::add::{
+    limbs_slice_add_greater_in_place_left, limbs_slice_add_same_length_in_place_left,
+};
 use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
 use crate::natural::arithmetic::mul::fft::{
     limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
 };
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
+use crate::natural::arithmetic::mul::ntt::limbs_mul_greater_to_out_ntt;
 use crate::natural::arithmetic::mul::toom::MUL_TOOM33_THRESHOLD_LIMIT;
 use crate::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_scratch_len,
@@ -17,16 +20,19 @@ use crate::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_6h, limbs_mul_greater_to_out_toom_6h_scratch_len,
     limbs_mul_greater_to_out_toom_8h, limbs_mul_greater_to_out_toom_8h_scratch_len,
 };
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::join;
 use crate::platform::{
-    Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM32_TO_TOOM43_THRESHOLD,
-    MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM42_TO_TOOM53_THRESHOLD,
-    MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD, MUL_TOOM6H_THRESHOLD,
-    MUL_TOOM8H_THRESHOLD,
+    Limb, INLINE_LIMB_COUNT, MUL_FFT_THRESHOLD, MUL_NTT_THRESHOLD, MUL_TOOM22_THRESHOLD,
+    MUL_TOOM32_TO_TOOM43_THRESHOLD, MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD,
+    MUL_TOOM42_TO_TOOM53_THRESHOLD, MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD,
+    MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD,
 };
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::basic::traits::Zero;
+use malachite_base::slices::slice_set_zero;
 use std::cmp::max;
 use std::iter::Product;
 use std::ops::{Mul, MulAssign};
@@ -100,8 +106,10 @@ pub_crate_test! { limbs_mul_same_length_to_out_scratch_len(len: usize) -> usize
         limbs_mul_greater_to_out_toom_6h_scratch_len(len, len)
     } else if len < MUL_FFT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h_scratch_len(len, len)
-    } else {
+    } else if len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(len, len)
+    } else {
+        0
     }
 }}
 
@@ -143,8 +151,10 @@ pub_crate_test! {limbs_mul_same_length_to_out(
         limbs_mul_greater_to_out_toom_6h(out, xs, ys, scratch);
     } else if len < MUL_FFT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
-    } else {
+    } else if len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_ntt(out, xs, ys);
     }
 }}
 
@@ -236,8 +246,10 @@ pub_crate_test! { limbs_mul_greater_to_out_scratch_len(xs_len: usize, ys_len: us
         } else {
             limbs_mul_greater_to_out_toom_8h_scratch_len(xs_len, ys_len)
         }
-    } else {
+    } else if (xs_len + ys_len) >> 1 < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(xs_len, ys_len)
+    } else {
+        0
     }
 }}
 
@@ -386,12 +398,68 @@ pub_crate_test! {limbs_mul_greater_to_out(
         } else {
             limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
         }
-    } else {
+    } else if (xs_len + ys_len) >> 1 < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_ntt(out, xs, ys);
     }
     out[xs_len + ys_len - 1]
 }}
 
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the `xs.len() + ys.len()` least-significant limbs of the product of the `Natural`s to an output
+// slice. The output must be at least as long as `xs.len() + ys.len()`, `xs` must be as least as
+// long as `ys`, and `ys` cannot be empty.
+//
+// When `threaded` is true and `ys` has more than one limb, `ys` is split in half and the two
+// partial products are computed on separate threads. This helps callers, like divide-and-conquer
+// division, whose products are too small to be split by `limbs_mul_greater_to_out` itself. The
+// result is the same either way.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is shorter than `ys`, or `ys` is empty.
+pub_crate_test! {limbs_mul_greater_to_out_maybe_threaded(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+    threaded: bool,
+) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert!(xs_len >= ys_len);
+    assert_ne!(ys_len, 0);
+    assert!(out.len() >= xs_len + ys_len);
+    if !threaded || ys_len == 1 {
+        let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs_len, ys_len)];
+        limbs_mul_greater_to_out(out, xs, ys, &mut mul_scratch);
+        return;
+    }
+    let (ys_lo, ys_hi) = ys.split_at(ys_len >> 1);
+    let ys_lo_len = ys_lo.len();
+    let mut product_hi = vec![0; xs_len + ys_hi.len()];
+    join(
+        || {
+            let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs_len, ys_lo_len)];
+            limbs_mul_greater_to_out(&mut out[..xs_len + ys_lo_len], xs, ys_lo, &mut mul_scratch);
+        },
+        || {
+            let mut mul_scratch =
+                vec![0; limbs_mul_greater_to_out_scratch_len(xs_len, ys_hi.len())];
+            limbs_mul_greater_to_out(&mut product_hi, xs, ys_hi, &mut mul_scratch);
+        },
+    );
+    let out = &mut out[ys_lo_len..xs_len + ys_len];
+    slice_set_zero(&mut out[xs_len..]);
+    assert!(!limbs_slice_add_same_length_in_place_left(out, &product_hi));
+}}
+
 pub_crate_test! {limbs_mul_to_out_scratch_len(xs_len: usize, ys_len: usize) -> usize {
     if xs_len >= ys_len {
         limbs_mul_greater_to_out_scratch_len(xs_len, ys_len)
@@ -468,6 +536,23 @@ pub_crate_test! {limbs_mul_greater_to_out_basecase(out: &mut [Limb], xs: &[Limb]
     }
 }}
 
+// Like `limbs_mul`, but the product is stored inline if it is short enough. Products that short are
+// always computed with the basecase algorithm.
+fn limb_vec_mul(xs: &[Limb], ys: &[Limb]) -> LimbVec {
+    let out_len = xs.len() + ys.len();
+    if out_len <= INLINE_LIMB_COUNT {
+        let mut out = LimbVec::zeroed(out_len);
+        if xs.len() >= ys.len() {
+            limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
+        } else {
+            limbs_mul_greater_to_out_basecase(&mut out, ys, xs);
+        }
+        out
+    } else {
+        limbs_mul(xs, ys).into()
+    }
+}
+
 impl Mul<Natural> for Natural {
     type Output = Natural;
 
@@ -622,9 +707,70 @@ impl<'a, 'b> Mul<&'a Natural> for &'b Natural {
             (Natural(Small(x)), y) => y.mul_limb_ref(*x),
             (x, Natural(Small(y))) => x.mul_limb_ref(*y),
             (Natural(Large(ref xs)), Natural(Large(ref ys))) => {
-                Natural::from_owned_limbs_asc(limbs_mul(xs, ys))
+                Natural::from_limb_vec_asc(limb_vec_mul(xs, ys))
+            }
+        }
+    }
+}
+
+impl Natural {
+    /// Multiplies two [`Natural`]s, taking both by reference and writing the product to an
+    /// existing [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow. The scratch space needed by the multiplication is taken from the same buffer,
+    /// so after a buffer has grown once, later calls with inputs of the same size don't allocate.
+    ///
+    /// $$
+    /// o \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(123u32).mul_to(&Natural::from(456u32), &mut out);
+    /// assert_eq!(out, 56088);
+    ///
+    /// Natural::from_str("123456789000")
+    ///     .unwrap()
+    ///     .mul_to(&Natural::from_str("987654321000").unwrap(), &mut out);
+    /// assert_eq!(out.to_string(), "121932631112635269000000");
+    /// ```
+    pub fn mul_to(&self, other: &Natural, out: &mut Natural) {
+        match (self, other) {
+            (&Natural(Small(x)), &Natural(Small(y))) => {
+                if let Some(product) = x.checked_mul(y) {
+                    out.set_limb(product);
+                    return;
+                }
+            }
+            (&natural_zero!(), _) | (_, &natural_zero!()) => {
+                out.set_limb(0);
+                return;
             }
+            _ => {}
         }
+        let xs = self.limbs_slice();
+        let ys = other.limbs_slice();
+        let out_len = xs.len() + ys.len();
+        let scratch_len = limbs_mul_to_out_scratch_len(xs.len(), ys.len());
+        let (out_limbs, mul_scratch) = out
+            .limbs_for_overwrite(out_len + scratch_len)
+            .split_at_mut(out_len);
+        limbs_mul_to_out(out_limbs, xs, ys, mul_scratch);
+        out.finish_overwrite(out_len);
     }
 }
 
@@ -665,7 +811,7 @@ impl MulAssign<Natural> for Natural {
             }
             (_, Natural(Small(y))) => self.mul_assign_limb(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                *xs = limbs_mul(xs, ys);
+                *xs = limb_vec_mul(xs, ys);
                 self.trim();
             }
         }
@@ -706,7 +852,7 @@ impl<'a> MulAssign<&'a Natural> for Natural {
             (Natural(Small(x)), _) => *self = other.mul_limb_ref(*x),
             (_, Natural(Small(y))) => self.mul_assign_limb(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                *xs = limbs_mul(xs, ys);
+                *xs = limb_vec_mul(xs, ys);
                 self.trim();
             }
         }
@@ -812,11 +958,17 @@ impl<'a> Product<&'a Natural> for Natural {
 pub mod fft;
 /// Code for multiplying a many-limbed [`Natural`] by a single [limb](crate#limbs).
 pub mod limb;
+/// Code for computing only the highest bits of the product of two [`Natural`]s, or an
+/// approximation of them, with the schoolbook algorithm or Mulders' short product.
+pub mod mul_high;
 /// Code for computing only the lowest [limbs](crate#limbs) of the product of two [`Natural`]s.
 pub mod mul_low;
 /// Code for multiplying two [`Natural`]s modulo one less than a large power of 2; used by the
 /// Schönhage-Strassen algorithm.
 pub mod mul_mod;
+/// Code for multiplying two [`Natural`]s using a number-theoretic transform over three word-size
+/// primes.
+pub mod ntt;
 /// Code for evaluating polynomials at various points; used in Toom-Cook multiplication.
 pub mod poly_eval;
 /// Code for reconstructing polynomials from their values at various points; used in Toom-Cook
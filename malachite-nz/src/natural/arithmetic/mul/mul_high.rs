@@ -0,0 +1,294 @@
+use crate::natural::arithmetic::add::limbs_slice_add_greater_in_place_left;
+use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
+use crate::natural::arithmetic::mul::limbs_mul;
+use crate::natural::InnerNatural::Large;
+use crate::natural::Natural;
+use crate::platform::{Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD};
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, PowerOf2, ShrRound};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::slices::slice_set_zero;
+use std::cmp::min;
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the limbs of an approximation of their product to `out`. The approximation leaves out the partial
+// products $x_i y_j B^{i+j}$ with $i + j < s$, where $B$ is $2^W$, $W$ is `Limb::WIDTH`, and $s$
+// is `start`. It is therefore at most the exact product, and it falls short of it by less than
+// $sB^{s+1}$. The lowest `start` limbs of `out` are set to zero.
+//
+// # Worst-case complexity
+// $T(n, m) = O(nm)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is `ys.len()`.
+//
+// # Panics
+// Panics if `ys` is empty or if `out` is shorter than `xs.len() + ys.len()`.
+pub_test! {limbs_mul_high_basecase(out: &mut [Limb], xs: &[Limb], ys: &[Limb], start: usize) {
+    let ys_len = ys.len();
+    assert_ne!(ys_len, 0);
+    let out = &mut out[..xs.len() + ys_len];
+    slice_set_zero(out);
+    for (i, &x) in xs.iter().enumerate() {
+        let j = start.saturating_sub(i);
+        if j < ys_len {
+            out[i + ys_len] = limbs_slice_add_mul_limb_same_length_in_place_left(
+                &mut out[i + j..i + ys_len],
+                &ys[j..],
+                x,
+            );
+        }
+    }
+}}
+
+// Adds to `out` the sum of a set of partial products $x_i y_j B^{i+j}$ of two slices of `Limb`s
+// that contains each partial product with $i + j \geq s$ once, where $B$ is $2^W$, $W$ is
+// `Limb::WIDTH`, and $s$ is `start`. Any other partial products in the set are also included only
+// once, so the sum is at most the exact product and falls short of it by less than $sB^{s+1}$, as
+// in `limbs_mul_high_basecase`. The sum is added to all of `out`, which must be at least
+// `xs.len() + ys.len()` limbs long and must not overflow.
+//
+// This is Mulders' short product. Splitting both inputs at index $\lfloor 3s/10 \rfloor$, the high
+// parts are multiplied with the full multiplication algorithm, both cross products are computed
+// recursively, and the product of the low parts is skipped, since all of its columns are below $s$.
+// The full product of the high parts covers some columns below $s$ as well, but that makes the
+// recursive products short enough that, with Karatsuba multiplication, the whole computation
+// costs about 80% of a full product.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), ys.len())`.
+fn limbs_mul_high_add(out: &mut [Limb], xs: &[Limb], ys: &[Limb], start: usize) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    if xs_len == 0 || ys_len == 0 || start >= xs_len + ys_len - 1 {
+        return;
+    }
+    // Limbs of one input that only ever meet the other input in columns below `start` can be
+    // dropped. Afterwards, `start` is less than both lengths.
+    if start >= ys_len {
+        let d = start + 1 - ys_len;
+        limbs_mul_high_add(&mut out[d..], &xs[d..], ys, start - d);
+        return;
+    }
+    if start >= xs_len {
+        let d = start + 1 - xs_len;
+        limbs_mul_high_add(&mut out[d..], xs, &ys[d..], start - d);
+        return;
+    }
+    let split = start * 3 / 10;
+    if min(xs_len, ys_len) < MUL_TOOM22_THRESHOLD || split == 0 {
+        let product = if split == 0 {
+            limbs_mul(xs, ys)
+        } else {
+            let mut product = vec![0; xs_len + ys_len];
+            limbs_mul_high_basecase(&mut product, xs, ys, start);
+            product
+        };
+        assert!(!limbs_slice_add_greater_in_place_left(out, &product));
+        return;
+    }
+    let (xs_lo, xs_hi) = xs.split_at(split);
+    let (ys_lo, ys_hi) = ys.split_at(split);
+    let product = limbs_mul(xs_hi, ys_hi);
+    assert!(!limbs_slice_add_greater_in_place_left(
+        &mut out[split << 1..],
+        &product
+    ));
+    limbs_mul_high_add(&mut out[split..], xs_hi, ys_lo, start - split);
+    limbs_mul_high_add(&mut out[split..], xs_lo, ys_hi, start - split);
+    // Every partial product of `xs_lo` and `ys_lo` is in a column less than `2 * split - 1`, which
+    // is less than `start`.
+}
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the limbs of an approximation of their product to `out`. Like `limbs_mul_high_basecase`, the
+// approximation includes every partial product $x_i y_j B^{i+j}$ with $i + j \geq s$, where $s$ is
+// `start`, so it is at most the exact product and falls short of it by less than $sB^{s+1}$. Unlike
+// `limbs_mul_high_basecase`, it may include some partial products with $i + j < s$, and the lowest
+// `start` limbs of `out` may be nonzero.
+//
+// For inputs at least as long as the Toom-22 threshold, this uses Mulders' short product, which is
+// faster than the full product.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), ys.len())`.
+//
+// # Panics
+// Panics if either slice is empty or if `out` is shorter than `xs.len() + ys.len()`.
+pub_test! {limbs_mul_high(out: &mut [Limb], xs: &[Limb], ys: &[Limb], start: usize) {
+    assert_ne!(xs.len(), 0);
+    assert_ne!(ys.len(), 0);
+    let out = &mut out[..xs.len() + ys.len()];
+    slice_set_zero(out);
+    limbs_mul_high_add(out, xs, ys, start);
+}}
+
+impl Natural {
+    // Returns an approximation of the product of two `Natural`s that is at most the exact product,
+    // together with an upper bound, less than $2^k$, on how far it falls short. Returns `None` if
+    // computing the exact product is about as fast, or if the product is less than $2^k$ anyway.
+    //
+    // The two lowest limbs that the result keeps absorb the error: with
+    // $s = \lfloor k/W \rfloor - 2$ omitted columns, the error is less than
+    // $sB^{s+1} < B^{s+2} \leq 2^k$.
+    fn mul_truncated(&self, other: &Natural, bits: u64) -> Option<(Natural, Natural)> {
+        if let (&Natural(Large(ref xs)), &Natural(Large(ref ys))) = (self, other) {
+            // At this size the full product uses the FFT, and a short product saves little or
+            // nothing.
+            if min(xs.len(), ys.len()) >= MUL_FFT_THRESHOLD {
+                return None;
+            }
+            let skipped_limbs = usize::saturating_from(bits >> Limb::LOG_WIDTH);
+            if skipped_limbs <= 2 || skipped_limbs >= xs.len() + ys.len() {
+                return None;
+            }
+            let start = skipped_limbs - 2;
+            let mut out = vec![0; xs.len() + ys.len()];
+            limbs_mul_high(&mut out, xs, ys, start);
+            let error = Natural::from(start) << (u64::exact_from(start + 1) << Limb::LOG_WIDTH);
+            Some((Natural::from_owned_limbs_asc(out), error))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an approximation of the high part of the product of two [`Natural`]s: the product
+    /// divided by $2^k$ and rounded down. Both [`Natural`]s are taken by reference.
+    ///
+    /// Most of the partial products that only contribute to the discarded low bits are skipped:
+    /// for inputs shorter than the Toom-22 threshold, the schoolbook algorithm leaves out the low
+    /// columns entirely, and for longer inputs Mulders' short product computes only a little more
+    /// than the high half of the product. Skipping them means that the result may be one less than
+    /// the exact value; it is never more. Use [`mul_high_round`](Natural::mul_high_round) if the
+    /// exact value is needed.
+    ///
+    /// For inputs that are long enough to be multiplied using the FFT, a short product isn't
+    /// faster, so the full product is computed and shifted.
+    ///
+    /// $f(x, y, k) = z$, where $\lfloor xy/2^k \rfloor - 1 \leq z \leq \lfloor xy/2^k \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(123u32).mul_high(&Natural::from(456u32), 4), 3505);
+    ///
+    /// let x = Natural::from(3u32).pow(200);
+    /// let y = Natural::from(5u32).pow(200);
+    /// let exact = (&x * &y) >> 500;
+    /// let approx = x.mul_high(&y, 500);
+    /// assert!(approx <= exact && exact <= approx + Natural::from(1u32));
+    /// ```
+    pub fn mul_high(&self, other: &Natural, bits: u64) -> Natural {
+        if let Some((product, _)) = self.mul_truncated(other, bits) {
+            product >> bits
+        } else {
+            (self * other) >> bits
+        }
+    }
+
+    /// Returns the high part of the product of two [`Natural`]s: the product divided by $2^k$ and
+    /// rounded according to the specified rounding mode. Both [`Natural`]s are taken by reference.
+    ///
+    /// This is equivalent to `(self * other).shr_round(bits, rm)`, but unless the inputs are long
+    /// enough for the FFT, it starts from the approximation computed by
+    /// [`mul_high`](Natural::mul_high), and only computes the full product when the approximation
+    /// can't determine how to round.
+    ///
+    /// Let $q = \frac{xy}{2^k}$:
+    ///
+    /// $f(x, y, k, \mathrm{Down}) = f(x, y, k, \mathrm{Floor}) = \lfloor q \rfloor.$
+    ///
+    /// $f(x, y, k, \mathrm{Up}) = f(x, y, k, \mathrm{Ceiling}) = \lceil q \rceil.$
+    ///
+    /// $$
+    /// f(x, y, k, \mathrm{Nearest}) = \begin{cases}
+    ///     \lfloor q \rfloor & \text{if} \\quad q - \lfloor q \rfloor < \frac{1}{2}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q - \lfloor q \rfloor > \frac{1}{2}, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q - \lfloor q \rfloor = \frac{1}{2} \\ \text{and}
+    ///         \\ \lfloor q \rfloor \\ \text{is even}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q - \lfloor q \rfloor = \frac{1}{2} \\ \text{and}
+    ///         \\ \lfloor q \rfloor \\ \text{is odd}.
+    /// \end{cases}
+    /// $$
+    ///
+    /// $f(x, y, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `RoundingMode::Exact` but the product is not divisible by $2^k$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = Natural::from(123u32);
+    /// let y = Natural::from(456u32);
+    /// assert_eq!(x.mul_high_round(&y, 4, RoundingMode::Floor), 3505);
+    /// assert_eq!(x.mul_high_round(&y, 4, RoundingMode::Ceiling), 3506);
+    /// assert_eq!(x.mul_high_round(&y, 4, RoundingMode::Nearest), 3506);
+    /// assert_eq!(x.mul_high_round(&y, 3, RoundingMode::Exact), 7011);
+    ///
+    /// let x = Natural::from(3u32).pow(200);
+    /// let y = Natural::from(5u32).pow(200);
+    /// assert_eq!(
+    ///     x.mul_high_round(&y, 500, RoundingMode::Floor),
+    ///     (&x * &y) >> 500
+    /// );
+    /// ```
+    pub fn mul_high_round(&self, other: &Natural, bits: u64, rm: RoundingMode) -> Natural {
+        if rm != RoundingMode::Exact {
+            if let Some((product, error)) = self.mul_truncated(other, bits) {
+                // The exact product lies in [product, product + error), so the exact remainder
+                // lies in [remainder, remainder + error) unless that range reaches $2^k$. If the
+                // range doesn't contain 0 or, when rounding to nearest, $2^{k-1}$, every value in
+                // it rounds the same way.
+                let remainder = (&product).mod_power_of_2(bits);
+                let remainder_limit = &remainder + error;
+                if remainder != 0u32 && remainder_limit <= Natural::power_of_2(bits) {
+                    let quotient = product >> bits;
+                    let half = Natural::power_of_2(bits - 1);
+                    match rm {
+                        RoundingMode::Down | RoundingMode::Floor => return quotient,
+                        RoundingMode::Up | RoundingMode::Ceiling => return quotient + Natural::ONE,
+                        RoundingMode::Nearest if remainder_limit <= half => return quotient,
+                        RoundingMode::Nearest if remainder > half => {
+                            return quotient + Natural::ONE
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        (self * other).shr_round(bits, rm)
+    }
+}
@@ -8,12 +8,14 @@ use crate::natural::arithmetic::mul::{
     limbs_mul_greater_to_out_basecase, limbs_mul_same_length_to_out,
     limbs_mul_same_length_to_out_scratch_len,
 };
+use crate::natural::Natural;
 use crate::platform::Limb;
 use crate::platform::{
     MULLO_BASECASE_THRESHOLD, MULLO_DC_THRESHOLD, MULLO_MUL_N_THRESHOLD, MUL_TOOM22_THRESHOLD,
     MUL_TOOM33_THRESHOLD, MUL_TOOM44_THRESHOLD, MUL_TOOM8H_THRESHOLD,
 };
-use malachite_base::num::arithmetic::traits::WrappingAddAssign;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Mul, WrappingAddAssign};
+use malachite_base::num::logic::traits::SignificantBits;
 
 // # Worst-case complexity
 // $T(n) = O(n^2)$
@@ -340,3 +342,44 @@ pub_crate_test! {limbs_mul_low_same_length_basecase_alt(
         limbs_slice_add_mul_limb_same_length_in_place_left(&mut out[i..], &xs[..n - i], ys[i]);
     }
 }}
+
+impl Natural {
+    /// Returns the low part of the product of two [`Natural`]s: the product modulo $2^k$. Both
+    /// [`Natural`]s are taken by reference.
+    ///
+    /// This is equivalent to `(self * other).mod_power_of_2(bits)`, but only the low limbs of the
+    /// product are computed: with the schoolbook algorithm for short inputs, and with Mulders'
+    /// divide-and-conquer short product for longer ones. For inputs that are long enough to be
+    /// multiplied using the FFT, a short product isn't faster, so the full product is computed and
+    /// truncated. Unlike [`mod_power_of_2_mul`](ModPowerOf2Mul::mod_power_of_2_mul), the inputs
+    /// don't need to be reduced modulo $2^k$.
+    ///
+    /// $f(x, y, k) = z$, where $z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModPowerOf2, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(123u32).mul_low(&Natural::from(456u32), 8), 24);
+    ///
+    /// let x = Natural::from(3u32).pow(200);
+    /// let y = Natural::from(5u32).pow(200);
+    /// assert_eq!(x.mul_low(&y, 100), (&x * &y).mod_power_of_2(100));
+    /// ```
+    pub fn mul_low(&self, other: &Natural, bits: u64) -> Natural {
+        if self.significant_bits() <= bits && other.significant_bits() <= bits {
+            self.mod_power_of_2_mul(other, bits)
+        } else {
+            self.mod_power_of_2(bits)
+                .mod_power_of_2_mul(other.mod_power_of_2(bits), bits)
+        }
+    }
+}
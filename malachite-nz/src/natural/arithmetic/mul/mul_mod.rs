@@ -59,7 +59,7 @@ pub_crate_test! {limbs_mul_mod_base_pow_n_minus_1_next_size(n: usize) -> usize {
 // The result is $O(n)$.
 //
 // This is equivalent to `mpn_mulmod_bnm1_itch` from `gmp-impl.h`, GMP 6.2.1.
-pub(crate) const fn limbs_mul_mod_base_pow_n_minus_1_scratch_len(
+pub_const_crate_test! {limbs_mul_mod_base_pow_n_minus_1_scratch_len(
     n: usize,
     xs_len: usize,
     ys_len: usize,
@@ -74,7 +74,7 @@ pub(crate) const fn limbs_mul_mod_base_pow_n_minus_1_scratch_len(
     } else {
         n + 4
     }
-}
+}}
 
 // Interpreting two equal-length, nonempty slices of `Limb`s as the limbs (in ascending order) of
 // two `Natural`s, multiplies the `Natural`s mod 2<sup>`Limb::WIDTH` * n</sup> - 1, where n is the
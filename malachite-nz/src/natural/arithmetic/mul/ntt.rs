@@ -0,0 +1,300 @@
+use crate::parallel::{join, use_threads};
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMulPrecomputed, ModPow, ModSub,
+};
+#[cfg(any(feature = "32_bit_limbs", feature = "128_bit_limbs"))]
+use malachite_base::num::conversion::traits::JoinHalves;
+use malachite_base::num::conversion::traits::{SplitInHalf, WrappingFrom};
+
+// Three primes of the form $k2^{42}+1$, each less than $2^{62}$. The inputs are split into 64-bit
+// words, so every coefficient of a convolution of length at most $2^{42}$ is less than
+// $2^{42}2^{128} = 2^{170}$, which is less than the product of the primes. This means that the
+// coefficients can be recovered exactly from their residues.
+const PRIMES: [u64; 3] = [0x3fff_c000_0000_0001, 0x3fff_8400_0000_0001, 0x3fff_5400_0000_0001];
+
+// A primitive root modulo each of `PRIMES`.
+const PRIMITIVE_ROOTS: [u64; 3] = [11, 19, 5];
+
+// Every element of `PRIMES` has a primitive $2^{42}$th root of unity.
+const MAX_LOG_LEN: u64 = 42;
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+fn limbs_to_words(xs: &[Limb]) -> Vec<u64> {
+    xs.to_vec()
+}
+
+#[cfg(feature = "32_bit_limbs")]
+fn limbs_to_words(xs: &[Limb]) -> Vec<u64> {
+    xs.chunks(2)
+        .map(|chunk| u64::join_halves(chunk.get(1).copied().unwrap_or(0), chunk[0]))
+        .collect()
+}
+
+#[cfg(all(feature = "128_bit_limbs", not(feature = "32_bit_limbs")))]
+fn limbs_to_words(xs: &[Limb]) -> Vec<u64> {
+    let mut words = Vec::with_capacity(xs.len() << 1);
+    for &x in xs {
+        let (hi, lo) = x.split_in_half();
+        words.push(lo);
+        words.push(hi);
+    }
+    words
+}
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+fn words_to_limbs(out: &mut [Limb], words: &[u64]) {
+    let len = out.len();
+    out.copy_from_slice(&words[..len]);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+fn words_to_limbs(out: &mut [Limb], words: &[u64]) {
+    for (chunk, &word) in out.chunks_mut(2).zip(words.iter()) {
+        let (hi, lo) = word.split_in_half();
+        chunk[0] = lo;
+        if let Some(x) = chunk.get_mut(1) {
+            *x = hi;
+        }
+    }
+}
+
+#[cfg(all(feature = "128_bit_limbs", not(feature = "32_bit_limbs")))]
+fn words_to_limbs(out: &mut [Limb], words: &[u64]) {
+    for (x, chunk) in out.iter_mut().zip(words.chunks(2)) {
+        *x = Limb::join_halves(chunk.get(1).copied().unwrap_or(0), chunk[0]);
+    }
+}
+
+// Returns $w^k$ for $0 \leq k < n$, where $w$ is `root` and $n$ is `len`.
+fn powers_mod(root: u64, len: usize, p: u64, data: &u64) -> Vec<u64> {
+    let mut powers = Vec::with_capacity(len);
+    let mut power = 1;
+    for _ in 0..len {
+        powers.push(power);
+        power = power.mod_mul_precomputed(root, p, data);
+    }
+    powers
+}
+
+// Computes the number-theoretic transform of `xs` modulo `p`, in place, using decimation in
+// frequency. `roots` contains the first `xs.len() / 2` powers of a primitive `xs.len()`th root of
+// unity. The output is in bit-reversed order, which is the order that `ntt_inverse` expects.
+fn ntt_forward(xs: &mut [u64], roots: &[u64], p: u64, data: &u64) {
+    let mut half = xs.len() >> 1;
+    let mut stride = 1;
+    while half != 0 {
+        for block in xs.chunks_mut(half << 1) {
+            let (lo, hi) = block.split_at_mut(half);
+            for ((x, y), &w) in lo
+                .iter_mut()
+                .zip(hi.iter_mut())
+                .zip(roots.iter().step_by(stride))
+            {
+                let (u, v) = (*x, *y);
+                *x = u.mod_add(v, p);
+                *y = u.mod_sub(v, p).mod_mul_precomputed(w, p, data);
+            }
+        }
+        half >>= 1;
+        stride <<= 1;
+    }
+}
+
+// Computes the inverse of `ntt_forward`, without dividing by `xs.len()`, using decimation in time.
+// The input is in bit-reversed order and the output is in natural order. `inverse_roots` contains
+// the first `xs.len() / 2` powers of the inverse of the root used by `ntt_forward`.
+fn ntt_inverse(xs: &mut [u64], inverse_roots: &[u64], p: u64, data: &u64) {
+    let len = xs.len();
+    let mut half = 1;
+    let mut stride = len >> 1;
+    while half < len {
+        for block in xs.chunks_mut(half << 1) {
+            let (lo, hi) = block.split_at_mut(half);
+            for ((x, y), &w) in lo
+                .iter_mut()
+                .zip(hi.iter_mut())
+                .zip(inverse_roots.iter().step_by(stride))
+            {
+                let u = *x;
+                let v = y.mod_mul_precomputed(w, p, data);
+                *x = u.mod_add(v, p);
+                *y = u.mod_sub(v, p);
+            }
+        }
+        half <<= 1;
+        stride >>= 1;
+    }
+}
+
+// Returns the cyclic convolution of `xs` and `ys` (or of `xs` with itself, if `ys` is `None`)
+// modulo `PRIMES[i]`, using a transform of length $2^k$, where $k$ is `log_len`. The result has
+// length $2^k$.
+fn convolution_mod(xs: &[u64], ys: Option<&[u64]>, log_len: u64, i: usize) -> Vec<u64> {
+    let p = PRIMES[i];
+    let data = u64::precompute_mod_mul_data(&p);
+    let len = 1usize << log_len;
+    let root = PRIMITIVE_ROOTS[i].mod_pow((p - 1) >> log_len, p);
+    let roots = powers_mod(root, len >> 1, p, &data);
+    let reduce = |xs: &[u64]| {
+        let mut reduced: Vec<u64> = xs.iter().map(|&x| x % p).collect();
+        reduced.resize(len, 0);
+        reduced
+    };
+    let mut xs = reduce(xs);
+    ntt_forward(&mut xs, &roots, p, &data);
+    // Dividing by the length is folded into the pointwise products.
+    let len_inverse = u64::wrapping_from(len).mod_inverse(p).unwrap();
+    if let Some(ys) = ys {
+        let mut ys = reduce(ys);
+        ntt_forward(&mut ys, &roots, p, &data);
+        for (x, y) in xs.iter_mut().zip(ys.into_iter()) {
+            *x = x
+                .mod_mul_precomputed(y, p, &data)
+                .mod_mul_precomputed(len_inverse, p, &data);
+        }
+    } else {
+        for x in xs.iter_mut() {
+            *x = x
+                .mod_mul_precomputed(*x, p, &data)
+                .mod_mul_precomputed(len_inverse, p, &data);
+        }
+    }
+    let inverse_roots = powers_mod(root.mod_inverse(p).unwrap(), len >> 1, p, &data);
+    ntt_inverse(&mut xs, &inverse_roots, p, &data);
+    xs
+}
+
+// Multiplies two numbers given as little-endian 64-bit words (or squares one, if `ys` is `None`),
+// returning the `xs.len() + ys.len()` words of the product.
+//
+// The product is computed modulo each of the three primes, possibly on separate threads, and the
+// coefficients are reconstructed from their residues with Garner's algorithm.
+fn words_mul_ntt(xs: &[u64], ys: Option<&[u64]>, threaded: bool) -> Vec<u64> {
+    let product_len = xs.len() + ys.map_or(xs.len(), <[u64]>::len);
+    let convolution_len = product_len - 1;
+    let log_len = u64::from(convolution_len.next_power_of_two().trailing_zeros());
+    assert!(log_len <= MAX_LOG_LEN);
+    let (r_0, (r_1, r_2)) = if threaded {
+        join(
+            || convolution_mod(xs, ys, log_len, 0),
+            || {
+                join(
+                    || convolution_mod(xs, ys, log_len, 1),
+                    || convolution_mod(xs, ys, log_len, 2),
+                )
+            },
+        )
+    } else {
+        (
+            convolution_mod(xs, ys, log_len, 0),
+            (
+                convolution_mod(xs, ys, log_len, 1),
+                convolution_mod(xs, ys, log_len, 2),
+            ),
+        )
+    };
+    let [p_0, p_1, p_2] = PRIMES;
+    let data_1 = u64::precompute_mod_mul_data(&p_1);
+    let data_2 = u64::precompute_mod_mul_data(&p_2);
+    let p_0_inverse = (p_0 % p_1).mod_inverse(p_1).unwrap();
+    let p_0_p_1 = u128::from(p_0) * u128::from(p_1);
+    let p_0_p_1_inverse = u64::wrapping_from(p_0_p_1 % u128::from(p_2))
+        .mod_inverse(p_2)
+        .unwrap();
+    let (p_0_p_1_hi, p_0_p_1_lo) = p_0_p_1.split_in_half();
+    let mut out = Vec::with_capacity(product_len);
+    // The sum of the coefficients not yet written out, shifted right by 64 bits for each word
+    // written so far. It is always less than $2^{192}$.
+    let mut carry_lo = 0u64;
+    let mut carry_hi = 0u128;
+    for i in 0..product_len {
+        if i < convolution_len {
+            let (x_0, x_1, x_2) = (r_0[i], r_1[i], r_2[i]);
+            // t = x_0 + p_0 * t_1 is the coefficient modulo p_0 * p_1.
+            let t_1 = x_1
+                .mod_sub(x_0 % p_1, p_1)
+                .mod_mul_precomputed(p_0_inverse, p_1, &data_1);
+            let t = u128::from(x_0) + u128::from(p_0) * u128::from(t_1);
+            // c = t + p_0 * p_1 * t_2 is the coefficient.
+            let t_2 = x_2
+                .mod_sub(u64::wrapping_from(t % u128::from(p_2)), p_2)
+                .mod_mul_precomputed(p_0_p_1_inverse, p_2, &data_2);
+            let (lo_product_hi, lo_product_lo) =
+                (u128::from(p_0_p_1_lo) * u128::from(t_2)).split_in_half();
+            let c_hi = u128::from(p_0_p_1_hi) * u128::from(t_2) + u128::from(lo_product_hi);
+            let (t_hi, t_lo) = t.split_in_half();
+            let (c_lo, overflow) = lo_product_lo.overflowing_add(t_lo);
+            let c_hi = c_hi + u128::from(t_hi) + u128::from(overflow);
+            let (sum, overflow) = carry_lo.overflowing_add(c_lo);
+            carry_lo = sum;
+            carry_hi += c_hi + u128::from(overflow);
+        }
+        out.push(carry_lo);
+        let (hi, lo) = carry_hi.split_in_half();
+        carry_lo = lo;
+        carry_hi = u128::from(hi);
+    }
+    assert_eq!(carry_lo, 0);
+    assert_eq!(carry_hi, 0);
+    out
+}
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the `xs.len() + ys.len()` least-significant limbs of the product of the `Natural`s to an output
+// slice, using a number-theoretic transform over three word-size primes. The output must be at
+// least as long as `xs.len() + ys.len()`, `xs` must be at least as long as `ys`, and `ys` cannot
+// be empty.
+//
+// Unlike the other multiplication algorithms, this one allocates its own memory rather than taking
+// a scratch slice, since it works with 64-bit words whatever the width of a `Limb` is. If the
+// `parallel` feature is enabled and the inputs are large enough, the transforms for the three
+// primes run on separate threads.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is shorter than `ys`, or `ys` is empty.
+pub_crate_test! {limbs_mul_greater_to_out_ntt(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert!(xs_len >= ys_len);
+    assert_ne!(ys_len, 0);
+    assert!(out.len() >= xs_len + ys_len);
+    let product = words_mul_ntt(
+        &limbs_to_words(xs),
+        Some(&limbs_to_words(ys)),
+        use_threads(xs_len),
+    );
+    words_to_limbs(&mut out[..xs_len + ys_len], &product);
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// `2 * xs.len()` least-significant limbs of the square of the `Natural` to an output slice, using
+// a number-theoretic transform over three word-size primes. The output must be at least twice as
+// long as `xs.len()`, and `xs` cannot be empty.
+//
+// This transforms the input only once per prime, so it does about two thirds of the work of
+// `limbs_mul_greater_to_out_ntt`.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short or `xs` is empty.
+pub_crate_test! {limbs_square_to_out_ntt(out: &mut [Limb], xs: &[Limb]) {
+    let xs_len = xs.len();
+    assert_ne!(xs_len, 0);
+    assert!(out.len() >= xs_len << 1);
+    let product = words_mul_ntt(&limbs_to_words(xs), None, use_threads(xs_len));
+    words_to_limbs(&mut out[..xs_len << 1], &product);
+}}
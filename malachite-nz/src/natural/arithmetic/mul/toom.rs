@@ -34,6 +34,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_same_length_with_borrow_in_to_out,
 };
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::parallel::{join, use_threads};
 use crate::platform::{
     Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM44_THRESHOLD,
     MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD,
@@ -100,6 +101,41 @@ pub(crate) fn limbs_toom_couple_handling(
     }
 }
 
+// Computes two independent same-length products with `mul`: `xs_0 * ys_0` is written to `out_0`
+// and `xs_1 * ys_1` to `out_1`. When `threaded` is true, the products are computed on separate
+// threads, and the second one gets its own scratch space of length `scratch_len(xs_1.len())`.
+// Otherwise, they are computed one after the other, both using `scratch`.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs_0.len()`.
+fn limbs_mul_same_length_to_out_pair(
+    mul: fn(&mut [Limb], &[Limb], &[Limb], &mut [Limb]),
+    scratch_len: fn(usize) -> usize,
+    threaded: bool,
+    out_0: &mut [Limb],
+    xs_0: &[Limb],
+    ys_0: &[Limb],
+    out_1: &mut [Limb],
+    xs_1: &[Limb],
+    ys_1: &[Limb],
+    scratch: &mut [Limb],
+) {
+    if threaded {
+        let mut scratch_1 = vec![0; scratch_len(xs_1.len())];
+        join(
+            || mul(out_0, xs_0, ys_0, scratch),
+            || mul(out_1, xs_1, ys_1, &mut scratch_1),
+        );
+    } else {
+        mul(out_0, xs_0, ys_0, scratch);
+        mul(out_1, xs_1, ys_1, scratch);
+    }
+}
+
 // TODO make these compiler flags?
 //TODO tune
 #[cfg(feature = "test_build")]
@@ -3199,6 +3235,7 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     let (r4, remainder) = remainder.split_at_mut(n << 2);
     split_into_chunks_mut!(remainder, m, [v0, v1, v2], _unused);
     let (v3, mul_scratch) = scratch2.split_at_mut(m);
+    let threaded = use_threads(xs_len);
     // Evaluation and recursive calls
     // 1/2, -1/2
     let out_lo_lo = &mut out_lo[..m];
@@ -3209,10 +3246,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
             );
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r5, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_6h_recursive,
+        limbs_mul_same_length_to_out_toom_6h_recursive_scratch_len,
+        threaded,
+        out_lo,
+        v0,
+        v1,
+        r5,
+        v2,
+        v3,
+        mul_scratch,
+    );
     if half {
         limbs_toom_couple_handling(r5, &mut out_lo[..r], v_neg_half_neg, n, 2, 1);
     } else {
@@ -3231,10 +3277,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     }
     // X(-1) * Y(-1)
     // X(1) * Y(1)
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r3, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_6h_recursive,
+        limbs_mul_same_length_to_out_toom_6h_recursive_scratch_len,
+        threaded,
+        out_lo,
+        v0,
+        v1,
+        r3,
+        v2,
+        v3,
+        mul_scratch,
+    );
     limbs_toom_couple_handling(r3, &mut out_lo[..r], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let out_lo_lo = &mut out_lo[..m];
@@ -3242,10 +3297,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
         limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, p, xs, n, 2, out_lo_lo)
             != limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v3, v1, q, ys, n, 2, out_lo_lo);
     // X(-4) * Y(-4)
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r1, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_6h_recursive,
+        limbs_mul_same_length_to_out_toom_6h_recursive_scratch_len,
+        threaded,
+        out_lo,
+        v0,
+        v1,
+        r1,
+        v2,
+        v3,
+        mul_scratch,
+    );
     // X(4) * B(4)
     limbs_toom_couple_handling(r1, &mut out_lo[..r], v_neg_4_neg, n, 2, 4);
     // 1/4, -1/4
@@ -3257,10 +3321,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
             );
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r4, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_6h_recursive,
+        limbs_mul_same_length_to_out_toom_6h_recursive_scratch_len,
+        threaded,
+        out_lo,
+        v0,
+        v1,
+        r4,
+        v2,
+        v3,
+        mul_scratch,
+    );
     if half {
         limbs_toom_couple_handling(r4, &mut out_lo[..r], v_neg_quarter_neg, n, 4, 2);
     } else {
@@ -3681,6 +3754,7 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     let m = n + 1;
     let u = m + n; // 2 * n + 1
     let r = 3 * n + 1;
+    let threaded = use_threads(xs_len);
     // Evaluation and recursive calls
     let (pp_lo, remainder) = out.split_at_mut(3 * n);
     split_into_chunks_mut!(remainder, n << 2, [r6, r4], remainder);
@@ -3696,10 +3770,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/8) * Y(-1/8) * 8^
     // X(1/8) * Y(1/8) * 8^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r7, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r7,
+        v2,
+        v3,
+        mul_scratch,
+    );
     let limit = if BIT_CORRECTION { m << 1 } else { u };
     let pp_lo_lo = &mut pp_lo[..limit];
     if half {
@@ -3718,10 +3801,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r5, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r5,
+        v2,
+        v3,
+        mul_scratch,
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r5, pp_lo_lo, v_neg_quarter_neg, n, 4, 2);
@@ -3734,10 +3826,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
         != limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v3, v1, q, ys, n, pp_lo_lo);
     // X(-2) * Y(-2)
     // X(2) * Y(2)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r3, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r3,
+        v2,
+        v3,
+        mul_scratch,
+    );
     limbs_toom_couple_handling(r3, &mut pp_lo[..u], v_neg_2_neg, n, 1, 2);
     // 8, -8
     let pp_lo_lo = &mut pp_lo[..m];
@@ -3746,10 +3847,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             != limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v3, v1, q, ys, n, 3, pp_lo_lo);
     // X(-8) * Y(-8)
     // X(8) * Y(8)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r1, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r1,
+        v2,
+        v3,
+        mul_scratch,
+    );
     limbs_toom_couple_handling(
         &mut scratch[3 * r..],
         &mut pp_lo[..limit],
@@ -3768,10 +3878,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r6, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r6,
+        v2,
+        v3,
+        mul_scratch,
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r6, pp_lo_lo, v_neg_half_neg, n, 2, 1);
@@ -3790,10 +3909,19 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     }
     // X(-1) * Y(-1)
     // X(1) * Y(1)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r4, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_mul_same_length_to_out_pair(
+        limbs_mul_same_length_to_out_toom_8h_recursive,
+        limbs_mul_same_length_to_out_toom_8h_recursive_scratch_len,
+        threaded,
+        pp_lo,
+        v0,
+        v1,
+        r4,
+        v2,
+        v3,
+        mul_scratch,
+    );
     limbs_toom_couple_handling(r4, &mut pp_lo[..u], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let pp_lo_lo = &mut pp_lo[..m];
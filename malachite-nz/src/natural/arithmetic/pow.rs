@@ -595,13 +595,13 @@ impl<'a> Pow<u64> for &'a Natural {
                 if small.significant_bits() * exp <= Limb::WIDTH {
                     Natural(Small(small.checked_pow(u32::wrapping_from(exp)).unwrap()))
                 } else {
-                    let mut out = Natural(Large(limbs_pow(&[*small], exp)));
+                    let mut out = Natural(Large(limbs_pow(&[*small], exp).into()));
                     out.demote_if_small();
                     out
                 }
             }
             (Natural(Large(ref limbs)), exp) => {
-                let mut out = Natural(Large(limbs_pow(limbs, exp)));
+                let mut out = Natural(Large(limbs_pow(limbs, exp).into()));
                 out.demote_if_small();
                 out
             }
@@ -609,6 +609,56 @@ impl<'a> Pow<u64> for &'a Natural {
     }
 }
 
+impl Natural {
+    /// Raises a [`Natural`] to a power, taking the [`Natural`] by reference and writing the result
+    /// to an existing [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so apart from scratch space, this doesn't
+    /// allocate unless the buffer needs to grow.
+    ///
+    /// $o \gets x^n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(3u32).pow_to(100, &mut out);
+    /// assert_eq!(out.to_string(), "515377520732011331036461129765621272702107522001");
+    ///
+    /// Natural::from_str("12345678987654321").unwrap().pow_to(3, &mut out);
+    /// assert_eq!(out.to_string(), "1881676411868862234942354805142998028003108518161");
+    /// ```
+    pub fn pow_to(&self, exp: u64, out: &mut Natural) {
+        match (self, exp) {
+            (_, 0) | (natural_one!(), _) => out.set_limb(1),
+            (natural_zero!(), _) => out.set_limb(0),
+            (x, 1) => out.clone_from(x),
+            (x, 2) => x.square_to(out),
+            (&Natural(Small(small)), exp) if small.significant_bits() * exp <= Limb::WIDTH => {
+                out.set_limb(small.checked_pow(u32::wrapping_from(exp)).unwrap());
+            }
+            (x, exp) => {
+                let out_limbs = out.limb_vec_for_overwrite();
+                // `limbs_pow_to_out` expects the low limbs of its output to start out as zeros.
+                out_limbs.clear();
+                let out_len = limbs_pow_to_out(out_limbs, x.limbs_slice(), exp);
+                out.finish_overwrite(out_len);
+            }
+        }
+    }
+}
+
 impl PowAssign<u64> for Natural {
     /// Raises a [`Natural`] to a power in place.
     ///
@@ -645,12 +695,12 @@ impl PowAssign<u64> for Natural {
                 if small.significant_bits() * exp <= Limb::WIDTH {
                     *small = small.checked_pow(u32::wrapping_from(exp)).unwrap();
                 } else {
-                    *self = Natural(Large(limbs_pow(&[*small], exp)));
+                    *self = Natural(Large(limbs_pow(&[*small], exp).into()));
                     self.demote_if_small();
                 }
             }
             (Natural(Large(ref mut limbs)), exp) => {
-                *self = Natural(Large(limbs_pow(limbs, exp)));
+                *self = Natural(Large(limbs_pow(limbs, exp).into()));
                 self.demote_if_small();
             }
         }
@@ -6,30 +6,36 @@ use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::{PowerOf2, Primorial, RotateLeftAssign};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, WrappingFrom};
+#[cfg(feature = "128_bit_limbs")]
+use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u128;
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
 use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
 
 #[cfg(feature = "32_bit_limbs")]
 const SMALL_PRIMORIAL_LIMIT: u64 = 29;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 const SMALL_PRIMORIAL_LIMIT: u64 = 53;
+#[cfg(feature = "128_bit_limbs")]
+const SMALL_PRIMORIAL_LIMIT: u64 = 103;
 
 // This is equivalent to `mpz_primorial_ui` from `mpz/primorial_ui.c`, GMP 6.2.1,
 // where n is too large for the primorial of n to fit in a single limb.
 #[allow(clippy::useless_conversion)]
 fn limbs_primorial(n: Limb) -> Vec<Limb> {
-    let n_u64 = u64::from(n);
+    let n_u64 = u64::exact_from(n);
     let size = usize::exact_from(n >> Limb::LOG_WIDTH);
     let size = size + (size >> 1) + 1;
     assert!(size >= limbs_prime_sieve_size::<Limb>(n_u64));
     let mut sieve = vec![0; size];
     #[cfg(feature = "32_bit_limbs")]
     let count = limbs_prime_sieve_u32(&mut sieve, n_u64);
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let count = limbs_prime_sieve_u64(&mut sieve, n);
+    #[cfg(feature = "128_bit_limbs")]
+    let count = limbs_prime_sieve_u128(&mut sieve, n_u64);
     let size = usize::exact_from((count + 1) / log_n_max(n) + 1);
     let mut factors = vec![0; size];
     let mut j = 0;
@@ -66,8 +72,10 @@ fn limbs_primorial(n: Limb) -> Vec<Limb> {
 
 #[cfg(feature = "32_bit_limbs")]
 const SMALL_PRODUCT_OF_FIRST_N_PRIMES_LIMIT: u64 = 10;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 const SMALL_PRODUCT_OF_FIRST_N_PRIMES_LIMIT: u64 = 16;
+#[cfg(feature = "128_bit_limbs")]
+const SMALL_PRODUCT_OF_FIRST_N_PRIMES_LIMIT: u64 = 27;
 
 fn limbs_product_of_first_n_primes(n: usize) -> Vec<Limb> {
     let mut prod: Limb = 1;
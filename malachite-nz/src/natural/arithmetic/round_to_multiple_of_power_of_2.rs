@@ -540,7 +540,7 @@ impl RoundToMultipleOfPowerOf2Assign<u64> for Natural {
                 *self <<= pow;
             }
             (Natural(Large(ref mut limbs)), pow) => {
-                if limbs_round_to_multiple_of_power_of_2_in_place(limbs, pow, rm) {
+                if limbs_round_to_multiple_of_power_of_2_in_place(limbs.vec_mut(), pow, rm) {
                     self.trim();
                 } else {
                     panic!("Rounding is not exact");
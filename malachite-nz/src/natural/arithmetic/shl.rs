@@ -6,6 +6,7 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::slices::slice_set_zero;
 use malachite_base::vecs::vec_pad_left;
 use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
 
@@ -173,11 +174,11 @@ where
             Natural(if let Some(shifted) = small.arithmetic_checked_shl(bits) {
                 Small(shifted)
             } else {
-                Large(limbs_shl(&[*small], u64::exact_from(bits)))
+                Large(limbs_shl(&[*small], u64::exact_from(bits)).into())
             })
         }
         (Natural(Large(ref limbs)), bits) => {
-            Natural(Large(limbs_shl(limbs, u64::exact_from(bits))))
+            Natural(Large(limbs_shl(limbs, u64::exact_from(bits)).into()))
         }
     }
 }
@@ -194,11 +195,74 @@ where
             if let Some(shifted) = small.arithmetic_checked_shl(bits) {
                 *small = shifted;
             } else {
-                *x = Natural(Large(limbs_shl(&[*small], u64::exact_from(bits))));
+                *x = Natural(Large(limbs_shl(&[*small], u64::exact_from(bits)).into()));
             }
         }
         (Natural(Large(ref mut limbs)), bits) => {
-            limbs_vec_shl_in_place(limbs, u64::exact_from(bits));
+            limbs_vec_shl_in_place(limbs.vec_mut(), u64::exact_from(bits));
+        }
+    }
+}
+
+impl Natural {
+    /// Left-shifts a [`Natural`] (multiplies it by a power of 2), taking it by reference and
+    /// writing the result to an existing [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $o \gets x2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(123u32).shl_to(2, &mut out);
+    /// assert_eq!(out, 492);
+    ///
+    /// Natural::from(123u32).shl_to(100, &mut out);
+    /// assert_eq!(out.to_string(), "155921023828072216384094494261248");
+    /// ```
+    pub fn shl_to(&self, bits: u64, out: &mut Natural) {
+        match self {
+            natural_zero!() => {
+                out.set_limb(0);
+                return;
+            }
+            &Natural(Small(small)) => {
+                if let Some(shifted) = small.arithmetic_checked_shl(bits) {
+                    out.set_limb(shifted);
+                    return;
+                }
+            }
+            _ => {}
+        }
+        let xs = self.limbs_slice();
+        let small_shift = usize::exact_from(bits >> Limb::LOG_WIDTH);
+        let bits = bits & Limb::WIDTH_MASK;
+        let len = xs.len() + small_shift;
+        if bits == 0 {
+            let out_limbs = out.limbs_for_overwrite(len);
+            let (out_lo, out_hi) = out_limbs.split_at_mut(small_shift);
+            slice_set_zero(out_lo);
+            out_hi.copy_from_slice(xs);
+            out.finish_overwrite(len);
+        } else {
+            let out_limbs = out.limbs_for_overwrite(len + 1);
+            let (out_lo, out_hi) = out_limbs.split_at_mut(small_shift);
+            slice_set_zero(out_lo);
+            out_hi[xs.len()] = limbs_shl_to_out(out_hi, xs, bits);
+            out.finish_overwrite(len + 1);
         }
     }
 }
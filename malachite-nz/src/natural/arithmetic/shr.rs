@@ -132,6 +132,65 @@ pub_crate_test! {limbs_vec_shr_in_place(xs: &mut Vec<Limb>, bits: u64) {
     }
 }}
 
+impl Natural {
+    /// Right-shifts a [`Natural`] (divides it by a power of 2 and takes the floor), taking it by
+    /// reference and writing the result to an existing [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $o \gets \lfloor x/2^k \rfloor$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(1, self.significant_bits() - bits)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(492u32).shr_to(2, &mut out);
+    /// assert_eq!(out, 123);
+    ///
+    /// Natural::from(10u32).pow(12).shr_to(10, &mut out);
+    /// assert_eq!(out, 976562500);
+    /// ```
+    pub fn shr_to(&self, bits: u64, out: &mut Natural) {
+        match self {
+            &Natural(Small(small)) => {
+                out.set_limb(if bits < Limb::WIDTH { small >> bits } else { 0 });
+            }
+            Natural(Large(ref xs)) => {
+                let delete_count = bits >> Limb::LOG_WIDTH;
+                if delete_count >= u64::exact_from(xs.len()) {
+                    out.set_limb(0);
+                    return;
+                }
+                let xs = &xs[usize::exact_from(delete_count)..];
+                let small_shift = bits & Limb::WIDTH_MASK;
+                if xs.len() == 1 {
+                    out.set_limb(xs[0] >> small_shift);
+                    return;
+                }
+                let out_limbs = out.limbs_for_overwrite(xs.len());
+                if small_shift == 0 {
+                    out_limbs.copy_from_slice(xs);
+                } else {
+                    limbs_shr_to_out(out_limbs, xs, small_shift);
+                }
+                out.finish_overwrite(xs.len());
+            }
+        }
+    }
+}
+
 fn shr_unsigned_ref<T: Copy + Eq + Ord + WrappingFrom<u64> + Zero>(x: &Natural, bits: T) -> Natural
 where
     u64: ExactFrom<T>,
@@ -163,7 +222,7 @@ where
             *small >>= bits;
         }
         (Natural(Large(ref mut limbs)), bits) => {
-            limbs_vec_shr_in_place(limbs, u64::exact_from(bits));
+            limbs_vec_shr_in_place(limbs.vec_mut(), u64::exact_from(bits));
             x.trim();
         }
     }
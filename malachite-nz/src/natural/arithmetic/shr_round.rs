@@ -290,7 +290,7 @@ where
             small.shr_round_assign(bits, rm);
         }
         (Natural(Large(ref mut limbs)), bits) => {
-            if !limbs_vec_shr_round_in_place(limbs, u64::exact_from(bits), rm) {
+            if !limbs_vec_shr_round_in_place(limbs.vec_mut(), u64::exact_from(bits), rm) {
                 panic!("Right shift is not exact.");
             }
             x.trim();
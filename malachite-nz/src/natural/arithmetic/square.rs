@@ -9,6 +9,7 @@ use crate::natural::arithmetic::mul::fft::{
 };
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
 use crate::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+use crate::natural::arithmetic::mul::ntt::limbs_square_to_out_ntt;
 use crate::natural::arithmetic::mul::poly_eval::{
     limbs_mul_toom_evaluate_deg_3_poly_in_1_and_neg_1,
     limbs_mul_toom_evaluate_deg_3_poly_in_2_and_neg_2, limbs_mul_toom_evaluate_poly_in_1_and_neg_1,
@@ -28,11 +29,13 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left, limbs_sub_same_length_to_out,
 };
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
-    DoubleLimb, Limb, SQR_BASECASE_THRESHOLD, SQR_TOOM2_THRESHOLD, SQR_TOOM3_THRESHOLD,
-    SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
+    DoubleLimb, Limb, SQR_BASECASE_THRESHOLD, SQR_FFT_THRESHOLD, SQR_NTT_THRESHOLD,
+    SQR_TOOM2_THRESHOLD, SQR_TOOM3_THRESHOLD, SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD,
+    SQR_TOOM8_THRESHOLD,
 };
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
@@ -44,8 +47,6 @@ use malachite_base::num::conversion::traits::{SplitInHalf, WrappingFrom};
 use malachite_base::rounding_modes::RoundingMode;
 use std::cmp::{max, Ordering};
 
-const SQR_FFT_MODF_THRESHOLD: usize = SQR_TOOM3_THRESHOLD * 3;
-
 // # Worst-case complexity
 // $T(n) = O(n)$
 //
@@ -719,9 +720,6 @@ pub_test! {limbs_square_to_out_toom_6(out: &mut [Limb], xs: &[Limb], scratch: &m
     limbs_mul_toom_interpolate_12_points(out, r1, r3, r5, n, s << 1, false, wse);
 }}
 
-//TODO tune
-pub(crate) const SQR_FFT_THRESHOLD: usize = SQR_FFT_MODF_THRESHOLD * 10;
-
 // This function can be used to determine whether the size of the input slice to
 // `limbs_square_to_out_toom_8` is valid.
 //
@@ -951,8 +949,10 @@ limbs_square_to_out_scratch_len(n: usize) -> usize {
         limbs_square_to_out_toom_6_scratch_len(n)
     } else if n < SQR_FFT_THRESHOLD {
         limbs_square_to_out_toom_8_scratch_len(n)
-    } else {
+    } else if n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_fft_scratch_len(n)
+    } else {
+        0
     }
 }}
 
@@ -985,8 +985,10 @@ limbs_square_to_out(out: &mut [Limb], xs: &[Limb], scratch: &mut [Limb]) {
         limbs_square_to_out_toom_6(out, xs, scratch);
     } else if n < SQR_FFT_THRESHOLD {
         limbs_square_to_out_toom_8(out, xs, scratch);
-    } else {
+    } else if n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_fft(out, xs, scratch);
+    } else {
+        limbs_square_to_out_ntt(out, xs);
     }
 }}
 
@@ -1063,7 +1065,7 @@ impl<'a> Square for &'a Natural {
                 if upper == 0 {
                     Small(lower)
                 } else {
-                    Large(vec![lower, upper])
+                    Large(LimbVec::from_slice(&[lower, upper]))
                 }
             }),
             Natural(Large(ref xs)) => Natural::from_owned_limbs_asc(limbs_square(xs)),
@@ -1071,6 +1073,63 @@ impl<'a> Square for &'a Natural {
     }
 }
 
+impl Natural {
+    /// Squares a [`Natural`], taking it by reference and writing the result to an existing
+    /// [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow. The scratch space needed by the squaring is taken from the same buffer, so
+    /// after a buffer has grown once, later calls with inputs of the same size don't allocate.
+    ///
+    /// $$
+    /// o \gets x^2.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(123u32).square_to(&mut out);
+    /// assert_eq!(out, 15129);
+    ///
+    /// Natural::from(10u32).pow(20).square_to(&mut out);
+    /// assert_eq!(out, Natural::from(10u32).pow(40));
+    /// ```
+    pub fn square_to(&self, out: &mut Natural) {
+        match self {
+            &Natural(Small(x)) => {
+                let (upper, lower) = Limb::x_mul_y_to_zz(x, x);
+                if upper == 0 {
+                    out.set_limb(lower);
+                } else {
+                    let out_limbs = out.limbs_for_overwrite(2);
+                    out_limbs[0] = lower;
+                    out_limbs[1] = upper;
+                }
+            }
+            Natural(Large(ref xs)) => {
+                let out_len = xs.len() << 1;
+                let scratch_len = limbs_square_to_out_scratch_len(xs.len());
+                let (out_limbs, square_scratch) = out
+                    .limbs_for_overwrite(out_len + scratch_len)
+                    .split_at_mut(out_len);
+                limbs_square_to_out(out_limbs, xs, square_scratch);
+                out.finish_overwrite(out_len);
+            }
+        }
+    }
+}
+
 impl SquareAssign for Natural {
     /// Squares a [`Natural`] in place.
     ///
@@ -1107,11 +1166,11 @@ impl SquareAssign for Natural {
                 if upper == 0 {
                     *x = lower;
                 } else {
-                    *self = Natural(Large(vec![lower, upper]));
+                    *self = Natural(Large(LimbVec::from_slice(&[lower, upper])));
                 }
             }
             Natural(Large(ref mut xs)) => {
-                *xs = limbs_square(xs);
+                *xs = limbs_square(xs).into();
                 self.trim();
             }
         }
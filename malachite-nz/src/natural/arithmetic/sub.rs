@@ -1,3 +1,4 @@
+use crate::natural::InnerNatural::Small;
 use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::{CheckedSub, OverflowingSubAssign};
@@ -155,6 +156,17 @@ pub_crate_test! {limbs_sub(xs: &[Limb], ys: &[Limb]) -> (Vec<Limb>, bool) {
 //
 // This is equivalent to `mpn_sub_n` from `gmp.h`, GMP 6.2.1.
 pub_crate_test! {limbs_sub_same_length_to_out(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) -> bool {
+    x86_64_kernel!(limbs_sub_same_length_to_out(out, xs, ys));
+    limbs_sub_same_length_to_out_portable(out, xs, ys)
+}}
+
+// The portable implementation of `limbs_sub_same_length_to_out`, which is used when no faster
+// kernel is available.
+pub_test! {limbs_sub_same_length_to_out_portable(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+) -> bool {
     let len = xs.len();
     assert_eq!(len, ys.len());
     assert!(out.len() >= len);
@@ -216,6 +228,13 @@ pub_crate_test! {limbs_sub_greater_to_out(out: &mut [Limb], xs: &[Limb], ys: &[L
 // This is equivalent to `mpn_sub_n` from `gmp.h`, GMP 6.2.1, where the output is written to the
 // first input.
 pub_crate_test! {limbs_sub_same_length_in_place_left(xs: &mut [Limb], ys: &[Limb]) -> bool {
+    x86_64_kernel!(limbs_sub_same_length_in_place_left(xs, ys));
+    limbs_sub_same_length_in_place_left_portable(xs, ys)
+}}
+
+// The portable implementation of `limbs_sub_same_length_in_place_left`, which is used when no
+// faster kernel is available.
+pub_test! {limbs_sub_same_length_in_place_left_portable(xs: &mut [Limb], ys: &[Limb]) -> bool {
     assert_eq!(xs.len(), ys.len());
     let mut borrow = false;
     for (x, &y) in xs.iter_mut().zip(ys.iter()) {
@@ -515,6 +534,58 @@ impl Natural {
             sub_panic(self, other);
         })
     }
+
+    /// Subtracts a [`Natural`] by another [`Natural`], taking both by reference and writing the
+    /// difference to an existing [`Natural`].
+    ///
+    /// If `out` already has a buffer, it is reused, so this doesn't allocate unless the buffer
+    /// needs to grow.
+    ///
+    /// $$
+    /// o \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the buffer of `out` needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is greater than `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = Natural::ZERO;
+    /// Natural::from(456u32).sub_to(&Natural::from(123u32), &mut out);
+    /// assert_eq!(out, 333);
+    ///
+    /// (Natural::from(10u32).pow(12) * Natural::from(3u32))
+    ///     .sub_to(&Natural::from(10u32).pow(12), &mut out);
+    /// assert_eq!(out, 2000000000000u64);
+    /// ```
+    pub fn sub_to(&self, other: &Natural, out: &mut Natural) {
+        if let (&Natural(Small(x)), &Natural(Small(y))) = (self, other) {
+            out.set_limb(x.checked_sub(y).unwrap_or_else(|| sub_panic(x, y)));
+            return;
+        }
+        if self < other {
+            sub_panic(self, other);
+        }
+        let xs = self.limbs_slice();
+        let out_limbs = out.limbs_for_overwrite(xs.len());
+        assert!(!limbs_sub_greater_to_out(
+            out_limbs,
+            xs,
+            other.limbs_slice()
+        ));
+        out.finish_overwrite(xs.len());
+    }
 }
 
 impl Sub<Natural> for Natural {
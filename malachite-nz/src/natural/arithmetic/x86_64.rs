@@ -0,0 +1,275 @@
+// Kernels for x86_64 that use instructions which aren't part of the baseline target: ADX, which
+// adds a second, independent carry flag, and BMI2, whose `mulx` multiplies without touching the
+// flags. Together they let a multiply-accumulate loop keep its carries in flags instead of
+// recomputing them from double-limb arithmetic, which is where GMP's x86_64 assembly gets most of
+// its advantage for these functions.
+//
+// Each public function here has the same name and arguments as the portable function that it
+// replaces, checks the same preconditions, and returns `None` if the CPU lacks the required
+// instructions, in which case the portable function runs instead. Dispatch happens through the
+// `x86_64_kernel` macro. The CPU's features are detected at runtime, on first use, by
+// `is_x86_feature_detected`, which caches them, so later checks are just a load.
+//
+// AVX2 isn't used: every limb of a sum or product depends on the carry out of the previous limb,
+// and vector units have no way to propagate carries across lanes.
+
+use crate::platform::Limb;
+use std::arch::x86_64::{_addcarryx_u64, _mulx_u64, _subborrow_u64};
+
+#[inline]
+fn has_adx() -> bool {
+    is_x86_feature_detected!("adx")
+}
+
+#[inline]
+fn has_adx_and_bmi2() -> bool {
+    is_x86_feature_detected!("adx") && is_x86_feature_detected!("bmi2")
+}
+
+#[target_feature(enable = "adx")]
+unsafe fn add_same_length_to_out_adx(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) -> bool {
+    let mut carry = 0;
+    for (out, (&x, &y)) in out.iter_mut().zip(xs.iter().zip(ys.iter())) {
+        carry = _addcarryx_u64(carry, x, y, out);
+    }
+    carry != 0
+}
+
+#[target_feature(enable = "adx")]
+unsafe fn add_same_length_in_place_left_adx(xs: &mut [Limb], ys: &[Limb]) -> bool {
+    let mut carry = 0;
+    for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+        carry = _addcarryx_u64(carry, *x, y, x);
+    }
+    carry != 0
+}
+
+#[target_feature(enable = "adx,bmi2")]
+unsafe fn mul_limb_with_carry_to_out_adx_bmi2(
+    out: &mut [Limb],
+    xs: &[Limb],
+    y: Limb,
+    mut carry: Limb,
+) -> Limb {
+    for (out, &x) in out.iter_mut().zip(xs.iter()) {
+        let mut hi = 0;
+        let lo = _mulx_u64(x, y, &mut hi);
+        // x * y + carry < 2^128, so `hi` + the carry out of the low half doesn't overflow.
+        carry = hi + Limb::from(_addcarryx_u64(0, lo, carry, out));
+    }
+    carry
+}
+
+#[target_feature(enable = "adx,bmi2")]
+unsafe fn mul_limb_with_carry_in_place_adx_bmi2(xs: &mut [Limb], y: Limb, mut carry: Limb) -> Limb {
+    for x in xs.iter_mut() {
+        let mut hi = 0;
+        let lo = _mulx_u64(*x, y, &mut hi);
+        carry = hi + Limb::from(_addcarryx_u64(0, lo, carry, x));
+    }
+    carry
+}
+
+#[target_feature(enable = "adx,bmi2")]
+unsafe fn add_mul_limb_same_length_in_place_left_adx_bmi2(
+    xs: &mut [Limb],
+    ys: &[Limb],
+    z: Limb,
+) -> Limb {
+    let mut carry = 0;
+    for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+        let mut hi = 0;
+        let lo = _mulx_u64(y, z, &mut hi);
+        let mut sum = 0;
+        let carry_1 = _addcarryx_u64(0, lo, carry, &mut sum);
+        let carry_2 = _addcarryx_u64(0, sum, *x, x);
+        // x + y * z + carry < 2^128, so the high limb can absorb both carries.
+        carry = hi + Limb::from(carry_1) + Limb::from(carry_2);
+    }
+    carry
+}
+
+// Subtraction has no ADX counterpart, but `sbb` is part of the baseline target, so the borrow
+// chain is available on every x86_64 CPU.
+fn sub_same_length_to_out_sbb(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) -> bool {
+    let mut borrow = 0;
+    for (out, (&x, &y)) in out.iter_mut().zip(xs.iter().zip(ys.iter())) {
+        // This is safe because `sbb` is available on all x86_64 CPUs.
+        borrow = unsafe { _subborrow_u64(borrow, x, y, out) };
+    }
+    borrow != 0
+}
+
+fn sub_same_length_in_place_left_sbb(xs: &mut [Limb], ys: &[Limb]) -> bool {
+    let mut borrow = 0;
+    for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+        // This is safe because `sbb` is available on all x86_64 CPUs.
+        borrow = unsafe { _subborrow_u64(borrow, *x, y, x) };
+    }
+    borrow != 0
+}
+
+// The x86_64 kernel for `limbs_add_same_length_to_out`, or `None` if the CPU doesn't support ADX.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `xs` and `ys` have different lengths or if `out` is too short.
+pub_crate_test! {limbs_add_same_length_to_out(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+) -> Option<bool> {
+    let len = xs.len();
+    assert_eq!(len, ys.len());
+    assert!(out.len() >= len);
+    if has_adx() {
+        // This is safe because the CPU supports ADX.
+        Some(unsafe { add_same_length_to_out_adx(out, xs, ys) })
+    } else {
+        None
+    }
+}}
+
+// The x86_64 kernel for `limbs_slice_add_same_length_in_place_left`, or `None` if the CPU doesn't
+// support ADX.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `xs` and `ys` have different lengths.
+pub_crate_test! {limbs_slice_add_same_length_in_place_left(
+    xs: &mut [Limb],
+    ys: &[Limb],
+) -> Option<bool> {
+    assert_eq!(xs.len(), ys.len());
+    if has_adx() {
+        // This is safe because the CPU supports ADX.
+        Some(unsafe { add_same_length_in_place_left_adx(xs, ys) })
+    } else {
+        None
+    }
+}}
+
+// The x86_64 kernel for `limbs_sub_same_length_to_out`. This is always available.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is shorter than `xs` or if `xs` and `ys` have different lengths.
+pub_crate_test! {limbs_sub_same_length_to_out(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ys: &[Limb],
+) -> Option<bool> {
+    let len = xs.len();
+    assert_eq!(len, ys.len());
+    assert!(out.len() >= len);
+    Some(sub_same_length_to_out_sbb(out, xs, ys))
+}}
+
+// The x86_64 kernel for `limbs_sub_same_length_in_place_left`. This is always available.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `xs` and `ys` have different lengths.
+pub_crate_test! {limbs_sub_same_length_in_place_left(xs: &mut [Limb], ys: &[Limb]) -> Option<bool> {
+    assert_eq!(xs.len(), ys.len());
+    Some(sub_same_length_in_place_left_sbb(xs, ys))
+}}
+
+// The x86_64 kernel for `limbs_mul_limb_with_carry_to_out`, or `None` if the CPU doesn't support
+// both ADX and BMI2.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is shorter than `xs`.
+pub_crate_test! {limbs_mul_limb_with_carry_to_out(
+    out: &mut [Limb],
+    xs: &[Limb],
+    y: Limb,
+    carry: Limb,
+) -> Option<Limb> {
+    assert!(out.len() >= xs.len());
+    if has_adx_and_bmi2() {
+        // This is safe because the CPU supports ADX and BMI2.
+        Some(unsafe { mul_limb_with_carry_to_out_adx_bmi2(out, xs, y, carry) })
+    } else {
+        None
+    }
+}}
+
+// The x86_64 kernel for `limbs_slice_mul_limb_with_carry_in_place`, or `None` if the CPU doesn't
+// support both ADX and BMI2.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+pub_crate_test! {limbs_slice_mul_limb_with_carry_in_place(
+    xs: &mut [Limb],
+    y: Limb,
+    carry: Limb,
+) -> Option<Limb> {
+    if has_adx_and_bmi2() {
+        // This is safe because the CPU supports ADX and BMI2.
+        Some(unsafe { mul_limb_with_carry_in_place_adx_bmi2(xs, y, carry) })
+    } else {
+        None
+    }
+}}
+
+// The x86_64 kernel for `limbs_slice_add_mul_limb_same_length_in_place_left`, or `None` if the CPU
+// doesn't support both ADX and BMI2.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `xs` and `ys` have different lengths.
+pub_crate_test! {limbs_slice_add_mul_limb_same_length_in_place_left(
+    xs: &mut [Limb],
+    ys: &[Limb],
+    z: Limb,
+) -> Option<Limb> {
+    assert_eq!(xs.len(), ys.len());
+    if has_adx_and_bmi2() {
+        // This is safe because the CPU supports ADX and BMI2.
+        Some(unsafe { add_mul_limb_same_length_in_place_left_adx_bmi2(xs, ys, z) })
+    } else {
+        None
+    }
+}}
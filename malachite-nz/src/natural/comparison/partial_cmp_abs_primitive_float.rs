@@ -1,6 +1,8 @@
 use crate::natural::Natural;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::comparison::traits::PartialOrdAbs;
 use malachite_base::num::conversion::traits::{ExactFrom, IntegerMantissaAndExponent};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::logic::traits::SignificantBits;
 use std::cmp::Ordering;
 
@@ -23,7 +25,7 @@ macro_rules! impl_float {
                     None
                 } else if !other.is_finite() {
                     Some(Ordering::Less)
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     self.partial_cmp_abs(&0u32)
                 } else if *self == 0u32 {
                     Some(Ordering::Less)
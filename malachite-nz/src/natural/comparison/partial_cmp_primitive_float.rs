@@ -1,5 +1,7 @@
 use crate::natural::Natural;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::{ExactFrom, IntegerMantissaAndExponent};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::logic::traits::SignificantBits;
 use std::cmp::Ordering;
 
@@ -19,11 +21,11 @@ macro_rules! impl_float {
             fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
                 if other.is_nan() {
                     None
-                } else if *other < 0.0 {
+                } else if *other < $t::ZERO {
                     Some(Ordering::Greater)
                 } else if !other.is_finite() {
                     Some(Ordering::Less)
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     self.partial_cmp(&0u32)
                 } else if *self == 0u32 {
                     Some(Ordering::Less)
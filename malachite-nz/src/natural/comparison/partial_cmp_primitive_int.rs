@@ -179,9 +179,14 @@ impl_partial_ord_limb!(u32);
 impl_partial_ord_smaller_than_limb!(u32);
 #[cfg(feature = "32_bit_limbs")]
 impl_partial_ord_larger_than_limb!(u64);
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 impl_partial_ord_limb!(u64);
+#[cfg(feature = "128_bit_limbs")]
+impl_partial_ord_smaller_than_limb!(u64);
+#[cfg(not(feature = "128_bit_limbs"))]
 impl_partial_ord_larger_than_limb!(u128);
+#[cfg(feature = "128_bit_limbs")]
+impl_partial_ord_limb!(u128);
 impl_partial_ord_larger_than_limb_or_usize!(usize);
 
 apply_to_signeds!(impl_signed);
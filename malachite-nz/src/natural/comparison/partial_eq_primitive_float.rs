@@ -1,5 +1,7 @@
 use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::IntegerMantissaAndExponent;
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::logic::traits::SignificantBits;
 use std::cmp::Ordering;
 
@@ -20,9 +22,9 @@ macro_rules! impl_float {
             fn eq(&self, other: &$t) -> bool {
                 if !other.is_finite() {
                     false
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     *self == 0u32
-                } else if *other < 1.0 || *self == 0u32 {
+                } else if *other < $t::ONE || *self == 0u32 {
                     false
                 } else {
                     let (m, e) = other.integer_mantissa_and_exponent();
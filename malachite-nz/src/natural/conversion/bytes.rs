@@ -0,0 +1,470 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitConvertible, BitIterable, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use std::fmt::{Display, Formatter};
+use std::mem::size_of;
+
+/// The error returned when a number doesn't fit in the requested number of bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BytesOverflowError;
+
+impl Display for BytesOverflowError {
+    /// Writes a description of a [`BytesOverflowError`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+    ///
+    /// assert_eq!(
+    ///     BytesOverflowError.to_string(),
+    ///     "the number doesn't fit in the requested number of bytes"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("the number doesn't fit in the requested number of bytes")
+    }
+}
+
+/// The order of the bytes within a word, or of the words within a sequence of words.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endianness {
+    /// Least-significant first.
+    Little,
+    /// Most-significant first.
+    Big,
+}
+
+impl Endianness {
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endianness = Endianness::Little;
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endianness = Endianness::Big;
+}
+
+/// A `struct` determining how a [`Natural`] is written to or read from a sequence of bytes by
+/// [`Natural::export`] and [`Natural::import`].
+///
+/// The options are the same as the arguments of GMP's `mpz_export` and `mpz_import`:
+///
+/// - The word size is the number of bytes in each word. The default is 1.
+///
+/// - The word order determines whether the least-significant or the most-significant word comes
+///   first. The default is [`Endianness::Little`].
+///
+/// - The endianness determines the order of the bytes within each word. The default is
+///   [`Endianness::Little`].
+///
+/// - The nails are the number of most-significant bits of each word that are not used. When
+///   exporting they are set to zero, and when importing they are ignored. The default is 0, and
+///   the nails must be fewer than the number of bits in a word.
+///
+/// With the default options, [`Natural::export`] is equivalent to [`Natural::to_bytes_le`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ImportExportOptions {
+    pub(crate) word_size: usize,
+    pub(crate) word_order: Endianness,
+    pub(crate) endianness: Endianness,
+    pub(crate) nails: u64,
+}
+
+impl Default for ImportExportOptions {
+    fn default() -> ImportExportOptions {
+        ImportExportOptions {
+            word_size: 1,
+            word_order: Endianness::Little,
+            endianness: Endianness::Little,
+            nails: 0,
+        }
+    }
+}
+
+impl ImportExportOptions {
+    /// Returns the number of bytes in each word. It is always positive.
+    #[inline]
+    pub const fn get_word_size(&self) -> usize {
+        self.word_size
+    }
+
+    /// Returns whether the least-significant or the most-significant word comes first.
+    #[inline]
+    pub const fn get_word_order(&self) -> Endianness {
+        self.word_order
+    }
+
+    /// Returns the order of the bytes within each word.
+    #[inline]
+    pub const fn get_endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Returns the number of unused most-significant bits in each word. It is always less than the
+    /// number of bits in a word.
+    #[inline]
+    pub const fn get_nails(&self) -> u64 {
+        self.nails
+    }
+
+    /// Sets the number of bytes in each word.
+    ///
+    /// # Panics
+    /// Panics if `word_size` is zero, or if it is too small for the current number of nails.
+    #[inline]
+    pub fn set_word_size(&mut self, word_size: usize) {
+        assert_ne!(word_size, 0);
+        assert!(self.nails < u64::exact_from(word_size) << 3);
+        self.word_size = word_size;
+    }
+
+    /// Sets whether the least-significant or the most-significant word comes first.
+    #[inline]
+    pub fn set_word_order(&mut self, word_order: Endianness) {
+        self.word_order = word_order;
+    }
+
+    /// Sets the order of the bytes within each word.
+    #[inline]
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+
+    /// Sets the number of unused most-significant bits in each word.
+    ///
+    /// # Panics
+    /// Panics if `nails` is not less than the number of bits in a word.
+    #[inline]
+    pub fn set_nails(&mut self, nails: u64) {
+        assert!(nails < u64::exact_from(self.word_size) << 3);
+        self.nails = nails;
+    }
+
+    // The number of bits of the number that each word holds.
+    fn bits_per_word(&self) -> u64 {
+        (u64::exact_from(self.word_size) << 3) - self.nails
+    }
+}
+
+// Rearranges `bytes`, which hold words in little-endian order with little-endian bytes, so that
+// they're in the order given by `options`.
+fn arrange_words(bytes: &mut [u8], options: &ImportExportOptions) {
+    if options.word_order == Endianness::Big {
+        bytes.reverse();
+        // Reversing all the bytes also reversed the bytes within each word.
+        if options.endianness == Endianness::Little {
+            for word in bytes.chunks_mut(options.word_size) {
+                word.reverse();
+            }
+        }
+    } else if options.endianness == Endianness::Big {
+        for word in bytes.chunks_mut(options.word_size) {
+            word.reverse();
+        }
+    }
+}
+
+impl Natural {
+    /// Returns the bytes of a [`Natural`], in ascending order, so that less significant bytes have
+    /// lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes. This function borrows the [`Natural`].
+    ///
+    /// The result doesn't depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_le().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_le(), &[123]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from(10u32).pow(12).to_bytes_le(), &[0x00, 0x10, 0xa5, 0xd4, 0xe8]);
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(usize::exact_from(self.limb_count()) * size_of::<Limb>());
+        for limb in self.limbs() {
+            bytes.extend_from_slice(&limb.to_le_bytes());
+        }
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`], in descending order, so that less significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes. This function borrows the [`Natural`].
+    ///
+    /// The result doesn't depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_be(), &[123]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from(10u32).pow(12).to_bytes_be(), &[0xe8, 0xd4, 0xa5, 0x10, 0x00]);
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`], in ascending order, padded with zeros to a specified
+    /// length. Returns an error if the [`Natural`] doesn't fit in that many bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), len)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(4), Ok(vec![0x34, 0x12, 0, 0]));
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(2), Ok(vec![0x34, 0x12]));
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(1), Err(BytesOverflowError));
+    /// ```
+    pub fn to_bytes_le_padded(&self, len: usize) -> Result<Vec<u8>, BytesOverflowError> {
+        let mut bytes = self.to_bytes_le();
+        if bytes.len() > len {
+            Err(BytesOverflowError)
+        } else {
+            bytes.resize(len, 0);
+            Ok(bytes)
+        }
+    }
+
+    /// Returns the bytes of a [`Natural`], in descending order, padded with zeros to a specified
+    /// length. Returns an error if the [`Natural`] doesn't fit in that many bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), len)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(4), Ok(vec![0, 0, 0x12, 0x34]));
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(2), Ok(vec![0x12, 0x34]));
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(1), Err(BytesOverflowError));
+    /// ```
+    pub fn to_bytes_be_padded(&self, len: usize) -> Result<Vec<u8>, BytesOverflowError> {
+        let mut bytes = self.to_bytes_le_padded(len)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// Converts a slice of bytes, in ascending order, to a [`Natural`].
+    ///
+    /// Trailing zero bytes are allowed and ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_le(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_le(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_le(&[0x00, 0x10, 0xa5, 0xd4, 0xe8, 0, 0]), 1000000000000u64);
+    /// ```
+    pub fn from_bytes_le(bytes: &[u8]) -> Natural {
+        const LIMB_BYTES: usize = size_of::<Limb>();
+        Natural::from_owned_limbs_asc(
+            bytes
+                .chunks(LIMB_BYTES)
+                .map(|chunk| {
+                    let mut limb_bytes = [0; LIMB_BYTES];
+                    limb_bytes[..chunk.len()].copy_from_slice(chunk);
+                    Limb::from_le_bytes(limb_bytes)
+                })
+                .collect(),
+        )
+    }
+
+    /// Converts a slice of bytes, in descending order, to a [`Natural`].
+    ///
+    /// Leading zero bytes are allowed and ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_be(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_be(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_be(&[0, 0, 0xe8, 0xd4, 0xa5, 0x10, 0x00]), 1000000000000u64);
+    /// ```
+    pub fn from_bytes_be(bytes: &[u8]) -> Natural {
+        const LIMB_BYTES: usize = size_of::<Limb>();
+        Natural::from_owned_limbs_asc(
+            bytes
+                .rchunks(LIMB_BYTES)
+                .map(|chunk| {
+                    let mut limb_bytes = [0; LIMB_BYTES];
+                    limb_bytes[LIMB_BYTES - chunk.len()..].copy_from_slice(chunk);
+                    Limb::from_be_bytes(limb_bytes)
+                })
+                .collect(),
+        )
+    }
+
+    /// Writes a [`Natural`] to a sequence of words, in the same way as GMP's `mpz_export`.
+    ///
+    /// The word size, the order of the words, the order of the bytes within each word, and the
+    /// number of unused bits at the top of each word are given by `options`; see
+    /// [`ImportExportOptions`]. The result has as few words as possible, so it is empty if the
+    /// [`Natural`] is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::{Endianness, ImportExportOptions};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = Natural::from(0x123456u32);
+    /// let mut options = ImportExportOptions::default();
+    /// assert_eq!(x.export(&options), &[0x56, 0x34, 0x12]);
+    ///
+    /// // Two-byte words, most-significant word first, big-endian words
+    /// options.set_word_size(2);
+    /// options.set_word_order(Endianness::Big);
+    /// options.set_endianness(Endianness::Big);
+    /// assert_eq!(x.export(&options), &[0x00, 0x12, 0x34, 0x56]);
+    ///
+    /// // The same, but with only 12 bits used in each word
+    /// options.set_nails(4);
+    /// assert_eq!(x.export(&options), &[0x01, 0x23, 0x04, 0x56]);
+    /// ```
+    pub fn export(&self, options: &ImportExportOptions) -> Vec<u8> {
+        let word_size = options.word_size;
+        let mut bytes = if options.nails == 0 {
+            let mut bytes = self.to_bytes_le();
+            let len = bytes.len().div_round(word_size, RoundingMode::Ceiling) * word_size;
+            bytes.resize(len, 0);
+            bytes
+        } else {
+            let bits_per_word = options.bits_per_word();
+            let word_count = usize::exact_from(
+                self.significant_bits()
+                    .div_round(bits_per_word, RoundingMode::Ceiling),
+            );
+            let mut bytes = vec![0; word_count * word_size];
+            for (i, bit) in self.bits().enumerate() {
+                if bit {
+                    let i = u64::exact_from(i);
+                    let word = usize::exact_from(i / bits_per_word);
+                    let j = i % bits_per_word;
+                    bytes[word * word_size + usize::exact_from(j >> 3)] |= 1 << (j & 7);
+                }
+            }
+            bytes
+        };
+        arrange_words(&mut bytes, options);
+        bytes
+    }
+
+    /// Reads a [`Natural`] from a sequence of words, in the same way as GMP's `mpz_import`.
+    ///
+    /// The word size, the order of the words, the order of the bytes within each word, and the
+    /// number of unused bits at the top of each word are given by `options`; see
+    /// [`ImportExportOptions`]. The unused bits are ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Panics
+    /// Panics if the length of `bytes` is not a multiple of the word size.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::{Endianness, ImportExportOptions};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut options = ImportExportOptions::default();
+    /// options.set_word_size(2);
+    /// options.set_word_order(Endianness::Big);
+    /// options.set_endianness(Endianness::Big);
+    /// assert_eq!(Natural::import(&[0x00, 0x12, 0x34, 0x56], &options), 0x123456);
+    ///
+    /// // The top 4 bits of each word are ignored.
+    /// options.set_nails(4);
+    /// assert_eq!(Natural::import(&[0xf1, 0x23, 0xf4, 0x56], &options), 0x123456);
+    /// ```
+    pub fn import(bytes: &[u8], options: &ImportExportOptions) -> Natural {
+        let word_size = options.word_size;
+        assert_eq!(bytes.len() % word_size, 0);
+        let mut bytes = bytes.to_vec();
+        // `arrange_words` is its own inverse, so this puts the words into little-endian order
+        arrange_words(&mut bytes, options);
+        if options.nails == 0 {
+            Natural::from_bytes_le(&bytes)
+        } else {
+            let bits_per_word = usize::exact_from(options.bits_per_word());
+            Natural::from_bits_asc(bytes.chunks(word_size).flat_map(|word| {
+                (0..bits_per_word).map(move |j| (word[j >> 3] >> (j & 7)) & 1 != 0)
+            }))
+        }
+    }
+}
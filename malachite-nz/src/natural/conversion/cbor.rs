@@ -0,0 +1,228 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// The major types and tags of RFC 8949 that are used by the bignum codecs.
+pub(crate) const MAJOR_UNSIGNED: u8 = 0;
+pub(crate) const MAJOR_NEGATIVE: u8 = 1;
+pub(crate) const MAJOR_BYTES: u8 = 2;
+pub(crate) const MAJOR_TAG: u8 = 6;
+pub(crate) const TAG_POSITIVE_BIGNUM: u64 = 2;
+pub(crate) const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// The reasons why a byte slice may fail to decode as a CBOR number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CborError {
+    /// The input ended before the data item was complete.
+    UnexpectedEnd,
+    /// A data item had an unexpected major type. The major type that was found is included.
+    WrongMajorType(u8),
+    /// A tag other than the ones that can represent the requested type was found. The tag that
+    /// was found is included.
+    WrongTag(u64),
+    /// The additional information of an initial byte was 28, 29, or 30, which are reserved.
+    ReservedAdditionalInfo(u8),
+    /// A data item had an indefinite length, which is not supported.
+    IndefiniteLength,
+    /// The length of a byte string does not fit in a [`usize`].
+    LengthOverflow,
+    /// An array had the wrong number of elements. The number of elements that was found is
+    /// included.
+    WrongArrayLength(u64),
+    /// The value was negative but a [`Natural`] was expected.
+    Negative,
+    /// The exponent of a decimal fraction or bigfloat does not fit in an [`i64`], or its absolute
+    /// value exceeds the limit used by the decoder.
+    ExponentOutOfRange,
+    /// The denominator of a rational number was zero.
+    ZeroDenominator,
+}
+
+/// Appends the head of a CBOR data item, consisting of its major type and its argument, to a
+/// [`Vec`]. The argument is written in the shortest possible form.
+///
+/// This is a building block for encoding data items that contain numbers, such as arrays or tags.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Panics
+/// Panics if `major_type` is greater than 7.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::conversion::cbor::encode_cbor_head;
+///
+/// let mut out = Vec::new();
+/// encode_cbor_head(&mut out, 4, 2); // An array of two elements
+/// encode_cbor_head(&mut out, 0, 500); // The unsigned integer 500
+/// assert_eq!(out, &[0x82, 0x19, 0x01, 0xf4]);
+/// ```
+pub fn encode_cbor_head(out: &mut Vec<u8>, major_type: u8, argument: u64) {
+    assert!(major_type < 8);
+    let major_type = major_type << 5;
+    if argument < 24 {
+        out.push(major_type | u8::exact_from(argument));
+    } else if let Ok(argument) = u8::try_from(argument) {
+        out.push(major_type | 24);
+        out.push(argument);
+    } else if let Ok(argument) = u16::try_from(argument) {
+        out.push(major_type | 25);
+        out.extend_from_slice(&argument.to_be_bytes());
+    } else if let Ok(argument) = u32::try_from(argument) {
+        out.push(major_type | 26);
+        out.extend_from_slice(&argument.to_be_bytes());
+    } else {
+        out.push(major_type | 27);
+        out.extend_from_slice(&argument.to_be_bytes());
+    }
+}
+
+/// Decodes the head of a CBOR data item at the start of a byte slice, returning its major type,
+/// its argument, and the number of bytes that the head occupied.
+///
+/// Arguments that are not written in the shortest possible form are accepted. Indefinite lengths
+/// and the reserved additional information values are rejected.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::conversion::cbor::{decode_cbor_head, CborError};
+///
+/// assert_eq!(decode_cbor_head(&[0x82, 0x00, 0x00]), Ok((4, 2, 1)));
+/// assert_eq!(decode_cbor_head(&[0x19, 0x01, 0xf4]), Ok((0, 500, 3)));
+/// assert_eq!(decode_cbor_head(&[0x19, 0x01]), Err(CborError::UnexpectedEnd));
+/// assert_eq!(decode_cbor_head(&[0x5f]), Err(CborError::IndefiniteLength));
+/// ```
+pub fn decode_cbor_head(bytes: &[u8]) -> Result<(u8, u64, usize), CborError> {
+    let (&initial, rest) = bytes.split_first().ok_or(CborError::UnexpectedEnd)?;
+    let major_type = initial >> 5;
+    let info = initial & 0x1f;
+    let len = match info {
+        0..=23 => return Ok((major_type, u64::from(info), 1)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        31 => return Err(CborError::IndefiniteLength),
+        _ => return Err(CborError::ReservedAdditionalInfo(info)),
+    };
+    if rest.len() < len {
+        return Err(CborError::UnexpectedEnd);
+    }
+    let argument = rest[..len]
+        .iter()
+        .fold(0, |acc, &b| (acc << 8) | u64::from(b));
+    Ok((major_type, argument, len + 1))
+}
+
+// Appends an unsigned integer or a bignum with the given tag.
+pub(crate) fn encode_cbor_magnitude(out: &mut Vec<u8>, major_type: u8, tag: u64, x: &Natural) {
+    if let Ok(small) = u64::try_from(x) {
+        encode_cbor_head(out, major_type, small);
+    } else {
+        let bytes = x.to_bytes_be();
+        encode_cbor_head(out, MAJOR_TAG, tag);
+        encode_cbor_head(out, MAJOR_BYTES, u64::exact_from(bytes.len()));
+        out.extend_from_slice(&bytes);
+    }
+}
+
+// Decodes an integer or a bignum, returning whether it is non-negative, the magnitude to which
+// the CBOR encoding applies (the absolute value, minus 1 if negative), and the number of bytes
+// read.
+pub(crate) fn decode_cbor_magnitude(bytes: &[u8]) -> Result<(bool, Natural, usize), CborError> {
+    let (major_type, argument, head_len) = decode_cbor_head(bytes)?;
+    let sign = match major_type {
+        MAJOR_UNSIGNED => return Ok((true, Natural::from(argument), head_len)),
+        MAJOR_NEGATIVE => return Ok((false, Natural::from(argument), head_len)),
+        MAJOR_TAG => match argument {
+            TAG_POSITIVE_BIGNUM => true,
+            TAG_NEGATIVE_BIGNUM => false,
+            tag => return Err(CborError::WrongTag(tag)),
+        },
+        _ => return Err(CborError::WrongMajorType(major_type)),
+    };
+    let bytes = &bytes[head_len..];
+    let (major_type, len, len_len) = decode_cbor_head(bytes)?;
+    if major_type != MAJOR_BYTES {
+        return Err(CborError::WrongMajorType(major_type));
+    }
+    let len = usize::try_from(len).map_err(|_| CborError::LengthOverflow)?;
+    let contents = bytes[len_len..]
+        .get(..len)
+        .ok_or(CborError::UnexpectedEnd)?;
+    Ok((
+        sign,
+        Natural::from_bytes_be(contents),
+        head_len + len_len + len,
+    ))
+}
+
+impl Natural {
+    /// Encodes a [`Natural`] as a CBOR data item, following RFC 8949.
+    ///
+    /// [`Natural`]s less than $2^{64}$ are encoded as unsigned integers (major type 0), and
+    /// larger ones as tag 2 (an unsigned bignum) followed by a byte string containing the
+    /// [`Natural`] in big-endian order, without leading zeros. This is the preferred
+    /// serialization of RFC 8949.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(10u32).to_cbor(), &[0x0a]);
+    /// assert_eq!(Natural::from(500u32).to_cbor(), &[0x19, 0x01, 0xf4]);
+    /// assert_eq!(
+    ///     Natural::power_of_2(64).to_cbor(),
+    ///     &[0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    /// );
+    /// ```
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_cbor_magnitude(&mut out, MAJOR_UNSIGNED, TAG_POSITIVE_BIGNUM, self);
+        out
+    }
+
+    /// Decodes a [`Natural`] from a CBOR data item at the start of a byte slice, returning the
+    /// [`Natural`] and the number of bytes that were read.
+    ///
+    /// Unsigned integers (major type 0) and unsigned bignums (tag 2) are accepted, whether or not
+    /// they use the preferred serialization. Negative integers and negative bignums (tag 3) are
+    /// rejected with [`CborError::Negative`]. Bytes after the end of the data item are ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::cbor::CborError;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_cbor(&[0x19, 0x01, 0xf4]), Ok((Natural::from(500u32), 3)));
+    /// assert_eq!(Natural::from_cbor(&[0xc2, 0x42, 0x01, 0xf4]), Ok((Natural::from(500u32), 4)));
+    /// assert_eq!(Natural::from_cbor(&[0x20]), Err(CborError::Negative));
+    /// assert_eq!(Natural::from_cbor(&[0x60]), Err(CborError::WrongMajorType(3)));
+    /// ```
+    pub fn from_cbor(bytes: &[u8]) -> Result<(Natural, usize), CborError> {
+        let (sign, x, len) = decode_cbor_magnitude(bytes)?;
+        if sign {
+            Ok((x, len))
+        } else {
+            Err(CborError::Negative)
+        }
+    }
+}
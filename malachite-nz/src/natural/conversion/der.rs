@@ -0,0 +1,97 @@
+use crate::integer::conversion::der::{der_decode_contents, der_encode_contents, DerError};
+use crate::natural::Natural;
+
+// Converts `INTEGER` contents to a `Natural`, failing if they encode a negative value.
+fn natural_from_der_contents(contents: &[u8]) -> Result<Natural, DerError> {
+    if contents[0] >> 7 != 0 {
+        Err(DerError::Negative)
+    } else {
+        Ok(Natural::from_bytes_be(contents))
+    }
+}
+
+impl Natural {
+    /// Encodes a [`Natural`] as an ASN.1 `INTEGER` using the Distinguished Encoding Rules (DER).
+    ///
+    /// The output is the same as that of [`Integer::to_der`](crate::integer::Integer::to_der):
+    /// the contents have a leading `0x00` octet whenever the highest bit of the [`Natural`]'s
+    /// most significant byte is set, so that the value is not read as negative.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.to_der(), &[0x02, 0x01, 0x00]);
+    /// assert_eq!(Natural::from(127u32).to_der(), &[0x02, 0x01, 0x7f]);
+    /// assert_eq!(Natural::from(255u32).to_der(), &[0x02, 0x02, 0x00, 0xff]);
+    /// ```
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut contents = self.to_bytes_be();
+        if contents.first().map_or(true, |&b| b >> 7 != 0) {
+            contents.insert(0, 0);
+        }
+        der_encode_contents(&contents)
+    }
+
+    /// Decodes a [`Natural`] from an ASN.1 `INTEGER` encoded using the Distinguished Encoding
+    /// Rules (DER).
+    ///
+    /// This is like [`Integer::from_der`](crate::integer::Integer::from_der), except that a
+    /// negative value is rejected with [`DerError::Negative`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::conversion::der::DerError;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_der(&[0x02, 0x02, 0x00, 0xff]), Ok(Natural::from(255u32)));
+    /// assert_eq!(Natural::from_der(&[0x02, 0x01, 0xff]), Err(DerError::Negative));
+    /// assert_eq!(
+    ///     Natural::from_der(&[0x02, 0x02, 0x00, 0x7f]),
+    ///     Err(DerError::NonMinimalContents)
+    /// );
+    /// ```
+    pub fn from_der(bytes: &[u8]) -> Result<Natural, DerError> {
+        natural_from_der_contents(der_decode_contents(bytes, true)?)
+    }
+
+    /// Decodes a [`Natural`] from an ASN.1 `INTEGER` encoded using the Basic Encoding Rules
+    /// (BER).
+    ///
+    /// This is like [`Integer::from_ber`](crate::integer::Integer::from_ber), except that a
+    /// negative value is rejected with [`DerError::Negative`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::conversion::der::DerError;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_ber(&[0x02, 0x03, 0x00, 0x00, 0x7f]), Ok(Natural::from(127u32)));
+    /// assert_eq!(Natural::from_ber(&[0x02, 0x02, 0xff, 0xff]), Err(DerError::Negative));
+    /// ```
+    pub fn from_ber(bytes: &[u8]) -> Result<Natural, DerError> {
+        natural_from_der_contents(der_decode_contents(bytes, false)?)
+    }
+}
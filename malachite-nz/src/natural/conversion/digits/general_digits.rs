@@ -12,6 +12,7 @@ use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_ou
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
+use crate::parallel::{join, use_threads};
 use crate::platform::{
     Limb, BASES, FROM_DIGITS_DIVIDE_AND_CONQUER_THRESHOLD, MP_BASES_BIG_BASE_10,
     MP_BASES_BIG_BASE_INVERTED_10, MP_BASES_CHARS_PER_LIMB_10, MP_BASES_NORMALIZATION_STEPS_10,
@@ -675,7 +676,7 @@ const GET_STR_DC_THRESHOLD: usize = 15;
 // where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
 //
 // This is equivalent to `mpn_dc_get_str` from `mpn/generic/get_str.c`, GMP 6.2.1.
-fn limbs_to_digits_small_base_divide_and_conquer<T: PrimitiveUnsigned>(
+fn limbs_to_digits_small_base_divide_and_conquer<T: PrimitiveUnsigned + Send>(
     out: &mut [T],
     mut len: usize,
     xs: &mut [Limb],
@@ -734,6 +735,39 @@ fn limbs_to_digits_small_base_divide_and_conquer<T: PrimitiveUnsigned>(
                 len -= powers[i].digits_in_base;
             }
             let (scratch_lo, scratch_hi) = scratch.split_at_mut(q_len);
+            if use_threads(xs_len) {
+                // The quotient and remainder are independent, so convert them at the same time,
+                // giving the remainder its own output and scratch.
+                let mut lo_out = vec![T::ZERO; power.digits_in_base];
+                let mut lo_scratch =
+                    vec![0; limbs_to_digits_small_base_divide_and_conquer_scratch_len(total_len)];
+                let (next_index, lo_len) = join(
+                    || {
+                        limbs_to_digits_small_base_divide_and_conquer(
+                            out,
+                            len,
+                            scratch_lo,
+                            base,
+                            powers,
+                            i - 1,
+                            scratch_hi,
+                        )
+                    },
+                    || {
+                        limbs_to_digits_small_base_divide_and_conquer(
+                            &mut lo_out,
+                            power.digits_in_base,
+                            &mut xs[..total_len],
+                            base,
+                            powers,
+                            i - 1,
+                            &mut lo_scratch,
+                        )
+                    },
+                );
+                out[next_index..next_index + lo_len].copy_from_slice(&lo_out[..lo_len]);
+                return next_index + lo_len;
+            }
             let next_index = limbs_to_digits_small_base_divide_and_conquer(
                 out,
                 len,
@@ -762,7 +796,7 @@ fn limbs_to_digits_small_base_divide_and_conquer<T: PrimitiveUnsigned>(
 // $M(n) = O(n \log n)$
 //
 // where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
-pub_crate_test! {limbs_to_digits_small_base_no_alg_specified<T: PrimitiveUnsigned>(
+pub_crate_test! {limbs_to_digits_small_base_no_alg_specified<T: PrimitiveUnsigned + Send>(
     out: &mut [T],
     base: u64,
     xs: &mut [Limb],
@@ -779,7 +813,7 @@ pub_crate_test! {limbs_to_digits_small_base_no_alg_specified<T: PrimitiveUnsigne
 //
 // This is equivalent to `mpn_get_str` from `mpn/generic/get_str.c`, GMP 6.2.1, where `un != 0` and
 // base is not a power of 2.
-pub_test! {limbs_to_digits_small_base<T: PrimitiveUnsigned>(
+pub_test! {limbs_to_digits_small_base<T: PrimitiveUnsigned + Send>(
     out: &mut [T],
     base: u64,
     xs: &mut [Limb],
@@ -918,7 +952,7 @@ fn compute_powers_for_to_digits(base: &Natural, bits: u64) -> Vec<Natural> {
 //
 // where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
 fn to_digits_asc_divide_and_conquer_limb<
-    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned,
+    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     digits: &mut Vec<T>,
     mut x: Natural,
@@ -942,8 +976,30 @@ fn to_digits_asc_divide_and_conquer_limb<
             to_digits_asc_naive_primitive(digits, &x, T::exact_from(base))
         }
     } else {
+        let threaded = use_threads(usize::exact_from(x.limb_count()));
         let (q, r) = x.div_mod(&powers[power_index]);
         let start_len = digits.len();
+        if threaded && q != 0 {
+            let (_, q_digits) = join(
+                || to_digits_asc_divide_and_conquer_limb(digits, r, base, powers, power_index - 1),
+                || {
+                    let mut q_digits = Vec::new();
+                    to_digits_asc_divide_and_conquer_limb(
+                        &mut q_digits,
+                        q,
+                        base,
+                        powers,
+                        power_index - 1,
+                    );
+                    q_digits
+                },
+            );
+            for _ in digits.len() - start_len..1 << power_index {
+                digits.push(T::ZERO);
+            }
+            digits.extend(q_digits);
+            return;
+        }
         to_digits_asc_divide_and_conquer_limb(digits, r, base, powers, power_index - 1);
         if q != 0 {
             for _ in digits.len() - start_len..1 << power_index {
@@ -971,8 +1027,30 @@ fn to_digits_asc_divide_and_conquer(
     if bits / base.significant_bits() < TO_DIGITS_DIVIDE_AND_CONQUER_THRESHOLD {
         to_digits_asc_naive(digits, x, base)
     } else {
+        let threaded = use_threads(usize::exact_from(x.limb_count()));
         let (q, r) = x.div_mod(&powers[power_index]);
         let start_len = digits.len();
+        if threaded && q != 0 {
+            let (_, q_digits) = join(
+                || to_digits_asc_divide_and_conquer(digits, &r, base, powers, power_index - 1),
+                || {
+                    let mut q_digits = Vec::new();
+                    to_digits_asc_divide_and_conquer(
+                        &mut q_digits,
+                        &q,
+                        base,
+                        powers,
+                        power_index - 1,
+                    );
+                    q_digits
+                },
+            );
+            for _ in digits.len() - start_len..1 << power_index {
+                digits.push(Natural::ZERO);
+            }
+            digits.extend(q_digits);
+            return;
+        }
         to_digits_asc_divide_and_conquer(digits, &r, base, powers, power_index - 1);
         if q != 0 {
             for _ in digits.len() - start_len..1 << power_index {
@@ -990,7 +1068,7 @@ fn to_digits_asc_divide_and_conquer(
 //
 // where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
 pub_test! {to_digits_asc_limb<
-    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned,
+    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     x: &Natural,
     base: Limb,
@@ -1050,7 +1128,7 @@ where
 //
 // where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
 pub_test! {to_digits_desc_limb<
-    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned,
+    T: ConvertibleFrom<Limb> + for<'a> ExactFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     x: &Natural,
     base: Limb,
@@ -2023,6 +2101,7 @@ fn to_digits_asc_unsigned<
     T: for<'a> TryFrom<&'a Natural>
         + ConvertibleFrom<Limb>
         + PrimitiveUnsigned
+        + Send
         + for<'a> WrappingFrom<&'a Natural>,
 >(
     x: &Natural,
@@ -2046,6 +2125,7 @@ fn to_digits_desc_unsigned<
     T: for<'a> TryFrom<&'a Natural>
         + ConvertibleFrom<Limb>
         + PrimitiveUnsigned
+        + Send
         + for<'a> WrappingFrom<&'a Natural>,
 >(
     x: &Natural,
@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -55,7 +56,7 @@ impl Natural {
         match significant_length {
             0 => Natural::ZERO,
             1 => Natural(Small(xs[0])),
-            _ => Natural(Large(xs[..significant_length].to_vec())),
+            _ => Natural(Large(LimbVec::from_slice(&xs[..significant_length]))),
         }
     }
 
@@ -124,6 +125,19 @@ impl Natural {
     /// }
     /// ```
     pub fn from_owned_limbs_asc(mut xs: Vec<Limb>) -> Natural {
+        let significant_length = limbs_significant_length(&xs);
+        match significant_length {
+            0 => Natural::ZERO,
+            1 => Natural(Small(xs[0])),
+            _ => {
+                xs.truncate(significant_length);
+                Natural(Large(xs.into()))
+            }
+        }
+    }
+
+    // Like `from_owned_limbs_asc`, but takes a `LimbVec`, so that short limbs can stay inline.
+    pub(crate) fn from_limb_vec_asc(mut xs: LimbVec) -> Natural {
         let significant_length = limbs_significant_length(&xs);
         match significant_length {
             0 => Natural::ZERO,
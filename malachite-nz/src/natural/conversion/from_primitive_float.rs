@@ -5,6 +5,7 @@ use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::{
     ConvertibleFrom, IntegerMantissaAndExponent, IsInteger, RoundingFrom,
 };
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::rounding_modes::RoundingMode;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -39,9 +40,9 @@ macro_rules! float_impls {
             fn rounding_from(value: $f, rm: RoundingMode) -> Self {
                 if value.is_nan() || value == $f::POSITIVE_INFINITY {
                     panic!("Cannot convert {} to Natural", value);
-                } else if value == 0.0 {
+                } else if value == $f::ZERO {
                     Natural::ZERO
-                } else if value < 0.0 {
+                } else if value < $f::ZERO {
                     if rm == RoundingMode::Down
                         || rm == RoundingMode::Ceiling
                         || rm == RoundingMode::Nearest
@@ -76,9 +77,9 @@ macro_rules! float_impls {
             fn try_from(value: $f) -> Result<Natural, Self::Error> {
                 if value.is_nan() || value.is_infinite() {
                     Err(NaturalFromPrimitiveFloatError::FloatInfiniteOrNan)
-                } else if value < 0.0 {
+                } else if value < $f::ZERO {
                     Err(NaturalFromPrimitiveFloatError::FloatNegative)
-                } else if value == 0.0 {
+                } else if value == $f::ZERO {
                     Ok(Natural::ZERO)
                 } else {
                     let (mantissa, exponent) = value.integer_mantissa_and_exponent();
@@ -102,7 +103,7 @@ macro_rules! float_impls {
             /// See [here](super::from_primitive_float#convertible_from).
             #[inline]
             fn convertible_from(value: $f) -> bool {
-                value >= 0.0 && value.is_integer()
+                value >= $f::ZERO && value.is_integer()
             }
         }
     };
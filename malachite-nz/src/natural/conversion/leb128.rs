@@ -0,0 +1,192 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use std::io::{self, ErrorKind, Read, Write};
+
+/// The reasons why a byte slice may fail to decode as a LEB128 value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Leb128Error {
+    /// The input ended before a byte without the continuation bit was found.
+    UnexpectedEnd,
+    /// The encoding was longer than necessary: its last byte carried no information.
+    Overlong,
+}
+
+impl From<Leb128Error> for io::Error {
+    /// Converts a [`Leb128Error`] to an [`io::Error`]. An unexpected end becomes an
+    /// [`ErrorKind::UnexpectedEof`] error, and an overlong encoding becomes an
+    /// [`ErrorKind::InvalidData`] error.
+    fn from(e: Leb128Error) -> io::Error {
+        match e {
+            Leb128Error::UnexpectedEnd => io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "LEB128 encoding ended unexpectedly",
+            ),
+            Leb128Error::Overlong => {
+                io::Error::new(ErrorKind::InvalidData, "overlong LEB128 encoding")
+            }
+        }
+    }
+}
+
+// Sets the continuation bit on every digit but the last, turning base-2^7 digits (in ascending
+// order, with at least one digit) into a LEB128 encoding.
+pub(crate) fn leb128_from_digits(mut digits: Vec<u8>) -> Vec<u8> {
+    let last = digits.len() - 1;
+    for d in &mut digits[..last] {
+        *d |= 0x80;
+    }
+    digits
+}
+
+// Returns the length of the LEB128 encoding at the start of `bytes`, including the byte without
+// the continuation bit.
+pub(crate) fn leb128_len(bytes: &[u8]) -> Result<usize, Leb128Error> {
+    bytes
+        .iter()
+        .position(|&b| b & 0x80 == 0)
+        .map(|i| i + 1)
+        .ok_or(Leb128Error::UnexpectedEnd)
+}
+
+// Reads bytes up to and including the first byte without the continuation bit.
+pub(crate) fn read_leb128_bytes<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    loop {
+        let mut b = [0];
+        reader.read_exact(&mut b)?;
+        bytes.push(b[0]);
+        if b[0] & 0x80 == 0 {
+            return Ok(bytes);
+        }
+    }
+}
+
+impl Natural {
+    /// Encodes a [`Natural`] using unsigned LEB128.
+    ///
+    /// The [`Natural`] is split into 7-bit groups, least-significant first, and each group is
+    /// stored in the low 7 bits of a byte. The high bit of each byte is set if more bytes follow.
+    /// The encoding is as short as possible; zero is encoded as a single zero byte.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.to_uleb128(), &[0x00]);
+    /// assert_eq!(Natural::from(127u32).to_uleb128(), &[0x7f]);
+    /// assert_eq!(Natural::from(128u32).to_uleb128(), &[0x80, 0x01]);
+    /// assert_eq!(Natural::from(624485u32).to_uleb128(), &[0xe5, 0x8e, 0x26]);
+    /// ```
+    pub fn to_uleb128(&self) -> Vec<u8> {
+        let mut digits: Vec<u8> = self.to_power_of_2_digits_asc(7);
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        leb128_from_digits(digits)
+    }
+
+    /// Writes the unsigned LEB128 encoding of a [`Natural`] to a [`Write`], returning the number
+    /// of bytes written.
+    ///
+    /// See [`to_uleb128`](Natural::to_uleb128) for the format. Since [`Vec<u8>`] implements
+    /// [`Write`], this can also be used to append to a [`Vec`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Errors
+    /// Returns any error returned by `writer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut out = vec![0xff];
+    /// assert_eq!(Natural::from(300u32).write_uleb128(&mut out).unwrap(), 2);
+    /// assert_eq!(out, &[0xff, 0xac, 0x02]);
+    /// ```
+    pub fn write_uleb128<W: Write>(&self, writer: &mut W) -> io::Result<usize> {
+        let bytes = self.to_uleb128();
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Decodes an unsigned LEB128 [`Natural`] from the start of a byte slice, returning the
+    /// [`Natural`] and the number of bytes that were read.
+    ///
+    /// Bytes after the end of the encoding are ignored, so a sequence of values can be decoded
+    /// by repeatedly advancing the slice. Overlong encodings, whose last byte is zero even though
+    /// there is more than one byte, are rejected.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::leb128::Leb128Error;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from_uleb128(&[0xe5, 0x8e, 0x26, 0x05]),
+    ///     Ok((Natural::from(624485u32), 3))
+    /// );
+    /// assert_eq!(Natural::from_uleb128(&[0xe5, 0x8e]), Err(Leb128Error::UnexpectedEnd));
+    /// assert_eq!(Natural::from_uleb128(&[0x85, 0x00]), Err(Leb128Error::Overlong));
+    /// ```
+    pub fn from_uleb128(bytes: &[u8]) -> Result<(Natural, usize), Leb128Error> {
+        let len = leb128_len(bytes)?;
+        let bytes = &bytes[..len];
+        if len > 1 && bytes[len - 1] == 0 {
+            return Err(Leb128Error::Overlong);
+        }
+        Ok((
+            Natural::from_power_of_2_digits_asc(7, bytes.iter().map(|&b| b & 0x7f)).unwrap(),
+            len,
+        ))
+    }
+
+    /// Reads an unsigned LEB128 [`Natural`] from a [`Read`].
+    ///
+    /// Exactly as many bytes as the encoding contains are read.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes read.
+    ///
+    /// # Errors
+    /// Returns any error returned by `reader`. If the input ends before the encoding does, an
+    /// [`ErrorKind::UnexpectedEof`] error is returned, and if the encoding is overlong, an
+    /// [`ErrorKind::InvalidData`] error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut input: &[u8] = &[0xac, 0x02, 0x7f];
+    /// assert_eq!(Natural::read_uleb128(&mut input).unwrap(), 300);
+    /// assert_eq!(Natural::read_uleb128(&mut input).unwrap(), 127);
+    /// assert!(Natural::read_uleb128(&mut input).is_err());
+    /// ```
+    pub fn read_uleb128<R: Read>(reader: &mut R) -> io::Result<Natural> {
+        Ok(Natural::from_uleb128(&read_leb128_bytes(reader)?)?.0)
+    }
+}
@@ -1,3 +1,13 @@
+/// Functions for converting a [`Natural`](crate::natural::Natural) to and from bytes, including
+/// GMP-compatible import and export of words with a specified size, order, and number of nail
+/// bits.
+pub mod bytes;
+/// Functions for encoding [`Natural`](crate::natural::Natural)s as CBOR data items, using bignum
+/// tags when necessary, and for decoding them.
+pub mod cbor;
+/// Functions for encoding [`Natural`](crate::natural::Natural)s as ASN.1 `INTEGER`s, and for
+/// decoding them using either the Distinguished or the Basic Encoding Rules.
+pub mod der;
 /// Implementations of traits for working with the digits of [`Natural`](crate::natural::Natural)s.
 pub mod digits;
 /// An implementation of the [`From`] trait for converting a [`bool`] to a
@@ -151,6 +161,9 @@ pub mod from_primitive_int;
 ///
 /// A [`Natural`](crate::natural::Natural) is always an integer.
 pub mod is_integer;
+/// Functions for encoding [`Natural`](crate::natural::Natural)s using unsigned LEB128, and for
+/// decoding them.
+pub mod leb128;
 /// A function for counting a [`Natural`](crate::natural::Natural)'s [`Limb`](crate#limbs)s.
 pub mod limb_count;
 /// Implementations of traits for converting numbers to and from mantissa-and-exponent
@@ -210,6 +223,10 @@ pub mod limb_count;
 /// test(0.5, 1, None);
 /// ```
 pub mod mantissa_and_exponent;
+/// Implementations of [`From`] for converting [`Natural`](crate::natural::Natural)s to and from
+/// the `BigUint` and `BigInt` types of [num-bigint](https://crates.io/crates/num-bigint).
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint;
 /// Implementations of traits for converting a [`Natural`](crate::natural::Natural) to a primitive
 /// float.
 ///
@@ -403,6 +420,7 @@ pub mod primitive_float_from_natural;
 pub mod primitive_int_from_natural;
 /// Implementations of traits for serialization and deserialization using
 /// [serde](https://serde.rs/).
+#[cfg(feature = "serde")]
 pub mod serde;
 /// Implementations of traits for converting [`Natural`](crate::natural::Natural)s to and from
 /// [`String`]s.
@@ -0,0 +1,184 @@
+use crate::natural::Natural;
+#[cfg(not(feature = "32_bit_limbs"))]
+use crate::platform::Limb;
+#[cfg(not(feature = "32_bit_limbs"))]
+use malachite_base::num::conversion::traits::VecFromOtherTypeSlice;
+use num_bigint::{BigInt, BigUint};
+
+impl From<BigUint> for Natural {
+    /// Converts a [`BigUint`] to a [`Natural`], taking the [`BigUint`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(Natural::from(BigUint::from(123u32)), 123);
+    /// assert_eq!(
+    ///     Natural::from(BigUint::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: BigUint) -> Natural {
+        Natural::from(&value)
+    }
+}
+
+impl<'a> From<&'a BigUint> for Natural {
+    /// Converts a [`BigUint`] to a [`Natural`], taking the [`BigUint`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(Natural::from(&BigUint::from(123u32)), 123);
+    /// assert_eq!(
+    ///     Natural::from(&BigUint::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: &'a BigUint) -> Natural {
+        #[cfg(feature = "32_bit_limbs")]
+        let limbs = value.to_u32_digits();
+        #[cfg(not(feature = "32_bit_limbs"))]
+        let limbs = Limb::vec_from_other_type_slice(&value.to_u32_digits());
+        Natural::from_owned_limbs_asc(limbs)
+    }
+}
+
+impl From<Natural> for BigUint {
+    /// Converts a [`Natural`] to a [`BigUint`], taking the [`Natural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(Natural::from(123u32)), BigUint::from(123u32));
+    /// assert_eq!(
+    ///     BigUint::from(Natural::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: Natural) -> BigUint {
+        #[cfg(feature = "32_bit_limbs")]
+        let digits = value.into_limbs_asc();
+        #[cfg(not(feature = "32_bit_limbs"))]
+        let digits = u32::vec_from_other_type_slice(&value.into_limbs_asc());
+        BigUint::new(digits)
+    }
+}
+
+impl<'a> From<&'a Natural> for BigUint {
+    /// Converts a [`Natural`] to a [`BigUint`], taking the [`Natural`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(&Natural::from(123u32)), BigUint::from(123u32));
+    /// assert_eq!(
+    ///     BigUint::from(&Natural::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: &'a Natural) -> BigUint {
+        #[cfg(feature = "32_bit_limbs")]
+        let digits = value.to_limbs_asc();
+        #[cfg(not(feature = "32_bit_limbs"))]
+        let digits = u32::vec_from_other_type_slice(&value.to_limbs_asc());
+        BigUint::new(digits)
+    }
+}
+
+impl From<Natural> for BigInt {
+    /// Converts a [`Natural`] to a [`BigInt`], taking the [`Natural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(Natural::from(123u32)), BigInt::from(123));
+    /// assert_eq!(
+    ///     BigInt::from(Natural::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: Natural) -> BigInt {
+        BigInt::from(BigUint::from(value))
+    }
+}
+
+impl<'a> From<&'a Natural> for BigInt {
+    /// Converts a [`Natural`] to a [`BigInt`], taking the [`Natural`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(&Natural::from(123u32)), BigInt::from(123));
+    /// assert_eq!(
+    ///     BigInt::from(&Natural::from(10u32).pow(20)).to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// ```
+    #[inline]
+    fn from(value: &'a Natural) -> BigInt {
+        BigInt::from(BigUint::from(value))
+    }
+}
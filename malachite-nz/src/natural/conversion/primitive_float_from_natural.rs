@@ -2,10 +2,12 @@ use crate::natural::Natural;
 use malachite_base::named::Named;
 use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::{
     ConvertibleFrom, ExactFrom, RawMantissaAndExponent, RoundingFrom, SciMantissaAndExponent,
     WrappingFrom,
 };
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::rounding_modes::RoundingMode;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,7 +44,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_natural#rounding_from).
             fn rounding_from(value: &'a Natural, rm: RoundingMode) -> $f {
                 if *value == 0 {
-                    0.0
+                    $f::ZERO
                 } else {
                     let (mantissa, exponent) = value
                         .sci_mantissa_and_exponent_with_rounding(rm)
@@ -84,7 +86,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_natural#try_from).
             fn try_from(value: &'a Natural) -> Result<$f, Self::Error> {
                 if *value == 0 {
-                    Ok(0.0)
+                    Ok($f::ZERO)
                 } else {
                     let (mantissa, exponent) = value
                         .sci_mantissa_and_exponent_with_rounding(RoundingMode::Exact)
@@ -691,7 +691,12 @@ impl_from_limb!(u32, i32);
 impl_from_smaller_than_limb!(u32, i32);
 #[cfg(feature = "32_bit_limbs")]
 impl_from_larger_than_limb!(u64, i64);
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 impl_from_limb!(u64, i64);
+#[cfg(feature = "128_bit_limbs")]
+impl_from_smaller_than_limb!(u64, i64);
+#[cfg(not(feature = "128_bit_limbs"))]
 impl_from_larger_than_limb!(u128, i128);
+#[cfg(feature = "128_bit_limbs")]
+impl_from_limb!(u128, i128);
 impl_from_larger_than_limb_or_xsize!(usize, isize);
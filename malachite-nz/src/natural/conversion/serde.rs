@@ -1,6 +1,9 @@
 use crate::natural::{Natural, SerdeNatural};
 use malachite_base::num::conversion::traits::FromStringBase;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
+use std::fmt::{self, Formatter};
 
 impl From<Natural> for SerdeNatural {
     #[inline]
@@ -22,3 +25,63 @@ impl TryFrom<SerdeNatural> for Natural {
         }
     }
 }
+
+// Accepts a byte string from a non-human-readable format. Formats without a native byte string
+// type may hand the bytes over as a sequence instead.
+pub(crate) struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+}
+
+impl Serialize for Natural {
+    /// Serializes a [`Natural`].
+    ///
+    /// Human-readable formats, such as JSON, get a hexadecimal string starting with `0x`. Other
+    /// formats get the bytes of the [`Natural`] in ascending order, as returned by
+    /// [`to_bytes_le`](Natural::to_bytes_le); in particular, zero is an empty byte array. Neither
+    /// representation depends on the width of a [limb](crate#limbs).
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{:#x}", self))
+        } else {
+            serializer.serialize_bytes(&self.to_bytes_le())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Natural {
+    /// Deserializes a [`Natural`] from either of the representations produced by its
+    /// [`Serialize`] implementation, depending on whether the format is human-readable.
+    ///
+    /// In the byte representation, trailing zero bytes are allowed.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Natural, D::Error> {
+        if deserializer.is_human_readable() {
+            Natural::try_from(SerdeNatural::deserialize(deserializer)?).map_err(D::Error::custom)
+        } else {
+            Ok(Natural::from_bytes_le(
+                &deserializer.deserialize_bytes(BytesVisitor)?,
+            ))
+        }
+    }
+}
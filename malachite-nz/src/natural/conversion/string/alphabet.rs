@@ -0,0 +1,281 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::string::alphabet::{
+    crockford_check_symbol, crockford_check_value, DigitAlphabet,
+};
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{
+    Digits, ExactFrom, FromStringWithAlphabet, PowerOf2Digits, ToStringWithAlphabet,
+};
+
+impl ToStringWithAlphabet for Natural {
+    /// Converts a [`Natural`] to a string using the digits of a [`DigitAlphabet`].
+    ///
+    /// Zero is written as the alphabet's zero digit; otherwise, there are no leading zeros.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    /// use malachite_base::num::conversion::traits::ToStringWithAlphabet;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.to_string_with_alphabet(DigitAlphabet::BASE62), "0");
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).to_string_with_alphabet(DigitAlphabet::BASE62),
+    ///     "1v973mBjywOu"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).to_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN),
+    ///     "518CApsYbVFd"
+    /// );
+    /// ```
+    fn to_string_with_alphabet(&self, alphabet: DigitAlphabet) -> String {
+        if *self == 0u32 {
+            alphabet.encode_digits(vec![0])
+        } else {
+            alphabet.encode_digits(self.to_digits_desc(&alphabet.get_base()))
+        }
+    }
+}
+
+impl FromStringWithAlphabet for Natural {
+    /// Converts a string written using the digits of a [`DigitAlphabet`] to a [`Natural`].
+    ///
+    /// If the string is not a valid number, an error is returned, describing the problem and
+    /// where in the string it was found. Leading zeros are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_base::num::conversion::traits::FromStringWithAlphabet;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from_string_with_alphabet(DigitAlphabet::BASE62, "1v973mBjywOu")
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN, "518CApsYbVFd")
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "100000000000000000000"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN, "518CApsYbV0d"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 10))
+    /// );
+    /// ```
+    fn from_string_with_alphabet(
+        alphabet: DigitAlphabet,
+        s: &str,
+    ) -> Result<Natural, ParseNumberError> {
+        let digits = alphabet.decode_digits(s, 0)?;
+        Ok(Natural::from_digits_desc(&alphabet.get_base(), digits.into_iter()).unwrap())
+    }
+}
+
+impl Natural {
+    /// Converts a [`Natural`] to a string in Crockford's base 32, followed by a check symbol.
+    ///
+    /// The check symbol stands for the [`Natural`] modulo 37. Its values 0 through 31 use the
+    /// ordinary Crockford digits, and the values 32 through 36 use `'*'`, `'~'`, `'$'`, `'='`,
+    /// and `'U'`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1234u32).to_crockford_string_with_check(), "16JD");
+    /// assert_eq!(Natural::from(32u32).to_crockford_string_with_check(), "10*");
+    /// ```
+    pub fn to_crockford_string_with_check(&self) -> String {
+        let mut s = self.to_string_with_alphabet(DigitAlphabet::CROCKFORD_BASE32);
+        let check = u8::exact_from(&(self % Natural::from(37u32)));
+        s.push(char::from(crockford_check_symbol(check)));
+        s
+    }
+
+    /// Converts a string in Crockford's base 32, followed by a check symbol, to a [`Natural`].
+    ///
+    /// The string is parsed as by
+    /// [`from_string_with_alphabet`](Natural::from_string_with_alphabet) with
+    /// [`DigitAlphabet::CROCKFORD_BASE32`], except that its last symbol is a check symbol. If the
+    /// check symbol does not match the value of the other digits modulo 37, an error of kind
+    /// [`ParseNumberErrorKind::CheckSymbolMismatch`] is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_crockford_string_with_check("16JD").unwrap(), 1234);
+    /// assert_eq!(Natural::from_crockford_string_with_check("16jd").unwrap(), 1234);
+    /// assert_eq!(Natural::from_crockford_string_with_check("1O*").unwrap(), 32);
+    /// assert_eq!(
+    ///     Natural::from_crockford_string_with_check("16JE"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::CheckSymbolMismatch, 3))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_crockford_string_with_check("16J"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::CheckSymbolMismatch, 2))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_crockford_string_with_check("D"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0))
+    /// );
+    /// ```
+    pub fn from_crockford_string_with_check(s: &str) -> Result<Natural, ParseNumberError> {
+        let check_index = s.len().saturating_sub(1);
+        let check = s
+            .as_bytes()
+            .last()
+            .and_then(|&b| crockford_check_value(b))
+            .ok_or_else(|| {
+                ParseNumberError::new(
+                    if s.is_empty() {
+                        ParseNumberErrorKind::Empty
+                    } else {
+                        ParseNumberErrorKind::InvalidDigit
+                    },
+                    check_index,
+                )
+            })?;
+        let n =
+            Natural::from_string_with_alphabet(DigitAlphabet::CROCKFORD_BASE32, &s[..check_index])?;
+        if u8::exact_from(&(&n % Natural::from(37u32))) == check {
+            Ok(n)
+        } else {
+            Err(ParseNumberError::new(
+                ParseNumberErrorKind::CheckSymbolMismatch,
+                check_index,
+            ))
+        }
+    }
+}
+
+/// Converts a byte string to a string using the digits of a [`DigitAlphabet`], following the
+/// base-58 convention for leading zeros.
+///
+/// The bytes are read as a big-endian number, which is written using the alphabet. Each leading
+/// zero byte is then written as a zero digit. This is how Bitcoin addresses are encoded in base
+/// 58, and it means that the byte string can be recovered exactly using
+/// [`bytes_from_string_with_alphabet`]. An empty byte string becomes an empty string.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+/// use malachite_nz::natural::conversion::string::alphabet::bytes_to_string_with_alphabet;
+///
+/// let alphabet = DigitAlphabet::BASE58_BITCOIN;
+/// assert_eq!(bytes_to_string_with_alphabet(alphabet, b""), "");
+/// assert_eq!(bytes_to_string_with_alphabet(alphabet, b"hello world"), "StV1DL6CwTryKyV");
+/// assert_eq!(bytes_to_string_with_alphabet(alphabet, &[0, 0, 1]), "112");
+/// assert_eq!(bytes_to_string_with_alphabet(alphabet, &[0, 0]), "11");
+/// ```
+pub fn bytes_to_string_with_alphabet(alphabet: DigitAlphabet, bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut digits = vec![0; zeros];
+    let n = Natural::from_power_of_2_digits_desc(8, bytes[zeros..].iter().cloned()).unwrap();
+    if n != 0u32 {
+        digits.extend(n.to_digits_desc(&alphabet.get_base()));
+    }
+    alphabet.encode_digits(digits)
+}
+
+/// Converts a string written using the digits of a [`DigitAlphabet`] to a byte string, following
+/// the base-58 convention for leading zeros.
+///
+/// This is the inverse of [`bytes_to_string_with_alphabet`]: each leading zero digit becomes a
+/// zero byte, and the remaining digits are read as a number, which is written as big-endian bytes.
+/// An empty string becomes an empty byte string. If the string contains a symbol that is not in
+/// the alphabet, an error is returned.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+/// use malachite_base::num::conversion::string::from_string::{
+///     ParseNumberError, ParseNumberErrorKind,
+/// };
+/// use malachite_nz::natural::conversion::string::alphabet::bytes_from_string_with_alphabet;
+///
+/// let alphabet = DigitAlphabet::BASE58_BITCOIN;
+/// assert_eq!(bytes_from_string_with_alphabet(alphabet, ""), Ok(vec![]));
+/// assert_eq!(
+///     bytes_from_string_with_alphabet(alphabet, "StV1DL6CwTryKyV"),
+///     Ok(b"hello world".to_vec())
+/// );
+/// assert_eq!(bytes_from_string_with_alphabet(alphabet, "112"), Ok(vec![0, 0, 1]));
+/// assert_eq!(
+///     bytes_from_string_with_alphabet(alphabet, "11O"),
+///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 2))
+/// );
+/// ```
+pub fn bytes_from_string_with_alphabet(
+    alphabet: DigitAlphabet,
+    s: &str,
+) -> Result<Vec<u8>, ParseNumberError> {
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+    let digits = alphabet.decode_digits(s, 0)?;
+    let zeros = digits.iter().take_while(|&&d| d == 0).count();
+    let mut bytes = vec![0; zeros];
+    let n =
+        Natural::from_digits_desc(&alphabet.get_base(), digits[zeros..].iter().cloned()).unwrap();
+    if n != 0u32 {
+        bytes.extend(n.to_power_of_2_digits_desc(8));
+    }
+    Ok(bytes)
+}
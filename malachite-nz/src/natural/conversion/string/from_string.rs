@@ -2,19 +2,26 @@ use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::{ModPowerOf2, ShrRound};
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::conversion::string::from_string::digit_from_display_byte;
-use malachite_base::num::conversion::traits::{Digits, ExactFrom, FromStringBase, WrappingFrom};
+use malachite_base::num::conversion::string::from_string::{
+    clean_digits, digit_from_display_byte, strip_sign, ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{
+    Digits, ExactFrom, FromStringBase, FromStringWithOptions, WrappingFrom,
+};
 use malachite_base::rounding_modes::RoundingMode;
 use std::str::FromStr;
 
 impl FromStr for Natural {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to a [`Natural`].
     ///
-    /// If the string does not represent a valid [`Natural`], an `Err` is returned. To be valid,
-    /// the string must be nonempty and only contain the [`char`]s `'0'` through `'9'`. Leading
-    /// zeros are allowed.
+    /// If the string does not represent a valid [`Natural`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. To be valid, the string must be nonempty and
+    /// only contain the [`char`]s `'0'` through `'9'`. Leading zeros are allowed. To accept other
+    /// syntax, such as separators or a base prefix, use
+    /// [`from_string_with_options`](Natural::from_string_with_options).
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -25,6 +32,9 @@ impl FromStr for Natural {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
     /// use malachite_nz::natural::Natural;
     /// use std::str::FromStr;
     ///
@@ -32,13 +42,78 @@ impl FromStr for Natural {
     /// assert_eq!(Natural::from_str("00123456").unwrap(), 123456);
     /// assert_eq!(Natural::from_str("0").unwrap(), 0);
     ///
-    /// assert!(Natural::from_str("").is_err());
-    /// assert!(Natural::from_str("a").is_err());
-    /// assert!(Natural::from_str("-5").is_err());
+    /// assert_eq!(
+    ///     Natural::from_str(""),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("12a"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 2))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("-5"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+    /// );
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Natural, ()> {
-        Natural::from_string_base(10, s).ok_or(())
+    fn from_str(s: &str) -> Result<Natural, ParseNumberError> {
+        Natural::from_string_with_options(s, ParseOptions::default())
+    }
+}
+
+impl FromStringWithOptions for Natural {
+    /// Converts a string to a [`Natural`], accepting the syntax specified by a [`ParseOptions`].
+    ///
+    /// If the string does not represent a valid [`Natural`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. A `'-'` is rejected, even before zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_base::num::conversion::string::options::ParseOptions;
+    /// use malachite_base::num::conversion::traits::FromStringWithOptions;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.set_allow_underscore_separators(true);
+    /// options.set_allow_whitespace(true);
+    /// options.set_allow_prefixes(true);
+    /// assert_eq!(
+    ///     Natural::from_string_with_options("1_000_000_000_000", options).unwrap(),
+    ///     1000000000000u64
+    /// );
+    /// assert_eq!(Natural::from_string_with_options(" 0x1F ", options).unwrap(), 31);
+    /// assert_eq!(
+    ///     Natural::from_string_with_options("1_000_", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::MisplacedSeparator, 5))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_string_with_options("+5", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0))
+    /// );
+    /// ```
+    fn from_string_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> Result<Natural, ParseNumberError> {
+        let (negative, range) = strip_sign(s, options)?;
+        if negative {
+            return Err(ParseNumberError::new(
+                ParseNumberErrorKind::InvalidSign,
+                range.start - 1,
+            ));
+        }
+        let (base, digits) = clean_digits(&s[range.clone()], range.start, options)?;
+        Ok(Natural::from_string_base(base, &digits).unwrap())
     }
 }
 
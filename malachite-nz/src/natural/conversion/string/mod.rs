@@ -1,3 +1,9 @@
+/// Implementations of
+/// [`ToStringWithAlphabet`](malachite_base::num::conversion::traits::ToStringWithAlphabet) and
+/// [`FromStringWithAlphabet`](malachite_base::num::conversion::traits::FromStringWithAlphabet),
+/// traits for converting numbers to and from strings that use custom digit symbols, along with
+/// Crockford base-32 check symbols and base-58-style byte string encoding.
+pub mod alphabet;
 /// Implementations of [`FromSciString`](malachite_base::num::conversion::traits::FromSciString).
 /// This is a trait for converting strings, possibly using scientific notation, to numbers.
 pub mod from_sci_string;
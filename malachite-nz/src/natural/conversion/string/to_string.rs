@@ -7,7 +7,7 @@ use crate::natural::Natural;
 use crate::platform::Limb;
 #[cfg(feature = "test_build")]
 use itertools::Itertools;
-use malachite_base::num::arithmetic::traits::{DivRound, Parity, ShrRound};
+use malachite_base::num::arithmetic::traits::{DivRound, Parity, Pow, ShrRound};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper, BaseFmtWrapper as BaseBaseFmtWrapper,
@@ -20,7 +20,9 @@ use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
 #[cfg(feature = "test_build")]
 use std::fmt::Write;
-use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex};
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
 
 /// A `struct` that allows for formatting a [`Natural`] or [`Integer`](crate::integer::Integer) and
 /// rendering its digits in a specified base.
@@ -264,6 +266,9 @@ impl Display for Natural {
     ///     "1000000000000"
     /// );
     /// assert_eq!(format!("{:05}", Natural::from(123u32)), "00123");
+    /// assert_eq!(format!("{:+}", Natural::from(123u32)), "+123");
+    /// assert_eq!(format!("{:>6}", Natural::from(123u32)), "   123");
+    /// assert_eq!(format!("{:*^7}", Natural::from(123u32)), "**123**");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
@@ -369,10 +374,14 @@ impl Binary for NaturalAlt2 {
                     {
                         result = write!(f, "{:032b}", x);
                     }
-                    #[cfg(not(feature = "32_bit_limbs"))]
+                    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
                     {
                         result = write!(f, "{:064b}", x);
                     }
+                    #[cfg(feature = "128_bit_limbs")]
+                    {
+                        result = write!(f, "{:0128b}", x);
+                    }
                 }
                 result
             }
@@ -414,6 +423,7 @@ impl Binary for Natural {
     ///     "0b1110100011010100101001010001000000000000"
     /// );
     /// assert_eq!(format!("{:#011b}", Natural::from(123u32)), "0b001111011");
+    /// assert_eq!(format!("{:>#11b}", Natural::from(123u32)), "  0b1111011");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
@@ -474,10 +484,15 @@ fn oz_fmt(f: &mut Formatter, x: Limb) -> Result {
     write!(f, "{:08o}", x)
 }
 #[cfg(feature = "test_build")]
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 fn oz_fmt(f: &mut Formatter, x: Limb) -> Result {
     write!(f, "{:016o}", x)
 }
+#[cfg(feature = "test_build")]
+#[cfg(feature = "128_bit_limbs")]
+fn oz_fmt(f: &mut Formatter, x: Limb) -> Result {
+    write!(f, "{:032o}", x)
+}
 
 #[cfg(feature = "test_build")]
 impl Octal for NaturalAlt2 {
@@ -705,10 +720,14 @@ impl LowerHex for NaturalAlt2 {
                     {
                         result = write!(f, "{:08x}", x);
                     }
-                    #[cfg(not(feature = "32_bit_limbs"))]
+                    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
                     {
                         result = write!(f, "{:016x}", x);
                     }
+                    #[cfg(feature = "128_bit_limbs")]
+                    {
+                        result = write!(f, "{:032x}", x);
+                    }
                 }
                 result
             }
@@ -843,3 +862,128 @@ impl UpperHex for Natural {
         }
     }
 }
+
+// Writes a number in scientific notation, as the `LowerExp` and `UpperExp` implementations of the
+// primitive types do, respecting the sign, fill, alignment, width, and zero-padding flags of a
+// `Formatter`. `digits` contains the decimal digits of the significand, without a point; a point is
+// written after the first digit if there is more than one. `exponent` is the exponent of the first
+// digit.
+pub(crate) fn pad_exp(
+    f: &mut Formatter,
+    non_negative: bool,
+    digits: &str,
+    exponent: i64,
+    upper: bool,
+) -> Result {
+    let (first, rest) = digits.split_at(1);
+    let s = format!(
+        "{}{}{}{}{}",
+        first,
+        if rest.is_empty() { "" } else { "." },
+        rest,
+        if upper { 'E' } else { 'e' },
+        exponent
+    );
+    f.pad_integral(non_negative, "", &s)
+}
+
+// Writes a `Natural`, or the absolute value of an `Integer`, in scientific notation. If the
+// `Formatter` has a precision, the significand is rounded to that many digits after the point,
+// with ties broken towards even, like the primitive integers do. Otherwise, all digits up to the
+// last nonzero one are written.
+pub(crate) fn fmt_natural_exp(
+    f: &mut Formatter,
+    non_negative: bool,
+    x: &Natural,
+    upper: bool,
+) -> Result {
+    let mut digits = x.to_string();
+    let mut exponent = i64::exact_from(digits.len() - 1);
+    if let Some(precision) = f.precision() {
+        let len = precision + 1;
+        if digits.len() > len {
+            let pow = Natural::from(10u32).pow(u64::exact_from(digits.len() - len));
+            digits = x.div_round(pow, RoundingMode::Nearest).to_string();
+            if digits.len() > len {
+                // The significand was rounded up to a power of 10
+                digits.pop();
+                exponent += 1;
+            }
+        } else {
+            digits.extend(std::iter::repeat('0').take(len - digits.len()));
+        }
+    } else {
+        let len = digits.trim_end_matches('0').len();
+        digits.truncate(len.max(1));
+    }
+    pad_exp(f, non_negative, &digits, exponent, upper)
+}
+
+impl LowerExp for Natural {
+    /// Converts a [`Natural`] to a [`String`] in scientific notation, using a lowercase `e`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even; otherwise, the value is written exactly, without trailing
+    /// zeros. This matches the behavior of the primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(format!("{:e}", Natural::ZERO), "0e0");
+    /// assert_eq!(format!("{:e}", Natural::from(123u32)), "1.23e2");
+    /// assert_eq!(format!("{:e}", Natural::from(1000u32)), "1e3");
+    /// assert_eq!(
+    ///     format!("{:.3e}", Natural::from_str("1000000000000").unwrap()),
+    ///     "1.000e12"
+    /// );
+    /// assert_eq!(format!("{:.1e}", Natural::from(125u32)), "1.2e2");
+    /// assert_eq!(format!("{:.1e}", Natural::from(999u32)), "1.0e3");
+    /// assert_eq!(format!("{:+09e}", Natural::from(123u32)), "+001.23e2");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_natural_exp(f, true, self, false)
+    }
+}
+
+impl UpperExp for Natural {
+    /// Converts a [`Natural`] to a [`String`] in scientific notation, using an uppercase `E`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even; otherwise, the value is written exactly, without trailing
+    /// zeros. This matches the behavior of the primitive integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(format!("{:E}", Natural::from(123u32)), "1.23E2");
+    /// assert_eq!(
+    ///     format!("{:.2E}", Natural::from_str("1000000000000").unwrap()),
+    ///     "1.00E12"
+    /// );
+    /// assert_eq!(format!("{:<8E}|", Natural::from(5u32)), "5E0     |");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_natural_exp(f, true, self, true)
+    }
+}
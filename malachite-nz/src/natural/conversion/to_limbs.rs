@@ -202,7 +202,7 @@ impl Natural {
         match *self {
             natural_zero!() => Vec::new(),
             Natural(Small(small)) => vec![small],
-            Natural(Large(ref limbs)) => limbs.clone(),
+            Natural(Large(ref limbs)) => limbs.to_vec(),
         }
     }
 
@@ -278,7 +278,7 @@ impl Natural {
         match self {
             natural_zero!() => Vec::new(),
             Natural(Small(small)) => vec![small],
-            Natural(Large(limbs)) => limbs,
+            Natural(Large(limbs)) => limbs.into_vec(),
         }
     }
 
@@ -320,7 +320,7 @@ impl Natural {
             Natural(Small(small)) => vec![small],
             Natural(Large(mut limbs)) => {
                 limbs.reverse();
-                limbs
+                limbs.into_vec()
             }
         }
     }
@@ -0,0 +1,240 @@
+use crate::natural::limb_vec::LimbVec::{Heap, Inline};
+use crate::platform::{Limb, INLINE_LIMB_COUNT};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+// The limbs of a `Large` `Natural`, in ascending order.
+//
+// Up to `INLINE_LIMB_COUNT` limbs are stored inline, so that numbers that are only a little too
+// large to fit in a single `Limb` don't need a heap allocation. Longer sequences of limbs are kept
+// in a `Vec`. A `LimbVec` that has moved to the heap stays there as it shrinks, so that its buffer
+// can be reused if it grows again; only `shrink_to_fit` and `clone` move short heap-allocated limbs
+// back inline.
+//
+// Equality and hashing only depend on the limbs, not on where they are stored.
+pub(crate) enum LimbVec {
+    // The number of limbs in use, followed by the storage. Limbs beyond the length are unused.
+    // The length is a `u8` so that it fits next to the discriminant.
+    Inline(u8, [Limb; INLINE_LIMB_COUNT]),
+    Heap(Vec<Limb>),
+}
+
+impl LimbVec {
+    // Creates a `LimbVec` with `len` zero limbs. If `len` is at most `INLINE_LIMB_COUNT`, no heap
+    // allocation takes place.
+    pub(crate) fn zeroed(len: usize) -> LimbVec {
+        if len <= INLINE_LIMB_COUNT {
+            Inline(len as u8, [0; INLINE_LIMB_COUNT])
+        } else {
+            Heap(vec![0; len])
+        }
+    }
+
+    // Creates a `LimbVec` holding a copy of `xs`. If `xs` has at most `INLINE_LIMB_COUNT` limbs, no
+    // heap allocation takes place.
+    pub(crate) fn from_slice(xs: &[Limb]) -> LimbVec {
+        let len = xs.len();
+        if len <= INLINE_LIMB_COUNT {
+            let mut limbs = [0; INLINE_LIMB_COUNT];
+            limbs[..len].copy_from_slice(xs);
+            Inline(len as u8, limbs)
+        } else {
+            Heap(xs.to_vec())
+        }
+    }
+
+    // Returns the limbs as a `Vec`, allocating only if they are stored inline.
+    pub(crate) fn into_vec(self) -> Vec<Limb> {
+        match self {
+            Inline(len, limbs) => limbs[..usize::from(len)].to_vec(),
+            Heap(xs) => xs,
+        }
+    }
+
+    // Returns a mutable reference to the limbs as a `Vec`, moving them to the heap first if they are
+    // stored inline. This is for code that needs to resize the limbs with functions that take a
+    // `&mut Vec<Limb>`.
+    pub(crate) fn vec_mut(&mut self) -> &mut Vec<Limb> {
+        if let Inline(len, limbs) = self {
+            *self = Heap(limbs[..usize::from(*len)].to_vec());
+        }
+        if let Heap(ref mut xs) = self {
+            xs
+        } else {
+            unreachable!();
+        }
+    }
+
+    // Returns the number of limbs that can be stored without reallocating.
+    pub(crate) fn capacity(&self) -> usize {
+        match self {
+            Inline(_, _) => INLINE_LIMB_COUNT,
+            Heap(xs) => xs.capacity(),
+        }
+    }
+
+    // Makes room for at least `additional` more limbs, moving the limbs to the heap if they won't
+    // fit inline.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        match self {
+            Inline(len, limbs) => {
+                let len = usize::from(*len);
+                if len + additional > INLINE_LIMB_COUNT {
+                    let mut xs = Vec::with_capacity(len + additional);
+                    xs.extend_from_slice(&limbs[..len]);
+                    *self = Heap(xs);
+                }
+            }
+            Heap(xs) => xs.reserve(additional),
+        }
+    }
+
+    // Releases unused capacity, moving the limbs inline if they fit.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        if let Heap(xs) = self {
+            if xs.len() <= INLINE_LIMB_COUNT {
+                *self = LimbVec::from_slice(xs);
+            } else {
+                xs.shrink_to_fit();
+            }
+        }
+    }
+
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        match self {
+            Inline(len, _) => {
+                if new_len < usize::from(*len) {
+                    *len = new_len as u8;
+                }
+            }
+            Heap(xs) => xs.truncate(new_len),
+        }
+    }
+
+    pub(crate) fn resize(&mut self, new_len: usize, value: Limb) {
+        match self {
+            Inline(len, limbs) if new_len <= INLINE_LIMB_COUNT => {
+                let old_len = usize::from(*len);
+                if new_len > old_len {
+                    limbs[old_len..new_len].fill(value);
+                }
+                *len = new_len as u8;
+            }
+            Inline(_, _) => self.vec_mut().resize(new_len, value),
+            Heap(xs) => xs.resize(new_len, value),
+        }
+    }
+
+    pub(crate) fn push(&mut self, x: Limb) {
+        match self {
+            Inline(len, limbs) if usize::from(*len) < INLINE_LIMB_COUNT => {
+                limbs[usize::from(*len)] = x;
+                *len += 1;
+            }
+            Inline(_, _) => self.vec_mut().push(x),
+            Heap(xs) => xs.push(x),
+        }
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<Limb> {
+        match self {
+            Inline(0, _) => None,
+            Inline(len, limbs) => {
+                *len -= 1;
+                Some(limbs[usize::from(*len)])
+            }
+            Heap(xs) => xs.pop(),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, index: usize, x: Limb) {
+        match self {
+            Inline(len, limbs) if usize::from(*len) < INLINE_LIMB_COUNT => {
+                let old_len = usize::from(*len);
+                assert!(index <= old_len);
+                limbs.copy_within(index..old_len, index + 1);
+                limbs[index] = x;
+                *len += 1;
+            }
+            Inline(_, _) => self.vec_mut().insert(index, x),
+            Heap(xs) => xs.insert(index, x),
+        }
+    }
+
+    pub(crate) fn extend_from_slice(&mut self, ys: &[Limb]) {
+        match self {
+            Inline(len, limbs) if usize::from(*len) + ys.len() <= INLINE_LIMB_COUNT => {
+                let old_len = usize::from(*len);
+                limbs[old_len..old_len + ys.len()].copy_from_slice(ys);
+                *len += ys.len() as u8;
+            }
+            Inline(_, _) => self.vec_mut().extend_from_slice(ys),
+            Heap(xs) => xs.extend_from_slice(ys),
+        }
+    }
+}
+
+impl From<Vec<Limb>> for LimbVec {
+    // Takes ownership of the `Vec`'s buffer rather than copying short vectors inline, since the
+    // allocation has already been paid for.
+    #[inline]
+    fn from(xs: Vec<Limb>) -> LimbVec {
+        Heap(xs)
+    }
+}
+
+impl Deref for LimbVec {
+    type Target = [Limb];
+
+    #[inline]
+    fn deref(&self) -> &[Limb] {
+        match self {
+            Inline(len, limbs) => &limbs[..usize::from(*len)],
+            Heap(xs) => xs,
+        }
+    }
+}
+
+impl DerefMut for LimbVec {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Limb] {
+        match self {
+            Inline(len, limbs) => &mut limbs[..usize::from(*len)],
+            Heap(xs) => xs,
+        }
+    }
+}
+
+impl Clone for LimbVec {
+    #[inline]
+    fn clone(&self) -> LimbVec {
+        LimbVec::from_slice(self)
+    }
+
+    fn clone_from(&mut self, source: &LimbVec) {
+        match self {
+            Heap(xs) => {
+                xs.clear();
+                xs.extend_from_slice(source);
+            }
+            Inline(_, _) => *self = source.clone(),
+        }
+    }
+}
+
+impl PartialEq for LimbVec {
+    #[inline]
+    fn eq(&self, other: &LimbVec) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for LimbVec {}
+
+impl Hash for LimbVec {
+    // Hashes the same way as a `Vec<Limb>` with the same limbs.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
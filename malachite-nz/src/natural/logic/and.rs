@@ -433,7 +433,7 @@ impl<'a> BitAndAssign<&'a Natural> for Natural {
             (_, Natural(Small(y))) => self.and_assign_limb(*y),
             (Natural(Small(ref mut x)), _) => *x = other.and_limb_ref(*x),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_vec_and_in_place_left(xs, ys);
+                limbs_vec_and_in_place_left(xs.vec_mut(), ys);
                 self.trim();
             }
         }
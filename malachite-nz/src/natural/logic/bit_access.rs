@@ -179,11 +179,11 @@ impl BitAccess for Natural {
                 } else {
                     let mut limbs = vec![*small];
                     limbs_vec_set_bit(&mut limbs, index);
-                    *self = Natural(Large(limbs));
+                    *self = Natural(Large(limbs.into()));
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_set_bit(limbs, index);
+                limbs_vec_set_bit(limbs.vec_mut(), index);
             }
         }
     }
@@ -1,30 +1,33 @@
+use crate::natural::limb_vec::LimbVec;
+use crate::natural::limb_vec::LimbVec::Heap;
 use crate::natural::InnerNatural::{Large, Small};
-use crate::platform::Limb;
+use crate::platform::{Limb, INLINE_LIMB_COUNT};
 #[cfg(feature = "doc-images")]
 use embed_doc_image::embed_doc_image;
 use malachite_base::comparison::traits::Min;
 use malachite_base::named::Named;
 use malachite_base::num::basic::traits::{One, Two, Zero};
 use malachite_base::slices::slice_trailing_zeros;
+use std::cell::Cell;
+use std::cmp::max;
+use std::mem::replace;
+use std::slice;
 
 /// A natural (non-negative) integer.
 ///
-/// Any `Natural` small enough to fit into a [`Limb`](crate#limbs) is represented inline. Only
-/// `Natural`s outside this range incur the costs of heap-allocation. Here's a diagram of a slice
-/// of `Natural`s (using 32-bit limbs) containing the first 8 values of
-/// [Sylvester's sequence](https://oeis.org/A000058):
+/// Any `Natural` small enough to fit into a few [`Limb`](crate#limbs)s (four 32- or 64-bit limbs,
+/// or two 128-bit limbs) is represented inline. Only `Natural`s outside this range incur the costs
+/// of heap-allocation. Here's a diagram of a slice of `Natural`s (using 32-bit limbs) containing
+/// the first 8 values of [Sylvester's sequence](https://oeis.org/A000058), with the last two
+/// values drawn heap-allocated to illustrate that layout (having only two and three limbs, they
+/// would actually be stored inline):
 ///
 /// ![Natural memory layout][natural-mem-layout]
 #[cfg_attr(
     feature = "doc-images",
     embed_doc_image("natural-mem-layout", "images/natural-mem-layout.svg")
 )]
-#[derive(Clone, Eq, Hash, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeNatural", into = "SerdeNatural")
-)]
+#[derive(Eq, Hash, PartialEq)]
 pub struct Natural(pub(crate) InnerNatural);
 
 // We want to limit the visibility of the `Small` and `Large` constructors to within this crate. To
@@ -32,11 +35,12 @@ pub struct Natural(pub(crate) InnerNatural);
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub(crate) enum InnerNatural {
     Small(Limb),
-    Large(Vec<Limb>),
+    Large(LimbVec),
 }
 
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
 pub(crate) struct SerdeNatural(String);
 
 macro_rules! natural_zero {
@@ -57,6 +61,61 @@ macro_rules! natural_two {
     };
 }
 
+thread_local! {
+    // A `Natural` that fits in a single `Limb` has nowhere to keep a heap buffer. When the
+    // destination of a `_to` method becomes that small, its buffer is parked here, and the next
+    // result on this thread that needs a buffer takes it back instead of allocating. Only the
+    // largest parked buffer is kept.
+    static SPARE_LIMBS: Cell<Vec<Limb>> = const { Cell::new(Vec::new()) };
+}
+
+// Parks `xs` as the thread's spare buffer, unless the current spare buffer is at least as large.
+fn park_spare_limbs(xs: Vec<Limb>) {
+    SPARE_LIMBS.with(|spare| {
+        let old = spare.take();
+        spare.set(if old.capacity() >= xs.capacity() {
+            old
+        } else {
+            xs
+        });
+    });
+}
+
+// Returns `len` limbs with unspecified values. They are stored inline if possible, and otherwise
+// in the thread's spare buffer if it is large enough.
+fn take_spare_limbs(len: usize) -> LimbVec {
+    if len <= INLINE_LIMB_COUNT {
+        return LimbVec::zeroed(len);
+    }
+    SPARE_LIMBS.with(|spare| {
+        let mut xs = spare.take();
+        if xs.capacity() >= len {
+            xs.clear();
+            xs.resize(len, 0);
+            Heap(xs)
+        } else {
+            spare.set(xs);
+            LimbVec::zeroed(len)
+        }
+    })
+}
+
+fn spare_limbs_capacity() -> usize {
+    SPARE_LIMBS.with(|spare| {
+        let xs = spare.take();
+        let capacity = xs.capacity();
+        spare.set(xs);
+        capacity
+    })
+}
+
+// Makes sure that the thread's spare buffer can hold at least `capacity` limbs.
+fn reserve_spare_limbs(capacity: usize) {
+    if capacity > INLINE_LIMB_COUNT && spare_limbs_capacity() < capacity {
+        park_spare_limbs(Vec::with_capacity(capacity));
+    }
+}
+
 impl Natural {
     // If a `Natural` is `Large` but is small enough to be `Small`, make it `Small`.
     fn demote_if_small(&mut self) {
@@ -72,7 +131,33 @@ impl Natural {
     // If a `Natural` is `Small`, make it `Large`. Return a reference to the `Limb` vector.
     pub(crate) fn promote_in_place(&mut self) -> &mut Vec<Limb> {
         if let Natural(Small(x)) = self {
-            *self = Natural(Large(vec![*x]));
+            *self = Natural(Large(vec![*x].into()));
+        }
+        if let Natural(Large(ref mut xs)) = self {
+            xs.vec_mut()
+        } else {
+            unreachable!();
+        }
+    }
+
+    // Returns the limbs of a `Natural`, in ascending order. Unlike `to_limbs_asc`, zero is
+    // represented by a single zero limb.
+    pub(crate) fn limbs_slice(&self) -> &[Limb] {
+        match self {
+            Natural(Small(x)) => slice::from_ref(x),
+            Natural(Large(xs)) => xs,
+        }
+    }
+
+    // Makes a `Natural` hold `len` limbs, reusing its buffer if it has one, and returns the limbs.
+    // Their values are unspecified, so the caller must overwrite all of them and then call
+    // `finish_overwrite`. If the limbs don't fit inline and the `Natural` has no buffer, the
+    // thread's spare buffer is used if it is large enough.
+    pub(crate) fn limbs_for_overwrite(&mut self, len: usize) -> &mut [Limb] {
+        match self {
+            Natural(Large(Heap(xs))) => xs.resize(len, 0),
+            Natural(Large(xs)) if len <= INLINE_LIMB_COUNT => xs.resize(len, 0),
+            _ => *self = Natural(Large(take_spare_limbs(len))),
         }
         if let Natural(Large(ref mut xs)) = self {
             xs
@@ -81,6 +166,47 @@ impl Natural {
         }
     }
 
+    // Like `limbs_for_overwrite`, but returns a `Vec` of unspecified length, for functions that
+    // resize their output themselves. The `Natural` is invalid until `finish_overwrite` is called.
+    pub(crate) fn limb_vec_for_overwrite(&mut self) -> &mut Vec<Limb> {
+        if !matches!(self, Natural(Large(Heap(_)))) {
+            *self = Natural(Large(Heap(SPARE_LIMBS.with(Cell::take))));
+        }
+        if let Natural(Large(Heap(ref mut xs))) = self {
+            xs
+        } else {
+            unreachable!();
+        }
+    }
+
+    // Finishes writing limbs obtained from `limbs_for_overwrite` or `limb_vec_for_overwrite`:
+    // keeps the first `len` limbs and removes leading zeros. If the result fits in a `Limb`, the
+    // buffer is parked as the thread's spare buffer instead of being freed.
+    pub(crate) fn finish_overwrite(&mut self, len: usize) {
+        let mut small = None;
+        if let Natural(Large(ref mut xs)) = self {
+            xs.truncate(len);
+            let len = len - slice_trailing_zeros(xs);
+            xs.truncate(len);
+            small = match len {
+                0 => Some(0),
+                1 => Some(xs[0]),
+                _ => None,
+            };
+        }
+        if let Some(x) = small {
+            self.set_limb(x);
+        }
+    }
+
+    // Sets a `Natural` to a `Limb`. If the `Natural` had a buffer, it is parked as the thread's
+    // spare buffer instead of being freed.
+    pub(crate) fn set_limb(&mut self, x: Limb) {
+        if let Natural(Large(Heap(xs))) = replace(self, Natural(Small(x))) {
+            park_spare_limbs(xs);
+        }
+    }
+
     pub(crate) fn trim(&mut self) {
         if let Natural(Large(ref mut limbs)) = *self {
             let trailing_zero_count = slice_trailing_zeros(limbs);
@@ -105,6 +231,173 @@ impl Natural {
     }
 }
 
+impl Natural {
+    /// Creates a [`Natural`] equal to zero that can hold at least `capacity` limbs without
+    /// reallocating.
+    ///
+    /// This lets the `_to` methods, like [`Natural::mul_to`], write results of up to `capacity`
+    /// limbs into the new [`Natural`] without allocating. Zero is stored inline, so the buffer
+    /// is set aside for the current thread until the first result that needs it.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and $O(n)$ additional memory, where $n$ is `capacity`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::with_capacity(100);
+    /// assert_eq!(x, 0);
+    /// assert!(x.capacity() >= 100);
+    ///
+    /// Natural::from(10u32).pow(50).square_to(&mut x);
+    /// assert_eq!(x, Natural::from(10u32).pow(100));
+    /// assert!(x.capacity() >= 100);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Natural {
+        reserve_spare_limbs(capacity);
+        Natural::ZERO
+    }
+
+    /// Returns the number of limbs that a [`Natural`] can hold without reallocating.
+    ///
+    /// A [`Natural`] whose limbs are stored inline can hold a few limbs (four 32- or 64-bit limbs,
+    /// or two 128-bit limbs), or more if a buffer has been set aside for the current thread by
+    /// [`Natural::with_capacity`], [`Natural::reserve`], or a `_to` method whose result became
+    /// small. Larger [`Natural`]s keep their limbs in a heap-allocated buffer.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.capacity() >= 2);
+    /// assert!(Natural::from(10u32).pow(100).capacity() >= 3);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        match self {
+            Natural(Large(Heap(xs))) => xs.capacity(),
+            _ => max(INLINE_LIMB_COUNT, spare_limbs_capacity()),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more limbs than a [`Natural`] currently has.
+    ///
+    /// This lets the `_to` methods, like [`Natural::mul_to`], write larger results into an
+    /// existing [`Natural`] without reallocating. A [`Natural`] that fits in a single
+    /// [`Limb`](crate#limbs) can't own a buffer, so for such values the buffer is set aside for
+    /// the current thread until the first result that needs it.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of limbs of `self` plus
+    /// `additional`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::from(10u32).pow(100);
+    /// x.reserve(10);
+    /// assert!(x.capacity() >= 13);
+    ///
+    /// let mut x = Natural::ZERO;
+    /// x.reserve(100);
+    /// assert!(x.capacity() >= 101);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        match self {
+            Natural(Small(_)) => reserve_spare_limbs(additional + 1),
+            Natural(Large(xs)) => xs.reserve(additional),
+        }
+    }
+
+    /// Shrinks the buffer of a [`Natural`] as much as possible.
+    ///
+    /// If the [`Natural`]'s limbs are stored inline, this frees any buffer that has been set
+    /// aside for the current thread.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::from(10u32).pow(100);
+    /// x.reserve(100);
+    /// x.shrink_to_fit();
+    /// assert!(x.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        match self {
+            Natural(Large(Heap(xs))) if xs.len() > INLINE_LIMB_COUNT => xs.shrink_to_fit(),
+            _ => {
+                // Short heap-allocated limbs are moved inline.
+                if let Natural(Large(xs)) = self {
+                    xs.shrink_to_fit();
+                }
+                drop(SPARE_LIMBS.with(Cell::take));
+            }
+        }
+    }
+}
+
+impl Clone for Natural {
+    /// Clones a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    #[inline]
+    fn clone(&self) -> Natural {
+        Natural(self.0.clone())
+    }
+
+    /// Copies a [`Natural`] into an existing one, reusing its buffer if it has one.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the underlying [`Vec`] needs to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `source.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Natural::from(10u32).pow(100);
+    /// x.clone_from(&Natural::from(3u32).pow(100));
+    /// assert_eq!(x, Natural::from(3u32).pow(100));
+    /// ```
+    fn clone_from(&mut self, source: &Natural) {
+        match source {
+            Natural(Small(y)) => self.set_limb(*y),
+            Natural(Large(ys)) => self.limbs_for_overwrite(ys.len()).copy_from_slice(ys),
+        }
+    }
+}
+
 /// The constant 0.
 impl Zero for Natural {
     const ZERO: Natural = natural_zero!();
@@ -146,7 +439,11 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for generating primes, primality testing, and factorization (TODO!)
 pub mod factorization;
+pub(crate) mod limb_vec;
 /// Traits for logic and bit manipulation.
 pub mod logic;
+/// Implementations of the traits from the `num-traits` and `num-integer` crates for [`Natural`]s.
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
 /// Iterators that generate [`Natural`]s randomly.
 pub mod random;
@@ -0,0 +1,342 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivMod, DivRem, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Mod, Parity, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::string::from_string::ParseNumberError;
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringWithOptions, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+
+// The syntax accepted by `from_str_radix`: digits in the given radix, optionally preceded by a
+// `'+'` and optionally grouped with underscores, as with the num-bigint types.
+pub(crate) fn from_str_radix_options(radix: u32) -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_base(u8::exact_from(radix));
+    options.set_allow_underscore_separators(true);
+    options.set_allow_plus_sign(true);
+    options
+}
+
+impl num_traits::Zero for Natural {
+    /// Returns 0.
+    #[inline]
+    fn zero() -> Natural {
+        Natural::ZERO
+    }
+
+    /// Determines whether a [`Natural`] is 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+impl num_traits::One for Natural {
+    /// Returns 1.
+    #[inline]
+    fn one() -> Natural {
+        Natural::ONE
+    }
+
+    /// Determines whether a [`Natural`] is 1.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+impl num_traits::Num for Natural {
+    type FromStrRadixErr = ParseNumberError;
+
+    /// Converts a string to a [`Natural`], using a specified radix.
+    ///
+    /// The digits may be preceded by a `'+'`, and groups of digits may be separated by `'_'`.
+    /// Digits greater than 9 may be lowercase or uppercase. If the string is not valid, an `Err`
+    /// is returned, describing the problem and where in the string it was found.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_traits::Num;
+    ///
+    /// assert_eq!(Natural::from_str_radix("ff", 16).unwrap(), 255);
+    /// assert_eq!(Natural::from_str_radix("+1_000", 10).unwrap(), 1000);
+    /// assert!(Natural::from_str_radix("-1", 10).is_err());
+    /// ```
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Natural, ParseNumberError> {
+        Natural::from_string_with_options(s, from_str_radix_options(radix))
+    }
+}
+
+impl num_traits::Unsigned for Natural {}
+
+impl num_traits::ToPrimitive for Natural {
+    /// Converts a [`Natural`] to an [`i64`], returning `None` if it is too large.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to an [`i128`], returning `None` if it is too large.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to a [`u64`], returning `None` if it is too large.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to a [`u128`], returning `None` if it is too large.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f32`]. A [`Natural`] too large to be represented
+    /// is converted to the largest finite [`f32`]. The result is never `None`.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest))
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f64`]. A [`Natural`] too large to be represented
+    /// is converted to the largest finite [`f64`]. The result is never `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    /// use num_traits::ToPrimitive;
+    ///
+    /// assert_eq!(Natural::from(123u32).to_u8(), Some(123));
+    /// assert_eq!(Natural::from(10u32).pow(20).to_u64(), None);
+    /// assert_eq!(Natural::from(10u32).pow(20).to_f64(), Some(1.0e20));
+    /// ```
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest))
+    }
+}
+
+impl num_traits::FromPrimitive for Natural {
+    /// Converts an [`i64`] to a [`Natural`], returning `None` if it is negative.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    /// Converts an [`i128`] to a [`Natural`], returning `None` if it is negative.
+    #[inline]
+    fn from_i128(n: i128) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    /// Converts a [`u64`] to a [`Natural`]. The result is never `None`.
+    #[inline]
+    fn from_u64(n: u64) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    /// Converts a [`u128`] to a [`Natural`]. The result is never `None`.
+    #[inline]
+    fn from_u128(n: u128) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    /// Converts an [`f64`] to a [`Natural`], rounding towards zero. Returns `None` if the [`f64`]
+    /// is infinite, NaN, or less than or equal to $-1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_traits::FromPrimitive;
+    ///
+    /// assert_eq!(Natural::from_f64(123.9).unwrap(), 123);
+    /// assert_eq!(Natural::from_f64(-0.5).unwrap(), 0);
+    /// assert_eq!(Natural::from_f64(-1.0), None);
+    /// assert_eq!(Natural::from_f64(f64::NAN), None);
+    /// ```
+    #[inline]
+    fn from_f64(n: f64) -> Option<Natural> {
+        Natural::try_from(n.trunc()).ok()
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        impl num_traits::Pow<$t> for Natural {
+            type Output = Natural;
+
+            /// Raises a [`Natural`] to a power, taking the [`Natural`] by value.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                Pow::pow(self, u64::exact_from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Natural {
+            type Output = Natural;
+
+            /// Raises a [`Natural`] to a power, taking the [`Natural`] by reference.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                Pow::pow(self, u64::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+impl_pow!(usize);
+
+impl num_traits::CheckedDiv for Natural {
+    /// Divides a [`Natural`] by another [`Natural`], rounding the quotient down. Returns `None` if
+    /// the divisor is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_traits::CheckedDiv;
+    ///
+    /// assert_eq!(Natural::from(23u32).checked_div(&Natural::from(10u32)).unwrap(), 2);
+    /// assert_eq!(Natural::from(23u32).checked_div(&Natural::from(0u32)), None);
+    /// ```
+    #[inline]
+    fn checked_div(&self, other: &Natural) -> Option<Natural> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl num_integer::Integer for Natural {
+    /// Divides a [`Natural`] by another [`Natural`], rounding the quotient down.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_floor(&self, other: &Natural) -> Natural {
+        self / other
+    }
+
+    /// Returns the remainder when a [`Natural`] is divided by another [`Natural`].
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn mod_floor(&self, other: &Natural) -> Natural {
+        self.mod_op(other)
+    }
+
+    /// Computes the GCD (greatest common divisor) of two [`Natural`]s.
+    #[inline]
+    fn gcd(&self, other: &Natural) -> Natural {
+        Gcd::gcd(self, other)
+    }
+
+    /// Computes the LCM (least common multiple) of two [`Natural`]s.
+    #[inline]
+    fn lcm(&self, other: &Natural) -> Natural {
+        Lcm::lcm(self, other)
+    }
+
+    /// Determines whether a [`Natural`] is divisible by another [`Natural`]. Only 0 is
+    /// divisible by 0.
+    #[inline]
+    fn divides(&self, other: &Natural) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether a [`Natural`] is divisible by another [`Natural`]. Only 0 is
+    /// divisible by 0.
+    #[inline]
+    fn is_multiple_of(&self, other: &Natural) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether a [`Natural`] is even.
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.even()
+    }
+
+    /// Determines whether a [`Natural`] is odd.
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.odd()
+    }
+
+    /// Divides a [`Natural`] by another [`Natural`], returning the quotient and remainder.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_rem(&self, other: &Natural) -> (Natural, Natural) {
+        DivRem::div_rem(self, other)
+    }
+
+    /// Divides a [`Natural`] by another [`Natural`], returning the quotient and remainder.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_mod_floor(&self, other: &Natural) -> (Natural, Natural) {
+        self.div_mod(other)
+    }
+}
+
+impl num_integer::Roots for Natural {
+    /// Returns the floor of the $n$th root of a [`Natural`].
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(Natural::from(1000u32).nth_root(3), 10);
+    /// assert_eq!(Natural::from(1001u32).nth_root(3), 10);
+    /// assert_eq!(Natural::from(999u32).sqrt(), 31);
+    /// ```
+    #[inline]
+    fn nth_root(&self, n: u32) -> Natural {
+        self.floor_root(u64::from(n))
+    }
+
+    /// Returns the floor of the square root of a [`Natural`].
+    #[inline]
+    fn sqrt(&self) -> Natural {
+        self.floor_sqrt()
+    }
+
+    /// Returns the floor of the cube root of a [`Natural`].
+    #[inline]
+    fn cbrt(&self) -> Natural {
+        self.floor_root(3)
+    }
+}
@@ -8,7 +8,7 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(any(feature = "32_bit_limbs", feature = "128_bit_limbs"))]
 use malachite_base::num::iterators::iterator_to_bit_chunks;
 use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
 use malachite_base::num::random::geometric::{
@@ -65,7 +65,17 @@ pub fn get_random_natural_with_up_to_bits(xs: &mut RandomPrimitiveInts<u64>, bit
     )
     .map(Option::unwrap)
     .collect_vec();
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(feature = "128_bit_limbs")]
+    let mut xs = iterator_to_bit_chunks(
+        xs.take(usize::exact_from(
+            bits.shr_round(u64::LOG_WIDTH, RoundingMode::Ceiling),
+        )),
+        u64::WIDTH,
+        u128::WIDTH,
+    )
+    .map(Option::unwrap)
+    .collect_vec();
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let mut xs = xs
         .take(usize::exact_from(
             bits.shr_round(u64::LOG_WIDTH, RoundingMode::Ceiling),
@@ -119,7 +129,17 @@ pub fn get_random_natural_with_bits(xs: &mut RandomPrimitiveInts<u64>, bits: u64
     )
     .map(Option::unwrap)
     .collect_vec();
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(feature = "128_bit_limbs")]
+    let mut xs = iterator_to_bit_chunks(
+        xs.take(usize::exact_from(
+            bits.shr_round(u64::LOG_WIDTH, RoundingMode::Ceiling),
+        )),
+        u64::WIDTH,
+        u128::WIDTH,
+    )
+    .map(Option::unwrap)
+    .collect_vec();
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let mut xs = xs
         .take(usize::exact_from(
             bits.shr_round(u64::LOG_WIDTH, RoundingMode::Ceiling),
@@ -0,0 +1,97 @@
+// Helpers for splitting large computations across threads. Without the `parallel` feature, they
+// run everything on the current thread, in order, so callers can use them unconditionally. Callers
+// must make sure that the pieces they hand out are independent; then the results don't depend on
+// whether the feature is enabled.
+
+use crate::platform::PARALLEL_THRESHOLD;
+#[cfg(feature = "test_build")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "test_build")]
+static PARALLEL_THRESHOLD_OVERRIDE: AtomicUsize = AtomicUsize::new(PARALLEL_THRESHOLD);
+
+// Returns whether an operation whose largest input has `len` limbs is large enough to be split
+// across threads. This is always false when the `parallel` feature is disabled.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+#[cfg(not(feature = "test_build"))]
+#[inline]
+pub(crate) const fn use_threads(len: usize) -> bool {
+    cfg!(feature = "parallel") && len >= PARALLEL_THRESHOLD
+}
+
+// Returns whether an operation whose largest input has `len` limbs is large enough to be split
+// across threads. This is always false when the `parallel` feature is disabled. In test builds,
+// the threshold can be changed with `set_parallel_threshold`.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+#[cfg(feature = "test_build")]
+#[inline]
+pub(crate) fn use_threads(len: usize) -> bool {
+    cfg!(feature = "parallel") && len >= PARALLEL_THRESHOLD_OVERRIDE.load(Ordering::Relaxed)
+}
+
+// Replaces `PARALLEL_THRESHOLD` with `threshold` for the whole process, so that tests can exercise
+// the multithreaded code paths with small inputs.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+#[cfg(feature = "test_build")]
+pub fn set_parallel_threshold(threshold: usize) {
+    PARALLEL_THRESHOLD_OVERRIDE.store(threshold, Ordering::Relaxed);
+}
+
+// Runs `a` and `b`, possibly on different threads, and returns both results.
+#[cfg(feature = "parallel")]
+#[inline]
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    rayon::join(a, b)
+}
+
+// Runs `a` and then `b`, and returns both results.
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    (a(), b())
+}
+
+// Calls `f` on every element of `items`, possibly on several threads. Each thread works with its
+// own state, which is created by `init`; `f` must not depend on the initial contents of the state.
+#[cfg(feature = "parallel")]
+pub(crate) fn for_each_init<T, S, I, F>(items: Vec<T>, init: I, f: F)
+where
+    T: Send,
+    I: Fn() -> S + Send + Sync,
+    F: Fn(&mut S, T) + Send + Sync,
+{
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    items.into_par_iter().for_each_init(init, f);
+}
+
+// Calls `f` on every element of `items`, in order, using a single state created by `init`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn for_each_init<T, S, I, F>(items: Vec<T>, init: I, f: F)
+where
+    T: Send,
+    I: Fn() -> S + Send + Sync,
+    F: Fn(&mut S, T) + Send + Sync,
+{
+    let mut state = init();
+    for item in items {
+        f(&mut state, item);
+    }
+}
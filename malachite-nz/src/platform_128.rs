@@ -0,0 +1,2759 @@
+use crate::natural::conversion::primitive_int_from_natural::UnsignedFromNaturalError;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedAdd, DivMod, DivRem, Gcd, JacobiSymbol, ModPowerOf2, ModPowerOf2Square, OverflowingAdd,
+    Parity, Square, WrappingAdd, WrappingAddAssign, WrappingSub, WrappingSubAssign, XMulYToZZ,
+    XXDivModYToQR,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{HasHalf, JoinHalves, SplitInHalf};
+use std::mem::swap;
+use std::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+
+pub type Limb = u128;
+pub type HalfLimb = u64;
+pub type SignedLimb = i128;
+pub type SignedHalfLimb = i64;
+pub type FloatWithLimbWidth = f64;
+
+// The number of limbs that a `Natural` stores without a heap allocation.
+pub const INLINE_LIMB_COUNT: usize = 2;
+
+// There is no 256-bit primitive type, so `DoubleLimb` is a pair of limbs that implements only the
+// operations that the limb-level algorithms need. Like the primitive types, it panics on overflow
+// in debug builds, except where the operation is explicitly wrapping.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DoubleLimb {
+    hi: Limb,
+    lo: Limb,
+}
+
+impl DoubleLimb {
+    const ZERO: DoubleLimb = DoubleLimb { hi: 0, lo: 0 };
+    const ONE: DoubleLimb = DoubleLimb { hi: 0, lo: 1 };
+
+    fn overflowing_sub(self, other: DoubleLimb) -> (DoubleLimb, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let (hi, overflow_1) = self.hi.overflowing_sub(other.hi);
+        let (hi, overflow_2) = hi.overflowing_sub(Limb::from(borrow));
+        (DoubleLimb { hi, lo }, overflow_1 || overflow_2)
+    }
+
+    fn trailing_zeros(self) -> u64 {
+        if self.lo == 0 {
+            Limb::WIDTH + u64::from(self.hi.trailing_zeros())
+        } else {
+            u64::from(self.lo.trailing_zeros())
+        }
+    }
+
+    // The quotient and remainder of `self` / `d`; `d` must be nonzero.
+    fn div_mod_helper(self, d: DoubleLimb) -> (DoubleLimb, DoubleLimb) {
+        assert_ne!(d, DoubleLimb::ZERO, "division by zero");
+        if d.hi == 0 {
+            let (q_hi, r_hi) = (self.hi / d.lo, self.hi % d.lo);
+            let (q_lo, r) = Limb::xx_div_mod_y_to_qr(r_hi, self.lo, d.lo);
+            (DoubleLimb::join_halves(q_hi, q_lo), DoubleLimb::from(r))
+        } else if self < d {
+            (DoubleLimb::ZERO, self)
+        } else {
+            // The quotient fits in a limb, so it can be found with shifts and subtractions.
+            let shift = u64::from(d.hi.leading_zeros());
+            let mut shifted_d = d << shift;
+            let mut q: Limb = 0;
+            let mut r = self;
+            for i in (0..=shift).rev() {
+                if r >= shifted_d {
+                    r = r - shifted_d;
+                    q |= 1 << i;
+                }
+                shifted_d = shifted_d >> 1;
+            }
+            (DoubleLimb::from(q), r)
+        }
+    }
+}
+
+impl From<Limb> for DoubleLimb {
+    #[inline]
+    fn from(x: Limb) -> DoubleLimb {
+        DoubleLimb { hi: 0, lo: x }
+    }
+}
+
+impl HasHalf for DoubleLimb {
+    type Half = Limb;
+}
+
+impl JoinHalves for DoubleLimb {
+    #[inline]
+    fn join_halves(upper: Limb, lower: Limb) -> DoubleLimb {
+        DoubleLimb {
+            hi: upper,
+            lo: lower,
+        }
+    }
+}
+
+impl SplitInHalf for DoubleLimb {
+    #[inline]
+    fn lower_half(&self) -> Limb {
+        self.lo
+    }
+
+    #[inline]
+    fn upper_half(&self) -> Limb {
+        self.hi
+    }
+}
+
+impl OverflowingAdd for DoubleLimb {
+    type Output = DoubleLimb;
+
+    fn overflowing_add(self, other: DoubleLimb) -> (DoubleLimb, bool) {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let (hi, overflow_1) = self.hi.overflowing_add(other.hi);
+        let (hi, overflow_2) = hi.overflowing_add(Limb::from(carry));
+        (DoubleLimb { hi, lo }, overflow_1 || overflow_2)
+    }
+}
+
+impl CheckedAdd for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn checked_add(self, other: DoubleLimb) -> Option<DoubleLimb> {
+        let (sum, overflow) = self.overflowing_add(other);
+        if overflow {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+}
+
+impl WrappingAdd for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn wrapping_add(self, other: DoubleLimb) -> DoubleLimb {
+        self.overflowing_add(other).0
+    }
+}
+
+impl WrappingAddAssign for DoubleLimb {
+    #[inline]
+    fn wrapping_add_assign(&mut self, other: DoubleLimb) {
+        *self = self.wrapping_add(other);
+    }
+}
+
+impl WrappingSub for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn wrapping_sub(self, other: DoubleLimb) -> DoubleLimb {
+        self.overflowing_sub(other).0
+    }
+}
+
+impl WrappingSubAssign for DoubleLimb {
+    #[inline]
+    fn wrapping_sub_assign(&mut self, other: DoubleLimb) {
+        *self = self.wrapping_sub(other);
+    }
+}
+
+impl Add for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn add(self, other: DoubleLimb) -> DoubleLimb {
+        let (sum, overflow) = self.overflowing_add(other);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        sum
+    }
+}
+
+impl Sub for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn sub(self, other: DoubleLimb) -> DoubleLimb {
+        let (difference, overflow) = self.overflowing_sub(other);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        difference
+    }
+}
+
+impl Mul for DoubleLimb {
+    type Output = DoubleLimb;
+
+    fn mul(self, other: DoubleLimb) -> DoubleLimb {
+        debug_assert!(
+            self.hi == 0 || other.hi == 0,
+            "attempt to multiply with overflow"
+        );
+        let (hi, lo) = Limb::x_mul_y_to_zz(self.lo, other.lo);
+        let (cross_hi, cross_lo) = if self.hi == 0 {
+            Limb::x_mul_y_to_zz(self.lo, other.hi)
+        } else {
+            Limb::x_mul_y_to_zz(self.hi, other.lo)
+        };
+        let (hi, overflow) = hi.overflowing_add(cross_lo);
+        debug_assert!(
+            cross_hi == 0 && !overflow,
+            "attempt to multiply with overflow"
+        );
+        DoubleLimb { hi, lo }
+    }
+}
+
+impl Shl<u64> for DoubleLimb {
+    type Output = DoubleLimb;
+
+    fn shl(self, bits: u64) -> DoubleLimb {
+        assert!(
+            bits < Limb::WIDTH << 1,
+            "attempt to shift left with overflow"
+        );
+        if bits == 0 {
+            self
+        } else if bits < Limb::WIDTH {
+            DoubleLimb {
+                hi: (self.hi << bits) | (self.lo >> (Limb::WIDTH - bits)),
+                lo: self.lo << bits,
+            }
+        } else {
+            DoubleLimb {
+                hi: self.lo << (bits - Limb::WIDTH),
+                lo: 0,
+            }
+        }
+    }
+}
+
+impl Shr<u64> for DoubleLimb {
+    type Output = DoubleLimb;
+
+    fn shr(self, bits: u64) -> DoubleLimb {
+        assert!(
+            bits < Limb::WIDTH << 1,
+            "attempt to shift right with overflow"
+        );
+        if bits == 0 {
+            self
+        } else if bits < Limb::WIDTH {
+            DoubleLimb {
+                hi: self.hi >> bits,
+                lo: (self.lo >> bits) | (self.hi << (Limb::WIDTH - bits)),
+            }
+        } else {
+            DoubleLimb {
+                hi: 0,
+                lo: self.hi >> (bits - Limb::WIDTH),
+            }
+        }
+    }
+}
+
+impl Div for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn div(self, other: DoubleLimb) -> DoubleLimb {
+        self.div_mod_helper(other).0
+    }
+}
+
+impl Rem for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn rem(self, other: DoubleLimb) -> DoubleLimb {
+        self.div_mod_helper(other).1
+    }
+}
+
+impl DivRem for DoubleLimb {
+    type DivOutput = DoubleLimb;
+    type RemOutput = DoubleLimb;
+
+    #[inline]
+    fn div_rem(self, other: DoubleLimb) -> (DoubleLimb, DoubleLimb) {
+        self.div_mod_helper(other)
+    }
+}
+
+impl DivMod for DoubleLimb {
+    type DivOutput = DoubleLimb;
+    type ModOutput = DoubleLimb;
+
+    #[inline]
+    fn div_mod(self, other: DoubleLimb) -> (DoubleLimb, DoubleLimb) {
+        self.div_mod_helper(other)
+    }
+}
+
+impl Square for DoubleLimb {
+    type Output = DoubleLimb;
+
+    #[inline]
+    fn square(self) -> DoubleLimb {
+        self * self
+    }
+}
+
+impl ModPowerOf2 for DoubleLimb {
+    type Output = DoubleLimb;
+
+    fn mod_power_of_2(self, pow: u64) -> DoubleLimb {
+        if pow >= Limb::WIDTH << 1 {
+            self
+        } else if pow >= Limb::WIDTH {
+            DoubleLimb {
+                hi: self.hi.mod_power_of_2(pow - Limb::WIDTH),
+                lo: self.lo,
+            }
+        } else {
+            DoubleLimb::from(self.lo.mod_power_of_2(pow))
+        }
+    }
+}
+
+impl ModPowerOf2Square for DoubleLimb {
+    type Output = DoubleLimb;
+
+    // Unlike `square`, this doesn't overflow; the square is computed modulo $2^{2W}$ before it is
+    // reduced.
+    fn mod_power_of_2_square(self, pow: u64) -> DoubleLimb {
+        let (hi, lo) = Limb::x_mul_y_to_zz(self.lo, self.lo);
+        let cross = self.lo.wrapping_mul(self.hi);
+        DoubleLimb {
+            hi: hi.wrapping_add(cross).wrapping_add(cross),
+            lo,
+        }
+        .mod_power_of_2(pow)
+    }
+}
+
+impl Gcd for DoubleLimb {
+    type Output = DoubleLimb;
+
+    // Binary GCD.
+    fn gcd(self, other: DoubleLimb) -> DoubleLimb {
+        if self == DoubleLimb::ZERO {
+            return other;
+        }
+        if other == DoubleLimb::ZERO {
+            return self;
+        }
+        let x_zeros = self.trailing_zeros();
+        let y_zeros = other.trailing_zeros();
+        let mut x = self >> x_zeros;
+        let mut y = other >> y_zeros;
+        while x != y {
+            if x < y {
+                swap(&mut x, &mut y);
+            }
+            x = x - y;
+            x = x >> x.trailing_zeros();
+        }
+        x << x_zeros.min(y_zeros)
+    }
+}
+
+impl JacobiSymbol for DoubleLimb {
+    fn jacobi_symbol(self, other: DoubleLimb) -> i8 {
+        assert!(other.lo.odd());
+        let mut x = self % other;
+        let mut y = other;
+        let mut s = 1;
+        while x != DoubleLimb::ZERO {
+            let zeros = x.trailing_zeros();
+            x = x >> zeros;
+            if zeros.odd() {
+                let r = y.lo & 7;
+                if r == 3 || r == 5 {
+                    s = -s;
+                }
+            }
+            swap(&mut x, &mut y);
+            if x.lo & 3 == 3 && y.lo & 3 == 3 {
+                s = -s;
+            }
+            x = x % y;
+        }
+        if y == DoubleLimb::ONE {
+            s
+        } else {
+            0
+        }
+    }
+}
+
+impl From<DoubleLimb> for Natural {
+    #[inline]
+    fn from(x: DoubleLimb) -> Natural {
+        Natural::from_owned_limbs_asc(vec![x.lo, x.hi])
+    }
+}
+
+impl<'a> TryFrom<&'a Natural> for DoubleLimb {
+    type Error = UnsignedFromNaturalError;
+
+    fn try_from(value: &Natural) -> Result<DoubleLimb, Self::Error> {
+        match value {
+            Natural(Small(x)) => Ok(DoubleLimb::from(*x)),
+            Natural(Large(xs)) if xs.len() == 2 => Ok(DoubleLimb::join_halves(xs[1], xs[0])),
+            _ => Err(UnsignedFromNaturalError),
+        }
+    }
+}
+
+// The thresholds below are provisional. None of them has been measured with 128-bit limbs; most are
+// the 64-bit values halved, so that each one corresponds to about the same number of bits. To
+// measure them on a particular machine, run the tuner with this feature enabled and build with
+// `tuned_thresholds`; see the crate documentation.
+
+pub const AORSMUL_FASTER_2AORSLSH: bool = true;
+pub const AORSMUL_FASTER_3AORSLSH: bool = true;
+pub const AORSMUL_FASTER_AORS_AORSLSH: bool = true;
+pub const AORSMUL_FASTER_AORS_2AORSLSH: bool = true;
+
+pub const MUL_TOOM22_THRESHOLD: usize = 10;
+pub const MUL_TOOM33_THRESHOLD: usize = 20;
+pub const MUL_TOOM44_THRESHOLD: usize = 170; // unclear when 44 is better than 33
+pub const MUL_TOOM6H_THRESHOLD: usize = 173;
+pub const MUL_TOOM8H_THRESHOLD: usize = 320;
+
+pub const MUL_TOOM32_TO_TOOM43_THRESHOLD: usize = 30;
+pub const MUL_TOOM32_TO_TOOM53_THRESHOLD: usize = 150;
+pub const MUL_TOOM42_TO_TOOM53_THRESHOLD: usize = 300;
+pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 52;
+
+pub const MUL_FFT_THRESHOLD: usize = 750;
+pub const SQR_FFT_THRESHOLD: usize = SQR_TOOM3_THRESHOLD * 30;
+
+// Operands whose average length is at least this many limbs are multiplied with the
+// number-theoretic transform instead of the FFT. Provisional, like the thresholds above: this is
+// the 64-bit value halved.
+pub const MUL_NTT_THRESHOLD: usize = 8000;
+pub const SQR_NTT_THRESHOLD: usize = 8000;
+
+// When the `parallel` feature is enabled, independent parts of an operation whose largest input has
+// at least this many limbs may run on separate threads.
+pub const PARALLEL_THRESHOLD: usize = 500;
+
+pub const DC_DIV_QR_THRESHOLD: usize = 43;
+pub const DC_DIVAPPR_Q_THRESHOLD: usize = 106;
+pub const MAYBE_DCP1_DIVAPPR: bool = true;
+pub const INV_NEWTON_THRESHOLD: usize = 395;
+pub const MU_DIV_QR_THRESHOLD: usize = 1047;
+pub const INV_MULMOD_BNM1_THRESHOLD: usize = 31;
+pub const MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD: usize = INV_MULMOD_BNM1_THRESHOLD >> 1;
+pub const MU_DIV_QR_SKEW_THRESHOLD: usize = 116;
+
+pub const MU_DIVAPPR_Q_THRESHOLD: usize = 1483;
+pub const FUDGE: usize = 311;
+
+pub const MULLO_BASECASE_THRESHOLD: usize = 1;
+pub const MULLO_DC_THRESHOLD: usize = 28;
+pub const MULLO_MUL_N_THRESHOLD: usize = 5403;
+
+pub const BINV_NEWTON_THRESHOLD: usize = 1106;
+pub const DC_BDIV_QR_THRESHOLD: usize = 106;
+pub const MU_BDIV_QR_THRESHOLD: usize = 3774;
+pub const DC_BDIV_Q_THRESHOLD: usize = 106;
+pub const MU_BDIV_Q_THRESHOLD: usize = 999;
+
+pub const MOD_1_NORM_THRESHOLD: usize = 0;
+pub const MOD_1_UNNORM_THRESHOLD: usize = 0;
+pub const MOD_1_1P_METHOD: bool = false;
+pub const MOD_1N_TO_MOD_1_1_THRESHOLD: usize = 3;
+pub const MOD_1U_TO_MOD_1_1_THRESHOLD: usize = 0;
+pub const MOD_1_1_TO_MOD_1_2_THRESHOLD: usize = 6;
+pub const MOD_1_2_TO_MOD_1_4_THRESHOLD: usize = 26;
+
+pub const BMOD_1_TO_MOD_1_THRESHOLD: usize = 100000000;
+
+pub const SQR_BASECASE_THRESHOLD: usize = 0;
+pub const SQR_TOOM2_THRESHOLD: usize = 22;
+pub const SQR_TOOM3_THRESHOLD: usize = 195;
+pub const SQR_TOOM4_THRESHOLD: usize = 545;
+pub const SQR_TOOM6_THRESHOLD: usize = 168;
+pub const SQR_TOOM8_THRESHOLD: usize = 419;
+
+pub const SQRLO_DC_THRESHOLD: usize = 195;
+
+pub const FROM_DIGITS_DIVIDE_AND_CONQUER_THRESHOLD: u64 = 6500;
+
+pub const MATRIX22_STRASSEN_THRESHOLD: usize = 15;
+
+// This section is created by digits_data.rs.
+
+// mp_bases[10] data, as literal values
+pub const MP_BASES_CHARS_PER_LIMB_10: usize = 38;
+pub const MP_BASES_BIG_BASE_CTZ_10: usize = 38;
+pub const MP_BASES_BIG_BASE_10: Limb = 0x4b3b4ca85a86c47a098a224000000000;
+pub const MP_BASES_BIG_BASE_INVERTED_10: Limb = 0xb38fb9daa78e44ab2dcf7a6b19209442;
+pub const MP_BASES_BIG_BASE_BINVERTED_10: Limb = 0x8e66502f8e41ca48b0d57d3d827fcdb9;
+pub const MP_BASES_NORMALIZATION_STEPS_10: u64 = 1;
+
+// Format is (chars_per_limb, logb2, log2b, big_base, big_base_inverted)
+pub const BASES: [(usize, Limb, Limb, Limb, Limb); 257] = [
+    (0, 0, 0, 0, 0), // 0
+    (0, 0, 0, 0, 0), // 1
+    (
+        128,
+        0xffffffffffffffffffffffffffffffff,
+        0x1fffffffffffffffffffffffffffffff,
+        0x1,
+        0x0,
+    ), // 2
+    (
+        80,
+        0xa1849cc1a9a9e94e043eaf7791f52142,
+        0x32b803473f7ad0f3f401624140d175ba,
+        0x6f32f1ef8b18a2bc3cea59789c79d441,
+        0x26adcc42519d4e6dbb945462cb4bf215,
+    ), // 3
+    (
+        64,
+        0x7fffffffffffffffffffffffffffffff,
+        0x3fffffffffffffffffffffffffffffff,
+        0x2,
+        0x0,
+    ), // 4
+    (
+        55,
+        0x6e40d1a4143dcb9433d522368f0d1d89,
+        0x4a4d3c25e68dc57f2495fb7fa6d7eda6,
+        0xd0cf4b50cfe20765fff4b4e3f741cf6d,
+        0x39dae6f76d88307aaa8c9bad2d0ac0d7,
+    ), // 5
+    (
+        49,
+        0x6308c91b702a7cf4ff85a5c1b80aaa91,
+        0x52b803473f7ad0f3f401624140d175ba,
+        0x6558e2a0921fe0694286000000000000,
+        0x4352fb8f21b71b9afb653f520e330702,
+    ), // 6
+    (
+        45,
+        0x5b3064eb3aa6d3889bd82cc11a7209d2,
+        0x59d5d9fd5010b36665592074827cb508,
+        0x5080c7b7d0e31ba75911a67ddd3d35e7,
+        0x970a5da5ebd86b84718d6342ee72022f,
+    ), // 7
+    (
+        42,
+        0x55555555555555555555555555555555,
+        0x5fffffffffffffffffffffffffffffff,
+        0x3,
+        0x0,
+    ), // 8
+    (
+        40,
+        0x50c24e60d4d4f4a7021f57bbc8fa90a1,
+        0x6570068e7ef5a1e7e802c48281a2eb74,
+        0x6f32f1ef8b18a2bc3cea59789c79d441,
+        0x26adcc42519d4e6dbb945462cb4bf215,
+    ), // 9
+    (
+        38,
+        0x4d104d427de7fbcc47c4acd605be48bc,
+        0x6a4d3c25e68dc57f2495fb7fa6d7eda6,
+        0x4b3b4ca85a86c47a098a224000000000,
+        0xb38fb9daa78e44ab2dcf7a6b19209442,
+    ), // 10
+    (
+        37,
+        0x4a00270775914e8870b466920e51e1f7,
+        0x6eb3a9f01975077f1f5f0cc82aaa9ad7,
+        0xffd1390a0adc2fb8dabbb8174d95c99b,
+        0x2ecf83a043d689f7e700fd06311ad7,
+    ), // 11
+    (
+        35,
+        0x4768ce0d05818e127f122e2f4c79f9ca,
+        0x72b803473f7ad0f3f401624140d175ba,
+        0x2c6fdb364c25e6c00000000000000000,
+        0x70b3c7bc7ee0d4ecced4db9c11803adb,
+    ), // 12
+    (
+        34,
+        0x452e53e365907bda2bf75000cfb72251,
+        0x766a008e4788cbcd2edb4390e5306a23,
+        0x384bacd642c343b4e90c427213506d29,
+        0x2308ff6b9d7122006828477572f24f2d,
+    ), // 13
+    (
+        33,
+        0x433cfffb4b5aae55c2d2e89586d2b763,
+        0x79d5d9fd5010b36665592074827cb508,
+        0x31f5db32a34aced60bf13a0e00000000,
+        0x47f09c615d57c6c3a0a60c9c9f353d8b,
+    ), // 14
+    (
+        32,
+        0x41867711b4f8535537bbdca4fca609de,
+        0x7d053f6d2608967318975dc0e7a96360,
+        0x20753adafd1e839f53686d013143ee01,
+        0xf8c6c8cb2a4065c5cbb65ac58d1502be,
+    ), // 15
+    (
+        32,
+        0x3fffffffffffffffffffffffffffffff,
+        0x7fffffffffffffffffffffffffffffff,
+        0x4,
+        0x0,
+    ), // 16
+    (
+        31,
+        0x3ea16afd58b10966e1c51ddbeac65f02,
+        0x82cc7edf592262cf998a704c288aeb58,
+        0x68ca11d6b4f6d1d1faa826678073c2f1,
+        0x38b41dc0bbe4c3f26b69e063396a6d18,
+    ), // 17
+    (
+        30,
+        0x3d64598d154dc4de0da34544e21084a1,
+        0x8570068e7ef5a1e7e802c48281a2eb74,
+        0x223e493bb3bb69ffa4b87d6c40000000,
+        0xde755fa407caf1b6fdd68c035039f613,
+    ), // 18
+    (
+        30,
+        0x3c43c23018bb55630369e97d641961e5,
+        0x87ef05ae409a0288ea366629e13bcd7c,
+        0xad62418d14ea824701c4b4886cc66f59,
+        0x79fb735b4915540a7039834affda2e78,
+    ), // 19
+    (
+        29,
+        0x3b3b9a42873069c702cceaea8207233f,
+        0x8a4d3c25e68dc57f2495fb7fa6d7eda6,
+        0x2863c1f5cdae42f95400000000000000,
+        0x95a5efea6b34767c12ddc8b027408660,
+    ), // 20
+    (
+        29,
+        0x3a4898f06cf41ac990409adae68a5d43,
+        0x8c8ddd448f8b845a595a82b5c34e2ac3,
+        0xa63fd833b9386b0736039e82be651b25,
+        0x8a33f41dfb812a5437591fcc2b04ac1a,
+    ), // 21
+    (
+        28,
+        0x39680b13582e7c1876f62d7317e2d8bd,
+        0x8eb3a9f01975077f1f5f0cc82aaa9ad7,
+        0x1d1f7a9cd087a14d28cdf3d510000000,
+        0x194a404b3caa09037c736d63cffc3af5,
+    ), // 22
+    (
+        28,
+        0x3897b2b751ae561ab0f3e4b3bda6639c,
+        0x90c10500d63aa6588257529b625de155,
+        0x651b5095c2ea8fc1b30e2c5777aaf7e1,
+        0x4417e038820f65a863c7f63468068893,
+    ), // 23
+    (
+        27,
+        0x37d5aed131f19c98cd9850af9a126d7e,
+        0x92b803473f7ad0f3f401624140d175ba,
+        0xddef20eff7600000000000000000000,
+        0x274b7c902f7e01884906ea82d6e43b23,
+    ), // 24
+    (
+        27,
+        0x372068d20a1ee5ca19ea911b47868ec4,
+        0x949a784bcd1b8afe492bf6ff4dafdb4c,
+        0x29c30f1029939b146664242d97d9f649,
+        0x8851a0b548ea3c99552fc298784d710d,
+    ), // 25
+    (
+        27,
+        0x3676867e5d60de291912e33748b4029f,
+        0x966a008e4788cbcd2edb4390e5306a23,
+        0x786a435ae9558b0e6aaf6d63a8000000,
+        0x102017b5a4d6e433fd7e49bcca9f65be,
+    ), // 26
+    (
+        26,
+        0x35d6deeb388df86f56bf8fd285fc606b,
+        0x982809d5be7072dbdc0426c3c274612e,
+        0xc5afe6ff302bcbf94fd9829d87f5079,
+        0x4b8385ca9bd0f83b7306deef24b57057,
+    ), // 27
+    (
+        26,
+        0x354071d61c77fa2e37ac410062da9305,
+        0x99d5d9fd5010b36665592074827cb508,
+        0x1fce575ce16927060710000000000000,
+        0x18fb15ae068fc0aa58e5fea5bb2a718,
+    ), // 28
+    (
+        26,
+        0x34b260c5671b18acf3315689e7fc958f,
+        0x9b74948f5532da4b4b71433638c6ece3,
+        0x4f34497c8597e14436e9180200528229,
+        0x9db716d8d56b8607f291473e885ae488,
+    ), // 29
+    (
+        26,
+        0x342be986572b45cc8d5dad3f1f35ccc3,
+        0x9d053f6d2608967318975dc0e7a96360,
+        0xbf3a8e1d41ef21707802130d84000000,
+        0x56b5c31be3514a2b0fd58d323a64f77d,
+    ), // 30
+    (
+        25,
+        0x33ac61b998fbbdf2b55bac355a82ee98,
+        0x9e88c6b3626a72aa21a3c7f01f78e28a,
+        0xe7819e17f1eb0fb6ee4fb8901d9531f,
+        0x1b15cc21798add085981c0570030cfd8,
+    ), // 31
+    (
+        25,
+        0x33333333333333333333333333333333,
+        0x9fffffffffffffffffffffffffffffff,
+        0x5,
+        0x0,
+    ), // 32
+    (
+        25,
+        0x32bfd90114c12861c220c028e9dbc15a,
+        0xa16bad3758efd87313606f096b7c1092,
+        0x4510460dd9e879c014a823752f22b321,
+        0xda762094604d076d49a78624344c9f87,
+    ), // 33
+    (
+        25,
+        0x3251dcf6169e45f2bed2f23982c11654,
+        0xa2cc7edf592262cf998a704c288aeb58,
+        0x91abce3c4b4117ade76d35db22000000,
+        0xc1e3e0d67bd8dc76a07632edff2d4d6b,
+    ), // 34
+    (
+        24,
+        0x31e8d59f180dc6309a55d658e0cac095,
+        0xa4231623369e78e589ef1bf42954a2af,
+        0x8973ea355d75bc22e42c391727d69e1,
+        0xdcca05b0626db611ce863c079e9b2c30,
+    ), // 35
+    (
+        24,
+        0x3184648db8153e7a7fc2d2e0dc055548,
+        0xa570068e7ef5a1e7e802c48281a2eb74,
+        0x10e425c56daffabc35c1000000000000,
+        0xe4fc7956b292a9687917defb154c8a84,
+    ), // 36
+    (
+        24,
+        0x312434e89c35dacd8582e68d01d31eb1,
+        0xa6b3d78b6d3b24fbf847b2f0060d8d2d,
+        0x209a1122718bdbd640b7cd9739b584a1,
+        0xf68c6f11f23677732b7d224a83194743,
+    ), // 37
+    (
+        24,
+        0x30c7fa349460a54168f6090a13559921,
+        0xa7ef05ae409a0288ea366629e13bcd7c,
+        0x3dd4c1b8d3f5da736073899d61000000,
+        0x8fae174d855d532cd01eb5374ef79a0,
+    ), // 38
+    (
+        24,
+        0x306f6f4c8432bc6d7f3c111ea617865c,
+        0xa92203d587039cc122dca5d22601dfdd,
+        0x735513fab924de1f056042fb69704941,
+        0x1c1e396996ac1929a9610d1e077c8efe,
+    ), // 39
+    (
+        24,
+        0x301a557ffbfdd2523737de42f53faffc,
+        0xaa4d3c25e68dc57f2495fb7fa6d7eda6,
+        0xd3c21bcecceda1000000000000000000,
+        0x357c299a88ea76a58924d52ce4f26a85,
+    ), // 40
+    (
+        23,
+        0x2fc873d1fda55f3bf7088ef857a4759e,
+        0xab7110e6ce866f2bc6a905a27b81e221,
+        0x9577e50bd16527d5cb82811f8f09ed9,
+        0xb675f3c20a62eabba987a8b3288d3d9f,
+    ), // 41
+    (
+        23,
+        0x2f799652a4e6dc496e834bf9b9a7c903,
+        0xac8ddd448f8b845a595a82b5c34e2ac3,
+        0x1042b0df8f6c5e1eda147b73ee800000,
+        0xf7cc14e596ad8adeb278f6b1019386ac,
+    ), // 42
+    (
+        23,
+        0x2f2d8d8f64460aad65557a7ade344037,
+        0xada3f5fb9c4150520a377c7ec513c756,
+        0x1befcb20663f89554f06540c2f1553e3,
+        0x253c02dfed6f1a6682ac2f2bfb33513f,
+    ), // 43
+    (
+        23,
+        0x2ee42e164e8f53a405614b4650f19f9c,
+        0xaeb3a9f01975077f1f5f0cc82aaa9ad7,
+        0x2f6757ba67f87e2ade80c00000000000,
+        0x59a08d66d1fe970a4212ddf963c6d63b,
+    ), // 44
+    (
+        23,
+        0x2e9d500984041dbd479c8f4e39161804,
+        0xafbd42b4658367670c98c002287ad91a,
+        0x4f7c4ed87dcc0d9dcc5d1ceb77dd7485,
+        0x9c403a126341fc2b57502fe425deabfd,
+    ), // 45
+    (
+        23,
+        0x2e58cec05a6a8144ad981719195ab3f3,
+        0xb0c10500d63aa6588257529b625de155,
+        0x83c63727bebaa2858fd9f63573800000,
+        0xf155d5e85b4d5e5d7492b3afdce26803,
+    ), // 46
+    (
+        23,
+        0x2e1688743ef9104cd44347535e0ac394,
+        0xb1bf311e95d00de3b513a9dccd86e01c,
+        0xd8192701fb925e6e96849c7a730caf4f,
+        0x2f44fb527ce044e4d3a2ba1ab62f0aaf,
+    ), // 47
+    (
+        22,
+        0x2dd65df7a583598f4121c968ca091f48,
+        0xb2b803473f7ad0f3f401624140d175ba,
+        0x74e74f8190000000000000000000000,
+        0x184ca73cdd149b745d51909a31faa422,
+    ), // 48
+    (
+        22,
+        0x2d9832759d5369c44dec16608d3904e9,
+        0xb3abb3faa02166cccab240e904f96a11,
+        0xb801c87f94503f355ddf3368d51e321,
+        0x642911f12e5d5e13e35bb6da90a3c1e9,
+    ), // 49
+    (
+        22,
+        0x2d5beb38dcd1394c89f98cf0ce4aff1e,
+        0xb49a784bcd1b8afe492bf6ff4dafdb4c,
+        0x11efc659cf7d4b8dfc90400474400000,
+        0xc8b821885456760b197134fb6ef8a0d5,
+    ), // 50
+    (
+        22,
+        0x2d216f7943e2ba6a4ffe2c3077b586c0,
+        0xb5848226989d33c38d8bd28d695c6112,
+        0x1bbaca5e96728f5375e8253b5cc9e979,
+        0x276c813c08feae3d8cb4cd4b3841885f,
+    ), // 51
+    (
+        22,
+        0x2ce8a82efbb3ff2cd17fd0e19bd1524c,
+        0xb66a008e4788cbcd2edb4390e5306a23,
+        0x2a822226bd03f559758f900000000000,
+        0x816e287cdd2982a7d82c192207501c8b,
+    ), // 52
+    (
+        22,
+        0x2cb17fea7ad7e332e8d73121e98fd497,
+        0xb74b1fd64e0753c6e5783fd150c91d3d,
+        0x40a2a1e04c52a22f14313f7474474c69,
+        0xfaf7baa7969b26140a8b9b02029eee9f,
+    ), // 53
+    (
+        22,
+        0x2c7be2b0cfa1ba503cd8f36e10e6df46,
+        0xb82809d5be7072dbdc0426c3c274612e,
+        0x61835e8b02d0b0d8fc115d2942400000,
+        0x5009648846166f7871a9ff4e87b5868c,
+    ), // 54
+    (
+        22,
+        0x2c47bddba92d74639e8df935f05eda7e,
+        0xb900e6160002ccfe43f50847d182887e,
+        0x92024f345e41f1b20fc9342a548280f1,
+        0xc0d956ac996a1888469ab96eaa58dfab,
+    ), // 55
+    (
+        22,
+        0x2c14fffcaa8b131efa04b6bf8d4842b5,
+        0xb9d5d9fd5010b36665592074827cb508,
+        0xd909e61d408984440000000000000000,
+        0x2df495ccaa57147bfb578a32a0c0d225,
+    ), // 56
+    (
+        21,
+        0x2be398c3a38be053fbe93cdb2e32df2c,
+        0xbaa708f58014d37cde37c86b220d4336,
+        0x59ed6ffd0582e5968dbee367ee79d19,
+        0x6c61e8dcf9d0af94860f36cb99a91f03,
+    ), // 57
+    (
+        21,
+        0x2bb378e7584510685745131f286687c1,
+        0xbb74948f5532da4b4b71433638c6ece3,
+        0x819237f3896f2f30bb832ce3da00000,
+        0xf9caa28237732928b6ef5d0eaf26a913,
+    ), // 58
+    (
+        21,
+        0x2b8492108be5e5f7f3fd783296a6df69,
+        0xbc3e9ca2e1a05533698b4e49b7b0301f,
+        0xb98786f719c67d8d4c454c867bdb24b,
+        0x613ce1182dd5543c4045ed75f0871c7d,
+    ), // 59
+    (
+        21,
+        0x2b56d6c70d55481b7f93c095f5acd065,
+        0xbd053f6d2608967318975dc0e7a96360,
+        0x1080e72bd6dd49a3053d3c0000000000,
+        0xf060f4938a295eec9997c2995061ba11,
+    ), // 60
+    (
+        21,
+        0x2b2a3a608c72ddd5d37cb236d46d56ce,
+        0xbdc899ab3ff56c5e673abad44318c673,
+        0x175a2726872cb1f31ebc8f7f4382068d,
+        0x5ecd7baba7d233fd9c47fc8c8d4c1076,
+    ), // 61
+    (
+        21,
+        0x2afeb0f1060c7e415d3cde5de0477d2f,
+        0xbe88c6b3626a72aa21a3c7f01f78e28a,
+        0x20db5ae453a9690b6bf0d0b753e00000,
+        0xf2a5d8a4f08c39e940cb08ed77f0db23,
+    ), // 62
+    (
+        21,
+        0x2ad42f3c9aca595cae5fbc4f32b14167,
+        0xbf45e08bcf06554e4d5be4f7041fa07d,
+        0x2dfa6bf73ed1882f36a9b447f3bae53f,
+        0x6457bbf285c5ac9afc3fa2b62d8dbe67,
+    ), // 63
+    (
+        21,
+        0x2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+        0xbfffffffffffffffffffffffffffffff,
+        0x6,
+        0x0,
+    ), // 64
+    (
+        21,
+        0x2a82193a134258838f1f90bda3fdd584,
+        0xc0b73cb42e16914c53713f108c0857ca,
+        0x58a14b2a0eca886eec883376b5d52541,
+        0x71b771ae9b6b669bef00b2b2f85222fe,
+    ), // 65
+    (
+        21,
+        0x2a5a717672f66450bd7ccaf57aab558d,
+        0xc16bad3758efd87313606f096b7c1092,
+        0x7a215699c9b3fbd12bce558954200000,
+        0xc4dc39818c9b22991560a044d544608,
+    ), // 66
+    (
+        21,
+        0x2a33aa6e56d9c71c936657d18c3b615e,
+        0xc21d6713f453f35679ee975733242e0c,
+        0xa77bb634136e686b205a116bd9af6bf3,
+        0x874c805f8deca0f30702ecc5e01ce9d6,
+    ), // 67
+    (
+        21,
+        0x2a0dbbaa3bdfcea45c114d4d4f23c41e,
+        0xc2cc7edf592262cf998a704c288aeb58,
+        0xe49b24f84e1afa1e13cd440000000000,
+        0x1ead35969c15cd67853aa16f00b0bd8b,
+    ), // 68
+    (
+        20,
+        0x29e89d244eb4bfafc441c198eb39cc23,
+        0xc379084815b5774c7658b4dca32f570f,
+        0x480735765721d7099b66bf473b9e731,
+        0xc6eee531af0e14144db5811dcc2629fc,
+    ), // 69
+    (
+        20,
+        0x29c44740d7db51e65044653a271f00f1,
+        0xc4231623369e78e589ef1bf42954a2af,
+        0x600be3f12e94cd0ec1bee4771100000,
+        0x552b13a96f13d127ba8e86bf8ba378ba,
+    ), // 70
+    (
+        20,
+        0x29a0b2c743b14d74656a46e212903232,
+        0xc4caba789e2b8687d47cb01d69d2e993,
+        0x7f8d53cfc2d58e94dab3112558b29e1,
+        0xe626906d84f14249f763c821156fa6,
+    ), // 71
+    (
+        20,
+        0x297dd8dbb7c22a2d083e93ba8dcfeec1,
+        0xc570068e7ef5a1e7e802c48281a2eb74,
+        0xa8b8b452291fe821000000000000000,
+        0x846d550e37b5063df43be64e07e6f550,
+    ), // 72
+    (
+        20,
+        0x295bb2f9285c8c1b4cdf0b911d7843a7,
+        0xc6130af40bc0ecbf748923b9d8ea55c3,
+        0xde51cfba9ecb2b5d615093577548521,
+        0x26c86962d68b843a7a1180feace79734,
+    ), // 73
+    (
+        20,
+        0x293a3aebe2be1c92b2573fd58b8b365a,
+        0xc6b3d78b6d3b24fbf847b2f0060d8d2d,
+        0x123d8c63400bb24dd21fafc26b100000,
+        0xc11cc510edad53665cb76fea7ee601c4,
+    ), // 74
+    (
+        20,
+        0x29196acc815ebd9ffcd5944f0020e9e8,
+        0xc7527b930c965bf23d2d59408e815106,
+        0x17db883353c4f379d76f1b15848936f1,
+        0x575f15f3578abb7b6c7acf3210d450a5,
+    ), // 75
+    (
+        20,
+        0x28f93cfb40f5c22afc80b4f2630c18ad,
+        0xc7ef05ae409a0288ea366629e13bcd7c,
+        0x1f17fc8b585f0ca4bbdb510000000000,
+        0x77635772572834c94fe3702c805745d,
+    ), // 76
+    (
+        20,
+        0x28d9ac1badc641176a45e1cf96e0360f,
+        0xc88983ed6985bae584b82d3cad274fe0,
+        0x28627080aa7c1c9fadc722450c36c0d1,
+        0x95b32d8e7e6b98a6017b3328d6d62157,
+    ), // 77
+    (
+        20,
+        0x28bab310a196b4784c064966f9588f75,
+        0xc92203d587039cc122dca5d22601dfdd,
+        0x34465ad32c45eb9d87364ad286100000,
+        0x396ba4485b407fab65215bca63bd28dd,
+    ), // 78
+    (
+        20,
+        0x289c4cf88b774469aa2f87a1fd2c5bd1,
+        0xc9b892675266f66cc899b64b03f7230d,
+        0x4371a0134b81a8d213716cbd1ce547c1,
+        0xe5db242bc101e4bb1fc04cb8cc239afb,
+    ), // 79
+    (
+        20,
+        0x287e7529fb244e91997a44061863362f,
+        0xca4d3c25e68dc57f2495fb7fa6d7eda6,
+        0x56bc75e2d63100000000000000000000,
+        0x79ca10c9242235d511e976394d79eb08,
+    ), // 80
+    (
+        20,
+        0x286127306a6a7a53810fabdde47d4850,
+        0xcae00d1cfdeb43cfd00589050345d6e8,
+        0x6f32f1ef8b18a2bc3cea59789c79d441,
+        0x26adcc42519d4e6dbb945462cb4bf215,
+    ), // 81
+    (
+        20,
+        0x28445ec93f792b1e9e7e1fb211a77f7d,
+        0xcb7110e6ce866f2bc6a905a27b81e221,
+        0x8e2098111766ba7de10db2fbea100000,
+        0xcd1b91662b1e40ad74ec4b78d1807133,
+    ), // 82
+    (
+        20,
+        0x282817e1038950fa6618f9b64fe95e9a,
+        0xcc0052b18b0e2a19575b04fa6fbd6446,
+        0xb51e49db2b36ad289c3d1928649c7251,
+        0x69d7403088e4cca70d182d04d1ccd1f0,
+    ), // 83
+    (
+        20,
+        0x280c4e90c9ab1f459a84a4059d84dff3,
+        0xcc8ddd448f8b845a595a82b5c34e2ac3,
+        0xe6230b481693acad28b0710000000000,
+        0x1cc5073fd62f9022e8f987d10558177b,
+    ), // 84
+    (
+        19,
+        0x27f0ff1bc1ee87cd7d44acbcd3bb60a0,
+        0xcd19bb053fb0284ebe206bcbcf62d8fe,
+        0x36e36a3586f9cabca64f67f2b6318ad,
+        0x2a7f35dad50bc5d22fc6f6ec879e5d1d,
+    ), // 85
+    (
+        19,
+        0x27d625ecf571c3403d196672a4fd1bda,
+        0xcda3f5fb9c4150520a377c7ec513c756,
+        0x448c235633ef7d6000611cb26980000,
+        0xde08b96397a2c607c4ea516dc6d85cd6,
+    ), // 86
+    (
+        19,
+        0x27bbbf95282fcd4598c9f892bbd4a458,
+        0xce2c97d694adab3f3f72a5777998629e,
+        0x5562cc2d1b512593e180f0857eba7c7,
+        0x7fc372c2ddcded97143f6e8e8e0fdca3,
+    ), // 87
+    (
+        19,
+        0x27a1c8c8ddaf84da9ed9b654074320ed,
+        0xceb3a9f01975077f1f5f0cc82aaa9ad7,
+        0x6a1817974c77832e600000000000000,
+        0x34db9cdbd5bf8570c6b364240fcd7b08,
+    ), // 88
+    (
+        19,
+        0x27883e5e7df3f51843137e80d609128c,
+        0xcf393550f3aa690628cf097a388999ab,
+        0x838060c42512782fb8b45d15c309149,
+        0xf25df587259c5468cfec0a81f454d102,
+    ), // 89
+    (
+        19,
+        0x276f1d4c9847e90e44283031f373a536,
+        0xcfbd42b4658367670c98c002287ad91a,
+        0xa29a55169628d4d9fed9bf639a80000,
+        0x930b184759873d2ec670608391deb4a5,
+    ), // 90
+    (
+        19,
+        0x275662a841b30191ddf2e2851d114f9e,
+        0xd03fda8b97997f339434640567ad1f2c,
+        0xc896c3f9d64341ba7c5f8a06271dce3,
+        0x46b7ca7e1554858c3ca6ee8c8968a359,
+    ), // 91
+    (
+        19,
+        0x273e0ba38d15a47b971836310eb6804c,
+        0xd0c10500d63aa6588257529b625de155,
+        0xf6e1c322da5ed777da9c1c000000000,
+        0x97472cb3418a31bc5984c02e077c3e9,
+    ), // 92
+    (
+        19,
+        0x2726158c1b13cf035518666568a95eaf,
+        0xd140c9faa1e5439e15a52a31604a5844,
+        0x12f2d5c6bc784970501c48228950d045,
+        0xb053fa3874b017604478f6a1d6560d3e,
+    ), // 93
+    (
+        19,
+        0x270e7dc9c01d8e9bf550f108d07961f2,
+        0xd1bf311e95d00de3b513a9dccd86e01c,
+        0x1737e020a99addc7dd91c1c984780000,
+        0x60d360b4e26f607a4cff739c607e9361,
+    ), // 94
+    (
+        19,
+        0x26f741dd3f070d6135ecf6db534d4948,
+        0xd23c41d42727c8080ecc61a98813bb22,
+        0x1c6390e72cd0bfbf11069d2794c17b1f,
+        0x20902ce435cb5408b6944dfe494b0a6f,
+    ), // 95
+    (
+        19,
+        0x26e05f5f16c2159e148d4f5825bb6394,
+        0xd2b803473f7ad0f3f401624140d175ba,
+        0x22a359ed800000000000000000000000,
+        0xd9015a36b512c6545d79a4043456f4fa,
+    ), // 96
+    (
+        19,
+        0x26c9d3fe61e8059880c587916b64da2f,
+        0xd3327c6ab49ca6c86b9205fa42202127,
+        0x2a2d0222559e63fd4e65810ee1719321,
+        0x84781643560d35c9b6c30a1cb26398a9,
+    ), // 97
+    (
+        19,
+        0x26b39d7fc6ddab0855076107dbff8ac9,
+        0xd3abb3faa02166cccab240e904f96a11,
+        0x3340102765fa11451aa82869b4880000,
+        0x3fafaf374bde5547f8f49be63990c4b7,
+    ), // 98
+    (
+        19,
+        0x269db9bc7772a5cc5ffed061898663f4,
+        0xd423b07e986aa9670761d14aac4d864c,
+        0x3e276dcd8ae940fd31b10d02de787ffb,
+        0x79a6d0c56ac8f29a74c69dbeace985b,
+    ), // 99
+    (
+        19,
+        0x268826a13ef3fde623e2566b02df245e,
+        0xd49a784bcd1b8afe492bf6ff4dafdb4c,
+        0x4b3b4ca85a86c47a098a224000000000,
+        0xb38fb9daa78e44ab2dcf7a6b19209442,
+    ), // 100
+    (
+        19,
+        0x2672e22d9dbdbd9fb6e62d4035e9cdf9,
+        0xd510118708a8f8dde949378b22691e28,
+        0x5ae35500676b932e2bdc22023705775d,
+        0x688815ad9494f0f9c014b51456b0e2b6,
+    ), // 101
+    (
+        19,
+        0x265dea72f169cc99b6811e54503d6df4,
+        0xd5848226989d33c38d8bd28d695c6112,
+        0x6d9929a07963dfcb2a90cf5607580000,
+        0x2afb965c97a46babe7863ce257bb8679,
+    ), // 102
+    (
+        19,
+        0x26493d93a8cb2514aa340754902857df,
+        0xd5f7cff41e09aeb8cb1ac05ccd609a76,
+        0x83eb4c53bed7cf10d1b6a09d541388f7,
+        0xf0ca08a3535cb838674a2208ace90254,
+    ), // 103
+    (
+        19,
+        0x2634d9c282f3ef826ce941d3a6b82334,
+        0xd66a008e4788cbcd2edb4390e5306a23,
+        0x9e80706d0588c243aa00000000000000,
+        0x9d78cc2ef2c0a7c94e464e762118509f,
+    ), // 104
+    (
+        19,
+        0x2620bd41d8933adc268c1f5fcb3e3901,
+        0xd6db196a761949d97df07e356a261869,
+        0xbe1b4a5db74556af1cbf65736f187a79,
+        0x58bb9f1545d11da9f48530760b6dbd54,
+    ), // 105
+    (
+        19,
+        0x260ce662ef04088a24b027715192da24,
+        0xd74b1fd64e0753c6e5783fd150c91d3d,
+        0xe39eed4348eec5615735bbb92a680000,
+        0x1feadd4409c70437dd3e296bfc038946,
+    ), // 106
+    (
+        18,
+        0x25f95385547353fde6f944a69fab0e66,
+        0xd7ba18f93502e409eab77f219c7ed02b,
+        0x28af386f4342fdcf286a6dcd4baa379,
+        0x92b5873ecc98c0dc808ee4ab87b6e358,
+    ), // 107
+    (
+        18,
+        0x25e60316448db8e177b982e0263732cb,
+        0xd82809d5be7072dbdc0426c3c274612e,
+        0x3019af8f355a2101f51299000000000,
+        0x549f0f56868c3a948635fd2d5bdd436f,
+    ), // 108
+    (
+        18,
+        0x25d2f390152f74f5ffa10e240cf6c2a4,
+        0xd894f74b06ef8b406ea2c7d91aac485f,
+        0x38c7bfe1e6194aa7c84c906d3c5baa9,
+        0x208d0987f6547972bab4e4052fbd659d,
+    ), // 109
+    (
+        18,
+        0x25c02379aa9ad043ad1229cb1815cc71,
+        0xd900e6160002ccfe43f50847d182887e,
+        0x42ecd167a5eb0234319d67fb9440000,
+        0xe99f485d255e143a19dfaf94948ea43f,
+    ), // 110
+    (
+        18,
+        0x25ad9165f2c18907276a82f0608effca,
+        0xd96bdad2acb5f5efec49153146df02e7,
+        0x4ec3e111b7448ff82ededf4c6bc4121,
+        0xa005836d24a1188676e3611c3ea84672,
+    ), // 111
+    (
+        18,
+        0x259b3bf36735c90ca99a49e0b8f432e6,
+        0xd9d5d9fd5010b36665592074827cb508,
+        0x5c908960d05b1000000000000000000,
+        0x62005e1e913356e3da0a00e49ab60e5e,
+    ), // 112
+    (
+        18,
+        0x258921cb955e769369185f93164beb94,
+        0xda3ee7f38e181ed0798d1aa216945613,
+        0x6ca02c322a68fa1ed11ad44f86650e1,
+        0x2da8e921897e794c23215cabbb5db9c0,
+    ), // 113
+    (
+        18,
+        0x257741a2ac9170afdcfd77958f193ef6,
+        0xdaa708f58014d37cde37c86b220d4336,
+        0x7f4b41891a22a72cd7d7ff4d8c40000,
+        0x16b7e34260ab59e1ea1fcff9f1eaa3c,
+    ), // 114
+    (
+        18,
+        0x25659a3711bc827da23e84ad98f44442,
+        0xdb0e4126bcc86bd7a6ed4e1b0935cefb,
+        0x94f6cf88976237fca1d64112d8e79e9,
+        0xb7f1e5e076efc312f7464295303219ad,
+    ), // 115
+    (
+        18,
+        0x25542a50f84b9c3947f0af9496a39bd9,
+        0xdb74948f5532da4b4b71433638c6ece3,
+        0xae16103f075a9202b9fe89000000000,
+        0x78750bd83f595ca067b844190fe91c53,
+    ), // 116
+    (
+        18,
+        0x2542f0c20000377d785b1d90313cf4c5,
+        0xdbda071cc67e6db516de081366d35598,
+        0xcb2c4a5fd8687a4929d08de053d4239,
+        0x429001eff37e02230b5873fcb5576dd4,
+    ), // 117
+    (
+        18,
+        0x2531ec64d772bd6498f9067d038b61e1,
+        0xdc3e9ca2e1a05533698b4e49b7b0301f,
+        0xeccee28ae38301921fde7000d640000,
+        0x14bf4b072a3a942e7347f90e28594ea7,
+    ), // 118
+    (
+        18,
+        0x25211c1ce2fb5a6e802aeb6b88a656d0,
+        0xdca258dca9331635fee390c0ab07a061,
+        0x113a7ab51fff1d1dec1a78415a9135d1,
+        0xdb7e348f7547d682273ef590e4fe1bd5,
+    ), // 119
+    (
+        18,
+        0x25107ed5e7c3ec3b97a2f8e159825d8c,
+        0xdd053f6d2608967318975dc0e7a96360,
+        0x140777acabde1065f840000000000000,
+        0x9900e38651a776a713ce7be833ab8641,
+    ), // 120
+    (
+        18,
+        0x25001383bac8a744385a33490728f0fb,
+        0xdd6753e032ea0efe3ebe1990555535af,
+        0x174190d25e1404569f856dd392b086b1,
+        0x60405d54fc5d46fdb4dda15be88384e8,
+    ), // 121
+    (
+        18,
+        0x24efd921f390bce35a228112f651f215,
+        0xddc899ab3ff56c5e673abad44318c673,
+        0x1af843c8118d6d1dcdeff76ccf240000,
+        0x2fbf511d331d2a9acf55b79d0de714c0,
+    ), // 122
+    (
+        18,
+        0x24dfceb3a26bb20328586e83854fbce9,
+        0xde29142e0e01401fbaaa67e3bc5357db,
+        0x1f3d196f2c2af26ab8c3f9bbd49e3cd9,
+        0x63d34894530074bcc1840cccfbf6a26,
+    ), // 123
+    (
+        18,
+        0x24cff3430a0341a75feab655deddf12f,
+        0xde88c6b3626a72aa21a3c7f01f78e28a,
+        0x2423e5caca19d5ecdaa61c1000000000,
+        0xc558636c44699c8e5dd616c7d4f14b5a,
+    ), // 124
+    (
+        18,
+        0x24c045e15c149931669c60bcda59b483,
+        0xdee7b471b3a9507d6dc1f27ef487c8f3,
+        0x29c30f1029939b146664242d97d9f649,
+        0x8851a0b548ea3c99552fc298784d710d,
+    ), // 125
+    (
+        18,
+        0x24b0c5a679267ae2765d7ac8e1605679,
+        0xdf45e08bcf06554e4d5be4f7041fa07d,
+        0x3033dca275003804ffb79d262e040000,
+        0x53e6168075fcdc22aa5533f43ce618aa,
+    ), // 126
+    (
+        18,
+        0x24a171b0b31461c8fbc44fc98cca0c76,
+        0xdfa34e1177c23362928b9ed74e7a923d,
+        0x3792cec8b8e05d33f68104feda263701,
+        0x26d12351099e4ee4b6792754eabf739d,
+    ), // 127
+    (
+        18,
+        0x24924924924924924924924924924924,
+        0xdfffffffffffffffffffffffffffffff,
+        0x7,
+        0x0,
+    ), // 128
+    (
+        18,
+        0x24834b2c9d85cdfe8ea010fa0f188c3e,
+        0xe05bf942dbbc2145fe38dec005e53d11,
+        0x499f90e057f51b21e7c31c7fa6264901,
+        0xbd13932881cf0aa06a7ce4d41562c0f0,
+    ), // 129
+    (
+        18,
+        0x247476f924137501d589fb6502693649,
+        0xe0b73cb42e16914c53713f108c0857ca,
+        0x549a1fa2007c1f8f42a8032652040000,
+        0x8351b9d5f7dd3a69551886f50a314a1d,
+    ), // 130
+    (
+        18,
+        0x2465cbc00a40cec0af467a358852f54f,
+        0xe111cd1d5133412ed814504fa934752f,
+        0x611d4c68627f53a2942a7c656941ac49,
+        0x516a945eedbb5ba7ef69289a4ec3cabe,
+    ), // 131
+    (
+        18,
+        0x245748bc980e042725b758ccc018a127,
+        0xe16bad3758efd87313606f096b7c1092,
+        0x6f5c4b92c2462e4b0da6641000000000,
+        0x264060dc945f63b67a763e284bd6c676,
+    ), // 132
+    (
+        18,
+        0x2448ed2f49eb0633d6e726edf112f700,
+        0xe1c4dfab90aab5ef4f8f869e63b88285,
+        0x7f90876f1873a4dde7587b496be816d9,
+        0xdfb3f29165515f2952d7095eec5440,
+    ), // 133
+    (
+        18,
+        0x243ab85da36e31670272b2c652ed1c58,
+        0xe21d6713f453f35679ee975733242e0c,
+        0x91fa52ca3e850c2e076bb43c3b240000,
+        0xc0f1e4ca01a664e20c839a54ffc2d446,
+    ), // 134
+    (
+        18,
+        0x242ca99203ea8c185a49421f6deb2659,
+        0xe27545fba4fe385b009a2243694c4ed4,
+        0xa6e1adf9cf57ca5718df58681a7604b1,
+        0x88b5abde0303fa7a5df18d1966d5d56e,
+    ), // 135
+    (
+        18,
+        0x241ec01b7cce4ea0cb26fa3c0fa6d54d,
+        0xe2cc7edf592262cf998a704c288aeb58,
+        0xbe97201fef69e9e68840000000000000,
+        0x57dba216f697e6affb3da34234c3fe5a,
+    ), // 136
+    (
+        18,
+        0x2410fb4da9b3b0fc48fa782f25c1c5a3,
+        0xe323142dc8c66b55ce464929b6747464,
+        0xd974a68fd26b3370c86c410bea1ed7d1,
+        0x2d6059f5e80e0ad3425b381be8d0f5f8,
+    ), // 137
+    (
+        18,
+        0x24035a808a0f315e2f14c51cc472ffad,
+        0xe379084815b5774c7658b4dca32f570f,
+        0xf7debc6376968b2dcdde8b93c1640000,
+        0x8658723c804e69b6cc9a472194d8e16,
+    ), // 138
+    (
+        17,
+        0x23f5dd105c67ab9d1fcc030ee473b57c,
+        0xe3ce5d822ff4b64397b6bb3b853e409d,
+        0x207dde722d1311698991b9c3a038ecb,
+        0xf840927ca48b672c148909f2b50f21ec,
+    ), // 139
+    (
+        17,
+        0x23e8825d7b05abb13dec21038d9c1701,
+        0xe4231623369e78e589ef1bf42954a2af,
+        0x24b3df236317e59b64d3f8c00000000,
+        0xbe660b0bf3bc97ecd19338c3643f7699,
+    ), // 140
+    (
+        17,
+        0x23db49cc3a0866fec2710911f19f4ff5,
+        0xe4773465d54aded7a9150c1e0e5855d6,
+        0x296c61ea0ff582733e6c02429f9ffcd,
+        0x8b86892294b8d8c7542be275c541e30e,
+    ), // 141
+    (
+        17,
+        0x23ce32c4c6cfb9f50ba6354dc0db004b,
+        0xe4caba789e2b8687d47cb01d69d2e993,
+        0x2eb61a99a92d36f43d0fddea58e0000,
+        0x5ebffad18b43751c6b38680836e98229,
+    ), // 142
+    (
+        17,
+        0x23c13cb308ab6ab71158dc54482cf68b,
+        0xe51daa7e60fdd34c4e3a50590fdb04fb,
+        0x34a145da2f851e4601b49acaccf818f,
+        0x374e3584a15d569b24258c734d504579,
+    ), // 143
+    (
+        17,
+        0x23b4670682c0c7093f891717a63cfce5,
+        0xe570068e7ef5a1e7e802c48281a2eb74,
+        0x3b3fcef310328900000000000000000,
+        0x1486d5cd5f289fb19b1fa4b50d202c24,
+    ), // 144
+    (
+        17,
+        0x23a7b13237187c8b1c435e0f0c4627e2,
+        0xe5c1d0b53bc09fca70073eb5df9eda8a,
+        0x42a578eaf5f9a1ed953b60ae6bd9191,
+        0xebab6dad583e80c73183d0569ed4fda0,
+    ), // 145
+    (
+        17,
+        0x239b1aac8ac74728c44df796eb4c41b8,
+        0xe6130af40bc0ecbf748923b9d8ea55c3,
+        0x4ae80b40f8b64cd01cb14bcad920000,
+        0xb573d5c61cc603be405034374f09e026,
+    ), // 146
+    (
+        17,
+        0x238ea2ef2b24c3797746e63517bc4e2a,
+        0xe663b741df9c37c0beb3a32a45cadfcb,
+        0x541f82888e58245b5164a7898bfbb53,
+        0x8586436d54212b9a00df2ce78f83e02e,
+    ), // 147
+    (
+        17,
+        0x23824976f4045a26eaba22a62bc9e494,
+        0xe6b3d78b6d3b24fbf847b2f0060d8d2d,
+        0x5e664536e7083d35da5239400000000,
+        0x5b1ed97c26838fcec4b7337b11168beb,
+    ), // 148
+    (
+        17,
+        0x23760dc3d6e4d7296563132dfbaf985f,
+        0xe7036db376537b90389b662673fbd0d7,
+        0x69d95cf5fba3276e96b0b1656eabc55,
+        0x3592a676d1396e11b70ab1c0d220aad5,
+    ), // 149
+    (
+        17,
+        0x2369ef58c30bd43ee7e3b1c4d9268e8d,
+        0xe7527b930c965bf23d2d59408e815106,
+        0x7698b4d78ed857061cc4caab5160000,
+        0x144c55af4cc6349be0a24272b8d97d8c,
+    ), // 150
+    (
+        17,
+        0x235dedbb8e82aa1cc113119732ce9cb1,
+        0xe7a102f9d39a9331f27cf5477bf0e0fc,
+        0x84c75cc8bb4382814506ee0e4e7c817,
+        0xed92aadc0b78ad494efff65c432baa8c,
+    ), // 151
+    (
+        17,
+        0x23520874dfeb1ffd3df09abdd98bdde6,
+        0xe7ef05ae409a0288ea366629e13bcd7c,
+        0x948bd2ad691f2d49698000000000000,
+        0xb92ec2a7f485323e4dbf72be7852a24c,
+    ), // 152
+    (
+        17,
+        0x23463f1019228dd76b68a06e837867ef,
+        0xe83c856dd81804b7818d34ceaa2dd6cc,
+        0xa6105192326f639b901b68115492c19,
+        0x8aa4c5538596cedc2a05a1df4a5702eb,
+    ), // 153
+    (
+        17,
+        0x233a911b42aa9b3ce833f5c0c758bbea,
+        0xe88983ed6985bae584b82d3cad274fe0,
+        0xb983276adaacb8713574600d71a0000,
+        0x614534d93259ebbcbc6df1e72d9b96ea,
+    ), // 154
+    (
+        17,
+        0x232efe26f7cf33f91c22578101024af2,
+        0xe8d602d948f838294639c36fc650d030,
+        0xcf1711db9546f74e44c1d8f27a893db,
+        0x3c76156a1958ec5530748b9b9ef7a5d1,
+    ), // 155
+    (
+        17,
+        0x232385c65381b485ba84409c06380560,
+        0xe92203d587039cc122dca5d22601dfdd,
+        0xe703a293eb55d600268029c00000000,
+        0x1bb02d2fba106e4eebc664ac3dacfc18,
+    ), // 156
+    (
+        17,
+        0x2318278edde1b39b18c00eedfc7b8cb5,
+        0xe96d887e26cd57b789bd1ec6379e6e3b,
+        0x10185abcb2b548137e9b0413f26c2cdd,
+        0xfcf943adb902491f7f2253ab71ef98bc,
+    ), // 157
+    (
+        17,
+        0x230ce3187a6c2be9e6f9775f430cc5b1,
+        0xe9b892675266f66cc899b64b03f7230d,
+        0x11edfb577f4fda827b05b7ce7a9e0000,
+        0xc8e5cdd278e58db6f40e74ae26f1ca34,
+    ), // 158
+    (
+        17,
+        0x2301b7fd56ca21bb7dcab0f6f985a1b7,
+        0xea03231d8d8224bad979a212919a92f7,
+        0x13f5a7be6aec8c86887cc0b1b50eea9f,
+        0x9a6de3b0cdd6b13eaea85c860d035eae,
+    ), // 159
+    (
+        17,
+        0x22f6a5d9da38341cd9a8fb9b04b69ab5,
+        0xea4d3c25e68dc57f2495fb7fa6d7eda6,
+        0x16345785d8a000000000000000000000,
+        0x70ef54646d496892137dfd73f5a90f85,
+    ), // 160
+    (
+        17,
+        0x22ebac4c9580d89fece9799d2a82aa6a,
+        0xea96defe264b59bee7b0730fe4da965d,
+        0x18af77488361d27eb5c8851406f92aa1,
+        0x4bdb1ba2bf9c62645dca5d0d68c04f19,
+    ), // 161
+    (
+        17,
+        0x22e0caf633834bebc0f095b02827697e,
+        0xeae00d1cfdeb43cfd00589050345d6e8,
+        0x1b6cf29718cab1bd06e4e2339aa20000,
+        0x2ab304075abe9bf948971bd406da3eb6,
+    ), // 162
+    (
+        17,
+        0x22d601796a4188860556565b2d4c93bb,
+        0xeb28c7f233bdd372913c76e44f4c43a5,
+        0x1e733eade5d466489e165e48cb39f863,
+        0xd079737542fb9815e641a95d34c2233,
+    ), // 163
+    (
+        17,
+        0x22cb4f7aec6fd8b414fce6a3d2679155,
+        0xeb7110e6ce866f2bc6a905a27b81e221,
+        0x21c965fea1ee83d26ec892a400000000,
+        0xe4eca2ebbb0e63b2aa7b53eac20a9c8c,
+    ), // 164
+    (
+        17,
+        0x22c0b4a15b80d83e31ec6520a60af32a,
+        0xebb8e95d3f7d9df237f66a891253fe38,
+        0x2577149d4b235144a3e5e0c823663165,
+        0xb5501f6ad251c2318bfbe3e1c0629965,
+    ), // 165
+    (
+        17,
+        0x22b630953a28f77a342174366ac104a7,
+        0xec0052b18b0e2a19575b04fa6fbd6446,
+        0x2984a59ff9a510968d5348cf4e260000,
+        0x8a9f60d8e68dc22b07fd7f99ef742fae,
+    ), // 166
+    (
+        17,
+        0x22abc300df54ca7c3ee931e1d129b893,
+        0xec474e39705912d25b3252646d4dd588,
+        0x2dfb318292972530164bb5cb8de9c927,
+        0x6451c107b026a7504c02c2f63bb0e4a0,
+    ), // 167
+    (
+        17,
+        0x22a16b90698da5d23fbadf144217be75,
+        0xec8ddd448f8b845a595a82b5c34e2ac3,
+        0x32e49da051f344829ea8000000000000,
+        0x41ee1d3a02bab509f73057b223f29e4b,
+    ), // 168
+    (
+        17,
+        0x229729f1b2c83ded15fba80067db9128,
+        0xecd4011c8f11979a5db68721ca60d447,
+        0x384bacd642c343b4e90c427213506d29,
+        0x2308ff6b9d7122006828477572f24f2d,
+    ), // 169
+    (
+        17,
+        0x228cfdd444992f78ae650078dbe481e2,
+        0xed19bb053fb0284ebe206bcbcf62d8fe,
+        0x3e3c1162ec4eb52fe5b4d8ee102a0000,
+        0x74301b76835ca1048333276cbfc238f,
+    ), // 170
+    (
+        17,
+        0x2282e6e94ccb8588f42fe913320f1b89,
+        0xed5f0c3cbf8fa470d2392aac62deb8f0,
+        0x44c28018bb1b468d7f339c828555c8eb,
+        0xdc8ec8cccdef9395f7728e52f5db5c7a,
+    ), // 171
+    (
+        17,
+        0x2278e4e392557ecfc25f4355a74f099e,
+        0xeda3f5fb9c4150520a377c7ec513c756,
+        0x4becc4f9fe8b45c7ca2581ac00000000,
+        0xaf95a0600a46d509c3a80cc43c2616d0,
+    ), // 172
+    (
+        17,
+        0x226ef7776aa7fd294d2313e854dde505,
+        0xede87974f3c81855a216719008b61453,
+        0x53c9d956b85b85df030906535f8aa9ed,
+        0x87147e1f3ac3c70d989a6db4ccacf071,
+    ), // 173
+    (
+        17,
+        0x22651e5aaf5532d0186d2f107cd12e1f,
+        0xee2c97d694adab3f3f72a5777998629e,
+        0x5c69fb85f32705424394a69047ae0000,
+        0x62940da09bffac6d7eed3b9e26b217d4,
+    ), // 174
+    (
+        17,
+        0x225b5944b40b4694dfdaf6bd8690a5cd,
+        0xee7052491d2c3e64ae851773d02c9055,
+        0x65dec855e15aeadd4cdc83221b6b43af,
+        0x41aa02270c4aac20625e489c135fd276,
+    ), // 175
+    (
+        17,
+        0x2251a7ee3cdfcca5702a5908645c8ea8,
+        0xeeb3a9f01975077f1f5f0cc82aaa9ad7,
+        0x703b564fa7a264b00000000000000000,
+        0x23f79a47d00f0c249bd594aa16f03e4e,
+    ), // 176
+    (
+        17,
+        0x22480a1174e913d94d5731283f2411bd,
+        0xeef69fea211b26275d8cb08af881a5d9,
+        0x7b9452ed6dcbce21d5fd76b1b303d3b1,
+        0x92850c07a6727c401d44739ca3d471b,
+    ), // 177
+    (
+        17,
+        0x223e7f69e522683c7e9a41f3c2a16129,
+        0xef393550f3aa690628cf097a388999ab,
+        0x880021e3208e2c36232e4d338fb20000,
+        0xe1e169cfd515d07df84b3388f7c7a2b8,
+    ), // 178
+    (
+        17,
+        0x223507b46b988abeebb0364f53b476dd,
+        0xef7b6b399471103e8f00d41c820920ff,
+        0x9596fe9c3681907c55e958059d93e573,
+        0xb61acbb90f2ae87592811ea94dd9c3f0,
+    ), // 179
+    (
+        17,
+        0x222ba2af32dbbb9ef279074741b89996,
+        0xefbd42b4658367670c98c002287ad91a,
+        0xa4732012cc33ea1b0c9e05b400000000,
+        0x8e8458618c65d85edff5dd6e179b545d,
+    ), // 180
+    (
+        17,
+        0x22225019a9b4d16c9186831251093876,
+        0xeffebccd41ffcd5ce63f9a17c5c31162,
+        0xb4b0df2687b7c1a6fb5d5b1943a57675,
+        0x6ab25cc1ce817841e5c7c95896d104f1,
+    ), // 181
+    (
+        17,
+        0x22190fb47b1af172d3bc2ccc96d36a33,
+        0xf03fda8b97997f339434640567ad1f2c,
+        0xc66edf9bec993797ddfe1f3f3f360000,
+        0x4a4479a943ffd89faaaf9ed40046ce5f,
+    ), // 182
+    (
+        17,
+        0x220fe1418667980116088fd9128f0c53,
+        0xf0809cf27f703d525b3c1d1583ea3c2e,
+        0xd9ce3bef1f1a0e321ecbddf418243a37,
+        0x2ce4652f0e31889e1c8d6d43d6e01f12,
+    ), // 183
+    (
+        17,
+        0x2206c483d7c6b786430fb680c0160c54,
+        0xf0c10500d63aa6588257529b625de155,
+        0xeef2b4278a4777fbfcb8000000000000,
+        0x1244d09af353748631a7da88f8ebbdea,
+    ), // 184
+    (
+        16,
+        0x21fdb93fa0e0ccc537f1cf9ce56d97b2,
+        0xf10113b153c8ea7b1cddae6face57ad3,
+        0x16a911fa283e647002da4149dee6981,
+        0x6982df9c49c5bf9148a7f47c6b000d07,
+    ), // 185
+    (
+        16,
+        0x21f4bf3a31bcdcaa458076478e34cb4f,
+        0xf140c9faa1e5439e15a52a31604a5844,
+        0x18b3a578dfe93443b4c072435410000,
+        0x4ba2f22b4cb438657fd45f9631dcd563,
+    ), // 186
+    (
+        16,
+        0x21ebd639f1d8658462d26bc769d43cb1,
+        0xf18028cf72976a4eb8e97d1453358630,
+        0x1aea1c3b17382ca322a7b81632f53c1,
+        0x305f0f359bc42822a42a1654ba8293e0,
+    ), // 187
+    (
+        16,
+        0x21e2fe06597361a693cbd921bf411a2b,
+        0xf1bf311e95d00de3b513a9dccd86e01c,
+        0x1d4fe20f33a76e0212f350100000000,
+        0x1779bf0897a257ec3010924bfc8112ee,
+    ), // 188
+    (
+        16,
+        0x21da3667eb0e8ccbe919da422792d910,
+        0xf1fde3d30e812642415d473844f11637,
+        0x1fe8a1cf4e4f186e24afd66b0db5341,
+        0xbb7a6d610efa1b3ffc606eb0079a54,
+    ), // 189
+    (
+        16,
+        0x21d17f282d1a300e4b07bbfae5b7144a,
+        0xf23c41d42727c8080ecc61a98813bb22,
+        0x22b8498bcd542ba0e418048da010000,
+        0xd7e421975c6beebea5320788a0d3b1f4,
+    ), // 190
+    (
+        16,
+        0x21c8d811a3d3c9e13ecdb3b8cc6c94d7,
+        0xf27a4c0585cbf805784ee0e3ae9f3aea,
+        0x25c30ef640550b2fe84f632f0037401,
+        0xb1e03d215830dedfd4821267745aff02,
+    ), // 191
+    (
+        16,
+        0x21c040efcb50f858afc4cbef7fc6be37,
+        0xf2b803473f7ad0f3f401624140d175ba,
+        0x290d741000000000000000000000000,
+        0x8f19241e28c7d7572edea2638c295eb3,
+    ), // 192
+    (
+        16,
+        0x21b7b98f11b61c1a8e237a1946449980,
+        0xf2f56875eb3f2614278cd16993118ba6,
+        0x2c9c4c203cb5c2ef4a98f4cc8838c01,
+        0x6f447a847018b63305f110fcd369b08a,
+    ), // 193
+    (
+        16,
+        0x21af41bcd19739ba5103ea5ed9e50345,
+        0xf3327c6ab49ca6c86b9205fa42202127,
+        0x3074c0f555b029a880a7b58e6010000,
+        0x521ee618294040dbdf51aa2f5ede7f3c,
+    ), // 194
+    (
+        16,
+        0x21a6d9474c81adf01a05e1b49be03347,
+        0xf36f3ffb6d9162404772a151ccd9cd84,
+        0x349c587581af8f5b769c6ba29155b41,
+        0x376b5d5ec68094f880d69ab821745372,
+    ), // 195
+    (
+        16,
+        0x219e7ffda5ad572ae169744ac3695bb1,
+        0xf3abb3faa02166cccab240e904f96a11,
+        0x3918fa8303c33586e913b0100000000,
+        0x1ef288d531accdeb2c914b090b4e95d9,
+    ), // 196
+    (
+        16,
+        0x219635afdcd3e46dbfeb824e4f176e1b,
+        0xf3e7d9379f70166ae2a7ada552c6d35a,
+        0x3df0f7385a6ce31e3698615a4948bc1,
+        0x88234713c2422cc958fb5383585d379,
+    ), // 197
+    (
+        16,
+        0x218dfa2ec92d0643bf28d6a603e86885,
+        0xf423b07e986aa9670761d14aac4d864c,
+        0x432b0d810d3a5c5c64168b139410000,
+        0xe7d99f6079a0d527f52cdaba467db627,
+    ), // 198
+    (
+        16,
+        0x2185cd4c148e4ae2c8cda18d82822739,
+        0xf45f3a98a20738a4d7ffe02672eaa838,
+        0x48ce7213f50c6e08c9641b430d39181,
+        0xc211f54c8ac0118420c8ecc628a9c78f,
+    ), // 199
+    (
+        16,
+        0x217daeda36ad7a5c2d28465983f94bfe,
+        0xf49a784bcd1b8afe492bf6ff4dafdb4c,
+        0x4ee2d6d415b85acef81000000000000,
+        0x9f623d5a8a732974cfbc31db4b0295e4,
+    ), // 200
+    (
+        16,
+        0x21759eac708452fe432a11a27d6de34a,
+        0xf4d56a5b33cec44a6deff99873f5a3c6,
+        0x5570729d5c9350500f007e30b726a81,
+        0x7f8622f4a0fddccc213dbdccace23f12,
+    ), // 201
+    (
+        16,
+        0x216d9c96c7d490d4f7a3aeb8d2c0912f,
+        0xf510118708a8f8dde949378b22691e28,
+        0x5c800982d6dea09851740cf9dc10000,
+        0x623f83035c2f94b2d8c704b0ac025961,
+    ), // 202
+    (
+        16,
+        0x2165a86e02cb358c8ea62e7cedb9e66e,
+        0xf54a6e8ca5438db1b0ca63aabb43a1ec,
+        0x641af584350e020f847299445d93ec1,
+        0x4755d561a0b49484b4c4ad8123052dca,
+    ), // 203
+    (
+        16,
+        0x215dc207a3c20fdfe1f739ee5127bef9,
+        0xf5848226989d33c38d8bd28d695c6112,
+        0x6c4b2fc0b496f04d14d904100000000,
+        0x2e95a540ce32153df62cc15f1bc5174c,
+    ), // 204
+    (
+        16,
+        0x2155e939e51e8b3742fbc18ad583b9e2,
+        0xf5be4d0cb51434aaeb3f01222259cfc7,
+        0x751b5a2dd17a12aceaf5035cc69e041,
+        0x17d016f16f6fa29128ca43ed1b864aa4,
+    ), // 205
+    (
+        16,
+        0x214e1ddbb54cd933c8f630c5aa00775d,
+        0xf5f7cff41e09aeb8cb1ac05ccd609a76,
+        0x7e96c9d86b1ea8c675087eda7810000,
+        0x2da79af67adcc7b2fe1ff96f3a62899,
+    ), // 206
+    (
+        16,
+        0x21465fc4b2d68f98f07c262ec4c78cbe,
+        0xf6310b8f553048406a5a171de400ccc9,
+        0x88c991b7557e8bf36a29ca4d9cc2b01,
+        0xdf1bc86cc60a79cc2b607cca9c36b5f4,
+    ), // 207
+    (
+        16,
+        0x213eaecd2893dd607bba48fa30e45b77,
+        0xf66a008e4788cbcd2edb4390e5306a23,
+        0x93c08e16a0224410000000000000000,
+        0xbb8db609dd29ebfe53982beb7940b57d,
+    ), // 208
+    (
+        16,
+        0x21370ace09f681c6b79af83f44ad16ee,
+        0xf6a2af9e5a0f0a08099572f20be66854,
+        0x9f8970a33023b6ea54dfe2f8c8a4501,
+        0x9ac9fea50581ab1171fdffb2d76b1231,
+    ), // 209
+    (
+        16,
+        0x212f73a0ef6db7cbac86770158ea33bf,
+        0xf6db196a761949d97df07e356a261869,
+        0xac32cd1ea177ab2694701d574810000,
+        0x7c958baec69aaed444992fa60d2a6225,
+    ), // 210
+    (
+        16,
+        0x2127e92012e2500422cec88d6baedbbe,
+        0xf7133e9b156c7be5167fbdc813726bce,
+        0xb9cc26c3be3816f0d2a1c1f05a2be41,
+        0x60ba6939ce80cbb073054b0fa4c0ad89,
+    ), // 211
+    (
+        16,
+        0x21206b264c4a39a762997e5af94b6f52,
+        0xf74b1fd64e0753c6e5783fd150c91d3d,
+        0xc865fe6438b144ca97f0ec100000000,
+        0x47074e43a45507980df1779f71b6ea3f,
+    ), // 212
+    (
+        16,
+        0x2118f98f0e52c28fa6aa101f3e3f1862,
+        0xf782bdbfdda6577bc87e125eaaa45f4d,
+        0xd811e148b6a96480e423f9ab1e790c1,
+        0x2f4f306269c6f6fa94949531a39db0b5,
+    ), // 213
+    (
+        16,
+        0x211194366320dc669e805bbe642db3c5,
+        0xf7ba18f93502e409eab77f219c7ed02b,
+        0xe8e278dca1dd341a371ace24cc10000,
+        0x1968e1b61e306e2515c5da42ed2208f5,
+    ), // 214
+    (
+        16,
+        0x210a3af8e926bb7833235836b115330d,
+        0xf7f1322182cf15d12ecd77fe6bebb4fd,
+        0xfaeb9b2f9b1e0b0217133a0eb920081,
+        0x52eb819bf8d24691c67c49905201b11,
+    ), // 215
+    (
+        16,
+        0x2102edb3d00e29a6ffd7374092ae38db,
+        0xf82809d5be7072dbdc0426c3c274612e,
+        0x10e425c56daffabc35c1000000000000,
+        0xe4fc7956b292a9687917defb154c8a84,
+    ), // 216
+    (
+        16,
+        0x20fbac44d5b6edc2650d2ba2cbf5e5db,
+        0xf85ea0b0b27b261086fce864a1f59793,
+        0x122fd20b93cb9c335b54335c92045b81,
+        0xc26fc5b3d4b1c96b20a8ca3a9e88c287,
+    ), // 217
+    (
+        16,
+        0x20f4768a4348ad0875266516780282e1,
+        0xf894f74b06ef8b406ea2c7d91aac485f,
+        0x13933b05125edd4151f5cf1022410000,
+        0xa27d8c7ab839b3c36cd8a953a5a4e787,
+    ), // 218
+    (
+        16,
+        0x20ed4c62ea57b1f0c40c6466a49c0b55,
+        0xf8cb0e3b4b3bbdb3688a85fb19bbcb7e,
+        0x150ff4aeff88b6e9df52546d87e399c1,
+        0x84f0dd1fa3df3e389d05be3702a852d9,
+    ), // 219
+    (
+        16,
+        0x20e62dae221c087ac894f262ade31d21,
+        0xf900e6160002ccfe43f50847d182887e,
+        0x16a7abd8f3b37fc5d76ada8100000000,
+        0x699932614a68cad028bbc29baaf6982d,
+    ), // 220
+    (
+        16,
+        0x20df1a4bc4ba65254fcb3f7d03ced4bf,
+        0xf9367f6da0ab2e9cc865b3dd0dbb557c,
+        0x185c278bc28942ae9852b08cb923dd41,
+        0x504a0f51476ccb394e95e47c69189698,
+    ), // 221
+    (
+        16,
+        0x20d8121c2c9e506eb0f211d26d426cdc,
+        0xf96bdad2acb5f5efec49153146df02e7,
+        0x1a2f4a82ade9f47aa5f82fcdf1010000,
+        0x38daa5699fbfb5757e7fd613418e839a,
+    ), // 222
+    (
+        16,
+        0x20d1150031e515491509e202d6ad7699,
+        0xf9a0f8d3b0e04fde95734abd2fcc1cc6,
+        0x1c2314b7ef579f5edd410f3882f3d201,
+        0x232582d2402a2bd17df618a4183b867c,
+    ), // 223
+    (
+        16,
+        0x20ca22d927d8f54d3c1fc4dff189fa47,
+        0xf9d5d9fd5010b36665592074827cb508,
+        0x1e39a5057d8100000000000000000000,
+        0xf08480f672b4e8672efa8af067362ff,
+    ), // 224
+    (
+        16,
+        0x20c33b88da7c29aa9bddee527e5304ef,
+        0xfa0a7eda4c112ce6312ebb81cf52c6c1,
+        0x20753adafd1e839f53686d013143ee01,
+        0xf8c6c8cb2a4065c5cbb65ac58d1502be,
+    ), // 225
+    (
+        16,
+        0x20bc5ef18c233bdf4d28bbb917468f4b,
+        0xfa3ee7f38e181ed0798d1aa216945613,
+        0x22d83809d63ae5318fb8bb97ff010000,
+        0xd633b0a28e586b017522187a8e64ef71,
+    ), // 226
+    (
+        16,
+        0x20b58cf5f31e4526bbc04ce273284d92,
+        0xfa7315d02f20c7bd560a3fee0894d14f,
+        0x256522a87142b168c177fbf647979141,
+        0xb621fb0137a630d73f8fea84500dbb24,
+    ), // 227
+    (
+        16,
+        0x20aec5793770a74d10169d70c427e95d,
+        0xfaa708f58014d37cde37c86b220d4336,
+        0x281ea70da9d7b4ba8894218100000000,
+        0x9860a7967ad9bd5f7858bb7d064e85d1,
+    ), // 228
+    (
+        16,
+        0x20a8085ef096d5306a7abda7bfe4bc6f,
+        0xfadac1e711c832d1562d61af73f7f529,
+        0x2b0799e59069cbbf066f72faa07f05c1,
+        0x7cc2a563743f116eb8a363febc8b9619,
+    ), // 229
+    (
+        16,
+        0x20a1558b2359c4b1be8b937d3abf1960,
+        0xfb0e4126bcc86bd7a6ed4e1b0935cefb,
+        0x2e22fa609f00dc8397ceadcffc410000,
+        0x631e7df1e38120923b20f296b45b30c2,
+    ), // 230
+    (
+        16,
+        0x209aace23fafa72edc28bce8a78a0201,
+        0xfb418734a9008bd978b98f7dedf8c59a,
+        0x3173f47e91771ab93b501697722a5f81,
+        0x4b4e0801351dd62049b9ed59a6512626,
+    ), // 231
+    (
+        16,
+        0x20940e491ea988d754aa84ed945324cd,
+        0xfb74948f5532da4b4b71433638c6ece3,
+        0x34fde3761da26b26e141000000000000,
+        0x352e20fa5a072452bd2f15ed95513383,
+    ), // 232
+    (
+        16,
+        0x208d79a5006d7a47bd05e65e00d8e634,
+        0xfba769b39e49640ef87ede14ae8ca0ca,
+        0x38c4543ad49b252286c8d2df67963c81,
+        0x209e6c8bd12166a369a9149f9017a54e,
+    ), // 233
+    (
+        16,
+        0x2086eedb8a3cead38d8c6b421256d8c1,
+        0xfbda071cc67e6db516de081366d35598,
+        0x3ccb082283710ab06693917a02c10000,
+        0xd8119de5f4cb4010fa6b3488ff3403f,
+    ), // 234
+    (
+        16,
+        0x20806dd2c486dcc6bdbef1c78d0b07f7,
+        0xfc0c6d447c5dd362d9a9a55c745ecdc2,
+        0x4115f7ab7738a86ff35120de1d8464c1,
+        0xf7755bc11aae30d8f5fe6631a68b5d70,
+    ), // 235
+    (
+        16,
+        0x2079f67119059fae5818d66429a8145c,
+        0xfc3e9ca2e1a05533698b4e49b7b0301f,
+        0x45a95565166f0ce4045fbbc100000000,
+        0xd663a4668da6d11d73b1b5323054cbd0,
+    ), // 236
+    (
+        16,
+        0x2073889d50e7bf63b3c1dfecae9323f5,
+        0xfc7095ae91e1c760bc9b188c44c898c7,
+        0x4a8990fc5043aaa580802a321a634a41,
+        0xb79e509a08d524eb87278fcf43bb1631,
+    ), // 237
+    (
+        16,
+        0x206d243e9303d929d1560d0fd8ac6a05,
+        0xfca258dca9331635fee390c0ab07a061,
+        0x4fbb5a6d716c1b835654801676810000,
+        0x9afa40e5ce5a2b435673c6b7502c3187,
+    ), // 238
+    (
+        16,
+        0x2066c93c62170aa8814c538272b12952,
+        0xfcd3e6a0ca8906c243749114c4aeeba4,
+        0x5543a55cfdbf4def00288d0f24186901,
+        0x804fa8605975a5ededcbf103cb28db9b,
+    ), // 239
+    (
+        16,
+        0x2060777e9b0db0f66faef2a1f92a4918,
+        0xfd053f6d2608967318975dc0e7a96360,
+        0x5b27ac993df977010000000000000000,
+        0x6779c7f90dc42f48d8687ae71569c0ff,
+    ), // 240
+    (
+        16,
+        0x205a2eed73563032d8e68c0cd6f8be4a,
+        0xfd3663b27f31d5297837adb4ad8e4aba,
+        0x616cf5c641bc19433e294bb13cf28701,
+        0x5056af92762a5a9e1c609119ea32b741,
+    ), // 241
+    (
+        16,
+        0x2053ef71773d7e6a6343a1e6282d1a72,
+        0xfd6753e032ea0efe3ebe1990555535af,
+        0x681955362721ce00ddaaab61c5810000,
+        0x3ac7046725d44aa44f53dd6565732711,
+    ), // 242
+    (
+        16,
+        0x204db8f388552ea9340c897e506439da,
+        0xfd9810643d6614c3c406eb4644174ca2,
+        0x6f32f1ef8b18a2bc3cea59789c79d441,
+        0x26adcc42519d4e6dbb945462cb4bf215,
+    ), // 243
+    (
+        16,
+        0x20478b5cdbe2bb2f9f47210f83af4a6c,
+        0xfdc899ab3ff56c5e673abad44318c673,
+        0x76c049e4182c41bbda92ab4100000000,
+        0x13f03d2016bf2f0442fa4e9d9cf13cca,
+    ), // 244
+    (
+        16,
+        0x20416696f957cfbf88a6766fb3a6d4b4,
+        0xfdf8f02086af2c4bef483c68abd157b8,
+        0x7ec836593c7f5b9668f9a8eba104eac1,
+        0x27590d339dcf5d7cb3eecfe6446a0e2,
+    ), // 245
+    (
+        16,
+        0x203b4a8bb8d356e76b0977267c5f74e2,
+        0xfe29142e0e01401fbaaa67e3bc5357db,
+        0x8751f085236e96344c9e57f407c10000,
+        0xe44db8ae22ce9af973842a6c3f0b3941,
+    ), // 246
+    (
+        16,
+        0x2035372541ab0f0db3f4fb03ed447e0a,
+        0xfe59063c8822ce561911a9bac66c37a0,
+        0x9065166223bb7825724f036d298aae81,
+        0xc5ddd4f8bebcb4eb71c3ff08df5b002b,
+    ), // 247
+    (
+        16,
+        0x202f2c4e08fd6dcc5afc398523997374,
+        0xfe88c6b3626a72aa21a3c7f01f78e28a,
+        0x9a09afbae83050a9de01000000000000,
+        0xa974334e5b341d2c9b902ae1099772f7,
+    ), // 248
+    (
+        16,
+        0x202929f0d04b99e9fc7b7a95b0148b77,
+        0xfeb855f8ca88fb0d4b5c673bb08eda00,
+        0xa4483371ae74103a08f117c190ba0d81,
+        0x8eec78b4bfd30046f2c231435a2674df,
+    ), // 249
+    (
+        16,
+        0x20232ff8a41b45ebdedd0ed98659daaa,
+        0xfee7b471b3a9507d6dc1f27ef487c8f3,
+        0xaf298d050e4395d69670b12b7f410000,
+        0x7624f8a762fd82b2aac18030b01abaa4,
+    ), // 250
+    (
+        16,
+        0x201d3e50daa036dbbec43a874c503546,
+        0xff16e281db76303b21928c215f6bd584,
+        0xbab72254d28276f7d56a4138a7919fc1,
+        0x5efe80008d0a43fc2576a090d909a646,
+    ), // 251
+    (
+        16,
+        0x201754e5126d446d97966f6881697ea0,
+        0xff45e08bcf06554e4d5be4f7041fa07d,
+        0xc6fad9a98595304313477c0100000000,
+        0x495c242dda54550f572d2fa7da42b646,
+    ), // 252
+    (
+        16,
+        0x201173a1312ca13563a1ec159dc546a2,
+        0xff74aef0efafadd7a1b65f638d087c2c,
+        0xd3ff20016b3f9427610ee026a4222741,
+        0x352316534e5b01a5a4aed754889b8be1,
+    ), // 253
+    (
+        16,
+        0x200b9a71625f3b13848faa9c4c10a633,
+        0xffa34e1177c23362928b9ed74e7a923d,
+        0xe1ceefa5bbd9ed1c97f17a1df8010000,
+        0x223a7a0852bbfc225f321c9c346820cd,
+    ), // 254
+    (
+        16,
+        0x2005c94216230568a7730a510a5b7427,
+        0xffd1be4c7f2af942b221ce0d10344eb9,
+        0xf075d70b0f1b82196f36f719d077f001,
+        0x108b3f616699bf6fc787d1af34dba7a2,
+    ), // 255
+    (
+        16,
+        0x1fffffffffffffffffffffffffffffff,
+        0xffffffffffffffffffffffffffffffff,
+        0x8,
+        0x0,
+    ), // 256
+];
+
+// This section is created by factorial_data.rs.
+
+// This is equivalent to `__gmp_oddfac_table` in `mpn/comb_tables.c`, GMP 6.2.1, which is the
+// combination of `ONE_LIMB_ODD_FACTORIAL_TABLE` and `ONE_LIMB_ODD_FACTORIAL_EXTTABLE` in
+// `fac_table.h`, GMP 6.2.1.
+pub const ONE_LIMB_ODD_FACTORIAL_TABLE: [Limb; 132] = [
+    0x1,
+    0x1,
+    0x1,
+    0x3,
+    0x3,
+    0xf,
+    0x2d,
+    0x13b,
+    0x13b,
+    0xb13,
+    0x375f,
+    0x26115,
+    0x7233f,
+    0x5cca33,
+    0x2898765,
+    0x260eeeeb,
+    0x260eeeeb,
+    0x286fddd9b,
+    0x16beecca73,
+    0x1b02b930689,
+    0x870d9df20ad,
+    0xb141df4dae31,
+    0x79dd498567c1b,
+    0xaf2e19afc5266d,
+    0x20d8a4d0f4f7347,
+    0x335281867ec241ef,
+    0x29b3093d46fdd5923,
+    0x465e1f9767cc5866b1,
+    0x1ec92dd23d6966aced7,
+    0x37cca30d0f4f0a196e5b,
+    0x344fd8dc3e5a1977d7755,
+    0x655ab42ab8ce915831734b,
+    0x655ab42ab8ce915831734b,
+    0xd10b13981d2a0bc5e5fdcab,
+    0xde1bc4d19efcac82445da75b,
+    0x1e5dcbe8a8bc8b95cf58cde171,
+    0x1114c2b2deea0e8444a1f3cecf9,
+    0x2780023da37d4191deb683ce3ffd,
+    0x2ee802a93224bddd3878bc84ebfc7,
+    0x7255867c6a398ecb39a64b83ff3751,
+    0x23baba06e131fc9f8203f7993fc1495,
+    0x5b8e7cb1a1101758bd2a2a78b35f4bdd,
+    0x82b03a923651ea4784757be6b6d13921,
+    0xf399d68f1fc25a033fbbcfc0b524988b,
+    0x779c38265d59de23bd11ed47c8928df9,
+    0x675debe68cc0c483c26b59e41c2f4c5,
+    0x9497031b6a551a7d677a5137e883fdb3,
+    0x47b99208859fdd05ff74e943b03b93dd,
+    0xd72cb61990df9711fe5ebbcb10b2bb97,
+    0x2f8edae4bacbea71b021f1de3235e7e7,
+    0xa4f360563de9e51a33509eb2e743a58f,
+    0xdc7c312e5598a438390f9da41279fb7d,
+    0x324e7f5a58c056dae5cb0154f031c559,
+    0x6a405db45fd1fb5193074695ba4ddb6d,
+    0x34c9e2061b25819a81c471caa636247f,
+    0x575f8f4fd50ed831e1347289b5a1d749,
+    0x639ceb2ed367e95d286f21c3f76ce2ff,
+    0x2df05d6d1222f5be00be84a2173e8ac7,
+    0x343a955b0df5d6861595065ca215b88b,
+    0x9806bfc37a870e6f95877595b018809,
+    0x8e8653c742de9d889c2efe3c5516f887,
+    0xf601f67aef0b898d373294604679382b,
+    0xca3cd8e2f265a819af1ff7a888adcd35,
+    0xc4f95fd9a7045e5218ddf279a2c5800b,
+    0xc4f95fd9a7045e5218ddf279a2c5800b,
+    0x3515643681bf2d8505a90e2542582cb,
+    0x6d7c1eb06b9a4de25bacad2cd8d5dc2b,
+    0xa77c082c2962623dfe3152bcbff89f41,
+    0x1f3c8aeebf88861de1467e88bf829351,
+    0x6b5172599fcc260db8001adb9e31b4d5,
+    0xac22a240d8e933e02803ac06a0cbb91f,
+    0xbd9afffc28ad632b1904b5d698805799,
+    0xaa72ffdd6e187c83e12a648b5c831461,
+    0x9acaf62464fb819b3516abbd6160cfa9,
+    0x5f5593429859bb6eac46d25f12fe036d,
+    0xee122482a249e96c78bfa1da906b00ef,
+    0xab58b5b20b7c530cf6390338b7f111bd,
+    0x89aea68d7464fae60f25f80f538255d9,
+    0xf99b5f8cbb62390c4ec8ca55b8db140f,
+    0x6f27c6dd34f9acc4ff670740b9b30a1,
+    0x22bc6e25208e05fd8fd032443a07f325,
+    0xfd9ed9bf4cefe53a80dfe7965c83eeb5,
+    0x9e70dfa3526bb65ea3dc1714d1213afd,
+    0x5e9881f3b8ec20af205b7bbfcdc62007,
+    0xc282a8fe2b5eae5da78126bbe140a093,
+    0x95621c64666fe5189de1dc61ca7550cf,
+    0x177ac4dd34cb7b2284f0046d01b492c5,
+    0xfab8e72cf126d8bb2d91810b945de0f3,
+    0xc5f1eeee5cab500af5408b7f6008aa71,
+    0xd11c10de378ed3cf43707f4863034149,
+    0xc1eef70fc41b3b6edac65fb9679279d5,
+    0xeff1d29ab5ae2067c48406e7d1114eb7,
+    0x8eb9ebe652a4e952a7dc9ed3c88e1271,
+    0xd98ab2ac05e8c306fb25b2efdb9cb30d,
+    0xf076cd9515bbce481bebda0951c4df63,
+    0x3c164a5310b18cc25c85e975580ee5bd,
+    0xb442def93214a6471591bc60082cb137,
+    0x4d567c6bf9d300ef2c38606318ef25d7,
+    0xcd8dd0aad1632dc776ca72f7c5c63e27,
+    0x7dd7b20ef95ab422f04a75d17baa0915,
+    0x4a10637659db976977458175139ae30d,
+    0x38773db173a2bc9c0e6c1330bc1b9421,
+    0x3fc14a5a096b9316df87d2b5797e8293,
+    0xa6c4ea39ca482e33efa5c703e1e68925,
+    0x77ffe4ef45aa58a32b6b1b3278b4f6e1,
+    0x37f4e62392de5aecceee27b382394249,
+    0x95b3a55d6808d306d74e3829f5dab91d,
+    0x92161e0a7bb033dbfdb17989c26b5f1f,
+    0x68552b1b0b957833c1b7d18781530845,
+    0x6c435a83eea42e097b4436b2105a8561,
+    0x427872584545e4097ba7c0418372a7d7,
+    0xd2399446094de01c9dbc5c67feb6c639,
+    0xbf930dea412120c8502686d7f6ff6b8f,
+    0x8fe92466bf9f786b6101855406be7a1f,
+    0xae91ae0aa81cfe89956afb5806930e7,
+    0xe6b712ec9850657ce1f0ee88af40f7c5,
+    0x22bd24cd411b7f25984b057bda5c1151,
+    0xe071d1cec1911c2e9a49819acc13ea05,
+    0xba3b5aa69c717ebd8ef0dead0896ef27,
+    0x91972372b8c1ea1d71f7826efe292b21,
+    0x87db13b8d35cb7b9ad80a480e46986ef,
+    0x368c525be6d2d6c301cdc0ebf5e0c6f7,
+    0xff6f9fe6003d2c776e06f839968f68db,
+    0xbaa1d3821d645d61dd5943ab56e76139,
+    0x99989cc18f274ed9cdcf31bf8604c5e7,
+    0xff848a82e63180597e2b4a847054a1cb,
+    0xe19e1636a62e96060ca75697a4d3d0f5,
+    0xed6d051c711c6d004703f53ac514a98b,
+    0xed6d051c711c6d004703f53ac514a98b,
+    0xa3ef9354ff52ed23c8fe929d4f696f0b,
+    0x9fd46894d40e361608a339f129c531cb,
+    0xc9b184288345ad466b86a6685fe87ae1,
+];
+// This is equivalent to `ODD_FACTORIAL_TABLE_LIMIT` in `fac_table.h`, GMP 6.2.1.
+pub const ODD_FACTORIAL_TABLE_LIMIT: usize = 41;
+// This is equivalent to `ODD_FACTORIAL_EXTTABLE_LIMIT` in `fac_table.h`, GMP 6.2.1.
+pub const ODD_FACTORIAL_EXTTABLE_LIMIT: usize = 131;
+// This is equivalent to `ODD_FACTORIAL_TABLE_MAX` in `fac_table.h`, GMP 6.2.1.
+pub const ODD_FACTORIAL_TABLE_MAX: Limb = 0x5b8e7cb1a1101758bd2a2a78b35f4bdd;
+
+// This is equivalent to `__gmp_odd2fac_table` in `mpn/comb_tables.c`, GMP 6.2.1, and
+// `ONE_LIMB_ODD_DOUBLEFACTORIAL_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const ONE_LIMB_ODD_DOUBLEFACTORIAL_TABLE: [Limb; 28] = [
+    0x1,
+    0x3,
+    0xf,
+    0x69,
+    0x3b1,
+    0x289b,
+    0x20fdf,
+    0x1eee11,
+    0x20dcf21,
+    0x27065f73,
+    0x33385d46f,
+    0x49a10615f9,
+    0x730b9982551,
+    0xc223930bef8b,
+    0x15fe07a85a22bf,
+    0x2a9c2ed62ea3521,
+    0x57e22099c030d941,
+    0xc03ea750546adb3e3,
+    0x1bc90e2e9c3371affcf,
+    0x43ba12919cbd651cf889,
+    0xad8ccf9521a5531a3cdf1,
+    0x1d26a6de0ca6c4f5683977b,
+    0x51fcb550839509f23521a09f,
+    0xf0d6549c8285cd377c12c7d31,
+    0x2e190631f4fb9c479ebf983f661,
+    0x92efc3bf3ce202244a02b54a1553,
+    0x1e6ba386979aca7183528f88566a2f,
+    0x6892021ea92417e6336bcd64a90d019,
+];
+// This is equivalent to `ODD_DOUBLEFACTORIAL_TABLE_LIMIT` in `fac_table.h`, GMP 6.2.1.
+pub const ODD_DOUBLEFACTORIAL_TABLE_LIMIT: usize = 55;
+// This is equivalent to `ODD_DOUBLEFACTORIAL_TABLE_MAX` in `fac_table.h`, GMP 6.2.1.
+pub const ODD_DOUBLEFACTORIAL_TABLE_MAX: Limb = 0x6892021ea92417e6336bcd64a90d019;
+
+// This is equivalent to `__gmp_limbroots_table` in `mpn/comb_tables.c`, GMP 6.2.1, and
+// `NTH_ROOT_NUMB_MASK_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const NTH_ROOT_NUMB_MASK_TABLE: [Limb; 8] = [
+    Limb::MAX,
+    0xffffffffffffffff,
+    0x6597fa94f5b,
+    0xffffffff,
+    0x3080c00,
+    0x285145,
+    0x4e045,
+    0xffff,
+];
+
+// This is equivalent to `ONE_LIMB_ODD_FACTORIAL_INVERSES_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const ONE_LIMB_ODD_FACTORIAL_INVERSES_TABLE: [Limb; 128] = [
+    0x1,
+    0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab,
+    0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab,
+    0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeef,
+    0xa4fa4fa4fa4fa4fa4fa4fa4fa4fa4fa5,
+    0xf2ff2ff2ff2ff2ff2ff2ff2ff2ff2ff3,
+    0xf2ff2ff2ff2ff2ff2ff2ff2ff2ff2ff3,
+    0x3771affe8e21c5aa938cc70553e3771b,
+    0xd7e389994fa05abbb71c27cddd93e49f,
+    0x5971c6b0d8b17c9cb38e3229fcdee63d,
+    0x7325ece59d907edee684bb63544a4cbf,
+    0x92b425ea47327fe9c2f684917ca340fb,
+    0xa73e4e8f2ebe1245f747c9cba417526d,
+    0x2d486ba3253fdf15bb26eb51d7bd49c3,
+    0x2d486ba3253fdf15bb26eb51d7bd49c3,
+    0xa84f8ddc6b9a586ab0a7efb985294093,
+    0xf641ba6dd3112644be4b8c69f259eabb,
+    0xff7cb8f84e7a2a6f6854d17ed6dc4fb9,
+    0x3318f1cb42e53bafe1aa904c915f4325,
+    0x7025c25f032f7cbf3b8206df131cead1,
+    0xf2ec28f15d61686e79c6009fea76fe13,
+    0x2bf401c7b625a05dd8c5d381633cd365,
+    0x63fc0097e761e01f4841f12b21144677,
+    0xb21451f1989d83d84a91ff68200b0d0f,
+    0xdb2cb39f80c1dd58f9513a58c4f9e8b,
+    0x4c5bf4905e8530832b3e690621a42251,
+    0x2f7ad9cb7b3799374f520f00e03c04e7,
+    0x134adb5f4ade9b5a2edf84ee600211d3,
+    0x78c0b94a9e975fb0adcaa2764aaacdfd,
+    0x9048480aaa46f292161f4f9033f4fe63,
+    0x9048480aaa46f292161f4f9033f4fe63,
+    0xdd959593b7958b3abada2932ea4d3e03,
+    0x85814508b072355dcec189f3efaa30d3,
+    0x62e66fafca86eb94f7475bb68330bf91,
+    0x60527e2ffa0efdbb37eb7bf7d5b01549,
+    0x9ad1cc0f226f1b9d46b35660a4e91555,
+    0x159f40a27b13524ba567c12d81f151f7,
+    0xf9fd84f0794f43c04c724007bb2071b1,
+    0x9865e7634b7640c00f4a0cce58a016bd,
+    0xd8027c471ad0eed8fa21068e66106475,
+    0x22aac8f73209f2fe244ab72b5a318ae1,
+    0xeef21c7cd189295f366ce67e080d0f23,
+    0xfe7319dccd3b03c2d666fdae5dd2a449,
+    0xf496780a9878c731d740ddd0acc06a0d,
+    0x4d6ab74e5fac352eb050bbbb28e6f97b,
+    0xe669c289d6760c0670b003fe890a5c75,
+    0x4ccdeb83477caeacd03aabff83037427,
+    0xa8c048b9894baff913ec4ca72c783bd7,
+    0x10fd738c8a9ca0a390282c06afdbd96f,
+    0xe2372a6c2adfef124414ddb9db4a95d5,
+    0x602ba0cd3e5ffeb2a2c68735ae6832e9,
+    0x1ecbb0ebb8b95bbfbf72d71455676665,
+    0x3a078b4b19cdf9eaa8469fab6b759b7f,
+    0x8809723ddfe329c7c1e55b56e606caf9,
+    0xa5b834e4448e2a8a40455630fc4a1cff,
+    0x733024db9569cada0120a7b0046d16f7,
+    0x301c2494d02fc933a7c3553b08faef23,
+    0x8ba9b284b116813d9f0bfd1b08d48639,
+    0x80c70be6b678f78ca433ffce9a304d37,
+    0xf9b7b8d9d0a1755ea22ad1d53915c683,
+    0x315881d57a57c9facb6cbc723ba5dd1d,
+    0xcf94723e57f5c9a547fb1b8ab9d0ba3,
+    0xcf94723e57f5c9a547fb1b8ab9d0ba3,
+    0x6a89be23ff979ef68f15a826498852e3,
+    0xa623344eaaa780f032e1a03f38880283,
+    0x11c3641fbdc55da03de4cce63283f0c1,
+    0x97a214f2ceed7dfa5dfe6667e4da95b1,
+    0x1c2b291d7d6f093efda6eeeef479e47d,
+    0x42a225bf0395754af14de991cc7882df,
+    0x74519b92ea6aab1de68db79247630ca9,
+    0x9b25831052ef6858a7d6db8207ee8fa1,
+    0x805f32e42b37e1de255e1f0fcf034499,
+    0xf5a1b5446fdeea6dc9a8990e43dd7e65,
+    0x40b71011faaa39bd3279b6f289702e0f,
+    0x979dd86cbc59cd24e7b5905d9b71b195,
+    0xee055fe6d09373b403025ba41ff0da69,
+    0xbde5e1a374f016a8b7df3d6d3be55aef,
+    0xf8aea81f3bcf30e4f89b212ebff2b361,
+    0x64efbb39725ca360fe856d095996f0ad,
+    0xd1d6b675a5c1ef0dd6e533e9fdf20f9d,
+    0x69053ca535fe7c76f8c0e84a63da3255,
+    0x5175449609e74e9ba677876cd91b4db7,
+    0xd31df71355ce0fef07ed4f97780d7d9b,
+    0x35af08ee282993c390a8705f258db62f,
+    0x6081180589dd3ef8a41bbb2be31b1c0d,
+    0x8585e5cb191ff7e56ec28690b038383b,
+    0xaf0c2c29bc774514db860c3bb2edd691,
+    0xf67611c13023db620838286838a980f9,
+    0x607fc78172d8f97f558417a74b36f77d,
+    0x60b572b847977bb571779afc3646ef07,
+    0x94e67f6c2fa2698d743cda377ccb6e91,
+    0x77f777bc589924eb7fdf9f3fe89153c5,
+    0x6498543fec190baddc97d25df49b9a4b,
+    0xbafee8a25b695bbe76321a778eb37d95,
+    0x93aa4d8b73cdc93f7cbb5e27da3bd487,
+    0xd4a80613e970f7849cff4ade1a009de7,
+    0xdfc4bc34a6b9299f70eb166d05c15197,
+    0x5f59e80ae01162aedcf0460b71d5fe3d,
+    0xc65fc19a08f674965ac1ee5260b6a3c5,
+    0x8d5ebaf25132993bc922dedfdd78efe1,
+    0x2aedc76e29bfbcbae5d381dc3b8eeb9b,
+    0xd3adcdbdf676ad53d57e5347bafc6aad,
+    0x9a210fd3891cd24186939040983acd21,
+    0x19d94e2b76559b9a395b9d69740a4ff9,
+    0x5c42e47e67aaabfe1467299c8e43d135,
+    0xdcf97295848ec1025fe440fcad975cdf,
+    0xe24220b034512397caa9a39794a6ca8d,
+    0xfb07a70d5c13888bf61dbd640868dea1,
+    0x6af6d47dea66c151ac09d98d74843be7,
+    0xd7733b926e8214312b103b9e1a6b4809,
+    0x43599acbc6a4de502ab92d16960f536f,
+    0xfe549db03eed11d36653323d5e3681df,
+    0xee017c079550828eefd48c1c0624e2d7,
+    0xc3bd3af4ef7d5c67a496fefe04816f0d,
+    0xb74d2557e4f2aae91754a7b07bbdd7b1,
+    0x4bf5b81d3217f45523353c829a3852cd,
+    0xcd383b8700d95aebbf831261abd59097,
+    0xf6f7de147eee771557a8e656df0618e1,
+    0x87ee64237fedc3ac16e9206c3100680f,
+    0x20dbe3352f9a4baaadad4c6ee921dac7,
+    0x2eb3ddf4481fe80f635f2b3860265353,
+    0x42fb37eb177b0e5fdd6d0059f44b3d09,
+    0x12ad43de4b147c55ac4dd6b894447dd7,
+    0xc8da795ffc81301942ea183eeaa87be3,
+    0xd26d1e5efbe18aec15612d1550ee5b5d,
+    0x1de09b73a54247a5226fa19d656cb623,
+    0x1de09b73a54247a5226fa19d656cb623,
+    0x7b4536c954a1420b33dd24f9483a64a3,
+];
+
+pub const ODD_CENTRAL_BINOMIAL_OFFSET: usize = 21;
+
+// This table contains binomial(2k, k) / 2 ^ t.
+//
+// This is equivalent to `bin2kk` in `mpz/bin_uiui.c`, GMP 6.2.1, and
+// `ONE_LIMB_ODD_CENTRAL_BINOMIAL_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const ONE_LIMB_ODD_CENTRAL_BINOMIAL_TABLE: [Limb; 47] = [
+    0xfaa556bc1,
+    0x3d3cc24821,
+    0x77cfeb6bbb,
+    0x7550ebd97c7,
+    0xe5f08695caf,
+    0x386120ffce11,
+    0x6eabb28dd6df,
+    0x3658e31c82a8f,
+    0x6ad2050312783,
+    0x1a42902a5af0bf,
+    0x33ac44f881661d,
+    0xcb764f927d82123,
+    0x190c23fa46b93983,
+    0x62b7609e25caf1b9,
+    0xc29cb72925ef2cff,
+    0x5ff4616b66425f131,
+    0xbd50dbe1bbeabb945,
+    0x2eb4ca752a268ae8cd,
+    0x5c36ff5059c8d09daf,
+    0x5b0fe8ec25764e021cd,
+    0xb3e73c8dd99ecc10a95,
+    0x2c70bd6f3b173a0729d3,
+    0x57d8e7946eda907952ad,
+    0x2b6caca7eb29219910241,
+    0x55e24fc942df9217f819d,
+    0x153cd51efc5cd9b10ebe09,
+    0x2a05fcdb450edfc5d65ccb,
+    0x532bd9c74e02c58ce2ecfc7,
+    0xa4a52c8017d13ded1a09617,
+    0x28bfeba7620e0701b9010aad,
+    0x50b34b2858c67244aa9dabbb,
+    0x27f652c184937ff8282e13cf1,
+    0x4f2b9f1ec7dbc38fdbad66095,
+    0x139bfd5670511953a1ab8a7545,
+    0x26dcb4e7d0a0b92cb96b3c4a27,
+    0x2683e1012c7ab78577c36bc058f,
+    0x4c5ac70b4f3228746232923e717,
+    0x12ec915502865cc8fba5e9c35699,
+    0x2587062abf954b85e1accf9061f7,
+    0x129b7b975453a01d0cb27c401f81f,
+    0x24e8e02c2d90e57892e424a0c4cb3,
+    0x9272b343ee99c007b22e5fc8361df,
+    0x122924f5e269ec627a69d072fc8d5d,
+    0x9026955fb528c44daba7e690b4a2123,
+    0x11e156f578afa42a205e6d175d4c39e3,
+    0x46faa6c6b08ab278af0a3cb21545de09,
+    0x8ce61964346b346da7e2a6560ba9424f,
+];
+
+pub const ODD_CENTRAL_BINOMIAL_TABLE_LIMIT: usize = 67;
+
+// This table contains the inverses of elements in the previous table.
+//
+// This is equivalent to `bin2kkinv` in `mpz/bin_uiui.c`, GMP 6.2.1, and
+// `ONE_LIMB_ODD_CENTRAL_BINOMIAL_INVERSE_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const ONE_LIMB_ODD_CENTRAL_BINOMIAL_INVERSE_TABLE: [Limb; 47] = [
+    0x424bf12fe48d0a50032dc192f948a441,
+    0xbd9c5b776a182c5000d02b90c2bf3be1,
+    0x6699df1ae694e372d897e8c1749aa173,
+    0x83d35a7efe60a70754a234fc01fef9f7,
+    0x67d452be2e314ffe83ff2ab4d1ff7a4f,
+    0xe340292b752fb504a427f1c9b304e2f1,
+    0x6ef062419c4d6f899c14595d1793651f,
+    0xba5672e7c4c4098a883a71c607a7b46f,
+    0x9dae3a75ead8829bd089863c54bc9f2b,
+    0x421f474507a385009022f6bce5d07f3f,
+    0x5c5b6fc28e5fb0b4bec207e218768c35,
+    0x97d0b0810e734bf29d70cb4cbb4f168b,
+    0x7475c3f2c071ac773c3d3403828a9d2b,
+    0xf75731b7dcc4f6447672df58c56bc489,
+    0x3e63e191349f45441e66ca55d727d2ff,
+    0xf5e164b4a8ec86fa365524770cd9c7d1,
+    0x4483c6548dba83895727a5c51b8df18d,
+    0xb89c434f726946de86b81fc14b3f4405,
+    0xefca28bdd988705cc29273d2f0ead94f,
+    0xce5dcebb00ce4e67160944e676f1b305,
+    0xadfcea38bae0868007fe5eecc0f270bd,
+    0x385bbfe32c32a39283908d38d134165b,
+    0x70d712c43d83077448946b9536a1e125,
+    0xd94d37569ae75f1a78fe2b0718900dc1,
+    0x8201a60c260d6f5deca3075fa1fe0cb5,
+    0x7ae178bcbda95faa065bd7aa74e63039,
+    0xf3c748074c94e8c6c96838b0fbc428e3,
+    0xd73168acb1938600e3542220894c41f7,
+    0xb69a4759e04d294cfc12bf6cce9801a7,
+    0xeae1241e741dd4205eaf66a486eae925,
+    0x11376358e1eb93aaf583a86c6cae6173,
+    0xebcdd35fb918c31a8c58b1d7014a3411,
+    0x3a128f43d275564a5a58a7c92c896abd,
+    0xbfb35664da30bfa4b4b4470c7bafd58d,
+    0x5755cb4cb94086475d8796eed0068597,
+    0x96ce0f20839c490b6b6030b9ba17796f,
+    0x392fc4d91adfc111672384b8fb5c0a7,
+    0x9cba36b84a94801f05a90bf857709da9,
+    0xb5deff263b7d35aafe7a713ff794afc7,
+    0xd43c60b3124143000a908271798f83df,
+    0xa63c0ee5e977a4f3539b5d43c8eb427b,
+    0xd8b9cda4177591a34f591bab19a99a1f,
+    0xacb7c1c14b4fbc16ea8d2a9dea1e2ef5,
+    0x6c31d329d64325c3b53f99884c6d168b,
+    0x7ff16a6671ea44a419d69eb9c348d3cb,
+    0x7e080d1fab5a4802412285c92d491039,
+    0xbc9a30f501a0f80122bcb308f42a94af,
+];
+
+// This table contains the values t in the formula binomial(2k, k) / 2 ^ t.
+//
+// This is equivalent to `fac2bin` in `mpz/bin_uiui.c`, GMP 6.2.1, and
+// `CENTRAL_BINOMIAL_2FAC_TABLE` from `fac_table.h`, GMP 6.2.1.
+pub const CENTRAL_BINOMIAL_2FAC_TABLE: [u64; 47] = [
+    3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 4, 5, 2, 3, 3, 4, 3,
+    4, 4, 5, 3, 4, 4, 5, 4, 5, 5, 6, 1, 2, 2, 3,
+];
+
+// https://oeis.org/A005187, skipping the initial 0
+//
+// This is equivalent to `__gmp_fac2cnt_table` in `mpn/comb_tables.c`, GMP 6.2.1, and
+// `TABLE_2N_MINUS_POPC_2N` from `fac_table.h`, GMP 6.2.1.
+pub const TABLE_2N_MINUS_POPC_2N: [u8; 72] = [
+    1, 3, 4, 7, 8, 10, 11, 15, 16, 18, 19, 22, 23, 25, 26, 31, 32, 34, 35, 38, 39, 41, 42, 46, 47,
+    49, 50, 53, 54, 56, 57, 63, 64, 66, 67, 70, 71, 73, 74, 78, 79, 81, 82, 85, 86, 88, 89, 94, 95,
+    97, 98, 101, 102, 104, 105, 109, 110, 112, 113, 116, 117, 119, 120, 127, 128, 130, 131, 134,
+    135, 137, 138, 142,
+];
+
+pub const TABLE_LIMIT_2N_MINUS_POPC_2N: u64 = 145;
+
+pub const FFT_TAB: [[u8; 2]; 5] = [[4, 4], [4, 3], [3, 2], [2, 1], [2, 1]];
+
+pub const MULMOD_TAB: [u8; 19] = [4, 4, 4, 4, 4, 3, 3, 3, 3, 3, 3, 3, 2, 2, 2, 2, 2, 1, 1];
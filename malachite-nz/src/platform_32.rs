@@ -6,6 +6,9 @@ pub type SignedHalfLimb = i16;
 pub type SignedDoubleLimb = i64;
 pub type FloatWithLimbWidth = f32;
 
+// The number of limbs that a `Natural` stores without a heap allocation.
+pub const INLINE_LIMB_COUNT: usize = 4;
+
 //TODO tune
 pub const AORSMUL_FASTER_2AORSLSH: bool = true;
 //TODO tune
@@ -37,6 +40,17 @@ pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 295;
 
 //TODO tune
 pub const MUL_FFT_THRESHOLD: usize = 5608;
+pub const SQR_FFT_THRESHOLD: usize = SQR_TOOM3_THRESHOLD * 30;
+
+// Operands whose average length is at least this many limbs are multiplied with the
+// number-theoretic transform instead of the FFT. Provisional: the crossover between the two hasn't
+// been measured yet.
+pub const MUL_NTT_THRESHOLD: usize = 32000;
+pub const SQR_NTT_THRESHOLD: usize = 32000;
+
+// When the `parallel` feature is enabled, independent parts of an operation whose largest input has
+// at least this many limbs may run on separate threads.
+pub const PARALLEL_THRESHOLD: usize = 2000;
 
 //TODO tune
 pub const DC_DIV_QR_THRESHOLD: usize = 7;
@@ -50,6 +64,7 @@ pub const INV_NEWTON_THRESHOLD: usize = 618;
 pub const MU_DIV_QR_THRESHOLD: usize = 2243;
 //TODO tune
 pub const INV_MULMOD_BNM1_THRESHOLD: usize = 68;
+pub const MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD: usize = INV_MULMOD_BNM1_THRESHOLD >> 1;
 //TODO tune
 pub const MU_DIV_QR_SKEW_THRESHOLD: usize = 233;
 
@@ -6,6 +6,9 @@ pub type SignedHalfLimb = i32;
 pub type SignedDoubleLimb = i128;
 pub type FloatWithLimbWidth = f64;
 
+// The number of limbs that a `Natural` stores without a heap allocation.
+pub const INLINE_LIMB_COUNT: usize = 4;
+
 pub const AORSMUL_FASTER_2AORSLSH: bool = true;
 pub const AORSMUL_FASTER_3AORSLSH: bool = true;
 pub const AORSMUL_FASTER_AORS_AORSLSH: bool = true;
@@ -23,6 +26,17 @@ pub const MUL_TOOM42_TO_TOOM53_THRESHOLD: usize = 600;
 pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 103;
 
 pub const MUL_FFT_THRESHOLD: usize = 1500;
+pub const SQR_FFT_THRESHOLD: usize = SQR_TOOM3_THRESHOLD * 30;
+
+// Operands whose average length is at least this many limbs are multiplied with the
+// number-theoretic transform instead of the FFT. Provisional: the crossover between the two hasn't
+// been measured yet.
+pub const MUL_NTT_THRESHOLD: usize = 16000;
+pub const SQR_NTT_THRESHOLD: usize = 16000;
+
+// When the `parallel` feature is enabled, independent parts of an operation whose largest input has
+// at least this many limbs may run on separate threads.
+pub const PARALLEL_THRESHOLD: usize = 1000;
 
 pub const DC_DIV_QR_THRESHOLD: usize = 85;
 pub const DC_DIVAPPR_Q_THRESHOLD: usize = 211;
@@ -30,6 +44,7 @@ pub const MAYBE_DCP1_DIVAPPR: bool = true;
 pub const INV_NEWTON_THRESHOLD: usize = 789;
 pub const MU_DIV_QR_THRESHOLD: usize = 2094;
 pub const INV_MULMOD_BNM1_THRESHOLD: usize = 62;
+pub const MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD: usize = INV_MULMOD_BNM1_THRESHOLD >> 1;
 pub const MU_DIV_QR_SKEW_THRESHOLD: usize = 231;
 
 pub const MU_DIVAPPR_Q_THRESHOLD: usize = 2965;
@@ -1,16 +1,21 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "num-bigint")))]
 use crate::platform::Limb;
+#[cfg(not(feature = "num-bigint"))]
 use malachite_base::num::arithmetic::traits::Sign as SignTrait;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "num-bigint")))]
 use malachite_base::num::conversion::traits::VecFromOtherTypeSlice;
+#[cfg(not(feature = "num-bigint"))]
 use num::bigint::Sign;
+#[cfg(not(feature = "num-bigint"))]
 use num::{BigInt, BigUint};
 use rug::integer::Order;
+#[cfg(not(feature = "num-bigint"))]
 use std::cmp::Ordering;
 
-#[cfg(feature = "32_bit_limbs")]
+// With the `num-bigint` feature, the crate itself provides these conversions.
+#[cfg(all(feature = "32_bit_limbs", not(feature = "num-bigint")))]
 impl From<&BigUint> for Natural {
     #[inline]
     fn from(n: &BigUint) -> Natural {
@@ -18,7 +23,7 @@ impl From<&BigUint> for Natural {
     }
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "num-bigint")))]
 impl From<&BigUint> for Natural {
     #[inline]
     fn from(n: &BigUint) -> Natural {
@@ -26,7 +31,7 @@ impl From<&BigUint> for Natural {
     }
 }
 
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(all(feature = "32_bit_limbs", not(feature = "num-bigint")))]
 impl From<&Natural> for BigUint {
     #[inline]
     fn from(n: &Natural) -> BigUint {
@@ -34,7 +39,7 @@ impl From<&Natural> for BigUint {
     }
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "num-bigint")))]
 impl From<&Natural> for BigUint {
     #[inline]
     fn from(n: &Natural) -> BigUint {
@@ -42,7 +47,7 @@ impl From<&Natural> for BigUint {
     }
 }
 
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(all(feature = "32_bit_limbs", not(feature = "num-bigint")))]
 impl From<&Natural> for BigInt {
     #[inline]
     fn from(n: &Natural) -> BigInt {
@@ -53,7 +58,7 @@ impl From<&Natural> for BigInt {
     }
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "num-bigint")))]
 impl From<&Natural> for BigInt {
     #[inline]
     fn from(n: &Natural) -> BigInt {
@@ -84,6 +89,7 @@ impl From<&Natural> for rug::Integer {
     }
 }
 
+#[cfg(not(feature = "num-bigint"))]
 impl From<&BigInt> for Integer {
     #[inline]
     fn from(n: &BigInt) -> Integer {
@@ -91,6 +97,7 @@ impl From<&BigInt> for Integer {
     }
 }
 
+#[cfg(not(feature = "num-bigint"))]
 impl From<&Integer> for BigInt {
     #[inline]
     fn from(n: &Integer) -> BigInt {
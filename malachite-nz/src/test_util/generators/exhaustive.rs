@@ -2820,8 +2820,10 @@ pub fn exhaustive_unsigned_vec_pair_gen_var_29<T: PrimitiveUnsigned>() -> It<(Ve
 pub fn exhaustive_unsigned_vec_pair_gen_var_34<T: PrimitiveUnsigned>() -> It<(Vec<T>, Vec<T>)> {
     #[cfg(feature = "32_bit_limbs")]
     let limit = 56;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let limit = 28;
+    #[cfg(feature = "128_bit_limbs")]
+    let limit = 14;
     exhaustive_square_helper(&limbs_square_to_out_fft_is_valid, limit)
 }
 
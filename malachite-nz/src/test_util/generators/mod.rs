@@ -3504,8 +3504,11 @@ pub fn large_type_gen_var_8() -> Generator<T8> {
 
 #[cfg(feature = "32_bit_limbs")]
 const PRIME_FACTORS_OF_LIMB_MAX: &[Limb] = &[3, 5, 17, 257, 65_537];
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 const PRIME_FACTORS_OF_LIMB_MAX: &[Limb] = &[3, 5, 17, 257, 641, 65_537, 6_700_417];
+#[cfg(feature = "128_bit_limbs")]
+const PRIME_FACTORS_OF_LIMB_MAX: &[Limb] =
+    &[3, 5, 17, 257, 641, 65_537, 274_177, 6_700_417, 67_280_421_310_721];
 
 pub(crate) fn factors_of_limb_max() -> Vec<Limb> {
     lex_ordered_unique_vecs(PRIME_FACTORS_OF_LIMB_MAX.iter())
@@ -4339,8 +4339,10 @@ pub fn random_primitive_int_vec_pair_gen_var_28<T: PrimitiveInt>(
 ) -> It<(Vec<T>, Vec<T>)> {
     #[cfg(feature = "32_bit_limbs")]
     let limit = 56;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let limit = 28;
+    #[cfg(feature = "128_bit_limbs")]
+    let limit = 14;
     random_square_helper(config, &limbs_square_to_out_fft_is_valid, limit)
 }
 
@@ -4663,8 +4665,10 @@ pub fn random_primitive_int_vec_triple_gen_var_47<T: PrimitiveInt>(
 ) -> It<(Vec<T>, Vec<T>, Vec<T>)> {
     #[cfg(feature = "32_bit_limbs")]
     let limit = 112;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let limit = 56;
+    #[cfg(feature = "128_bit_limbs")]
+    let limit = 28;
     Box::new(PrimitiveIntVecTripleLenGenerator1 {
         phantom: PhantomData,
         lengths: random_triples_from_single(geometric_random_positive_unsigneds::<usize>(
@@ -5565,8 +5565,10 @@ pub fn special_random_unsigned_vec_pair_gen_var_34<T: PrimitiveUnsigned>(
 ) -> It<(Vec<T>, Vec<T>)> {
     #[cfg(feature = "32_bit_limbs")]
     let limit = 56;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let limit = 28;
+    #[cfg(feature = "128_bit_limbs")]
+    let limit = 14;
     special_random_square_helper(config, &limbs_square_to_out_fft_is_valid, limit)
 }
 
@@ -6794,8 +6796,10 @@ pub fn special_random_unsigned_vec_triple_gen_var_60<T: PrimitiveUnsigned>(
 ) -> It<(Vec<T>, Vec<T>, Vec<T>)> {
     #[cfg(feature = "32_bit_limbs")]
     let limit = 112;
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let limit = 56;
+    #[cfg(feature = "128_bit_limbs")]
+    let limit = 28;
     Box::new(UnsignedVecTripleLenGenerator1 {
         phantom: PhantomData,
         lengths: random_triples_from_single(geometric_random_positive_unsigneds::<u64>(
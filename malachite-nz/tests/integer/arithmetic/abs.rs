@@ -3,7 +3,11 @@ use malachite_base::num::basic::traits::Two;
 use malachite_base::test_util::generators::signed_gen;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use malachite_nz::platform::{SignedDoubleLimb, SignedLimb};
+#[cfg(not(feature = "128_bit_limbs"))]
+use malachite_nz::platform::SignedDoubleLimb;
+#[cfg(feature = "128_bit_limbs")]
+use malachite_nz::platform::SignedHalfLimb;
+use malachite_nz::platform::SignedLimb;
 use malachite_nz::test_util::generators::{integer_gen, integer_integer_natural_triple_gen};
 use num::{BigInt, Signed};
 use std::str::FromStr;
@@ -97,12 +101,20 @@ fn abs_properties() {
         assert_eq!(*internal_abs, abs_alt);
     });
 
+    #[cfg(not(feature = "128_bit_limbs"))]
     signed_gen::<SignedLimb>().test_properties(|i| {
         assert_eq!(
             Integer::from(i).abs(),
             Integer::from(SignedDoubleLimb::from(i).abs())
         );
     });
+    #[cfg(feature = "128_bit_limbs")]
+    signed_gen::<SignedHalfLimb>().test_properties(|i| {
+        assert_eq!(
+            Integer::from(i).abs(),
+            Integer::from(SignedLimb::from(i).abs())
+        );
+    });
 }
 
 #[test]
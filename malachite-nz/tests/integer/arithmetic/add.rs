@@ -3,7 +3,11 @@ use malachite_base::test_util::generators::signed_pair_gen;
 use malachite_base::vecs::vec_from_str;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use malachite_nz::platform::{SignedDoubleLimb, SignedLimb};
+#[cfg(not(feature = "128_bit_limbs"))]
+use malachite_nz::platform::SignedDoubleLimb;
+#[cfg(feature = "128_bit_limbs")]
+use malachite_nz::platform::SignedHalfLimb;
+use malachite_nz::platform::SignedLimb;
 use malachite_nz::test_util::generators::{
     integer_gen, integer_pair_gen, integer_triple_gen, integer_vec_gen, natural_pair_gen,
     natural_vec_gen,
@@ -96,6 +100,10 @@ fn add_properties() {
         let sum_val_ref = x.clone() + &y;
         let sum_ref_val = &x + y.clone();
         let sum = &x + &y;
+        let mut out = y.clone();
+        x.add_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, sum);
         assert!(sum_val_val.is_valid());
         assert!(sum_val_ref.is_valid());
         assert!(sum_ref_val.is_valid());
@@ -142,12 +150,20 @@ fn add_properties() {
         assert_eq!(&x + &y, Integer::from(x) + Integer::from(y));
     });
 
+    #[cfg(not(feature = "128_bit_limbs"))]
     signed_pair_gen::<SignedLimb>().test_properties(|(x, y)| {
         assert_eq!(
             Integer::from(SignedDoubleLimb::from(x) + SignedDoubleLimb::from(y)),
             Integer::from(x) + Integer::from(y)
         );
     });
+    #[cfg(feature = "128_bit_limbs")]
+    signed_pair_gen::<SignedHalfLimb>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(SignedLimb::from(x) + SignedLimb::from(y)),
+            Integer::from(x) + Integer::from(y)
+        );
+    });
 }
 
 #[test]
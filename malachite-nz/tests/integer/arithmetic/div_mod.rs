@@ -1468,6 +1468,14 @@ fn div_mod_properties_helper(x: Integer, y: Integer) {
     assert!(r.is_valid());
     let q = mut_x;
 
+    let mut q_alt = y.clone();
+    let mut r_alt = x.clone();
+    x.div_mod_to(&y, &mut q_alt, &mut r_alt);
+    assert!(q_alt.is_valid());
+    assert!(r_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert_eq!(r_alt, r);
+
     let mut mut_x = x.clone();
     let r_alt = mut_x.div_assign_mod(y.clone());
     let q_alt = mut_x;
@@ -28,7 +28,7 @@ fn test_limbs_kronecker_symbol() {
         assert_eq!((&x).kronecker_symbol(&y), s);
         assert_eq!(limbs_kronecker_symbol(x_sign, xs, y_sign, ys), s);
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // ys_len != 0
         // xs_len != 0
@@ -76,6 +76,23 @@ fn test_limbs_kronecker_symbol() {
         // ys_len == 2 && b1 >> y_twos == 0 second time
         test(false, &[2, 1], false, &[1, 0, 1], -1);
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(true, &[1], true, &[1], 1);
+        test(false, &[], false, &[], 0);
+        test(false, &[], false, &[1], 1);
+        test(false, &[1], false, &[1], -1);
+        test(false, &[1], false, &[3], 1);
+        test(false, &[2], false, &[2], 0);
+        test(false, &[1], false, &[1, 1], -1);
+        test(false, &[3], false, &[1, 1], 1);
+        test(false, &[1, 1], false, &[1, 1], 0);
+        test(false, &[1], false, &[2, 1], -1);
+        test(false, &[1], false, &[2, 2], -1);
+        test(false, &[1, 1], false, &[1, 0, 1], -1);
+        test(false, &[1, 1], false, &[2, 2], 0);
+        test(false, &[2, 1], false, &[1, 0, 1], -1);
+    }
     #[cfg(feature = "32_bit_limbs")]
     {
         // xs_len >= ys_len << 1
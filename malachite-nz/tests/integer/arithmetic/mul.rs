@@ -4,7 +4,11 @@ use malachite_base::test_util::generators::signed_pair_gen;
 use malachite_base::vecs::vec_from_str;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use malachite_nz::platform::{SignedDoubleLimb, SignedLimb};
+#[cfg(not(feature = "128_bit_limbs"))]
+use malachite_nz::platform::SignedDoubleLimb;
+#[cfg(feature = "128_bit_limbs")]
+use malachite_nz::platform::SignedHalfLimb;
+use malachite_nz::platform::SignedLimb;
 use malachite_nz::test_util::generators::{
     integer_gen, integer_pair_gen, integer_triple_gen, integer_vec_gen, natural_pair_gen,
     natural_vec_gen,
@@ -141,6 +145,10 @@ fn mul_properties() {
         let product_val_ref = x.clone() * &y;
         let product_ref_val = &x * y.clone();
         let product = &x * &y;
+        let mut out = x.clone();
+        x.mul_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, product);
         assert!(product_val_val.is_valid());
         assert!(product_val_ref.is_valid());
         assert!(product_ref_val.is_valid());
@@ -206,12 +214,20 @@ fn mul_properties() {
         assert_eq!(&x * &y, Integer::from(x) * Integer::from(y));
     });
 
+    #[cfg(not(feature = "128_bit_limbs"))]
     signed_pair_gen::<SignedLimb>().test_properties(|(x, y)| {
         assert_eq!(
             Integer::from(SignedDoubleLimb::from(x) * SignedDoubleLimb::from(y)),
             Integer::from(x) * Integer::from(y)
         );
     });
+    #[cfg(feature = "128_bit_limbs")]
+    signed_pair_gen::<SignedHalfLimb>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(SignedLimb::from(x) * SignedLimb::from(y)),
+            Integer::from(x) * Integer::from(y)
+        );
+    });
 }
 
 #[test]
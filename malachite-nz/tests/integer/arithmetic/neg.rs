@@ -1,7 +1,11 @@
 use malachite_base::num::arithmetic::traits::NegAssign;
 use malachite_base::test_util::generators::signed_gen;
 use malachite_nz::integer::Integer;
-use malachite_nz::platform::{SignedDoubleLimb, SignedLimb};
+#[cfg(not(feature = "128_bit_limbs"))]
+use malachite_nz::platform::SignedDoubleLimb;
+#[cfg(feature = "128_bit_limbs")]
+use malachite_nz::platform::SignedHalfLimb;
+use malachite_nz::platform::SignedLimb;
 use malachite_nz::test_util::generators::{integer_gen, natural_gen};
 use num::BigInt;
 use std::str::FromStr;
@@ -55,9 +59,14 @@ fn neg_properties() {
         assert_eq!(x + negative, 0);
     });
 
+    #[cfg(not(feature = "128_bit_limbs"))]
     signed_gen::<SignedLimb>().test_properties(|x| {
         assert_eq!(Integer::from(-SignedDoubleLimb::from(x)), -Integer::from(x));
     });
+    #[cfg(feature = "128_bit_limbs")]
+    signed_gen::<SignedHalfLimb>().test_properties(|x| {
+        assert_eq!(Integer::from(-SignedLimb::from(x)), -Integer::from(x));
+    });
 
     natural_gen().test_properties(|x| {
         assert_eq!(-&x, -Integer::from(x));
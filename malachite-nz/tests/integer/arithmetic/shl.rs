@@ -358,6 +358,10 @@ fn shl_properties() {
 
     integer_unsigned_pair_gen_var_2::<u32>().test_properties(|(n, u)| {
         let shifted = &n << u;
+        let mut out = n.clone();
+        n.shl_to(u64::from(u), &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, shifted);
         let mut rug_n = rug::Integer::from(&n);
         rug_n <<= u;
         assert_eq!(Integer::from(&rug_n), shifted);
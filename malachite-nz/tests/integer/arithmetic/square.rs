@@ -39,6 +39,10 @@ fn test_square() {
 fn square_properties() {
     integer_gen().test_properties(|x| {
         let square = (&x).square();
+        let mut out = x.clone();
+        x.square_to(&mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, square);
         assert!(square.is_valid());
 
         let mut mut_x = x.clone();
@@ -1,7 +1,11 @@
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::test_util::generators::signed_pair_gen;
 use malachite_nz::integer::Integer;
-use malachite_nz::platform::{SignedDoubleLimb, SignedLimb};
+#[cfg(not(feature = "128_bit_limbs"))]
+use malachite_nz::platform::SignedDoubleLimb;
+#[cfg(feature = "128_bit_limbs")]
+use malachite_nz::platform::SignedHalfLimb;
+use malachite_nz::platform::SignedLimb;
 use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen, natural_pair_gen};
 use num::BigInt;
 use rug;
@@ -68,6 +72,10 @@ fn sub_properties() {
         let diff_val_ref = x.clone() - &y;
         let diff_ref_val = &x - y.clone();
         let diff = &x - &y;
+        let mut out = y.clone();
+        x.sub_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, diff);
         assert!(diff_val_val.is_valid());
         assert!(diff_val_ref.is_valid());
         assert!(diff_ref_val.is_valid());
@@ -114,10 +122,18 @@ fn sub_properties() {
         }
     });
 
+    #[cfg(not(feature = "128_bit_limbs"))]
     signed_pair_gen::<SignedLimb>().test_properties(|(x, y)| {
         assert_eq!(
             Integer::from(SignedDoubleLimb::from(x) - SignedDoubleLimb::from(y)),
             Integer::from(x) - Integer::from(y)
         );
     });
+    #[cfg(feature = "128_bit_limbs")]
+    signed_pair_gen::<SignedHalfLimb>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(SignedLimb::from(x) - SignedLimb::from(y)),
+            Integer::from(x) - Integer::from(y)
+        );
+    });
 }
@@ -56,7 +56,7 @@ fn test_limbs_overflowing_sub_mul_limb() {
             false,
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[18446744073709551615],
@@ -66,6 +66,16 @@ fn test_limbs_overflowing_sub_mul_limb() {
             false,
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[340282366920938463463374607431768211455],
+            &[0, 340282366920938463168226702252415385600],
+            340282366920937254537554992802593505280,
+            &[1, 340282366920938463463374607431768211455, 340282366920937254242407087623241727999],
+            false,
+        );
+    }
 }
 
 #[cfg(feature = "32_bit_limbs")]
@@ -1,9 +1,15 @@
 use malachite_nz::integer::Integer;
+use malachite_nz::platform::Limb;
 use std::mem::size_of;
 
 #[test]
 fn test_size() {
     if size_of::<usize>() == 8 {
-        assert_eq!(size_of::<Integer>(), 32);
+        let expected_size = match size_of::<Limb>() {
+            4 => 40,
+            8 => 48,
+            _ => 64,
+        };
+        assert_eq!(size_of::<Integer>(), expected_size);
     }
 }
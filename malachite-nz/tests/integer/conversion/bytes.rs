@@ -0,0 +1,128 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::bytes::BytesOverflowError;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_to_twos_complement_bytes() {
+    let test = |s, le: &[u8]| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_twos_complement_bytes_le(), le);
+        let be = le.iter().rev().cloned().collect::<Vec<u8>>();
+        assert_eq!(x.to_twos_complement_bytes_be(), be);
+        assert_eq!(Integer::from_twos_complement_bytes_le(le), x);
+        assert_eq!(Integer::from_twos_complement_bytes_be(&be), x);
+    };
+    test("0", &[]);
+    test("1", &[1]);
+    test("-1", &[255]);
+    test("127", &[127]);
+    test("128", &[128, 0]);
+    test("-128", &[128]);
+    test("-129", &[127, 255]);
+    test("255", &[255, 0]);
+    test("-256", &[0, 255]);
+    test("1000000000000", &[0, 16, 165, 212, 232, 0]);
+    test("-1000000000000", &[0, 240, 90, 43, 23, 255]);
+    test(
+        "18446744073709551615",
+        &[255, 255, 255, 255, 255, 255, 255, 255, 0],
+    );
+    test("-18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 255]);
+}
+
+#[test]
+fn test_from_twos_complement_bytes() {
+    assert_eq!(Integer::from_twos_complement_bytes_le(&[0, 0, 0]), 0);
+    assert_eq!(Integer::from_twos_complement_bytes_le(&[255; 11]), -1);
+    assert_eq!(
+        Integer::from_twos_complement_bytes_le(&[1, 0, 0, 0, 0, 0, 0, 0, 0]),
+        1
+    );
+    assert_eq!(
+        Integer::from_twos_complement_bytes_be(&[255, 255, 255, 255, 255, 255, 255, 255, 254]),
+        -2
+    );
+}
+
+#[test]
+fn test_to_twos_complement_bytes_padded() {
+    let test = |s, len, out: Result<Vec<u8>, BytesOverflowError>| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_twos_complement_bytes_le_padded(len), out);
+        assert_eq!(
+            x.to_twos_complement_bytes_be_padded(len),
+            out.map(|mut bytes| {
+                bytes.reverse();
+                bytes
+            })
+        );
+    };
+    test("0", 0, Ok(vec![]));
+    test("0", 2, Ok(vec![0, 0]));
+    test("-1", 3, Ok(vec![255, 255, 255]));
+    test("127", 1, Ok(vec![127]));
+    test("128", 1, Err(BytesOverflowError));
+    test("128", 2, Ok(vec![128, 0]));
+    test("-128", 1, Ok(vec![128]));
+    test("-129", 1, Err(BytesOverflowError));
+    test("-129", 4, Ok(vec![127, 255, 255, 255]));
+}
+
+#[test]
+fn twos_complement_bytes_properties() {
+    integer_gen().test_properties(|x| {
+        let le = x.to_twos_complement_bytes_le();
+        assert_eq!(Integer::from_twos_complement_bytes_le(&le), x);
+        let be = x.to_twos_complement_bytes_be();
+        assert_eq!(be.iter().rev().cloned().collect::<Vec<u8>>(), le);
+        assert_eq!(Integer::from_twos_complement_bytes_be(&be), x);
+        assert_eq!(le.is_empty(), x == 0);
+        if let Some(&last) = le.last() {
+            assert_eq!(last >> 7 != 0, x < 0);
+        }
+        let len = le.len();
+        assert_eq!(x.to_twos_complement_bytes_le_padded(len).unwrap(), le);
+        let padded = x.to_twos_complement_bytes_le_padded(len + 3).unwrap();
+        assert_eq!(Integer::from_twos_complement_bytes_le(&padded), x);
+        assert_eq!(padded[..len], le[..]);
+        let padded = x.to_twos_complement_bytes_be_padded(len + 3).unwrap();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&padded), x);
+        if len != 0 {
+            assert_eq!(
+                x.to_twos_complement_bytes_le_padded(len - 1),
+                Err(BytesOverflowError)
+            );
+        }
+    });
+
+    natural_gen().test_properties(|x| {
+        let bytes = Integer::from(&x).to_twos_complement_bytes_le();
+        let mut natural_bytes = x.to_bytes_le();
+        if natural_bytes.last().map_or(false, |&b| b >> 7 != 0) {
+            natural_bytes.push(0);
+        }
+        assert_eq!(bytes, natural_bytes);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        let x = Integer::from_twos_complement_bytes_le(&bytes);
+        let out = x.to_twos_complement_bytes_le();
+        assert!(out.len() <= bytes.len());
+        assert_eq!(
+            x.to_twos_complement_bytes_le_padded(bytes.len()).unwrap(),
+            bytes
+        );
+        assert_eq!(Integer::from_twos_complement_bytes_le(&out), x);
+        assert_eq!(
+            Integer::ZERO.to_twos_complement_bytes_le(),
+            Vec::<u8>::new()
+        );
+    });
+}
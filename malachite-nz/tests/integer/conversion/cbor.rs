@@ -0,0 +1,97 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::cbor::CborError;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_cbor() {
+    let test = |s, out: &[u8]| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_cbor(), out);
+        assert_eq!(Integer::from_cbor(out), Ok((x, out.len())));
+    };
+    test("0", &[0x00]);
+    test("10", &[0x0a]);
+    test("-1", &[0x20]);
+    test("-10", &[0x29]);
+    test("-100", &[0x38, 0x63]);
+    test("-1000", &[0x39, 0x03, 0xe7]);
+    test(
+        "-18446744073709551616",
+        &[0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    );
+    test(
+        "-18446744073709551617",
+        &[0xc3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    );
+    test(
+        "18446744073709551616",
+        &[0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    );
+}
+
+#[test]
+fn test_from_cbor() {
+    assert_eq!(
+        Integer::from_cbor(&[0xc3, 0x40]),
+        Ok((Integer::from(-1), 2))
+    );
+    assert_eq!(
+        Integer::from_cbor(&[0xc3, 0x42, 0x00, 0x09, 0x00]),
+        Ok((Integer::from(-10), 4))
+    );
+    assert_eq!(
+        Integer::from_cbor(&[0xc5, 0x40]),
+        Err(CborError::WrongTag(5))
+    );
+    assert_eq!(
+        Integer::from_cbor(&[0x80]),
+        Err(CborError::WrongMajorType(4))
+    );
+    assert_eq!(
+        Integer::from_cbor(&[0x39, 0x03]),
+        Err(CborError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn cbor_properties() {
+    integer_gen().test_properties(|x| {
+        let bytes = x.to_cbor();
+        assert_eq!(Integer::from_cbor(&bytes), Ok((x.clone(), bytes.len())));
+        let fits = if x >= 0 {
+            x < Integer::power_of_2(64)
+        } else {
+            x >= -Integer::power_of_2(64)
+        };
+        assert_eq!(bytes[0] >> 5 == 6, !fits);
+    });
+
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_cbor();
+        assert_eq!(Integer::from(&x).to_cbor(), bytes);
+        assert_eq!(
+            Integer::from_cbor(&bytes),
+            Ok((Integer::from(x), bytes.len()))
+        );
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        match (Natural::from_cbor(&bytes), Integer::from_cbor(&bytes)) {
+            (Ok((x, len)), Ok((y, len_2))) => {
+                assert_eq!(x, y);
+                assert_eq!(len, len_2);
+            }
+            (Err(CborError::Negative), Ok((y, _))) => assert!(y < 0),
+            (Err(e), Err(f)) => assert_eq!(e, f),
+            _ => panic!(),
+        }
+    });
+}
@@ -0,0 +1,139 @@
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::conversion::der::DerError;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_to_der() {
+    let test = |s, out: &[u8]| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_der(), out);
+        assert_eq!(Integer::from_der(out), Ok(x.clone()));
+        assert_eq!(Integer::from_ber(out), Ok(x));
+    };
+    test("0", &[0x02, 0x01, 0x00]);
+    test("1", &[0x02, 0x01, 0x01]);
+    test("-1", &[0x02, 0x01, 0xff]);
+    test("127", &[0x02, 0x01, 0x7f]);
+    test("128", &[0x02, 0x02, 0x00, 0x80]);
+    test("-128", &[0x02, 0x01, 0x80]);
+    test("-129", &[0x02, 0x02, 0xff, 0x7f]);
+    test("256", &[0x02, 0x02, 0x01, 0x00]);
+    test(
+        "1000000000000",
+        &[0x02, 0x06, 0x00, 0xe8, 0xd4, 0xa5, 0x10, 0x00],
+    );
+    test(
+        "-1000000000000",
+        &[0x02, 0x06, 0xff, 0x17, 0x2b, 0x5a, 0xf0, 0x00],
+    );
+
+    // A 1016-bit value needs 128 contents octets, so the length switches to the long form
+    let x = Integer::from(1) << 1015u32;
+    let der = x.to_der();
+    assert_eq!(der[..4], [0x02, 0x81, 0x80, 0x00]);
+    assert_eq!(der.len(), 131);
+    assert_eq!(Integer::from_der(&der), Ok(x));
+    let x = Integer::from(1) << 2100u32;
+    let der = x.to_der();
+    assert_eq!(der[..4], [0x02, 0x82, 0x01, 0x07]);
+    assert_eq!(Integer::from_der(&der), Ok(x));
+}
+
+#[test]
+fn test_from_der_errors() {
+    let test = |bytes: &[u8], der_out, ber_out| {
+        assert_eq!(Integer::from_der(bytes), der_out);
+        assert_eq!(Integer::from_ber(bytes), ber_out);
+    };
+    let both = |bytes: &[u8], e| test(bytes, Err(e), Err(e));
+    both(&[], DerError::UnexpectedEnd);
+    both(&[0x02], DerError::UnexpectedEnd);
+    both(&[0x02, 0x02, 0x01], DerError::UnexpectedEnd);
+    both(&[0x02, 0x82, 0x01], DerError::UnexpectedEnd);
+    both(&[0x03, 0x01, 0x00], DerError::WrongTag(0x03));
+    both(&[0x22, 0x01, 0x00], DerError::WrongTag(0x22));
+    both(&[0x02, 0x80, 0x01, 0x00, 0x00], DerError::IndefiniteLength);
+    both(&[0x02, 0xff], DerError::ReservedLength);
+    both(&[0x02, 0x00], DerError::EmptyContents);
+    both(&[0x02, 0x01, 0x05, 0x00], DerError::TrailingBytes(1));
+    both(
+        &[0x02, 0x01, 0x05, 0x02, 0x01, 0x05],
+        DerError::TrailingBytes(3),
+    );
+    both(
+        &[0x02, 0x89, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        DerError::LengthOverflow,
+    );
+
+    let non_minimal_length = |bytes: &[u8], out: i32| {
+        test(
+            bytes,
+            Err(DerError::NonMinimalLength),
+            Ok(Integer::from(out)),
+        );
+    };
+    non_minimal_length(&[0x02, 0x81, 0x01, 0x05], 5);
+    non_minimal_length(&[0x02, 0x82, 0x00, 0x01, 0x05], 5);
+    non_minimal_length(&[0x02, 0x84, 0x00, 0x00, 0x00, 0x02, 0xff, 0x7f], -129);
+
+    let non_minimal_contents = |bytes: &[u8], out: i32| {
+        test(
+            bytes,
+            Err(DerError::NonMinimalContents),
+            Ok(Integer::from(out)),
+        );
+    };
+    non_minimal_contents(&[0x02, 0x02, 0x00, 0x00], 0);
+    non_minimal_contents(&[0x02, 0x02, 0x00, 0x7f], 127);
+    non_minimal_contents(&[0x02, 0x03, 0x00, 0x00, 0x80], 128);
+    non_minimal_contents(&[0x02, 0x02, 0xff, 0xff], -1);
+    non_minimal_contents(&[0x02, 0x02, 0xff, 0x80], -128);
+}
+
+#[test]
+fn der_properties() {
+    integer_gen().test_properties(|x| {
+        let der = x.to_der();
+        assert_eq!(der[0], 0x02);
+        assert_eq!(Integer::from_der(&der), Ok(x.clone()));
+        assert_eq!(Integer::from_ber(&der), Ok(x.clone()));
+        let mut contents = x.to_twos_complement_bytes_be();
+        if contents.is_empty() {
+            contents.push(0);
+        }
+        assert!(der.ends_with(&contents));
+
+        // Sign-extending the contents and using a long-form length is still valid BER
+        let mut ber = vec![0x02, 0x84];
+        ber.extend_from_slice(&u32::try_from(contents.len() + 1).unwrap().to_be_bytes());
+        ber.push(if x < 0 { 0xff } else { 0x00 });
+        ber.extend_from_slice(&contents);
+        assert_eq!(Integer::from_ber(&ber), Ok(x.clone()));
+        assert!(Integer::from_der(&ber).is_err());
+
+        let mut extended = der.clone();
+        extended.push(0);
+        assert_eq!(
+            Integer::from_der(&extended),
+            Err(DerError::TrailingBytes(1))
+        );
+        for i in 0..der.len() {
+            assert!(Integer::from_der(&der[..i]).is_err());
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        if let Ok(x) = Integer::from_der(&bytes) {
+            assert_eq!(x.to_der(), bytes);
+        }
+        if let Ok(x) = Integer::from_ber(&bytes) {
+            assert_eq!(Integer::from_der(&x.to_der()), Ok(x));
+        }
+    });
+}
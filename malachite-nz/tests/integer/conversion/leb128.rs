@@ -0,0 +1,194 @@
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::leb128::Leb128Error;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, integer_vec_gen, natural_gen};
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+#[test]
+fn test_zigzag() {
+    let test = |s, out| {
+        let x = Integer::from_str(s).unwrap();
+        let z = Natural::from_str(out).unwrap();
+        assert_eq!(x.to_zigzag(), z);
+        assert_eq!(Integer::from_zigzag(&z), x);
+    };
+    test("0", "0");
+    test("-1", "1");
+    test("1", "2");
+    test("-2", "3");
+    test("2", "4");
+    test("2147483647", "4294967294");
+    test("-2147483648", "4294967295");
+    test("-18446744073709551616", "36893488147419103231");
+}
+
+#[test]
+fn test_sleb128() {
+    let test = |s, out: &[u8]| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_sleb128(), out);
+        let mut written = Vec::new();
+        assert_eq!(x.write_sleb128(&mut written).unwrap(), out.len());
+        assert_eq!(written, out);
+        assert_eq!(Integer::from_sleb128(out), Ok((x.clone(), out.len())));
+        let mut reader = out;
+        assert_eq!(Integer::read_sleb128(&mut reader).unwrap(), x);
+        assert!(reader.is_empty());
+    };
+    test("0", &[0x00]);
+    test("1", &[0x01]);
+    test("63", &[0x3f]);
+    test("64", &[0xc0, 0x00]);
+    test("127", &[0xff, 0x00]);
+    test("128", &[0x80, 0x01]);
+    test("-1", &[0x7f]);
+    test("-64", &[0x40]);
+    test("-65", &[0xbf, 0x7f]);
+    test("-128", &[0x80, 0x7f]);
+    test("-129", &[0xff, 0x7e]);
+    test("-123456", &[0xc0, 0xbb, 0x78]);
+    test(
+        "-9223372036854775808",
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f],
+    );
+}
+
+#[test]
+fn test_from_sleb128_errors() {
+    assert_eq!(Integer::from_sleb128(&[]), Err(Leb128Error::UnexpectedEnd));
+    assert_eq!(
+        Integer::from_sleb128(&[0xc0]),
+        Err(Leb128Error::UnexpectedEnd)
+    );
+    assert_eq!(
+        Integer::from_sleb128(&[0x80, 0x00]),
+        Err(Leb128Error::Overlong)
+    );
+    assert_eq!(
+        Integer::from_sleb128(&[0xbf, 0x00]),
+        Err(Leb128Error::Overlong)
+    );
+    assert_eq!(
+        Integer::from_sleb128(&[0xff, 0x7f]),
+        Err(Leb128Error::Overlong)
+    );
+    assert_eq!(
+        Integer::from_sleb128(&[0xc0, 0x7f]),
+        Err(Leb128Error::Overlong)
+    );
+    // The last byte is needed when it changes the sign
+    assert_eq!(
+        Integer::from_sleb128(&[0xc0, 0x00]),
+        Ok((Integer::from(64), 2))
+    );
+    assert_eq!(
+        Integer::from_sleb128(&[0xbf, 0x7f]),
+        Ok((Integer::from(-65), 2))
+    );
+
+    let mut reader: &[u8] = &[0xff, 0x7f];
+    assert_eq!(
+        Integer::read_sleb128(&mut reader).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    let mut reader: &[u8] = &[0xff];
+    assert_eq!(
+        Integer::read_sleb128(&mut reader).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_zigzag_uleb128() {
+    let test = |s, out: &[u8]| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_zigzag_uleb128(), out);
+        let mut written = Vec::new();
+        assert_eq!(x.write_zigzag_uleb128(&mut written).unwrap(), out.len());
+        assert_eq!(written, out);
+        assert_eq!(
+            Integer::from_zigzag_uleb128(out),
+            Ok((x.clone(), out.len()))
+        );
+        let mut reader = out;
+        assert_eq!(Integer::read_zigzag_uleb128(&mut reader).unwrap(), x);
+    };
+    test("0", &[0x00]);
+    test("-1", &[0x01]);
+    test("1", &[0x02]);
+    test("-64", &[0x7f]);
+    test("64", &[0x80, 0x01]);
+    test("-65", &[0x81, 0x01]);
+    assert_eq!(
+        Integer::from_zigzag_uleb128(&[0x81, 0x00]),
+        Err(Leb128Error::Overlong)
+    );
+}
+
+#[test]
+fn leb128_properties() {
+    integer_gen().test_properties(|x| {
+        let z = x.to_zigzag();
+        assert_eq!(Integer::from_zigzag(&z), x);
+        assert_eq!(z.odd(), x < 0);
+
+        let bytes = x.to_sleb128();
+        assert_eq!(Integer::from_sleb128(&bytes), Ok((x.clone(), bytes.len())));
+        assert_eq!(bytes.last().unwrap() & 0x40 != 0, x < 0);
+        for i in 0..bytes.len() {
+            assert_eq!(
+                Integer::from_sleb128(&bytes[..i]),
+                Err(Leb128Error::UnexpectedEnd)
+            );
+        }
+        let mut overlong = bytes.clone();
+        *overlong.last_mut().unwrap() |= 0x80;
+        overlong.push(if x < 0 { 0x7f } else { 0x00 });
+        assert_eq!(Integer::from_sleb128(&overlong), Err(Leb128Error::Overlong));
+
+        let bytes = x.to_zigzag_uleb128();
+        assert_eq!(bytes, z.to_uleb128());
+        assert_eq!(
+            Integer::from_zigzag_uleb128(&bytes),
+            Ok((x.clone(), bytes.len()))
+        );
+    });
+
+    natural_gen().test_properties(|x| {
+        assert_eq!(Integer::from_zigzag(&x).to_zigzag(), x);
+        // SLEB128 never needs more than one byte more than ULEB128
+        let u = x.to_uleb128();
+        let s = Integer::from(&x).to_sleb128();
+        assert!(s.len() == u.len() || s.len() == u.len() + 1);
+    });
+
+    integer_vec_gen().test_properties(|xs| {
+        let mut stream = Vec::new();
+        for x in &xs {
+            x.write_sleb128(&mut stream).unwrap();
+            x.write_zigzag_uleb128(&mut stream).unwrap();
+        }
+        let mut reader = &stream[..];
+        for x in &xs {
+            assert_eq!(Integer::read_sleb128(&mut reader).unwrap(), *x);
+            assert_eq!(Integer::read_zigzag_uleb128(&mut reader).unwrap(), *x);
+        }
+        assert!(reader.is_empty());
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        if let Ok((x, len)) = Integer::from_sleb128(&bytes) {
+            assert_eq!(x.to_sleb128(), &bytes[..len]);
+        }
+        if let Ok((x, len)) = Integer::from_zigzag_uleb128(&bytes) {
+            assert_eq!(x.to_zigzag_uleb128(), &bytes[..len]);
+        }
+    });
+}
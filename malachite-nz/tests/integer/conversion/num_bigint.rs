@@ -0,0 +1,76 @@
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use num::{BigInt, BigUint, Signed};
+use std::str::FromStr;
+
+#[test]
+fn test_from_bigint() {
+    let test = |s| {
+        let x = BigInt::from_str(s).unwrap();
+
+        let n = Integer::from(x.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), s);
+
+        let n = Integer::from(&x);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("2147483648");
+    test("-2147483648");
+    test("1000000000000");
+    test("-1000000000000");
+    test("-18446744073709551616");
+    test("-1000000000000000000000000000000");
+}
+
+#[test]
+fn test_bigint_from_integer() {
+    let test = |s| {
+        let n = Integer::from_str(s).unwrap();
+
+        assert_eq!(BigInt::from(n.clone()).to_string(), s);
+        assert_eq!(BigInt::from(&n).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("2147483648");
+    test("-2147483648");
+    test("1000000000000");
+    test("-1000000000000");
+    test("-18446744073709551616");
+    test("-1000000000000000000000000000000");
+}
+
+#[test]
+fn num_bigint_conversion_properties() {
+    integer_gen().test_properties(|x| {
+        let big_x = BigInt::from(&x);
+        assert_eq!(BigInt::from(x.clone()), big_x);
+        assert_eq!(big_x.to_string(), x.to_string());
+        assert_eq!(big_x.is_negative(), x < 0);
+
+        let y = Integer::from(&big_x);
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert_eq!(Integer::from(big_x), x);
+
+        assert_eq!(Integer::from(-BigInt::from(&x)), -&x);
+    });
+
+    natural_gen().test_properties(|x| {
+        assert_eq!(
+            BigInt::from(&Integer::from(&x)),
+            BigInt::from(BigUint::from(&x))
+        );
+        assert_eq!(
+            Integer::from(BigInt::from(&x)),
+            Natural::from(BigUint::from(&x))
+        );
+    });
+}
@@ -46,12 +46,44 @@ fn test_serde() {
     );
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(bincode::serialize(&x).unwrap(), out);
+        assert_eq!(bincode::deserialize::<Integer>(out).unwrap(), x);
+    };
+    test("0", &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test("100", &[2, 0, 0, 0, 0, 0, 0, 0, 0, 100]);
+    test("-100", &[2, 0, 0, 0, 0, 0, 0, 0, 1, 100]);
+    test(
+        "-1000000000000",
+        &[6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 16, 165, 212, 232],
+    );
+    test(
+        "-18446744073709551616",
+        &[10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+    // Negative zero is read as zero
+    assert_eq!(
+        bincode::deserialize::<Integer>(&[2, 0, 0, 0, 0, 0, 0, 0, 1, 0]).unwrap(),
+        0
+    );
+    // The sign byte must be present and be 0 or 1
+    assert!(bincode::deserialize::<Integer>(&[0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    assert!(bincode::deserialize::<Integer>(&[2, 0, 0, 0, 0, 0, 0, 0, 2, 100]).is_err());
+}
+
 #[test]
 fn serde_properties() {
     integer_gen().test_properties(|x| {
         let s = serde_json::to_string(&x).unwrap();
         assert_eq!(serde_json::from_str::<Integer>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\"-0123456789abcdefx"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Integer>(&bytes).unwrap(), x);
+        assert_eq!(bytes[9..], x.unsigned_abs_ref().to_bytes_le()[..]);
     });
 
     string_gen().test_properties(|s| {
@@ -0,0 +1,133 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{FromStringWithAlphabet, ToStringWithAlphabet};
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::integer_gen;
+
+const ALPHABETS: [DigitAlphabet; 3] =
+    [DigitAlphabet::BASE58_BITCOIN, DigitAlphabet::BASE62, DigitAlphabet::CROCKFORD_BASE32];
+
+#[test]
+fn test_to_string_with_alphabet() {
+    let test = |n: Integer, alphabet, out| {
+        assert_eq!(n.to_string_with_alphabet(alphabet), out);
+    };
+    test(Integer::from(0), DigitAlphabet::BASE62, "0");
+    test(Integer::from(-57), DigitAlphabet::BASE58_BITCOIN, "-z");
+    test(
+        Integer::from(10).pow(20),
+        DigitAlphabet::BASE62,
+        "1v973mBjywOu",
+    );
+    test(
+        -Integer::from(10).pow(20),
+        DigitAlphabet::BASE62,
+        "-1v973mBjywOu",
+    );
+    test(Integer::from(62), DigitAlphabet::BASE64_URL, "-");
+}
+
+#[test]
+#[should_panic]
+fn to_string_with_alphabet_fail() {
+    Integer::from(-1).to_string_with_alphabet(DigitAlphabet::BASE64_URL);
+}
+
+#[test]
+fn test_from_string_with_alphabet() {
+    let test_ok = |alphabet, s, out| {
+        assert_eq!(
+            Integer::from_string_with_alphabet(alphabet, s)
+                .unwrap()
+                .to_string(),
+            out
+        );
+    };
+    test_ok(DigitAlphabet::BASE62, "0", "0");
+    test_ok(DigitAlphabet::BASE62, "-0", "0");
+    test_ok(DigitAlphabet::BASE58_BITCOIN, "-z", "-57");
+    test_ok(
+        DigitAlphabet::BASE62,
+        "-1v973mBjywOu",
+        "-100000000000000000000",
+    );
+    test_ok(DigitAlphabet::BASE64_URL, "-", "62");
+    test_ok(DigitAlphabet::CROCKFORD_BASE32, "-1-0", "-32");
+
+    let test_err = |alphabet, s, kind, index| {
+        assert_eq!(
+            Integer::from_string_with_alphabet(alphabet, s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err(DigitAlphabet::BASE62, "", ParseNumberErrorKind::Empty, 0);
+    test_err(DigitAlphabet::BASE62, "-", ParseNumberErrorKind::Empty, 1);
+    test_err(
+        DigitAlphabet::BASE62,
+        "--1",
+        ParseNumberErrorKind::InvalidDigit,
+        1,
+    );
+    test_err(
+        DigitAlphabet::BASE62,
+        "+1",
+        ParseNumberErrorKind::InvalidDigit,
+        0,
+    );
+    test_err(
+        DigitAlphabet::CROCKFORD_BASE32,
+        "--1",
+        ParseNumberErrorKind::MisplacedSeparator,
+        1,
+    );
+}
+
+#[test]
+fn alphabet_properties() {
+    integer_gen().test_properties(|n| {
+        for alphabet in ALPHABETS {
+            let s = n.to_string_with_alphabet(alphabet);
+            assert_eq!(
+                Integer::from_string_with_alphabet(alphabet, &s),
+                Ok(n.clone())
+            );
+            assert_eq!(s.starts_with('-'), n < 0);
+            assert_eq!(
+                (-&n).to_string_with_alphabet(alphabet),
+                if n > 0 {
+                    format!("-{}", s)
+                } else {
+                    s.trim_start_matches('-').to_string()
+                }
+            );
+        }
+        if n >= 0 {
+            let s = n.to_string_with_alphabet(DigitAlphabet::BASE64_URL);
+            assert_eq!(
+                Integer::from_string_with_alphabet(DigitAlphabet::BASE64_URL, &s),
+                Ok(n.clone())
+            );
+            assert_eq!(
+                s,
+                Natural::try_from(n)
+                    .unwrap()
+                    .to_string_with_alphabet(DigitAlphabet::BASE64_URL)
+            );
+        }
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|i| {
+        for alphabet in ALPHABETS {
+            assert_eq!(
+                Integer::from(i).to_string_with_alphabet(alphabet),
+                i.to_string_with_alphabet(alphabet)
+            );
+        }
+    });
+}
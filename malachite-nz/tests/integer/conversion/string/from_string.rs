@@ -1,4 +1,10 @@
-use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase, ToStringBase};
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, FromStringBase, FromStringWithOptions, ToStringBase,
+};
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::exhaustive::valid_digit_chars;
 use malachite_base::test_util::generators::{
@@ -7,6 +13,7 @@ use malachite_base::test_util::generators::{
 };
 use malachite_nz::integer::Integer;
 use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::integer_gen;
 use num::{BigInt, Num};
 use rug;
 use std::collections::HashMap;
@@ -224,3 +231,94 @@ fn from_string_base_properties() {
         );
     });
 }
+
+fn lenient_options() -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_allow_underscore_separators(true);
+    options.set_allow_whitespace(true);
+    options.set_allow_prefixes(true);
+    options.set_allow_plus_sign(true);
+    options
+}
+
+#[test]
+fn test_from_str_errors() {
+    let test = |s, kind, index| {
+        assert_eq!(
+            Integer::from_str(s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test("", ParseNumberErrorKind::Empty, 0);
+    test("-", ParseNumberErrorKind::Empty, 1);
+    test("12A", ParseNumberErrorKind::InvalidDigit, 2);
+    test("-12A", ParseNumberErrorKind::InvalidDigit, 3);
+    test(" 10", ParseNumberErrorKind::InvalidDigit, 0);
+    test("--1", ParseNumberErrorKind::InvalidDigit, 1);
+    test("+5", ParseNumberErrorKind::InvalidSign, 0);
+}
+
+#[test]
+fn test_from_string_with_options() {
+    let test_ok = |s, options, n| {
+        assert_eq!(
+            Integer::from_string_with_options(s, options)
+                .unwrap()
+                .to_string(),
+            n
+        );
+    };
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    test_ok("-123", default, "-123");
+    test_ok("-0", default, "0");
+    test_ok(" -1_000_000_000_000 ", lenient, "-1000000000000");
+    test_ok("+1_000", lenient, "1000");
+    test_ok("-0xf_f", lenient, "-255");
+    test_ok("-0b1010", lenient, "-10");
+
+    let test_err = |s, options, kind, index| {
+        assert_eq!(
+            Integer::from_string_with_options(s, options),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err(" - ", lenient, ParseNumberErrorKind::Empty, 2);
+    test_err("-0x", lenient, ParseNumberErrorKind::Empty, 3);
+    test_err("- 1", lenient, ParseNumberErrorKind::InvalidDigit, 1);
+    test_err("+-1", lenient, ParseNumberErrorKind::InvalidDigit, 1);
+    test_err("-_1", lenient, ParseNumberErrorKind::MisplacedSeparator, 1);
+    test_err("-1,000", lenient, ParseNumberErrorKind::InvalidDigit, 2);
+}
+
+#[test]
+fn from_string_with_options_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        if let Ok(n) = Integer::from_str(&s) {
+            assert_eq!(
+                Integer::from_string_with_options(&s, lenient_options()),
+                Ok(n)
+            );
+        }
+    });
+
+    integer_gen().test_properties(|n| {
+        let s = n.to_string();
+        assert_eq!(Integer::from_str(&s), Ok(n.clone()));
+        let lenient = lenient_options();
+        assert_eq!(
+            Integer::from_string_with_options(&format!(" {} ", s), lenient),
+            Ok(n.clone())
+        );
+        assert_eq!(
+            Integer::from_string_with_options(&format!("{:#x}", n), lenient),
+            Ok(n.clone())
+        );
+        assert_eq!(
+            Integer::from_string_with_options(&format!("{:+#o}", n), lenient),
+            Ok(n)
+        );
+    });
+}
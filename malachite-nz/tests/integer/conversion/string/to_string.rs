@@ -1,8 +1,11 @@
 use itertools::repeat_n;
 use malachite_base::num::arithmetic::traits::SaturatingSubAssign;
 use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::string::options::ToSciOptions;
 use malachite_base::num::conversion::string::to_string::BaseFmtWrapper as BaseBaseFmtWrapper;
-use malachite_base::num::conversion::traits::{FromStringBase, ToStringBase};
+use malachite_base::num::conversion::traits::{
+    ExactFrom, FromSciString, FromStringBase, ToSci, ToStringBase,
+};
 use malachite_base::strings::{
     string_is_subset, ToBinaryString, ToDebugString, ToLowerHexString, ToOctalString,
     ToUpperHexString,
@@ -1185,3 +1188,177 @@ fn to_string_base_upper_properties() {
         },
     );
 }
+
+#[test]
+pub fn test_fmt_flags() {
+    let x = Integer::from(-123);
+    assert_eq!(format!("{:>8}", x), "    -123");
+    assert_eq!(format!("{:<8}|", x), "-123    |");
+    assert_eq!(format!("{:^9}", x), "  -123   ");
+    assert_eq!(format!("{:*^9}", x), "**-123***");
+    assert_eq!(format!("{:+}", Integer::from(123)), "+123");
+    assert_eq!(format!("{:+08}", Integer::from(123)), "+0000123");
+    assert_eq!(format!("{:+08}", x), "-0000123");
+    assert_eq!(format!("{:>#12b}", x), "  -0b1111011");
+    assert_eq!(format!("{:<#8o}|", x), "-0o173  |");
+    assert_eq!(format!("{:^+#8x}", Integer::from(123)), " +0x7b  ");
+    assert_eq!(format!("{:>8X}", x), "     -7B");
+
+    let x = Integer::from_str("-1000000000000").unwrap();
+    assert_eq!(format!("{:>16}", x), "  -1000000000000");
+    assert_eq!(format!("{:_<#16x}", x), "-0xe8d4a51000___");
+    assert_eq!(format!("{:>#16X}", x), "   -0xE8D4A51000");
+    assert_eq!(
+        format!("{:^11}", BaseFmtWrapper::new(&x, 36)),
+        " -cre66i9s "
+    );
+    assert_eq!(
+        format!("{:<#11}|", BaseFmtWrapper::new(&x, 36)),
+        "-CRE66I9S  |"
+    );
+}
+
+#[test]
+pub fn test_to_exp_string() {
+    fn test(u: &str, out: &str, out_upper: &str) {
+        let x = Integer::from_str(u).unwrap();
+        assert_eq!(format!("{:e}", x), out);
+        assert_eq!(format!("{:E}", x), out_upper);
+    }
+    test("0", "0e0", "0E0");
+    test("123", "1.23e2", "1.23E2");
+    test("1000000000000", "1e12", "1E12");
+    test("-1", "-1e0", "-1E0");
+    test("-123", "-1.23e2", "-1.23E2");
+    test("-1000", "-1e3", "-1E3");
+    test(
+        "-123456789012345678901234567890",
+        "-1.2345678901234567890123456789e29",
+        "-1.2345678901234567890123456789E29",
+    );
+
+    fn test_precision(u: &str, precision: usize, out: &str) {
+        let x = Integer::from_str(u).unwrap();
+        assert_eq!(format!("{:.*e}", precision, x), out);
+    }
+    test_precision("0", 2, "0.00e0");
+    test_precision("123", 1, "1.2e2");
+    test_precision("-123", 0, "-1e2");
+    test_precision("-125", 1, "-1.2e2");
+    test_precision("-135", 1, "-1.4e2");
+    test_precision("-999", 1, "-1.0e3");
+    test_precision("-123456789012345678901234567890", 4, "-1.2346e29");
+
+    let x = Integer::from(-123);
+    assert_eq!(format!("{:10e}", x), "   -1.23e2");
+    assert_eq!(format!("{:<10e}|", x), "-1.23e2   |");
+    assert_eq!(format!("{:010e}", x), "-0001.23e2");
+    assert_eq!(format!("{:+e}", Integer::from(123)), "+1.23e2");
+}
+
+#[test]
+fn to_exp_string_properties() {
+    integer_gen().test_properties(|x| {
+        let s = format!("{:e}", x);
+        assert_eq!(format!("{:E}", x), s.to_uppercase());
+        assert_eq!(Integer::from_sci_string(&s).unwrap(), x);
+        assert_eq!(
+            format!("{:e}", x.unsigned_abs_ref()),
+            s.trim_start_matches('-')
+        );
+    });
+
+    integer_unsigned_pair_gen_var_2().test_properties(|(x, precision)| {
+        let s = format!("{:.*e}", precision, x);
+        assert_eq!(
+            format!("{:.*e}", precision, x.unsigned_abs_ref()),
+            s.trim_start_matches('-')
+        );
+        let mut options = ToSciOptions::default();
+        options.set_precision(u64::exact_from(precision) + 1);
+        assert_eq!(
+            Integer::from_sci_string(&s).unwrap(),
+            Integer::from_sci_string(&x.to_sci_with_options(options).to_string()).unwrap()
+        );
+    });
+
+    signed_unsigned_pair_gen_var_5::<SignedLimb, usize>().test_properties(|(x, precision)| {
+        let n = Integer::from(x);
+        assert_eq!(format!("{:e}", n), format!("{:e}", x));
+        assert_eq!(
+            format!("{:.*e}", precision, n),
+            format!("{:.*e}", precision, x)
+        );
+        assert_eq!(
+            format!("{:>+width$E}", n, width = precision),
+            format!("{:>+width$E}", x, width = precision)
+        );
+        assert_eq!(
+            format!("{:0width$e}", n, width = precision),
+            format!("{:0width$e}", x, width = precision)
+        );
+    });
+}
+
+#[test]
+fn fmt_flags_properties() {
+    integer_unsigned_pair_gen_var_2().test_properties(|(x, width)| {
+        let s = x.to_string();
+        assert_eq!(
+            format!("{:>width$}", x, width = width),
+            format!("{:>width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:*<width$}", x, width = width),
+            format!("{:*<width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:^width$}", x, width = width),
+            format!("{:^width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:^#width$x}", x, width = width),
+            format!("{:^width$}", format!("{:#x}", x), width = width)
+        );
+        let b = if x >= 0 {
+            format!("{:#b}", x)
+        } else {
+            format!("-{:#b}", x.unsigned_abs_ref())
+        };
+        assert_eq!(
+            format!("{:<#width$b}", x, width = width),
+            format!("{:<width$}", b, width = width)
+        );
+        if x >= 0 {
+            assert_eq!(
+                format!("{:>+width$}", x, width = width),
+                format!("{:>width$}", format!("+{}", s), width = width)
+            );
+        } else {
+            assert_eq!(
+                format!("{:>+width$}", x, width = width),
+                format!("{:>width$}", s, width = width)
+            );
+        }
+    });
+
+    signed_unsigned_pair_gen_var_5::<SignedLimb, usize>().test_properties(|(x, width)| {
+        let n = Integer::from(x);
+        assert_eq!(
+            format!("{:>width$}", n, width = width),
+            format!("{:>width$}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:+width$}", n, width = width),
+            format!("{:+width$}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:^+width$}", n, width = width),
+            format!("{:^+width$}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:_<width$}", n, width = width),
+            format!("{:_<width$}", x, width = width)
+        );
+    });
+}
@@ -0,0 +1,188 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{
+    integer_gen, integer_pair_gen, integer_pair_gen_var_1, integer_unsigned_pair_gen_var_2,
+    integer_unsigned_pair_gen_var_3,
+};
+use num::integer::Roots;
+use num::traits::{CheckedDiv, FromPrimitive, Num, Signed, ToPrimitive};
+use num::BigInt;
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Result<&str, ParseNumberError>| {
+        let result = <Integer as Num>::from_str_radix(s, radix).map(|n| {
+            assert!(n.is_valid());
+            n.to_string()
+        });
+        assert_eq!(result, out.map(ToString::to_string));
+    };
+    test("0", 10, Ok("0"));
+    test("-0", 10, Ok("0"));
+    test("123", 10, Ok("123"));
+    test("+123", 10, Ok("123"));
+    test("-1_000_000", 10, Ok("-1000000"));
+    test("-ff", 16, Ok("-255"));
+    test("101", 2, Ok("5"));
+    test(
+        "-",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1)),
+    );
+    test(
+        "+-1",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1)),
+    );
+    test(
+        "12",
+        2,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1)),
+    );
+}
+
+#[test]
+fn test_num_integer() {
+    let test = |x: i64, y: i64, q_floor: i64, r_floor: i64, q: i64, r: i64| {
+        let x = Integer::from(x);
+        let y = Integer::from(y);
+        assert_eq!(num::Integer::div_floor(&x, &y), q_floor);
+        assert_eq!(num::Integer::mod_floor(&x, &y), r_floor);
+        assert_eq!(
+            num::Integer::div_mod_floor(&x, &y),
+            (Integer::from(q_floor), Integer::from(r_floor))
+        );
+        assert_eq!(
+            num::Integer::div_rem(&x, &y),
+            (Integer::from(q), Integer::from(r))
+        );
+    };
+    test(0, 5, 0, 0, 0, 0);
+    test(23, 10, 2, 3, 2, 3);
+    test(-23, 10, -3, 7, -2, -3);
+    test(23, -10, -3, -7, -2, 3);
+    test(-23, -10, 2, -3, 2, -3);
+    test(-20, 10, -2, 0, -2, 0);
+
+    let x = Integer::from(-24);
+    let y = Integer::from(18);
+    assert_eq!(num::Integer::gcd(&x, &y), 6);
+    assert_eq!(num::Integer::lcm(&x, &y), 72);
+    assert!(num::Integer::is_even(&x));
+    assert!(num::Integer::is_multiple_of(&x, &Integer::from(-8)));
+}
+
+#[test]
+fn test_signed() {
+    let test = |x: i64, abs: i64, signum: i64| {
+        let x = Integer::from(x);
+        assert_eq!(Signed::abs(&x), abs);
+        assert_eq!(x.signum(), signum);
+        assert_eq!(x.is_positive(), signum == 1);
+        assert_eq!(x.is_negative(), signum == -1);
+    };
+    test(0, 0, 0);
+    test(123, 123, 1);
+    test(-123, 123, -1);
+
+    assert_eq!(Integer::from(10).abs_sub(&Integer::from(-3)), 13);
+    assert_eq!(Integer::from(-3).abs_sub(&Integer::from(10)), 0);
+}
+
+#[test]
+fn test_to_and_from_primitive() {
+    assert_eq!(Integer::from(-123).to_i8(), Some(-123));
+    assert_eq!(Integer::from(-123).to_u8(), None);
+    assert_eq!(Integer::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!(Integer::from(i128::MIN).to_i128(), Some(i128::MIN));
+    assert_eq!(Integer::from(u128::MAX).to_i128(), None);
+    assert_eq!(Integer::from(-123).to_f32(), Some(-123.0));
+
+    assert_eq!(Integer::from_i64(-1), Some(Integer::from(-1)));
+    assert_eq!(
+        Integer::from_u128(u128::MAX),
+        Some(Integer::from(u128::MAX))
+    );
+    assert_eq!(Integer::from_f64(-0.9), Some(Integer::ZERO));
+    assert_eq!(Integer::from_f64(-1.5), Some(Integer::from(-1)));
+    assert_eq!(
+        Integer::from_f64(-1.0e100).unwrap().to_f64(),
+        Some(-1.0e100)
+    );
+    assert_eq!(Integer::from_f64(f64::NEG_INFINITY), None);
+}
+
+#[test]
+#[should_panic]
+fn nth_root_fail() {
+    Integer::from(-4).nth_root(2);
+}
+
+#[test]
+fn num_traits_properties() {
+    integer_gen().test_properties(|x| {
+        let num_x = BigInt::from(&x);
+        assert_eq!(
+            <Integer as Num>::from_str_radix(&x.to_string(), 10).unwrap(),
+            x
+        );
+        assert_eq!(x.to_i64(), num_x.to_i64());
+        assert_eq!(x.to_u128(), num_x.to_u128());
+        assert_eq!(Integer::from(Signed::abs(&num_x)), Signed::abs(&x));
+        assert_eq!(Integer::from(num_x.signum()), x.signum());
+        assert_eq!(num::Integer::is_odd(&x), num::Integer::is_odd(&num_x));
+        assert_eq!(Integer::from(num_x.cbrt()), x.cbrt());
+        assert_eq!(-(-&x).cbrt(), x.cbrt());
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let num_x = BigInt::from(&x);
+        let num_y = BigInt::from(&y);
+        assert_eq!(
+            Integer::from(num::Integer::gcd(&num_x, &num_y)),
+            num::Integer::gcd(&x, &y)
+        );
+        assert_eq!(
+            Integer::from(num::Integer::lcm(&num_x, &num_y)),
+            num::Integer::lcm(&x, &y)
+        );
+        assert_eq!(Integer::from(num_x.abs_sub(&num_y)), x.abs_sub(&y));
+        assert_eq!(
+            num_x.checked_div(&num_y).map(Integer::from),
+            x.checked_div(&y)
+        );
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let num_x = BigInt::from(&x);
+        let num_y = BigInt::from(&y);
+        let (q, r) = num::Integer::div_mod_floor(&num_x, &num_y);
+        assert_eq!(
+            num::Integer::div_mod_floor(&x, &y),
+            (Integer::from(q), Integer::from(r))
+        );
+        let (q, r) = num::Integer::div_rem(&num_x, &num_y);
+        assert_eq!(
+            num::Integer::div_rem(&x, &y),
+            (Integer::from(q), Integer::from(r))
+        );
+        assert_eq!(
+            num::Integer::is_multiple_of(&x, &y),
+            num::Integer::is_multiple_of(&num_x, &num_y)
+        );
+    });
+
+    integer_unsigned_pair_gen_var_2::<u32>().test_properties(|(x, exp)| {
+        assert_eq!(
+            Integer::from(num::traits::Pow::pow(BigInt::from(&x), exp)),
+            num::traits::Pow::pow(&x, exp)
+        );
+    });
+
+    integer_unsigned_pair_gen_var_3::<u32>().test_properties(|(x, n)| {
+        assert_eq!(Integer::from(BigInt::from(&x).nth_root(n)), x.nth_root(n));
+    });
+}
@@ -103,7 +103,10 @@ pub mod integer {
         pub mod partial_eq_primitive_int;
     }
     pub mod conversion {
+        pub mod bytes;
+        pub mod cbor;
         pub mod clone;
+        pub mod der;
         pub mod floating_point_from_integer;
         pub mod from_bool;
         pub mod from_natural;
@@ -111,11 +114,15 @@ pub mod integer {
         pub mod from_primitive_int;
         pub mod from_twos_complement_limbs;
         pub mod is_integer;
+        pub mod leb128;
         pub mod natural_from_integer;
+        #[cfg(feature = "num-bigint")]
+        pub mod num_bigint;
         pub mod primitive_int_from_integer;
         #[cfg(feature = "serde")]
         pub mod serde;
         pub mod string {
+            pub mod alphabet;
             pub mod from_sci_string;
             pub mod from_string;
             pub mod to_sci;
@@ -162,6 +169,8 @@ pub mod integer {
         pub mod trailing_zeros;
         pub mod xor;
     }
+    #[cfg(feature = "num-traits")]
+    pub mod num_traits;
     pub mod random {
         pub mod random_integer_inclusive_range;
         pub mod random_integer_range;
@@ -253,8 +262,15 @@ pub mod natural {
         pub mod square;
         pub mod sub;
         pub mod sub_mul;
+        #[cfg(all(
+            feature = "asm",
+            target_arch = "x86_64",
+            not(any(feature = "32_bit_limbs", feature = "128_bit_limbs"))
+        ))]
+        pub mod x86_64;
     }
     pub mod basic {
+        pub mod capacity;
         pub mod constants;
         pub mod default;
         pub mod named;
@@ -272,7 +288,10 @@ pub mod natural {
         pub mod partial_eq_primitive_int;
     }
     pub mod conversion {
+        pub mod bytes;
+        pub mod cbor;
         pub mod clone;
+        pub mod der;
         pub mod digits {
             pub mod from_digits;
             pub mod from_power_of_2_digits;
@@ -286,14 +305,18 @@ pub mod natural {
         pub mod from_limbs;
         pub mod from_primitive_int;
         pub mod is_integer;
+        pub mod leb128;
         pub mod mantissa_and_exponent {
             pub mod integer_mantissa_and_exponent;
             pub mod sci_mantissa_and_exponent;
         }
+        #[cfg(feature = "num-bigint")]
+        pub mod num_bigint;
         pub mod primitive_int_from_natural;
         #[cfg(feature = "serde")]
         pub mod serde;
         pub mod string {
+            pub mod alphabet;
             pub mod from_sci_string;
             pub mod from_string;
             pub mod to_sci;
@@ -335,6 +358,8 @@ pub mod natural {
         pub mod trailing_zeros;
         pub mod xor;
     }
+    #[cfg(feature = "num-traits")]
+    pub mod num_traits;
     pub mod random {
         pub mod get_random_natural_with_bits;
         pub mod get_random_natural_with_up_to_bits;
@@ -781,6 +781,17 @@ fn test_add() {
         "2350262829889206551114184866",
         "159839293964198030952342417792",
     );
+    test("340282366920938463463374607431768211455", "1", "340282366920938463463374607431768211456");
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "1",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "231584178474632390847141970017375815706539969331281128078915168015826259279870",
+    );
 }
 
 #[test]
@@ -1138,6 +1149,10 @@ fn add_properties() {
         let sum_val_ref = x.clone() + &y;
         let sum_ref_val = &x + y.clone();
         let sum = &x + &y;
+        let mut out = y.clone();
+        x.add_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, sum);
         assert!(sum_val_val.is_valid());
         assert!(sum_val_ref.is_valid());
         assert!(sum_ref_val.is_valid());
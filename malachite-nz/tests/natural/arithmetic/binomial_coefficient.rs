@@ -14,7 +14,7 @@ use malachite_nz::test_util::generators::{
 use malachite_nz::test_util::natural::arithmetic::binomial_coefficient::*;
 use std::str::FromStr;
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_binomial_coefficient_limb_limb_bdiv() {
     fn test(n: Limb, k: Limb, out: &[Limb]) {
@@ -68,7 +68,46 @@ fn test_limbs_binomial_coefficient_limb_limb_bdiv() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_binomial_coefficient_limb_limb_bdiv() {
+    fn test(n: Limb, k: Limb, out: &[Limb]) {
+        let xs = limbs_binomial_coefficient_limb_limb_bdiv(n, k);
+        assert_ne!(*xs.last().unwrap(), 0);
+        assert_eq!(xs, out);
+        assert_eq!(
+            Natural::from_owned_limbs_asc(xs),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+        );
+    }
+    test(42, 42, &[1]);
+    test(100, 50, &[100891344545564193334812497256]);
+    test(
+        364,
+        227,
+        &[
+            280481739520599572677341137615148608504,
+            269973440323965642488977143560526660050,
+            183400639381794260240453783,
+        ],
+    );
+    test(
+        1000,
+        500,
+        &[
+            42187822988881491339181601854259374656,
+            171685547455916136788209976450774011435,
+            119042093251044266635565507288030089019,
+            302148024060165424703199081483621483383,
+            73935075989382833595444909948802651901,
+            130758993499907906275519449636398941248,
+            232747651300240629346686446577758175760,
+            511624150953073093684476083683,
+        ],
+    );
+}
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_binomial_coefficient_limb_limb_small_k() {
     fn test(n: Limb, k: Limb, out: &[Limb]) {
@@ -97,7 +136,44 @@ fn test_limbs_binomial_coefficient_limb_limb_small_k() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_binomial_coefficient_limb_limb_small_k() {
+    fn test(n: Limb, k: Limb, out: &[Limb]) {
+        let xs = limbs_binomial_coefficient_limb_limb_small_k(n, k);
+        assert_ne!(*xs.last().unwrap(), 0);
+        assert_eq!(xs, out);
+        assert_eq!(
+            Natural::from_owned_limbs_asc(xs),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+        );
+    }
+    test(2, 2, &[1]);
+    test(9, 9, &[1]);
+    test(17, 17, &[1]);
+    test(836, 7, &[55217369185858880]);
+    test(
+        1000,
+        25,
+        &[338586353664869909711047942636046039136, 140006850684],
+    );
+}
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+#[test]
+fn test_limbs_binomial_coefficient_limb_limb_basecase() {
+    fn test(n: Limb, k: Limb, out: Limb) {
+        assert_eq!(limbs_binomial_coefficient_limb_limb_basecase(n, k), out);
+        assert_eq!(
+            Natural::from(out),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+        );
+    }
+    test(10, 5, 252);
+    test(67, 65, 2211);
+}
+
+#[cfg(feature = "128_bit_limbs")]
 #[test]
 fn test_limbs_binomial_coefficient_limb_limb_basecase() {
     fn test(n: Limb, k: Limb, out: Limb) {
@@ -111,7 +187,7 @@ fn test_limbs_binomial_coefficient_limb_limb_basecase() {
     test(67, 65, 2211);
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_binomial_coefficient_limb_limb_small_k_divide_and_conquer() {
     fn test(n: Limb, k: Limb, out: &[Limb]) {
@@ -139,7 +215,29 @@ fn test_limbs_binomial_coefficient_limb_limb_small_k_divide_and_conquer() {
     test(93, 51, &[10325892988062052036, 28774313]);
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_binomial_coefficient_limb_limb_small_k_divide_and_conquer() {
+    fn test(n: Limb, k: Limb, out: &[Limb]) {
+        let xs = limbs_binomial_coefficient_limb_limb_small_k_divide_and_conquer(n, k);
+        assert_ne!(*xs.last().unwrap(), 0);
+        assert_eq!(xs, out);
+        assert_eq!(
+            Natural::from_owned_limbs_asc(xs),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+        );
+    }
+    test(27, 25, &[351]);
+    test(
+        10000,
+        25,
+        &[183569346933307549701045567216812581120, 1838549759973838059127283689452608255],
+    );
+    test(54, 52, &[1431]);
+    test(93, 51, &[530792398133706697350491844]);
+}
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_binomial_coefficient_limb_limb_goetgheluck() {
     fn test(n: Limb, k: Limb, out: &[Limb]) {
@@ -228,6 +326,49 @@ fn test_limbs_binomial_coefficient_limb_limb_goetgheluck() {
     );
 }
 
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_binomial_coefficient_limb_limb_goetgheluck() {
+    fn test(n: Limb, k: Limb, out: &[Limb]) {
+        let xs = limbs_binomial_coefficient_limb_limb_goetgheluck(n, k);
+        assert_ne!(*xs.last().unwrap(), 0);
+        assert_eq!(xs, out);
+        assert_eq!(
+            Natural::from_owned_limbs_asc(xs),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+        );
+    }
+    test(
+        1024,
+        512,
+        &[
+            50208589489485113065967984068055548486,
+            66040043507465318959475548817302414001,
+            48607598483397692503200952776326778425,
+            239158174100280142767661442777613001019,
+            222495437729771826501500774321110380547,
+            117006118208392352129948803130210145148,
+            244694133070003864800612982505930247372,
+            8482492791408797180593459538825202987,
+        ],
+    );
+    test(
+        1045,
+        519,
+        &[
+            334064871371760317953948444848951268176,
+            221626777054997739110089112959296705465,
+            188484662429452226721044389394700222682,
+            290326103219351504329236315824744596445,
+            22042951731437601212822871811559538367,
+            36076527063641226095524244671215825507,
+            48612047131953248379464710865694294526,
+            230262628092557806061146308874423388916,
+            50551,
+        ],
+    );
+}
+
 #[test]
 fn test_binomial_coefficient_limb_limb() {
     fn test(n: Limb, k: Limb, out: &str) {
@@ -1643,7 +1643,7 @@ fn test_limbs_div_schoolbook() {
             &[0],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // !limbs_sub_limb_in_place(
         //      ns_hi,
@@ -2740,354 +2740,18 @@ fn test_limbs_div_schoolbook() {
             &[u64::MAX, 0, 2305843009213431808],
         );
     }
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_schoolbook_fail_1() {
-    let ds = &[3, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_schoolbook_fail_2() {
-    let ds = &[3, 4, 5, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_schoolbook_fail_3() {
-    let ds = &[3, 4, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3, 4, 5], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_schoolbook_fail_4() {
-    let ds = &[3, 4, 5];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-fn test_limbs_div_divide_and_conquer() {
-    let test = |qs_in: &[Limb], ns: &[Limb], ds: &[Limb], q_highest, qs_out: &[Limb]| {
-        let mut qs = qs_in.to_vec();
-        let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-        assert_eq!(
-            limbs_div_divide_and_conquer(&mut qs, ns, ds, d_inv),
-            q_highest
-        );
-        assert_eq!(qs, qs_out);
-        verify_limbs_div(qs_in, ns, ds, q_highest, &qs);
-    };
-    {
-        // *scratch_2_head != 0
-        test(
-            &[10; 4],
-            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
-            &[3, 4, 5, 6, 7, 0x80000000],
-            false,
-            &[4294967057, 15, 18, 10],
-        );
-        // *scratch_2_head == 0
-        // !(highest_q &&
-        //      limbs_slice_add_same_length_in_place_left(&mut scratch_init[q_len..], ds)
-        //      || limbs_cmp_same_length(scratch_init, ns) == Ordering::Greater)
-        test(
-            &[10; 3],
-            &[0; 9],
-            &[0, 0, 0, 0, 0, 0x80000000],
-            false,
-            &[0, 0, 0],
-        );
-        test(
-            &[10; 3],
-            &[0, 0, 0, 0, 0, 0, 0, 0, 1],
-            &[0, 0, 0, 0, 0, 0x80000000],
-            false,
-            &[0, 0, 2],
-        );
-        // highest_q &&
-        //      limbs_slice_add_same_length_in_place_left(&mut scratch_init[q_len..], ds)
-        //      || limbs_cmp_same_length(scratch_init, ns) == Ordering::Greater
-        test(
-            &[10; 3],
-            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
-            &[0, 0, 0, 1, 0, 0, 0, 0x80000000],
-            false,
-            &[1, 0, 0],
-        );
-    }
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_divide_and_conquer_fail_1() {
-    let ds = &[3, 4, 5, 6, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_divide_and_conquer_fail_2() {
-    let ds = &[3, 4, 5, 6, 7, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_divide_and_conquer_fail_3() {
-    let ds = &[3, 4, 5, 6, 7, 0x80000000];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_divide_and_conquer(&mut [10, 10], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_divide_and_conquer_fail_4() {
-    let ds = &[3, 4, 5, 6, 7, 8];
-    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
-    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
-}
-
-fn verify_limbs_div_approx(
-    qs_in: &[Limb],
-    ns_in: &[Limb],
-    ds: &[Limb],
-    q_highest: bool,
-    qs_out: &[Limb],
-) {
-    let n = Natural::from_limbs_asc(ns_in);
-    let d = Natural::from_limbs_asc(ds);
-    let (expected_q, expected_r) = (&n).div_mod(&d);
-    let base_q_len = ns_in.len() - ds.len();
-    let mut qs = qs_out[..base_q_len].to_vec();
-    if q_highest {
-        qs.push(1);
-    }
-    let q = Natural::from_owned_limbs_asc(qs);
-    let q_is_one_too_large = q != expected_q;
-    if q_is_one_too_large {
-        assert_eq!(q, expected_q + Natural::ONE);
-        assert_eq!((q - Natural::ONE) * &d + expected_r, n);
-    } else {
-        assert_eq!(q * &d + expected_r, n);
-    }
-    assert_eq!(&qs_in[base_q_len..], &qs_out[base_q_len..]);
-}
-
-#[test]
-fn test_limbs_div_barrett() {
-    let test = |qs_in: &[Limb], ns: &[Limb], ds: &[Limb], q_highest, qs_out: &[Limb]| {
-        let mut qs = qs_in.to_vec();
-        let mut scratch = vec![0; limbs_div_barrett_scratch_len(ns.len(), ds.len())];
-        assert_eq!(limbs_div_barrett(&mut qs, ns, ds, &mut scratch), q_highest);
-        assert_eq!(qs, qs_out);
-        verify_limbs_div(qs_in, ns, ds, q_highest, &qs);
-    };
-    #[cfg(feature = "32_bit_limbs")]
+    #[cfg(feature = "128_bit_limbs")]
     {
-        // q_len < d_len
-        // ghost_limb
-        // *scratch_2_head <= 6
-        // !highest_q third time
-        // !(highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..], ds)
-        //      || limbs_cmp_same_length(&mut rs, ns) == Ordering::Greater)
-        test(&[10; 3], &[1, 2, 3], &[0, 0x80000000], false, &[6, 10, 10]);
-        test(&[10; 3], &[1, 2, 3], &[3, 0x80000000], false, &[6, 10, 10]);
-        // q_len >= d_len
-        // !highest_q first time
-        // !_limbs_div_barrett_approx(&mut scratch_2, &mut rs, ds, scratch)
-        // *scratch_2_head > 4
-        test(
-            &[10; 3],
-            &[1, 2, 3, 4],
-            &[0, 0x80000000],
-            false,
-            &[6, 8, 10],
-        );
-        test(
-            &[10; 3],
-            &[1, 2, 3, 4],
-            &[3, 0x80000000],
-            false,
-            &[5, 8, 10],
-        );
-        // *scratch_2_head > 6
-        test(&[10; 3], &[0, 0, 1], &[0, 0x80000001], false, &[1, 10, 10]);
-        // !ghost_limb
-        test(
-            &[10; 3],
-            &[0, 0, 0, 0],
-            &[0, 0, 0x80000000],
-            false,
-            &[0, 10, 10],
-        );
-        // highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..], ds)
-        //      || limbs_cmp_same_length(&mut rs, ns) == Ordering::Greater
-        // !limbs_sub_limb_to_out(qs, &tp[1..q_len + 1], 1) second time
-        test(&[10; 3], &[0, 0, 1], &[1, 0x80000000], false, &[1, 10, 10]);
-        // *scratch_2_head <= 4
-        // !highest_q second time
-        // !(highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..d_len], ds)
-        //      || limbs_cmp_same_length(&rs[..n_len], ns) == Ordering::Greater)
-        test(
-            &[10; 3],
-            &[0, 0, 0, 0],
-            &[0, 0x80000000],
-            false,
-            &[0, 0, 10],
-        );
-        // highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..d_len], ds)
-        //      || limbs_cmp_same_length(&rs[..n_len], ns) == Ordering::Greater
-        // !limbs_sub_limb_to_out(qs, &tp[1..q_len + 1], 1) first time
         test(
-            &[10; 3],
-            &[0, 0, 1, 0],
-            &[1, 0x80000000],
+            &[10],
+            &[0, 0, 1, 1],
+            &[0, 1, 170141183460469231731687303715884105728],
             false,
-            &[1, 0, 10],
+            &[2],
         );
-        // highest_q first time
         test(
-            &[10; 337],
+            &[10; 518],
             &[
-                2119562458, 822658147, 3797927043, 3236945010, 3998585665, 1799547707, 694717600,
-                81695911, 596552600, 1030767834, 2420116557, 2879241862, 4265568378, 644932084,
-                525117944, 1830433723, 3385005819, 1023897437, 3938627008, 3218345466, 471356423,
-                1008605584, 192751188, 2084112099, 2804133613, 3079231960, 3746102582, 3887941757,
-                2403297368, 3093735039, 2439864684, 2473555841, 2532233722, 2609655906, 3449112520,
-                2817574574, 1129609143, 821518979, 991339292, 2760112178, 890698714, 990807460,
-                2521797827, 3797612523, 1500537907, 3973412117, 2699035435, 2317986252, 2861160946,
-                2101011897, 1732640676, 3352339942, 3286471253, 1371519955, 1379608062, 2699834414,
-                3261360638, 226259522, 1897478770, 2788528264, 2309134747, 4024965405, 3976506219,
-                1035727997, 3131955545, 3667438412, 384232858, 2419576220, 793788636, 334784748,
-                3765760253, 1098631674, 1499085537, 2112219622, 1421552393, 1432628811, 643517209,
-                3632058889, 1632079643, 2223695587, 1476932543, 1960156116, 3436427017, 2201635037,
-                3182693886, 1548942116, 3157649082, 980646399, 831953531, 2763454966, 3961226196,
-                3582012549, 3882861773, 3003921500, 1404369615, 4000431109, 969558169, 660750652,
-                4122382799, 2357249946, 3678033512, 1327191217, 2116553842, 22168676, 1216607516,
-                2222670817, 4212062746, 3066154963, 4179712741, 406795945, 2599414815, 3647640166,
-                4071465241, 883313541, 1105574450, 866562291, 372730105, 970111908, 749995626,
-                1253456197, 2374658843, 3555086224, 3636919504, 488985805, 2204950393, 1262942086,
-                3204402881, 4275635994, 2310416697, 4135349793, 1226698657, 1188086923, 3222723934,
-                1042266586, 4047620517, 3948968819, 4123810886, 1610456917, 556933362, 4197028761,
-                59668868, 2993790244, 854419727, 933448524, 897309660, 3037260848, 1562257062,
-                4083457628, 981822256, 295330752, 3711901403, 1052560139, 3617703336, 262191843,
-                1142114318, 3848091155, 2086394563, 469188180, 3855914481, 154128290, 2738524940,
-                1460255288, 1285831461, 534017408, 3101261674, 118239159, 1944080872, 4203935546,
-                2653496603, 3202103001, 4264655348, 3809947358, 331166910, 4277075817, 1031408102,
-                866321189, 356095977, 1058770275, 3617580770, 2573338110, 1315597113, 1752964646,
-                2179362239, 2449996072, 1745253117, 85067425, 347176893, 240744367, 123940072,
-                3568364276, 1405760257, 2210945376, 2261469245, 2772215200, 746282058, 568635214,
-                1171903297, 1744293446, 1789334502, 1459265780, 2293069614, 319891368, 1979148180,
-                627405437, 1184813190, 3346831763, 1106015952, 940476403, 1182911489, 1469568725,
-                2364938114, 1157432499, 2372887700, 1043172544, 2250011461, 2085295087, 604304964,
-                340825308, 2422766482, 2410385883, 791761071, 2733359762, 1681282344, 1354240087,
-                1139722734, 1143256298, 2677141676, 664248460, 1670722978, 3073187653, 4102786625,
-                2618886769, 2531436405, 3579964409, 3950031759, 2248680433, 984112565, 3136159147,
-                3101912984, 1099692243, 2145841930, 1116532125, 4172191260, 1659871196, 1924994861,
-                3162922129, 2658827997, 1059354695, 486907692, 3395508395, 295826664, 2173667478,
-                3774792204, 1393285442, 1481797518, 3124974926, 3444355559, 76091140, 368283108,
-                4144769582, 1548649424, 2500545816, 2934156349, 324366676, 3648212298, 4072218004,
-                2165091138, 1096979546, 1763071718, 1899235026, 803276905, 1146994263, 2575402634,
-                2199211565, 757588391, 2673498553, 81023164, 439773287, 3406088797, 2333068608,
-                4043446581, 360179846, 2767375326, 69621430, 1199860390, 2642727874, 2693185292,
-                3218919242, 2999720961, 1196125438, 1368472471, 992806312, 3414591657, 1991386017,
-                1806201055, 3597622657, 998040031, 901561537, 3420061213, 1598740651, 3883097859,
-                2560593523, 2144383095, 2844056757, 689661366, 4046234614, 792024425, 2086938139,
-                1867234422, 1449908164, 4144743168, 1158803667, 2979094734, 1636380917, 2507967837,
-                1146823800, 4089295245, 3701313701, 3893526214, 1323911033, 3620380011, 3952758387,
-                3265334125, 2820495010, 2855022691, 1720959672, 825537074, 1695132633, 4264601047,
-                3483737293, 3618271080, 2259345874, 806986786, 3597560543, 3609564742, 3752395175,
-                1910432839, 2910423130, 2748643710, 2345098346, 3595323045, 1619954943, 4250836093,
-                2316914206, 3801483715, 3657868704,
-            ],
-            &[
-                3231068322, 2952358964, 2649323214, 3829109446, 3277451762, 1929830117, 128637820,
-                2648688236, 3506019657,
-            ],
-            true,
-            &[
-                1863226650, 1957700928, 2397867988, 3361691958, 4165763036, 2541448103, 3330236317,
-                390820454, 3199800095, 1741385769, 489727642, 3156882136, 3364833247, 2137995472,
-                49695877, 3008476841, 2862420416, 3492919918, 3413332847, 336822005, 1172092721,
-                3834063622, 387233639, 4033191572, 825059607, 1739762995, 2020314499, 3839219267,
-                693092469, 33950982, 1177221008, 3244207164, 2680526838, 4242912184, 2481840017,
-                3724363464, 1448349630, 2835676977, 2435088559, 2020244573, 2206043073, 1215858002,
-                3357751071, 3401291562, 3486448975, 1614914078, 3741841610, 3754003433, 545275396,
-                696866963, 2296823190, 2173589284, 3887309169, 2170555018, 2354335949, 1942858008,
-                2275759948, 238261999, 3409767723, 4050603972, 3841360830, 479906857, 3816482700,
-                3132890483, 1442647186, 2664711624, 3255895001, 2304652118, 490441941, 1384930190,
-                3781000441, 1197124175, 3673914796, 3264625683, 2934430644, 4012652010, 645313445,
-                1342417027, 4259899126, 127557767, 1863840635, 4240034833, 3380128902, 1721040466,
-                258484486, 2943320471, 4080044726, 1184529896, 50392490, 99485071, 2656469171,
-                394602583, 3236432821, 829680970, 4180640632, 216345893, 794871810, 776950374,
-                188852287, 2768051863, 1478264409, 3116222344, 2035264630, 3540143745, 3312627463,
-                1243839690, 4283661964, 2255627632, 2493155473, 3422881207, 672998229, 1330645402,
-                805858947, 3157155303, 2449760637, 541100830, 3959355989, 2578797546, 1805505456,
-                1105314669, 4044058628, 3236890928, 2881753558, 2935150784, 3619155497, 1247576722,
-                1057473446, 2602148123, 2591947096, 3098219463, 203480759, 3190196698, 1560456418,
-                1737612953, 1525519966, 1833713546, 2974486003, 3554849856, 1570515568, 3538788251,
-                3534319124, 3133957142, 2947537950, 1328568927, 2472554746, 3121690099, 3058658055,
-                2512921696, 4268236616, 507933028, 1384977378, 2914276309, 2170658818, 2676773599,
-                1344588011, 1406282402, 4240165134, 1774763910, 3592097532, 323634811, 4493032,
-                2026844160, 4259889667, 1561456992, 1745963453, 3318810240, 2613669069, 3295340381,
-                3322882413, 1172726818, 2542871057, 889925562, 2512140586, 1002695611, 3804029809,
-                510918143, 2428502323, 1435737298, 2312478558, 800761403, 822185524, 2215463403,
-                2284293602, 1855687935, 3929793519, 3135842877, 397151223, 819010071, 3143846345,
-                616813752, 2964423320, 2479441994, 2399498982, 3731231486, 1268178985, 637959829,
-                1437260196, 1762640607, 2763892037, 3152346415, 130897174, 150170711, 3043757213,
-                3263747790, 2268840104, 3897286925, 539186347, 2052996062, 427292218, 4071153750,
-                2387542695, 1403176825, 3054171069, 612471588, 1215312135, 1616958897, 1846418285,
-                1376844475, 1228696609, 534770698, 3860539144, 3746082038, 3223899771, 2595640187,
-                243793487, 698835349, 2226712063, 4113186925, 2899418198, 1544475879, 1323263527,
-                3387040179, 3921953055, 1816962743, 3835265005, 512922592, 4131079192, 3966796549,
-                586497526, 3324562547, 4163601002, 2280208374, 1649440850, 2888163653, 1932515002,
-                2915989787, 1835707588, 661236253, 1521623480, 1468090177, 62176243, 1761654778,
-                654002630, 3078945097, 2118465419, 622606572, 1872263161, 371147127, 2407796135,
-                1691024627, 1530874911, 2999266525, 3959644478, 2593752318, 673127524, 2516817005,
-                1435234490, 2950535738, 3814456529, 2728341971, 1618690437, 2649703585, 1797683058,
-                2532611783, 3170196918, 1687541847, 3276477161, 3728293877, 1601226381, 2623169918,
-                4277737387, 972038484, 3652503133, 1692819095, 4166924696, 679045210, 2147824742,
-                1754890006, 3603422769, 760272390, 2805004613, 1482946566, 2730421710, 638284193,
-                1248071107, 1757322486, 2253794113, 446496343, 3072090920, 2265361578, 1244157949,
-                703538048, 36778122, 2938030723, 4284977264, 1670946207, 3674544567, 3705855800,
-                1533888849, 2783879966, 1509595576, 2692068678, 4286514245, 931483302, 2846849819,
-                3750140783, 1937245748, 1537653123, 482103265, 2110925496, 3832643610, 1721135933,
-                1078480349, 2178800517, 768852883, 1630173117, 854612116, 4050668505, 3460165548,
-                3293238206, 4141790935, 764767464, 3763613607, 3433771841, 2639622, 654168294,
-                186019120,
-            ],
-        );
-        // highest_q third time
-        test(
-            &[10; 37],
-            &[
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                0x3ffffff,
                 0,
                 0,
                 0,
@@ -3174,6 +2838,454 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+            ],
+            &[Limb::MAX; 11],
+            true,
+            &[
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3182,6 +3294,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3190,6 +3305,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3198,6 +3316,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3206,6 +3327,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3214,6 +3338,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3222,6 +3349,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3230,6 +3360,9 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -3241,6 +3374,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3251,6 +3385,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3261,6 +3396,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3271,6 +3407,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3281,6 +3418,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3291,140 +3429,18 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
                 0,
                 0,
-                4294966784,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                0xfffffff,
                 0,
                 0,
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3435,6 +3451,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3445,6 +3462,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3455,6 +3473,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3465,6 +3484,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3475,6 +3495,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3485,6 +3506,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3495,6 +3517,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3505,6 +3528,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3515,6 +3539,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3525,6 +3550,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3535,6 +3561,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3545,6 +3572,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3555,6 +3583,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3565,6 +3594,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3575,6 +3605,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3585,6 +3616,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3595,6 +3627,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3605,6 +3638,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3615,6 +3649,7 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
@@ -3625,14 +3660,1063 @@ fn test_limbs_div_barrett() {
                 0,
                 0,
                 0,
+                1,
                 0,
                 0,
                 0,
                 0,
-                4261412864,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ],
+        );
+        test(
+            &[10, 10, 10],
+            &[
+                536870912,
+                4503599090491408,
+                31664835368329200,
+                18410716373991817215,
+                18157950747604419646,
+                2301339547025015295,
+            ],
+            &[18446744073172680704, 18442240474082189295, 339617773205455609160146383775169773568],
+            false,
+            &[
+                336440579615715900017785584626360548098,
+                47279954976728282481120912125081046379,
+                2305843009213431807,
+            ],
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_schoolbook_fail_1() {
+    let ds = &[3, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_schoolbook_fail_2() {
+    let ds = &[3, 4, 5, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_schoolbook_fail_3() {
+    let ds = &[3, 4, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3, 4, 5], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_schoolbook_fail_4() {
+    let ds = &[3, 4, 5];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_schoolbook(&mut [10], &mut [1, 2, 3], ds, d_inv);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_div_divide_and_conquer() {
+    let test = |qs_in: &[Limb], ns: &[Limb], ds: &[Limb], q_highest, qs_out: &[Limb]| {
+        let mut qs = qs_in.to_vec();
+        let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+        assert_eq!(
+            limbs_div_divide_and_conquer(&mut qs, ns, ds, d_inv),
+            q_highest
+        );
+        assert_eq!(qs, qs_out);
+        verify_limbs_div(qs_in, ns, ds, q_highest, &qs);
+    };
+    {
+        // *scratch_2_head != 0
+        test(
+            &[10; 4],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[3, 4, 5, 6, 7, 0x80000000],
+            false,
+            &[4294967057, 15, 18, 10],
+        );
+        // *scratch_2_head == 0
+        // !(highest_q &&
+        //      limbs_slice_add_same_length_in_place_left(&mut scratch_init[q_len..], ds)
+        //      || limbs_cmp_same_length(scratch_init, ns) == Ordering::Greater)
+        test(
+            &[10; 3],
+            &[0; 9],
+            &[0, 0, 0, 0, 0, 0x80000000],
+            false,
+            &[0, 0, 0],
+        );
+        test(
+            &[10; 3],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1],
+            &[0, 0, 0, 0, 0, 0x80000000],
+            false,
+            &[0, 0, 2],
+        );
+        // highest_q &&
+        //      limbs_slice_add_same_length_in_place_left(&mut scratch_init[q_len..], ds)
+        //      || limbs_cmp_same_length(scratch_init, ns) == Ordering::Greater
+        test(
+            &[10; 3],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 1, 0, 0, 0, 0x80000000],
+            false,
+            &[1, 0, 0],
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_divide_and_conquer_fail_1() {
+    let ds = &[3, 4, 5, 6, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_divide_and_conquer_fail_2() {
+    let ds = &[3, 4, 5, 6, 7, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_divide_and_conquer_fail_3() {
+    let ds = &[3, 4, 5, 6, 7, 0x80000000];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_divide_and_conquer(&mut [10, 10], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_divide_and_conquer_fail_4() {
+    let ds = &[3, 4, 5, 6, 7, 8];
+    let d_inv = limbs_two_limb_inverse_helper(ds[ds.len() - 1], ds[ds.len() - 2]);
+    limbs_div_divide_and_conquer(&mut [10; 4], &[1, 2, 3, 4, 5, 6, 7, 8, 9], ds, d_inv);
+}
+
+fn verify_limbs_div_approx(
+    qs_in: &[Limb],
+    ns_in: &[Limb],
+    ds: &[Limb],
+    q_highest: bool,
+    qs_out: &[Limb],
+) {
+    let n = Natural::from_limbs_asc(ns_in);
+    let d = Natural::from_limbs_asc(ds);
+    let (expected_q, expected_r) = (&n).div_mod(&d);
+    let base_q_len = ns_in.len() - ds.len();
+    let mut qs = qs_out[..base_q_len].to_vec();
+    if q_highest {
+        qs.push(1);
+    }
+    let q = Natural::from_owned_limbs_asc(qs);
+    let q_is_one_too_large = q != expected_q;
+    if q_is_one_too_large {
+        assert_eq!(q, expected_q + Natural::ONE);
+        assert_eq!((q - Natural::ONE) * &d + expected_r, n);
+    } else {
+        assert_eq!(q * &d + expected_r, n);
+    }
+    assert_eq!(&qs_in[base_q_len..], &qs_out[base_q_len..]);
+}
+
+#[test]
+fn test_limbs_div_barrett() {
+    let test = |qs_in: &[Limb], ns: &[Limb], ds: &[Limb], q_highest, qs_out: &[Limb]| {
+        let mut qs = qs_in.to_vec();
+        let mut scratch = vec![0; limbs_div_barrett_scratch_len(ns.len(), ds.len())];
+        assert_eq!(limbs_div_barrett(&mut qs, ns, ds, &mut scratch), q_highest);
+        assert_eq!(qs, qs_out);
+        verify_limbs_div(qs_in, ns, ds, q_highest, &qs);
+    };
+    #[cfg(feature = "32_bit_limbs")]
+    {
+        // q_len < d_len
+        // ghost_limb
+        // *scratch_2_head <= 6
+        // !highest_q third time
+        // !(highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..], ds)
+        //      || limbs_cmp_same_length(&mut rs, ns) == Ordering::Greater)
+        test(&[10; 3], &[1, 2, 3], &[0, 0x80000000], false, &[6, 10, 10]);
+        test(&[10; 3], &[1, 2, 3], &[3, 0x80000000], false, &[6, 10, 10]);
+        // q_len >= d_len
+        // !highest_q first time
+        // !_limbs_div_barrett_approx(&mut scratch_2, &mut rs, ds, scratch)
+        // *scratch_2_head > 4
+        test(
+            &[10; 3],
+            &[1, 2, 3, 4],
+            &[0, 0x80000000],
+            false,
+            &[6, 8, 10],
+        );
+        test(
+            &[10; 3],
+            &[1, 2, 3, 4],
+            &[3, 0x80000000],
+            false,
+            &[5, 8, 10],
+        );
+        // *scratch_2_head > 6
+        test(&[10; 3], &[0, 0, 1], &[0, 0x80000001], false, &[1, 10, 10]);
+        // !ghost_limb
+        test(
+            &[10; 3],
+            &[0, 0, 0, 0],
+            &[0, 0, 0x80000000],
+            false,
+            &[0, 10, 10],
+        );
+        // highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..], ds)
+        //      || limbs_cmp_same_length(&mut rs, ns) == Ordering::Greater
+        // !limbs_sub_limb_to_out(qs, &tp[1..q_len + 1], 1) second time
+        test(&[10; 3], &[0, 0, 1], &[1, 0x80000000], false, &[1, 10, 10]);
+        // *scratch_2_head <= 4
+        // !highest_q second time
+        // !(highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..d_len], ds)
+        //      || limbs_cmp_same_length(&rs[..n_len], ns) == Ordering::Greater)
+        test(
+            &[10; 3],
+            &[0, 0, 0, 0],
+            &[0, 0x80000000],
+            false,
+            &[0, 0, 10],
+        );
+        // highest_q && limbs_slice_add_same_length_in_place_left(&mut rs[q_len..d_len], ds)
+        //      || limbs_cmp_same_length(&rs[..n_len], ns) == Ordering::Greater
+        // !limbs_sub_limb_to_out(qs, &tp[1..q_len + 1], 1) first time
+        test(
+            &[10; 3],
+            &[0, 0, 1, 0],
+            &[1, 0x80000000],
+            false,
+            &[1, 0, 10],
+        );
+        // highest_q first time
+        test(
+            &[10; 337],
+            &[
+                2119562458, 822658147, 3797927043, 3236945010, 3998585665, 1799547707, 694717600,
+                81695911, 596552600, 1030767834, 2420116557, 2879241862, 4265568378, 644932084,
+                525117944, 1830433723, 3385005819, 1023897437, 3938627008, 3218345466, 471356423,
+                1008605584, 192751188, 2084112099, 2804133613, 3079231960, 3746102582, 3887941757,
+                2403297368, 3093735039, 2439864684, 2473555841, 2532233722, 2609655906, 3449112520,
+                2817574574, 1129609143, 821518979, 991339292, 2760112178, 890698714, 990807460,
+                2521797827, 3797612523, 1500537907, 3973412117, 2699035435, 2317986252, 2861160946,
+                2101011897, 1732640676, 3352339942, 3286471253, 1371519955, 1379608062, 2699834414,
+                3261360638, 226259522, 1897478770, 2788528264, 2309134747, 4024965405, 3976506219,
+                1035727997, 3131955545, 3667438412, 384232858, 2419576220, 793788636, 334784748,
+                3765760253, 1098631674, 1499085537, 2112219622, 1421552393, 1432628811, 643517209,
+                3632058889, 1632079643, 2223695587, 1476932543, 1960156116, 3436427017, 2201635037,
+                3182693886, 1548942116, 3157649082, 980646399, 831953531, 2763454966, 3961226196,
+                3582012549, 3882861773, 3003921500, 1404369615, 4000431109, 969558169, 660750652,
+                4122382799, 2357249946, 3678033512, 1327191217, 2116553842, 22168676, 1216607516,
+                2222670817, 4212062746, 3066154963, 4179712741, 406795945, 2599414815, 3647640166,
+                4071465241, 883313541, 1105574450, 866562291, 372730105, 970111908, 749995626,
+                1253456197, 2374658843, 3555086224, 3636919504, 488985805, 2204950393, 1262942086,
+                3204402881, 4275635994, 2310416697, 4135349793, 1226698657, 1188086923, 3222723934,
+                1042266586, 4047620517, 3948968819, 4123810886, 1610456917, 556933362, 4197028761,
+                59668868, 2993790244, 854419727, 933448524, 897309660, 3037260848, 1562257062,
+                4083457628, 981822256, 295330752, 3711901403, 1052560139, 3617703336, 262191843,
+                1142114318, 3848091155, 2086394563, 469188180, 3855914481, 154128290, 2738524940,
+                1460255288, 1285831461, 534017408, 3101261674, 118239159, 1944080872, 4203935546,
+                2653496603, 3202103001, 4264655348, 3809947358, 331166910, 4277075817, 1031408102,
+                866321189, 356095977, 1058770275, 3617580770, 2573338110, 1315597113, 1752964646,
+                2179362239, 2449996072, 1745253117, 85067425, 347176893, 240744367, 123940072,
+                3568364276, 1405760257, 2210945376, 2261469245, 2772215200, 746282058, 568635214,
+                1171903297, 1744293446, 1789334502, 1459265780, 2293069614, 319891368, 1979148180,
+                627405437, 1184813190, 3346831763, 1106015952, 940476403, 1182911489, 1469568725,
+                2364938114, 1157432499, 2372887700, 1043172544, 2250011461, 2085295087, 604304964,
+                340825308, 2422766482, 2410385883, 791761071, 2733359762, 1681282344, 1354240087,
+                1139722734, 1143256298, 2677141676, 664248460, 1670722978, 3073187653, 4102786625,
+                2618886769, 2531436405, 3579964409, 3950031759, 2248680433, 984112565, 3136159147,
+                3101912984, 1099692243, 2145841930, 1116532125, 4172191260, 1659871196, 1924994861,
+                3162922129, 2658827997, 1059354695, 486907692, 3395508395, 295826664, 2173667478,
+                3774792204, 1393285442, 1481797518, 3124974926, 3444355559, 76091140, 368283108,
+                4144769582, 1548649424, 2500545816, 2934156349, 324366676, 3648212298, 4072218004,
+                2165091138, 1096979546, 1763071718, 1899235026, 803276905, 1146994263, 2575402634,
+                2199211565, 757588391, 2673498553, 81023164, 439773287, 3406088797, 2333068608,
+                4043446581, 360179846, 2767375326, 69621430, 1199860390, 2642727874, 2693185292,
+                3218919242, 2999720961, 1196125438, 1368472471, 992806312, 3414591657, 1991386017,
+                1806201055, 3597622657, 998040031, 901561537, 3420061213, 1598740651, 3883097859,
+                2560593523, 2144383095, 2844056757, 689661366, 4046234614, 792024425, 2086938139,
+                1867234422, 1449908164, 4144743168, 1158803667, 2979094734, 1636380917, 2507967837,
+                1146823800, 4089295245, 3701313701, 3893526214, 1323911033, 3620380011, 3952758387,
+                3265334125, 2820495010, 2855022691, 1720959672, 825537074, 1695132633, 4264601047,
+                3483737293, 3618271080, 2259345874, 806986786, 3597560543, 3609564742, 3752395175,
+                1910432839, 2910423130, 2748643710, 2345098346, 3595323045, 1619954943, 4250836093,
+                2316914206, 3801483715, 3657868704,
+            ],
+            &[
+                3231068322, 2952358964, 2649323214, 3829109446, 3277451762, 1929830117, 128637820,
+                2648688236, 3506019657,
+            ],
+            true,
+            &[
+                1863226650, 1957700928, 2397867988, 3361691958, 4165763036, 2541448103, 3330236317,
+                390820454, 3199800095, 1741385769, 489727642, 3156882136, 3364833247, 2137995472,
+                49695877, 3008476841, 2862420416, 3492919918, 3413332847, 336822005, 1172092721,
+                3834063622, 387233639, 4033191572, 825059607, 1739762995, 2020314499, 3839219267,
+                693092469, 33950982, 1177221008, 3244207164, 2680526838, 4242912184, 2481840017,
+                3724363464, 1448349630, 2835676977, 2435088559, 2020244573, 2206043073, 1215858002,
+                3357751071, 3401291562, 3486448975, 1614914078, 3741841610, 3754003433, 545275396,
+                696866963, 2296823190, 2173589284, 3887309169, 2170555018, 2354335949, 1942858008,
+                2275759948, 238261999, 3409767723, 4050603972, 3841360830, 479906857, 3816482700,
+                3132890483, 1442647186, 2664711624, 3255895001, 2304652118, 490441941, 1384930190,
+                3781000441, 1197124175, 3673914796, 3264625683, 2934430644, 4012652010, 645313445,
+                1342417027, 4259899126, 127557767, 1863840635, 4240034833, 3380128902, 1721040466,
+                258484486, 2943320471, 4080044726, 1184529896, 50392490, 99485071, 2656469171,
+                394602583, 3236432821, 829680970, 4180640632, 216345893, 794871810, 776950374,
+                188852287, 2768051863, 1478264409, 3116222344, 2035264630, 3540143745, 3312627463,
+                1243839690, 4283661964, 2255627632, 2493155473, 3422881207, 672998229, 1330645402,
+                805858947, 3157155303, 2449760637, 541100830, 3959355989, 2578797546, 1805505456,
+                1105314669, 4044058628, 3236890928, 2881753558, 2935150784, 3619155497, 1247576722,
+                1057473446, 2602148123, 2591947096, 3098219463, 203480759, 3190196698, 1560456418,
+                1737612953, 1525519966, 1833713546, 2974486003, 3554849856, 1570515568, 3538788251,
+                3534319124, 3133957142, 2947537950, 1328568927, 2472554746, 3121690099, 3058658055,
+                2512921696, 4268236616, 507933028, 1384977378, 2914276309, 2170658818, 2676773599,
+                1344588011, 1406282402, 4240165134, 1774763910, 3592097532, 323634811, 4493032,
+                2026844160, 4259889667, 1561456992, 1745963453, 3318810240, 2613669069, 3295340381,
+                3322882413, 1172726818, 2542871057, 889925562, 2512140586, 1002695611, 3804029809,
+                510918143, 2428502323, 1435737298, 2312478558, 800761403, 822185524, 2215463403,
+                2284293602, 1855687935, 3929793519, 3135842877, 397151223, 819010071, 3143846345,
+                616813752, 2964423320, 2479441994, 2399498982, 3731231486, 1268178985, 637959829,
+                1437260196, 1762640607, 2763892037, 3152346415, 130897174, 150170711, 3043757213,
+                3263747790, 2268840104, 3897286925, 539186347, 2052996062, 427292218, 4071153750,
+                2387542695, 1403176825, 3054171069, 612471588, 1215312135, 1616958897, 1846418285,
+                1376844475, 1228696609, 534770698, 3860539144, 3746082038, 3223899771, 2595640187,
+                243793487, 698835349, 2226712063, 4113186925, 2899418198, 1544475879, 1323263527,
+                3387040179, 3921953055, 1816962743, 3835265005, 512922592, 4131079192, 3966796549,
+                586497526, 3324562547, 4163601002, 2280208374, 1649440850, 2888163653, 1932515002,
+                2915989787, 1835707588, 661236253, 1521623480, 1468090177, 62176243, 1761654778,
+                654002630, 3078945097, 2118465419, 622606572, 1872263161, 371147127, 2407796135,
+                1691024627, 1530874911, 2999266525, 3959644478, 2593752318, 673127524, 2516817005,
+                1435234490, 2950535738, 3814456529, 2728341971, 1618690437, 2649703585, 1797683058,
+                2532611783, 3170196918, 1687541847, 3276477161, 3728293877, 1601226381, 2623169918,
+                4277737387, 972038484, 3652503133, 1692819095, 4166924696, 679045210, 2147824742,
+                1754890006, 3603422769, 760272390, 2805004613, 1482946566, 2730421710, 638284193,
+                1248071107, 1757322486, 2253794113, 446496343, 3072090920, 2265361578, 1244157949,
+                703538048, 36778122, 2938030723, 4284977264, 1670946207, 3674544567, 3705855800,
+                1533888849, 2783879966, 1509595576, 2692068678, 4286514245, 931483302, 2846849819,
+                3750140783, 1937245748, 1537653123, 482103265, 2110925496, 3832643610, 1721135933,
+                1078480349, 2178800517, 768852883, 1630173117, 854612116, 4050668505, 3460165548,
+                3293238206, 4141790935, 764767464, 3763613607, 3433771841, 2639622, 654168294,
+                186019120,
+            ],
+        );
+        // highest_q third time
+        test(
+            &[10; 37],
+            &[
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                0x3ffffff,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                4294966784,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                0xfffffff,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                4261412864,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
                 u32::MAX,
                 u32::MAX,
                 u32::MAX,
@@ -6030,7 +7114,7 @@ fn test_limbs_div_barrett() {
             &[u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 3],
@@ -6040,6 +7124,16 @@ fn test_limbs_div_barrett() {
             &[1, 10, 10],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10, 10, 10],
+            &[0, 0, 1],
+            &[0, 170141183460469231750134047789593657344],
+            false,
+            &[1, 10, 10],
+        );
+    }
 }
 
 #[test]
@@ -8737,7 +9831,7 @@ fn test_limbs_div_schoolbook_approx() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -14175,7 +15269,7 @@ fn test_limbs_div_divide_and_conquer_approx() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -16068,7 +17162,7 @@ fn test_limbs_div_barrett_approx() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // highest_q first time in limbs_div_barrett_approx_preinverted
         // i_len >= MUL_TO_MULMOD_BNM1_FOR_2NXN_THRESHOLD in limbs_div_barrett_approx_preinverted
@@ -30164,7 +31258,7 @@ fn test_limbs_div() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 519],
@@ -30703,17 +31797,678 @@ fn test_limbs_div() {
                 u64::MAX,
                 u64::MAX,
                 u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+            ],
+            &[
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                u64::MAX,
+                u64::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                1,
+            ],
+        );
+        // r_len > n_len || limbs_cmp_same_length(ns, &rs[..n_len]) == Ordering::Less
+        //      in limbs_div_to_out_balanced
+        test(
+            &[10; 5],
+            &[
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709535232,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+            ],
+            &[
+                u64::MAX,
+                u64::MAX,
+                0xfffffff,
+                0,
+                0,
+                18446744073709550592,
+                u64::MAX,
+                0x1ffffffff,
+                0,
+                0,
+                0,
+                18446744073709551488,
                 u64::MAX,
                 u64::MAX,
                 u64::MAX,
             ],
+            &[127, 0, 0, 0, 1],
+        );
+        test(
+            &[10; 4],
             &[
-                u64::MAX,
+                18446463148488654849,
+                280925229285374,
+                16285016218270955520,
+                37348968499195,
+                18437754466102935552,
+                4286578943,
+            ],
+            &[280925220896767, 18446744073701163008, 34292631551],
+            &[u64::MAX, 0, 2305843009213431808, 0],
+        );
+    }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10; 519],
+            &[
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
                 0,
                 0,
                 0,
@@ -30723,8 +32478,452 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+            ],
+            &[Limb::MAX; 11],
+            &[
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30734,8 +32933,8 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30745,8 +32944,8 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30756,8 +32955,8 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30767,8 +32966,8 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30778,8 +32977,8 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -30789,8 +32988,19 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709027328,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                18446744073709027328,
+                Limb::MAX,
+                Limb::MAX,
                 0,
                 0,
                 0,
@@ -31234,8 +33444,6 @@ fn test_limbs_div() {
                 1,
             ],
         );
-        // r_len > n_len || limbs_cmp_same_length(ns, &rs[..n_len]) == Ordering::Less
-        //      in limbs_div_to_out_balanced
         test(
             &[10; 5],
             &[
@@ -31247,39 +33455,39 @@ fn test_limbs_div() {
                 0,
                 0,
                 18446744073709535232,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
             ],
             &[
-                u64::MAX,
-                u64::MAX,
-                0xfffffff,
+                Limb::MAX,
+                Limb::MAX,
+                268435455,
                 0,
                 0,
                 18446744073709550592,
-                u64::MAX,
-                0x1ffffffff,
+                Limb::MAX,
+                8589934591,
                 0,
                 0,
                 0,
                 18446744073709551488,
-                u64::MAX,
-                u64::MAX,
-                u64::MAX,
+                Limb::MAX,
+                Limb::MAX,
+                Limb::MAX,
             ],
-            &[127, 0, 0, 0, 1],
+            &[340282366920938463444927863358058659968, 0, 0, 0, 1],
         );
         test(
-            &[10; 4],
+            &[10, 10, 10, 10],
             &[
                 18446463148488654849,
                 280925229285374,
@@ -31289,7 +33497,12 @@ fn test_limbs_div() {
                 4286578943,
             ],
             &[280925220896767, 18446744073701163008, 34292631551],
-            &[u64::MAX, 0, 2305843009213431808, 0],
+            &[
+                251530991674133799684581077353428466016,
+                58191735463741122919212325959749010687,
+                42535295856434770093473379818836547005,
+                0,
+            ],
         );
     }
 }
@@ -31509,6 +33722,21 @@ fn test_div() {
         "11669177832462215441614364516705357863717491965951",
         "784637716923245892498679555408392159158150581185689944063"
     );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211457",
+    );
+    test(
+        "13407807929942597099574024998205846127479365820592393377723561443721764030073315392623399665776056285720014482370779510884422601683867654778417822746804225",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    );
+    test(
+        "2135987035920910082395021706169552114602704522356652769947041607822219725780640550022962086936581",
+        "340282366920938463463374607431768211456",
+        "6277101735386680763835789423207666416102355444464034512896",
+    );
 }
 
 #[test]
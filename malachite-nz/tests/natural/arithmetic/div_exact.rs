@@ -1827,7 +1827,7 @@ fn test_limbs_modular_div_mod_barrett() {
             ]),
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 595],
@@ -2081,6 +2081,337 @@ fn test_limbs_modular_div_mod_barrett() {
             ]),
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10; 595],
+            &[10; 731],
+            &rle_decode(&[
+                (Limb::MAX, 51),
+                (9007199254740991, 1),
+                (0, 346),
+                (18446603336221196288, 1),
+                (Limb::MAX, 39),
+                (127, 1),
+                (0, 170),
+                (18446743798831644672, 1),
+                (Limb::MAX, 380),
+                (8191, 1),
+                (0, 224),
+                (18446744073709551488, 1),
+                (Limb::MAX, 110),
+            ]),
+            &rle_decode(&[
+                (Limb::MAX, 183),
+                (1048575, 1),
+                (0, 4),
+                (18446744071562067968, 1),
+                (Limb::MAX, 206),
+                (134217727, 1),
+                (0, 130),
+                (18446744072635809792, 1),
+                (Limb::MAX, 204),
+            ]),
+            false,
+            &rle_decode(&[
+                (1, 1),
+                (0, 50),
+                (340282366920938463463365600232513470464, 1),
+                (Limb::MAX, 131),
+                (1048575, 1),
+                (0, 4),
+                (18446744071562067968, 1),
+                (Limb::MAX, 45),
+                (340282366920938454018641641692477784063, 1),
+                (Limb::MAX, 4),
+                (340116213421484691792375465616028467199, 1),
+                (9007199254740991, 1),
+                (0, 125),
+                (1099511627776, 1),
+                (0, 4),
+                (38685626223164533963227136, 1),
+                (340282366920938463463374607431766114304, 1),
+                (Limb::MAX, 3),
+                (340282366841710300953721955856651649023, 1),
+                (340282366920938463426481119288644075520, 1),
+                (0, 17),
+                (134217728, 1),
+                (0, 2),
+                (340282366920938463444928004095547015168, 1),
+                (0, 18),
+                (340282366911034943149091565232575217664, 1),
+                (Limb::MAX, 4),
+                (40564819207303340847894502572031, 1),
+                (18889465931478580853760, 1),
+                (0, 3),
+                (340240828546070184842346363461134450688, 1),
+                (332306998907543341989276432226844671, 1),
+                (340282366920938463463365600232513470464, 1),
+                (Limb::MAX, 8),
+                (340282366920938463463374607431768211327, 1),
+                (Limb::MAX, 7),
+                (340282366920937254537554992802593505279, 1),
+                (Limb::MAX, 79),
+                (18446744072635809791, 1),
+                (Limb::MAX, 22),
+                (1152921504606846975, 1),
+                (0, 4),
+                (60847228803871461547537286037504, 1),
+                (340282366920938463463374604133233328128, 1),
+                (Limb::MAX, 3),
+                (340033136671743298847040519158062120959, 1),
+                (340282366920822406584705113829881675775, 1),
+                (3145727, 1),
+                (0, 2),
+                (255211775180800327283247913374633164800, 1),
+                (237684487547404698792616787968, 1),
+                (55340232214686203901, 1),
+                (Limb::MAX, 10),
+                (340116213421475020385818548582630817791, 1),
+                (9288674231451647, 1),
+                (0, 2),
+                (340282366920919120797834725954649325568, 1),
+                (1048575, 1),
+                (4951760156565060347293073408, 1),
+                (340282366920938463463374607431499776000, 1),
+                (Limb::MAX, 1),
+                (2596188043046439491530387642908671, 1),
+                (36893347407783264255, 1),
+                (Limb::MAX, 7),
+            ]),
+            &rle_decode(&[
+                (1, 1),
+                (0, 4),
+                (10384593717069655257060992658440192, 1),
+                (0, 4),
+                (276479423123262501563991868538311671808, 1),
+                (340282366891227902520525480835799842815, 1),
+                (Limb::MAX, 2),
+                (18446743798831644671, 1),
+                (Limb::MAX, 1),
+                (121694457650244221434304309231999, 1),
+                (28334198897217871279104, 1),
+                (0, 3),
+                (41538374810250181693497169688657920, 1),
+                (498460498361315012970403849461301248, 1),
+                (340282366920938463463365600232513470464, 1),
+                (Limb::MAX, 2),
+                (134217727, 1),
+                (0, 4),
+                (2361183241159944699904, 1),
+                (340282366920938463463374607431768211328, 1),
+                (Limb::MAX, 1),
+                (2535301200456458802993406410751, 1),
+                (0, 4),
+                (340277174624079928635746076935438991360, 1),
+                (340282366920936045611735378173418930175, 1),
+                (Limb::MAX, 73),
+                (340282366920899777837149191097991299071, 1),
+                (2097151, 1),
+                (0, 3),
+                (118842243766784820371888537600, 1),
+                (73786976288395755518, 1),
+                (340282366920938463463374607431768211454, 1),
+                (Limb::MAX, 14),
+                (340282366920938463463374607431768211454, 1),
+                (340282366920937254537554992802593505279, 1),
+                (Limb::MAX, 4),
+                (255211775200607367911813997773019152383, 1),
+                (4611686018427387903, 1),
+                (0, 3),
+                (340282336497324057985868971510891282432, 1),
+                (243388915215485846183552074384895, 1),
+                (340282366920938463463374600834698444800, 1),
+                (Limb::MAX, 2),
+                (41538374868278621028243970633760767, 1),
+                (339285445924080433745609939667523731456, 1),
+                (340282366920706349706035620228001431551, 1),
+                (4194303, 1),
+                (0, 1),
+                (319014718988379809496913694467282698240, 1),
+                (118842243743726390281899081727, 1),
+                (475368975131702885719767777282, 1),
+                (73786976286248271866, 1),
+                (Limb::MAX, 4),
+                (340282346638528859811704183484516925439, 1),
+                (340282366920938444131186818184158118911, 1),
+                (Limb::MAX, 2),
+                (20282254861146759751412888895487, 1),
+                (41538374868278621028242871122132992, 1),
+                (339602176032530242872126050955294670848, 1),
+                (18858823439613951, 1),
+                (0, 1),
+                (340199290805027206335432820238852292608, 1),
+                (340282366920861092506071679943569309679, 1),
+                (31901471896981070890763299034381680639, 1),
+                (14855280469695181041476567040, 1),
+                (340282366920938463463374607431365558272, 1),
+                (85069942693127299012390085545900900351, 1),
+                (5192455314255407082315682328641536, 1),
+                (55340091479345332221, 1),
+                (Limb::MAX, 1),
+                (340282366920938463463374607431767162879, 1),
+                (Limb::MAX, 3),
+                (9007199254740991, 1),
+                (340282366920938463444927863360206143488, 1),
+                (0, 6),
+                (340282366920938463463356593033258729472, 1),
+                (Limb::MAX, 2),
+                (2475861189104829071217393663, 1),
+                (340282366920938463463374607431633993728, 1),
+                (Limb::MAX, 17),
+                (1329227995784915872903807060280344575, 1),
+                (0, 5),
+                (340282364385637263006915804575800754160, 1),
+                (Limb::MAX, 4),
+                (5192296859743753447863650527231999, 1),
+                (1208925819614629174575104, 1),
+                (0, 8),
+                (17179869184, 1),
+                (0, 7),
+                (162259276829213363391578010288128, 1),
+                (0, 50),
+                (340282346638528860992295804201928228864, 1),
+                (1099511627775, 1),
+                (0, 3),
+                (124615124600000159806273395202457600, 1),
+                (77371252448580867738042368, 1),
+                (340282366920938463463374607431766114304, 1),
+                (Limb::MAX, 2),
+                (170141183465420991888828824815480602623, 1),
+                (340282366722868057168490391416422268927, 1),
+                (340282366920938463408034375216008265730, 1),
+                (0, 9),
+                (340282366920938463463374607431767162880, 1),
+                (Limb::MAX, 4),
+                (340282366920938463444927863360206143487, 1),
+                (0, 1),
+                (340282366915986703306521316708323426304, 1),
+                (268435455, 1),
+                (0, 1),
+                (340279770752702306536721709613864452096, 1),
+                (340282366920938463426481260022911205376, 1),
+                (0, 17),
+                (329648542954659136480144150949525454848, 1),
+                (340282366910880200644180892698749698047, 1),
+                (Limb::MAX, 4),
+                (61797966780015649251144327233663, 1),
+                (19332187789247610091496, 1),
+                (0, 3),
+                (81129638366249648915707437783056384, 1),
+                (514037388944777513683068215618535424, 1),
+                (340282366920938463463365178020048404480, 1),
+                (Limb::MAX, 2),
+                (1329227995784915872903807060280344575, 1),
+                (308380895021481512494905238975944851456, 1),
+                (340282366913510823228527016911231254527, 1),
+                (134217727, 1),
+                (0, 1),
+                (2361183241297383653376, 1),
+                (9444732965739290427264, 1),
+                (0, 4),
+                (166153499453771670999141815739744256, 1),
+                (340282366920938463463365600232513470464, 1),
+                (340279770772509196049560342183603601407, 1),
+                (340282366920936007832803515216257220607, 1),
+                (Limb::MAX, 2),
+                (2596128622226785248180849778622463, 1),
+                (340282366920938463463374466694279856128, 1),
+                (339617752923123376779378040168809234431, 1),
+                (36028797018963967, 1),
+                (0, 1),
+                (334965495502618007275100227085149405184, 1),
+                (340282366915986722195987248187038497791, 1),
+                (134217727, 1),
+                (0, 6),
+                (340282366920938463463374607431634001920, 1),
+                (Limb::MAX, 61),
+                (39614081255979247292165128191, 1),
+                (36893488145271619583, 1),
+                (Limb::MAX, 21),
+                (319014718989617749536199074742181822463, 1),
+                (1152921504606846975, 1),
+                (0, 3),
+                (340282359315034862093998198451548979200, 1),
+                (121694457611284697953928271102527, 1),
+                (340282366920938463463374604133233328128, 1),
+                (Limb::MAX, 2),
+                (15576890575604482885591488987660287, 1),
+                (339659291297885269928297085270669918208, 1),
+                (340282366920764378145366989329222598655, 1),
+                (3145727, 1),
+                (0, 1),
+                (329648542954659136480144150949525454848, 1),
+                (85070591844125099459346050067080413183, 1),
+                (415947853252922161555472121857, 1),
+                (73786976287322013690, 1),
+                (Limb::MAX, 3),
+                (340282366920938463463374606332256583679, 1),
+                (Limb::MAX, 4),
+                (340282366920899777837151442897804984319, 1),
+                (10384593717069655257060992660537343, 1),
+                (339944867625153344712097870093711572992, 1),
+                (9288674231451647, 1),
+                (0, 1),
+                (340261597971188811691041812208655794176, 1),
+                (340282366920899778021615505930886053878, 1),
+                (15950735948800020455202994585915621374, 1),
+                (9903520313418351070469423104, 1),
+                (340282366920938463463374607431499776000, 1),
+                (42534971346563649506195042772950450175, 1),
+                (5192415700325267983441724490932224, 1),
+                (55340091480419074045, 1),
+                (Limb::MAX, 6),
+                (340282366920938463444927863359132401663, 1),
+                (0, 4),
+                (340282366920938463463374607431633993728, 1),
+                (Limb::MAX, 2),
+                (18446603336221196287, 1),
+                (Limb::MAX, 18),
+                (9903520314283042199192993791, 1),
+                (0, 4),
+                (340282326356119256160033759537265639424, 1),
+                (340282366920938444573908675953187357695, 1),
+                (Limb::MAX, 3),
+                (41538374868278621028243970633760767, 1),
+                (339950059922030920121385330999541366784, 1),
+                (9007199254740991, 1),
+                (0, 8),
+                (128, 1),
+                (0, 7),
+                (1208925819614629174706176, 1),
+                (0, 37),
+                (18446744073709551488, 1),
+                (Limb::MAX, 41),
+                (340282366920938463444927863359132401663, 1),
+                (0, 22),
+                (340282366920938463462221685927161364480, 1),
+                (Limb::MAX, 4),
+                (340282306073709659591913059894482173951, 1),
+                (3298534883327, 1),
+                (0, 3),
+                (249230249195164616334088273706090496, 1),
+                (116056878669493601886535680, 1),
+                (340282366920938463463374607431765065728, 1),
+                (Limb::MAX, 2),
+                (85070591740138136180126694057135046655, 1),
+                (340282366683253975915969908639151423487, 1),
+                (340282366920938463408034375217082007554, 1),
+                (0, 10),
+                (166153499463443077556058849137393664, 1),
+                (340282366920938463463365318757536759808, 1),
+                (Limb::MAX, 2),
+                (19342665539881477118885887, 1),
+                (340282366920938463463374607431767162880, 1),
+                (340282366915986703306809547084475138047, 1),
+                (268435455, 1),
+                (0, 1),
+                (340279770732895417023883077044125302784, 1),
+                (340282366920938463426481260023984947200, 1),
+                (0, 7),
+            ]),
+        );
+    }
 }
 
 #[cfg(feature = "32_bit_limbs")]
@@ -2200,7 +2531,7 @@ fn test_limbs_modular_div_schoolbook() {
             &[u32::MAX, 0xfffffffc, 0xfffffffd],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 3],
@@ -2209,6 +2540,15 @@ fn test_limbs_modular_div_schoolbook() {
             &[u64::MAX, u64::MAX, 0xfffffffffffffffc],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10, 10, 10],
+            &[1, 2, 3],
+            &[1, 2],
+            &[Limb::MAX, Limb::MAX, 340282366920938463463374607431768211452],
+        );
+    }
 }
 
 #[cfg(feature = "32_bit_limbs")]
@@ -681,7 +681,7 @@ fn test_limbs_div_mod_schoolbook() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 17],
@@ -778,6 +778,103 @@ fn test_limbs_div_mod_schoolbook() {
             ],
         )
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10; 17],
+            &[
+                9995257893114397114,
+                9401504468144459131,
+                558615837638945228,
+                10733662027974786928,
+                18295107704289976677,
+                1814706268673753787,
+                12474943759854623335,
+                8814778832826774208,
+                9159057654048965906,
+                4451260977376821357,
+                18241701617364042056,
+                6169989192350218482,
+                15071965537117101028,
+                13509168527678537782,
+                12224278653171635329,
+                16077066393714953826,
+                1433938684868066489,
+                13014970036232570373,
+                899282336249563956,
+                3089487642230339536,
+                3787737519477527148,
+                16667686214395942740,
+                8787122953224574943,
+                7841835218775877827,
+                9693303502025838409,
+                16122224776459879427,
+                144327425397945219,
+            ],
+            &[
+                2350654041004706911,
+                7834348511584604247,
+                12756796070221345724,
+                3842923787777653903,
+                12373799197090248752,
+                9712029403347085570,
+                1426676505264168302,
+                10586232903332693517,
+                8387833601131974459,
+                6290888746273553243,
+                175317296821820190316687158184603811840,
+            ],
+            false,
+            &[
+                250437435732889861063298600850716558894,
+                192627785820741166509281253315559408282,
+                224683365910207137219371281523876603604,
+                105475527285496480444799018247255080698,
+                326287371465947688992637535580520779359,
+                296284215973009577257100039625640327385,
+                180352130176139072568210835369904210297,
+                314275255033267404459025413370032158157,
+                250951268128481706807033377431019523121,
+                328181040709739707344317533622044150566,
+                57334011215850138058061866423202800986,
+                186416976177871502287248219838933836941,
+                218763340876028729219426013899513453155,
+                38848671495889933006844090455962142308,
+                323368288844042753146740905455405285357,
+                280132530083052380,
+                10,
+            ],
+            &[
+                306702114188089112885788492317711093416,
+                319325021304293184460903862499084935806,
+                176122800227916446921037970412814295681,
+                194608905964421815127914034324245244975,
+                208437470459307973286775856547348782152,
+                285645650640872960319976303114103044572,
+                59025998215301350606416061393503145867,
+                263213226208096628611269200598116077399,
+                275335393698802960332665627410579878572,
+                45936137646432451427938903742720378559,
+                167016278915157966723267560593578938942,
+                306895979477655927640411528182858837709,
+                120617240194740752241415238318429777420,
+                56808138638469884630283595843518574520,
+                274979447605680962472774130907988066340,
+                273023579591535808024811953964155640445,
+                113305374819878970751273782183830880272,
+                311271374534882287131774612130999078754,
+                88738470104497194389464910485292225149,
+                160463155373579570138834138738184434644,
+                296535202197989055465396282625128697142,
+                13404301837753587040023501214565734856,
+                297256513793287312758496005564004411880,
+                313185587628812714275637234820700265067,
+                338520084339973851648323381415906181524,
+                16122224776459879427,
+                144327425397945219,
+            ],
+        )
+    }
 }
 
 #[test]
@@ -2849,7 +2946,7 @@ fn test_limbs_invert_newton_approx() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // d_len >= INV_MULMOD_BNM1_THRESHOLD
         // !condition
@@ -7270,7 +7367,7 @@ fn test_limbs_div_mod_barrett() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -7968,211 +8065,909 @@ fn test_limbs_div_mod_barrett() {
             &[0, 0],
         );
     }
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_mod_barrett_fail_1() {
-    let ns = &[1, 2, 3];
-    let ds = &[0x80000000];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_mod_barrett_fail_2() {
-    let ns = &[1, 2];
-    let ds = &[1, 0x80000000];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_mod_barrett_fail_3() {
-    let ns = &[1, 2, 3, 4];
-    let ds = &[1, 0x80000000];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_div_mod_barrett(&mut [10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-#[should_panic]
-fn limbs_div_mod_barrett_fail_4() {
-    let ns = &[1, 2, 3];
-    let ds = &[1, 2];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-fn verify_limbs_div_mod_3(
-    qs_in: &[Limb],
-    rs_in: &[Limb],
-    ns: &[Limb],
-    ds: &[Limb],
-    qs_out: &[Limb],
-    rs_out: &[Limb],
-) {
-    let n = Natural::from_limbs_asc(ns);
-    let d = Natural::from_limbs_asc(ds);
-    let d_len = ds.len();
-    let (expected_q, expected_r) = (&n).div_mod(&d);
-    let base_q_len = ns.len() - d_len + 1;
-    let qs = qs_out[..base_q_len].to_vec();
-    let q = Natural::from_owned_limbs_asc(qs);
-    let r = Natural::from_limbs_asc(&rs_out[..d_len]);
-    assert_eq!(q, expected_q);
-    assert_eq!(r, expected_r);
-    assert_eq!(&qs_in[base_q_len..], &qs_out[base_q_len..]);
-    assert_eq!(&rs_in[d_len..], &rs_out[d_len..]);
-    assert!(r < d);
-    assert_eq!(q * d + r, n);
-}
-
-#[test]
-fn test_limbs_div_mod() {
-    let test = |qs_in: &[Limb],
-                rs_in: &[Limb],
-                ns: &[Limb],
-                ds: &[Limb],
-                qs_out: &[Limb],
-                rs_out: &[Limb]| {
-        let mut qs = qs_in.to_vec();
-        let mut rs = rs_in.to_vec();
-        limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
-        assert_eq!(qs, qs_out);
-        assert_eq!(rs, rs_out);
-
-        let (qs, rs) = limbs_div_mod(ns, ds);
-        let d_len = ds.len();
-        let qs_limit = ns.len() - d_len + 1;
-        assert_eq!(qs, &qs_out[..qs_limit]);
-        assert_eq!(&qs_in[qs_limit..], &qs_out[qs_limit..]);
-        let rs_limit = d_len;
-        assert_eq!(rs, &rs_out[..rs_limit]);
-        assert_eq!(&rs_in[rs_limit..], &rs_out[rs_limit..]);
-
-        verify_limbs_div_mod_3(qs_in, rs_in, ns, ds, qs_out, rs_out);
-    };
-    #[cfg(feature = "32_bit_limbs")]
+    #[cfg(feature = "128_bit_limbs")]
     {
-        let test_only_verify = |qs_in: &[Limb], rs_in: &[Limb], ns: &[Limb], ds: &[Limb]| {
-            let mut qs = qs_in.to_vec();
-            let mut rs = rs_in.to_vec();
-            limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
-            let qs_out_alt = qs;
-            let rs_out_alt = rs;
-
-            let (qs, rs) = limbs_div_mod(ns, ds);
-            let d_len = ds.len();
-            let qs_limit = ns.len() - d_len + 1;
-            let mut qs_out = qs_in.to_vec();
-            qs_out[..qs_limit].copy_from_slice(&qs);
-            let rs_limit = d_len;
-            let mut rs_out = rs_in.to_vec();
-            rs_out[..rs_limit].copy_from_slice(&rs);
-
-            assert_eq!(qs_out, qs_out_alt);
-            assert_eq!(rs_out, rs_out_alt);
-            verify_limbs_div_mod_3(qs_in, rs_in, ns, ds, &qs_out, &rs_out);
-        };
-        // d_len == 2
-        // bits != 0 in limbs_div_mod_by_two_limb
-        // carry == 0 in limbs_div_mod_by_two_limb
-        test(
-            &[10; 4],
-            &[10; 4],
-            &[1, 2],
-            &[3, 4],
-            &[0, 10, 10, 10],
-            &[1, 2, 10, 10],
-        );
-        test(
-            &[10; 4],
-            &[10; 4],
-            &[1, 2, 3],
-            &[4, 5],
-            &[2576980377, 0, 10, 10],
-            &[2576980381, 2, 10, 10],
-        );
-        // bits == 0 in limbs_div_mod_by_two_limb
-        test(
-            &[10; 4],
-            &[10; 4],
-            &[1, 2, 3],
-            &[4, 0x80000000],
-            &[6, 0, 10, 10],
-            &[4294967273, 1, 10, 10],
-        );
-        // adjusted_n_len < 2 * d_len
-        // q_len == 0 in limbs_div_mod_balanced
-        test(&[10], &[10; 3], &[0; 3], &[0, 0, 1], &[0], &[0; 3]);
-        // _q_len > 0 in limbs_div_mod_balanced
-        // adjust in limbs_div_mod_balanced
-        // q_len == 0 in limbs_div_mod_balanced
-        // i_len >= 2 in limbs_div_mod_balanced
-        // ns_shifted[q_len - 1] >=
-        //      (DoubleLimb::from(x) * DoubleLimb::from(qs[q_len - 1])).upper_half()
-        //      in limbs_div_mod_balanced
-        // bits != 0 limbs_div_mod_balanced
-        // q_len == r_len in limbs_div_mod_balanced
-        // do_extra_cleanup in limbs_div_mod_balanced
-        // !quotient_too_large in limbs_div_mod_balanced
-        test(&[10], &[10; 3], &[0, 0, 1], &[0, 0, 1], &[1], &[0; 3]);
-        // !adjust in limbs_div_mod_balanced
-        test(&[10, 10], &[10; 3], &[0; 4], &[0, 0, 1], &[0, 0], &[0; 3]);
-        // quotient_too_large in limbs_div_mod_balanced
-        test(&[10], &[10; 3], &[0, 0, 1], &[0, 1, 1], &[0], &[0, 0, 1]);
-        // bits != 0 in limbs_div_mod_unbalanced
-        // bits != 0 and Schoolbook condition in limbs_div_mod_unbalanced
         test(
-            &[10; 264],
-            &[10; 30],
-            &[
-                3099176493, 2686179191, 2963763290, 1498905807, 2459138342, 883505904, 186294937,
-                1240988195, 1152000807, 3485254691, 3058980612, 4051918579, 84687528, 3474110821,
-                825051253, 2495113655, 4269053297, 531158278, 2131423786, 735959410, 1116746011,
-                2099249419, 427789428, 2182383446, 3493666451, 2086611578, 2349523902, 1865831092,
-                104597580, 720145483, 1653677313, 2449086005, 1225118075, 1458687427, 2284889737,
-                1178000580, 1204354477, 1109887135, 2302764777, 1833071533, 2749496868, 3989183692,
-                3112262804, 2143914125, 71393498, 3686808425, 568295620, 856601035, 205234115,
-                617861388, 102368116, 561132401, 733711249, 2931194848, 1371887453, 2942553563,
-                977874680, 3567773306, 2225947571, 668306082, 3150218776, 3697051793, 4272816685,
-                2926797142, 2177250555, 2840203688, 51052169, 1663516163, 3085068676, 471736374,
-                3127282104, 1358199856, 250868071, 54398826, 2107868776, 1621910930, 1677400830,
-                20889047, 4180454819, 908186988, 1625301399, 817226551, 4039437379, 261787419,
-                1484678288, 1183594253, 3154399910, 888455895, 1713982625, 2925178936, 2076694919,
-                2288237521, 620657938, 2457761902, 2906110636, 649773865, 1387981473, 1600821385,
-                3512543520, 2803028516, 1584293468, 1443490691, 2589741655, 3170284085, 1902835632,
-                1603116322, 2850443356, 60694796, 779743237, 4006039758, 3272304347, 2442366350,
-                3601524312, 720808564, 3291979084, 1675880280, 4139440559, 3672978467, 3938651783,
-                3257918674, 2077369764, 3027135446, 3940308694, 2533432781, 4105883289, 626796131,
-                608794713, 810463080, 2363401530, 2800065942, 2679046183, 4063827982, 2327835929,
-                522607612, 1660561124, 817356463, 2984129158, 146372510, 4293731514, 2558750639,
-                3948304613, 1448810261, 826590770, 2423032883, 1050524737, 2770168039, 265887860,
-                3353243705, 2674401993, 1496121248, 2053790031, 1863298286, 1068977659, 2875518960,
-                4137037281, 2888534695, 473250405, 3368783492, 3797281057, 132028186, 316202261,
-                381223941, 3366394660, 425124513, 4083952857, 2301832392, 3321645917, 509444341,
-                361894284, 3976042471, 2957832871, 250836826, 1408114735, 1614725824, 4214313885,
-                4081964203, 2663894263, 145380453, 2329325761, 2055092903, 126874014, 1000460254,
-                3910319503, 2561073113, 4231784000, 1042785346, 3511487116, 1270832223, 3456586087,
-                1210528079, 4119713948, 3299598065, 4178946856, 1623358249, 1001721969, 3964704699,
-                4133343553, 2193492316, 407893767, 2484237727, 3782842008, 2959959832, 449539588,
-                2978317444, 4008576738, 3478248618, 98697842, 3986898689, 1622966191, 3594042026,
-                3280563264, 270713233, 3999415188, 401848066, 623805281, 1710411200, 1801291443,
-                640646012, 2283134929, 1043531051,
-            ],
-            &[
-                3120245340, 1558358768, 2311090615, 3377497278, 2047683134, 1453801550, 1974940808,
-                1545066418, 2840151982, 2463969682, 2025473894, 697610529, 906262319,
-            ],
             &[
-                3603474202, 23640019, 2133576502, 3778379659, 883962915, 1866519837, 1888919496,
-                2545522706, 1473641504, 1017307414, 1207107908, 43613763, 2001107652, 1295816376,
-                3276720994, 750683734, 862054731, 2423105118, 647184565, 82749472, 3134905644,
-                3469243304, 3869523273, 2200822282, 2271529456, 1805512365, 576197860, 1881352922,
-                2694444088, 700188823, 4014261501, 998600230, 3921476919, 3042342458, 1113168589,
+                9467139676469209549,
+                590281264913108801,
+                2444597864339828042,
+                14864081623018167053,
+                17247631583397892818,
+                14553244820490552393,
+                12829855212365838534,
+                3531466761266135925,
+                11372171159031384620,
+                5870847048926628622,
+                9193240591510160396,
+                4025304033900694645,
+                17261252553070218810,
+                1960954339685170046,
+                9689788042403522315,
+                6777781555383721706,
+                564338697137211139,
+                2236230241770389317,
+                7260612298284603217,
+                7551831827404764167,
+                14240930170717885933,
+                5403113159439788814,
+                11890192816726889700,
+                4571074562843162754,
+                6585174915018781919,
+                16820621164336832115,
+                2906428786044704407,
+                14549789283221663094,
+                8574533295629171239,
+                2554371108614275803,
+                12102140726062325305,
+                5927759880327312421,
+                1947106727211751169,
+                4243634376196439953,
+                3958901784387088795,
+                11997496505606868854,
+                8614259227764305665,
+                2851316343853334425,
+                7360014330841540867,
+                12833167596911644967,
+                16420023392297553774,
+                448987443751486030,
+                1127916262354476550,
+                998365641524414747,
+                771182118275428982,
+                3644906482543632509,
+                12008471662501141232,
+                6735530311778854203,
+                14937996347189247818,
+                10310266533399488799,
+                14173112110010990829,
+                10048196983951682150,
+                7660343487007291390,
+                5442254482173747309,
+                11894682918116055490,
+                555474340707057713,
+                5454210195350763050,
+                6335921756850263208,
+                11125242160747149811,
+                5950900297278200050,
+                4972887779003626968,
+                15099558014605218082,
+                8070946285642924813,
+                5661924633602804698,
+                4017981546647826153,
+                3298784914124356106,
+                1048634509662954303,
+            ],
+            &[
+                1601810336850541391,
+                6414377018375174391,
+                919960012376992260,
+                7432535796251013733,
+                14687204344100976419,
+                4239980047441451729,
+                3419961342059496953,
+                16241811836659790563,
+                582511433240649668,
+                246415310737356671,
+                4213289580820322712,
+                6570486144808547472,
+                649071692625916450,
+                16246395526862594705,
+                5418002168615819323,
+                8787804720479728592,
+                16606257073152622475,
+                1410262373214068670,
+                2846556520446018460,
+                12702224195603835801,
+                14824788470038136235,
+                8070823597295684045,
+                10064047777457825217,
+                12473928276513608352,
+                4502242984490214594,
+                8213242804638977717,
+                11407293275124988149,
+                15303970978422100227,
+                5734015081210218763,
+                6780255848655226189,
+                5308681743121306497,
+                3590094480707706491,
+                924657275311967241,
+                13313245703725306506,
+                13755314570936606477,
+                1722612846595274627,
+                11310713460998976321,
+                17860713973992950196,
+                4832972642835413575,
+                6159638888337886550,
+                16436259186156623772,
+                8219825892743153908,
+                12825816757113080127,
+                17576561025816321316,
+                16426316569762062979,
+                7041069634919241163,
+                14958139263401063299,
+                8541928253694453091,
+                14623281385826596559,
+                5881920493887697974,
+                2856125878289959548,
+                13138961086309035424,
+                1445182795958550281,
+                989371283719803801,
+                14414401959190662319,
+                12694741659414145902,
+                4944169755440522083,
+                4850330269090259826,
+                7263731888738583042,
+                3847985956261979907,
+                10886313908655224591,
+                16884924304355496352,
+                17434369248195376612,
+                9624045826924140661,
+                9440358242723121635,
+                44413621672380228,
+                14501853932445164832,
+                4412509400967439905,
+                4059150452772511000,
+                14121559478607349396,
+                6939247432141568945,
+                9144772592231287902,
+                9035665952014395555,
+                14897716038428818227,
+                15076823799682351749,
+                16277606343067785741,
+                1901372957955104636,
+                9089098420745572372,
+                7424125507905187829,
+                12368330388186365917,
+                12778631331588513256,
+                7648700244963873530,
+                3776028467270636181,
+                16107369772612157292,
+                9046543866366629415,
+                13251838453492673272,
+                2975138303374961664,
+                2462966081502544324,
+                814917744063498594,
+                13005222969141014201,
+                5026855542811222762,
+                2382391535955253463,
+                5899528506680208740,
+                13336141787604226636,
+                2972106516856532181,
+                14045206722662122716,
+                13361481863470745757,
+                1303122885509130861,
+                2127042842590220699,
+                6185514996199626930,
+                16622745329653187578,
+                1256533115604594696,
+                11243434057073050028,
+                9066405370642929758,
+                4552716624777987017,
+                1128092841571320177,
+                3756894170446405064,
+                3328979948330012406,
+                15049042231988504879,
+                3530903968533512703,
+                17905884378428276079,
+                3713606437359152813,
+                9370125751473669209,
+                10012381228375856292,
+                6916709082927033810,
+                17606888822444197359,
+                10818646898854586102,
+                9927520028322801489,
+                17015575684851383261,
+                15287563104761130407,
+                4246630569287612899,
+                6737522138244324583,
+                16956805675707509108,
+                10507692348129622148,
+                1144006384258648355,
+                4260138925314579646,
+                14508270375905482517,
+                3559046762122289814,
+                15199903498605585717,
+                16432575538338639756,
+            ],
+            &[
+                5592822181652430458,
+                17506309594623475426,
+                14050063092843436505,
+                7714948654849123602,
+                12960251261153655218,
+                12293354553127881932,
+                18236552269356335752,
+                8116592444680865353,
+                5788148289215893900,
+                8971586422059718981,
+                7830225577778842728,
+                16542468928391674395,
+                7685364916096821326,
+                10621040870461365797,
+                13017786142160529410,
+                15623634767986429431,
+                3570280230795725028,
+                5462496964935066825,
+                13096782281304038571,
+                1028538565298248606,
+                1306240401949593430,
+                9752885768751332733,
+                16834186448734276157,
+                7637206024963845142,
+                14347989823115406647,
+                11694534965309339857,
+                17506164867360865472,
+                18226657528377127028,
+                17113377911080849277,
+                12836900580850373696,
+                7624296086468816307,
+                3241150049341673184,
+                11432625191496196476,
+                7201112746916642381,
+                14159401858377741999,
+                13042389876849434472,
+                6539044773109830458,
+                5626612565019858620,
+                16855355146181557220,
+                2896027096805100269,
+                1749121842377065409,
+                14486576285464434664,
+                13003134176160772443,
+                4520736241988979615,
+                14386190542886985088,
+                5203873393963986621,
+                2767011034432552285,
+                6993214728799607735,
+                16219726698174042484,
+                12883752315794694947,
+                4638588568194864850,
+                13671093412063473486,
+                16507766724668892429,
+                508640878850638089,
+                10821378407060575406,
+                7345992160360704865,
+                10043908069076742056,
+                18320857698273010360,
+                2218460838923946432,
+                12515547441597943704,
+                8373193888106171069,
+                11138535537637434655,
+                2449630875466873477,
+                8767699022869197665,
+                6446940169111329267,
+                12481583138381941451,
+                18405856492243480913,
+                8856983257605794349,
+                11977198292403090397,
+                4992072464455161558,
+                1429124422280954077,
+                16695629052720642207,
+                15320583853113777709,
+                9270873446273846783,
+                9544568698292818385,
+                16150036388405871492,
+                4707553634577955164,
+                12819651092171657742,
+                6194167822526464289,
+                3302239600303656809,
+                13520149877311082646,
+                3658928982143500918,
+                6938149248133961941,
+                3561482775788486836,
+                11608588618646326460,
+                9312042305619109305,
+                7752033026671842884,
+                17638989744464665362,
+                11720586895422616384,
+                17488161719714908794,
+                14359533380966089634,
+                9112047402314539693,
+                9045007769242360827,
+                14039981061261051366,
+                12161985564133395359,
+                12552005864162233454,
+                13987473146854769743,
+                18218863257920884509,
+                527093394921779615,
+                10809320813728048297,
+                9176568449151735783,
+                16181777654712188911,
+                3708863559833042684,
+                15705373009786311560,
+                12044345266545331965,
+                15745097802473032619,
+                3448641903062070968,
+                7079863925626111888,
+                10936059842602692227,
+                3949990826310278419,
+                8781569794504977516,
+                2710649351206510739,
+                8045244454460377457,
+                16793164706992819994,
+                14171071447733414359,
+                6442975288102390424,
+                13408292761208182437,
+                18190891873633561623,
+                14416452326133420106,
+                11467995109126255854,
+                10355921853391241663,
+                3362337025912778005,
+                12828210017750944427,
+                6724529289281531448,
+                17910365619735459537,
+                18061583666579344364,
+                7385510916096038449,
+                14735084634699542626,
+                2983574817812405387,
+                1160726944282883582,
+                2119364765206141036,
+                6464791768964988391,
+                8824016806539484116,
+                8270030586906660422,
+                15002907845345901548,
+                5776511587881140498,
+                8026215921305305807,
+                4715947225014261487,
+                1386404302622265399,
+                155879991043344208,
+                7029054386547293524,
+                11220489073691424833,
+                2733588115435171341,
+                5063187558440228172,
+                14208382232307280669,
+                3376720278729662133,
+                14690710213721258877,
+                11094816467682244196,
+                13929756812886477086,
+                14698847015580325706,
+                15992372099283576304,
+                5818288394714584003,
+                5038324365106419021,
+                506432342213631013,
+                7781480413773762085,
+                2275557418573742756,
+                8703301345083257514,
+                11424227525030059244,
+                8529590924998378026,
+                5577211436885609271,
+                16859602499387351883,
+                5848360527348246100,
+                2118117857825986323,
+                3178709503818913442,
+                14068618324826971348,
+                7820101807986528976,
+                16849757836429474618,
+            ],
+            &[
+                10900792384749518304,
+                1752565570529908396,
+                3402229115647561238,
+                2472381872242532960,
+                15748089475115162936,
+                1958855681762413475,
+                12100416912810779188,
+                12256578057348862042,
+                6556831077371185734,
+                15914846823057329492,
+                17346954154793811255,
+                17566187606614467459,
+                1433606366066775495,
+                9089332045922722756,
+                10056944581186126460,
+                5324425019386643029,
+                5281765195814058625,
+                1449711238109407238,
+                5903959110668039125,
+                3336955200427408551,
+                751494194154096512,
+                15350321905800137137,
+                12407374450431165353,
+                8705815621686854350,
+                18038286270431178148,
+                11671842546699641930,
+                9343865367071815679,
+                13401838367914321671,
+                18365991333043790435,
+                17428290065100096976,
+                6040216493892400727,
+                4224515713015397505,
+                16578741590625036060,
+                11835373548777581169,
+                18413478850867685366,
+                8453265724386285209,
+                5394500720474148965,
+                1927463313122594080,
+                4177838821929605731,
+                10680620304882583021,
+                180005403771618203,
+                2256408572502279608,
+                11718426532525535626,
+                14260315287467647015,
+                4035759666841010016,
+                16259497729863184485,
+                7772704202422133476,
+                6815813069474359325,
+                11207378575459431371,
+                18308033894506293455,
+                9875145231436590806,
+                15354934628544213986,
+                761822562304640527,
+                7391550101325083295,
+                4023926600201752832,
+                922969942182092752,
+                12110946035817932140,
+                16574399923422896843,
+                7087993004495856759,
+                8299428112066197874,
+                4589434828506762129,
+                13978205413565566735,
+                15675366647238478172,
+                7819770375827015142,
+                6823625407675143456,
+                2042269662440457350,
+                11521115322912473140,
+                13703874674141705702,
+                1295561690992462505,
+                12464082489717915012,
+                11378922861990148970,
+                2076282285705918066,
+                1390689690731346588,
+                13670979351308531000,
+                12980996477862222169,
+                10496970808504864546,
+                14015388605987660396,
+                4171129107047347396,
+                1656857204469415571,
+                17492457435753920912,
+                10132937897450237781,
+                5065601177732655021,
+                17498367701449356268,
+                9552937910825811119,
+                6213399497134928078,
+                12865519292113075754,
+                8548871019474664332,
+                12973212090641168109,
+                3018095992673320728,
+                4102580256148037725,
+                11411505815957245048,
+                8044142604358855954,
+                6163064685377006161,
+                7676133172626151339,
+                15177331097378985405,
+                923128391789363540,
+                8405355494789853124,
+                8409013636286216842,
+                17519952046647436442,
+                12690425880634822079,
+                7295927951214020420,
+                5103171252065286692,
+                4531269626482776566,
+                17509133779966482098,
+                16771567673323510549,
+                9940518318209913958,
+                2566490491730418524,
+                4997841530198583881,
+                11759671980624847072,
+                236198295111281753066320039251613319168,
+            ],
+            false,
+            &[
+                71899280513623458552145249016552994237,
+                178022248289107386782917101936650380353,
+                255625838769009388824927860355940076434,
+                121962254099711754859038532086609248763,
+                59872961115052908306543061040164816410,
+                145744363507658104710837010581989823810,
+                338319661685339082590358956215045566174,
+                290739457187625163940692784836032119938,
+                102353717779891934233349934687853721544,
+                225329579460006440746297710294946036022,
+                316163400314075335114543355138924774144,
+                242629424202419431808060333880335299183,
+                336637366621973787760394741552187847516,
+                127149310224131772152404903622952869836,
+                85217086639349069560268584179569796229,
+                288456162238828957975357475768556813720,
+                2322053591645986692513269952293282248,
+                23886686336705823397335841516762678432,
+                175632263115026286862513266601513383318,
+                17830140982463194144226240912000074490,
+                48031562988977348924053425612183570385,
+                185034753859739067489830327172526977045,
+                227710709668885830797329625310786334267,
+                308759265368402767635757360507661643012,
+                334805483362310850923518630586926851230,
+                220415649899704549501214403190778847834,
+                43861542838181626718849469492834306674,
+                204265678709880220426546421484453129827,
+                151063072938574094210227543931302587764,
+                316073058683782563317954119803382604708,
+                62902979426359664656753339956655087538,
+                109409329199481099619272968691966582849,
+                288334634117818811070276698737796069402,
+                199892461857203242580516566989632259415,
+                40002171350745060965510798891251585277,
+                154633225597607913360969790937676622771,
+                23331392927180118743589726593952514844,
+                178670952793548037890171459500571727899,
+                205286709952631574257293606198868132981,
+                290572351036059743492819701939343693082,
+                313302240730465502361816138561779790199,
+                298930986275348694022905862510398053615,
+                143456292053271069037074208070260628506,
+                220677843266374049186931830068053265543,
+                196023110922474126654172898892505234227,
+                33819999196236263017344391827751817580,
+                77522810613895457963216160506958201060,
+                330821768913570349672159160303849597195,
+                4416315002058679027770944785571921018,
+                10913635548951525134832497185712102363,
+                288350877512010606019534605878065850240,
+                21927056540018894796144141934471639862,
+                244484540024722818648417915663855548498,
+                214095710372771665274655618068966631913,
+                289721706368761104500963849385616867881,
+                216530199123718605797526803034954862270,
+                24274838545822297819,
+                6335921756850263208,
+                11125242160747149811,
+                5950900297278200050,
+                4972887779003626968,
+                15099558014605218082,
+                8070946285642924813,
+                5661924633602804698,
+                4017981546647826153,
+                3298784914124356106,
+                1048634509662954303,
+            ],
+            &[
+                20742352318266030096293177166380232730,
+                94723301457440466416361925604863344972,
+                155183536715731359725724734972807873803,
+                17061437345925241038820470775729341170,
+                338506898704479034065745690014951359180,
+                43717027696126343423561096950046753223,
+                206684034774881171188140438639504808257,
+                128362937108829038734385905110008622486,
+                87151049887575354063311828417433827997,
+                107892718984211214989804758295369875609,
+                133084862829684806850772300726350656544,
+                162514062030914754550618977235417506648,
+                165058789069291257349934574537114824598,
+                277198514697041118196021022693506614024,
+                162437330453376083180926285797114271648,
+                115009772065128319924603953069583309992,
+                25032784687777136214732786490770845243,
+                309990914317713615214289572289342560873,
+                338425053361876946065759009700045990454,
+                286484716582605469312570945445056758964,
+                221448791532849325093372844686481444045,
+                48184057690022042858369535834656291060,
+                133463518587414034764016182199104582899,
+                269768493722217453576401034493697101015,
+                311399462258872415741769948300203172484,
+                336199315195315916858884708617820489732,
+                292461808440769384603385832465039000855,
+                160044893835039038185177186372249678825,
+                43077500716708167882078599652268708900,
+                17278205436056836544073021164440803717,
+                176098723893246998912359201661297865505,
+                291088199854254277441062049813536378358,
+                236749069104924285306779330678741514257,
+                309751541491435366200690390528030014747,
+                242592660498585046974387629250816575790,
+                197069240479898878928318508527868753008,
+                138121334554597194481794955090152058589,
+                201190108552643313768631488657961541631,
+                179356670441676310159917413488530877259,
+                268278222366096697835088143083328203292,
+                318794067005085262841166696210137700827,
+                40874837179785510504397890806951637667,
+                123680897609825506387154084268426827640,
+                313767975974249357541099465059334876157,
+                2071825044513907767090088049260374636,
+                47820933888185951661627471869627290113,
+                1838951709092742414966563787650644067,
+                21303017424012933601078884365605704751,
+                315336540493569213504098552397173653408,
+                73371653354488664452194272958603058630,
+                7899569006621393877762849624550836832,
+                294005508911525014699846739685614638579,
+                321186021925035495214246369016536290535,
+                111342425302680670795761389248502308739,
+                185184030704182123025230520153147770707,
+                120143741680765611634683681792388717452,
+                40548940365495920312595882362031199093,
+                315584031049945414768255297236347931770,
+                128429657094257742278183378006149207661,
+                213339978596035699725511197805519041735,
+                276636881407671864442999375275847114373,
+                218601924635614105410620737250297074483,
+                215159228025982146385124797722092243717,
+                250263457417253225050977444308477654497,
+                274108051538678202260221230622319734146,
+                338458728370068428906305401782402443348,
+                146638673322244377128455545054512142327,
+                290616929605143990122080933230805471356,
+                290137925119708215096944280716510400683,
+                308048490614971989705749056510286128673,
+                287481780070614051549853593929647052321,
+                214468729128431145492763836921198876301,
+                92094026635176584579480441125408971500,
+                94992787286133328605813416791535715325,
+                238011727156105406407021840486388051281,
+                159433896883228428704831388845453629049,
+                199226505992072492316531773924450000498,
+                225398069322266723442612494691510904788,
+                98758154007029378539724264158205283901,
+                9984942975157341635322683391337020783,
+                41696392447368022580731069966109777507,
+                340145781544529515098667713416349751295,
+                1333631518504782759373020349822009092,
+                170762070126059844317932615790711100968,
+                338323933533994395968192030944095143665,
+                333561148646672917954191738419072170064,
+                212620363480317011064454046042180208658,
+                339721215187917179977612512108875364465,
+                192451360041964269696463775037241915846,
+                547596688109051585356991083206600905,
+                232059254782548866439365381227829566193,
+                168810866532027429315705281994744558430,
+                271939674914452163330173781814334929363,
+                221169853109231922016131569450298400352,
+                32866146546623419946261051886210731429,
+                237389529845076669618654894800044203318,
+                176354393594468913139606226946515473516,
+                247054868789101133885962670558747342430,
+                46867675411888178421082247142448841336,
+                32293362349270467684268722851288568987,
+                10174094575812096055427502521482110128,
+                41887396864311506398387420304437279762,
+                172630137502233527538385796103527186787,
+                21261718901246453418783555040503655263,
+                96475545427260403767904622869149936999,
+                322020650748227864764835118409459375944,
+                180179252272572125238793009685533583381,
+                27047733118531912593295841986843255213,
+                175147865312411754147900051668116705028,
+                141864471564237168453481428607522268188,
+                17905884378428276079,
+                3713606437359152813,
+                9370125751473669209,
+                10012381228375856292,
+                6916709082927033810,
+                17606888822444197359,
+                10818646898854586102,
+                9927520028322801489,
+                17015575684851383261,
+                15287563104761130407,
+                4246630569287612899,
+                6737522138244324583,
+                16956805675707509108,
+                10507692348129622148,
+                1144006384258648355,
+                4260138925314579646,
+                14508270375905482517,
+                3559046762122289814,
+                15199903498605585717,
+                16432575538338639756,
+            ],
+        );
+        test(
+            &[0],
+            &[0, 0],
+            &[0, 0, 1],
+            &[0, 170141183460469231731687303715884105728],
+            false,
+            &[2],
+            &[0, 0],
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_mod_barrett_fail_1() {
+    let ns = &[1, 2, 3];
+    let ds = &[0x80000000];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_mod_barrett_fail_2() {
+    let ns = &[1, 2];
+    let ds = &[1, 0x80000000];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_mod_barrett_fail_3() {
+    let ns = &[1, 2, 3, 4];
+    let ds = &[1, 0x80000000];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_div_mod_barrett(&mut [10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+#[should_panic]
+fn limbs_div_mod_barrett_fail_4() {
+    let ns = &[1, 2, 3];
+    let ds = &[1, 2];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_div_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+fn verify_limbs_div_mod_3(
+    qs_in: &[Limb],
+    rs_in: &[Limb],
+    ns: &[Limb],
+    ds: &[Limb],
+    qs_out: &[Limb],
+    rs_out: &[Limb],
+) {
+    let n = Natural::from_limbs_asc(ns);
+    let d = Natural::from_limbs_asc(ds);
+    let d_len = ds.len();
+    let (expected_q, expected_r) = (&n).div_mod(&d);
+    let base_q_len = ns.len() - d_len + 1;
+    let qs = qs_out[..base_q_len].to_vec();
+    let q = Natural::from_owned_limbs_asc(qs);
+    let r = Natural::from_limbs_asc(&rs_out[..d_len]);
+    assert_eq!(q, expected_q);
+    assert_eq!(r, expected_r);
+    assert_eq!(&qs_in[base_q_len..], &qs_out[base_q_len..]);
+    assert_eq!(&rs_in[d_len..], &rs_out[d_len..]);
+    assert!(r < d);
+    assert_eq!(q * d + r, n);
+}
+
+#[test]
+fn test_limbs_div_mod() {
+    let test = |qs_in: &[Limb],
+                rs_in: &[Limb],
+                ns: &[Limb],
+                ds: &[Limb],
+                qs_out: &[Limb],
+                rs_out: &[Limb]| {
+        let mut qs = qs_in.to_vec();
+        let mut rs = rs_in.to_vec();
+        limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
+        assert_eq!(qs, qs_out);
+        assert_eq!(rs, rs_out);
+
+        let (qs, rs) = limbs_div_mod(ns, ds);
+        let d_len = ds.len();
+        let qs_limit = ns.len() - d_len + 1;
+        assert_eq!(qs, &qs_out[..qs_limit]);
+        assert_eq!(&qs_in[qs_limit..], &qs_out[qs_limit..]);
+        let rs_limit = d_len;
+        assert_eq!(rs, &rs_out[..rs_limit]);
+        assert_eq!(&rs_in[rs_limit..], &rs_out[rs_limit..]);
+
+        verify_limbs_div_mod_3(qs_in, rs_in, ns, ds, qs_out, rs_out);
+    };
+    #[cfg(feature = "32_bit_limbs")]
+    {
+        let test_only_verify = |qs_in: &[Limb], rs_in: &[Limb], ns: &[Limb], ds: &[Limb]| {
+            let mut qs = qs_in.to_vec();
+            let mut rs = rs_in.to_vec();
+            limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
+            let qs_out_alt = qs;
+            let rs_out_alt = rs;
+
+            let (qs, rs) = limbs_div_mod(ns, ds);
+            let d_len = ds.len();
+            let qs_limit = ns.len() - d_len + 1;
+            let mut qs_out = qs_in.to_vec();
+            qs_out[..qs_limit].copy_from_slice(&qs);
+            let rs_limit = d_len;
+            let mut rs_out = rs_in.to_vec();
+            rs_out[..rs_limit].copy_from_slice(&rs);
+
+            assert_eq!(qs_out, qs_out_alt);
+            assert_eq!(rs_out, rs_out_alt);
+            verify_limbs_div_mod_3(qs_in, rs_in, ns, ds, &qs_out, &rs_out);
+        };
+        // d_len == 2
+        // bits != 0 in limbs_div_mod_by_two_limb
+        // carry == 0 in limbs_div_mod_by_two_limb
+        test(
+            &[10; 4],
+            &[10; 4],
+            &[1, 2],
+            &[3, 4],
+            &[0, 10, 10, 10],
+            &[1, 2, 10, 10],
+        );
+        test(
+            &[10; 4],
+            &[10; 4],
+            &[1, 2, 3],
+            &[4, 5],
+            &[2576980377, 0, 10, 10],
+            &[2576980381, 2, 10, 10],
+        );
+        // bits == 0 in limbs_div_mod_by_two_limb
+        test(
+            &[10; 4],
+            &[10; 4],
+            &[1, 2, 3],
+            &[4, 0x80000000],
+            &[6, 0, 10, 10],
+            &[4294967273, 1, 10, 10],
+        );
+        // adjusted_n_len < 2 * d_len
+        // q_len == 0 in limbs_div_mod_balanced
+        test(&[10], &[10; 3], &[0; 3], &[0, 0, 1], &[0], &[0; 3]);
+        // _q_len > 0 in limbs_div_mod_balanced
+        // adjust in limbs_div_mod_balanced
+        // q_len == 0 in limbs_div_mod_balanced
+        // i_len >= 2 in limbs_div_mod_balanced
+        // ns_shifted[q_len - 1] >=
+        //      (DoubleLimb::from(x) * DoubleLimb::from(qs[q_len - 1])).upper_half()
+        //      in limbs_div_mod_balanced
+        // bits != 0 limbs_div_mod_balanced
+        // q_len == r_len in limbs_div_mod_balanced
+        // do_extra_cleanup in limbs_div_mod_balanced
+        // !quotient_too_large in limbs_div_mod_balanced
+        test(&[10], &[10; 3], &[0, 0, 1], &[0, 0, 1], &[1], &[0; 3]);
+        // !adjust in limbs_div_mod_balanced
+        test(&[10, 10], &[10; 3], &[0; 4], &[0, 0, 1], &[0, 0], &[0; 3]);
+        // quotient_too_large in limbs_div_mod_balanced
+        test(&[10], &[10; 3], &[0, 0, 1], &[0, 1, 1], &[0], &[0, 0, 1]);
+        // bits != 0 in limbs_div_mod_unbalanced
+        // bits != 0 and Schoolbook condition in limbs_div_mod_unbalanced
+        test(
+            &[10; 264],
+            &[10; 30],
+            &[
+                3099176493, 2686179191, 2963763290, 1498905807, 2459138342, 883505904, 186294937,
+                1240988195, 1152000807, 3485254691, 3058980612, 4051918579, 84687528, 3474110821,
+                825051253, 2495113655, 4269053297, 531158278, 2131423786, 735959410, 1116746011,
+                2099249419, 427789428, 2182383446, 3493666451, 2086611578, 2349523902, 1865831092,
+                104597580, 720145483, 1653677313, 2449086005, 1225118075, 1458687427, 2284889737,
+                1178000580, 1204354477, 1109887135, 2302764777, 1833071533, 2749496868, 3989183692,
+                3112262804, 2143914125, 71393498, 3686808425, 568295620, 856601035, 205234115,
+                617861388, 102368116, 561132401, 733711249, 2931194848, 1371887453, 2942553563,
+                977874680, 3567773306, 2225947571, 668306082, 3150218776, 3697051793, 4272816685,
+                2926797142, 2177250555, 2840203688, 51052169, 1663516163, 3085068676, 471736374,
+                3127282104, 1358199856, 250868071, 54398826, 2107868776, 1621910930, 1677400830,
+                20889047, 4180454819, 908186988, 1625301399, 817226551, 4039437379, 261787419,
+                1484678288, 1183594253, 3154399910, 888455895, 1713982625, 2925178936, 2076694919,
+                2288237521, 620657938, 2457761902, 2906110636, 649773865, 1387981473, 1600821385,
+                3512543520, 2803028516, 1584293468, 1443490691, 2589741655, 3170284085, 1902835632,
+                1603116322, 2850443356, 60694796, 779743237, 4006039758, 3272304347, 2442366350,
+                3601524312, 720808564, 3291979084, 1675880280, 4139440559, 3672978467, 3938651783,
+                3257918674, 2077369764, 3027135446, 3940308694, 2533432781, 4105883289, 626796131,
+                608794713, 810463080, 2363401530, 2800065942, 2679046183, 4063827982, 2327835929,
+                522607612, 1660561124, 817356463, 2984129158, 146372510, 4293731514, 2558750639,
+                3948304613, 1448810261, 826590770, 2423032883, 1050524737, 2770168039, 265887860,
+                3353243705, 2674401993, 1496121248, 2053790031, 1863298286, 1068977659, 2875518960,
+                4137037281, 2888534695, 473250405, 3368783492, 3797281057, 132028186, 316202261,
+                381223941, 3366394660, 425124513, 4083952857, 2301832392, 3321645917, 509444341,
+                361894284, 3976042471, 2957832871, 250836826, 1408114735, 1614725824, 4214313885,
+                4081964203, 2663894263, 145380453, 2329325761, 2055092903, 126874014, 1000460254,
+                3910319503, 2561073113, 4231784000, 1042785346, 3511487116, 1270832223, 3456586087,
+                1210528079, 4119713948, 3299598065, 4178946856, 1623358249, 1001721969, 3964704699,
+                4133343553, 2193492316, 407893767, 2484237727, 3782842008, 2959959832, 449539588,
+                2978317444, 4008576738, 3478248618, 98697842, 3986898689, 1622966191, 3594042026,
+                3280563264, 270713233, 3999415188, 401848066, 623805281, 1710411200, 1801291443,
+                640646012, 2283134929, 1043531051,
+            ],
+            &[
+                3120245340, 1558358768, 2311090615, 3377497278, 2047683134, 1453801550, 1974940808,
+                1545066418, 2840151982, 2463969682, 2025473894, 697610529, 906262319,
+            ],
+            &[
+                3603474202, 23640019, 2133576502, 3778379659, 883962915, 1866519837, 1888919496,
+                2545522706, 1473641504, 1017307414, 1207107908, 43613763, 2001107652, 1295816376,
+                3276720994, 750683734, 862054731, 2423105118, 647184565, 82749472, 3134905644,
+                3469243304, 3869523273, 2200822282, 2271529456, 1805512365, 576197860, 1881352922,
+                2694444088, 700188823, 4014261501, 998600230, 3921476919, 3042342458, 1113168589,
                 1849643027, 272533119, 3745240014, 846408292, 2765600200, 337759056, 1580016038,
                 958561971, 2320387143, 2022805900, 1706525297, 2849929806, 2816448769, 4287480228,
                 531300416, 3987101730, 2194947684, 1539543472, 2563500078, 250905437, 2906546975,
@@ -14217,7 +15012,7 @@ fn test_limbs_div_mod() {
             ],
         )
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // d_len > 2
         // adjusted_n_len >= 2 * d_len
@@ -23136,6 +23931,472 @@ fn test_limbs_div_mod() {
             ],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10; 17],
+            &[10; 17],
+            &[
+                9995257893114397114,
+                9401504468144459131,
+                558615837638945228,
+                10733662027974786928,
+                18295107704289976677,
+                1814706268673753787,
+                12474943759854623335,
+                8814778832826774208,
+                9159057654048965906,
+                4451260977376821357,
+                18241701617364042056,
+                6169989192350218482,
+                15071965537117101028,
+                13509168527678537782,
+                12224278653171635329,
+                16077066393714953826,
+                1433938684868066489,
+                13014970036232570373,
+                899282336249563956,
+                3089487642230339536,
+                3787737519477527148,
+                16667686214395942740,
+                8787122953224574943,
+                7841835218775877827,
+                9693303502025838409,
+                16122224776459879427,
+                144327425397945219,
+            ],
+            &[
+                2350654041004706911,
+                7834348511584604247,
+                12756796070221345724,
+                3842923787777653903,
+                12373799197090248752,
+                9712029403347085570,
+                1426676505264168302,
+                10586232903332693517,
+                8387833601131974459,
+                6290888746273553243,
+                9503969704425173615,
+            ],
+            &[
+                202456657147558152109901507475537122715,
+                141544818913881519874092238178433867843,
+                275524182346007619566480653112029670693,
+                215172050818252896986565580534109016796,
+                92733538312761310790452889448576547166,
+                91701466450957754160846038754611934439,
+                164779931950588452028911477730096873117,
+                21744620976689376757367148110176546981,
+                115309102765763607741130301287348207687,
+                250600965720963975451669310424742733750,
+                317483558830600885090648460088021590313,
+                332490021250532497965386858736841474909,
+                205373566107256884845758954657628866407,
+                199458611746084590647534359301136716262,
+                230384362032351989571141559341699862076,
+                5167533089162809195457972234323209093,
+                0,
+            ],
+            &[
+                208710984273895826839997682188906477877,
+                115424523712619040589751309572566504864,
+                209135340640354573149074822416809180546,
+                326775285311223522225628352860569964038,
+                314751758377986933148201576748707282183,
+                216151724507296816517044234546333415103,
+                277308233638267890964069599861626004752,
+                51771133057805244455953459830699776406,
+                123718831728721031920600735971673732358,
+                325668411318097624383400979022554842237,
+                6458064213065170136,
+                10,
+                10,
+                10,
+                10,
+                10,
+                10,
+            ],
+        );
+        test(
+            &[10, 10, 10],
+            &[10; 23],
+            &[
+                748159237152854524,
+                14199895651244313572,
+                9044210482484213648,
+                3880401870711113518,
+                1694971440240542063,
+                13547801197479934494,
+                5244069077418598572,
+                17329479401291658084,
+                12613311850003558282,
+                5618071535926791206,
+                16954511293879569524,
+                8600749590433482901,
+                11708546551548237376,
+                10879843710159659952,
+                9101678715417935644,
+                12126242459863584426,
+                17259866272884195621,
+                4418382641453775715,
+                542305129955142216,
+                6563442437678466173,
+                12794875758080454756,
+                7461769876910639905,
+                17925257245127463276,
+                5137728719899113924,
+                12905981752247605071,
+            ],
+            &[
+                2654882163556630563,
+                2047318842992691178,
+                17944530594807555614,
+                17278864523505748498,
+                1160166728089482341,
+                18368953657130322418,
+                3937719995815345698,
+                12007028340444721520,
+                1496744539933999053,
+                1476923054783110845,
+                6551619938265612084,
+                16801911333947266527,
+                13986495313155597995,
+                6571595571877061463,
+                10140569634762389822,
+                16210530410764331582,
+                15172903143228403872,
+                5831780706385794192,
+                12288937301416472500,
+                16224579586702000460,
+                14545605105156691376,
+                8614987803254853144,
+                16629891239728134900,
+            ],
+            &[276278514181472520101485469297146083271, 264083387845719364574735108316264052452, 0],
+            &[
+                77322852854628317079881075001597030855,
+                182019502651037890019504476608012098358,
+                330766606322074505688480060249154471800,
+                278671770270168966009670812696908093907,
+                202306942107018718015921858969086481002,
+                253405769825256671155111902224947530678,
+                154270885963276339250562186946003160568,
+                10873057250044319586103818569031054888,
+                288934995981279043979869665708982114095,
+                57330477671982858869995856486031912230,
+                15613933717044194204420793802205716570,
+                59831232645155749537245533000979832821,
+                282924012409595261424574570092964037748,
+                83293000557849037703842859757248799142,
+                263024298041277891080703596932538478088,
+                16155258866535267865180460258034929548,
+                171823041504711418750591504833742673012,
+                323728144755403680176217117848382736347,
+                181099982219925957555704521450511296921,
+                6479604789273630471063253534564442544,
+                325471474273172753578048165770927182729,
+                36270903644037438507178106663507521367,
+                10496779692524198840,
+            ],
+        );
+        test(
+            &[10; 60],
+            &[10; 56],
+            &[
+                14660214196707223375,
+                14265972253040120215,
+                15506320303100465818,
+                17085621003033826581,
+                11203337550022453944,
+                15493204961705835371,
+                5803021083410871755,
+                8112917457002746745,
+                12663484193891261040,
+                1721048899893287199,
+                8062187621610464306,
+                13431761655884620090,
+                7331427712144411262,
+                3626934647030185267,
+                13231383914073320042,
+                11637171044660683638,
+                15189928975258171045,
+                941827519265124224,
+                2992792486091076914,
+                2044203374633195985,
+                8310380355675814732,
+                1677894573715118386,
+                1863631713396879617,
+                13750903464355877990,
+                13561054993991137710,
+                6643134394212488277,
+                9782189322903525535,
+                7987880548748269544,
+                17396502810230452231,
+                9355336424066456608,
+                6974435047841500624,
+                4695995454788932008,
+                9790410161672155866,
+                7324176676989916049,
+                14873447357313289350,
+                17933513319573948354,
+                16221633809094225356,
+                1119296061370324791,
+                13659405622992751643,
+                10536448431317839371,
+                15771892335411705715,
+                6450515195565208913,
+                12583173873673842188,
+                8943105588740166659,
+                16781237121411387206,
+                7355272525679995848,
+                8924936502454129260,
+                9464007023044637842,
+                2392086820925613645,
+                6952992660961663836,
+                15709161892606831425,
+                15961199354349516091,
+                8170938350051511007,
+                10106337242460916657,
+                4519632767875399815,
+                13966478644099829332,
+                18146666299243951179,
+                18001892575388798951,
+                17442461326088111501,
+                12996149925790510613,
+                15125238000270787220,
+                13458137050174539117,
+                7565676737178758148,
+                7820895745333505106,
+                18391820881894926862,
+                17227107494212736312,
+                16170524482788524562,
+                18292226432698054709,
+                16409124153431213414,
+                2622798522164114141,
+                2030148142272451724,
+                12631034221630749586,
+                12521714531249855181,
+                4869764655816857917,
+                18312880399388298885,
+                1881841240505020002,
+                16686085102712131293,
+                1638984612454565124,
+                5980766772519196081,
+                14473546029553426533,
+                2610255570241349719,
+                4121823778233332328,
+                15196027812344512481,
+                17634932614139407184,
+                14566629132274047837,
+                6629067916649366603,
+                39453246491293667,
+                4118307938296638515,
+                176389639877922730,
+                2385844666265721927,
+                14424300909552701177,
+                2596064544694255252,
+                9262830285738421829,
+                8366979142044016136,
+                12451088247268499723,
+                16456341544263224076,
+                405434591376297036,
+                5989071471671786526,
+                17922319711997177283,
+                12402685985480014221,
+                11440567647536028583,
+                17109382986734751589,
+                1165111999013207871,
+                9042409351611763515,
+                335396288523389342,
+                6889397323074150916,
+                13998858741906849976,
+                15927944587197048898,
+                10995067153735213576,
+                13255077995174337515,
+                11985913648073551062,
+                16606199253171990948,
+                16615211378568935152,
+                13000672060735124358,
+            ],
+            &[
+                6726150808576237754,
+                9590776370558469124,
+                4613857594775205869,
+                5605914158178321857,
+                12627075307783464761,
+                456502911636413728,
+                6201419543988208076,
+                12457367465345491402,
+                9194484469177303126,
+                14469237774454463326,
+                8872571916644400618,
+                10371861714649740250,
+                9551882050917532587,
+                1418647961867356190,
+                11742587182398063873,
+                11015016132415914044,
+                8777839015232205587,
+                11080046461630228193,
+                13740325869131645472,
+                17716201322003396844,
+                2184375889136968144,
+                2744007897878529583,
+                10107840174031679018,
+                6807210551800087042,
+                3927845063936277496,
+                4657264236265855475,
+                18202437017170404187,
+                5332422779150911238,
+                15515262280249200267,
+                248667350560422394,
+                3473467338029486524,
+                5450666559053310869,
+                9114347711968955703,
+                1001965327187909086,
+                9391480248060184246,
+                9069754537718985217,
+                6108113375902101471,
+                615335597740998377,
+                7341924484422171664,
+                7557688311245960406,
+                10629369615492290302,
+                6551022068682485711,
+                13009629572214277263,
+                9801266711191462998,
+                12475469715378400041,
+                16817728089246511388,
+                5318131496704799888,
+                14034696640350324685,
+                173195053797772988,
+                9465580662794117123,
+                9395502290798332505,
+                172507413604644051,
+                13462235362634225088,
+                9267822876689174860,
+                12978933587961252639,
+            ],
+            &[
+                76476051638370509428397758234977634284,
+                293263312822502451272056851364553261598,
+                329130453545050072578826602094725921538,
+                2809547844534239232489834314389847998,
+                127711284870084613597111684929335986056,
+                52268641627375558869784370715216452751,
+                117673743451256273214228978398817976864,
+                303516739918305182992524319576069637138,
+                93926831996759953811887247333261457489,
+                27234676907703067872534127156997614273,
+                78139951332908020188577571473570753239,
+                230762492390726801129878821545524553685,
+                228604629395320307104674264369331287477,
+                258063437945010399888657278614044051305,
+                150578735137494646325100045806338626972,
+                98211171083096418152302376320511255135,
+                240925480501497730922254774113726391322,
+                330868267145062972376585100771048828968,
+                112317165171618897986837398244803934517,
+                169740065171263799015796553357009693710,
+                237164612088039843640381222017849897308,
+                303241373133493629251464693960508934664,
+                152117293101246129564075795487649572691,
+                106242652892613170931192128617681679488,
+                116405919162240150257939174572211792984,
+                98107399229452330861498708955653712232,
+                215876793817800410258658604440508410817,
+                149749491094914455653285675661090090084,
+                129195970366700385647073273387707283352,
+                95182803409394396010615710469360140698,
+                150321867028973634332510436449805907006,
+                171608744542023081316864584079196119248,
+                237731421265197660598643291854456167157,
+                224848931147414801812215834630789577139,
+                129070893826709539273780308663273467282,
+                120530033738411395635146914489199896048,
+                107665435990296508593589601636675938713,
+                195555092669127817796804685876800708649,
+                29424158904699107100778108350742877109,
+                318493082482838194309611211892596633873,
+                26837921283673105376544178744970632394,
+                13239512277555501172107125105169931968,
+                76059951944484365439011331208254178031,
+                265545518328993837059214257158616360624,
+                211825582518504134304653570979167291823,
+                18132313873358684303658822512300037044,
+                302559501539522266725696893812158790667,
+                167940205599303051961852273831451560087,
+                120636416896811039621971725200152685541,
+                59560022620336654562523431635498114461,
+                81861473300509973105260206371432366196,
+                103718869204360002113111459680709445240,
+                45053086106147280177110421524823601446,
+                250587066105263619550939105201469170622,
+                150707313475751732223367718993315440401,
+                328358608677677728166776312096858568378,
+                61569983580730288026663881358730952868,
+                43043620853378260578056282254845788562,
+                569940428356981197902637756384229071,
+                1,
+            ],
+            &[
+                95231636842318525934125190365125158359,
+                314395419443120424913958270592033697511,
+                297312765257509229310806924816145056416,
+                15370450878220031758706418743647123596,
+                250204170398748326568459488358590296480,
+                96169269301100549166344868816474133502,
+                9432736278005901600215112630677905099,
+                334092357024357125243700951972786801542,
+                138208288069473594517104126152450847684,
+                77950846428200233108225049911481633733,
+                240019056446918490848212128352485552189,
+                71773384699816053436640710289495107194,
+                326694745185895485303623943928312167997,
+                159765228986901250792175354496120518849,
+                195222300492612277484264728212415430002,
+                235103222978670582207131110646995740158,
+                12465717886221074348258814929666980440,
+                281389686980661677603990073118820333260,
+                311629088139261644631681278168161886722,
+                248700390931710715714927513544487628310,
+                150692236342178180664774256172598242007,
+                131280766359412070071778907681294422682,
+                297598538187797561273058313265912428002,
+                335260443725660764746917324986427277744,
+                41808238080256171345742925980402460673,
+                187074093499417600678594123425339044378,
+                42760981581835765340186363585903669517,
+                189094546669858583487751953256396333650,
+                323977866422841494603125236306088407616,
+                338389986622494133193980932200879218432,
+                132985004796553201731333370095131615589,
+                209631275090924238032330343850550171136,
+                327855583895583453743828111689963403431,
+                106854109497890334805058762126383608095,
+                4473457267294452626457484807208704683,
+                167619730100487536571749307123996351372,
+                252245652008810612362587034415602149140,
+                151408385369965953971188045432470204232,
+                89143309693860727784612567145846170330,
+                25397474438458531020984341168293794162,
+                271621924801622544287372688047479666838,
+                104178886049707033272885400282435800405,
+                306903839594883580094417724916667115829,
+                330558312668949470390895523842833265635,
+                104600511923908158744836394101036486555,
+                69235609284086315657584776386172970268,
+                34715155663415630851002731675061617096,
+                171427658814474263809601738156873921784,
+                100618758844255153019012247408867569471,
+                162764495667369502608539278957203210749,
+                206941684225644992530894866754989599441,
+                238409825342703265919598893217857977311,
+                52895905026851524024671311409611535861,
+                295287074033515913305744318526673902659,
+                3954366546262078500,
+                10,
+            ],
+        );
+    }
 }
 
 #[test]
@@ -23381,6 +24642,24 @@ fn test_div_mod() {
     );
     test("0", "1000000000000000000000000", "0", "0");
     test("123", "1000000000000000000000000", "0", "123");
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211457",
+        "1",
+    );
+    test(
+        "13407807929942597099574024998205846127479365820592393377723561443721764030073315392623399665776056285720014482370779510884422601683867654778417822746804225",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "0",
+    );
+    test(
+        "2135987035920910082395021706169552114602704522356652769947041607822219725780640550022962086936581",
+        "340282366920938463463374607431768211455",
+        "6277101735386680763835789423207666416120802188537744064512",
+        "18446744073709551621",
+    );
 }
 
 #[test]
@@ -23923,6 +25202,14 @@ fn div_mod_and_div_rem_properties_helper(x: Natural, y: Natural) {
     assert!(r.is_valid());
     let q = mut_x;
 
+    let mut q_alt = y.clone();
+    let mut r_alt = x.clone();
+    x.div_mod_to(&y, &mut q_alt, &mut r_alt);
+    assert!(q_alt.is_valid());
+    assert!(r_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert_eq!(r_alt, r);
+
     let mut mut_x = x.clone();
     let r_alt = mut_x.div_assign_mod(y.clone());
     let q_alt = mut_x;
@@ -42,7 +42,7 @@ fn test_limbs_extended_gcd() {
             x,
         );
     };
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // s == 0 second time in limbs_gcd_subdivide_step
         // scratch <= scratch_2 first time
@@ -26700,6 +26700,62 @@ fn test_limbs_extended_gcd() {
             true,
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(&[0, 0], &[0, 1], &[0, 1], &[0, 0, 0], false);
+        test(&[0, 1], &[1, 1], &[1], &[1, 0, 0], false);
+        test(&[1, 1], &[0, 1], &[1], &[1, 0, 0], true);
+        test(&[0, 0, 0], &[0, 1], &[0, 1], &[0, 0, 0], true);
+        test(&[0, 1], &[0, 1], &[0, 1], &[0, 0, 0], false);
+        test(&[0, 0, 0], &[0, 0, 1], &[0, 0, 1], &[0, 0, 0, 0], false);
+        test(&[0, 0, 1], &[1, 1], &[1], &[1, 0, 0], true);
+        test(&[0, 1], &[0, 2], &[0, 1], &[1, 0, 0], true);
+        test(&[1, 1], &[0, 2], &[1], &[Limb::MAX, 0, 0], false);
+        test(&[1, 1], &[0, 3], &[1], &[1, 1, 0], true);
+        test(
+            &[0, 2],
+            &[1, 1],
+            &[1],
+            &[170141183460469231731687303715884105728, 0, 0],
+            true,
+        );
+        test(
+            &[0, 3],
+            &[1, 1],
+            &[1],
+            &[113427455640312821154458202477256070486, 0, 0],
+            false,
+        );
+        test(
+            &[0, 1],
+            &[3, 1],
+            &[1],
+            &[113427455640312821154458202477256070486, 0, 0],
+            true,
+        );
+        test(&[0, 3], &[2, 1], &[6], &[1, 0, 0], false);
+        test(
+            &[0, 1, 1],
+            &[1, 0, 1],
+            &[1],
+            &[
+                170141183460469231731687303715884105728,
+                170141183460469231731687303715884105727,
+                0,
+                0,
+            ],
+            true,
+        );
+        test(&[2, 1], &[0, 3], &[6], &[3, 0, 0], true);
+        test(&[0, 0, 0, 1], &[0, 1, 2], &[0, 1], &[4, 0, 0, 0], true);
+        test(
+            &[6848271667560079512, 13861374652666379954, 600542],
+            &[15542],
+            &[2],
+            &[2108, 0],
+            false,
+        );
+    }
     #[cfg(feature = "32_bit_limbs")]
     {
         // scratch > scratch_2 first time
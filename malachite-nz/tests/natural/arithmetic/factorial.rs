@@ -18,7 +18,7 @@ use malachite_nz::test_util::natural::arithmetic::factorial::{
 };
 use rug::Complete;
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_odd_factorial() {
     fn test(n: usize, double: bool, out: &[Limb]) {
@@ -1912,6 +1912,66 @@ fn test_limbs_odd_factorial() {
     test(35, false, &[13586117418673889649, 130422663336]);
 }
 
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_odd_factorial() {
+    fn test(n: usize, double: bool, out: &[Limb]) {
+        assert_eq!(limbs_odd_factorial(n, double), out);
+        let x = Natural::from_limbs_asc(out);
+        let n = u64::exact_from(n);
+        let f = if double {
+            double_factorial_naive(if n.odd() { n } else { n - 1 })
+        } else {
+            factorial_naive(n)
+        };
+        let zeros = f.trailing_zeros().unwrap_or(0);
+        assert_eq!(x, f >> zeros);
+    }
+    test(0, false, &[1]);
+    test(1, false, &[1]);
+    test(2, false, &[1]);
+    test(3, false, &[3]);
+    test(4, false, &[3]);
+    test(5, false, &[15]);
+    test(
+        236,
+        false,
+        &[
+            87239505457803900849528475386177072509,
+            131751033305350274799487278788453345955,
+            101125822659591603256439352456714415442,
+            71227683236995615092866194399237068716,
+            54392800433923195323011203385243307913,
+            29643752029881922939100960464674294066,
+            176793593567029698603815207557652153043,
+            27358831857780360167944768707707243355,
+            263319816269920805342446012827364280192,
+            317227039793136022533880501040911921902,
+            17502,
+        ],
+    );
+    test(
+        253,
+        false,
+        &[
+            322169528925301808728755711712718834955,
+            148267147762859336170521651134064247505,
+            84176556231687859047138345692524610720,
+            238608694938072740068158473457481678826,
+            29076674553711328091751294301203845708,
+            243849040400198095551608857000706058884,
+            110664476608417792834598276244088986052,
+            39969124256768773470494809333025872984,
+            283597068522804373186182731637687077742,
+            306679237343795542051196309261064169350,
+            200921768282151101693999514739600071485,
+            64,
+        ],
+    );
+    test(26, false, &[48076088562799171875]);
+    test(35, false, &[2405873491984360136479756640625]);
+}
+
 #[test]
 fn test_factorial() {
     fn test(n: u64, out: &str) {
@@ -2584,7 +2584,7 @@ fn test_limbs_gcd_reduced() {
             &[1],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(&[0, 0, 0, 1], &[1, 0, 1], &[1]);
         // xs[0].even()
@@ -12827,6 +12827,36 @@ fn test_limbs_gcd_reduced() {
             &[1],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(&[0, 0, 0, 1], &[1, 0, 1], &[1]);
+        test(&[0, 1, 1], &[1, 1], &[1, 1]);
+        test(&[0, 0, 0, 0, 1], &[1, 0, 1], &[1]);
+        test(&[1, 0, 1], &[1, 0, 1], &[1, 0, 1]);
+        test(&[0, 1, 1, 1], &[1, 0, 0, 1], &[1]);
+        test(
+            &[
+                200026607999020359057646866105208381022,
+                62974876758819561207112733006773113888,
+                10271613972074,
+            ],
+            &[253751883746473923979394711829685822587, 3293124641497424134489],
+            &[1127943472104912171197645853409],
+        );
+        test(
+            &[
+                205734502301999712861051477399722059600,
+                208235044402757193264212610931654160617,
+                12517881086360330798681592733,
+            ],
+            &[
+                137813803457414016614161564490877279607,
+                50670036541863464840669673031612963617,
+                13423012916774973586,
+            ],
+            &[242665167688670059131141822770957845683, 3773371576277436904877],
+        );
+    }
 }
 
 #[test]
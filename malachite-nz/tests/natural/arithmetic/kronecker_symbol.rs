@@ -37,7 +37,7 @@ fn test_limbs_jacobi_symbol_same_length() {
         let mut ys = ys_orig.to_vec();
         assert_eq!(limbs_jacobi_symbol_same_length(&mut xs, &mut ys, bits), s);
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // n < JACOBI_DC_THRESHOLD
         // bits < 16
@@ -141,6 +141,38 @@ fn test_limbs_jacobi_symbol_same_length() {
         // x_1 == y_1 third time in limbs_half_gcd_2_jacobi
         test(&[0, 1, 0, 1, 2], &[1, 0, 1, 0, 1], 0);
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(&[0], &[1], 1);
+        test(&[0], &[3], 0);
+        test(&[0, 0], &[1, 1], 0);
+        test(&[0, 0, 0], &[1, 0, 1], 0);
+        test(&[0, 0, 1], &[1, 0, 0], 1);
+        test(&[0, 0, 1], &[1, 0, 1], 1);
+        test(&[0, 0, 1], &[1, 1, 0], 1);
+        test(&[0, 1, 1], &[1, 0, 1], 1);
+        test(&[0, 0, 1], &[1, 0, 2], 1);
+        test(&[0, 0, 1], &[1, 0, 3], 1);
+        test(&[0, 1, 1], &[1, 0, 2], -1);
+        test(&[1, 0, 1], &[1, 1, 3], -1);
+        test(&[0, 0, 2], &[1, 0, 1], 1);
+        test(&[0, 0, 3], &[1, 0, 1], -1);
+        test(&[0, 0, 2], &[1, 1, 1], 1);
+        test(&[0, 1, 2], &[1, 1, 1], 0);
+        test(&[0, 0, 3], &[1, 1, 2], 1);
+        test(&[0, 1, 2], &[1, 0, 3], 1);
+        test(&[0, 0, 0, 1, 1], &[1, 0, 0, 0, 1], 1);
+        test(&[0, 0, 1, 1], &[1, 1, 0, 2], 1);
+        test(&[0, 0, 0, 1, 1], &[1, 1, 1, 0, 1], 1);
+        test(&[0, 1, 1, 0, 1], &[1, 0, 0, 1, 1], 0);
+        test(&[0, 0, 1, 3], &[1, 1, 0, 2], 1);
+        test(&[0, 0, 0, 1, 1], &[1, 1, 1, 0, 3], 1);
+        test(&[0, 0, 1, 0, 1], &[1, 1, 0, 1, 3], 1);
+        test(&[0, 1, 0, 1, 1], &[1, 0, 1, 0, 3], 1);
+        test(&[0, 1, 1, 1, 1], &[1, 0, 0, 1, 3], 1);
+        test(&[0, 1, 1, 1, 1], &[1, 1, 0, 0, 3], 1);
+        test(&[0, 1, 0, 1, 2], &[1, 0, 1, 0, 1], 1);
+    }
     #[cfg(feature = "32_bit_limbs")]
     {
         // n >= JACOBI_DC_THRESHOLD
@@ -425,7 +425,7 @@ fn test_limbs_mod_schoolbook() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -501,6 +501,82 @@ fn test_limbs_mod_schoolbook() {
             ],
         )
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[
+                9995257893114397114,
+                9401504468144459131,
+                558615837638945228,
+                10733662027974786928,
+                18295107704289976677,
+                1814706268673753787,
+                12474943759854623335,
+                8814778832826774208,
+                9159057654048965906,
+                4451260977376821357,
+                18241701617364042056,
+                6169989192350218482,
+                15071965537117101028,
+                13509168527678537782,
+                12224278653171635329,
+                16077066393714953826,
+                1433938684868066489,
+                13014970036232570373,
+                899282336249563956,
+                3089487642230339536,
+                3787737519477527148,
+                16667686214395942740,
+                8787122953224574943,
+                7841835218775877827,
+                9693303502025838409,
+                16122224776459879427,
+                144327425397945219,
+            ],
+            &[
+                2350654041004706911,
+                7834348511584604247,
+                12756796070221345724,
+                3842923787777653903,
+                12373799197090248752,
+                9712029403347085570,
+                1426676505264168302,
+                10586232903332693517,
+                8387833601131974459,
+                6290888746273553243,
+                175317296821820190316687158184603811840,
+            ],
+            &[
+                306702114188089112885788492317711093416,
+                319325021304293184460903862499084935806,
+                176122800227916446921037970412814295681,
+                194608905964421815127914034324245244975,
+                208437470459307973286775856547348782152,
+                285645650640872960319976303114103044572,
+                59025998215301350606416061393503145867,
+                263213226208096628611269200598116077399,
+                275335393698802960332665627410579878572,
+                45936137646432451427938903742720378559,
+                167016278915157966723267560593578938942,
+                306895979477655927640411528182858837709,
+                120617240194740752241415238318429777420,
+                56808138638469884630283595843518574520,
+                274979447605680962472774130907988066340,
+                273023579591535808024811953964155640445,
+                113305374819878970751273782183830880272,
+                311271374534882287131774612130999078754,
+                88738470104497194389464910485292225149,
+                160463155373579570138834138738184434644,
+                296535202197989055465396282625128697142,
+                13404301837753587040023501214565734856,
+                297256513793287312758496005564004411880,
+                313185587628812714275637234820700265067,
+                338520084339973851648323381415906181524,
+                16122224776459879427,
+                144327425397945219,
+            ],
+        )
+    }
 }
 
 #[test]
@@ -1613,7 +1689,7 @@ fn test_limbs_mod_barrett() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -2233,572 +2309,770 @@ fn test_limbs_mod_barrett() {
         );
         test(&[0], &[0, 0], &[0, 0, 1], &[0, 0x8000000000000000], &[0, 0]);
     }
-}
-
-#[test]
-#[should_panic]
-fn limbs_mod_barrett_fail_1() {
-    let ns = &[1, 2, 3];
-    let ds = &[0x80000000];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-#[should_panic]
-fn limbs_mod_barrett_fail_2() {
-    let ns = &[1, 2];
-    let ds = &[1, 0x80000000];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-#[should_panic]
-fn limbs_mod_barrett_fail_3() {
-    let ns = &[1, 2, 3];
-    let ds = &[1, 2];
-    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
-    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
-}
-
-#[test]
-fn test_limbs_mod() {
-    let test = |rs_in: &[Limb], ns: &[Limb], ds: &[Limb], rs_out: &[Limb]| {
-        let mut rs = rs_in.to_vec();
-        limbs_mod_to_out(&mut rs, ns, ds);
-        assert_eq!(rs, rs_out);
-
-        let rs = limbs_mod(ns, ds);
-        let rs_limit = ds.len();
-        assert_eq!(rs, &rs_out[..rs_limit]);
-        assert_eq!(&rs_in[rs_limit..], &rs_out[rs_limit..]);
-
-        verify_limbs_mod_2(rs_in, ns, ds, rs_out);
-    };
-    #[cfg(feature = "32_bit_limbs")]
+    #[cfg(feature = "128_bit_limbs")]
     {
-        let test_only_verify = |rs_in: &[Limb], ns: &[Limb], ds: &[Limb]| {
-            let mut rs = rs_in.to_vec();
-            limbs_mod_to_out(&mut rs, ns, ds);
-            let rs_out_alt = rs;
-
-            let rs = limbs_mod(ns, ds);
-            let rs_limit = ds.len();
-            let mut rs_out = rs_in.to_vec();
-            rs_out[..rs_limit].copy_from_slice(&rs);
-
-            assert_eq!(rs_out, rs_out_alt);
-            verify_limbs_mod_2(rs_in, ns, ds, &rs_out);
-        };
-        test(&[10; 4], &[1, 2], &[3, 4], &[1, 2, 10, 10]);
-        test(&[10; 4], &[1, 2, 3], &[4, 5], &[2576980381, 2, 10, 10]);
-        test(
-            &[10; 4],
-            &[1, 2, 3],
-            &[4, 0x80000000],
-            &[4294967273, 1, 10, 10],
-        );
-        test(&[10; 3], &[0; 3], &[0, 0, 1], &[0; 3]);
-        test(&[10; 3], &[0, 0, 1], &[0, 0, 1], &[0; 3]);
-        test(&[10; 3], &[0; 4], &[0, 0, 1], &[0; 3]);
-        test(&[10; 3], &[0, 0, 1], &[0, 1, 1], &[0, 0, 1]);
-        test(
-            &[10; 30],
-            &[
-                3099176493, 2686179191, 2963763290, 1498905807, 2459138342, 883505904, 186294937,
-                1240988195, 1152000807, 3485254691, 3058980612, 4051918579, 84687528, 3474110821,
-                825051253, 2495113655, 4269053297, 531158278, 2131423786, 735959410, 1116746011,
-                2099249419, 427789428, 2182383446, 3493666451, 2086611578, 2349523902, 1865831092,
-                104597580, 720145483, 1653677313, 2449086005, 1225118075, 1458687427, 2284889737,
-                1178000580, 1204354477, 1109887135, 2302764777, 1833071533, 2749496868, 3989183692,
-                3112262804, 2143914125, 71393498, 3686808425, 568295620, 856601035, 205234115,
-                617861388, 102368116, 561132401, 733711249, 2931194848, 1371887453, 2942553563,
-                977874680, 3567773306, 2225947571, 668306082, 3150218776, 3697051793, 4272816685,
-                2926797142, 2177250555, 2840203688, 51052169, 1663516163, 3085068676, 471736374,
-                3127282104, 1358199856, 250868071, 54398826, 2107868776, 1621910930, 1677400830,
-                20889047, 4180454819, 908186988, 1625301399, 817226551, 4039437379, 261787419,
-                1484678288, 1183594253, 3154399910, 888455895, 1713982625, 2925178936, 2076694919,
-                2288237521, 620657938, 2457761902, 2906110636, 649773865, 1387981473, 1600821385,
-                3512543520, 2803028516, 1584293468, 1443490691, 2589741655, 3170284085, 1902835632,
-                1603116322, 2850443356, 60694796, 779743237, 4006039758, 3272304347, 2442366350,
-                3601524312, 720808564, 3291979084, 1675880280, 4139440559, 3672978467, 3938651783,
-                3257918674, 2077369764, 3027135446, 3940308694, 2533432781, 4105883289, 626796131,
-                608794713, 810463080, 2363401530, 2800065942, 2679046183, 4063827982, 2327835929,
-                522607612, 1660561124, 817356463, 2984129158, 146372510, 4293731514, 2558750639,
-                3948304613, 1448810261, 826590770, 2423032883, 1050524737, 2770168039, 265887860,
-                3353243705, 2674401993, 1496121248, 2053790031, 1863298286, 1068977659, 2875518960,
-                4137037281, 2888534695, 473250405, 3368783492, 3797281057, 132028186, 316202261,
-                381223941, 3366394660, 425124513, 4083952857, 2301832392, 3321645917, 509444341,
-                361894284, 3976042471, 2957832871, 250836826, 1408114735, 1614725824, 4214313885,
-                4081964203, 2663894263, 145380453, 2329325761, 2055092903, 126874014, 1000460254,
-                3910319503, 2561073113, 4231784000, 1042785346, 3511487116, 1270832223, 3456586087,
-                1210528079, 4119713948, 3299598065, 4178946856, 1623358249, 1001721969, 3964704699,
-                4133343553, 2193492316, 407893767, 2484237727, 3782842008, 2959959832, 449539588,
-                2978317444, 4008576738, 3478248618, 98697842, 3986898689, 1622966191, 3594042026,
-                3280563264, 270713233, 3999415188, 401848066, 623805281, 1710411200, 1801291443,
-                640646012, 2283134929, 1043531051,
-            ],
-            &[
-                3120245340, 1558358768, 2311090615, 3377497278, 2047683134, 1453801550, 1974940808,
-                1545066418, 2840151982, 2463969682, 2025473894, 697610529, 906262319,
-            ],
-            &[
-                4239744213, 626950715, 3916878897, 2974637695, 488695410, 1094975104, 2496621051,
-                205419221, 4192241096, 3583664917, 511224033, 2016541230, 718692763, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-            ],
-        );
         test(
-            &[10; 215],
             &[
-                59488434, 3053025912, 3197673861, 28499967, 919132002, 3254889605, 3510992086,
-                4155414397, 1401076337, 3663475665, 3859836963, 838452584, 1714061888, 756807500,
-                721664913, 2122189093, 190377396, 772161692, 337532878, 2801246946, 3347165247,
-                504261973, 4207433768, 3203836949, 1637702776, 3950126612, 3575550053, 630469114,
-                2468674532, 3237190898, 2699368075, 2126693510, 2005217541, 990977199, 211751826,
-                4095342386, 2390539499, 553381774, 1202910267, 3124334908, 3631027545, 3118560130,
-                3505258080, 2533393565, 3897399802, 1644233433, 2966724466, 1228506720, 4266980301,
-                1975806255, 3087455989, 430105567, 2496389669, 2559019944, 1343075842, 515604272,
-                2623722375, 3549762134, 3517683279, 3564893038, 3816995397, 21727446, 2930818039,
-                2672793843, 1589653728, 2917937865, 665591277, 1222288512, 1197537333, 2425822187,
-                3647080994, 3180811677, 3800694132, 4006852228, 1392314914, 1823809919, 3315974102,
-                2004045597, 2557007205, 532899885, 1088861606, 3969820933, 711573681, 3744617855,
-                3618206965, 3834838995, 601286347, 1494520421, 2007503431, 3672534190, 3437739925,
-                3075991743, 1108097829, 438830502, 854842946, 798336207, 2497251014, 985928236,
-                1930130581, 260199427, 3446617597,
+                9467139676469209549,
+                590281264913108801,
+                2444597864339828042,
+                14864081623018167053,
+                17247631583397892818,
+                14553244820490552393,
+                12829855212365838534,
+                3531466761266135925,
+                11372171159031384620,
+                5870847048926628622,
+                9193240591510160396,
+                4025304033900694645,
+                17261252553070218810,
+                1960954339685170046,
+                9689788042403522315,
+                6777781555383721706,
+                564338697137211139,
+                2236230241770389317,
+                7260612298284603217,
+                7551831827404764167,
+                14240930170717885933,
+                5403113159439788814,
+                11890192816726889700,
+                4571074562843162754,
+                6585174915018781919,
+                16820621164336832115,
+                2906428786044704407,
+                14549789283221663094,
+                8574533295629171239,
+                2554371108614275803,
+                12102140726062325305,
+                5927759880327312421,
+                1947106727211751169,
+                4243634376196439953,
+                3958901784387088795,
+                11997496505606868854,
+                8614259227764305665,
+                2851316343853334425,
+                7360014330841540867,
+                12833167596911644967,
+                16420023392297553774,
+                448987443751486030,
+                1127916262354476550,
+                998365641524414747,
+                771182118275428982,
+                3644906482543632509,
+                12008471662501141232,
+                6735530311778854203,
+                14937996347189247818,
+                10310266533399488799,
+                14173112110010990829,
+                10048196983951682150,
+                7660343487007291390,
+                5442254482173747309,
+                11894682918116055490,
+                555474340707057713,
+                5454210195350763050,
+                6335921756850263208,
+                11125242160747149811,
+                5950900297278200050,
+                4972887779003626968,
+                15099558014605218082,
+                8070946285642924813,
+                5661924633602804698,
+                4017981546647826153,
+                3298784914124356106,
+                1048634509662954303,
             ],
-            &[4005818469, 1809723722],
             &[
-                1137651599, 55996911, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10,
-            ],
-        );
-        test(
-            &[10; 178],
-            &[
-                3300759985, 47489222, 1596412201, 3681499224, 721942549, 2798268772, 1725678834,
-                1018213306, 278963757, 2162182910, 4238919550, 1480123217, 3550714331, 2714414047,
-                1584331762, 3790545549, 2452172382, 3823866136, 3793112677,
-            ],
-            &[
-                2532879920, 749327979, 378994598, 3512427187, 1768486327, 874381062, 3750599339,
-                1824619676, 4102262081, 3872688397, 4080392661, 1233784995, 3967512105, 536874,
-                1334357500, 533131065, 3802334171, 2688028352,
-            ],
-            &[
-                687097313, 233246948, 2670005176, 2918696316, 2059690051, 3559547370, 982825692,
-                2828616847, 3397764024, 1640472335, 1827782800, 239756415, 4169556349, 1129911247,
-                2514993175, 2469589340, 3137350801, 1512435665, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10,
-            ],
-        );
-        test(
-            &[10; 6],
-            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
-            &[3, 4, 5, 6, 7, 0x80000000],
-            &[718, 910, 1080, 1286, 1492, 2147483434],
-        );
-        test(
-            &[10; 4],
-            &[0, 0, 0, 3, u32::MAX, 0x80000000],
-            &[3, 4, u32::MAX, 0x80000000],
-            &[3, 4, 0xfffffffc, 0x7fffffff],
-        );
-        test(
-            &[10; 10],
-            &[500160962, 3482059973, 3833374734, 2382534866, 7345183],
-            &[1962915382, 2761901894, 931109938],
-            &[3497463394, 501014622, 297308821, 10, 10, 10, 10, 10, 10, 10],
-        );
-        test(
-            &[10; 20],
-            &[
-                3406830026, 653096149, 665840651, 3475733215, 2990001405, 3962996135, 1125790437,
-                510807273, 2268497988, 3801098676, 4251717337, 4009243500, 3093915781, 2221063229,
-                1145961534, 1520950302, 4156341537, 4051749345, 1887697916, 2938053362, 1317017771,
-                2551451381, 1165783725, 1692722530,
-            ],
-            &[
-                757200076, 1850648953, 1310285965, 43796838, 2811750593, 2350568971, 1162649579,
-                3588922014, 843685186, 327689313, 822131258, 632127361, 4287259347, 855554137,
-                2978907761, 2140676852,
-            ],
-            &[
-                1472562202, 467285767, 3874015352, 3774608073, 3547803297, 3252310888, 379016022,
-                3915526178, 172347662, 1424794357, 2354655682, 1763403834, 1622341466, 1527845266,
-                321699454, 1968063162, 10, 10, 10, 10,
+                1601810336850541391,
+                6414377018375174391,
+                919960012376992260,
+                7432535796251013733,
+                14687204344100976419,
+                4239980047441451729,
+                3419961342059496953,
+                16241811836659790563,
+                582511433240649668,
+                246415310737356671,
+                4213289580820322712,
+                6570486144808547472,
+                649071692625916450,
+                16246395526862594705,
+                5418002168615819323,
+                8787804720479728592,
+                16606257073152622475,
+                1410262373214068670,
+                2846556520446018460,
+                12702224195603835801,
+                14824788470038136235,
+                8070823597295684045,
+                10064047777457825217,
+                12473928276513608352,
+                4502242984490214594,
+                8213242804638977717,
+                11407293275124988149,
+                15303970978422100227,
+                5734015081210218763,
+                6780255848655226189,
+                5308681743121306497,
+                3590094480707706491,
+                924657275311967241,
+                13313245703725306506,
+                13755314570936606477,
+                1722612846595274627,
+                11310713460998976321,
+                17860713973992950196,
+                4832972642835413575,
+                6159638888337886550,
+                16436259186156623772,
+                8219825892743153908,
+                12825816757113080127,
+                17576561025816321316,
+                16426316569762062979,
+                7041069634919241163,
+                14958139263401063299,
+                8541928253694453091,
+                14623281385826596559,
+                5881920493887697974,
+                2856125878289959548,
+                13138961086309035424,
+                1445182795958550281,
+                989371283719803801,
+                14414401959190662319,
+                12694741659414145902,
+                4944169755440522083,
+                4850330269090259826,
+                7263731888738583042,
+                3847985956261979907,
+                10886313908655224591,
+                16884924304355496352,
+                17434369248195376612,
+                9624045826924140661,
+                9440358242723121635,
+                44413621672380228,
+                14501853932445164832,
+                4412509400967439905,
+                4059150452772511000,
+                14121559478607349396,
+                6939247432141568945,
+                9144772592231287902,
+                9035665952014395555,
+                14897716038428818227,
+                15076823799682351749,
+                16277606343067785741,
+                1901372957955104636,
+                9089098420745572372,
+                7424125507905187829,
+                12368330388186365917,
+                12778631331588513256,
+                7648700244963873530,
+                3776028467270636181,
+                16107369772612157292,
+                9046543866366629415,
+                13251838453492673272,
+                2975138303374961664,
+                2462966081502544324,
+                814917744063498594,
+                13005222969141014201,
+                5026855542811222762,
+                2382391535955253463,
+                5899528506680208740,
+                13336141787604226636,
+                2972106516856532181,
+                14045206722662122716,
+                13361481863470745757,
+                1303122885509130861,
+                2127042842590220699,
+                6185514996199626930,
+                16622745329653187578,
+                1256533115604594696,
+                11243434057073050028,
+                9066405370642929758,
+                4552716624777987017,
+                1128092841571320177,
+                3756894170446405064,
+                3328979948330012406,
+                15049042231988504879,
+                3530903968533512703,
+                17905884378428276079,
+                3713606437359152813,
+                9370125751473669209,
+                10012381228375856292,
+                6916709082927033810,
+                17606888822444197359,
+                10818646898854586102,
+                9927520028322801489,
+                17015575684851383261,
+                15287563104761130407,
+                4246630569287612899,
+                6737522138244324583,
+                16956805675707509108,
+                10507692348129622148,
+                1144006384258648355,
+                4260138925314579646,
+                14508270375905482517,
+                3559046762122289814,
+                15199903498605585717,
+                16432575538338639756,
             ],
-        );
-        test(
-            &[10; 3],
-            &[3156053040, 2869923590, 2300216426, 1369503],
-            &[177914576, 1102898054, 1547778],
-            &[0, 0, 0],
-        );
-        test(
-            &[10; 255],
             &[
-                4077004042, 60445643, 361888880, 1370511290, 4140611, 1545574856, 337863511,
-                2696753299, 882513095, 2220102704, 4040224968, 3895008884, 3144563393, 2298197836,
-                2781817257, 1704369652, 3931316078, 315544063, 3497851423, 3664143565, 952326443,
-                3938628137, 2331019960, 3709840874, 886597817, 2718223965, 2271519236, 1069952925,
-                2973484971, 694036791, 3948658826, 1091836994, 3976014345, 2590014773, 1030558914,
-                3050284515, 4064531951, 227487465, 1614818201, 2853653798, 312516670, 1131265287,
-                1079324522, 2790732553, 2857570668, 2459258033, 3676592728, 1861660079, 3846516218,
-                2487383610, 820186200, 670229005, 795815915, 4236865449, 388462685, 3530199852,
-                4067109237, 228011739, 3243897206, 2102622551, 3066279582, 2307396469, 3918457987,
-                3177100158, 1165801255, 3470877002, 730654273, 225744395, 303984647, 71923315,
-                2631641933, 642193977, 214491076, 4083126324, 1300818039, 3145270519, 3071074628,
-                533164498, 2260983885, 2398373112, 2291850622, 2549903002, 2876921663, 2087750942,
-                3012568578, 2072377257, 2300996389, 1724754966, 3663537905, 2452259556, 681943280,
-                495483009, 1806854180, 2022877157, 3545541285, 3155213185, 1520127898, 881038528,
-                2682219847, 2061051159, 4091777429, 2973569703, 2243163157, 3812478845, 2868568231,
-                538252316, 915738068, 3645567840, 557499318, 586868707, 334386881, 1009292772,
-                9683309, 1394382805, 1469121902, 3538152891, 192398621, 3298083372, 291538032,
-                454155529, 3467440904, 107593274, 2581455983, 4068643130, 1289926312, 2620830750,
-                3599696168, 845286964, 93649777, 211929373, 405469122, 2046160625, 4269019707,
-                3153165259, 3273675158, 740768718, 1724953971, 4107531497, 3669145824, 2308427932,
-                4057339865, 30691770, 4215434099, 2209368084, 3410096812, 3930010459, 4058257897,
-                1401228000, 3205298856, 919645629, 1080800085, 3531478127, 4244298042, 1920158944,
-                4150416695, 2942997287, 621554087, 2193963845, 3535984780, 1948458592, 1237268195,
-                4059838770, 4178023707, 2225635923, 1078133440, 1904752780, 1129557671, 2721601376,
-                3991770285, 2491529378, 2424674576, 4164361438, 1566434777, 3112804218, 2185237562,
-                2557399204, 967876237, 2243639679, 2436745635, 2376273731, 3848434966, 1970386149,
-                294413738, 1420657192, 694358663, 647017168, 4094657777, 3013362236, 590143693,
-                2285757920, 2393504081, 116938458, 891518480, 696284131, 2710811430, 3317562620,
-                1647436225, 1605219555, 3358343198, 2183786684, 1105785201, 248367120, 1398964063,
-                3309346508, 3273593185, 2032930327, 2675472140, 557577078, 692086759, 1588022257,
-                1734092444, 927029618, 3696557822, 3370092072, 2151067302, 2650483754, 3670460764,
-                2763985465, 1126450086, 3029345718, 1504895755, 3694166108, 578785502, 294874178,
-                110079616, 1327583117, 2661938072, 2345632606, 3722933355, 656745495, 671269721,
-                2346516327, 1169529001, 4238866211, 949351039, 3500460463, 4199863511, 2082398726,
-                1281275042, 2560151133, 1138051905, 468589983, 727511715, 2554428159, 2014177155,
-                3346720979, 1317583392, 1714879892, 4249909774, 2158352329, 3040382616, 964600510,
-                319055149, 890296957, 2071841867, 2811829970, 3945344817, 4075668231, 1336582250,
-                1632615222, 2996499016, 1247703908, 2161089989, 1757474064, 1957983140, 2427088146,
-                3819098678, 1414774317, 1685786700, 2842967660, 2434489739, 2748571761, 2393986575,
-                585646425, 733271380, 4096908487, 866235842, 1811304066, 2337315068, 4064326552,
-                2517677609, 595634311, 929413085, 2556167349, 2814648343, 3447175234, 4171314821,
-                3354462286, 2190450759, 3693163187, 817168953, 753817804, 2679932775, 2584000650,
-                511478235, 2858863655, 2320104001, 2367392444, 1724096919, 1152655320, 3131579385,
-                2076635048, 3557452129, 3931367674, 1011051727, 3910860016, 3742755232, 3977120817,
-                178461096, 1938068862, 1480470517, 2997286985, 196403685, 3581556360, 3800228840,
-                2583177118, 3801520456, 1254931401, 3392702841, 1046934176, 412728369, 2864978525,
-                970771733, 672204372, 1528132877, 2673063996, 740759188, 1840165684, 1821213771,
-                3111387733, 2386566079, 2682984985, 808050061, 4160818440, 1656303941, 2884647798,
-                3997066586, 3727980283, 4181671626, 2736389654, 1808865173, 1642983278, 875419327,
-                3960909969, 3751011271, 2807559080, 1535615269, 2321637137, 2745054513, 3020092780,
-                1359015906, 2466514344, 591997660, 2754906030, 3487658632, 3722637404, 1613546148,
-                915678357, 1842708127, 701921987, 1207101857, 1974760740, 2410755764, 3324705651,
-                3105821672, 335929431, 1887715703, 4035171395, 2815003797, 3632947325, 3664189665,
-                2211203246, 1473602679, 3802261181, 3699100807, 3964975584, 2757250875, 3240191191,
-                3824907808, 1582581356, 116402974, 2321914135, 933007824, 136161346, 2857937718,
-                1044097090, 477414473, 3966936277, 21169877, 2518410639, 1938987054, 4164953085,
-                3287593768, 96004465, 3316812094, 2635456314, 2931969475, 3535012794, 3954299175,
-                4188146823, 1054040153, 4020697192, 3043463112, 487456176, 1536604607, 2191156801,
-                2869189759, 650124563, 54254415, 910946976, 3240585021, 4240104628, 989174439,
-                3235043341, 607641178, 2910679971, 4072297259, 2664931864, 2560203429, 3992282762,
-                480443057, 3944140064, 1601223002, 3984987075, 3894479377, 1082591102, 2444518988,
-                2566734417, 1272094512, 2581007089, 3838472665, 2810473520, 1590808097, 143027202,
-                1667662742, 3686892725, 228309572, 4091365295, 1107025920, 938240502, 567559985,
-                2237721627, 1939039548, 3053102548, 3379831217, 3536253061, 1586694963, 931323468,
-                593457460, 1981974171, 443146100, 11888347, 2403661012, 2646485528, 528884126,
-                1040587284, 2828170682, 512577110, 3534338212, 1642316111, 124785841,
-            ],
-            &[
-                3924577409, 3801138880, 2554871927, 1797946680, 1773117295, 3509783608, 281892096,
-                2358224613, 4217828194, 1339769957, 439570060, 3151617679, 1384372613, 4141908033,
-                3034076248, 201556707, 1940999750, 1134129834, 1062995144, 3653931665, 1653789920,
-                2219526834, 849219893, 3448402897, 890163003, 378127096, 3324725657, 2858888153,
-                4101277783, 1736762052, 4083759525, 1398269743, 946713296, 1332088349, 1241448676,
-                820326205, 1554372778, 2902257209, 2531752530, 593903741, 283370156, 1184475111,
-                4048000423, 3262885223, 3065903568, 2181798675, 970937186, 2831985588, 3318099557,
-                125775489, 2602810229, 1265835529, 370295842, 3885440035, 332195328, 803805465,
-                2339766805, 3074827324, 176637217, 2717782694, 3228882886, 2079180306, 1985828369,
-                1451458086, 2647399511, 653380804, 2816232893, 580123271, 1284828784, 1488365849,
-                3992136885, 1712696753, 3200712671, 4019248351, 2502831049, 3353947366, 3805407296,
-                3975182552, 1255379188, 3079539536, 2754826248, 2654365805, 1932758080, 2018940849,
-                3987435655, 2107608701, 1979416324, 2553264951, 2332482333, 3854067399, 7383141,
-                2032883058, 209316404, 1509786266, 891329562, 2446773803, 981357189, 289424276,
-                1563495883, 1124421740, 4019623418, 229810471, 1293459273, 685378813, 763089605,
-                543868756, 4294383498, 4256223402, 3521141578, 1300426165, 4203249157, 2446225842,
-                3483103616, 2930204118, 3459214772, 2242535858, 2845721016, 2523036196, 1191008269,
-                1438983871, 1990994626, 1369473842, 3325530252, 2436280648, 3247037040, 1827745637,
-                1440370214, 565710731, 918521819, 3174181412, 2021188910, 1292274207, 3161583133,
-                1940037031, 1320244184, 1944735324, 1624924970, 1914256377, 1558283566, 1557557944,
-                819014915, 3477749819, 2317549528, 1815788616, 3410581584, 865672422, 3902451420,
-                47231850, 2052078859, 2128580726, 2861615294, 2875806926, 2504678981, 2922243612,
-                2567002225, 3176460060, 3501319351, 256937154, 986867411, 2877370533, 3829593455,
-                169449010, 1762966206, 126552381, 3303194639, 437611604, 2065984359, 215674808,
-                2549495983, 226202732, 19733282, 265226462, 732006741, 2011526642, 2592527346,
-                3141322109, 980881336, 4085884669, 2038689570, 3704380296, 1688979285, 1869912321,
-                1472588169, 3395855158, 2409096192, 2603014340, 2571643809, 1244430574, 2331499515,
-                234553812, 3949996932, 1906820531, 1849149876, 4204335779, 1113287279, 1165814564,
-                3834491692, 3992932111, 1545656810, 1251393152,
-            ],
-            &[
-                1301325451, 2202463531, 827674839, 2990077551, 3515177881, 1362651403, 481136630,
-                3397298241, 3518449263, 76128572, 4225087952, 1094548681, 282440964, 1243437992,
-                2536206935, 1312305087, 2350135248, 1191467757, 772049416, 1651785710, 13757971,
-                4177684085, 4225218737, 3829973687, 4224488792, 835349925, 807373018, 2003072240,
-                1998180420, 1893011542, 2772647530, 1845722545, 3927750947, 1582656468, 3929302887,
-                2309901890, 2219253077, 74452907, 3721812597, 3102504646, 1837697683, 4169869788,
-                1109227870, 1893389948, 1637025892, 401123956, 2362840033, 3249816285, 1331916977,
-                11330013, 1790389738, 4046514714, 4242300773, 386281183, 4154390602, 2907471583,
-                596332521, 1031920105, 1416982457, 936732589, 620289351, 2021344934, 4134683536,
-                254271151, 3153428294, 2775130118, 767392350, 1524517878, 1803212363, 1935475282,
-                476597337, 1665283842, 385826494, 1418089792, 1583155781, 904332458, 2167494919,
-                4213977373, 2749948942, 1366862995, 974249813, 2151831423, 2426239456, 4154619782,
-                1909704093, 82889380, 1836307000, 1860128761, 2497608852, 302875545, 2512570768,
-                3465381901, 3664521586, 2113690348, 3208498261, 211553098, 1122435807, 3989762258,
-                1790955068, 1274985561, 801567833, 206607829, 1945509947, 2689438654, 630881630,
-                3166357111, 950757161, 2525660644, 2782583030, 2082925684, 4017471838, 2770389652,
-                3152723497, 2730012549, 375610667, 331640140, 3189394626, 1169047456, 1269402316,
-                366259196, 659129976, 1835642575, 1109092533, 1409510258, 1055228915, 3865844484,
-                1951513725, 1561115766, 1535328235, 566121235, 1218990885, 2397023975, 110038834,
-                410651474, 2293988363, 1341825237, 3049031992, 1068909343, 3433095008, 226980250,
-                234888265, 3753182189, 712474545, 3392300015, 358854770, 2053632965, 2314494072,
-                3270641699, 1763895670, 4027033942, 3610557903, 1952033954, 3311300088, 2108677074,
-                2293958755, 649449413, 1137877459, 1632674803, 3368118971, 4027363661, 3533599056,
-                4112294692, 1992613374, 2157460184, 2344709644, 3126961604, 3915648450, 163946806,
-                3452706048, 3278493797, 3569418312, 619110361, 1791108219, 53432167, 1675611199,
-                3468608269, 3380246190, 3977636639, 2303818017, 2959386722, 4259696814, 2826759181,
-                1786462977, 974733518, 2879253522, 3102475706, 2731858652, 2537398244, 2096104946,
-                3000514581, 821433471, 3175348116, 2836803346, 3386301193, 400757797, 3528093517,
-                689295706, 494008951, 3664544857, 1053036864, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10,
+                5592822181652430458,
+                17506309594623475426,
+                14050063092843436505,
+                7714948654849123602,
+                12960251261153655218,
+                12293354553127881932,
+                18236552269356335752,
+                8116592444680865353,
+                5788148289215893900,
+                8971586422059718981,
+                7830225577778842728,
+                16542468928391674395,
+                7685364916096821326,
+                10621040870461365797,
+                13017786142160529410,
+                15623634767986429431,
+                3570280230795725028,
+                5462496964935066825,
+                13096782281304038571,
+                1028538565298248606,
+                1306240401949593430,
+                9752885768751332733,
+                16834186448734276157,
+                7637206024963845142,
+                14347989823115406647,
+                11694534965309339857,
+                17506164867360865472,
+                18226657528377127028,
+                17113377911080849277,
+                12836900580850373696,
+                7624296086468816307,
+                3241150049341673184,
+                11432625191496196476,
+                7201112746916642381,
+                14159401858377741999,
+                13042389876849434472,
+                6539044773109830458,
+                5626612565019858620,
+                16855355146181557220,
+                2896027096805100269,
+                1749121842377065409,
+                14486576285464434664,
+                13003134176160772443,
+                4520736241988979615,
+                14386190542886985088,
+                5203873393963986621,
+                2767011034432552285,
+                6993214728799607735,
+                16219726698174042484,
+                12883752315794694947,
+                4638588568194864850,
+                13671093412063473486,
+                16507766724668892429,
+                508640878850638089,
+                10821378407060575406,
+                7345992160360704865,
+                10043908069076742056,
+                18320857698273010360,
+                2218460838923946432,
+                12515547441597943704,
+                8373193888106171069,
+                11138535537637434655,
+                2449630875466873477,
+                8767699022869197665,
+                6446940169111329267,
+                12481583138381941451,
+                18405856492243480913,
+                8856983257605794349,
+                11977198292403090397,
+                4992072464455161558,
+                1429124422280954077,
+                16695629052720642207,
+                15320583853113777709,
+                9270873446273846783,
+                9544568698292818385,
+                16150036388405871492,
+                4707553634577955164,
+                12819651092171657742,
+                6194167822526464289,
+                3302239600303656809,
+                13520149877311082646,
+                3658928982143500918,
+                6938149248133961941,
+                3561482775788486836,
+                11608588618646326460,
+                9312042305619109305,
+                7752033026671842884,
+                17638989744464665362,
+                11720586895422616384,
+                17488161719714908794,
+                14359533380966089634,
+                9112047402314539693,
+                9045007769242360827,
+                14039981061261051366,
+                12161985564133395359,
+                12552005864162233454,
+                13987473146854769743,
+                18218863257920884509,
+                527093394921779615,
+                10809320813728048297,
+                9176568449151735783,
+                16181777654712188911,
+                3708863559833042684,
+                15705373009786311560,
+                12044345266545331965,
+                15745097802473032619,
+                3448641903062070968,
+                7079863925626111888,
+                10936059842602692227,
+                3949990826310278419,
+                8781569794504977516,
+                2710649351206510739,
+                8045244454460377457,
+                16793164706992819994,
+                14171071447733414359,
+                6442975288102390424,
+                13408292761208182437,
+                18190891873633561623,
+                14416452326133420106,
+                11467995109126255854,
+                10355921853391241663,
+                3362337025912778005,
+                12828210017750944427,
+                6724529289281531448,
+                17910365619735459537,
+                18061583666579344364,
+                7385510916096038449,
+                14735084634699542626,
+                2983574817812405387,
+                1160726944282883582,
+                2119364765206141036,
+                6464791768964988391,
+                8824016806539484116,
+                8270030586906660422,
+                15002907845345901548,
+                5776511587881140498,
+                8026215921305305807,
+                4715947225014261487,
+                1386404302622265399,
+                155879991043344208,
+                7029054386547293524,
+                11220489073691424833,
+                2733588115435171341,
+                5063187558440228172,
+                14208382232307280669,
+                3376720278729662133,
+                14690710213721258877,
+                11094816467682244196,
+                13929756812886477086,
+                14698847015580325706,
+                15992372099283576304,
+                5818288394714584003,
+                5038324365106419021,
+                506432342213631013,
+                7781480413773762085,
+                2275557418573742756,
+                8703301345083257514,
+                11424227525030059244,
+                8529590924998378026,
+                5577211436885609271,
+                16859602499387351883,
+                5848360527348246100,
+                2118117857825986323,
+                3178709503818913442,
+                14068618324826971348,
+                7820101807986528976,
+                16849757836429474618,
             ],
-        );
-        test(
-            &[10; 1098],
             &[
-                1563750116, 2761722522, 2234492700, 664378669, 2289696554, 166287485, 476834844,
-                2920420087, 2528304999, 1938173917, 3950825207, 4243596790, 2986888741, 3513584208,
-                1621935302, 3010962100, 97331004, 3497602837, 3156993605, 4246943277, 3773399914,
-                1791046376, 1330596670, 2066725731, 3080890361, 1411737887, 1547876370, 3080590448,
-                1532248923, 1435492412, 3448185497, 1125870178, 3236487585, 411921826, 2813696973,
-                3217407722, 3223519146, 3065092232, 2369951103, 2509352358, 926337125, 3014682136,
-                2966028824, 1505359010, 1713762975, 4092602064, 637732943, 1302647295, 2902550792,
-                889992967, 3865589903, 1419953312, 9792449, 3415320263, 564771153, 1688162383,
-                2484779041, 309493832, 218454065, 3482805065, 3854968150, 917520015, 394395502,
-                1316087688, 4013170326, 3611139371, 3969996396, 845435642, 1250928392, 3564637530,
-                3534519817, 4193257039, 1210838207, 1018604374, 1039438522, 3241956599, 283401613,
-                3791643370, 2495704071, 2807365421, 66163757, 3720427495, 929326153, 1056427022,
-                2224017890, 3983649303, 3242577483, 2252715700, 3763817420, 3945056819, 2536219802,
-                2347365037, 1332467980, 932313386, 1344905435, 1546705437, 2064541576, 1511380419,
-                3830839286, 1360902083, 3503290266, 3917441903, 1833414880, 3943874358, 1755117544,
-                2923194969, 2839238526, 1436154878, 2022570233, 2627252135, 514344885, 3629007310,
-                2734265902, 3693108522, 3208247898, 2234133971, 1972552128, 2954899309, 2020593517,
-                2046949131, 3731357545, 1937584680, 3590346900, 3944796673, 1847972007, 2243279972,
-                659814707, 354026232, 3897554349, 2861167827, 616720453, 3566033773, 667580062,
-                2925065641, 1209421025, 2590558623, 4061392256, 1200913167, 951116272, 3677973046,
-                3503505276, 3890324717, 588682794, 1822470598, 3062466072, 1622960923, 2217967478,
-                1671943310, 3797078111, 306673750, 416365363, 1127402537, 4051830565, 1295357578,
-                3597600014, 3944475003, 289083572, 792152829, 1866204223, 154676033, 1521441452,
-                3508161103, 925444108, 1492828246, 661274700, 3234551268, 2848116256, 2684114954,
-                1278505794, 1135004416, 1528837298, 903435517, 897822285, 1306717602, 1475128383,
-                1820901356, 1682104357, 2694156349, 1295051939, 3518824442, 250688398, 2216356021,
-                3513738778, 1822646861, 2230691522, 3766112863, 615919379, 1974329303, 1351423644,
-                2477642991, 1213629777, 1378792281, 2617577340, 2140073178, 2191340227, 2566413020,
-            ],
-            &[
-                3993985187, 418743558, 1313360511, 1682822200, 2190606963, 1153956890, 2732042229,
-                1286070126, 662863720, 4186279397, 540453600, 3865675059, 1267823400, 3515598059,
-                2652841176, 1202274130, 1747727592, 4024332644, 1774850346, 681999022, 3602470822,
-                2758087563, 1879963947, 3431635945, 246837220, 425774791, 1105979956, 2942553343,
-                1698889280, 2350552370, 3068812671, 3185616175, 3088938498, 878065968, 4173406915,
-                3359260462, 3381157370, 170884832, 4229318755, 2494013993, 1549083413, 4140845217,
-                4084551031, 3363271174, 234069829, 579586268, 409399673, 3823445787, 644521777,
-                2518846537, 536802144, 2920279334, 18872050, 1166062818, 330384075, 567479240,
-                242105288, 2256634487, 2099809688, 3182064703, 3455323968, 3796049588, 913224553,
-                1273986744, 1216752331, 3471953608, 4141617369, 113668956, 2271813874, 3836046471,
-                1442113087, 1985184453, 1605355495, 4002876308, 3555195054, 1327862010, 2467826608,
-                2786784068, 2229364499, 4162880057, 2362120653, 2428764072, 3253302211, 4041072194,
-                3342725375, 5091414, 4236090390, 645428080, 2998645452, 2029624491, 56740124,
-                2165228859, 3087533984, 1636492749, 2209776976, 3692581237, 607934780, 2356087899,
-                844604833, 3795358717, 1191407440, 2348446542, 2260870238, 3095317646, 2239633241,
-                1510395276, 1414456297, 1992793921, 2093060671, 3555947012, 2097207883, 2341507439,
-                1797902178, 3894103463, 589765482, 4279776370, 1462654158, 1306903445, 2072601153,
-                2881422521, 41492691, 12234573, 1317588012, 460035424, 2087095783, 1325294692,
-                639610198, 163158835, 583584804, 2753511772, 3964488699, 2486983401, 2238895215,
-                1588375790, 2681620680, 4165955199,
+                10900792384749518304,
+                1752565570529908396,
+                3402229115647561238,
+                2472381872242532960,
+                15748089475115162936,
+                1958855681762413475,
+                12100416912810779188,
+                12256578057348862042,
+                6556831077371185734,
+                15914846823057329492,
+                17346954154793811255,
+                17566187606614467459,
+                1433606366066775495,
+                9089332045922722756,
+                10056944581186126460,
+                5324425019386643029,
+                5281765195814058625,
+                1449711238109407238,
+                5903959110668039125,
+                3336955200427408551,
+                751494194154096512,
+                15350321905800137137,
+                12407374450431165353,
+                8705815621686854350,
+                18038286270431178148,
+                11671842546699641930,
+                9343865367071815679,
+                13401838367914321671,
+                18365991333043790435,
+                17428290065100096976,
+                6040216493892400727,
+                4224515713015397505,
+                16578741590625036060,
+                11835373548777581169,
+                18413478850867685366,
+                8453265724386285209,
+                5394500720474148965,
+                1927463313122594080,
+                4177838821929605731,
+                10680620304882583021,
+                180005403771618203,
+                2256408572502279608,
+                11718426532525535626,
+                14260315287467647015,
+                4035759666841010016,
+                16259497729863184485,
+                7772704202422133476,
+                6815813069474359325,
+                11207378575459431371,
+                18308033894506293455,
+                9875145231436590806,
+                15354934628544213986,
+                761822562304640527,
+                7391550101325083295,
+                4023926600201752832,
+                922969942182092752,
+                12110946035817932140,
+                16574399923422896843,
+                7087993004495856759,
+                8299428112066197874,
+                4589434828506762129,
+                13978205413565566735,
+                15675366647238478172,
+                7819770375827015142,
+                6823625407675143456,
+                2042269662440457350,
+                11521115322912473140,
+                13703874674141705702,
+                1295561690992462505,
+                12464082489717915012,
+                11378922861990148970,
+                2076282285705918066,
+                1390689690731346588,
+                13670979351308531000,
+                12980996477862222169,
+                10496970808504864546,
+                14015388605987660396,
+                4171129107047347396,
+                1656857204469415571,
+                17492457435753920912,
+                10132937897450237781,
+                5065601177732655021,
+                17498367701449356268,
+                9552937910825811119,
+                6213399497134928078,
+                12865519292113075754,
+                8548871019474664332,
+                12973212090641168109,
+                3018095992673320728,
+                4102580256148037725,
+                11411505815957245048,
+                8044142604358855954,
+                6163064685377006161,
+                7676133172626151339,
+                15177331097378985405,
+                923128391789363540,
+                8405355494789853124,
+                8409013636286216842,
+                17519952046647436442,
+                12690425880634822079,
+                7295927951214020420,
+                5103171252065286692,
+                4531269626482776566,
+                17509133779966482098,
+                16771567673323510549,
+                9940518318209913958,
+                2566490491730418524,
+                4997841530198583881,
+                11759671980624847072,
+                236198295111281753066320039251613319168,
             ],
             &[
-                847465617, 4013314980, 3846912964, 910246757, 2972404216, 2762134840, 1953196515,
-                4029750073, 4055861164, 1261155057, 4100430088, 2785511074, 497236120, 1367918422,
-                3738577206, 736241263, 650868678, 2349520844, 3387563635, 741561301, 2423229549,
-                2724745547, 894805326, 2982356553, 4201925489, 3718492797, 3268940283, 1316855216,
-                535979702, 1867135512, 2894454664, 1953954059, 3331547089, 3629484636, 809197002,
-                3453878941, 2710176999, 2236099000, 2474407754, 508610082, 3021250908, 950343151,
-                2347617926, 3116087366, 387686701, 1840236979, 608794169, 80987566, 2929948001,
-                3099591933, 1498594442, 976518465, 2412591727, 4182908032, 3079365048, 2450010282,
-                2281968581, 2243779001, 309401913, 3783895152, 2093682035, 2006514294, 2836109544,
-                964684103, 1645279117, 3042605589, 3518893342, 3753614018, 3245078642, 976146102,
-                470909372, 417907929, 584991811, 4141480919, 3596625836, 3530716922, 1149488478,
-                595234233, 353336372, 638993563, 341110766, 984515266, 1262652725, 843520981,
-                594906936, 3922870845, 2571018879, 2329734185, 4233366705, 2923004114, 168356226,
-                666545721, 3625364230, 2627653737, 2717371138, 3344260869, 893744508, 407453169,
-                1376334915, 1670800848, 320471216, 2114539766, 1671037181, 886024377, 1940914459,
-                1457430737, 1427559259, 3091470472, 2856003945, 2098123517, 1418768818, 2088924969,
-                3884270171, 233325771, 2475658630, 3306400235, 1042747488, 2251057616, 288186037,
-                1069520858, 1716749834, 2851756715, 3107695069, 4055132959, 1800778936, 1250813397,
-                4225902318, 3898227912, 2436303557, 4099682647, 4014271299, 2579688947, 2474049743,
-                1793988451, 1750223744, 2886556309, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10,
+                20742352318266030096293177166380232730,
+                94723301457440466416361925604863344972,
+                155183536715731359725724734972807873803,
+                17061437345925241038820470775729341170,
+                338506898704479034065745690014951359180,
+                43717027696126343423561096950046753223,
+                206684034774881171188140438639504808257,
+                128362937108829038734385905110008622486,
+                87151049887575354063311828417433827997,
+                107892718984211214989804758295369875609,
+                133084862829684806850772300726350656544,
+                162514062030914754550618977235417506648,
+                165058789069291257349934574537114824598,
+                277198514697041118196021022693506614024,
+                162437330453376083180926285797114271648,
+                115009772065128319924603953069583309992,
+                25032784687777136214732786490770845243,
+                309990914317713615214289572289342560873,
+                338425053361876946065759009700045990454,
+                286484716582605469312570945445056758964,
+                221448791532849325093372844686481444045,
+                48184057690022042858369535834656291060,
+                133463518587414034764016182199104582899,
+                269768493722217453576401034493697101015,
+                311399462258872415741769948300203172484,
+                336199315195315916858884708617820489732,
+                292461808440769384603385832465039000855,
+                160044893835039038185177186372249678825,
+                43077500716708167882078599652268708900,
+                17278205436056836544073021164440803717,
+                176098723893246998912359201661297865505,
+                291088199854254277441062049813536378358,
+                236749069104924285306779330678741514257,
+                309751541491435366200690390528030014747,
+                242592660498585046974387629250816575790,
+                197069240479898878928318508527868753008,
+                138121334554597194481794955090152058589,
+                201190108552643313768631488657961541631,
+                179356670441676310159917413488530877259,
+                268278222366096697835088143083328203292,
+                318794067005085262841166696210137700827,
+                40874837179785510504397890806951637667,
+                123680897609825506387154084268426827640,
+                313767975974249357541099465059334876157,
+                2071825044513907767090088049260374636,
+                47820933888185951661627471869627290113,
+                1838951709092742414966563787650644067,
+                21303017424012933601078884365605704751,
+                315336540493569213504098552397173653408,
+                73371653354488664452194272958603058630,
+                7899569006621393877762849624550836832,
+                294005508911525014699846739685614638579,
+                321186021925035495214246369016536290535,
+                111342425302680670795761389248502308739,
+                185184030704182123025230520153147770707,
+                120143741680765611634683681792388717452,
+                40548940365495920312595882362031199093,
+                315584031049945414768255297236347931770,
+                128429657094257742278183378006149207661,
+                213339978596035699725511197805519041735,
+                276636881407671864442999375275847114373,
+                218601924635614105410620737250297074483,
+                215159228025982146385124797722092243717,
+                250263457417253225050977444308477654497,
+                274108051538678202260221230622319734146,
+                338458728370068428906305401782402443348,
+                146638673322244377128455545054512142327,
+                290616929605143990122080933230805471356,
+                290137925119708215096944280716510400683,
+                308048490614971989705749056510286128673,
+                287481780070614051549853593929647052321,
+                214468729128431145492763836921198876301,
+                92094026635176584579480441125408971500,
+                94992787286133328605813416791535715325,
+                238011727156105406407021840486388051281,
+                159433896883228428704831388845453629049,
+                199226505992072492316531773924450000498,
+                225398069322266723442612494691510904788,
+                98758154007029378539724264158205283901,
+                9984942975157341635322683391337020783,
+                41696392447368022580731069966109777507,
+                340145781544529515098667713416349751295,
+                1333631518504782759373020349822009092,
+                170762070126059844317932615790711100968,
+                338323933533994395968192030944095143665,
+                333561148646672917954191738419072170064,
+                212620363480317011064454046042180208658,
+                339721215187917179977612512108875364465,
+                192451360041964269696463775037241915846,
+                547596688109051585356991083206600905,
+                232059254782548866439365381227829566193,
+                168810866532027429315705281994744558430,
+                271939674914452163330173781814334929363,
+                221169853109231922016131569450298400352,
+                32866146546623419946261051886210731429,
+                237389529845076669618654894800044203318,
+                176354393594468913139606226946515473516,
+                247054868789101133885962670558747342430,
+                46867675411888178421082247142448841336,
+                32293362349270467684268722851288568987,
+                10174094575812096055427502521482110128,
+                41887396864311506398387420304437279762,
+                172630137502233527538385796103527186787,
+                21261718901246453418783555040503655263,
+                96475545427260403767904622869149936999,
+                322020650748227864764835118409459375944,
+                180179252272572125238793009685533583381,
+                27047733118531912593295841986843255213,
+                175147865312411754147900051668116705028,
+                141864471564237168453481428607522268188,
+                17905884378428276079,
+                3713606437359152813,
+                9370125751473669209,
+                10012381228375856292,
+                6916709082927033810,
+                17606888822444197359,
+                10818646898854586102,
+                9927520028322801489,
+                17015575684851383261,
+                15287563104761130407,
+                4246630569287612899,
+                6737522138244324583,
+                16956805675707509108,
+                10507692348129622148,
+                1144006384258648355,
+                4260138925314579646,
+                14508270375905482517,
+                3559046762122289814,
+                15199903498605585717,
+                16432575538338639756,
             ],
         );
         test(
-            &[10; 915],
-            &[
-                685114319, 3257424506, 414393229, 2860628494, 121479429, 2579870766, 1597023839,
-                2474392621, 3223958133, 1457435159, 3048194581, 3047568591, 328216793, 3688729284,
-                1474966660, 3229714080, 1674178552, 3622356479, 2813923308, 420491140, 1181947036,
-                2552013519, 2545590339, 977030817, 1410754865, 418734423, 3219708398, 3814271579,
-                856825330, 886986018, 2340527841, 1574000724, 1326246490, 2345646501, 3649082184,
-                1757236341, 3333117097, 4191792834, 771549916, 4014890173, 1767239699, 1537408864,
-                860021926, 3301854273, 2439584990, 3450574632, 2067936331, 2217599411, 1936116337,
-                3184038132, 3501118169, 1312763670, 1815889084, 4185547518, 1921708290, 4252193163,
-                733366199, 1748333822, 3613571030, 2021878401, 674325326, 1834274504, 1974211381,
-                2155793730, 666543182, 3988638747, 719903603, 4243752700, 3417033998, 578937389,
-                1954345891, 438767411, 1067012960, 2140679028, 2616731558, 3608791372, 234168266,
-                115663374, 37715525, 3155431063, 1484074906, 2987669067, 1980354536, 201618151,
-                2443603378, 442745319, 2757914412, 1266139308, 4142086597, 1989465976, 3517367864,
-                1441253229, 1295109068, 2757883716, 1533532909, 4121897334, 3324479034, 33282683,
-                1821922930, 1002968212, 762196862, 13770263, 826603273, 4072569825, 780821896,
-                788706413, 2104768306, 1607373740, 341951230, 1675442736, 3679554432, 4040499065,
-                571466582, 467434507, 1883560688, 3831540337, 740943368, 2376257013, 1304728970,
-                917813781, 3342830532, 3374539547, 1366263565, 1830393744, 3801219913, 3174561984,
-                3973286677, 1398849159, 369072692, 656722452, 2994544393, 2007585192, 3393313477,
-                2976738602, 1184554260, 1566038994, 826752733, 477094709, 3837269061, 2769881480,
-                2709841643, 2163313442, 1223013930, 2855285371, 472880962, 695734454, 3106659328,
-                336220721, 2424316775, 1005851508, 3997249632, 3813922059, 4109122372, 1011074885,
-                44571353, 3135429322, 2678006854, 1812501650, 531726754, 684688016, 82728273,
-                2816638159, 2837354685, 3655899911, 36796549, 4088908020, 1199108102, 2363335387,
-                2702162409, 1359179115, 4038752961, 4132030616, 3472925013, 918360670, 1612076468,
-                1617334280, 3399902835, 1794719516, 1364930290, 3884047381, 1715844217, 2543581627,
-                1948226880, 3734808909, 1123962649, 6885664, 4055662667, 2036545059, 1825684950,
-                626135857, 3682021373, 2923868404, 1141437069, 301320286, 2038697946, 4203441370,
-                2625080149, 2416510088, 3453059660, 2196830401, 1003239496, 766384828, 1454135529,
-                3753927217, 289196672, 3027589815, 386319177, 4286570851, 34998813, 2808034465,
-                654631613, 2919774640, 3980646343, 3390105294, 3876707444, 342623382, 3063311246,
-                2951194817, 2409427609, 277380170, 1128962197, 512899487, 1130696384, 337608154,
-                4248250968, 2538526153, 408791364, 1355901969, 930023605, 619907788, 1270155017,
-                2669635170,
+            &[0],
+            &[0, 0],
+            &[0, 0, 1],
+            &[0, 170141183460469231731687303715884105728],
+            &[0, 0],
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn limbs_mod_barrett_fail_1() {
+    let ns = &[1, 2, 3];
+    let ds = &[0x80000000];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+#[should_panic]
+fn limbs_mod_barrett_fail_2() {
+    let ns = &[1, 2];
+    let ds = &[1, 0x80000000];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+#[should_panic]
+fn limbs_mod_barrett_fail_3() {
+    let ns = &[1, 2, 3];
+    let ds = &[1, 2];
+    let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(ns.len(), ds.len())];
+    limbs_mod_barrett(&mut [10, 10], &mut [10, 10, 10], ns, ds, &mut scratch);
+}
+
+#[test]
+fn test_limbs_mod() {
+    let test = |rs_in: &[Limb], ns: &[Limb], ds: &[Limb], rs_out: &[Limb]| {
+        let mut rs = rs_in.to_vec();
+        limbs_mod_to_out(&mut rs, ns, ds);
+        assert_eq!(rs, rs_out);
+
+        let rs = limbs_mod(ns, ds);
+        let rs_limit = ds.len();
+        assert_eq!(rs, &rs_out[..rs_limit]);
+        assert_eq!(&rs_in[rs_limit..], &rs_out[rs_limit..]);
+
+        verify_limbs_mod_2(rs_in, ns, ds, rs_out);
+    };
+    #[cfg(feature = "32_bit_limbs")]
+    {
+        let test_only_verify = |rs_in: &[Limb], ns: &[Limb], ds: &[Limb]| {
+            let mut rs = rs_in.to_vec();
+            limbs_mod_to_out(&mut rs, ns, ds);
+            let rs_out_alt = rs;
+
+            let rs = limbs_mod(ns, ds);
+            let rs_limit = ds.len();
+            let mut rs_out = rs_in.to_vec();
+            rs_out[..rs_limit].copy_from_slice(&rs);
+
+            assert_eq!(rs_out, rs_out_alt);
+            verify_limbs_mod_2(rs_in, ns, ds, &rs_out);
+        };
+        test(&[10; 4], &[1, 2], &[3, 4], &[1, 2, 10, 10]);
+        test(&[10; 4], &[1, 2, 3], &[4, 5], &[2576980381, 2, 10, 10]);
+        test(
+            &[10; 4],
+            &[1, 2, 3],
+            &[4, 0x80000000],
+            &[4294967273, 1, 10, 10],
+        );
+        test(&[10; 3], &[0; 3], &[0, 0, 1], &[0; 3]);
+        test(&[10; 3], &[0, 0, 1], &[0, 0, 1], &[0; 3]);
+        test(&[10; 3], &[0; 4], &[0, 0, 1], &[0; 3]);
+        test(&[10; 3], &[0, 0, 1], &[0, 1, 1], &[0, 0, 1]);
+        test(
+            &[10; 30],
+            &[
+                3099176493, 2686179191, 2963763290, 1498905807, 2459138342, 883505904, 186294937,
+                1240988195, 1152000807, 3485254691, 3058980612, 4051918579, 84687528, 3474110821,
+                825051253, 2495113655, 4269053297, 531158278, 2131423786, 735959410, 1116746011,
+                2099249419, 427789428, 2182383446, 3493666451, 2086611578, 2349523902, 1865831092,
+                104597580, 720145483, 1653677313, 2449086005, 1225118075, 1458687427, 2284889737,
+                1178000580, 1204354477, 1109887135, 2302764777, 1833071533, 2749496868, 3989183692,
+                3112262804, 2143914125, 71393498, 3686808425, 568295620, 856601035, 205234115,
+                617861388, 102368116, 561132401, 733711249, 2931194848, 1371887453, 2942553563,
+                977874680, 3567773306, 2225947571, 668306082, 3150218776, 3697051793, 4272816685,
+                2926797142, 2177250555, 2840203688, 51052169, 1663516163, 3085068676, 471736374,
+                3127282104, 1358199856, 250868071, 54398826, 2107868776, 1621910930, 1677400830,
+                20889047, 4180454819, 908186988, 1625301399, 817226551, 4039437379, 261787419,
+                1484678288, 1183594253, 3154399910, 888455895, 1713982625, 2925178936, 2076694919,
+                2288237521, 620657938, 2457761902, 2906110636, 649773865, 1387981473, 1600821385,
+                3512543520, 2803028516, 1584293468, 1443490691, 2589741655, 3170284085, 1902835632,
+                1603116322, 2850443356, 60694796, 779743237, 4006039758, 3272304347, 2442366350,
+                3601524312, 720808564, 3291979084, 1675880280, 4139440559, 3672978467, 3938651783,
+                3257918674, 2077369764, 3027135446, 3940308694, 2533432781, 4105883289, 626796131,
+                608794713, 810463080, 2363401530, 2800065942, 2679046183, 4063827982, 2327835929,
+                522607612, 1660561124, 817356463, 2984129158, 146372510, 4293731514, 2558750639,
+                3948304613, 1448810261, 826590770, 2423032883, 1050524737, 2770168039, 265887860,
+                3353243705, 2674401993, 1496121248, 2053790031, 1863298286, 1068977659, 2875518960,
+                4137037281, 2888534695, 473250405, 3368783492, 3797281057, 132028186, 316202261,
+                381223941, 3366394660, 425124513, 4083952857, 2301832392, 3321645917, 509444341,
+                361894284, 3976042471, 2957832871, 250836826, 1408114735, 1614725824, 4214313885,
+                4081964203, 2663894263, 145380453, 2329325761, 2055092903, 126874014, 1000460254,
+                3910319503, 2561073113, 4231784000, 1042785346, 3511487116, 1270832223, 3456586087,
+                1210528079, 4119713948, 3299598065, 4178946856, 1623358249, 1001721969, 3964704699,
+                4133343553, 2193492316, 407893767, 2484237727, 3782842008, 2959959832, 449539588,
+                2978317444, 4008576738, 3478248618, 98697842, 3986898689, 1622966191, 3594042026,
+                3280563264, 270713233, 3999415188, 401848066, 623805281, 1710411200, 1801291443,
+                640646012, 2283134929, 1043531051,
             ],
             &[
-                933436633, 2314417619, 1779862136, 2339830299, 3359221691, 1983848875, 2097698892,
-                1645402710, 49190984, 3806363526, 2374325643, 638588450, 3467828663, 2693267297,
-                3081019625, 2568134532, 3644457728, 2630819968, 707790566, 1984505565, 3749563552,
-                3700374589, 2579510542, 4246015133, 1527325532, 3034605869, 2134963426, 3613350443,
-                2082268909, 3145097012, 497158738, 1750605816, 1683654362, 1392048080, 2595287102,
-                1859642116, 3880370994, 773829956, 2727575776, 868421082, 910865307, 4010486592,
-                72360528, 2330397412, 2764112435, 2833247271, 1236763483, 1139702723, 3454679019,
-                3138128998, 3268622050, 3622582141, 1726130545, 2831795892, 391680447, 1964919870,
-                2937963749, 260385042, 1893950828, 1182888075, 3460030123, 2590897592, 3391091057,
-                3700415037, 1619162545, 1524016666, 1313243906, 3716478858, 1054838129, 1929422210,
-                4093970820, 1243478860, 3650034984, 3598628391, 99479112, 2103638976, 3422493162,
-                3133671222, 3540259323, 1874029880, 1634209319, 2379655185, 1946213151, 2520479253,
+                3120245340, 1558358768, 2311090615, 3377497278, 2047683134, 1453801550, 1974940808,
+                1545066418, 2840151982, 2463969682, 2025473894, 697610529, 906262319,
             ],
             &[
-                4135839129, 3082159379, 2808741151, 2746492478, 1317271925, 1590248590, 1871245480,
-                883232624, 3985674442, 3997584338, 2338792001, 1776575346, 2531584902, 261625854,
-                3578066182, 2058830353, 684820033, 3683099826, 1776182826, 2182228087, 919424929,
-                4095708279, 1423878550, 172604911, 255380658, 4104949687, 3178922494, 1444103543,
-                1691042525, 1011488353, 3955571774, 2253259467, 3143874569, 377143694, 2390377782,
-                1070304427, 1715840158, 2972468795, 455414172, 3300053546, 2552753048, 3276730351,
-                1581696761, 1405031742, 2503564946, 1980393840, 2686153828, 3279538716, 1074513470,
-                2389426005, 592338809, 348493719, 3669366843, 2086362650, 1888752201, 1485850549,
-                3098846363, 839653456, 2380177511, 1732519385, 1998829691, 3296699081, 2705709135,
-                2848494034, 4155180828, 1425421469, 3752183557, 2319259329, 2757221818, 1921158733,
-                3302049214, 1696454223, 3356952349, 3100878977, 324054921, 2131412976, 1078305944,
-                698318350, 4151030129, 2259288990, 762849915, 3134288938, 4090864118, 1223661238,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                4239744213, 626950715, 3916878897, 2974637695, 488695410, 1094975104, 2496621051,
+                205419221, 4192241096, 3583664917, 511224033, 2016541230, 718692763, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 215],
+            &[
+                59488434, 3053025912, 3197673861, 28499967, 919132002, 3254889605, 3510992086,
+                4155414397, 1401076337, 3663475665, 3859836963, 838452584, 1714061888, 756807500,
+                721664913, 2122189093, 190377396, 772161692, 337532878, 2801246946, 3347165247,
+                504261973, 4207433768, 3203836949, 1637702776, 3950126612, 3575550053, 630469114,
+                2468674532, 3237190898, 2699368075, 2126693510, 2005217541, 990977199, 211751826,
+                4095342386, 2390539499, 553381774, 1202910267, 3124334908, 3631027545, 3118560130,
+                3505258080, 2533393565, 3897399802, 1644233433, 2966724466, 1228506720, 4266980301,
+                1975806255, 3087455989, 430105567, 2496389669, 2559019944, 1343075842, 515604272,
+                2623722375, 3549762134, 3517683279, 3564893038, 3816995397, 21727446, 2930818039,
+                2672793843, 1589653728, 2917937865, 665591277, 1222288512, 1197537333, 2425822187,
+                3647080994, 3180811677, 3800694132, 4006852228, 1392314914, 1823809919, 3315974102,
+                2004045597, 2557007205, 532899885, 1088861606, 3969820933, 711573681, 3744617855,
+                3618206965, 3834838995, 601286347, 1494520421, 2007503431, 3672534190, 3437739925,
+                3075991743, 1108097829, 438830502, 854842946, 798336207, 2497251014, 985928236,
+                1930130581, 260199427, 3446617597,
+            ],
+            &[4005818469, 1809723722],
+            &[
+                1137651599, 55996911, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
@@ -2808,6 +3082,25 @@ fn test_limbs_mod() {
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 178],
+            &[
+                3300759985, 47489222, 1596412201, 3681499224, 721942549, 2798268772, 1725678834,
+                1018213306, 278963757, 2162182910, 4238919550, 1480123217, 3550714331, 2714414047,
+                1584331762, 3790545549, 2452172382, 3823866136, 3793112677,
+            ],
+            &[
+                2532879920, 749327979, 378994598, 3512427187, 1768486327, 874381062, 3750599339,
+                1824619676, 4102262081, 3872688397, 4080392661, 1233784995, 3967512105, 536874,
+                1334357500, 533131065, 3802334171, 2688028352,
+            ],
+            &[
+                687097313, 233246948, 2670005176, 2918696316, 2059690051, 3559547370, 982825692,
+                2828616847, 3397764024, 1640472335, 1827782800, 239756415, 4169556349, 1129911247,
+                2514993175, 2469589340, 3137350801, 1512435665, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
@@ -2815,12 +3108,432 @@ fn test_limbs_mod() {
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
                 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
-                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10,
             ],
         );
         test(
-            &[10; 1458],
+            &[10; 6],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[3, 4, 5, 6, 7, 0x80000000],
+            &[718, 910, 1080, 1286, 1492, 2147483434],
+        );
+        test(
+            &[10; 4],
+            &[0, 0, 0, 3, u32::MAX, 0x80000000],
+            &[3, 4, u32::MAX, 0x80000000],
+            &[3, 4, 0xfffffffc, 0x7fffffff],
+        );
+        test(
+            &[10; 10],
+            &[500160962, 3482059973, 3833374734, 2382534866, 7345183],
+            &[1962915382, 2761901894, 931109938],
+            &[3497463394, 501014622, 297308821, 10, 10, 10, 10, 10, 10, 10],
+        );
+        test(
+            &[10; 20],
             &[
+                3406830026, 653096149, 665840651, 3475733215, 2990001405, 3962996135, 1125790437,
+                510807273, 2268497988, 3801098676, 4251717337, 4009243500, 3093915781, 2221063229,
+                1145961534, 1520950302, 4156341537, 4051749345, 1887697916, 2938053362, 1317017771,
+                2551451381, 1165783725, 1692722530,
+            ],
+            &[
+                757200076, 1850648953, 1310285965, 43796838, 2811750593, 2350568971, 1162649579,
+                3588922014, 843685186, 327689313, 822131258, 632127361, 4287259347, 855554137,
+                2978907761, 2140676852,
+            ],
+            &[
+                1472562202, 467285767, 3874015352, 3774608073, 3547803297, 3252310888, 379016022,
+                3915526178, 172347662, 1424794357, 2354655682, 1763403834, 1622341466, 1527845266,
+                321699454, 1968063162, 10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 3],
+            &[3156053040, 2869923590, 2300216426, 1369503],
+            &[177914576, 1102898054, 1547778],
+            &[0, 0, 0],
+        );
+        test(
+            &[10; 255],
+            &[
+                4077004042, 60445643, 361888880, 1370511290, 4140611, 1545574856, 337863511,
+                2696753299, 882513095, 2220102704, 4040224968, 3895008884, 3144563393, 2298197836,
+                2781817257, 1704369652, 3931316078, 315544063, 3497851423, 3664143565, 952326443,
+                3938628137, 2331019960, 3709840874, 886597817, 2718223965, 2271519236, 1069952925,
+                2973484971, 694036791, 3948658826, 1091836994, 3976014345, 2590014773, 1030558914,
+                3050284515, 4064531951, 227487465, 1614818201, 2853653798, 312516670, 1131265287,
+                1079324522, 2790732553, 2857570668, 2459258033, 3676592728, 1861660079, 3846516218,
+                2487383610, 820186200, 670229005, 795815915, 4236865449, 388462685, 3530199852,
+                4067109237, 228011739, 3243897206, 2102622551, 3066279582, 2307396469, 3918457987,
+                3177100158, 1165801255, 3470877002, 730654273, 225744395, 303984647, 71923315,
+                2631641933, 642193977, 214491076, 4083126324, 1300818039, 3145270519, 3071074628,
+                533164498, 2260983885, 2398373112, 2291850622, 2549903002, 2876921663, 2087750942,
+                3012568578, 2072377257, 2300996389, 1724754966, 3663537905, 2452259556, 681943280,
+                495483009, 1806854180, 2022877157, 3545541285, 3155213185, 1520127898, 881038528,
+                2682219847, 2061051159, 4091777429, 2973569703, 2243163157, 3812478845, 2868568231,
+                538252316, 915738068, 3645567840, 557499318, 586868707, 334386881, 1009292772,
+                9683309, 1394382805, 1469121902, 3538152891, 192398621, 3298083372, 291538032,
+                454155529, 3467440904, 107593274, 2581455983, 4068643130, 1289926312, 2620830750,
+                3599696168, 845286964, 93649777, 211929373, 405469122, 2046160625, 4269019707,
+                3153165259, 3273675158, 740768718, 1724953971, 4107531497, 3669145824, 2308427932,
+                4057339865, 30691770, 4215434099, 2209368084, 3410096812, 3930010459, 4058257897,
+                1401228000, 3205298856, 919645629, 1080800085, 3531478127, 4244298042, 1920158944,
+                4150416695, 2942997287, 621554087, 2193963845, 3535984780, 1948458592, 1237268195,
+                4059838770, 4178023707, 2225635923, 1078133440, 1904752780, 1129557671, 2721601376,
+                3991770285, 2491529378, 2424674576, 4164361438, 1566434777, 3112804218, 2185237562,
+                2557399204, 967876237, 2243639679, 2436745635, 2376273731, 3848434966, 1970386149,
+                294413738, 1420657192, 694358663, 647017168, 4094657777, 3013362236, 590143693,
+                2285757920, 2393504081, 116938458, 891518480, 696284131, 2710811430, 3317562620,
+                1647436225, 1605219555, 3358343198, 2183786684, 1105785201, 248367120, 1398964063,
+                3309346508, 3273593185, 2032930327, 2675472140, 557577078, 692086759, 1588022257,
+                1734092444, 927029618, 3696557822, 3370092072, 2151067302, 2650483754, 3670460764,
+                2763985465, 1126450086, 3029345718, 1504895755, 3694166108, 578785502, 294874178,
+                110079616, 1327583117, 2661938072, 2345632606, 3722933355, 656745495, 671269721,
+                2346516327, 1169529001, 4238866211, 949351039, 3500460463, 4199863511, 2082398726,
+                1281275042, 2560151133, 1138051905, 468589983, 727511715, 2554428159, 2014177155,
+                3346720979, 1317583392, 1714879892, 4249909774, 2158352329, 3040382616, 964600510,
+                319055149, 890296957, 2071841867, 2811829970, 3945344817, 4075668231, 1336582250,
+                1632615222, 2996499016, 1247703908, 2161089989, 1757474064, 1957983140, 2427088146,
+                3819098678, 1414774317, 1685786700, 2842967660, 2434489739, 2748571761, 2393986575,
+                585646425, 733271380, 4096908487, 866235842, 1811304066, 2337315068, 4064326552,
+                2517677609, 595634311, 929413085, 2556167349, 2814648343, 3447175234, 4171314821,
+                3354462286, 2190450759, 3693163187, 817168953, 753817804, 2679932775, 2584000650,
+                511478235, 2858863655, 2320104001, 2367392444, 1724096919, 1152655320, 3131579385,
+                2076635048, 3557452129, 3931367674, 1011051727, 3910860016, 3742755232, 3977120817,
+                178461096, 1938068862, 1480470517, 2997286985, 196403685, 3581556360, 3800228840,
+                2583177118, 3801520456, 1254931401, 3392702841, 1046934176, 412728369, 2864978525,
+                970771733, 672204372, 1528132877, 2673063996, 740759188, 1840165684, 1821213771,
+                3111387733, 2386566079, 2682984985, 808050061, 4160818440, 1656303941, 2884647798,
+                3997066586, 3727980283, 4181671626, 2736389654, 1808865173, 1642983278, 875419327,
+                3960909969, 3751011271, 2807559080, 1535615269, 2321637137, 2745054513, 3020092780,
+                1359015906, 2466514344, 591997660, 2754906030, 3487658632, 3722637404, 1613546148,
+                915678357, 1842708127, 701921987, 1207101857, 1974760740, 2410755764, 3324705651,
+                3105821672, 335929431, 1887715703, 4035171395, 2815003797, 3632947325, 3664189665,
+                2211203246, 1473602679, 3802261181, 3699100807, 3964975584, 2757250875, 3240191191,
+                3824907808, 1582581356, 116402974, 2321914135, 933007824, 136161346, 2857937718,
+                1044097090, 477414473, 3966936277, 21169877, 2518410639, 1938987054, 4164953085,
+                3287593768, 96004465, 3316812094, 2635456314, 2931969475, 3535012794, 3954299175,
+                4188146823, 1054040153, 4020697192, 3043463112, 487456176, 1536604607, 2191156801,
+                2869189759, 650124563, 54254415, 910946976, 3240585021, 4240104628, 989174439,
+                3235043341, 607641178, 2910679971, 4072297259, 2664931864, 2560203429, 3992282762,
+                480443057, 3944140064, 1601223002, 3984987075, 3894479377, 1082591102, 2444518988,
+                2566734417, 1272094512, 2581007089, 3838472665, 2810473520, 1590808097, 143027202,
+                1667662742, 3686892725, 228309572, 4091365295, 1107025920, 938240502, 567559985,
+                2237721627, 1939039548, 3053102548, 3379831217, 3536253061, 1586694963, 931323468,
+                593457460, 1981974171, 443146100, 11888347, 2403661012, 2646485528, 528884126,
+                1040587284, 2828170682, 512577110, 3534338212, 1642316111, 124785841,
+            ],
+            &[
+                3924577409, 3801138880, 2554871927, 1797946680, 1773117295, 3509783608, 281892096,
+                2358224613, 4217828194, 1339769957, 439570060, 3151617679, 1384372613, 4141908033,
+                3034076248, 201556707, 1940999750, 1134129834, 1062995144, 3653931665, 1653789920,
+                2219526834, 849219893, 3448402897, 890163003, 378127096, 3324725657, 2858888153,
+                4101277783, 1736762052, 4083759525, 1398269743, 946713296, 1332088349, 1241448676,
+                820326205, 1554372778, 2902257209, 2531752530, 593903741, 283370156, 1184475111,
+                4048000423, 3262885223, 3065903568, 2181798675, 970937186, 2831985588, 3318099557,
+                125775489, 2602810229, 1265835529, 370295842, 3885440035, 332195328, 803805465,
+                2339766805, 3074827324, 176637217, 2717782694, 3228882886, 2079180306, 1985828369,
+                1451458086, 2647399511, 653380804, 2816232893, 580123271, 1284828784, 1488365849,
+                3992136885, 1712696753, 3200712671, 4019248351, 2502831049, 3353947366, 3805407296,
+                3975182552, 1255379188, 3079539536, 2754826248, 2654365805, 1932758080, 2018940849,
+                3987435655, 2107608701, 1979416324, 2553264951, 2332482333, 3854067399, 7383141,
+                2032883058, 209316404, 1509786266, 891329562, 2446773803, 981357189, 289424276,
+                1563495883, 1124421740, 4019623418, 229810471, 1293459273, 685378813, 763089605,
+                543868756, 4294383498, 4256223402, 3521141578, 1300426165, 4203249157, 2446225842,
+                3483103616, 2930204118, 3459214772, 2242535858, 2845721016, 2523036196, 1191008269,
+                1438983871, 1990994626, 1369473842, 3325530252, 2436280648, 3247037040, 1827745637,
+                1440370214, 565710731, 918521819, 3174181412, 2021188910, 1292274207, 3161583133,
+                1940037031, 1320244184, 1944735324, 1624924970, 1914256377, 1558283566, 1557557944,
+                819014915, 3477749819, 2317549528, 1815788616, 3410581584, 865672422, 3902451420,
+                47231850, 2052078859, 2128580726, 2861615294, 2875806926, 2504678981, 2922243612,
+                2567002225, 3176460060, 3501319351, 256937154, 986867411, 2877370533, 3829593455,
+                169449010, 1762966206, 126552381, 3303194639, 437611604, 2065984359, 215674808,
+                2549495983, 226202732, 19733282, 265226462, 732006741, 2011526642, 2592527346,
+                3141322109, 980881336, 4085884669, 2038689570, 3704380296, 1688979285, 1869912321,
+                1472588169, 3395855158, 2409096192, 2603014340, 2571643809, 1244430574, 2331499515,
+                234553812, 3949996932, 1906820531, 1849149876, 4204335779, 1113287279, 1165814564,
+                3834491692, 3992932111, 1545656810, 1251393152,
+            ],
+            &[
+                1301325451, 2202463531, 827674839, 2990077551, 3515177881, 1362651403, 481136630,
+                3397298241, 3518449263, 76128572, 4225087952, 1094548681, 282440964, 1243437992,
+                2536206935, 1312305087, 2350135248, 1191467757, 772049416, 1651785710, 13757971,
+                4177684085, 4225218737, 3829973687, 4224488792, 835349925, 807373018, 2003072240,
+                1998180420, 1893011542, 2772647530, 1845722545, 3927750947, 1582656468, 3929302887,
+                2309901890, 2219253077, 74452907, 3721812597, 3102504646, 1837697683, 4169869788,
+                1109227870, 1893389948, 1637025892, 401123956, 2362840033, 3249816285, 1331916977,
+                11330013, 1790389738, 4046514714, 4242300773, 386281183, 4154390602, 2907471583,
+                596332521, 1031920105, 1416982457, 936732589, 620289351, 2021344934, 4134683536,
+                254271151, 3153428294, 2775130118, 767392350, 1524517878, 1803212363, 1935475282,
+                476597337, 1665283842, 385826494, 1418089792, 1583155781, 904332458, 2167494919,
+                4213977373, 2749948942, 1366862995, 974249813, 2151831423, 2426239456, 4154619782,
+                1909704093, 82889380, 1836307000, 1860128761, 2497608852, 302875545, 2512570768,
+                3465381901, 3664521586, 2113690348, 3208498261, 211553098, 1122435807, 3989762258,
+                1790955068, 1274985561, 801567833, 206607829, 1945509947, 2689438654, 630881630,
+                3166357111, 950757161, 2525660644, 2782583030, 2082925684, 4017471838, 2770389652,
+                3152723497, 2730012549, 375610667, 331640140, 3189394626, 1169047456, 1269402316,
+                366259196, 659129976, 1835642575, 1109092533, 1409510258, 1055228915, 3865844484,
+                1951513725, 1561115766, 1535328235, 566121235, 1218990885, 2397023975, 110038834,
+                410651474, 2293988363, 1341825237, 3049031992, 1068909343, 3433095008, 226980250,
+                234888265, 3753182189, 712474545, 3392300015, 358854770, 2053632965, 2314494072,
+                3270641699, 1763895670, 4027033942, 3610557903, 1952033954, 3311300088, 2108677074,
+                2293958755, 649449413, 1137877459, 1632674803, 3368118971, 4027363661, 3533599056,
+                4112294692, 1992613374, 2157460184, 2344709644, 3126961604, 3915648450, 163946806,
+                3452706048, 3278493797, 3569418312, 619110361, 1791108219, 53432167, 1675611199,
+                3468608269, 3380246190, 3977636639, 2303818017, 2959386722, 4259696814, 2826759181,
+                1786462977, 974733518, 2879253522, 3102475706, 2731858652, 2537398244, 2096104946,
+                3000514581, 821433471, 3175348116, 2836803346, 3386301193, 400757797, 3528093517,
+                689295706, 494008951, 3664544857, 1053036864, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 1098],
+            &[
+                1563750116, 2761722522, 2234492700, 664378669, 2289696554, 166287485, 476834844,
+                2920420087, 2528304999, 1938173917, 3950825207, 4243596790, 2986888741, 3513584208,
+                1621935302, 3010962100, 97331004, 3497602837, 3156993605, 4246943277, 3773399914,
+                1791046376, 1330596670, 2066725731, 3080890361, 1411737887, 1547876370, 3080590448,
+                1532248923, 1435492412, 3448185497, 1125870178, 3236487585, 411921826, 2813696973,
+                3217407722, 3223519146, 3065092232, 2369951103, 2509352358, 926337125, 3014682136,
+                2966028824, 1505359010, 1713762975, 4092602064, 637732943, 1302647295, 2902550792,
+                889992967, 3865589903, 1419953312, 9792449, 3415320263, 564771153, 1688162383,
+                2484779041, 309493832, 218454065, 3482805065, 3854968150, 917520015, 394395502,
+                1316087688, 4013170326, 3611139371, 3969996396, 845435642, 1250928392, 3564637530,
+                3534519817, 4193257039, 1210838207, 1018604374, 1039438522, 3241956599, 283401613,
+                3791643370, 2495704071, 2807365421, 66163757, 3720427495, 929326153, 1056427022,
+                2224017890, 3983649303, 3242577483, 2252715700, 3763817420, 3945056819, 2536219802,
+                2347365037, 1332467980, 932313386, 1344905435, 1546705437, 2064541576, 1511380419,
+                3830839286, 1360902083, 3503290266, 3917441903, 1833414880, 3943874358, 1755117544,
+                2923194969, 2839238526, 1436154878, 2022570233, 2627252135, 514344885, 3629007310,
+                2734265902, 3693108522, 3208247898, 2234133971, 1972552128, 2954899309, 2020593517,
+                2046949131, 3731357545, 1937584680, 3590346900, 3944796673, 1847972007, 2243279972,
+                659814707, 354026232, 3897554349, 2861167827, 616720453, 3566033773, 667580062,
+                2925065641, 1209421025, 2590558623, 4061392256, 1200913167, 951116272, 3677973046,
+                3503505276, 3890324717, 588682794, 1822470598, 3062466072, 1622960923, 2217967478,
+                1671943310, 3797078111, 306673750, 416365363, 1127402537, 4051830565, 1295357578,
+                3597600014, 3944475003, 289083572, 792152829, 1866204223, 154676033, 1521441452,
+                3508161103, 925444108, 1492828246, 661274700, 3234551268, 2848116256, 2684114954,
+                1278505794, 1135004416, 1528837298, 903435517, 897822285, 1306717602, 1475128383,
+                1820901356, 1682104357, 2694156349, 1295051939, 3518824442, 250688398, 2216356021,
+                3513738778, 1822646861, 2230691522, 3766112863, 615919379, 1974329303, 1351423644,
+                2477642991, 1213629777, 1378792281, 2617577340, 2140073178, 2191340227, 2566413020,
+            ],
+            &[
+                3993985187, 418743558, 1313360511, 1682822200, 2190606963, 1153956890, 2732042229,
+                1286070126, 662863720, 4186279397, 540453600, 3865675059, 1267823400, 3515598059,
+                2652841176, 1202274130, 1747727592, 4024332644, 1774850346, 681999022, 3602470822,
+                2758087563, 1879963947, 3431635945, 246837220, 425774791, 1105979956, 2942553343,
+                1698889280, 2350552370, 3068812671, 3185616175, 3088938498, 878065968, 4173406915,
+                3359260462, 3381157370, 170884832, 4229318755, 2494013993, 1549083413, 4140845217,
+                4084551031, 3363271174, 234069829, 579586268, 409399673, 3823445787, 644521777,
+                2518846537, 536802144, 2920279334, 18872050, 1166062818, 330384075, 567479240,
+                242105288, 2256634487, 2099809688, 3182064703, 3455323968, 3796049588, 913224553,
+                1273986744, 1216752331, 3471953608, 4141617369, 113668956, 2271813874, 3836046471,
+                1442113087, 1985184453, 1605355495, 4002876308, 3555195054, 1327862010, 2467826608,
+                2786784068, 2229364499, 4162880057, 2362120653, 2428764072, 3253302211, 4041072194,
+                3342725375, 5091414, 4236090390, 645428080, 2998645452, 2029624491, 56740124,
+                2165228859, 3087533984, 1636492749, 2209776976, 3692581237, 607934780, 2356087899,
+                844604833, 3795358717, 1191407440, 2348446542, 2260870238, 3095317646, 2239633241,
+                1510395276, 1414456297, 1992793921, 2093060671, 3555947012, 2097207883, 2341507439,
+                1797902178, 3894103463, 589765482, 4279776370, 1462654158, 1306903445, 2072601153,
+                2881422521, 41492691, 12234573, 1317588012, 460035424, 2087095783, 1325294692,
+                639610198, 163158835, 583584804, 2753511772, 3964488699, 2486983401, 2238895215,
+                1588375790, 2681620680, 4165955199,
+            ],
+            &[
+                847465617, 4013314980, 3846912964, 910246757, 2972404216, 2762134840, 1953196515,
+                4029750073, 4055861164, 1261155057, 4100430088, 2785511074, 497236120, 1367918422,
+                3738577206, 736241263, 650868678, 2349520844, 3387563635, 741561301, 2423229549,
+                2724745547, 894805326, 2982356553, 4201925489, 3718492797, 3268940283, 1316855216,
+                535979702, 1867135512, 2894454664, 1953954059, 3331547089, 3629484636, 809197002,
+                3453878941, 2710176999, 2236099000, 2474407754, 508610082, 3021250908, 950343151,
+                2347617926, 3116087366, 387686701, 1840236979, 608794169, 80987566, 2929948001,
+                3099591933, 1498594442, 976518465, 2412591727, 4182908032, 3079365048, 2450010282,
+                2281968581, 2243779001, 309401913, 3783895152, 2093682035, 2006514294, 2836109544,
+                964684103, 1645279117, 3042605589, 3518893342, 3753614018, 3245078642, 976146102,
+                470909372, 417907929, 584991811, 4141480919, 3596625836, 3530716922, 1149488478,
+                595234233, 353336372, 638993563, 341110766, 984515266, 1262652725, 843520981,
+                594906936, 3922870845, 2571018879, 2329734185, 4233366705, 2923004114, 168356226,
+                666545721, 3625364230, 2627653737, 2717371138, 3344260869, 893744508, 407453169,
+                1376334915, 1670800848, 320471216, 2114539766, 1671037181, 886024377, 1940914459,
+                1457430737, 1427559259, 3091470472, 2856003945, 2098123517, 1418768818, 2088924969,
+                3884270171, 233325771, 2475658630, 3306400235, 1042747488, 2251057616, 288186037,
+                1069520858, 1716749834, 2851756715, 3107695069, 4055132959, 1800778936, 1250813397,
+                4225902318, 3898227912, 2436303557, 4099682647, 4014271299, 2579688947, 2474049743,
+                1793988451, 1750223744, 2886556309, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 915],
+            &[
+                685114319, 3257424506, 414393229, 2860628494, 121479429, 2579870766, 1597023839,
+                2474392621, 3223958133, 1457435159, 3048194581, 3047568591, 328216793, 3688729284,
+                1474966660, 3229714080, 1674178552, 3622356479, 2813923308, 420491140, 1181947036,
+                2552013519, 2545590339, 977030817, 1410754865, 418734423, 3219708398, 3814271579,
+                856825330, 886986018, 2340527841, 1574000724, 1326246490, 2345646501, 3649082184,
+                1757236341, 3333117097, 4191792834, 771549916, 4014890173, 1767239699, 1537408864,
+                860021926, 3301854273, 2439584990, 3450574632, 2067936331, 2217599411, 1936116337,
+                3184038132, 3501118169, 1312763670, 1815889084, 4185547518, 1921708290, 4252193163,
+                733366199, 1748333822, 3613571030, 2021878401, 674325326, 1834274504, 1974211381,
+                2155793730, 666543182, 3988638747, 719903603, 4243752700, 3417033998, 578937389,
+                1954345891, 438767411, 1067012960, 2140679028, 2616731558, 3608791372, 234168266,
+                115663374, 37715525, 3155431063, 1484074906, 2987669067, 1980354536, 201618151,
+                2443603378, 442745319, 2757914412, 1266139308, 4142086597, 1989465976, 3517367864,
+                1441253229, 1295109068, 2757883716, 1533532909, 4121897334, 3324479034, 33282683,
+                1821922930, 1002968212, 762196862, 13770263, 826603273, 4072569825, 780821896,
+                788706413, 2104768306, 1607373740, 341951230, 1675442736, 3679554432, 4040499065,
+                571466582, 467434507, 1883560688, 3831540337, 740943368, 2376257013, 1304728970,
+                917813781, 3342830532, 3374539547, 1366263565, 1830393744, 3801219913, 3174561984,
+                3973286677, 1398849159, 369072692, 656722452, 2994544393, 2007585192, 3393313477,
+                2976738602, 1184554260, 1566038994, 826752733, 477094709, 3837269061, 2769881480,
+                2709841643, 2163313442, 1223013930, 2855285371, 472880962, 695734454, 3106659328,
+                336220721, 2424316775, 1005851508, 3997249632, 3813922059, 4109122372, 1011074885,
+                44571353, 3135429322, 2678006854, 1812501650, 531726754, 684688016, 82728273,
+                2816638159, 2837354685, 3655899911, 36796549, 4088908020, 1199108102, 2363335387,
+                2702162409, 1359179115, 4038752961, 4132030616, 3472925013, 918360670, 1612076468,
+                1617334280, 3399902835, 1794719516, 1364930290, 3884047381, 1715844217, 2543581627,
+                1948226880, 3734808909, 1123962649, 6885664, 4055662667, 2036545059, 1825684950,
+                626135857, 3682021373, 2923868404, 1141437069, 301320286, 2038697946, 4203441370,
+                2625080149, 2416510088, 3453059660, 2196830401, 1003239496, 766384828, 1454135529,
+                3753927217, 289196672, 3027589815, 386319177, 4286570851, 34998813, 2808034465,
+                654631613, 2919774640, 3980646343, 3390105294, 3876707444, 342623382, 3063311246,
+                2951194817, 2409427609, 277380170, 1128962197, 512899487, 1130696384, 337608154,
+                4248250968, 2538526153, 408791364, 1355901969, 930023605, 619907788, 1270155017,
+                2669635170,
+            ],
+            &[
+                933436633, 2314417619, 1779862136, 2339830299, 3359221691, 1983848875, 2097698892,
+                1645402710, 49190984, 3806363526, 2374325643, 638588450, 3467828663, 2693267297,
+                3081019625, 2568134532, 3644457728, 2630819968, 707790566, 1984505565, 3749563552,
+                3700374589, 2579510542, 4246015133, 1527325532, 3034605869, 2134963426, 3613350443,
+                2082268909, 3145097012, 497158738, 1750605816, 1683654362, 1392048080, 2595287102,
+                1859642116, 3880370994, 773829956, 2727575776, 868421082, 910865307, 4010486592,
+                72360528, 2330397412, 2764112435, 2833247271, 1236763483, 1139702723, 3454679019,
+                3138128998, 3268622050, 3622582141, 1726130545, 2831795892, 391680447, 1964919870,
+                2937963749, 260385042, 1893950828, 1182888075, 3460030123, 2590897592, 3391091057,
+                3700415037, 1619162545, 1524016666, 1313243906, 3716478858, 1054838129, 1929422210,
+                4093970820, 1243478860, 3650034984, 3598628391, 99479112, 2103638976, 3422493162,
+                3133671222, 3540259323, 1874029880, 1634209319, 2379655185, 1946213151, 2520479253,
+            ],
+            &[
+                4135839129, 3082159379, 2808741151, 2746492478, 1317271925, 1590248590, 1871245480,
+                883232624, 3985674442, 3997584338, 2338792001, 1776575346, 2531584902, 261625854,
+                3578066182, 2058830353, 684820033, 3683099826, 1776182826, 2182228087, 919424929,
+                4095708279, 1423878550, 172604911, 255380658, 4104949687, 3178922494, 1444103543,
+                1691042525, 1011488353, 3955571774, 2253259467, 3143874569, 377143694, 2390377782,
+                1070304427, 1715840158, 2972468795, 455414172, 3300053546, 2552753048, 3276730351,
+                1581696761, 1405031742, 2503564946, 1980393840, 2686153828, 3279538716, 1074513470,
+                2389426005, 592338809, 348493719, 3669366843, 2086362650, 1888752201, 1485850549,
+                3098846363, 839653456, 2380177511, 1732519385, 1998829691, 3296699081, 2705709135,
+                2848494034, 4155180828, 1425421469, 3752183557, 2319259329, 2757221818, 1921158733,
+                3302049214, 1696454223, 3356952349, 3100878977, 324054921, 2131412976, 1078305944,
+                698318350, 4151030129, 2259288990, 762849915, 3134288938, 4090864118, 1223661238,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+                10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            ],
+        );
+        test(
+            &[10; 1458],
+            &[
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
+                u32::MAX,
                 u32::MAX,
                 u32::MAX,
                 u32::MAX,
@@ -5726,17 +6439,6 @@ fn test_limbs_mod() {
                 u32::MAX,
                 u32::MAX,
                 u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
-                u32::MAX,
             ],
             &[
                 1699768805, 1493014143, 2592376845, 190926105, 542951397, 3841252648, 1343830055,
@@ -6163,7 +6865,7 @@ fn test_limbs_mod() {
         );
         test_only_verify(&[10; 100], &[123; 20000], &[123; 100]);
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[10; 17],
@@ -12317,6 +13019,387 @@ fn test_limbs_mod() {
             ],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[10; 17],
+            &[
+                9995257893114397114,
+                9401504468144459131,
+                558615837638945228,
+                10733662027974786928,
+                18295107704289976677,
+                1814706268673753787,
+                12474943759854623335,
+                8814778832826774208,
+                9159057654048965906,
+                4451260977376821357,
+                18241701617364042056,
+                6169989192350218482,
+                15071965537117101028,
+                13509168527678537782,
+                12224278653171635329,
+                16077066393714953826,
+                1433938684868066489,
+                13014970036232570373,
+                899282336249563956,
+                3089487642230339536,
+                3787737519477527148,
+                16667686214395942740,
+                8787122953224574943,
+                7841835218775877827,
+                9693303502025838409,
+                16122224776459879427,
+                144327425397945219,
+            ],
+            &[
+                2350654041004706911,
+                7834348511584604247,
+                12756796070221345724,
+                3842923787777653903,
+                12373799197090248752,
+                9712029403347085570,
+                1426676505264168302,
+                10586232903332693517,
+                8387833601131974459,
+                6290888746273553243,
+                9503969704425173615,
+            ],
+            &[
+                208710984273895826839997682188906477877,
+                115424523712619040589751309572566504864,
+                209135340640354573149074822416809180546,
+                326775285311223522225628352860569964038,
+                314751758377986933148201576748707282183,
+                216151724507296816517044234546333415103,
+                277308233638267890964069599861626004752,
+                51771133057805244455953459830699776406,
+                123718831728721031920600735971673732358,
+                325668411318097624383400979022554842237,
+                6458064213065170136,
+                10,
+                10,
+                10,
+                10,
+                10,
+                10,
+            ],
+        );
+        test(
+            &[10; 23],
+            &[
+                748159237152854524,
+                14199895651244313572,
+                9044210482484213648,
+                3880401870711113518,
+                1694971440240542063,
+                13547801197479934494,
+                5244069077418598572,
+                17329479401291658084,
+                12613311850003558282,
+                5618071535926791206,
+                16954511293879569524,
+                8600749590433482901,
+                11708546551548237376,
+                10879843710159659952,
+                9101678715417935644,
+                12126242459863584426,
+                17259866272884195621,
+                4418382641453775715,
+                542305129955142216,
+                6563442437678466173,
+                12794875758080454756,
+                7461769876910639905,
+                17925257245127463276,
+                5137728719899113924,
+                12905981752247605071,
+            ],
+            &[
+                2654882163556630563,
+                2047318842992691178,
+                17944530594807555614,
+                17278864523505748498,
+                1160166728089482341,
+                18368953657130322418,
+                3937719995815345698,
+                12007028340444721520,
+                1496744539933999053,
+                1476923054783110845,
+                6551619938265612084,
+                16801911333947266527,
+                13986495313155597995,
+                6571595571877061463,
+                10140569634762389822,
+                16210530410764331582,
+                15172903143228403872,
+                5831780706385794192,
+                12288937301416472500,
+                16224579586702000460,
+                14545605105156691376,
+                8614987803254853144,
+                16629891239728134900,
+            ],
+            &[
+                77322852854628317079881075001597030855,
+                182019502651037890019504476608012098358,
+                330766606322074505688480060249154471800,
+                278671770270168966009670812696908093907,
+                202306942107018718015921858969086481002,
+                253405769825256671155111902224947530678,
+                154270885963276339250562186946003160568,
+                10873057250044319586103818569031054888,
+                288934995981279043979869665708982114095,
+                57330477671982858869995856486031912230,
+                15613933717044194204420793802205716570,
+                59831232645155749537245533000979832821,
+                282924012409595261424574570092964037748,
+                83293000557849037703842859757248799142,
+                263024298041277891080703596932538478088,
+                16155258866535267865180460258034929548,
+                171823041504711418750591504833742673012,
+                323728144755403680176217117848382736347,
+                181099982219925957555704521450511296921,
+                6479604789273630471063253534564442544,
+                325471474273172753578048165770927182729,
+                36270903644037438507178106663507521367,
+                10496779692524198840,
+            ],
+        );
+        test(
+            &[10; 56],
+            &[
+                14660214196707223375,
+                14265972253040120215,
+                15506320303100465818,
+                17085621003033826581,
+                11203337550022453944,
+                15493204961705835371,
+                5803021083410871755,
+                8112917457002746745,
+                12663484193891261040,
+                1721048899893287199,
+                8062187621610464306,
+                13431761655884620090,
+                7331427712144411262,
+                3626934647030185267,
+                13231383914073320042,
+                11637171044660683638,
+                15189928975258171045,
+                941827519265124224,
+                2992792486091076914,
+                2044203374633195985,
+                8310380355675814732,
+                1677894573715118386,
+                1863631713396879617,
+                13750903464355877990,
+                13561054993991137710,
+                6643134394212488277,
+                9782189322903525535,
+                7987880548748269544,
+                17396502810230452231,
+                9355336424066456608,
+                6974435047841500624,
+                4695995454788932008,
+                9790410161672155866,
+                7324176676989916049,
+                14873447357313289350,
+                17933513319573948354,
+                16221633809094225356,
+                1119296061370324791,
+                13659405622992751643,
+                10536448431317839371,
+                15771892335411705715,
+                6450515195565208913,
+                12583173873673842188,
+                8943105588740166659,
+                16781237121411387206,
+                7355272525679995848,
+                8924936502454129260,
+                9464007023044637842,
+                2392086820925613645,
+                6952992660961663836,
+                15709161892606831425,
+                15961199354349516091,
+                8170938350051511007,
+                10106337242460916657,
+                4519632767875399815,
+                13966478644099829332,
+                18146666299243951179,
+                18001892575388798951,
+                17442461326088111501,
+                12996149925790510613,
+                15125238000270787220,
+                13458137050174539117,
+                7565676737178758148,
+                7820895745333505106,
+                18391820881894926862,
+                17227107494212736312,
+                16170524482788524562,
+                18292226432698054709,
+                16409124153431213414,
+                2622798522164114141,
+                2030148142272451724,
+                12631034221630749586,
+                12521714531249855181,
+                4869764655816857917,
+                18312880399388298885,
+                1881841240505020002,
+                16686085102712131293,
+                1638984612454565124,
+                5980766772519196081,
+                14473546029553426533,
+                2610255570241349719,
+                4121823778233332328,
+                15196027812344512481,
+                17634932614139407184,
+                14566629132274047837,
+                6629067916649366603,
+                39453246491293667,
+                4118307938296638515,
+                176389639877922730,
+                2385844666265721927,
+                14424300909552701177,
+                2596064544694255252,
+                9262830285738421829,
+                8366979142044016136,
+                12451088247268499723,
+                16456341544263224076,
+                405434591376297036,
+                5989071471671786526,
+                17922319711997177283,
+                12402685985480014221,
+                11440567647536028583,
+                17109382986734751589,
+                1165111999013207871,
+                9042409351611763515,
+                335396288523389342,
+                6889397323074150916,
+                13998858741906849976,
+                15927944587197048898,
+                10995067153735213576,
+                13255077995174337515,
+                11985913648073551062,
+                16606199253171990948,
+                16615211378568935152,
+                13000672060735124358,
+            ],
+            &[
+                6726150808576237754,
+                9590776370558469124,
+                4613857594775205869,
+                5605914158178321857,
+                12627075307783464761,
+                456502911636413728,
+                6201419543988208076,
+                12457367465345491402,
+                9194484469177303126,
+                14469237774454463326,
+                8872571916644400618,
+                10371861714649740250,
+                9551882050917532587,
+                1418647961867356190,
+                11742587182398063873,
+                11015016132415914044,
+                8777839015232205587,
+                11080046461630228193,
+                13740325869131645472,
+                17716201322003396844,
+                2184375889136968144,
+                2744007897878529583,
+                10107840174031679018,
+                6807210551800087042,
+                3927845063936277496,
+                4657264236265855475,
+                18202437017170404187,
+                5332422779150911238,
+                15515262280249200267,
+                248667350560422394,
+                3473467338029486524,
+                5450666559053310869,
+                9114347711968955703,
+                1001965327187909086,
+                9391480248060184246,
+                9069754537718985217,
+                6108113375902101471,
+                615335597740998377,
+                7341924484422171664,
+                7557688311245960406,
+                10629369615492290302,
+                6551022068682485711,
+                13009629572214277263,
+                9801266711191462998,
+                12475469715378400041,
+                16817728089246511388,
+                5318131496704799888,
+                14034696640350324685,
+                173195053797772988,
+                9465580662794117123,
+                9395502290798332505,
+                172507413604644051,
+                13462235362634225088,
+                9267822876689174860,
+                12978933587961252639,
+            ],
+            &[
+                95231636842318525934125190365125158359,
+                314395419443120424913958270592033697511,
+                297312765257509229310806924816145056416,
+                15370450878220031758706418743647123596,
+                250204170398748326568459488358590296480,
+                96169269301100549166344868816474133502,
+                9432736278005901600215112630677905099,
+                334092357024357125243700951972786801542,
+                138208288069473594517104126152450847684,
+                77950846428200233108225049911481633733,
+                240019056446918490848212128352485552189,
+                71773384699816053436640710289495107194,
+                326694745185895485303623943928312167997,
+                159765228986901250792175354496120518849,
+                195222300492612277484264728212415430002,
+                235103222978670582207131110646995740158,
+                12465717886221074348258814929666980440,
+                281389686980661677603990073118820333260,
+                311629088139261644631681278168161886722,
+                248700390931710715714927513544487628310,
+                150692236342178180664774256172598242007,
+                131280766359412070071778907681294422682,
+                297598538187797561273058313265912428002,
+                335260443725660764746917324986427277744,
+                41808238080256171345742925980402460673,
+                187074093499417600678594123425339044378,
+                42760981581835765340186363585903669517,
+                189094546669858583487751953256396333650,
+                323977866422841494603125236306088407616,
+                338389986622494133193980932200879218432,
+                132985004796553201731333370095131615589,
+                209631275090924238032330343850550171136,
+                327855583895583453743828111689963403431,
+                106854109497890334805058762126383608095,
+                4473457267294452626457484807208704683,
+                167619730100487536571749307123996351372,
+                252245652008810612362587034415602149140,
+                151408385369965953971188045432470204232,
+                89143309693860727784612567145846170330,
+                25397474438458531020984341168293794162,
+                271621924801622544287372688047479666838,
+                104178886049707033272885400282435800405,
+                306903839594883580094417724916667115829,
+                330558312668949470390895523842833265635,
+                104600511923908158744836394101036486555,
+                69235609284086315657584776386172970268,
+                34715155663415630851002731675061617096,
+                171427658814474263809601738156873921784,
+                100618758844255153019012247408867569471,
+                162764495667369502608539278957203210749,
+                206941684225644992530894866754989599441,
+                238409825342703265919598893217857977311,
+                52895905026851524024671311409611535861,
+                295287074033515913305744318526673902659,
+                3954366546262078500,
+                10,
+            ],
+        );
+    }
 }
 
 #[test]
@@ -1,7 +1,9 @@
-use malachite_base::num::arithmetic::traits::{DivMod, Square};
+use malachite_base::num::arithmetic::traits::{DivMod, ModPowerOf2, Pow, ShrRound, Square};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::common::rle_decode;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::{
@@ -23,6 +25,7 @@ use malachite_nz::natural::arithmetic::mul::limb::{
     limbs_slice_mul_limb_in_place, limbs_slice_mul_limb_with_carry_in_place,
     limbs_vec_mul_limb_in_place,
 };
+use malachite_nz::natural::arithmetic::mul::mul_high::{limbs_mul_high, limbs_mul_high_basecase};
 use malachite_nz::natural::arithmetic::mul::mul_low::{
     limbs_mul_low_same_length, limbs_mul_low_same_length_basecase,
     limbs_mul_low_same_length_basecase_alt, limbs_mul_low_same_length_divide_and_conquer,
@@ -30,6 +33,9 @@ use malachite_nz::natural::arithmetic::mul::mul_low::{
 };
 #[cfg(not(feature = "32_bit_limbs"))]
 use malachite_nz::natural::arithmetic::mul::mul_mod::limbs_mul_mod_base_pow_n_minus_1;
+use malachite_nz::natural::arithmetic::mul::ntt::{
+    limbs_mul_greater_to_out_ntt, limbs_square_to_out_ntt,
+};
 use malachite_nz::natural::arithmetic::mul::product_of_limbs::limbs_product;
 use malachite_nz::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_scratch_len,
@@ -48,18 +54,20 @@ use malachite_nz::natural::arithmetic::mul::toom::{
 };
 use malachite_nz::natural::arithmetic::mul::{
     limbs_mul, limbs_mul_greater, limbs_mul_greater_to_out, limbs_mul_greater_to_out_basecase,
-    limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
-    limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
+    limbs_mul_greater_to_out_maybe_threaded, limbs_mul_greater_to_out_scratch_len,
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out,
+    limbs_mul_to_out_scratch_len,
 };
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::{DoubleLimb, Limb};
 use malachite_nz::test_util::generators::{
-    natural_gen, natural_pair_gen, natural_triple_gen, natural_vec_gen,
-    unsigned_vec_pair_gen_var_33, unsigned_vec_triple_gen_var_10, unsigned_vec_triple_gen_var_11,
-    unsigned_vec_triple_gen_var_12, unsigned_vec_triple_gen_var_13, unsigned_vec_triple_gen_var_14,
-    unsigned_vec_triple_gen_var_15, unsigned_vec_triple_gen_var_16, unsigned_vec_triple_gen_var_4,
-    unsigned_vec_triple_gen_var_5, unsigned_vec_triple_gen_var_6, unsigned_vec_triple_gen_var_60,
-    unsigned_vec_triple_gen_var_7, unsigned_vec_triple_gen_var_8, unsigned_vec_triple_gen_var_9,
+    natural_gen, natural_natural_unsigned_triple_gen_var_1, natural_pair_gen, natural_triple_gen,
+    natural_vec_gen, unsigned_vec_pair_gen_var_33, unsigned_vec_triple_gen_var_10,
+    unsigned_vec_triple_gen_var_11, unsigned_vec_triple_gen_var_12, unsigned_vec_triple_gen_var_13,
+    unsigned_vec_triple_gen_var_14, unsigned_vec_triple_gen_var_15, unsigned_vec_triple_gen_var_16,
+    unsigned_vec_triple_gen_var_4, unsigned_vec_triple_gen_var_5, unsigned_vec_triple_gen_var_6,
+    unsigned_vec_triple_gen_var_60, unsigned_vec_triple_gen_var_7, unsigned_vec_triple_gen_var_8,
+    unsigned_vec_triple_gen_var_9,
 };
 use malachite_nz::test_util::natural::arithmetic::mul::natural_product_naive;
 use malachite_nz::test_util::natural::arithmetic::mul::{
@@ -594,7 +602,7 @@ fn test_limbs_mul_greater_to_out() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_mul_greater_to_out() {
     let test = |xs: Vec<Limb>,
@@ -1371,508 +1379,1301 @@ fn test_limbs_mul_greater_to_out() {
     );
 }
 
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_fail_1() {
-    let mut out = vec![10; 4];
-    let xs = series(6, 3);
-    limbs_mul_greater_to_out_basecase(&mut out, &xs, &[1, 2]);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_fail_2() {
-    let mut out = vec![10; 5];
-    let ys = series(1, 3);
-    limbs_mul_greater_to_out_basecase(&mut out, &[6, 7], &ys);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_fail_3() {
-    let mut out = vec![10; 3];
-    limbs_mul_greater_to_out_basecase(&mut out, &[6, 7], &[]);
-}
-
-fn limbs_mul_basecase_helper(out: &[Limb], xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
-    let mut out = out.to_vec();
-    let old_out = out.clone();
-    limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
-    let n = Natural::from_limbs_asc(xs) * Natural::from_limbs_asc(ys);
-    let len = xs.len() + ys.len();
-    let mut limbs = n.into_limbs_asc();
-    limbs.resize(len, 0);
-    assert_eq!(limbs, &out[..len]);
-    assert_eq!(&out[len..], &old_out[len..]);
-    out
-}
-
-#[test]
-fn limbs_mul_greater_to_out_properties() {
-    let mut config = GenConfig::new();
-    config.insert("mean_length_n", 1024);
-    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
-    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(mut out, xs, ys)| {
-        let old_out = out.clone();
-        let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
-        let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs.len(), ys.len())];
-        let highest_result_limb = limbs_mul_greater_to_out(&mut out, &xs, &ys, &mut mul_scratch);
-        assert_eq!(highest_result_limb, out[xs.len() + ys.len() - 1]);
-        assert_eq!(out, expected_out);
-        let mut out = old_out;
-        limbs_mul_greater_to_out_basecase_mem_opt(&mut out, &xs, &ys);
-        assert_eq!(out, expected_out);
-    });
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-fn test_limbs_mul_to_out() {
-    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
-        let mut out = out_before;
-        let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(xs.len(), ys.len())];
-        limbs_mul_to_out(&mut out, &xs, &ys, &mut mul_scratch);
-        assert_eq!(out, out_after);
-    };
-    test(vec![2], vec![3], vec![10; 3], vec![6, 0, 10]);
-    test(
-        vec![1; 3],
-        series(1, 3),
-        vec![5; 8],
-        vec![1, 3, 6, 5, 3, 0, 5, 5],
-    );
-    test(series(1, 3), vec![6, 7], vec![0; 5], vec![6, 19, 32, 21, 0]);
-    test(vec![6, 7], series(1, 3), vec![0; 5], vec![6, 19, 32, 21, 0]);
-    test(
-        vec![100, 101, 102],
-        vec![102, 101, 100],
-        vec![10; 7],
-        vec![10200, 20402, 30605, 20402, 10200, 0, 10],
-    );
-    test(vec![u32::MAX], vec![1], vec![10; 3], vec![u32::MAX, 0, 10]);
-    test(
-        vec![u32::MAX],
-        vec![u32::MAX],
-        vec![10; 4],
-        vec![1, u32::MAX - 1, 10, 10],
-    );
-    test(
-        vec![u32::MAX; 3],
-        vec![u32::MAX; 3],
-        vec![10; 6],
-        vec![1, 0, 0, u32::MAX - 1, u32::MAX, u32::MAX],
-    );
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_to_out_fail_1() {
-    let mut out = vec![10, 10, 10];
-    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(2, 2)];
-    limbs_mul_to_out(&mut out, &[6, 7], &[1, 2], &mut mul_scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_to_out_fail_2() {
-    let mut out = vec![10, 10, 10, 10];
-    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(1, 3)];
-    limbs_mul_to_out(&mut out, &[], &[1, 2, 3], &mut mul_scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_to_out_fail_3() {
-    let mut out = vec![10, 10, 10, 10];
-    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(1, 1)];
-    limbs_mul_to_out(&mut out, &[1, 2, 3], &[], &mut mul_scratch);
-}
-
-#[test]
-fn limbs_mul_to_out_properties() {
-    let mut config = GenConfig::new();
-    config.insert("mean_length_n", 32);
-    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
-    unsigned_vec_triple_gen_var_3().test_properties_with_config(&config, |(mut out, xs, ys)| {
-        let old_out = out.clone();
-        let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(xs.len(), ys.len())];
-        let highest_result_limb = limbs_mul_to_out(&mut out, &xs, &ys, &mut mul_scratch);
-        assert_eq!(highest_result_limb, out[xs.len() + ys.len() - 1]);
-        let len = xs.len() + ys.len();
-        let n = Natural::from_owned_limbs_asc(xs) * Natural::from_owned_limbs_asc(ys);
-        let mut limbs = n.into_limbs_asc();
-        limbs.resize(len, 0);
-        assert_eq!(limbs, &out[..len]);
-        assert_eq!(&out[len..], &old_out[len..]);
-    });
-}
-
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(feature = "128_bit_limbs")]
 #[test]
-fn test_limbs_mul_greater_to_out_toom_22() {
-    let test = |xs: &[Limb], ys: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
-        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_22_scratch_len(xs.len(), ys.len())];
-        let mut out = out_before.to_vec();
-        limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
+fn test_limbs_mul_greater_to_out() {
+    let test = |xs: Vec<Limb>,
+                ys: Vec<Limb>,
+                out_before: Vec<Limb>,
+                highest_result_limb,
+                out_after: Vec<Limb>| {
+        let mut out = out_before.clone();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
         assert_eq!(out, out_after);
-        let mut out = out_before.to_vec();
-        limbs_mul_greater_to_out_toom_22(&mut out, xs, ys, &mut scratch);
+        let mut out = out_before;
+        let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs.len(), ys.len())];
+        assert_eq!(
+            limbs_mul_greater_to_out(&mut out, &xs, &ys, &mut mul_scratch),
+            highest_result_limb
+        );
         assert_eq!(out, out_after);
     };
-    // s != n
-    // !(xs0[s] == 0 && limbs_cmp_same_length(&xs0[..s], xs1) == Ordering::Less)
-    // t != n
-    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) != Ordering::Less
-    // s <= t
-    // !v_neg_1_neg
-    // carry <= 2
-    test(
-        &series(2, 3),
-        &series(3, 3),
-        &[10; 6],
-        &[6, 17, 34, 31, 20, 0],
-    );
-    // xs0[s] == 0 && limbs_cmp_same_length(&xs0[..s], xs1) == Ordering::Less
-    // v_neg_1_neg
-    //test(&[2, 0, 4], &[3, 4, 5], &[10, 10, 10, 10, 10, 10], vec![6, 8, 22, 16, 20, 0]);
-    test(&[1; 3], &series(1, 3), &[5; 8], &[1, 3, 6, 5, 3, 0, 5, 5]);
-    // s == n
-    // limbs_cmp_same_length(ys0, ys1) != Ordering::Less
-    // t == n
-    // limbs_cmp_same_length(ys0, ys1) == Ordering::Less
-    test(&[1; 4], &series(1, 4), &[5; 8], &[1, 3, 6, 10, 9, 7, 4, 0]);
-    // limbs_cmp_same_length(&a0[..n], &a1[..n]) == Ordering::Less
-    // limbs_cmp_same_length(&b0[..n], &b1[..n]) != Ordering::Less
-    test(&series(1, 4), &[1; 4], &[5; 8], &[1, 3, 6, 10, 9, 7, 4, 0]);
-    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less
     test(
-        &series(1, 5),
-        &[1, 0, 0, 4],
-        &[5; 9],
-        &[1, 2, 3, 8, 13, 12, 16, 20, 0],
-    );
-    // s > t
-    test(&[1; 4], &series(1, 3), &[5; 8], &[1, 3, 6, 6, 5, 3, 0, 5]);
-    test(
-        &[100, 101, 102],
-        &[102, 101, 100],
-        &[10; 7],
-        &[10200, 20402, 30605, 20402, 10200, 0, 10],
-    );
-    let xs = rle_decode(&[(u32::MAX, 8), (4294950911, 1), (u32::MAX, 21), (536870911, 1), (0, 25)]);
-    let ys = rle_decode(&[(u32::MAX, 2), (4294963199, 1), (u32::MAX, 18), (268435455, 1), (0, 34)]);
-    let out_len = xs.len() + ys.len();
-    // carry > 2
-    test(
-        &xs,
-        &ys,
-        &vec![10; out_len],
-        &rle_decode(&[
-            (1, 1),
-            (0, 1),
-            (4096, 1),
-            (0, 5),
-            (16384, 1),
-            (0, 1),
-            (67108864, 1),
-            (0, 10),
-            (4026531840, 1),
-            (u32::MAX, 8),
-            (3758095359, 1),
-            (u32::MAX, 2),
-            (4294966783, 1),
-            (u32::MAX, 18),
-            (33554431, 1),
-            (0, 59),
-        ]),
-    );
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_1() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 4];
-    limbs_mul_greater_to_out_toom_22(&mut out, &[6], &[1], &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_2() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 7];
-    let xs = series(6, 3);
-    let ys = series(1, 4);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_3() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 7];
-    let xs = series(6, 4);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[1, 2], &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_4() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 7];
-    let xs = series(6, 3);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[1, 2], &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_5() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 4];
-    let xs = series(6, 3);
-    let ys = series(1, 3);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_6() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 4];
-    let xs = series(6, 3);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[], &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_22_fail_7() {
-    let mut scratch = vec![];
-    let mut out = vec![10; 4];
-    let xs = series(6, 3);
-    let ys = series(1, 4);
-    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
-}
-
-macro_rules! mul_properties_helper {
-    ($properties: ident, $mul: ident, $scratch: ident, $gen: ident) => {
-        #[test]
-        fn $properties() {
-            let mut config = GenConfig::new();
-            config.insert("mean_length_n", 2048);
-            config.insert("mean_stripe_n", 4 << Limb::LOG_WIDTH);
-            $gen().test_properties_with_config(&config, |(mut out, xs, ys)| {
-                let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
-                let mut scratch = vec![0; $scratch(xs.len(), ys.len())];
-                $mul(&mut out, &xs, &ys, &mut scratch);
-                assert_eq!(out, expected_out);
-            });
-        }
-    };
-}
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_22_properties,
-    limbs_mul_greater_to_out_toom_22,
-    limbs_mul_greater_to_out_toom_22_scratch_len,
-    unsigned_vec_triple_gen_var_4
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_32_properties,
-    limbs_mul_greater_to_out_toom_32,
-    limbs_mul_greater_to_out_toom_32_scratch_len,
-    unsigned_vec_triple_gen_var_5
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_33_properties,
-    limbs_mul_greater_to_out_toom_33,
-    limbs_mul_greater_to_out_toom_33_scratch_len,
-    unsigned_vec_triple_gen_var_6
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_42_properties,
-    limbs_mul_greater_to_out_toom_42,
-    limbs_mul_greater_to_out_toom_42_scratch_len,
-    unsigned_vec_triple_gen_var_7
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_43_properties,
-    limbs_mul_greater_to_out_toom_43,
-    limbs_mul_greater_to_out_toom_43_scratch_len,
-    unsigned_vec_triple_gen_var_8
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_44_properties,
-    limbs_mul_greater_to_out_toom_44,
-    limbs_mul_greater_to_out_toom_44_scratch_len,
-    unsigned_vec_triple_gen_var_9
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_52_properties,
-    limbs_mul_greater_to_out_toom_52,
-    limbs_mul_greater_to_out_toom_52_scratch_len,
-    unsigned_vec_triple_gen_var_10
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_53_properties,
-    limbs_mul_greater_to_out_toom_53,
-    limbs_mul_greater_to_out_toom_53_scratch_len,
-    unsigned_vec_triple_gen_var_11
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_54_properties,
-    limbs_mul_greater_to_out_toom_54,
-    limbs_mul_greater_to_out_toom_54_scratch_len,
-    unsigned_vec_triple_gen_var_12
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_62_properties,
-    limbs_mul_greater_to_out_toom_62,
-    limbs_mul_greater_to_out_toom_62_scratch_len,
-    unsigned_vec_triple_gen_var_13
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_63_properties,
-    limbs_mul_greater_to_out_toom_63,
-    limbs_mul_greater_to_out_toom_63_scratch_len,
-    unsigned_vec_triple_gen_var_14
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_6h_properties,
-    limbs_mul_greater_to_out_toom_6h,
-    limbs_mul_greater_to_out_toom_6h_scratch_len,
-    unsigned_vec_triple_gen_var_15
-);
-mul_properties_helper!(
-    limbs_mul_greater_to_out_toom_8h_properties,
-    limbs_mul_greater_to_out_toom_8h,
-    limbs_mul_greater_to_out_toom_8h_scratch_len,
-    unsigned_vec_triple_gen_var_16
-);
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-fn test_limbs_mul_greater_to_out_toom_32() {
-    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
-        let mut out = out_before.to_vec();
-        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
-        assert_eq!(out, out_after);
-        let mut out = out_before.to_vec();
-        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(xs.len(), ys.len())];
-        limbs_mul_greater_to_out_toom_32(&mut out, &xs, &ys, &mut scratch);
-        assert_eq!(out, out_after);
-    };
-    // !(ap1hi == 0 && limbs_cmp_same_length(ap1, xs1) == Ordering::Less)
-    // t == n
-    // limbs_cmp_same_length(ys0, ys1) == Ordering::Less
-    // ap1hi != 1 and ap1hi != 2
-    // !bp1hi
-    // hi == 0 first time
-    // v_neg_1_neg
-    // s <= t
-    // s + t > n
-    // hi >= 0 second time
-    test(
-        series(2, 6),
-        series(3, 4),
-        vec![10; 10],
-        vec![6, 17, 34, 58, 76, 94, 88, 71, 42, 0],
-    );
-    // limbs_cmp_same_length(ys0, ys1) != Ordering::Less
-    // ap1hi == 2
-    // bp1hi
-    // !v_neg_1_neg
-    test(
-        vec![u32::MAX; 6],
-        vec![u32::MAX; 4],
-        vec![10; 10],
-        vec![1, 0, 0, 0, u32::MAX, u32::MAX, u32::MAX - 1, u32::MAX, u32::MAX, u32::MAX],
-    );
-    // ap1hi == 0 && limbs_cmp_same_length(ap1, xs1) == Ordering::Less
-    test(
-        vec![0, 0, 1, 1, 0, 1],
-        vec![0, 0, 0, 1],
-        vec![10; 10],
-        vec![0, 0, 0, 0, 0, 1, 1, 0, 1, 0],
-    );
-    // t != n
-    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less
-    // s + t <= n
-    test(
-        vec![0, 0, 0, 0, 0, 0, 1],
-        vec![0, 0, 0, 0, 1],
-        vec![10; 12],
-        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0],
-    );
-    // !(slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less)
-    test(
-        vec![0, 0, 0, 0, 0, 0, 1],
-        vec![0, 0, 1, 0, 1],
-        vec![10; 12],
-        vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0],
-    );
-    // s > t
-    test(
-        series(1, 9),
-        vec![9, 8, 7, 6, 5],
-        vec![10; 14],
-        vec![9, 26, 50, 80, 115, 150, 185, 220, 255, 200, 146, 94, 45, 0],
-    );
-    // ap1hi == 1
-    test(
-        vec![2543705880, 1859419010, 3343322808, 1165039137, 1872701663, 1957510151, 1589243046],
-        vec![1919189400, 1295801997, 354566481, 1212146910, 1886225431],
-        vec![10; 14],
-        vec![
-            1753714240, 1114397484, 4100081063, 2352383720, 667557204, 920036609, 2291920497,
-            3338154324, 3806846000, 1880963052, 291601955, 697949587, 10, 10,
-        ],
-    );
-    // hi != 0 first time
-    test(
-        vec![706760835, 4153647095, 3843998199, 2077172825, 1158686949, 3157624247],
-        vec![2847735618, 2779635711, 2471732382, 2655639495],
-        vec![10; 10],
         vec![
-            2814066374, 2022835469, 2101335047, 312674723, 2952296274, 1055977952, 590716674,
-            290888444, 3944399226, 1952404077,
-        ],
-    );
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_32_fail_1() {
-    let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(1, 1)];
-    let mut out = vec![10; 4];
-    limbs_mul_greater_to_out_toom_32(&mut out, &[6], &[1], &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
-#[should_panic]
-fn limbs_mul_greater_to_out_toom_32_fail_2() {
-    let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(3, 4)];
-    let mut out = vec![10; 7];
-    let xs = series(6, 3);
-    let ys = series(1, 4);
-    limbs_mul_greater_to_out_toom_32(&mut out, &xs, &ys, &mut scratch);
-}
-
-#[cfg(feature = "32_bit_limbs")]
-#[test]
+            12709525342598979476,
+            11509224300783138838,
+            393964466388471327,
+            14232928317295888119,
+            13732076968605655238,
+            15707747516397285107,
+            8732499155930007762,
+            9865419549041312495,
+            1072105962419307309,
+            17879862180104468293,
+            17068561868445402638,
+            1866096115219256579,
+            6855809432664356654,
+            1393588600207482081,
+            3815615263372249309,
+            4991042746319579278,
+            15465001638246553057,
+            16087255091514657862,
+            5044736888141764583,
+            2779747905405388451,
+            583627419881315049,
+            4335440233116895431,
+            9498366494409866085,
+            8645413868323766569,
+            10728932435460960803,
+            2150088858744686298,
+            9562258835198494013,
+            17827191588813955307,
+            13784645620229731318,
+            6296382068077415813,
+            11689157886369173742,
+            17754283123435968230,
+            1461090916898201642,
+            1288610558427232175,
+            8104103888086828127,
+            8032176301470720968,
+            12200951204181999648,
+            9485404571555436368,
+            10222400990562648891,
+            7599079551870785840,
+            11203522977648266038,
+            18338050175108207759,
+            12077517359207243953,
+            1611826255828631363,
+            15837930674386751191,
+            461582836356148934,
+            16860704548252932358,
+            6041830073720446893,
+            16833467135075592988,
+            17496069201595551026,
+            10318992522772404757,
+            13524041658187206186,
+            4506190253313254923,
+            17231310439197044711,
+            13546843641632582646,
+            7651927160556964586,
+            9190936365032409113,
+            9913829309881494193,
+            12083378901909297431,
+            287809474670991447,
+            7728504523588277111,
+            16741959317437359789,
+            9529628049862654500,
+            13419553383911388725,
+            13376506161484177119,
+            8176094358898406545,
+            13190641351956097350,
+            12568817836646213483,
+            3486855157615498387,
+            14702408831781542831,
+            98979558380456360,
+            13178740567667926424,
+            17968226918253726036,
+            9245411514261104062,
+            2907160002341084957,
+            1980943369383764848,
+            6564658997896463013,
+            13002525217264158147,
+            444335199954317567,
+            16863109666887451301,
+            11108312108618761726,
+            7630498129836691848,
+            5435171385264263429,
+            10900954800466810672,
+            16615568172072435554,
+            4587778094537619469,
+            10961411668824294491,
+            17076006711925106918,
+            5351453460500307468,
+            7629258051101663482,
+            16987233075558685907,
+            7043270533100406405,
+            1951394976862135309,
+            13689790855612457174,
+            2278410054587042806,
+            17001418508549255568,
+            11660613606297735566,
+            2855471124776808043,
+            10065175989746288214,
+            5055380914672426055,
+            14126789454240780537,
+            12269074817478677711,
+            15318371572624008687,
+            17454608042943339898,
+            266175370449515926,
+            10258065246409153340,
+            7913204199005555677,
+            2015162987422567864,
+            947599677954716876,
+            4742046749175767719,
+            4638837750457895566,
+            7651567606901939182,
+            1517253577417508168,
+            10046266355925262869,
+            17517100300192594127,
+            1840911236085514585,
+            17925925769498956584,
+            649534223875437606,
+            4629497761671206138,
+            6940070820665949971,
+            11499097596331562367,
+            6066662975772729994,
+            14012285030054181,
+            15069455665359536649,
+            15693110947967738351,
+            6137496527357766025,
+            17475548660925055392,
+            7927767715587303464,
+            8531250928287116369,
+            11631019584025111086,
+            14052618820385867457,
+            3118784937861555333,
+            183376537996253187,
+            5402208906954882112,
+            4415682544496910978,
+            4679985880085587510,
+            8446593383458511654,
+            15604884470214334395,
+            1138627504082666786,
+            10518517338133402469,
+            4864527599992532396,
+            13936709675277621349,
+            564858321719103528,
+            17954444276502582707,
+            7973580172007214272,
+            5448002835725952206,
+            17643582902922581336,
+            6555152033284063414,
+            10046934352485272915,
+            13709275446788968044,
+            3781650587286942277,
+            856963687705102140,
+            14231130335064067998,
+            12012182879109532509,
+            14408852620208381144,
+            18399276894609882283,
+            8786560043544514971,
+            9040897797784271497,
+            3289492106827693022,
+            2537349882995177,
+            9547621122715901978,
+            18031028021813298205,
+            13759002194232082515,
+            8230796682226694274,
+            16497307259652601605,
+            4532459299949788115,
+            9096244343722808065,
+            15835940740299471650,
+            16962714874349511272,
+            12751247365216834654,
+            5665483802634541323,
+            5932524403187765899,
+            9724384992325030819,
+            4567260165559481416,
+            12988617208150638305,
+            3810267272444218110,
+            12215376695703433417,
+            15917770368925117452,
+            10076439022504697381,
+            15419145301042845244,
+            12520526582226591499,
+            10731090420480517823,
+            7095369748974936538,
+            7265727720840614264,
+            14328612713128953576,
+            9796482770007505116,
+            12823579117053411854,
+            5055821064893108365,
+            12630218511428402185,
+            5189066330700437332,
+            5109762584664698804,
+            10723647767885063411,
+            15665488082047274789,
+            4569259999401990745,
+            3735809282876517401,
+            7285645596632892122,
+            10962161595160119179,
+            4816357354602339409,
+            10873333778222769846,
+            8013894684430460951,
+            1877066355845544058,
+            9184544411342906577,
+            1919785302854216344,
+            11743122258369849152,
+            3429022440866576828,
+            7006536176872515230,
+            12354403703503061654,
+            16589390678996741825,
+            8567087982659180500,
+            8236943971468579104,
+            14606686909208062275,
+            4461894282559697271,
+            2623848805529273446,
+            6820927267034378486,
+            14393599619569715182,
+            5199894747923716756,
+            206321364421131251,
+            4071363758717862161,
+            11965914866997389305,
+            8109905844769656284,
+            3492539957359367736,
+            16856371139562646083,
+            4333218987558602531,
+            16089995180748288161,
+            11191427142864224026,
+            2622884336931596940,
+            16105201810330583174,
+            11381683384063743452,
+            7873797126730706201,
+            11178731223505120377,
+            16800978016652635785,
+            13461685021093913844,
+            3055116765865974959,
+            3602813646007838280,
+            829147354810404950,
+            4780507430409308744,
+            18314860632940156926,
+            6995096023652594133,
+            5759566466885931830,
+            4840076000542784388,
+        ],
+        vec![
+            15245088662193948010,
+            854969528224537163,
+            192457876290468361,
+            3156774054099849881,
+            10102117358735393641,
+            13923135497401538045,
+            15603007686998930972,
+            3707765480829539463,
+            1075990372015045994,
+            4440028045035707188,
+            779932550205535682,
+            13284596850012603887,
+            13447370325749987403,
+            10657005451799608034,
+            17344058779081327933,
+            1801131630646010099,
+            17879455113972297046,
+            1049662270419803525,
+            17887003202529550415,
+            13730724178286439296,
+            3086493866184691051,
+            7455503161286080904,
+            14945249663072669446,
+            7413071270018261565,
+            8165098975144402988,
+            15667870805615006559,
+            4534237642686726425,
+            5675059133984408369,
+            13542693529471369730,
+            4650690134857994243,
+            10593876026982724440,
+            8719234160809710444,
+            7340192483727047710,
+            2225660849988538666,
+            3260628781823840386,
+            14784063213821786553,
+            13478324037708856111,
+            6239844587086244103,
+            14508626048519473050,
+            11443816492520902359,
+            7084448144752764341,
+            11673478635762496725,
+            13444020463604694513,
+            1798574113181758005,
+            15195278749704748030,
+            3490272214933312037,
+            15632500462832370824,
+            9808665338648603851,
+            6377980234800091876,
+            11306384233660763805,
+            6392788317448223882,
+            8005181869701567455,
+            4601526777105113530,
+            9348184476999479133,
+            16105441815997897842,
+            15373735633778437011,
+            11733794529384137433,
+            769246272107807645,
+            2922899274256775805,
+            16218486247871807873,
+            10650657974127272786,
+            579665301817927565,
+            6403006378940431337,
+            10150254532952843560,
+            3736822004545760197,
+            10244207440138560761,
+            16631379436671010056,
+            17418302422321190629,
+            4844439457855539440,
+            9662799133272397874,
+            11622100630061039998,
+            11017257064923257696,
+            14025546287952884200,
+            1170766120552674008,
+            4852413824670160293,
+            18019298735978800767,
+            14042374992041286164,
+            6103187929964524269,
+            5988592592688695870,
+            5579172720281387479,
+            10738878044274955012,
+            8401646271610146442,
+            12016061916593958227,
+            14752402557741497038,
+            5053283107906893264,
+            12910662726197463795,
+            787526459034857809,
+            10304827788120361107,
+            8387521101013404665,
+            6030209567663971422,
+            7511028869236306454,
+            11105170944119024313,
+            2911699195421772292,
+            11710398806568443147,
+            7599646386487625804,
+            2146501359265516686,
+            1193294087739295886,
+            16419769173966961854,
+            14779980297792837632,
+            6286361066120350249,
+            8246126699673376536,
+            2339493649448723726,
+            12383521129608538925,
+            17459816050942292574,
+            7213741082075285427,
+            14702683527305456088,
+            17849030573001874153,
+            3273901152373442943,
+            10086273715179643444,
+            14351251935054659627,
+            3067622597087477151,
+            4241957707372911307,
+            16686513037697490920,
+            1503886102490162470,
+            4222986769290077389,
+            17209928444872897872,
+            10064374817012298812,
+            1391022681726221923,
+            3482099619102309134,
+            151151415131464647,
+            5477310851692317777,
+            8185741896741403527,
+            12297179519749775078,
+            6980896315258250234,
+            5491311995173541969,
+            10908311176531272611,
+            15140263006374103771,
+            16292302828281485620,
+            13488663273854028028,
+            17078235461511918753,
+            523009743565281503,
+            11105648925812514991,
+            13827146014280242829,
+        ],
+        vec![10; 373],
+        0,
+        vec![
+            193757840702322354770579941409401042760,
+            186325401784635747309127371201420078968,
+            18292107546613598659609625536399704700,
+            259655222441912450930636738201643465365,
+            46034042795037654754058238321363506762,
+            208131018027545262939205204248045344033,
+            216379173936748061064247734558298016352,
+            239925380935224022791691234749059497924,
+            135148969354469838977781733878327737564,
+            264623931184862237147170495675547417229,
+            261672622514297643563356233516837066777,
+            81432551134270115671214704816183754149,
+            230040365215394951433380781923621327099,
+            167063570240305706574145725412101344470,
+            147717304720994938237631225944437801590,
+            282034495633287442609043420141853535328,
+            163472817202825307974919596192604863745,
+            84242609588514020354309575907419685090,
+            285124646015798622972599022619659622096,
+            152329145767554608066533707848186237734,
+            22891161459584243878712396409346224249,
+            235874884512253246623877329684621274044,
+            37968499943817842385943607396767576102,
+            109245380295300418502157579606254016947,
+            253790421125861489697884907587034350162,
+            58430483315744776842869963149539084281,
+            169492970514261021183335093131120618443,
+            14241508450692861312046983349566179939,
+            38756575491443424743111148100151314189,
+            192294181491410144184684030233673357902,
+            257191129266657511646995935360341261059,
+            204931260103970494475253755677145593429,
+            24996775150296788251037542557466812427,
+            9088432690678500679994372272570643015,
+            219936537225020829849087374809202246071,
+            234560017184503447795943741332429098126,
+            329115872616671554295665117836690120899,
+            222504465165008282151981399511800983976,
+            47847370256947576858726518185480270971,
+            272810965232052469422956011531698342976,
+            338534516547627711803692135711540453418,
+            178060953532737923981327464052896491060,
+            339348859532566056991464265764444851632,
+            70836233920114733614596440412137059518,
+            233630577522923213159041368567093864412,
+            136524397417095631407796405519663107856,
+            194880549560934459375058745591190074195,
+            330658620180291547470467637372473981453,
+            155302105118199559646715250102811916444,
+            72458474581470172870112977379579574334,
+            271585232280966177205460699441778041968,
+            66133168407955237398345170511281822665,
+            105439983603093098011999151355298734284,
+            43051886783569365627524542088145727575,
+            44657800681979688408017857338409208330,
+            112012013065147276771699446944582242121,
+            33037151916208666695102530457563987268,
+            149223406793349653737200755664031951529,
+            6244935732047925924376078135518330380,
+            21574210833096954859505722403045278108,
+            29331332680795997187567150502231032565,
+            321928510908949242193117714857470706361,
+            26552501875443432594767346827059973376,
+            119972426694778983362088047080145936199,
+            55114228688532225494781126713666251985,
+            227001972302969724225854055136756922919,
+            250083346972261626925974892105481381172,
+            130829277492666846894910891461072287614,
+            230048492405673411746128666050760725955,
+            289926464392349764839195534986462376959,
+            292174730563476449705288413075576612379,
+            228449298933766337614434664619602918294,
+            169822450849742354013301628705559088268,
+            17713888132546033809348714761649602999,
+            162113703245962660696167122137819974155,
+            201374141697670987774900407603391182957,
+            169634497142458862565123573332658618360,
+            279329918191424792329992320715397069375,
+            69913915770362697513640632596726884538,
+            113789440177121596780920248070636836084,
+            111100625954656402198170504864977933710,
+            82378514329522159625349185812223851470,
+            120390020547169671905225504965036220758,
+            75707221383495505884199526705841267958,
+            158086918826240887572728581373308625701,
+            45673867312035535848521415571928023176,
+            75618156405450379283117409759563170799,
+            182724060666902202009886704919838767858,
+            186416329420316365283302782330656135347,
+            182847670896725242347567679800356153638,
+            48223209451883758246297617030829442306,
+            50242060340439156472721355970494160882,
+            38601074631162301950387875753191127640,
+            168158179472470571530949231605045207301,
+            123767399615059477631430082303635006990,
+            178450610301359216525945410607564208815,
+            96618546891442055660512622121382755032,
+            334879467332354652658603127802898064470,
+            245739197986283281130496732957927507603,
+            179291069848751924996146044292067012381,
+            112435626303647072405333388529450832853,
+            84278966989448828277072800247838466166,
+            59398121108535410953970350835140722627,
+            286254506265394184189180007691305141384,
+            253107458559240509713926876796528841008,
+            323727480796726166774521208372894175505,
+            213278091341962035993949524534101982371,
+            204031578267642863242772137349517998917,
+            249657195204379542851350974050397394562,
+            94473858046153594232228527798322065172,
+            12949698373928798960595694156924583473,
+            271289109400188064565434030757771941161,
+            129133439208342903302025253452468953167,
+            23805372402872581661028860663821478678,
+            188028199871028854764050973796681496864,
+            217464138366963010435851789767566024736,
+            212363994863023462212180387505540362768,
+            207637603905548894667339393955036221399,
+            80045850855777121754445300383042190574,
+            161543110916062630417247841318659290587,
+            174796488393648382494217763627794722063,
+            168245253593762043675545257355554628666,
+            319931542317755157625759620049528259476,
+            122036624022388278025279150333944518529,
+            113539387369036807270904174185707387797,
+            265292018666079521451576927385837755503,
+            93779648829817663909899332558828578607,
+            78592801046943752136083178495435652165,
+            330863390950684814530045280395228070561,
+            197284152733183380917059398435171085184,
+            319516340919085047730491596172674515518,
+            305236782133956149467376396271434850722,
+            119359541814793811215641261138853541146,
+            250849238717803842593478052181152243646,
+            238164679386867696402674614129781343568,
+            173646411976230090964091851432981372142,
+            142893406413595012557761893525771244201,
+            66209080645999762306459627635486240442,
+            217373966797333213863653453737273797418,
+            256141961231984718740346076852170844333,
+            225815598426636858643058070187792689303,
+            328166088286795418468595363008853782288,
+            263154915824259689253616676003960127375,
+            36865638814313581230182628533193539534,
+            45652335508133500714425508974727480156,
+            304150078772988559343744145661416476026,
+            193225639095948397658653632537751012061,
+            236327128936793479956630554624900471684,
+            84910196300624257012973604877688977031,
+            115026235157369404570743741507752990632,
+            134882296775726915000343394137971774560,
+            30384040844924960217398659556436587719,
+            330248955547574056796275932327175764700,
+            243417380451891857725832488202737145224,
+            17589119129471099231420185701626785651,
+            165301825928399398100126086640314466780,
+            135395999757914788101520150928607110364,
+            233154882652149383550416460780491251341,
+            49162944399113756547625912837749302285,
+            31227530152708309356561694974250818500,
+            7100675419028386677617531781408530124,
+            286073921324735252739211480351339925536,
+            68861378950854333412842124464582875535,
+            76364697073101233017682426319445319076,
+            174636847227952987512193447521727340420,
+            120222587498762827674330286330009516156,
+            185957337611493349124015531641759571729,
+            175091639812500085600025906345864594875,
+            65317572918470596432549594190205324563,
+            34090435166461603184652661392671348532,
+            152128387238915198153677575178536372759,
+            29671620412091440553211018068985477270,
+            296531600937508588174561458851977873177,
+            114500689378843610304194039507958621307,
+            141397324157860247720490080485278831300,
+            21275349567930490093474858771911277,
+            35551056643150576542253414140375420492,
+            20240628420398093703407075619912185327,
+            294650473368487781976382289449317659659,
+            193633532411574655083341848085539201551,
+            194250458585545828945940723358941493557,
+            271909520545140695631992797167550788409,
+            207094950864761065527372702844142105902,
+            330225134074388580523035037050799303280,
+            330101866838472646463889123727123705010,
+            325197765206145884707367472764492403938,
+            91315542843898260428921445883448945008,
+            11578079685767861832847351699228121221,
+            96273686688828245748546162576510867415,
+            196963364810595045083507194440957718353,
+            273187140026973411200616124648472004976,
+            307723913399899338558277462341572722292,
+            302865249223693009529041469289284750923,
+            331067618504954196929259490520874282023,
+            275388054620591007003206140714070319736,
+            144762951139616053225856884823802913220,
+            54026137646332103555361873299502910356,
+            97192994524595055955646930528202124547,
+            287486644144953687801780212478185518839,
+            294023187540669865412179460593478059241,
+            302245300054662321230416898475786573564,
+            197622011671879357491063875808267887274,
+            131171329900875543886496083398217906600,
+            270156026443654211594005723154888291450,
+            277933845071333522615898901863563648954,
+            234181680168086828231989848057530019228,
+            223637174878394727805137682522994108565,
+            100580605363919998574552457819807006646,
+            110678466304977471901870395580356866401,
+            218331461863429699368373423935930642523,
+            188204767400288006445899266578073418934,
+            270971772478175655611127034543392094566,
+            213790582660826102203022368064582425638,
+            86167309809251269963310068495577529757,
+            113050307190847467432708259783000090234,
+            311587295231982174810127755587939997467,
+            46789958558075157475006016296344170039,
+            42781954519064752440811219539316857287,
+            251146384758686393940831868552463134779,
+            138261025744899526740115162054031247785,
+            233659880910369121350648127863352963244,
+            124126432162665721545270943649782177705,
+            188184894320842556767052446665919715048,
+            19611923907718556498810804787876682447,
+            181465335264355675980901382293437875487,
+            217160179402617054729429228993232305038,
+            151381354887505224766936065330260680081,
+            76708936298971315082980304368394723490,
+            50354532444624861316858992563044977536,
+            235300809331010335046487297377353757852,
+            286650627502829810200007140010993217163,
+            233003466148951228392434950632213668092,
+            256583674077490759458450575719302758651,
+            149100177965032486808307847904472574881,
+            48949598019495602277574463859264210315,
+            196996861046237577706343383407351147472,
+            94107936078369894090763401424586882797,
+            197129330423184962596688940202288763578,
+            210777779070915179390083696344998362147,
+            330879953499639417790574259273472766767,
+            22714129310553679421330123173224931922,
+            239928491174528704264391084166505695113,
+            155696337025946201782839281265793760287,
+            124688568013162504153875670490893571696,
+            209225360420777950641058691839232056240,
+            22144975295273573169373485704136468034,
+            157767568075592238727506779627580820318,
+            269130464680939490543451308984471399479,
+            204778111981979589689009460299590706288,
+            10003285465871540480071606017253559907,
+            156227244283226551554066613061494819447,
+            324408153215915350252028227129006423262,
+            281405126263569175372957074326492971520,
+            202798738259656950096954758221560799340,
+            179177542629428412373389506656404769306,
+            1667160489090659662593132779669735567,
+            163738271198812103615408294808691972723,
+            20766499409773360426482841265729095784,
+            243576482241161279301908697965441914646,
+            55658938996637256559531018287909496441,
+            236530489729598006111224805607367892538,
+            324362902583364286355847505805949515741,
+            335005691764318824410525708476738817158,
+            266718357037044315122113713091967351238,
+            271111136973267697660472594330315193324,
+            217289020923730955800193979673703868477,
+            209631412762621863086717569539223993536,
+            22802540637168820918635024704478848118,
+            179820923738182519265821098007354141599,
+            24250254059199750707803251346696739081,
+            111628611599041835459866194132183890184,
+            123206809904016549933917212475345790607,
+            254265934315063529673658685853945238054,
+            28605337598795205858502284355510962151,
+            34759348007481441996923670174091266065,
+            29136915221244840585852933728956129122,
+            198190790413646189988296230745250615688,
+            245527559323832063773164304812089442658,
+            227320362816648403150337946829931673911,
+            44011044282719393994216195211362474535,
+            102419367284328461418048491563004950753,
+            96169495929981881504396435776197812048,
+            143664830493902776194012027626259680787,
+            142935966365785895650966175527055241423,
+            149984337566591748296988473635381269308,
+            78448552543668637671558816256593534635,
+            126831757586636713441331716580480159077,
+            149099258408165623096045355908201737508,
+            168868647205391852031358288766078520406,
+            254819495468740820931110878140147713546,
+            60907262993513250752430598516922736866,
+            317415939200559428160714803859744546483,
+            116051633586600245454496744134391752746,
+            277443978765614047620973327370807932138,
+            304963761506120780921555686652987234717,
+            337228084006472145085833210314339988107,
+            292233271450032126217674725544674784747,
+            121074461963218599399252735839804937154,
+            169095385144584385765661472325692360114,
+            180454366816662713944437056154145528140,
+            181783559866495288343131246601166179929,
+            100547883810952886228095891610881700588,
+            241180599638271219528902516938056953008,
+            146455598161129111727149896753030229621,
+            50792335628191077589574904187516072530,
+            219635683832901780179041855287299726131,
+            151871129509584208210953820130549773549,
+            134853998069474441816301550875554808819,
+            176360366641570079191470395712128792097,
+            254744043553781629193116430601928558169,
+            241351765526864121956639705326611945494,
+            22817622873664455696326124965481452207,
+            227498924437721879708525549883165177243,
+            270966677027407538734838320824378817926,
+            196944299040733789154966622906086856981,
+            103294777390485938857593761373073411962,
+            69925933452488224640457273971470518972,
+            249673040886060677948645380080039076924,
+            329467623369194638081986912279414557776,
+            293792353241991314091673135069814224501,
+            88262523309694254630906984134702209954,
+            99263881601911915499293986289082040075,
+            296576872852484031902478657106759244011,
+            327291234997797761214689276068501341917,
+            225377700018588344405633045964776537470,
+            108949094142262178376902450692152339944,
+            300186407086198994770091815021969530897,
+            304849833603937644091663197698872178658,
+            102692501754622943760071106764966975190,
+            108740407116165576633217617518897667996,
+            267593708781603481992228248252666928079,
+            294505923800643804571959892310721638843,
+            41400084213804863120214808865952617700,
+            278127191833581002983641852171336333915,
+            17251848391217571672681308954500996945,
+            220797312092645248079106216405558431550,
+            66500811593199168897093218103499474241,
+            63019578223136077957425652710526028969,
+            20282801677835431866801008391216916254,
+            112040648240276776057259119742326906685,
+            106517217335013412937873501093161555091,
+            58585130471028544858466477376662160893,
+            72225800626046607193448164286049665747,
+            273208464360419750239942415400368392703,
+            182985214127495981851843667319922637067,
+            89173686742074365768162648648595601046,
+            267105965773469013183312289790049450311,
+            19350070631722083432718609518039902502,
+            73159954054653313747875004566231729145,
+            256002541410255718599451960333992261516,
+            123509141245886673788735956021453260092,
+            109722467994499153515887996359503490962,
+            174021305643829687232861401193815521740,
+            296760995714876152584084458013221628793,
+            45275241547759980016442158212818725438,
+            152758425904044705133713893802979424745,
+            274796407818233430807591987110498691771,
+            286672647161228306343166880315908625964,
+            67177067033364740789107525571664619981,
+            125479812975774935408268347764367225693,
+            215602408391063752177372402746203767832,
+            192214247562543230787117358382031146828,
+            272454846168389167193198614908785475627,
+            113732700082524193968671634710644618922,
+            325056662815388393285942482372716796505,
+            295499356642864267550497940744157055097,
+            9860595949581786997179121887168221126,
+            96524710702735508707010443183372919740,
+            76317232892726746681527017370449888656,
+            163217344156953428166627643462553360952,
+            133390551352862859659473193328471107578,
+            66924437579718619577962744864430153652,
+            0,
+        ],
+    );
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_fail_1() {
+    let mut out = vec![10; 4];
+    let xs = series(6, 3);
+    limbs_mul_greater_to_out_basecase(&mut out, &xs, &[1, 2]);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_fail_2() {
+    let mut out = vec![10; 5];
+    let ys = series(1, 3);
+    limbs_mul_greater_to_out_basecase(&mut out, &[6, 7], &ys);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_fail_3() {
+    let mut out = vec![10; 3];
+    limbs_mul_greater_to_out_basecase(&mut out, &[6, 7], &[]);
+}
+
+fn limbs_mul_basecase_helper(out: &[Limb], xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
+    let mut out = out.to_vec();
+    let old_out = out.clone();
+    limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
+    let n = Natural::from_limbs_asc(xs) * Natural::from_limbs_asc(ys);
+    let len = xs.len() + ys.len();
+    let mut limbs = n.into_limbs_asc();
+    limbs.resize(len, 0);
+    assert_eq!(limbs, &out[..len]);
+    assert_eq!(&out[len..], &old_out[len..]);
+    out
+}
+
+#[test]
+fn limbs_mul_greater_to_out_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 1024);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(mut out, xs, ys)| {
+        let old_out = out.clone();
+        let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
+        let mut mul_scratch = vec![0; limbs_mul_greater_to_out_scratch_len(xs.len(), ys.len())];
+        let highest_result_limb = limbs_mul_greater_to_out(&mut out, &xs, &ys, &mut mul_scratch);
+        assert_eq!(highest_result_limb, out[xs.len() + ys.len() - 1]);
+        assert_eq!(out, expected_out);
+        let mut out = old_out;
+        limbs_mul_greater_to_out_basecase_mem_opt(&mut out, &xs, &ys);
+        assert_eq!(out, expected_out);
+    });
+}
+
+#[test]
+fn limbs_mul_greater_to_out_maybe_threaded_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 1024);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(out, xs, ys)| {
+        let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
+        let mut out_serial = out.clone();
+        limbs_mul_greater_to_out_maybe_threaded(&mut out_serial, &xs, &ys, false);
+        assert_eq!(out_serial, expected_out);
+        let mut out_threaded = out;
+        limbs_mul_greater_to_out_maybe_threaded(&mut out_threaded, &xs, &ys, true);
+        assert_eq!(out_threaded, expected_out);
+    });
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_mul_to_out() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before;
+        let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(xs.len(), ys.len())];
+        limbs_mul_to_out(&mut out, &xs, &ys, &mut mul_scratch);
+        assert_eq!(out, out_after);
+    };
+    test(vec![2], vec![3], vec![10; 3], vec![6, 0, 10]);
+    test(
+        vec![1; 3],
+        series(1, 3),
+        vec![5; 8],
+        vec![1, 3, 6, 5, 3, 0, 5, 5],
+    );
+    test(series(1, 3), vec![6, 7], vec![0; 5], vec![6, 19, 32, 21, 0]);
+    test(vec![6, 7], series(1, 3), vec![0; 5], vec![6, 19, 32, 21, 0]);
+    test(
+        vec![100, 101, 102],
+        vec![102, 101, 100],
+        vec![10; 7],
+        vec![10200, 20402, 30605, 20402, 10200, 0, 10],
+    );
+    test(vec![u32::MAX], vec![1], vec![10; 3], vec![u32::MAX, 0, 10]);
+    test(
+        vec![u32::MAX],
+        vec![u32::MAX],
+        vec![10; 4],
+        vec![1, u32::MAX - 1, 10, 10],
+    );
+    test(
+        vec![u32::MAX; 3],
+        vec![u32::MAX; 3],
+        vec![10; 6],
+        vec![1, 0, 0, u32::MAX - 1, u32::MAX, u32::MAX],
+    );
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_to_out_fail_1() {
+    let mut out = vec![10, 10, 10];
+    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(2, 2)];
+    limbs_mul_to_out(&mut out, &[6, 7], &[1, 2], &mut mul_scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_to_out_fail_2() {
+    let mut out = vec![10, 10, 10, 10];
+    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(1, 3)];
+    limbs_mul_to_out(&mut out, &[], &[1, 2, 3], &mut mul_scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_to_out_fail_3() {
+    let mut out = vec![10, 10, 10, 10];
+    let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(1, 1)];
+    limbs_mul_to_out(&mut out, &[1, 2, 3], &[], &mut mul_scratch);
+}
+
+#[test]
+fn limbs_mul_to_out_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_3().test_properties_with_config(&config, |(mut out, xs, ys)| {
+        let old_out = out.clone();
+        let mut mul_scratch = vec![0; limbs_mul_to_out_scratch_len(xs.len(), ys.len())];
+        let highest_result_limb = limbs_mul_to_out(&mut out, &xs, &ys, &mut mul_scratch);
+        assert_eq!(highest_result_limb, out[xs.len() + ys.len() - 1]);
+        let len = xs.len() + ys.len();
+        let n = Natural::from_owned_limbs_asc(xs) * Natural::from_owned_limbs_asc(ys);
+        let mut limbs = n.into_limbs_asc();
+        limbs.resize(len, 0);
+        assert_eq!(limbs, &out[..len]);
+        assert_eq!(&out[len..], &old_out[len..]);
+    });
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_22() {
+    let test = |xs: &[Limb], ys: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_22_scratch_len(xs.len(), ys.len())];
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, xs, ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_toom_22(&mut out, xs, ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    // s != n
+    // !(xs0[s] == 0 && limbs_cmp_same_length(&xs0[..s], xs1) == Ordering::Less)
+    // t != n
+    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) != Ordering::Less
+    // s <= t
+    // !v_neg_1_neg
+    // carry <= 2
+    test(
+        &series(2, 3),
+        &series(3, 3),
+        &[10; 6],
+        &[6, 17, 34, 31, 20, 0],
+    );
+    // xs0[s] == 0 && limbs_cmp_same_length(&xs0[..s], xs1) == Ordering::Less
+    // v_neg_1_neg
+    //test(&[2, 0, 4], &[3, 4, 5], &[10, 10, 10, 10, 10, 10], vec![6, 8, 22, 16, 20, 0]);
+    test(&[1; 3], &series(1, 3), &[5; 8], &[1, 3, 6, 5, 3, 0, 5, 5]);
+    // s == n
+    // limbs_cmp_same_length(ys0, ys1) != Ordering::Less
+    // t == n
+    // limbs_cmp_same_length(ys0, ys1) == Ordering::Less
+    test(&[1; 4], &series(1, 4), &[5; 8], &[1, 3, 6, 10, 9, 7, 4, 0]);
+    // limbs_cmp_same_length(&a0[..n], &a1[..n]) == Ordering::Less
+    // limbs_cmp_same_length(&b0[..n], &b1[..n]) != Ordering::Less
+    test(&series(1, 4), &[1; 4], &[5; 8], &[1, 3, 6, 10, 9, 7, 4, 0]);
+    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less
+    test(
+        &series(1, 5),
+        &[1, 0, 0, 4],
+        &[5; 9],
+        &[1, 2, 3, 8, 13, 12, 16, 20, 0],
+    );
+    // s > t
+    test(&[1; 4], &series(1, 3), &[5; 8], &[1, 3, 6, 6, 5, 3, 0, 5]);
+    test(
+        &[100, 101, 102],
+        &[102, 101, 100],
+        &[10; 7],
+        &[10200, 20402, 30605, 20402, 10200, 0, 10],
+    );
+    let xs = rle_decode(&[(u32::MAX, 8), (4294950911, 1), (u32::MAX, 21), (536870911, 1), (0, 25)]);
+    let ys = rle_decode(&[(u32::MAX, 2), (4294963199, 1), (u32::MAX, 18), (268435455, 1), (0, 34)]);
+    let out_len = xs.len() + ys.len();
+    // carry > 2
+    test(
+        &xs,
+        &ys,
+        &vec![10; out_len],
+        &rle_decode(&[
+            (1, 1),
+            (0, 1),
+            (4096, 1),
+            (0, 5),
+            (16384, 1),
+            (0, 1),
+            (67108864, 1),
+            (0, 10),
+            (4026531840, 1),
+            (u32::MAX, 8),
+            (3758095359, 1),
+            (u32::MAX, 2),
+            (4294966783, 1),
+            (u32::MAX, 18),
+            (33554431, 1),
+            (0, 59),
+        ]),
+    );
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_1() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 4];
+    limbs_mul_greater_to_out_toom_22(&mut out, &[6], &[1], &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_2() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 7];
+    let xs = series(6, 3);
+    let ys = series(1, 4);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_3() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 7];
+    let xs = series(6, 4);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[1, 2], &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_4() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 7];
+    let xs = series(6, 3);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[1, 2], &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_5() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 4];
+    let xs = series(6, 3);
+    let ys = series(1, 3);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_6() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 4];
+    let xs = series(6, 3);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &[], &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_22_fail_7() {
+    let mut scratch = vec![];
+    let mut out = vec![10; 4];
+    let xs = series(6, 3);
+    let ys = series(1, 4);
+    limbs_mul_greater_to_out_toom_22(&mut out, &xs, &ys, &mut scratch);
+}
+
+macro_rules! mul_properties_helper {
+    ($properties: ident, $mul: ident, $scratch: ident, $gen: ident) => {
+        #[test]
+        fn $properties() {
+            let mut config = GenConfig::new();
+            config.insert("mean_length_n", 2048);
+            config.insert("mean_stripe_n", 4 << Limb::LOG_WIDTH);
+            $gen().test_properties_with_config(&config, |(mut out, xs, ys)| {
+                let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
+                let mut scratch = vec![0; $scratch(xs.len(), ys.len())];
+                $mul(&mut out, &xs, &ys, &mut scratch);
+                assert_eq!(out, expected_out);
+            });
+        }
+    };
+}
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_22_properties,
+    limbs_mul_greater_to_out_toom_22,
+    limbs_mul_greater_to_out_toom_22_scratch_len,
+    unsigned_vec_triple_gen_var_4
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_32_properties,
+    limbs_mul_greater_to_out_toom_32,
+    limbs_mul_greater_to_out_toom_32_scratch_len,
+    unsigned_vec_triple_gen_var_5
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_33_properties,
+    limbs_mul_greater_to_out_toom_33,
+    limbs_mul_greater_to_out_toom_33_scratch_len,
+    unsigned_vec_triple_gen_var_6
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_42_properties,
+    limbs_mul_greater_to_out_toom_42,
+    limbs_mul_greater_to_out_toom_42_scratch_len,
+    unsigned_vec_triple_gen_var_7
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_43_properties,
+    limbs_mul_greater_to_out_toom_43,
+    limbs_mul_greater_to_out_toom_43_scratch_len,
+    unsigned_vec_triple_gen_var_8
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_44_properties,
+    limbs_mul_greater_to_out_toom_44,
+    limbs_mul_greater_to_out_toom_44_scratch_len,
+    unsigned_vec_triple_gen_var_9
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_52_properties,
+    limbs_mul_greater_to_out_toom_52,
+    limbs_mul_greater_to_out_toom_52_scratch_len,
+    unsigned_vec_triple_gen_var_10
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_53_properties,
+    limbs_mul_greater_to_out_toom_53,
+    limbs_mul_greater_to_out_toom_53_scratch_len,
+    unsigned_vec_triple_gen_var_11
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_54_properties,
+    limbs_mul_greater_to_out_toom_54,
+    limbs_mul_greater_to_out_toom_54_scratch_len,
+    unsigned_vec_triple_gen_var_12
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_62_properties,
+    limbs_mul_greater_to_out_toom_62,
+    limbs_mul_greater_to_out_toom_62_scratch_len,
+    unsigned_vec_triple_gen_var_13
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_63_properties,
+    limbs_mul_greater_to_out_toom_63,
+    limbs_mul_greater_to_out_toom_63_scratch_len,
+    unsigned_vec_triple_gen_var_14
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_6h_properties,
+    limbs_mul_greater_to_out_toom_6h,
+    limbs_mul_greater_to_out_toom_6h_scratch_len,
+    unsigned_vec_triple_gen_var_15
+);
+mul_properties_helper!(
+    limbs_mul_greater_to_out_toom_8h_properties,
+    limbs_mul_greater_to_out_toom_8h,
+    limbs_mul_greater_to_out_toom_8h_scratch_len,
+    unsigned_vec_triple_gen_var_16
+);
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_32() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_32(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    // !(ap1hi == 0 && limbs_cmp_same_length(ap1, xs1) == Ordering::Less)
+    // t == n
+    // limbs_cmp_same_length(ys0, ys1) == Ordering::Less
+    // ap1hi != 1 and ap1hi != 2
+    // !bp1hi
+    // hi == 0 first time
+    // v_neg_1_neg
+    // s <= t
+    // s + t > n
+    // hi >= 0 second time
+    test(
+        series(2, 6),
+        series(3, 4),
+        vec![10; 10],
+        vec![6, 17, 34, 58, 76, 94, 88, 71, 42, 0],
+    );
+    // limbs_cmp_same_length(ys0, ys1) != Ordering::Less
+    // ap1hi == 2
+    // bp1hi
+    // !v_neg_1_neg
+    test(
+        vec![u32::MAX; 6],
+        vec![u32::MAX; 4],
+        vec![10; 10],
+        vec![1, 0, 0, 0, u32::MAX, u32::MAX, u32::MAX - 1, u32::MAX, u32::MAX, u32::MAX],
+    );
+    // ap1hi == 0 && limbs_cmp_same_length(ap1, xs1) == Ordering::Less
+    test(
+        vec![0, 0, 1, 1, 0, 1],
+        vec![0, 0, 0, 1],
+        vec![10; 10],
+        vec![0, 0, 0, 0, 0, 1, 1, 0, 1, 0],
+    );
+    // t != n
+    // slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less
+    // s + t <= n
+    test(
+        vec![0, 0, 0, 0, 0, 0, 1],
+        vec![0, 0, 0, 0, 1],
+        vec![10; 12],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0],
+    );
+    // !(slice_test_zero(&ys0[t..]) && limbs_cmp_same_length(&ys0[..t], ys1) == Ordering::Less)
+    test(
+        vec![0, 0, 0, 0, 0, 0, 1],
+        vec![0, 0, 1, 0, 1],
+        vec![10; 12],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0],
+    );
+    // s > t
+    test(
+        series(1, 9),
+        vec![9, 8, 7, 6, 5],
+        vec![10; 14],
+        vec![9, 26, 50, 80, 115, 150, 185, 220, 255, 200, 146, 94, 45, 0],
+    );
+    // ap1hi == 1
+    test(
+        vec![2543705880, 1859419010, 3343322808, 1165039137, 1872701663, 1957510151, 1589243046],
+        vec![1919189400, 1295801997, 354566481, 1212146910, 1886225431],
+        vec![10; 14],
+        vec![
+            1753714240, 1114397484, 4100081063, 2352383720, 667557204, 920036609, 2291920497,
+            3338154324, 3806846000, 1880963052, 291601955, 697949587, 10, 10,
+        ],
+    );
+    // hi != 0 first time
+    test(
+        vec![706760835, 4153647095, 3843998199, 2077172825, 1158686949, 3157624247],
+        vec![2847735618, 2779635711, 2471732382, 2655639495],
+        vec![10; 10],
+        vec![
+            2814066374, 2022835469, 2101335047, 312674723, 2952296274, 1055977952, 590716674,
+            290888444, 3944399226, 1952404077,
+        ],
+    );
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_32_fail_1() {
+    let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(1, 1)];
+    let mut out = vec![10; 4];
+    limbs_mul_greater_to_out_toom_32(&mut out, &[6], &[1], &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_toom_32_fail_2() {
+    let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(3, 4)];
+    let mut out = vec![10; 7];
+    let xs = series(6, 3);
+    let ys = series(1, 4);
+    limbs_mul_greater_to_out_toom_32(&mut out, &xs, &ys, &mut scratch);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
 #[should_panic]
 fn limbs_mul_greater_to_out_toom_32_fail_3() {
     let mut scratch = vec![0; limbs_mul_greater_to_out_toom_32_scratch_len(5, 4)];
@@ -2417,7 +3218,7 @@ fn test_limbs_mul_greater_to_out_toom_43() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_43() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
@@ -2522,6 +3323,111 @@ fn test_limbs_mul_greater_to_out_toom_43() {
     );
 }
 
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_43() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_43_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_43(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(
+        vec![
+            18446744073701163071,
+            Limb::MAX,
+            Limb::MAX,
+            68719476735,
+            0,
+            0,
+            0,
+            0,
+            18446743936270598144,
+            Limb::MAX,
+            Limb::MAX,
+            Limb::MAX,
+            262143,
+            0,
+            0,
+            0,
+            18446462598732840960,
+            32767,
+            0,
+            0,
+            18446744073709518848,
+            Limb::MAX,
+            Limb::MAX,
+            Limb::MAX,
+            Limb::MAX,
+        ],
+        vec![
+            18437736874454810624,
+            1048575,
+            0,
+            18446744039349813248,
+            Limb::MAX,
+            Limb::MAX,
+            Limb::MAX,
+            140737488355327,
+            0,
+            18446744073709551600,
+            Limb::MAX,
+            Limb::MAX,
+            134217727,
+            18446744056529682432,
+            Limb::MAX,
+            Limb::MAX,
+        ],
+        vec![10; 41],
+        vec![
+            340116213421310683193792108019847266304,
+            19342776229344322612363201,
+            340282366920938463463374607431767162881,
+            633051588751994442931729596415,
+            340282366920938463426553176844035686338,
+            0,
+            1267650597867046160061880598528,
+            2596148429266233231510923800215552,
+            340116210887402088562088068093237526528,
+            340282366920803064601546144429882997776,
+            9671369663428885985886162,
+            340282363751811967615167586559655411713,
+            1270131313612000764161794506752,
+            340282366603871072063695575478699819009,
+            340282366920938463426481119301537365953,
+            2596148414760313193254535668170752,
+            340111024927558613674176288161814806591,
+            340282364385657209360611532938802102271,
+            340282366920938463426481119456146718737,
+            340277173990264299965081781436623618048,
+            340116213423941229297639220666685194240,
+            340282364068748793790549507099840217089,
+            340282366920938463426481119438966620161,
+            2595474989281579658714288360521728,
+            340282366920938463449539443720290598913,
+            340277174624084764062314367147239800831,
+            604426016600642145222671,
+            2596148429267409202579229737189377,
+            2475842318085500894498258944,
+            340277174307171509523909476981934653439,
+            604389122549561952337937,
+            340282366920938463463374607431768178690,
+            2475880097017359487972933631,
+            340282366604025208943408012692824293375,
+            340282366920938463444927863375238561776,
+            Limb::MAX,
+            18446744073709518847,
+            134217727,
+            18446744056529682432,
+            Limb::MAX,
+            Limb::MAX,
+        ],
+    );
+}
+
 #[cfg(feature = "32_bit_limbs")]
 #[test]
 #[should_panic]
@@ -2667,7 +3573,27 @@ fn test_limbs_mul_greater_to_out_toom_44() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_44() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_44_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_44(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(
+        series(2, 4),
+        series(3, 4),
+        vec![10; 8],
+        vec![6, 17, 34, 58, 58, 49, 30, 0],
+    );
+}
+
+#[cfg(feature = "128_bit_limbs")]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_44() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
@@ -2887,7 +3813,27 @@ fn test_limbs_mul_greater_to_out_toom_52() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_52() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_52_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_52(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+        vec![0, 0, 0, 0, 1, 0],
+        vec![10; 20],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+    );
+}
+
+#[cfg(feature = "128_bit_limbs")]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_52() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
@@ -3066,7 +4012,7 @@ fn test_limbs_mul_greater_to_out_toom_53() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_53() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
@@ -3086,6 +4032,35 @@ fn test_limbs_mul_greater_to_out_toom_53() {
     );
 }
 
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_53() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_53_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_53(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(
+        vec![Limb::MAX, Limb::MAX, 3, 340282366920938463463374607431768211454, Limb::MAX],
+        vec![Limb::MAX, Limb::MAX, Limb::MAX],
+        vec![10; 8],
+        vec![
+            1,
+            0,
+            340282366920938463463374607431768211452,
+            0,
+            0,
+            3,
+            340282366920938463463374607431768211454,
+            Limb::MAX,
+        ],
+    );
+}
+
 #[cfg(feature = "32_bit_limbs")]
 #[test]
 #[should_panic]
@@ -3704,7 +4679,7 @@ fn test_limbs_mul_greater_to_out_toom_63() {
     );
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_63() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
@@ -3781,6 +4756,83 @@ fn test_limbs_mul_greater_to_out_toom_63() {
     );
 }
 
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_63() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>, out_after| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_63_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_63(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(
+        vec![
+            6746486103788831552,
+            2922469023463657485,
+            7190781201699911122,
+            6369274278675525514,
+            11602031538822447399,
+            18146097755068799938,
+            10715195159596301824,
+            1582667531232164822,
+            17310503547119278200,
+            11108448614311336701,
+            16131384432757080248,
+            10724146198461527790,
+            17486718158725257827,
+            6011711000953739951,
+            12044019786490872751,
+            12126819472937875768,
+            11736689834584491812,
+            2624631955548590096,
+        ],
+        vec![
+            8718882040837103283,
+            12513261442998616191,
+            3363599670593686195,
+            2576001491054566526,
+            8476413363242630098,
+            11800520882738943180,
+            15256756628116724015,
+            15102633230716809194,
+            4752404995807312312,
+        ],
+        vec![10; 27],
+        vec![
+            58821816529081525037513635710913185216,
+            109901207121846776478159952728679881687,
+            121957670465124997704923244623236577801,
+            172722050385162041256864044243654586691,
+            269758337480736588679008647297347668087,
+            107441830422324343290971855590100602499,
+            234009057791782177377806553583637947600,
+            183842491222933004011629293662979213034,
+            272647586076089628915019425185786836981,
+            176211990616961229489095034618263630828,
+            273723902271326316639622964430345272894,
+            318951743361532004206926509515871881643,
+            6764298063659521080147114430870894031,
+            238937148652815972759235125023541360219,
+            192864797892576068634233883199967378109,
+            20557572588334523858793004113870964705,
+            60148331211458474521795168533867029150,
+            278342618010779194295461005712354100869,
+            101441936475787530837866000812889872553,
+            10195037879421620177258612792887575549,
+            266715821676722474422057019808069325641,
+            215946584172781460305347514591330958039,
+            110138444263780352887695747001157620309,
+            274929850345038186156519463990886530585,
+            95416357194389616822572623358640931968,
+            12473314017704635229620943018742061952,
+            0,
+        ],
+    );
+}
+
 #[cfg(feature = "32_bit_limbs")]
 #[test]
 #[should_panic]
@@ -4107,7 +5159,22 @@ fn test_limbs_mul_greater_to_out_toom_6h() {
     test(xs, ys, vec![10; out_len]);
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_6h() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        let out_after = out;
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_6h_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_6h(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(series(2, 42), series(3, 42), vec![10; 84]);
+}
+
+#[cfg(feature = "128_bit_limbs")]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_6h() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>| {
@@ -4571,26 +5638,228 @@ fn test_limbs_mul_greater_to_out_toom_8h() {
         1470517413,
     ];
     let ys = vec![
-        2312659839, 2350424241, 1787407270, 1271425122, 4187967770, 818645453, 3539315256,
-        2178962268, 2575529612, 3589703821, 2051328589, 1350506812, 1181962471, 440960359,
-        1364212437, 3414960630, 901255513, 1225743051, 2301315145, 1970642256, 2850715818,
-        3128888797, 2317420929, 2155667782, 1962983120, 2710186451, 648444928, 2272821232,
-        133989660, 3141011857, 1529770260, 802759102, 2173416392, 1305065341, 45650077, 1082105231,
-        1602486318, 3755990436, 1936896216, 2400713018, 1591016508, 4068454220, 3596573883,
-        2619324298, 33580971, 2286577695, 3083324417, 1169438566, 3225233768, 808739442,
-        2766243970, 3455083573, 1549857550, 3592398125, 2248831497, 3521856807, 1967034,
-        3078700295, 1346379862, 3820864333, 2903766704, 3884607466, 4174763992, 270916374,
-        3218398044, 3434381035, 159751999, 2768080251, 2464394277, 566049661, 442155673,
-        4112913396, 1456961327, 38309439, 1525792638, 2372197825, 1956558568, 4294769490,
-        3096019721, 2031664251, 3017984223, 1381760341, 4260655051, 2253457354, 2984264086,
-        1088854315,
+        2312659839, 2350424241, 1787407270, 1271425122, 4187967770, 818645453, 3539315256,
+        2178962268, 2575529612, 3589703821, 2051328589, 1350506812, 1181962471, 440960359,
+        1364212437, 3414960630, 901255513, 1225743051, 2301315145, 1970642256, 2850715818,
+        3128888797, 2317420929, 2155667782, 1962983120, 2710186451, 648444928, 2272821232,
+        133989660, 3141011857, 1529770260, 802759102, 2173416392, 1305065341, 45650077, 1082105231,
+        1602486318, 3755990436, 1936896216, 2400713018, 1591016508, 4068454220, 3596573883,
+        2619324298, 33580971, 2286577695, 3083324417, 1169438566, 3225233768, 808739442,
+        2766243970, 3455083573, 1549857550, 3592398125, 2248831497, 3521856807, 1967034,
+        3078700295, 1346379862, 3820864333, 2903766704, 3884607466, 4174763992, 270916374,
+        3218398044, 3434381035, 159751999, 2768080251, 2464394277, 566049661, 442155673,
+        4112913396, 1456961327, 38309439, 1525792638, 2372197825, 1956558568, 4294769490,
+        3096019721, 2031664251, 3017984223, 1381760341, 4260655051, 2253457354, 2984264086,
+        1088854315,
+    ];
+    let out_len = xs.len() + ys.len();
+    // Limb::WIDTH <= 11 * 3 || xs_len * 4 < 9 * ys_len
+    test(xs, ys, vec![10; out_len]);
+}
+
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
+#[test]
+fn test_limbs_mul_greater_to_out_toom_8h() {
+    let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_basecase(&mut out, &xs, &ys);
+        let out_after = out;
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_mul_greater_to_out_toom_8h_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_toom_8h(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    test(series(2, 86), series(3, 86), vec![10; 172]);
+    let xs = vec![
+        4161517722334671206,
+        271035878974614969,
+        8768264966582261304,
+        8206956804546718361,
+        10016740860128464264,
+        2943682457917422384,
+        10577659840261915262,
+        12098681961003341371,
+        2525073961085508373,
+        6868684266500244649,
+        509821878609210517,
+        4263249474085213536,
+        2307565444887817803,
+        12419028787241261317,
+        1281995004584322618,
+        13869993964458308221,
+        4485392892470363180,
+        3274613913599014818,
+        13075432300049036016,
+        14042578030952079199,
+        13098932791585915075,
+        10142506622182970580,
+        7251838551095799764,
+        17051632328075633635,
+        14834683551906590335,
+        18022997779550454484,
+        13851155116066438974,
+        3279920275984726839,
+        12575373964173554443,
+        15489604937489489906,
+        12630529117895897413,
+        9562379919499143009,
+        1417878505992996127,
+        2188363987094684136,
+        4744951957683006311,
+        12112952790370550632,
+        313413052918057660,
+        952838993607855174,
+        5933080761807357068,
+        5875775551766205334,
+        10228588026136726855,
+        13111641204516926240,
+        10636665232562365918,
+        11359964631071199362,
+        5929704785320756798,
+        7890881054270407934,
+        4884891330151666074,
+        11055829837821054078,
+        13707765469312479203,
+        8153558212434726394,
+        17445193585880639275,
+        6568289716541023323,
+        8041757936108402209,
+        11089742802624534358,
+        9104866424438942973,
+        3236275382520001400,
+        9213626463300221545,
+        5359296447813232573,
+        2888775200925828643,
+        1504166968227419931,
+        14327007717613163305,
+        11802896026004225094,
+        12726419078417922871,
+        13309155468447837337,
+        8586421913645886721,
+        53962250520164792,
+        10299535356260218467,
+        16946113957982976032,
+        2902460381404773190,
+        14757465720632393328,
+        4285719983639600380,
+        8437230965528545912,
+        5716398831975234496,
+        1373020012523386515,
+        3326027605041066746,
+        17656221602314109866,
+        5927567778944922379,
+        7395768072445629410,
+        11551011221061348004,
+        13862329630891761456,
+        3443745263810155735,
+        497965567194021216,
+        13073929868627981515,
+        9340721263069758697,
+        16189911797862953019,
+        17331477506134450185,
+        18441976800868209749,
+        3733349995001197864,
+        6937510789920909911,
+        10459182483341515090,
+        16282716012969111817,
+        3142838808933013004,
+        176169927348158611,
+        11447076894000834768,
+    ];
+    let ys = vec![
+        3898028307372664956,
+        17056541935478225194,
+        14004255653437064260,
+        5500365157672511509,
+        15774417221201329293,
+        3229812365626959565,
+        1542674716041014040,
+        7356251598468809943,
+        18181760582149085284,
+        6447899299954117957,
+        15228766707939040914,
+        15272444333081468110,
+        8256864946368840840,
+        15131537266446006793,
+        15615697223616434527,
+        18149135087211146951,
+        6359898540214993921,
+        11306735121000975748,
+        10447887135010383963,
+        12772438236294882417,
+        17631737056955710770,
+        8945404460793598129,
+        8945720889114856152,
+        3648711115155303988,
+        4353348842999127960,
+        2258094147328762698,
+        17154005505580115535,
+        13882701371593165208,
+        1610163839528654069,
+        15350954595089578211,
+        2071555476679360064,
+        7797386300145290156,
+        12827100752536039252,
+        9294676638100895403,
+        13194197740670114341,
+        9490868657650122292,
+        13133123495028388830,
+        12350221742051084451,
+        12424378851382358824,
+        9807292823459903392,
+        10987641767148832341,
+        10914994897211362878,
+        828242546480310184,
+        18006801931269403354,
+        3042908768715701160,
+        8117699035539485321,
+        11944855102415629844,
+        7384949013429384602,
+        11066738683960763872,
+        14686958392900209441,
+        16412025437157422416,
+        1334344044228684681,
+        1631366399820348565,
+        18062594111889109095,
+        5175299421808157128,
+        16616812968596909641,
+        797326939277169478,
+        14593183003025528412,
+        3580961852669434633,
+        2104948106588459323,
+        14322976299272137248,
+        3536903766355663369,
+        6932211742640251008,
+        17616766237027326857,
+        1477865108082927148,
+        7817082715310166375,
+        16183969129154492111,
+        18146981620947356859,
+        11618268397687338183,
+        15294321769160092821,
+        2447614867702883346,
+        15261926111061449320,
+        4029723450982123355,
+        7820711996327940306,
+        6188156586792352365,
+        15703528769184364862,
+        6698415575574578533,
+        7770946582061166480,
+        3543987370105940918,
+        8845414905041844753,
+        13110356713999163167,
+        12862812457872444435,
+        10749027774576978236,
+        17822296942008093229,
+        13898152040175560707,
+        1879212271519144526,
+        5428215269251527991,
     ];
     let out_len = xs.len() + ys.len();
-    // Limb::WIDTH <= 11 * 3 || xs_len * 4 < 9 * ys_len
     test(xs, ys, vec![10; out_len]);
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(feature = "128_bit_limbs")]
 #[test]
 fn test_limbs_mul_greater_to_out_toom_8h() {
     let test = |xs: Vec<Limb>, ys: Vec<Limb>, out_before: Vec<Limb>| {
@@ -4855,7 +6124,7 @@ fn limbs_mul_greater_to_out_toom_8h_fail_6() {
     limbs_mul_greater_to_out_toom_8h(&mut out, &xs, &[], &mut scratch);
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
 #[test]
 fn test_limbs_mul_mod_base_pow_n_minus_1() {
     let test = |out_before: Vec<Limb>,
@@ -8574,193 +9843,4103 @@ fn test_limbs_mul_mod_base_pow_n_minus_1() {
             2051217090370709743,
             13908204460746584986,
             10039065459685700894,
-            14729811272925582111,
-            2491677743278343614,
-            2841321092069312904,
-            15064468426708488588,
-            9844013577617278901,
-            15394077255564897694,
-            7846267917227712141,
-            2926235763818832770,
-            3614802673424810100,
-            9439654670958755441,
-            11228283553719081427,
-            300805303577965353,
-            137972366037263628,
-            0,
-            0,
-            17236590422641137780,
-            3624909720816563168,
-            14760980184861256094,
-            12619697833885886701,
-            11158654646608400806,
-            6427394341906398356,
-            14871794339281852631,
-            4436807681521846899,
-            18179949743405593894,
-            13872577160616872141,
-            5825838831881338115,
-            3862312932472761757,
-            138044675074312990,
-            15071993629914359360,
-            17124765792905529105,
-            3248142733680724962,
-            3891075505082712037,
-            6225475960510427854,
-            11369706706515368571,
-            11286728465632095620,
-            5182849661875987731,
-            11179192798752789740,
-            10750517245961749782,
-            9660102259642485054,
-            7905348372980940366,
-            6606241368966986909,
-            10292241739332011027,
-            3848900457088510551,
-            12152591835908862357,
-            4562770772987021222,
-            15938089548127288011,
-            9698047696083781011,
-            13560645597951715331,
-            7732793292629543444,
-            7001973034910440318,
-            409123120027429255,
-            17678180108806694420,
-            12698074214205994356,
-            7447251798260171941,
-            9757491913093219480,
-            10221393982691316695,
-            12294139009925614381,
-            12645218559587650799,
-            1569889406832824935,
-            15186575789336541212,
-            11156326477797897792,
-            3241434575939356910,
-            7753423363217428843,
-            12882554975845472437,
-            8367515576296379496,
-            7308654739706172282,
-            10357093098345630105,
-            7448894655389345071,
-            13033958940183436012,
-            4023926600201752831,
-            922969942182092752,
-            0,
-            0,
-        ],
-        vec![
-            428006685349647609,
-            7204145293518952443,
-            11791003654963925746,
-            2505792214484682253,
-            8108316487989608659,
-            13780196866264665329,
-            9953181804399070508,
-            4136674009057114789,
-            16606729965278593786,
-            17177157337945625561,
-            4837680254021048240,
-            9379658380128247416,
-            3012240265336162150,
-            10902131616911906952,
-            4485885110776748559,
-            11729287033558411069,
-            3601025811236822387,
-            16042927197309963407,
-            15195320086232266472,
-            1335053215979905641,
-            13532069890206403083,
-            18094969954718697842,
-            11554202269641514254,
-            6403207599202754602,
-            5506746445690691110,
-            15578759879114917443,
-            8851198090240221910,
-            33092233213362571,
-            3901122084110515729,
-            1066956798888524232,
-            14634384737761094242,
-            12113217349928728328,
-            9311779266239300758,
-            3954657017302988647,
-            15532948787491930233,
-            7561648003514142824,
-            3533375924863968581,
-            11403256543506170549,
-            14720984883173782359,
-            17386473882517270759,
-            13904760793036335927,
-            1743693703212112035,
-            16080759313684757024,
-            16414994735840739,
-            5271412552749845691,
-            14285133708577039222,
-            2285218927081566054,
-            4416233718641470493,
-            18000037526325532907,
-            9874370178595584823,
-            2431098204987529914,
-            16053941488194364614,
-            13953671515916339883,
-            2042478395473246676,
-            15825222700565763229,
-            215338277757125297,
-            11715801345488940860,
-            15276655380547065511,
-            18173108185582673895,
-            14702410041012894911,
-            17207703913939254166,
-            4048944409583435567,
-            9240704600129021640,
-            10746010074070008578,
-            10882274053549736437,
-            17502803382613798018,
-            17103667474686148623,
-            1210754893985321636,
-            14411333195446047958,
-            9505671694718631767,
-            17438945960504601463,
-            17181086340701682720,
-            2087061119253391199,
-            4309925416075845511,
-            15682876991754565762,
-            1083109033127246208,
-            4261779269652031267,
-            12103546078796301947,
-            11869822949907847429,
-            10776362556491885652,
-            3496260740980747565,
-            1952845492765004055,
-            3956805099328134345,
-            13449597823431522461,
-            1303096111058814577,
-            2380211731709650934,
-            18106526914056683197,
-            11484139358020791769,
-            2127792824095743993,
-            4065001616617210813,
-            16699812364763026472,
-            7039834103740457737,
-            13922378224749177434,
-            3842744781605852390,
-            2881049947442869386,
-            9197037672589549736,
-            16294658006443583090,
-            1354837441772061197,
-            6800107739861512622,
-            7157401423299319780,
-            11344094968696196811,
-            14476284546277236050,
-            5424464793786542079,
-            5963838631551795433,
-            5066844411121684619,
-            17308776236012135105,
-            4153319859448120557,
-            9810663971090332314,
-            4914337127311050195,
-            3998867848399474691,
-            5662987571533822588,
-            879647540404803095,
+            14729811272925582111,
+            2491677743278343614,
+            2841321092069312904,
+            15064468426708488588,
+            9844013577617278901,
+            15394077255564897694,
+            7846267917227712141,
+            2926235763818832770,
+            3614802673424810100,
+            9439654670958755441,
+            11228283553719081427,
+            300805303577965353,
+            137972366037263628,
+            0,
+            0,
+            17236590422641137780,
+            3624909720816563168,
+            14760980184861256094,
+            12619697833885886701,
+            11158654646608400806,
+            6427394341906398356,
+            14871794339281852631,
+            4436807681521846899,
+            18179949743405593894,
+            13872577160616872141,
+            5825838831881338115,
+            3862312932472761757,
+            138044675074312990,
+            15071993629914359360,
+            17124765792905529105,
+            3248142733680724962,
+            3891075505082712037,
+            6225475960510427854,
+            11369706706515368571,
+            11286728465632095620,
+            5182849661875987731,
+            11179192798752789740,
+            10750517245961749782,
+            9660102259642485054,
+            7905348372980940366,
+            6606241368966986909,
+            10292241739332011027,
+            3848900457088510551,
+            12152591835908862357,
+            4562770772987021222,
+            15938089548127288011,
+            9698047696083781011,
+            13560645597951715331,
+            7732793292629543444,
+            7001973034910440318,
+            409123120027429255,
+            17678180108806694420,
+            12698074214205994356,
+            7447251798260171941,
+            9757491913093219480,
+            10221393982691316695,
+            12294139009925614381,
+            12645218559587650799,
+            1569889406832824935,
+            15186575789336541212,
+            11156326477797897792,
+            3241434575939356910,
+            7753423363217428843,
+            12882554975845472437,
+            8367515576296379496,
+            7308654739706172282,
+            10357093098345630105,
+            7448894655389345071,
+            13033958940183436012,
+            4023926600201752831,
+            922969942182092752,
+            0,
+            0,
+        ],
+        vec![
+            428006685349647609,
+            7204145293518952443,
+            11791003654963925746,
+            2505792214484682253,
+            8108316487989608659,
+            13780196866264665329,
+            9953181804399070508,
+            4136674009057114789,
+            16606729965278593786,
+            17177157337945625561,
+            4837680254021048240,
+            9379658380128247416,
+            3012240265336162150,
+            10902131616911906952,
+            4485885110776748559,
+            11729287033558411069,
+            3601025811236822387,
+            16042927197309963407,
+            15195320086232266472,
+            1335053215979905641,
+            13532069890206403083,
+            18094969954718697842,
+            11554202269641514254,
+            6403207599202754602,
+            5506746445690691110,
+            15578759879114917443,
+            8851198090240221910,
+            33092233213362571,
+            3901122084110515729,
+            1066956798888524232,
+            14634384737761094242,
+            12113217349928728328,
+            9311779266239300758,
+            3954657017302988647,
+            15532948787491930233,
+            7561648003514142824,
+            3533375924863968581,
+            11403256543506170549,
+            14720984883173782359,
+            17386473882517270759,
+            13904760793036335927,
+            1743693703212112035,
+            16080759313684757024,
+            16414994735840739,
+            5271412552749845691,
+            14285133708577039222,
+            2285218927081566054,
+            4416233718641470493,
+            18000037526325532907,
+            9874370178595584823,
+            2431098204987529914,
+            16053941488194364614,
+            13953671515916339883,
+            2042478395473246676,
+            15825222700565763229,
+            215338277757125297,
+            11715801345488940860,
+            15276655380547065511,
+            18173108185582673895,
+            14702410041012894911,
+            17207703913939254166,
+            4048944409583435567,
+            9240704600129021640,
+            10746010074070008578,
+            10882274053549736437,
+            17502803382613798018,
+            17103667474686148623,
+            1210754893985321636,
+            14411333195446047958,
+            9505671694718631767,
+            17438945960504601463,
+            17181086340701682720,
+            2087061119253391199,
+            4309925416075845511,
+            15682876991754565762,
+            1083109033127246208,
+            4261779269652031267,
+            12103546078796301947,
+            11869822949907847429,
+            10776362556491885652,
+            3496260740980747565,
+            1952845492765004055,
+            3956805099328134345,
+            13449597823431522461,
+            1303096111058814577,
+            2380211731709650934,
+            18106526914056683197,
+            11484139358020791769,
+            2127792824095743993,
+            4065001616617210813,
+            16699812364763026472,
+            7039834103740457737,
+            13922378224749177434,
+            3842744781605852390,
+            2881049947442869386,
+            9197037672589549736,
+            16294658006443583090,
+            1354837441772061197,
+            6800107739861512622,
+            7157401423299319780,
+            11344094968696196811,
+            14476284546277236050,
+            5424464793786542079,
+            5963838631551795433,
+            5066844411121684619,
+            17308776236012135105,
+            4153319859448120557,
+            9810663971090332314,
+            4914337127311050195,
+            3998867848399474691,
+            5662987571533822588,
+            879647540404803095,
+        ],
+    );
+}
+
+#[cfg(feature = "128_bit_limbs")]
+#[test]
+fn test_limbs_mul_mod_base_pow_n_minus_1() {
+    let test = |out_before: Vec<Limb>,
+                rn: usize,
+                xs: Vec<Limb>,
+                ys: Vec<Limb>,
+                scratch_before: Vec<Limb>,
+                out_after: Vec<Limb>| {
+        let mut out = out_before;
+        let mut scratch = scratch_before;
+        limbs_mul_mod_base_pow_n_minus_1(&mut out, rn, &xs, &ys, &mut scratch);
+        assert_eq!(out, out_after);
+    };
+    let out = vec![10; 905];
+    let xs = vec![
+        6809535447687914471,
+        1103330005972507068,
+        372788324988356904,
+        3310988417383334329,
+        2768136713654061049,
+        17867128683005223868,
+        2238946582723710409,
+        3081056558438604150,
+        17026613024401611127,
+        1744473747933758430,
+        4884032754714318833,
+        5071706901657208751,
+        12689572021505695693,
+        10978480551041753799,
+        1800129976413451867,
+        16286820002622524539,
+        7281000574726170357,
+        4219678291438654832,
+        15700081839772688751,
+        15205907878248907247,
+        14393184334614991066,
+        14756848254875178077,
+        4793637008204029812,
+        9858797803427744303,
+        16884118866110086987,
+        2330774877124323542,
+        16233995226797389662,
+        6091870787973288330,
+        5905123672617126566,
+        6532637120046850310,
+        3781012952181700512,
+        1147955258215944149,
+        16381863291477272057,
+        13470194198585905847,
+        2807745192926696062,
+        10417338102018291024,
+        1182003379390951531,
+        10572015994470653864,
+        11256589899556451377,
+        7047560236256902575,
+        16809106838958682299,
+        8167397083217775737,
+        10405120914265859441,
+        6050515699222625782,
+        17283763639396397450,
+        12481792043865481917,
+        10515115098175408116,
+        3547231139273206009,
+        2955870806881464447,
+        81763485884574417,
+        18038197306999979657,
+        2697663113419170508,
+        13640328591104580425,
+        16380387086795482436,
+        5937997179794219672,
+        11280227770841311656,
+        15247718447550407960,
+        3356207189433327172,
+        9295483089881333487,
+        1048090603086884014,
+        17630347864859687326,
+        16292502783826501772,
+        2554058376409680235,
+        8746244974811121511,
+        13601151617020904249,
+        11424464616482878222,
+        16592787416774613845,
+        9541965809588758702,
+        10304263209519954371,
+        9718232210246640079,
+        3964199137038797179,
+        1133689222449100290,
+        11762544598027160268,
+        8892566166642784135,
+        4992855273094478682,
+        3467095579457639126,
+        17527051578764837605,
+        12788831523184320706,
+        15884832626408420422,
+        15445614371886990386,
+        4605408054811466579,
+        9468992277252416191,
+        16059556135741327423,
+        13703354904914355468,
+        12651524370372775470,
+        12076530382821868093,
+        9203720925482564499,
+        6751521177612318975,
+        14730048400600245248,
+        9583087821356332745,
+        7635737274166465491,
+        15909961927185268608,
+        15152412967792641434,
+        1117148048506231546,
+        15036765086001151283,
+        9461075977858154319,
+        283984775170615496,
+        10870934978120381565,
+        16402595751643852267,
+        13300950714409955136,
+        16269834104766854165,
+        3823800040156766433,
+        8001239710257605750,
+        8854456135465589491,
+        3559503577426585699,
+        82907824154359285,
+        10429508186090534661,
+        1795271336507026791,
+        16052612691484506490,
+        4449527904102534257,
+        6652828614119627650,
+        9436314767260323923,
+        14084834581845197080,
+        3924506166878684856,
+        4006253714000661354,
+        16835787368527074469,
+        2939573978616449941,
+        827685924983906914,
+        513438846841933201,
+        5499086145667050935,
+        5970432596053952196,
+        2357808915999726215,
+        13130152994826263557,
+        1029371010558121271,
+        9673453535289645591,
+        13630045027309361503,
+        15140438118448998733,
+        14475237670869667854,
+        646607524428232975,
+        8603154126726854477,
+        562632002079264944,
+        11132087168116199036,
+        712016548873706286,
+        1162552953778143562,
+        2720821187699295729,
+        12629405027198622023,
+        10085431077125120126,
+        11036408623217209328,
+        9412034889605831573,
+        3580947814659655797,
+        2384617485952276249,
+        11991332791506170431,
+        355732385395682650,
+        11957596579072080069,
+        7637503009753705463,
+        8641065560841157989,
+        5385185370436588141,
+        14613602605895034796,
+        16149929536213837897,
+        14926494841744326505,
+        8478550502351441150,
+        4754411934055301811,
+        8446559217491804253,
+        175563428577047295,
+        13317411913787179089,
+        1999388610388624930,
+        11997574948104529333,
+        7709394800320180604,
+        141106960118475071,
+        9185076664574888313,
+        14711830716786764563,
+        8646328906792567341,
+        6435770954980752754,
+        10889857927018380675,
+        4856598903599320278,
+        5559749328765755744,
+        55448005653193385,
+        18376290341644060781,
+        5411230779476997204,
+        3208977317832509473,
+        17939099296520163690,
+        14610289283912272679,
+        15727344360076838968,
+        17691225907664733828,
+        6277158099136871988,
+        6227268882497066304,
+        10537912046761025798,
+        4569425383713839933,
+        1540443185169512503,
+        8083699057632389568,
+        7713824171800942769,
+        8199521127345296820,
+        18024850838783811685,
+        11847360547777377558,
+        4462968389243655548,
+        3995475265600836322,
+        1735988164410255922,
+        5169863977080869823,
+        2205341702567171145,
+        2235183401932173280,
+        4237083636488253034,
+        8651273126396740151,
+        7677914001769310460,
+        2488550701988271373,
+        6362894001274871299,
+        6938141915629282617,
+        15289077957178314583,
+        11369273085874533146,
+        15146552950762343609,
+        2250130405529921684,
+        4507408042222997556,
+        12067657188384750699,
+        753594982979265248,
+        9819871477024072995,
+        8074675523776512539,
+        17863686634271613087,
+        10148559641897798765,
+        12078707926245944334,
+        16056144107847929568,
+        11477562644132166451,
+        633839849054735197,
+        1974937519863601198,
+        9431759751115500349,
+        16231263638095445675,
+        2852944099010004296,
+        10805052896366212239,
+        1090853228632071464,
+        5943661063504137996,
+        12279437176065016726,
+        6997180572817064008,
+        5167976820090909749,
+        7252605595265132660,
+        2377574067337995256,
+        898853472418766215,
+        15283250736100608687,
+        11681971203683297764,
+        15733049786589504720,
+        1874550821442898161,
+        10775751683446032879,
+        14346795044143203654,
+        7996691180178068337,
+        7988670994586391460,
+        15438536777951336029,
+        15583275281862893881,
+        1645086929138484726,
+        345744887729039216,
+        3687893952132688571,
+        10659362456954960532,
+        9602393538964870338,
+        16520689555988157334,
+        12546754178798486611,
+        8040849778898919845,
+        18425567932309437192,
+        7524804173796997825,
+        7482803818757957426,
+        2159381166607938760,
+        11953191500503254263,
+        11662381083106843270,
+        15954507061982303817,
+        2311355641538841753,
+        6262214959978467810,
+        3205610786437952171,
+        5833762588841339039,
+        9584891950834271718,
+        16431258786750754174,
+        11295777256302945260,
+        10262743010189168916,
+        6579167273633548585,
+        10655344951630924167,
+        12426159972843518796,
+        13809556914733676017,
+        10421408021758282646,
+        13742014587503715343,
+        1213383935103977733,
+        6155186430649126184,
+        180686018379637244,
+        6671736391598895649,
+        6711423272868884639,
+        10567222126096946863,
+        236255051969434496,
+        10538599139522078386,
+        1840416208489527534,
+        14920772374579070414,
+        12181583640158673672,
+        13150385378549419551,
+        2385161800547570055,
+        7427796248918503499,
+        1086611422915217495,
+        1034188908993802486,
+        17895224758095668351,
+        5605583861310416260,
+        8276090981819119594,
+        16774347628768167103,
+        4438689443393994418,
+        1445832965009646259,
+        12020209207509332983,
+        12101154623632027374,
+        15952145139262310731,
+        4569147745515423074,
+        1032438357998575006,
+        7719204784227574899,
+        7620365528941515938,
+        9134138337791325501,
+        17595820721307989341,
+        10837897611871188641,
+        3628300610284347069,
+        6056782082517998847,
+        11889121953261384595,
+        15658848261681587671,
+        760281128415892566,
+        9901843341271410215,
+        17997287540098693599,
+        11471174400256577350,
+        13773435664609284401,
+        224645455536727129,
+        3248370257584471677,
+        3402146064818516495,
+        15419892046652096835,
+        6817408051904455983,
+        13462567452174648864,
+        3865219578658340818,
+        6401692728490725482,
+        12241061753482985847,
+        18093898726091231688,
+        2085695270753393724,
+        1486152210073874278,
+        10962392580868374592,
+        18233050379629714175,
+        15440497426454102854,
+        17029248778709090701,
+        11943840019902256468,
+        1838494147415724342,
+        10624384750477063994,
+        2802400104447544796,
+        14482700272009891532,
+        7488752288284248623,
+        16689053330367821624,
+        8122258539396763672,
+        9378594940099937779,
+        1953829770254669263,
+        9095248272317331200,
+        8866691394084862202,
+        13201017990930506125,
+        5874101266603325988,
+        12758355031414108476,
+        10906401543018450367,
+        7070115242582431545,
+        8015463853343573521,
+        6337419761897564053,
+        8245569799737529081,
+        2143044650526073628,
+        10001469296387640338,
+        11129612211343148678,
+        9846592166231801208,
+        3293328206977069772,
+        8271586651454757561,
+        2516303987084978983,
+        18226852422794010543,
+        15105013013529789613,
+        18154225228636318154,
+        2013990863661732083,
+        1784747160358384741,
+        12567581004427688912,
+        12324983884662973603,
+        4935897487517618842,
+        14994876132441559883,
+        3460392282827044973,
+        178151701108469307,
+        14330685158880278679,
+        17005759398710652579,
+        12918079073431941444,
+        14737890013936547836,
+        13116140709796209375,
+        4406659418908136238,
+        12427086661031458855,
+        7492548499951595477,
+        16111633531233494957,
+        6547469955282364389,
+        10562100669850668222,
+        15418878616411295006,
+        4956757255402949102,
+        8013302230254643702,
+        528640010877453099,
+        14207088120532813066,
+        16751882716911936366,
+        7949419147113034512,
+        1638837693666608472,
+        13878268226712426549,
+        8273407385523164786,
+        11509174550235804944,
+        15966833959669295468,
+        16501367189938100697,
+        18237618137386130737,
+        12449896376279048977,
+        18110666911456042806,
+        3193062725115205782,
+        6188346541223558421,
+        8058450572610971381,
+        6102995183728564982,
+        1217219394955358461,
+        4696899915064498873,
+        3897448246350199671,
+        12662172956137287871,
+        6610124623177066401,
+        17355303367288349560,
+        2046855789488294262,
+        3211014904560014361,
+        17115241076245460723,
+        15298358927000333664,
+        9182539314691893135,
+        5164209594070862585,
+        9062044958688820752,
+        11123512834458221854,
+        2659061244791686400,
+        6806035717656270289,
+        1027290028815967628,
+        9405947735639936936,
+        2540349601344589130,
+        6895259978837037548,
+        8904474026325226434,
+        12273615493885622366,
+        8677764880724506780,
+        6002959367751808149,
+        13123965742105252412,
+        11735284964695785299,
+        2417001610052848193,
+        16769279424767143997,
+        7098399088498274096,
+        9910748236374366737,
+        11111297830299095939,
+        13640041663283902885,
+        15915174780574330612,
+        10929481089808875146,
+        4581632826936763294,
+        8581099749824253588,
+        12645736569302882821,
+        7359153121066395683,
+        114042912187761457,
+        8021867264848320576,
+        15492861144941287563,
+        17574065125533105845,
+        16374369432221228355,
+        10459169993431587286,
+        16366034776295832243,
+        2627084718398353897,
+        18099240392705942111,
+        4326916362018195403,
+        6552915731272193737,
+        4273219172539573763,
+        12682979794809012349,
+        2630341686377453497,
+        2883112777041844122,
+        17280553753834600520,
+        16523493292363775458,
+        4645653082909935710,
+        10857150457399097857,
+        16240900012104288727,
+        13936061320958754124,
+        7489056125188749067,
+        3240618672663587347,
+        4473897013556224538,
+        15959787311013455542,
+        6807910945586226902,
+        9410336371061067189,
+        6906088553149840730,
+        5605158148442495482,
+        884531864210940213,
+        1872297304786275835,
+        313522309067627421,
+        17334018801756959340,
+        1675436862731542012,
+        8842978529412038193,
+        16491099153488944340,
+        3255602365380029669,
+        4091823593236384660,
+        9059086866751700824,
+        18074592049968473162,
+        8939611511088706324,
+        2901954556715326677,
+        3168063820638083277,
+        9642457237234984168,
+        12973730047202029231,
+        12383420012085361069,
+        11980461635855055601,
+        815939574328079776,
+        14011981531029282088,
+        3819777392902083260,
+        3664266433302366398,
+        3842827072078938508,
+        11680613555445372946,
+    ];
+    let ys = vec![
+        15245088662193948010,
+        854969528224537163,
+        192457876290468361,
+        3156774054099849881,
+        10102117358735393641,
+        13923135497401538045,
+        15603007686998930972,
+        3707765480829539463,
+        1075990372015045994,
+        4440028045035707188,
+        779932550205535682,
+        13284596850012603887,
+        13447370325749987403,
+        10657005451799608034,
+        17344058779081327933,
+        1801131630646010099,
+        17879455113972297046,
+        1049662270419803525,
+        17887003202529550415,
+        13730724178286439296,
+        3086493866184691051,
+        7455503161286080904,
+        14945249663072669446,
+        7413071270018261565,
+        8165098975144402988,
+        15667870805615006559,
+        4534237642686726425,
+        5675059133984408369,
+        13542693529471369730,
+        4650690134857994243,
+        10593876026982724440,
+        8719234160809710444,
+        7340192483727047710,
+        2225660849988538666,
+        3260628781823840386,
+        14784063213821786553,
+        13478324037708856111,
+        6239844587086244103,
+        14508626048519473050,
+        11443816492520902359,
+        7084448144752764341,
+        11673478635762496725,
+        13444020463604694513,
+        1798574113181758005,
+        15195278749704748030,
+        3490272214933312037,
+        15632500462832370824,
+        9808665338648603851,
+        6377980234800091876,
+        11306384233660763805,
+        6392788317448223882,
+        8005181869701567455,
+        4601526777105113530,
+        9348184476999479133,
+        16105441815997897842,
+        15373735633778437011,
+        11733794529384137433,
+        769246272107807645,
+        2922899274256775805,
+        16218486247871807873,
+        10650657974127272786,
+        579665301817927565,
+        6403006378940431337,
+        10150254532952843560,
+        3736822004545760197,
+        10244207440138560761,
+        16631379436671010056,
+        17418302422321190629,
+        4844439457855539440,
+        9662799133272397874,
+        11622100630061039998,
+        11017257064923257696,
+        14025546287952884200,
+        1170766120552674008,
+        4852413824670160293,
+        18019298735978800767,
+        14042374992041286164,
+        6103187929964524269,
+        5988592592688695870,
+        5579172720281387479,
+        10738878044274955012,
+        8401646271610146442,
+        12016061916593958227,
+        14752402557741497038,
+        5053283107906893264,
+        12910662726197463795,
+        787526459034857809,
+        10304827788120361107,
+        8387521101013404665,
+        6030209567663971422,
+        7511028869236306454,
+        11105170944119024313,
+        2911699195421772292,
+        11710398806568443147,
+        7599646386487625804,
+        2146501359265516686,
+        1193294087739295886,
+        16419769173966961854,
+        14779980297792837632,
+        6286361066120350249,
+        8246126699673376536,
+        2339493649448723726,
+        12383521129608538925,
+        17459816050942292574,
+        7213741082075285427,
+        14702683527305456088,
+        17849030573001874153,
+        3273901152373442943,
+        10086273715179643444,
+        14351251935054659627,
+        3067622597087477151,
+        4241957707372911307,
+        16686513037697490920,
+        1503886102490162470,
+        4222986769290077389,
+        17209928444872897872,
+        10064374817012298812,
+        1391022681726221923,
+        3482099619102309134,
+        151151415131464647,
+        5477310851692317777,
+        8185741896741403527,
+        12297179519749775078,
+        6980896315258250234,
+        5491311995173541969,
+        10908311176531272611,
+        15140263006374103771,
+        16292302828281485620,
+        13488663273854028028,
+        17078235461511918753,
+        523009743565281503,
+        11105648925812514991,
+        13827146014280242829,
+    ];
+    let scratch = vec![0; 964];
+    let out_after = vec![
+        108918525455256680538837849328252576761,
+        315679659183693206590475473955710245546,
+        24616635394836280989796737024864117144,
+        20044932311669055414621663849080213002,
+        290828952345049113491859459178722700051,
+        46997882561816272692398540918648803589,
+        6010296069959511021900094171735045149,
+        263871118957161786182408734445403777842,
+        139071324839207851866443556459867653956,
+        99821321399103486523620620065387691948,
+        183287627537389153790836507115392664187,
+        201003474299331969049517237265849195020,
+        194348218216221981467772016274780286514,
+        324496858569195481935828548339333502025,
+        126659773220750811538722771006607957214,
+        302071173007731590815656032924957230074,
+        335168955552313211185796248299640345506,
+        298673821199597755756368813219278172579,
+        147906389963250235981385941129904934315,
+        43760047091841109501750906469982715290,
+        256914941620708895225069550832545858693,
+        91591384062983933853149061914631758504,
+        51267507287405680710072828285147992894,
+        86986961566061770312540038128690817051,
+        42040070158582869304294501990447291257,
+        131342226312103707447889166426806326076,
+        8754355199769803233797966535208532079,
+        75460475486931785423852460505262615788,
+        100113903763194147939294453253621549930,
+        225201128590444022107549860463942585742,
+        236873750809257359927609549900206144597,
+        147572887666605609834308305807458084368,
+        242527900966917342176183541767151849574,
+        185606410799620684353433171519803255204,
+        193664512026259622784843560954057308533,
+        248483908904252621323304680630658011484,
+        228897066527805868627541543865706762060,
+        132763869582812162827790584193897129768,
+        162517179417982645042057168479808072145,
+        286584611753043051790181918751972717445,
+        97405765380140016227727477243628749880,
+        125146966038907052337272417184714890219,
+        247614228047429572511943431308511794753,
+        8719560888721031391532038024780754524,
+        214318079011748024793073376510276825908,
+        20439574149543941511849515862755965411,
+        305315941876493641450151684091640496739,
+        285078119499253617185677085021385470024,
+        178739873948036456627076659746770065032,
+        213422111597760356092080517796236185459,
+        15169466052268933447836066260292316779,
+        335362210553012506132285171008592056602,
+        179384470965279946073583666930777360376,
+        170704136421919391348536146825276917154,
+        90100698004410812505674979432173300219,
+        156597854039395768823588558800664059956,
+        309097225679753262779538487387212134834,
+        62510835390040074045633535082415710345,
+        138148772069967383401263559769957779697,
+        207254674987939520380250749375722518502,
+        271545115432126528120036963726567815303,
+        240608405826384822356938204556411780663,
+        107720929696823253258487392973565597993,
+        49922600341435258708201415573488082035,
+        149284670704894010048779351854891191864,
+        63530611818336430710828643574886434392,
+        49225029135376462422877029266416417877,
+        300981364143132334223108233443719799796,
+        194344319044911572268374471141015551128,
+        22404251048368457338506085664565412197,
+        76623021537941996635322514019683568164,
+        273459500887499724415372528238598788992,
+        182119187914396080705428201481433140472,
+        167598447444566546439555231716021267478,
+        255417040920911669184634978273958996992,
+        9220150229061410489994639014778746565,
+        235080003578095984177813643807928834010,
+        271398083997385301531146257536331728917,
+        302935251024952651414682525662525296407,
+        315316998392069851519553750775033887296,
+        281445402655191349093118958368348512254,
+        90895290248645393571992211649480847937,
+        38449904242745983928380163952931307418,
+        46228109562123259901512915181490837824,
+        111688992642685248363968041984239475288,
+        23570761846573878425728604252880655188,
+        21221848938511029823429726657933395847,
+        137261406375784660975397890614769881795,
+        308048342872541987263146910952149763921,
+        121334679911937554628079323799936239530,
+        16267377149765789731603481722260312313,
+        154030504253443358973536222840938721455,
+        142997117921239725529500857981021573633,
+        255641425198506886441462161307734278337,
+        155945200019714161515221205097434270555,
+        268816224674944432670851745224121634875,
+        280168991145647260691890257067844841202,
+        335876668079140942172583170442610878496,
+        223051489542606362665229553260053433679,
+        236313982517197658601896414186229516673,
+        172798293813209967488460961732755458617,
+        270386413684170584835675813709272165349,
+        292514109597372693068793665034198091551,
+        76276409135695466083632817720209382588,
+        30194837907877047526196572696515990908,
+        191564604367590968466735946887181375437,
+        16284187602857009076867578539595876396,
+        254209584958185556747657580723880474084,
+        76564551872049102546397188899742284421,
+        33966044551404607619717180584421270969,
+        202960760130165586699677477180415267857,
+        166556549457067670152671613739014969972,
+        72795709658412780712996729752655036945,
+        115368581850439410866109142589953614000,
+        130687961581348479654226252395005267376,
+        302500979800076901116448459423223919569,
+        164706752559378983294996558102859027139,
+        137252751508393605650100845042217660395,
+        245540366981607841071649011600845688248,
+        190853278546663450432251808128259299915,
+        22395612274499360678777597463836111022,
+        103491261110057525199979366416867874368,
+        56257292269775761822137202793628605857,
+        295489392895759920833391927694167154861,
+        266204378968261937589431670217889774109,
+        198882393226329991960376516728395065418,
+        275480421507889235981866436524815711125,
+        292778319345763280601067362581541120095,
+        132776949950254129065257798049771504302,
+        205545635461017231495729027712278050635,
+        281801439221933469047873458935747357067,
+        24469725804643039873050333913884432636,
+        292405263682847719367509840380018554398,
+        107817069897832122645557571077408478214,
+        310796350589184280119465075947536771171,
+        310874939057440394344042738979619876249,
+        295181783305407339128663140226668394499,
+        339902583576777332566921306562344681176,
+        192955466676637236785735514515108666406,
+        262467188979960507867937786401121184487,
+        320547270408794467585423331280895174445,
+        191663370607553281769571850960657031081,
+        76962236242845637984557686270426884117,
+        237146968140307120596914194739246554700,
+        214751844721242371056949980877739657008,
+        175209417120595042687868871525672048754,
+        334952982027883761122817124872423913164,
+        301931512731294259951512121026555098270,
+        209479971695689623690357337036166896465,
+        157859043543744680024401424785000171864,
+        239768501076427514961458045761358044100,
+        111780211608384350668134159251220144563,
+        54733029147686953928784147921450729848,
+        51511508059746312576623308610911741909,
+        145632879455140685111041316475842781258,
+        167476879112676565652741495413050396661,
+        29242912549932140653230982504271282162,
+        182171802512783689319038960256672249416,
+        61992510679840510400410087634245507361,
+        145050182514674335542022526794515756625,
+        129967438026969373368764281160047118418,
+        337222864408719226261002841324396405073,
+        151933237389493361426638550289565914483,
+        203896378243374309424968938901840215859,
+        251316276282363310936615060937351235967,
+        104402654095699941564756435161256260698,
+        129623020775817731043833937491998263979,
+        283525478231778982104738847286142506293,
+        141037760691899082051157648867306412275,
+        114307813334770731103688492734761799718,
+        166624832969805451658761109606971336869,
+        226010235987144433444213103800259267845,
+        199151179719150363234088598320721508590,
+        238924638835683634089745765900459077601,
+        217767084265848262161095653009422262045,
+        281788230256313071376492848434709147270,
+        329512365194107751017622364633993272976,
+        132759059303094173006064863129382485953,
+        207048469442222087690921288383173506229,
+        175977358119594957282428807114119594598,
+        243460140893100774834667799831829558761,
+        133971779892049325660414350981807180835,
+        255202579578317752162732605959199670014,
+        167817104118446191997085314664293463424,
+        233348096094422369303619454622792694923,
+        67365365912183246457910245699786984304,
+        260862877127769286808358462841439788369,
+        128108986584675661327291176051583563010,
+        303929880272221463350601941771037723295,
+        149873660367207946219082988145137742537,
+        260007041040246918243505323337662406949,
+        248891350868865167347723427363078688023,
+        295787847774135115059318251153711033763,
+        130388439253577312780789045614324950828,
+        97438694219136920208822904689524203135,
+        150874540049062087894155959920506285146,
+        222298119155024790614984241999599465585,
+        225085449712619583087401896074962277167,
+        101545929500100658330705090895539540974,
+        135853703195753996755417013753939362792,
+        89540138643600732891927582210574951368,
+        258049236291025519054228403390012546359,
+        163652658983365598022469781295063145345,
+        276292537259550893410634363207101101386,
+        284358020646273966880058455087256314958,
+        53687990894984215348895262869118425831,
+        159058038466236658489672104740701713703,
+        234267876913177872970063326679483461135,
+        164773865248329452081851096342810535395,
+        221752542429904322039869396141700058693,
+        6725158064406309904565913319556927887,
+        21967280772225685719884875576031057284,
+        324556604393126224233794561971555438532,
+        185734412377881776468473441941938224773,
+        191648965222860357439743603224311221071,
+        246731518883239316961474842662240860869,
+        293037230417662105773705387731278828879,
+        94814733918857519649371057309688049208,
+        89167084212346665990498849214217603435,
+        32561234321403676903998722450537350592,
+        118550742067648784083272316800314210993,
+        7486136367737714168391004782304105537,
+        336194682351982268656009398178887790326,
+        46250887746513180716735093277516004630,
+        115111968676139207437465498362726910685,
+        324687943226057895873162355559396305085,
+        124494283594102542114611675596993735401,
+        16208096900335135002067019639611536563,
+        93492945986778538281060400936568587696,
+        239092577503447883510937907138996741890,
+        147072646299569775514098523822861210835,
+        268254851785399315862109767383948716667,
+        260644248518449208759890551552652142273,
+        240638637705368948824596768803892906214,
+        75544013332933144088871388833701273946,
+        104930150476106038201795850380747858731,
+        260410758292243037796565667512326513816,
+        299337591139089272876010689634446285637,
+        115481466615061546280884817723696559022,
+        22202734923463768439891880699714174856,
+        46433237111179848832198557321650133806,
+        67663917267179778457834868465974803922,
+        233159016125861162643103098696943888661,
+        99501785723455003325043419584316287373,
+        81596189309943220665808151919470577293,
+        166186049505141524006417280698623644244,
+        96990473620810707468784614023191024409,
+        130045006724035919774282309338705004613,
+        37798177460180717582188503014071999538,
+        300981473329884628457511808084159983568,
+        2791951319270208532553353566635598184,
+        171299612645034736851160314050636732951,
+        285466329902316899200851573562296572824,
+        286364938326607195767356921963389099887,
+        227003834434008654683827931245614700511,
+        62725827396993161211860755592238201627,
+        149174424730044592747491522776419874960,
+        20361244590869138222052258139711414067,
+        131960872483653662948498927251514226107,
+        317056766844047158909139031458605743272,
+        274086399657820347293542018023996112917,
+        244506341512804199297364581286380917077,
+        114234424567529284127916935603350533464,
+        74046417300337791169658530945957740575,
+        278954119409878816720337775266548096020,
+        274057594874130916750832528591663313891,
+        88690461157135422308102307776578483163,
+        46747611989496083566743740868829351535,
+        7078261897800719896583790999900464089,
+        318352543298867716681903517401050516679,
+        43849047006169762034264103392699687245,
+        109171995561377043218908007397806739859,
+        308364694978828330935260172872644240417,
+        40134660222291262597524497494718222375,
+        41942483435712017923556484308014827183,
+        201398527011608697619971689168076793114,
+        309186425358622650637471954680247410491,
+        49822663629773693022300900962016875241,
+        298262730891536396931157710361371378180,
+        62316430006880193682926611026067100297,
+        20838156953030318825914672182450156548,
+        132034916983784521732873786748121914434,
+        214052604758578032629628425023223208283,
+        297486484245449401584106933166403396821,
+        273665231025038088315348880106491643665,
+        292308744440782639821923521393765699602,
+        273057708096558331106198440135178612813,
+        202754233986968906579734684804897956464,
+        172934308671329364068787710160017245748,
+        155687485912511049782412682224531156217,
+        319799357546973714815261052657396921976,
+        34818636031859366201601999029991984900,
+        36388065720437505635951884026488056806,
+        2156354333907916775315732836316352179,
+        46200373137456894226011084936274926564,
+        323022949825482388855897079538207609478,
+        125955646428727552651261432590817886354,
+        334199487006096285590821085497233946651,
+        179627395319921045066687010952317673036,
+        13001561087277711538373482004278010856,
+        332511636057300500280201615714066482152,
+        164628040912299096197629658493004615340,
+        339202130693916986677401123446757110695,
+        22947117457127982747684623017240667946,
+        135074207308164701788045856185540719196,
+        239077440066532734686331936462235085127,
+        327120183178300345547335754981403936632,
+        217994984135595757234337803755715592695,
+        136883231961687595519771285634185938831,
+        338861880184658538839427677861611793344,
+        67792609261902934735824154865681891396,
+        244185106435389570102243284164915174089,
+        172983523669777320066355981719593996275,
+        171282968314619064058876659833676668677,
+        205258850212153151313824201251571985215,
+        313851619426695921310268200886187864750,
+        217191047842511132565448727904463529637,
+        278793308185933869784235417524332136712,
+        302798026367754276522319729585140895141,
+        72723430098693144925005486879546954736,
+        101207021660433203033492356502881849600,
+        191180321025966551928754966714362971622,
+        196834357512690362322921986134420958156,
+        280369983015025733852469875043910369375,
+        291766458749810342958235638119646471260,
+        222622351694539730311507120639770781964,
+        157237164387702124331111852075226734065,
+        216241766603181564353726455789810591144,
+        324399631298758636222695017886557274984,
+        323980046849247909190136744302938748586,
+        55638738999601724817991199971703099805,
+        329981061323009827448231354586073214101,
+        326223310226993845155485865932923382891,
+        93489118306073558340561648858121347471,
+        281968196105773108032707052509385070919,
+        76088456952232834561754551083199211156,
+        279223060267694945602189241230303744050,
+        275773937321431685685360856948884012321,
+        307516652811871291695128252263403255973,
+        108945680952660778127924712386988710827,
+        91374691491379629428754583311669039622,
+        272150812945555456542067990822165223203,
+        30840663688701580251648045446633003420,
+        120457338089907311203372682498495748242,
+        142918904423573789344348154727391904977,
+        183627964975292910004372043826985313898,
+        338649938834724119414126955814860458463,
+        304664567363622837297759976854098379757,
+        275650426824013019494826944833625502790,
+        281557760874428732626724450981873235638,
+        304917242858495444418719441508094269560,
+        248036363048243280243132185609067460200,
+        225840476586236408927689025768468371151,
+        88138991967795563266115476117251167215,
+        268145372582823081749250440788918904594,
+        267743763652791399057242200955079614672,
+        265645372522938549421815060175786299230,
+        247945816494097869532095513801361041299,
+        113295041211559537320310061577720346095,
+        235105128325973279833312108272808511340,
+        210464008664727162731465637886484623924,
+        97524562672798492102105557565602390999,
+        120594830496614452177619791452458170496,
+        320980094892519814021107753453224031495,
+        195366622204678725337209386388214368771,
+        310901165572437490142641837592552771112,
+        198461489920012092872312002247925918985,
+        65177669716471107775199866352662844241,
+        107875777941141410587739550663613092952,
+        246434422221002047200033462682525958660,
+        145823847980975457473260036185533435946,
+        164737580064815097243930119058109684249,
+        200179557645382941501403088663827508759,
+        131221602656815311616113708842240731817,
+        320196663017401267934374225510283058928,
+        194432644627882165491897215244640454795,
+        86670700385308485947542049156536481628,
+        20557098909555907170915482317049467563,
+        76261048605823387019319334492978529867,
+        151050946764830981939807048987123618527,
+        192053027459064447424367504649900611990,
+        31760760864629246948889444111310368689,
+        81506862609743101655864662660060046818,
+        144548410560012817853591902453784767303,
+        58717808075288545462168995379590386772,
+        168084170784352117117054445331379731895,
+        244352063869465384856330695415447276471,
+        108177553019242730231083834695988116874,
+        6109493776766187727716014477967008787,
+        48791136480579914584333526783894835920,
+        257609252735464021712899507372141849575,
+        52034640675673637331885414956566452880,
+        63245516258845760453270351181648464944,
+        269672689069716975605428787564644552634,
+        90706654965173385119745868642229185253,
+        216893031995983695703707008834560179283,
+        190388192992184232888815081897791368890,
+        125531030956945859182875837891528984849,
+        300065771360816126091901957527543499139,
+        329081260600199136868790462775594267256,
+        12917374661600789423678064435283970509,
+        15547386112703143541668455583489702565,
+        325262100602485912705525358085967008468,
+        85725741519680989652064422060679423872,
+        5365464965327232724207846086521443712,
+        252990365344267516651779879327256631909,
+        335727487841961616858178199003223886391,
+        307778854970474440912884606775521942010,
+        27374463423348703640039362434910432502,
+        18452229825304006149641856311435414230,
+        205108590934921513618995779317606201312,
+        334569034246053569828897626030280972417,
+        197748542294424844767669739249086609077,
+        82363512000898160208291526077368663450,
+        183245593702896324828957590609331001618,
+        24345256273739911405797201701688239701,
+        92690468297688323630913742372627167060,
+        10804023945241527738083205070559906771,
+        274266576477065596414239286069167805975,
+        319803437112433253203078124464912597176,
+        307617110188887532412375777454219362207,
+        334429563840038726260371301926762550790,
+        100544658940752059815953901496017495219,
+        226600955037317617887274497412825100878,
+        155714321034337150838102271723105121041,
+        194954564174158865477448902424386957491,
+        19784900606763501004889392067202488276,
+        242840100637081919803264158457613670479,
+        80840314095502417920556874085652203569,
+        100732545150914242181463198084265604734,
+        204897723453208004829556242097416903893,
+        37888605173621089233061791493490041486,
+        207444612761367423786050574556791391274,
+        34312653409883136602357216940318898131,
+        77878957969065959270079588762136877137,
+        14389425848629132179193646150024546665,
+        18423187892667707606584791378842098425,
+        317931941119513711044377098773740644897,
+        177312236959666276157600277951296317343,
+        321350464146211656322206254809090860628,
+        52304218597083810141948095416424097722,
+        71246987765244822894828804238534323843,
+        120637840029767181143827057156351906130,
+        231823503982113051631105343759044828808,
+        303263905366628563335089200073239064756,
+        17945696379976755035998995655152296299,
+        290077630016771520447748743834095394212,
+        85872404830762228495310780811532887102,
+        255122475313939699634701665419194797900,
+        200551859545957695828724886963330963319,
+        203663683299820268890212053164893111003,
+        841437649203667484724477728855108181,
+        31667136474621328156307580943661300920,
+        331630113911975232610668107955671393796,
+        317380465337648681925704547721161558827,
+        327151895785785307143542327760603467360,
+        192928907468551567678591757493353870618,
+        209288650237483764859255838621546611632,
+        91737341155009402738617605887315480260,
+        182635041536645293271777190137262929682,
+        137077677387921594046569093294585107217,
+        99735210862639212302439964521202498989,
+        172644565224676189852252303067165092967,
+        130193678864207983223200028500459406888,
+        325298749419838706855716081861970911644,
+        325206642563781549535871167381045175289,
+        185706144405194864213348236773573249706,
+        269664460435900428209922391536403675232,
+        313341075054748315259774770674848231514,
+        150723287975900063261998906911822922947,
+        276477310668036685125112615917051540931,
+        241757212122944113275440274380254255546,
+        76501177591274314926001804995790036540,
+        261339290245579350154774359937837272288,
+        290347235184279723412052028813156268030,
+        65330946648310611598760880361836420187,
+        300403869365905349183983932084274704582,
+        117268035846357120764125171693097735701,
+        86856821832904881491496479272607426734,
+        74378168057866757020762560596535860989,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+        10,
+    ];
+    test(out, 480, xs, ys, scratch, out_after);
+
+    let out = vec![10; 905];
+    let xs = vec![
+        12477471680342457016,
+        7439834363175998259,
+        8364531072478531307,
+        7720669117264982572,
+        13871089126608066865,
+        5538739089658515009,
+        13485862549968349516,
+        16668965138415286574,
+        12815918125584966674,
+        14984155091932260860,
+        16644766636646585123,
+        306590725006293979,
+        8429368518159297616,
+        9218529725576681069,
+        15016741949480768499,
+        1670879514738013725,
+        8417955162372577946,
+        15387720484925756369,
+        5110476884223883348,
+        1816362467650579730,
+        6344760880304946457,
+        4582050077501139760,
+        11544561500401244826,
+        17507375286136941082,
+        6780825611959218867,
+        2173592718397418409,
+        14895320899302250577,
+        1732130437372403944,
+        2652228013798656770,
+        14059887082577558829,
+        17320142963333434007,
+        14639018477416372959,
+        4436670787324500429,
+        5494397060264160564,
+        14648982379079464056,
+        4281784633461453767,
+        6798187094949642558,
+        6608877920405717218,
+        12631712797504769978,
+        8551308273612802041,
+        17246776198396440310,
+        6444397599333607623,
+        15925650298696285563,
+        10601768410796015631,
+        15000906276520941112,
+        3338797399168993953,
+        1246352375954040582,
+        11420394385271506024,
+        17068095307090480528,
+        7644631914604520606,
+        11033781661348318008,
+        11117169690184835735,
+        258761790873428567,
+        17489901188933003933,
+        14840505235785535230,
+        13227413113638715481,
+        16997698053799118525,
+        9019797589817151925,
+        4493694255366969932,
+        9725237995968980495,
+        15577413875064628551,
+        2280312394933338862,
+        225569847977698671,
+        2323301829890963353,
+        10256874123357921822,
+        12539122566148296448,
+        9989537237723725906,
+        6811992642529374338,
+        88727847633051669,
+        15695593575052197094,
+        2761202085861005143,
+        16533039048484256106,
+        9351088285086271793,
+        4055478109313752276,
+        10213434408975318081,
+        8762684020269433141,
+        5837079994402234584,
+        16514518402377369291,
+        12378208937452956180,
+        14421807887515276362,
+        3988627559762405319,
+        3918544582422029230,
+        4755956678288947720,
+        9676877814462397554,
+        224961575368542488,
+        14611489560386313010,
+        5196486534973110781,
+        14213777193365038984,
+        4602978866967803674,
+        11003619085742827120,
+        16763677630294953394,
+        1684559030448096764,
+        17767374982649848838,
+        4877949630873126245,
+        3682814944580280752,
+        2561235719818456295,
+        2364934896956055350,
+        18263613281445563076,
+        4089114060737928308,
+        1871768083735193579,
+        16678770828678552573,
+        4414846026098045432,
+        17125831209897882395,
+        3912127090800830255,
+        14011181803889192282,
+        1295043426575781791,
+        10420967703804408197,
+        6199906131836288010,
+        11807153033347357192,
+        14265899593105313257,
+        8147605267573587369,
+        3295330776500509264,
+        16732577613226211515,
+        3630606949899242603,
+        4077531443619740772,
+        11812426165247810337,
+        780989759563676061,
+        16843715221613475518,
+        520503200629457264,
+        15698734581373560858,
+        15717047446773117386,
+        11739182472950746776,
+        5508507755766772851,
+        11277744890514126849,
+        7102937323315103744,
+        15043426316219001362,
+        17264933403548961444,
+        4786858792980655802,
+        2014728966166520846,
+        13049152613696846196,
+        8986797982833879405,
+        5686978963676857351,
+        772150166774986658,
+        14517086870053753709,
+        14954116996489490375,
+        7746391697910526188,
+        4606434146793009376,
+        3009553593220536962,
+        11834331104428006940,
+        9959812709547803325,
+        9312818550721389795,
+        8167722699792448458,
+        732874657192970052,
+        698773781905867242,
+        12119405880051172902,
+        17673033350796167980,
+        13538870733321508367,
+        2664725175527539380,
+        7636536994105874784,
+        8629987496621113680,
+        2000550558774774739,
+        13628751714628702436,
+        3345724186836801042,
+        5296651644730514746,
+        15440075910536479523,
+        11566524344598274755,
+        12142530999548267726,
+        3178476168461706193,
+        11231993373182544616,
+        5535836762655782188,
+        1821857691788680579,
+        15398067123112832375,
+        2959937906512595934,
+        9894128090659796945,
+        10977676213479413606,
+        9896053141077387340,
+        4732359585478105829,
+        2782537058538126590,
+        16748650113511090469,
+        16234573623069140583,
+        13526364553240274764,
+        3472042735026885459,
+        1375504011635585658,
+        14134901088610758184,
+        5812986093773998116,
+        18343907802066284210,
+        10338195362284836496,
+        4538933452393836273,
+        7202972851508573831,
+        10663048845744919725,
+        11111095559628771539,
+        15813947556665176307,
+        3844063447234634925,
+        5652338735091822320,
+        15812061039848958137,
+        8229753298839583649,
+        802923134917039889,
+        4829163585440544772,
+        15429176731296846429,
+        8019558793413477543,
+        1452525545607666514,
+        15573790577533593753,
+        491936016981197942,
+        10062863560179613545,
+        11061384784011621889,
+        1900052214617990619,
+        7753731008479284745,
+        16019010346473083888,
+        10253841258470892164,
+        13903408453788781151,
+        10169884958783022914,
+        1771119784479465694,
+        16876761255984683913,
+        4507734336556855851,
+        1644190668914968903,
+        11524855357000701681,
+        6768296219427733234,
+        10057582861726337550,
+        9899648217778374596,
+        18140808584162437045,
+        3502136377186507090,
+        4607134693582048621,
+        13936949880580583914,
+        2596788772608678076,
+        1796713826239012327,
+        4260582063129126187,
+        5748801488885906379,
+        16694615442676144004,
+        3887305188180051618,
+        2550350767205712363,
+        18068173339311621649,
+        12758173303022710234,
+        9740862123276893551,
+        5102010307820377604,
+        10942936333209708005,
+        18421941488948996650,
+        11388637231777062032,
+        9127668535392226249,
+        6469837987038520357,
+        14193922743168722337,
+        8879463076013852218,
+        1299536255974471810,
+        11657123384643255381,
+        7261659951101262301,
+        5894363505946510117,
+        4245862285394351605,
+        5678371230350087802,
+        17178046578086915046,
+        5767351492432132301,
+        2765778331274782081,
+        5032104048099942935,
+        17629257268172754881,
+        3538476034090404567,
+        9059696921132002704,
+        10148267678588608063,
+        7431557905485244392,
+        17701219837659593435,
+        11179519028472592747,
+        4072852058212419917,
+        6998722206646617171,
+        4881588377784087158,
+        8942392135657118187,
+        12450098984983403167,
+        4877380715364130438,
+        7442425351254657978,
+        16893368119062256892,
+        4133190116578139434,
+        14033231224823505685,
+        17690698791038106309,
+        69885719555671168,
+        5451291976155541960,
+        7641909931651695485,
+        14034042004808926588,
+        12720147227862124510,
+        12957030947591398482,
+        12124026692922785247,
+        4883408107294185147,
+        3308537627824539756,
+        4406371143811003270,
+        17321810257306281660,
+        5243104860854009275,
+        16431126877381187748,
+        9754896554997520777,
+        6749101884247660127,
+        6263295512058358448,
+        8583119091158463486,
+        5555416137611535608,
+        9162144452187531823,
+        15840624178481981270,
+        6187472780701357377,
+        3164204801472770089,
+        9460961259991884760,
+        12198117330023239045,
+        9445542084090344416,
+        6826521970103491663,
+        6996442717256261847,
+        7363775912789871847,
+        1821616883363314737,
+        13718695280220668528,
+        9265991471759860428,
+        15403328465978445840,
+        10435756695146507564,
+        9134138337791325500,
+        17595820721307989341,
+        10837897611871188641,
+        3628300610284347069,
+        6056782082517998847,
+        11889121953261384595,
+        15658848261681587671,
+        760281128415892566,
+        9901843341271410215,
+        17997287540098693599,
+        11471174400256577350,
+        13773435664609284401,
+        224645455536727129,
+        3248370257584471677,
+        3402146064818516495,
+        15419892046652096835,
+        6817408051904455983,
+        13462567452174648864,
+        3865219578658340818,
+        6401692728490725482,
+        12241061753482985847,
+        18093898726091231688,
+        2085695270753393724,
+        1486152210073874278,
+        10962392580868374592,
+        18233050379629714175,
+        15440497426454102854,
+        17029248778709090701,
+        11943840019902256468,
+        1838494147415724342,
+        10624384750477063994,
+        2802400104447544796,
+        14482700272009891532,
+        7488752288284248623,
+        16689053330367821624,
+        8122258539396763672,
+        9378594940099937779,
+        1953829770254669263,
+        9095248272317331200,
+        8866691394084862202,
+        13201017990930506125,
+        5874101266603325988,
+        12758355031414108476,
+        10906401543018450367,
+        7070115242582431545,
+        8015463853343573521,
+        6337419761897564053,
+        8245569799737529081,
+        2143044650526073628,
+        10001469296387640338,
+        11129612211343148678,
+        9846592166231801208,
+        3293328206977069772,
+        8271586651454757561,
+        2516303987084978983,
+        18226852422794010543,
+        15105013013529789613,
+        18154225228636318154,
+        2013990863661732083,
+        1784747160358384741,
+        12567581004427688912,
+        12324983884662973603,
+        4935897487517618842,
+        14994876132441559883,
+        3460392282827044973,
+        178151701108469307,
+        14330685158880278679,
+        17005759398710652579,
+        12918079073431941444,
+        14737890013936547836,
+        13116140709796209375,
+        4406659418908136238,
+        12427086661031458855,
+        7492548499951595477,
+        16111633531233494957,
+        6547469955282364389,
+        10562100669850668222,
+        15418878616411295006,
+        4956757255402949102,
+        8013302230254643702,
+        528640010877453099,
+        14207088120532813066,
+        16751882716911936366,
+        7949419147113034512,
+        1638837693666608472,
+        13878268226712426549,
+        8273407385523164786,
+        11509174550235804944,
+        15966833959669295468,
+        16501367189938100697,
+        18237618137386130737,
+        12449896376279048977,
+        18110666911456042806,
+        3193062725115205782,
+        6188346541223558421,
+        8058450572610971381,
+        6102995183728564982,
+        1217219394955358461,
+        4696899915064498873,
+        3897448246350199671,
+        12662172956137287871,
+        6610124623177066401,
+        17355303367288349560,
+        2046855789488294262,
+        3211014904560014361,
+        17115241076245460723,
+        15298358927000333664,
+        9182539314691893135,
+        5164209594070862585,
+        9062044958688820752,
+        11123512834458221854,
+        2659061244791686400,
+        6806035717656270289,
+        1027290028815967628,
+        9405947735639936936,
+        2540349601344589130,
+        6895259978837037548,
+        8904474026325226434,
+        12273615493885622366,
+        8677764880724506780,
+        6002959367751808149,
+        13123965742105252412,
+        11735284964695785299,
+        2417001610052848193,
+        16769279424767143997,
+        7098399088498274096,
+        9910748236374366737,
+        11111297830299095939,
+        13640041663283902885,
+        15915174780574330612,
+        10929481089808875146,
+        4581632826936763294,
+        8581099749824253588,
+        12645736569302882821,
+        7359153121066395683,
+        114042912187761457,
+        8021867264848320576,
+        15492861144941287563,
+        17574065125533105845,
+        16374369432221228355,
+        10459169993431587286,
+        16366034776295832243,
+        2627084718398353897,
+        18099240392705942111,
+        4326916362018195403,
+        6552915731272193737,
+        4273219172539573763,
+        12682979794809012349,
+        2630341686377453497,
+        2883112777041844122,
+        17280553753834600520,
+        16523493292363775458,
+        4645653082909935710,
+        10857150457399097857,
+        16240900012104288727,
+        13936061320958754124,
+        7489056125188749067,
+        3240618672663587347,
+        4473897013556224538,
+        15959787311013455542,
+        6807910945586226902,
+        9410336371061067189,
+        6906088553149840730,
+        5605158148442495482,
+        884531864210940213,
+        1872297304786275835,
+        313522309067627421,
+        17334018801756959340,
+        1675436862731542012,
+        8842978529412038193,
+        16491099153488944340,
+        3255602365380029669,
+        4091823593236384660,
+        9059086866751700824,
+        18074592049968473162,
+        8939611511088706324,
+        2901954556715326677,
+        3168063820638083277,
+        9642457237234984168,
+        12973730047202029231,
+        12383420012085361069,
+        11980461635855055601,
+        815939574328079776,
+        14011981531029282088,
+        3819777392902083260,
+        3664266433302366398,
+        3842827072078938508,
+        11680613555445372946,
+        12778807841055009071,
+        12110239716506060424,
+        10455001326219377212,
+        14037063373827903372,
+        7343791660755545799,
+        12328389593346708858,
+        7199828106464912509,
+        4858835493732869191,
+        4210694898816644452,
+        5207062729711049186,
+        6686010191777285325,
+        4765116176650914771,
+        4260203503346398077,
+        1759950825465072730,
+        5230132100642234984,
+        14615940487884510813,
+        17309789486063144027,
+        7278701880222450078,
+        10589604955548805402,
+        13389545410598327517,
+        8048423454310044609,
+        10174798177374038317,
+        11695819581512336602,
+        10798166591000354836,
+        10103293254150868119,
+        157182158726905133,
+        1338674327495139085,
+        4359740350600884386,
+        3252895658818469796,
+        10919494111178843097,
+        4907614062557818120,
+        4955680854509122805,
+        11945192504152771627,
+        7975797138321745283,
+        6605506887556783622,
+        6135553468556837256,
+        12830560358150860589,
+        3963138074064936645,
+        17071621175761233015,
+        16942996036353652149,
+        18009074714271793604,
+        1722999483884168113,
+        12926214689279125494,
+        13895491362136161766,
+        2282857362875456337,
+        9142994644696487964,
+        9268762722221367534,
+        10573580827711251601,
+        4334519573500535534,
+        10883875644989605426,
+        7004415645651661648,
+        10027237496943886389,
+        13381566800231151857,
+        17337229971572030119,
+        9544236017718236057,
+        16499558730912147790,
+        16696764467460841050,
+        12783153673325726862,
+        4801788834514363554,
+        9769596680827455135,
+        2052933989795058774,
+        14012190388893162910,
+        2328488528431981564,
+        6422943144920158158,
+        3344277493662982427,
+        17332086124044133390,
+        6603250179050887938,
+        2729973167059384364,
+        10215535361886902702,
+        12469382708903994601,
+        1202997051177792035,
+        3047394247674395800,
+        2411456312940888474,
+        4837088057329031859,
+        13226164937828712217,
+        13151155632897757600,
+        11689971584362603020,
+        14721057194516503031,
+        3506623688955464241,
+        1023806484371714024,
+        616780495049061260,
+        5550447694830386961,
+        11303599457452379703,
+        4026477090451957914,
+        12426562795004232982,
+        15911784896145106699,
+        4007234390509453717,
+        10984488057956831607,
+        10127069533632441573,
+        17026212809323057241,
+        9318803717581063712,
+        14225402896737171843,
+        15831782058852344212,
+        14685942491342656916,
+        11353950141420870530,
+        6899840258039698024,
+        16365793951924111762,
+        11054065770384370104,
+        12313481690905923958,
+        11429182630674761557,
+        18037807349797853208,
+        17855698087768272616,
+        9322152574069274970,
+        4942329044664759235,
+        7995065847246945033,
+        17234608471288129109,
+        8540482286126463,
+        14042109278380290397,
+        4245459658137149297,
+        8630372384706772616,
+        16951967420255591896,
+        6140983990759814658,
+        15799001042328537181,
+        293899216979442252,
+        18375466344090472198,
+        5023361203279264131,
+        2158584219052773880,
+        2430714777079983012,
+        18439679719922027552,
+        8247095638003041692,
+        8700129222990386425,
+        9065370516758531054,
+        7621645239059490705,
+        8198370193753546038,
+        2570516211974541846,
+        17033362784799911757,
+        16322248788609588904,
+        9688378877889012051,
+        17078622631971263745,
+        14000745586739559896,
+        10022578092954937154,
+        5445108204439341684,
+        18386610455808271244,
+        5092210157433941468,
+        6213448264919356969,
+        4883013329288095834,
+        5478996930332110750,
+        8026855029996672366,
+        16024447858887376249,
+        12067879178821404087,
+        11518543008940438069,
+        3823610091713721972,
+        18069601801912264214,
+        11258822797166212826,
+        13964841203412084177,
+        9414776283754541624,
+        10293058710824631389,
+        11948877430367495415,
+        8513392542107963113,
+        6296507345123212825,
+        6477999943576666411,
+        9572404293136150991,
+        5100835030655003210,
+        13325655857556084165,
+        16324080076960251181,
+        8879608339499901790,
+        18301788022265813222,
+        4530918631858474410,
+        7355857660645482071,
+        3649239901919106124,
+        12889973024998083984,
+        11695005857389286582,
+        3475833048468156819,
+        995729836358583730,
+        12325666763829458288,
+        14110440261397920019,
+        13769832493884639171,
+        15593753283105934190,
+        7109324739675458351,
+        5421147768472920505,
+        4412734743279888925,
+        11138246548885387220,
+        14351840348441253310,
+        3556324819053975644,
+        464172005362873872,
+        6330105154140333710,
+        199716684476189301,
+        30491931320003660,
+        12784214407370490288,
+        15867394285597021458,
+        15049472685881722845,
+        10832317644389672128,
+        14180787391549176759,
+        6195021812685555238,
+        7097651423104249027,
+        14212466040470804288,
+        933065029493216032,
+        340700391640325051,
+        5222909044979876332,
+        12662368682228247352,
+        2784558090880586519,
+        11524226622572698014,
+        7185977984788112517,
+        10872431215518209444,
+        13748253290972801025,
+        5038089701011291997,
+        7535346948699029838,
+        13797006813111000874,
+        4892711692291451444,
+        6793466025450692149,
+        12784267157149526257,
+        10296537403905285004,
+        2323577800704132951,
+        5312137140467217143,
+        6430484854861543636,
+        6338831277270911406,
+        3380263422470065531,
+        2021125064519606784,
+        6156495890069554972,
+        11783498133679281022,
+        15578447545577779722,
+        15814546899991104192,
+        13941553944244468050,
+        13634474865486767598,
+        1056230272770991969,
+        6544470833237086052,
+        13788795813455716701,
+        7695789694537545607,
+        8392131987884965107,
+        4446829805611351645,
+        5546547554488839716,
+        12941176365951974041,
+        11083456017770653320,
+        14243587238307940226,
+        4340314402890900681,
+        11706773788443852730,
+        4344412554812442687,
+        11193626359760223528,
+        4305913696407512521,
+        152872300974481317,
+        17563972177873767735,
+        6689134738611919649,
+        3781413393308080648,
+        8321615330761631580,
+        14197467496901526225,
+        14546626676044239226,
+        16456266795492152384,
+        11928059952577597101,
+        3835042046532738036,
+        13754911224713375253,
+        7514650130698543676,
+        8858336584435716580,
+        14887091898219032624,
+        16911851326374546737,
+        15781280213878900978,
+        13174567334832245983,
+        12698715736553212443,
+        482862054634250522,
+        11881655003769883900,
+        13759377508601776198,
+        1380626582194380651,
+        12709962724490385600,
+        11830407677567487487,
+        4707511235470141279,
+        8988833435496096196,
+        12849153210950239984,
+        6129552893611029481,
+        10992680122519594516,
+        11411390234302369473,
+        12356274253287847627,
+        8358264938578134057,
+        2779498090106587161,
+        18154716656404340371,
+        6939980780951404838,
+        11644899556767279317,
+        6503403399166403612,
+        1788328284304710501,
+        3402885645044344883,
+        6160850982285943593,
+        1361188868372704452,
+        5295494278668069110,
+        3856033404817891402,
+        5165875819581549636,
+        5432481755911013545,
+        6887089866491061103,
+        12248786783098658185,
+        1872380111306967890,
+        10371211044437237288,
+        3640308804221372831,
+        11707751977394310973,
+        2441379059837646171,
+        17261873795536786450,
+        4576230298744928057,
+        13439891433013201618,
+        13066055068615706211,
+        5023766490253071135,
+        4737378710842155527,
+        14130528255898995994,
+        9297196539004306162,
+        10213190959948266193,
+        10762620391958680674,
+        15631352907504559989,
+    ];
+    let ys = vec![
+        15245088662193948010,
+        854969528224537163,
+        192457876290468361,
+        3156774054099849881,
+        10102117358735393641,
+        13923135497401538045,
+        15603007686998930972,
+        3707765480829539463,
+        1075990372015045994,
+        4440028045035707188,
+        779932550205535682,
+        13284596850012603887,
+        13447370325749987403,
+        10657005451799608034,
+        17344058779081327933,
+        1801131630646010099,
+        17879455113972297046,
+        1049662270419803525,
+        17887003202529550415,
+        13730724178286439296,
+        3086493866184691051,
+        7455503161286080904,
+        14945249663072669446,
+        7413071270018261565,
+        8165098975144402988,
+        15667870805615006559,
+        4534237642686726425,
+        5675059133984408369,
+        13542693529471369730,
+        4650690134857994243,
+        10593876026982724440,
+        8719234160809710444,
+        7340192483727047710,
+        2225660849988538666,
+        3260628781823840386,
+        14784063213821786553,
+        13478324037708856111,
+        6239844587086244103,
+        14508626048519473050,
+        11443816492520902359,
+        7084448144752764341,
+        11673478635762496725,
+        13444020463604694513,
+        1798574113181758005,
+        15195278749704748030,
+        3490272214933312037,
+        15632500462832370824,
+        9808665338648603851,
+        6377980234800091876,
+        11306384233660763805,
+        6392788317448223882,
+        8005181869701567455,
+        4601526777105113530,
+        9348184476999479133,
+        16105441815997897842,
+        15373735633778437011,
+        11733794529384137433,
+        769246272107807645,
+        2922899274256775805,
+        16218486247871807873,
+        10650657974127272786,
+        579665301817927565,
+        6403006378940431337,
+        10150254532952843560,
+        3736822004545760197,
+        10244207440138560761,
+        16631379436671010056,
+        17418302422321190629,
+        4844439457855539440,
+        9662799133272397874,
+        11622100630061039998,
+        11017257064923257696,
+        14025546287952884200,
+        1170766120552674008,
+        4852413824670160293,
+        18019298735978800767,
+        14042374992041286164,
+        6103187929964524269,
+        5988592592688695870,
+        5579172720281387479,
+        10738878044274955012,
+        8401646271610146442,
+        12016061916593958227,
+        14752402557741497038,
+        5053283107906893264,
+        12910662726197463795,
+        787526459034857809,
+        10304827788120361107,
+        8387521101013404665,
+        6030209567663971422,
+        7511028869236306454,
+        11105170944119024313,
+        2911699195421772292,
+        11710398806568443147,
+        7599646386487625804,
+        2146501359265516686,
+        1193294087739295886,
+        16419769173966961854,
+        14779980297792837632,
+        6286361066120350249,
+        8246126699673376536,
+        2339493649448723726,
+        12383521129608538925,
+        17459816050942292574,
+        7213741082075285427,
+        14702683527305456088,
+        17849030573001874153,
+        3273901152373442943,
+        10086273715179643444,
+        14351251935054659627,
+        3067622597087477151,
+        4241957707372911307,
+        16686513037697490920,
+        1503886102490162470,
+        4222986769290077389,
+        17209928444872897872,
+        10064374817012298812,
+        1391022681726221923,
+        3482099619102309134,
+        151151415131464647,
+        5477310851692317777,
+        8185741896741403527,
+        12297179519749775078,
+        6980896315258250234,
+        5491311995173541969,
+        10908311176531272611,
+        15140263006374103771,
+        16292302828281485620,
+        13488663273854028028,
+        17078235461511918753,
+        523009743565281503,
+        11105648925812514991,
+        13827146014280242829,
+    ];
+    let scratch = vec![0; 1444];
+    let out_after = vec![
+        190220162046834860534077728248763738160,
+        124088792574632755884126988214918600198,
+        136280237194237896114705267643634619063,
+        165674117991784220385577664659730342356,
+        28929132714127863763844761981137870692,
+        32790007087118855741406785103201848328,
+        279859647795134296472175502166585045363,
+        327024290830730216005263695604417392330,
+        308574842321172894190917265378646870482,
+        68574092366476129628899008144697744634,
+        151258222484063270231666905379137063047,
+        92696749554605472508392199516317065694,
+        73288334038160314944404790547551554918,
+        237105063924183074035284395446348905841,
+        77483693221259336784644068377798995069,
+        172888082644410077241018924613872172026,
+        27584566782968543255507978315656849003,
+        328228913141001588000283063095288463486,
+        546103067634160044581321098904436986,
+        297832916827565878363817415858052700510,
+        185938833594294591245694513698480311481,
+        147457952089096683443319547879183472529,
+        120961983122479574450890346220617874993,
+        160513798789435888053315789703855280031,
+        189071531206094997487179916307643289402,
+        41627870761073225073156083464807213607,
+        99540841858354684923178552001166695054,
+        61118093563516014330171781372668877805,
+        14414227552857326623683051584280109669,
+        171805878116320432687833891888569964387,
+        190100827539703560280698117602589716738,
+        222480181710652144315712850819148461229,
+        276012678821217904129892457344437700552,
+        325167556342833744771791437884034849264,
+        141351109567527109114093691346578351943,
+        159802833232092938551810338565790380182,
+        23292126934217938688208252297654220347,
+        189533895694553602336484171135701544709,
+        89686010142778304476607354260466553938,
+        124940838162387302435131399357698824324,
+        252208575072157812755418596386354450907,
+        182259203591907434968369573304968908938,
+        287615856165145186314827348018620460540,
+        332535580963506886230570527510567685959,
+        318532543969483901888400597344577268188,
+        112264232883997425991862012539655855904,
+        35101252321303819421399949145113941197,
+        316043789820190855210901813408410166619,
+        308814803394942934298135578660689654167,
+        26050799474296596532638150150945247135,
+        45956967639532290128910073794643638799,
+        210796596839032779610904531469975334233,
+        28499604064305012949144364240188748141,
+        72840977154345663648433339566904961857,
+        12482243698260120171839849802280765382,
+        56060900056560450025789259138027086880,
+        197747376719483121110614390756445497295,
+        31906687576019090722247415837192965340,
+        95720619919088647231940902478432838961,
+        270665445631667378825372312888020046633,
+        76926773499468739413984909214982344372,
+        30536848535474388899191981262186132201,
+        32351453084679860186246543827787710331,
+        46827434449396148330357929656241173063,
+        15801177323084874127969915278917089746,
+        203319532465425735009755287416250975924,
+        18070862123680636185683050396045574544,
+        41092164150088297113905540218789006124,
+        134681827638028151097761237033474645989,
+        295404237517409227098711054116363930787,
+        311444553311084895529479249606336167679,
+        304353739841388539210062874331517559694,
+        281007128093408730597953003015911302666,
+        105207487137662079893074677333742136509,
+        39057883659207953594792135705048622864,
+        172387769430091297802172935923535207769,
+        144366557647355186156312201867816475930,
+        245946917204790799075814281734872007941,
+        310778733917582831847859697430403312059,
+        95011723459169420809836753066955061594,
+        261315773590734702944209345393516761913,
+        151670338506685222120664046576200298791,
+        329341621979817480321722621884277191255,
+        39981472838814252103297418196719740163,
+        58245834703458332373128555413209286920,
+        289255261099592016992272739997652947712,
+        85824275316157706193166708218369619418,
+        337068241535586145938848529897945299555,
+        274028513564821196523528477690837810080,
+        323753338850384292380081464338612345873,
+        301955013112146170028126314007106065587,
+        91686780760706764155529408871138629671,
+        54973084206819701043182351609232597803,
+        140790470779365835727110826926026566100,
+        268406566424473867638534936264007459548,
+        132604177310806318264120448226105592508,
+        32592275106109726441929304375193050512,
+        145574815008073012031148875200398775953,
+        74762115787311160931828585215828087732,
+        162286165100108940477419420877105341306,
+        235710044712881049491179222561186540,
+        71851063976454055148665764949883259923,
+        129635132117020994820665588312155002328,
+        40599375814400003826435705564635122355,
+        100252092528174239811017479940950180011,
+        27092675946582426279879825449531687630,
+        159634879328514153599376257341377756557,
+        85917095791069120928491074910282699531,
+        177658549339780231027360443173378881044,
+        11803859826112125890234460555364328397,
+        111521904168151624811423356070976245917,
+        333466670048953907798686274874707833209,
+        239897553003561561189785098367772427350,
+        322007777433242059826587254274898676410,
+        38259582638882139951220688719320487642,
+        268742555262955060349978006959195472071,
+        51617564308747277311976026264791112288,
+        19987180798123810703496912032398880222,
+        289379884569488930086459327680279223919,
+        323768285479062508108051297855018658520,
+        69546194254900980974739915615626490399,
+        220101812194406910729849334356765241368,
+        65392623030292226064602737839543405306,
+        338071949249362895950575843823731954446,
+        238010438227445155100561787087393183963,
+        271400889352184494000886809752197684721,
+        210522669343778428568604206184802055303,
+        25560059418689527542176025565064036930,
+        330794384166964653975032400981058058862,
+        271794939564805000756454255364586786132,
+        324444193060450192673926393933539642511,
+        25893277432753676810156559495331675173,
+        183363227194819954766875015590933615319,
+        299240247230271624380370168558437910234,
+        188049171191384969026029295147904032519,
+        252201344085114828136175292511208216332,
+        286131157483416506390310727201908344330,
+        152018250890053681994385302107839159928,
+        293917756507155200607555989516571947488,
+        274014415547816436115953608822207529001,
+        220130249008067166849273425677373397477,
+        186702855558987261556430231532633895418,
+        322593638849857266272561430200031009488,
+        217784177924250029509738070497252948639,
+        123013832211689122935699124039630210403,
+        333106419565093689846119880017647423035,
+        115546199618462449777345450984062404640,
+        99390044019229482414528379451295068319,
+        331953216577913222047812423920834605740,
+        154099223316727357078695085154696617390,
+        270372498730697040643225466258706068540,
+        306861465979505769866721341163832598112,
+        263808469578912014951319748691739919784,
+        291701819363427867162196773180255901204,
+        112261208900533428640603220909134550447,
+        234367866695817670582634340292447379095,
+        223305326117919529450521609850807518784,
+        338789107741632823850690949024235945440,
+        263618714002337018598364101128327072219,
+        175145583701418681829603239763613053430,
+        163743426223734926442724850940788254137,
+        292478840584097190522466131048471191130,
+        171203615089222000000007661743705137781,
+        311730442925509611651005029389540517855,
+        107524937890480379551079627502320381920,
+        18560032190142719435471352606170190553,
+        20886129543797549714688494115206292481,
+        253424131431736370179049403675692400147,
+        337971318120414517349813515650725477393,
+        304971507933664399453277836217524743345,
+        87585868977844239460460966709376090993,
+        320325246283004733888803133957645617161,
+        198524802627406429929817568621699550153,
+        10750020470854111806145901775312756226,
+        147241188814726381024825045736122936883,
+        74759528406825372642948996209283860535,
+        137178093747773454678468727077843841520,
+        155193192757360518618555529813881267934,
+        4345465149284368873113547445908033080,
+        89714374182130472973752490065443358544,
+        210809813172351837112593845971023810269,
+        169939947683457953887375383687305430734,
+        244775193931373755070772760597036566032,
+        121702388767639132141265392667453124173,
+        243603203090970055149104924547444562554,
+        29182339834628865319960900274345801537,
+        324299612753032799401801158657978243618,
+        285499229381086607388504485297260809470,
+        162542148934060874909180057580328382148,
+        63597063292539776617811339344502333114,
+        223197306978814721384044877164732011329,
+        315488343828779217292342713935342566589,
+        20279206632038282074907265678282229299,
+        129712436378665798323325101513558497279,
+        134861980336954647057111535194392165887,
+        111925019805185473644927803894836794156,
+        198983706933891720238165298094792536883,
+        58993765226305714112320735036477131683,
+        257002081444316917979210671483520033837,
+        173416475182301038383102819845371711064,
+        104397961147148667860099171059798102273,
+        176912375269544462354824094915653402059,
+        319523447907172194045202776964538335905,
+        228285381680402821462673414469270316200,
+        123852987825671864180023323578086902949,
+        226987452849631054913261179022971673011,
+        224781397248938139624873217196934822848,
+        298704194864583789580350678694409503612,
+        183240735964570065543495005563244607482,
+        16546951663210506604494190693175952640,
+        36769584631487479911972137519862302745,
+        15184804790136617583559988132914909615,
+        121812903135338985700203021332856606945,
+        251304314772400693169136936862034903331,
+        50266063865031673798812154856884159719,
+        180489235788151817933928460309118418829,
+        59848747812102469050709969690243647922,
+        266908657616177895549880040051327227655,
+        112636747072282639726572861439905752360,
+        189387496966689141402286850068916901693,
+        92967514729626306210192164806248496614,
+        235629787368137568781512200436744679816,
+        188840451595128389769834599706138888215,
+        231603074901791802052617691949885749286,
+        232216477481449832231938296977275279283,
+        239978209121946238835503266705699242070,
+        99070748259123947159872037429970934451,
+        150696888889211027850446702440302794926,
+        18103395665701696497310795584859049951,
+        300330241261424711025071279330212850825,
+        159408501031421571960377197359347481855,
+        135964921457631340333268553376299139957,
+        228715882839760370207022786853544590347,
+        181041404080187143129578016612086307426,
+        196502797274402906985207795729919526473,
+        136290940275971551443860137091749896989,
+        247770289387813889785633188472911821769,
+        300936166823138364626955633515194602381,
+        171090754234670691461313818548471675776,
+        183078710311810808878413589777587749966,
+        68357901657800103898786652530173863517,
+        135194005310265008283281057409048029492,
+        161357188408002941233974230586635064411,
+        149034174069004002461053399848265172561,
+        199964744508160507707897800926366074983,
+        286045007191856083599775384121957870794,
+        198580004192294124806237468492078450012,
+        4806331709144689039185131036229486637,
+        315866932482402529355014449699176088603,
+        331993814759059300130856669021727390163,
+        295048362178588605330238481416772948983,
+        303210482721051510598800091852069842178,
+        278582055963667652183391905124037508940,
+        254942927257912635906667591215705048635,
+        128895424121944832338075664474691588393,
+        4267255114397805589258956753070216749,
+        263882559705189824676049538370128722433,
+        175823366064867594765613991209746012457,
+        188165697542735634786427111763165009294,
+        159622870672310300011794016188048228246,
+        165725515777853698126169051458559736651,
+        66568534285942969798749125685690556319,
+        201988399795901731881653371421832254335,
+        127795005190283619596815699463834784937,
+        86671632779371017831432293679386668941,
+        193003193738575987464832235682498790624,
+        3824093976027959259902855667587826839,
+        1396366405150643870755428448877496969,
+        315389710939123287994231263179701654316,
+        20686623648214940910531744221365006824,
+        77535045104831094011656703586745793177,
+        75572445911481756903490280166965758601,
+        170319479626253271047231845715524912340,
+        68972428996263973586356809838482290138,
+        47571669367798357569756000630936425290,
+        104818449588262649324496118021616437115,
+        75050848724749201190546493071440961128,
+        26960551904319773015075172935439283585,
+        320542548958577329972043189680292792592,
+        256909503720702983396008515030591593540,
+        241847415648074576929260558651276690840,
+        63634315823060976890316190408463357377,
+        186207046889045557864061083837611149147,
+        87748102326429964414744876308598691114,
+        43471131953711013181992420311464632172,
+        142883627963251799700621805099043833939,
+        30844233777592671422824896309027475350,
+        185405860521351892139113909438865522765,
+        260645543668524970508059588535128017184,
+        104767356060441590237843319230154842644,
+        133451353053428177009556168064033447690,
+        525952715863846194302709824081235580,
+        83530299281415720128579519759998003389,
+        37547438522188383813991918825860977155,
+        171223250393541996587087891170607125265,
+        192854328876857975279036472827396255529,
+        97768277231084401419287799122282469078,
+        286934354548406092548889454578628837674,
+        231472789534467310414037346057723144246,
+        280436220902638604322121009036046584549,
+        204571825692973042093074299243746204051,
+        18652388079135743699480813571170355949,
+        150241650218172865256004163443516125414,
+        130430214586574332468783209483690935224,
+        214005618757942970125883502762952971246,
+        40321812596629892154090429361793235859,
+        135365015095061627313102248446422811537,
+        285531028064951679326607185985029419908,
+        142721958267477965001986019286787477773,
+        241178021259084664927733145687496984489,
+        90912615167706005474408881034679691698,
+        150703802833620615000596445874975468365,
+        104201714336356341781874194790659130638,
+        206326175639463253937059586081282224407,
+        89837738026116008509811022119852887809,
+        82829706040530814342150524973118757073,
+        65991397041986099398286426871473774804,
+        222882445856892347292445918939258400102,
+        140158454171983086278512488689997233884,
+        295494318334233926762552995360601140609,
+        184031652897781192975558226865727272225,
+        134215344965484349786798886280446175813,
+        158014019219843609043703322903640261093,
+        23313313208865261587749947203331125247,
+        1654010672192767151385463981159649972,
+        176213773715313103220674302213574520309,
+        228711820130345378772260372153188287863,
+        80032319166069234060506447928425593232,
+        160242230606696713130334866283535218081,
+        262948580498001621863427974233352054919,
+        160835768032191030258085280116980187878,
+        165333414419318348041834983534189422601,
+        61708868498728786144651549164386474483,
+        215448705276251067728403625932533989374,
+        174237309166321130127854252725565681637,
+        318994228431523047591245077509511639257,
+        179070189694136206986364079291581802350,
+        60083296644465195341901106455014326571,
+        213960924724515116781013384131379232255,
+        4009292844472316521827850025466873851,
+        35148357591122203173138911912521790064,
+        143273530503409464540846962209427423980,
+        232295814516986878355230205314477137429,
+        71692321492446708528733009110720938381,
+        235853490936893718529467260453612918186,
+        158651455918539980721171508460571783005,
+        35223411020090468583175726324021689208,
+        330232200434119733048672831291606959186,
+        142792432826301327838643018741327746372,
+        36959222980209740741640720717698502900,
+        36185339224544045653393412882299771726,
+        266966933081600080979854351008853206550,
+        252041491392415684780508225160058233932,
+        20999424590975533512470634193918031694,
+        80696365781363377340425777819211385447,
+        220748152165838895009263928837888114295,
+        9334922829968938071147408532015694559,
+        292717766662850347393625110881061127837,
+        42391362160408866039707106265617788136,
+        215123746109316903018261073958712938407,
+        317164339636965510650254065518505997938,
+        99975574008606572901877613868745669531,
+        328313799070222013886172671437423269780,
+        180969325923098183019244991140870020726,
+        141506732318828292885490052889332358327,
+        103753832882829344197052826114570306538,
+        54991368808590599923942715063246953343,
+        28559522802727450727850216882943057345,
+        294920773250584451876261164526394401399,
+        186966664983027238080165857491851325475,
+        39170635045599463060209838000211446068,
+        294334652215414098680502258843063042709,
+        100879830391850347964130072817865893613,
+        122652073607863553975098865448381140249,
+        291819779988002553702977870995052732755,
+        31473209989724361733975064763384713006,
+        84032535032495259058887552102971701727,
+        183488763631290670052145065739450300254,
+        12901109612551605548706399677835092651,
+        265543957493022628291109987215844738361,
+        3332074495341273668675046284532747652,
+        297205555244686947876748908250359440146,
+        106700030106307056825193189295785055987,
+        323169942312723335698744374924139236590,
+        338614194601665173030682969779499566980,
+        220427722746146954585511984159544929136,
+        159049454145589786873228610517358947151,
+        269147012539240650007978389313948210361,
+        323583507734422718124378996146676740374,
+        80897220132497119950810613402344231015,
+        61768739596318733728290112520134976094,
+        303472097565397016572049460108860960414,
+        334781206080952132873023440421024396801,
+        291978783765629057539822645188962213017,
+        159220467269694542371866267190820299354,
+        173786555889665679972755034381009170957,
+        10661235452996633811843812488119685788,
+        222458216551619415050146502196779097284,
+        144608000063492865087856298634190627894,
+        3436375646025433879054063678384914835,
+        116941569027377854308536522859171810282,
+        15404976218099291959012552300381701325,
+        253437300146304399598090062806967626687,
+        114720487001867158355304286983128728973,
+        80948273029939847506986884799518806969,
+        121104699974806593848064190354265473774,
+        266804019533003731700249631644525811628,
+        15995385079006260981454517738918237657,
+        113535640210532227045646302996383491070,
+        219190085146127231346621484435351867696,
+        102770656697900182858632199516898841659,
+        261345926333232342747811922411744336534,
+        250388718912372298753983474669634065682,
+        16832130291975397338669857092924953677,
+        88661660752954079156613662977116029433,
+        252202658752817220499809929546959036489,
+        272105192853758049841229541447191831017,
+        316473187770203911083027238569561242921,
+        6468720079291542767371670020799587407,
+        59261220968839486882554827963404373356,
+        237386608097405546954762973724215099993,
+        194803394963329202441012065181136629829,
+        238060904960816549156258660997326411172,
+        265529779779748772529677023298204135841,
+        155714321034337150824275125708824878212,
+        194954564174158865477448902424386957491,
+        19784900606763501004889392067202488276,
+        242840100637081919803264158457613670479,
+        80840314095502417920556874085652203569,
+        100732545150914242181463198084265604734,
+        204897723453208004829556242097416903893,
+        37888605173621089233061791493490041486,
+        207444612761367423786050574556791391274,
+        34312653409883136602357216940318898131,
+        77878957969065959270079588762136877137,
+        14389425848629132179193646150024546665,
+        18423187892667707606584791378842098425,
+        317931941119513711044377098773740644897,
+        177312236959666276157600277951296317343,
+        321350464146211656322206254809090860628,
+        52304218597083810141948095416424097722,
+        71246987765244822894828804238534323843,
+        120637840029767181143827057156351906130,
+        231823503982113051631105343759044828808,
+        303263905366628563335089200073239064756,
+        17945696379976755035998995655152296299,
+        290077630016771520447748743834095394212,
+        85872404830762228495310780811532887102,
+        255122475313939699634701665419194797900,
+        200551859545957695828724886963330963319,
+        203663683299820268890212053164893111003,
+        841437649203667484724477728855108181,
+        31667136474621328156307580943661300920,
+        331630113911975232610668107955671393796,
+        317380465337648681925704547721161558827,
+        327151895785785307143542327760603467360,
+        192928907468551567678591757493353870618,
+        209288650237483764859255838621546611632,
+        91737341155009402738617605887315480260,
+        182635041536645293271777190137262929682,
+        137077677387921594046569093294585107217,
+        99735210862639212302439964521202498989,
+        172644565224676189852252303067165092967,
+        130193678864207983223200028500459406888,
+        325298749419838706855716081861970911644,
+        325206642563781549535871167381045175289,
+        185706144405194864213348236773573249706,
+        269664460435900428209922391536403675232,
+        313341075054748315259774770674848231514,
+        150723287975900063261998906911822922947,
+        276477310668036685125112615917051540931,
+        241757212122944113275440274380254255546,
+        76501177591274314926001804995790036540,
+        261339290245579350154774359937837272288,
+        290347235184279723412052028813156268030,
+        65330946648310611598760880361836420187,
+        300403869365905349183983932084274704582,
+        117268035846357120764125171693097735701,
+        86856821832904881491496479272607426734,
+        74378168057866757020762560596535860989,
+        199920612340924706931020759725404322761,
+        148302152698603106289110391146603675450,
+        188880272399979427182177616954529623853,
+        213146891593772358609897614107780485752,
+        126462336582102357498313313265746845528,
+        194667027638461013372958205716441535807,
+        119602967954367110684625706048680608344,
+        70634164684310464893081246767202147036,
+        328792841118018419839331087758174365470,
+        68214789227461154812702122685153668332,
+        25120020580658732008369526639225237165,
+        319844862231433745149119488665053407607,
+        315913967756689407628542157155490417214,
+        113062680462160231119831343980968472966,
+        184452343884211099007420976377816745390,
+        132827683658400529932791462340395868969,
+        130806236453253925570931243523163049710,
+        211164854574592820419899839352333307756,
+        278795570184724888554054901382790553816,
+        210004855619228658337258578023516365906,
+        327023684250106096822656990918502816113,
+        57379804554601378661690405173337595674,
+        255151770429118625328773092158181413493,
+        5515148675141838095570976267371079256,
+        73802118425319512984088153219298400266,
+        52225898701998693376732961737550762156,
+        51578505274183912866593497080768035403,
+        180369047838192545112663537455822718997,
+        255131411379782412265690694533260999225,
+        322353408213355833574608268254299363440,
+        97572463846990162288975192129981043537,
+        307644942783373607080378467353243270988,
+        63760170293948718667281887992870500992,
+        22998508211450785270450117290212789918,
+        58804076991911516308726454168080229723,
+        316782653850715585083256158483868190881,
+        30550479637664749394343744103185999197,
+        144132386952561882846920270502839055795,
+        174537046529119087377817083976987280803,
+        110525650053695245119275318122163362924,
+        49679982661936167878252292478118463292,
+        146902300524315309584157415904555816736,
+        106615494550672597313051762120775167120,
+        98769308502421899860655931969535302793,
+        73261035789372561749184259605406751455,
+        259749528101009614978145526582719406103,
+        116350954142169149252703653225446733452,
+        313884192717729742801398876263666267545,
+        177434151035591472727738223920754009278,
+        92465602050242779178581079948705025730,
+        274217825072386591941632176635265445661,
+        249090562651826245914073078133594150851,
+        15288773445065967245346633262953002514,
+        99695976828286770595129138141862557698,
+        13730955861530662216794878521253122724,
+        135609336123885923484349494289675477584,
+        339771096815771390307427474453648569952,
+        143965021541042152018431739062766606408,
+        87232106480896966998919244142919080211,
+        7621877847373337371007301375852349797,
+        50561719853067311261041161442486988258,
+        97412350267551318429217490397933647087,
+        330966889298066044227724145792928491157,
+        222319593728578377860136130393601566932,
+        160435791070483244159521805561380570191,
+        152868410444899252802458929802848812908,
+        106622605402290566750725366971152557886,
+        336972274903291545541339058343114981983,
+        298032260430799353596015075478527781066,
+        120840396242443853486216588153818756109,
+        130044160002862234051108240836104820645,
+        286084838559699361819030720207286654398,
+        291003361160273698170805034372249543874,
+        88806790291104900957497318255802008637,
+        161242933752911547183922902240777172982,
+        291374878240380378346848151304012932019,
+        193808815428280361294569883813213403306,
+        201392509901452877578900034086512935635,
+        80971996403277565417565105206762881724,
+        67895190457126340280407899982917470597,
+        145969554158272479936728797014314733129,
+        260488652984817363065173227724384636391,
+        329527480751040522267868520174228967372,
+        188858409607362193209437195093481881136,
+        82699371613658802120708714270104589731,
+        327727058742852552310484029826784193356,
+        218335488302094278123836750164640185931,
+        252746656360227473422404866845617271035,
+        115967775330470103495609756277007192978,
+        89428485130314263085937692676429509151,
+        143646145637290232913629758802334075666,
+        291248438453815971714836967237060821382,
+        92716160351132913952377983633313519730,
+        193763593245982620913736584139017898826,
+        12863464799880201086052821004478120788,
+        241563849398720898210791726021277085580,
+        161830332702447994093669619155459347153,
+        279716509030779729548665814661044258061,
+        322016489851739306793350562583673116570,
+        34991819248980368205318785444438478657,
+        27642183121106467463839200219416158837,
+        22146200420757557775024954579555312265,
+        131255948057717559503573188381625949203,
+        89846629064346444855870731739614887568,
+        209517987126036416121071226111026645515,
+        261492097169742459174348029499611008410,
+        17343365250858513696955818122685003040,
+        62205287117317973745228639846726830236,
+        21773849249426315664156252765812203348,
+        54853750544657960230691672048447648716,
+        27584547170572765538026128741425883050,
+        266411414456581854379543369441373612878,
+        310023446196237527855656892523674756977,
+        271117860394607832095611743927582472303,
+        139739029301663166627231292837069106739,
+        218142671820023743346540016021625169464,
+        29066234191324396186707997026752397037,
+        29730235763889337975644897634073066321,
+        13685701296047071433247232499249127162,
+        101657986068334309178554802087708622498,
+        314318568438578027995031424659001969515,
+        214442163760886255716556123173974331446,
+        120431338317664362020912723345064591800,
+        225067372736860069497694994779090076154,
+        215743147623428651659871510806674996422,
+        308623277842443241161116446420904256501,
+        89577282448052720897608153085575160314,
+        90318182791779485306768423909798642417,
+        196167196807407129035117503942016554745,
+        220192640108271497519789674866046276487,
+        158884474733942135401211812994493464493,
+        300048837821866563384689226809802920599,
+        68104169664912776765792124915557864527,
+        223734899762301073936070335885020992382,
+        106082437329456491707689494111571865701,
+        147778775928328654913913244588558392416,
+        33460689983423098666317264871366864676,
+        51575221387687170732190232602704863359,
+        335924569484764762757011292024435719302,
+        209617822471455473480351747507154939165,
+        103915621677607776529695828933183244974,
+        165165614561595823621073902857554804358,
+        120343659805949859002705087184113175978,
+        65495213289591749090776875932195692331,
+        37339123789079304721104901450539462224,
+        213765355017678130699788129282567514643,
+        243011960679740669184185933907035665790,
+        175914877007940378174934748168827311286,
+        16806389874179968855092892818425652469,
+        261242162177585468492817336105598602733,
+        305657310097035968757385089035092922276,
+        193102102631587633082782983486393563947,
+        151780114808811059059988802825690627101,
+        150363960132324541882622493229720540014,
+        141625642325958431408996195146959967291,
+        163049753107783964337756026924355535609,
+        58216218924184510380752329167528078903,
+        311803105047571893295231737427017290095,
+        309977518180441835434213264780652466945,
+        284065624384443449121844272847148099279,
+        169646484030739550225466894262509019033,
+        189633939600493411373349552984924886150,
+        33015671301345820669396118763202089883,
+        33356998532560506116216081691575579390,
+        153547550816258579362514912551344817342,
+        213567945569054041434459690017147346826,
+        63659287135687026036126204361493275031,
+        187434520330487109981126481453623431609,
+        319802739763076305445101024336135535541,
+        210740168575789757935411003316926737820,
+        81703117202138800018140069490803281147,
+        202829169613078786346452863890698941711,
+        183277426639888299141970519909577105954,
+        212231076015123202099095342811490638535,
+        204299939053620578304364488324240107313,
+        56710323500327115990738596540616628265,
+        293161619828971510000675778680301197595,
+        327931083032728558957444809689043138812,
+        83414663804516572408156947370842082779,
+        26381164444386493248660642003539824944,
+        192598773029790359743299495579457060103,
+        308524541154870339542845033585718669573,
+        204989626720994967530224044123973406475,
+        53994679754119271234543934108882646155,
+        31117993365009683332488659754838493422,
+        145454405964018046883669977665301998040,
+        72776520582200992148739229753910949645,
+        284977884374604177515659126682634742527,
+        70654010131469777895522444323477089378,
+        124208316130598181456088650759741008568,
+        29186425677286013337592106224650732468,
+        280382160778255812264818298017194540109,
+        305021380223015820920283883026890941729,
+        293513982565623223115284683808503648214,
+        27439205744417814534578891299744047960,
+        193121578728454390876177101613969265818,
+        317261331738426664825051310264544313473,
+        184549182136721987280131069601153275525,
+        79103859137504792666359482682179733438,
+        322767255278152024353502843890391399108,
+        112342782169517053764962521906528117511,
+        21227256624393945447004054048694466040,
+        43035629402172740913807410628319521933,
+        136464657330224792800480660395163596065,
+        272060173762765551558219345786270985897,
+        124063311138421272983195673919235680256,
+        267947884120455989289292574301953632958,
+        307550473338992542835609413609046886415,
+        280942749431393840522804370518630637349,
+        315364947864590163796465962599858073247,
+        242613455815450583793326522701986655010,
+        79125227702198754741491616477759555242,
+        309619987398973326771711166601274462606,
+        40174543626152513534232445811103466709,
+        289330209966368545581799986846291719724,
+        84251758785326044324930451262628161375,
+        48355816658149425434309203902315653667,
+        135016479006486975122165963028242547247,
+        262474902565235724838695703372915017010,
+        211081614544339002774372535462986193807,
+        286670446483688417786434228610265634746,
+        131189814356060327839667528838755457996,
+        120857302984467105656162559171572253926,
+        35377346928003353502039762661733809613,
+        150204200063347746591344460626508553646,
+        92799181082076127715567919213337944705,
+        168747224742881349214930107779519556742,
+        106025530014418736242882071522419931532,
+        215812168604070985568340892118207664402,
+        126371641779553139334353012473304639029,
+        204283486508420284732551265632253439384,
+        232184018957082294894180655720608796681,
+        20306996663860503123250799779217097454,
+        117314571620314353063628307688336058611,
+        297090716742110753007783796814533851673,
+        334037075910781132903974344759794106851,
+        36489637076323165378460756728037360905,
+        56349737832027282170291938509780275404,
+        214099079532619333767201388148118193537,
+        284453977304754101361550395821868875108,
+        97026157250385212399445849107070138276,
+        332374369428187842547038217496040624080,
+        43237955811407544735423077051878045536,
+        115876567677704252313707289632906934790,
+        281539922031308746925470758974176333116,
+        303924894576149118752156100334808205366,
+        3401387451553877891646378878241534301,
+        180603978797355699969967047760395439122,
+        332889194221026180011356466297715174561,
+        297486256899598586263126491155931037440,
+        209045578703600557907321319300505822541,
+        245794354019657969490475988742154183568,
+        170641770496511038791289828835491908026,
+        107723019125376547488747425851734485272,
+        287007731111010759362800080182321615333,
+        257643186704097101296280056371180066293,
+        283383075406252590735765711668035620064,
+        179895773218345111906755923746941741479,
+        66839716694104121187812798974703345616,
+        11004482613061914704940100631031147812,
+        128266555993906869917447117889009623044,
+        90252208648341522223300172509698827951,
+        71541177806809419425438655081326359768,
+        157555122089364045758796478256287737265,
+        101051647331308515570704671074193576785,
+        280685372216849786533509873881341652293,
+        249339246973490057477359305720554189691,
+        58352025808794131010551465575251623513,
+        252730170642743442406432964300653842138,
+        11263251460823555086829812747243329505,
+        152081586086339898051652548811892612783,
+        188808842043810434728048411010502972119,
+        78667168285300493687235518402758404388,
+        208085295269859690604601855947337615723,
+        167090894418876892526294914057203889521,
+        233724461332966475658452061787805708458,
+        135063993525155700074403184212927321581,
+        325674689924410491639145455232768619490,
+        187146159374183011858335093821614685008,
+        147912829920412153804021324744828815528,
+        15854546557636205659657124690769753083,
+        337152308042733474193438207067518582476,
+        40200757497796714953452403974363306631,
+        331397679357865896199036758902018605526,
+        212634788858358126754749341436794316385,
+        251907596189528269022208278263906718173,
+        90916641984417030540507500207064751362,
+        211414079072536481493623536912433007332,
+        324675770445576572031398422805829799546,
+        97596402102335272658361014141964549758,
+        41360074230248813398894277883564692522,
+        321458713866281954795992840491132301852,
+        259632892354066703465877384457214549701,
+        88318727867286627544457443100977639404,
+        256483265996977034579822856398628462101,
+        221695604564798317958779855544846865099,
+        98329539346724144768607600271455060279,
+        107427477718941226683333310902218454198,
+        505069030588765749605197507513865960,
+        322069315976892065592323856105805515426,
+        168125063780275955226474638506113247832,
+        66826395103319360462571221120096818893,
+        159980343234694018243155319631296942877,
+        171612705013255949632489672961494140340,
+        96161791774856341131906237460314473563,
+        301332262444970006503713310835926968676,
+        34883678783587306779861815920437486282,
+        17342851686562679419290631459547754487,
+        79812859850633303330992111625492032314,
+        127673455485113577574282910200484359832,
+        93795048634706875017728160120869264740,
+        36478704682367433941851769055673616235,
+        94848424383641351369016539686835316081,
+        281704802855495523491138023378984561603,
+        266418263905246968920475506150869733228,
+        264652088256680903089437151721791957313,
+        100992543709097886948162015189462012941,
+        219672505776696820039714657838488651327,
+        210744710490708565973064811481235114417,
+        99383522653813283444045009229349781005,
+        50130312703077737331589191392977727917,
+        105964311735055955475813532002511031486,
+        162321874791178333545087038290690446122,
+        332856833244028563627634728096663980791,
+        22456600605356558136937286914857745058,
+        254080502934858725705471105814081869806,
+        182761436910800676346159952985480786191,
+        6861668709866654166821324599989722968,
+        151595576213259835423496998382330928470,
+        118118668078152821984755257047841500936,
+        236458119459259491504003375768566415413,
+        154833855578173593137932159298856085126,
+        59768358064285450736354490792368196599,
+        61056060474106748424075723948629474440,
+        314901421808614502949646097336331249606,
+        29458928710564436015188436390782466346,
+        283330555510183347218841683471979246881,
+        208897891192025823192014787742245524608,
+        250618904589710747885041294883612423843,
+        156736450343242706446502711055520587348,
+        172448588525489436938271023235115056974,
+        86675610720454347462233052736003880418,
+        154070364940044071627813199008989718976,
+        157863455270316406361539528778198863205,
+        261285229729005180577374324391125837208,
+        213856372824602047597977765636593669179,
+        270660304173778428445558031365111559274,
+        116347317346531666688187890607508004405,
+        83940955712546945701052965887547482738,
+        128492128256628560235045392858020585703,
+        75619065358788748596036136895746788437,
+        289211295754735298517900997234209595263,
+        285704186231862564472818670079916840238,
+        142402261752910824549366757868136459073,
+        333486610597823875906368968641187136620,
+        172862820857826721154520680867767353930,
+        26942965146224613165202660153484321016,
+        51322277337767992304568157055391892481,
+        298459778097213072050308933002824593017,
+        179644654541623648107986995026529247863,
+        143573040266644390364588628751404248911,
+        152581290604656552210400925600806917567,
+        97250487834151052639693070905741666053,
+        260609459491053906830148472003027464786,
+        17805887556008045517048406374616785117,
+        53604451749771925463167731655848041026,
+        328994956678580865935370626112017081367,
+        3494083598142140895031864507420284261,
+        264010071005333939996290800015738976981,
+        96486485191262202119899981528830219205,
+        60291709041878468460271596295071883131,
+        37670210836640314247109059629216920561,
+        114226000793121072210317575300189456699,
+        170565376884728931479821973138626594021,
+        73028389684677980932798394799258584052,
+        159592158751722864602281129830164904562,
+        52373974622708788166606485780211501131,
+        205441349351892958255304645971883464727,
+        39235976200155325566886738681466170657,
+        58725304922003525570881302287592297054,
+        137848422645703316061104069617604077455,
+        188768788372239615730106596725495246816,
+        221326918475764359057512795351892930589,
+        45149237498473416264142479980078254555,
+        223919419628728888688932591095489250166,
+        208290897798071709650837909117026578574,
+        49875502850003729144973605365386372665,
+        51029817334827838294627506457291821502,
+        65243909877733851934475869034045785787,
+        247271788503140713298767903723836804437,
+        78817184897385770855790606216627750448,
+        178274818177946212750004201888889246982,
+        177119271144445904774948924376151099425,
+        172950929605638473273428279118860649473,
+        96424534104438983171870972492532353232,
+        300404655330769746062654544487825566508,
+        137127261292856253136669910128395447720,
+        106963841074055187262847481187974361876,
+        98955582482008652271623668970333267707,
+        174546697081826584306632116404320449863,
+        192792130665403851694641916430770358765,
+        74410170634327552504751293413246855975,
+        228344228181269750771282691432302464333,
+        315583410095450216769476422977933364510,
+        107449588969434391768232686784911115676,
+        75305841493084135833567467071751675771,
+        286090343005312124188824527425087502343,
+        298102121209278682229051426575424478298,
+        244564614851774104967682957139044977749,
+        286737721264858522891119163290386973033,
+        32528271965095886693766888829908340293,
+        219042262469432733466861595325591634990,
+        303150388212905566817295466854059416543,
+        282573153133687829755627689237945283660,
+        339113105676223446356435171728796283963,
+        93572533145912824053373221856823108023,
+        84677239867362309146087558913731690031,
+        157454357963370775798639700039786162034,
+        120998281265538500156287273781370916894,
+        18065763873606210169513811080656257951,
+        174280321228443139124793733357202146846,
+        268920516145547844503671702340713947399,
+        322412641282107085961688276176110681845,
+        216136999052809561898621687067717568881,
+        0,
+    ];
+    test(out, 960, xs, ys, scratch, out_after);
+    test(
+        vec![
+            14220278540957382380,
+            2341486122221271577,
+            7196589596013057856,
+            1551746986989660725,
+            10730116577084868866,
+            5936722303301071470,
+            10369883251536410381,
+            12850276745016711948,
+            5296892922384710655,
+            17927383456923143639,
+            4297800151447374193,
+            13092886296891104150,
+            4359313044481849916,
+            3298387784200302647,
+            16596015395483459285,
+            6430047254377585078,
+            6434371216959893287,
+            14030068303817750616,
+            17753911968053092798,
+            4279608746592328433,
+            4376377052237075099,
+            41468300827872346,
+            14769069119913127616,
+            9177168551686374492,
+            15140565915751014925,
+            924212491380315180,
+            1560999192236438812,
+            18226600332889405930,
+            13348576723395503060,
+            3609063818274619379,
+            10959876508495766635,
+            1673947094507794325,
+            8930147615298565568,
+            1510138210058461841,
+            138658670646289458,
+            12458879312664552547,
+            2808979850323582450,
+            14034036666484023484,
+            5585848372598666548,
+            4257210144363692139,
+            6338629395801650540,
+            17487850811313620991,
+            11723765031594531899,
+            12058758430990318672,
+            13113234745283246366,
+            14258831759967032298,
+            5702057171550109179,
+            13097449289463197974,
+            4956526745376824433,
+            5705156949115651685,
+            12015733702012855186,
+            8669471405965585399,
+            1905089081593904691,
+            9375413288655317408,
+            2862087246546719140,
+            1438802037360108086,
+            18188344038773760076,
+            6710952137267346735,
+            5113990331512729307,
+            1249942195157804768,
+            12370173123137454524,
+            17270573617823502592,
+            7850217070904007210,
+            15008954292697138434,
+            14323227552451257069,
+            3071194499601430769,
+            1423266339629343645,
+            17719424777360227280,
+            5817741038340475518,
+            2288688081269683349,
+            1294813101366983999,
+            11187697042332288595,
+            16383307749918316156,
+            11964485411396601998,
+            2882482593432453314,
+            9642249517934314115,
+            10542384929787952175,
+            1685709334753615213,
+            9706601754650993843,
+            1704742035456300361,
+            14513229339862334723,
+            6682003045097239025,
+            4714611199273627241,
+            10432585451230614215,
+            5273605414681358823,
+            9969985476182536371,
+            5399491654701460340,
+            13433254437284849384,
+            8737041519297820559,
+            14151041462241779583,
+            12800325041646118168,
+            1054683376318581041,
+            10442674392431772880,
+            1301805922104023475,
+            12991162900765496493,
+            14882797183035693101,
+            4301011085545481404,
+            11250534379510854507,
+            6111542722921923807,
+            16925710356305375375,
+            4650629800913345495,
+            8040250152135495246,
+            6010724734735944849,
+            704649712350181160,
+            12623719311045527320,
+            17274359723691631952,
+            12634456101984828972,
+            18130410107220630156,
+            11889003125474723933,
+            16069412996687629414,
+            14767547198599161992,
+            1104256363303539555,
+        ],
+        112,
+        vec![
+            10900792384749518304,
+            1752565570529908396,
+            3402229115647561238,
+            2472381872242532960,
+            15748089475115162936,
+            1958855681762413475,
+            12100416912810779188,
+            12256578057348862042,
+            6556831077371185734,
+            15914846823057329492,
+            17346954154793811255,
+            17566187606614467459,
+            1433606366066775495,
+            9089332045922722756,
+            10056944581186126460,
+            5324425019386643029,
+            5281765195814058625,
+            1449711238109407238,
+            5903959110668039125,
+            3336955200427408551,
+            751494194154096512,
+            15350321905800137137,
+            12407374450431165353,
+            8705815621686854350,
+            18038286270431178148,
+            11671842546699641930,
+            9343865367071815679,
+            13401838367914321671,
+            18365991333043790435,
+            17428290065100096976,
+            6040216493892400727,
+            4224515713015397505,
+            16578741590625036060,
+            11835373548777581169,
+            18413478850867685366,
+            8453265724386285209,
+            5394500720474148965,
+            1927463313122594080,
+            4177838821929605731,
+            10680620304882583021,
+            180005403771618203,
+            2256408572502279608,
+            11718426532525535626,
+            14260315287467647015,
+            4035759666841010016,
+            16259497729863184485,
+            7772704202422133476,
+            6815813069474359325,
+            11207378575459431371,
+            18308033894506293455,
+            9875145231436590806,
+            15354934628544213986,
+            761822562304640527,
+            7391550101325083295,
+            4023926600201752832,
+            922969942182092752,
+            12110946035817932140,
+            16574399923422896843,
+            7087993004495856759,
+            8299428112066197874,
+            4589434828506762129,
+            13978205413565566735,
+            15675366647238478172,
+            7819770375827015142,
+            6823625407675143456,
+            2042269662440457350,
+            11521115322912473140,
+            13703874674141705702,
+            1295561690992462505,
+            12464082489717915012,
+            11378922861990148970,
+            2076282285705918066,
+            1390689690731346588,
+            13670979351308531000,
+            12980996477862222169,
+            10496970808504864546,
+            14015388605987660396,
+            4171129107047347396,
+            1656857204469415571,
+            17492457435753920912,
+            10132937897450237781,
+            5065601177732655021,
+            17498367701449356268,
+            9552937910825811119,
+            6213399497134928078,
+            12865519292113075754,
+            8548871019474664332,
+            12973212090641168109,
+            3018095992673320728,
+            4102580256148037725,
+            11411505815957245048,
+            8044142604358855954,
+            6163064685377006161,
+            7676133172626151339,
+            15177331097378985405,
+            923128391789363540,
+            8405355494789853124,
+            8409013636286216842,
+            17519952046647436442,
+            12690425880634822079,
+            7295927951214020420,
+            5103171252065286692,
+            4531269626482776566,
+            17509133779966482098,
+            16771567673323510549,
+            9940518318209913958,
+            2566490491730418524,
+            4997841530198583881,
+            11759671980624847072,
+            12804335234851198898,
+        ],
+        vec![
+            564820221219774033,
+            4488711358679422475,
+            10020082426042197380,
+            17225157352286806558,
+            5780768250525361575,
+            1970180556702143116,
+            5857604197270789289,
+            4060596445048742789,
+            4197799076012455571,
+            7044577438443748571,
+            9865458079653433267,
+            16329626967551115891,
+            4152461199188161627,
+            13000775528850398936,
+            7619420622350160180,
+            14900279174214956337,
+            1704825421557733731,
+            47372161928033978,
+            3056759021249434255,
+            16034528189533406528,
+            6435981853629992716,
+            7347416955208902363,
+            7867885339734871956,
+            16003312811447303393,
+            11973054691848315139,
+            4061237791967812067,
+            2991418391396596002,
+            4703879799196538602,
+        ],
+        vec![
+            17578771842269238556,
+            10550562699554142644,
+            8014103604728001619,
+            7390436293466268454,
+            7100508597220429999,
+            13756539744133134331,
+            3525655558627413814,
+            170556856626573274,
+            13733802409149293411,
+            7547658189901533049,
+            10109863308220110581,
+            2718018624165645986,
+            17221869495150324628,
+            1084076247398656329,
+            9986824749465272556,
+            9281213789632200478,
+            8263835511857229414,
+            2738190782342964576,
+            4727749457903038288,
+            5193416305832697137,
+            10922073469544468796,
+            10479120898291460619,
+            16531138594227806551,
+            5669282286095321480,
+            910652003754331469,
+            7613181106845480358,
+            9797364435815597385,
+            2605791728953382133,
+            3252270662152843680,
+            14622379517499579600,
+            15478463498321531331,
+            11333349078754873211,
+            3607338878951398292,
+            13712359136800994328,
+            2877919791629132004,
+            16504311509495367657,
+            3984067008787761258,
+            12750589284505094681,
+            2943531823722430709,
+            13059724297333425458,
+            913829495058925011,
+            18148528311470701517,
+            9740722521202851845,
+            14187622249425297058,
+            18404294373540546372,
+            16126489610510541686,
+            7594528449544330894,
+            7307162781265364836,
+            18291168119091813753,
+            6207931594466286839,
+            4606167418509673090,
+            14995240148627089997,
+            15715313103810577432,
+            18105567578045566655,
+            722560899028528632,
+            7800517321196764697,
+            0,
+            11237865801974219633,
+            8495979800230117621,
+            9987880506353342706,
+            13881397368512772448,
+            16410969001089181498,
+            333374346498930988,
+            4965563385767496990,
+            672660669687292950,
+            15110765557390623369,
+            6227398366445560799,
+            10437883884413763417,
+            186853687685224617,
+            12984935070726568218,
+            17363252202577108630,
+            11014686580262231685,
+            12860125675167553971,
+            5547449898028817740,
+            1954311880857527678,
+            12081499992106861757,
+            14088922543958348561,
+            5962182563926402036,
+            5741569353545905243,
+            4210355652762257668,
+            9053751503514703697,
+            7217335315946897611,
+            6001545942842673798,
+            2462625304327945285,
+            291604632040409357,
+            2217739517798639603,
+            2459684629220685427,
+            3250634546851783096,
+            6059950473822714693,
+            582656439794855702,
+            10281608630795849151,
+            15786006397997472307,
+            16261716592524561399,
+            17594773541694900671,
+            16563607893390940119,
+            15505686658080216809,
+            2051217090370709743,
+            13908204460746584986,
+            10039065459685700894,
+            14729811272925582111,
+            2491677743278343614,
+            2841321092069312904,
+            15064468426708488588,
+            9844013577617278901,
+            15394077255564897694,
+            7846267917227712141,
+            2926235763818832770,
+            3614802673424810100,
+            9439654670958755441,
+            11228283553719081427,
+            300805303577965353,
+            137972366037263628,
+            0,
+            0,
+            17236590422641137780,
+            3624909720816563168,
+            14760980184861256094,
+            12619697833885886701,
+            11158654646608400806,
+            6427394341906398356,
+            14871794339281852631,
+            4436807681521846899,
+            18179949743405593894,
+            13872577160616872141,
+            5825838831881338115,
+            3862312932472761757,
+            138044675074312990,
+            15071993629914359360,
+            17124765792905529105,
+            3248142733680724962,
+            3891075505082712037,
+            6225475960510427854,
+            11369706706515368571,
+            11286728465632095620,
+            5182849661875987731,
+            11179192798752789740,
+            10750517245961749782,
+            9660102259642485054,
+            7905348372980940366,
+            6606241368966986909,
+            10292241739332011027,
+            3848900457088510551,
+            12152591835908862357,
+            4562770772987021222,
+            15938089548127288011,
+            9698047696083781011,
+            13560645597951715331,
+            7732793292629543444,
+            7001973034910440318,
+            409123120027429255,
+            17678180108806694420,
+            12698074214205994356,
+            7447251798260171941,
+            9757491913093219480,
+            10221393982691316695,
+            12294139009925614381,
+            12645218559587650799,
+            1569889406832824935,
+            15186575789336541212,
+            11156326477797897792,
+            3241434575939356910,
+            7753423363217428843,
+            12882554975845472437,
+            8367515576296379496,
+            7308654739706172282,
+            10357093098345630105,
+            7448894655389345071,
+            13033958940183436012,
+            4023926600201752831,
+            922969942182092752,
+            0,
+            0,
+        ],
+        vec![
+            47352956643360245490207063503995731953,
+            107703120487382426247311091367074867089,
+            19438745105552514320333970401555351738,
+            302384963817684189154073583835629710622,
+            182666315716912074205522240018913895104,
+            62891833307653863278260247942329268594,
+            176343927871386912749374226072599430445,
+            122726968452281503618169268265123838224,
+            235471386999655893661661098015148323285,
+            265272300644619145572476377582152969615,
+            234864712068164760463767100368070220812,
+            229957017808348868092178270733735845634,
+            121133694648359409365089216307359122627,
+            46329978654663561788539484649103917009,
+            37027739525064087734966229042635902139,
+            264493695846603061970250539472895848425,
+            31793272907155636541489622603304881520,
+            5848771759101445955214166723881498748,
+            140849117880419181616571345753802478794,
+            112290418491293312405477941416435049613,
+            300053296184532245054319755861094512156,
+            192355802114621328288829730734855118046,
+            127831272135605245360615027759773199042,
+            253539988510591111174548081604537689741,
+            144173114179938241012900223078460261244,
+            55986942471722052178175820663247445035,
+            336906901224579126552480080898749380536,
+            62841825417345680062879172060543713571,
+            119337051728035109899409324604521992605,
+            253567821442143720260294732410789284656,
+            49424653662271545106854599512526993996,
+            331403348767354866024709597644623622681,
+            94172813737069773014909969115911897140,
+            66496574798699666659287725420856879499,
+            125231807210062671183876410293599855485,
+            177213558737626946155462810432337232267,
+            119301936697396611451496623916378681409,
+            188204638295225693896000210906359427894,
+            256500871068074784704060319483735713440,
+            98083583844636688298261230380864580263,
+            136534414289829358907600545066237745152,
+            58384869437299071158022758535490515245,
+            160082676569314974801415401567169700636,
+            264639532087314279009358339238642455024,
+            280165614017701920364854524414090104135,
+            160996178115285509855809961894450534619,
+            18472815250045706143925204368913070065,
+            236767024887685327265677346217454629946,
+            244062241622707684304451736218869963149,
+            325945016483252034225873116612012152711,
+            155757141892748721320787938862352038911,
+            53567732568026191324351067670347147375,
+            178756866425040464199831043404942630357,
+            93146202617011788249942971019475223078,
+            274848828550713161999234282553959208717,
+            79007670502239216852115227839765480442,
+            197673043513806817570512370983573875813,
+            135169772407754126208485337733191258672,
+            246728681922856661111169018697629219423,
+            105426773699926296649241954360131324365,
+            197344174326357602289948055283936806228,
+            335676969004579658033451442239562467754,
+            133747964877724449147000222136047649781,
+            258091016995598963838760592544688067098,
+            285716197439974269459195490297762206868,
+            71010844830624864284352409841630950854,
+            115492424794801915967958030145070676459,
+            24247430440344174169806300779818571688,
+            257528937702033582787024038292314817025,
+            268767474928093547870695060921975167723,
+            337686293817382575222536886251404213690,
+            141237911312099716128224622797506660542,
+            203473018549898537269398814552525361244,
+            157198776702152832122597050825603431676,
+            228845445827454618672468901648536441674,
+            14462519465831914271560314281005963799,
+            272737306445336442182768770278771439923,
+            222254903873508711479139110876522319321,
+            318011882626743405802238836410737893603,
+            281493432851779459762486613163769447916,
+            233685217631501195410956563407279992274,
+            185626937963914459208144488128687002413,
+            229596293698974722539893769308450503541,
+            80671728876519888093795620415621690944,
+            223570026138309236956002064590684489846,
+            108793452925048743273821918821413706996,
+            257572391902769848782904612312785170935,
+            14861704720611933083773418440692843156,
+            286666493925113414013175736705467031425,
+            313933433285097492540773880002358766078,
+            204232625371602915763904599149971396211,
+            241205261198879624083290455218833972634,
+            73224704750164324819141323901969146150,
+            330957149840802064364139824654780125029,
+            204056612624810319514638590650738975572,
+            129976254534252696042299666697012864421,
+            180093680804686744109039731176868938663,
+            273883171556057388714025029604112255222,
+            214816101978043895031726446514615807338,
+            80984130174352223019942039468350739858,
+            283556822168793788389060061584296509704,
+            321742675122021728229731237649504539856,
+            238487117660293164778165974219349209571,
+            90159116310225989860533703361004714016,
+            103072255718480641399897530350450534777,
+            6276097656147853550310546064503390809,
+            95842848367588774332178568128759071802,
+            289883847023148424727677910810495480497,
+            99240505037299643169818665418980028626,
+            324413131981352678816908910602029332242,
+            84934489093944262041014387144213673209,
+            132952996168191615946816977654075921078,
         ],
     );
 }
@@ -11426,7 +16605,7 @@ fn test_limbs_product() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(&[0, 0], &[0]);
         // factor_len >= RECURSIVE_PROD_THRESHOLD
@@ -11607,6 +16786,149 @@ fn test_limbs_product() {
             ],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(&[0, 0], &[0]);
+        test(
+            &[
+                10008125746032292648,
+                18321114734333840332,
+                17920212203847604612,
+                11217657766511696793,
+                11281083380440747080,
+                7892713234117558634,
+                8902471323128328235,
+                11892365124252837749,
+                12611508814807140936,
+                11815551321438701427,
+                18110749687253320811,
+                11715728409437156086,
+                3339319126985623378,
+                6325617411041006209,
+                14096883611650388622,
+                17687808067973538964,
+                168019485471086672,
+                15693590891786235960,
+                2786725115760255375,
+                9052512570921605652,
+                14177547685319586247,
+                15370386165587184937,
+                5970865523433924174,
+            ],
+            &[
+                240077568825876756002498170659494952960,
+                45227925236387151280598917979689131706,
+                80628048425949459208838623305653474442,
+                239552943818252637510569824201631662841,
+                216252749141050209738841398826639392335,
+                166490154497893602841529735831281707559,
+                317053505340128930513318738106901021180,
+                270297090724489145663024656704153460960,
+                108986433885121000571320886626471852276,
+                195872423938539377380772633382498807642,
+                24720558156750853290956804850479176115,
+                707009434669,
+            ],
+        );
+        test(
+            &rle_decode(&[
+                (0, 2),
+                (18446743798831644672, 1),
+                (Limb::MAX, 8),
+                (131071, 1),
+                (0, 1),
+                (18446181123756130304, 1),
+                (Limb::MAX, 1),
+                (0, 3),
+                (18446744073709289472, 1),
+                (18446744069414584323, 1),
+                (Limb::MAX, 2),
+                (1048575, 1),
+            ]),
+            &[0],
+        );
+        test(
+            &[
+                10985406156730349404,
+                16998073653641921623,
+                17457059674168741173,
+                7286565767689019192,
+                18125356898477139174,
+                16492808515310020304,
+                3477958611236241232,
+                16292175871113024008,
+                5585730561604558759,
+                6236295300549613743,
+                10319623255002503349,
+                9463751064224151456,
+                591089135516199459,
+                2252218169318269834,
+                862892606080353458,
+                5048725452063866206,
+                8818325570889918907,
+                8659485206830156469,
+                13423243087758132387,
+                5931638651683836702,
+                726189598260086702,
+                17552568882310631283,
+                16362554893644374308,
+                2407211671412641092,
+                1658933737262819201,
+                8531670718492391711,
+                7551068411167036177,
+                1219676338169570619,
+                3249808943561478386,
+                8240095392791493806,
+                18379760227315341655,
+                15217742262236663404,
+                17914601533857880122,
+                6316977119306097487,
+                15466746727219811764,
+                3809403759956883034,
+                7752635439402559334,
+                18006879800705758675,
+                10224737295140518487,
+                8383030445894670697,
+                7272423850473130597,
+                9751703358656322718,
+                5778325638584493526,
+                8175215950976649646,
+                8581067395248196883,
+                4729909244293992358,
+                7626677144783852491,
+                15620921255634335582,
+                17768397379068248272,
+                7127187304413875110,
+            ],
+            &[
+                23459123811112255192670793719823204352,
+                324861460488373279276187309316938643885,
+                319726707456873302394175870886636765718,
+                155815101091582229695388040902301897695,
+                82619119056893344695112178361952930800,
+                154754477755439156187332175534162740813,
+                141756454926499413922900087417995989557,
+                244597049409582282897860927098620999566,
+                334156439934171788994877624879385863101,
+                70672076476760626614486586337554218309,
+                37292764551458572117056103267917035029,
+                324069137375980768555839173307077310335,
+                272791757863606507832051400304598855311,
+                201277178545982701970730586669008983402,
+                248788377186112262211230863898301490455,
+                206200934424219962890615543525703035186,
+                202719123672462737334804366240819170898,
+                14948792265081907251197120384186955310,
+                10895571021200540185627403033688948620,
+                57545402967046307842897877258049422931,
+                2290361408066361166162683180130825455,
+                329285501805162430130881311825634495356,
+                268923249423765567390623333262826448809,
+                307378013346004085186337119167264664784,
+                666535375735877288,
+            ],
+        );
+    }
 }
 
 fn verify_limbs_mul_greater_to_out_fft(
@@ -11652,7 +16974,7 @@ fn test_limbs_mul_greater_to_out_fftzzz() {
 
         verify_limbs_mul_greater_to_out_fft(&out_before, xs, ys, &out);
     };
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // depth < 11
         // depth < 6
@@ -11866,50 +17188,130 @@ fn test_limbs_mul_greater_to_out_fftzzz() {
         // 50 cutoff: r[j] == 0 && SignedLimb::wrapping_from(xss[j][limbs]) < 0 second time
         //      in limbs_fft_mulmod_2expp1_helper
         test_big(&[10; 178940], &[10; 21015]);
-        // w.odd() in limbs_fft_truncate_sqrt2
-        // b1 >= wn in limbs_fft_butterfly_sqrt2
-        // negate in limbs_fft_butterfly_sqrt2
-        // w.odd() in limbs_ifft_truncate_sqrt2
+        // w.odd() in limbs_fft_truncate_sqrt2
+        // b1 >= wn in limbs_fft_butterfly_sqrt2
+        // negate in limbs_fft_butterfly_sqrt2
+        // w.odd() in limbs_ifft_truncate_sqrt2
+        test_big(&[10; 10758], &[10; 14125]);
+        // depth >= 6
+        test_big(&[10; 10000], &[10; 10000]);
+        // w == 1
+        // w != 1
+        // depth >= 11
+        // j1 + j2 - 1 > 3 * n
+        test_big(&[10; 100000], &[10; 100000]);
+        // limbs.odd() in limbs_fft_butterfly_sqrt2
+        // limbs.odd() in limbs_fft_adjust_sqrt2
+        // limbs.odd() in limbs_ifft_butterfly_sqrt2
+        test_big(&[10; 188], &[10; 3231]);
+        test_big(&[Limb::MAX; 27300], &[Limb::MAX; 49384]);
+        let xs = &[
+            (0, 3772),
+            (18446743936270598144, 1),
+            (u64::MAX, 12730),
+            (9223372036854775807, 1),
+            (0, 10796),
+        ];
+        let ys = &[
+            (0, 1181),
+            (18446744073709486080, 1),
+            (u64::MAX, 2874),
+            (1048575, 1),
+            (0, 14962),
+            (18410715276690587648, 1),
+            (u64::MAX, 10211),
+            (4611686018427387903, 1),
+            (0, 111),
+            (18410715276690587648, 1),
+            (u64::MAX, 1566),
+            (9007199254740991, 1),
+            (0, 468),
+            (18442240474082181120, 1),
+            (u64::MAX, 3062),
+            (35184372088831, 1),
+            (0, 830),
+            (18446181123756130304, 1),
+            (u64::MAX, 11736),
+            (63, 1),
+            (0, 2373),
+        ];
+        test_big(&rle_decode(xs), &rle_decode(ys));
+    }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ],
+            &[10; 57],
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+                0,
+            ],
+        );
+        test(
+            &[10; 25],
+            &[10; 32],
+            &[100; 57],
+            &[
+                100, 200, 300, 400, 500, 600, 700, 800, 900, 1000, 1100, 1200, 1300, 1400, 1500,
+                1600, 1700, 1800, 1900, 2000, 2100, 2200, 2300, 2400, 2500, 2500, 2500, 2500, 2500,
+                2500, 2500, 2500, 2400, 2300, 2200, 2100, 2000, 1900, 1800, 1700, 1600, 1500, 1400,
+                1300, 1200, 1100, 1000, 900, 800, 700, 600, 500, 400, 300, 200, 100, 0,
+            ],
+        );
+        test(
+            &[Limb::MAX; 25],
+            &[Limb::MAX; 32],
+            &[100; 57],
+            &rle_decode(&[
+                (1, 1),
+                (0, 24),
+                (Limb::MAX, 7),
+                (340282366920938463463374607431768211454, 1),
+                (Limb::MAX, 24),
+            ]),
+        );
+        test_big(&[10; 1000], &[10; 1000]);
+        test_big(&[Limb::MAX; 1000], &[Limb::MAX; 1000]);
+        test_big(&[10; 69569], &[10; 2591]);
+        test_big(&[10; 44636], &[10; 25927]);
+        test_big(&[10; 178940], &[10; 21015]);
         test_big(&[10; 10758], &[10; 14125]);
-        // depth >= 6
         test_big(&[10; 10000], &[10; 10000]);
-        // w == 1
-        // w != 1
-        // depth >= 11
-        // j1 + j2 - 1 > 3 * n
         test_big(&[10; 100000], &[10; 100000]);
-        // limbs.odd() in limbs_fft_butterfly_sqrt2
-        // limbs.odd() in limbs_fft_adjust_sqrt2
-        // limbs.odd() in limbs_ifft_butterfly_sqrt2
         test_big(&[10; 188], &[10; 3231]);
         test_big(&[Limb::MAX; 27300], &[Limb::MAX; 49384]);
         let xs = &[
             (0, 3772),
             (18446743936270598144, 1),
-            (u64::MAX, 12730),
+            (Limb::MAX, 12730),
             (9223372036854775807, 1),
             (0, 10796),
         ];
         let ys = &[
             (0, 1181),
             (18446744073709486080, 1),
-            (u64::MAX, 2874),
+            (Limb::MAX, 2874),
             (1048575, 1),
             (0, 14962),
             (18410715276690587648, 1),
-            (u64::MAX, 10211),
+            (Limb::MAX, 10211),
             (4611686018427387903, 1),
             (0, 111),
             (18410715276690587648, 1),
-            (u64::MAX, 1566),
+            (Limb::MAX, 1566),
             (9007199254740991, 1),
             (0, 468),
             (18442240474082181120, 1),
-            (u64::MAX, 3062),
+            (Limb::MAX, 3062),
             (35184372088831, 1),
             (0, 830),
             (18446181123756130304, 1),
-            (u64::MAX, 11736),
+            (Limb::MAX, 11736),
             (63, 1),
             (0, 2373),
         ];
@@ -11953,7 +17355,7 @@ fn verify_limbs_square_to_out_fft(out_before: &[Limb], xs: &[Limb], out_after: &
 
 #[test]
 fn test_limbs_square_to_out_fftzzz() {
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     let test = |xs: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
         let mut out = out_before.to_vec();
         let mut scratch = vec![0; limbs_square_to_out_fft_scratch_len(xs.len())];
@@ -11966,6 +17368,18 @@ fn test_limbs_square_to_out_fftzzz() {
 
         verify_limbs_square_to_out_fft(out_before, xs, out_after);
     };
+    #[cfg(feature = "128_bit_limbs")]
+    let test = |xs: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_square_to_out_fft_scratch_len(xs.len())];
+        limbs_square_to_out_fft(&mut out, xs, &mut scratch);
+        assert_eq!(out, out_after);
+        let mut out = out_before.to_vec();
+        let mut scratch = vec![0; limbs_square_to_out_fft_with_cutoff_scratch_len(xs.len(), 50)];
+        limbs_square_to_out_fft_with_cutoff(&mut out, xs, 50, &mut scratch);
+        assert_eq!(out, out_after);
+        verify_limbs_square_to_out_fft(out_before, xs, out_after);
+    };
     let test_big = |xs: &[Limb]| {
         let mut out = vec![0; xs.len() << 1];
         let out_before = out.clone();
@@ -11979,7 +17393,7 @@ fn test_limbs_square_to_out_fftzzz() {
 
         verify_limbs_square_to_out_fft(&out_before, xs, &out);
     };
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // depth < 11
         // depth < 6
@@ -12068,6 +17482,62 @@ fn test_limbs_square_to_out_fftzzz() {
         ]);
         test_big(&xs);
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                1,
+            ],
+            &[10; 58],
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                1, 0,
+            ],
+        );
+        test_big(&[10; 100]);
+        test_big(&[10; 1000]);
+        test_big(&[10; 100000]);
+        test_big(&[10; 38424]);
+        test_big(&[10; 5354]);
+        let xs = rle_decode(&[
+            (0, 9249),
+            (18410715276690587648, 1),
+            (Limb::MAX, 11159),
+            (134217727, 1),
+            (0, 2780),
+            (18446726481523507200, 1),
+            (Limb::MAX, 1972),
+            (8796093022207, 1),
+            (0, 2972),
+            (18446742974197923840, 1),
+            (Limb::MAX, 1456),
+            (255, 1),
+            (0, 1446),
+            (16140901064495857664, 1),
+            (Limb::MAX, 534),
+            (2047, 1),
+            (0, 4901),
+            (18446603336221196288, 1),
+            (Limb::MAX, 1262),
+            (2251799813685247, 1),
+            (0, 802),
+            (18446726481523507200, 1),
+            (Limb::MAX, 131),
+            (137438953471, 1),
+            (0, 1394),
+            (18446744073709551614, 1),
+            (Limb::MAX, 3007),
+            (8589934591, 1),
+            (0, 960),
+            (18446726481523507200, 1),
+            (Limb::MAX, 2902),
+            (288230376151711743, 1),
+            (0, 7719),
+        ]);
+        test_big(&xs);
+    }
     #[cfg(feature = "32_bit_limbs")]
     {
         // w <= wadj
@@ -12122,6 +17592,85 @@ fn limbs_square_to_out_fft_properties() {
     });
 }
 
+#[test]
+fn test_limbs_mul_greater_to_out_ntt() {
+    let test = |xs: &[Limb], ys: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_ntt(&mut out, xs, ys);
+        assert_eq!(out, out_after);
+        verify_limbs_mul_greater_to_out_fft(out_before, xs, ys, out_after);
+    };
+    test(&[2], &[3], &[10, 10, 10], &[6, 0, 10]);
+    test(&[1, 1, 1], &[1, 2, 3], &[5; 8], &[1, 3, 6, 5, 3, 0, 5, 5]);
+    test(&[Limb::MAX], &[Limb::MAX], &[10, 10], &[1, Limb::MAX - 1]);
+    test(
+        &[Limb::MAX; 3],
+        &[Limb::MAX; 2],
+        &[10; 6],
+        &[1, 0, Limb::MAX, Limb::MAX - 1, Limb::MAX, 10],
+    );
+    let test_big = |xs: &[Limb], ys: &[Limb]| {
+        let out_before = vec![10; xs.len() + ys.len() + 1];
+        let mut out = out_before.clone();
+        limbs_mul_greater_to_out_ntt(&mut out, xs, ys);
+        verify_limbs_mul_greater_to_out_fft(&out_before, xs, ys, &out);
+    };
+    test_big(&[Limb::MAX; 1000], &[Limb::MAX; 1000]);
+    test_big(&[Limb::MAX; 3000], &[Limb::MAX; 17]);
+    test_big(&[10; 5000], &[Limb::MAX; 2500]);
+}
+
+#[test]
+fn test_limbs_square_to_out_ntt() {
+    let test = |xs: &[Limb]| {
+        let out_before = vec![10; (xs.len() << 1) + 1];
+        let mut out = out_before.clone();
+        limbs_square_to_out_ntt(&mut out, xs);
+        verify_limbs_square_to_out_fft(&out_before, xs, &out);
+    };
+    test(&[2]);
+    test(&[1, 2, 3]);
+    test(&[Limb::MAX]);
+    test(&[Limb::MAX; 1000]);
+    test(&[10; 4000]);
+}
+
+#[test]
+fn limbs_mul_greater_to_out_ntt_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32768);
+    config.insert("mean_stripe_n", 4096 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_60().test_properties_with_config(
+        &config,
+        |(out_before, xs, ys)| {
+            let mut out = out_before.to_vec();
+            limbs_mul_greater_to_out_ntt(&mut out, &xs, &ys);
+            verify_limbs_mul_greater_to_out_fft(&out_before, &xs, &ys, &out);
+        },
+    );
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 1024);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(out_before, xs, ys)| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_ntt(&mut out, &xs, &ys);
+        verify_limbs_mul_greater_to_out_fft(&out_before, &xs, &ys, &out);
+    });
+}
+
+#[test]
+fn limbs_square_to_out_ntt_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32768);
+    config.insert("mean_stripe_n", 4096 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_33().test_properties_with_config(&config, |(out_before, xs)| {
+        let mut out = out_before.to_vec();
+        limbs_square_to_out_ntt(&mut out, &xs);
+        verify_limbs_square_to_out_fft(&out_before, &xs, &out);
+    });
+}
+
 #[test]
 fn limbs_mul_low_same_length_properties() {
     let mut config = GenConfig::new();
@@ -12134,6 +17683,125 @@ fn limbs_mul_low_same_length_properties() {
     });
 }
 
+fn verify_limbs_mul_high_basecase(xs: &[Limb], ys: &[Limb], start: usize, out: &[Limb]) {
+    let len = xs.len() + ys.len();
+    assert!(out[..start.min(len)].iter().all(|&x| x == 0));
+    verify_limbs_mul_high(xs, ys, start, out);
+}
+
+fn verify_limbs_mul_high(xs: &[Limb], ys: &[Limb], start: usize, out: &[Limb]) {
+    let len = xs.len() + ys.len();
+    let approx = Natural::from_limbs_asc(&out[..len]);
+    let exact = Natural::from_limbs_asc(xs) * Natural::from_limbs_asc(ys);
+    assert!(approx <= exact);
+    let error_bound = Natural::from(start) << (u64::exact_from(start + 1) << Limb::LOG_WIDTH);
+    assert!(exact - approx <= error_bound);
+}
+
+#[test]
+fn test_limbs_mul_high_basecase() {
+    let test = |xs: &[Limb], ys: &[Limb], start: usize, out_after: &[Limb]| {
+        let mut out = vec![10; xs.len() + ys.len()];
+        limbs_mul_high_basecase(&mut out, xs, ys, start);
+        assert_eq!(out, out_after);
+        verify_limbs_mul_high_basecase(xs, ys, start, &out);
+    };
+    test(&[2], &[3], 0, &[6, 0]);
+    test(&[1, 2, 3], &[4, 5], 0, &[4, 13, 22, 15, 0]);
+    // the column-0 product 1 * 4 is left out
+    test(&[1, 2, 3], &[4, 5], 1, &[0, 13, 22, 15, 0]);
+    // columns 0 and 1 are left out
+    test(&[1, 2, 3], &[4, 5], 2, &[0, 0, 22, 15, 0]);
+    test(&[1, 2, 3], &[4, 5], 5, &[0, 0, 0, 0, 0]);
+    test(
+        &[Limb::MAX, Limb::MAX],
+        &[Limb::MAX, Limb::MAX],
+        1,
+        &[0, 2, Limb::MAX - 2, Limb::MAX],
+    );
+}
+
+#[test]
+fn limbs_mul_high_basecase_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_1().test_properties_with_config(&config, |(xs, ys)| {
+        let len = xs.len() + ys.len();
+        let mut out = vec![0; len];
+        limbs_mul_high_basecase(&mut out, &xs, &ys, 0);
+        assert_eq!(
+            Natural::from_limbs_asc(&out),
+            Natural::from_limbs_asc(&xs) * Natural::from_limbs_asc(&ys)
+        );
+        for start in [1, len >> 1, len] {
+            limbs_mul_high_basecase(&mut out, &xs, &ys, start);
+            verify_limbs_mul_high_basecase(&xs, &ys, start, &out);
+        }
+    });
+}
+
+#[test]
+fn test_limbs_mul_high() {
+    let test = |xs: &[Limb], ys: &[Limb], start: usize, out_after: &[Limb]| {
+        let mut out = vec![10; xs.len() + ys.len()];
+        limbs_mul_high(&mut out, xs, ys, start);
+        assert_eq!(out, out_after);
+        verify_limbs_mul_high(xs, ys, start, &out);
+    };
+    test(&[2], &[3], 0, &[6, 0]);
+    test(&[1, 2, 3], &[4, 5], 0, &[4, 13, 22, 15, 0]);
+    test(&[1, 2, 3], &[4, 5], 2, &[0, 0, 22, 15, 0]);
+    test(&[1, 2, 3], &[4, 5], 5, &[0, 0, 0, 0, 0]);
+
+    // Long enough for the short product
+    let xs = vec![Limb::MAX; 100];
+    let ys = vec![Limb::MAX; 80];
+    for start in [0, 1, 3, 50, 79, 99, 120, 179, 180] {
+        let mut out = vec![10; 180];
+        limbs_mul_high(&mut out, &xs, &ys, start);
+        verify_limbs_mul_high(&xs, &ys, start, &out);
+    }
+}
+
+#[test]
+#[should_panic]
+fn limbs_mul_high_fail_1() {
+    let mut out = vec![10; 3];
+    limbs_mul_high(&mut out, &[1, 2], &[], 1);
+}
+
+#[test]
+#[should_panic]
+fn limbs_mul_high_fail_2() {
+    let mut out = vec![10; 3];
+    limbs_mul_high(&mut out, &[1, 2], &[3, 4], 1);
+}
+
+#[test]
+fn limbs_mul_high_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 128);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_1().test_properties_with_config(&config, |(xs, ys)| {
+        let len = xs.len() + ys.len();
+        let mut out = vec![0; len];
+        limbs_mul_high(&mut out, &xs, &ys, 0);
+        assert_eq!(
+            Natural::from_limbs_asc(&out),
+            Natural::from_limbs_asc(&xs) * Natural::from_limbs_asc(&ys)
+        );
+        for start in [1, len >> 2, len >> 1, len - 1, len] {
+            limbs_mul_high(&mut out, &xs, &ys, start);
+            verify_limbs_mul_high(&xs, &ys, start, &out);
+
+            let mut out_alt = vec![0; len];
+            limbs_mul_high_basecase(&mut out_alt, &xs, &ys, start);
+            assert!(Natural::from_limbs_asc(&out_alt) <= Natural::from_limbs_asc(&out));
+        }
+    });
+}
+
 #[test]
 fn test_mul() {
     let test = |s, t, out| {
@@ -12220,6 +17888,21 @@ fn test_mul() {
         9079906337286599226335508424466369316294442004040440528589582239717042654541745348050157252\
         3448224036804997350851153108395928780441635856",
     );
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211455",
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025",
+    );
+    test(
+        "340282366920938463463374607431768211456",
+        "340282366920938463463374607431768211456",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "340282366920938463463374607431768211455",
+        "39402006196394479212279040100143613804963947181228130472524722419237033863643600344381704752381994682191283092455425",
+    );
 }
 
 #[test]
@@ -12253,6 +17936,10 @@ fn mul_properties() {
         let product_val_ref = x.clone() * &y;
         let product_ref_val = &x * y.clone();
         let product = &x * &y;
+        let mut out = x.clone();
+        x.mul_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, product);
         assert!(product_val_val.is_valid());
         assert!(product_val_ref.is_valid());
         assert!(product_ref_val.is_valid());
@@ -12344,3 +18031,177 @@ fn product_properties() {
         assert_eq!(Natural::product([x, y].into_iter()), product);
     });
 }
+
+#[test]
+fn test_mul_low() {
+    let test = |s, t, bits, out| {
+        let u = Natural::from_str(s).unwrap();
+        let v = Natural::from_str(t).unwrap();
+        let n = u.mul_low(&v, bits);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+        assert_eq!((u * v).mod_power_of_2(bits), n);
+    };
+    test("0", "123", 10, "0");
+    test("123", "456", 0, "0");
+    test("123", "456", 8, "24");
+    test("123", "456", 100, "56088");
+    test("1000000000000", "1000000000000", 64, "2003764205206896640");
+    test(
+        "12345678987654321",
+        "98765432123456789",
+        60,
+        "124825072910303109",
+    );
+    test(
+        "12345678987654321",
+        "98765432123456789",
+        100,
+        "1114093253831111233913332268933",
+    );
+}
+
+#[test]
+fn test_mul_high() {
+    let test = |s, t, bits, out| {
+        let u = Natural::from_str(s).unwrap();
+        let v = Natural::from_str(t).unwrap();
+        let n = u.mul_high(&v, bits);
+        assert!(n.is_valid());
+        let exact = Natural::from_str(out).unwrap();
+        assert!(n <= exact);
+        assert!(exact <= n + Natural::ONE);
+    };
+    test("0", "123", 10, "0");
+    test("123", "456", 0, "56088");
+    test("123", "456", 4, "3505");
+    test("123", "456", 100, "0");
+    test(
+        "12345678987654321",
+        "98765432123456789",
+        60,
+        "1057596996153660",
+    );
+    test("12345678987654321", "98765432123456789", 100, "961");
+    let x = Natural::from(3u32).pow(200);
+    let y = Natural::from(5u32).pow(200);
+    test(
+        &x.to_string(),
+        &y.to_string(),
+        500,
+        "5049565141419419229418901702025062527441040413491092740107300601013759384356986307348",
+    );
+    test(
+        &x.to_string(),
+        &y.to_string(),
+        700,
+        "3142352102160810247535846",
+    );
+    // Long enough for the short product
+    let x = Natural::from(3u32).pow(5000);
+    let y = Natural::from(5u32).pow(5000);
+    for bits in [64, 1000, 10000, 19000] {
+        let exact = ((&x * &y) >> bits).to_string();
+        test(&x.to_string(), &y.to_string(), bits, &exact);
+    }
+}
+
+#[test]
+fn test_mul_high_round() {
+    let test = |s, t, bits, rm, out| {
+        let u = Natural::from_str(s).unwrap();
+        let v = Natural::from_str(t).unwrap();
+        let n = u.mul_high_round(&v, bits, rm);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("123", "456", 4, RoundingMode::Down, "3505");
+    test("123", "456", 4, RoundingMode::Floor, "3505");
+    test("123", "456", 4, RoundingMode::Up, "3506");
+    test("123", "456", 4, RoundingMode::Ceiling, "3506");
+    test("123", "456", 4, RoundingMode::Nearest, "3506");
+    test("123", "456", 3, RoundingMode::Exact, "7011");
+    let x = Natural::from(3u32).pow(200).to_string();
+    let y = Natural::from(5u32).pow(200).to_string();
+    let floor =
+        "5049565141419419229418901702025062527441040413491092740107300601013759384356986307348";
+    let ceiling =
+        "5049565141419419229418901702025062527441040413491092740107300601013759384356986307349";
+    test(&x, &y, 500, RoundingMode::Down, floor);
+    test(&x, &y, 500, RoundingMode::Floor, floor);
+    test(&x, &y, 500, RoundingMode::Up, ceiling);
+    test(&x, &y, 500, RoundingMode::Ceiling, ceiling);
+    test(&x, &y, 500, RoundingMode::Nearest, ceiling);
+}
+
+#[test]
+#[should_panic]
+fn mul_high_round_fail() {
+    Natural::from(123u32).mul_high_round(&Natural::from(456u32), 4, RoundingMode::Exact);
+}
+
+#[test]
+fn mul_low_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 1024);
+    config.insert("mean_small_n", 1024);
+    natural_natural_unsigned_triple_gen_var_1().test_properties_with_config(
+        &config,
+        |(x, y, bits)| {
+            let low = x.mul_low(&y, bits);
+            assert!(low.is_valid());
+            assert_eq!((&x * &y).mod_power_of_2(bits), low);
+            assert_eq!(y.mul_low(&x, bits), low);
+            assert!(low.significant_bits() <= bits);
+        },
+    );
+}
+
+#[test]
+fn mul_high_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 1024);
+    config.insert("mean_small_n", 1024);
+    natural_natural_unsigned_triple_gen_var_1().test_properties_with_config(
+        &config,
+        |(x, y, bits)| {
+            let high = x.mul_high(&y, bits);
+            assert!(high.is_valid());
+            let exact = (&x * &y) >> bits;
+            assert!(high <= exact);
+            assert!(exact <= &high + Natural::ONE);
+            for rm in [
+                RoundingMode::Down,
+                RoundingMode::Up,
+                RoundingMode::Floor,
+                RoundingMode::Ceiling,
+                RoundingMode::Nearest,
+            ] {
+                let rounded = x.mul_high_round(&y, bits, rm);
+                assert!(rounded.is_valid());
+                assert_eq!((&x * &y).shr_round(bits, rm), rounded);
+            }
+        },
+    );
+
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8192);
+    config.insert("mean_small_n", 8192);
+    natural_natural_unsigned_triple_gen_var_1().test_properties_with_config(
+        &config,
+        |(x, y, bits)| {
+            let high = x.mul_high(&y, bits);
+            assert!(high.is_valid());
+            let exact = (&x * &y) >> bits;
+            assert!(high <= exact);
+            assert!(exact <= &high + Natural::ONE);
+            let rounded = x.mul_high_round(&y, bits, RoundingMode::Nearest);
+            assert_eq!((&x * &y).shr_round(bits, RoundingMode::Nearest), rounded);
+        },
+    );
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(x.mul_high(&y, 0), &x * &y);
+        assert_eq!(x.mul_high_round(&y, 0, RoundingMode::Exact), &x * &y);
+    });
+}
@@ -539,6 +539,10 @@ fn shr_properties() {
 
     natural_unsigned_pair_gen_var_4::<u32>().test_properties(|(n, u)| {
         let shifted = &n >> u;
+        let mut out = n.clone();
+        n.shr_to(u64::from(u), &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, shifted);
         let mut rug_n = rug::Integer::from(&n);
         rug_n >>= u;
         assert_eq!(Natural::exact_from(&rug_n), shifted);
@@ -491,7 +491,7 @@ fn test_limbs_square_to_out_toom_2() {
             ]),
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[18446744073642442752, u64::MAX, 0],
@@ -499,6 +499,25 @@ fn test_limbs_square_to_out_toom_2() {
             &[0x10000000000000, 0, 18446744073575333888, u64::MAX, 0, 0, 10, 10, 10, 10],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[18446744073642442752, Limb::MAX, 0],
+            &[10; 10],
+            &[
+                340282366918462583384808350481597333504,
+                340282366920938463426481119284483325952,
+                36893488147284885504,
+                340282366920938463463374607431768211454,
+                0,
+                0,
+                10,
+                10,
+                10,
+                10,
+            ],
+        );
+    }
 }
 
 #[cfg(feature = "32_bit_limbs")]
@@ -931,7 +950,7 @@ fn test_limbs_square_to_out_toom_3() {
             ],
         );
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         // (!TOOM3MAYBE_SQR_BASECASE || n >= SQR_TOOM2_THRESHOLD) &&
         //      (!TOOM3MAYBE_SQR_TOOM3 || n < SQR_TOOM3THRESHOLD)
@@ -1201,6 +1220,273 @@ fn test_limbs_square_to_out_toom_3() {
             ],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[
+                9525251150715707485,
+                8587763504821516080,
+                2117747772997939438,
+                3548585473389575775,
+                4368725143826266690,
+                4151422622588495985,
+                4202765536837222381,
+                6536868548778498226,
+                8537832607905834756,
+                9336293381477767433,
+                18090648844404510506,
+                3105525971724643379,
+                12707275375611860976,
+                3317865524960562713,
+                823982841074944760,
+                12143276137407678534,
+                11718343265209945098,
+                972615260809311500,
+                4497808493385741158,
+                4284234930706130439,
+                7584650555635583701,
+                13401700804872779083,
+                13056378070380119176,
+                16204947268987935954,
+                16754422922407328446,
+                3616382235390010996,
+                12081821858664337228,
+                12298806784194631642,
+                11068381938728473266,
+                1166551759858966293,
+                5303215576037036724,
+                1459955941298517816,
+                4011807711704072033,
+                7096373155148960903,
+                16682910161941435568,
+                8024381590924490417,
+                6949947117794717851,
+                9040317863012712195,
+                4785228307667500564,
+                13067737071940529766,
+                1789161767332960961,
+                10829540163630385583,
+                7172597173152077539,
+                5503961818523919125,
+                8087706384348918471,
+                4492802132769991717,
+                1188164630850319633,
+                13164198248699467318,
+                16183338424361636779,
+                9532503617554385117,
+                12467724616830286857,
+                8664003772679010381,
+                2864953899335878328,
+                8424900728671425836,
+                9730105501383490341,
+                13243619488513115315,
+                1992085995601070126,
+                4541056932031034892,
+                10048052926002006333,
+                4149742854488410708,
+                6604581724576852902,
+                8116872836838236679,
+                12909377853889042956,
+                2618412544527404589,
+                6240717678602672320,
+                17262717497935134385,
+                8933339373489336979,
+                4555255977252543496,
+                3187455824539014469,
+                1325520967350793711,
+                17902242471445880413,
+                14791679552918179638,
+                11505616209756266048,
+                18413169815441978527,
+                17159886807923826780,
+                8658827867630023515,
+                5729773240957150070,
+                17835287398020799335,
+                6255399748948477863,
+                18317413051795712550,
+                10021633350178308396,
+                9052815322626548310,
+                6892740207763967297,
+                14104401112852513805,
+                11421574096539340635,
+            ],
+            &[10; 172],
+            &[
+                90730409484210909589631952704085025225,
+                163601208412751006411148169295007717600,
+                114093840838092828660054297000405153260,
+                103975769802371690110117493520650677830,
+                148660089681050879061236384020276029144,
+                169151860898458492665047604955044474750,
+                182463840664913843244823106393748612235,
+                245304263518551480384287512259168798540,
+                991705262514621257239388414774840630,
+                78007958065821086442146097125096407341,
+                301220329937962886643800010311015971206,
+                221744653002241064651910960276883426693,
+                244557523897939127359141682492034719972,
+                290134212014614025280484654574401651691,
+                158342677754698935798160552415968024689,
+                36597745529339997725526609994234625555,
+                262110423461312082163748439253121034834,
+                153049163883697717121027273730372790420,
+                135877552756281640844565850963295034889,
+                263381722605904939714020797978641887922,
+                78941617610008994569368466797414767830,
+                39731386138994519170364045864117902077,
+                30378010308645467239242963404361079720,
+                280574285469075602365257854446027483742,
+                177897573889872102529306511751781995641,
+                195934297647092961690348615321666866740,
+                88557907232814482787816754497616386809,
+                255909196943861428010862833774535489542,
+                120780791975442379874142610773029560842,
+                142555061457127234992793526537047934365,
+                103856912110300237036315203612274249516,
+                95348920248941790561864019746417176379,
+                312349233023372658214787994354836947090,
+                141675693557621354489343667827721974805,
+                19267227153412329224747172362975327174,
+                151985224070091235400872541194156646905,
+                81179865281356607187877183417425354794,
+                240097727239023586122652308785060680591,
+                308691311581094770455167060586516802235,
+                329031808132307781795645142103523041992,
+                141156119969037690904295842060168638809,
+                324111020044429318240243799709586977940,
+                309665380417168444050677944890798021132,
+                41962083855159250028776139184308264335,
+                263016751436199542245249775395959576016,
+                64725946952522674570402309306547296830,
+                166108114682883935030782832775858346014,
+                251647904069966626859997160319916353524,
+                126099688848874735826115865478377945810,
+                163458092751051254693404046276214478459,
+                217492902445634828017786627467621905382,
+                290386980314171332748196064595769417379,
+                119759576360062833726796366740585754837,
+                134781699040181660733918514428097784298,
+                304043166778357666336150583448538231798,
+                320731472792690177821399012125364655729,
+                338551951759352662849895338703149537047,
+                83593930686808125629352160918003761939,
+                121901729751477841554893538562727282370,
+                307290450869852381311275140610975106649,
+                320654711749615728358550504723675475214,
+                317297090511214918385568960289152890809,
+                269890983195944991472541188018889768286,
+                97991147411398442920542611067899060709,
+                318723807727571112714439661378538000101,
+                212927424145841237080413242030412202660,
+                176446825804076682433357901034863663243,
+                141406005086907157957402215701945513587,
+                186819042904365058582772606728594169387,
+                36265199715174375890315972391542533387,
+                286800435810306739146275832763903690669,
+                313173494437475266854554285405430800517,
+                267970782892810159393220371108840788287,
+                134396949431199930782415175487466298581,
+                230844596859106639271224819366019891583,
+                207519514141978999332901318538345030067,
+                206486992943921725307035535432536950457,
+                286060404749099382803244883941235270706,
+                150631306292065795197210926770136474795,
+                329663070072612975002348978861911306607,
+                196688080360974587801428340156316545356,
+                221744217655843238135056161058329114922,
+                287758477525159305433536669351205518004,
+                315654680621555713377097139232623973445,
+                132663309343872193484982792214655143298,
+                214674972099736899981928709721271228363,
+                26339822145021822340816930077336688800,
+                187122978308531797537532931141008918616,
+                290293146666651970105081198282619714152,
+                230660419287048722511766328277318422851,
+                301873367477761856726559676732026244020,
+                178206828355561221573751075707089826665,
+                52109267396972065482189097275898852837,
+                151355591988881532825810159813369499168,
+                309357425501166614766230999194500630651,
+                130587761101090864612887873572470652579,
+                80709766076673249257261955683236776688,
+                319588208388196519117408782281171451717,
+                138437597766888626863433041383248162703,
+                229953469068080306787775247954634952283,
+                231968820706670622538801238080223782596,
+                95698695627301484600347323082822271446,
+                47616054219917345568865692577621773985,
+                239024480280481932357223099103483793969,
+                300528362164055444475181509283365059567,
+                334783087075773571058607129400823486435,
+                97707067301740174779437580544858608447,
+                208090313153559108685519811347467509299,
+                252046137380838561127092717898835678438,
+                29897934652458428831241411102189563849,
+                304075098172819930617714241502218467355,
+                317477434542754448138389680979144252868,
+                21793093491150467637192066279873472260,
+                91936691865510787361183401181559173557,
+                127802479020237287984670926693006315809,
+                122192730847812691094249741217620859876,
+                56507507609270913681094316830155002067,
+                199518399795167247184943110439347461870,
+                34987125931475103155733738019444541159,
+                202137402362409203536647136177831371717,
+                316605420152391962125014319616197714897,
+                105056452427418225286444140477292480408,
+                137187998218900778490169759506106271115,
+                45775231751162688075620729758910297821,
+                77521527450296328593408985577282319299,
+                85210453194111398889115274346809143887,
+                226338366388282162523906936193973816432,
+                174239332910731402428723352810293507838,
+                329381694604306851773418267175291987787,
+                198860627894907725128946034690268392610,
+                339371835829282248150455777672893054838,
+                152480601248137876088923714316420533635,
+                261958240413671429250893118438607608263,
+                262673561065723185647638854269651859668,
+                300106739561752463672295655846057271094,
+                93740363293871254309775895791676830944,
+                186891517569679643830351151218558760568,
+                179261908457575786894750238813033888070,
+                208249781251498491466293162305150306077,
+                74246442851292473053033187373902366860,
+                308064692269531686614444185337993594347,
+                259330635728696281256642924668668226558,
+                221350584015987756996573967450804528361,
+                213029123835231959495393497981856884389,
+                265685831593665462680345244820202852173,
+                76934710401089397651711060415445173064,
+                13678012820499731183474768272377791407,
+                117530751166182727529901052849773688223,
+                62397941466680804030113470441193796887,
+                170920917449905588292637894427474456299,
+                331892369249600939088019845481674599826,
+                154353399291109890630825542073005939072,
+                44665362839841410465852324987181696438,
+                216235240737735738812084077243351689076,
+                327433799733935203199802486842859403517,
+                132155457113087262327105900174496317994,
+                281523001469309491366054614734837571484,
+                13012949353663384361779536931981163745,
+                137924601745251183003039932816264661207,
+                64844585129446546089358310526932820036,
+                131466194170668672925123120401729760323,
+                337269044407217773727328879703815506191,
+                199147067241020240038041729111077715924,
+                145358328503295237642418231118388565290,
+                191522233427414625661170336043715056775,
+                60948380372371907702160382087501012415,
+                16103649853641010084130451804113793760,
+                322188924795513841992341953473869932351,
+                130452354842738455267563270240562203225,
+                0,
+                10,
+                10,
+            ],
+        );
+    }
 }
 
 #[cfg(feature = "32_bit_limbs")]
@@ -3576,6 +3862,10 @@ square_properties_helper!(
 fn sign_properties() {
     natural_gen().test_properties(|x| {
         let square = (&x).square();
+        let mut out = x.clone();
+        x.square_to(&mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, square);
         assert!(square.is_valid());
 
         let mut mut_x = x.clone();
@@ -791,6 +791,17 @@ fn test_sub_natural() {
     test("18446744073709551615", "18446744073709551615", "0");
     test("18446744073709551616", "18446744073709551615", "1");
     test("70734740290631708", "282942734368", "70734457347897340");
+    test("340282366920938463463374607431768211456", "1", "340282366920938463463374607431768211455");
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "1",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    );
+    test(
+        "231584178474632390847141970017375815706539969331281128078915168015826259279870",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    );
 }
 
 #[test]
@@ -1253,6 +1264,11 @@ fn sub_properties() {
         assert!(mut_x.is_valid());
         let diff = mut_x;
 
+        let mut out = y.clone();
+        x.sub_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, diff);
+
         let mut mut_x = x.clone();
         mut_x -= &y;
         assert!(mut_x.is_valid());
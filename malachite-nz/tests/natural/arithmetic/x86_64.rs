@@ -0,0 +1,222 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    large_type_gen_var_1, unsigned_vec_pair_gen_var_6, unsigned_vec_triple_gen_var_31,
+    unsigned_vec_unsigned_unsigned_triple_gen,
+    unsigned_vec_unsigned_vec_unsigned_triple_gen_var_12,
+};
+use malachite_nz::natural::arithmetic::add::{
+    limbs_add_same_length_to_out_portable, limbs_slice_add_same_length_in_place_left_portable,
+};
+use malachite_nz::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left_portable;
+use malachite_nz::natural::arithmetic::mul::limb::{
+    limbs_mul_limb_with_carry_to_out_portable, limbs_slice_mul_limb_with_carry_in_place_portable,
+};
+use malachite_nz::natural::arithmetic::sub::{
+    limbs_sub_same_length_in_place_left_portable, limbs_sub_same_length_to_out_portable,
+};
+use malachite_nz::natural::arithmetic::x86_64::{
+    limbs_add_same_length_to_out, limbs_mul_limb_with_carry_to_out,
+    limbs_slice_add_mul_limb_same_length_in_place_left, limbs_slice_add_same_length_in_place_left,
+    limbs_slice_mul_limb_with_carry_in_place, limbs_sub_same_length_in_place_left,
+    limbs_sub_same_length_to_out,
+};
+use malachite_nz::platform::Limb;
+
+// The kernels return `None` on CPUs that lack the instructions they need; there is nothing to
+// compare in that case, so the tests only check the kernels that ran.
+
+#[test]
+fn test_limbs_add_same_length_to_out() {
+    let test = |out_before: &[Limb], xs: &[Limb], ys: &[Limb]| {
+        let mut out = out_before.to_vec();
+        if let Some(carry) = limbs_add_same_length_to_out(&mut out, xs, ys) {
+            let mut out_alt = out_before.to_vec();
+            assert_eq!(
+                carry,
+                limbs_add_same_length_to_out_portable(&mut out_alt, xs, ys)
+            );
+            assert_eq!(out, out_alt);
+        }
+    };
+    test(&[10, 10, 10, 10], &[], &[]);
+    test(&[10, 10, 10, 10], &[2], &[3]);
+    test(&[10, 10, 10, 10], &[1, 1, 1], &[1, 2, 3]);
+    test(&[10, 10, 10, 10], &[u64::MAX, u64::MAX], &[1, 0]);
+    test(&[10, 10, 10, 10], &[u64::MAX; 3], &[u64::MAX; 3]);
+}
+
+#[test]
+fn test_limbs_sub_same_length_to_out() {
+    let test = |out_before: &[Limb], xs: &[Limb], ys: &[Limb]| {
+        let mut out = out_before.to_vec();
+        let borrow = limbs_sub_same_length_to_out(&mut out, xs, ys).unwrap();
+        let mut out_alt = out_before.to_vec();
+        assert_eq!(
+            borrow,
+            limbs_sub_same_length_to_out_portable(&mut out_alt, xs, ys)
+        );
+        assert_eq!(out, out_alt);
+    };
+    test(&[10, 10, 10, 10], &[], &[]);
+    test(&[10, 10, 10, 10], &[3], &[2]);
+    test(&[10, 10, 10, 10], &[0, 0, 1], &[1, 0, 0]);
+    test(&[10, 10, 10, 10], &[0, 0], &[1, 0]);
+}
+
+#[test]
+fn test_limbs_slice_add_mul_limb_same_length_in_place_left() {
+    let test = |xs_before: &[Limb], ys: &[Limb], z: Limb| {
+        let mut xs = xs_before.to_vec();
+        if let Some(carry) = limbs_slice_add_mul_limb_same_length_in_place_left(&mut xs, ys, z) {
+            let mut xs_alt = xs_before.to_vec();
+            assert_eq!(
+                carry,
+                limbs_slice_add_mul_limb_same_length_in_place_left_portable(&mut xs_alt, ys, z)
+            );
+            assert_eq!(xs, xs_alt);
+        }
+    };
+    test(&[], &[], 5);
+    test(&[123, 456], &[789, 123], 0);
+    test(&[123, 456], &[789, 123], 5);
+    test(&[u64::MAX; 3], &[u64::MAX; 3], u64::MAX);
+}
+
+#[test]
+fn limbs_add_same_length_to_out_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_31().test_properties_with_config(&config, |(out, xs, ys)| {
+        let mut out_kernel = out.clone();
+        if let Some(carry) = limbs_add_same_length_to_out(&mut out_kernel, &xs, &ys) {
+            let mut out_portable = out;
+            assert_eq!(
+                carry,
+                limbs_add_same_length_to_out_portable(&mut out_portable, &xs, &ys)
+            );
+            assert_eq!(out_kernel, out_portable);
+        }
+    });
+}
+
+#[test]
+fn limbs_slice_add_same_length_in_place_left_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_6().test_properties_with_config(&config, |(xs, ys)| {
+        let mut xs_kernel = xs.clone();
+        if let Some(carry) = limbs_slice_add_same_length_in_place_left(&mut xs_kernel, &ys) {
+            let mut xs_portable = xs;
+            assert_eq!(
+                carry,
+                limbs_slice_add_same_length_in_place_left_portable(&mut xs_portable, &ys)
+            );
+            assert_eq!(xs_kernel, xs_portable);
+        }
+    });
+}
+
+#[test]
+fn limbs_sub_same_length_to_out_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_31().test_properties_with_config(&config, |(out, xs, ys)| {
+        let mut out_kernel = out.clone();
+        let borrow = limbs_sub_same_length_to_out(&mut out_kernel, &xs, &ys).unwrap();
+        let mut out_portable = out;
+        assert_eq!(
+            borrow,
+            limbs_sub_same_length_to_out_portable(&mut out_portable, &xs, &ys)
+        );
+        assert_eq!(out_kernel, out_portable);
+    });
+}
+
+#[test]
+fn limbs_sub_same_length_in_place_left_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_6().test_properties_with_config(&config, |(xs, ys)| {
+        let mut xs_kernel = xs.clone();
+        let borrow = limbs_sub_same_length_in_place_left(&mut xs_kernel, &ys).unwrap();
+        let mut xs_portable = xs;
+        assert_eq!(
+            borrow,
+            limbs_sub_same_length_in_place_left_portable(&mut xs_portable, &ys)
+        );
+        assert_eq!(xs_kernel, xs_portable);
+    });
+}
+
+#[test]
+fn limbs_mul_limb_with_carry_to_out_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    large_type_gen_var_1().test_properties_with_config(&config, |(out, xs, y, carry)| {
+        let mut out_kernel = out.clone();
+        if let Some(carry_out) = limbs_mul_limb_with_carry_to_out(&mut out_kernel, &xs, y, carry) {
+            let mut out_portable = out;
+            assert_eq!(
+                carry_out,
+                limbs_mul_limb_with_carry_to_out_portable(&mut out_portable, &xs, y, carry)
+            );
+            assert_eq!(out_kernel, out_portable);
+        }
+    });
+}
+
+#[test]
+fn limbs_slice_mul_limb_with_carry_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_unsigned_triple_gen().test_properties_with_config(
+        &config,
+        |(xs, y, carry)| {
+            let mut xs_kernel = xs.clone();
+            if let Some(carry_out) =
+                limbs_slice_mul_limb_with_carry_in_place(&mut xs_kernel, y, carry)
+            {
+                let mut xs_portable = xs;
+                assert_eq!(
+                    carry_out,
+                    limbs_slice_mul_limb_with_carry_in_place_portable(&mut xs_portable, y, carry)
+                );
+                assert_eq!(xs_kernel, xs_portable);
+            }
+        },
+    );
+}
+
+#[test]
+fn limbs_slice_add_mul_limb_same_length_in_place_left_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_vec_unsigned_triple_gen_var_12().test_properties_with_config(
+        &config,
+        |(xs, ys, z)| {
+            let mut xs_kernel = xs.clone();
+            if let Some(carry) =
+                limbs_slice_add_mul_limb_same_length_in_place_left(&mut xs_kernel, &ys, z)
+            {
+                let mut xs_portable = xs;
+                assert_eq!(
+                    carry,
+                    limbs_slice_add_mul_limb_same_length_in_place_left_portable(
+                        &mut xs_portable,
+                        &ys,
+                        z
+                    )
+                );
+                assert_eq!(xs_kernel, xs_portable);
+            }
+        },
+    );
+}
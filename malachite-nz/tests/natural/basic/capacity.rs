@@ -0,0 +1,86 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_with_capacity() {
+    let test = |capacity| {
+        let x = Natural::with_capacity(capacity);
+        assert!(x.is_valid());
+        assert_eq!(x, 0);
+        assert!(x.capacity() >= capacity);
+    };
+    test(0);
+    test(1);
+    test(10);
+    test(100);
+}
+
+#[test]
+fn test_reserve() {
+    let test = |s, additional| {
+        let mut x = Natural::from_str(s).unwrap();
+        let old_x = x.clone();
+        x.reserve(additional);
+        assert!(x.is_valid());
+        assert_eq!(x, old_x);
+        assert!(x.capacity() >= usize::exact_from(x.limb_count()) + additional);
+    };
+    test("0", 0);
+    test("0", 100);
+    test("123", 10);
+    test("1000000000000000000000000", 0);
+    test("1000000000000000000000000", 100);
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let test = |s, additional| {
+        let mut x = Natural::from_str(s).unwrap();
+        let old_x = x.clone();
+        x.reserve(additional);
+        x.shrink_to_fit();
+        assert!(x.is_valid());
+        assert_eq!(x, old_x);
+        assert!(x.capacity() >= usize::exact_from(x.limb_count()));
+    };
+    test("0", 100);
+    test("123", 10);
+    test("1000000000000000000000000", 100);
+}
+
+#[test]
+fn test_buffer_kept_when_result_is_small() {
+    let x = Natural::from(10u32).pow(100);
+    let mut out = Natural::ZERO;
+    x.mul_to(&x, &mut out);
+    let capacity = out.capacity();
+
+    x.shr_to(1000, &mut out);
+    assert_eq!(out, 0);
+    assert!(out.capacity() >= capacity);
+
+    x.mul_to(&x, &mut out);
+    assert_eq!(out, Natural::from(10u32).pow(200));
+    assert_eq!(out.capacity(), capacity);
+}
+
+#[test]
+fn capacity_properties() {
+    natural_gen().test_properties(|x| {
+        assert!(x.capacity() >= usize::exact_from(x.limb_count()));
+
+        let mut mut_x = x.clone();
+        mut_x.reserve(10);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, x);
+        assert!(mut_x.capacity() >= usize::exact_from(x.limb_count()) + 10);
+
+        mut_x.shrink_to_fit();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, x);
+    });
+}
@@ -1,9 +1,15 @@
 use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
 use std::mem::size_of;
 
 #[test]
 fn test_size() {
     if size_of::<usize>() == 8 {
-        assert_eq!(size_of::<Natural>(), 24);
+        let expected_size = match size_of::<Limb>() {
+            4 => 32,
+            8 => 40,
+            _ => 48,
+        };
+        assert_eq!(size_of::<Natural>(), expected_size);
     }
 }
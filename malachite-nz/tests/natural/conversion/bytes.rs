@@ -0,0 +1,186 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::conversion::bytes::{
+    BytesOverflowError, Endianness, ImportExportOptions,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_to_bytes() {
+    let test = |s, le: &[u8]| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_bytes_le(), le);
+        let be = le.iter().rev().cloned().collect::<Vec<u8>>();
+        assert_eq!(x.to_bytes_be(), be);
+        assert_eq!(Natural::from_bytes_le(le), x);
+        assert_eq!(Natural::from_bytes_be(&be), x);
+    };
+    test("0", &[]);
+    test("1", &[1]);
+    test("255", &[255]);
+    test("256", &[0, 1]);
+    test("1000000000000", &[0, 16, 165, 212, 232]);
+    test("340282366920938463463374607431768211455", &[255; 16]);
+    test(
+        "340282366920938463463374607431768211456",
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+}
+
+#[test]
+fn test_from_bytes() {
+    assert_eq!(Natural::from_bytes_le(&[0, 0, 0]), 0);
+    assert_eq!(Natural::from_bytes_le(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0]), 1);
+    assert_eq!(
+        Natural::from_bytes_be(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0]),
+        256
+    );
+}
+
+#[test]
+fn test_to_bytes_padded() {
+    let x = Natural::from(0x1234u32);
+    assert_eq!(x.to_bytes_le_padded(4), Ok(vec![0x34, 0x12, 0, 0]));
+    assert_eq!(x.to_bytes_be_padded(4), Ok(vec![0, 0, 0x12, 0x34]));
+    assert_eq!(x.to_bytes_le_padded(2), Ok(vec![0x34, 0x12]));
+    assert_eq!(x.to_bytes_be_padded(1), Err(BytesOverflowError));
+    assert_eq!(Natural::ZERO.to_bytes_le_padded(0), Ok(vec![]));
+    assert_eq!(Natural::ZERO.to_bytes_be_padded(3), Ok(vec![0, 0, 0]));
+}
+
+#[test]
+fn test_export_import() {
+    let test = |s, word_size, word_order, endianness, nails, out: &[u8]| {
+        let x = Natural::from_str(s).unwrap();
+        let mut options = ImportExportOptions::default();
+        options.set_word_size(word_size);
+        options.set_word_order(word_order);
+        options.set_endianness(endianness);
+        options.set_nails(nails);
+        assert_eq!(options.get_word_size(), word_size);
+        assert_eq!(options.get_word_order(), word_order);
+        assert_eq!(options.get_endianness(), endianness);
+        assert_eq!(options.get_nails(), nails);
+        assert_eq!(x.export(&options), out);
+        assert_eq!(Natural::import(out, &options), x);
+    };
+    use Endianness::*;
+    test("0", 4, Big, Big, 0, &[]);
+    test("0", 4, Big, Big, 7, &[]);
+    test("1193046", 1, Little, Little, 0, &[0x56, 0x34, 0x12]);
+    test("1193046", 1, Big, Big, 0, &[0x12, 0x34, 0x56]);
+    test("1193046", 2, Little, Little, 0, &[0x56, 0x34, 0x12, 0x00]);
+    test("1193046", 2, Little, Big, 0, &[0x34, 0x56, 0x00, 0x12]);
+    test("1193046", 2, Big, Little, 0, &[0x12, 0x00, 0x56, 0x34]);
+    test("1193046", 2, Big, Big, 0, &[0x00, 0x12, 0x34, 0x56]);
+    test("1193046", 2, Big, Big, 4, &[0x01, 0x23, 0x04, 0x56]);
+    test("1193046", 1, Little, Little, 4, &[6, 5, 4, 3, 2, 1]);
+    test(
+        "1193046",
+        1,
+        Little,
+        Little,
+        7,
+        &[0, 1, 1, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 1],
+    );
+    test(
+        "1000000000000",
+        3,
+        Big,
+        Little,
+        0,
+        &[212, 232, 0, 0, 16, 165],
+    );
+}
+
+#[test]
+fn test_import_ignores_nails() {
+    let mut options = ImportExportOptions::default();
+    options.set_word_size(2);
+    options.set_endianness(Endianness::Big);
+    options.set_nails(4);
+    assert_eq!(
+        Natural::import(&[0xf4, 0x56, 0xf1, 0x23], &options),
+        0x123456
+    );
+}
+
+#[test]
+#[should_panic]
+fn import_fail() {
+    let mut options = ImportExportOptions::default();
+    options.set_word_size(2);
+    Natural::import(&[1, 2, 3], &options);
+}
+
+#[test]
+#[should_panic]
+fn set_word_size_fail() {
+    ImportExportOptions::default().set_word_size(0);
+}
+
+#[test]
+#[should_panic]
+fn set_nails_fail() {
+    let mut options = ImportExportOptions::default();
+    options.set_word_size(2);
+    options.set_nails(16);
+}
+
+#[test]
+fn bytes_properties() {
+    natural_gen().test_properties(|x| {
+        let le = x.to_bytes_le();
+        assert_eq!(le.last(), le.last().filter(|&&b| b != 0));
+        assert_eq!(Natural::from_bytes_le(&le), x);
+        let be = x.to_bytes_be();
+        assert_eq!(be.iter().rev().cloned().collect::<Vec<u8>>(), le);
+        assert_eq!(Natural::from_bytes_be(&be), x);
+
+        let len = le.len();
+        assert_eq!(x.to_bytes_le_padded(len).unwrap(), le);
+        let mut padded = x.to_bytes_le_padded(len + 3).unwrap();
+        assert_eq!(Natural::from_bytes_le(&padded), x);
+        padded.truncate(len);
+        assert_eq!(padded, le);
+        assert_eq!(x.to_bytes_be_padded(len + 3).unwrap()[3..], be[..]);
+        if len != 0 {
+            assert_eq!(x.to_bytes_le_padded(len - 1), Err(BytesOverflowError));
+        }
+
+        for word_size in [1, 2, 3, 8] {
+            for word_order in [Endianness::Little, Endianness::Big] {
+                for endianness in [Endianness::Little, Endianness::Big] {
+                    for nails in [0, 1, 7] {
+                        let mut options = ImportExportOptions::default();
+                        options.set_word_size(word_size);
+                        options.set_word_order(word_order);
+                        options.set_endianness(endianness);
+                        options.set_nails(nails);
+                        let exported = x.export(&options);
+                        assert_eq!(exported.len() % word_size, 0);
+                        assert_eq!(Natural::import(&exported, &options), x);
+                    }
+                }
+            }
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        let x = Natural::from_bytes_le(&bytes);
+        let mut trimmed = bytes.clone();
+        while trimmed.last() == Some(&0) {
+            trimmed.pop();
+        }
+        assert_eq!(x.to_bytes_le(), trimmed);
+        let reversed = bytes.iter().rev().cloned().collect::<Vec<u8>>();
+        assert_eq!(Natural::from_bytes_be(&reversed), x);
+        assert_eq!(Natural::import(&bytes, &ImportExportOptions::default()), x);
+    });
+}
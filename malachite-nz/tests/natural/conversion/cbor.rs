@@ -0,0 +1,131 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::conversion::cbor::{decode_cbor_head, encode_cbor_head, CborError};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_cbor_head() {
+    let test = |major_type, argument, out: &[u8]| {
+        let mut bytes = Vec::new();
+        encode_cbor_head(&mut bytes, major_type, argument);
+        assert_eq!(bytes, out);
+        assert_eq!(decode_cbor_head(out), Ok((major_type, argument, out.len())));
+    };
+    test(0, 0, &[0x00]);
+    test(0, 23, &[0x17]);
+    test(0, 24, &[0x18, 0x18]);
+    test(1, 255, &[0x38, 0xff]);
+    test(2, 256, &[0x59, 0x01, 0x00]);
+    test(4, 65536, &[0x9a, 0x00, 0x01, 0x00, 0x00]);
+    test(
+        6,
+        u64::MAX,
+        &[0xdb, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    );
+    // Non-preferred arguments are accepted
+    assert_eq!(decode_cbor_head(&[0x19, 0x00, 0x05]), Ok((0, 5, 3)));
+
+    assert_eq!(decode_cbor_head(&[]), Err(CborError::UnexpectedEnd));
+    assert_eq!(
+        decode_cbor_head(&[0x1a, 0x00]),
+        Err(CborError::UnexpectedEnd)
+    );
+    assert_eq!(
+        decode_cbor_head(&[0x1c]),
+        Err(CborError::ReservedAdditionalInfo(28))
+    );
+    assert_eq!(
+        decode_cbor_head(&[0x5e]),
+        Err(CborError::ReservedAdditionalInfo(30))
+    );
+    assert_eq!(decode_cbor_head(&[0x9f]), Err(CborError::IndefiniteLength));
+}
+
+#[test]
+#[should_panic]
+fn encode_cbor_head_fail() {
+    encode_cbor_head(&mut Vec::new(), 8, 0);
+}
+
+#[test]
+fn test_cbor() {
+    let test = |s, out: &[u8]| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_cbor(), out);
+        assert_eq!(Natural::from_cbor(out), Ok((x, out.len())));
+    };
+    test("0", &[0x00]);
+    test("10", &[0x0a]);
+    test("100", &[0x18, 0x64]);
+    test("1000000", &[0x1a, 0x00, 0x0f, 0x42, 0x40]);
+    test(
+        "18446744073709551615",
+        &[0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    );
+    test(
+        "18446744073709551616",
+        &[0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    );
+}
+
+#[test]
+fn test_from_cbor() {
+    // Bignums that don't use the preferred serialization are accepted
+    assert_eq!(
+        Natural::from_cbor(&[0xc2, 0x40]),
+        Ok((Natural::from(0u32), 2))
+    );
+    assert_eq!(
+        Natural::from_cbor(&[0xc2, 0x43, 0x00, 0x01, 0x00, 0xff]),
+        Ok((Natural::from(256u32), 5))
+    );
+    assert_eq!(Natural::from_cbor(&[0x20]), Err(CborError::Negative));
+    assert_eq!(Natural::from_cbor(&[0xc3, 0x40]), Err(CborError::Negative));
+    assert_eq!(
+        Natural::from_cbor(&[0xc4, 0x40]),
+        Err(CborError::WrongTag(4))
+    );
+    assert_eq!(
+        Natural::from_cbor(&[0xc2, 0x01]),
+        Err(CborError::WrongMajorType(0))
+    );
+    assert_eq!(
+        Natural::from_cbor(&[0xc2, 0x5f]),
+        Err(CborError::IndefiniteLength)
+    );
+    assert_eq!(
+        Natural::from_cbor(&[0xc2, 0x42, 0x01]),
+        Err(CborError::UnexpectedEnd)
+    );
+    assert_eq!(
+        Natural::from_cbor(&[0xf5]),
+        Err(CborError::WrongMajorType(7))
+    );
+}
+
+#[test]
+fn cbor_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_cbor();
+        assert_eq!(Natural::from_cbor(&bytes), Ok((x.clone(), bytes.len())));
+        if x < Natural::power_of_2(64) {
+            assert_eq!(bytes[0] >> 5, 0);
+        } else {
+            assert_eq!(bytes[0], 0xc2);
+            assert!(bytes.ends_with(&x.to_bytes_be()));
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        if let Ok((x, len)) = Natural::from_cbor(&bytes) {
+            assert!(len <= bytes.len());
+            assert_eq!(Natural::from_cbor(&x.to_cbor()), Ok((x, x.to_cbor().len())));
+        }
+    });
+}
@@ -0,0 +1,87 @@
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::conversion::der::DerError;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_to_der() {
+    let test = |s, out: &[u8]| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_der(), out);
+        assert_eq!(Natural::from_der(out), Ok(x.clone()));
+        assert_eq!(Natural::from_ber(out), Ok(x));
+    };
+    test("0", &[0x02, 0x01, 0x00]);
+    test("1", &[0x02, 0x01, 0x01]);
+    test("127", &[0x02, 0x01, 0x7f]);
+    test("128", &[0x02, 0x02, 0x00, 0x80]);
+    test("255", &[0x02, 0x02, 0x00, 0xff]);
+    test("256", &[0x02, 0x02, 0x01, 0x00]);
+    test(
+        "1000000000000",
+        &[0x02, 0x06, 0x00, 0xe8, 0xd4, 0xa5, 0x10, 0x00],
+    );
+}
+
+#[test]
+fn test_from_der_errors() {
+    assert_eq!(
+        Natural::from_der(&[0x02, 0x01, 0xff]),
+        Err(DerError::Negative)
+    );
+    assert_eq!(
+        Natural::from_ber(&[0x02, 0x01, 0x80]),
+        Err(DerError::Negative)
+    );
+    assert_eq!(
+        Natural::from_ber(&[0x02, 0x02, 0xff, 0xff]),
+        Err(DerError::Negative)
+    );
+    // Structural errors take precedence over the sign
+    assert_eq!(
+        Natural::from_der(&[0x02, 0x02, 0xff, 0xff]),
+        Err(DerError::NonMinimalContents)
+    );
+    assert_eq!(
+        Natural::from_der(&[0x02, 0x00]),
+        Err(DerError::EmptyContents)
+    );
+    assert_eq!(
+        Natural::from_der(&[0x30, 0x00]),
+        Err(DerError::WrongTag(0x30))
+    );
+    assert_eq!(
+        Natural::from_der(&[0x02, 0x81, 0x01, 0x05]),
+        Err(DerError::NonMinimalLength)
+    );
+    assert_eq!(
+        Natural::from_ber(&[0x02, 0x81, 0x01, 0x05]),
+        Ok(Natural::from(5u32))
+    );
+}
+
+#[test]
+fn der_properties() {
+    natural_gen().test_properties(|x| {
+        let der = x.to_der();
+        assert_eq!(Natural::from_der(&der), Ok(x.clone()));
+        assert_eq!(Natural::from_ber(&der), Ok(x.clone()));
+        assert_eq!(Integer::from(&x).to_der(), der);
+        assert_eq!(Integer::from_der(&der), Ok(Integer::from(x)));
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        match (Natural::from_ber(&bytes), Integer::from_ber(&bytes)) {
+            (Ok(x), Ok(y)) => assert_eq!(x, y),
+            (Err(DerError::Negative), Ok(y)) => assert!(y < 0),
+            (Err(e), Err(f)) => assert_eq!(e, f),
+            _ => panic!(),
+        }
+    });
+}
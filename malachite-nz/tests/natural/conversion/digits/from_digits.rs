@@ -816,7 +816,7 @@ fn test_limbs_from_digits_small_base() {
         // out_len_lo == 0 in limbs_from_digits_small_base_divide_and_conquer
         test(&[10; 739], &[0; 7100], 10, &[0; 369]);
     }
-    #[cfg(not(feature = "32_bit_limbs"))]
+    #[cfg(not(any(feature = "32_bit_limbs", feature = "128_bit_limbs")))]
     {
         test(
             &[
@@ -882,6 +882,66 @@ fn test_limbs_from_digits_small_base() {
             ],
         );
     }
+    #[cfg(feature = "128_bit_limbs")]
+    {
+        test(
+            &[
+                424216987022722268,
+                8103283177193628673,
+                4470414499436387861,
+                13801449255692046935,
+                9547961461378503966,
+                5606458381418993750,
+                17091516772434375724,
+                11977748006393229058,
+                6812658461074707191,
+                12015828441561040006,
+            ],
+            &[
+                51, 153, 87, 70, 104, 138, 111, 23, 85, 119, 176, 8, 65, 118, 3, 156, 55, 121, 87,
+                162,
+            ],
+            182,
+            &[172935954107444052258296489466683081672, 1331096],
+        );
+        test(
+            &[
+                5518555633093191759,
+                12836059490899113620,
+                12661099089298342811,
+                1992484047621263128,
+                3777109748949388481,
+                4126346840817077875,
+                7608898082196224717,
+                6664909716221346590,
+                679023819841892642,
+            ],
+            &[41, 16, 48, 34, 6, 51, 35, 56, 47, 44, 44, 20, 51, 39, 36, 33, 4, 14, 25, 3],
+            61,
+            &[344316296641319613082945412961433787],
+        );
+        test(
+            &[10; 10],
+            &[
+                2, 3, 0, 1, 0, 1, 0, 2, 0, 0, 1, 2, 3, 2, 0, 0, 3, 0, 3, 1, 3, 2, 4, 4, 2, 1, 0, 1,
+                1, 3, 1, 2, 2, 4, 1, 4, 1, 0, 2, 3, 1, 1, 4, 4, 0, 3, 3, 3, 0, 0, 0, 3, 1, 0, 1, 1,
+                3, 1, 1, 0, 3, 3, 2, 4, 4, 4, 3, 4, 2, 0, 1, 1, 3, 4, 4, 3, 0, 4, 3, 0, 2, 2, 3, 4,
+                3, 0, 4, 2, 2, 0, 3, 4, 0, 1, 0, 4, 3, 1, 1, 2, 1, 2, 0, 1, 3, 0, 2, 2, 1, 1, 2, 2,
+                2, 0, 4, 3, 3, 3, 1, 2, 2, 2, 2, 0, 1, 0, 2, 4, 4, 4, 1, 3, 2, 2, 0, 1, 3, 1, 1, 4,
+                1, 3, 4, 2, 3, 0, 1, 2, 2, 0, 0, 4, 1, 1, 1, 3, 1, 3, 0, 2, 0, 2, 0, 2, 1, 1, 2, 4,
+                0, 3, 2, 3, 0, 4, 3, 1, 2, 4, 4, 1, 3, 3, 3, 4, 2, 0, 3, 0, 3, 4, 3, 1, 4, 3, 3, 2,
+                2, 1, 1, 1, 0, 3, 2, 0, 4, 1, 1, 1, 0, 3, 0, 3, 4, 4, 4, 4, 4, 0, 4, 2, 1, 4, 4,
+            ],
+            5,
+            &[
+                154923871108653510595854493003045720510,
+                325097634257319882300974103084769858247,
+                163925639779890902157761794797935470258,
+                293819125574370842990807609740519249191,
+                28,
+            ],
+        );
+    }
 }
 
 fn limbs_from_digits_small_base_properties_helper<T: PrimitiveUnsigned>()
@@ -24,7 +24,7 @@ use std::panic::catch_unwind;
 use std::str::FromStr;
 
 fn verify_limbs_to_digits_small_base_basecase<
-    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >(
     original_out: &[T],
     len: usize,
@@ -144,7 +144,7 @@ fn test_limbs_to_digits_small_base_basecase() {
 }
 
 fn limbs_to_digits_small_base_basecase_properties_helper<
-    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >() {
     let mut config = GenConfig::new();
     config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
@@ -367,7 +367,7 @@ fn test_limbs_to_digits_small_base() {
 }
 
 fn limbs_to_digits_small_base_properties_helper<
-    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + PrimitiveUnsigned + Send,
 >() {
     let mut config = GenConfig::new();
     config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
@@ -531,7 +531,7 @@ fn limbs_to_digits_basecase_properties() {
 
 #[test]
 fn test_to_digits_asc_limb() {
-    fn test<T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned>(
+    fn test<T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send>(
         x: &str,
         base: Limb,
         out: &[T],
@@ -598,7 +598,7 @@ fn test_to_digits_asc_limb() {
 }
 
 fn to_digits_asc_limb_fail_helper<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >()
 where
     Limb: Digits<T>,
@@ -616,7 +616,7 @@ fn to_digits_asc_limb_fail() {
 }
 
 fn to_digits_asc_limb_properties_helper<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >()
 where
     Limb: Digits<T> + SaturatingFrom<T>,
@@ -653,7 +653,7 @@ fn to_digits_asc_limb_properties() {
 
 #[test]
 fn test_to_digits_desc_limb() {
-    fn test<T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned>(
+    fn test<T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send>(
         x: &str,
         base: Limb,
         out: &[T],
@@ -702,7 +702,7 @@ fn test_to_digits_desc_limb() {
 }
 
 fn to_digits_desc_limb_fail_helper<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >()
 where
     Limb: Digits<T>,
@@ -720,7 +720,7 @@ fn to_digits_desc_limb_fail() {
 }
 
 fn to_digits_desc_limb_properties_helper<
-    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned,
+    T: for<'a> TryFrom<&'a Natural> + ConvertibleFrom<Limb> + PrimitiveUnsigned + Send,
 >()
 where
     Limb: Digits<T> + SaturatingFrom<T>,
@@ -1,6 +1,7 @@
 use malachite_base::num::arithmetic::traits::Parity;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, RoundingFrom};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::{
@@ -332,6 +333,40 @@ fn f64_rounding_from_natural_fail_3() {
     );
 }
 
+#[test]
+fn test_half_float_rounding_from_natural() {
+    let test = |n: &str, rm: RoundingMode, out: F16| {
+        assert_eq!(
+            NiceFloat(F16::rounding_from(&Natural::from_str(n).unwrap(), rm)),
+            NiceFloat(out)
+        );
+    };
+    test("0", RoundingMode::Exact, F16::ZERO);
+    test("2048", RoundingMode::Exact, F16::from(2048.0));
+    test("2049", RoundingMode::Floor, F16::from(2048.0));
+    test("2049", RoundingMode::Ceiling, F16::from(2050.0));
+    test("2049", RoundingMode::Nearest, F16::from(2048.0));
+    test("2051", RoundingMode::Nearest, F16::from(2052.0));
+    test("65504", RoundingMode::Exact, F16::MAX_FINITE);
+    test("65520", RoundingMode::Nearest, F16::MAX_FINITE);
+    test("65520", RoundingMode::Ceiling, F16::POSITIVE_INFINITY);
+    test("1000000", RoundingMode::Down, F16::MAX_FINITE);
+    test("1000000", RoundingMode::Up, F16::POSITIVE_INFINITY);
+
+    let test = |n: &str, rm: RoundingMode, out: BF16| {
+        assert_eq!(
+            NiceFloat(BF16::rounding_from(&Natural::from_str(n).unwrap(), rm)),
+            NiceFloat(out)
+        );
+    };
+    test("256", RoundingMode::Exact, BF16::from(256.0));
+    test("257", RoundingMode::Nearest, BF16::from(256.0));
+    test("257", RoundingMode::Up, BF16::from(258.0));
+    test("259", RoundingMode::Nearest, BF16::from(260.0));
+    test("1000000", RoundingMode::Floor, BF16::from(999424.0));
+    test("1000000", RoundingMode::Ceiling, BF16::from(1003520.0));
+}
+
 #[test]
 fn test_f32_try_from_natural() {
     let test = |n: &str, out: Result<f32, PrimitiveFloatFromNaturalError>| {
@@ -680,6 +715,8 @@ where
 #[test]
 fn float_rounding_from_natural_properties() {
     apply_fn_to_primitive_floats!(float_rounding_from_natural_properties_helper);
+    float_rounding_from_natural_properties_helper::<F16>();
+    float_rounding_from_natural_properties_helper::<BF16>();
 }
 
 fn float_try_from_natural_properties_helper<
@@ -730,6 +767,8 @@ where
 #[test]
 fn float_try_from_natural_properties() {
     apply_fn_to_primitive_floats!(float_try_from_natural_properties_helper);
+    float_try_from_natural_properties_helper::<F16>();
+    float_try_from_natural_properties_helper::<BF16>();
 }
 
 fn float_convertible_from_natural_properties_helper<
@@ -763,4 +802,6 @@ where
 #[test]
 fn float_convertible_from_natural_properties() {
     apply_fn_to_primitive_floats!(float_convertible_from_natural_properties_helper);
+    float_convertible_from_natural_properties_helper::<F16>();
+    float_convertible_from_natural_properties_helper::<BF16>();
 }
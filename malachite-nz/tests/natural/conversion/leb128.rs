@@ -0,0 +1,124 @@
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::conversion::leb128::Leb128Error;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_vec_gen};
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+#[test]
+fn test_uleb128() {
+    let test = |s, out: &[u8]| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_uleb128(), out);
+        let mut written = Vec::new();
+        assert_eq!(x.write_uleb128(&mut written).unwrap(), out.len());
+        assert_eq!(written, out);
+        assert_eq!(Natural::from_uleb128(out), Ok((x.clone(), out.len())));
+        let mut reader = out;
+        assert_eq!(Natural::read_uleb128(&mut reader).unwrap(), x);
+        assert!(reader.is_empty());
+    };
+    test("0", &[0x00]);
+    test("1", &[0x01]);
+    test("127", &[0x7f]);
+    test("128", &[0x80, 0x01]);
+    test("300", &[0xac, 0x02]);
+    test("16383", &[0xff, 0x7f]);
+    test("16384", &[0x80, 0x80, 0x01]);
+    test("624485", &[0xe5, 0x8e, 0x26]);
+    test(
+        "18446744073709551615",
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+    );
+    test(
+        "18446744073709551616",
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02],
+    );
+}
+
+#[test]
+fn test_from_uleb128_errors() {
+    assert_eq!(Natural::from_uleb128(&[]), Err(Leb128Error::UnexpectedEnd));
+    assert_eq!(
+        Natural::from_uleb128(&[0x80]),
+        Err(Leb128Error::UnexpectedEnd)
+    );
+    assert_eq!(
+        Natural::from_uleb128(&[0xff, 0xff]),
+        Err(Leb128Error::UnexpectedEnd)
+    );
+    assert_eq!(
+        Natural::from_uleb128(&[0x80, 0x00]),
+        Err(Leb128Error::Overlong)
+    );
+    assert_eq!(
+        Natural::from_uleb128(&[0xff, 0x80, 0x00]),
+        Err(Leb128Error::Overlong)
+    );
+    // Only the first encoding is checked
+    assert_eq!(
+        Natural::from_uleb128(&[0x05, 0x80, 0x00]),
+        Ok((Natural::from(5u32), 1))
+    );
+
+    let mut reader: &[u8] = &[0x80, 0x80];
+    assert_eq!(
+        Natural::read_uleb128(&mut reader).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+    let mut reader: &[u8] = &[0x80, 0x00];
+    assert_eq!(
+        Natural::read_uleb128(&mut reader).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn uleb128_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_uleb128();
+        assert!(!bytes.is_empty());
+        assert_eq!(Natural::from_uleb128(&bytes), Ok((x.clone(), bytes.len())));
+        assert!(bytes[..bytes.len() - 1].iter().all(|&b| b & 0x80 != 0));
+        assert_eq!(bytes.last().unwrap() & 0x80, 0);
+        assert!(bytes.len() == 1 || *bytes.last().unwrap() != 0);
+        for i in 0..bytes.len() {
+            assert_eq!(
+                Natural::from_uleb128(&bytes[..i]),
+                Err(Leb128Error::UnexpectedEnd)
+            );
+        }
+        let mut overlong = bytes.clone();
+        *overlong.last_mut().unwrap() |= 0x80;
+        overlong.push(0);
+        assert_eq!(Natural::from_uleb128(&overlong), Err(Leb128Error::Overlong));
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        let mut stream = Vec::new();
+        for x in &xs {
+            x.write_uleb128(&mut stream).unwrap();
+        }
+        let mut reader = &stream[..];
+        for x in &xs {
+            assert_eq!(Natural::read_uleb128(&mut reader).unwrap(), *x);
+        }
+        assert!(reader.is_empty());
+        let mut rest = &stream[..];
+        for x in &xs {
+            let (y, len) = Natural::from_uleb128(rest).unwrap();
+            assert_eq!(y, *x);
+            rest = &rest[len..];
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        if let Ok((x, len)) = Natural::from_uleb128(&bytes) {
+            assert_eq!(x.to_uleb128(), &bytes[..len]);
+        }
+    });
+}
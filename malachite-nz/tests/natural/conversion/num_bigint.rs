@@ -0,0 +1,65 @@
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use num::{BigInt, BigUint};
+use std::str::FromStr;
+
+#[test]
+fn test_from_biguint() {
+    let test = |s| {
+        let x = BigUint::from_str(s).unwrap();
+
+        let n = Natural::from(x.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), s);
+
+        let n = Natural::from(&x);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("4294967295");
+    test("4294967296");
+    test("1000000000000");
+    test("18446744073709551616");
+    test("1000000000000000000000000000000");
+}
+
+#[test]
+fn test_biguint_and_bigint_from_natural() {
+    let test = |s| {
+        let n = Natural::from_str(s).unwrap();
+
+        assert_eq!(BigUint::from(n.clone()).to_string(), s);
+        assert_eq!(BigUint::from(&n).to_string(), s);
+        assert_eq!(BigInt::from(n.clone()).to_string(), s);
+        assert_eq!(BigInt::from(&n).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("4294967295");
+    test("4294967296");
+    test("1000000000000");
+    test("18446744073709551616");
+    test("1000000000000000000000000000000");
+}
+
+#[test]
+fn num_bigint_conversion_properties() {
+    natural_gen().test_properties(|x| {
+        let big_x = BigUint::from(&x);
+        assert_eq!(BigUint::from(x.clone()), big_x);
+        assert_eq!(big_x.to_string(), x.to_string());
+        assert_eq!(big_x.bits(), x.significant_bits());
+
+        let y = Natural::from(&big_x);
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert_eq!(Natural::from(big_x), x);
+
+        let big_x = BigInt::from(&x);
+        assert_eq!(BigInt::from(x.clone()), big_x);
+        assert_eq!(big_x.to_string(), x.to_string());
+    });
+}
@@ -32,6 +32,32 @@ fn test_serde() {
     );
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(bincode::serialize(&x).unwrap(), out);
+        assert_eq!(bincode::deserialize::<Natural>(out).unwrap(), x);
+    };
+    test("0", &[0, 0, 0, 0, 0, 0, 0, 0]);
+    test("100", &[1, 0, 0, 0, 0, 0, 0, 0, 100]);
+    test(
+        "1000000000000",
+        &[5, 0, 0, 0, 0, 0, 0, 0, 0, 16, 165, 212, 232],
+    );
+    test("4294967296", &[5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "18446744073709551616",
+        &[9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+    // Trailing zero bytes are accepted
+    assert_eq!(
+        bincode::deserialize::<Natural>(&[3, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0]).unwrap(),
+        100
+    );
+    assert!(bincode::deserialize::<Natural>(&[3, 0, 0, 0, 0, 0, 0, 0, 100]).is_err());
+}
+
 #[test]
 fn serde_properties() {
     natural_gen().test_properties(|x| {
@@ -39,6 +65,10 @@ fn serde_properties() {
         assert_eq!(serde_json::from_str::<Natural>(&s).unwrap(), x);
         assert_eq!(serde_json::from_str::<Integer>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\"0123456789abcdefx"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Natural>(&bytes).unwrap(), x);
+        assert_eq!(bytes[8..], x.to_bytes_le()[..]);
     });
 
     string_gen().test_properties(|s| {
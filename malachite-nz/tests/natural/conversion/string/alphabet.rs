@@ -0,0 +1,209 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::string::alphabet::DigitAlphabet;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::{
+    FromStringWithAlphabet, PowerOf2Digits, ToStringBase, ToStringWithAlphabet,
+};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_vec_gen};
+use malachite_nz::natural::conversion::string::alphabet::{
+    bytes_from_string_with_alphabet, bytes_to_string_with_alphabet,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_gen;
+
+const ALPHABETS: [DigitAlphabet; 4] = [
+    DigitAlphabet::BASE58_BITCOIN,
+    DigitAlphabet::BASE62,
+    DigitAlphabet::BASE64_URL,
+    DigitAlphabet::CROCKFORD_BASE32,
+];
+
+#[test]
+fn test_to_string_with_alphabet() {
+    let test = |n: Natural, alphabet, out| {
+        assert_eq!(n.to_string_with_alphabet(alphabet), out);
+    };
+    test(Natural::from(0u32), DigitAlphabet::BASE58_BITCOIN, "1");
+    test(Natural::from(0u32), DigitAlphabet::BASE64_URL, "A");
+    test(Natural::from(57u32), DigitAlphabet::BASE58_BITCOIN, "z");
+    test(Natural::from(58u32), DigitAlphabet::BASE58_BITCOIN, "21");
+    test(
+        Natural::from(2u32).pow(100),
+        DigitAlphabet::BASE58_BITCOIN,
+        "2LJ7YLqvVc8DmhwPNw",
+    );
+    test(
+        Natural::from(10u32).pow(20),
+        DigitAlphabet::BASE62,
+        "1v973mBjywOu",
+    );
+    test(
+        Natural::from(2u32).pow(66) - Natural::from(1u32),
+        DigitAlphabet::BASE64_URL,
+        "___________",
+    );
+    test(
+        Natural::from(10u32).pow(30),
+        DigitAlphabet::CROCKFORD_BASE32,
+        "S7SCKK84CX7DX9000000",
+    );
+}
+
+#[test]
+fn test_from_string_with_alphabet() {
+    let test_ok = |alphabet, s, out| {
+        assert_eq!(
+            Natural::from_string_with_alphabet(alphabet, s)
+                .unwrap()
+                .to_string(),
+            out
+        );
+    };
+    test_ok(DigitAlphabet::BASE58_BITCOIN, "1", "0");
+    test_ok(DigitAlphabet::BASE58_BITCOIN, "1111z", "57");
+    test_ok(
+        DigitAlphabet::BASE58_BITCOIN,
+        "2LJ7YLqvVc8DmhwPNw",
+        "1267650600228229401496703205376",
+    );
+    test_ok(
+        DigitAlphabet::CROCKFORD_BASE32,
+        "s7sc-kk84-cx7d-x9oo-oooo",
+        "1000000000000000000000000000000",
+    );
+
+    let test_err = |alphabet, s, kind, index| {
+        assert_eq!(
+            Natural::from_string_with_alphabet(alphabet, s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err(DigitAlphabet::BASE62, "", ParseNumberErrorKind::Empty, 0);
+    test_err(
+        DigitAlphabet::BASE62,
+        "-1",
+        ParseNumberErrorKind::InvalidDigit,
+        0,
+    );
+    test_err(
+        DigitAlphabet::BASE58_BITCOIN,
+        "2LJ7YLqvVc8DmhwPNl",
+        ParseNumberErrorKind::InvalidDigit,
+        17,
+    );
+    test_err(
+        DigitAlphabet::CROCKFORD_BASE32,
+        "s7sc--kk84",
+        ParseNumberErrorKind::MisplacedSeparator,
+        5,
+    );
+}
+
+#[test]
+fn test_crockford_string_with_check() {
+    let test = |n: Natural, out| {
+        let s = n.to_crockford_string_with_check();
+        assert_eq!(s, out);
+        assert_eq!(Natural::from_crockford_string_with_check(&s), Ok(n));
+    };
+    test(Natural::from(0u32), "00");
+    test(Natural::from(36u32), "14U");
+    test(Natural::from(1234u32), "16JD");
+    test(Natural::from(10u32).pow(30), "S7SCKK84CX7DX90000001");
+
+    let test_err = |s, kind, index| {
+        assert_eq!(
+            Natural::from_crockford_string_with_check(s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err("", ParseNumberErrorKind::Empty, 0);
+    test_err("0", ParseNumberErrorKind::Empty, 0);
+    test_err("16J-", ParseNumberErrorKind::InvalidDigit, 3);
+    test_err("16J#", ParseNumberErrorKind::InvalidDigit, 3);
+    test_err("16U=", ParseNumberErrorKind::InvalidDigit, 2);
+    test_err("16JC", ParseNumberErrorKind::CheckSymbolMismatch, 3);
+    test_err("16JU", ParseNumberErrorKind::CheckSymbolMismatch, 3);
+}
+
+#[test]
+fn test_bytes_to_string_with_alphabet() {
+    let test = |bytes: &[u8], out| {
+        let alphabet = DigitAlphabet::BASE58_BITCOIN;
+        assert_eq!(bytes_to_string_with_alphabet(alphabet, bytes), out);
+        assert_eq!(
+            bytes_from_string_with_alphabet(alphabet, out).unwrap(),
+            bytes
+        );
+    };
+    test(&[], "");
+    test(&[0], "1");
+    test(&[0, 0, 0], "111");
+    test(&[57], "z");
+    test(&[0, 57], "1z");
+    test(&[0, 0, 1, 0], "115R");
+    test(b"hello world", "StV1DL6CwTryKyV");
+
+    assert_eq!(
+        bytes_from_string_with_alphabet(DigitAlphabet::BASE58_BITCOIN, "1I"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1))
+    );
+}
+
+#[test]
+fn alphabet_properties() {
+    natural_gen().test_properties(|n| {
+        for alphabet in ALPHABETS {
+            let s = n.to_string_with_alphabet(alphabet);
+            assert_eq!(
+                Natural::from_string_with_alphabet(alphabet, &s),
+                Ok(n.clone())
+            );
+            let zero = char::from(alphabet.digit_to_byte(0).unwrap());
+            assert_eq!(s.starts_with(zero), n == 0u32);
+            assert_eq!(
+                bytes_to_string_with_alphabet(alphabet, &n.to_power_of_2_digits_desc(8)),
+                if n == 0u32 { String::new() } else { s }
+            );
+        }
+        let base_36 = DigitAlphabet::new("0123456789abcdefghijklmnopqrstuvwxyz").case_insensitive();
+        assert_eq!(n.to_string_with_alphabet(base_36), n.to_string_base(36));
+        assert_eq!(
+            Natural::from_string_with_alphabet(base_36, &n.to_string_base_upper(36)),
+            Ok(n.clone())
+        );
+
+        let s = n.to_crockford_string_with_check();
+        assert_eq!(
+            Natural::from_crockford_string_with_check(&s.to_lowercase()),
+            Ok(n)
+        );
+    });
+
+    unsigned_gen::<Limb>().test_properties(|u| {
+        for alphabet in ALPHABETS {
+            assert_eq!(
+                Natural::from(u).to_string_with_alphabet(alphabet),
+                u.to_string_with_alphabet(alphabet)
+            );
+        }
+    });
+
+    unsigned_vec_gen::<u8>().test_properties(|bytes| {
+        for alphabet in ALPHABETS {
+            let s = bytes_to_string_with_alphabet(alphabet, &bytes);
+            assert_eq!(
+                bytes_from_string_with_alphabet(alphabet, &s),
+                Ok(bytes.clone())
+            );
+            let zero = alphabet.digit_to_byte(0).unwrap();
+            assert_eq!(
+                s.bytes().take_while(|&b| b == zero).count(),
+                bytes.iter().take_while(|&&b| b == 0).count()
+            );
+        }
+    });
+}
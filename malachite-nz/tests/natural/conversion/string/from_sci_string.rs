@@ -1,5 +1,8 @@
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
 use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, ToStringBase};
 use malachite_base::rounding_modes::RoundingMode;
@@ -375,6 +378,35 @@ pub fn test_from_sci_string_with_options() {
     test("-0.04", options, None);
 }
 
+#[test]
+pub fn test_try_from_sci_string_with_options() {
+    fn test(
+        s: &str,
+        options: FromSciStringOptions,
+        out: Result<&'static str, (ParseNumberErrorKind, usize)>,
+    ) {
+        assert_eq!(
+            Natural::try_from_sci_string_with_options(s, options),
+            out.map(|s| Natural::from_str(s).unwrap())
+                .map_err(|(kind, index)| ParseNumberError::new(kind, index))
+        );
+    }
+    let mut options = FromSciStringOptions::default();
+    test("1.5e30", options, Ok("1500000000000000000000000000000"));
+    test("", options, Err((ParseNumberErrorKind::Empty, 0)));
+    test(
+        "1.5e3.0",
+        options,
+        Err((ParseNumberErrorKind::InvalidDigit, 5)),
+    );
+    test("-1", options, Err((ParseNumberErrorKind::OutOfRange, 1)));
+    test("-0.4", options, Ok("0"));
+    options.set_rounding_mode(RoundingMode::Exact);
+    test("-0.4", options, Err((ParseNumberErrorKind::Inexact, 1)));
+    test("-1.5", options, Err((ParseNumberErrorKind::OutOfRange, 1)));
+    test("1.25e1", options, Err((ParseNumberErrorKind::Inexact, 0)));
+}
+
 fn from_sci_string_helper(s: &str) {
     if let Some(x) = Natural::from_sci_string(s) {
         assert!(x.is_valid());
@@ -406,6 +438,10 @@ fn from_sci_string_properties() {
 }
 
 fn from_sci_string_with_options_helper(s: &str, options: FromSciStringOptions) {
+    assert_eq!(
+        Natural::try_from_sci_string_with_options(s, options).ok(),
+        Natural::from_sci_string_with_options(s, options)
+    );
     if let Some(x) = Natural::from_sci_string_with_options(s, options) {
         assert!(x.is_valid());
         assert!(!s.ends_with('+'));
@@ -1,5 +1,9 @@
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
 use malachite_base::num::conversion::traits::{
-    ExactFrom, FromStringBase, ToStringBase, WrappingFrom,
+    ExactFrom, FromStringBase, FromStringWithOptions, ToStringBase, WrappingFrom,
 };
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::exhaustive::valid_digit_chars;
@@ -10,6 +14,7 @@ use malachite_base::test_util::generators::{
 };
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_gen;
 use num::{BigUint, Num};
 use rug;
 use std::collections::HashMap;
@@ -212,3 +217,139 @@ fn from_string_base_properties() {
         );
     });
 }
+
+fn lenient_options() -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_allow_underscore_separators(true);
+    options.set_allow_comma_separators(true);
+    options.set_allow_whitespace(true);
+    options.set_allow_prefixes(true);
+    options.set_allow_plus_sign(true);
+    options
+}
+
+// Inserts a separator between every group of three digits, counting from the right.
+fn group_digits(s: &str, separator: char) -> String {
+    let mut grouped = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if i != 0 && (s.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[test]
+fn test_from_str_errors() {
+    let test = |s, kind, index| {
+        assert_eq!(
+            Natural::from_str(s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test("", ParseNumberErrorKind::Empty, 0);
+    test("12A", ParseNumberErrorKind::InvalidDigit, 2);
+    test(" 10", ParseNumberErrorKind::InvalidDigit, 0);
+    test("1.0", ParseNumberErrorKind::InvalidDigit, 1);
+    test("1_000", ParseNumberErrorKind::InvalidDigit, 1);
+    test("-5", ParseNumberErrorKind::InvalidSign, 0);
+    test("-", ParseNumberErrorKind::InvalidSign, 0);
+    test("+5", ParseNumberErrorKind::InvalidSign, 0);
+}
+
+#[test]
+fn test_from_string_with_options() {
+    let test_ok = |s, options, n| {
+        assert_eq!(
+            Natural::from_string_with_options(s, options)
+                .unwrap()
+                .to_string(),
+            n
+        );
+    };
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    test_ok("123", default, "123");
+    test_ok(
+        "1_000_000_000_000_000_000_000",
+        lenient,
+        "1000000000000000000000",
+    );
+    test_ok("1,000,000", lenient, "1000000");
+    test_ok(
+        "  +0xffffffffffffffffffff\n",
+        lenient,
+        "1208925819614629174706175",
+    );
+    test_ok("0b1010", lenient, "10");
+    test_ok("0o777", lenient, "511");
+    let mut hex = default;
+    hex.set_base(16);
+    test_ok("ff", hex, "255");
+    test_ok("0b1", hex, "177");
+
+    let test_err = |s, options, kind, index| {
+        assert_eq!(
+            Natural::from_string_with_options(s, options),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err("", lenient, ParseNumberErrorKind::Empty, 0);
+    test_err("  ", lenient, ParseNumberErrorKind::Empty, 2);
+    test_err(" +", lenient, ParseNumberErrorKind::Empty, 2);
+    test_err("0x", lenient, ParseNumberErrorKind::Empty, 2);
+    test_err("0xfg", lenient, ParseNumberErrorKind::InvalidDigit, 3);
+    test_err("1 000", lenient, ParseNumberErrorKind::InvalidDigit, 1);
+    test_err("_1", lenient, ParseNumberErrorKind::MisplacedSeparator, 0);
+    test_err(
+        "1__000",
+        lenient,
+        ParseNumberErrorKind::MisplacedSeparator,
+        2,
+    );
+    test_err(
+        "1,000,",
+        lenient,
+        ParseNumberErrorKind::MisplacedSeparator,
+        5,
+    );
+    test_err(" -0", lenient, ParseNumberErrorKind::InvalidSign, 1);
+    test_err("++1", lenient, ParseNumberErrorKind::InvalidDigit, 1);
+}
+
+#[test]
+fn from_string_with_options_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        if let Ok(n) = Natural::from_str(&s) {
+            assert_eq!(
+                Natural::from_string_with_options(&s, lenient_options()),
+                Ok(n)
+            );
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        let s = n.to_string();
+        assert_eq!(Natural::from_str(&s), Ok(n.clone()));
+        let lenient = lenient_options();
+        assert_eq!(
+            Natural::from_string_with_options(&group_digits(&s, '_'), lenient),
+            Ok(n.clone())
+        );
+        assert_eq!(
+            Natural::from_string_with_options(&group_digits(&s, ','), lenient),
+            Ok(n.clone())
+        );
+        assert_eq!(
+            Natural::from_string_with_options(&format!(" +{:#x} ", n), lenient),
+            Ok(n.clone())
+        );
+        assert_eq!(
+            Natural::from_string_with_options(&format!("{:#b}", n), lenient),
+            Ok(n)
+        );
+    });
+}
@@ -1,7 +1,10 @@
 use itertools::repeat_n;
 use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::string::options::ToSciOptions;
 use malachite_base::num::conversion::string::to_string::BaseFmtWrapper as BaseBaseFmtWrapper;
-use malachite_base::num::conversion::traits::{FromStringBase, ToStringBase};
+use malachite_base::num::conversion::traits::{
+    ExactFrom, FromSciString, FromStringBase, ToSci, ToStringBase,
+};
 use malachite_base::strings::{
     string_is_subset, ToBinaryString, ToDebugString, ToLowerHexString, ToOctalString,
     ToUpperHexString,
@@ -1007,3 +1010,157 @@ fn to_string_base_upper_properties() {
         );
     });
 }
+
+#[test]
+pub fn test_fmt_flags() {
+    let x = Natural::from(123u32);
+    assert_eq!(format!("{:>8}", x), "     123");
+    assert_eq!(format!("{:<8}|", x), "123     |");
+    assert_eq!(format!("{:^8}", x), "  123   ");
+    assert_eq!(format!("{:*^8}", x), "**123***");
+    assert_eq!(format!("{:+}", x), "+123");
+    assert_eq!(format!("{:+08}", x), "+0000123");
+    assert_eq!(format!("{:>#12b}", x), "   0b1111011");
+    assert_eq!(format!("{:<#8o}|", x), "0o173   |");
+    assert_eq!(format!("{:^+#8x}", x), " +0x7b  ");
+    assert_eq!(format!("{:>8X}", x), "      7B");
+
+    let x = Natural::from_str("1000000000000").unwrap();
+    assert_eq!(format!("{:>16}", x), "   1000000000000");
+    assert_eq!(format!("{:+}", x), "+1000000000000");
+    assert_eq!(format!("{:_<#16x}", x), "0xe8d4a51000____");
+    assert_eq!(format!("{:>+#16X}", x), "   +0xE8D4A51000");
+    assert_eq!(format!("{:^10}", BaseFmtWrapper::new(&x, 36)), " cre66i9s ");
+}
+
+#[test]
+pub fn test_to_exp_string() {
+    fn test(u: &str, out: &str, out_upper: &str) {
+        let x = Natural::from_str(u).unwrap();
+        assert_eq!(format!("{:e}", x), out);
+        assert_eq!(format!("{:E}", x), out_upper);
+    }
+    test("0", "0e0", "0E0");
+    test("1", "1e0", "1E0");
+    test("10", "1e1", "1E1");
+    test("123", "1.23e2", "1.23E2");
+    test("1000", "1e3", "1E3");
+    test("1000000000000", "1e12", "1E12");
+    test(
+        "123456789012345678901234567890",
+        "1.2345678901234567890123456789e29",
+        "1.2345678901234567890123456789E29",
+    );
+
+    fn test_precision(u: &str, precision: usize, out: &str) {
+        let x = Natural::from_str(u).unwrap();
+        assert_eq!(format!("{:.*e}", precision, x), out);
+    }
+    test_precision("0", 0, "0e0");
+    test_precision("0", 3, "0.000e0");
+    test_precision("123", 0, "1e2");
+    test_precision("123", 1, "1.2e2");
+    test_precision("123", 5, "1.23000e2");
+    test_precision("125", 1, "1.2e2");
+    test_precision("135", 1, "1.4e2");
+    test_precision("999", 1, "1.0e3");
+    test_precision("1000000000000", 3, "1.000e12");
+    test_precision("123456789012345678901234567890", 4, "1.2346e29");
+    test_precision("999999999999999999999999999999", 10, "1.0000000000e30");
+
+    let x = Natural::from(123u32);
+    assert_eq!(format!("{:10e}", x), "    1.23e2");
+    assert_eq!(format!("{:<10e}|", x), "1.23e2    |");
+    assert_eq!(format!("{:010e}", x), "00001.23e2");
+    assert_eq!(format!("{:+.1E}", x), "+1.2E2");
+}
+
+#[test]
+fn to_exp_string_properties() {
+    natural_gen().test_properties(|x| {
+        let s = format!("{:e}", x);
+        assert_eq!(format!("{:E}", x), s.to_uppercase());
+        assert_eq!(Natural::from_sci_string(&s).unwrap(), x);
+        let significand = s.split('e').next().unwrap();
+        assert!(!significand.ends_with('0') || significand == "0");
+    });
+
+    natural_unsigned_pair_gen_var_4().test_properties(|(x, precision)| {
+        let s = format!("{:.*e}", precision, x);
+        let significand = s.split('e').next().unwrap();
+        assert_eq!(
+            significand.chars().filter(char::is_ascii_digit).count(),
+            precision + 1
+        );
+        let mut options = ToSciOptions::default();
+        options.set_precision(u64::exact_from(precision) + 1);
+        assert_eq!(
+            Natural::from_sci_string(&s).unwrap(),
+            Natural::from_sci_string(&x.to_sci_with_options(options).to_string()).unwrap()
+        );
+    });
+
+    unsigned_pair_gen_var_2::<Limb, usize>().test_properties(|(x, precision)| {
+        let n = Natural::from(x);
+        assert_eq!(format!("{:e}", n), format!("{:e}", x));
+        assert_eq!(
+            format!("{:.*e}", precision, n),
+            format!("{:.*e}", precision, x)
+        );
+        assert_eq!(
+            format!("{:>+width$E}", n, width = precision),
+            format!("{:>+width$E}", x, width = precision)
+        );
+    });
+}
+
+#[test]
+fn fmt_flags_properties() {
+    natural_unsigned_pair_gen_var_4().test_properties(|(x, width)| {
+        let s = x.to_string();
+        assert_eq!(
+            format!("{:>width$}", x, width = width),
+            format!("{:>width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:*<width$}", x, width = width),
+            format!("{:*<width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:^width$}", x, width = width),
+            format!("{:^width$}", s, width = width)
+        );
+        assert_eq!(
+            format!("{:>+width$}", x, width = width),
+            format!("{:>width$}", format!("+{}", s), width = width)
+        );
+        assert_eq!(
+            format!("{:^#width$x}", x, width = width),
+            format!("{:^width$}", format!("{:#x}", x), width = width)
+        );
+    });
+
+    unsigned_pair_gen_var_2::<Limb, usize>().test_properties(|(x, width)| {
+        let n = Natural::from(x);
+        assert_eq!(
+            format!("{:>width$}", n, width = width),
+            format!("{:>width$}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:+width$}", n, width = width),
+            format!("{:+width$}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:<#width$b}", n, width = width),
+            format!("{:<#width$b}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:^+#width$o}", n, width = width),
+            format!("{:^+#width$o}", x, width = width)
+        );
+        assert_eq!(
+            format!("{:_>#width$X}", n, width = width),
+            format!("{:_>#width$X}", x, width = width)
+        );
+    });
+}
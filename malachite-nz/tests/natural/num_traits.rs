@@ -0,0 +1,168 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_pair_gen, natural_pair_gen_var_5, natural_unsigned_pair_gen_var_4,
+    natural_unsigned_pair_gen_var_7,
+};
+use num::integer::Roots;
+use num::traits::{CheckedDiv, FromPrimitive, Num, ToPrimitive};
+use num::BigUint;
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Result<&str, ParseNumberError>| {
+        let result = <Natural as Num>::from_str_radix(s, radix).map(|n| {
+            assert!(n.is_valid());
+            n.to_string()
+        });
+        assert_eq!(result, out.map(ToString::to_string));
+    };
+    test("0", 10, Ok("0"));
+    test("123", 10, Ok("123"));
+    test("+123", 10, Ok("123"));
+    test("1_000_000", 10, Ok("1000000"));
+    test("ff", 16, Ok("255"));
+    test("FF", 16, Ok("255"));
+    test("zz", 36, Ok("1295"));
+    test("101", 2, Ok("5"));
+    test(
+        "",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0)),
+    );
+    test(
+        "-1",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidSign, 0)),
+    );
+    test(
+        "1__0",
+        10,
+        Err(ParseNumberError::new(
+            ParseNumberErrorKind::MisplacedSeparator,
+            2,
+        )),
+    );
+    test(
+        "12",
+        2,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1)),
+    );
+}
+
+#[test]
+fn test_num_integer() {
+    let test = |x: u64, y: u64, q: u64, r: u64, gcd: u64, lcm: u64| {
+        let x = Natural::from(x);
+        let y = Natural::from(y);
+        assert_eq!(num::Integer::div_floor(&x, &y), q);
+        assert_eq!(num::Integer::mod_floor(&x, &y), r);
+        assert_eq!(
+            num::Integer::div_rem(&x, &y),
+            (Natural::from(q), Natural::from(r))
+        );
+        assert_eq!(
+            num::Integer::div_mod_floor(&x, &y),
+            (Natural::from(q), Natural::from(r))
+        );
+        assert_eq!(num::Integer::gcd(&x, &y), gcd);
+        assert_eq!(num::Integer::lcm(&x, &y), lcm);
+        assert_eq!(num::Integer::is_multiple_of(&x, &y), r == 0);
+    };
+    test(0, 1, 0, 0, 1, 0);
+    test(1, 1, 1, 0, 1, 1);
+    test(23, 10, 2, 3, 1, 230);
+    test(24, 18, 1, 6, 6, 72);
+    test(1000000000, 7, 142857142, 6, 1, 7000000000);
+}
+
+#[test]
+fn test_to_and_from_primitive() {
+    assert_eq!(Natural::from(123u32).to_i8(), Some(123));
+    assert_eq!(Natural::from(300u32).to_u8(), None);
+    assert_eq!(Natural::from(u64::MAX).to_u64(), Some(u64::MAX));
+    assert_eq!(Natural::from(u64::MAX).to_i64(), None);
+    assert_eq!(Natural::from(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(Natural::from(123u32).to_f32(), Some(123.0));
+
+    assert_eq!(Natural::from_i64(-1), None);
+    assert_eq!(Natural::from_i64(123), Some(Natural::from(123u32)));
+    assert_eq!(
+        Natural::from_u128(u128::MAX),
+        Some(Natural::from(u128::MAX))
+    );
+    assert_eq!(Natural::from_f64(0.9), Some(Natural::ZERO));
+    assert_eq!(Natural::from_f64(1.0e100).unwrap().to_f64(), Some(1.0e100));
+    assert_eq!(Natural::from_f64(f64::INFINITY), None);
+    assert_eq!(Natural::from_f32(-1.5), None);
+}
+
+#[test]
+fn num_traits_properties() {
+    natural_gen().test_properties(|x| {
+        let num_x = BigUint::from(&x);
+        assert_eq!(
+            <Natural as Num>::from_str_radix(&x.to_string(), 10).unwrap(),
+            x
+        );
+        assert_eq!(x.to_u64(), num_x.to_u64());
+        assert_eq!(x.to_i128(), num_x.to_i128());
+        assert_eq!(num::Integer::is_even(&x), num::Integer::is_even(&num_x));
+        assert_eq!(num::Integer::is_odd(&x), num::Integer::is_odd(&num_x));
+        assert_eq!(Natural::from(num_x.sqrt()), x.sqrt());
+        assert_eq!(Natural::from(num_x.cbrt()), x.cbrt());
+        if let Some(f) = x.to_f64() {
+            assert!(f.is_finite());
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        let num_x = BigUint::from(&x);
+        let num_y = BigUint::from(&y);
+        assert_eq!(
+            Natural::from(num::Integer::gcd(&num_x, &num_y)),
+            num::Integer::gcd(&x, &y)
+        );
+        assert_eq!(
+            Natural::from(num::Integer::lcm(&num_x, &num_y)),
+            num::Integer::lcm(&x, &y)
+        );
+        assert_eq!(
+            num::Integer::is_multiple_of(&num_x, &num_y),
+            num::Integer::is_multiple_of(&x, &y)
+        );
+        assert_eq!(
+            num_x.checked_div(&num_y).map(Natural::from),
+            x.checked_div(&y)
+        );
+    });
+
+    natural_pair_gen_var_5().test_properties(|(x, y)| {
+        let num_x = BigUint::from(&x);
+        let num_y = BigUint::from(&y);
+        let (q, r) = num::Integer::div_mod_floor(&num_x, &num_y);
+        assert_eq!(
+            num::Integer::div_mod_floor(&x, &y),
+            (Natural::from(q), Natural::from(r))
+        );
+        let (q, r) = num::Integer::div_rem(&num_x, &num_y);
+        assert_eq!(
+            num::Integer::div_rem(&x, &y),
+            (Natural::from(q), Natural::from(r))
+        );
+    });
+
+    natural_unsigned_pair_gen_var_4::<u32>().test_properties(|(x, exp)| {
+        assert_eq!(
+            Natural::from(num::traits::Pow::pow(BigUint::from(&x), exp)),
+            num::traits::Pow::pow(&x, exp)
+        );
+    });
+
+    natural_unsigned_pair_gen_var_7::<u32>().test_properties(|(x, n)| {
+        assert_eq!(Natural::from(BigUint::from(&x).nth_root(n)), x.nth_root(n));
+    });
+}
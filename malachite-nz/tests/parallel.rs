@@ -0,0 +1,188 @@
+#![cfg(feature = "parallel")]
+
+// These tests lower the threshold at which work is split across threads. The threshold is shared
+// by the whole process, so the tests live in their own test binary rather than in `lib.rs`. It is
+// only ever set to the same low value, so the tests can still run concurrently. Each
+// multithreaded result is compared against one computed without splitting the work, either by GMP
+// or by naive digit extraction.
+
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate rug;
+
+use malachite_base::num::arithmetic::traits::{DivExact, DivMod, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{Digits, ExactFrom};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_nz::natural::arithmetic::mul::fft::{
+    limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
+    limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::toom::{
+    limbs_mul_greater_to_out_toom_6h, limbs_mul_greater_to_out_toom_6h_scratch_len,
+    limbs_mul_greater_to_out_toom_8h, limbs_mul_greater_to_out_toom_8h_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::{
+    limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len,
+};
+use malachite_nz::natural::conversion::digits::general_digits::{
+    to_digits_asc_naive, to_digits_asc_naive_primitive,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::parallel::set_parallel_threshold;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_pair_gen_var_2, natural_pair_gen_var_5, natural_unsigned_pair_gen_var_1,
+    unsigned_vec_pair_gen_var_33, unsigned_vec_triple_gen_var_15, unsigned_vec_triple_gen_var_16,
+    unsigned_vec_triple_gen_var_2, unsigned_vec_triple_gen_var_60,
+};
+
+// Low enough that every recursive step of the tested algorithms is split across threads.
+const TEST_PARALLEL_THRESHOLD: usize = 1;
+
+fn limbs_mul_rug_helper(out: &[Limb], xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
+    let mut out = out.to_vec();
+    let product = Natural::exact_from(
+        &(rug::Integer::from(&Natural::from_limbs_asc(xs))
+            * rug::Integer::from(&Natural::from_limbs_asc(ys))),
+    );
+    let len = xs.len() + ys.len();
+    let mut limbs = product.into_limbs_asc();
+    limbs.resize(len, 0);
+    out[..len].copy_from_slice(&limbs);
+    out
+}
+
+macro_rules! mul_parallel_helper {
+    ($name: ident, $mul: ident, $scratch: ident, $gen: ident) => {
+        #[test]
+        fn $name() {
+            set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+            let mut config = GenConfig::new();
+            config.insert("mean_length_n", 2048);
+            config.insert("mean_stripe_n", 4 << Limb::LOG_WIDTH);
+            $gen().test_properties_with_config(&config, |(mut out, xs, ys)| {
+                let expected_out = limbs_mul_rug_helper(&out, &xs, &ys);
+                let mut scratch = vec![0; $scratch(xs.len(), ys.len())];
+                $mul(&mut out, &xs, &ys, &mut scratch);
+                assert_eq!(out, expected_out);
+            });
+        }
+    };
+}
+mul_parallel_helper!(
+    limbs_mul_greater_to_out_parallel,
+    limbs_mul_greater_to_out,
+    limbs_mul_greater_to_out_scratch_len,
+    unsigned_vec_triple_gen_var_2
+);
+mul_parallel_helper!(
+    limbs_mul_greater_to_out_toom_6h_parallel,
+    limbs_mul_greater_to_out_toom_6h,
+    limbs_mul_greater_to_out_toom_6h_scratch_len,
+    unsigned_vec_triple_gen_var_15
+);
+mul_parallel_helper!(
+    limbs_mul_greater_to_out_toom_8h_parallel,
+    limbs_mul_greater_to_out_toom_8h,
+    limbs_mul_greater_to_out_toom_8h_scratch_len,
+    unsigned_vec_triple_gen_var_16
+);
+
+#[test]
+fn limbs_mul_greater_to_out_fft_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 4096);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_60().test_properties_with_config(&config, |(mut out, xs, ys)| {
+        let expected_out = limbs_mul_rug_helper(&out, &xs, &ys);
+        let mut scratch = vec![0; limbs_mul_greater_to_out_fft_scratch_len(xs.len(), ys.len())];
+        limbs_mul_greater_to_out_fft(&mut out, &xs, &ys, &mut scratch);
+        assert_eq!(out, expected_out);
+    });
+}
+
+#[test]
+fn limbs_square_to_out_fft_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 4096);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_33().test_properties_with_config(&config, |(mut out, xs)| {
+        let expected_out = limbs_mul_rug_helper(&out, &xs, &xs);
+        let mut scratch = vec![0; limbs_square_to_out_fft_scratch_len(xs.len())];
+        limbs_square_to_out_fft(&mut out, &xs, &mut scratch);
+        assert_eq!(out, expected_out);
+    });
+}
+
+#[test]
+fn square_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 65536);
+    config.insert("mean_stripe_n", 4096);
+    natural_gen().test_properties_with_config(&config, |x| {
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::from(&x).square()),
+            (&x).square()
+        );
+    });
+}
+
+#[test]
+fn div_mod_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32768);
+    config.insert("mean_stripe_n", 256 << Limb::LOG_WIDTH);
+    natural_pair_gen_var_5().test_properties_with_config(&config, |(x, y)| {
+        let (q, r) = (&x).div_mod(&y);
+        let (rug_q, rug_r) = rug::Integer::from(&x).div_rem(rug::Integer::from(&y));
+        assert_eq!(Natural::exact_from(&rug_q), q);
+        assert_eq!(Natural::exact_from(&rug_r), r);
+        assert_eq!(&x % &y, r);
+        assert_eq!((&x - &r).div_exact(&y), q);
+    });
+}
+
+#[test]
+fn to_digits_asc_limb_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 2048);
+    config.insert("mean_stripe_n", 128);
+    natural_unsigned_pair_gen_var_1::<Limb, Limb>().test_properties_with_config(
+        &config,
+        |(x, base)| {
+            let mut digits = Vec::new();
+            to_digits_asc_naive_primitive(&mut digits, &x, base);
+            assert_eq!(x.to_digits_asc(&base), digits);
+        },
+    );
+}
+
+#[test]
+fn to_digits_asc_natural_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 2048);
+    config.insert("mean_stripe_n", 128);
+    natural_pair_gen_var_2().test_properties_with_config(&config, |(x, base)| {
+        let mut digits = Vec::new();
+        to_digits_asc_naive(&mut digits, &x, &base);
+        assert_eq!(x.to_digits_asc(&base), digits);
+    });
+}
+
+#[test]
+fn to_string_parallel() {
+    set_parallel_threshold(TEST_PARALLEL_THRESHOLD);
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 65536);
+    config.insert("mean_stripe_n", 4096);
+    natural_gen().test_properties_with_config(&config, |x| {
+        assert_eq!(x.to_string(), rug::Integer::from(&x).to_string());
+    });
+}
@@ -4,6 +4,7 @@ use malachite_base::num::arithmetic::traits::{
 };
 use malachite_base::num::basic::traits::Zero;
 use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 
@@ -248,6 +249,113 @@ impl<'a, 'b> Add<&'a Rational> for &'b Rational {
     }
 }
 
+// Writes `|x + y|` to `out`, where `x` and `y` are given by their signs and absolute values, and
+// returns whether `x + y` is non-negative.
+fn add_signed_naturals_to(
+    x_sign: bool,
+    x_abs: &Natural,
+    y_sign: bool,
+    y_abs: &Natural,
+    out: &mut Natural,
+) -> bool {
+    if x_sign == y_sign {
+        x_abs.add_to(y_abs, out);
+        x_sign || *out == 0u32
+    } else if x_abs >= y_abs {
+        x_abs.sub_to(y_abs, out);
+        x_sign || *out == 0u32
+    } else {
+        y_abs.sub_to(x_abs, out);
+        y_sign
+    }
+}
+
+// Writes `x + y` to `out`, where the signs of `x` and `y` are given by `x_sign` and `y_sign`
+// rather than by the `Rational`s themselves.
+pub(crate) fn add_signed_to(
+    x_sign: bool,
+    x: &Rational,
+    y_sign: bool,
+    y: &Rational,
+    out: &mut Rational,
+) {
+    if x.numerator == 0u32 {
+        out.clone_from(y);
+        out.sign = y_sign || y.numerator == 0u32;
+        return;
+    } else if y.numerator == 0u32 {
+        out.clone_from(x);
+        out.sign = x_sign;
+        return;
+    }
+    let mut gcd = (&x.denominator).gcd(&y.denominator);
+    if gcd == 1u32 {
+        out.sign = add_signed_naturals_to(
+            x_sign,
+            &(&x.numerator * &y.denominator),
+            y_sign,
+            &(&y.numerator * &x.denominator),
+            &mut out.numerator,
+        );
+        x.denominator.mul_to(&y.denominator, &mut out.denominator);
+    } else {
+        let reduced_x_d = (&x.denominator).div_exact(&gcd);
+        out.sign = add_signed_naturals_to(
+            x_sign,
+            &(&x.numerator * (&y.denominator).div_exact(&gcd)),
+            y_sign,
+            &(&y.numerator * &reduced_x_d),
+            &mut out.numerator,
+        );
+        gcd.gcd_assign(&out.numerator);
+        if gcd == 1u32 {
+            y.denominator.mul_to(&reduced_x_d, &mut out.denominator);
+        } else {
+            out.numerator.div_exact_assign(&gcd);
+            (&y.denominator)
+                .div_exact(gcd)
+                .mul_to(&reduced_x_d, &mut out.denominator);
+        }
+    }
+}
+
+impl Rational {
+    /// Adds two [`Rational`]s, taking both by reference and writing the sum to an existing
+    /// [`Rational`].
+    ///
+    /// The final products are written into the numerator and denominator of `out`, reusing their
+    /// buffers, although intermediate values are still allocated.
+    ///
+    /// $$
+    /// o \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{OneHalf, Zero};
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::ONE_HALF.add_to(&Rational::ONE_HALF, &mut out);
+    /// assert_eq!(out, 1);
+    ///
+    /// Rational::from_signeds(22, 7).add_to(&Rational::from_signeds(99, 100), &mut out);
+    /// assert_eq!(out.to_string(), "2893/700");
+    /// ```
+    #[inline]
+    pub fn add_to(&self, other: &Rational, out: &mut Rational) {
+        add_signed_to(self.sign, self, other.sign, other, out);
+    }
+}
+
 impl AddAssign<Rational> for Rational {
     /// Adds a [`Rational`] to a [`Rational`] in place, taking the [`Rational`] on the right-hand
     /// side by value.
@@ -0,0 +1,438 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Abs, DivRound, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+
+/// The $n$th term of a series that is evaluated by [`binary_splitting`].
+///
+/// The series is
+/// $$
+/// S = \sum_{n=n_0}^{n_1-1} \frac{a(n)}{b(n)} \prod_{k=n_0}^n \frac{p(k)}{q(k)},
+/// $$
+/// so $p(n)/q(n)$ is the ratio between consecutive products, and $a(n)/b(n)$ is a factor that
+/// only applies to the $n$th term. For hypergeometric-type series, $p$, $q$, $a$, and $b$ are
+/// polynomials in $n$ with integer coefficients; for example, $e = \sum_{n=0}^\infty 1/n!$ has
+/// $a(n) = b(n) = p(n) = 1$, $q(0) = 1$, and $q(n) = n$ for $n > 0$.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SeriesTerm {
+    /// $a(n)$, the numerator of the factor that only applies to the $n$th term.
+    pub a: Integer,
+    /// $b(n)$, the denominator of the factor that only applies to the $n$th term. It may not be
+    /// zero.
+    pub b: Integer,
+    /// $p(n)$, the numerator of the ratio between the $n$th product and the previous one.
+    pub p: Integer,
+    /// $q(n)$, the denominator of the ratio between the $n$th product and the previous one. It may
+    /// not be zero.
+    pub q: Integer,
+}
+
+impl SeriesTerm {
+    /// Creates a [`SeriesTerm`] from $a(n)$, $b(n)$, $p(n)$, and $q(n)$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `b` or `q` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::SeriesTerm;
+    ///
+    /// let term = SeriesTerm::new(
+    ///     Integer::from(1),
+    ///     Integer::from(3),
+    ///     Integer::from(-1),
+    ///     Integer::from(25),
+    /// );
+    /// assert_eq!(term.b, 3);
+    /// ```
+    pub fn new(a: Integer, b: Integer, p: Integer, q: Integer) -> SeriesTerm {
+        assert_ne!(b, 0);
+        assert_ne!(q, 0);
+        SeriesTerm { a, b, p, q }
+    }
+}
+
+/// The result of evaluating a range of a series by [`binary_splitting`].
+///
+/// For the terms from $n_0$ to $n_1 - 1$, this holds the four [`Integer`]s
+/// $P = \prod p(n)$, $Q = \prod q(n)$, $B = \prod b(n)$, and $T$, where $T/(BQ)$ is the sum of
+/// the terms. Results for adjacent ranges can be combined with
+/// [`append`](BinarySplitting::append), so a sum can be extended when more precision is needed
+/// without starting over.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BinarySplitting {
+    pub(crate) start: u64,
+    pub(crate) end: u64,
+    pub(crate) p: Integer,
+    pub(crate) q: Integer,
+    pub(crate) b: Integer,
+    pub(crate) t: Integer,
+}
+
+/// Evaluates the terms of a series with indices from `start` to `end - 1`, using binary
+/// splitting.
+///
+/// The series has the form described in [`SeriesTerm`], and `term` returns its $n$th term. The
+/// range is split in half recursively and the halves are combined using only [`Integer`]
+/// multiplications, so that the large multiplications near the top of the recursion are balanced
+/// and benefit from fast multiplication algorithms. No GCDs are computed until the sum is
+/// converted to a [`Rational`].
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^3 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits in
+/// the $a$, $b$, $p$, and $q$ values of the terms.
+///
+/// # Panics
+/// Panics if `start > end`, or if `term` returns a [`SeriesTerm`] whose `b` or `q` is zero.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+///
+/// // e = 1/0! + 1/1! + 1/2! + ...
+/// let e = binary_splitting(0, 10, |n| {
+///     SeriesTerm::new(
+///         Integer::from(1),
+///         Integer::from(1),
+///         Integer::from(1),
+///         Integer::from(n.max(1)),
+///     )
+/// });
+/// assert_eq!(e.sum().to_string(), "98641/36288");
+/// ```
+pub fn binary_splitting<F: Fn(u64) -> SeriesTerm>(
+    start: u64,
+    end: u64,
+    term: F,
+) -> BinarySplitting {
+    assert!(start <= end);
+    if start == end {
+        BinarySplitting::empty(start)
+    } else {
+        binary_splitting_helper(start, end, &term)
+    }
+}
+
+fn binary_splitting_helper<F: Fn(u64) -> SeriesTerm>(
+    start: u64,
+    end: u64,
+    term: &F,
+) -> BinarySplitting {
+    if end - start == 1 {
+        let SeriesTerm { a, b, p, q } = term(start);
+        assert_ne!(b, 0);
+        assert_ne!(q, 0);
+        BinarySplitting {
+            start,
+            end,
+            t: a * &p,
+            p,
+            q,
+            b,
+        }
+    } else {
+        let mid = start + ((end - start) >> 1);
+        let mut left = binary_splitting_helper(start, mid, term);
+        left.append(binary_splitting_helper(mid, end, term));
+        left
+    }
+}
+
+impl BinarySplitting {
+    // The result for an empty range of terms: the sum is 0 and the product is 1.
+    fn empty(index: u64) -> BinarySplitting {
+        BinarySplitting {
+            start: index,
+            end: index,
+            p: Integer::ONE,
+            q: Integer::ONE,
+            b: Integer::ONE,
+            t: Integer::ZERO,
+        }
+    }
+
+    /// Returns the index of the first term that has been evaluated.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// let s = binary_splitting(3, 10, |_| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(2))
+    /// });
+    /// assert_eq!(s.start(), 3);
+    /// ```
+    pub const fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the index after the last term that has been evaluated.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// let s = binary_splitting(3, 10, |_| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(2))
+    /// });
+    /// assert_eq!(s.end(), 10);
+    /// ```
+    pub const fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Extends a [`BinarySplitting`] with the terms evaluated by another [`BinarySplitting`], whose
+    /// range must begin where the first one's ends. The other [`BinarySplitting`] is taken by
+    /// value.
+    ///
+    /// If the first range is from $n_0$ to $n_1$ and the second from $n_1$ to $n_2$, the sum of
+    /// the result is
+    /// $$
+    /// S(n_0, n_2) = S(n_0, n_1) + \left ( \prod_{k=n_0}^{n_1-1} \frac{p(k)}{q(k)} \right )
+    /// S(n_1, n_2).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the [`Integer`]s held by `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other.start()` is not equal to `self.end()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// let term = |n: u64| {
+    ///     SeriesTerm::new(
+    ///         Integer::from(1),
+    ///         Integer::from(1),
+    ///         Integer::from(1),
+    ///         Integer::from(n.max(1)),
+    ///     )
+    /// };
+    /// let mut e = binary_splitting(0, 5, term);
+    /// e.append(binary_splitting(5, 10, term));
+    /// assert_eq!(e, binary_splitting(0, 10, term));
+    /// ```
+    pub fn append(&mut self, other: BinarySplitting) {
+        assert_eq!(self.end, other.start);
+        // T = B_2 Q_2 T_1 + B_1 P_1 T_2
+        self.t *= &other.b * &other.q;
+        self.t += &self.b * &self.p * other.t;
+        self.p *= other.p;
+        self.q *= other.q;
+        self.b *= other.b;
+        self.end = other.end;
+    }
+
+    /// Returns the exact sum of the evaluated terms, as a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the [`Integer`]s held by `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// // 1/2 + 1/4 + 1/8 + 1/16
+    /// let s = binary_splitting(0, 4, |_| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(2))
+    /// });
+    /// assert_eq!(s.sum().to_string(), "15/16");
+    /// ```
+    pub fn sum(&self) -> Rational {
+        Rational::from_integers_ref(&self.t, &(&self.b * &self.q))
+    }
+
+    /// Returns the product $\prod_{n=n_0}^{n_1-1} p(n)/q(n)$ over the evaluated terms, as a
+    /// [`Rational`].
+    ///
+    /// The next term of the series is this product times $a(n_1)p(n_1)/(b(n_1)q(n_1))$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the [`Integer`]s held by `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// let s = binary_splitting(0, 4, |_| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(2))
+    /// });
+    /// assert_eq!(s.product().to_string(), "1/16");
+    /// ```
+    pub fn product(&self) -> Rational {
+        Rational::from_integers_ref(&self.p, &self.q)
+    }
+
+    /// Returns the sum of the evaluated terms, rounded to a multiple of $2^{-k}$ according to a
+    /// specified rounding mode.
+    ///
+    /// This avoids the GCD computation that [`sum`](BinarySplitting::sum) needs, so it is the
+    /// cheaper way to get a numerical approximation.
+    ///
+    /// $f(S, k, \mathrm{Floor}) = \lfloor 2^kS \rfloor / 2^k$, and similarly for the other
+    /// rounding modes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec` plus the total number of
+    /// significant bits in the [`Integer`]s held by `self`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sum is not a multiple of $2^{-k}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    ///
+    /// // 1/3 + 1/9 + 1/27
+    /// let s = binary_splitting(0, 3, |_| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(3))
+    /// });
+    /// assert_eq!(s.sum().to_string(), "13/27");
+    /// assert_eq!(s.approximate_sum(4, RoundingMode::Floor).to_string(), "7/16");
+    /// assert_eq!(s.approximate_sum(4, RoundingMode::Ceiling).to_string(), "1/2");
+    /// ```
+    pub fn approximate_sum(&self, prec: u64, rm: RoundingMode) -> Rational {
+        Rational::from((&self.t << prec).div_round(&self.b * &self.q, rm)) >> prec
+    }
+
+    /// Returns an approximation of the sum of an infinite series, together with a bound on its
+    /// error, given an upper bound on the absolute value of the sum of the terms that haven't been
+    /// evaluated.
+    ///
+    /// The approximation is the sum of the evaluated terms rounded to the nearest multiple of
+    /// $2^{-k}$, so the returned error bound is $2^{-k-1}$ plus `tail_bound`. A tail bound for
+    /// series whose terms eventually decrease geometrically can be computed with
+    /// [`geometric_tail_bound`](BinarySplitting::geometric_tail_bound).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec` plus the total number of
+    /// significant bits in the [`Integer`]s held by `self`.
+    ///
+    /// # Panics
+    /// Panics if `tail_bound` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    /// use malachite_q::Rational;
+    ///
+    /// // 1 = 1/2 + 1/4 + 1/8 + ...
+    /// let term = |_: u64| {
+    ///     SeriesTerm::new(Integer::from(1), Integer::from(1), Integer::from(1), Integer::from(2))
+    /// };
+    /// let s = binary_splitting(0, 20, term);
+    /// let tail_bound = s.geometric_tail_bound(&term(20), &Rational::from_signeds(1, 2));
+    /// let (approx, error) = s.approximate_sum_with_error(30, &tail_bound);
+    /// assert!((approx - Rational::from(1)).abs() <= error);
+    /// ```
+    pub fn approximate_sum_with_error(
+        &self,
+        prec: u64,
+        tail_bound: &Rational,
+    ) -> (Rational, Rational) {
+        assert!(*tail_bound >= 0u32);
+        let approx = self.approximate_sum(prec, RoundingMode::Nearest);
+        let error = Rational::power_of_2(-i64::exact_from(prec) - 1) + tail_bound;
+        (approx, error)
+    }
+
+    /// Returns an upper bound on the absolute value of the sum of the terms from `self.end()`
+    /// onwards, assuming that each of those terms is at most $r$ times the previous one in
+    /// absolute value, where $r$ is `ratio_bound`.
+    ///
+    /// `next` must be the term with index `self.end()`. The bound is
+    /// $$
+    /// \frac{|t|}{1 - r},
+    /// $$
+    /// where $t$ is the value of that term. It is only valid if the assumption about the ratios
+    /// holds; for hypergeometric-type series, it usually does from some index onwards, which can
+    /// be found by examining $a(n+1)b(n)p(n+1)/(a(n)b(n+1)q(n+1))$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the [`Integer`]s held by `self` and `next`.
+    ///
+    /// # Panics
+    /// Panics if `ratio_bound` is negative or not less than 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+    /// use malachite_q::Rational;
+    ///
+    /// let term = |n: u64| {
+    ///     SeriesTerm::new(
+    ///         Integer::from(1),
+    ///         Integer::from(1),
+    ///         Integer::from(1),
+    ///         Integer::from(n.max(1)),
+    ///     )
+    /// };
+    /// // From n = 10 on, each term of the series for e is at most 1/11 of the previous one.
+    /// let e = binary_splitting(0, 10, term);
+    /// let bound = e.geometric_tail_bound(&term(10), &Rational::from_signeds(1, 11));
+    /// assert_eq!(bound.to_string(), "11/36288000");
+    /// ```
+    pub fn geometric_tail_bound(&self, next: &SeriesTerm, ratio_bound: &Rational) -> Rational {
+        assert!(*ratio_bound >= 0u32 && *ratio_bound < 1u32);
+        let next_term =
+            Rational::from_integers(&next.a * &next.p * &self.p, &next.b * &next.q * &self.q);
+        next_term.abs() / (Rational::ONE - ratio_bound)
+    }
+}
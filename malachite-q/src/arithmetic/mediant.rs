@@ -0,0 +1,48 @@
+use crate::Rational;
+use malachite_nz::integer::Integer;
+
+impl Rational {
+    /// Returns the mediant of two [`Rational`]s.
+    ///
+    /// If $x = a/b$ and $y = c/d$ are in lowest terms, with $b, d > 0$, their mediant is
+    /// $(a+c)/(b+d)$. If $x < y$, the mediant lies strictly between them. Mediants of neighbors
+    /// are how the Stern-Brocot tree and Farey sequences are built: if $bc - ad = 1$, the mediant
+    /// is the [`Rational`] between $x$ and $y$ with the smallest denominator.
+    ///
+    /// $$
+    /// f(a/b, c/d) = \frac{a+c}{b+d}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).mediant(&Rational::from_signeds(1, 2)).to_string(),
+    ///     "2/5"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 2).mediant(&Rational::from(1u32)).to_string(),
+    ///     "0"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).mediant(&Rational::from_signeds(2, 3)).to_string(),
+    ///     "1/2"
+    /// );
+    /// ```
+    pub fn mediant(&self, other: &Rational) -> Rational {
+        Rational::from_integers(
+            Integer::from_sign_and_abs_ref(self.sign, &self.numerator)
+                + Integer::from_sign_and_abs_ref(other.sign, &other.numerator),
+            Integer::from(&self.denominator + &other.denominator),
+        )
+    }
+}
@@ -6,6 +6,8 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// Exact evaluation of hypergeometric-type series by binary splitting.
+pub mod binary_splitting;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking
 /// the ceiling of a number.
@@ -41,6 +43,8 @@ pub mod log_base_2;
 /// and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// A function for computing the mediant of two [`Rational`](super::Rational)s.
+pub mod mediant;
 /// Multiplication of [`Rational`](super::Rational)s.
 pub mod mul;
 /// Negation of a [`Rational`](super::Rational).
@@ -60,6 +64,12 @@ pub mod power_of_2;
 /// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
 /// computing the reciprocal of a number.
 pub mod reciprocal;
+/// Implementations of [`Rem`](std::ops::Rem) and [`RemAssign`](std::ops::RemAssign), traits for
+/// finding the remainder of a truncating division.
+///
+/// These are always available; they don't depend on the `num-traits` feature, although
+/// `num_traits::Num` requires them.
+pub mod rem;
 /// Implementations of [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot), a
 /// trait for computing the root of a number, if the number is a perfect power.
 pub mod root;
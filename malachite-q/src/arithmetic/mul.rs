@@ -176,6 +176,60 @@ impl<'a, 'b> Mul<&'a Rational> for &'b Rational {
     }
 }
 
+impl Rational {
+    /// Multiplies two [`Rational`]s, taking both by reference and writing the product to an
+    /// existing [`Rational`].
+    ///
+    /// The final products are written into the numerator and denominator of `out`, reusing their
+    /// buffers. Intermediate values are only allocated when the inputs share a common factor.
+    ///
+    /// $$
+    /// o \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{OneHalf, Two, Zero};
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::ONE_HALF.mul_to(&Rational::TWO, &mut out);
+    /// assert_eq!(out, 1);
+    ///
+    /// Rational::from_signeds(22, 7).mul_to(&Rational::from_signeds(99, 100), &mut out);
+    /// assert_eq!(out.to_string(), "1089/350");
+    /// ```
+    pub fn mul_to(&self, other: &Rational, out: &mut Rational) {
+        if *self == 0u32 || *other == 0u32 {
+            out.clone_from(&Rational::ZERO);
+            return;
+        }
+        let g_1 = (&self.numerator).gcd(&other.denominator);
+        let g_2 = (&other.numerator).gcd(&self.denominator);
+        if g_1 == 1u32 && g_2 == 1u32 {
+            self.numerator.mul_to(&other.numerator, &mut out.numerator);
+            self.denominator
+                .mul_to(&other.denominator, &mut out.denominator);
+        } else {
+            (&self.numerator)
+                .div_exact(&g_1)
+                .mul_to(&(&other.numerator).div_exact(&g_2), &mut out.numerator);
+            (&other.denominator)
+                .div_exact(g_1)
+                .mul_to(&(&self.denominator).div_exact(g_2), &mut out.denominator);
+        }
+        out.sign = self.sign == other.sign;
+    }
+}
+
 impl MulAssign<Rational> for Rational {
     /// Multiplies a [`Rational`] by a [`Rational`] in place, taking the [`Rational`] on the
     /// right-hand side by value.
@@ -70,6 +70,42 @@ impl<'a> Pow<u64> for &'a Rational {
     }
 }
 
+impl Rational {
+    /// Raises a [`Rational`] to a power, taking the [`Rational`] by reference and writing the
+    /// result to an existing [`Rational`].
+    ///
+    /// The numerator and denominator of `out` reuse their buffers, so apart from scratch space,
+    /// this doesn't allocate unless the buffers need to grow.
+    ///
+    /// $o \gets x^n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::from_signeds(22, 7).pow_to(3, &mut out);
+    /// assert_eq!(out.to_string(), "10648/343");
+    ///
+    /// Rational::from_signeds(-22, 7).pow_to(3, &mut out);
+    /// assert_eq!(out.to_string(), "-10648/343");
+    /// ```
+    pub fn pow_to(&self, exp: u64, out: &mut Rational) {
+        self.numerator.pow_to(exp, &mut out.numerator);
+        self.denominator.pow_to(exp, &mut out.denominator);
+        out.sign = self.sign || exp.even();
+    }
+}
+
 impl PowAssign<u64> for Rational {
     /// Raises a [`Rational`] to a power in place.
     ///
@@ -0,0 +1,307 @@
+use crate::Rational;
+use std::ops::{Rem, RemAssign};
+
+// With |x| = a/b and |y| = c/d, the remainder of |x| divided by |y| is ((a * d) mod (b * c)) /
+// (b * d). The remainder takes the sign of x.
+fn rem_helper(x: &Rational, y: &Rational) -> Rational {
+    if *y == 0u32 {
+        panic!("division by zero");
+    }
+    Rational::from_sign_and_naturals(
+        x.sign,
+        (&x.numerator * &y.denominator) % (&x.denominator * &y.numerator),
+        &x.denominator * &y.denominator,
+    )
+}
+
+impl Rem<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder of the truncated quotient. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(7, 2) % Rational::from_signeds(1, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // -10 * -1/3 + 1/6 = 7/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(7, 2) % Rational::from_signeds(-1, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-7, 2) % Rational::from_signeds(1, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % Rational::from_signeds(1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        rem_helper(&self, &other)
+    }
+}
+
+impl<'a> Rem<&'a Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder of the truncated quotient. The remainder has the
+    /// same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(7, 2) % &Rational::from_signeds(1, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-7, 2) % &Rational::from_signeds(1, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Rational) -> Rational {
+        rem_helper(&self, other)
+    }
+}
+
+impl<'a> Rem<Rational> for &'a Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the
+    /// second by value and returning just the remainder of the truncated quotient. The remainder
+    /// has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(7, 2) % Rational::from_signeds(1, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-7, 2) % Rational::from_signeds(1, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        rem_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Rem<&'b Rational> for &'a Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder of the truncated quotient. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(7, 2) % &Rational::from_signeds(1, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-7, 2) % &Rational::from_signeds(1, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &'b Rational) -> Rational {
+        rem_helper(self, other)
+    }
+}
+
+impl RemAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value and replacing the first number by the remainder of the truncated
+    /// quotient. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// let mut x = Rational::from_signeds(7, 2);
+    /// x %= Rational::from_signeds(1, 3);
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// let mut x = Rational::from_signeds(-7, 2);
+    /// x %= Rational::from_signeds(1, 3);
+    /// assert_eq!(x.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Rational) {
+        *self = rem_helper(self, &other);
+    }
+}
+
+impl<'a> RemAssign<&'a Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference and replacing the first number by the remainder of the
+    /// truncated quotient. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$, and the quotient and remainder
+    /// would satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 10 * 1/3 + 1/6 = 7/2
+    /// let mut x = Rational::from_signeds(7, 2);
+    /// x %= &Rational::from_signeds(1, 3);
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// // -10 * 1/3 + -1/6 = -7/2
+    /// let mut x = Rational::from_signeds(-7, 2);
+    /// x %= &Rational::from_signeds(1, 3);
+    /// assert_eq!(x.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &'a Rational) {
+        *self = rem_helper(self, other);
+    }
+}
@@ -45,6 +45,51 @@ where
     }
 }
 
+impl Rational {
+    /// Left-shifts a [`Rational`] (multiplies it by a power of 2), taking it by reference and
+    /// writing the result to an existing [`Rational`].
+    ///
+    /// The numerator and denominator of `out` reuse their buffers, so this doesn't allocate
+    /// unless the buffers need to grow.
+    ///
+    /// $o \gets x2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$ (only if the buffers of `out` need to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::from_signeds(-22, 24).shl_to(2, &mut out);
+    /// assert_eq!(out.to_string(), "-11/3");
+    /// ```
+    pub fn shl_to(&self, bits: u64, out: &mut Rational) {
+        if *self == 0u32 {
+            out.clone_from(&Rational::ZERO);
+            return;
+        }
+        let denominator_zeros = self.denominator.trailing_zeros().unwrap();
+        if denominator_zeros >= bits {
+            out.numerator.clone_from(&self.numerator);
+            self.denominator.shr_to(bits, &mut out.denominator);
+        } else {
+            self.numerator
+                .shl_to(bits - denominator_zeros, &mut out.numerator);
+            self.denominator
+                .shr_to(denominator_zeros, &mut out.denominator);
+        }
+        out.sign = self.sign;
+    }
+}
+
 macro_rules! impl_shl_unsigned {
     ($t:ident) => {
         impl Shl<$t> for Rational {
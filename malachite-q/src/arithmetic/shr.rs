@@ -45,6 +45,50 @@ where
     }
 }
 
+impl Rational {
+    /// Right-shifts a [`Rational`] (divides it by a power of 2), taking it by reference and
+    /// writing the result to an existing [`Rational`].
+    ///
+    /// The numerator and denominator of `out` reuse their buffers, so this doesn't allocate
+    /// unless the buffers need to grow.
+    ///
+    /// $o \gets x/2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$ (only if the buffers of `out` need to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `bits`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::from_signeds(-22, 7).shr_to(2, &mut out);
+    /// assert_eq!(out.to_string(), "-11/14");
+    /// ```
+    pub fn shr_to(&self, bits: u64, out: &mut Rational) {
+        if *self == 0u32 {
+            out.clone_from(&Rational::ZERO);
+            return;
+        }
+        let numerator_zeros = self.numerator.trailing_zeros().unwrap();
+        if numerator_zeros >= bits {
+            self.numerator.shr_to(bits, &mut out.numerator);
+            out.denominator.clone_from(&self.denominator);
+        } else {
+            self.numerator.shr_to(numerator_zeros, &mut out.numerator);
+            self.denominator
+                .shl_to(bits - numerator_zeros, &mut out.denominator);
+        }
+        out.sign = self.sign;
+    }
+}
+
 macro_rules! impl_shr_unsigned {
     ($t:ident) => {
         impl Shr<$t> for Rational {
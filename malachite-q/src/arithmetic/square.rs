@@ -70,6 +70,40 @@ impl<'a> Square for &'a Rational {
     }
 }
 
+impl Rational {
+    /// Squares a [`Rational`], taking it by reference and writing the result to an existing
+    /// [`Rational`].
+    ///
+    /// The numerator and denominator of `out` reuse their buffers, which also hold the scratch
+    /// space needed by the squaring, so this doesn't allocate unless the buffers need to grow.
+    ///
+    /// $$
+    /// o \gets x^2.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::from_signeds(-22, 7).square_to(&mut out);
+    /// assert_eq!(out.to_string(), "484/49");
+    /// ```
+    pub fn square_to(&self, out: &mut Rational) {
+        self.numerator.square_to(&mut out.numerator);
+        self.denominator.square_to(&mut out.denominator);
+        out.sign = true;
+    }
+}
+
 impl SquareAssign for Rational {
     /// Squares a [`Rational`] in place.
     ///
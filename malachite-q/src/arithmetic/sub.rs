@@ -1,3 +1,4 @@
+use crate::arithmetic::add::add_signed_to;
 use crate::Rational;
 use malachite_base::num::arithmetic::traits::{
     DivExact, DivExactAssign, Gcd, GcdAssign, NegAssign, UnsignedAbs,
@@ -250,6 +251,43 @@ impl<'a, 'b> Sub<&'a Rational> for &'b Rational {
     }
 }
 
+impl Rational {
+    /// Subtracts a [`Rational`] by another [`Rational`], taking both by reference and writing the
+    /// difference to an existing [`Rational`].
+    ///
+    /// The final products are written into the numerator and denominator of `out`, reusing their
+    /// buffers, although intermediate values are still allocated.
+    ///
+    /// $$
+    /// o \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{OneHalf, Zero};
+    /// use malachite_q::Rational;
+    ///
+    /// let mut out = Rational::ZERO;
+    /// Rational::ONE_HALF.sub_to(&Rational::ONE_HALF, &mut out);
+    /// assert_eq!(out, 0);
+    ///
+    /// Rational::from_signeds(22, 7).sub_to(&Rational::from_signeds(99, 100), &mut out);
+    /// assert_eq!(out.to_string(), "1507/700");
+    /// ```
+    #[inline]
+    pub fn sub_to(&self, other: &Rational, out: &mut Rational) {
+        add_signed_to(self.sign, self, !other.sign, other, out);
+    }
+}
+
 impl SubAssign<Rational> for Rational {
     /// Subtracts a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
     /// right-hand side by value.
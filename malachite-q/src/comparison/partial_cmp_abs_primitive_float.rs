@@ -1,7 +1,9 @@
 use crate::Rational;
 use malachite_base::num::arithmetic::traits::FloorLogBase2;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::comparison::traits::{OrdAbs, PartialOrdAbs};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::half::{BF16, F16};
 use std::cmp::Ordering;
 
 macro_rules! impl_float {
@@ -22,7 +24,7 @@ macro_rules! impl_float {
             fn partial_cmp_abs(&self, other: &$t) -> Option<Ordering> {
                 if other.is_nan() {
                     None
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     self.partial_cmp_abs(&0u32)
                 } else if !other.is_finite() || *self == 0u32 {
                     Some(Ordering::Less)
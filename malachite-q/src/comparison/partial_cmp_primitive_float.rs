@@ -1,5 +1,7 @@
 use crate::Rational;
 use malachite_base::num::arithmetic::traits::FloorLogBase2;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::float::half::{BF16, F16};
 use std::cmp::Ordering;
 
 macro_rules! impl_float {
@@ -20,7 +22,7 @@ macro_rules! impl_float {
             fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
                 if other.is_nan() {
                     None
-                } else if self.sign != (*other >= 0.0) {
+                } else if self.sign != (*other >= $t::ZERO) {
                     Some(if self.sign {
                         Ordering::Greater
                     } else {
@@ -32,10 +34,10 @@ macro_rules! impl_float {
                     } else {
                         Ordering::Greater
                     })
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     self.partial_cmp(&0u32)
                 } else if *self == 0u32 {
-                    0.0.partial_cmp(other)
+                    $t::ZERO.partial_cmp(other)
                 } else {
                     let ord_cmp = self
                         .floor_log_base_2_of_abs()
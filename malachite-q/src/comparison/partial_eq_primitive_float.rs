@@ -1,6 +1,8 @@
 use crate::Rational;
 use malachite_base::num::arithmetic::traits::{FloorLogBase2, IsPowerOf2};
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::half::{BF16, F16};
 
 macro_rules! impl_float {
     ($t: ident) => {
@@ -22,11 +24,11 @@ macro_rules! impl_float {
             fn eq(&self, other: &$t) -> bool {
                 if !other.is_finite() {
                     false
-                } else if *other == 0.0 {
+                } else if *other == $t::ZERO {
                     *self == 0u32
                 } else {
                     *self != 0u32
-                        && self.sign == (*other > 0.0)
+                        && self.sign == (*other > $t::ZERO)
                         && self.denominator.is_power_of_2()
                         && self.floor_log_base_2_of_abs() == other.abs().floor_log_base_2()
                         && *self == Rational::exact_from(*other)
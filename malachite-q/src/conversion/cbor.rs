@@ -0,0 +1,245 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{CheckedLogBase, IsPowerOf2, Pow, PowerOf2};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::cbor::{decode_cbor_head, encode_cbor_head, CborError};
+use malachite_nz::natural::Natural;
+use std::cmp::max;
+
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_TAG: u8 = 6;
+const TAG_DECIMAL_FRACTION: u64 = 4;
+const TAG_BIGFLOAT: u64 = 5;
+const TAG_RATIONAL: u64 = 30;
+
+/// The largest absolute value of a decimal fraction or bigfloat exponent that
+/// [`Rational::from_cbor`] accepts.
+///
+/// A data item of a few bytes can specify an exponent close to $2^{63}$, and building the
+/// corresponding [`Rational`] would take an enormous amount of time and memory. With this limit,
+/// a bigfloat exponent adds at most about $2^{20}$ bits to the decoded value, and a decimal
+/// fraction exponent at most about $3.3 \times 2^{20}$. To accept larger exponents, use
+/// [`Rational::from_cbor_with_max_exponent`].
+pub const DEFAULT_MAX_CBOR_EXPONENT: u64 = 1 << 20;
+
+// Appends a tag followed by the head of a two-element array.
+fn encode_tagged_pair_head(out: &mut Vec<u8>, tag: u64) {
+    encode_cbor_head(out, MAJOR_TAG, tag);
+    encode_cbor_head(out, MAJOR_ARRAY, 2);
+}
+
+// Decodes an integer or bignum exponent, whose absolute value must be at most `max_exponent`.
+fn decode_exponent(bytes: &[u8], max_exponent: u64) -> Result<(i64, usize), CborError> {
+    let (exponent, len) = Integer::from_cbor(bytes)?;
+    match i64::try_from(&exponent) {
+        Ok(exponent) if exponent.unsigned_abs() <= max_exponent => Ok((exponent, len)),
+        _ => Err(CborError::ExponentOutOfRange),
+    }
+}
+
+impl Rational {
+    /// Encodes a [`Rational`] as a CBOR data item.
+    ///
+    /// The shortest exact representation among the following is chosen:
+    /// - Integers are encoded like [`Integer`]s: as integers (major type 0 or 1), or as bignums
+    ///   (tag 2 or 3).
+    /// - If the denominator is a power of 2, the [`Rational`] is encoded as a bigfloat (tag 5 of
+    ///   RFC 8949): an array containing an exponent $e$ and a mantissa $m$, with value $m2^e$.
+    /// - Otherwise, if the denominator divides a power of 10, the [`Rational`] is encoded as a
+    ///   decimal fraction (tag 4): an array containing an exponent $e$ and a mantissa $m$, with
+    ///   value $m10^e$.
+    /// - Otherwise, the [`Rational`] is encoded as a rational number (tag 30): an array
+    ///   containing the numerator and the denominator.
+    ///
+    /// A bigfloat or decimal fraction is only used if the absolute value of its exponent is at
+    /// most [`DEFAULT_MAX_CBOR_EXPONENT`]; otherwise, the rational number encoding is used. This
+    /// ensures that [`from_cbor`](Rational::from_cbor) can decode the output of this function.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(-3).to_cbor(), &[0x22]);
+    /// // 3 * 2^-2
+    /// assert_eq!(Rational::from_signeds(3, 4).to_cbor(), &[0xc5, 0x82, 0x21, 0x03]);
+    /// // 27315 * 10^-2
+    /// assert_eq!(
+    ///     Rational::from_signeds(5463, 20).to_cbor(),
+    ///     &[0xc4, 0x82, 0x21, 0x19, 0x6a, 0xb3]
+    /// );
+    /// assert_eq!(Rational::from_signeds(-1, 3).to_cbor(), &[0xd8, 0x1e, 0x82, 0x20, 0x03]);
+    /// ```
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let numerator = Integer::from_sign_and_abs_ref(self.sign, &self.numerator);
+        if self.denominator == 1u32 {
+            return numerator.to_cbor();
+        }
+        let twos = self.denominator.trailing_zeros().unwrap();
+        if twos > DEFAULT_MAX_CBOR_EXPONENT {
+            encode_tagged_pair_head(&mut out, TAG_RATIONAL);
+            out.extend(numerator.to_cbor());
+            out.extend(self.denominator.to_cbor());
+        } else if self.denominator.is_power_of_2() {
+            encode_tagged_pair_head(&mut out, TAG_BIGFLOAT);
+            out.extend(Integer::from(-i64::exact_from(twos)).to_cbor());
+            out.extend(numerator.to_cbor());
+        } else if let Some(fives) = (&(&self.denominator >> twos))
+            .checked_log_base(&Natural::from(5u32))
+            .filter(|&fives| fives <= DEFAULT_MAX_CBOR_EXPONENT)
+        {
+            // The denominator is 2^twos * 5^fives, so multiplying by 10^max(twos, fives) gives
+            // an integer
+            let k = max(twos, fives);
+            let mantissa = numerator
+                * Integer::from(Natural::power_of_2(k - twos) * Natural::from(5u32).pow(k - fives));
+            encode_tagged_pair_head(&mut out, TAG_DECIMAL_FRACTION);
+            out.extend(Integer::from(-i64::exact_from(k)).to_cbor());
+            out.extend(mantissa.to_cbor());
+        } else {
+            encode_tagged_pair_head(&mut out, TAG_RATIONAL);
+            out.extend(numerator.to_cbor());
+            out.extend(self.denominator.to_cbor());
+        }
+        out
+    }
+
+    /// Decodes a [`Rational`] from a CBOR data item at the start of a byte slice, returning the
+    /// [`Rational`] and the number of bytes that were read.
+    ///
+    /// Integers and bignums (major types 0 and 1, and tags 2 and 3), decimal fractions (tag 4),
+    /// bigfloats (tag 5), and rational numbers (tag 30) are accepted. The exponents of decimal
+    /// fractions and bigfloats may be positive, but their absolute values must be at most
+    /// [`DEFAULT_MAX_CBOR_EXPONENT`]; otherwise, [`CborError::ExponentOutOfRange`] is returned.
+    /// The numerator and denominator of a rational number need not be in lowest terms, but the
+    /// denominator must be positive. Bytes after the end of the data item are ignored.
+    ///
+    /// To use a different limit on the exponents, use
+    /// [`from_cbor_with_max_exponent`](Rational::from_cbor_with_max_exponent).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the decoded
+    /// value.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::cbor::CborError;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_cbor(&[0xc4, 0x82, 0x21, 0x19, 0x6a, 0xb3]),
+    ///     Ok((Rational::from_signeds(5463, 20), 6))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_cbor(&[0xc5, 0x82, 0x01, 0x03]),
+    ///     Ok((Rational::from(6), 4))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_cbor(&[0xd8, 0x1e, 0x82, 0x02, 0x04]),
+    ///     Ok((Rational::from_signeds(1, 2), 5))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_cbor(&[0xd8, 0x1e, 0x82, 0x01, 0x00]),
+    ///     Err(CborError::ZeroDenominator)
+    /// );
+    /// // 10^(2^32)
+    /// assert_eq!(
+    ///     Rational::from_cbor(&[0xc4, 0x82, 0x1b, 0, 0, 0, 1, 0, 0, 0, 0, 0x01]),
+    ///     Err(CborError::ExponentOutOfRange)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_cbor(bytes: &[u8]) -> Result<(Rational, usize), CborError> {
+        Rational::from_cbor_with_max_exponent(bytes, DEFAULT_MAX_CBOR_EXPONENT)
+    }
+
+    /// Decodes a [`Rational`] from a CBOR data item at the start of a byte slice, returning the
+    /// [`Rational`] and the number of bytes that were read. The absolute values of the exponents
+    /// of decimal fractions and bigfloats must be at most `max_exponent`.
+    ///
+    /// This is like [`from_cbor`](Rational::from_cbor), but with a caller-specified limit on the
+    /// exponents instead of [`DEFAULT_MAX_CBOR_EXPONENT`]. Decoding a bigfloat with exponent $e$
+    /// takes $O(|e|)$ time and memory, and decoding a decimal fraction with exponent $e$ takes
+    /// $O(|e| \log |e| \log\log |e|)$ time and $O(|e|)$ memory, so `max_exponent` should only be
+    /// large if the input is trusted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the decoded
+    /// value.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::cbor::CborError;
+    /// use malachite_q::Rational;
+    ///
+    /// // 5 * 10^-2
+    /// let bytes = [0xc4, 0x82, 0x21, 0x05];
+    /// assert_eq!(
+    ///     Rational::from_cbor_with_max_exponent(&bytes, 2),
+    ///     Ok((Rational::from_signeds(1, 20), 4))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_cbor_with_max_exponent(&bytes, 1),
+    ///     Err(CborError::ExponentOutOfRange)
+    /// );
+    /// ```
+    pub fn from_cbor_with_max_exponent(
+        bytes: &[u8],
+        max_exponent: u64,
+    ) -> Result<(Rational, usize), CborError> {
+        let (major_type, tag, tag_len) = decode_cbor_head(bytes)?;
+        if major_type != MAJOR_TAG || tag < TAG_DECIMAL_FRACTION {
+            let (x, len) = Integer::from_cbor(bytes)?;
+            return Ok((Rational::from(x), len));
+        }
+        if tag != TAG_DECIMAL_FRACTION && tag != TAG_BIGFLOAT && tag != TAG_RATIONAL {
+            return Err(CborError::WrongTag(tag));
+        }
+        let mut len = tag_len;
+        let (major_type, array_len, head_len) = decode_cbor_head(&bytes[len..])?;
+        if major_type != MAJOR_ARRAY {
+            return Err(CborError::WrongMajorType(major_type));
+        }
+        if array_len != 2 {
+            return Err(CborError::WrongArrayLength(array_len));
+        }
+        len += head_len;
+        let x = if tag == TAG_RATIONAL {
+            let (numerator, numerator_len) = Integer::from_cbor(&bytes[len..])?;
+            len += numerator_len;
+            let (denominator, denominator_len) = Natural::from_cbor(&bytes[len..])?;
+            len += denominator_len;
+            if denominator == 0u32 {
+                return Err(CborError::ZeroDenominator);
+            }
+            Rational::from_integers(numerator, Integer::from(denominator))
+        } else {
+            let (exponent, exponent_len) = decode_exponent(&bytes[len..], max_exponent)?;
+            len += exponent_len;
+            let (mantissa, mantissa_len) = Integer::from_cbor(&bytes[len..])?;
+            len += mantissa_len;
+            let mantissa = Rational::from(mantissa);
+            if tag == TAG_BIGFLOAT {
+                mantissa * Rational::power_of_2(exponent)
+            } else {
+                mantissa * Rational::from(10u32).pow(exponent)
+            }
+        };
+        Ok((x, len))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Rational;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::IntegerMantissaAndExponent;
+use malachite_base::num::float::half::{BF16, F16};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct RationalFromPrimitiveFloatError;
@@ -31,12 +32,12 @@ macro_rules! float_impls {
             fn try_from(value: $f) -> Result<Rational, Self::Error> {
                 if !value.is_finite() {
                     Err(RationalFromPrimitiveFloatError)
-                } else if value == 0.0 {
+                } else if value == $f::ZERO {
                     Ok(Rational::ZERO)
                 } else {
                     let (mantissa, exponent) = value.integer_mantissa_and_exponent();
                     let x = Rational::from(mantissa) << exponent;
-                    Ok(if value > 0.0 { x } else { -x })
+                    Ok(if value > $f::ZERO { x } else { -x })
                 }
             }
         }
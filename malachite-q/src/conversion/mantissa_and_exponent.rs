@@ -1,9 +1,11 @@
 use crate::Rational;
 use malachite_base::num::arithmetic::traits::DivRound;
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{One, Two, Zero};
 use malachite_base::num::conversion::traits::{
     ExactFrom, IntegerMantissaAndExponent, SciMantissaAndExponent, WrappingFrom,
 };
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
 use std::cmp::Ordering;
@@ -262,8 +264,8 @@ macro_rules! impl_mantissa_and_exponent {
                 sci_mantissa: $t,
                 sci_exponent: i64,
             ) -> Option<Rational> {
-                assert_ne!(sci_mantissa, 0.0);
-                if sci_mantissa < 1.0 || sci_mantissa >= 2.0 {
+                assert_ne!(sci_mantissa, $t::ZERO);
+                if sci_mantissa < $t::ONE || sci_mantissa >= $t::TWO {
                     None
                 } else {
                     let m = sci_mantissa.integer_mantissa();
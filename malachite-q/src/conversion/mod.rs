@@ -1,3 +1,6 @@
+/// Functions for encoding [`Rational`](crate::Rational)s as CBOR data items, using decimal
+/// fractions, bigfloats, or rational-number tags, and for decoding them.
+pub mod cbor;
 /// Implementations of traits for converting [`Rational`](crate::Rational)s to and from continued
 /// fractions.
 pub mod continued_fraction;
@@ -122,6 +125,10 @@ pub mod mutate_numerator_and_denominator;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
 pub mod natural_from_rational;
+/// Implementations of [`From`] for converting [`Rational`](crate::Rational)s to and from the
+/// `BigRational` type of [num-rational](https://crates.io/crates/num-rational).
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint;
 /// Functions and implementations of traits for converting a [`Rational`](crate::Rational) to a
 /// primitive float.
 ///
@@ -0,0 +1,141 @@
+use crate::Rational;
+use malachite_nz::integer::Integer;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+impl From<BigRational> for Rational {
+    /// Converts a [`BigRational`] to a [`Rational`], taking the [`BigRational`] by value.
+    ///
+    /// The [`BigRational`] does not need to be in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(value.numer().bits(), value.denom().bits())`.
+    ///
+    /// # Panics
+    /// Panics if the denominator of `value` is zero, which can only happen if it was constructed
+    /// with [`BigRational::new_raw`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from(BigRational::new(BigInt::from(22), BigInt::from(-7))).to_string(),
+    ///     "-22/7"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(BigRational::new_raw(BigInt::from(6), BigInt::from(4))).to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    fn from(value: BigRational) -> Rational {
+        let (numerator, denominator): (BigInt, BigInt) = value.into();
+        Rational::from_integers(Integer::from(numerator), Integer::from(denominator))
+    }
+}
+
+impl<'a> From<&'a BigRational> for Rational {
+    /// Converts a [`BigRational`] to a [`Rational`], taking the [`BigRational`] by reference.
+    ///
+    /// The [`BigRational`] does not need to be in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(value.numer().bits(), value.denom().bits())`.
+    ///
+    /// # Panics
+    /// Panics if the denominator of `value` is zero, which can only happen if it was constructed
+    /// with [`BigRational::new_raw`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from(&BigRational::new(BigInt::from(22), BigInt::from(-7))).to_string(),
+    ///     "-22/7"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(&BigRational::new_raw(BigInt::from(6), BigInt::from(4))).to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    fn from(value: &'a BigRational) -> Rational {
+        Rational::from_integers(Integer::from(value.numer()), Integer::from(value.denom()))
+    }
+}
+
+impl From<Rational> for BigRational {
+    /// Converts a [`Rational`] to a [`BigRational`], taking the [`Rational`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// assert_eq!(
+    ///     BigRational::from(Rational::from_signeds(-22, 7)),
+    ///     BigRational::new(BigInt::from(-22), BigInt::from(7))
+    /// );
+    /// ```
+    fn from(value: Rational) -> BigRational {
+        let sign = value.sign;
+        let (numerator, denominator) = value.into_numerator_and_denominator();
+        let numerator = BigInt::from(numerator);
+        BigRational::new_raw(
+            if sign { numerator } else { -numerator },
+            BigInt::from(denominator),
+        )
+    }
+}
+
+impl<'a> From<&'a Rational> for BigRational {
+    /// Converts a [`Rational`] to a [`BigRational`], taking the [`Rational`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_bigint::BigInt;
+    /// use num_rational::BigRational;
+    ///
+    /// assert_eq!(
+    ///     BigRational::from(&Rational::from_signeds(-22, 7)),
+    ///     BigRational::new(BigInt::from(-22), BigInt::from(7))
+    /// );
+    /// ```
+    fn from(value: &'a Rational) -> BigRational {
+        let numerator = BigInt::from(&value.numerator);
+        BigRational::new_raw(
+            if value.sign { numerator } else { -numerator },
+            BigInt::from(&value.denominator),
+        )
+    }
+}
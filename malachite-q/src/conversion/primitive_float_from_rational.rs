@@ -3,10 +3,12 @@ use malachite_base::num::arithmetic::traits::{
     DivRound, DivisibleByPowerOf2, IsPowerOf2, NegAssign,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::{
     ConvertibleFrom, ExactFrom, RawMantissaAndExponent, RoundingFrom, SciMantissaAndExponent,
     WrappingFrom,
 };
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
 
@@ -65,7 +67,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_rational#rounding_from).
             fn rounding_from(mut value: Rational, mut rm: RoundingMode) -> $f {
                 if value == 0u32 {
-                    0.0
+                    $f::ZERO
                 } else {
                     let sign = value.sign;
                     if !sign {
@@ -132,14 +134,14 @@ macro_rules! float_impls {
                             RoundingMode::Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            RoundingMode::Floor | RoundingMode::Down => 0.0,
+                            RoundingMode::Floor | RoundingMode::Down => $f::ZERO,
                             RoundingMode::Nearest => {
                                 if exponent == $f::MIN_EXPONENT - 1
                                     && !abs_is_neg_power_of_2(&value)
                                 {
                                     $f::MIN_POSITIVE_SUBNORMAL
                                 } else {
-                                    0.0
+                                    $f::ZERO
                                 }
                             }
                             _ => $f::MIN_POSITIVE_SUBNORMAL,
@@ -171,7 +173,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_rational#try_from).
             fn try_from(value: Rational) -> Result<$f, Self::Error> {
                 if value == 0 {
-                    Ok(0.0)
+                    Ok($f::ZERO)
                 } else {
                     let sign = value.sign;
                     let (mantissa, exponent) = value
@@ -271,7 +273,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_rational#rounding_from).
             fn rounding_from(value: &'a Rational, mut rm: RoundingMode) -> $f {
                 if *value == 0u32 {
-                    0.0
+                    $f::ZERO
                 } else {
                     if !value.sign {
                         rm.neg_assign();
@@ -337,14 +339,14 @@ macro_rules! float_impls {
                             RoundingMode::Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            RoundingMode::Floor | RoundingMode::Down => 0.0,
+                            RoundingMode::Floor | RoundingMode::Down => $f::ZERO,
                             RoundingMode::Nearest => {
                                 if exponent == $f::MIN_EXPONENT - 1
                                     && !abs_is_neg_power_of_2(&value)
                                 {
                                     $f::MIN_POSITIVE_SUBNORMAL
                                 } else {
-                                    0.0
+                                    $f::ZERO
                                 }
                             }
                             _ => $f::MIN_POSITIVE_SUBNORMAL,
@@ -376,7 +378,7 @@ macro_rules! float_impls {
             /// See [here](super::primitive_float_from_rational#try_from).
             fn try_from(value: &'a Rational) -> Result<$f, Self::Error> {
                 if *value == 0 {
-                    Ok(0.0)
+                    Ok($f::ZERO)
                 } else {
                     let (mantissa, exponent) = value
                         .sci_mantissa_and_exponent_with_rounding_ref(RoundingMode::Exact)
@@ -0,0 +1,255 @@
+use crate::conversion::string::to_repeating_string::COMBINING_OVERLINE;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::from_string::{
+    digit_from_display_byte, ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{Digits, ExactFrom, FromStringWithOptions};
+use malachite_nz::natural::Natural;
+
+// Reads the digits in `s[start..end]`, returning their value and how many there are. Every byte in
+// the range must be a digit in the base; the range may be empty.
+fn parse_digits(
+    s: &[u8],
+    start: usize,
+    end: usize,
+    base: u8,
+) -> Result<(Natural, u64), ParseNumberError> {
+    let mut digits = Vec::with_capacity(end - start);
+    for (i, &b) in s[start..end].iter().enumerate() {
+        match digit_from_display_byte(b) {
+            Some(d) if d < base => digits.push(d),
+            _ => {
+                return Err(ParseNumberError::new(
+                    ParseNumberErrorKind::InvalidDigit,
+                    start + i,
+                ))
+            }
+        }
+    }
+    let len = u64::exact_from(digits.len());
+    Ok((
+        Natural::from_digits_desc(&base, digits.into_iter()).unwrap(),
+        len,
+    ))
+}
+
+fn nonempty(start: usize, end: usize) -> Result<(), ParseNumberError> {
+    if start == end {
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, start))
+    } else {
+        Ok(())
+    }
+}
+
+// Finds the repeating digits of an expansion written with overlines: each repeating digit is
+// followed by U+0305, and the repeating digits come last. Returns the byte index where the
+// repeating digits start, along with the digits themselves.
+fn parse_overlined_digits(
+    s: &str,
+    first_overline: usize,
+    frac_start: usize,
+    base: u8,
+) -> Result<(usize, Vec<u8>), ParseNumberError> {
+    let bytes = s.as_bytes();
+    let overline_len = COMBINING_OVERLINE.len_utf8();
+    if first_overline == frac_start || !bytes[first_overline - 1].is_ascii() {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::InvalidDigit,
+            first_overline,
+        ));
+    }
+    let rep_start = first_overline - 1;
+    let mut digits = Vec::new();
+    let mut i = rep_start;
+    while i < bytes.len() {
+        match digit_from_display_byte(bytes[i]) {
+            Some(d) if d < base => digits.push(d),
+            _ => return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, i)),
+        }
+        // Once the repeating digits start, every digit must be overlined
+        if !s[i + 1..].starts_with(COMBINING_OVERLINE) {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, i));
+        }
+        i += 1 + overline_len;
+    }
+    Ok((rep_start, digits))
+}
+
+impl Rational {
+    /// Converts a string to a [`Rational`], where the string is a decimal expansion whose
+    /// repeating part, if any, is marked.
+    ///
+    /// This is equivalent to
+    /// [`from_repeating_string_base`](Rational::from_repeating_string_base) with base 10. See that
+    /// function for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// let sixth = Rational::from_signeds(1, 6);
+    /// assert_eq!(Rational::from_repeating_string("0.1(6)").unwrap(), sixth);
+    /// assert_eq!(Rational::from_repeating_string("0.1[6]").unwrap(), sixth);
+    /// assert_eq!(Rational::from_repeating_string("0.16\u{305}").unwrap(), sixth);
+    /// assert_eq!(Rational::from_repeating_string("1/6").unwrap(), sixth);
+    /// assert_eq!(Rational::from_repeating_string("0.(9)").unwrap(), 1);
+    /// assert_eq!(
+    ///     Rational::from_repeating_string("-1.25").unwrap(),
+    ///     Rational::from_signeds(-5, 4)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_repeating_string(s: &str) -> Result<Rational, ParseNumberError> {
+        Rational::from_repeating_string_base(10, s)
+    }
+
+    /// Converts a string to a [`Rational`], where the string is an expansion in the given base
+    /// whose repeating part, if any, is marked.
+    ///
+    /// The string consists of an optional `'-'`, an integer part, and an optional `'.'` followed
+    /// by a fractional part. The fractional part has one of these forms:
+    /// - some digits, with no repeating part, as in `"0.25"`;
+    /// - some digits, possibly none, followed by the repeating digits in parentheses or square
+    ///   brackets, as in `"0.1(6)"` or `"0.1[6]"`;
+    /// - some digits, possibly none, followed by the repeating digits, each of which is followed
+    ///   by the combining overline U+0305, as in `"0.16\u{305}"`.
+    ///
+    /// A fraction such as `"1/6"` is also accepted. Digits greater than 9 may be lowercase or
+    /// uppercase. The expansion need not be in its simplest form, so `"0.(9)"` and `"0.9(99)"` are
+    /// both accepted and represent 1. If the string is not valid, an `Err` is returned,
+    /// describing the problem and where in the string it was found.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(16, "0.(1745D)").unwrap(),
+    ///     Rational::from_signeds(1, 11)
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(2, "-0.(01)").unwrap(),
+    ///     Rational::from_signeds(-1, 3)
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(16, "ff/100").unwrap(),
+    ///     Rational::from_signeds(255, 256)
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(10, "0.1()"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 4))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(10, "0.(1)2"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 5))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_repeating_string_base(2, "0.(2)"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3))
+    /// );
+    /// ```
+    pub fn from_repeating_string_base(base: u8, s: &str) -> Result<Rational, ParseNumberError> {
+        let mut options = ParseOptions::default();
+        options.set_base(base);
+        if s.contains('/') {
+            return Rational::from_string_with_options(s, options);
+        }
+        let bytes = s.as_bytes();
+        let negative = s.starts_with('-');
+        let start = usize::from(negative);
+        let point = s[start..].find('.').map(|i| start + i);
+        let int_end = point.unwrap_or(s.len());
+        nonempty(start, int_end)?;
+        let (integer, _) = parse_digits(bytes, start, int_end, base)?;
+        let point = if let Some(point) = point {
+            point
+        } else {
+            return Ok(Rational::from_sign_and_naturals(
+                !negative,
+                integer,
+                Natural::ONE,
+            ));
+        };
+        let frac_start = point + 1;
+        let open = bytes[frac_start..]
+            .iter()
+            .position(|&b| b == b'(' || b == b'[')
+            .map(|i| frac_start + i);
+        let (nonrepeating_end, repeating) = if let Some(open) = open {
+            let close = if bytes[open] == b'(' { b')' } else { b']' };
+            let rep_start = open + 1;
+            let rep_end = bytes[rep_start..].iter().position(|&b| b == close);
+            let rep_end = if let Some(i) = rep_end {
+                rep_start + i
+            } else {
+                // Report a bad digit before reporting the missing bracket
+                parse_digits(bytes, rep_start, s.len(), base)?;
+                return Err(ParseNumberError::new(ParseNumberErrorKind::Empty, s.len()));
+            };
+            nonempty(rep_start, rep_end)?;
+            let repeating = parse_digits(bytes, rep_start, rep_end, base)?;
+            if rep_end + 1 != s.len() {
+                return Err(ParseNumberError::new(
+                    ParseNumberErrorKind::InvalidDigit,
+                    rep_end + 1,
+                ));
+            }
+            (open, Some(repeating))
+        } else if let Some(i) = s[frac_start..].find(COMBINING_OVERLINE) {
+            let (rep_start, digits) = parse_overlined_digits(s, frac_start + i, frac_start, base)?;
+            let len = u64::exact_from(digits.len());
+            (
+                rep_start,
+                Some((
+                    Natural::from_digits_desc(&base, digits.into_iter()).unwrap(),
+                    len,
+                )),
+            )
+        } else {
+            nonempty(frac_start, s.len())?;
+            (s.len(), None)
+        };
+        let (nonrepeating, nonrepeating_len) =
+            parse_digits(bytes, frac_start, nonrepeating_end, base)?;
+        let base = Natural::from(base);
+        let scale = (&base).pow(nonrepeating_len);
+        // With integer part I, n nonrepeating digits N, and r repeating digits R, the value is
+        // ((I * b^n + N) * (b^r - 1) + R) / (b^n * (b^r - 1)).
+        let mut numerator = integer * &scale + nonrepeating;
+        let mut denominator = scale;
+        if let Some((repeating, repeating_len)) = repeating {
+            let period_scale = base.pow(repeating_len) - Natural::ONE;
+            numerator *= &period_scale;
+            numerator += repeating;
+            denominator *= period_scale;
+        }
+        Ok(Rational::from_sign_and_naturals(
+            !negative,
+            numerator,
+            denominator,
+        ))
+    }
+}
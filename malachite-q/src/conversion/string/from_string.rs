@@ -1,16 +1,23 @@
 use crate::Rational;
 use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::from_string::{
+    clean_digits, strip_sign, ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{FromStringBase, FromStringWithOptions};
 use malachite_nz::natural::Natural;
 use std::str::FromStr;
 
 impl FromStr for Rational {
-    type Err = ();
+    type Err = ParseNumberError;
 
     /// Converts an string to a [`Rational`].
     ///
-    /// If the string does not represent a valid [`Rational`], an `Err` is returned. The numerator
-    /// and denominator do not need to be in lowest terms, but the denominator must be nonzero. A
-    /// negative sign is only allowed at the 0th position of the string.
+    /// If the string does not represent a valid [`Rational`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. The numerator and denominator do not need to
+    /// be in lowest terms, but the denominator must be nonzero. A negative sign is only allowed at
+    /// the 0th position of the string. To accept other syntax, such as separators or a base
+    /// prefix, use [`from_string_with_options`](Rational::from_string_with_options).
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
@@ -21,6 +28,9 @@ impl FromStr for Rational {
     ///
     /// # Examples
     /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
     /// use malachite_q::Rational;
     /// use std::str::FromStr;
     ///
@@ -44,28 +54,92 @@ impl FromStr for Rational {
     /// assert!(Rational::from_str("1/").is_err());
     /// assert!(Rational::from_str("--1").is_err());
     /// assert!(Rational::from_str("1/-2").is_err());
+    /// assert_eq!(
+    ///     Rational::from_str("22/0"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::ZeroDenominator, 3))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_str("22/7x"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 4))
+    /// );
     /// ```
     #[inline]
-    fn from_str(s: &str) -> Result<Rational, ()> {
-        let (abs_string, sign) = if let Some(abs_string) = s.strip_prefix('-') {
-            (abs_string, false)
-        } else {
-            (s, true)
+    fn from_str(s: &str) -> Result<Rational, ParseNumberError> {
+        Rational::from_string_with_options(s, ParseOptions::default())
+    }
+}
+
+impl FromStringWithOptions for Rational {
+    /// Converts a string to a [`Rational`], accepting the syntax specified by a [`ParseOptions`].
+    ///
+    /// The string consists of an optional sign, followed by a numerator and an optional `'/'` and
+    /// denominator. The numerator and denominator do not need to be in lowest terms, but the
+    /// denominator must be nonzero. If prefixes are allowed, the numerator and denominator may each
+    /// have one. If the string does not represent a valid [`Rational`], an `Err` is returned,
+    /// describing the problem and where in the string it was found.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_base::num::conversion::string::options::ParseOptions;
+    /// use malachite_base::num::conversion::traits::FromStringWithOptions;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.set_allow_underscore_separators(true);
+    /// options.set_allow_whitespace(true);
+    /// options.set_allow_prefixes(true);
+    /// assert_eq!(
+    ///     Rational::from_string_with_options(" -22_000/7_000 ", options).unwrap(),
+    ///     Rational::from_signeds(-22, 7)
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_string_with_options("0xff/0x10", options).unwrap(),
+    ///     Rational::from_signeds(255, 16)
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_string_with_options("1/0", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::ZeroDenominator, 2))
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_string_with_options("1/", options),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 2))
+    /// );
+    /// ```
+    fn from_string_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> Result<Rational, ParseNumberError> {
+        let (negative, range) = strip_sign(s, options)?;
+        let parse_natural = |start: usize, end: usize| {
+            clean_digits(&s[start..end], start, options)
+                .map(|(base, digits)| Natural::from_string_base(base, &digits).unwrap())
         };
-        let numerator;
-        let denominator;
-        if let Some(slash_index) = abs_string.find('/') {
-            numerator = Natural::from_str(&abs_string[..slash_index])?;
-            denominator = Natural::from_str(&abs_string[slash_index + 1..])?;
+        let (numerator, denominator) = if let Some(i) = s[range.clone()].find('/') {
+            let slash_index = range.start + i;
+            let numerator = parse_natural(range.start, slash_index)?;
+            let denominator = parse_natural(slash_index + 1, range.end)?;
             if denominator == 0u32 {
-                return Err(());
+                return Err(ParseNumberError::new(
+                    ParseNumberErrorKind::ZeroDenominator,
+                    slash_index + 1,
+                ));
             }
+            (numerator, denominator)
         } else {
-            numerator = Natural::from_str(abs_string)?;
-            denominator = Natural::ONE;
-        }
+            (parse_natural(range.start, range.end)?, Natural::ONE)
+        };
         Ok(Rational::from_sign_and_naturals(
-            sign,
+            !negative,
             numerator,
             denominator,
         ))
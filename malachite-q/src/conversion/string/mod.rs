@@ -1,8 +1,14 @@
+/// Functions for parsing expansions with a marked repeating part, such as `"0.1(6)"`, as
+/// [`Rational`](crate::Rational)s.
+pub mod from_repeating_string;
 /// Implementations of [`FromSciString`](malachite_base::num::conversion::traits::FromSciString).
 /// This is a trait for converting strings, possibly using scientific notation, to numbers.
 pub mod from_sci_string;
 /// An implementation of [`FromStr`](std::str::FromStr).
 pub mod from_string;
+/// Functions for writing [`Rational`](crate::Rational)s as expansions with a marked repeating
+/// part, such as `"0.1(6)"`.
+pub mod to_repeating_string;
 /// Implementations of [`ToSci`](malachite_base::num::conversion::traits::ToSci), a trait for
 /// converting a number to string, possibly using scientific notation.
 pub mod to_sci;
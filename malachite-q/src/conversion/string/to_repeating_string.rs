@@ -0,0 +1,299 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivAssignMod, DivMod, Gcd};
+use malachite_base::num::conversion::string::to_string::{
+    digit_to_display_byte_lower, digit_to_display_byte_upper,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, ToStringBase};
+use malachite_nz::natural::Natural;
+
+/// The combining character U+0305, which draws a line over the character before it.
+pub const COMBINING_OVERLINE: char = '\u{305}';
+
+/// The default maximum period length of a [`ToRepeatingOptions`]: the largest number of repeating
+/// digits that [`Rational::to_repeating_string`] writes.
+pub const DEFAULT_MAX_PERIOD_LEN: u64 = 1 << 16;
+
+/// The ways in which the repeating part of an expansion can be marked.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RepeatingNotation {
+    /// The repeating digits are enclosed in parentheses, as in `"0.1(6)"`.
+    Parentheses,
+    /// The repeating digits are enclosed in square brackets, as in `"0.1[6]"`.
+    Brackets,
+    /// Each repeating digit is followed by [`COMBINING_OVERLINE`], as in `"0.16\u{305}"`.
+    Overline,
+}
+
+/// A `struct` determining how a [`Rational`] is converted to a string showing its exact expansion,
+/// with the repeating part marked.
+///
+/// # Details
+/// - The base must be between 2 and 36, inclusive. The default is 10.
+///
+/// - The lowercase setting determines whether digits greater than 9 are lowercase or uppercase.
+///   The default is `true`.
+///
+/// - The notation determines how the repeating part is marked. The default is
+///   [`RepeatingNotation::Parentheses`].
+///
+/// - The maximum period length, if set, is the largest number of repeating digits that may be
+///   written. The length of the repeating part of $1/q$ may be as large as $q-1$, so it is a good
+///   idea to keep a maximum when the denominator is large. The default is
+///   [`DEFAULT_MAX_PERIOD_LEN`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ToRepeatingOptions {
+    base: u8,
+    lowercase: bool,
+    notation: RepeatingNotation,
+    max_period_len: Option<u64>,
+}
+
+impl Default for ToRepeatingOptions {
+    fn default() -> ToRepeatingOptions {
+        ToRepeatingOptions {
+            base: 10,
+            lowercase: true,
+            notation: RepeatingNotation::Parentheses,
+            max_period_len: Some(DEFAULT_MAX_PERIOD_LEN),
+        }
+    }
+}
+
+impl ToRepeatingOptions {
+    /// Returns the base to be used in the conversion. It is always between 2 and 36, inclusive.
+    #[inline]
+    pub const fn get_base(&self) -> u8 {
+        self.base
+    }
+
+    /// Returns whether the digits should be lowercase.
+    #[inline]
+    pub const fn get_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// Returns the notation used to mark the repeating part.
+    #[inline]
+    pub const fn get_notation(&self) -> RepeatingNotation {
+        self.notation
+    }
+
+    /// Returns the largest number of repeating digits that may be written, or `None` if there is
+    /// no maximum.
+    #[inline]
+    pub const fn get_max_period_len(&self) -> Option<u64> {
+        self.max_period_len
+    }
+
+    /// Sets the base to be used in the conversion.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    #[inline]
+    pub fn set_base(&mut self, base: u8) {
+        assert!(base >= 2);
+        assert!(base <= 36);
+        self.base = base;
+    }
+
+    /// Specifies that digits greater than 9 should be lowercase.
+    #[inline]
+    pub fn set_lowercase(&mut self) {
+        self.lowercase = true;
+    }
+
+    /// Specifies that digits greater than 9 should be uppercase.
+    #[inline]
+    pub fn set_uppercase(&mut self) {
+        self.lowercase = false;
+    }
+
+    /// Sets the notation used to mark the repeating part.
+    #[inline]
+    pub fn set_notation(&mut self, notation: RepeatingNotation) {
+        self.notation = notation;
+    }
+
+    /// Sets the largest number of repeating digits that may be written. `None` means that there is
+    /// no maximum.
+    #[inline]
+    pub fn set_max_period_len(&mut self, max_period_len: Option<u64>) {
+        self.max_period_len = max_period_len;
+    }
+}
+
+impl Rational {
+    /// Converts a [`Rational`] to a string showing its exact decimal expansion, with the repeating
+    /// part, if any, in parentheses.
+    ///
+    /// Integers are written without a decimal point, and expansions that terminate are written
+    /// without a repeating part. The expansion is in its simplest form: the repeating part starts
+    /// as early as possible and is as short as possible.
+    ///
+    /// The repeating part may be very long: for $p/q$ in lowest terms, it may have as many as
+    /// $q-1$ digits. If it is longer than [`DEFAULT_MAX_PERIOD_LEN`], `None` is returned. To use a
+    /// different limit, or no limit, use
+    /// [`to_repeating_string_with_options`](Rational::to_repeating_string_with_options).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n2^n)$
+    ///
+    /// $M(n) = O(2^n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(3u32).to_repeating_string().unwrap(), "3");
+    /// assert_eq!(Rational::from_signeds(1, 4).to_repeating_string().unwrap(), "0.25");
+    /// assert_eq!(Rational::from_signeds(1, 6).to_repeating_string().unwrap(), "0.1(6)");
+    /// assert_eq!(
+    ///     Rational::from_signeds(-22, 7).to_repeating_string().unwrap(),
+    ///     "-3.(142857)"
+    /// );
+    /// // The period of 1/3^13 has 3^11 = 177147 digits
+    /// assert_eq!(Rational::from_signeds(1, 1594323).to_repeating_string(), None);
+    /// ```
+    #[inline]
+    pub fn to_repeating_string(&self) -> Option<String> {
+        self.to_repeating_string_with_options(ToRepeatingOptions::default())
+    }
+
+    /// Converts a [`Rational`] to a string showing its exact expansion, with the repeating part,
+    /// if any, marked as specified by a [`ToRepeatingOptions`].
+    ///
+    /// Integers are written without a point, and expansions that terminate are written without a
+    /// repeating part. The expansion is in its simplest form: the repeating part starts as early
+    /// as possible and is as short as possible. If the repeating part is longer than the maximum
+    /// period length in the options, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n2^n)$
+    ///
+    /// $M(n) = O(2^n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::conversion::string::to_repeating_string::{
+    ///     RepeatingNotation, ToRepeatingOptions,
+    /// };
+    /// use malachite_q::Rational;
+    ///
+    /// let mut options = ToRepeatingOptions::default();
+    /// options.set_notation(RepeatingNotation::Brackets);
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 6).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.1[6]"
+    /// );
+    ///
+    /// options.set_notation(RepeatingNotation::Overline);
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 6).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.16\u{305}"
+    /// );
+    ///
+    /// options = ToRepeatingOptions::default();
+    /// options.set_base(16);
+    /// options.set_uppercase();
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.(5)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 10).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.1(9)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 11).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.(1745D)"
+    /// );
+    ///
+    /// options.set_max_period_len(Some(4));
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 10).to_repeating_string_with_options(options).unwrap(),
+    ///     "0.1(9)"
+    /// );
+    /// assert_eq!(Rational::from_signeds(1, 11).to_repeating_string_with_options(options), None);
+    /// ```
+    pub fn to_repeating_string_with_options(&self, options: ToRepeatingOptions) -> Option<String> {
+        let base = options.base;
+        let digit_to_byte = if options.lowercase {
+            digit_to_display_byte_lower
+        } else {
+            digit_to_display_byte_upper
+        };
+        let d = &self.denominator;
+        let (floor, mut remainder) = (&self.numerator).div_mod(d);
+        let mut s = String::new();
+        if !self.sign {
+            s.push('-');
+        }
+        s.push_str(&if options.lowercase {
+            floor.to_string_base(base)
+        } else {
+            floor.to_string_base_upper(base)
+        });
+        if remainder == 0u32 {
+            return Some(s);
+        }
+        s.push('.');
+        let base_n = Natural::from(base);
+        let next_digit = |remainder: &mut Natural| {
+            *remainder *= &base_n;
+            let new_remainder = remainder.div_assign_mod(d);
+            let digit = u8::exact_from(&*remainder);
+            *remainder = new_remainder;
+            char::from(digit_to_byte(digit).unwrap())
+        };
+        // The number of digits before the repeating part is the number of times that common
+        // factors of the denominator and the base can be divided out of the denominator.
+        let mut reduced_d = d.clone();
+        loop {
+            let gcd = (&reduced_d).gcd(&base_n);
+            if gcd == 1u32 {
+                break;
+            }
+            reduced_d /= gcd;
+            s.push(next_digit(&mut remainder));
+        }
+        if reduced_d == 1u32 {
+            return Some(s);
+        }
+        // The remainder now starts a cycle; the repeating part ends when it comes back around.
+        let start = remainder.clone();
+        let mut period = String::new();
+        let mut period_len = 0;
+        loop {
+            if options.max_period_len == Some(period_len) {
+                return None;
+            }
+            let c = next_digit(&mut remainder);
+            period.push(c);
+            if options.notation == RepeatingNotation::Overline {
+                period.push(COMBINING_OVERLINE);
+            }
+            period_len += 1;
+            if remainder == start {
+                break;
+            }
+        }
+        match options.notation {
+            RepeatingNotation::Parentheses => {
+                s.push('(');
+                s.push_str(&period);
+                s.push(')');
+            }
+            RepeatingNotation::Brackets => {
+                s.push('[');
+                s.push_str(&period);
+                s.push(']');
+            }
+            RepeatingNotation::Overline => s.push_str(&period),
+        }
+        Some(s)
+    }
+}
@@ -1,9 +1,88 @@
+use crate::conversion::string::to_sci::floor_log_base_of_abs;
 use crate::Rational;
-use std::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::arithmetic::traits::{Abs, Pow};
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_nz::natural::Natural;
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
+
+// Writes the numerator and, unless the `Rational` is an integer, the denominator using `fmt`,
+// separated by a slash. The whole string is then padded along with the sign. If the `#` flag is
+// given, `prefix` is written before both the numerator and the denominator.
+fn pad_fraction(
+    x: &Rational,
+    f: &mut Formatter,
+    prefix: &str,
+    fmt: fn(&Natural) -> String,
+) -> Result {
+    let s = if x.denominator == 1u32 {
+        fmt(&x.numerator)
+    } else {
+        format!(
+            "{}/{}{}",
+            fmt(&x.numerator),
+            if f.alternate() { prefix } else { "" },
+            fmt(&x.denominator)
+        )
+    };
+    f.pad_integral(x.sign, prefix, &s)
+}
+
+// Writes a `Rational` in scientific notation. The significand, which lies in $[1, 10)$, is written
+// using `to_sci_with_options`. If the `Formatter` has a precision, the significand is rounded to
+// that many digits after the point, with ties broken towards even. Otherwise, the value is written
+// exactly if its decimal expansion terminates, and rounded to the default `ToSciOptions` precision
+// if it doesn't; in either case, trailing zeros are omitted.
+fn fmt_exp(x: &Rational, f: &mut Formatter, upper: bool) -> Result {
+    let ten = Rational::from(10u32);
+    let mut exponent = if *x == 0u32 {
+        0
+    } else {
+        floor_log_base_of_abs(x, &ten)
+    };
+    let significand = x.abs() / ten.pow(exponent);
+    let mut options = ToSciOptions::default();
+    if let Some(precision) = f.precision() {
+        options.set_scale(u64::exact_from(precision));
+        options.set_include_trailing_zeros(true);
+    } else {
+        options.set_size_complete();
+        if !significand.fmt_sci_valid(options) {
+            options = ToSciOptions::default();
+        }
+    }
+    let s = significand.to_sci_with_options(options).to_string();
+    // If the significand was rounded up to 10, there are two digits before the point
+    let int_len = s.find('.').unwrap_or(s.len());
+    exponent += i64::exact_from(int_len) - 1;
+    let mut digits = s.replace('.', "");
+    if let Some(precision) = f.precision() {
+        digits.truncate(precision + 1);
+    } else {
+        let len = digits.trim_end_matches('0').len();
+        digits.truncate(len.max(1));
+    }
+    let (first, rest) = digits.split_at(1);
+    let s = format!(
+        "{}{}{}{}{}",
+        first,
+        if rest.is_empty() { "" } else { "." },
+        rest,
+        if upper { 'E' } else { 'e' },
+        exponent
+    );
+    f.pad_integral(x.sign, "", &s)
+}
 
 impl Display for Rational {
     /// Converts a [`Rational`] to a [`String`].
     ///
+    /// The numerator and denominator are separated by a slash, and the denominator is omitted if
+    /// it is 1. Width, fill, alignment, sign, and padding with zeros apply to the whole string, as
+    /// they do for the primitive integers.
+    ///
     /// # Worst-case complexity
     /// $T(n) = O(n (\log n)^2 \log\log n)$
     ///
@@ -20,18 +99,14 @@ impl Display for Rational {
     /// assert_eq!(Rational::ZERO.to_string(), "0");
     /// assert_eq!(Rational::from(123).to_string(), "123");
     /// assert_eq!(Rational::from_str("22/7").unwrap().to_string(), "22/7");
+    /// assert_eq!(Rational::from_str("-22/7").unwrap().to_string(), "-22/7");
+    /// assert_eq!(format!("{:>7}", Rational::from_signeds(-22, 7)), "  -22/7");
+    /// assert_eq!(format!("{:+}", Rational::from_signeds(22, 7)), "+22/7");
+    /// assert_eq!(format!("{:07}", Rational::from_signeds(-22, 7)), "-0022/7");
     /// ```
+    #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if !self.sign {
-            f.write_char('-')?;
-        }
-        let result = Display::fmt(&self.numerator, f);
-        if self.denominator != 1 {
-            f.write_char('/')?;
-            Display::fmt(&self.denominator, f)
-        } else {
-            result
-        }
+        pad_fraction(self, f, "", Natural::to_string)
     }
 }
 
@@ -62,3 +137,199 @@ impl Debug for Rational {
         Display::fmt(self, f)
     }
 }
+
+impl Binary for Rational {
+    /// Converts a [`Rational`] to a binary [`String`].
+    ///
+    /// The numerator and denominator are written in binary and separated by a slash, and the
+    /// denominator is omitted if it is 1. Using the `#` format flag prepends `"0b"` to both
+    /// the numerator and the denominator. Width, fill, alignment, sign, and padding with zeros
+    /// apply to the whole string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:b}", Rational::ZERO), "0");
+    /// assert_eq!(format!("{:b}", Rational::from(-6)), "-110");
+    /// assert_eq!(format!("{:b}", Rational::from_signeds(22, 7)), "10110/111");
+    /// assert_eq!(format!("{:#b}", Rational::from_signeds(-22, 7)), "-0b10110/0b111");
+    /// assert_eq!(format!("{:>#16b}", Rational::from_signeds(22, 7)), "   0b10110/0b111");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        pad_fraction(self, f, "0b", |x| format!("{:b}", x))
+    }
+}
+
+impl Octal for Rational {
+    /// Converts a [`Rational`] to an octal [`String`].
+    ///
+    /// The numerator and denominator are written in octal and separated by a slash, and the
+    /// denominator is omitted if it is 1. Using the `#` format flag prepends `"0o"` to both
+    /// the numerator and the denominator. Width, fill, alignment, sign, and padding with zeros
+    /// apply to the whole string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:o}", Rational::ZERO), "0");
+    /// assert_eq!(format!("{:o}", Rational::from(-64)), "-100");
+    /// assert_eq!(format!("{:o}", Rational::from_signeds(22, 9)), "26/11");
+    /// assert_eq!(format!("{:#o}", Rational::from_signeds(-22, 9)), "-0o26/0o11");
+    /// assert_eq!(format!("{:*<10o}", Rational::from_signeds(22, 9)), "26/11*****");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        pad_fraction(self, f, "0o", |x| format!("{:o}", x))
+    }
+}
+
+impl LowerHex for Rational {
+    /// Converts a [`Rational`] to a hexadecimal [`String`] using lowercase characters.
+    ///
+    /// The numerator and denominator are written in hexadecimal and separated by a slash, and the
+    /// denominator is omitted if it is 1. Using the `#` format flag prepends `"0x"` to both
+    /// the numerator and the denominator. Width, fill, alignment, sign, and padding with zeros
+    /// apply to the whole string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:x}", Rational::ZERO), "0");
+    /// assert_eq!(format!("{:x}", Rational::from(-255)), "-ff");
+    /// assert_eq!(format!("{:x}", Rational::from_signeds(255, 16)), "ff/10");
+    /// assert_eq!(format!("{:#x}", Rational::from_signeds(-255, 16)), "-0xff/0x10");
+    /// assert_eq!(format!("{:+x}", Rational::from_signeds(255, 16)), "+ff/10");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        pad_fraction(self, f, "0x", |x| format!("{:x}", x))
+    }
+}
+
+impl UpperHex for Rational {
+    /// Converts a [`Rational`] to a hexadecimal [`String`] using uppercase characters.
+    ///
+    /// The numerator and denominator are written in hexadecimal and separated by a slash, and the
+    /// denominator is omitted if it is 1. Using the `#` format flag prepends `"0x"` to both
+    /// the numerator and the denominator. Width, fill, alignment, sign, and padding with zeros
+    /// apply to the whole string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:X}", Rational::ZERO), "0");
+    /// assert_eq!(format!("{:X}", Rational::from(-255)), "-FF");
+    /// assert_eq!(format!("{:X}", Rational::from_signeds(255, 16)), "FF/10");
+    /// assert_eq!(format!("{:#X}", Rational::from_signeds(-255, 16)), "-0xFF/0x10");
+    /// assert_eq!(format!("{:^9X}", Rational::from_signeds(255, 16)), "  FF/10  ");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        pad_fraction(self, f, "0x", |x| format!("{:X}", x))
+    }
+}
+
+impl LowerExp for Rational {
+    /// Converts a [`Rational`] to a [`String`] in scientific notation, using a lowercase `e`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even. Otherwise, the value is written exactly if its decimal
+    /// expansion terminates, and is rounded to 16 significant digits if it doesn't; in either
+    /// case, trailing zeros are omitted. Width, fill, alignment, sign, and padding with zeros work
+    /// as they do for the primitive types.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), p)`, where `p` is the precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:e}", Rational::ZERO), "0e0");
+    /// assert_eq!(format!("{:.2e}", Rational::ZERO), "0.00e0");
+    /// assert_eq!(format!("{:e}", Rational::from(1200)), "1.2e3");
+    /// assert_eq!(format!("{:e}", Rational::from_signeds(-3, 8)), "-3.75e-1");
+    /// assert_eq!(format!("{:e}", Rational::from_signeds(1, 3)), "3.333333333333333e-1");
+    /// assert_eq!(format!("{:.2e}", Rational::from_signeds(22, 7)), "3.14e0");
+    /// assert_eq!(format!("{:.1e}", Rational::from_signeds(999, 1000)), "1.0e0");
+    /// assert_eq!(format!("{:+011.2e}", Rational::from_signeds(22, 7)), "+00003.14e0");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_exp(self, f, false)
+    }
+}
+
+impl UpperExp for Rational {
+    /// Converts a [`Rational`] to a [`String`] in scientific notation, using an uppercase `E`.
+    ///
+    /// If a precision is given, the significand is rounded to that many digits after the point,
+    /// with ties broken towards even. Otherwise, the value is written exactly if its decimal
+    /// expansion terminates, and is rounded to 16 significant digits if it doesn't; in either
+    /// case, trailing zeros are omitted. Width, fill, alignment, sign, and padding with zeros work
+    /// as they do for the primitive types.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), p)`, where `p` is the precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(format!("{:E}", Rational::from_signeds(-1, 1024)), "-9.765625E-4");
+    /// assert_eq!(format!("{:.3E}", Rational::from_signeds(2, 3)), "6.667E-1");
+    /// assert_eq!(format!("{:>10E}", Rational::from_signeds(5, 2)), "     2.5E0");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_exp(self, f, true)
+    }
+}
@@ -0,0 +1,330 @@
+use crate::decimal::{power_of_10, scale_difference, Decimal};
+use malachite_base::num::basic::traits::Zero;
+use std::cmp::Ordering;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+impl Add<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!((x + y).to_string(), "1.245");
+    ///
+    /// let x = Decimal::from_str("0.10").unwrap();
+    /// let y = Decimal::from_str("0.20").unwrap();
+    /// assert_eq!((x + y).to_string(), "0.30");
+    /// ```
+    fn add(self, other: Decimal) -> Decimal {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => Decimal {
+                coefficient: self.coefficient + other.coefficient,
+                scale: self.scale,
+            },
+            Ordering::Less => Decimal {
+                coefficient: self.coefficient
+                    * power_of_10(scale_difference(other.scale, self.scale))
+                    + other.coefficient,
+                scale: other.scale,
+            },
+            Ordering::Greater => Decimal {
+                coefficient: self.coefficient
+                    + other.coefficient * power_of_10(scale_difference(self.scale, other.scale)),
+                scale: self.scale,
+            },
+        }
+    }
+}
+
+impl<'a> Add<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!((x + &y).to_string(), "1.245");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &'a Decimal) -> Decimal {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!((&x + y).to_string(), "1.245");
+    /// ```
+    #[inline]
+    fn add(self, other: Decimal) -> Decimal {
+        other + self
+    }
+}
+
+impl<'a, 'b> Add<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!((&x + &y).to_string(), "1.245");
+    /// ```
+    fn add(self, other: &'a Decimal) -> Decimal {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => Decimal {
+                coefficient: &self.coefficient + &other.coefficient,
+                scale: self.scale,
+            },
+            Ordering::Less => Decimal {
+                coefficient: &self.coefficient
+                    * power_of_10(scale_difference(other.scale, self.scale))
+                    + &other.coefficient,
+                scale: other.scale,
+            },
+            Ordering::Greater => Decimal {
+                coefficient: &self.coefficient
+                    + &other.coefficient * power_of_10(scale_difference(self.scale, other.scale)),
+                scale: self.scale,
+            },
+        }
+    }
+}
+
+impl AddAssign<Decimal> for Decimal {
+    /// Adds a [`Decimal`] to a [`Decimal`] in place, taking the [`Decimal`] on the right-hand side
+    /// by value.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x += Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!(x.to_string(), "1.245");
+    /// ```
+    fn add_assign(&mut self, other: Decimal) {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.coefficient += other.coefficient,
+            Ordering::Less => {
+                self.coefficient *= power_of_10(scale_difference(other.scale, self.scale));
+                self.coefficient += other.coefficient;
+                self.scale = other.scale;
+            }
+            Ordering::Greater => {
+                self.coefficient +=
+                    other.coefficient * power_of_10(scale_difference(self.scale, other.scale));
+            }
+        }
+    }
+}
+
+impl<'a> AddAssign<&'a Decimal> for Decimal {
+    /// Adds a [`Decimal`] to a [`Decimal`] in place, taking the [`Decimal`] on the right-hand side
+    /// by reference.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x += &Decimal::from_str("-0.005").unwrap();
+    /// assert_eq!(x.to_string(), "1.245");
+    /// ```
+    fn add_assign(&mut self, other: &'a Decimal) {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.coefficient += &other.coefficient,
+            Ordering::Less => {
+                self.coefficient *= power_of_10(scale_difference(other.scale, self.scale));
+                self.coefficient += &other.coefficient;
+                self.scale = other.scale;
+            }
+            Ordering::Greater => {
+                self.coefficient +=
+                    &other.coefficient * power_of_10(scale_difference(self.scale, other.scale));
+            }
+        }
+    }
+}
+
+impl Sum for Decimal {
+    /// Adds up all the [`Decimal`]s in an iterator.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::iter::Sum;
+    ///
+    /// assert_eq!(
+    ///     Decimal::sum(vec_from_str::<Decimal>("[2, -3.5, 0.25, 7]").unwrap().into_iter())
+    ///         .to_string(),
+    ///     "5.75"
+    /// );
+    /// ```
+    fn sum<I>(xs: I) -> Decimal
+    where
+        I: Iterator<Item = Decimal>,
+    {
+        let mut s = Decimal::ZERO;
+        for x in xs {
+            s += x;
+        }
+        s
+    }
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+    /// Adds up all the [`Decimal`]s in an iterator of [`Decimal`] references.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::iter::Sum;
+    ///
+    /// assert_eq!(
+    ///     Decimal::sum(vec_from_str::<Decimal>("[2, -3.5, 0.25, 7]").unwrap().iter())
+    ///         .to_string(),
+    ///     "5.75"
+    /// );
+    /// ```
+    fn sum<I>(xs: I) -> Decimal
+    where
+        I: Iterator<Item = &'a Decimal>,
+    {
+        let mut s = Decimal::ZERO;
+        for x in xs {
+            s += x;
+        }
+        s
+    }
+}
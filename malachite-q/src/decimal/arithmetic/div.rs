@@ -0,0 +1,85 @@
+use crate::decimal::{power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+
+impl Decimal {
+    /// Divides a [`Decimal`] by another [`Decimal`], returning a [`Decimal`] with a specified
+    /// scale. The quotient is rounded according to a specified rounding mode. Both [`Decimal`]s
+    /// are taken by reference.
+    ///
+    /// Let $q = x/y$ and $s$ be the specified scale. Then
+    ///
+    /// $f(x, y, s, \mathrm{Down}) = \operatorname{sgn}(q) \lfloor |q| 10^s \rfloor 10^{-s}.$
+    ///
+    /// $f(x, y, s, \mathrm{Up}) = \operatorname{sgn}(q) \lceil |q| 10^s \rceil 10^{-s}.$
+    ///
+    /// $f(x, y, s, \mathrm{Floor}) = \lfloor q 10^s \rfloor 10^{-s}.$
+    ///
+    /// $f(x, y, s, \mathrm{Ceiling}) = \lceil q 10^s \rceil 10^{-s}.$
+    ///
+    /// $$
+    /// f(x, y, s, \mathrm{Nearest}) = \begin{cases}
+    ///     \lfloor q 10^s \rfloor 10^{-s} & \text{if}
+    ///         \\quad q 10^s - \lfloor q 10^s \rfloor < \frac{1}{2}, \\\\
+    ///     \lceil q 10^s \rceil 10^{-s} & \text{if}
+    ///         \\quad q 10^s - \lfloor q 10^s \rfloor > \frac{1}{2}, \\\\
+    ///     \lfloor q 10^s \rfloor 10^{-s} & \text{if}
+    ///         \\quad q 10^s - \lfloor q 10^s \rfloor = \frac{1}{2}
+    ///         \\ \text{and} \\ \lfloor q 10^s \rfloor \\ \text{is even}, \\\\
+    ///     \lceil q 10^s \rceil 10^{-s} & \text{if}
+    ///         \\quad q 10^s - \lfloor q 10^s \rfloor = \frac{1}{2}
+    ///         \\ \text{and} \\ \lfloor q 10^s \rfloor \\ \text{is odd.}
+    /// \end{cases}
+    /// $$
+    ///
+    /// $f(x, y, s, \mathrm{Exact}) = q$, but panics if $q 10^s$ is not an integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |scale + other.scale() - self.scale()|`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient cannot be represented
+    /// exactly with the specified scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("10").unwrap();
+    /// let y = Decimal::from_str("3").unwrap();
+    /// assert_eq!(x.div_round_to_scale(&y, 4, RoundingMode::Floor).to_string(), "3.3333");
+    /// assert_eq!(x.div_round_to_scale(&y, 4, RoundingMode::Ceiling).to_string(), "3.3334");
+    /// assert_eq!(x.div_round_to_scale(&y, -1, RoundingMode::Nearest).to_string(), "0e1");
+    ///
+    /// let x = Decimal::from_str("-2.5").unwrap();
+    /// let y = Decimal::from_str("0.04").unwrap();
+    /// assert_eq!(x.div_round_to_scale(&y, 2, RoundingMode::Exact).to_string(), "-62.50");
+    /// assert_eq!(x.div_round_to_scale(&y, 0, RoundingMode::Nearest).to_string(), "-62");
+    /// assert_eq!(x.div_round_to_scale(&y, 0, RoundingMode::Down).to_string(), "-62");
+    /// assert_eq!(x.div_round_to_scale(&y, 0, RoundingMode::Up).to_string(), "-63");
+    /// ```
+    pub fn div_round_to_scale(&self, other: &Decimal, scale: i64, rm: RoundingMode) -> Decimal {
+        assert!(other.coefficient != 0u32, "division by zero");
+        // x / y * 10^scale = c_x * 10^(s_y + scale - s_x) / c_y
+        let exponent = i128::from(other.scale) + i128::from(scale) - i128::from(self.scale);
+        let coefficient = if exponent >= 0 {
+            (&self.coefficient * power_of_10(u64::exact_from(exponent)))
+                .div_round(&other.coefficient, rm)
+        } else {
+            (&self.coefficient).div_round(
+                &other.coefficient * power_of_10(u64::exact_from(-exponent)),
+                rm,
+            )
+        };
+        Decimal { coefficient, scale }
+    }
+}
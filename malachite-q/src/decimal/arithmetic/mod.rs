@@ -0,0 +1,13 @@
+/// Addition of [`Decimal`](super::Decimal)s.
+pub mod add;
+/// Division of [`Decimal`](super::Decimal)s to a specified scale, with a specified rounding mode.
+pub mod div;
+/// Multiplication of [`Decimal`](super::Decimal)s.
+pub mod mul;
+/// Negation of a [`Decimal`](super::Decimal).
+pub mod neg;
+/// Functions for changing the scale of a [`Decimal`](super::Decimal), and for removing trailing
+/// zeros from its coefficient.
+pub mod rescale;
+/// Subtraction of [`Decimal`](super::Decimal)s.
+pub mod sub;
@@ -0,0 +1,305 @@
+use crate::decimal::Decimal;
+use malachite_base::num::basic::traits::One;
+use std::iter::Product;
+use std::ops::{Mul, MulAssign};
+
+// The scale of a product is the sum of the scales of its factors.
+fn product_scale(x: i64, y: i64) -> i64 {
+    x.checked_add(y).expect("Decimal scale overflow")
+}
+
+impl Mul<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!((x * y).to_string(), "-0.500");
+    /// ```
+    fn mul(self, other: Decimal) -> Decimal {
+        Decimal {
+            coefficient: self.coefficient * other.coefficient,
+            scale: product_scale(self.scale, other.scale),
+        }
+    }
+}
+
+impl<'a> Mul<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!((x * &y).to_string(), "-0.500");
+    /// ```
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        Decimal {
+            coefficient: self.coefficient * &other.coefficient,
+            scale: product_scale(self.scale, other.scale),
+        }
+    }
+}
+
+impl<'a> Mul<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!((&x * y).to_string(), "-0.500");
+    /// ```
+    fn mul(self, other: Decimal) -> Decimal {
+        Decimal {
+            coefficient: &self.coefficient * other.coefficient,
+            scale: product_scale(self.scale, other.scale),
+        }
+    }
+}
+
+impl<'a, 'b> Mul<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!((&x * &y).to_string(), "-0.500");
+    /// ```
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        Decimal {
+            coefficient: &self.coefficient * &other.coefficient,
+            scale: product_scale(self.scale, other.scale),
+        }
+    }
+}
+
+impl MulAssign<Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by a [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by value.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x *= Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!(x.to_string(), "-0.500");
+    /// ```
+    fn mul_assign(&mut self, other: Decimal) {
+        self.scale = product_scale(self.scale, other.scale);
+        self.coefficient *= other.coefficient;
+    }
+}
+
+impl<'a> MulAssign<&'a Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by a [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// The scale of the result is the sum of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + other.coefficient_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x *= &Decimal::from_str("-0.4").unwrap();
+    /// assert_eq!(x.to_string(), "-0.500");
+    /// ```
+    fn mul_assign(&mut self, other: &'a Decimal) {
+        self.scale = product_scale(self.scale, other.scale);
+        self.coefficient *= &other.coefficient;
+    }
+}
+
+impl Product for Decimal {
+    /// Multiplies together all the [`Decimal`]s in an iterator.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::iter::Product;
+    ///
+    /// assert_eq!(
+    ///     Decimal::product(vec_from_str::<Decimal>("[2, -3.5, 0.25]").unwrap().into_iter())
+    ///         .to_string(),
+    ///     "-1.750"
+    /// );
+    /// ```
+    fn product<I>(xs: I) -> Decimal
+    where
+        I: Iterator<Item = Decimal>,
+    {
+        let mut p = Decimal::ONE;
+        for x in xs {
+            p *= x;
+        }
+        p
+    }
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+    /// Multiplies together all the [`Decimal`]s in an iterator of [`Decimal`] references.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not representable as an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::iter::Product;
+    ///
+    /// assert_eq!(
+    ///     Decimal::product(vec_from_str::<Decimal>("[2, -3.5, 0.25]").unwrap().iter())
+    ///         .to_string(),
+    ///     "-1.750"
+    /// );
+    /// ```
+    fn product<I>(xs: I) -> Decimal
+    where
+        I: Iterator<Item = &'a Decimal>,
+    {
+        let mut p = Decimal::ONE;
+        for x in xs {
+            p *= x;
+        }
+        p
+    }
+}
@@ -0,0 +1,91 @@
+use crate::decimal::Decimal;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by value. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((-Decimal::from_str("0.00").unwrap()).to_string(), "0.00");
+    /// assert_eq!((-Decimal::from_str("1.50").unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-Decimal::from_str("-1.50").unwrap()).to_string(), "1.50");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Decimal {
+        self.coefficient.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by reference. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((-&Decimal::from_str("0.00").unwrap()).to_string(), "0.00");
+    /// assert_eq!((-&Decimal::from_str("1.50").unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-&Decimal::from_str("-1.50").unwrap()).to_string(), "1.50");
+    /// ```
+    fn neg(self) -> Decimal {
+        Decimal {
+            coefficient: -&self.coefficient,
+            scale: self.scale,
+        }
+    }
+}
+
+impl NegAssign for Decimal {
+    /// Negates a [`Decimal`] in place. The scale is unchanged.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.50").unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-1.50");
+    /// ```
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.coefficient.neg_assign();
+    }
+}
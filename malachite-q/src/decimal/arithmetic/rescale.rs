@@ -0,0 +1,158 @@
+use crate::decimal::{power_of_10, scale_difference, Decimal};
+use malachite_base::num::arithmetic::traits::{
+    DivExactAssign, DivRound, DivRoundAssign, DivisibleBy,
+};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+impl Decimal {
+    /// Changes the scale of a [`Decimal`], rounding according to a specified rounding mode if the
+    /// new scale is smaller than the old one. The [`Decimal`] is taken by reference.
+    ///
+    /// If the new scale is larger than or equal to the old scale, the value does not change and no
+    /// rounding takes place; the coefficient is just multiplied by a power of 10.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + |scale - self.scale()|`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the [`Decimal`] cannot be represented exactly with the new
+    /// scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("123.455").unwrap();
+    /// assert_eq!(x.rescale(5, RoundingMode::Exact).to_string(), "123.45500");
+    /// assert_eq!(x.rescale(2, RoundingMode::Nearest).to_string(), "123.46");
+    /// assert_eq!(x.rescale(2, RoundingMode::Floor).to_string(), "123.45");
+    /// assert_eq!(x.rescale(0, RoundingMode::Ceiling).to_string(), "124");
+    /// assert_eq!(x.rescale(-2, RoundingMode::Nearest).to_string(), "1e2");
+    /// ```
+    pub fn rescale(&self, scale: i64, rm: RoundingMode) -> Decimal {
+        let coefficient = match scale.cmp(&self.scale) {
+            Ordering::Equal => self.coefficient.clone(),
+            Ordering::Greater => {
+                &self.coefficient * power_of_10(scale_difference(scale, self.scale))
+            }
+            Ordering::Less => {
+                (&self.coefficient).div_round(power_of_10(scale_difference(self.scale, scale)), rm)
+            }
+        };
+        Decimal { coefficient, scale }
+    }
+
+    /// Changes the scale of a [`Decimal`] in place, rounding according to a specified rounding
+    /// mode if the new scale is smaller than the old one.
+    ///
+    /// If the new scale is larger than or equal to the old scale, the value does not change and no
+    /// rounding takes place; the coefficient is just multiplied by a power of 10.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + |scale - self.scale()|`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the [`Decimal`] cannot be represented exactly with the new
+    /// scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("-123.455").unwrap();
+    /// x.rescale_assign(2, RoundingMode::Down);
+    /// assert_eq!(x.to_string(), "-123.45");
+    /// x.rescale_assign(4, RoundingMode::Exact);
+    /// assert_eq!(x.to_string(), "-123.4500");
+    /// ```
+    pub fn rescale_assign(&mut self, scale: i64, rm: RoundingMode) {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                self.coefficient *= power_of_10(scale_difference(scale, self.scale));
+            }
+            Ordering::Less => self
+                .coefficient
+                .div_round_assign(power_of_10(scale_difference(self.scale, scale)), rm),
+        }
+        self.scale = scale;
+    }
+
+    /// Removes trailing zeros from the coefficient of a [`Decimal`], returning the representation
+    /// of the same value with the smallest possible scale. The [`Decimal`] is taken by reference.
+    ///
+    /// Zero is normalized to a coefficient of 0 and a scale of 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("123.4500").unwrap().normalize().to_string(), "123.45");
+    /// assert_eq!(Decimal::from_str("1200").unwrap().normalize().to_string(), "12e2");
+    /// assert_eq!(Decimal::from_str("0.000").unwrap().normalize().to_string(), "0");
+    /// ```
+    pub fn normalize(&self) -> Decimal {
+        let mut x = self.clone();
+        x.normalize_assign();
+        x
+    }
+
+    /// Removes trailing zeros from the coefficient of a [`Decimal`] in place, leaving the
+    /// representation of the same value with the smallest possible scale.
+    ///
+    /// Zero is normalized to a coefficient of 0 and a scale of 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("-5.0e-3").unwrap();
+    /// x.normalize_assign();
+    /// assert_eq!(x.to_string(), "-0.005");
+    /// ```
+    pub fn normalize_assign(&mut self) {
+        if self.coefficient == 0u32 {
+            self.scale = 0;
+            return;
+        }
+        let ten = Integer::from(10u32);
+        while (&self.coefficient).divisible_by(&ten) {
+            self.coefficient.div_exact_assign(&ten);
+            self.scale = self.scale.checked_sub(1).expect("Decimal scale overflow");
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::decimal::{power_of_10, scale_difference, Decimal};
+use std::cmp::Ordering;
+use std::ops::{Sub, SubAssign};
+
+impl Sub<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("0.005").unwrap();
+    /// assert_eq!((x - y).to_string(), "1.245");
+    ///
+    /// let x = Decimal::from_str("0.10").unwrap();
+    /// let y = Decimal::from_str("0.10").unwrap();
+    /// assert_eq!((x - y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        self + -other
+    }
+}
+
+impl<'a> Sub<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking the first by value and the second
+    /// by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("0.005").unwrap();
+    /// assert_eq!((x - &y).to_string(), "1.245");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &'a Decimal) -> Decimal {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking the first by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("0.005").unwrap();
+    /// assert_eq!((&x - y).to_string(), "1.245");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        self + -other
+    }
+}
+
+impl<'a, 'b> Sub<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.25").unwrap();
+    /// let y = Decimal::from_str("0.005").unwrap();
+    /// assert_eq!((&x - &y).to_string(), "1.245");
+    /// ```
+    fn sub(self, other: &'a Decimal) -> Decimal {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => Decimal {
+                coefficient: &self.coefficient - &other.coefficient,
+                scale: self.scale,
+            },
+            Ordering::Less => Decimal {
+                coefficient: &self.coefficient
+                    * power_of_10(scale_difference(other.scale, self.scale))
+                    - &other.coefficient,
+                scale: other.scale,
+            },
+            Ordering::Greater => Decimal {
+                coefficient: &self.coefficient
+                    - &other.coefficient * power_of_10(scale_difference(self.scale, other.scale)),
+                scale: self.scale,
+            },
+        }
+    }
+}
+
+impl SubAssign<Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x -= Decimal::from_str("0.005").unwrap();
+    /// assert_eq!(x.to_string(), "1.245");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Decimal) {
+        *self += -other;
+    }
+}
+
+impl<'a> SubAssign<&'a Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// The scale of the result is the larger of the two input scales, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(), other.coefficient_ref().significant_bits())
+    /// + |self.scale() - other.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let mut x = Decimal::from_str("1.25").unwrap();
+    /// x -= &Decimal::from_str("0.005").unwrap();
+    /// assert_eq!(x.to_string(), "1.245");
+    /// ```
+    fn sub_assign(&mut self, other: &'a Decimal) {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.coefficient -= &other.coefficient,
+            Ordering::Less => {
+                self.coefficient *= power_of_10(scale_difference(other.scale, self.scale));
+                self.coefficient -= &other.coefficient;
+                self.scale = other.scale;
+            }
+            Ordering::Greater => {
+                self.coefficient -=
+                    &other.coefficient * power_of_10(scale_difference(self.scale, other.scale));
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::decimal::{power_of_10, scale_difference, Decimal};
+use malachite_base::num::arithmetic::traits::{FloorLogBase, Sign};
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Returns the exponent of the leading digit of a nonzero `Decimal`, $\lfloor\log_{10} |x|\rfloor$.
+// It's returned as an `i128`, since the digit count minus the scale may not fit in an `i64`.
+fn leading_digit_exponent(x: &Decimal) -> i128 {
+    let digits_minus_1 = x
+        .coefficient
+        .unsigned_abs_ref()
+        .floor_log_base(&Natural::from(10u32));
+    i128::from(digits_minus_1) - i128::from(x.scale)
+}
+
+impl PartialOrd for Decimal {
+    /// Compares two [`Decimal`]s by value.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compares two [`Decimal`]s by value. The scales do not matter, except insofar as they affect
+    /// the values: $1.5$ and $1.50$ are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(),
+    /// other.coefficient_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(Decimal::from_str("2.5").unwrap() > Decimal::from_str("2.49").unwrap());
+    /// assert!(Decimal::from_str("-2.5").unwrap() < Decimal::from_str("-2.49").unwrap());
+    /// assert_eq!(
+    ///     Decimal::from_str("1.5").unwrap().cmp(&Decimal::from_str("1.500").unwrap()),
+    ///     Ordering::Equal
+    /// );
+    /// assert!(
+    ///     Decimal::from_str("1e-9000000000000000000").unwrap() < Decimal::from_str("1").unwrap()
+    /// );
+    /// ```
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        if std::ptr::eq(self, other) {
+            return Ordering::Equal;
+        }
+        // First check signs
+        let self_sign = self.coefficient.sign();
+        let other_sign = other.coefficient.sign();
+        let sign_cmp = self_sign.cmp(&other_sign);
+        if sign_cmp != Ordering::Equal || self_sign == Ordering::Equal {
+            return sign_cmp;
+        }
+        // Then compare the positions of the leading digits. If they differ, so do the magnitudes,
+        // and the scales may be arbitrarily far apart, so don't rescale.
+        let magnitude_cmp = leading_digit_exponent(self).cmp(&leading_digit_exponent(other));
+        if magnitude_cmp != Ordering::Equal {
+            return if self_sign == Ordering::Greater {
+                magnitude_cmp
+            } else {
+                magnitude_cmp.reverse()
+            };
+        }
+        // Otherwise the difference in scales equals the difference in digit counts, so bringing
+        // the coefficients to a common scale is cheap.
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.coefficient.cmp(&other.coefficient),
+            Ordering::Less => (&self.coefficient
+                * power_of_10(scale_difference(other.scale, self.scale)))
+            .cmp(&other.coefficient),
+            Ordering::Greater => self.coefficient.cmp(
+                &(&other.coefficient * power_of_10(scale_difference(self.scale, other.scale))),
+            ),
+        }
+    }
+}
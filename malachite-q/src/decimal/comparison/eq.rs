@@ -0,0 +1,39 @@
+use crate::decimal::Decimal;
+use std::cmp::Ordering;
+
+impl PartialEq for Decimal {
+    /// Determines whether two [`Decimal`]s are equal by value. The scales do not matter, except
+    /// insofar as they affect the values: $1.5$ and $1.50$ are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits(),
+    /// other.coefficient_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("1.5").unwrap(), Decimal::from_str("1.500").unwrap());
+    /// assert_eq!(Decimal::from_str("0").unwrap(), Decimal::from_str("-0.00").unwrap());
+    /// assert_ne!(Decimal::from_str("1.5").unwrap(), Decimal::from_str("1.05").unwrap());
+    /// assert_ne!(
+    ///     Decimal::from_str("1e-9000000000000000000").unwrap(),
+    ///     Decimal::from_str("1").unwrap()
+    /// );
+    /// ```
+    fn eq(&self, other: &Decimal) -> bool {
+        if self.scale == other.scale {
+            self.coefficient == other.coefficient
+        } else {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+}
+
+impl Eq for Decimal {}
@@ -0,0 +1,22 @@
+use crate::decimal::Decimal;
+use std::hash::{Hash, Hasher};
+
+impl Hash for Decimal {
+    /// Hashes a [`Decimal`].
+    ///
+    /// Since equal [`Decimal`]s may have different scales, the hash is computed from the
+    /// [normalized](Decimal::normalize) representation; $1.5$ and $1.50$ have the same hash.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.coefficient.hash(state);
+        normalized.scale.hash(state);
+    }
+}
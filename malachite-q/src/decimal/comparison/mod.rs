@@ -0,0 +1,7 @@
+/// Comparison of [`Decimal`](super::Decimal)s by value.
+pub mod cmp;
+/// Equality of [`Decimal`](super::Decimal)s by value.
+pub mod eq;
+/// An implementation of [`Hash`](std::hash::Hash) for [`Decimal`](super::Decimal)s that is
+/// consistent with equality by value.
+pub mod hash;
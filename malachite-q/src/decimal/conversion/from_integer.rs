@@ -0,0 +1,52 @@
+use crate::decimal::Decimal;
+use malachite_nz::integer::Integer;
+
+impl From<Integer> for Decimal {
+    /// Converts an [`Integer`](malachite_nz::integer::Integer) to a [`Decimal`] with scale 0,
+    /// taking the [`Integer`](malachite_nz::integer::Integer) by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from(Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(value: Integer) -> Decimal {
+        Decimal {
+            coefficient: value,
+            scale: 0,
+        }
+    }
+}
+
+impl<'a> From<&'a Integer> for Decimal {
+    /// Converts an [`Integer`](malachite_nz::integer::Integer) to a [`Decimal`] with scale 0,
+    /// taking the [`Integer`](malachite_nz::integer::Integer) by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from(&Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(value: &'a Integer) -> Decimal {
+        Decimal {
+            coefficient: value.clone(),
+            scale: 0,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::decimal::Decimal;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when converting a [`Rational`] to a [`Decimal`] fails because the
+/// [`Rational`] has a non-terminating decimal expansion; that is, because its denominator has a
+/// prime factor other than 2 or 5.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecimalFromRationalError;
+
+impl Display for DecimalFromRationalError {
+    /// Writes a description of a [`DecimalFromRationalError`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::conversion::from_rational::DecimalFromRationalError;
+    ///
+    /// assert_eq!(
+    ///     DecimalFromRationalError.to_string(),
+    ///     "the Rational has a non-terminating decimal expansion"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("the Rational has a non-terminating decimal expansion")
+    }
+}
+
+impl<'a> TryFrom<&'a Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by reference. If the
+    /// [`Rational`] has a non-terminating decimal expansion (that is, if its denominator has a
+    /// prime factor other than 2 or 5), an error is returned.
+    ///
+    /// The result has the smallest non-negative scale that represents the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::conversion::from_rational::DecimalFromRationalError;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Decimal::try_from(&Rational::from(123)).unwrap().to_string(), "123");
+    /// assert_eq!(Decimal::try_from(&Rational::from_signeds(-5, 4)).unwrap().to_string(), "-1.25");
+    /// assert_eq!(Decimal::try_from(&Rational::from_signeds(1, 3)), Err(DecimalFromRationalError));
+    /// ```
+    fn try_from(x: &'a Rational) -> Result<Decimal, Self::Error> {
+        let scale = x
+            .length_after_point_in_small_base(10)
+            .ok_or(DecimalFromRationalError)?;
+        Ok(Decimal {
+            coefficient: Integer::exact_from(x * Rational::from(10u32).pow(scale)),
+            scale: i64::exact_from(scale),
+        })
+    }
+}
+
+impl TryFrom<Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by value. If the
+    /// [`Rational`] has a non-terminating decimal expansion (that is, if its denominator has a
+    /// prime factor other than 2 or 5), an error is returned.
+    ///
+    /// The result has the smallest non-negative scale that represents the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::conversion::from_rational::DecimalFromRationalError;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Decimal::try_from(Rational::from(123)).unwrap().to_string(), "123");
+    /// assert_eq!(Decimal::try_from(Rational::from_signeds(-5, 4)).unwrap().to_string(), "-1.25");
+    /// assert_eq!(Decimal::try_from(Rational::from_signeds(1, 3)), Err(DecimalFromRationalError));
+    /// ```
+    #[inline]
+    fn try_from(x: Rational) -> Result<Decimal, Self::Error> {
+        Decimal::try_from(&x)
+    }
+}
+
+impl<'a> ConvertibleFrom<&'a Rational> for Decimal {
+    /// Determines whether a [`Rational`] can be converted to a [`Decimal`] exactly; that is,
+    /// whether its denominator has no prime factors other than 2 and 5.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!(Decimal::convertible_from(&Rational::from_signeds(-5, 4)));
+    /// assert!(Decimal::convertible_from(&Rational::from_signeds(7, 50)));
+    /// assert!(!Decimal::convertible_from(&Rational::from_signeds(1, 3)));
+    /// ```
+    #[inline]
+    fn convertible_from(x: &'a Rational) -> bool {
+        x.length_after_point_in_small_base(10).is_some()
+    }
+}
+
+impl Decimal {
+    /// Converts a [`Rational`] to a [`Decimal`] with a specified scale, rounding according to a
+    /// specified rounding mode.
+    ///
+    /// This is equivalent to rounding the [`Rational`] to a multiple of $10^{-s}$, but it avoids
+    /// creating an intermediate [`Rational`] with a non-decimal denominator.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `x.significant_bits() + |scale|`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the [`Rational`] cannot be represented exactly with the
+    /// specified scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// let q = Rational::from_signeds(2, 3);
+    /// assert_eq!(
+    ///     Decimal::rounding_from_rational(&q, 3, RoundingMode::Floor).to_string(),
+    ///     "0.666"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::rounding_from_rational(&q, 3, RoundingMode::Nearest).to_string(),
+    ///     "0.667"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::rounding_from_rational(&Rational::from(1234), -2, RoundingMode::Down)
+    ///         .to_string(),
+    ///     "12e2"
+    /// );
+    /// ```
+    pub fn rounding_from_rational(x: &Rational, scale: i64, rm: RoundingMode) -> Decimal {
+        Decimal {
+            coefficient: Integer::rounding_from(x * Rational::from(10u32).pow(scale), rm),
+            scale,
+        }
+    }
+}
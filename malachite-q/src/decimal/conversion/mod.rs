@@ -0,0 +1,15 @@
+/// Implementations of the [`From`] trait for converting an
+/// [`Integer`](malachite_nz::integer::Integer) to a [`Decimal`](super::Decimal).
+pub mod from_integer;
+/// Implementations of traits for converting a [`Rational`](crate::Rational) to a
+/// [`Decimal`](super::Decimal).
+///
+/// The traits are [`TryFrom`] and
+/// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom). There is also a
+/// function for converting with a specified scale and rounding mode.
+pub mod from_rational;
+/// Implementations of the [`From`] trait for converting a [`Decimal`](super::Decimal) to a
+/// [`Rational`](crate::Rational).
+pub mod rational_from_decimal;
+/// Functions for converting [`Decimal`](super::Decimal)s to and from strings.
+pub mod string;
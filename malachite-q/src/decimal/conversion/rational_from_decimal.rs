@@ -0,0 +1,57 @@
+use crate::decimal::Decimal;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Pow;
+
+impl From<Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by value. The conversion
+    /// is always exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `value.coefficient_ref().significant_bits() + |value.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Rational::from(Decimal::from_str("-1.250").unwrap()).to_string(), "-5/4");
+    /// assert_eq!(Rational::from(Decimal::from_str("12e3").unwrap()).to_string(), "12000");
+    /// ```
+    #[inline]
+    fn from(value: Decimal) -> Rational {
+        Rational::from(value.coefficient) / Rational::from(10u32).pow(value.scale)
+    }
+}
+
+impl<'a> From<&'a Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by reference. The
+    /// conversion is always exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `value.coefficient_ref().significant_bits() + |value.scale()|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Rational::from(&Decimal::from_str("-1.250").unwrap()).to_string(), "-5/4");
+    /// assert_eq!(Rational::from(&Decimal::from_str("12e3").unwrap()).to_string(), "12000");
+    /// ```
+    #[inline]
+    fn from(value: &'a Decimal) -> Rational {
+        Rational::from(&value.coefficient) / Rational::from(10u32).pow(value.scale)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::decimal::Decimal;
+use crate::Rational;
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::FromSciString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::string::from_sci_string::FromSciStringHelper;
+
+impl FromSciString for Decimal {
+    /// Converts a string, possibly in scientfic notation, to a [`Decimal`].
+    ///
+    /// Use
+    /// [`FromSciStringOptions`](malachite_base::num::conversion::string::options::FromSciStringOptions)
+    /// to specify the base (from 2 to 36, inclusive). The rounding mode option is ignored.
+    ///
+    /// In base 10, the scale of the result is determined by the digits in the string: the number
+    /// of digits after the decimal point, minus the exponent. So `"1.50"` has scale 2, and
+    /// `"1.5e3"` has scale $-2$.
+    ///
+    /// In other bases, the string is first parsed as a [`Rational`], which is then converted to a
+    /// [`Decimal`] with the smallest non-negative scale that represents it exactly. If the number
+    /// has no terminating decimal expansion, `None` is returned.
+    ///
+    /// If the base is greater than 10, the higher digits are represented by the letters `'a'`
+    /// through `'z'` or `'A'` through `'Z'`; the case doesn't matter and doesn't need to be
+    /// consistent.
+    ///
+    /// Exponents are allowed, and are indicated using the character `'e'` or `'E'`. If the base is
+    /// 15 or greater, an ambiguity arises where it may not be clear whether `'e'` is a digit or
+    /// an exponent indicator. To resolve this ambiguity, always use a `'+'` or `'-'` sign after
+    /// the exponent indicator when the base is 15 or greater.
+    ///
+    /// The exponent itself is always parsed using base 10.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is `options.base`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::traits::FromSciString;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from_sci_string("123").unwrap().to_string(), "123");
+    /// assert_eq!(Decimal::from_sci_string("0.10").unwrap().to_string(), "0.10");
+    /// assert_eq!(Decimal::from_sci_string("1.2e5").unwrap().to_string(), "12e4");
+    /// assert_eq!(Decimal::from_sci_string("1.2e-5").unwrap().to_string(), "0.000012");
+    /// assert!(Decimal::from_sci_string("1.2.3").is_none());
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// assert_eq!(
+    ///     Decimal::from_sci_string_with_options("ff.8", options).unwrap().to_string(),
+    ///     "255.5"
+    /// );
+    ///
+    /// options.set_base(3);
+    /// assert!(Decimal::from_sci_string_with_options("0.1", options).is_none());
+    /// ```
+    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Decimal> {
+        if options.get_base() == 10 {
+            let (s, exponent) = preprocess_sci_string(s, options)?;
+            Some(Decimal {
+                coefficient: Integer::parse_int(&s, 10)?,
+                scale: exponent.checked_neg()?,
+            })
+        } else {
+            Decimal::try_from(Rational::from_sci_string_with_options(s, options)?).ok()
+        }
+    }
+}
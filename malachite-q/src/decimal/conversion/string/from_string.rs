@@ -0,0 +1,139 @@
+use crate::decimal::Decimal;
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+// Parses the digits of an exponent, which start at byte `start` of `s` and may be preceded by a
+// sign. `number_start` is the index of the first byte of the number after its sign, which is where
+// an `OutOfRange` error is reported.
+fn parse_exponent(s: &str, start: usize, number_start: usize) -> Result<i64, ParseNumberError> {
+    let bytes = s.as_bytes();
+    let (negative, digits_start) = match bytes.get(start) {
+        Some(b'-') => (true, start + 1),
+        Some(b'+') => (false, start + 1),
+        _ => (false, start),
+    };
+    if digits_start == bytes.len() {
+        return Err(ParseNumberError::new(
+            ParseNumberErrorKind::Empty,
+            digits_start,
+        ));
+    }
+    let mut exponent = 0i64;
+    for (i, &b) in bytes.iter().enumerate().skip(digits_start) {
+        if !b.is_ascii_digit() {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, i));
+        }
+        let digit = i64::from(b - b'0');
+        exponent = exponent
+            .checked_mul(10)
+            .and_then(|e| {
+                if negative {
+                    e.checked_sub(digit)
+                } else {
+                    e.checked_add(digit)
+                }
+            })
+            .ok_or_else(|| ParseNumberError::new(ParseNumberErrorKind::OutOfRange, number_start))?;
+    }
+    Ok(exponent)
+}
+
+impl FromStr for Decimal {
+    type Err = ParseNumberError;
+
+    /// Converts a string to a [`Decimal`].
+    ///
+    /// The string may contain a decimal point and an exponent, and the scale of the result is
+    /// determined by the digits that appear in the string: `"1.50"` has scale 2 and `"15e1"` has
+    /// scale $-1$. This is the inverse of [`Display`](std::fmt::Display) for [`Decimal`]s. For
+    /// other bases, see [`Decimal::from_sci_string_with_options`].
+    ///
+    /// If the string does not represent a valid [`Decimal`], an `Err` is returned, describing the
+    /// problem and where in the string it was found. If the scale of the result would not fit in
+    /// an [`i64`], the error is [`OutOfRange`](ParseNumberErrorKind::OutOfRange).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::from_string::{
+    ///     ParseNumberError, ParseNumberErrorKind,
+    /// };
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("123.450").unwrap().to_string(), "123.450");
+    /// assert_eq!(Decimal::from_str("-0.05").unwrap().to_string(), "-0.05");
+    /// assert_eq!(Decimal::from_str("1.5e3").unwrap().to_string(), "15e2");
+    /// assert_eq!(Decimal::from_str("1.5e-3").unwrap().to_string(), "0.0015");
+    ///
+    /// assert_eq!(
+    ///     Decimal::from_str(""),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0))
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_str("1/2"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1))
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_str("1.2.3"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3))
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_str("1e99999999999999999999"),
+    ///     Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0))
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Decimal, ParseNumberError> {
+        let bytes = s.as_bytes();
+        let (negative, start) = match bytes.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let mut digits = String::with_capacity(bytes.len() - start);
+        let mut fraction_len = 0usize;
+        let mut point_seen = false;
+        let mut exponent = 0i64;
+        for (i, &b) in bytes.iter().enumerate().skip(start) {
+            match b {
+                b'0'..=b'9' => {
+                    digits.push(char::from(b));
+                    if point_seen {
+                        fraction_len += 1;
+                    }
+                }
+                b'.' if !point_seen => point_seen = true,
+                b'e' | b'E' if !digits.is_empty() => {
+                    exponent = parse_exponent(s, i + 1, start)?;
+                    break;
+                }
+                _ => {
+                    return Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, i));
+                }
+            }
+        }
+        if digits.is_empty() {
+            return Err(ParseNumberError::new(ParseNumberErrorKind::Empty, start));
+        }
+        let scale = i64::try_from(fraction_len)
+            .ok()
+            .and_then(|f| f.checked_sub(exponent))
+            .ok_or_else(|| ParseNumberError::new(ParseNumberErrorKind::OutOfRange, start))?;
+        let abs = Natural::from_string_base(10, &digits).unwrap();
+        Ok(Decimal {
+            coefficient: Integer::from_sign_and_abs(!negative, abs),
+            scale,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+/// An implementation of
+/// [`FromSciString`](malachite_base::num::conversion::traits::FromSciString) for
+/// [`Decimal`](crate::decimal::Decimal)s.
+pub mod from_sci_string;
+/// An implementation of [`FromStr`](std::str::FromStr) for [`Decimal`](crate::decimal::Decimal)s.
+pub mod from_string;
+/// An implementation of [`ToSci`](malachite_base::num::conversion::traits::ToSci) for
+/// [`Decimal`](crate::decimal::Decimal)s.
+pub mod to_sci;
+/// Implementations of [`Display`](std::fmt::Display) and [`Debug`] for
+/// [`Decimal`](crate::decimal::Decimal)s.
+pub mod to_string;
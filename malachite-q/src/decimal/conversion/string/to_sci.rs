@@ -0,0 +1,97 @@
+use crate::decimal::Decimal;
+use crate::Rational;
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::ToSci;
+use std::fmt::Formatter;
+
+impl ToSci for Decimal {
+    /// Determines whether a [`Decimal`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// This is the same as for the [`Rational`] with the same value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits() + |self.scale()|, s)`, where `s` depends on
+    /// the size type specified in `options`. See the documentation for the
+    /// [`Rational`](crate::Rational) implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("123.456").unwrap();
+    /// let mut options = ToSciOptions::default();
+    /// options.set_size_complete();
+    /// assert!(x.fmt_sci_valid(options));
+    ///
+    /// options.set_precision(4);
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert!(!x.fmt_sci_valid(options));
+    /// ```
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        Rational::from(self).fmt_sci_valid(options)
+    }
+
+    /// Converts a [`Decimal`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// The output is the same as for the [`Rational`] with the same value, so the scale of the
+    /// [`Decimal`] is not taken into account; use
+    /// [`set_scale`](malachite_base::num::conversion::string::options::ToSciOptions::set_scale)
+    /// and
+    /// [`set_include_trailing_zeros`](malachite_base::num::conversion::string::options::ToSciOptions::set_include_trailing_zeros)
+    /// to control the number of digits after the point. See
+    /// [`ToSciOptions`](malachite_base::num::conversion::string::options::ToSciOptions) for
+    /// details on the available options.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.coefficient_ref().significant_bits() + |self.scale()|, s)`, where `s` depends on
+    /// the size type specified in `options`. See the documentation for the
+    /// [`Rational`](crate::Rational) implementation.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("123456.789").unwrap();
+    /// assert_eq!(x.to_sci().to_string(), "123456.789");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(3);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.23e5");
+    ///
+    /// options.set_scale(5);
+    /// options.set_include_trailing_zeros(true);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "123456.78900");
+    ///
+    /// options = ToSciOptions::default();
+    /// options.set_base(16);
+    /// options.set_precision(6);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1e240.d");
+    /// ```
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+        Rational::from(self).fmt_sci(f, options)
+    }
+}
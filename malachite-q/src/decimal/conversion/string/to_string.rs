@@ -0,0 +1,92 @@
+use crate::decimal::Decimal;
+use malachite_base::num::conversion::traits::ExactFrom;
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+
+impl Display for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// If the scale is non-negative, exactly `scale` digits are written after the decimal point,
+    /// including any trailing zeros. If the scale is negative, the coefficient is written followed
+    /// by `'e'` and the negated scale. In either case, the result can be parsed back into an
+    /// identical [`Decimal`] using [`FromStr`](std::str::FromStr).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + max(self.scale(), 0)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::ZERO.to_string(), "0");
+    /// assert_eq!(
+    ///     Decimal::from_coefficient_and_scale(Integer::from(1230), 3).to_string(),
+    ///     "1.230"
+    /// );
+    /// assert_eq!(Decimal::from_coefficient_and_scale(Integer::from(-5), 2).to_string(), "-0.05");
+    /// assert_eq!(Decimal::from_coefficient_and_scale(Integer::from(-5), -2).to_string(), "-5e2");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.scale <= 0 {
+            Display::fmt(&self.coefficient, f)?;
+            if self.scale != 0 {
+                f.write_char('e')?;
+                Display::fmt(&self.scale.unsigned_abs(), f)?;
+            }
+            return Ok(());
+        }
+        let digits = self.coefficient.unsigned_abs_ref().to_string();
+        if self.coefficient < 0u32 {
+            f.write_char('-')?;
+        }
+        let scale = usize::exact_from(self.scale);
+        if digits.len() > scale {
+            let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+            f.write_str(int_part)?;
+            f.write_char('.')?;
+            f.write_str(frac_part)
+        } else {
+            f.write_str("0.")?;
+            for _ in digits.len()..scale {
+                f.write_char('0')?;
+            }
+            f.write_str(&digits)
+        }
+    }
+}
+
+impl Debug for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.coefficient_ref().significant_bits() + max(self.scale(), 0)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(
+    ///     Decimal::from_coefficient_and_scale(Integer::from(1230), 3).to_debug_string(),
+    ///     "1.230"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,163 @@
+use malachite_base::named::Named;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::integer::Integer;
+
+/// An arbitrary-precision decimal number.
+///
+/// A [`Decimal`] consists of an [`Integer`](malachite_nz::integer::Integer) coefficient $c$ and an
+/// [`i64`] scale $s$; its value is $c \cdot 10^{-s}$. The scale is the number of digits after the
+/// decimal point; a negative scale means that the coefficient is implicitly followed by zeros.
+///
+/// Unlike a [`Rational`](crate::Rational), a [`Decimal`] is not normalized: $1.5$ and $1.50$ are
+/// represented differently, and the scale is preserved by string conversion. Comparison and
+/// hashing, however, only depend on the value.
+///
+/// Addition, subtraction, and multiplication are exact. Division requires a result scale and a
+/// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode); see
+/// [`Decimal::div_round_to_scale`].
+#[derive(Clone)]
+pub struct Decimal {
+    pub(crate) coefficient: Integer,
+    pub(crate) scale: i64,
+}
+
+impl Decimal {
+    /// Creates a [`Decimal`] from a coefficient and a scale. The value of the result is
+    /// $c \cdot 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(
+    ///     Decimal::from_coefficient_and_scale(Integer::from(12345), 2).to_string(),
+    ///     "123.45"
+    /// );
+    /// assert_eq!(Decimal::from_coefficient_and_scale(Integer::from(-5), 3).to_string(), "-0.005");
+    /// assert_eq!(Decimal::from_coefficient_and_scale(Integer::from(12), -3).to_string(), "12e3");
+    /// ```
+    pub const fn from_coefficient_and_scale(coefficient: Integer, scale: i64) -> Decimal {
+        Decimal { coefficient, scale }
+    }
+
+    /// Returns a reference to the coefficient of a [`Decimal`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(*Decimal::from_str("-123.450").unwrap().coefficient_ref(), -123450);
+    /// ```
+    pub const fn coefficient_ref(&self) -> &Integer {
+        &self.coefficient
+    }
+
+    /// Returns the scale of a [`Decimal`]; that is, the number of digits after the decimal point.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("-123.450").unwrap().scale(), 3);
+    /// assert_eq!(Decimal::from_str("123").unwrap().scale(), 0);
+    /// assert_eq!(Decimal::from_str("1.2e5").unwrap().scale(), -4);
+    /// ```
+    pub const fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Takes a [`Decimal`] by value and returns its coefficient and scale.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Decimal::from_str("-123.450").unwrap().into_coefficient_and_scale().to_debug_string(),
+    ///     "(-123450, 3)"
+    /// );
+    /// ```
+    pub fn into_coefficient_and_scale(self) -> (Integer, i64) {
+        (self.coefficient, self.scale)
+    }
+}
+
+// Returns $10^p$.
+pub(crate) fn power_of_10(p: u64) -> Integer {
+    Integer::from(10u32).pow(p)
+}
+
+// Returns `larger - smaller`, which must be non-negative, as a `u64`. This never overflows.
+pub(crate) fn scale_difference(larger: i64, smaller: i64) -> u64 {
+    assert!(larger >= smaller);
+    u64::wrapping_from(larger.wrapping_sub(smaller))
+}
+
+/// The constant 0.
+impl Zero for Decimal {
+    const ZERO: Decimal = Decimal {
+        coefficient: Integer::ZERO,
+        scale: 0,
+    };
+}
+
+/// The constant 1.
+impl One for Decimal {
+    const ONE: Decimal = Decimal {
+        coefficient: Integer::ONE,
+        scale: 0,
+    };
+}
+
+/// The constant 2.
+impl Two for Decimal {
+    const TWO: Decimal = Decimal {
+        coefficient: Integer::TWO,
+        scale: 0,
+    };
+}
+
+/// The constant -1.
+impl NegativeOne for Decimal {
+    const NEGATIVE_ONE: Decimal = Decimal {
+        coefficient: Integer::NEGATIVE_ONE,
+        scale: 0,
+    };
+}
+
+impl Default for Decimal {
+    /// The default value of a [`Decimal`], 0.
+    fn default() -> Decimal {
+        Decimal::ZERO
+    }
+}
+
+// Implements `Named` for `Decimal`.
+impl_named!(Decimal);
+
+/// Arithmetic on [`Decimal`]s.
+pub mod arithmetic;
+/// Comparing [`Decimal`]s for equality or order, and hashing them.
+pub mod comparison;
+/// Converting [`Decimal`]s to and from [`Integer`](malachite_nz::integer::Integer)s,
+/// [`Rational`](crate::Rational)s, and strings.
+pub mod conversion;
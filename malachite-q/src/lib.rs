@@ -13,6 +13,8 @@
 //! - Most arithmetic involving [`Rational`]s requires (automatically) reducing the numerator and
 //!   denominator. This is done very efficiently by using the high performance GCD and exact
 //!   division algorithms implemented by [`Natural`](malachite_nz::natural::Natural)s.
+//! - This crate also defines [`Decimal`](decimal::Decimal)s, which represent base-10 fixed-point
+//!   numbers exactly. They are never reduced, so they are a good fit for financial calculations.
 //!
 //! # Demos and benchmarks
 //! This crate comes with a `bin` target that can be used for running demos and benchmarks.
@@ -49,6 +51,21 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
 //!   default, [`u64`].
+//! - `128_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u128`] instead of the
+//!   default, [`u64`]. This can't be combined with `32_bit_limbs`.
+//! - `parallel`: Uses multiple threads for very large multiplications, divisions, and base
+//!   conversions; see the [`malachite-nz`](malachite_nz) documentation.
+//! - `num-traits`: Implements the traits from the [num-traits](https://crates.io/crates/num-traits)
+//!   crate, such as `Num`, `Signed`, `ToPrimitive`, and `FromPrimitive`, for [`Rational`]s, and
+//!   enables the same feature in [`malachite-nz`](malachite_nz). Some of these traits have methods
+//!   with the same names as methods of Malachite's traits, such as `abs` and `pow`. If both traits
+//!   are imported, calls to these methods on [`Rational`]s are ambiguous and must be written in
+//!   fully qualified form, like `num_traits::Signed::abs(&x)`. The [`Rem`](std::ops::Rem)
+//!   implementations that `Num` requires are part of the crate whether or not this feature is
+//!   enabled.
+//! - `num-bigint`: Implements conversions between [`Rational`]s and the `BigRational` type from
+//!   the [num-rational](https://crates.io/crates/num-rational) crate, in both directions, and
+//!   enables the same feature in [`malachite-nz`](malachite_nz).
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
@@ -99,6 +116,12 @@ extern crate malachite_nz;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "num-bigint")]
+extern crate num_bigint;
+#[cfg(feature = "num-bigint")]
+extern crate num_rational;
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
@@ -115,7 +138,7 @@ use malachite_base::num::logic::traits::SignificantBits;
 use malachite_nz::natural::Natural;
 
 /// A rational number.
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Rational {
     // whether the `Rational` is non-negative
@@ -141,6 +164,51 @@ impl Rational {
     }
 }
 
+impl Clone for Rational {
+    /// Clones a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    #[inline]
+    fn clone(&self) -> Rational {
+        Rational {
+            sign: self.sign,
+            numerator: self.numerator.clone(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// Copies a [`Rational`] into an existing one, reusing the buffers of its numerator and
+    /// denominator when possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$ (only if the underlying [`Vec`]s need to reallocate)
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `source.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::OneHalf;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x.clone_from(&Rational::ONE_HALF);
+    /// assert_eq!(x, Rational::ONE_HALF);
+    /// ```
+    #[inline]
+    fn clone_from(&mut self, source: &Rational) {
+        self.sign = source.sign;
+        self.numerator.clone_from(&source.numerator);
+        self.denominator.clone_from(&source.denominator);
+    }
+}
+
 impl<'a> SignificantBits for &'a Rational {
     /// Returns the sum of the bits needed to represent the numerator and denominator.
     ///
@@ -224,10 +292,26 @@ pub mod comparison;
 /// Traits for converting to and from [`Rational`]s, converting to and from strings, and extracting
 /// digits and continued fractions.
 pub mod conversion;
+/// The arbitrary-precision decimal type [`Decimal`](decimal::Decimal), which represents numbers of
+/// the form $c \cdot 10^{-s}$ exactly.
+pub mod decimal;
 /// Iterators that generate [`Rational`]s without repetition.
 pub mod exhaustive;
+/// Implementations of the traits from the `num-traits` crate for [`Rational`]s.
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
+/// The type [`QuadraticIrrational`](quadratic_irrational::QuadraticIrrational), which represents
+/// numbers of the form $(a + b\sqrt{d})/c$ exactly, along with their periodic continued fractions
+/// and a solver for Pell's equation.
+pub mod quadratic_irrational;
 /// Iterators that generate [`Rational`]s randomly.
 pub mod random;
+/// Farey sequences, and navigation of the Stern-Brocot and Calkin-Wilf trees, which contain every
+/// positive [`Rational`] exactly once.
+pub mod stern_brocot;
+/// The type [`UnreducedRational`](unreduced::UnreducedRational), which represents rational numbers
+/// without keeping them in lowest terms, so that long sums and products can skip most GCDs.
+pub mod unreduced;
 
 #[cfg(feature = "test_build")]
 pub mod test_util;
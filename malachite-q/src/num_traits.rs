@@ -0,0 +1,300 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Abs, Pow, Sign};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::string::from_string::ParseNumberError;
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringWithOptions, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+impl num_traits::Zero for Rational {
+    /// Returns 0.
+    #[inline]
+    fn zero() -> Rational {
+        Rational::ZERO
+    }
+
+    /// Determines whether a [`Rational`] is 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+impl num_traits::One for Rational {
+    /// Returns 1.
+    #[inline]
+    fn one() -> Rational {
+        Rational::ONE
+    }
+
+    /// Determines whether a [`Rational`] is 1.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+impl num_traits::Num for Rational {
+    type FromStrRadixErr = ParseNumberError;
+
+    /// Converts a string to a [`Rational`], using a specified radix.
+    ///
+    /// The string consists of an optional `'+'` or `'-'`, followed by a numerator and an optional
+    /// `'/'` and denominator, both written in the given radix. Groups of digits may be separated
+    /// by `'_'`. If the string is not valid, an `Err` is returned, describing the problem and
+    /// where in the string it was found.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_traits::Num;
+    ///
+    /// assert_eq!(Rational::from_str_radix("-ff/10", 16).unwrap().to_string(), "-255/16");
+    /// assert_eq!(Rational::from_str_radix("+6/4", 10).unwrap().to_string(), "3/2");
+    /// assert!(Rational::from_str_radix("1/0", 10).is_err());
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Result<Rational, ParseNumberError> {
+        let mut options = ParseOptions::default();
+        options.set_base(u8::exact_from(radix));
+        options.set_allow_underscore_separators(true);
+        options.set_allow_plus_sign(true);
+        Rational::from_string_with_options(s, options)
+    }
+}
+
+impl num_traits::Signed for Rational {
+    /// Returns the absolute value of a [`Rational`].
+    #[inline]
+    fn abs(&self) -> Rational {
+        Abs::abs(self)
+    }
+
+    /// Returns the difference of two [`Rational`]s if it is positive, and 0 otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Rational) -> Rational {
+        if self <= other {
+            Rational::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Returns $-1$, 0, or 1, depending on whether a [`Rational`] is negative, zero, or positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_traits::Signed;
+    ///
+    /// assert_eq!(Rational::from_signeds(-22, 7).signum(), -1);
+    /// assert_eq!(Rational::from(0).signum(), 0);
+    /// assert_eq!(Rational::from_signeds(22, 7).abs_sub(&Rational::from(3)).to_string(), "1/7");
+    /// assert_eq!(Rational::from(3).abs_sub(&Rational::from_signeds(22, 7)), 0);
+    /// ```
+    #[inline]
+    fn signum(&self) -> Rational {
+        match self.sign() {
+            Ordering::Less => Rational::NEGATIVE_ONE,
+            Ordering::Equal => Rational::ZERO,
+            Ordering::Greater => Rational::ONE,
+        }
+    }
+
+    /// Determines whether a [`Rational`] is greater than 0.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0u32
+    }
+
+    /// Determines whether a [`Rational`] is less than 0.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0u32
+    }
+}
+
+impl num_traits::ToPrimitive for Rational {
+    /// Converts a [`Rational`] to an [`i64`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(&Integer::rounding_from(self, RoundingMode::Down)).ok()
+    }
+
+    /// Converts a [`Rational`] to an [`i128`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(&Integer::rounding_from(self, RoundingMode::Down)).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u64`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&Integer::rounding_from(self, RoundingMode::Down)).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u128`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(&Integer::rounding_from(self, RoundingMode::Down)).ok()
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f32`]. A [`Rational`] whose absolute value is
+    /// too large to be represented is converted to the finite [`f32`] with the largest absolute
+    /// value and the same sign. The result is never `None`.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest))
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f64`]. A [`Rational`] whose absolute value is
+    /// too large to be represented is converted to the finite [`f64`] with the largest absolute
+    /// value and the same sign. The result is never `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_traits::ToPrimitive;
+    ///
+    /// assert_eq!(Rational::from_signeds(-22, 7).to_i32(), Some(-3));
+    /// assert_eq!(Rational::from_signeds(-22, 7).to_u32(), None);
+    /// assert_eq!(Rational::from_signeds(-1, 2).to_u32(), Some(0));
+    /// assert_eq!(Rational::from_signeds(1, 4).to_f64(), Some(0.25));
+    /// ```
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest))
+    }
+}
+
+impl num_traits::FromPrimitive for Rational {
+    /// Converts an [`i64`] to a [`Rational`]. The result is never `None`.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts an [`i128`] to a [`Rational`]. The result is never `None`.
+    #[inline]
+    fn from_i128(n: i128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts a [`u64`] to a [`Rational`]. The result is never `None`.
+    #[inline]
+    fn from_u64(n: u64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts a [`u128`] to a [`Rational`]. The result is never `None`.
+    #[inline]
+    fn from_u128(n: u128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts an [`f32`] to the equal [`Rational`]. Returns `None` if the [`f32`] is infinite or
+    /// NaN.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+
+    /// Converts an [`f64`] to the equal [`Rational`]. Returns `None` if the [`f64`] is infinite or
+    /// NaN.
+    ///
+    /// The conversion is exact; see [`Rational::try_from_float_simplest`] for an alternative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_traits::FromPrimitive;
+    ///
+    /// assert_eq!(Rational::from_f64(-1.5).unwrap().to_string(), "-3/2");
+    /// assert_eq!(
+    ///     Rational::from_f64(0.1).unwrap().to_string(),
+    ///     "3602879701896397/36028797018963968"
+    /// );
+    /// assert_eq!(Rational::from_f64(f64::NAN), None);
+    /// ```
+    #[inline]
+    fn from_f64(n: f64) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident, $u: ident) => {
+        impl num_traits::Pow<$t> for Rational {
+            type Output = Rational;
+
+            /// Raises a [`Rational`] to a power, taking the [`Rational`] by value.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                Pow::pow(self, $u::exact_from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Rational {
+            type Output = Rational;
+
+            /// Raises a [`Rational`] to a power, taking the [`Rational`] by reference.
+            ///
+            /// See [`Pow`](malachite_base::num::arithmetic::traits::Pow) for details.
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                Pow::pow(self, $u::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8, u64);
+impl_pow!(u16, u64);
+impl_pow!(u32, u64);
+impl_pow!(u64, u64);
+impl_pow!(usize, u64);
+impl_pow!(i8, i64);
+impl_pow!(i16, i64);
+impl_pow!(i32, i64);
+impl_pow!(i64, i64);
+impl_pow!(isize, i64);
+
+impl num_traits::CheckedDiv for Rational {
+    /// Divides a [`Rational`] by another [`Rational`]. Returns `None` if the divisor is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use num_traits::CheckedDiv;
+    ///
+    /// assert_eq!(
+    ///     Rational::from(22).checked_div(&Rational::from(-7)).unwrap().to_string(),
+    ///     "-22/7"
+    /// );
+    /// assert_eq!(Rational::from(22).checked_div(&Rational::from(0)), None);
+    /// ```
+    #[inline]
+    fn checked_div(&self, other: &Rational) -> Option<Rational> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::quadratic_irrational::{expect_common_radicand, QuadraticIrrational};
+use malachite_nz::integer::Integer;
+use std::ops::{Add, AddAssign};
+
+// Computes $(a_1c_2 + a_2c_1 + (b_1c_2 + b_2c_1)\sqrt{d})/(c_1c_2)$.
+fn add_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    let (x, y) = expect_common_radicand(x, y);
+    let x_c = Integer::from(&x.c);
+    let y_c = Integer::from(&y.c);
+    QuadraticIrrational::from_parts(
+        true,
+        &x.a * &y_c + &y.a * &x_c,
+        &x.b * &y_c + &y.b * &x_c,
+        &x.c * &y.c,
+        x.d.clone(),
+    )
+}
+
+impl Add<QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Adds two [`QuadraticIrrational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((x.clone() + QuadraticIrrational::ONE).to_string(), "1+sqrt(2)");
+    /// assert_eq!((x + y).to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        add_helper(&self, &other)
+    }
+}
+
+impl<'a> Add<&'a QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Adds two [`QuadraticIrrational`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((x.clone() + QuadraticIrrational::ONE).to_string(), "1+sqrt(2)");
+    /// assert_eq!((x + &y).to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a QuadraticIrrational) -> QuadraticIrrational {
+        add_helper(&self, other)
+    }
+}
+
+impl<'a> Add<QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Adds two [`QuadraticIrrational`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x + QuadraticIrrational::ONE).to_string(), "1+sqrt(2)");
+    /// assert_eq!((&x + y).to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        add_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Add<&'b QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Adds two [`QuadraticIrrational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x + QuadraticIrrational::ONE).to_string(), "1+sqrt(2)");
+    /// assert_eq!((&x + &y).to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add(self, other: &'b QuadraticIrrational) -> QuadraticIrrational {
+        add_helper(self, other)
+    }
+}
+
+impl AddAssign<QuadraticIrrational> for QuadraticIrrational {
+    /// Adds a [`QuadraticIrrational`] to a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// x += QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!(x.to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: QuadraticIrrational) {
+        *self = add_helper(self, &other);
+    }
+}
+
+impl<'a> AddAssign<&'a QuadraticIrrational> for QuadraticIrrational {
+    /// Adds a [`QuadraticIrrational`] to a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// x += &QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!(x.to_string(), "3*sqrt(2)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a QuadraticIrrational) {
+        *self = add_helper(self, other);
+    }
+}
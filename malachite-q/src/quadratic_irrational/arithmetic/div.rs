@@ -0,0 +1,232 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use std::ops::{Div, DivAssign};
+
+fn div_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    x * y.reciprocal()
+}
+
+impl Div<QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Divides a [`QuadraticIrrational`] by another [`QuadraticIrrational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((QuadraticIrrational::ONE / x.clone()).to_string(), "sqrt(2)/2");
+    /// assert_eq!((y / x).to_string(), "2");
+    /// ```
+    #[inline]
+    fn div(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        div_helper(&self, &other)
+    }
+}
+
+impl<'a> Div<&'a QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Divides a [`QuadraticIrrational`] by another [`QuadraticIrrational`], taking the first by
+    /// value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((QuadraticIrrational::ONE / x.clone()).to_string(), "sqrt(2)/2");
+    /// assert_eq!((&y / x).to_string(), "2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a QuadraticIrrational) -> QuadraticIrrational {
+        div_helper(&self, other)
+    }
+}
+
+impl<'a> Div<QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Divides a [`QuadraticIrrational`] by another [`QuadraticIrrational`], taking the first by
+    /// reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x / QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((y / &x).to_string(), "2");
+    /// ```
+    #[inline]
+    fn div(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        div_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Div<&'b QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Divides a [`QuadraticIrrational`] by another [`QuadraticIrrational`], taking both by
+    /// reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x / QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((&y / &x).to_string(), "2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'b QuadraticIrrational) -> QuadraticIrrational {
+        div_helper(self, other)
+    }
+}
+
+impl DivAssign<QuadraticIrrational> for QuadraticIrrational {
+    /// Divides a [`QuadraticIrrational`] by a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// x /= QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert_eq!(x.to_string(), "2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: QuadraticIrrational) {
+        *self = div_helper(self, &other);
+    }
+}
+
+impl<'a> DivAssign<&'a QuadraticIrrational> for QuadraticIrrational {
+    /// Divides a [`QuadraticIrrational`] by a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if the radicands of `self` and `other` do not differ by a
+    /// square factor and neither number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// x /= &QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert_eq!(x.to_string(), "2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a QuadraticIrrational) {
+        *self = div_helper(self, other);
+    }
+}
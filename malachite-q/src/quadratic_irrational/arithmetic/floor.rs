@@ -0,0 +1,167 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use malachite_base::num::arithmetic::traits::{Ceiling, DivRound, Floor, FloorSqrt};
+use malachite_base::num::basic::traits::One;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+
+impl Floor for QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the floor of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by value.
+    ///
+    /// $$
+    /// f(x) = \lfloor x \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Floor;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(QuadraticIrrational::sqrt(Natural::from(2u32)).floor(), 1);
+    /// assert_eq!((-QuadraticIrrational::sqrt(Natural::from(2u32))).floor(), -2);
+    /// ```
+    #[inline]
+    fn floor(self) -> Integer {
+        (&self).floor()
+    }
+}
+
+impl<'a> Floor for &'a QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the floor of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by
+    /// reference.
+    ///
+    /// $$
+    /// f(x) = \lfloor x \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Floor;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!((&phi).floor(), 1);
+    /// assert_eq!((&phi.conjugate()).floor(), -1);
+    /// ```
+    fn floor(self) -> Integer {
+        // Since $c > 0$, $\lfloor (a + t)/c \rfloor = \lfloor (a + \lfloor t \rfloor)/c \rfloor$.
+        let numerator = if self.b == 0u32 {
+            self.a.clone()
+        } else {
+            let b_abs = self.b.unsigned_abs_ref();
+            let s = Integer::from((b_abs * b_abs * &self.d).floor_sqrt());
+            // $b^2d$ is not a perfect square, so when $b < 0$,
+            // $\lfloor b\sqrt{d} \rfloor = -\lfloor |b|\sqrt{d} \rfloor - 1$.
+            if self.b > 0u32 {
+                &self.a + s
+            } else {
+                &self.a - s - Integer::ONE
+            }
+        };
+        numerator.div_round(Integer::from(&self.c), RoundingMode::Floor)
+    }
+}
+
+impl Ceiling for QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by
+    /// value.
+    ///
+    /// $$
+    /// f(x) = \lceil x \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ceiling;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(QuadraticIrrational::sqrt(Natural::from(2u32)).ceiling(), 2);
+    /// assert_eq!((-QuadraticIrrational::sqrt(Natural::from(2u32))).ceiling(), -1);
+    /// ```
+    #[inline]
+    fn ceiling(self) -> Integer {
+        (&self).ceiling()
+    }
+}
+
+impl<'a> Ceiling for &'a QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by
+    /// reference.
+    ///
+    /// $$
+    /// f(x) = \lceil x \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ceiling;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!((&phi).ceiling(), 2);
+    /// assert_eq!((&phi.conjugate()).ceiling(), 0);
+    /// ```
+    fn ceiling(self) -> Integer {
+        if self.b == 0u32 {
+            self.a
+                .div_round(Integer::from(&self.c), RoundingMode::Ceiling)
+        } else {
+            self.floor() + Integer::ONE
+        }
+    }
+}
@@ -0,0 +1,21 @@
+/// Addition of [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod add;
+/// Division of [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod div;
+/// Implementations of [`Floor`](malachite_base::num::arithmetic::traits::Floor) and
+/// [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) for
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod floor;
+/// Multiplication of [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod mul;
+/// Negation of a [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational).
+pub mod neg;
+/// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
+/// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign) for
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod reciprocal;
+/// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign) for
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod sign;
+/// Subtraction of [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod sub;
@@ -0,0 +1,239 @@
+use crate::quadratic_irrational::{expect_common_radicand, QuadraticIrrational};
+use malachite_nz::integer::Integer;
+use std::ops::{Mul, MulAssign};
+
+// Computes $(a_1a_2 + b_1b_2d + (a_1b_2 + a_2b_1)\sqrt{d})/(c_1c_2)$.
+fn mul_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    let (x, y) = expect_common_radicand(x, y);
+    QuadraticIrrational::from_parts(
+        true,
+        &x.a * &y.a + &x.b * &y.b * Integer::from(&x.d),
+        &x.a * &y.b + &x.b * &y.a,
+        &x.c * &y.c,
+        x.d.clone(),
+    )
+}
+
+impl Mul<QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Multiplies two [`QuadraticIrrational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((x.clone() * QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((x * y).to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a QuadraticIrrational> for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Multiplies two [`QuadraticIrrational`]s, taking the first by value and the second by
+    /// reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((x.clone() * QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((x * &y).to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a QuadraticIrrational) -> QuadraticIrrational {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Multiplies two [`QuadraticIrrational`]s, taking the first by reference and the second by
+    /// value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x * QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((&x * y).to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul(self, other: QuadraticIrrational) -> QuadraticIrrational {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'b QuadraticIrrational> for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Multiplies two [`QuadraticIrrational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let y = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!((&x * QuadraticIrrational::ONE).to_string(), "sqrt(2)");
+    /// assert_eq!((&x * &y).to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'b QuadraticIrrational) -> QuadraticIrrational {
+        mul_helper(self, other)
+    }
+}
+
+impl MulAssign<QuadraticIrrational> for QuadraticIrrational {
+    /// Multiplies a [`QuadraticIrrational`] by a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// x *= QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!(x.to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: QuadraticIrrational) {
+        *self = mul_helper(self, &other);
+    }
+}
+
+impl<'a> MulAssign<&'a QuadraticIrrational> for QuadraticIrrational {
+    /// Multiplies a [`QuadraticIrrational`] by a [`QuadraticIrrational`] in place, taking the
+    /// [`QuadraticIrrational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the radicands of `self` and `other` do not differ by a square factor and neither
+    /// number is rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// x *= &QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!(x.to_string(), "4");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a QuadraticIrrational) {
+        *self = mul_helper(self, other);
+    }
+}
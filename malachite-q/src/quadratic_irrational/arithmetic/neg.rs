@@ -0,0 +1,103 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Neg for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Negates a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!((-phi).to_string(), "(-1-sqrt(5))/2");
+    /// ```
+    #[inline]
+    fn neg(mut self) -> QuadraticIrrational {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Negates a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!((-&phi).to_string(), "(-1-sqrt(5))/2");
+    /// ```
+    fn neg(self) -> QuadraticIrrational {
+        QuadraticIrrational {
+            a: -&self.a,
+            b: -&self.b,
+            c: self.c.clone(),
+            d: self.d.clone(),
+        }
+    }
+}
+
+impl NegAssign for QuadraticIrrational {
+    /// Negates a [`QuadraticIrrational`] in place.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-sqrt(2)");
+    /// ```
+    fn neg_assign(&mut self) {
+        self.a.neg_assign();
+        self.b.neg_assign();
+    }
+}
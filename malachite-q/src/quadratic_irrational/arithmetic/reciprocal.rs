@@ -0,0 +1,126 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use malachite_base::num::arithmetic::traits::{Reciprocal, ReciprocalAssign, UnsignedAbs};
+use malachite_nz::integer::Integer;
+
+impl Reciprocal for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Reciprocates a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    /// );
+    /// assert_eq!(x.reciprocal().to_string(), "-1+sqrt(2)");
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> QuadraticIrrational {
+        (&self).reciprocal()
+    }
+}
+
+impl<'a> Reciprocal for &'a QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Reciprocates a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// If `self` is $(a + b\sqrt{d})/c$, the result is $c(a - b\sqrt{d})/(a^2 - b^2d)$. The
+    /// denominator is nonzero unless `self` is, since $d$ is not a perfect square.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!((&phi).reciprocal().to_string(), "(-1+sqrt(5))/2");
+    /// ```
+    fn reciprocal(self) -> QuadraticIrrational {
+        let norm = &self.a * &self.a - &self.b * &self.b * Integer::from(&self.d);
+        assert_ne!(norm, 0u32, "Cannot take reciprocal of zero");
+        let c = Integer::from(&self.c);
+        QuadraticIrrational::from_parts(
+            norm > 0u32,
+            &self.a * &c,
+            -(&self.b * c),
+            norm.unsigned_abs(),
+            self.d.clone(),
+        )
+    }
+}
+
+impl ReciprocalAssign for QuadraticIrrational {
+    /// Reciprocates a [`QuadraticIrrational`] in place.
+    ///
+    /// $$
+    /// x \gets 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalAssign;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let mut x = QuadraticIrrational::sqrt(Natural::from(3u32));
+    /// x.reciprocal_assign();
+    /// assert_eq!(x.to_string(), "sqrt(3)/3");
+    /// ```
+    #[inline]
+    fn reciprocal_assign(&mut self) {
+        *self = (&*self).reciprocal();
+    }
+}
@@ -0,0 +1,117 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use malachite_base::num::arithmetic::traits::{Sign, Square};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Returns the sign of $a + b\sqrt{d}$, where $d$ is not a perfect square.
+pub(crate) fn sign_of_sum(a: &Integer, b: &Integer, d: &Natural) -> Ordering {
+    let a_sign = a.sign();
+    let b_sign = b.sign();
+    if a_sign == b_sign || b_sign == Ordering::Equal {
+        a_sign
+    } else if a_sign == Ordering::Equal {
+        b_sign
+    } else {
+        // The signs differ, so the sign is that of the term with the larger absolute value. The
+        // squares can't be equal, since $d$ is not a perfect square.
+        let a_abs = a.unsigned_abs_ref();
+        let b_abs = b.unsigned_abs_ref();
+        if a_abs * a_abs > b_abs * b_abs * d {
+            a_sign
+        } else {
+            b_sign
+        }
+    }
+}
+
+// Returns the sign of $a + b\sqrt{d} + c\sqrt{e}$, where none of $d$, $e$, and $de$ is a perfect
+// square.
+//
+// The sign of $s = b\sqrt{d} + c\sqrt{e}$ is found by comparing the squares of its terms. If $a$
+// and $s$ have opposite signs, the sign of the sum is that of the term with the larger absolute
+// value, which is found from the sign of $a^2 - s^2 = (a^2 - b^2d - c^2e) - 2bc\sqrt{de}$. Since
+// $de$ is not a perfect square, none of the squares compared here can be equal.
+pub(crate) fn sign_of_sum_with_two_radicands(
+    a: &Integer,
+    b: &Integer,
+    d: &Natural,
+    c: &Integer,
+    e: &Natural,
+) -> Ordering {
+    let b_sign = b.sign();
+    let c_sign = c.sign();
+    let s_sign = if b_sign == c_sign || c_sign == Ordering::Equal {
+        b_sign
+    } else if b_sign == Ordering::Equal {
+        c_sign
+    } else {
+        let b_abs = b.unsigned_abs_ref();
+        let c_abs = c.unsigned_abs_ref();
+        if b_abs * b_abs * d > c_abs * c_abs * e {
+            b_sign
+        } else {
+            c_sign
+        }
+    };
+    let a_sign = a.sign();
+    if a_sign == s_sign || s_sign == Ordering::Equal {
+        a_sign
+    } else if a_sign == Ordering::Equal {
+        s_sign
+    } else {
+        let rational_part =
+            a.square() - b.square() * Integer::from(d) - c.square() * Integer::from(e);
+        let irrational_part = Integer::from(-2) * b * c;
+        if sign_of_sum(&rational_part, &irrational_part, &(d * e)) == Ordering::Greater {
+            a_sign
+        } else {
+            s_sign
+        }
+    }
+}
+
+impl Sign for QuadraticIrrational {
+    /// Compares a [`QuadraticIrrational`] to zero.
+    ///
+    /// Returns `Greater`, `Equal`, or `Less`, depending on whether the [`QuadraticIrrational`] is
+    /// positive, zero, or negative, respectively.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sign;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(QuadraticIrrational::ZERO.sign(), Ordering::Equal);
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(-3),
+    ///     Integer::from(2),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    /// );
+    /// assert_eq!(x.sign(), Ordering::Less);
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(-2),
+    ///     Integer::from(2),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    /// );
+    /// assert_eq!(x.sign(), Ordering::Greater);
+    /// ```
+    #[inline]
+    fn sign(&self) -> Ordering {
+        sign_of_sum(&self.a, &self.b, &self.d)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::quadratic_irrational::arithmetic::sign::{sign_of_sum, sign_of_sum_with_two_radicands};
+use crate::quadratic_irrational::{with_common_radicand, QuadraticIrrational};
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+impl PartialOrd for QuadraticIrrational {
+    /// Compares two [`QuadraticIrrational`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &QuadraticIrrational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QuadraticIrrational {
+    /// Compares two [`QuadraticIrrational`]s.
+    ///
+    /// The comparison is exact, whatever the radicands. If the radicands differ by a square factor,
+    /// or one of the numbers is rational, the numbers are rewritten with a common radicand and the
+    /// sign of their difference is computed directly. Otherwise, the difference has the form
+    /// $p + q\sqrt{d} + r\sqrt{e}$, and its sign is found by isolating terms and squaring.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let sqrt_3 = QuadraticIrrational::sqrt(Natural::from(3u32));
+    /// let sqrt_8 = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(17),
+    ///     Integer::from(0),
+    ///     Integer::from(12),
+    ///     Natural::from(3u32),
+    /// );
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert!(sqrt_2 > QuadraticIrrational::ONE);
+    /// assert!(sqrt_2 < x);
+    /// assert!(sqrt_2 < sqrt_8);
+    /// assert!(sqrt_2 < sqrt_3);
+    /// assert!(phi > sqrt_2);
+    /// assert!(phi < sqrt_3);
+    /// ```
+    fn cmp(&self, other: &QuadraticIrrational) -> Ordering {
+        if let Some((x, y)) = with_common_radicand(self, other) {
+            let x_c = Integer::from(&x.c);
+            let y_c = Integer::from(&y.c);
+            return sign_of_sum(
+                &(&x.a * &y_c - &y.a * &x_c),
+                &(&x.b * y_c - &y.b * x_c),
+                &x.d,
+            );
+        }
+        // The radicands don't differ by a square factor, so $d_1d_2$ isn't a perfect square. The
+        // difference is $(a_1c_2 - a_2c_1 + b_1c_2\sqrt{d_1} - b_2c_1\sqrt{d_2})/(c_1c_2)$.
+        let self_c = Integer::from(&self.c);
+        let other_c = Integer::from(&other.c);
+        sign_of_sum_with_two_radicands(
+            &(&self.a * &other_c - &other.a * &self_c),
+            &(&self.b * other_c),
+            &self.d,
+            &-(&other.b * self_c),
+            &other.d,
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use crate::quadratic_irrational::{with_common_radicand, QuadraticIrrational};
+
+impl PartialEq for QuadraticIrrational {
+    /// Determines whether two [`QuadraticIrrational`]s are equal.
+    ///
+    /// The comparison is by value: $\sqrt{8}$ and $2\sqrt{2}$ are equal, even though their
+    /// radicands differ. Two irrational numbers whose radicands do not differ by a square factor
+    /// are never equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// let sqrt_3 = QuadraticIrrational::sqrt(Natural::from(3u32));
+    /// let sqrt_8 = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// assert_eq!(&sqrt_2 + &sqrt_2, sqrt_8);
+    /// assert_ne!(sqrt_2, sqrt_3);
+    /// assert_eq!(
+    ///     QuadraticIrrational::new(
+    ///         Integer::from(2),
+    ///         Integer::from(0),
+    ///         Integer::from(1),
+    ///         Natural::from(3u32)
+    ///     ),
+    ///     &sqrt_2 * &sqrt_2
+    /// );
+    /// ```
+    fn eq(&self, other: &QuadraticIrrational) -> bool {
+        if self.d == other.d || (self.b == 0u32 && other.b == 0u32) {
+            self.a == other.a && self.b == other.b && self.c == other.c
+        } else if let Some((x, y)) = with_common_radicand(self, other) {
+            x.a == y.a && x.b == y.b && x.c == y.c
+        } else {
+            false
+        }
+    }
+}
+
+impl Eq for QuadraticIrrational {}
@@ -0,0 +1,14 @@
+/// Implementations of [`PartialOrd`] and [`Ord`] for
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod cmp;
+/// Implementations of [`PartialEq`] and [`Eq`] for
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s.
+pub mod eq;
+/// Implementations of [`PartialOrd`] for comparing a
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational) with a
+/// [`Rational`](crate::Rational).
+pub mod partial_cmp_rational;
+/// Implementations of [`PartialEq`] for comparing a
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational) with a
+/// [`Rational`](crate::Rational).
+pub mod partial_eq_rational;
@@ -0,0 +1,65 @@
+use crate::quadratic_irrational::arithmetic::sign::sign_of_sum;
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::Rational;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+impl PartialOrd<Rational> for QuadraticIrrational {
+    /// Compares a [`QuadraticIrrational`] to a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and in `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert!(sqrt_2 < Rational::from_signeds(99, 70));
+    /// assert!(sqrt_2 > Rational::from_signeds(140, 99));
+    /// ```
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        // Compare $(a + b\sqrt{d})/c$ with $p/q$ by finding the sign of $aq - pc + bq\sqrt{d}$.
+        let q = Integer::from(&other.denominator);
+        let p = Integer::from_sign_and_abs_ref(other.sign, &other.numerator);
+        Some(sign_of_sum(
+            &(&self.a * &q - p * Integer::from(&self.c)),
+            &(&self.b * q),
+            &self.d,
+        ))
+    }
+}
+
+impl PartialOrd<QuadraticIrrational> for Rational {
+    /// Compares a [`Rational`] to a [`QuadraticIrrational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in `self` and in the parts of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert!(Rational::from_signeds(99, 70) > sqrt_2);
+    /// assert!(Rational::from_signeds(140, 99) < sqrt_2);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &QuadraticIrrational) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
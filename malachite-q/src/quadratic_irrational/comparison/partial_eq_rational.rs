@@ -0,0 +1,58 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::Rational;
+
+impl PartialEq<Rational> for QuadraticIrrational {
+    /// Determines whether a [`QuadraticIrrational`] is equal to a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of the [`QuadraticIrrational`] and in the [`Rational`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert_eq!(&sqrt_2 * &sqrt_2, Rational::from(2));
+    /// assert_ne!(sqrt_2, Rational::from_signeds(99, 70));
+    /// ```
+    fn eq(&self, other: &Rational) -> bool {
+        self.b == 0u32
+            && (self.a >= 0u32) == other.sign
+            && *self.a.unsigned_abs_ref() == other.numerator
+            && self.c == other.denominator
+    }
+}
+
+impl PartialEq<QuadraticIrrational> for Rational {
+    /// Determines whether a [`Rational`] is equal to a [`QuadraticIrrational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of the [`QuadraticIrrational`] and in the [`Rational`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert_eq!(Rational::from(2), &sqrt_2 * &sqrt_2);
+    /// assert_ne!(Rational::from_signeds(99, 70), sqrt_2);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &QuadraticIrrational) -> bool {
+        other == self
+    }
+}
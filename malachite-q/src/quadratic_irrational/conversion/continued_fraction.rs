@@ -0,0 +1,351 @@
+use crate::conversion::continued_fraction::to_continued_fraction::RationalContinuedFraction;
+use crate::conversion::traits::ContinuedFraction;
+use crate::quadratic_irrational::{remove_small_square_factors, QuadraticIrrational};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivRound, FloorSqrt, Reciprocal, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rational_sequences::RationalSequence;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::collections::HashMap;
+
+// The remaining value of an irrational continued fraction is $(p + \sqrt{d})/q$, where $q \neq 0$
+// and $q$ divides $d - p^2$. `sqrt_d` is $\lfloor \sqrt{d} \rfloor$.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct IrrationalState {
+    pub(crate) p: Integer,
+    pub(crate) q: Integer,
+    d: Integer,
+    sqrt_d: Integer,
+}
+
+impl IrrationalState {
+    fn new(x: &QuadraticIrrational) -> IrrationalState {
+        // $(a + b\sqrt{d})/c = (\pm a + \sqrt{b^2d})/(\pm c)$, with the sign of $b$.
+        let b_abs = x.b.unsigned_abs_ref();
+        let mut d = Integer::from(b_abs * b_abs * &x.d);
+        let (mut p, mut q) = if x.b > 0u32 {
+            (x.a.clone(), Integer::from(&x.c))
+        } else {
+            (-&x.a, -Integer::from(&x.c))
+        };
+        // Make $q$ divide $d - p^2$ by multiplying the numerator and denominator by $|q|$.
+        if !(&d - &p * &p).divisible_by(&q) {
+            let q_abs = Integer::from(q.unsigned_abs_ref());
+            d *= &q_abs * &q_abs;
+            p *= &q_abs;
+            q *= q_abs;
+        }
+        IrrationalState::from_parts(p, q, d)
+    }
+
+    // Creates the state for $(p + \sqrt{d})/q$. `q` must be nonzero and must divide $d - p^2$.
+    pub(crate) fn from_parts(p: Integer, q: Integer, d: Integer) -> IrrationalState {
+        let sqrt_d = (&d).floor_sqrt();
+        IrrationalState { p, q, d, sqrt_d }
+    }
+
+    // Returns $\lfloor (p + \sqrt{d})/q \rfloor$ and advances to the reciprocal of the fractional
+    // part.
+    pub(crate) fn next_term(&mut self) -> Integer {
+        // $\sqrt{d}$ is irrational, so $\lfloor (p + \sqrt{d})/q \rfloor$ is
+        // $\lfloor (p + \lfloor \sqrt{d} \rfloor)/q \rfloor$ when $q > 0$, and
+        // $\lfloor (p + \lfloor \sqrt{d} \rfloor + 1)/q \rfloor$ when $q < 0$.
+        let mut numerator = &self.p + &self.sqrt_d;
+        if self.q < 0u32 {
+            numerator += Integer::ONE;
+        }
+        let term = numerator.div_round(&self.q, RoundingMode::Floor);
+        self.p = &term * &self.q - &self.p;
+        self.q = (&self.d - &self.p * &self.p).div_exact(&self.q);
+        term
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ContinuedFractionState {
+    Rational(RationalContinuedFraction),
+    Irrational(IrrationalState),
+}
+
+/// An iterator that produces the continued fraction of a [`QuadraticIrrational`].
+///
+/// See [`continued_fraction`](QuadraticIrrational::continued_fraction) for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuadraticIrrationalContinuedFraction(ContinuedFractionState);
+
+impl Iterator for QuadraticIrrationalContinuedFraction {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        match &mut self.0 {
+            ContinuedFractionState::Rational(cf) => cf.next(),
+            ContinuedFractionState::Irrational(state) => Some(state.next_term().unsigned_abs()),
+        }
+    }
+}
+
+impl ContinuedFraction for QuadraticIrrational {
+    type CF = QuadraticIrrationalContinuedFraction;
+
+    /// Returns the continued fraction of a [`QuadraticIrrational`], taking the
+    /// [`QuadraticIrrational`] by value.
+    ///
+    /// The output has two components. The first is the first value of the continued fraction,
+    /// which may be any [`Integer`] and is equal to the floor of the [`QuadraticIrrational`]. The
+    /// second is an iterator that produces the remaining values, which are all positive. If the
+    /// number is irrational, the iterator never ends; its output is eventually periodic. To get
+    /// the period, use
+    /// [`to_periodic_continued_fraction`](QuadraticIrrational::to_periodic_continued_fraction).
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::conversion::traits::ContinuedFraction;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let (head, tail) = QuadraticIrrational::sqrt(Natural::from(7u32)).continued_fraction();
+    /// assert_eq!(head, 2);
+    /// assert_eq!(tail.take(8).collect_vec().to_debug_string(), "[1, 1, 1, 4, 1, 1, 1, 4]");
+    /// ```
+    #[inline]
+    fn continued_fraction(self) -> (Integer, QuadraticIrrationalContinuedFraction) {
+        (&self).continued_fraction()
+    }
+}
+
+impl<'a> ContinuedFraction for &'a QuadraticIrrational {
+    type CF = QuadraticIrrationalContinuedFraction;
+
+    /// Returns the continued fraction of a [`QuadraticIrrational`], taking the
+    /// [`QuadraticIrrational`] by reference.
+    ///
+    /// The output has two components. The first is the first value of the continued fraction,
+    /// which may be any [`Integer`] and is equal to the floor of the [`QuadraticIrrational`]. The
+    /// second is an iterator that produces the remaining values, which are all positive. If the
+    /// number is irrational, the iterator never ends; its output is eventually periodic. To get
+    /// the period, use
+    /// [`to_periodic_continued_fraction`](QuadraticIrrational::to_periodic_continued_fraction).
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::conversion::traits::ContinuedFraction;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(-1),
+    ///     Integer::from(-1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// let (head, tail) = (&x).continued_fraction();
+    /// assert_eq!(head, -2);
+    /// assert_eq!(tail.take(6).collect_vec().to_debug_string(), "[2, 1, 1, 1, 1, 1]");
+    /// ```
+    fn continued_fraction(self) -> (Integer, QuadraticIrrationalContinuedFraction) {
+        if let Some(x) = self.to_rational() {
+            let (head, tail) = x.continued_fraction();
+            (
+                head,
+                QuadraticIrrationalContinuedFraction(ContinuedFractionState::Rational(tail)),
+            )
+        } else {
+            let mut state = IrrationalState::new(self);
+            let head = state.next_term();
+            (
+                head,
+                QuadraticIrrationalContinuedFraction(ContinuedFractionState::Irrational(state)),
+            )
+        }
+    }
+}
+
+impl QuadraticIrrational {
+    /// Returns the continued fraction of a [`QuadraticIrrational`] as a floor and an eventually
+    /// periodic [`RationalSequence`].
+    ///
+    /// The first component is the first value of the continued fraction, which is equal to the
+    /// floor of the number. The second component holds the remaining values, which are all
+    /// positive. The sequence is finite iff the number is rational, in which case it is the
+    /// shorter of the two continued fraction representations (the one that does not end in 1).
+    ///
+    /// The period of $\sqrt{d}$ can have length as large as $O(\sqrt{d} \log d)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n 2^{n/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n 2^{n/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let (head, tail) = QuadraticIrrational::sqrt(Natural::from(2u32))
+    ///     .to_periodic_continued_fraction();
+    /// assert_eq!(head, 1);
+    /// assert_eq!(tail.to_string(), "[[2]]");
+    ///
+    /// let (head, tail) = QuadraticIrrational::sqrt(Natural::from(19u32))
+    ///     .to_periodic_continued_fraction();
+    /// assert_eq!(head, 4);
+    /// assert_eq!(tail.to_string(), "[[2, 1, 3, 1, 2, 8]]");
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(1),
+    ///     Integer::from(7),
+    ///     Natural::from(2u32),
+    /// );
+    /// let (head, tail) = x.to_periodic_continued_fraction();
+    /// assert_eq!(head, 0);
+    /// assert_eq!(tail.to_string(), "[1, 1, 1, [2]]");
+    /// ```
+    pub fn to_periodic_continued_fraction(&self) -> (Integer, RationalSequence<Natural>) {
+        if let Some(x) = self.to_rational() {
+            let (head, tail) = x.continued_fraction();
+            return (head, RationalSequence::from_vec(tail.collect()));
+        }
+        let mut state = IrrationalState::new(self);
+        let head = state.next_term();
+        // The states are eventually periodic, and so are the terms. The first repeated state marks
+        // the start of the period.
+        let mut terms = Vec::new();
+        let mut seen = HashMap::new();
+        let period_start = loop {
+            let key = (state.p.clone(), state.q.clone());
+            if let Some(&i) = seen.get(&key) {
+                break i;
+            }
+            seen.insert(key, terms.len());
+            terms.push(state.next_term().unsigned_abs());
+        };
+        let repeating = terms.split_off(period_start);
+        (head, RationalSequence::from_vecs(terms, repeating))
+    }
+
+    /// Converts an eventually periodic continued fraction to a [`QuadraticIrrational`].
+    ///
+    /// The first argument is the first value of the continued fraction, and the second holds the
+    /// remaining values, which must be positive and must repeat eventually. Finite continued
+    /// fractions represent rational numbers, which can be obtained with
+    /// [`Rational::from_continued_fraction`](crate::Rational::from_continued_fraction).
+    ///
+    /// The radicand of the result is the discriminant of the number's minimal polynomial, with
+    /// small square factors removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in `floor` and in the elements of `xs`, counting each element once.
+    ///
+    /// # Panics
+    /// Panics if `xs` is finite or contains a zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rational_sequences::RationalSequence;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let xs = RationalSequence::from_vecs(vec![], vec![Natural::from(2u32)]);
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(1), &xs)
+    ///         .to_string(),
+    ///     "sqrt(2)"
+    /// );
+    ///
+    /// let xs = RationalSequence::from_vecs(vec![], vec![Natural::from(1u32)]);
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(1), &xs)
+    ///         .to_string(),
+    ///     "(1+sqrt(5))/2"
+    /// );
+    ///
+    /// let xs = RationalSequence::from_vecs(
+    ///     vec_from_str("[1, 1, 1]").unwrap(),
+    ///     vec_from_str("[2]").unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(0), &xs)
+    ///         .to_string(),
+    ///     "(3+sqrt(2))/7"
+    /// );
+    /// ```
+    pub fn from_periodic_continued_fraction(
+        floor: &Integer,
+        xs: &RationalSequence<Natural>,
+    ) -> QuadraticIrrational {
+        let (non_repeating, repeating) = xs.slices_ref();
+        assert!(
+            !repeating.is_empty(),
+            "The continued fraction must be eventually periodic"
+        );
+        // If the period is $[r_0; r_1, \ldots, r_{k-1}]$ with convergents $p_i/q_i$, its value $y$
+        // satisfies $y = (p_{k-1}y + p_{k-2})/(q_{k-1}y + q_{k-2})$.
+        let mut previous_numerator = Natural::ZERO;
+        let mut numerator = Natural::ONE;
+        let mut previous_denominator = Natural::ONE;
+        let mut denominator = Natural::ZERO;
+        for r in repeating {
+            assert_ne!(
+                *r, 0u32,
+                "Continued fraction terms after the first must be positive"
+            );
+            previous_numerator += r * &numerator;
+            std::mem::swap(&mut numerator, &mut previous_numerator);
+            previous_denominator += r * &denominator;
+            std::mem::swap(&mut denominator, &mut previous_denominator);
+        }
+        // $y$ is the positive root of $q_{k-1}y^2 + (q_{k-2} - p_{k-1})y - p_{k-2}$.
+        let b = Integer::from(&previous_denominator) - Integer::from(&numerator);
+        let discriminant =
+            (&b * &b).unsigned_abs() + Natural::from(4u32) * &denominator * previous_numerator;
+        let (k, d) = remove_small_square_factors(discriminant);
+        let mut x =
+            QuadraticIrrational::from_parts(true, -b, Integer::from(k), denominator << 1, d);
+        let d = x.d.clone();
+        for r in non_repeating.iter().rev() {
+            assert_ne!(
+                *r, 0u32,
+                "Continued fraction terms after the first must be positive"
+            );
+            x = x.reciprocal() + QuadraticIrrational::from_rational(Rational::from(r), d.clone());
+        }
+        x.reciprocal() + QuadraticIrrational::from_rational(Rational::from(floor), d)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::conversion::traits::{ContinuedFraction, Convergents};
+use crate::quadratic_irrational::conversion::continued_fraction::QuadraticIrrationalContinuedFraction;
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::mem::swap;
+
+/// An iterator that produces the convergents of a [`QuadraticIrrational`].
+///
+/// See [`convergents`](QuadraticIrrational::convergents) for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuadraticIrrationalConvergents {
+    first: bool,
+    previous_numerator: Integer,
+    previous_denominator: Natural,
+    numerator: Integer,
+    denominator: Natural,
+    cf: QuadraticIrrationalContinuedFraction,
+}
+
+impl Iterator for QuadraticIrrationalConvergents {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        if self.first {
+            self.first = false;
+            Some(Rational::from(&self.numerator))
+        } else if let Some(n) = self.cf.next() {
+            self.previous_numerator
+                .add_mul_assign(&self.numerator, Integer::from(&n));
+            self.previous_denominator
+                .add_mul_assign(&self.denominator, n);
+            swap(&mut self.numerator, &mut self.previous_numerator);
+            swap(&mut self.denominator, &mut self.previous_denominator);
+            Some(Rational {
+                sign: self.numerator >= 0,
+                numerator: (&self.numerator).unsigned_abs(),
+                denominator: self.denominator.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Convergents for QuadraticIrrational {
+    type C = QuadraticIrrationalConvergents;
+
+    /// Returns the convergents of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by
+    /// value.
+    ///
+    /// The convergents of a number are the sequence of rational numbers whose continued fractions
+    /// are the prefixes of the number's continued fraction. The first convergent is the floor of
+    /// the number. The sequence of convergents is finite iff the number is rational. Each
+    /// convergent is closer to the number than the previous convergent is.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and the current convergent.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::conversion::traits::Convergents;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(
+    ///     QuadraticIrrational::sqrt(Natural::from(2u32))
+    ///         .convergents()
+    ///         .take(6)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 3/2, 7/5, 17/12, 41/29, 99/70]"
+    /// );
+    /// ```
+    #[inline]
+    fn convergents(self) -> QuadraticIrrationalConvergents {
+        (&self).convergents()
+    }
+}
+
+impl<'a> Convergents for &'a QuadraticIrrational {
+    type C = QuadraticIrrationalConvergents;
+
+    /// Returns the convergents of a [`QuadraticIrrational`], taking the [`QuadraticIrrational`] by
+    /// reference.
+    ///
+    /// The convergents of a number are the sequence of rational numbers whose continued fractions
+    /// are the prefixes of the number's continued fraction. The first convergent is the floor of
+    /// the number. The sequence of convergents is finite iff the number is rational. Each
+    /// convergent is closer to the number than the previous convergent is.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self` and the current convergent.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::conversion::traits::Convergents;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(
+    ///     (&phi).convergents().take(7).collect_vec().to_debug_string(),
+    ///     "[1, 2, 3/2, 5/3, 8/5, 13/8, 21/13]"
+    /// );
+    /// ```
+    fn convergents(self) -> QuadraticIrrationalConvergents {
+        let (floor, cf) = self.continued_fraction();
+        QuadraticIrrationalConvergents {
+            first: true,
+            previous_numerator: Integer::ONE,
+            previous_denominator: Natural::ZERO,
+            numerator: floor,
+            denominator: Natural::ONE,
+            cf,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// Implementations of [`ContinuedFraction`](crate::conversion::traits::ContinuedFraction), a trait
+/// for generating the continued fraction of a number, and functions for converting
+/// [`QuadraticIrrational`](crate::quadratic_irrational::QuadraticIrrational)s to and from periodic
+/// continued fractions.
+pub mod continued_fraction;
+/// Implementations of [`Convergents`](crate::conversion::traits::Convergents), a trait for
+/// generating the convergents of a number.
+pub mod convergents;
+/// Implementations of [`Display`](std::fmt::Display) and [`Debug`].
+pub mod to_string;
@@ -0,0 +1,102 @@
+use crate::quadratic_irrational::QuadraticIrrational;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+impl Display for QuadraticIrrational {
+    /// Converts a [`QuadraticIrrational`] to a [`String`].
+    ///
+    /// The number is written as $(a+b\sqrt{d})/c$, in the form `"(a+b*sqrt(d))/c"`. Terms that are
+    /// zero, coefficients that are 1 or -1, and a denominator of 1 are omitted, along with any
+    /// parentheses that are no longer needed. Rational numbers are written as they would be for a
+    /// [`Rational`](crate::Rational). Width, fill, and alignment apply to the whole string.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let new = |a: i32, b: i32, c: i32, d: u32| {
+    ///     QuadraticIrrational::new(
+    ///         Integer::from(a),
+    ///         Integer::from(b),
+    ///         Integer::from(c),
+    ///         Natural::from(d),
+    ///     )
+    /// };
+    /// assert_eq!(QuadraticIrrational::ZERO.to_string(), "0");
+    /// assert_eq!(new(-3, 0, 4, 2).to_string(), "-3/4");
+    /// assert_eq!(new(0, 1, 1, 2).to_string(), "sqrt(2)");
+    /// assert_eq!(new(0, -1, 1, 2).to_string(), "-sqrt(2)");
+    /// assert_eq!(new(0, 3, 1, 2).to_string(), "3*sqrt(2)");
+    /// assert_eq!(new(0, 1, 2, 2).to_string(), "sqrt(2)/2");
+    /// assert_eq!(new(-1, 1, 1, 2).to_string(), "-1+sqrt(2)");
+    /// assert_eq!(new(1, -1, 2, 5).to_string(), "(1-sqrt(5))/2");
+    /// assert_eq!(new(1, -2, 3, 7).to_string(), "(1-2*sqrt(7))/3");
+    /// assert_eq!(format!("{:>12}", new(0, 1, 1, 2)), "     sqrt(2)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut numerator = String::new();
+        let has_a = self.a != 0u32 || self.b == 0u32;
+        if has_a {
+            numerator.push_str(&self.a.to_string());
+        }
+        if self.b != 0u32 {
+            if self.b < 0u32 {
+                numerator.push('-');
+            } else if has_a {
+                numerator.push('+');
+            }
+            let b_abs = self.b.unsigned_abs_ref();
+            if *b_abs != 1u32 {
+                numerator.push_str(&b_abs.to_string());
+                numerator.push('*');
+            }
+            numerator.push_str("sqrt(");
+            numerator.push_str(&self.d.to_string());
+            numerator.push(')');
+        }
+        if self.c == 1u32 {
+            f.pad(&numerator)
+        } else if has_a && self.b != 0u32 {
+            f.pad(&format!("({})/{}", numerator, self.c))
+        } else {
+            f.pad(&format!("{}/{}", numerator, self.c))
+        }
+    }
+}
+
+impl Debug for QuadraticIrrational {
+    /// Converts a [`QuadraticIrrational`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the parts of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(QuadraticIrrational::sqrt(Natural::from(3u32)).to_debug_string(), "sqrt(3)");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
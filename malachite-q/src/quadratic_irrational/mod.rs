@@ -0,0 +1,502 @@
+use crate::Rational;
+use malachite_base::named::Named;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, DivExactAssign, DivisibleBy, Gcd, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::borrow::Cow;
+
+/// A number of the form $(a + b\sqrt{d})/c$, where $a$ and $b$ are integers, $c$ is a positive
+/// integer, and $d$, the radicand, is a positive integer that is not a perfect square.
+///
+/// Every real root of a quadratic polynomial with integer coefficients has this form, and the
+/// [`QuadraticIrrational`]s are exactly the numbers whose continued fractions are eventually
+/// periodic. When $b = 0$ the number is rational.
+///
+/// The representation is kept in lowest terms: $c > 0$ and $\gcd(a, b, c) = 1$. The radicand is
+/// kept as given, so $\sqrt{8}$ and $2\sqrt{2}$ are represented differently; they are still equal,
+/// and may be combined arithmetically. Two numbers can be combined iff their radicands differ by a
+/// square factor, or one of them is rational; any two numbers can be compared.
+#[derive(Clone)]
+pub struct QuadraticIrrational {
+    // Invariants: `c` is positive, `gcd(a, b, c) == 1`, and `d` is not a perfect square. Zero is
+    // represented with `a == 0`, `b == 0`, and `c == 1`.
+    pub(crate) a: Integer,
+    pub(crate) b: Integer,
+    pub(crate) c: Natural,
+    pub(crate) d: Natural,
+}
+
+// Small square factors of a radicand are pulled out when a radicand is computed, rather than given
+// by the user. Larger square factors are left alone, since finding them requires factoring.
+const MAX_SQUARE_FACTOR_TRIAL: u32 = 1 << 10;
+
+// Writes `d` as $k^2 d'$, removing the squares of primes less than `MAX_SQUARE_FACTOR_TRIAL`.
+// Returns $(k, d')$.
+pub(crate) fn remove_small_square_factors(mut d: Natural) -> (Natural, Natural) {
+    let mut k = Natural::ONE;
+    let mut p = 2u32;
+    while p < MAX_SQUARE_FACTOR_TRIAL && Natural::from(p * p) <= d {
+        let p_squared = Natural::from(p * p);
+        while (&d).divisible_by(&p_squared) {
+            d.div_exact_assign(&p_squared);
+            k *= Natural::from(p);
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    (k, d)
+}
+
+impl QuadraticIrrational {
+    // Restores the invariants on `c` and on the common factors of `a`, `b`, and `c`.
+    pub(crate) fn from_parts(sign: bool, a: Integer, b: Integer, c: Natural, d: Natural) -> Self {
+        assert_ne!(c, 0u32);
+        let (mut a, mut b) = if sign { (a, b) } else { (-a, -b) };
+        let mut c = c;
+        if a == 0u32 && b == 0u32 {
+            return QuadraticIrrational {
+                a,
+                b,
+                c: Natural::ONE,
+                d,
+            };
+        }
+        let gcd = a.unsigned_abs_ref().gcd(b.unsigned_abs_ref()).gcd(&c);
+        if gcd != 1u32 {
+            let gcd_i = Integer::from(&gcd);
+            a.div_exact_assign(&gcd_i);
+            b.div_exact_assign(&gcd_i);
+            c.div_exact_assign(gcd);
+        }
+        QuadraticIrrational { a, b, c, d }
+    }
+
+    /// Creates a [`QuadraticIrrational`] equal to $(a + b\sqrt{d})/c$.
+    ///
+    /// The result is reduced to lowest terms, but the radicand is left as it is.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in `a`, `b`, `c`, and `d`.
+    ///
+    /// # Panics
+    /// Panics if `c` is zero or `d` is a perfect square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(phi.to_string(), "(1+sqrt(5))/2");
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(4),
+    ///     Integer::from(-2),
+    ///     Integer::from(-6),
+    ///     Natural::from(3u32),
+    /// );
+    /// assert_eq!(x.to_string(), "(-2+sqrt(3))/3");
+    /// ```
+    pub fn new(a: Integer, b: Integer, c: Integer, d: Natural) -> QuadraticIrrational {
+        assert!((&d).checked_sqrt().is_none(), "The radicand cannot be a perfect square");
+        let sign = c > 0u32;
+        QuadraticIrrational::from_parts(sign, a, b, c.unsigned_abs(), d)
+    }
+
+    /// Creates a [`QuadraticIrrational`] equal to a [`Rational`], with the given radicand.
+    ///
+    /// The radicand does not affect the value, and a rational [`QuadraticIrrational`] can be
+    /// combined with any other [`QuadraticIrrational`]; the radicand only determines how results
+    /// are written.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `d` is a perfect square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x =
+    ///     QuadraticIrrational::from_rational(Rational::from_signeds(-3, 4), Natural::from(2u32));
+    /// assert!(x.is_rational());
+    /// assert_eq!(x.to_string(), "-3/4");
+    /// ```
+    pub fn from_rational(x: Rational, d: Natural) -> QuadraticIrrational {
+        assert!((&d).checked_sqrt().is_none(), "The radicand cannot be a perfect square");
+        let sign = x.sign;
+        let (n, c) = x.into_numerator_and_denominator();
+        QuadraticIrrational {
+            a: Integer::from_sign_and_abs(sign, n),
+            b: Integer::ZERO,
+            c,
+            d,
+        }
+    }
+
+    /// Creates a [`QuadraticIrrational`] equal to $\sqrt{d}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `d` is a perfect square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(QuadraticIrrational::sqrt(Natural::from(2u32)).to_string(), "sqrt(2)");
+    /// ```
+    pub fn sqrt(d: Natural) -> QuadraticIrrational {
+        assert!((&d).checked_sqrt().is_none(), "The radicand cannot be a perfect square");
+        QuadraticIrrational {
+            a: Integer::ZERO,
+            b: Integer::ONE,
+            c: Natural::ONE,
+            d,
+        }
+    }
+
+    /// Returns a reference to $a$, where `self` is $(a + b\sqrt{d})/c$ in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(2),
+    ///     Integer::from(4),
+    ///     Integer::from(6),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(*x.a_ref(), 1);
+    /// assert_eq!(*x.b_ref(), 2);
+    /// assert_eq!(*x.c_ref(), 3);
+    /// assert_eq!(*x.radicand_ref(), 5);
+    /// ```
+    pub const fn a_ref(&self) -> &Integer {
+        &self.a
+    }
+
+    /// Returns a reference to $b$, where `self` is $(a + b\sqrt{d})/c$ in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [`a_ref`](QuadraticIrrational::a_ref).
+    pub const fn b_ref(&self) -> &Integer {
+        &self.b
+    }
+
+    /// Returns a reference to $c$, where `self` is $(a + b\sqrt{d})/c$ in lowest terms. It is
+    /// always positive.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [`a_ref`](QuadraticIrrational::a_ref).
+    pub const fn c_ref(&self) -> &Natural {
+        &self.c
+    }
+
+    /// Returns a reference to the radicand $d$, where `self` is $(a + b\sqrt{d})/c$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [`a_ref`](QuadraticIrrational::a_ref).
+    pub const fn radicand_ref(&self) -> &Natural {
+        &self.d
+    }
+
+    /// Determines whether a [`QuadraticIrrational`] is rational; that is, whether $b = 0$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!(!QuadraticIrrational::sqrt(Natural::from(2u32)).is_rational());
+    /// assert!(
+    ///     QuadraticIrrational::from_rational(Rational::from(3), Natural::from(2u32)).is_rational()
+    /// );
+    /// ```
+    pub fn is_rational(&self) -> bool {
+        self.b == 0u32
+    }
+
+    /// Converts a [`QuadraticIrrational`] to a [`Rational`], if it is rational.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = QuadraticIrrational::sqrt(Natural::from(2u32));
+    /// assert_eq!(sqrt_2.to_rational(), None);
+    /// assert_eq!((&sqrt_2 * &sqrt_2).to_rational(), Some(Rational::from(2)));
+    /// ```
+    pub fn to_rational(&self) -> Option<Rational> {
+        if self.b == 0u32 {
+            Some(Rational::from_sign_and_naturals_ref(
+                self.a >= 0u32,
+                self.a.unsigned_abs_ref(),
+                &self.c,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the conjugate of a [`QuadraticIrrational`]: $(a - b\sqrt{d})/c$, the other root of
+    /// its minimal polynomial.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in $a$, $b$, $c$, and $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(phi.conjugate().to_string(), "(1-sqrt(5))/2");
+    /// ```
+    pub fn conjugate(&self) -> QuadraticIrrational {
+        QuadraticIrrational {
+            a: self.a.clone(),
+            b: -&self.b,
+            c: self.c.clone(),
+            d: self.d.clone(),
+        }
+    }
+
+    /// Returns the norm of a [`QuadraticIrrational`]: the product of the number and its
+    /// conjugate, $(a^2 - b^2d)/c^2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in $a$, $b$, $c$, and $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(phi.norm(), -1);
+    /// ```
+    pub fn norm(&self) -> Rational {
+        let numerator = &self.a * &self.a - &self.b * &self.b * Integer::from(&self.d);
+        Rational::from_integers(numerator, Integer::from(&self.c * &self.c))
+    }
+
+    /// Returns the trace of a [`QuadraticIrrational`]: the sum of the number and its conjugate,
+    /// $2a/c$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in $a$ and $c$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let phi = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Integer::from(2),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(phi.trace(), 1);
+    /// ```
+    pub fn trace(&self) -> Rational {
+        Rational::from_integers(Integer::from(2u32) * &self.a, Integer::from(&self.c))
+    }
+
+    /// Rewrites a [`QuadraticIrrational`] using a different radicand, if possible.
+    ///
+    /// If `self` is $(a + b\sqrt{d})/c$ and $dd'$ is a perfect square $m^2$, then
+    /// $\sqrt{d} = m\sqrt{d'}/d'$, and the result is $(ad' + bm\sqrt{d'})/(cd')$ in lowest terms.
+    /// If $dd'$ is not a perfect square and `self` is irrational, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in $a$, $b$, $c$, $d$, and $d'$.
+    ///
+    /// # Panics
+    /// Panics if `d` is a perfect square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let sqrt_8 = QuadraticIrrational::sqrt(Natural::from(8u32));
+    /// let x = sqrt_8.with_radicand(&Natural::from(2u32)).unwrap();
+    /// assert_eq!(x.to_string(), "2*sqrt(2)");
+    /// assert_eq!(x, sqrt_8);
+    /// assert_eq!(sqrt_8.with_radicand(&Natural::from(3u32)), None);
+    /// ```
+    pub fn with_radicand(&self, d: &Natural) -> Option<QuadraticIrrational> {
+        assert!(d.checked_sqrt().is_none(), "The radicand cannot be a perfect square");
+        if *d == self.d {
+            return Some(self.clone());
+        }
+        if self.b == 0u32 {
+            return Some(QuadraticIrrational {
+                a: self.a.clone(),
+                b: Integer::ZERO,
+                c: self.c.clone(),
+                d: d.clone(),
+            });
+        }
+        let m = (&self.d * d).checked_sqrt()?;
+        let d_i = Integer::from(d);
+        Some(QuadraticIrrational::from_parts(
+            true,
+            &self.a * &d_i,
+            &self.b * Integer::from(m),
+            &self.c * d,
+            d.clone(),
+        ))
+    }
+}
+
+// Rewrites `x` and `y` with the same radicand, returning `None` if this is impossible. The radicand
+// of `x` is preferred.
+pub(crate) fn with_common_radicand<'a>(
+    x: &'a QuadraticIrrational,
+    y: &'a QuadraticIrrational,
+) -> Option<(Cow<'a, QuadraticIrrational>, Cow<'a, QuadraticIrrational>)> {
+    if x.d == y.d {
+        Some((Cow::Borrowed(x), Cow::Borrowed(y)))
+    } else if x.b == 0u32 {
+        Some((Cow::Owned(x.with_radicand(&y.d).unwrap()), Cow::Borrowed(y)))
+    } else {
+        Some((Cow::Borrowed(x), Cow::Owned(y.with_radicand(&x.d)?)))
+    }
+}
+
+// Like `with_common_radicand`, but panics if the radicands are incompatible. Used by arithmetic
+// operations.
+pub(crate) fn expect_common_radicand<'a>(
+    x: &'a QuadraticIrrational,
+    y: &'a QuadraticIrrational,
+) -> (Cow<'a, QuadraticIrrational>, Cow<'a, QuadraticIrrational>) {
+    with_common_radicand(x, y).expect("The radicands must differ by a square factor")
+}
+
+/// The constant 0, with radicand 2.
+impl Zero for QuadraticIrrational {
+    const ZERO: QuadraticIrrational = QuadraticIrrational {
+        a: Integer::ZERO,
+        b: Integer::ZERO,
+        c: Natural::ONE,
+        d: Natural::TWO,
+    };
+}
+
+/// The constant 1, with radicand 2.
+impl One for QuadraticIrrational {
+    const ONE: QuadraticIrrational = QuadraticIrrational {
+        a: Integer::ONE,
+        b: Integer::ZERO,
+        c: Natural::ONE,
+        d: Natural::TWO,
+    };
+}
+
+impl Default for QuadraticIrrational {
+    /// The default value of a [`QuadraticIrrational`], 0.
+    fn default() -> QuadraticIrrational {
+        QuadraticIrrational::ZERO
+    }
+}
+
+// Implements `Named` for `QuadraticIrrational`.
+impl_named!(QuadraticIrrational);
+
+/// Arithmetic on [`QuadraticIrrational`]s.
+pub mod arithmetic;
+/// Comparing [`QuadraticIrrational`]s.
+pub mod comparison;
+/// Converting [`QuadraticIrrational`]s to and from continued fractions and strings.
+pub mod conversion;
+/// Solving Pell's equation $x^2 - dy^2 = \pm 1$ and the generalized Pell equation
+/// $x^2 - dy^2 = n$.
+pub mod pell;
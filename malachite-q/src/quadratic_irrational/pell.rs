@@ -0,0 +1,370 @@
+use crate::quadratic_irrational::conversion::continued_fraction::IrrationalState;
+use malachite_base::num::arithmetic::traits::{
+    AbsAssign, CheckedSqrt, DivExact, DivisibleBy, FloorSqrt, NegAssign, Parity, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::collections::HashSet;
+use std::mem::swap;
+
+// Expands the continued fraction of $\sqrt{d}$ through its first period, $[a_0; a_1, \ldots, a_L]$.
+// Returns the convergent $p/q$ of $[a_0; a_1, \ldots, a_{L-1}]$ and the period length $L$. The
+// remaining value of the expansion is $(P + \sqrt{d})/Q$, and the period ends when $Q = 1$.
+//
+// The period has length $O(\sqrt{d} \log d)$, so the number of steps, and the size of $p$ and $q$,
+// are exponential in the number of bits of $d$. There is no cheaper way to find the fundamental
+// solution in general: it may itself have $O(\sqrt{d} \log d)$ digits.
+fn sqrt_period(d: &Natural) -> (Natural, Natural, u64) {
+    assert!(d.checked_sqrt().is_none(), "d cannot be a perfect square");
+    let a_0 = d.floor_sqrt();
+    let mut big_p = Natural::ZERO;
+    let mut big_q = Natural::ONE;
+    let mut a = a_0.clone();
+    let mut previous_p = Natural::ONE;
+    let mut p = a_0.clone();
+    let mut previous_q = Natural::ZERO;
+    let mut q = Natural::ONE;
+    let mut period_len = 0;
+    loop {
+        big_p = &a * &big_q - big_p;
+        big_q = (d - &big_p * &big_p) / big_q;
+        a = (&a_0 + &big_p) / &big_q;
+        period_len += 1;
+        if big_q == 1u32 {
+            return (p, q, period_len);
+        }
+        previous_p += &a * &p;
+        std::mem::swap(&mut p, &mut previous_p);
+        previous_q += &a * &q;
+        std::mem::swap(&mut q, &mut previous_q);
+    }
+}
+
+/// Returns the fundamental solution of Pell's equation $x^2 - dy^2 = 1$.
+///
+/// The fundamental solution is the solution $(x, y)$ with $y > 0$ and $y$ as small as possible.
+/// Every solution with $x, y > 0$ is produced by [`pell_solutions`]. The solution is found from the
+/// continued fraction of $\sqrt{d}$, whose period may have length $O(\sqrt{d} \log d)$; the
+/// solution itself may have that many digits. The running time is therefore exponential in the
+/// number of bits of `d`.
+///
+/// # Worst-case complexity
+/// $T(n) = O(2^n n^2)$
+///
+/// $M(n) = O(2^{n/2} n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+///
+/// # Panics
+/// Panics if `d` is a perfect square.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::quadratic_irrational::pell::pell_fundamental_solution;
+///
+/// assert_eq!(pell_fundamental_solution(&Natural::from(2u32)).to_debug_string(), "(3, 2)");
+/// assert_eq!(pell_fundamental_solution(&Natural::from(13u32)).to_debug_string(), "(649, 180)");
+/// assert_eq!(
+///     pell_fundamental_solution(&Natural::from(61u32)).to_debug_string(),
+///     "(1766319049, 226153980)"
+/// );
+/// ```
+pub fn pell_fundamental_solution(d: &Natural) -> (Natural, Natural) {
+    let (p, q, period_len) = sqrt_period(d);
+    if period_len.even() {
+        (p, q)
+    } else {
+        // $p^2 - dq^2 = -1$, so $(p + q\sqrt{d})^2$ gives the fundamental solution.
+        let x = &p * &p + d * &q * &q;
+        let y = (p * q) << 1;
+        (x, y)
+    }
+}
+
+/// Returns the fundamental solution of the negative Pell equation $x^2 - dy^2 = -1$, or `None` if
+/// it has no solutions.
+///
+/// There are solutions iff the period of the continued fraction of $\sqrt{d}$ has odd length. If
+/// $(x, y)$ is the fundamental solution, the solutions with $x, y > 0$ are given by the odd powers
+/// of $x + y\sqrt{d}$. As with [`pell_fundamental_solution`], the period may have length
+/// $O(\sqrt{d} \log d)$, so the running time is exponential in the number of bits of `d`.
+///
+/// # Worst-case complexity
+/// $T(n) = O(2^n n^2)$
+///
+/// $M(n) = O(2^{n/2} n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+///
+/// # Panics
+/// Panics if `d` is a perfect square.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::quadratic_irrational::pell::negative_pell_fundamental_solution;
+///
+/// assert_eq!(
+///     negative_pell_fundamental_solution(&Natural::from(2u32)).to_debug_string(),
+///     "Some((1, 1))"
+/// );
+/// assert_eq!(
+///     negative_pell_fundamental_solution(&Natural::from(13u32)).to_debug_string(),
+///     "Some((18, 5))"
+/// );
+/// assert_eq!(negative_pell_fundamental_solution(&Natural::from(3u32)), None);
+/// ```
+pub fn negative_pell_fundamental_solution(d: &Natural) -> Option<(Natural, Natural)> {
+    let (p, q, period_len) = sqrt_period(d);
+    if period_len.odd() {
+        Some((p, q))
+    } else {
+        None
+    }
+}
+
+// Expands $(z + \sqrt{d})/|m|$ as a continued fraction, keeping track of the convergents'
+// numerators $A_i$ and denominators $B_i$ through $G_i = |m|A_i - zB_i$. This is the core of the
+// Lagrange-Matthews-Mollin algorithm. Returns $(G_{i-1}, B_{i-1})$ for the first $i \geq 1$ at
+// which the remaining value $(P_i + \sqrt{d})/Q_i$ has $Q_i = \pm 1$; then
+// $G_{i-1}^2 - dB_{i-1}^2 = \pm m$. Returns `None` if every state of the expansion is seen
+// without finding such a $Q_i$. $|m|$ must divide $z^2 - d$.
+fn lmm_convergent(d: &Integer, z: &Integer, m_abs: &Integer) -> Option<(Integer, Integer)> {
+    let mut state = IrrationalState::from_parts(z.clone(), m_abs.clone(), d.clone());
+    let mut previous_g = -z;
+    let mut g = m_abs.clone();
+    let mut previous_b = Integer::ONE;
+    let mut b = Integer::ZERO;
+    // The states are eventually periodic, so every state has been seen once one repeats.
+    let mut seen = HashSet::new();
+    while seen.insert((state.p.clone(), state.q.clone())) {
+        let a = state.next_term();
+        previous_g += &a * &g;
+        swap(&mut g, &mut previous_g);
+        previous_b += a * &b;
+        swap(&mut b, &mut previous_b);
+        if *state.q.unsigned_abs_ref() == 1u32 {
+            return Some((g, b));
+        }
+    }
+    None
+}
+
+// Returns the member of the class of $x + y\sqrt{d}$ with the smallest $|y|$, negated if necessary
+// so that $y \geq 0$. The members of the class are $\pm(x + y\sqrt{d})(x_1 + y_1\sqrt{d})^k$, and
+// $|y|$ first decreases and then increases with $k$, so the smallest $|y|$ is found by stepping in
+// whichever direction decreases it. Two members with the same $|y|$ must be $x + y\sqrt{d}$ and
+// $-x + y\sqrt{d}$, up to sign; in that case the one with $x \geq 0$ is returned.
+fn class_representative(
+    mut x: Integer,
+    mut y: Integer,
+    d: &Integer,
+    x_1: &Integer,
+    y_1: &Integer,
+) -> (Integer, Natural) {
+    loop {
+        let x_down = &x * x_1 - d * &y * y_1;
+        let y_down = &y * x_1 - &x * y_1;
+        if y_down.unsigned_abs_ref() < y.unsigned_abs_ref() {
+            x = x_down;
+            y = y_down;
+            continue;
+        }
+        let x_up = &x * x_1 + d * &y * y_1;
+        let y_up = &y * x_1 + &x * y_1;
+        if y_up.unsigned_abs_ref() < y.unsigned_abs_ref() {
+            x = x_up;
+            y = y_up;
+            continue;
+        }
+        let tied = y_down.unsigned_abs_ref() == y.unsigned_abs_ref()
+            || y_up.unsigned_abs_ref() == y.unsigned_abs_ref();
+        if y < 0u32 {
+            x.neg_assign();
+            y.neg_assign();
+        }
+        // $x + 0\sqrt{d}$ and $-x + 0\sqrt{d}$ are always in the same class.
+        if tied || y == 0u32 {
+            x.abs_assign();
+        }
+        return (x, y.unsigned_abs());
+    }
+}
+
+/// Returns one fundamental solution from each class of solutions of the generalized Pell equation
+/// $x^2 - dy^2 = n$.
+///
+/// Two solutions are in the same class if one can be obtained from the other by multiplying
+/// $x + y\sqrt{d}$ by a solution of $x^2 - dy^2 = 1$, or by $-1$. Every solution is therefore
+/// $\pm(x + y\sqrt{d})(x_1 + y_1\sqrt{d})^k$ for one of the returned $(x, y)$, some
+/// $k \in \Z$, and the fundamental solution $(x_1, y_1)$ of Pell's equation.
+///
+/// The returned solutions have $y \geq 0$ and are in increasing order of $y$; each is the member of
+/// its class with the smallest $y$, and when $x$ and $-x$ both appear with the same $y$, the
+/// non-negative $x$ comes first. If there are no solutions, the result is empty.
+///
+/// The classes are found with the Lagrange-Matthews-Mollin algorithm. For each $f$ with
+/// $f^2 \mid n$, and each $z$ with $-|m|/2 < z \leq |m|/2$ and $z^2 \equiv d \pmod {|m|}$, where
+/// $m = n/f^2$, the continued fraction of $(z + \sqrt{d})/|m|$ is expanded through one period.
+/// This takes time polynomial in $|n|$ rather than in the size of the solutions, but each
+/// expansion, like finding the fundamental solution of Pell's equation, takes a number of steps
+/// that is exponential in the number of bits of `d`.
+///
+/// # Worst-case complexity
+/// $T(n, m) = O(2^{n + m} n^2)$
+///
+/// $M(n, m) = O(2^{n/2} n(n + m))$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `d.significant_bits()`, and $m$ is
+/// `n.significant_bits()`.
+///
+/// # Panics
+/// Panics if `d` is a perfect square.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::quadratic_irrational::pell::generalized_pell_fundamental_solutions;
+///
+/// assert_eq!(
+///     generalized_pell_fundamental_solutions(&Natural::from(2u32), &Integer::from(7))
+///         .to_debug_string(),
+///     "[(3, 1), (-3, 1)]"
+/// );
+/// assert_eq!(
+///     generalized_pell_fundamental_solutions(&Natural::from(5u32), &Integer::from(-4))
+///         .to_debug_string(),
+///     "[(1, 1), (-1, 1), (4, 2)]"
+/// );
+/// assert_eq!(
+///     generalized_pell_fundamental_solutions(&Natural::from(3u32), &Integer::from(-1))
+///         .to_debug_string(),
+///     "[]"
+/// );
+/// ```
+pub fn generalized_pell_fundamental_solutions(d: &Natural, n: &Integer) -> Vec<(Integer, Natural)> {
+    let (x_1, y_1) = pell_fundamental_solution(d);
+    if *n == 0u32 {
+        return vec![(Integer::ZERO, Natural::ZERO)];
+    }
+    let x_1 = Integer::from(x_1);
+    let y_1 = Integer::from(y_1);
+    let negative_solution =
+        negative_pell_fundamental_solution(d).map(|(x, y)| (Integer::from(x), Integer::from(y)));
+    let d = Integer::from(d);
+    let n_abs = n.unsigned_abs_ref();
+    let mut solutions = Vec::new();
+    // A solution with $\gcd(x, y) = f$ is $f$ times a solution of $x^2 - dy^2 = n/f^2$ with
+    // $\gcd(x, y) = 1$.
+    let mut f = Natural::ONE;
+    while &(&f * &f) <= n_abs {
+        let f_squared = &f * &f;
+        if n_abs.divisible_by(&f_squared) {
+            let m = n.div_exact(Integer::from(f_squared));
+            let m_abs = m.unsigned_abs_ref();
+            let z_max = Integer::from(m_abs >> 1);
+            let mut z = -Integer::from((m_abs - Natural::ONE) >> 1);
+            let m_abs = Integer::from(m_abs);
+            let f_i = Integer::from(&f);
+            while z <= z_max {
+                if (&z * &z - &d).divisible_by(&m_abs) {
+                    if let Some((g, b)) = lmm_convergent(&d, &z, &m_abs) {
+                        let solution = if &g * &g - &d * &b * &b == m {
+                            Some((g, b))
+                        } else {
+                            // $g^2 - db^2 = -m$, so multiplying by a solution of
+                            // $x^2 - dy^2 = -1$, if there is one, gives a solution.
+                            negative_solution
+                                .as_ref()
+                                .map(|(t, u)| (&g * t + &d * &b * u, g * u + b * t))
+                        };
+                        if let Some((x, y)) = solution {
+                            solutions.push(class_representative(
+                                x * &f_i,
+                                y * &f_i,
+                                &d,
+                                &x_1,
+                                &y_1,
+                            ));
+                        }
+                    }
+                }
+                z += Integer::ONE;
+            }
+        }
+        f += Natural::ONE;
+    }
+    solutions.sort_by(|(x, y), (x_other, y_other)| y.cmp(y_other).then_with(|| x_other.cmp(x)));
+    solutions
+}
+
+/// An iterator that produces the solutions of Pell's equation $x^2 - dy^2 = 1$ with $x, y > 0$.
+///
+/// See [`pell_solutions`] for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PellSolutions {
+    d: Natural,
+    x_1: Natural,
+    y_1: Natural,
+    x: Natural,
+    y: Natural,
+}
+
+impl Iterator for PellSolutions {
+    type Item = (Natural, Natural);
+
+    // Multiplies $x + y\sqrt{d}$ by the fundamental solution $x_1 + y_1\sqrt{d}$.
+    fn next(&mut self) -> Option<(Natural, Natural)> {
+        let x = &self.x_1 * &self.x + &self.d * &self.y_1 * &self.y;
+        let y = &self.x_1 * &self.y + &self.y_1 * &self.x;
+        self.x = x;
+        self.y = y;
+        Some((self.x.clone(), self.y.clone()))
+    }
+}
+
+/// Returns an iterator over the solutions of Pell's equation $x^2 - dy^2 = 1$ with $x, y > 0$, in
+/// increasing order.
+///
+/// The $k$th solution is given by $(x_1 + y_1\sqrt{d})^k$, where $(x_1, y_1)$ is the fundamental
+/// solution. The iterator never ends.
+///
+/// # Worst-case complexity per iteration
+/// $T(n) = O(n \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits in the
+/// current solution. Getting the first solution takes as long as [`pell_fundamental_solution`].
+///
+/// # Panics
+/// Panics if `d` is a perfect square.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::quadratic_irrational::pell::pell_solutions;
+///
+/// assert_eq!(
+///     pell_solutions(&Natural::from(2u32)).take(4).collect_vec().to_debug_string(),
+///     "[(3, 2), (17, 12), (99, 70), (577, 408)]"
+/// );
+/// ```
+pub fn pell_solutions(d: &Natural) -> PellSolutions {
+    let (x_1, y_1) = pell_fundamental_solution(d);
+    PellSolutions {
+        d: d.clone(),
+        x_1,
+        y_1,
+        x: Natural::ONE,
+        y: Natural::ZERO,
+    }
+}
@@ -0,0 +1,182 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, DivMod, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::BitIterable;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Returns the [`Rational`] after a positive [`Rational`] in the breadth-first traversal of
+    /// the Calkin-Wilf tree.
+    ///
+    /// This is the order in which
+    /// [`exhaustive_positive_rationals`](crate::exhaustive::exhaustive_positive_rationals)
+    /// generates the positive [`Rational`]s. The next element after $x$ is
+    /// $1/(2\lfloor x \rfloor + 1 - x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(1u32).calkin_wilf_next().to_string(), "1/2");
+    /// assert_eq!(Rational::from_signeds(1, 2).calkin_wilf_next().to_string(), "2");
+    /// assert_eq!(Rational::from_signeds(3, 2).calkin_wilf_next().to_string(), "2/3");
+    /// assert_eq!(Rational::from(3u32).calkin_wilf_next().to_string(), "1/4");
+    /// ```
+    pub fn calkin_wilf_next(&self) -> Rational {
+        assert!(
+            *self > 0u32,
+            "Only positive Rationals are in the Calkin-Wilf tree"
+        );
+        // With $a = qb + r$, $(2q + 1)b - a = (q + 1)b - r$, which is coprime to $b$.
+        let (q, r) = (&self.numerator).div_mod(&self.denominator);
+        Rational {
+            sign: true,
+            numerator: self.denominator.clone(),
+            denominator: (q + Natural::ONE) * &self.denominator - r,
+        }
+    }
+
+    /// Returns the position of a positive [`Rational`] in the breadth-first traversal of the
+    /// Calkin-Wilf tree.
+    ///
+    /// Positions start at 0, so this is a bijection from the positive [`Rational`]s to the
+    /// [`Natural`]s, and the inverse of
+    /// [`from_calkin_wilf_index`](Rational::from_calkin_wilf_index). The [`Rational`] at position
+    /// $i$ is the $i$th element generated by
+    /// [`exhaustive_positive_rationals`](crate::exhaustive::exhaustive_positive_rationals). The
+    /// binary digits of $i + 1$ after the leading 1 give the path from the root to the
+    /// [`Rational`], with 0 for a step to the left child $a/(a+b)$ and 1 for a step to the right
+    /// child $(a+b)/b$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the output, which may be exponentially larger than `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(1u32).calkin_wilf_index(), 0);
+    /// assert_eq!(Rational::from_signeds(1, 2).calkin_wilf_index(), 1);
+    /// assert_eq!(Rational::from_signeds(3, 4).calkin_wilf_index(), 13);
+    /// assert_eq!(Rational::from_signeds(355, 113).calkin_wilf_index(), 67107846);
+    /// ```
+    pub fn calkin_wilf_index(&self) -> Natural {
+        assert!(
+            *self > 0u32,
+            "Only positive Rationals are in the Calkin-Wilf tree"
+        );
+        // Walk up the tree to the root, using division to take runs of steps at once. Each step
+        // up from a right child removes a trailing 1 from the path, and each step up from a left
+        // child removes a trailing 0.
+        let mut a = self.numerator.clone();
+        let mut b = self.denominator.clone();
+        let mut index = Natural::ZERO;
+        let mut shift = 0;
+        loop {
+            if a > b {
+                let (q, r) = (&a).div_mod(&b);
+                let run = if r == 0u32 {
+                    u64::exact_from(&(q - Natural::ONE))
+                } else {
+                    u64::exact_from(&q)
+                };
+                index |= (Natural::power_of_2(run) - Natural::ONE) << shift;
+                shift += run;
+                if r == 0u32 {
+                    break;
+                }
+                a = r;
+            } else if a < b {
+                let (q, r) = (&b).div_mod(&a);
+                if r == 0u32 {
+                    shift += u64::exact_from(&(q - Natural::ONE));
+                    break;
+                }
+                shift += u64::exact_from(&q);
+                b = r;
+            } else {
+                break;
+            }
+        }
+        index |= Natural::power_of_2(shift);
+        index - Natural::ONE
+    }
+
+    /// Returns the positive [`Rational`] at a given position in the breadth-first traversal of
+    /// the Calkin-Wilf tree.
+    ///
+    /// Positions start at 0, so this is a bijection from the [`Natural`]s to the positive
+    /// [`Rational`]s, and the inverse of [`calkin_wilf_index`](Rational::calkin_wilf_index). See
+    /// that function for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `index.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from_calkin_wilf_index(&Natural::from(0u32)), 1);
+    /// assert_eq!(Rational::from_calkin_wilf_index(&Natural::from(1u32)).to_string(), "1/2");
+    /// assert_eq!(Rational::from_calkin_wilf_index(&Natural::from(13u32)).to_string(), "3/4");
+    /// assert_eq!(
+    ///     Rational::from_calkin_wilf_index(&Natural::from(67107846u32)).to_string(),
+    ///     "355/113"
+    /// );
+    /// ```
+    pub fn from_calkin_wilf_index(index: &Natural) -> Rational {
+        let n = index + Natural::ONE;
+        let mut a = Natural::ONE;
+        let mut b = Natural::ONE;
+        // Follow the path given by the bits of `n` after the leading 1, taking runs of equal bits
+        // at once.
+        let mut run_bit = true;
+        let mut run_len = 0u64;
+        for bit in n.bits().rev().skip(1) {
+            if bit != run_bit {
+                if run_bit {
+                    a.add_mul_assign(&b, Natural::from(run_len));
+                } else {
+                    b.add_mul_assign(&a, Natural::from(run_len));
+                }
+                run_bit = bit;
+                run_len = 0;
+            }
+            run_len += 1;
+        }
+        if run_bit {
+            a.add_mul_assign(&b, Natural::from(run_len));
+        } else {
+            b.add_mul_assign(&a, Natural::from(run_len));
+        }
+        Rational {
+            sign: true,
+            numerator: a,
+            denominator: b,
+        }
+    }
+}
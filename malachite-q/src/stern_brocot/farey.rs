@@ -0,0 +1,253 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Floor, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::min;
+
+// Builds the `Rational` $p/q$, where $p$ and $q$ are known to be coprime.
+fn from_coprime(p: Integer, q: Natural) -> Rational {
+    Rational {
+        sign: p >= 0u32,
+        numerator: p.unsigned_abs(),
+        denominator: q,
+    }
+}
+
+impl Rational {
+    /// Returns the neighbors of a [`Rational`] among the [`Rational`]s whose denominators are no
+    /// greater than a specified maximum.
+    ///
+    /// The first component of the output is the largest [`Rational`] less than `self` with
+    /// denominator at most $n$, and the second is the smallest [`Rational`] greater than `self`
+    /// with denominator at most $n$. When `self` is in $[0, 1]$, these are its neighbors in the
+    /// Farey sequence of order $n$, or the two consecutive elements of that sequence that surround
+    /// it. If the neighbors are $a/b < c/d$, then $bc - ad = 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), max_denominator.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `max_denominator` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 3).farey_neighbors(&Natural::from(7u32)).to_debug_string(),
+    ///     "(2/7, 2/5)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(355, 113).farey_neighbors(&Natural::from(100u32))
+    ///         .to_debug_string(),
+    ///     "(311/99, 22/7)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(2u32).farey_neighbors(&Natural::from(5u32)).to_debug_string(),
+    ///     "(9/5, 11/5)"
+    /// );
+    /// ```
+    ///
+    /// # Implementation notes
+    /// The neighbors are found by descending the Stern-Brocot tree towards `self`. Runs of steps
+    /// in the same direction are taken all at once, using division, so the number of iterations is
+    /// proportional to the length of the continued fraction of `self`.
+    pub fn farey_neighbors(&self, max_denominator: &Natural) -> (Rational, Rational) {
+        assert_ne!(*max_denominator, 0u32);
+        let n = max_denominator;
+        let a = Integer::from_sign_and_abs_ref(self.sign, &self.numerator);
+        let b = &self.denominator;
+        let b_i = Integer::from(b);
+        if *b == 1u32 {
+            let n_i = Integer::from(n);
+            let scaled = &a * &n_i;
+            return (
+                from_coprime(&scaled - Integer::ONE, n.clone()),
+                from_coprime(scaled + Integer::ONE, n.clone()),
+            );
+        }
+        // Invariants: $p_0/q_0 < x < p_1/q_1$, $p_1q_0 - p_0q_1 = 1$, and $q_0, q_1 \leq n$.
+        let mut p_0 = self.floor();
+        let mut q_0 = Natural::ONE;
+        let mut p_1 = &p_0 + Integer::ONE;
+        let mut q_1 = Natural::ONE;
+        loop {
+            let q_sum = &q_0 + &q_1;
+            if q_sum > *n {
+                return (from_coprime(p_0, q_0), from_coprime(p_1, q_1));
+            }
+            if q_sum == *b && &p_0 + &p_1 == a {
+                // `self` is the mediant of its bounds. Its neighbors are the last fractions with
+                // small enough denominators on the paths from `self` towards each bound,
+                // $(p_0 + ja)/(q_0 + jb)$ and $(p_1 + ja)/(q_1 + jb)$.
+                let j = (n - &q_0) / b;
+                let left = from_coprime(p_0 + Integer::from(&j) * &a, q_0 + j * b);
+                let j = (n - &q_1) / b;
+                let right = from_coprime(p_1 + Integer::from(&j) * &a, q_1 + j * b);
+                return (left, right);
+            }
+            // Move the left bound right as far as possible: $(p_0 + kp_1)/(q_0 + kq_1) < x$ iff
+            // $k(p_1b - aq_1) < aq_0 - p_0b$.
+            let num = (&a * Integer::from(&q_0) - &p_0 * &b_i).unsigned_abs();
+            let den = (&p_1 * &b_i - &a * Integer::from(&q_1)).unsigned_abs();
+            let k = min((num - Natural::ONE) / &den, (n - &q_0) / &q_1);
+            p_0 += &p_1 * Integer::from(&k);
+            q_0 += &q_1 * k;
+            // Move the right bound left as far as possible, in the same way.
+            let num = (&a * Integer::from(&q_0) - &p_0 * &b_i).unsigned_abs();
+            let k = min((den - Natural::ONE) / num, (n - &q_1) / &q_0);
+            p_1 += &p_0 * Integer::from(&k);
+            q_1 += &q_0 * k;
+        }
+    }
+}
+
+/// Generates the [`Rational`]s in a closed interval whose denominators are no greater than a
+/// specified maximum, in ascending order.
+///
+/// This `struct` is created by [`farey`] and [`farey_in_closed_interval`]; see their
+/// documentation for more.
+#[derive(Clone, Debug)]
+pub struct FareySequence {
+    max_denominator: Natural,
+    a: Integer,
+    b: Natural,
+    c: Integer,
+    d: Natural,
+    end: Rational,
+}
+
+impl Iterator for FareySequence {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let x = Rational {
+            sign: self.a >= 0u32,
+            numerator: self.a.unsigned_abs_ref().clone(),
+            denominator: self.b.clone(),
+        };
+        if x > self.end {
+            return None;
+        }
+        // If $a/b$ and $c/d$ are consecutive, the next element is $(kc - a)/(kd - b)$, where
+        // $k = \lfloor (n + b)/d \rfloor$.
+        let k = (&self.max_denominator + &self.b) / &self.d;
+        let e = Integer::from(&k) * &self.c - &self.a;
+        let f = k * &self.d - &self.b;
+        self.a = std::mem::replace(&mut self.c, e);
+        self.b = std::mem::replace(&mut self.d, f);
+        Some(x)
+    }
+}
+
+/// Generates the Farey sequence of order $n$: the [`Rational`]s in $[0, 1]$ whose denominators are
+/// no greater than $n$, in ascending order.
+///
+/// Each element is computed from the previous two, without sorting: if $a/b$ and $c/d$ are
+/// consecutive, the next element is $(kc - a)/(kd - b)$, where $k = \lfloor (n + b)/d \rfloor$.
+///
+/// The output length is $1 + \sum_{k=1}^n \varphi(k) \sim 3n^2/\pi^2$, where $\varphi$ is Euler's
+/// totient function.
+///
+/// # Worst-case complexity per iteration
+/// $T(m) = O(m \log m \log\log m)$
+///
+/// $M(m) = O(m \log m)$
+///
+/// where $T$ is time, $M$ is additional memory, and $m$ is `n.significant_bits()`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::stern_brocot::farey::farey;
+///
+/// assert_eq!(
+///     prefix_to_string(farey(Natural::from(5u32)), 20),
+///     "[0, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1]"
+/// );
+/// ```
+#[inline]
+pub fn farey(n: Natural) -> FareySequence {
+    farey_in_closed_interval(n, &Rational::ZERO, &Rational::ONE)
+}
+
+/// Generates the [`Rational`]s in the closed interval $[x, y]$ whose denominators are no greater
+/// than $n$, in ascending order.
+///
+/// This extends the Farey sequence of order $n$ to an arbitrary interval. The first two elements
+/// are found with [`farey_neighbors`](Rational::farey_neighbors), and each later element is
+/// computed from the previous two, as in [`farey`].
+///
+/// If $x > y$, the output is empty. Otherwise, the output length is about $3n^2(y-x)/\pi^2$.
+///
+/// # Worst-case complexity per iteration
+/// $T(m) = O(m \log m \log\log m)$
+///
+/// $M(m) = O(m \log m)$
+///
+/// where $T$ is time, $M$ is additional memory, and $m$ is
+/// `max(n.significant_bits(), x.significant_bits(), y.significant_bits())`. Computing the first
+/// element takes $O(m^2 \log m \log\log m)$ time.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::stern_brocot::farey::farey_in_closed_interval;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         farey_in_closed_interval(
+///             Natural::from(4u32),
+///             &Rational::from_signeds(-1, 2),
+///             &Rational::from_signeds(3, 2)
+///         ),
+///         20
+///     ),
+///     "[-1/2, -1/3, -1/4, 0, 1/4, 1/3, 1/2, 2/3, 3/4, 1, 5/4, 4/3, 3/2]"
+/// );
+/// assert_eq!(
+///     prefix_to_string(
+///         farey_in_closed_interval(
+///             Natural::from(3u32),
+///             &Rational::from_signeds(3, 10),
+///             &Rational::from_signeds(7, 10)
+///         ),
+///         20
+///     ),
+///     "[1/3, 1/2, 2/3]"
+/// );
+/// ```
+pub fn farey_in_closed_interval(n: Natural, x: &Rational, y: &Rational) -> FareySequence {
+    assert_ne!(n, 0u32);
+    let first = if x.denominator <= n {
+        x.clone()
+    } else {
+        x.farey_neighbors(&n).1
+    };
+    let (_, second) = first.farey_neighbors(&n);
+    FareySequence {
+        max_denominator: n,
+        a: Integer::from_sign_and_abs(first.sign, first.numerator),
+        b: first.denominator,
+        c: Integer::from_sign_and_abs(second.sign, second.numerator),
+        d: second.denominator,
+        end: y.clone(),
+    }
+}
@@ -0,0 +1,9 @@
+/// Functions for moving through the Calkin-Wilf tree, and for converting between positive
+/// [`Rational`](crate::Rational)s and their positions in it.
+pub mod calkin_wilf;
+/// Iterators that generate Farey sequences, and a function for finding the neighbors of a
+/// [`Rational`](crate::Rational) in a Farey sequence.
+pub mod farey;
+/// Functions for converting between positive [`Rational`](crate::Rational)s and their paths in
+/// the Stern-Brocot tree.
+pub mod path;
@@ -0,0 +1,137 @@
+use crate::conversion::traits::ContinuedFraction;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+use std::iter::once;
+
+/// A step from a node of the Stern-Brocot tree to one of its children.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SternBrocotDirection {
+    /// A step to the left child, which is less than its parent.
+    Left,
+    /// A step to the right child, which is greater than its parent.
+    Right,
+}
+
+impl Rational {
+    /// Returns the path from the root of the Stern-Brocot tree to a positive [`Rational`].
+    ///
+    /// The root of the tree is 1, and every positive [`Rational`] appears exactly once. The path
+    /// is run-length encoded: each element is a direction and the number of consecutive steps
+    /// taken in that direction. The directions alternate and the counts are positive, so the path
+    /// of 1 is empty. The counts are the terms of the continued fraction of `self`, with the last
+    /// term decreased by 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(1u32).stern_brocot_path().to_debug_string(), "[]");
+    /// assert_eq!(
+    ///     Rational::from_signeds(3, 5).stern_brocot_path().to_debug_string(),
+    ///     "[(Left, 1), (Right, 1), (Left, 1)]"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(355, 113).stern_brocot_path().to_debug_string(),
+    ///     "[(Right, 3), (Left, 7), (Right, 15)]"
+    /// );
+    /// ```
+    pub fn stern_brocot_path(&self) -> Vec<(SternBrocotDirection, Natural)> {
+        assert!(
+            *self > 0u32,
+            "Only positive Rationals are in the Stern-Brocot tree"
+        );
+        let (floor, cf) = self.continued_fraction();
+        let mut terms: Vec<Natural> = once(floor.unsigned_abs()).chain(cf).collect();
+        *terms.last_mut().unwrap() -= Natural::ONE;
+        terms
+            .into_iter()
+            .zip(
+                [SternBrocotDirection::Right, SternBrocotDirection::Left]
+                    .iter()
+                    .cycle(),
+            )
+            .filter(|(count, _)| *count != 0u32)
+            .map(|(count, &direction)| (direction, count))
+            .collect()
+    }
+
+    /// Returns the [`Rational`] at the end of a path from the root of the Stern-Brocot tree.
+    ///
+    /// The path is run-length encoded, as in the output of
+    /// [`stern_brocot_path`](Rational::stern_brocot_path). Consecutive elements may have the same
+    /// direction, and counts may be zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the counts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::stern_brocot::path::SternBrocotDirection;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from_stern_brocot_path(&[]), 1);
+    /// assert_eq!(
+    ///     Rational::from_stern_brocot_path(&[
+    ///         (SternBrocotDirection::Right, Natural::from(3u32)),
+    ///         (SternBrocotDirection::Left, Natural::from(7u32)),
+    ///         (SternBrocotDirection::Right, Natural::from(15u32)),
+    ///     ])
+    ///     .to_string(),
+    ///     "355/113"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_stern_brocot_path(&[
+    ///         (SternBrocotDirection::Left, Natural::from(1u32)),
+    ///         (SternBrocotDirection::Left, Natural::from(1u32)),
+    ///     ])
+    ///     .to_string(),
+    ///     "1/3"
+    /// );
+    /// ```
+    pub fn from_stern_brocot_path(path: &[(SternBrocotDirection, Natural)]) -> Rational {
+        // The node reached so far is $(a + b)/(c + d)$, the mediant of $b/d$ and $a/c$, its nearest
+        // ancestors to the left and right. A run of $k$ steps multiplies the matrix
+        // `[[a, b], [c, d]]` on the right by `[[1, 0], [k, 1]]` for left steps, or by
+        // `[[1, k], [0, 1]]` for right steps.
+        let mut a = Natural::ONE;
+        let mut b = Natural::ZERO;
+        let mut c = Natural::ZERO;
+        let mut d = Natural::ONE;
+        for (direction, count) in path {
+            match direction {
+                SternBrocotDirection::Left => {
+                    a.add_mul_assign(&b, count);
+                    c.add_mul_assign(&d, count);
+                }
+                SternBrocotDirection::Right => {
+                    b.add_mul_assign(&a, count);
+                    d.add_mul_assign(&c, count);
+                }
+            }
+        }
+        Rational {
+            sign: true,
+            numerator: a + b,
+            denominator: c + d,
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use crate::Rational;
 use malachite_nz::integer::Integer;
+#[cfg(not(feature = "num-bigint"))]
 use num::{BigInt, BigRational};
 
+// With the `num-bigint` feature, the crate itself provides these conversions.
+#[cfg(not(feature = "num-bigint"))]
 impl From<&BigRational> for Rational {
     fn from(n: &BigRational) -> Rational {
         Rational::from_integers(Integer::from(n.numer()), Integer::from(n.denom()))
     }
 }
 
+#[cfg(not(feature = "num-bigint"))]
 impl From<&Rational> for BigRational {
     fn from(n: &Rational) -> BigRational {
         let mut q = BigRational::new_raw(
@@ -0,0 +1,654 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+impl UnreducedRational {
+    // Adds $(-1)^{s+1}n/d$ to `self`, where $s$ is `sign`, $n$ is `numerator`, and $d$ is
+    // `denominator`. Subtraction passes the negated sign.
+    pub(crate) fn add_assign_parts(
+        &mut self,
+        sign: bool,
+        numerator: &Natural,
+        denominator: &Natural,
+    ) {
+        let sum = if self.denominator == *denominator {
+            Integer::from_sign_and_abs_ref(self.sign, &self.numerator)
+                + Integer::from_sign_and_abs_ref(sign, numerator)
+        } else {
+            let x = Integer::from_sign_and_abs(self.sign, &self.numerator * denominator);
+            let y = Integer::from_sign_and_abs(sign, numerator * &self.denominator);
+            self.denominator *= denominator;
+            x + y
+        };
+        self.sign = sum >= 0;
+        self.numerator = sum.unsigned_abs();
+        self.maybe_reduce();
+    }
+}
+
+impl Add<UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds two [`UnreducedRational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// The result's numerator and denominator are computed by cross-multiplication and are only
+    /// reduced once the denominator has doubled in size since the last reduction.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = x + y;
+    /// assert_eq!(*z.numerator_ref(), 9);
+    /// assert_eq!(*z.denominator_ref(), 18);
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add(mut self, other: UnreducedRational) -> UnreducedRational {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<&'a UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds two [`UnreducedRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = x + &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &'a UnreducedRational) -> UnreducedRational {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<UnreducedRational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds two [`UnreducedRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = &x + y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add(self, other: UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x += other;
+        x
+    }
+}
+
+impl<'a, 'b> Add<&'a UnreducedRational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds two [`UnreducedRational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = &x + &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x += other;
+        x
+    }
+}
+
+impl AddAssign<UnreducedRational> for UnreducedRational {
+    /// Adds an [`UnreducedRational`] to an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// x += y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: UnreducedRational) {
+        self.add_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> AddAssign<&'a UnreducedRational> for UnreducedRational {
+    /// Adds an [`UnreducedRational`] to an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// x += &y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a UnreducedRational) {
+        self.add_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Add<Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds an [`UnreducedRational`] and a [`Rational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x + y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(mut self, other: Rational) -> UnreducedRational {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<&'a Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds an [`UnreducedRational`] and a [`Rational`], taking the [`UnreducedRational`] by value
+    /// and the [`Rational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x + &y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &'a Rational) -> UnreducedRational {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<Rational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds an [`UnreducedRational`] and a [`Rational`], taking the [`UnreducedRational`] by
+    /// reference and the [`Rational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x + y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x += other;
+        x
+    }
+}
+
+impl<'a, 'b> Add<&'a Rational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Adds an [`UnreducedRational`] and a [`Rational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x + &y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x += other;
+        x
+    }
+}
+
+impl AddAssign<Rational> for UnreducedRational {
+    /// Adds a [`Rational`] to an [`UnreducedRational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x += y;
+    /// assert_eq!(x.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Rational) {
+        self.add_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> AddAssign<&'a Rational> for UnreducedRational {
+    /// Adds a [`Rational`] to an [`UnreducedRational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x += &y;
+    /// assert_eq!(x.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Rational) {
+        self.add_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Add<UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Adds a [`Rational`] and an [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = x + y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: UnreducedRational) -> UnreducedRational {
+        other + self
+    }
+}
+
+impl<'a> Add<&'a UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Adds a [`Rational`] and an [`UnreducedRational`], taking the [`Rational`] by value and the
+    /// [`UnreducedRational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = x + &y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a UnreducedRational) -> UnreducedRational {
+        other + self
+    }
+}
+
+impl<'a> Add<UnreducedRational> for &'a Rational {
+    type Output = UnreducedRational;
+
+    /// Adds a [`Rational`] and an [`UnreducedRational`], taking the [`Rational`] by reference and
+    /// the [`UnreducedRational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = &x + y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: UnreducedRational) -> UnreducedRational {
+        other + self
+    }
+}
+
+impl<'a, 'b> Add<&'a UnreducedRational> for &'b Rational {
+    type Output = UnreducedRational;
+
+    /// Adds a [`Rational`] and an [`UnreducedRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = &x + &y;
+    /// assert_eq!(z.to_string(), "-7/12");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a UnreducedRational) -> UnreducedRational {
+        other + self
+    }
+}
+
+impl Sum for UnreducedRational {
+    /// Adds up all the [`UnreducedRational`]s in an iterator.
+    ///
+    /// This is the main use case for [`UnreducedRational`]: summing many [`Rational`]s this way
+    /// only computes a GCD each time the denominator doubles in size, instead of once per term.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let harmonic: UnreducedRational = (1..=10)
+    ///     .map(|i| UnreducedRational::from(Rational::from_unsigneds(1u32, i)))
+    ///     .sum();
+    /// assert_eq!(harmonic.to_string(), "7381/2520");
+    /// ```
+    fn sum<I>(xs: I) -> UnreducedRational
+    where
+        I: Iterator<Item = UnreducedRational>,
+    {
+        let mut s = UnreducedRational::ZERO;
+        for x in xs {
+            s += x;
+        }
+        s
+    }
+}
+
+impl<'a> Sum<&'a UnreducedRational> for UnreducedRational {
+    /// Adds up all the [`UnreducedRational`]s in an iterator of [`UnreducedRational`] references.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let xs = (1..=10)
+    ///     .map(|i| UnreducedRational::from(Rational::from_unsigneds(1u32, i)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(xs.iter().sum::<UnreducedRational>().to_string(), "7381/2520");
+    /// ```
+    fn sum<I>(xs: I) -> UnreducedRational
+    where
+        I: Iterator<Item = &'a UnreducedRational>,
+    {
+        let mut s = UnreducedRational::ZERO;
+        for x in xs {
+            s += x;
+        }
+        s
+    }
+}
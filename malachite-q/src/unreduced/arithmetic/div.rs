@@ -0,0 +1,615 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use malachite_nz::natural::Natural;
+use std::ops::{Div, DivAssign};
+
+impl UnreducedRational {
+    // Divides `self` by $(-1)^{s+1}n/d$, where $s$ is `sign`, $n$ is `numerator`, and $d$ is
+    // `denominator`.
+    pub(crate) fn div_assign_parts(
+        &mut self,
+        sign: bool,
+        numerator: &Natural,
+        denominator: &Natural,
+    ) {
+        if *numerator == 0u32 {
+            panic!("division by zero");
+        }
+        self.mul_assign_parts(sign, denominator, numerator);
+    }
+}
+
+impl Div<UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by another [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// The result's numerator and denominator are computed by cross-multiplication and are only
+    /// reduced once the denominator has doubled in size since the last reduction.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// let z = x / y;
+    /// assert_eq!(*z.numerator_ref(), 6);
+    /// assert_eq!(*z.denominator_ref(), 12);
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div(mut self, other: UnreducedRational) -> UnreducedRational {
+        self /= other;
+        self
+    }
+}
+
+impl<'a> Div<&'a UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by another [`UnreducedRational`], taking the first by value
+    /// and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// let z = x / &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div(mut self, other: &'a UnreducedRational) -> UnreducedRational {
+        self /= other;
+        self
+    }
+}
+
+impl<'a> Div<UnreducedRational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by another [`UnreducedRational`], taking the first by
+    /// reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// let z = &x / y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div(self, other: UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x /= other;
+        x
+    }
+}
+
+impl<'a, 'b> Div<&'a UnreducedRational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by another [`UnreducedRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// let z = &x / &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x /= other;
+        x
+    }
+}
+
+impl DivAssign<UnreducedRational> for UnreducedRational {
+    /// Divides an [`UnreducedRational`] by an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// x /= y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: UnreducedRational) {
+        self.div_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> DivAssign<&'a UnreducedRational> for UnreducedRational {
+    /// Divides an [`UnreducedRational`] by an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(4, 3));
+    /// x /= &y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a UnreducedRational) {
+        self.div_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Div<Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by a [`Rational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// let z = x / y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div(mut self, other: Rational) -> UnreducedRational {
+        self /= other;
+        self
+    }
+}
+
+impl<'a> Div<&'a Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by a [`Rational`], taking the [`UnreducedRational`] by
+    /// value and the [`Rational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// let z = x / &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div(mut self, other: &'a Rational) -> UnreducedRational {
+        self /= other;
+        self
+    }
+}
+
+impl<'a> Div<Rational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by a [`Rational`], taking the [`UnreducedRational`] by
+    /// reference and the [`Rational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// let z = &x / y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div(self, other: Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x /= other;
+        x
+    }
+}
+
+impl<'a, 'b> Div<&'a Rational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Divides an [`UnreducedRational`] by a [`Rational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// let z = &x / &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x /= other;
+        x
+    }
+}
+
+impl DivAssign<Rational> for UnreducedRational {
+    /// Divides an [`UnreducedRational`] by a [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// x /= y;
+    /// assert_eq!(x.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Rational) {
+        self.div_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> DivAssign<&'a Rational> for UnreducedRational {
+    /// Divides an [`UnreducedRational`] by a [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-4, 3);
+    /// x /= &y;
+    /// assert_eq!(x.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Rational) {
+        self.div_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Div<UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Divides a [`Rational`] by an [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-4, 3);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = x / y;
+    /// assert_eq!(z.to_string(), "-2");
+    /// ```
+    #[inline]
+    fn div(self, other: UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) / other
+    }
+}
+
+impl<'a> Div<&'a UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Divides a [`Rational`] by an [`UnreducedRational`], taking the [`Rational`] by value and the
+    /// [`UnreducedRational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-4, 3);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = x / &y;
+    /// assert_eq!(z.to_string(), "-2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) / other
+    }
+}
+
+impl<'a> Div<UnreducedRational> for &'a Rational {
+    type Output = UnreducedRational;
+
+    /// Divides a [`Rational`] by an [`UnreducedRational`], taking the [`Rational`] by reference and
+    /// the [`UnreducedRational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-4, 3);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = &x / y;
+    /// assert_eq!(z.to_string(), "-2");
+    /// ```
+    #[inline]
+    fn div(self, other: UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) / other
+    }
+}
+
+impl<'a, 'b> Div<&'a UnreducedRational> for &'b Rational {
+    type Output = UnreducedRational;
+
+    /// Divides a [`Rational`] by an [`UnreducedRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-4, 3);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = &x / &y;
+    /// assert_eq!(z.to_string(), "-2");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) / other
+    }
+}
@@ -0,0 +1,14 @@
+/// Addition of [`UnreducedRational`](super::UnreducedRational)s, and of an
+/// [`UnreducedRational`](super::UnreducedRational) and a [`Rational`](crate::Rational).
+pub mod add;
+/// Division of [`UnreducedRational`](super::UnreducedRational)s, and of an
+/// [`UnreducedRational`](super::UnreducedRational) by a [`Rational`](crate::Rational).
+pub mod div;
+/// Multiplication of [`UnreducedRational`](super::UnreducedRational)s, and of an
+/// [`UnreducedRational`](super::UnreducedRational) by a [`Rational`](crate::Rational).
+pub mod mul;
+/// Negation of an [`UnreducedRational`](super::UnreducedRational).
+pub mod neg;
+/// Subtraction of [`UnreducedRational`](super::UnreducedRational)s, and of a
+/// [`Rational`](crate::Rational) from an [`UnreducedRational`](super::UnreducedRational).
+pub mod sub;
@@ -0,0 +1,644 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use malachite_base::num::basic::traits::One;
+use malachite_nz::natural::Natural;
+use std::iter::Product;
+use std::ops::{Mul, MulAssign};
+
+impl UnreducedRational {
+    // Multiplies `self` by $(-1)^{s+1}n/d$, where $s$ is `sign`, $n$ is `numerator`, and $d$ is
+    // `denominator`.
+    pub(crate) fn mul_assign_parts(
+        &mut self,
+        sign: bool,
+        numerator: &Natural,
+        denominator: &Natural,
+    ) {
+        self.sign = self.sign == sign;
+        self.numerator *= numerator;
+        self.denominator *= denominator;
+        self.maybe_reduce();
+    }
+}
+
+impl Mul<UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies two [`UnreducedRational`]s, taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// The result's numerator and denominator are computed by cross-multiplication and are only
+    /// reduced once the denominator has doubled in size since the last reduction.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// let z = x * y;
+    /// assert_eq!(*z.numerator_ref(), 6);
+    /// assert_eq!(*z.denominator_ref(), 12);
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: UnreducedRational) -> UnreducedRational {
+        self *= other;
+        self
+    }
+}
+
+impl<'a> Mul<&'a UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies two [`UnreducedRational`]s, taking the first by value and the second by
+    /// reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// let z = x * &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: &'a UnreducedRational) -> UnreducedRational {
+        self *= other;
+        self
+    }
+}
+
+impl<'a> Mul<UnreducedRational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies two [`UnreducedRational`]s, taking the first by reference and the second by
+    /// value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// let z = &x * y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x *= other;
+        x
+    }
+}
+
+impl<'a, 'b> Mul<&'a UnreducedRational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies two [`UnreducedRational`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// let z = &x * &y;
+    /// assert_eq!(z.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x *= other;
+        x
+    }
+}
+
+impl MulAssign<UnreducedRational> for UnreducedRational {
+    /// Multiplies an [`UnreducedRational`] by an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// x *= y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: UnreducedRational) {
+        self.mul_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> MulAssign<&'a UnreducedRational> for UnreducedRational {
+    /// Multiplies an [`UnreducedRational`] by an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(3, 4));
+    /// x *= &y;
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a UnreducedRational) {
+        self.mul_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Mul<Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x * y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: Rational) -> UnreducedRational {
+        self *= other;
+        self
+    }
+}
+
+impl<'a> Mul<&'a Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`], taking the [`UnreducedRational`] by
+    /// value and the [`Rational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x * &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: &'a Rational) -> UnreducedRational {
+        self *= other;
+        self
+    }
+}
+
+impl<'a> Mul<Rational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`], taking the [`UnreducedRational`] by
+    /// reference and the [`Rational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x * y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x *= other;
+        x
+    }
+}
+
+impl<'a, 'b> Mul<&'a Rational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x * &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x *= other;
+        x
+    }
+}
+
+impl MulAssign<Rational> for UnreducedRational {
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`] in place, taking the [`Rational`] on
+    /// the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x *= y;
+    /// assert_eq!(x.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Rational) {
+        self.mul_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> MulAssign<&'a Rational> for UnreducedRational {
+    /// Multiplies an [`UnreducedRational`] by a [`Rational`] in place, taking the [`Rational`] on
+    /// the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x *= &y;
+    /// assert_eq!(x.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Rational) {
+        self.mul_assign_parts(other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Mul<UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Multiplies a [`Rational`] by an [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = x * y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: UnreducedRational) -> UnreducedRational {
+        other * self
+    }
+}
+
+impl<'a> Mul<&'a UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Multiplies a [`Rational`] by an [`UnreducedRational`], taking the [`Rational`] by value and
+    /// the [`UnreducedRational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = x * &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a UnreducedRational) -> UnreducedRational {
+        other * self
+    }
+}
+
+impl<'a> Mul<UnreducedRational> for &'a Rational {
+    type Output = UnreducedRational;
+
+    /// Multiplies a [`Rational`] by an [`UnreducedRational`], taking the [`Rational`] by reference
+    /// and the [`UnreducedRational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = &x * y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: UnreducedRational) -> UnreducedRational {
+        other * self
+    }
+}
+
+impl<'a, 'b> Mul<&'a UnreducedRational> for &'b Rational {
+    type Output = UnreducedRational;
+
+    /// Multiplies a [`Rational`] by an [`UnreducedRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let z = &x * &y;
+    /// assert_eq!(z.to_string(), "-1/2");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a UnreducedRational) -> UnreducedRational {
+        other * self
+    }
+}
+
+impl Product for UnreducedRational {
+    /// Multiplies together all the [`UnreducedRational`]s in an iterator.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let p: UnreducedRational = (1u32..=10)
+    ///     .map(|i| UnreducedRational::from(Rational::from_unsigneds(i, i + 1)))
+    ///     .product();
+    /// assert_eq!(p.to_string(), "1/11");
+    /// ```
+    fn product<I>(xs: I) -> UnreducedRational
+    where
+        I: Iterator<Item = UnreducedRational>,
+    {
+        let mut p = UnreducedRational::ONE;
+        for x in xs {
+            p *= x;
+        }
+        p
+    }
+}
+
+impl<'a> Product<&'a UnreducedRational> for UnreducedRational {
+    /// Multiplies together all the [`UnreducedRational`]s in an iterator of [`UnreducedRational`]
+    /// references.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let xs = (1u32..=10)
+    ///     .map(|i| UnreducedRational::from(Rational::from_unsigneds(i, i + 1)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(xs.iter().product::<UnreducedRational>().to_string(), "1/11");
+    /// ```
+    fn product<I>(xs: I) -> UnreducedRational
+    where
+        I: Iterator<Item = &'a UnreducedRational>,
+    {
+        let mut p = UnreducedRational::ONE;
+        for x in xs {
+            p *= x;
+        }
+        p
+    }
+}
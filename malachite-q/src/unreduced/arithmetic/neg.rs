@@ -0,0 +1,89 @@
+use crate::unreduced::UnreducedRational;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::logic::traits::NotAssign;
+use std::ops::Neg;
+
+impl Neg for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Negates an [`UnreducedRational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(22, 7));
+    /// assert_eq!((-x).to_string(), "-22/7");
+    /// ```
+    fn neg(mut self) -> UnreducedRational {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Negates an [`UnreducedRational`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerator and denominator of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(22, 7));
+    /// assert_eq!((-&x).to_string(), "-22/7");
+    /// ```
+    fn neg(self) -> UnreducedRational {
+        let mut x = self.clone();
+        x.neg_assign();
+        x
+    }
+}
+
+impl NegAssign for UnreducedRational {
+    /// Negates an [`UnreducedRational`] in place.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(-22, 7));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "22/7");
+    /// ```
+    fn neg_assign(&mut self) {
+        if self.numerator != 0 {
+            self.sign.not_assign();
+        }
+    }
+}
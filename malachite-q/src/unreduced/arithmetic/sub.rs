@@ -0,0 +1,551 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use std::ops::{Sub, SubAssign};
+
+impl Sub<UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] by another [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// The result's numerator and denominator are computed by cross-multiplication and are only
+    /// reduced once the denominator has doubled in size since the last reduction.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = x - y;
+    /// assert_eq!(*z.numerator_ref(), 3);
+    /// assert_eq!(*z.denominator_ref(), 18);
+    /// assert_eq!(z.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: UnreducedRational) -> UnreducedRational {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<&'a UnreducedRational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] by another [`UnreducedRational`], taking the first by
+    /// value and the second by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = x - &y;
+    /// assert_eq!(z.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &'a UnreducedRational) -> UnreducedRational {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<UnreducedRational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] by another [`UnreducedRational`], taking the first by
+    /// reference and the second by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = &x - y;
+    /// assert_eq!(z.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x -= other;
+        x
+    }
+}
+
+impl<'a, 'b> Sub<&'a UnreducedRational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] by another [`UnreducedRational`], taking both by
+    /// reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let z = &x - &y;
+    /// assert_eq!(z.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a UnreducedRational) -> UnreducedRational {
+        let mut x = self.clone();
+        x -= other;
+        x
+    }
+}
+
+impl SubAssign<UnreducedRational> for UnreducedRational {
+    /// Subtracts an [`UnreducedRational`] from an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// x -= y;
+    /// assert_eq!(x.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: UnreducedRational) {
+        self.add_assign_parts(!other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> SubAssign<&'a UnreducedRational> for UnreducedRational {
+    /// Subtracts an [`UnreducedRational`] from an [`UnreducedRational`] in place, taking the
+    /// [`UnreducedRational`] on the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// x -= &y;
+    /// assert_eq!(x.to_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a UnreducedRational) {
+        self.add_assign_parts(!other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Sub<Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x - y;
+    /// assert_eq!(z.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: Rational) -> UnreducedRational {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<&'a Rational> for UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`], taking the [`UnreducedRational`] by
+    /// value and the [`Rational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = x - &y;
+    /// assert_eq!(z.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &'a Rational) -> UnreducedRational {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<Rational> for &'a UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`], taking the [`UnreducedRational`] by
+    /// reference and the [`Rational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x - y;
+    /// assert_eq!(z.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x -= other;
+        x
+    }
+}
+
+impl<'a, 'b> Sub<&'a Rational> for &'b UnreducedRational {
+    type Output = UnreducedRational;
+
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// let z = &x - &y;
+    /// assert_eq!(z.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Rational) -> UnreducedRational {
+        let mut x = self.clone();
+        x -= other;
+        x
+    }
+}
+
+impl SubAssign<Rational> for UnreducedRational {
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`] in place, taking the [`Rational`] on
+    /// the right-hand side by value.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x -= y;
+    /// assert_eq!(x.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Rational) {
+        self.add_assign_parts(!other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl<'a> SubAssign<&'a Rational> for UnreducedRational {
+    /// Subtracts a [`Rational`] from an [`UnreducedRational`] in place, taking the [`Rational`] on
+    /// the right-hand side by reference.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let y = Rational::from_signeds(-3, 4);
+    /// x -= &y;
+    /// assert_eq!(x.to_string(), "11/12");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Rational) {
+        self.add_assign_parts(!other.sign, &other.numerator, &other.denominator);
+    }
+}
+
+impl Sub<UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] from a [`Rational`], taking both by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = x - y;
+    /// assert_eq!(z.to_string(), "-11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) - other
+    }
+}
+
+impl<'a> Sub<&'a UnreducedRational> for Rational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] from a [`Rational`], taking the [`Rational`] by value and
+    /// the [`UnreducedRational`] by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = x - &y;
+    /// assert_eq!(z.to_string(), "-11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) - other
+    }
+}
+
+impl<'a> Sub<UnreducedRational> for &'a Rational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] from a [`Rational`], taking the [`Rational`] by reference
+    /// and the [`UnreducedRational`] by value.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = &x - y;
+    /// assert_eq!(z.to_string(), "-11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) - other
+    }
+}
+
+impl<'a, 'b> Sub<&'a UnreducedRational> for &'b Rational {
+    type Output = UnreducedRational;
+
+    /// Subtracts an [`UnreducedRational`] from a [`Rational`], taking both by reference.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Rational::from_signeds(-3, 4);
+    /// let y = UnreducedRational::from(Rational::from_signeds(1, 6));
+    /// let z = &x - &y;
+    /// assert_eq!(z.to_string(), "-11/12");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a UnreducedRational) -> UnreducedRational {
+        UnreducedRational::from(self) - other
+    }
+}
@@ -0,0 +1,83 @@
+use crate::unreduced::UnreducedRational;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Compares $(-1)^{s_1+1}n_1/d_1$ with $(-1)^{s_2+1}n_2/d_2$. Zero must have a `true` sign, as it
+// does in both `Rational` and `UnreducedRational`.
+pub(crate) fn cmp_parts(
+    x_sign: bool,
+    x_numerator: &Natural,
+    x_denominator: &Natural,
+    y_sign: bool,
+    y_numerator: &Natural,
+    y_denominator: &Natural,
+) -> Ordering {
+    if x_sign != y_sign {
+        return x_sign.cmp(&y_sign);
+    }
+    let abs_cmp = if x_denominator == y_denominator {
+        x_numerator.cmp(y_numerator)
+    } else {
+        (x_numerator * y_denominator).cmp(&(y_numerator * x_denominator))
+    };
+    if x_sign {
+        abs_cmp
+    } else {
+        abs_cmp.reverse()
+    }
+}
+
+impl PartialOrd for UnreducedRational {
+    /// Compares two [`UnreducedRational`]s by value.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &UnreducedRational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UnreducedRational {
+    /// Compares two [`UnreducedRational`]s by value. Common factors of the numerators and
+    /// denominators do not matter: $2/4$ and $1/2$ are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from(Rational::from_signeds(-1, 2));
+    /// assert!(x > y);
+    ///
+    /// let z = UnreducedRational::from_sign_and_naturals(
+    ///     true,
+    ///     Natural::from(4u32),
+    ///     Natural::from(6u32),
+    /// );
+    /// assert_eq!(x.cmp(&z), Ordering::Equal);
+    /// ```
+    fn cmp(&self, other: &UnreducedRational) -> Ordering {
+        if std::ptr::eq(self, other) {
+            return Ordering::Equal;
+        }
+        cmp_parts(
+            self.sign,
+            &self.numerator,
+            &self.denominator,
+            other.sign,
+            &other.numerator,
+            &other.denominator,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::unreduced::comparison::cmp::cmp_parts;
+use crate::unreduced::UnreducedRational;
+use std::cmp::Ordering;
+
+impl PartialEq for UnreducedRational {
+    /// Determines whether two [`UnreducedRational`]s are equal by value. Common factors of the
+    /// numerators and denominators do not matter: $2/4$ and $1/2$ are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// let y = UnreducedRational::from_sign_and_naturals(
+    ///     true,
+    ///     Natural::from(4u32),
+    ///     Natural::from(6u32),
+    /// );
+    /// assert_eq!(x, y);
+    /// assert_ne!(x, -y);
+    /// ```
+    fn eq(&self, other: &UnreducedRational) -> bool {
+        cmp_parts(
+            self.sign,
+            &self.numerator,
+            &self.denominator,
+            other.sign,
+            &other.numerator,
+            &other.denominator,
+        ) == Ordering::Equal
+    }
+}
+
+impl Eq for UnreducedRational {}
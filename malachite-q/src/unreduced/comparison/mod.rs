@@ -0,0 +1,10 @@
+/// Comparison of [`UnreducedRational`](super::UnreducedRational)s by value.
+pub mod cmp;
+/// Equality of [`UnreducedRational`](super::UnreducedRational)s by value.
+pub mod eq;
+/// Implementations of [`PartialOrd`] for comparing an
+/// [`UnreducedRational`](super::UnreducedRational) with a [`Rational`](crate::Rational).
+pub mod partial_cmp_rational;
+/// Implementations of [`PartialEq`] for comparing an
+/// [`UnreducedRational`](super::UnreducedRational) with a [`Rational`](crate::Rational).
+pub mod partial_eq_rational;
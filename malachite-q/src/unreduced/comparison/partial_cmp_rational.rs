@@ -0,0 +1,64 @@
+use crate::unreduced::comparison::cmp::cmp_parts;
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use std::cmp::Ordering;
+
+impl PartialOrd<Rational> for UnreducedRational {
+    /// Compares an [`UnreducedRational`] to a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert!(x > Rational::from_signeds(1, 3));
+    /// assert!(x < Rational::from_signeds(2, 3));
+    /// ```
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(cmp_parts(
+            self.sign,
+            &self.numerator,
+            &self.denominator,
+            other.sign,
+            &other.numerator,
+            &other.denominator,
+        ))
+    }
+}
+
+impl PartialOrd<UnreducedRational> for Rational {
+    /// Compares a [`Rational`] to an [`UnreducedRational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert!(Rational::from_signeds(1, 3) < x);
+    /// assert!(Rational::from_signeds(2, 3) > x);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &UnreducedRational) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
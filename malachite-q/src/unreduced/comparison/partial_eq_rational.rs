@@ -0,0 +1,64 @@
+use crate::unreduced::comparison::cmp::cmp_parts;
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use std::cmp::Ordering;
+
+impl PartialEq<Rational> for UnreducedRational {
+    /// Determines whether an [`UnreducedRational`] is equal to a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(x, Rational::from_signeds(1, 2));
+    /// assert_ne!(x, Rational::from_signeds(1, 3));
+    /// ```
+    fn eq(&self, other: &Rational) -> bool {
+        cmp_parts(
+            self.sign,
+            &self.numerator,
+            &self.denominator,
+            other.sign,
+            &other.numerator,
+            &other.denominator,
+        ) == Ordering::Equal
+    }
+}
+
+impl PartialEq<UnreducedRational> for Rational {
+    /// Determines whether a [`Rational`] is equal to an [`UnreducedRational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of significant bits
+    /// in the numerators and denominators of `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(Rational::from_signeds(1, 2), x);
+    /// assert_ne!(Rational::from_signeds(1, 3), x);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &UnreducedRational) -> bool {
+        other == self
+    }
+}
@@ -0,0 +1,60 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+use malachite_base::num::logic::traits::SignificantBits;
+
+impl From<Rational> for UnreducedRational {
+    /// Converts a [`Rational`] to an [`UnreducedRational`], taking the [`Rational`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     UnreducedRational::from(Rational::from_signeds(-22, 7)).to_string(),
+    ///     "-22/7"
+    /// );
+    /// ```
+    fn from(value: Rational) -> UnreducedRational {
+        let reduced_denominator_bits = value.denominator.significant_bits();
+        UnreducedRational {
+            sign: value.sign,
+            numerator: value.numerator,
+            denominator: value.denominator,
+            reduced_denominator_bits,
+        }
+    }
+}
+
+impl<'a> From<&'a Rational> for UnreducedRational {
+    /// Converts a [`Rational`] to an [`UnreducedRational`], taking the [`Rational`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     UnreducedRational::from(&Rational::from_signeds(-22, 7)).to_string(),
+    ///     "-22/7"
+    /// );
+    /// ```
+    fn from(value: &'a Rational) -> UnreducedRational {
+        UnreducedRational {
+            sign: value.sign,
+            numerator: value.numerator.clone(),
+            denominator: value.denominator.clone(),
+            reduced_denominator_bits: value.denominator.significant_bits(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// Implementations of the [`From`] trait for converting a [`Rational`](crate::Rational) to an
+/// [`UnreducedRational`](super::UnreducedRational).
+pub mod from_rational;
+/// Implementations of the [`From`] trait for converting an
+/// [`UnreducedRational`](super::UnreducedRational) to a [`Rational`](crate::Rational).
+pub mod rational_from_unreduced;
+/// Implementations of [`Display`](std::fmt::Display) and [`Debug`] for
+/// [`UnreducedRational`](super::UnreducedRational)s.
+pub mod to_string;
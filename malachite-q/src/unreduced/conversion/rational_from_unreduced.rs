@@ -0,0 +1,60 @@
+use crate::unreduced::UnreducedRational;
+use crate::Rational;
+
+impl From<UnreducedRational> for Rational {
+    /// Converts an [`UnreducedRational`] to a [`Rational`], taking the [`UnreducedRational`] by
+    /// value. The numerator and denominator are reduced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(value.numerator_ref().significant_bits(), value.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let y = Rational::from(x);
+    /// assert_eq!(*y.numerator_ref(), 1);
+    /// assert_eq!(*y.denominator_ref(), 2);
+    /// ```
+    #[inline]
+    fn from(value: UnreducedRational) -> Rational {
+        value.into_rational()
+    }
+}
+
+impl<'a> From<&'a UnreducedRational> for Rational {
+    /// Converts an [`UnreducedRational`] to a [`Rational`], taking the [`UnreducedRational`] by
+    /// reference. The numerator and denominator are reduced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(value.numerator_ref().significant_bits(), value.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// let y = Rational::from(&x);
+    /// assert_eq!(*y.numerator_ref(), 1);
+    /// assert_eq!(*y.denominator_ref(), 2);
+    /// ```
+    #[inline]
+    fn from(value: &'a UnreducedRational) -> Rational {
+        value.to_rational()
+    }
+}
@@ -0,0 +1,60 @@
+use crate::unreduced::UnreducedRational;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+impl Display for UnreducedRational {
+    /// Converts an [`UnreducedRational`] to a [`String`]. The value is written in lowest terms,
+    /// the same way as the equal [`Rational`](crate::Rational) would be.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.numerator_ref().significant_bits(), self.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(UnreducedRational::ZERO.to_string(), "0");
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_string(), "1/2");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.to_rational(), f)
+    }
+}
+
+impl Debug for UnreducedRational {
+    /// Converts an [`UnreducedRational`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.numerator_ref().significant_bits(), self.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     - UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_debug_string(), "-1/6");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
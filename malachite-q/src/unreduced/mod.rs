@@ -0,0 +1,270 @@
+use crate::Rational;
+use malachite_base::named::Named;
+use malachite_base::num::arithmetic::traits::{DivExactAssign, Gcd};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::Natural;
+
+/// A rational number whose numerator and denominator are not kept in lowest terms.
+///
+/// Every [`Rational`] operation divides out the GCD of its result's numerator and denominator.
+/// In long computations, such as summing the terms of a series, those GCDs can dominate the
+/// running time. An [`UnreducedRational`] skips them: sums, differences, products, and quotients
+/// are computed by cross-multiplication alone, and the numerator and denominator are only
+/// cancelled when the denominator has doubled in size since the last cancellation, or when
+/// [`reduce`](UnreducedRational::reduce) is called explicitly. Doubling keeps the total cost of
+/// the cancellations proportional to the cost of the last one.
+///
+/// An [`UnreducedRational`] behaves like the [`Rational`] it represents: equality and comparison
+/// depend only on its value, and it can be combined with a [`Rational`] on either side of `+`,
+/// `-`, `*`, and `/`, with an [`UnreducedRational`] as the result. Use [`into_rational`](UnreducedRational::into_rational) to get the result.
+#[derive(Clone)]
+pub struct UnreducedRational {
+    // Same invariants as in `Rational`, except that `numerator` and `denominator` may have common
+    // factors. Zero is always represented as 0/1 with a `true` sign.
+    pub(crate) sign: bool,
+    pub(crate) numerator: Natural,
+    pub(crate) denominator: Natural,
+    // The number of significant bits of the denominator right after the last reduction.
+    pub(crate) reduced_denominator_bits: u64,
+}
+
+// Denominators with at most this many bits are never reduced automatically; for numbers this small
+// the GCD is cheap compared to the bookkeeping of deferring it, and its benefit is small as well.
+const MIN_REDUCE_BITS: u64 = 256;
+
+impl UnreducedRational {
+    /// Converts a sign and two [`Natural`](malachite_nz::natural::Natural)s to an
+    /// [`UnreducedRational`], taking the [`Natural`](malachite_nz::natural::Natural)s by value.
+    ///
+    /// The [`Natural`](malachite_nz::natural::Natural)s become the numerator and denominator, and
+    /// the sign indicates whether the result should be non-negative. Common factors are not
+    /// removed.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::unreduced::UnreducedRational;
+    ///
+    /// let x = UnreducedRational::from_sign_and_naturals(
+    ///     false,
+    ///     Natural::from(4u32),
+    ///     Natural::from(6u32),
+    /// );
+    /// assert_eq!(*x.numerator_ref(), 4);
+    /// assert_eq!(*x.denominator_ref(), 6);
+    /// assert_eq!(x.to_string(), "-2/3");
+    /// ```
+    pub fn from_sign_and_naturals(
+        sign: bool,
+        numerator: Natural,
+        denominator: Natural,
+    ) -> UnreducedRational {
+        assert_ne!(denominator, 0u32);
+        let reduced_denominator_bits = denominator.significant_bits();
+        let mut x = UnreducedRational {
+            sign,
+            numerator,
+            denominator,
+            reduced_denominator_bits,
+        };
+        x.normalize_zero();
+        x
+    }
+
+    /// Returns a reference to the numerator of an [`UnreducedRational`]. The numerator may have
+    /// factors in common with the denominator.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(*x.numerator_ref(), 9);
+    /// ```
+    pub const fn numerator_ref(&self) -> &Natural {
+        &self.numerator
+    }
+
+    /// Returns a reference to the denominator of an [`UnreducedRational`]. The denominator may
+    /// have factors in common with the numerator.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// assert_eq!(*x.denominator_ref(), 18);
+    /// ```
+    pub const fn denominator_ref(&self) -> &Natural {
+        &self.denominator
+    }
+
+    /// Divides the numerator and denominator of an [`UnreducedRational`] by their GCD, in place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.numerator_ref().significant_bits(), self.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     + UnreducedRational::from(Rational::from_signeds(1, 3));
+    /// x.reduce();
+    /// assert_eq!(*x.numerator_ref(), 1);
+    /// assert_eq!(*x.denominator_ref(), 2);
+    /// ```
+    pub fn reduce(&mut self) {
+        let gcd = (&self.numerator).gcd(&self.denominator);
+        if gcd != 1u32 {
+            self.numerator.div_exact_assign(&gcd);
+            self.denominator.div_exact_assign(gcd);
+        }
+        self.reduced_denominator_bits = self.denominator.significant_bits();
+    }
+
+    /// Converts an [`UnreducedRational`] to a [`Rational`], taking the [`UnreducedRational`] by
+    /// value. This is the same as `Rational::from(self)`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.numerator_ref().significant_bits(), self.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     - UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// assert_eq!(x.into_rational().to_string(), "-1/2");
+    /// ```
+    pub fn into_rational(mut self) -> Rational {
+        self.reduce();
+        Rational {
+            sign: self.sign,
+            numerator: self.numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    /// Converts an [`UnreducedRational`] to a [`Rational`], taking the [`UnreducedRational`] by
+    /// reference. This is the same as `Rational::from(&self)`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.numerator_ref().significant_bits(), self.denominator_ref().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::unreduced::UnreducedRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = UnreducedRational::from(Rational::from_signeds(1, 6))
+    ///     - UnreducedRational::from(Rational::from_signeds(2, 3));
+    /// assert_eq!(x.to_rational().to_string(), "-1/2");
+    /// ```
+    pub fn to_rational(&self) -> Rational {
+        Rational::from_sign_and_naturals_ref(self.sign, &self.numerator, &self.denominator)
+    }
+
+    // Makes zero's representation canonical; the other operations rely on 0 being 0/1 and
+    // non-negative.
+    pub(crate) fn normalize_zero(&mut self) {
+        if self.numerator == 0u32 {
+            self.sign = true;
+            if self.denominator != 1u32 {
+                self.denominator = Natural::ONE;
+            }
+            self.reduced_denominator_bits = 1;
+        }
+    }
+
+    // Called after every operation that may have grown the denominator. Reduces once the
+    // denominator has more than doubled in size since the last reduction.
+    pub(crate) fn maybe_reduce(&mut self) {
+        self.normalize_zero();
+        let bits = self.denominator.significant_bits();
+        if bits > MIN_REDUCE_BITS && bits > self.reduced_denominator_bits << 1 {
+            self.reduce();
+        }
+    }
+
+    // Returns true iff all internal invariants hold. These are weaker than `Rational`'s: the
+    // numerator and denominator don't need to be coprime.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.denominator != 0
+            && (self.sign || self.numerator != 0)
+            && (self.numerator != 0 || self.denominator == 1)
+    }
+}
+
+/// The constant 0.
+impl Zero for UnreducedRational {
+    const ZERO: UnreducedRational = UnreducedRational {
+        sign: true,
+        numerator: Natural::ZERO,
+        denominator: Natural::ONE,
+        reduced_denominator_bits: 1,
+    };
+}
+
+/// The constant 1.
+impl One for UnreducedRational {
+    const ONE: UnreducedRational = UnreducedRational {
+        sign: true,
+        numerator: Natural::ONE,
+        denominator: Natural::ONE,
+        reduced_denominator_bits: 1,
+    };
+}
+
+impl Default for UnreducedRational {
+    /// The default value of an [`UnreducedRational`], 0.
+    fn default() -> UnreducedRational {
+        UnreducedRational::ZERO
+    }
+}
+
+// Implements `Named` for `UnreducedRational`.
+impl_named!(UnreducedRational);
+
+/// Arithmetic on [`UnreducedRational`]s, and between [`UnreducedRational`]s and
+/// [`Rational`](crate::Rational)s.
+pub mod arithmetic;
+/// Comparing [`UnreducedRational`]s with each other and with [`Rational`](crate::Rational)s.
+pub mod comparison;
+/// Converting [`UnreducedRational`]s to and from [`Rational`](crate::Rational)s and strings.
+pub mod conversion;
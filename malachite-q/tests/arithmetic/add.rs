@@ -109,6 +109,10 @@ fn add_properties() {
         let sum_val_ref = x.clone() + &y;
         let sum_ref_val = &x + y.clone();
         let sum = &x + &y;
+        let mut out = y.clone();
+        x.add_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, sum);
         assert!(sum_val_val.is_valid());
         assert!(sum_val_ref.is_valid());
         assert!(sum_ref_val.is_valid());
@@ -0,0 +1,192 @@
+use malachite_base::num::arithmetic::traits::{Abs, Pow, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::arithmetic::binary_splitting::{binary_splitting, SeriesTerm};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn binary_splitting_naive<F: Fn(u64) -> SeriesTerm>(start: u64, end: u64, term: F) -> Rational {
+    let mut sum = Rational::ZERO;
+    let mut product = Rational::ONE;
+    for n in start..end {
+        let SeriesTerm { a, b, p, q } = term(n);
+        product *= Rational::from_integers(p, q);
+        sum += Rational::from_integers(a, b) * &product;
+    }
+    sum
+}
+
+fn e_term(n: u64) -> SeriesTerm {
+    SeriesTerm::new(
+        Integer::ONE,
+        Integer::ONE,
+        Integer::ONE,
+        Integer::from(n.max(1)),
+    )
+}
+
+// The terms of arctan(1/k) = 1/k - 1/(3k^3) + 1/(5k^5) - ...
+fn arctan_term(k: u32) -> impl Fn(u64) -> SeriesTerm {
+    move |n| {
+        let k = Integer::from(k);
+        if n == 0 {
+            SeriesTerm::new(Integer::ONE, Integer::ONE, Integer::ONE, k)
+        } else {
+            SeriesTerm::new(
+                Integer::ONE,
+                Integer::from(2 * n + 1),
+                Integer::from(-1),
+                &k * &k,
+            )
+        }
+    }
+}
+
+// Terms that exercise signs and non-trivial values of all four sequences.
+fn mixed_term(n: u64) -> SeriesTerm {
+    let n = i64::try_from(n).unwrap();
+    SeriesTerm::new(
+        Integer::from(n * n - 3),
+        Integer::from(-2 * n - 1),
+        Integer::from(n * n + n - 5),
+        Integer::from(3 * n + 2),
+    )
+}
+
+#[test]
+fn test_binary_splitting() {
+    let test = |start, end, out| {
+        let s = binary_splitting(start, end, e_term);
+        assert_eq!(s.start(), start);
+        assert_eq!(s.end(), end);
+        assert_eq!(s.sum().to_string(), out);
+    };
+    test(0, 0, "0");
+    test(5, 5, "0");
+    test(0, 1, "1");
+    test(0, 2, "2");
+    test(0, 3, "5/2");
+    test(0, 10, "98641/36288");
+    test(2, 4, "2/3");
+
+    let s = binary_splitting(0, 4, |_| {
+        SeriesTerm::new(Integer::ONE, Integer::ONE, Integer::ONE, Integer::from(2))
+    });
+    assert_eq!(s.sum().to_string(), "15/16");
+    assert_eq!(s.product().to_string(), "1/16");
+}
+
+#[test]
+#[should_panic]
+fn binary_splitting_fail_1() {
+    binary_splitting(3, 2, e_term);
+}
+
+#[test]
+#[should_panic]
+fn binary_splitting_fail_2() {
+    binary_splitting(0, 3, |_| SeriesTerm {
+        a: Integer::ONE,
+        b: Integer::ZERO,
+        p: Integer::ONE,
+        q: Integer::ONE,
+    });
+}
+
+#[test]
+fn test_append() {
+    let mut e = binary_splitting(0, 5, e_term);
+    e.append(binary_splitting(5, 10, e_term));
+    assert_eq!(e, binary_splitting(0, 10, e_term));
+    e.append(binary_splitting(10, 10, e_term));
+    assert_eq!(e, binary_splitting(0, 10, e_term));
+
+    let mut e = binary_splitting(0, 0, e_term);
+    e.append(binary_splitting(0, 10, e_term));
+    assert_eq!(e.sum(), binary_splitting(0, 10, e_term).sum());
+}
+
+#[test]
+#[should_panic]
+fn append_fail() {
+    let mut e = binary_splitting(0, 5, e_term);
+    e.append(binary_splitting(6, 10, e_term));
+}
+
+#[test]
+fn test_approximate_sum() {
+    let s = binary_splitting(0, 3, |_| {
+        SeriesTerm::new(Integer::ONE, Integer::ONE, Integer::ONE, Integer::from(3))
+    });
+    assert_eq!(
+        s.approximate_sum(4, RoundingMode::Floor).to_string(),
+        "7/16"
+    );
+    assert_eq!(
+        s.approximate_sum(4, RoundingMode::Ceiling).to_string(),
+        "1/2"
+    );
+    assert_eq!(
+        s.approximate_sum(4, RoundingMode::Nearest).to_string(),
+        "1/2"
+    );
+    assert_eq!(s.approximate_sum(0, RoundingMode::Down).to_string(), "0");
+
+    let s = binary_splitting(0, 10, mixed_term);
+    let exact = s.sum();
+    for prec in 0..100 {
+        let floor = s.approximate_sum(prec, RoundingMode::Floor);
+        let ceiling = s.approximate_sum(prec, RoundingMode::Ceiling);
+        assert!(floor <= exact);
+        assert!(ceiling >= exact);
+        assert!(&ceiling - &floor <= Rational::power_of_2(-i64::try_from(prec).unwrap()));
+    }
+}
+
+#[test]
+fn test_pi() {
+    // Machin's formula: π = 16 arctan(1/5) - 4 arctan(1/239)
+    let prec = 200;
+    let terms_5 = 150;
+    let terms_239 = 50;
+    let a = binary_splitting(0, terms_5, arctan_term(5));
+    let b = binary_splitting(0, terms_239, arctan_term(239));
+    // The terms alternate in sign and decrease in absolute value, so the ratio of consecutive
+    // terms is at most 1/k^2 in absolute value.
+    let a_tail = a.geometric_tail_bound(&arctan_term(5)(terms_5), &Rational::from_signeds(1, 25));
+    let b_tail = b.geometric_tail_bound(
+        &arctan_term(239)(terms_239),
+        &Rational::from_signeds(1, 57121),
+    );
+    let (a_approx, a_error) = a.approximate_sum_with_error(prec, &a_tail);
+    let (b_approx, b_error) = b.approximate_sum_with_error(prec, &b_tail);
+    let pi = a_approx * Rational::from(16) - b_approx * Rational::from(4);
+    let error = a_error * Rational::from(16) + b_error * Rational::from(4);
+    assert!(error < Rational::power_of_2(-190i64));
+
+    let pi_50_digits = Rational::from(
+        Integer::from_str("314159265358979323846264338327950288419716939937510").unwrap(),
+    ) / Rational::from(10u32).pow(50u64);
+    assert!((pi - pi_50_digits).abs() < Rational::from(10u32).pow(-49i64));
+}
+
+#[test]
+fn binary_splitting_properties() {
+    for start in 0..12 {
+        for end in start..24 {
+            let s = binary_splitting(start, end, mixed_term);
+            assert_eq!(s.sum(), binary_splitting_naive(start, end, mixed_term));
+            let s = binary_splitting(start, end, arctan_term(3));
+            assert_eq!(s.sum(), binary_splitting_naive(start, end, arctan_term(3)));
+            for mid in start..=end {
+                let mut left = binary_splitting(start, mid, mixed_term);
+                let right = binary_splitting(mid, end, mixed_term);
+                let product = left.product() * right.product();
+                left.append(right);
+                assert_eq!(left.sum(), binary_splitting_naive(start, end, mixed_term));
+                assert_eq!(left.product(), product);
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mediant() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        let mediant = x.mediant(&y);
+        assert!(mediant.is_valid());
+        assert_eq!(mediant.to_string(), out);
+    };
+    test("0", "1", "1/2");
+    test("1/3", "1/2", "2/5");
+    test("1/3", "2/3", "1/2");
+    test("-1/2", "1", "0");
+    test("-1/2", "-1/3", "-2/5");
+    test("22/7", "355/113", "377/120");
+}
+
+#[test]
+fn mediant_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let mediant = x.mediant(&y);
+        assert!(mediant.is_valid());
+        assert_eq!(y.mediant(&x), mediant);
+        assert_eq!((-&x).mediant(&-&y), -&mediant);
+        if x < y {
+            assert!(x < mediant);
+            assert!(mediant < y);
+        } else if x == y {
+            assert_eq!(mediant, x);
+        }
+    });
+}
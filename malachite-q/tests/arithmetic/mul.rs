@@ -113,6 +113,10 @@ fn mul_properties() {
         let product_val_ref = x.clone() * &y;
         let product_ref_val = &x * y.clone();
         let product = &x * &y;
+        let mut out = x.clone();
+        x.mul_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, product);
         assert!(product_val_val.is_valid());
         assert!(product_val_ref.is_valid());
         assert!(product_ref_val.is_valid());
@@ -200,6 +200,10 @@ fn pow_properties() {
 
     rational_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, exp)| {
         let power = (&x).pow(exp);
+        let mut out = x.clone();
+        x.pow_to(exp, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, power);
         assert!(power.is_valid());
 
         let power_alt = x.clone().pow(exp);
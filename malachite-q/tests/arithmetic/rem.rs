@@ -0,0 +1,135 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_gen_var_1, rational_pair_gen_var_1,
+};
+use malachite_q::Rational;
+use num::BigRational;
+use std::str::FromStr;
+
+#[test]
+fn test_rem() {
+    let test = |s, t, out| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n %= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n %= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() % v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u % v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() % &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u % &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = BigRational::from_str(s).unwrap() % BigRational::from_str(t).unwrap();
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "1/123", "0");
+    test("1", "1/123", "0");
+    test("123", "1", "0");
+    test("123", "1/456", "0");
+    test("7/2", "1/3", "1/6");
+    test("7/2", "-1/3", "1/6");
+    test("-7/2", "1/3", "-1/6");
+    test("-7/2", "-1/3", "-1/6");
+    test("22/7", "2/3", "10/21");
+    test("-22/7", "2/3", "-10/21");
+    test("22/7", "1/2", "1/7");
+    test("4/5", "4/5", "0");
+    test("1/3", "1/2", "1/3");
+    test("-1/3", "1/2", "-1/3");
+    test("355/113", "22/7", "355/113");
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_fail() {
+    Rational::ONE % Rational::ZERO;
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_ref_ref_fail() {
+    &Rational::ONE % &Rational::ZERO;
+}
+
+#[test]
+#[should_panic]
+fn rem_assign_fail() {
+    let mut x = Rational::ONE;
+    x %= Rational::ZERO;
+}
+
+#[test]
+fn rem_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let remainder_val_val = x.clone() % y.clone();
+        let remainder_val_ref = x.clone() % &y;
+        let remainder_ref_val = &x % y.clone();
+        let remainder = &x % &y;
+        assert!(remainder_val_val.is_valid());
+        assert!(remainder_val_ref.is_valid());
+        assert!(remainder_ref_val.is_valid());
+        assert!(remainder.is_valid());
+        assert_eq!(remainder_val_val, remainder);
+        assert_eq!(remainder_val_ref, remainder);
+        assert_eq!(remainder_ref_val, remainder);
+
+        let mut mut_x = x.clone();
+        mut_x %= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+        let mut mut_x = x.clone();
+        mut_x %= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+
+        assert_eq!(
+            Rational::from(&(BigRational::from(&x) % BigRational::from(&y))),
+            remainder
+        );
+
+        let quotient = Integer::rounding_from(&x / &y, RoundingMode::Down);
+        assert_eq!(&y * Rational::from(quotient) + &remainder, x);
+        assert!((&remainder).abs() < (&y).abs());
+        assert!(remainder == 0u32 || (remainder > 0u32) == (x > 0u32));
+        assert_eq!(-&x % &y, -&remainder);
+        assert_eq!(&x % -&y, remainder);
+    });
+
+    rational_gen().test_properties(|x| {
+        assert_eq!(
+            &x % Rational::ONE,
+            &x - Rational::from(Integer::rounding_from(&x, RoundingMode::Down))
+        );
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(Rational::ZERO % x, 0);
+        assert_eq!(x % x, 0);
+        assert_eq!(x % -x, 0);
+    });
+}
@@ -276,6 +276,10 @@ fn shl_properties() {
 
     rational_unsigned_pair_gen_var_1::<u32>().test_properties(|(n, u)| {
         let shifted = &n << u;
+        let mut out = n.clone();
+        n.shl_to(u64::from(u), &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, shifted);
         let mut rug_n = rug::Rational::from(&n);
         rug_n <<= u;
         assert_eq!(Rational::from(&rug_n), shifted);
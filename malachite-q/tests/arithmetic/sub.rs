@@ -75,6 +75,10 @@ fn sub_properties() {
         let diff_val_ref = x.clone() - &y;
         let diff_ref_val = &x - y.clone();
         let diff = &x - &y;
+        let mut out = y.clone();
+        x.sub_to(&y, &mut out);
+        assert!(out.is_valid());
+        assert_eq!(out, diff);
         assert!(diff_val_val.is_valid());
         assert!(diff_val_ref.is_valid());
         assert!(diff_ref_val.is_valid());
@@ -1,9 +1,15 @@
+use malachite_nz::platform::Limb;
 use malachite_q::Rational;
 use std::mem::size_of;
 
 #[test]
 fn test_size() {
     if size_of::<usize>() == 8 {
-        assert_eq!(size_of::<Rational>(), 56);
+        let expected_size = match size_of::<Limb>() {
+            4 => 72,
+            8 => 88,
+            _ => 112,
+        };
+        assert_eq!(size_of::<Rational>(), expected_size);
     }
 }
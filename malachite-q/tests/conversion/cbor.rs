@@ -0,0 +1,200 @@
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, Pow, PowerOf2};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::cbor::CborError;
+use malachite_q::conversion::cbor::DEFAULT_MAX_CBOR_EXPONENT;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_cbor() {
+    let test = |s, out: &[u8]| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.to_cbor(), out);
+        assert_eq!(Rational::from_cbor(out), Ok((x, out.len())));
+    };
+    test("0", &[0x00]);
+    test("-1", &[0x20]);
+    test("100", &[0x18, 0x64]);
+    test("1/2", &[0xc5, 0x82, 0x20, 0x01]);
+    test("-3/4", &[0xc5, 0x82, 0x21, 0x22]);
+    test("1/1024", &[0xc5, 0x82, 0x29, 0x01]);
+    test("1/10", &[0xc4, 0x82, 0x20, 0x01]);
+    test("1/5", &[0xc4, 0x82, 0x20, 0x02]);
+    test("1/20", &[0xc4, 0x82, 0x21, 0x05]);
+    test("5463/20", &[0xc4, 0x82, 0x21, 0x19, 0x6a, 0xb3]);
+    test("-1/3", &[0xd8, 0x1e, 0x82, 0x20, 0x03]);
+    test("22/7", &[0xd8, 0x1e, 0x82, 0x16, 0x07]);
+    test("1/30", &[0xd8, 0x1e, 0x82, 0x01, 0x18, 0x1e]);
+    test(
+        "18446744073709551616/3",
+        &[0xd8, 0x1e, 0x82, 0xc2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03],
+    );
+}
+
+#[test]
+fn test_cbor_large_exponents() {
+    let test = |x: Rational, tag: u8| {
+        let bytes = x.to_cbor();
+        assert_eq!(bytes[0], tag);
+        assert_eq!(Rational::from_cbor(&bytes), Ok((x, bytes.len())));
+    };
+    let max = i64::exact_from(DEFAULT_MAX_CBOR_EXPONENT);
+    test(Rational::power_of_2(-max), 0xc5);
+    test(Rational::power_of_2(-max - 1), 0xd8);
+    test(Rational::power_of_2(-(max << 2)), 0xd8);
+    test(Rational::from(10u32).pow(-max), 0xc4);
+    test(Rational::from(10u32).pow(-max - 1), 0xd8);
+    test(Rational::from_signeds(3, 5).pow(max + 1), 0xd8);
+}
+
+#[test]
+fn test_from_cbor() {
+    let test = |bytes: &[u8], out: Result<(&str, usize), CborError>| {
+        assert_eq!(
+            Rational::from_cbor(bytes),
+            out.map(|(s, len)| (Rational::from_str(s).unwrap(), len))
+        );
+    };
+    // Positive exponents and unreduced fractions are accepted
+    test(&[0xc4, 0x82, 0x02, 0x03], Ok(("300", 4)));
+    test(&[0xc5, 0x82, 0x03, 0x20], Ok(("-8", 4)));
+    test(&[0xc4, 0x82, 0x21, 0x19, 0x01, 0xf4], Ok(("5", 6)));
+    test(&[0xd8, 0x1e, 0x82, 0x06, 0x04], Ok(("3/2", 5)));
+    test(&[0xd8, 0x1e, 0x82, 0x20, 0x01, 0xff], Ok(("-1", 5)));
+    test(&[0xc3, 0x41, 0x01], Ok(("-2", 3)));
+
+    test(&[], Err(CborError::UnexpectedEnd));
+    test(&[0xc4, 0x82, 0x21], Err(CborError::UnexpectedEnd));
+    test(
+        &[0xd8, 0x1e, 0x82, 0x01, 0x00],
+        Err(CborError::ZeroDenominator),
+    );
+    test(&[0xd8, 0x1e, 0x82, 0x01, 0x20], Err(CborError::Negative));
+    test(
+        &[0xd8, 0x1e, 0x83, 0x01, 0x02, 0x03],
+        Err(CborError::WrongArrayLength(3)),
+    );
+    test(&[0xc4, 0x01], Err(CborError::WrongMajorType(0)));
+    test(&[0xc6, 0x82, 0x00, 0x00], Err(CborError::WrongTag(6)));
+    test(&[0xc1, 0x00], Err(CborError::WrongTag(1)));
+    test(&[0x60], Err(CborError::WrongMajorType(3)));
+    test(
+        &[0xc4, 0x82, 0x1b, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+        Err(CborError::ExponentOutOfRange),
+    );
+}
+
+#[test]
+fn test_from_cbor_with_max_exponent() {
+    // 2^(2^20), 2^(2^20 + 1), and 2^(-2^20 - 1)
+    let power_of_2_bytes = |exponent_head: u8, exponent: u32| {
+        let mut bytes = vec![0xc5, 0x82, exponent_head];
+        bytes.extend(exponent.to_be_bytes());
+        bytes.push(0x01);
+        bytes
+    };
+    let bytes = power_of_2_bytes(0x1a, 1 << 20);
+    assert_eq!(
+        Rational::from_cbor(&bytes),
+        Ok((Rational::power_of_2(1i64 << 20), 8))
+    );
+    let bytes = power_of_2_bytes(0x1a, (1 << 20) + 1);
+    assert_eq!(
+        Rational::from_cbor(&bytes),
+        Err(CborError::ExponentOutOfRange)
+    );
+    assert_eq!(
+        Rational::from_cbor_with_max_exponent(&bytes, (1 << 20) + 1),
+        Ok((Rational::power_of_2((1i64 << 20) + 1), 8))
+    );
+    let bytes = power_of_2_bytes(0x3a, 1 << 20);
+    assert_eq!(
+        Rational::from_cbor(&bytes),
+        Err(CborError::ExponentOutOfRange)
+    );
+    assert_eq!(
+        Rational::from_cbor_with_max_exponent(&bytes, 1 << 21),
+        Ok((Rational::power_of_2(-(1i64 << 20) - 1), 8))
+    );
+
+    let test = |bytes: &[u8], max_exponent: u64, out: Result<(&str, usize), CborError>| {
+        assert_eq!(
+            Rational::from_cbor_with_max_exponent(bytes, max_exponent),
+            out.map(|(s, len)| (Rational::from_str(s).unwrap(), len))
+        );
+    };
+    test(&[0xc4, 0x82, 0x02, 0x03], 2, Ok(("300", 4)));
+    test(
+        &[0xc4, 0x82, 0x02, 0x03],
+        1,
+        Err(CborError::ExponentOutOfRange),
+    );
+    test(&[0xc5, 0x82, 0x21, 0x03], 2, Ok(("3/4", 4)));
+    test(
+        &[0xc5, 0x82, 0x21, 0x03],
+        1,
+        Err(CborError::ExponentOutOfRange),
+    );
+    test(&[0xc5, 0x82, 0x00, 0x03], 0, Ok(("3", 4)));
+    // The limit doesn't apply to rational numbers
+    test(
+        &[0xd8, 0x1e, 0x82, 0x01, 0x19, 0x04, 0x00],
+        0,
+        Ok(("1/1024", 7)),
+    );
+}
+
+#[test]
+fn cbor_properties() {
+    rational_gen().test_properties(|x| {
+        let bytes = x.to_cbor();
+        assert_eq!(Rational::from_cbor(&bytes), Ok((x.clone(), bytes.len())));
+        let (numerator, denominator) = x.to_numerator_and_denominator();
+        if denominator == 1u32 {
+            assert_eq!(bytes, Integer::try_from(&x).unwrap().to_cbor());
+        } else if denominator.is_power_of_2() {
+            assert_eq!(bytes[0], 0xc5);
+        } else if bytes[0] == 0xc4 {
+            // The exponent is -k, where k is the smallest exponent for which x * 10^k is an
+            // integer
+            let (e, _) = Integer::from_cbor(&bytes[2..]).unwrap();
+            let k = -i64::exact_from(&e);
+            assert!((&x * Rational::from(10u32).pow(k)).is_integer());
+            assert!(!(&x * Rational::from(10u32).pow(k - 1)).is_integer());
+        } else {
+            assert_eq!(bytes[..3], [0xd8, 0x1e, 0x82]);
+            let mut expected = bytes[..3].to_vec();
+            expected.extend(Integer::from_sign_and_abs(x >= 0, numerator).to_cbor());
+            expected.extend(denominator.to_cbor());
+            assert_eq!(bytes, expected);
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 64);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |bytes| {
+        let result = Rational::from_cbor(&bytes);
+        assert_eq!(
+            Rational::from_cbor_with_max_exponent(&bytes, DEFAULT_MAX_CBOR_EXPONENT),
+            result
+        );
+        if let Ok((x, len)) = result {
+            assert_eq!(
+                Rational::from_cbor_with_max_exponent(&bytes, u64::MAX),
+                Ok((x.clone(), len))
+            );
+            assert!(len <= bytes.len());
+            assert_eq!(Rational::from_cbor(&x.to_cbor()).unwrap().0, x);
+        }
+    });
+
+    for k in 1..100 {
+        let x = Rational::power_of_2(-k);
+        assert_eq!(x.to_cbor()[0], 0xc5);
+    }
+}
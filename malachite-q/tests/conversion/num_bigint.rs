@@ -0,0 +1,78 @@
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use num::{BigInt, BigRational};
+use std::str::FromStr;
+
+#[test]
+fn test_from_big_rational() {
+    let test = |n, d, out| {
+        let x = BigRational::new(BigInt::from_str(n).unwrap(), BigInt::from_str(d).unwrap());
+
+        let q = Rational::from(x.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+
+        let q = Rational::from(&x);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+
+        let x = BigRational::new_raw(BigInt::from_str(n).unwrap(), BigInt::from_str(d).unwrap());
+
+        let q = Rational::from(x.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+
+        let q = Rational::from(&x);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("0", "-5", "0");
+    test("123", "1", "123");
+    test("-123", "1", "-123");
+    test("22", "7", "22/7");
+    test("22", "-7", "-22/7");
+    test("-22", "-7", "22/7");
+    test("6", "4", "3/2");
+    test("1000000000000000000000", "-3000000000000000000000", "-1/3");
+}
+
+#[test]
+#[should_panic]
+fn from_big_rational_fail() {
+    Rational::from(BigRational::new_raw(BigInt::from(1), BigInt::from(0)));
+}
+
+#[test]
+fn test_big_rational_from_rational() {
+    let test = |s| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(BigRational::from(x.clone()).to_string(), s);
+        assert_eq!(BigRational::from(&x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("22/7");
+    test("-22/7");
+    test("-1/3000000000000000000000");
+}
+
+#[test]
+fn num_bigint_conversion_properties() {
+    rational_gen().test_properties(|x| {
+        let big_x = BigRational::from(&x);
+        assert_eq!(BigRational::from(x.clone()), big_x);
+        assert_eq!(big_x.to_string(), x.to_string());
+        assert!(num::Signed::is_positive(big_x.denom()));
+        assert!(num::One::is_one(&num::Integer::gcd(
+            big_x.numer(),
+            big_x.denom()
+        )));
+
+        let y = Rational::from(&big_x);
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert_eq!(Rational::from(big_x), x);
+    });
+}
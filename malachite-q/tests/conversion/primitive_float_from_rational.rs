@@ -2,6 +2,7 @@ use malachite_base::num::arithmetic::traits::Parity;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::comparison::traits::PartialOrdAbs;
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, RoundingFrom};
+use malachite_base::num::float::half::{BF16, F16};
 use malachite_base::num::float::NiceFloat;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_nz::integer::Integer;
@@ -1513,6 +1514,8 @@ where
 #[test]
 fn float_try_from_rational_properties() {
     apply_fn_to_primitive_floats!(float_try_from_rational_properties_helper);
+    float_try_from_rational_properties_helper::<F16>();
+    float_try_from_rational_properties_helper::<BF16>();
 }
 
 #[allow(clippy::trait_duplication_in_bounds)]
@@ -1550,4 +1553,6 @@ where
 #[test]
 fn float_convertible_from_rational_properties() {
     apply_fn_to_primitive_floats!(float_convertible_from_rational_properties_helper);
+    float_convertible_from_rational_properties_helper::<F16>();
+    float_convertible_from_rational_properties_helper::<BF16>();
 }
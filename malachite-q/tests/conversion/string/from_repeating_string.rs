@@ -0,0 +1,137 @@
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_q::conversion::string::to_repeating_string::ToRepeatingOptions;
+use malachite_q::test_util::generators::rational_unsigned_pair_gen_var_6;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_repeating_string() {
+    let test_ok = |s, out| {
+        assert_eq!(
+            Rational::from_repeating_string(s).unwrap(),
+            Rational::from_str(out).unwrap()
+        );
+    };
+    test_ok("0", "0");
+    test_ok("-0", "0");
+    test_ok("007", "7");
+    test_ok("-123", "-123");
+    test_ok("0.5", "1/2");
+    test_ok("-0.50", "-1/2");
+    test_ok("0.(3)", "1/3");
+    test_ok("0.[3]", "1/3");
+    test_ok("0.3\u{305}", "1/3");
+    test_ok("0.1(6)", "1/6");
+    test_ok("0.1[6]", "1/6");
+    test_ok("0.16\u{305}", "1/6");
+    test_ok("-0.16\u{305}", "-1/6");
+    test_ok("3.(142857)", "22/7");
+    test_ok("3.1\u{305}4\u{305}2\u{305}8\u{305}5\u{305}7\u{305}", "22/7");
+    test_ok("0.(9)", "1");
+    test_ok("0.9(99)", "1");
+    test_ok("0.1(0)", "1/10");
+    test_ok("0.03(571428)", "1/28");
+    test_ok("1/6", "1/6");
+    test_ok("-2/12", "-1/6");
+
+    let test_err = |s, kind, index| {
+        assert_eq!(
+            Rational::from_repeating_string(s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    use ParseNumberErrorKind::*;
+    test_err("", Empty, 0);
+    test_err("-", Empty, 1);
+    test_err(".5", Empty, 0);
+    test_err("-.5", Empty, 1);
+    test_err("1.", Empty, 2);
+    test_err("0.()", Empty, 3);
+    test_err("0.1(6", Empty, 5);
+    test_err("0.1(6]", InvalidDigit, 5);
+    test_err("0.1(6))", InvalidDigit, 6);
+    test_err("0.1(6)7", InvalidDigit, 6);
+    test_err("0.1(a)", InvalidDigit, 4);
+    test_err("0.a(1)", InvalidDigit, 2);
+    test_err("0.1)", InvalidDigit, 3);
+    test_err("1.2.3", InvalidDigit, 3);
+    test_err("+1", InvalidDigit, 0);
+    test_err("--1", InvalidDigit, 1);
+    test_err("0.\u{305}", InvalidDigit, 2);
+    test_err("0.6\u{305}\u{305}", InvalidDigit, 5);
+    test_err("0.6\u{305}7", InvalidDigit, 5);
+    test_err("0.6\u{305}(7)", InvalidDigit, 3);
+    test_err("1/0", ZeroDenominator, 2);
+    test_err("1.5/2", InvalidDigit, 1);
+}
+
+#[test]
+fn test_from_repeating_string_base() {
+    let test_ok = |base, s, out| {
+        assert_eq!(
+            Rational::from_repeating_string_base(base, s).unwrap(),
+            Rational::from_str(out).unwrap()
+        );
+    };
+    test_ok(2, "0.(01)", "1/3");
+    test_ok(2, "-10.1", "-5/2");
+    test_ok(3, "0.1", "1/3");
+    test_ok(16, "0.(1745D)", "1/11");
+    test_ok(16, "0.(1745d)", "1/11");
+    test_ok(16, "-17.(2E8BA)", "-255/11");
+    test_ok(16, "0.1\u{305}", "1/15");
+    test_ok(16, "ff/100", "255/256");
+    test_ok(36, "z.(z)", "36");
+
+    assert_eq!(
+        Rational::from_repeating_string_base(2, "0.(2)"),
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3))
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_repeating_string_base_fail_1() {
+    Rational::from_repeating_string_base(1, "0").unwrap();
+}
+
+#[test]
+#[should_panic]
+fn from_repeating_string_base_fail_2() {
+    Rational::from_repeating_string_base(37, "0").unwrap();
+}
+
+#[test]
+fn from_repeating_string_properties() {
+    rational_unsigned_pair_gen_var_6().test_properties(|(x, base)| {
+        let mut options = ToRepeatingOptions::default();
+        options.set_base(base);
+        let s = x.to_repeating_string_with_options(options).unwrap();
+        // Expansions that are not in their simplest form represent the same number
+        if let Some(open) = s.find('(') {
+            let repeating = &s[open + 1..s.len() - 1];
+            let doubled = format!("{}({}{})", &s[..open], repeating, repeating);
+            assert_eq!(
+                Rational::from_repeating_string_base(base, &doubled),
+                Ok(x.clone())
+            );
+            let (first, rest) = repeating.split_at(1);
+            let shifted = format!("{}{}({}{})", &s[..open], first, rest, first);
+            assert_eq!(
+                Rational::from_repeating_string_base(base, &shifted),
+                Ok(x.clone())
+            );
+        } else if s.contains('.') {
+            let padded = format!("{}0", s);
+            assert_eq!(
+                Rational::from_repeating_string_base(base, &padded),
+                Ok(x.clone())
+            );
+        }
+        if base == 10 {
+            assert_eq!(Rational::from_repeating_string(&x.to_string()), Ok(x));
+        }
+    });
+}
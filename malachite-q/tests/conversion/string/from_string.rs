@@ -1,8 +1,13 @@
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::ParseOptions;
+use malachite_base::num::conversion::traits::FromStringWithOptions;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::string_gen;
 use malachite_nz::integer::Integer;
 use malachite_nz::test_util::generators::integer_gen;
-use malachite_q::test_util::generators::string_gen_var_12;
+use malachite_q::test_util::generators::{rational_gen, string_gen_var_12};
 use malachite_q::Rational;
 use num::BigRational;
 use std::str::FromStr;
@@ -65,3 +70,93 @@ fn from_str_properties() {
         );
     });
 }
+
+fn lenient_options() -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_allow_underscore_separators(true);
+    options.set_allow_whitespace(true);
+    options.set_allow_prefixes(true);
+    options.set_allow_plus_sign(true);
+    options
+}
+
+#[test]
+fn test_from_str_errors() {
+    let test = |s, kind, index| {
+        assert_eq!(
+            Rational::from_str(s),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test("", ParseNumberErrorKind::Empty, 0);
+    test("-", ParseNumberErrorKind::Empty, 1);
+    test("/1", ParseNumberErrorKind::Empty, 0);
+    test("1/", ParseNumberErrorKind::Empty, 2);
+    test("12A", ParseNumberErrorKind::InvalidDigit, 2);
+    test("1.0", ParseNumberErrorKind::InvalidDigit, 1);
+    test("1/2/3", ParseNumberErrorKind::InvalidDigit, 3);
+    test("1/-2", ParseNumberErrorKind::InvalidDigit, 2);
+    test("x/0", ParseNumberErrorKind::InvalidDigit, 0);
+    test("+1", ParseNumberErrorKind::InvalidSign, 0);
+    test("1/0", ParseNumberErrorKind::ZeroDenominator, 2);
+    test("-22/000", ParseNumberErrorKind::ZeroDenominator, 4);
+}
+
+#[test]
+fn test_from_string_with_options() {
+    let test_ok = |s, options, n| {
+        assert_eq!(
+            Rational::from_string_with_options(s, options)
+                .unwrap()
+                .to_string(),
+            n
+        );
+    };
+    let default = ParseOptions::default();
+    let lenient = lenient_options();
+    test_ok("-3/21", default, "-1/7");
+    test_ok(" +1_000/3_000 ", lenient, "1/3");
+    test_ok("-0x10/0b11", lenient, "-16/3");
+    let mut hex = default;
+    hex.set_base(16);
+    test_ok("a/f", hex, "2/3");
+
+    let test_err = |s, options, kind, index| {
+        assert_eq!(
+            Rational::from_string_with_options(s, options),
+            Err(ParseNumberError::new(kind, index))
+        );
+    };
+    test_err(" 1 / 2 ", lenient, ParseNumberErrorKind::InvalidDigit, 2);
+    test_err("1_/2", lenient, ParseNumberErrorKind::MisplacedSeparator, 1);
+    test_err("1/0x", lenient, ParseNumberErrorKind::Empty, 4);
+    test_err("1/0x0", lenient, ParseNumberErrorKind::ZeroDenominator, 2);
+}
+
+#[test]
+fn from_string_with_options_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        if let Ok(x) = Rational::from_str(&s) {
+            assert_eq!(
+                Rational::from_string_with_options(&s, lenient_options()),
+                Ok(x)
+            );
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(Rational::from_str(&s), Ok(x.clone()));
+        let lenient = lenient_options();
+        assert_eq!(
+            Rational::from_string_with_options(&format!(" {} ", s), lenient),
+            Ok(x.clone())
+        );
+        assert_eq!(
+            Rational::from_string_with_options(&format!("{:#x}", x), lenient),
+            Ok(x)
+        );
+    });
+}
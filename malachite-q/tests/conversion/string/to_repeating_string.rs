@@ -0,0 +1,177 @@
+use malachite_base::num::conversion::traits::{IsInteger, ToStringBase};
+use malachite_nz::test_util::generators::integer_gen;
+use malachite_q::conversion::string::to_repeating_string::{
+    RepeatingNotation, ToRepeatingOptions, DEFAULT_MAX_PERIOD_LEN,
+};
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_6};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+const NOTATIONS: [RepeatingNotation; 3] =
+    [RepeatingNotation::Parentheses, RepeatingNotation::Brackets, RepeatingNotation::Overline];
+
+#[test]
+fn test_to_repeating_string() {
+    let test = |s, out| {
+        assert_eq!(
+            Rational::from_str(s)
+                .unwrap()
+                .to_repeating_string()
+                .as_deref(),
+            Some(out)
+        );
+    };
+    test("0", "0");
+    test("123", "123");
+    test("-123", "-123");
+    test("1/2", "0.5");
+    test("-1/2", "-0.5");
+    test("1/3", "0.(3)");
+    test("2/3", "0.(6)");
+    test("1/6", "0.1(6)");
+    test("-1/6", "-0.1(6)");
+    test("1/7", "0.(142857)");
+    test("22/7", "3.(142857)");
+    test("1/12", "0.08(3)");
+    test("1/28", "0.03(571428)");
+    test("1/81", "0.(012345679)");
+    test("1/1024", "0.0009765625");
+    test("100/3", "33.(3)");
+
+    // The period of 1/3^k has 3^(k - 2) digits, so it is within the default limit for k = 12 but
+    // not for k = 13
+    let x = Rational::from_signeds(1, 531441);
+    assert_eq!(x.to_repeating_string().unwrap().len(), 59053);
+    let x = Rational::from_signeds(1, 1594323);
+    assert_eq!(x.to_repeating_string(), None);
+    let mut options = ToRepeatingOptions::default();
+    options.set_max_period_len(None);
+    assert_eq!(
+        x.to_repeating_string_with_options(options).unwrap().len(),
+        177151
+    );
+}
+
+#[test]
+fn test_to_repeating_options_default() {
+    let options = ToRepeatingOptions::default();
+    assert_eq!(options.get_base(), 10);
+    assert!(options.get_lowercase());
+    assert_eq!(options.get_notation(), RepeatingNotation::Parentheses);
+    assert_eq!(options.get_max_period_len(), Some(DEFAULT_MAX_PERIOD_LEN));
+}
+
+#[test]
+fn test_to_repeating_string_with_options() {
+    let test = |s, base, lowercase, notation, max_period_len, out: Option<&str>| {
+        let mut options = ToRepeatingOptions::default();
+        options.set_base(base);
+        if !lowercase {
+            options.set_uppercase();
+        }
+        options.set_notation(notation);
+        options.set_max_period_len(max_period_len);
+        assert_eq!(
+            Rational::from_str(s)
+                .unwrap()
+                .to_repeating_string_with_options(options)
+                .as_deref(),
+            out
+        );
+    };
+    let parens = RepeatingNotation::Parentheses;
+    let brackets = RepeatingNotation::Brackets;
+    let overline = RepeatingNotation::Overline;
+    test("1/6", 10, true, brackets, None, Some("0.1[6]"));
+    test("1/6", 10, true, overline, None, Some("0.16\u{305}"));
+    test(
+        "1/7",
+        10,
+        true,
+        overline,
+        None,
+        Some("0.1\u{305}4\u{305}2\u{305}8\u{305}5\u{305}7\u{305}"),
+    );
+    test("5", 10, true, overline, None, Some("5"));
+    test("1/3", 2, true, parens, None, Some("0.(01)"));
+    test("1/5", 2, true, parens, None, Some("0.(0011)"));
+    test("1/3", 3, true, parens, None, Some("0.1"));
+    test("1/11", 16, true, parens, None, Some("0.(1745d)"));
+    test("1/11", 16, false, parens, None, Some("0.(1745D)"));
+    test("-255/11", 16, false, brackets, None, Some("-17.[2E8BA]"));
+    test("1/7", 10, true, parens, Some(6), Some("0.(142857)"));
+    test("1/7", 10, true, parens, Some(5), None);
+    test("1/7", 10, true, parens, Some(0), None);
+    test("1/8", 10, true, parens, Some(0), Some("0.125"));
+    test("1/36", 36, true, parens, Some(0), Some("0.1"));
+}
+
+#[test]
+#[should_panic]
+fn set_base_fail_1() {
+    ToRepeatingOptions::default().set_base(1);
+}
+
+#[test]
+#[should_panic]
+fn set_base_fail_2() {
+    ToRepeatingOptions::default().set_base(37);
+}
+
+#[test]
+fn to_repeating_string_properties() {
+    rational_unsigned_pair_gen_var_6().test_properties(|(x, base)| {
+        let mut options = ToRepeatingOptions::default();
+        options.set_base(base);
+        let s = x.to_repeating_string_with_options(options).unwrap();
+        assert_eq!(
+            Rational::from_repeating_string_base(base, &s),
+            Ok(x.clone())
+        );
+        assert_eq!(s.starts_with('-'), x < 0u32);
+        assert_eq!(s.contains('.'), !(&x).is_integer());
+        let integer_len = s.find('.').unwrap_or(s.len());
+        assert_eq!(
+            (-&x)
+                .to_repeating_string_with_options(options)
+                .unwrap()
+                .trim_start_matches('-'),
+            s.trim_start_matches('-')
+        );
+        options.set_uppercase();
+        assert_eq!(
+            x.to_repeating_string_with_options(options).unwrap(),
+            s.to_uppercase()
+        );
+        for notation in NOTATIONS {
+            options.set_notation(notation);
+            let s_alt = x.to_repeating_string_with_options(options).unwrap();
+            assert_eq!(s_alt[..integer_len], s[..integer_len].to_uppercase());
+            assert_eq!(
+                Rational::from_repeating_string_base(base, &s_alt),
+                Ok(x.clone())
+            );
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let mut options = ToRepeatingOptions::default();
+        options.set_max_period_len(Some(100));
+        if let Some(s) = x.to_repeating_string_with_options(options) {
+            assert_eq!(Rational::from_repeating_string(&s), Ok(x.clone()));
+            assert_eq!(x.to_repeating_string(), Some(s));
+        }
+    });
+
+    integer_gen().test_properties(|n| {
+        let x = Rational::from(&n);
+        assert_eq!(x.to_repeating_string(), Some(n.to_string()));
+        let mut options = ToRepeatingOptions::default();
+        options.set_base(16);
+        options.set_max_period_len(Some(0));
+        assert_eq!(
+            x.to_repeating_string_with_options(options).unwrap(),
+            n.to_string_base(16)
+        );
+    });
+}
@@ -1,3 +1,4 @@
+use malachite_base::num::conversion::traits::FromSciString;
 use malachite_base::strings::string_is_subset;
 use malachite_base::strings::ToDebugString;
 use malachite_nz::test_util::generators::integer_gen;
@@ -49,3 +50,163 @@ fn to_string_properties() {
         assert_eq!(Rational::from(&x).to_string(), x.to_string());
     });
 }
+
+#[test]
+pub fn test_fmt_flags() {
+    let x = Rational::from_signeds(-22, 7);
+    assert_eq!(format!("{:>8}", x), "   -22/7");
+    assert_eq!(format!("{:<8}|", x), "-22/7   |");
+    assert_eq!(format!("{:*^9}", x), "**-22/7**");
+    assert_eq!(format!("{:08}", x), "-00022/7");
+    assert_eq!(format!("{:+}", Rational::from_signeds(22, 7)), "+22/7");
+    assert_eq!(format!("{:+}", Rational::from(5)), "+5");
+    assert_eq!(format!("{:>5}", Rational::from(-5)), "   -5");
+    assert_eq!(format!("{:>8?}", x), "   -22/7");
+}
+
+#[test]
+pub fn test_to_radix_strings() {
+    fn test(u: &str, b: &str, o: &str, x: &str, upper_x: &str) {
+        let q = Rational::from_str(u).unwrap();
+        assert_eq!(format!("{:b}", q), b);
+        assert_eq!(format!("{:o}", q), o);
+        assert_eq!(format!("{:x}", q), x);
+        assert_eq!(format!("{:X}", q), upper_x);
+    }
+    test("0", "0", "0", "0", "0");
+    test("123", "1111011", "173", "7b", "7B");
+    test("-123", "-1111011", "-173", "-7b", "-7B");
+    test("22/7", "10110/111", "26/7", "16/7", "16/7");
+    test("-255/16", "-11111111/10000", "-377/20", "-ff/10", "-FF/10");
+    test(
+        "1000000000000/3",
+        "1110100011010100101001010001000000000000/11",
+        "16432451210000/3",
+        "e8d4a51000/3",
+        "E8D4A51000/3",
+    );
+
+    fn test_alt(u: &str, b: &str, o: &str, x: &str, upper_x: &str) {
+        let q = Rational::from_str(u).unwrap();
+        assert_eq!(format!("{:#b}", q), b);
+        assert_eq!(format!("{:#o}", q), o);
+        assert_eq!(format!("{:#x}", q), x);
+        assert_eq!(format!("{:#X}", q), upper_x);
+    }
+    test_alt("0", "0b0", "0o0", "0x0", "0x0");
+    test_alt("-123", "-0b1111011", "-0o173", "-0x7b", "-0x7B");
+    test_alt("22/7", "0b10110/0b111", "0o26/0o7", "0x16/0x7", "0x16/0x7");
+    test_alt(
+        "-255/16",
+        "-0b11111111/0b10000",
+        "-0o377/0o20",
+        "-0xff/0x10",
+        "-0xFF/0x10",
+    );
+
+    let x = Rational::from_signeds(-22, 7);
+    assert_eq!(format!("{:>12b}", x), "  -10110/111");
+    assert_eq!(format!("{:#012b}", x), "-0b10110/0b111");
+    assert_eq!(format!("{:#016b}", x), "-0b0010110/0b111");
+    assert_eq!(format!("{:_<8x}", x), "-16/7___");
+    assert_eq!(format!("{:+o}", Rational::from_signeds(22, 7)), "+26/7");
+}
+
+#[test]
+pub fn test_to_exp_string() {
+    fn test(u: &str, out: &str, out_upper: &str) {
+        let x = Rational::from_str(u).unwrap();
+        assert_eq!(format!("{:e}", x), out);
+        assert_eq!(format!("{:E}", x), out_upper);
+    }
+    test("0", "0e0", "0E0");
+    test("123", "1.23e2", "1.23E2");
+    test("-1000", "-1e3", "-1E3");
+    test("1/2", "5e-1", "5E-1");
+    test("-3/8", "-3.75e-1", "-3.75E-1");
+    test("1/1024", "9.765625e-4", "9.765625E-4");
+    test("1001/100", "1.001e1", "1.001E1");
+    test("1/3", "3.333333333333333e-1", "3.333333333333333E-1");
+    test("-2/3", "-6.666666666666667e-1", "-6.666666666666667E-1");
+    test("22/7", "3.142857142857143e0", "3.142857142857143E0");
+    test("1000000/3", "3.333333333333333e5", "3.333333333333333E5");
+    test("1/30", "3.333333333333333e-2", "3.333333333333333E-2");
+
+    fn test_precision(u: &str, precision: usize, out: &str) {
+        let x = Rational::from_str(u).unwrap();
+        assert_eq!(format!("{:.*e}", precision, x), out);
+    }
+    test_precision("0", 0, "0e0");
+    test_precision("0", 3, "0.000e0");
+    test_precision("123", 4, "1.2300e2");
+    test_precision("1/3", 0, "3e-1");
+    test_precision("1/3", 5, "3.33333e-1");
+    test_precision("2/3", 2, "6.67e-1");
+    test_precision("-22/7", 3, "-3.143e0");
+    test_precision("1/8", 1, "1.2e-1");
+    test_precision("3/8", 1, "3.8e-1");
+    test_precision("999/1000", 1, "1.0e0");
+    test_precision("-99999/10", 2, "-1.00e4");
+
+    let x = Rational::from_signeds(-22, 7);
+    assert_eq!(format!("{:12.2e}", x), "     -3.14e0");
+    assert_eq!(format!("{:<12.2e}|", x), "-3.14e0     |");
+    assert_eq!(format!("{:012.2e}", x), "-000003.14e0");
+    assert_eq!(format!("{:+.2E}", Rational::from_signeds(22, 7)), "+3.14E0");
+}
+
+#[test]
+fn fmt_properties() {
+    rational_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(format!("{:>40}", x), format!("{:>40}", s));
+        assert_eq!(format!("{:*^40}", x), format!("{:*^40}", s));
+        if x >= 0 {
+            assert_eq!(format!("{:+}", x), format!("+{}", s));
+        } else {
+            assert_eq!(format!("{:+}", x), s);
+        }
+
+        let n = x.to_numerator();
+        let d = x.to_denominator();
+        let sign = if x < 0 { "-" } else { "" };
+        let (b, o, h, upper_h) = if d == 1u32 {
+            (
+                format!("{}{:b}", sign, n),
+                format!("{}{:o}", sign, n),
+                format!("{}{:x}", sign, n),
+                format!("{}{:X}", sign, n),
+            )
+        } else {
+            (
+                format!("{}{:b}/{:b}", sign, n, d),
+                format!("{}{:o}/{:o}", sign, n, d),
+                format!("{}{:x}/{:x}", sign, n, d),
+                format!("{}{:X}/{:X}", sign, n, d),
+            )
+        };
+        assert_eq!(format!("{:b}", x), b);
+        assert_eq!(format!("{:o}", x), o);
+        assert_eq!(format!("{:x}", x), h);
+        assert_eq!(format!("{:X}", x), upper_h);
+        assert_eq!(format!("{:>40b}", x), format!("{:>40}", b));
+        assert!(string_is_subset(&b, "-/01"));
+
+        let e = format!("{:e}", x);
+        assert_eq!(format!("{:E}", x), e.to_uppercase());
+        if x.length_after_point_in_small_base(10).is_some() {
+            assert_eq!(Rational::from_sci_string(&e).unwrap(), x);
+        }
+        if x > 0 {
+            assert_eq!(format!("{:e}", -&x), format!("-{}", e));
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let q = Rational::from(&x);
+        assert_eq!(format!("{:e}", q), format!("{:e}", x));
+        assert_eq!(format!("{:.3e}", q), format!("{:.3e}", x));
+        assert_eq!(format!("{:>+#30x}", q), format!("{:>+#30x}", x));
+        assert_eq!(format!("{:^20}", q), format!("{:^20}", x));
+    });
+}
@@ -0,0 +1,104 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::test_util::generators::{integer_pair_gen, integer_signed_pair_gen};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_add() {
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() + v.clone()).to_string(), out);
+        assert_eq!((u.clone() + &v).to_string(), out);
+        assert_eq!((&u + v.clone()).to_string(), out);
+        assert_eq!((&u + &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "0.00", "0.00");
+    test("1.5", "2.25", "3.75");
+    test("0.1", "0.2", "0.3");
+    test("0.10", "0.2", "0.30");
+    test("-1.5", "1.5", "0.0");
+    test("123", "0.001", "123.001");
+    test("12e3", "5", "12005");
+    test("12e3", "5e2", "125e2");
+    test("-0.005", "1.25", "1.245");
+    test(
+        "99999999999999999999.99",
+        "0.01",
+        "100000000000000000000.00",
+    );
+}
+
+#[test]
+fn test_sub() {
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() - v.clone()).to_string(), out);
+        assert_eq!((u.clone() - &v).to_string(), out);
+        assert_eq!((&u - v.clone()).to_string(), out);
+        assert_eq!((&u - &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("1.5", "2.25", "-0.75");
+    test("0.3", "0.1", "0.2");
+    test("1.50", "1.5", "0.00");
+    test("12e3", "5", "11995");
+    test("1.25", "0.005", "1.245");
+}
+
+#[test]
+fn add_properties() {
+    let scales = [-3i64, 0, 2, 5];
+    integer_pair_gen().test_properties(|(c, d)| {
+        for &s in &scales {
+            for &t in &scales {
+                let x = Decimal::from_coefficient_and_scale(c.clone(), s);
+                let y = Decimal::from_coefficient_and_scale(d.clone(), t);
+                let sum = &x + &y;
+                assert_eq!(sum.scale(), s.max(t));
+                assert_eq!(
+                    Rational::from(&sum),
+                    Rational::from(&x) + Rational::from(&y)
+                );
+                assert_eq!(&y + &x, sum);
+
+                let diff = &x - &y;
+                assert_eq!(diff.scale(), s.max(t));
+                assert_eq!(
+                    Rational::from(&diff),
+                    Rational::from(&x) - Rational::from(&y)
+                );
+                assert_eq!(&diff + &y, x);
+                assert_eq!(-(&y - &x), diff);
+            }
+        }
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        assert_eq!(&x + Decimal::ZERO, x);
+        assert_eq!(&x - &x, Decimal::ZERO);
+        assert_eq!((&x - &x).scale(), x.scale());
+    });
+}
@@ -0,0 +1,96 @@
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::test_util::generators::integer_pair_gen_var_1;
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_div_round_to_scale() {
+    let test = |s, t, scale, rm, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+        let q = u.div_round_to_scale(&v, scale, rm);
+        assert_eq!(q.scale(), scale);
+        assert_eq!(q.to_string(), out);
+    };
+    test("0", "1", 2, RoundingMode::Exact, "0.00");
+    test("1", "3", 0, RoundingMode::Down, "0");
+    test("1", "3", 0, RoundingMode::Up, "1");
+    test("1", "3", 5, RoundingMode::Floor, "0.33333");
+    test("1", "3", 5, RoundingMode::Ceiling, "0.33334");
+    test("-1", "3", 5, RoundingMode::Floor, "-0.33334");
+    test("-1", "3", 5, RoundingMode::Ceiling, "-0.33333");
+    test("-1", "3", 5, RoundingMode::Down, "-0.33333");
+    test("-1", "3", 5, RoundingMode::Up, "-0.33334");
+    test("2", "3", 2, RoundingMode::Nearest, "0.67");
+    test("0.125", "1", 2, RoundingMode::Nearest, "0.12");
+    test("0.135", "1", 2, RoundingMode::Nearest, "0.14");
+    test("100.00", "0.08", 1, RoundingMode::Exact, "1250.0");
+    test("1", "7", -1, RoundingMode::Nearest, "0e1");
+    test("1234", "1", -2, RoundingMode::Floor, "12e2");
+    test("1e3", "2e-3", 0, RoundingMode::Exact, "500000");
+}
+
+#[test]
+#[should_panic]
+fn div_round_to_scale_fail_1() {
+    Decimal::from_str("1")
+        .unwrap()
+        .div_round_to_scale(&Decimal::ZERO, 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_round_to_scale_fail_2() {
+    Decimal::from_str("1").unwrap().div_round_to_scale(
+        &Decimal::from_str("3").unwrap(),
+        10,
+        RoundingMode::Exact,
+    );
+}
+
+#[test]
+fn div_round_to_scale_properties() {
+    let scales = [-3i64, 0, 2, 5];
+    let rms = [
+        RoundingMode::Down,
+        RoundingMode::Up,
+        RoundingMode::Floor,
+        RoundingMode::Ceiling,
+        RoundingMode::Nearest,
+    ];
+    integer_pair_gen_var_1().test_properties(|(c, d)| {
+        for &s in &scales {
+            for &t in &scales {
+                let x = Decimal::from_coefficient_and_scale(c.clone(), s);
+                let y = Decimal::from_coefficient_and_scale(d.clone(), t);
+                for &scale in &scales {
+                    for &rm in &rms {
+                        let q = x.div_round_to_scale(&y, scale, rm);
+                        assert_eq!(q.scale(), scale);
+                        assert_eq!(
+                            q,
+                            Decimal::rounding_from_rational(
+                                &(Rational::from(&x) / Rational::from(&y)),
+                                scale,
+                                rm
+                            )
+                        );
+                    }
+                }
+                // Exact division by the divisor recovers the dividend
+                let product = &x * &y;
+                assert_eq!(product.div_round_to_scale(&y, s, RoundingMode::Exact), x);
+            }
+        }
+        assert_eq!(
+            Decimal::from(&c)
+                .div_round_to_scale(&Decimal::from(&d), 0, RoundingMode::Floor)
+                .into_coefficient_and_scale()
+                .0,
+            (&c).div_round(&d, RoundingMode::Floor)
+        );
+    });
+}
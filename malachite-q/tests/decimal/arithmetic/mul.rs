@@ -0,0 +1,73 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::test_util::generators::{integer_pair_gen, integer_signed_pair_gen};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mul() {
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() * v.clone()).to_string(), out);
+        assert_eq!((u.clone() * &v).to_string(), out);
+        assert_eq!((&u * v.clone()).to_string(), out);
+        assert_eq!((&u * &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "1.5", "0.0");
+    test("1", "1.5", "1.5");
+    test("1.25", "-0.4", "-0.500");
+    test("-1.25", "-0.4", "0.500");
+    test("12e3", "0.5", "6e2");
+    test("12e3", "5e-2", "6e2");
+    test("0.001", "0.001", "0.000001");
+    test(
+        "123456789.123456789",
+        "987654321.987654321",
+        "121932631356500531.347203169112635269",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = Decimal::from_coefficient_and_scale(malachite_nz::integer::Integer::ONE, i64::MAX)
+        * Decimal::from_str("0.1").unwrap();
+}
+
+#[test]
+fn mul_properties() {
+    let scales = [-3i64, 0, 2, 5];
+    integer_pair_gen().test_properties(|(c, d)| {
+        for &s in &scales {
+            for &t in &scales {
+                let x = Decimal::from_coefficient_and_scale(c.clone(), s);
+                let y = Decimal::from_coefficient_and_scale(d.clone(), t);
+                let product = &x * &y;
+                assert_eq!(product.scale(), s + t);
+                assert_eq!(
+                    Rational::from(&product),
+                    Rational::from(&x) * Rational::from(&y)
+                );
+                assert_eq!(&y * &x, product);
+            }
+        }
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        assert_eq!(&x * Decimal::ONE, x);
+        assert_eq!(&x * Decimal::ZERO, Decimal::ZERO);
+        assert_eq!(&x * -Decimal::ONE, -&x);
+    });
+}
@@ -0,0 +1,85 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_rescale() {
+    let test = |s, scale, rm, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let r = u.rescale(scale, rm);
+        assert_eq!(r.to_string(), out);
+        assert_eq!(r.scale(), scale);
+
+        let mut r = u;
+        r.rescale_assign(scale, rm);
+        assert_eq!(r.to_string(), out);
+    };
+    test("0", 3, RoundingMode::Exact, "0.000");
+    test("0.000", 0, RoundingMode::Exact, "0");
+    test("123.455", 5, RoundingMode::Exact, "123.45500");
+    test("123.455", 2, RoundingMode::Nearest, "123.46");
+    test("123.445", 2, RoundingMode::Nearest, "123.44");
+    test("123.455", 2, RoundingMode::Down, "123.45");
+    test("123.455", 2, RoundingMode::Up, "123.46");
+    test("-123.455", 2, RoundingMode::Floor, "-123.46");
+    test("-123.455", 2, RoundingMode::Ceiling, "-123.45");
+    test("123.455", -2, RoundingMode::Nearest, "1e2");
+    test("12e3", 1, RoundingMode::Exact, "12000.0");
+}
+
+#[test]
+#[should_panic]
+fn rescale_fail() {
+    Decimal::from_str("1.25")
+        .unwrap()
+        .rescale(1, RoundingMode::Exact);
+}
+
+#[test]
+fn test_normalize() {
+    let test = |s, out| {
+        let u = Decimal::from_str(s).unwrap();
+        assert_eq!(u.normalize().to_string(), out);
+
+        let mut n = u;
+        n.normalize_assign();
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0");
+    test("0.000", "0");
+    test("-0e5", "0");
+    test("1.50", "1.5");
+    test("1200", "12e2");
+    test("-1.2e5", "-12e4");
+    test("0.001", "0.001");
+}
+
+#[test]
+fn rescale_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        for scale in [-5i64, 0, 3, 20] {
+            let r = x.rescale(scale, RoundingMode::Floor);
+            assert!(r <= x);
+            assert!(x < &r + Decimal::from_coefficient_and_scale(1.into(), scale));
+            assert_eq!(
+                r,
+                Decimal::rounding_from_rational(&Rational::from(&x), scale, RoundingMode::Floor)
+            );
+            if scale >= x.scale() {
+                assert_eq!(x.rescale(scale, RoundingMode::Exact), x);
+            }
+        }
+
+        let n = x.normalize();
+        assert_eq!(n, x);
+        assert!(n.scale() <= x.scale() || *x.coefficient_ref() == 0);
+        assert_eq!(n.normalize().to_string(), n.to_string());
+        assert_eq!(
+            n.rescale(x.scale(), RoundingMode::Exact).to_string(),
+            x.to_string()
+        );
+    });
+}
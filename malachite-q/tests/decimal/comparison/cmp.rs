@@ -0,0 +1,108 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::hash::hash;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen, integer_signed_pair_gen};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_cmp() {
+    let strings =
+        vec!["-12e3", "-1.5", "-1", "-0.001", "0", "0.001", "0.1", "0.25", "1.5", "2", "12e3"];
+    let xs: Vec<Decimal> = strings
+        .iter()
+        .map(|s| Decimal::from_str(s).unwrap())
+        .collect();
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in xs.iter().enumerate() {
+            assert_eq!(x.cmp(y), i.cmp(&j));
+            assert_eq!(x.partial_cmp(y), Some(i.cmp(&j)));
+            assert_eq!(x == y, i == j);
+        }
+    }
+
+    let test = |s, t| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+        assert_eq!(u, v);
+        assert_eq!(u.cmp(&v), Ordering::Equal);
+        assert_eq!(hash(&u), hash(&v));
+    };
+    test("0", "0.000");
+    test("0", "0e5");
+    test("1.5", "1.50");
+    test("-1.5", "-1.500");
+    test("12e3", "12000");
+    test("12e3", "12000.0");
+
+    // Scales that are far apart are compared without rescaling
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+        assert_eq!(u.cmp(&v), out);
+        assert_eq!(v.cmp(&u), out.reverse());
+        assert_eq!(u == v, out == Ordering::Equal);
+    };
+    test("1e-9000000000000000000", "1", Ordering::Less);
+    test("-1e-9000000000000000000", "-1", Ordering::Greater);
+    test("1e9000000000000000000", "123456789", Ordering::Greater);
+    test("-1e9000000000000000000", "-123456789", Ordering::Less);
+    test(
+        "1e-9000000000000000000",
+        "1e-8999999999999999999",
+        Ordering::Less,
+    );
+    test(
+        "99e-9000000000000000000",
+        "1e-8999999999999999998",
+        Ordering::Less,
+    );
+    test(
+        "10e-9000000000000000000",
+        "1.0e-8999999999999999999",
+        Ordering::Equal,
+    );
+
+    let x = Decimal::from_coefficient_and_scale(Integer::from(10), i64::MAX);
+    let y = Decimal::from_coefficient_and_scale(Integer::ONE, i64::MAX - 1);
+    assert_eq!(x, y);
+    assert_eq!(hash(&x), hash(&y));
+    let y = Decimal::from_coefficient_and_scale(Integer::ONE, i64::MIN);
+    assert!(x < y);
+    assert!(-&y < -&x);
+}
+
+#[test]
+fn cmp_properties() {
+    let scales = [-3i64, 0, 2, 5];
+    integer_pair_gen().test_properties(|(c, d)| {
+        for &s in &scales {
+            for &t in &scales {
+                let x = Decimal::from_coefficient_and_scale(c.clone(), s);
+                let y = Decimal::from_coefficient_and_scale(d.clone(), t);
+                let ord = x.cmp(&y);
+                assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), ord);
+                assert_eq!(y.cmp(&x).reverse(), ord);
+                assert_eq!(x == y, ord == Ordering::Equal);
+                assert_eq!((-&y).cmp(&-&x), ord);
+                if x == y {
+                    assert_eq!(hash(&x), hash(&y));
+                }
+            }
+        }
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        assert_eq!(x.cmp(&x), Ordering::Equal);
+        for scale in [-2i64, 0, 3] {
+            if scale >= x.scale() {
+                let y = x.rescale(scale, malachite_base::rounding_modes::RoundingMode::Exact);
+                assert_eq!(x, y);
+                assert_eq!(hash(&x), hash(&y));
+            }
+        }
+    });
+}
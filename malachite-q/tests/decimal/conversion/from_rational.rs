@@ -0,0 +1,94 @@
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::decimal::conversion::from_rational::DecimalFromRationalError;
+use malachite_q::decimal::Decimal;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s, out: Option<&str>| {
+        let x = Rational::from_str(s).unwrap();
+        let on = Decimal::try_from(x.clone());
+        assert_eq!(on.as_ref().ok().map(ToString::to_string).as_deref(), out);
+        assert_eq!(on.is_err(), out.is_none());
+        if on.is_err() {
+            assert_eq!(on, Err(DecimalFromRationalError));
+        }
+
+        let on = Decimal::try_from(&x);
+        assert_eq!(on.as_ref().ok().map(ToString::to_string).as_deref(), out);
+        assert_eq!(Decimal::convertible_from(&x), out.is_some());
+    };
+    test("0", Some("0"));
+    test("123", Some("123"));
+    test("-123", Some("-123"));
+    test("1/2", Some("0.5"));
+    test("-5/4", Some("-1.25"));
+    test("7/50", Some("0.14"));
+    test("1/1024", Some("0.0009765625"));
+    test("1/3", None);
+    test("-22/7", None);
+}
+
+#[test]
+fn test_rounding_from_rational() {
+    let test = |s, scale, rm, out| {
+        let x = Rational::from_str(s).unwrap();
+        let d = Decimal::rounding_from_rational(&x, scale, rm);
+        assert_eq!(d.scale(), scale);
+        assert_eq!(d.to_string(), out);
+    };
+    test("0", 3, RoundingMode::Exact, "0.000");
+    test("2/3", 3, RoundingMode::Floor, "0.666");
+    test("2/3", 3, RoundingMode::Ceiling, "0.667");
+    test("2/3", 3, RoundingMode::Nearest, "0.667");
+    test("-2/3", 3, RoundingMode::Down, "-0.666");
+    test("-2/3", 3, RoundingMode::Up, "-0.667");
+    test("1/8", 2, RoundingMode::Nearest, "0.12");
+    test("1/8", 5, RoundingMode::Exact, "0.12500");
+    test("1234", -2, RoundingMode::Down, "12e2");
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_rational_fail() {
+    Decimal::rounding_from_rational(&Rational::from_signeds(1, 3), 10, RoundingMode::Exact);
+}
+
+#[test]
+fn try_from_rational_properties() {
+    rational_gen().test_properties(|x| {
+        let od = Decimal::try_from(&x);
+        assert_eq!(Decimal::try_from(x.clone()), od);
+        assert_eq!(Decimal::convertible_from(&x), od.is_ok());
+        if let Ok(d) = od {
+            assert!(d.scale() >= 0);
+            assert_eq!(Rational::from(&d), x);
+            assert_eq!(Rational::from(d.clone()), x);
+            if d.scale() > 0 {
+                assert_eq!(d.normalize().scale(), d.scale());
+            }
+        }
+
+        for scale in [-2i64, 0, 5] {
+            let floor = Decimal::rounding_from_rational(&x, scale, RoundingMode::Floor);
+            let ceiling = Decimal::rounding_from_rational(&x, scale, RoundingMode::Ceiling);
+            assert!(Rational::from(&floor) <= x);
+            assert!(Rational::from(&ceiling) >= x);
+            assert!(&ceiling - &floor <= Decimal::from_coefficient_and_scale(1.into(), scale));
+        }
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let d = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        let x = Rational::from(&d);
+        assert_eq!(Decimal::try_from(&x).unwrap(), d);
+        assert_eq!(
+            Decimal::rounding_from_rational(&x, d.scale(), RoundingMode::Exact).to_string(),
+            d.to_string()
+        );
+    });
+}
@@ -0,0 +1,126 @@
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::FromSciString;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test = |s, out: Result<&str, ParseNumberError>| {
+        assert_eq!(
+            Decimal::from_str(s).map(|x| x.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test("0", Ok("0"));
+    test("0.00", Ok("0.00"));
+    test("-0", Ok("0"));
+    test("+5", Ok("5"));
+    test("123", Ok("123"));
+    test("-123.450", Ok("-123.450"));
+    test("1.", Ok("1"));
+    test(".5", Ok("0.5"));
+    test("1.2e5", Ok("12e4"));
+    test("1.2e-5", Ok("0.000012"));
+    test("1.2E+5", Ok("12e4"));
+    test("-5.0e-3", Ok("-0.0050"));
+    test(
+        "",
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0)),
+    );
+    test(
+        "-",
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 1)),
+    );
+    test(
+        ".",
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 0)),
+    );
+    test(
+        "abc",
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 0)),
+    );
+    test(
+        "e5",
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 0)),
+    );
+    test(
+        "1.2.3",
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3)),
+    );
+    test(
+        "--1",
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1)),
+    );
+    test(
+        "1e",
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 2)),
+    );
+    test(
+        "1e-",
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 3)),
+    );
+    test(
+        "1e5.5",
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 3)),
+    );
+    test(
+        "-1e99999999999999999999",
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 1)),
+    );
+    test(
+        "0.1e-9223372036854775807",
+        Err(ParseNumberError::new(ParseNumberErrorKind::OutOfRange, 0)),
+    );
+}
+
+#[test]
+fn test_from_sci_string() {
+    let test = |s, out: Option<&str>| {
+        assert_eq!(
+            Decimal::from_sci_string(s)
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test("123", Some("123"));
+    test("0.10", Some("0.10"));
+    test("1.2e5", Some("12e4"));
+    test("1.2e-5", Some("0.000012"));
+    test("1.2.3", None);
+
+    let test_with_options = |s, base, out: Option<&str>| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        assert_eq!(
+            Decimal::from_sci_string_with_options(s, options)
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test_with_options("ff", 16, Some("255"));
+    test_with_options("0.8", 16, Some("0.5"));
+    test_with_options("0.1", 2, Some("0.5"));
+    test_with_options("-0.01", 2, Some("-0.25"));
+    test_with_options("0.1", 3, None);
+}
+
+#[test]
+fn from_sci_string_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        let y = Decimal::from_sci_string(&x.to_string()).unwrap();
+        assert_eq!(y.coefficient_ref(), x.coefficient_ref());
+        assert_eq!(y.scale(), x.scale());
+        assert_eq!(
+            Decimal::try_from(Rational::from_sci_string(&x.to_string()).unwrap()).unwrap(),
+            x
+        );
+    });
+}
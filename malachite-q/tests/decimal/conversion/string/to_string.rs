@@ -0,0 +1,47 @@
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    let test = |c: &str, s: i64, out| {
+        let x = Decimal::from_coefficient_and_scale(Integer::from_str(c).unwrap(), s);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", 0, "0");
+    test("0", 2, "0.00");
+    test("0", -1, "0e1");
+    test("123", 0, "123");
+    test("-123", 0, "-123");
+    test("12345", 2, "123.45");
+    test("-5", 2, "-0.05");
+    test("-5", 3, "-0.005");
+    test("5", -2, "5e2");
+    test("-5", -2, "-5e2");
+    test("1000", 3, "1.000");
+    test("1000000000000000000000", 20, "10.00000000000000000000");
+}
+
+#[test]
+fn to_string_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(c, s)| {
+        let x = Decimal::from_coefficient_and_scale(c, i64::from(s));
+        let string = x.to_string();
+        assert_eq!(x.to_debug_string(), string);
+        let y = Decimal::from_str(&string).unwrap();
+        assert_eq!(y.coefficient_ref(), x.coefficient_ref());
+        assert_eq!(y.scale(), x.scale());
+        if x.scale() >= 0 {
+            assert!(!string.contains('e'));
+            assert_eq!(
+                string.find('.').map_or(0, |i| string.len() - i - 1),
+                usize::try_from(x.scale()).unwrap()
+            );
+        }
+        assert_eq!(Rational::from(&x), Rational::from(&y));
+    });
+}
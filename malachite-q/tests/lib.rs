@@ -49,6 +49,7 @@ pub mod arithmetic {
     pub mod abs;
     pub mod add;
     pub mod approximate;
+    pub mod binary_splitting;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
@@ -57,12 +58,14 @@ pub mod arithmetic {
     pub mod log_base;
     pub mod log_base_2;
     pub mod log_base_power_of_2;
+    pub mod mediant;
     pub mod mul;
     pub mod neg;
     pub mod next_power_of_2;
     pub mod pow;
     pub mod power_of_2;
     pub mod reciprocal;
+    pub mod rem;
     pub mod root;
     pub mod round_to_multiple;
     pub mod round_to_multiple_of_power_of_2;
@@ -103,6 +106,7 @@ pub mod comparison {
     pub mod partial_eq_primitive_int;
 }
 pub mod conversion {
+    pub mod cbor;
     pub mod clone;
     pub mod continued_fraction {
         pub mod convergents;
@@ -127,17 +131,39 @@ pub mod conversion {
     pub mod integer_from_rational;
     pub mod is_integer;
     pub mod natural_from_rational;
+    #[cfg(feature = "num-bigint")]
+    pub mod num_bigint;
     pub mod primitive_float_from_rational;
     pub mod primitive_int_from_rational;
     pub mod sci_mantissa_and_exponent;
     pub mod serde;
     pub mod string {
+        pub mod from_repeating_string;
         pub mod from_sci_string;
         pub mod from_string;
+        pub mod to_repeating_string;
         pub mod to_sci;
         pub mod to_string;
     }
 }
+pub mod decimal {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod div;
+        pub mod mul;
+        pub mod rescale;
+    }
+    pub mod comparison {
+        pub mod cmp;
+    }
+    pub mod conversion {
+        pub mod from_rational;
+        pub mod string {
+            pub mod from_sci_string;
+            pub mod to_string;
+        }
+    }
+}
 pub mod exhaustive {
     pub mod exhaustive_negative_rationals;
     pub mod exhaustive_non_negative_rationals;
@@ -149,6 +175,22 @@ pub mod exhaustive {
     pub mod exhaustive_rationals_with_denominator_range_to_infinity;
     pub mod exhaustive_rationals_with_denominator_range_to_negative_infinity;
 }
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
+pub mod quadratic_irrational {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod mul;
+    }
+    pub mod comparison {
+        pub mod cmp;
+    }
+    pub mod conversion {
+        pub mod continued_fraction;
+        pub mod to_string;
+    }
+    pub mod pell;
+}
 pub mod random {
     pub mod random_negative_rationals;
     pub mod random_non_negative_rationals;
@@ -165,3 +207,23 @@ pub mod random {
     pub mod striped_random_positive_rationals;
     pub mod striped_random_rationals;
 }
+pub mod stern_brocot {
+    pub mod calkin_wilf;
+    pub mod farey;
+    pub mod path;
+}
+pub mod unreduced {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod div;
+        pub mod mul;
+        pub mod neg;
+        pub mod sub;
+    }
+    pub mod comparison {
+        pub mod cmp;
+    }
+    pub mod conversion {
+        pub mod from_rational;
+    }
+}
@@ -0,0 +1,157 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::string::from_string::{
+    ParseNumberError, ParseNumberErrorKind,
+};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_pair_gen, rational_signed_pair_gen_var_4,
+};
+use malachite_q::Rational;
+use num::traits::{CheckedDiv, FromPrimitive, Num, Signed, ToPrimitive};
+use num::BigRational;
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Result<&str, ParseNumberError>| {
+        let result = <Rational as Num>::from_str_radix(s, radix).map(|q| {
+            assert!(q.is_valid());
+            q.to_string()
+        });
+        assert_eq!(result, out.map(ToString::to_string));
+    };
+    test("0", 10, Ok("0"));
+    test("-0/5", 10, Ok("0"));
+    test("+22/7", 10, Ok("22/7"));
+    test("-6/4", 10, Ok("-3/2"));
+    test("1_000/3", 10, Ok("1000/3"));
+    test("-ff/10", 16, Ok("-255/16"));
+    test("101/11", 2, Ok("5/3"));
+    test(
+        "1/0",
+        10,
+        Err(ParseNumberError::new(
+            ParseNumberErrorKind::ZeroDenominator,
+            2,
+        )),
+    );
+    test(
+        "1/",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::Empty, 2)),
+    );
+    test(
+        "1.5",
+        10,
+        Err(ParseNumberError::new(ParseNumberErrorKind::InvalidDigit, 1)),
+    );
+}
+
+#[test]
+fn test_signed() {
+    let test = |s: &str, abs: &str, signum: i32| {
+        let x: Rational = s.parse().unwrap();
+        assert_eq!(Signed::abs(&x).to_string(), abs);
+        assert_eq!(x.signum(), signum);
+        assert_eq!(x.is_positive(), signum == 1);
+        assert_eq!(x.is_negative(), signum == -1);
+    };
+    test("0", "0", 0);
+    test("22/7", "22/7", 1);
+    test("-22/7", "22/7", -1);
+
+    assert_eq!(
+        Rational::from_signeds(1, 2)
+            .abs_sub(&Rational::from_signeds(-1, 3))
+            .to_string(),
+        "5/6"
+    );
+    assert_eq!(
+        Rational::from_signeds(-1, 3).abs_sub(&Rational::from_signeds(1, 2)),
+        0
+    );
+}
+
+#[test]
+fn test_to_and_from_primitive() {
+    assert_eq!(Rational::from_signeds(-22, 7).to_i8(), Some(-3));
+    assert_eq!(Rational::from_signeds(22, 7).to_u8(), Some(3));
+    assert_eq!(Rational::from_signeds(-22, 7).to_u64(), None);
+    assert_eq!(Rational::from_signeds(-1, 2).to_u64(), Some(0));
+    assert_eq!(Rational::from(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(Rational::from(u128::MAX).to_i128(), None);
+    assert_eq!(Rational::from_signeds(1, 3).to_f32(), Some(1.0 / 3.0));
+    assert_eq!(Rational::from_signeds(-1, 3).to_f64(), Some(-1.0 / 3.0));
+
+    assert_eq!(Rational::from_i64(-5), Some(Rational::from(-5)));
+    assert_eq!(
+        Rational::from_u128(u128::MAX),
+        Some(Rational::from(u128::MAX))
+    );
+    assert_eq!(Rational::from_f32(0.5), Some(Rational::from_signeds(1, 2)));
+    assert_eq!(
+        Rational::from_f64(-0.75),
+        Some(Rational::from_signeds(-3, 4))
+    );
+    assert_eq!(Rational::from_f64(f64::NAN), None);
+    assert_eq!(Rational::from_f64(f64::INFINITY), None);
+}
+
+#[test]
+fn test_pow() {
+    let test = |s: &str, exp: i32, out| {
+        let x: Rational = s.parse().unwrap();
+        assert_eq!(num::traits::Pow::pow(&x, exp).to_string(), out);
+        assert_eq!(num::traits::Pow::pow(x, exp).to_string(), out);
+    };
+    test("2/3", 0, "1");
+    test("2/3", 3, "8/27");
+    test("-2/3", 3, "-8/27");
+    test("-2/3", -3, "-27/8");
+    test("1/10", -2, "100");
+}
+
+#[test]
+fn num_traits_properties() {
+    rational_gen().test_properties(|x| {
+        let num_x = BigRational::from(&x);
+        assert_eq!(
+            <Rational as Num>::from_str_radix(&x.to_string(), 10).unwrap(),
+            x
+        );
+        assert_eq!(Rational::from(Signed::abs(&num_x)), Signed::abs(&x));
+        assert_eq!(Rational::from(num_x.signum()), x.signum());
+        assert_eq!(
+            x.to_i64(),
+            i64::try_from(&Integer::rounding_from(&x, RoundingMode::Down)).ok()
+        );
+        assert_eq!(x.to_i64(), num_x.to_i64());
+        assert_eq!(x.to_u64(), num_x.to_u64());
+        let f = x.to_f64().unwrap();
+        assert_eq!(f, f64::rounding_from(&x, RoundingMode::Nearest));
+        assert_eq!(Rational::from_f64(f), Rational::try_from(f).ok());
+        assert_eq!(num::Zero::is_zero(&x), x == 0u32);
+        assert_eq!(num::One::is_one(&x), x == 1u32);
+    });
+
+    rational_pair_gen().test_properties(|(x, y)| {
+        let num_x = BigRational::from(&x);
+        let num_y = BigRational::from(&y);
+        assert_eq!(Rational::from(num_x.abs_sub(&num_y)), x.abs_sub(&y));
+        assert_eq!(
+            num_x.checked_div(&num_y).map(Rational::from),
+            x.checked_div(&y)
+        );
+    });
+
+    rational_signed_pair_gen_var_4::<i32>().test_properties(|(x, exp)| {
+        assert_eq!(
+            Rational::from(num::traits::Pow::pow(BigRational::from(&x), exp)),
+            num::traits::Pow::pow(&x, exp)
+        );
+    });
+
+    assert_eq!(<Rational as num::Zero>::zero(), Rational::ZERO);
+    assert_eq!(<Rational as num::One>::one(), Rational::ONE);
+}
@@ -0,0 +1,87 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::quadratic_irrational::QuadraticIrrational;
+
+fn new(a: i32, b: i32, c: i32, d: u32) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Integer::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_add() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out| {
+        let mut n = x.clone();
+        n += y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = x.clone();
+        n += &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((x.clone() + y.clone()).to_string(), out);
+        assert_eq!((x.clone() + &y).to_string(), out);
+        assert_eq!((&x + y.clone()).to_string(), out);
+        assert_eq!((&x + &y).to_string(), out);
+    };
+    test(QuadraticIrrational::ZERO, new(0, 1, 1, 2), "sqrt(2)");
+    test(new(1, 1, 2, 5), new(1, -1, 2, 5), "1");
+    test(new(0, 1, 1, 2), new(0, 1, 1, 2), "2*sqrt(2)");
+    test(new(0, 1, 1, 2), new(0, 1, 1, 8), "3*sqrt(2)");
+    test(new(1, 0, 3, 7), new(0, 1, 2, 7), "(2+3*sqrt(7))/6");
+    test(new(0, 1, 1, 3), new(0, -1, 1, 3), "0");
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    new(0, 1, 1, 2) + new(0, 1, 1, 3);
+}
+
+#[test]
+fn test_sub() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out| {
+        let mut n = x.clone();
+        n -= y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = x.clone();
+        n -= &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((x.clone() - y.clone()).to_string(), out);
+        assert_eq!((x.clone() - &y).to_string(), out);
+        assert_eq!((&x - y.clone()).to_string(), out);
+        assert_eq!((&x - &y).to_string(), out);
+    };
+    test(QuadraticIrrational::ZERO, new(0, 1, 1, 2), "-sqrt(2)");
+    test(new(1, 1, 2, 5), new(1, -1, 2, 5), "sqrt(5)");
+    test(new(0, 1, 1, 2), new(0, 1, 1, 2), "0");
+    test(new(3, 0, 4, 2), new(0, 1, 1, 2), "(3-4*sqrt(2))/4");
+}
+
+#[test]
+fn add_properties() {
+    signed_quadruple_gen::<i8>().test_properties(|(a, b, c, e)| {
+        if c == 0 || e == 0 {
+            return;
+        }
+        for d in [2u32, 3, 5] {
+            let x = new(i32::from(a), i32::from(b), i32::from(c), d);
+            let y = new(i32::from(e), i32::from(c), i32::from(e), d);
+            let sum = &x + &y;
+            assert_eq!(&y + &x, sum);
+            assert_eq!(&sum - &y, x);
+            assert_eq!(&x - &y, -(&y - &x));
+            assert_eq!(sum.trace(), x.trace() + y.trace());
+            assert_eq!(&x - &x, QuadraticIrrational::ZERO);
+            assert_eq!(&x + QuadraticIrrational::ZERO, x);
+            assert_eq!(&x + -&x, QuadraticIrrational::ZERO);
+        }
+    });
+}
@@ -0,0 +1,104 @@
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::quadratic_irrational::QuadraticIrrational;
+
+fn new(a: i32, b: i32, c: i32, d: u32) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Integer::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_mul() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out| {
+        let mut n = x.clone();
+        n *= y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = x.clone();
+        n *= &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((x.clone() * y.clone()).to_string(), out);
+        assert_eq!((x.clone() * &y).to_string(), out);
+        assert_eq!((&x * y.clone()).to_string(), out);
+        assert_eq!((&x * &y).to_string(), out);
+    };
+    test(QuadraticIrrational::ZERO, new(0, 1, 1, 2), "0");
+    test(new(0, 1, 1, 2), new(0, 1, 1, 2), "2");
+    test(new(1, 1, 2, 5), new(1, -1, 2, 5), "-1");
+    test(new(1, 1, 2, 5), new(1, 1, 2, 5), "(3+sqrt(5))/2");
+    test(new(0, 1, 1, 2), new(0, 1, 1, 8), "4");
+    test(new(2, 1, 3, 3), new(-1, 1, 1, 3), "(1+sqrt(3))/3");
+}
+
+#[test]
+fn test_div() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out| {
+        let mut n = x.clone();
+        n /= y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = x.clone();
+        n /= &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((x.clone() / y.clone()).to_string(), out);
+        assert_eq!((x.clone() / &y).to_string(), out);
+        assert_eq!((&x / y.clone()).to_string(), out);
+        assert_eq!((&x / &y).to_string(), out);
+    };
+    test(QuadraticIrrational::ONE, new(0, 1, 1, 2), "sqrt(2)/2");
+    test(QuadraticIrrational::ONE, new(1, 1, 1, 2), "-1+sqrt(2)");
+    test(new(1, 1, 2, 5), new(1, -1, 2, 5), "(-3-sqrt(5))/2");
+    test(new(0, 1, 1, 8), new(0, 1, 1, 2), "2");
+}
+
+#[test]
+#[should_panic]
+fn div_fail() {
+    new(0, 1, 1, 2) / QuadraticIrrational::ZERO;
+}
+
+#[test]
+fn test_reciprocal() {
+    let test = |x: QuadraticIrrational, out| {
+        assert_eq!(x.clone().reciprocal().to_string(), out);
+        assert_eq!((&x).reciprocal().to_string(), out);
+    };
+    test(new(0, 1, 1, 2), "sqrt(2)/2");
+    test(new(1, 1, 2, 5), "(-1+sqrt(5))/2");
+    test(new(-3, 0, 4, 2), "-4/3");
+    test(new(2, -1, 1, 3), "2+sqrt(3)");
+}
+
+#[test]
+fn mul_properties() {
+    signed_quadruple_gen::<i8>().test_properties(|(a, b, c, e)| {
+        if c == 0 || e == 0 {
+            return;
+        }
+        for d in [2u32, 3, 5] {
+            let x = new(i32::from(a), i32::from(b), i32::from(c), d);
+            let y = new(i32::from(e), i32::from(c), i32::from(e), d);
+            let product = &x * &y;
+            assert_eq!(&y * &x, product);
+            assert_eq!(product.norm(), x.norm() * y.norm());
+            assert_eq!(&product / &y, x);
+            assert_eq!((&y).reciprocal().reciprocal(), y);
+            assert_eq!(&y * (&y).reciprocal(), QuadraticIrrational::ONE);
+            assert_eq!(&x * QuadraticIrrational::ONE, x);
+            assert_eq!(&x * QuadraticIrrational::ZERO, QuadraticIrrational::ZERO);
+            assert_eq!(
+                &x * x.conjugate(),
+                QuadraticIrrational::from_rational(x.norm(), x.radicand_ref().clone())
+            );
+        }
+    });
+}
@@ -0,0 +1,135 @@
+use malachite_base::num::arithmetic::traits::{Ceiling, Floor};
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::quadratic_irrational::QuadraticIrrational;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+fn new(a: i32, b: i32, c: i32, d: u32) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Integer::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_cmp() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out: Ordering| {
+        assert_eq!(x.cmp(&y), out);
+        assert_eq!(y.cmp(&x), out.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(x == y, out == Ordering::Equal);
+    };
+    test(new(0, 1, 1, 2), new(0, 1, 1, 2), Ordering::Equal);
+    test(new(0, 1, 1, 8), new(0, 2, 1, 2), Ordering::Equal);
+    test(new(0, 1, 1, 2), new(3, 0, 2, 2), Ordering::Less);
+    test(new(0, 1, 1, 2), new(7, 0, 5, 3), Ordering::Greater);
+    test(new(1, 1, 2, 5), new(1, -1, 2, 5), Ordering::Greater);
+    test(new(-1, 0, 1, 2), new(0, -1, 1, 2), Ordering::Greater);
+    // Radicands that don't differ by a square factor
+    test(new(0, 1, 1, 2), new(0, 1, 1, 3), Ordering::Less);
+    test(new(1, 1, 2, 5), new(0, 1, 1, 3), Ordering::Less);
+    test(new(1, 1, 2, 5), new(0, 1, 1, 2), Ordering::Greater);
+    test(new(0, -1, 1, 2), new(0, -1, 1, 3), Ordering::Greater);
+    test(new(0, 1, 1, 10), new(0, 1, 1, 3), Ordering::Greater);
+    test(new(-1, 1, 1, 10), new(0, 1, 1, 2), Ordering::Greater);
+    test(new(-3, 1, 1, 10), new(0, 1, 1, 3), Ordering::Less);
+    // (1 + 28 * sqrt(3)) / 35 = 1.4142120... and sqrt(2) = 1.4142135...
+    test(new(1, 28, 35, 3), new(0, 1, 1, 2), Ordering::Less);
+    test(new(-1, -28, 35, 3), new(0, -1, 1, 2), Ordering::Greater);
+    // (-9 + 35 * sqrt(5)) / 38 = 1.8226...; (1 + sqrt(7)) / 2 = 1.8228...
+    test(new(-9, 35, 38, 5), new(1, 1, 2, 7), Ordering::Less);
+}
+
+#[test]
+fn test_partial_cmp_rational() {
+    let test = |x: QuadraticIrrational, y: Rational, out| {
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(y.partial_cmp(&x), Some(out.reverse()));
+        assert_eq!(x == y, out == Ordering::Equal);
+        assert_eq!(y == x, out == Ordering::Equal);
+    };
+    test(
+        new(0, 1, 1, 2),
+        Rational::from_signeds(99, 70),
+        Ordering::Less,
+    );
+    test(
+        new(0, 1, 1, 2),
+        Rational::from_signeds(140, 99),
+        Ordering::Greater,
+    );
+    test(
+        new(-3, 0, 4, 7),
+        Rational::from_signeds(-3, 4),
+        Ordering::Equal,
+    );
+    test(new(1, -1, 2, 5), Rational::from(0u32), Ordering::Less);
+}
+
+#[test]
+fn test_floor_and_ceiling() {
+    let test = |x: QuadraticIrrational, floor: i32, ceiling: i32| {
+        assert_eq!((&x).floor(), floor);
+        assert_eq!((&x).ceiling(), ceiling);
+        assert_eq!(x.clone().floor(), floor);
+        assert_eq!(x.ceiling(), ceiling);
+    };
+    test(new(0, 1, 1, 2), 1, 2);
+    test(new(0, -1, 1, 2), -2, -1);
+    test(new(1, 1, 2, 5), 1, 2);
+    test(new(1, -1, 2, 5), -1, 0);
+    test(new(7, 0, 2, 3), 3, 4);
+    test(new(-6, 0, 2, 3), -3, -3);
+}
+
+#[test]
+fn cmp_properties() {
+    signed_quadruple_gen::<i8>().test_properties(|(a, b, c, e)| {
+        if c == 0 || e == 0 {
+            return;
+        }
+        for d in [2u32, 3, 5] {
+            let x = new(i32::from(a), i32::from(b), i32::from(c), d);
+            let y = new(i32::from(e), i32::from(c), i32::from(e), d);
+            let ord = x.cmp(&y);
+            assert_eq!(x.partial_cmp(&y), Some(ord));
+            assert_eq!(y.cmp(&x), ord.reverse());
+            assert_eq!((&x - &y).partial_cmp(&Rational::from(0u32)), Some(ord));
+            assert_eq!((-&x).cmp(&-&y), ord.reverse());
+            let floor = (&x).floor();
+            assert!(Rational::from(&floor) <= x);
+            assert!(Rational::from(floor + Integer::from(1)) > x);
+            assert_eq!((-&x).ceiling(), -(&x).floor());
+        }
+    });
+
+    signed_quadruple_gen::<i8>().test_properties(|(a, b, c, e)| {
+        if c == 0 || e == 0 {
+            return;
+        }
+        for (d, d_2) in [(2u32, 3u32), (2, 5), (3, 10), (6, 7)] {
+            let x = new(i32::from(a), i32::from(b), i32::from(c), d);
+            let y = new(i32::from(e), i32::from(c), i32::from(e), d_2);
+            let ord = x.cmp(&y);
+            assert_eq!(y.cmp(&x), ord.reverse());
+            assert_eq!((-&x).cmp(&-&y), ord.reverse());
+            // `y` is irrational, and its radicand doesn't differ from `x`'s by a square factor
+            assert_ne!(ord, Ordering::Equal);
+            assert_ne!(x, y);
+            let x_floor = (&x).floor();
+            let y_floor = (&y).floor();
+            if x_floor != y_floor {
+                assert_eq!(ord, x_floor.cmp(&y_floor));
+            }
+            let x_ceiling = (&x).ceiling();
+            let y_ceiling = (&y).ceiling();
+            if x_ceiling != y_ceiling {
+                assert_eq!(ord, x_ceiling.cmp(&y_ceiling));
+            }
+        }
+    });
+}
@@ -0,0 +1,153 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, Floor};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rational_sequences::RationalSequence;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::conversion::traits::{ContinuedFraction, Convergents};
+use malachite_q::quadratic_irrational::QuadraticIrrational;
+use malachite_q::Rational;
+
+fn new(a: i32, b: i32, c: i32, d: u32) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Integer::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_continued_fraction() {
+    let test = |x: QuadraticIrrational, head: i32, tail: &str| {
+        let (h, t) = (&x).continued_fraction();
+        assert_eq!(h, head);
+        assert_eq!(t.take(8).collect_vec().to_debug_string(), tail);
+        let (h, t) = x.continued_fraction();
+        assert_eq!(h, head);
+        assert_eq!(t.take(8).collect_vec().to_debug_string(), tail);
+    };
+    test(new(0, 1, 1, 2), 1, "[2, 2, 2, 2, 2, 2, 2, 2]");
+    test(new(1, 1, 2, 5), 1, "[1, 1, 1, 1, 1, 1, 1, 1]");
+    test(new(0, 1, 1, 7), 2, "[1, 1, 1, 4, 1, 1, 1, 4]");
+    test(new(0, -1, 1, 2), -2, "[1, 1, 2, 2, 2, 2, 2, 2]");
+    test(new(4, -2, 6, 3), 0, "[11, 5, 10, 5, 10, 5, 10, 5]");
+    test(new(-22, 0, 7, 2), -4, "[1, 6]");
+}
+
+#[test]
+fn test_to_periodic_continued_fraction() {
+    let test = |x: QuadraticIrrational, head: i32, tail: &str| {
+        let (h, t) = x.to_periodic_continued_fraction();
+        assert_eq!(h, head);
+        assert_eq!(t.to_string(), tail);
+        assert_eq!(
+            QuadraticIrrational::from_periodic_continued_fraction(&h, &t),
+            x
+        );
+    };
+    test(new(0, 1, 1, 2), 1, "[[2]]");
+    test(new(1, 1, 2, 5), 1, "[[1]]");
+    test(new(0, 1, 1, 7), 2, "[[1, 1, 1, 4]]");
+    test(new(0, 1, 1, 19), 4, "[[2, 1, 3, 1, 2, 8]]");
+    test(new(0, -1, 1, 2), -2, "[1, 1, [2]]");
+    test(new(3, 1, 7, 2), 0, "[1, 1, 1, [2]]");
+    test(new(4, -2, 6, 3), 0, "[11, [5, 10]]");
+}
+
+#[test]
+fn test_from_periodic_continued_fraction() {
+    let test = |head: i32, non_repeating: &str, repeating: &str, out| {
+        let xs = RationalSequence::from_vecs(
+            vec_from_str(non_repeating).unwrap(),
+            vec_from_str(repeating).unwrap(),
+        );
+        let x = QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(head), &xs);
+        assert_eq!(x.to_string(), out);
+    };
+    test(1, "[]", "[2]", "sqrt(2)");
+    test(1, "[]", "[1]", "(1+sqrt(5))/2");
+    test(2, "[]", "[1, 1, 1, 4]", "sqrt(7)");
+    test(0, "[1, 1, 1]", "[2]", "(3+sqrt(2))/7");
+    test(3, "[]", "[6]", "sqrt(10)");
+    test(0, "[]", "[2, 2]", "-1+sqrt(2)");
+}
+
+#[test]
+#[should_panic]
+fn from_periodic_continued_fraction_fail_1() {
+    let xs = RationalSequence::from_vec(vec![Natural::from(2u32)]);
+    QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(1), &xs);
+}
+
+#[test]
+#[should_panic]
+fn from_periodic_continued_fraction_fail_2() {
+    let xs = RationalSequence::from_vecs(vec![], vec![Natural::from(0u32)]);
+    QuadraticIrrational::from_periodic_continued_fraction(&Integer::from(1), &xs);
+}
+
+#[test]
+fn test_convergents() {
+    let test = |x: QuadraticIrrational, out: &str| {
+        assert_eq!(
+            (&x).convergents().take(6).collect_vec().to_debug_string(),
+            out
+        );
+        assert_eq!(x.convergents().take(6).collect_vec().to_debug_string(), out);
+    };
+    test(new(0, 1, 1, 2), "[1, 3/2, 7/5, 17/12, 41/29, 99/70]");
+    test(new(1, 1, 2, 5), "[1, 2, 3/2, 5/3, 8/5, 13/8]");
+    test(new(0, 1, 1, 3), "[1, 2, 5/3, 7/4, 19/11, 26/15]");
+    test(new(-22, 0, 7, 2), "[-4, -3, -22/7]");
+}
+
+#[test]
+fn continued_fraction_properties() {
+    signed_quadruple_gen::<i8>().test_properties(|(a, b, c, d)| {
+        if c == 0 {
+            return;
+        }
+        let d = u32::from(d.unsigned_abs()) + 2;
+        if Natural::from(d).checked_sqrt().is_some() {
+            return;
+        }
+        let x = new(i32::from(a), i32::from(b), i32::from(c), d);
+        let (head, tail) = x.to_periodic_continued_fraction();
+        assert_eq!(head, (&x).floor());
+        assert_eq!(tail.is_finite(), x.is_rational());
+        assert_eq!(
+            QuadraticIrrational::from_periodic_continued_fraction(&head, &tail),
+            x
+        );
+        let (head_alt, tail_alt) = (&x).continued_fraction();
+        assert_eq!(head_alt, head);
+        let n = if x.is_rational() {
+            tail.len().unwrap()
+        } else {
+            20
+        };
+        assert!(tail_alt.take(n).eq(tail.iter().take(n).cloned()));
+
+        let mut previous_error: Option<QuadraticIrrational> = None;
+        for p in (&x).convergents().take(10) {
+            let difference = QuadraticIrrational::from_rational(p, Natural::from(d)) - &x;
+            let error = if difference < QuadraticIrrational::ZERO {
+                -difference
+            } else {
+                difference
+            };
+            if let Some(previous_error) = previous_error {
+                assert!(error < previous_error);
+            }
+            previous_error = Some(error);
+        }
+        if let Some(r) = x.to_rational() {
+            assert_eq!((&x).convergents().last().unwrap(), r);
+            assert_eq!(Rational::from_continued_fraction_ref(&head, tail.iter()), r);
+        }
+    });
+}
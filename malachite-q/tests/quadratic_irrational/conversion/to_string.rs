@@ -0,0 +1,51 @@
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_triple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::quadratic_irrational::QuadraticIrrational;
+
+fn new(a: i32, b: i32, c: i32, d: u32) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Integer::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_to_string() {
+    let test = |x: QuadraticIrrational, out| {
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(new(0, 0, 1, 2), "0");
+    test(new(5, 0, 1, 2), "5");
+    test(new(-3, 0, 4, 2), "-3/4");
+    test(new(0, 1, 1, 2), "sqrt(2)");
+    test(new(0, -1, 1, 2), "-sqrt(2)");
+    test(new(0, 3, 1, 2), "3*sqrt(2)");
+    test(new(0, -3, 2, 2), "-3*sqrt(2)/2");
+    test(new(0, 1, 2, 2), "sqrt(2)/2");
+    test(new(-1, 1, 1, 2), "-1+sqrt(2)");
+    test(new(1, -1, 2, 5), "(1-sqrt(5))/2");
+    test(new(-1, -3, 2, 5), "(-1-3*sqrt(5))/2");
+    test(new(2, 4, 6, 8), "(1+2*sqrt(8))/3");
+    assert_eq!(format!("{:<10}|", new(0, 1, 1, 2)), "sqrt(2)   |");
+}
+
+#[test]
+fn to_string_properties() {
+    signed_triple_gen::<i8>().test_properties(|(a, b, c)| {
+        if c == 0 {
+            return;
+        }
+        let x = new(i32::from(a), i32::from(b), i32::from(c), 2);
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert_eq!(s.contains("sqrt"), !x.is_rational());
+        if let Some(r) = x.to_rational() {
+            assert_eq!(s, r.to_string());
+        }
+    });
+}
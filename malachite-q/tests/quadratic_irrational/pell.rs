@@ -0,0 +1,144 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, DivisibleBy};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::quadratic_irrational::pell::{
+    generalized_pell_fundamental_solutions, negative_pell_fundamental_solution,
+    pell_fundamental_solution, pell_solutions,
+};
+
+#[test]
+fn test_pell_fundamental_solution() {
+    let test = |d: u32, out| {
+        assert_eq!(
+            pell_fundamental_solution(&Natural::from(d)).to_debug_string(),
+            out
+        );
+    };
+    test(2, "(3, 2)");
+    test(3, "(2, 1)");
+    test(5, "(9, 4)");
+    test(7, "(8, 3)");
+    test(13, "(649, 180)");
+    test(61, "(1766319049, 226153980)");
+    test(109, "(158070671986249, 15140424455100)");
+}
+
+#[test]
+#[should_panic]
+fn pell_fundamental_solution_fail() {
+    pell_fundamental_solution(&Natural::from(16u32));
+}
+
+#[test]
+fn test_negative_pell_fundamental_solution() {
+    let test = |d: u32, out| {
+        assert_eq!(
+            negative_pell_fundamental_solution(&Natural::from(d)).to_debug_string(),
+            out
+        );
+    };
+    test(2, "Some((1, 1))");
+    test(3, "None");
+    test(5, "Some((2, 1))");
+    test(7, "None");
+    test(13, "Some((18, 5))");
+    test(61, "Some((29718, 3805))");
+    test(109, "Some((8890182, 851525))");
+}
+
+#[test]
+fn test_generalized_pell_fundamental_solutions() {
+    let test = |d: u32, n: i32, out| {
+        assert_eq!(
+            generalized_pell_fundamental_solutions(&Natural::from(d), &Integer::from(n))
+                .to_debug_string(),
+            out
+        );
+    };
+    test(2, 0, "[(0, 0)]");
+    test(2, 1, "[(1, 0)]");
+    test(2, -1, "[(1, 1)]");
+    test(3, -1, "[]");
+    test(2, 7, "[(3, 1), (-3, 1)]");
+    test(5, -4, "[(1, 1), (-1, 1), (4, 2)]");
+    test(10, 9, "[(3, 0), (7, 2), (-7, 2)]");
+    test(13, -3, "[(7, 2), (-7, 2)]");
+    test(61, -3, "[(5639, 722), (-5639, 722)]");
+    test(61, 15, "[(86, 11), (-86, 11), (7162, 917), (-7162, 917)]");
+    test(109, -5, "[(1061372, 101661), (-1061372, 101661)]");
+    // The fundamental solution of x^2 - 991y^2 = 1 has 30 digits, so searching over y up to
+    // Nagell's bound would take about 10^13 steps.
+    test(991, 1, "[(1, 0)]");
+    test(991, -1, "[]");
+    test(991, 9, "[(3, 0), (619372, 19675), (-619372, 19675)]");
+    test(
+        991,
+        1000,
+        "[(77064465898, 2448033438), (-77064465898, 2448033438), \
+        (244540938901490, 7768098933990), (-244540938901490, 7768098933990)]",
+    );
+}
+
+#[test]
+fn test_pell_solutions() {
+    let test = |d: u32, out| {
+        assert_eq!(
+            pell_solutions(&Natural::from(d))
+                .take(4)
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test(2, "[(3, 2), (17, 12), (99, 70), (577, 408)]");
+    test(3, "[(2, 1), (7, 4), (26, 15), (97, 56)]");
+    test(7, "[(8, 3), (127, 48), (2024, 765), (32257, 12192)]");
+}
+
+#[test]
+fn pell_properties() {
+    unsigned_gen::<u16>().test_properties(|d| {
+        let d = Natural::from(d);
+        if (&d).checked_sqrt().is_some() {
+            return;
+        }
+        let (x, y) = pell_fundamental_solution(&d);
+        assert_ne!(y, 0u32);
+        assert_eq!(&x * &x, &d * &y * &y + Natural::from(1u32));
+        if let Some((x, y)) = negative_pell_fundamental_solution(&d) {
+            assert_eq!(&x * &x + Natural::from(1u32), &d * &y * &y);
+        }
+        for (i, (x_i, y_i)) in pell_solutions(&d).take(3).enumerate() {
+            if i == 0 {
+                assert_eq!((&x_i, &y_i), (&x, &y));
+            }
+            assert_eq!(&x_i * &x_i, &d * &y_i * &y_i + Natural::from(1u32));
+        }
+    });
+
+    signed_gen::<i8>().test_properties(|n| {
+        let n = Integer::from(n);
+        for d in [2u32, 3, 5, 6, 7, 13] {
+            let d_i = Integer::from(d);
+            let solutions = generalized_pell_fundamental_solutions(&Natural::from(d), &n);
+            for (x, y) in &solutions {
+                let y = Integer::from(y);
+                assert_eq!(x * x - &d_i * &y * &y, n);
+            }
+            if n != 0u32 {
+                // No two solutions are in the same class.
+                for ((x, y), (x_prev, y_prev)) in solutions.iter().tuple_combinations() {
+                    let y = Integer::from(y);
+                    let y_prev = Integer::from(y_prev);
+                    assert!(
+                        !((x * x_prev - &d_i * &y * &y_prev).divisible_by(&n)
+                            && (&y * x_prev - x * &y_prev).divisible_by(&n))
+                    );
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,93 @@
+use malachite_base::iterators::prefix_to_string;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use malachite_q::exhaustive::exhaustive_positive_rationals;
+use malachite_q::test_util::generators::rational_gen_var_2;
+use malachite_q::Rational;
+use std::iter::successors;
+use std::str::FromStr;
+
+#[test]
+fn test_calkin_wilf_next() {
+    let test = |s, out| {
+        let next = Rational::from_str(s).unwrap().calkin_wilf_next();
+        assert!(next.is_valid());
+        assert_eq!(next.to_string(), out);
+    };
+    test("1", "1/2");
+    test("1/2", "2");
+    test("2", "1/3");
+    test("3/2", "2/3");
+    test("3", "1/4");
+    test("355/113", "113/436");
+
+    assert_eq!(
+        prefix_to_string(
+            successors(Some(Rational::from(1u32)), |x| Some(x.calkin_wilf_next())),
+            20
+        ),
+        prefix_to_string(exhaustive_positive_rationals(), 20)
+    );
+}
+
+#[test]
+#[should_panic]
+fn calkin_wilf_next_fail() {
+    Rational::from(0u32).calkin_wilf_next();
+}
+
+#[test]
+fn test_calkin_wilf_index() {
+    let test = |s, out: u32| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.calkin_wilf_index(), out);
+        assert_eq!(Rational::from_calkin_wilf_index(&Natural::from(out)), x);
+    };
+    test("1", 0);
+    test("1/2", 1);
+    test("2", 2);
+    test("1/3", 3);
+    test("3/2", 4);
+    test("3", 6);
+    test("3/4", 13);
+    test("5", 30);
+    test("1/5", 15);
+    test("355/113", 67107846);
+}
+
+#[test]
+#[should_panic]
+fn calkin_wilf_index_fail() {
+    Rational::from_signeds(-1, 2).calkin_wilf_index();
+}
+
+#[test]
+fn calkin_wilf_properties() {
+    rational_gen_var_2().test_properties(|x| {
+        let next = x.calkin_wilf_next();
+        assert!(next.is_valid());
+        assert!(next > 0u32);
+        // The index of $x$ can have as many bits as the sum of the terms of its continued fraction,
+        // so only small numerators and denominators are checked.
+        if *x.numerator_ref() < 1u32 << 16 && *x.denominator_ref() < 1u32 << 16 {
+            let index = x.calkin_wilf_index();
+            assert_eq!(Rational::from_calkin_wilf_index(&index), x);
+            assert_eq!(next.calkin_wilf_index(), index + Natural::from(1u32));
+        }
+    });
+
+    natural_gen().test_properties(|i| {
+        let x = Rational::from_calkin_wilf_index(&i);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.calkin_wilf_index(), i);
+        assert_eq!(
+            Rational::from_calkin_wilf_index(&(&i + Natural::from(1u32))),
+            x.calkin_wilf_next()
+        );
+    });
+
+    for (i, x) in exhaustive_positive_rationals().take(1000).enumerate() {
+        assert_eq!(x.calkin_wilf_index(), i);
+    }
+}
@@ -0,0 +1,146 @@
+use itertools::Itertools;
+use malachite_base::iterators::prefix_to_string;
+use malachite_base::num::arithmetic::traits::{Ceiling, Floor};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::stern_brocot::farey::{farey, farey_in_closed_interval};
+use malachite_q::test_util::generators::{
+    rational_natural_pair_gen_var_3, rational_natural_pair_gen_var_4,
+};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_farey_neighbors() {
+    let test = |s, n: u32, out| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.farey_neighbors(&Natural::from(n)).to_debug_string(), out);
+    };
+    test("0", 1, "(-1, 1)");
+    test("0", 5, "(-1/5, 1/5)");
+    test("2", 5, "(9/5, 11/5)");
+    test("1/2", 1, "(0, 1)");
+    test("1/2", 5, "(2/5, 3/5)");
+    test("1/3", 7, "(2/7, 2/5)");
+    test("3/7", 6, "(2/5, 1/2)");
+    test("355/113", 100, "(311/99, 22/7)");
+    test("355/113", 113, "(333/106, 22/7)");
+    test("-355/113", 100, "(-22/7, -311/99)");
+}
+
+#[test]
+#[should_panic]
+fn farey_neighbors_fail() {
+    Rational::from(1u32).farey_neighbors(&Natural::from(0u32));
+}
+
+#[test]
+fn test_farey() {
+    let test = |n: u32, out| {
+        assert_eq!(prefix_to_string(farey(Natural::from(n)), 20), out);
+    };
+    test(1, "[0, 1]");
+    test(2, "[0, 1/2, 1]");
+    test(3, "[0, 1/3, 1/2, 2/3, 1]");
+    test(5, "[0, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1]");
+    test(
+        100,
+        "[0, 1/100, 1/99, 1/98, 1/97, 1/96, 1/95, 1/94, 1/93, 1/92, 1/91, 1/90, 1/89, 1/88, \
+        1/87, 1/86, 1/85, 1/84, 1/83, 1/82, ...]",
+    );
+    assert_eq!(farey(Natural::from(100u32)).count(), 3045);
+}
+
+#[test]
+#[should_panic]
+fn farey_fail() {
+    farey(Natural::from(0u32));
+}
+
+#[test]
+fn test_farey_in_closed_interval() {
+    let test = |n: u32, x, y, out| {
+        let x = Rational::from_str(x).unwrap();
+        let y = Rational::from_str(y).unwrap();
+        assert_eq!(
+            prefix_to_string(farey_in_closed_interval(Natural::from(n), &x, &y), 20),
+            out
+        );
+    };
+    test(1, "-2", "2", "[-2, -1, 0, 1, 2]");
+    test(3, "3/10", "7/10", "[1/3, 1/2, 2/3]");
+    test(3, "1/2", "1/2", "[1/2]");
+    test(3, "2/5", "3/5", "[1/2]");
+    test(3, "3/5", "2/5", "[]");
+    test(3, "4/11", "5/11", "[]");
+    test(
+        4,
+        "-1/2",
+        "3/2",
+        "[-1/2, -1/3, -1/4, 0, 1/4, 1/3, 1/2, 2/3, 3/4, 1, 5/4, 4/3, 3/2]",
+    );
+}
+
+#[test]
+fn farey_neighbors_properties() {
+    rational_natural_pair_gen_var_3().test_properties(|(x, n)| {
+        let (left, right) = x.farey_neighbors(&n);
+        assert!(left.is_valid());
+        assert!(right.is_valid());
+        assert!(left < x);
+        assert!(x < right);
+        assert!(*left.denominator_ref() <= n);
+        assert!(*right.denominator_ref() <= n);
+        // If the neighbors are $a/b$ and $c/d$, then $bc - ad = 1$.
+        assert_eq!(
+            &right - &left,
+            Rational::from_naturals(
+                Natural::ONE,
+                left.denominator_ref() * right.denominator_ref()
+            )
+        );
+        assert_eq!((-&x).farey_neighbors(&n), (-right, -left));
+    });
+
+    rational_natural_pair_gen_var_4().test_properties(|(x, n)| {
+        let (left, right) = x.farey_neighbors(&n);
+        // Compare with the closest fractions on either side for each denominator.
+        let n = u64::exact_from(&n);
+        let naive_left = (1..=n)
+            .map(|q| {
+                let q = Rational::from(q);
+                Rational::from((&x * &q).ceiling() - Integer::from(1)) / q
+            })
+            .max()
+            .unwrap();
+        let naive_right = (1..=n)
+            .map(|q| {
+                let q = Rational::from(q);
+                Rational::from((&x * &q).floor() + Integer::from(1)) / q
+            })
+            .min()
+            .unwrap();
+        assert_eq!(left, naive_left);
+        assert_eq!(right, naive_right);
+    });
+}
+
+#[test]
+fn farey_in_closed_interval_properties() {
+    rational_natural_pair_gen_var_4().test_properties(|(x, n)| {
+        let y = &x + Rational::from(1u32);
+        let xs = farey_in_closed_interval(n.clone(), &x, &y).collect_vec();
+        assert!(xs.iter().all(Rational::is_valid));
+        assert!(xs
+            .iter()
+            .all(|z| *z.denominator_ref() <= n && x <= *z && *z <= y));
+        for (a, b) in xs.iter().tuple_windows() {
+            assert_eq!(a.farey_neighbors(&n).1, *b);
+        }
+        // A closed interval of length 1 contains at least $n$ multiples of $1/n$.
+        assert!(xs.len() >= usize::exact_from(&n));
+    });
+}
@@ -0,0 +1,84 @@
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_q::conversion::traits::ContinuedFraction;
+use malachite_q::stern_brocot::path::SternBrocotDirection;
+use malachite_q::test_util::generators::rational_gen_var_2;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_stern_brocot_path() {
+    let test = |s, out| {
+        let x = Rational::from_str(s).unwrap();
+        let path = x.stern_brocot_path();
+        assert_eq!(path.to_debug_string(), out);
+        assert_eq!(Rational::from_stern_brocot_path(&path), x);
+    };
+    test("1", "[]");
+    test("2", "[(Right, 1)]");
+    test("1/2", "[(Left, 1)]");
+    test("1/100", "[(Left, 99)]");
+    test("3/5", "[(Left, 1), (Right, 1), (Left, 1)]");
+    test("22/7", "[(Right, 3), (Left, 6)]");
+    test("355/113", "[(Right, 3), (Left, 7), (Right, 15)]");
+}
+
+#[test]
+#[should_panic]
+fn stern_brocot_path_fail() {
+    Rational::from(0u32).stern_brocot_path();
+}
+
+#[test]
+fn test_from_stern_brocot_path() {
+    let test = |path: &[(SternBrocotDirection, u32)], out| {
+        let path = path
+            .iter()
+            .map(|&(direction, count)| (direction, Natural::from(count)))
+            .collect::<Vec<_>>();
+        let x = Rational::from_stern_brocot_path(&path);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], "1");
+    test(&[(SternBrocotDirection::Left, 0)], "1");
+    test(&[(SternBrocotDirection::Right, 2)], "3");
+    test(
+        &[(SternBrocotDirection::Left, 1), (SternBrocotDirection::Left, 2)],
+        "1/4",
+    );
+    test(
+        &[(SternBrocotDirection::Left, 1), (SternBrocotDirection::Right, 1)],
+        "2/3",
+    );
+}
+
+#[test]
+fn stern_brocot_path_properties() {
+    rational_gen_var_2().test_properties(|x| {
+        let path = x.stern_brocot_path();
+        assert_eq!(Rational::from_stern_brocot_path(&path), x);
+        assert!(path.iter().all(|(_, count)| *count != 0u32));
+        assert!(path.windows(2).all(|w| w[0].0 != w[1].0));
+        assert_eq!(
+            path.first().map(|(direction, _)| *direction),
+            if x > 1u32 {
+                Some(SternBrocotDirection::Right)
+            } else if x < 1u32 {
+                Some(SternBrocotDirection::Left)
+            } else {
+                None
+            }
+        );
+        let (_, cf) = (&x).continued_fraction();
+        assert!(path.len() <= cf.count() + 1);
+
+        let reciprocal_path = (&x).reciprocal().stern_brocot_path();
+        assert_eq!(path.len(), reciprocal_path.len());
+        assert!(path
+            .iter()
+            .zip(reciprocal_path.iter())
+            .all(|((d, m), (e, n))| d != e && m == n));
+    });
+}
@@ -0,0 +1,101 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_q::test_util::generators::{rational_pair_gen, rational_vec_gen};
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_add() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n += y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n += &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() + v.clone()).to_string(), out);
+        assert_eq!((u.clone() + &v).to_string(), out);
+        assert_eq!((&u + v.clone()).to_string(), out);
+        assert_eq!((&u + &v).to_string(), out);
+        assert_eq!((u.clone() + y.clone()).to_string(), out);
+        assert_eq!((u.clone() + &y).to_string(), out);
+        assert_eq!((&u + y.clone()).to_string(), out);
+        assert_eq!((&u + &y).to_string(), out);
+        assert_eq!((x.clone() + v.clone()).to_string(), out);
+        assert_eq!((x.clone() + &v).to_string(), out);
+        assert_eq!((&x + v.clone()).to_string(), out);
+        assert_eq!((&x + &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-123", "-123");
+    test("123", "0", "123");
+    test("1/6", "1/3", "1/2");
+    test("1/2", "-1/2", "0");
+    test("22/7", "99/100", "2893/700");
+    test("-22/7", "-99/100", "-2893/700");
+    test("1/4", "3/4", "1");
+}
+
+#[test]
+fn test_harmonic_sum() {
+    // The partial sums of the harmonic series are never integers, and their denominators grow
+    // quickly, so this exercises the automatic reduction.
+    let mut sum = UnreducedRational::ZERO;
+    let mut expected = Rational::ZERO;
+    for i in 1u32..=500 {
+        let term = Rational::from_unsigneds(1u32, i);
+        sum += &term;
+        expected += term;
+        assert!(sum.is_valid());
+        assert_eq!(sum, expected);
+    }
+    assert_eq!(sum.into_rational(), expected);
+}
+
+#[test]
+fn add_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+        let sum = &u + &v;
+        assert!(sum.is_valid());
+        let expected = &x + &y;
+        assert_eq!(sum, expected);
+        assert_eq!(Rational::from(&sum), expected);
+        assert_eq!(&u + &y, expected);
+        assert_eq!(&x + &v, expected);
+        assert_eq!(&v + &u, expected);
+    });
+}
+
+#[test]
+fn sum_properties() {
+    rational_vec_gen().test_properties(|xs| {
+        let sum = xs
+            .iter()
+            .map(UnreducedRational::from)
+            .sum::<UnreducedRational>();
+        assert!(sum.is_valid());
+        let expected = xs.iter().sum::<Rational>();
+        assert_eq!(sum, expected);
+
+        let us = xs.iter().map(UnreducedRational::from).collect::<Vec<_>>();
+        assert_eq!(us.iter().sum::<UnreducedRational>(), expected);
+    });
+}
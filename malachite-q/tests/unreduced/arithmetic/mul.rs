@@ -0,0 +1,100 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::{rational_pair_gen, rational_vec_gen};
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mul() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n *= y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n *= &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() * v.clone()).to_string(), out);
+        assert_eq!((u.clone() * &v).to_string(), out);
+        assert_eq!((&u * v.clone()).to_string(), out);
+        assert_eq!((&u * &v).to_string(), out);
+        assert_eq!((u.clone() * y.clone()).to_string(), out);
+        assert_eq!((u.clone() * &y).to_string(), out);
+        assert_eq!((&u * y.clone()).to_string(), out);
+        assert_eq!((&u * &y).to_string(), out);
+        assert_eq!((x.clone() * v.clone()).to_string(), out);
+        assert_eq!((x.clone() * &v).to_string(), out);
+        assert_eq!((&x * v.clone()).to_string(), out);
+        assert_eq!((&x * &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-123", "0");
+    test("-123", "0", "0");
+    test("2/3", "3/4", "1/2");
+    test("-2/3", "3/4", "-1/2");
+    test("-2/3", "-3/4", "1/2");
+    test("22/7", "99/100", "1089/350");
+}
+
+#[test]
+fn test_automatic_reduction() {
+    // Multiplying by 2/2 over and over would double the size of the denominator every time if it
+    // were never reduced.
+    let two_halves =
+        UnreducedRational::from_sign_and_naturals(true, Natural::from(2u32), Natural::from(2u32));
+    let mut x = UnreducedRational::ONE;
+    for _ in 0..2000 {
+        x *= &two_halves;
+        assert!(x.is_valid());
+        assert!(x.denominator_ref().significant_bits() <= 1024);
+    }
+    assert_eq!(x, Rational::ONE);
+    x.reduce();
+    assert_eq!(*x.numerator_ref(), 1u32);
+    assert_eq!(*x.denominator_ref(), 1u32);
+}
+
+#[test]
+fn mul_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+        let product = &u * &v;
+        assert!(product.is_valid());
+        let expected = &x * &y;
+        assert_eq!(product, expected);
+        assert_eq!(&u * &y, expected);
+        assert_eq!(&x * &v, expected);
+        assert_eq!(&v * &u, expected);
+    });
+}
+
+#[test]
+fn product_properties() {
+    rational_vec_gen().test_properties(|xs| {
+        let product = xs
+            .iter()
+            .map(UnreducedRational::from)
+            .product::<UnreducedRational>();
+        assert!(product.is_valid());
+        let expected = xs.iter().product::<Rational>();
+        assert_eq!(product, expected);
+    });
+}
@@ -0,0 +1,45 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_neg() {
+    let test = |s, out| {
+        let u = UnreducedRational::from(Rational::from_str(s).unwrap());
+
+        let neg = -u.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let neg = -&u;
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let mut n = u.clone();
+        n.neg_assign();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0");
+    test("123", "-123");
+    test("-123", "123");
+    test("22/7", "-22/7");
+    test("-22/7", "22/7");
+}
+
+#[test]
+fn neg_properties() {
+    rational_gen().test_properties(|x| {
+        let u = UnreducedRational::from(&x);
+        let neg = -&u;
+        assert!(neg.is_valid());
+        assert_eq!(neg, -&x);
+        assert_eq!(-u.clone(), -&x);
+        let mut u_alt = u.clone();
+        u_alt.neg_assign();
+        assert_eq!(u_alt, neg);
+        assert_eq!(-neg, u);
+    });
+}
@@ -0,0 +1,65 @@
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_sub() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n -= y.clone();
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n -= &y;
+        assert_eq!(n.to_string(), out);
+
+        assert_eq!((u.clone() - v.clone()).to_string(), out);
+        assert_eq!((u.clone() - &v).to_string(), out);
+        assert_eq!((&u - v.clone()).to_string(), out);
+        assert_eq!((&u - &v).to_string(), out);
+        assert_eq!((u.clone() - y.clone()).to_string(), out);
+        assert_eq!((u.clone() - &y).to_string(), out);
+        assert_eq!((&u - y.clone()).to_string(), out);
+        assert_eq!((&u - &y).to_string(), out);
+        assert_eq!((x.clone() - v.clone()).to_string(), out);
+        assert_eq!((x.clone() - &v).to_string(), out);
+        assert_eq!((&x - v.clone()).to_string(), out);
+        assert_eq!((&x - &v).to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-123", "123");
+    test("1/6", "1/3", "-1/6");
+    test("1/2", "1/2", "0");
+    test("22/7", "99/100", "1507/700");
+}
+
+#[test]
+fn sub_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+        let diff = &u - &v;
+        assert!(diff.is_valid());
+        let expected = &x - &y;
+        assert_eq!(diff, expected);
+        assert_eq!(&u - &y, expected);
+        assert_eq!(&x - &v, expected);
+        assert_eq!(-(&v - &u), expected);
+        assert_eq!(diff + &v, x);
+    });
+}
@@ -0,0 +1,63 @@
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_cmp() {
+    let test = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+        assert_eq!(u.cmp(&v), out);
+        assert_eq!(u.partial_cmp(&y), Some(out));
+        assert_eq!(x.partial_cmp(&v), Some(out));
+        assert_eq!(u == v, out == Ordering::Equal);
+        assert_eq!(u == y, out == Ordering::Equal);
+        assert_eq!(x == v, out == Ordering::Equal);
+    };
+    test("0", "0", Ordering::Equal);
+    test("0", "1/2", Ordering::Less);
+    test("0", "-1/2", Ordering::Greater);
+    test("2/3", "1/2", Ordering::Greater);
+    test("-2/3", "-1/2", Ordering::Less);
+    test("-2/3", "1/2", Ordering::Less);
+    test("22/7", "22/7", Ordering::Equal);
+
+    // Equal values with different representations
+    let x =
+        UnreducedRational::from_sign_and_naturals(false, Natural::from(4u32), Natural::from(6u32));
+    let y =
+        UnreducedRational::from_sign_and_naturals(false, Natural::from(6u32), Natural::from(9u32));
+    assert_eq!(x, y);
+    assert_eq!(x.cmp(&y), Ordering::Equal);
+    assert_eq!(x, Rational::from_signeds(-2, 3));
+}
+
+#[test]
+fn cmp_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let u = UnreducedRational::from(&x);
+        let v = UnreducedRational::from(&y);
+        let ord = x.cmp(&y);
+        assert_eq!(u.cmp(&v), ord);
+        assert_eq!(v.cmp(&u), ord.reverse());
+        assert_eq!(u.partial_cmp(&y), Some(ord));
+        assert_eq!(y.partial_cmp(&u), Some(ord.reverse()));
+        assert_eq!(u == v, x == y);
+        assert_eq!(u == y, x == y);
+
+        // Scaling the numerator and denominator by the same factor doesn't change the value
+        let scaled = &u
+            * UnreducedRational::from_sign_and_naturals(
+                true,
+                Natural::from(6u32),
+                Natural::from(6u32),
+            );
+        assert_eq!(scaled, u);
+        assert_eq!(scaled.cmp(&v), ord);
+    });
+}
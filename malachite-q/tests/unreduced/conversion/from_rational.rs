@@ -0,0 +1,43 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::unreduced::UnreducedRational;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_sign_and_naturals() {
+    let test = |sign, n: u32, d: u32, out| {
+        let x = UnreducedRational::from_sign_and_naturals(sign, Natural::from(n), Natural::from(d));
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_rational().to_string(), out);
+        assert_eq!(x.into_rational().to_string(), out);
+    };
+    test(true, 0, 5, "0");
+    test(false, 0, 5, "0");
+    test(true, 4, 6, "2/3");
+    test(false, 4, 6, "-2/3");
+    test(true, 6, 3, "2");
+}
+
+#[test]
+#[should_panic]
+fn from_sign_and_naturals_fail() {
+    UnreducedRational::from_sign_and_naturals(true, Natural::from(1u32), Natural::ZERO);
+}
+
+#[test]
+fn from_rational_properties() {
+    rational_gen().test_properties(|x| {
+        let u = UnreducedRational::from(x.clone());
+        assert!(u.is_valid());
+        assert_eq!(UnreducedRational::from(&x), u);
+        assert_eq!(u.numerator_ref(), x.numerator_ref());
+        assert_eq!(u.denominator_ref(), x.denominator_ref());
+        assert_eq!(u.to_string(), x.to_string());
+        assert_eq!(format!("{u:?}"), format!("{x:?}"));
+        assert_eq!(Rational::from(&u), x);
+        assert_eq!(Rational::from(u.clone()), x);
+        assert_eq!(u.into_rational(), x);
+    });
+}